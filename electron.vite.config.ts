@@ -19,7 +19,7 @@ export default defineConfig({
     },
     build: {
       rollupOptions: {
-        external: ['@libsql/client', 'bufferutil', 'utf-8-validate'],
+        external: ['@libsql/client', 'bufferutil', 'utf-8-validate', 'node-llama-cpp'],
         output: {
           // 彻底禁用代码分割 - 返回 null 强制单文件打包
           manualChunks: undefined,
@@ -83,7 +83,10 @@ export default defineConfig({
           index: resolve(__dirname, 'src/renderer/index.html'),
           miniWindow: resolve(__dirname, 'src/renderer/miniWindow.html'),
           selectionToolbar: resolve(__dirname, 'src/renderer/selectionToolbar.html'),
-          selectionAction: resolve(__dirname, 'src/renderer/selectionAction.html')
+          selectionAction: resolve(__dirname, 'src/renderer/selectionAction.html'),
+          lockScreen: resolve(__dirname, 'src/renderer/lockScreen.html'),
+          transcription: resolve(__dirname, 'src/renderer/transcription.html'),
+          presentation: resolve(__dirname, 'src/renderer/presentation.html')
         }
       }
     }
@@ -5,15 +5,20 @@ export enum IpcChannel {
   App_SetLanguage = 'app:set-language',
   App_ShowUpdateDialog = 'app:show-update-dialog',
   App_CheckForUpdate = 'app:check-for-update',
+  App_VerifyInstallation = 'app:verify-installation',
+  App_RepairInstallation = 'app:repair-installation',
   App_Reload = 'app:reload',
   App_Info = 'app:info',
   App_Proxy = 'app:proxy',
   App_SetLaunchToTray = 'app:set-launch-to-tray',
   App_SetTray = 'app:set-tray',
   App_SetTrayOnClose = 'app:set-tray-on-close',
+  App_SetTrayTooltip = 'app:set-tray-tooltip',
+  App_SetTrayStatus = 'app:set-tray-status',
   App_SetTheme = 'app:set-theme',
   App_SetAutoUpdate = 'app:set-auto-update',
   App_SetFeedUrl = 'app:set-feed-url',
+  App_SetContentProtection = 'app:set-content-protection',
   App_HandleZoomFactor = 'app:handle-zoom-factor',
   App_Select = 'app:select',
   App_HasWritePermission = 'app:has-write-permission',
@@ -25,6 +30,7 @@ export enum IpcChannel {
   App_GetBinaryPath = 'app:get-binary-path',
   App_InstallUvBinary = 'app:install-uv-binary',
   App_InstallBunBinary = 'app:install-bun-binary',
+  App_Error = 'app:error',
 
   App_QuoteToMain = 'app:quote-to-main',
 
@@ -32,6 +38,67 @@ export enum IpcChannel {
   Notification_OnClick = 'notification:on-click',
 
   Webview_SetOpenLinkExternal = 'webview:set-open-link-external',
+  Webview_ClearEphemeralSession = 'webview:clear-ephemeral-session',
+  Webview_SetShortcutPassThrough = 'webview:set-shortcut-pass-through',
+  Webview_ShortcutForwarded = 'webview:shortcut-forwarded',
+  Webview_SetContextMenu = 'webview:set-context-menu',
+  Webview_ContextMenuAction = 'webview:context-menu-action',
+  Webview_Suspend = 'webview:suspend',
+  Webview_Resume = 'webview:resume',
+  Webview_SetContentBlocking = 'webview:set-content-blocking',
+  Webview_UpdateFilterLists = 'webview:update-filter-lists',
+
+  // mini app data handoff
+  MiniApp_RegisterWebview = 'miniapp:register-webview',
+  MiniApp_UnregisterWebview = 'miniapp:unregister-webview',
+  MiniApp_GetIntents = 'miniapp:get-intents',
+  MiniApp_SendIntent = 'miniapp:send-intent',
+  MiniApp_Navigate = 'miniapp:navigate',
+  MiniApp_GoBack = 'miniapp:go-back',
+  MiniApp_GoForward = 'miniapp:go-forward',
+  MiniApp_Reload = 'miniapp:reload',
+  MiniApp_GetNavigationState = 'miniapp:get-navigation-state',
+  MiniApp_NavigationChanged = 'miniapp:navigation-changed',
+  MiniApp_SetZoom = 'miniapp:set-zoom',
+  MiniApp_GetZoom = 'miniapp:get-zoom',
+  MiniApp_Capture = 'miniapp:capture',
+  MiniApp_PrintToPdf = 'miniapp:print-to-pdf',
+  MiniApp_GetCookies = 'miniapp:get-cookies',
+  MiniApp_SetCookie = 'miniapp:set-cookie',
+  MiniApp_ClearCookies = 'miniapp:clear-cookies',
+  MiniApp_PermissionRequested = 'miniapp:permission-requested',
+  MiniApp_RespondPermission = 'miniapp:respond-permission',
+  MiniApp_GetPermissions = 'miniapp:get-permissions',
+  MiniApp_ResetPermissions = 'miniapp:reset-permissions',
+  MiniApp_GetStats = 'miniapp:get-stats',
+  MiniApp_StatsUpdated = 'miniapp:stats-updated',
+  MiniApp_SetMuted = 'miniapp:set-muted',
+  MiniApp_GetAudioState = 'miniapp:get-audio-state',
+  MiniApp_AudioStateChanged = 'miniapp:audio-state-changed',
+  MiniApp_PreparePreload = 'miniapp:prepare-preload',
+  MiniApp_PostBridgeMessage = 'miniapp:post-bridge-message',
+  MiniApp_BridgeInvoke = 'miniapp:bridge-invoke',
+  MiniApp_SetVisible = 'miniapp:set-visible',
+  MiniApp_Lifecycle = 'miniapp:lifecycle',
+  MiniApp_GetAll = 'miniapp:get-all',
+  MiniApp_HideAll = 'miniapp:hide-all',
+  MiniApp_DestroyAll = 'miniapp:destroy-all',
+  MiniApp_HideAllRequested = 'miniapp:hide-all-requested',
+  MiniApp_DestroyAllRequested = 'miniapp:destroy-all-requested',
+  MiniApp_RestoreLayout = 'miniapp:restore-layout',
+  MiniApp_GetManifestApps = 'miniapp:get-manifest-apps',
+  MiniApp_GetRegistry = 'miniapp:get-registry',
+  MiniApp_GetInstalledPackages = 'miniapp:get-installed-packages',
+  MiniApp_InstallPackage = 'miniapp:install-package',
+  MiniApp_InstallFromUrl = 'miniapp:install-from-url',
+  MiniApp_UninstallPackage = 'miniapp:uninstall-package',
+  MiniApp_GetTrustedKeys = 'miniapp:get-trusted-keys',
+  MiniApp_SetTrustedKeys = 'miniapp:set-trusted-keys',
+  MiniApp_CheckUpdates = 'miniapp:check-updates',
+  MiniApp_UpdatePackage = 'miniapp:update-package',
+  MiniApp_PackageUpdated = 'miniapp:package-updated',
+  MiniApp_GetAutoUpdate = 'miniapp:get-auto-update',
+  MiniApp_SetAutoUpdate = 'miniapp:set-auto-update',
 
   // Open
   Open_Path = 'open:path',
@@ -42,12 +109,143 @@ export enum IpcChannel {
   Config_Set = 'config:set',
   Config_Get = 'config:get',
 
+  Policy_Get = 'policy:get',
+
+  // confirmation of agent actions
+  ActionPolicy_GetRules = 'action-policy:get-rules',
+  ActionPolicy_SetRules = 'action-policy:set-rules',
+  ActionPolicy_ListPending = 'action-policy:list-pending',
+  ActionPolicy_Approve = 'action-policy:approve',
+  ActionPolicy_Deny = 'action-policy:deny',
+  ActionPolicy_ApprovalRequested = 'action-policy:approval-requested',
+  ActionPolicy_ApprovalResolved = 'action-policy:approval-resolved',
+
+  // accessibility
+  Accessibility_GetState = 'accessibility:get-state',
+  Accessibility_Announce = 'accessibility:announce',
+  Accessibility_StateChanged = 'accessibility:state-changed',
+
+  // app lock
+  Lock_Lock = 'lock:lock',
+  Lock_Unlock = 'lock:unlock',
+  Lock_UnlockWithBiometric = 'lock:unlock-with-biometric',
+  Lock_GetState = 'lock:get-state',
+  Lock_SetPassphrase = 'lock:set-passphrase',
+  Lock_SetIdleTimeout = 'lock:set-idle-timeout',
+  Lock_StateChanged = 'lock:state-changed',
+
+  // health monitor
+  Health_GetReport = 'health:get-report',
+  Health_ComponentUnhealthy = 'health:component-unhealthy',
+
+  // secure conversations
+  SecureTopic_Seal = 'secure-topic:seal',
+  SecureTopic_Open = 'secure-topic:open',
+  SecureTopic_SealFiles = 'secure-topic:seal-files',
+  SecureTopic_OpenFiles = 'secure-topic:open-files',
+  SecureTopic_RemoveKey = 'secure-topic:remove-key',
+  SecureTopic_Unlock = 'secure-topic:unlock',
+
+  // memory and performance profiling
+  Perf_GetSnapshot = 'perf:get-snapshot',
+  Perf_StartTrace = 'perf:start-trace',
+  Perf_StopTrace = 'perf:stop-trace',
+  Perf_GetStartupReport = 'perf:get-startup-report',
+
+  // log redaction and support bundles
+  Log_PreviewRedaction = 'log:preview-redaction',
+  Log_GetRedactionPatterns = 'log:get-redaction-patterns',
+  Log_SetRedactionPatterns = 'log:set-redaction-patterns',
+  Log_ExportSupportBundle = 'log:export-support-bundle',
+
+  // command registry
+  Command_List = 'command:list',
+  Command_Invoke = 'command:invoke',
+  Command_RegisterRenderer = 'command:register-renderer',
+
+  // worker pool
+  WorkerPool_GetStatus = 'worker-pool:get-status',
+  WorkerPool_SetSize = 'worker-pool:set-size',
+
+  // audit log
+  Audit_GetLog = 'audit:get-log',
+  Audit_Export = 'audit:export',
+
+  // workspace bundle
+  Workspace_Export = 'workspace:export',
+  Workspace_Import = 'workspace:import',
+  Workspace_GetFingerprint = 'workspace:get-fingerprint',
+  Workspace_TrustSigner = 'workspace:trust-signer',
+
+  // download manager
+  Download_Start = 'download:start',
+  Download_Pause = 'download:pause',
+  Download_Resume = 'download:resume',
+  Download_Cancel = 'download:cancel',
+  Download_Remove = 'download:remove',
+  Download_List = 'download:list',
+  Download_Updated = 'download:updated',
+  Download_GetDirectory = 'download:get-directory',
+  Download_SetDirectory = 'download:set-directory',
+
+  // local model files
+  ModelFile_List = 'model-file:list',
+  ModelFile_Download = 'model-file:download',
+  ModelFile_Import = 'model-file:import',
+  ModelFile_Verify = 'model-file:verify',
+  ModelFile_Remove = 'model-file:remove',
+  ModelFile_Cleanup = 'model-file:cleanup',
+
+  // local inference
+  LocalInference_GetBackendInfo = 'local-inference:get-backend-info',
+  LocalInference_LoadModel = 'local-inference:load-model',
+  LocalInference_UnloadModel = 'local-inference:unload-model',
+  LocalInference_ListModels = 'local-inference:list-models',
+  LocalInference_GetEmbeddingDimensions = 'local-inference:get-embedding-dimensions',
+  LocalInference_ChatCompletion = 'local-inference:chat-completion',
+  LocalInference_Abort = 'local-inference:abort',
+  LocalInference_Chunk = 'local-inference:chunk',
+  LocalInference_ChunkAck = 'local-inference:chunk-ack',
+
+  // feedback
+  Feedback_PlaySound = 'feedback:play-sound',
+  Feedback_ListThemes = 'feedback:list-themes',
+
+  // long-term memory
+  Memory_Add = 'memory:add',
+  Memory_Search = 'memory:search',
+  Memory_List = 'memory:list',
+  Memory_Delete = 'memory:delete',
+  Memory_Clear = 'memory:clear',
+  Memory_Export = 'memory:export',
+
+  // conversation archive
+  Archive_List = 'archive:list',
+  Archive_Add = 'archive:add',
+  Archive_Get = 'archive:get',
+  Archive_Remove = 'archive:remove',
+
   MiniWindow_Show = 'miniwindow:show',
   MiniWindow_Hide = 'miniwindow:hide',
   MiniWindow_Close = 'miniwindow:close',
   MiniWindow_Toggle = 'miniwindow:toggle',
   MiniWindow_SetPin = 'miniwindow:set-pin',
 
+  // tiling layouts of the main window and the quick assistant
+  SnapLayout_List = 'snap-layout:list',
+  SnapLayout_Apply = 'snap-layout:apply',
+  SnapLayout_Save = 'snap-layout:save',
+  SnapLayout_Remove = 'snap-layout:remove',
+
+  // live transcription overlay
+  Transcription_Open = 'transcription:open',
+  Transcription_Close = 'transcription:close',
+  Transcription_SendToChat = 'transcription:send-to-chat',
+
+  // read-only full-screen presentation of a conversation
+  Presentation_Enter = 'presentation:enter',
+  Presentation_Exit = 'presentation:exit',
+
   // Mcp
   Mcp_AddServer = 'mcp:add-server',
   Mcp_RemoveServer = 'mcp:remove-server',
@@ -63,6 +261,21 @@ export enum IpcChannel {
   Mcp_ServersChanged = 'mcp:servers-changed',
   Mcp_ServersUpdated = 'mcp:servers-updated',
   Mcp_CheckConnectivity = 'mcp:check-connectivity',
+  Mcp_GetRegistry = 'mcp:get-registry',
+  Mcp_CheckDependencies = 'mcp:check-dependencies',
+  Mcp_GetInstalled = 'mcp:get-installed',
+  Mcp_Install = 'mcp:install',
+  Mcp_Uninstall = 'mcp:uninstall',
+  Mcp_CheckHealth = 'mcp:check-health',
+  Mcp_CheckUpdates = 'mcp:check-updates',
+  Mcp_Update = 'mcp:update',
+  Mcp_GetAutoUpdate = 'mcp:get-auto-update',
+  Mcp_SetAutoUpdate = 'mcp:set-auto-update',
+  Mcp_ServerUpdated = 'mcp:server-updated',
+  Mcp_ListToolCalls = 'mcp:list-tool-calls',
+  Mcp_GetToolCall = 'mcp:get-tool-call',
+  Mcp_ReplayToolCall = 'mcp:replay-tool-call',
+  Mcp_ClearToolCalls = 'mcp:clear-tool-calls',
 
   //copilot
   Copilot_GetAuthMessage = 'copilot:get-auth-message',
@@ -95,6 +308,99 @@ export enum IpcChannel {
   VertexAI_GetAuthHeaders = 'vertexai:get-auth-headers',
   VertexAI_ClearAuthCache = 'vertexai:clear-auth-cache',
 
+  // AWS Bedrock
+  AwsBedrock_SetCredentials = 'aws-bedrock:set-credentials',
+  AwsBedrock_ClearCredentials = 'aws-bedrock:clear-credentials',
+  AwsBedrock_GetAccessKeyId = 'aws-bedrock:get-access-key-id',
+  AwsBedrock_SignRequest = 'aws-bedrock:sign-request',
+
+  // local API server
+  ApiServer_GetStatus = 'api-server:get-status',
+  ApiServer_SetEnabled = 'api-server:set-enabled',
+  ApiServer_SetPort = 'api-server:set-port',
+  ApiServer_RegenerateToken = 'api-server:regenerate-token',
+  ApiServer_Request = 'api-server:request',
+  ApiServer_Response = 'api-server:response',
+  ApiServer_Abort = 'api-server:abort',
+  ApiServer_Publish = 'api-server:publish',
+  ApiServer_Ready = 'api-server:ready',
+
+  // inbound webhooks of the local API server
+  Webhook_List = 'webhook:list',
+  Webhook_Create = 'webhook:create',
+  Webhook_Update = 'webhook:update',
+  Webhook_Remove = 'webhook:remove',
+  Webhook_RegenerateSecret = 'webhook:regenerate-secret',
+  Webhook_GetLogs = 'webhook:get-logs',
+
+  // outbound integrations (Slack, Discord, Telegram, HTTP)
+  Outbound_List = 'outbound:list',
+  Outbound_Save = 'outbound:save',
+  Outbound_Remove = 'outbound:remove',
+  Outbound_Test = 'outbound:test',
+  Outbound_Emit = 'outbound:emit',
+  Outbound_GetLogs = 'outbound:get-logs',
+
+  // csv, tsv and xlsx previews
+  Tabular_Parse = 'tabular:parse',
+
+  // line ranges of large text files
+  TextPreview_ReadLines = 'text-preview:read-lines',
+
+  // unsaved drafts journaled for recovery after a crash
+  Session_SaveDraftState = 'session:save-draft-state',
+  Session_GetRecovered = 'session:get-recovered',
+  Session_DiscardRecovered = 'session:discard-recovered',
+
+  // print the calling view, e.g. the chat, to pdf
+  Print_ToPdf = 'print:to-pdf',
+
+  // user templates
+  Template_List = 'template:list',
+  Template_Save = 'template:save',
+  Template_Remove = 'template:remove',
+  Template_Render = 'template:render',
+  Template_RenderSource = 'template:render-source',
+  Template_ExpandIncludes = 'template:expand-includes',
+
+  // share target
+  Share_Received = 'share:received',
+  Share_TakePending = 'share:take-pending',
+
+  // smart paste
+  Clipboard_Classify = 'clipboard:classify',
+
+  // link previews
+  LinkPreview_Fetch = 'link-preview:fetch',
+
+  // .metheuschat transcripts opened from the OS
+  Transcript_Opened = 'transcript:opened',
+  Transcript_TakePending = 'transcript:take-pending',
+
+  // conversation sharing as HTML
+  ConversationShare_Share = 'conversation-share:share',
+  ConversationShare_List = 'conversation-share:list',
+  ConversationShare_Revoke = 'conversation-share:revoke',
+
+  // scheduled messages
+  Scheduler_Schedule = 'scheduler:schedule',
+  Scheduler_List = 'scheduler:list',
+  Scheduler_Cancel = 'scheduler:cancel',
+  Scheduler_Completed = 'scheduler:completed',
+
+  // folders mounted to conversations
+  ConversationWorkspace_Mount = 'conversation-workspace:mount',
+  ConversationWorkspace_Unmount = 'conversation-workspace:unmount',
+  ConversationWorkspace_List = 'conversation-workspace:list',
+  ConversationWorkspace_GetChanges = 'conversation-workspace:get-changes',
+  ConversationWorkspace_ComputeDiff = 'conversation-workspace:compute-diff',
+  ConversationWorkspace_ApplyPatch = 'conversation-workspace:apply-patch',
+
+  // browser extension bridge
+  BrowserBridge_GetStatus = 'browser-bridge:get-status',
+  BrowserBridge_Install = 'browser-bridge:install',
+  BrowserBridge_Uninstall = 'browser-bridge:uninstall',
+
   Windows_ResetMinimumSize = 'window:reset-minimum-size',
   Windows_SetMinimumSize = 'window:set-minimum-size',
 
@@ -105,6 +411,9 @@ export enum IpcChannel {
   KnowledgeBase_Remove = 'knowledge-base:remove',
   KnowledgeBase_Search = 'knowledge-base:search',
   KnowledgeBase_Rerank = 'knowledge-base:rerank',
+  KnowledgeBase_SyncSource = 'knowledge-base:sync-source',
+  KnowledgeBase_ResolveCitation = 'knowledge-base:resolve-citation',
+  KnowledgeBase_OpenCitation = 'knowledge-base:open-citation',
 
   //file
   File_Open = 'file:open',
@@ -151,6 +460,7 @@ export enum IpcChannel {
   // system
   System_GetDeviceType = 'system:getDeviceType',
   System_GetHostname = 'system:getHostname',
+  System_GetIdleTime = 'system:getIdleTime',
 
   // DevTools
   System_ToggleDevTools = 'system:toggleDevTools',
@@ -173,6 +483,8 @@ export enum IpcChannel {
 
   HideMiniWindow = 'hide-mini-window',
   ShowMiniWindow = 'show-mini-window',
+  MiniWindowPinChanged = 'mini-window-pin-changed',
+  MiniWindowListen = 'mini-window-listen',
 
   ReduxStateChange = 'redux-state-change',
   ReduxStoreReady = 'redux-store-ready',
@@ -198,6 +510,7 @@ export enum IpcChannel {
   Selection_ToolbarDetermineSize = 'selection:toolbar-determine-size',
   Selection_WriteToClipboard = 'selection:write-to-clipboard',
   Selection_SetEnabled = 'selection:set-enabled',
+  Selection_SetPermissionGranted = 'selection:set-permission-granted',
   Selection_SetTriggerMode = 'selection:set-trigger-mode',
   Selection_SetFilterMode = 'selection:set-filter-mode',
   Selection_SetFilterList = 'selection:set-filter-list',
@@ -0,0 +1,107 @@
+export type ManagerErrorCode =
+  | 'not_found'
+  | 'invalid_argument'
+  | 'already_exists'
+  | 'conflict'
+  | 'unavailable'
+  | 'busy'
+  | 'permission_denied'
+  | 'cancelled'
+  | 'internal'
+
+export interface SerializedManagerError {
+  name: 'ManagerError'
+  manager: string
+  code: ManagerErrorCode
+  message: string
+  // whether retrying or a recovery action can succeed, the renderer only offers actions for these
+  recoverable: boolean
+  // values the renderer needs to describe the failure or recover, e.g. the id of the failed server
+  details?: Record<string, unknown>
+}
+
+export interface ManagerErrorOptions {
+  recoverable?: boolean
+  details?: Record<string, unknown>
+}
+
+// failures that are worth retrying by default
+const RECOVERABLE_CODES: ManagerErrorCode[] = ['unavailable', 'busy', 'conflict', 'cancelled']
+
+// Electron only keeps the message of errors thrown by ipcMain.handle, so the structured error is encoded in it
+const IPC_PREFIX = 'ManagerError:'
+
+/**
+ * Typed error shared by the main process managers and the renderer.
+ *
+ * Managers throw it with a stable code instead of plain strings, the IPC layer serializes it and the
+ * renderer restores it with `ManagerError.parse` to decide how to handle the failure. Failures of background work
+ * reach the renderer as `app:error` events carrying the same serialized form.
+ */
+export class ManagerError extends Error {
+  public readonly recoverable: boolean
+  public readonly details?: Record<string, unknown>
+
+  constructor(
+    public readonly manager: string,
+    public readonly code: ManagerErrorCode,
+    message: string,
+    options: ManagerErrorOptions = {}
+  ) {
+    super(message)
+    this.name = 'ManagerError'
+    this.recoverable = options.recoverable ?? RECOVERABLE_CODES.includes(code)
+    this.details = options.details
+  }
+
+  /**
+   * Wrap any thrown value, keeping existing manager errors as they are
+   */
+  static from(manager: string, error: unknown): ManagerError {
+    if (error instanceof ManagerError) return error
+    const message = error instanceof Error ? error.message : String(error)
+    return new ManagerError(manager, 'internal', message)
+  }
+
+  /**
+   * Restore a manager error from an IPC rejection, returns null for other errors
+   */
+  static parse(error: unknown): ManagerError | null {
+    if (error instanceof ManagerError) return error
+
+    const message = error instanceof Error ? error.message : typeof error === 'string' ? error : ''
+    const index = message.indexOf(IPC_PREFIX)
+    if (index < 0) return null
+
+    try {
+      return ManagerError.fromJSON(JSON.parse(message.slice(index + IPC_PREFIX.length)))
+    } catch {
+      return null
+    }
+  }
+
+  static fromJSON(data: SerializedManagerError): ManagerError {
+    return new ManagerError(data.manager, data.code, data.message, {
+      recoverable: data.recoverable,
+      details: data.details
+    })
+  }
+
+  toJSON(): SerializedManagerError {
+    return {
+      name: 'ManagerError',
+      manager: this.manager,
+      code: this.code,
+      message: this.message,
+      recoverable: this.recoverable,
+      details: this.details
+    }
+  }
+
+  /**
+   * Error to throw from an IPC handler, the renderer side restores it with `ManagerError.parse`
+   */
+  toIpcError(): Error {
+    return new Error(`${IPC_PREFIX}${JSON.stringify(this.toJSON())}`)
+  }
+}
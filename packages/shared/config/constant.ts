@@ -404,6 +404,15 @@ export const MB = 1024 * KB
 export const GB = 1024 * MB
 export const defaultLanguage = 'en-US'
 
+// mini apps launched in incognito use an in-memory partition with this prefix, wiped when the webview closes
+export const EPHEMERAL_WEBVIEW_PARTITION_PREFIX = 'minapp-incognito:'
+
+// channel of messages between a mini app's `window.metheus` bridge and the window hosting its webview
+export const MINIAPP_BRIDGE_CHANNEL = 'metheus'
+
+// exported conversation bundles, opening one from the file manager imports it as a read-only conversation
+export const TRANSCRIPT_EXTENSION = '.metheuschat'
+
 export enum FeedUrl {
   PRODUCTION = 'https://releases.cherry-ai.com',
   EARLY_ACCESS = 'https://github.com/CherryHQ/cherry-studio/releases/latest/download'
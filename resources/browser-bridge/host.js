@@ -0,0 +1,105 @@
+/**
+ * Native messaging host for the Cherry Studio browser extension.
+ *
+ * The browser starts this script through the wrapper written by the app and talks to it
+ * over stdio using the native messaging framing (4-byte little-endian length + JSON).
+ * Messages are relayed as newline-delimited JSON to the local socket of the running app.
+ *
+ * Usage: host.js <socket-path>
+ */
+const net = require('net')
+
+const socketPath = process.argv[2]
+let socket = null
+let connected = false
+let queue = []
+
+function writeNative(message) {
+  const json = Buffer.from(JSON.stringify(message), 'utf8')
+  const header = Buffer.alloc(4)
+  header.writeUInt32LE(json.length, 0)
+  process.stdout.write(Buffer.concat([header, json]))
+}
+
+function fail(message, error) {
+  writeNative({ id: message && message.id, ok: false, error })
+}
+
+function connect() {
+  socket = net.createConnection(socketPath)
+  let buffer = ''
+
+  socket.on('connect', () => {
+    connected = true
+    for (const message of queue) {
+      socket.write(JSON.stringify(message) + '\n')
+    }
+    queue = []
+  })
+
+  socket.on('data', (data) => {
+    buffer += data.toString('utf8')
+    let index
+    while ((index = buffer.indexOf('\n')) >= 0) {
+      const line = buffer.slice(0, index)
+      buffer = buffer.slice(index + 1)
+      if (line.trim()) {
+        try {
+          writeNative(JSON.parse(line))
+        } catch {
+          // ignore malformed lines from the app
+        }
+      }
+    }
+  })
+
+  socket.on('error', () => {
+    // the app is not running, answer every queued message so the extension does not hang
+    for (const message of queue) {
+      fail(message, 'Cherry Studio is not running')
+    }
+    queue = []
+  })
+
+  socket.on('close', () => {
+    connected = false
+    socket = null
+  })
+}
+
+function send(message) {
+  if (!socket) {
+    connect()
+  }
+  if (connected) {
+    socket.write(JSON.stringify(message) + '\n')
+  } else {
+    queue.push(message)
+  }
+}
+
+let input = Buffer.alloc(0)
+
+process.stdin.on('data', (chunk) => {
+  input = Buffer.concat([input, chunk])
+
+  while (input.length >= 4) {
+    const length = input.readUInt32LE(0)
+    if (input.length < 4 + length) break
+
+    const body = input.subarray(4, 4 + length).toString('utf8')
+    input = input.subarray(4 + length)
+
+    try {
+      send(JSON.parse(body))
+    } catch {
+      fail(null, 'Invalid message')
+    }
+  }
+})
+
+// the browser closes stdin when the extension disconnects
+process.stdin.on('end', () => {
+  if (socket) socket.end()
+  process.exit(0)
+})
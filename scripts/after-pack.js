@@ -1,7 +1,13 @@
 const { Arch } = require('electron-builder')
+const crypto = require('crypto')
 const fs = require('fs')
 const path = require('path')
 
+// checked by IntegrityService at runtime, keep in sync
+const INTEGRITY_MANIFEST = 'integrity.json'
+// native binaries are rewritten by code signing after packing, so their checksums would never match
+const UNHASHED_EXTENSIONS = ['.node', '.dylib', '.dll', '.exe', '.so']
+
 exports.default = async function (context) {
   const platform = context.packager.platform.name
   const arch = context.arch
@@ -41,6 +47,38 @@ exports.default = async function (context) {
     fs.rmSync(path.join(context.appOutDir, 'LICENSE.electron.txt'), { force: true })
     fs.rmSync(path.join(context.appOutDir, 'LICENSES.chromium.html'), { force: true })
   }
+
+  const resourcesPath =
+    platform === 'mac'
+      ? path.join(context.appOutDir, 'Cherry Studio.app', 'Contents', 'Resources')
+      : path.join(context.appOutDir, 'resources')
+  writeIntegrityManifest(resourcesPath, context.packager.appInfo.version)
+}
+
+/**
+ * 生成资源文件的校验清单，用于检测安装是否被篡改或损坏
+ * @param {*} resourcesPath
+ * @param {*} version
+ */
+function writeIntegrityManifest(resourcesPath, version) {
+  const files = {}
+  const walk = (dir) => {
+    for (const entry of fs.readdirSync(dir, { withFileTypes: true })) {
+      const entryPath = path.join(dir, entry.name)
+      if (entry.isDirectory()) {
+        walk(entryPath)
+      } else if (entry.isFile() && !UNHASHED_EXTENSIONS.includes(path.extname(entry.name).toLowerCase())) {
+        const relativePath = path.relative(resourcesPath, entryPath).split(path.sep).join('/')
+        if (relativePath === INTEGRITY_MANIFEST) continue
+        files[relativePath] = crypto.createHash('sha256').update(fs.readFileSync(entryPath)).digest('hex')
+      }
+    }
+  }
+  walk(resourcesPath)
+
+  const manifest = { version, algorithm: 'sha256', files }
+  fs.writeFileSync(path.join(resourcesPath, INTEGRITY_MANIFEST), JSON.stringify(manifest, null, 2))
+  console.log(`[After Pack] Wrote integrity manifest with ${Object.keys(files).length} files`)
 }
 
 /**
@@ -0,0 +1,84 @@
+import { describe, expect, it } from 'vitest'
+
+import { parseCliArgs } from '../args'
+
+describe('parseCliArgs', () => {
+  it('should ignore regular app arguments', () => {
+    expect(parseCliArgs([])).toBeUndefined()
+    expect(parseCliArgs(['cherrystudio://open'])).toBeUndefined()
+    expect(parseCliArgs(['--no-sandbox', 'ask', 'hi'])).toBeUndefined()
+  })
+
+  it('should parse ask with an optional model', () => {
+    expect(parseCliArgs(['ask', 'what', 'is', 'this?'])).toEqual({
+      name: 'ask',
+      question: 'what is this?',
+      model: undefined
+    })
+    expect(parseCliArgs(['ask', '--model', 'openai:gpt-4o', 'hello'])).toEqual({
+      name: 'ask',
+      question: 'hello',
+      model: 'openai:gpt-4o'
+    })
+    expect(parseCliArgs(['ask', 'hello', '--model=assistant:default'])).toMatchObject({ model: 'assistant:default' })
+  })
+
+  it('should parse agent run', () => {
+    expect(parseCliArgs(['agent', 'run', 'abc', '--input', 'task.md'])).toEqual({
+      name: 'agent-run',
+      assistantId: 'abc',
+      input: 'task.md'
+    })
+    expect(parseCliArgs(['agent', 'run', 'abc', '--input', '-'])).toMatchObject({ input: '-' })
+  })
+
+  it('should parse export with defaults', () => {
+    expect(parseCliArgs(['export', 'topic-1'])).toEqual({
+      name: 'export',
+      conversationId: 'topic-1',
+      format: 'markdown',
+      output: undefined
+    })
+    expect(parseCliArgs(['export', 'topic-1', '--format', 'json', '--output', 'out.json'])).toMatchObject({
+      format: 'json',
+      output: 'out.json'
+    })
+  })
+
+  it('should parse context', () => {
+    expect(parseCliArgs(['context', 'topic-1', 'what', 'next'])).toEqual({
+      name: 'context',
+      conversationId: 'topic-1',
+      message: 'what next',
+      maxTokens: undefined
+    })
+    expect(parseCliArgs(['context', 'topic-1', 'hi', '--max-tokens', '4000'])).toMatchObject({ maxTokens: 4000 })
+    expect(parseCliArgs(['context', 'topic-1', 'hi', '--max-tokens', 'lots'])).toEqual({
+      name: 'help',
+      error: 'Invalid --max-tokens'
+    })
+  })
+
+  it('should parse commands with an optional filter', () => {
+    expect(parseCliArgs(['commands'])).toEqual({ name: 'commands', filter: undefined })
+    expect(parseCliArgs(['commands', 'mini', 'app'])).toEqual({ name: 'commands', filter: 'mini app' })
+  })
+
+  it('should pass the options of run as command arguments', () => {
+    const argv = ['run', 'miniapp.install-from-url', '--url', 'https://x.com/a.zip', '--version=1.0']
+    expect(parseCliArgs(argv)).toEqual({
+      name: 'run',
+      commandId: 'miniapp.install-from-url',
+      args: { url: 'https://x.com/a.zip', version: '1.0' }
+    })
+    expect(parseCliArgs(['run', 'perf.start-trace'])).toEqual({ name: 'run', commandId: 'perf.start-trace', args: {} })
+  })
+
+  it('should return help when arguments are missing', () => {
+    expect(parseCliArgs(['ask'])).toEqual({ name: 'help', error: 'Missing question' })
+    expect(parseCliArgs(['agent', 'list'])).toEqual({ name: 'help', error: 'Missing assistant id' })
+    expect(parseCliArgs(['export'])).toEqual({ name: 'help', error: 'Missing conversation id' })
+    expect(parseCliArgs(['context', 'topic-1'])).toEqual({ name: 'help', error: 'Missing message' })
+    expect(parseCliArgs(['run'])).toEqual({ name: 'help', error: 'Missing command id' })
+  })
+})
@@ -0,0 +1,81 @@
+/**
+ * Argument parsing of the command line companion
+ */
+
+export type CliCommand =
+  | { name: 'ask'; question: string; model?: string }
+  | { name: 'agent-run'; assistantId: string; input?: string }
+  | { name: 'export'; conversationId: string; format: string; output?: string }
+  | { name: 'context'; conversationId: string; message: string; maxTokens?: number }
+  | { name: 'commands'; filter?: string }
+  | { name: 'run'; commandId: string; args: Record<string, string> }
+  | { name: 'help'; error?: string }
+
+const COMMANDS = ['ask', 'agent', 'export', 'context', 'commands', 'run']
+
+export const USAGE = `Usage:
+  cherry-studio ask "<question>" [--model <provider:model | assistant:id>]
+  cherry-studio agent run <assistant-id> [--input <file | ->]
+  cherry-studio export <conversation-id> [--format markdown|text|json] [--output <file>]
+  cherry-studio context <conversation-id> "<message>" [--max-tokens <n>]
+  cherry-studio commands [filter]
+  cherry-studio run <command-id> [--<argument> <value> ...]
+`
+
+function parseOptions(args: string[]) {
+  const positional: string[] = []
+  const options: Record<string, string> = {}
+
+  for (let i = 0; i < args.length; i++) {
+    const arg = args[i]
+    if (arg.startsWith('--') && arg.length > 2) {
+      const [key, value] = arg.slice(2).split(/=(.*)/s)
+      options[key] = value ?? args[++i] ?? ''
+    } else {
+      positional.push(arg)
+    }
+  }
+  return { positional, options }
+}
+
+/**
+ * Returns the CLI command to run, or undefined to start the GUI as usual.
+ * Chromium and deep link arguments never match since only the first argument is considered.
+ */
+export function parseCliArgs(argv: string[]): CliCommand | undefined {
+  if (!COMMANDS.includes(argv[0])) return undefined
+
+  const { positional, options } = parseOptions(argv.slice(1))
+
+  switch (argv[0]) {
+    case 'ask':
+      if (!positional.length) return { name: 'help', error: 'Missing question' }
+      return { name: 'ask', question: positional.join(' '), model: options.model }
+    case 'agent':
+      if (positional[0] !== 'run' || !positional[1]) return { name: 'help', error: 'Missing assistant id' }
+      return { name: 'agent-run', assistantId: positional[1], input: options.input }
+    case 'export':
+      if (!positional[0]) return { name: 'help', error: 'Missing conversation id' }
+      return {
+        name: 'export',
+        conversationId: positional[0],
+        format: options.format ?? 'markdown',
+        output: options.output
+      }
+    case 'context': {
+      if (!positional[0]) return { name: 'help', error: 'Missing conversation id' }
+      if (positional.length < 2) return { name: 'help', error: 'Missing message' }
+      const maxTokens = options['max-tokens'] === undefined ? undefined : Number(options['max-tokens'])
+      if (maxTokens !== undefined && !(Number.isInteger(maxTokens) && maxTokens > 0)) {
+        return { name: 'help', error: 'Invalid --max-tokens' }
+      }
+      return { name: 'context', conversationId: positional[0], message: positional.slice(1).join(' '), maxTokens }
+    }
+    case 'commands':
+      return { name: 'commands', filter: positional.length ? positional.join(' ') : undefined }
+    case 'run':
+      if (!positional[0]) return { name: 'help', error: 'Missing command id' }
+      return { name: 'run', commandId: positional[0], args: options }
+  }
+  return undefined
+}
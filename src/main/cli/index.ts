@@ -0,0 +1,171 @@
+import fs from 'node:fs'
+
+import { startHeadlessCore } from '@main/headless'
+import { API_SERVER_HOST, apiServer } from '@main/services/ApiServer'
+import { configManager } from '@main/services/ConfigManager'
+import { CommandInfo } from '@types'
+import { app } from 'electron'
+import Logger from 'electron-log'
+
+import { CliCommand, USAGE } from './args'
+
+/**
+ * Command line companion, e.g. `cherry-studio ask "question"`.
+ *
+ * Commands talk to the running app over the local API server. When the app is not
+ * running, a headless instance with a hidden main window is started for the duration
+ * of the command so the same providers, assistants and knowledge bases are used.
+ */
+
+class CliError extends Error {}
+
+function getBaseUrl() {
+  return `http://${API_SERVER_HOST}:${configManager.getApiServerPort()}/v1`
+}
+
+async function request(path: string, init: RequestInit = {}): Promise<Response> {
+  const response = await fetch(`${getBaseUrl()}${path}`, {
+    ...init,
+    headers: {
+      ...init.headers,
+      Authorization: `Bearer ${apiServer.getStatus().token}`,
+      'Content-Type': 'application/json'
+    }
+  })
+
+  if (!response.ok) {
+    const body = await response.json().catch(() => undefined)
+    throw new CliError(body?.error?.message ?? `Request failed with status ${response.status}`)
+  }
+  return response
+}
+
+async function isServerReachable(): Promise<boolean> {
+  try {
+    await request('/models')
+    return true
+  } catch (error) {
+    // an error response still means a server is listening
+    return error instanceof CliError
+  }
+}
+
+async function readInput(input: string | undefined): Promise<string> {
+  if (!input) return ''
+  if (input !== '-') return fs.promises.readFile(input, 'utf-8')
+
+  const chunks: Buffer[] = []
+  for await (const chunk of process.stdin) {
+    chunks.push(chunk)
+  }
+  return Buffer.concat(chunks).toString('utf-8')
+}
+
+/**
+ * Stream a chat completion to stdout
+ */
+async function complete(model: string, content: string) {
+  const response = await request('/chat/completions', {
+    method: 'POST',
+    body: JSON.stringify({ model, stream: true, messages: [{ role: 'user', content }] })
+  })
+
+  const reader = response.body!.pipeThrough(new TextDecoderStream()).getReader()
+  let buffer = ''
+  for (;;) {
+    const { done, value } = await reader.read()
+    if (done) break
+
+    buffer += value
+    let index: number
+    while ((index = buffer.indexOf('\n\n')) >= 0) {
+      const event = buffer.slice(0, index).replace(/^data: /, '')
+      buffer = buffer.slice(index + 2)
+      if (event === '[DONE]') continue
+
+      const data = JSON.parse(event)
+      if (data.error) throw new CliError(data.error.message)
+      process.stdout.write(data.choices?.[0]?.delta?.content ?? '')
+    }
+  }
+  process.stdout.write('\n')
+}
+
+async function runCommand(command: Exclude<CliCommand, { name: 'help' }>) {
+  switch (command.name) {
+    case 'ask':
+      return complete(command.model ?? 'assistant:default', command.question)
+    case 'agent-run': {
+      const input = await readInput(command.input)
+      if (!input.trim()) throw new CliError('Missing input, pass a file with --input or `--input -` to read stdin')
+      return complete(`assistant:${command.assistantId}`, input)
+    }
+    case 'export': {
+      const query = new URLSearchParams({ format: command.format })
+      const response = await request(`/conversations/${encodeURIComponent(command.conversationId)}/export?${query}`)
+      const content = await response.text()
+      if (command.output) {
+        await fs.promises.writeFile(command.output, content)
+      } else {
+        process.stdout.write(content + '\n')
+      }
+      return
+    }
+    case 'context': {
+      const response = await request(`/conversations/${encodeURIComponent(command.conversationId)}/context`, {
+        method: 'POST',
+        body: JSON.stringify({ message: command.message, max_tokens: command.maxTokens })
+      })
+      process.stdout.write(JSON.stringify(await response.json(), null, 2) + '\n')
+      return
+    }
+    case 'commands': {
+      const query = command.filter ? `?${new URLSearchParams({ filter: command.filter })}` : ''
+      const { data } = (await (await request(`/commands${query}`)).json()) as { data: CommandInfo[] }
+      for (const item of data) {
+        const args = item.args.map((arg) => (arg.required ? ` --${arg.name} <${arg.type}>` : ` [--${arg.name}]`))
+        process.stdout.write(`${item.id}${args.join('')}\n    ${item.title}\n`)
+      }
+      return
+    }
+    case 'run': {
+      const response = await request(`/commands/${encodeURIComponent(command.commandId)}/invoke`, {
+        method: 'POST',
+        body: JSON.stringify({ args: command.args })
+      })
+      const { result } = await response.json()
+      if (result !== null) {
+        process.stdout.write((typeof result === 'string' ? result : JSON.stringify(result, null, 2)) + '\n')
+      }
+    }
+  }
+}
+
+/**
+ * Run a CLI command and resolve with the process exit code
+ */
+export async function runCli(command: CliCommand): Promise<number> {
+  if (command.name === 'help') {
+    if (command.error) process.stderr.write(`${command.error}\n\n`)
+    process.stderr.write(USAGE)
+    return command.error ? 2 : 0
+  }
+
+  try {
+    if (!(await isServerReachable())) {
+      // another instance owns the app data but does not expose the local API
+      if (!app.requestSingleInstanceLock()) {
+        throw new CliError('Cherry Studio is running, enable the local API server in settings to use the CLI')
+      }
+      await app.whenReady()
+      await startHeadlessCore()
+    }
+
+    await runCommand(command)
+    return 0
+  } catch (error: any) {
+    Logger.error('[CLI] Command failed:', error)
+    process.stderr.write(`Error: ${error.message}\n`)
+    return 1
+  }
+}
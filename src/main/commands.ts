@@ -0,0 +1,126 @@
+import { getDataPath } from '@main/utils'
+import { shell } from 'electron'
+
+import { commandRegistry } from './services/CommandRegistry'
+import { logRedactor } from './services/LogRedactor'
+import { mcpRegistryManager } from './services/MCPRegistryManager'
+import { miniAppManager } from './services/MiniAppManager'
+import { miniAppRegistryManager } from './services/MiniAppRegistryManager'
+import { perfManager } from './services/PerfManager'
+import { windowService } from './services/WindowService'
+
+let registered = false
+
+/**
+ * Commands of the main process modules, the renderer registers its own once loaded
+ */
+export function registerMainCommands() {
+  if (registered) return
+  registered = true
+
+  commandRegistry.register({
+    id: 'app.show-main-window',
+    title: 'Show main window',
+    category: 'App',
+    run: () => windowService.showMainWindow()
+  })
+  commandRegistry.register({
+    id: 'app.toggle-quick-assistant',
+    title: 'Toggle quick assistant',
+    category: 'App',
+    run: () => windowService.toggleMiniWindow()
+  })
+  commandRegistry.register({
+    id: 'app.open-data-folder',
+    title: 'Open data folder',
+    category: 'App',
+    run: () => shell.openPath(getDataPath())
+  })
+
+  commandRegistry.register({
+    id: 'miniapp.hide-all',
+    title: 'Hide all mini apps',
+    description: 'Returns the ids of the hidden mini apps',
+    category: 'Mini apps',
+    run: () => miniAppManager.hideAll()
+  })
+  commandRegistry.register({
+    id: 'miniapp.install',
+    title: 'Install mini app from the registry',
+    category: 'Mini apps',
+    args: [{ name: 'id', type: 'string', required: true, description: 'Id of the registry entry' }],
+    run: ({ id }) => miniAppRegistryManager.install(id)
+  })
+  commandRegistry.register({
+    id: 'miniapp.install-from-url',
+    title: 'Install mini app from URL',
+    description: 'Downloads a signed mini app package, the signature is read from `<url>.sig` when not given',
+    category: 'Mini apps',
+    args: [
+      { name: 'url', type: 'string', required: true },
+      { name: 'signature', type: 'string', description: 'Base64 Ed25519 signature of the package' },
+      { name: 'version', type: 'string' }
+    ],
+    run: ({ url, signature, version }) => miniAppRegistryManager.installFromUrl(url, signature, version)
+  })
+  commandRegistry.register({
+    id: 'miniapp.check-updates',
+    title: 'Check mini apps for updates',
+    category: 'Mini apps',
+    run: () => miniAppRegistryManager.checkUpdates()
+  })
+  commandRegistry.register({
+    id: 'miniapp.update',
+    title: 'Update mini app',
+    category: 'Mini apps',
+    args: [
+      { name: 'id', type: 'string', required: true, description: 'Id of the installed mini app' },
+      { name: 'confirmed', type: 'boolean', description: 'Also apply an update that declares new permissions' }
+    ],
+    run: ({ id, confirmed }) => miniAppRegistryManager.update(id, confirmed)
+  })
+
+  commandRegistry.register({
+    id: 'mcp.install',
+    title: 'Install MCP server from the registry',
+    category: 'MCP',
+    args: [{ name: 'id', type: 'string', required: true, description: 'Id of the registry entry' }],
+    run: ({ id }) => mcpRegistryManager.install(id)
+  })
+  commandRegistry.register({
+    id: 'mcp.check-updates',
+    title: 'Check MCP servers for updates',
+    category: 'MCP',
+    run: () => mcpRegistryManager.checkUpdates()
+  })
+
+  commandRegistry.register({
+    id: 'logs.export-support-bundle',
+    title: 'Export support bundle',
+    description: 'Zip the redacted log files with information about the app',
+    category: 'Logs',
+    run: () => logRedactor.exportSupportBundle()
+  })
+  commandRegistry.register({
+    id: 'logs.preview-redaction',
+    title: 'Preview log redaction',
+    category: 'Logs',
+    args: [{ name: 'text', type: 'string', required: true }],
+    run: ({ text }) => logRedactor.redact(text)
+  })
+
+  commandRegistry.register({
+    id: 'perf.start-trace',
+    title: 'Start performance trace',
+    category: 'Performance',
+    run: () => perfManager.startTrace()
+  })
+  commandRegistry.register({
+    id: 'perf.stop-trace',
+    title: 'Stop performance trace',
+    description: 'Saves the trace to the logs directory unless an absolute path is given',
+    category: 'Performance',
+    args: [{ name: 'path', type: 'string' }],
+    run: ({ path }) => perfManager.stopTrace(path)
+  })
+}
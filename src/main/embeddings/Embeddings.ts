@@ -1,10 +1,18 @@
 import type { BaseEmbeddings } from '@cherrystudio/embedjs-interfaces'
 import { KnowledgeBaseParams } from '@types'
+import Logger from 'electron-log'
 
 import EmbeddingsFactory from './EmbeddingsFactory'
 
+// local models keep their weights in memory, only the most recently used ones stay loaded
+const MAX_CACHED = 4
+
 export default class Embeddings {
+  private static cache = new Map<string, { fingerprint: string; embeddings: Embeddings }>()
+
   private sdk: BaseEmbeddings
+  private initialized: Promise<void> | null = null
+
   constructor({ model, provider, apiKey, apiVersion, baseURL, dimensions }: KnowledgeBaseParams) {
     this.sdk = EmbeddingsFactory.create({
       model,
@@ -15,8 +23,44 @@ export default class Embeddings {
       dimensions
     } as KnowledgeBaseParams)
   }
+
+  /**
+   * Shared instance for the model, so a local model is loaded once instead of for every call.
+   * An instance whose settings changed, or that is no longer among the recently used ones, is disposed.
+   */
+  public static get(params: KnowledgeBaseParams): Embeddings {
+    const { model, provider, apiKey, apiVersion, baseURL, dimensions } = params
+    const key = `${provider}:${model}`
+    const fingerprint = JSON.stringify([apiKey, apiVersion, baseURL, dimensions])
+
+    const cached = this.cache.get(key)
+    this.cache.delete(key)
+    if (cached?.fingerprint === fingerprint) {
+      this.cache.set(key, cached)
+      return cached.embeddings
+    }
+    if (cached) this.evict(cached.embeddings)
+
+    const embeddings = new Embeddings(params)
+    this.cache.set(key, { fingerprint, embeddings })
+    if (this.cache.size > MAX_CACHED) {
+      const [oldest, entry] = this.cache.entries().next().value!
+      this.cache.delete(oldest)
+      this.evict(entry.embeddings)
+    }
+    return embeddings
+  }
+
+  private static evict(embeddings: Embeddings) {
+    embeddings.dispose().catch((error) => Logger.warn('[Embeddings] Failed to dispose embeddings:', error))
+  }
+
   public async init(): Promise<void> {
-    return this.sdk.init()
+    this.initialized ??= this.sdk.init().catch((error) => {
+      this.initialized = null
+      throw error
+    })
+    return this.initialized
   }
   public async getDimensions(): Promise<number> {
     return this.sdk.getDimensions()
@@ -28,4 +72,9 @@ export default class Embeddings {
   public async embedQuery(text: string): Promise<number[]> {
     return this.sdk.embedQuery(text)
   }
+
+  public async dispose(): Promise<void> {
+    const sdk = this.sdk as BaseEmbeddings & { dispose?: () => Promise<void> }
+    await sdk.dispose?.()
+  }
 }
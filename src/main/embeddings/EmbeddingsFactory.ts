@@ -5,11 +5,16 @@ import { AzureOpenAiEmbeddings } from '@cherrystudio/embedjs-openai/src/azure-op
 import { getInstanceName } from '@main/utils'
 import { KnowledgeBaseParams } from '@types'
 
+import { LocalEmbeddings } from './LocalEmbeddings'
 import { SUPPORTED_DIM_MODELS as VOYAGE_SUPPORTED_DIM_MODELS, VoyageEmbeddings } from './VoyageEmbeddings'
 
 export default class EmbeddingsFactory {
   static create({ model, provider, apiKey, apiVersion, baseURL, dimensions }: KnowledgeBaseParams): BaseEmbeddings {
     const batchSize = 10
+    if (provider === 'local') {
+      // model is the id of a file registered in the ModelFileManager
+      return new LocalEmbeddings(model, dimensions)
+    }
     if (provider === 'voyageai') {
       if (VOYAGE_SUPPORTED_DIM_MODELS.includes(model)) {
         return new VoyageEmbeddings({
@@ -0,0 +1,81 @@
+import { BaseEmbeddings } from '@cherrystudio/embedjs-interfaces'
+import { localInferenceManager } from '@main/services/LocalInferenceManager'
+import { modelFileManager } from '@main/services/ModelFileManager'
+import { workerPool } from '@main/services/WorkerPool'
+
+/**
+ * Embeddings computed on this machine with a GGUF embedding model (e.g. all-MiniLM-L6-v2, nomic-embed-text)
+ * managed by the ModelFileManager, so knowledge bases can be indexed offline.
+ */
+export class LocalEmbeddings extends BaseEmbeddings {
+  private model: any = null
+  private context: Promise<any> | null = null
+  private dimensions: number | null = null
+
+  constructor(
+    private readonly modelId: string,
+    private readonly configuredDimensions?: number
+  ) {
+    super()
+  }
+
+  private getContext(): Promise<any> {
+    // concurrent calls share the load
+    this.context ??= this.loadContext().catch((error) => {
+      this.context = null
+      throw error
+    })
+    return this.context
+  }
+
+  private async loadContext() {
+    const modelFile = modelFileManager.getModel(this.modelId)
+    if (!modelFile || modelFile.status !== 'ready') {
+      throw new Error(`Local embedding model is not available: ${this.modelId}`)
+    }
+    if (modelFile.format !== 'gguf') {
+      throw new Error(`Local embeddings require a GGUF model: ${modelFile.name}`)
+    }
+
+    const llama = await localInferenceManager.getLlama()
+    this.model = await llama.loadModel({ modelPath: modelFile.path })
+    const context = await this.model.createEmbeddingContext({ threads: workerPool.getThreadCount() })
+    modelFileManager.markUsed(this.modelId)
+    return context
+  }
+
+  /**
+   * Free the model and its context, the next embedding loads them again
+   */
+  public async dispose() {
+    const loading = this.context
+    this.context = null
+    const context = await loading?.catch(() => null)
+    await context?.dispose()
+    await this.model?.dispose()
+    this.model = null
+  }
+
+  override async getDimensions(): Promise<number> {
+    if (this.configuredDimensions) return this.configuredDimensions
+    if (this.dimensions === null) {
+      this.dimensions = (await this.embedQuery('dimensions')).length
+    }
+    return this.dimensions
+  }
+
+  override async embedDocuments(texts: string[]): Promise<number[][]> {
+    const embeddings: number[][] = []
+    // llama.cpp evaluates one sequence at a time on the embedding context
+    for (const text of texts) {
+      embeddings.push(await this.embedQuery(text))
+    }
+    return embeddings
+  }
+
+  override async embedQuery(text: string): Promise<number[]> {
+    const context = await this.getContext()
+    const embedding = await context.getEmbeddingFor(text)
+    return [...embedding.vector]
+  }
+}
@@ -0,0 +1,51 @@
+import { isLinux } from '@main/constant'
+import { registerIpc } from '@main/ipc'
+import { apiServer } from '@main/services/ApiServer'
+import { windowService } from '@main/services/WindowService'
+import { app, BrowserWindow } from 'electron'
+import Logger from 'electron-log'
+
+// providers, assistants and jobs live in the renderer, it may take a while to load on a cold start
+const RENDERER_START_TIMEOUT = 60 * 1000
+
+/**
+ * Started with `--headless`, the app runs as a background daemon: no visible window,
+ * no tray and no shortcuts, only the core services and the local API server.
+ */
+export const isHeadless = process.argv.includes('--headless')
+
+/**
+ * Chromium switches for machines without a display, must be applied before the app is ready
+ */
+export function configureHeadless() {
+  if (isLinux && !process.env.DISPLAY && !process.env.WAYLAND_DISPLAY) {
+    app.commandLine.appendSwitch('ozone-platform', 'headless')
+  }
+  app.disableHardwareAcceleration()
+}
+
+/**
+ * Start the core of the app without showing any window.
+ * The main window is still created (hidden) since the renderer owns the LLM request pipeline.
+ */
+export async function startHeadlessCore(): Promise<BrowserWindow> {
+  app.dock?.hide()
+
+  const mainWindow = windowService.createMainWindow({ hidden: true })
+  registerIpc(mainWindow, app)
+  await apiServer.start()
+  await apiServer.waitForRenderer(RENDERER_START_TIMEOUT)
+  return mainWindow
+}
+
+/**
+ * Quit gracefully on termination signals so the before-quit and will-quit cleanups run
+ */
+export function handleShutdownSignals() {
+  for (const signal of ['SIGINT', 'SIGTERM', 'SIGHUP'] as const) {
+    process.once(signal, () => {
+      Logger.info(`[Headless] Received ${signal}, shutting down`)
+      app.quit()
+    })
+  }
+}
@@ -7,20 +7,44 @@ import { app } from 'electron'
 import installExtension, { REACT_DEVELOPER_TOOLS, REDUX_DEVTOOLS } from 'electron-devtools-installer'
 import Logger from 'electron-log'
 
+import { runCli } from './cli'
+import { parseCliArgs } from './cli/args'
 import { isDev, isWin } from './constant'
+import { configureHeadless, handleShutdownSignals, isHeadless, startHeadlessCore } from './headless'
 import { registerIpc } from './ipc'
+import LocalReranker from './reranker/LocalReranker'
+import { accessibilityManager } from './services/AccessibilityManager'
+import { apiServer } from './services/ApiServer'
+import { browserBridge } from './services/BrowserBridge'
 import { configManager } from './services/ConfigManager'
+import { conversationArchive } from './services/ConversationArchive'
+import { downloadManager } from './services/DownloadManager'
+import { healthMonitor } from './services/HealthMonitor'
+import { lockManager } from './services/LockManager'
+import { logRedactor } from './services/LogRedactor'
+import { mcpRegistryManager } from './services/MCPRegistryManager'
 import mcpService from './services/MCPService'
+import { memoryManager } from './services/MemoryManager'
+import { miniAppManager } from './services/MiniAppManager'
+import { miniAppRegistryManager } from './services/MiniAppRegistryManager'
+import { perfManager } from './services/PerfManager'
 import {
   CHERRY_STUDIO_PROTOCOL,
   handleProtocolUrl,
   registerProtocolClient,
   setupAppImageDeepLink
 } from './services/ProtocolClient'
+import { schedulerManager } from './services/SchedulerManager'
 import selectionService, { initSelectionService } from './services/SelectionService'
+import { sessionJournal } from './services/SessionJournal'
+import { SHARE_ARG, shareManager } from './services/ShareManager'
 import { registerShortcuts } from './services/ShortcutService'
+import { startupProfiler } from './services/StartupProfiler'
+import { toolCallRecorder } from './services/ToolCallRecorder'
+import { transcriptManager } from './services/TranscriptManager'
 import { TrayService } from './services/TrayService'
 import { windowService } from './services/WindowService'
+import { workerPool } from './services/WorkerPool'
 
 initAppDataDir()
 Logger.initialize()
@@ -68,8 +92,16 @@ if (!isDev) {
   })
 }
 
-// Check for single instance lock
-if (!app.requestSingleInstanceLock()) {
+// Command line mode, e.g. `cherry-studio ask "question"`, runs without the GUI and exits
+const cliCommand = parseCliArgs(process.argv.slice(app.isPackaged ? 1 : 2))
+
+if (cliCommand || isHeadless) {
+  configureHeadless()
+}
+
+if (cliCommand) {
+  runCli(cliCommand).then((code) => app.exit(code))
+} else if (!app.requestSingleInstanceLock()) {
   app.quit()
   process.exit(0)
 } else {
@@ -78,17 +110,40 @@ if (!app.requestSingleInstanceLock()) {
   // Some APIs can only be used after this event occurs.
 
   app.whenReady().then(async () => {
+    startupProfiler.mark('app-ready')
+    // before anything else logs, so secrets never reach the log files
+    startupProfiler.measure('log-redactor', () => logRedactor.init())
+
     // Set app user model id for windows
     electronApp.setAppUserModelId(import.meta.env.VITE_MAIN_BUNDLE_ID || 'com.kangfenmao.CherryStudio')
 
+    // Background daemon: core services and the local API only, no tray, shortcuts or visible window
+    if (isHeadless) {
+      handleShutdownSignals()
+      try {
+        await startupProfiler.measure('headless-core', () => startHeadlessCore())
+      } catch (error) {
+        Logger.error('[Headless] Failed to start:', error)
+        app.exit(1)
+        return
+      }
+      startupProfiler.measure('download-manager', () => downloadManager.init())
+      startupProfiler.measure('memory-manager', () => memoryManager.init())
+      startupProfiler.measure('browser-bridge', () => browserBridge.init())
+      Logger.info('[Headless] Running in headless mode')
+      return
+    }
+
     // Mac: Hide dock icon before window creation when launch to tray is set
     const isLaunchToTray = configManager.getLaunchToTray()
     if (isLaunchToTray) {
       app.dock?.hide()
     }
 
-    const mainWindow = windowService.createMainWindow()
-    new TrayService()
+    const mainWindow = startupProfiler.measure('main-window', () => windowService.createMainWindow())
+    startupProfiler.mark('window-created')
+    startupProfiler.waitForFirstPaint(mainWindow)
+    startupProfiler.measure('tray', () => new TrayService())
 
     app.on('activate', function () {
       const mainWindow = windowService.getMainWindow()
@@ -99,23 +154,56 @@ if (!app.requestSingleInstanceLock()) {
       }
     })
 
-    registerShortcuts(mainWindow)
+    startupProfiler.measure('shortcuts', () => registerShortcuts(mainWindow))
+
+    startupProfiler.measure('ipc', () => registerIpc(mainWindow, app))
+
+    // needed before the window shows content: the lock screen, mini app shims and startup metrics
+    startupProfiler.measure('perf-manager', () => perfManager.init())
+    startupProfiler.measure('accessibility-manager', () => accessibilityManager.init())
+    startupProfiler.measure('lock-manager', () => lockManager.init())
+    startupProfiler.measure('mini-app-manager', () => miniAppManager.init())
+    startupProfiler.measure('worker-pool', () => workerPool.init())
+    startupProfiler.measure('session-journal', () => sessionJournal.init())
+
+    // everything else waits until the main window painted
+    startupProfiler.defer('download-manager', () => downloadManager.init())
+    startupProfiler.defer('memory-manager', () => memoryManager.init())
+    startupProfiler.defer('api-server', () => apiServer.init())
+    startupProfiler.defer('browser-bridge', () => browserBridge.init())
+    startupProfiler.defer('share-manager', () => shareManager.init())
+    startupProfiler.defer('scheduler-manager', () => schedulerManager.init())
+    startupProfiler.defer('mcp-registry-manager', () => mcpRegistryManager.init())
+    startupProfiler.defer('mini-app-registry-manager', () => miniAppRegistryManager.init())
+    startupProfiler.defer('health-monitor', () => {
+      healthMonitor.init()
+      healthMonitor.registerProbe({
+        name: 'ConversationArchive',
+        check: () => !conversationArchive.isStalled(),
+        recover: () => conversationArchive.reset()
+      })
+      healthMonitor.registerProbe({
+        name: 'MCPRegistryManager',
+        check: () => !mcpRegistryManager.isStalled(),
+        recover: () => mcpRegistryManager.reset()
+      })
+    })
+    startupProfiler.defer('selection-service', () => initSelectionService())
 
-    registerIpc(mainWindow, app)
+    // content shared from the file manager when the app was not running yet
+    shareManager.share(shareManager.parseArgv(process.argv))
+    transcriptManager.open(transcriptManager.parseArgv(process.argv))
 
     replaceDevtoolsFont(mainWindow)
 
     // Setup deep link for AppImage on Linux
-    await setupAppImageDeepLink()
+    await startupProfiler.measure('appimage-deep-link', () => setupAppImageDeepLink())
 
     if (isDev) {
       installExtension([REDUX_DEVTOOLS, REACT_DEVELOPER_TOOLS])
         .then((name) => console.log(`Added Extension:  ${name}`))
         .catch((err) => console.log('An error occurred: ', err))
     }
-
-    //start selection assistant service
-    initSelectionService()
   })
 
   registerProtocolClient(app)
@@ -126,18 +214,37 @@ if (!app.requestSingleInstanceLock()) {
     handleProtocolUrl(url)
   })
 
+  // macOS specific: files dropped on the Dock icon or opened with the app
+  app.on('open-file', (event, filePath) => {
+    event.preventDefault()
+    if (transcriptManager.isTranscript(filePath)) {
+      transcriptManager.open([filePath])
+    } else {
+      shareManager.share(shareManager.parseArgv([SHARE_ARG, filePath]))
+    }
+  })
+
   // Listen for second instance
   app.on('second-instance', (_event, argv) => {
+    if (isHeadless) return
+
     windowService.showMainWindow()
 
     // Protocol handler for Windows/Linux
     // The commandLine is an array of strings where the last item might be the URL
     const url = argv.find((arg) => arg.startsWith(CHERRY_STUDIO_PROTOCOL + '://'))
     if (url) handleProtocolUrl(url)
+
+    // "Send to Cherry Studio" while the app is running
+    shareManager.share(shareManager.parseArgv(argv))
+
+    // .metheuschat transcript opened while the app is running
+    transcriptManager.open(transcriptManager.parseArgv(argv))
   })
 
   app.on('browser-window-created', (_, window) => {
     optimizer.watchWindowShortcuts(window)
+    windowService.applyContentProtection(window)
   })
 
   app.on('before-quit', () => {
@@ -147,15 +254,25 @@ if (!app.requestSingleInstanceLock()) {
     if (selectionService) {
       selectionService.quit()
     }
+
+    browserBridge.stop()
+    apiServer.stop()
+    mcpRegistryManager.stop()
+    miniAppRegistryManager.stop()
+    // the webviews are still alive here, the windows close after
+    miniAppManager.saveLayout()
   })
 
   app.on('will-quit', async () => {
     // event.preventDefault()
+    if (!isHeadless) sessionJournal.close()
     try {
       await mcpService.cleanup()
     } catch (error) {
       Logger.error('Error cleaning up MCP service:', error)
     }
+    await toolCallRecorder.close()
+    await LocalReranker.dispose().catch((error) => Logger.error('Error disposing the local reranker:', error))
   })
 
   // In this file you can include the rest of your app"s specific main process
@@ -2,39 +2,119 @@ import fs from 'node:fs'
 import { arch } from 'node:os'
 
 import { isMac, isWin } from '@main/constant'
+import { GgufModelKind } from '@main/utils/gguf'
+import { computeDiff } from '@main/utils/patch'
 import { getBinaryPath, isBinaryExists, runInstallScript } from '@main/utils/process'
 import { handleZoomFactor } from '@main/utils/zoom'
 import { FeedUrl } from '@shared/config/constant'
 import { IpcChannel } from '@shared/IpcChannel'
-import { Shortcut, ThemeMode } from '@types'
-import { BrowserWindow, dialog, ipcMain, session, shell } from 'electron'
+import {
+  CommandInfo,
+  ConfirmationRule,
+  ContentBlockingConfig,
+  KnowledgeBaseParams,
+  MiniAppCaptureOptions,
+  MiniAppCookieInput,
+  OutboundEvent,
+  OutboundIntegration,
+  PdfPrintOptions,
+  SessionDraftState,
+  ShareConversationOptions,
+  SharedConversationMessage,
+  Shortcut,
+  TabularParseOptions,
+  TextDiffMode,
+  ThemeMode,
+  ToolCallQuery,
+  TrayStatus,
+  UserTemplate,
+  WebviewContextMenuAction
+} from '@types'
+import { BrowserWindow, dialog, powerMonitor, session, shell } from 'electron'
 import log from 'electron-log'
 import { Notification } from 'src/renderer/src/types/notification'
 
+import { registerMainCommands } from './commands'
+import { accessibilityManager, AnnouncePoliteness } from './services/AccessibilityManager'
+import { actionPolicyManager } from './services/ActionPolicyManager'
+import { apiServer, ApiServerResponseMessage } from './services/ApiServer'
 import AppUpdater from './services/AppUpdater'
+import { AuditFilter, auditLog } from './services/AuditLog'
+import AwsBedrockService from './services/AwsBedrockService'
 import BackupManager from './services/BackupManager'
+import { BridgeBrowser, browserBridge } from './services/BrowserBridge'
+import { citationService } from './services/CitationService'
+import { clipboardManager } from './services/ClipboardManager'
+import { commandRegistry } from './services/CommandRegistry'
 import { configManager } from './services/ConfigManager'
+import { contentBlocker } from './services/ContentBlocker'
+import { conversationShareManager } from './services/ConversationShareManager'
+import { conversationWorkspaceManager } from './services/ConversationWorkspaceManager'
+import { ArchivedConversation, conversationArchive } from './services/ConversationArchive'
 import CopilotService from './services/CopilotService'
+import { downloadManager, DownloadOptions } from './services/DownloadManager'
 import { ExportService } from './services/ExportService'
+import { FeedbackEvent, feedbackManager } from './services/FeedbackManager'
 import FileService from './services/FileService'
 import FileStorage from './services/FileStorage'
+import { healthMonitor } from './services/HealthMonitor'
+import { integrityService } from './services/IntegrityService'
 import KnowledgeService from './services/KnowledgeService'
+import { localInferenceManager } from './services/LocalInferenceManager'
+import { linkPreviewManager } from './services/LinkPreviewManager'
+import { lockManager } from './services/LockManager'
+import { logRedactor } from './services/LogRedactor'
+import { mcpRegistryManager } from './services/MCPRegistryManager'
 import mcpService from './services/MCPService'
+import { AddMemoryInput, memoryManager } from './services/MemoryManager'
+import { miniAppBridge } from './services/MiniAppBridge'
+import { miniAppManager } from './services/MiniAppManager'
+import { miniAppRegistryManager } from './services/MiniAppRegistryManager'
+import { modelFileManager } from './services/ModelFileManager'
 import NotificationService from './services/NotificationService'
 import * as NutstoreService from './services/NutstoreService'
 import ObsidianVaultService from './services/ObsidianVaultService'
+import { outboundIntegrationManager } from './services/OutboundIntegrationManager'
+import { perfManager } from './services/PerfManager'
+import { policyService } from './services/PolicyService'
+import { printService } from './services/PrintService'
 import { ProxyConfig, proxyManager } from './services/ProxyManager'
+import { pushChannel } from './services/PushChannel'
+import { ScheduleMessageInput, schedulerManager } from './services/SchedulerManager'
 import { searchService } from './services/SearchService'
+import { secureTopicManager } from './services/SecureTopicManager'
 import { SelectionService } from './services/SelectionService'
+import { sessionJournal } from './services/SessionJournal'
+import { shareManager } from './services/ShareManager'
 import { registerShortcuts, unregisterAllShortcuts } from './services/ShortcutService'
+import { snapLayoutManager } from './services/SnapLayoutManager'
+import { startupProfiler } from './services/StartupProfiler'
 import storeSyncService from './services/StoreSyncService'
+import { tabularService } from './services/TabularService'
+import { templateManager } from './services/TemplateManager'
+import { textPreviewService } from './services/TextPreviewService'
 import { themeService } from './services/ThemeService'
+import { toolCallRecorder } from './services/ToolCallRecorder'
+import { transcriptManager } from './services/TranscriptManager'
+import { TrayService } from './services/TrayService'
 import VertexAIService from './services/VertexAIService'
-import { setOpenLinkExternal } from './services/WebviewService'
+import { WebhookInput, webhookManager } from './services/WebhookManager'
+import {
+  clearEphemeralSession,
+  resumeWebview,
+  setOpenLinkExternal,
+  setShortcutPassThrough,
+  setWebviewContextMenu,
+  suspendWebview
+} from './services/WebviewService'
 import { windowService } from './services/WindowService'
+import { workerPool } from './services/WorkerPool'
+import { workspaceManager } from './services/WorkspaceManager'
 import { calculateDirectorySize, getResourcePath } from './utils'
 import { decrypt, encrypt } from './utils/aes'
 import { getCacheDir, getConfigDir, getFilesDir, hasWritePermission, updateConfig } from './utils/file'
+import { createManagerHandler, handleIpc } from './utils/ipc'
+import { AwsCredentials, SignableRequest } from './utils/sigv4'
 import { compress, decompress } from './utils/zip'
 
 const fileManager = new FileStorage()
@@ -42,12 +122,13 @@ const backupManager = new BackupManager()
 const exportService = new ExportService(fileManager)
 const obsidianVaultService = new ObsidianVaultService()
 const vertexAIService = VertexAIService.getInstance()
+const awsBedrockService = AwsBedrockService.getInstance()
 
 export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
   const appUpdater = new AppUpdater(mainWindow)
   const notificationService = new NotificationService(mainWindow)
 
-  ipcMain.handle(IpcChannel.App_Info, () => ({
+  handleIpc(IpcChannel.App_Info, () => ({
     version: app.getVersion(),
     isPackaged: app.isPackaged,
     appPath: app.getAppPath(),
@@ -60,9 +141,14 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
     isPortable: isWin && 'PORTABLE_EXECUTABLE_DIR' in process.env
   }))
 
-  ipcMain.handle(IpcChannel.App_Proxy, async (_, proxy: string) => {
+  handleIpc(IpcChannel.App_Proxy, async (_, proxy: string) => {
     let proxyConfig: ProxyConfig
 
+    const forcedProxy = policyService.getForcedProxy()
+    if (forcedProxy !== undefined) {
+      proxy = forcedProxy
+    }
+
     if (proxy === 'system') {
       proxyConfig = { mode: 'system' }
     } else if (proxy) {
@@ -74,19 +160,22 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
     await proxyManager.configureProxy(proxyConfig)
   })
 
-  ipcMain.handle(IpcChannel.App_Reload, () => mainWindow.reload())
-  ipcMain.handle(IpcChannel.Open_Website, (_, url: string) => shell.openExternal(url))
+  handleIpc(IpcChannel.App_Reload, () => mainWindow.reload())
+  handleIpc(IpcChannel.Open_Website, (_, url: string) => shell.openExternal(url))
 
   // Update
-  ipcMain.handle(IpcChannel.App_ShowUpdateDialog, () => appUpdater.showUpdateDialog(mainWindow))
+  handleIpc(IpcChannel.App_ShowUpdateDialog, () => appUpdater.showUpdateDialog(mainWindow))
+
+  // settings stored by the config manager, a setting locked by policy is refused before anything is applied
+  const handleConfig = createManagerHandler('ConfigManager')
 
   // language
-  ipcMain.handle(IpcChannel.App_SetLanguage, (_, language) => {
+  handleConfig(IpcChannel.App_SetLanguage, (_, language) => {
     configManager.setLanguage(language)
   })
 
   // launch on boot
-  ipcMain.handle(IpcChannel.App_SetLaunchOnBoot, (_, openAtLogin: boolean) => {
+  handleIpc(IpcChannel.App_SetLaunchOnBoot, (_, openAtLogin: boolean) => {
     // Set login item settings for windows and mac
     // linux is not supported because it requires more file operations
     if (isWin || isMac) {
@@ -95,51 +184,241 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
   })
 
   // launch to tray
-  ipcMain.handle(IpcChannel.App_SetLaunchToTray, (_, isActive: boolean) => {
+  handleConfig(IpcChannel.App_SetLaunchToTray, (_, isActive: boolean) => {
     configManager.setLaunchToTray(isActive)
   })
 
   // tray
-  ipcMain.handle(IpcChannel.App_SetTray, (_, isActive: boolean) => {
+  handleConfig(IpcChannel.App_SetTray, (_, isActive: boolean) => {
     configManager.setTray(isActive)
   })
 
   // to tray on close
-  ipcMain.handle(IpcChannel.App_SetTrayOnClose, (_, isActive: boolean) => {
+  handleConfig(IpcChannel.App_SetTrayOnClose, (_, isActive: boolean) => {
     configManager.setTrayOnClose(isActive)
   })
 
+  handleIpc(IpcChannel.App_SetTrayTooltip, (_, text: string | null) => {
+    TrayService.getInstance()?.setTooltip(text)
+  })
+
+  handleIpc(IpcChannel.App_SetTrayStatus, (_, status: Partial<TrayStatus>) => {
+    TrayService.getInstance()?.setStatus(status)
+  })
+
   // auto update
-  ipcMain.handle(IpcChannel.App_SetAutoUpdate, (_, isActive: boolean) => {
-    appUpdater.setAutoUpdate(isActive)
+  handleConfig(IpcChannel.App_SetAutoUpdate, (_, isActive: boolean) => {
     configManager.setAutoUpdate(isActive)
+    appUpdater.setAutoUpdate(isActive)
   })
 
-  ipcMain.handle(IpcChannel.App_SetFeedUrl, (_, feedUrl: FeedUrl) => {
+  handleIpc(IpcChannel.App_SetFeedUrl, (_, feedUrl: FeedUrl) => {
     appUpdater.setFeedUrl(feedUrl)
   })
 
-  ipcMain.handle(IpcChannel.Config_Set, (_, key: string, value: any, isNotify: boolean = false) => {
+  // privacy
+  handleIpc(IpcChannel.App_SetContentProtection, (_, enabled: boolean) => {
+    windowService.setContentProtection(enabled)
+  })
+
+  handleConfig(IpcChannel.Config_Set, (_, key: string, value: any, isNotify: boolean = false) => {
     configManager.set(key, value, isNotify)
   })
 
-  ipcMain.handle(IpcChannel.Config_Get, (_, key: string) => {
+  handleIpc(IpcChannel.Config_Get, (_, key: string) => {
     return configManager.get(key)
   })
 
+  // accessibility
+  handleIpc(IpcChannel.Accessibility_GetState, () => accessibilityManager.getAccessibilityState())
+  handleIpc(IpcChannel.Accessibility_Announce, (_, text: string, politeness?: AnnouncePoliteness) =>
+    accessibilityManager.announce(text, politeness)
+  )
+
+  // app lock
+  handleIpc(IpcChannel.Lock_Lock, () => lockManager.lockApp())
+  handleIpc(IpcChannel.Lock_Unlock, (_, passphrase: string) => lockManager.unlock(passphrase))
+  handleIpc(IpcChannel.Lock_UnlockWithBiometric, () => lockManager.unlockWithBiometric())
+  handleIpc(IpcChannel.Lock_GetState, () => ({
+    locked: lockManager.isLocked(),
+    hasPassphrase: lockManager.hasPassphrase(),
+    canUseBiometric: lockManager.canUseBiometric(),
+    idleTimeout: configManager.getLockIdleTimeout()
+  }))
+  handleIpc(IpcChannel.Lock_SetPassphrase, (_, passphrase: string | null, currentPassphrase?: string) =>
+    lockManager.setPassphrase(passphrase, currentPassphrase)
+  )
+  handleIpc(IpcChannel.Lock_SetIdleTimeout, (_, minutes: number) => lockManager.setIdleTimeout(minutes))
+
+  // health monitor
+  handleIpc(IpcChannel.Health_GetReport, () => healthMonitor.getReport())
+
+  // secure conversations
+  const handleSecureTopic = createManagerHandler('SecureTopicManager')
+  handleSecureTopic(IpcChannel.SecureTopic_Seal, (_, topicId: string, payloads: string[]) =>
+    secureTopicManager.seal(topicId, payloads)
+  )
+  handleSecureTopic(IpcChannel.SecureTopic_Open, (_, topicId: string, payloads: string[]) =>
+    secureTopicManager.open(topicId, payloads)
+  )
+  handleSecureTopic(IpcChannel.SecureTopic_SealFiles, (_, topicId: string, names: string[]) =>
+    secureTopicManager.sealFiles(topicId, names)
+  )
+  handleSecureTopic(IpcChannel.SecureTopic_OpenFiles, (_, topicId: string, names: string[]) =>
+    secureTopicManager.openFiles(topicId, names)
+  )
+  handleSecureTopic(IpcChannel.SecureTopic_RemoveKey, (_, topicId: string) => secureTopicManager.removeKey(topicId))
+  handleSecureTopic(IpcChannel.SecureTopic_Unlock, (_, passphrase?: string) => secureTopicManager.unlock(passphrase))
+
+  // performance profiling
+  const handlePerf = createManagerHandler('PerfManager')
+  handlePerf(IpcChannel.Perf_GetSnapshot, () => perfManager.getSnapshot())
+  handlePerf(IpcChannel.Perf_StartTrace, () => perfManager.startTrace())
+  handlePerf(IpcChannel.Perf_StopTrace, (_, outputPath?: string) => perfManager.stopTrace(outputPath))
+  handlePerf(IpcChannel.Perf_GetStartupReport, () => startupProfiler.getReport())
+
+  // log redaction
+  const handleLog = createManagerHandler('LogRedactor')
+  handleLog(IpcChannel.Log_PreviewRedaction, (_, text: string) => logRedactor.redact(text))
+  handleLog(IpcChannel.Log_GetRedactionPatterns, () => logRedactor.getPatterns())
+  handleLog(IpcChannel.Log_SetRedactionPatterns, (_, patterns: string[]) => logRedactor.setPatterns(patterns))
+  handleLog(IpcChannel.Log_ExportSupportBundle, () => logRedactor.exportSupportBundle())
+
+  // command registry
+  registerMainCommands()
+  const handleCommand = createManagerHandler('CommandRegistry')
+  handleCommand(IpcChannel.Command_List, (_, filter?: string) => commandRegistry.list(filter))
+  handleCommand(IpcChannel.Command_Invoke, (_, id: string, args?: Record<string, unknown>) =>
+    commandRegistry.invoke(id, args)
+  )
+  handleCommand(IpcChannel.Command_RegisterRenderer, (_, commands: Omit<CommandInfo, 'source'>[]) =>
+    commandRegistry.registerRenderer(commands, (id, args) => apiServer.callRenderer('command.run', { id, args }))
+  )
+
+  // worker pool
+  const handleWorkerPool = createManagerHandler('WorkerPool')
+  handleWorkerPool(IpcChannel.WorkerPool_GetStatus, () => workerPool.getStatus())
+  handleWorkerPool(IpcChannel.WorkerPool_SetSize, (_, size: number) => workerPool.setSize(size))
+
+  // audit log
+  handleIpc(IpcChannel.Audit_GetLog, (_, filter?: AuditFilter) => auditLog.getEntries(filter))
+  handleIpc(IpcChannel.Audit_Export, (_, filter?: AuditFilter, filePath?: string) => auditLog.export(filter, filePath))
+
+  // workspace bundle
+  const handleWorkspace = createManagerHandler('WorkspaceManager')
+  handleWorkspace(IpcChannel.Workspace_Export, (_, data: string, passphrase: string, filePath?: string) =>
+    workspaceManager.export(data, passphrase, filePath)
+  )
+  handleWorkspace(IpcChannel.Workspace_Import, (_, filePath: string, passphrase: string) =>
+    workspaceManager.import(filePath, passphrase)
+  )
+  handleWorkspace(IpcChannel.Workspace_GetFingerprint, () => workspaceManager.getFingerprint())
+  handleWorkspace(IpcChannel.Workspace_TrustSigner, (_, fingerprint: string) =>
+    workspaceManager.trustSigner(fingerprint)
+  )
+
+  // download manager
+  const handleDownload = createManagerHandler('DownloadManager')
+  handleDownload(IpcChannel.Download_Start, (_, url: string, dest: string, options?: DownloadOptions) =>
+    downloadManager.startDownload(url, dest, options)
+  )
+  handleDownload(IpcChannel.Download_Pause, (_, id: string) => downloadManager.pause(id))
+  handleDownload(IpcChannel.Download_Resume, (_, id: string) => downloadManager.resume(id))
+  handleDownload(IpcChannel.Download_Cancel, (_, id: string) => downloadManager.cancel(id))
+  handleDownload(IpcChannel.Download_Remove, (_, id: string) => downloadManager.remove(id))
+  handleDownload(IpcChannel.Download_List, (_, source?: string) => downloadManager.listTasks(source))
+  handleDownload(IpcChannel.Download_GetDirectory, () => downloadManager.getDirectory())
+  handleDownload(IpcChannel.Download_SetDirectory, (_, directory: string) => downloadManager.setDirectory(directory))
+
+  // local model files
+  const handleModelFile = createManagerHandler('ModelFileManager')
+  handleModelFile(IpcChannel.ModelFile_List, () => modelFileManager.listModels())
+  handleModelFile(IpcChannel.ModelFile_Download, (_, url: string, fileName?: string, sha256?: string) =>
+    modelFileManager.downloadModel(url, fileName, sha256)
+  )
+  handleModelFile(IpcChannel.ModelFile_Import, (_, filePath: string) => modelFileManager.importModel(filePath))
+  handleModelFile(IpcChannel.ModelFile_Verify, (_, id: string) => modelFileManager.verifyModel(id))
+  handleModelFile(IpcChannel.ModelFile_Remove, (_, id: string, deleteFile?: boolean) =>
+    modelFileManager.removeModel(id, deleteFile)
+  )
+  handleModelFile(IpcChannel.ModelFile_Cleanup, (_, days: number) => modelFileManager.cleanupUnused(days))
+
+  // local inference
+  const handleLocalInference = createManagerHandler('LocalInferenceManager')
+  handleLocalInference(IpcChannel.LocalInference_GetBackendInfo, () => localInferenceManager.getBackendInfo())
+  handleLocalInference(IpcChannel.LocalInference_LoadModel, (_, modelId: string) =>
+    localInferenceManager.loadModel(modelId)
+  )
+  handleLocalInference(IpcChannel.LocalInference_UnloadModel, () => localInferenceManager.unloadModel())
+  handleLocalInference(IpcChannel.LocalInference_ListModels, (_, kind: GgufModelKind) =>
+    localInferenceManager.listModels(kind)
+  )
+  handleLocalInference(IpcChannel.LocalInference_GetEmbeddingDimensions, (_, modelId: string) =>
+    localInferenceManager.getEmbeddingDimensions(modelId)
+  )
+  handleLocalInference(IpcChannel.LocalInference_ChatCompletion, localInferenceManager.chatCompletionHandler)
+  handleLocalInference(IpcChannel.LocalInference_Abort, (_, requestId: string) =>
+    localInferenceManager.abort(requestId)
+  )
+  handleLocalInference(IpcChannel.LocalInference_ChunkAck, (_, requestId: string, seq: number) =>
+    localInferenceManager.ackChunk(requestId, seq)
+  )
+
+  // feedback
+  handleIpc(IpcChannel.Feedback_PlaySound, (_, eventName: FeedbackEvent) => feedbackManager.playSound(eventName))
+  handleIpc(IpcChannel.Feedback_ListThemes, () => feedbackManager.listThemes())
+
+  // long-term memory
+  const handleMemory = createManagerHandler('MemoryManager')
+  handleMemory(IpcChannel.Memory_Add, (_, input: AddMemoryInput, embeddingParams?: KnowledgeBaseParams) =>
+    memoryManager.addMemory(input, embeddingParams)
+  )
+  handleMemory(IpcChannel.Memory_Search, (_, query: string, limit?: number, embeddingParams?: KnowledgeBaseParams) =>
+    memoryManager.searchMemories(query, limit, embeddingParams)
+  )
+  handleMemory(IpcChannel.Memory_List, () => memoryManager.listMemories())
+  handleMemory(IpcChannel.Memory_Delete, (_, id: string) => memoryManager.deleteMemory(id))
+  handleMemory(IpcChannel.Memory_Clear, () => memoryManager.clearMemories())
+  handleMemory(IpcChannel.Memory_Export, (_, filePath?: string) => memoryManager.exportMemories(filePath))
+
+  // conversation archive
+  const handleArchive = createManagerHandler('ConversationArchive')
+  handleArchive(IpcChannel.Archive_List, () => conversationArchive.list())
+  handleArchive(IpcChannel.Archive_Add, (_, conversation: ArchivedConversation) =>
+    conversationArchive.archive(conversation)
+  )
+  handleArchive(IpcChannel.Archive_Get, (_, topicId: string) => conversationArchive.get(topicId))
+  handleArchive(IpcChannel.Archive_Remove, (_, topicId: string) => conversationArchive.remove(topicId))
+
+  // policy
+  handleIpc(IpcChannel.Policy_Get, () => ({
+    ...policyService.getPolicy(),
+    lockedKeys: policyService.getLockedKeys()
+  }))
+
+  // confirmation of agent actions
+  const handleActionPolicy = createManagerHandler('ActionPolicyManager')
+  handleActionPolicy(IpcChannel.ActionPolicy_GetRules, () => actionPolicyManager.getRules())
+  handleActionPolicy(IpcChannel.ActionPolicy_SetRules, (_, rules: ConfirmationRule[]) =>
+    actionPolicyManager.setRules(rules)
+  )
+  handleActionPolicy(IpcChannel.ActionPolicy_ListPending, () => actionPolicyManager.listPending())
+  handleActionPolicy(IpcChannel.ActionPolicy_Approve, (_, id: string) => actionPolicyManager.approve(id))
+  handleActionPolicy(IpcChannel.ActionPolicy_Deny, (_, id: string) => actionPolicyManager.deny(id))
+
   // theme
-  ipcMain.handle(IpcChannel.App_SetTheme, (_, theme: ThemeMode) => {
+  handleIpc(IpcChannel.App_SetTheme, (_, theme: ThemeMode) => {
     themeService.setTheme(theme)
   })
 
-  ipcMain.handle(IpcChannel.App_HandleZoomFactor, (_, delta: number, reset: boolean = false) => {
+  handleIpc(IpcChannel.App_HandleZoomFactor, (_, delta: number, reset: boolean = false) => {
     const windows = BrowserWindow.getAllWindows()
     handleZoomFactor(windows, delta, reset)
     return configManager.getZoomFactor()
   })
 
   // clear cache
-  ipcMain.handle(IpcChannel.App_ClearCache, async () => {
+  handleIpc(IpcChannel.App_ClearCache, async () => {
     const sessions = [session.defaultSession, session.fromPartition('persist:webview')]
 
     try {
@@ -161,7 +440,7 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
   })
 
   // get cache size
-  ipcMain.handle(IpcChannel.App_GetCacheSize, async () => {
+  handleIpc(IpcChannel.App_GetCacheSize, async () => {
     const cachePath = getCacheDir()
     log.info(`Calculating cache size for path: ${cachePath}`)
 
@@ -176,7 +455,7 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
   })
 
   let preventQuitListener: ((event: Electron.Event) => void) | null = null
-  ipcMain.handle(IpcChannel.App_SetStopQuitApp, (_, stop: boolean = false, reason: string = '') => {
+  handleIpc(IpcChannel.App_SetStopQuitApp, (_, stop: boolean = false, reason: string = '') => {
     if (stop) {
       // Only add listener if not already added
       if (!preventQuitListener) {
@@ -199,7 +478,7 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
   })
 
   // Select app data path
-  ipcMain.handle(IpcChannel.App_Select, async (_, options: Electron.OpenDialogOptions) => {
+  handleIpc(IpcChannel.App_Select, async (_, options: Electron.OpenDialogOptions) => {
     try {
       const { canceled, filePaths } = await dialog.showOpenDialog(options)
       if (canceled || filePaths.length === 0) {
@@ -212,18 +491,18 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
     }
   })
 
-  ipcMain.handle(IpcChannel.App_HasWritePermission, async (_, filePath: string) => {
+  handleIpc(IpcChannel.App_HasWritePermission, async (_, filePath: string) => {
     return hasWritePermission(filePath)
   })
 
   // Set app data path
-  ipcMain.handle(IpcChannel.App_SetAppDataPath, async (_, filePath: string) => {
+  handleIpc(IpcChannel.App_SetAppDataPath, async (_, filePath: string) => {
     updateConfig(filePath)
     app.setPath('userData', filePath)
   })
 
   // Copy user data to new location
-  ipcMain.handle(IpcChannel.App_Copy, async (_, oldPath: string, newPath: string) => {
+  handleIpc(IpcChannel.App_Copy, async (_, oldPath: string, newPath: string) => {
     try {
       await fs.promises.cp(oldPath, newPath, { recursive: true })
       return { success: true }
@@ -234,82 +513,88 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
   })
 
   // Relaunch app
-  ipcMain.handle(IpcChannel.App_RelaunchApp, () => {
+  handleIpc(IpcChannel.App_RelaunchApp, () => {
+    sessionJournal.close()
     app.relaunch()
     app.exit(0)
   })
 
   // check for update
-  ipcMain.handle(IpcChannel.App_CheckForUpdate, async () => {
+  handleIpc(IpcChannel.App_CheckForUpdate, async () => {
     return await appUpdater.checkForUpdates()
   })
 
+  // installation integrity
+  handleIpc(IpcChannel.App_VerifyInstallation, () => integrityService.verifyInstallation())
+  handleIpc(IpcChannel.App_RepairInstallation, () => appUpdater.repairInstallation())
+
   // notification
-  ipcMain.handle(IpcChannel.Notification_Send, async (_, notification: Notification) => {
+  handleIpc(IpcChannel.Notification_Send, async (_, notification: Notification) => {
     await notificationService.sendNotification(notification)
   })
-  ipcMain.handle(IpcChannel.Notification_OnClick, (_, notification: Notification) => {
+  handleIpc(IpcChannel.Notification_OnClick, (_, notification: Notification) => {
     mainWindow.webContents.send('notification-click', notification)
   })
 
   // zip
-  ipcMain.handle(IpcChannel.Zip_Compress, (_, text: string) => compress(text))
-  ipcMain.handle(IpcChannel.Zip_Decompress, (_, text: Buffer) => decompress(text))
+  handleIpc(IpcChannel.Zip_Compress, (_, text: string) => compress(text))
+  handleIpc(IpcChannel.Zip_Decompress, (_, text: Buffer) => decompress(text))
 
   // system
-  ipcMain.handle(IpcChannel.System_GetDeviceType, () => (isMac ? 'mac' : isWin ? 'windows' : 'linux'))
-  ipcMain.handle(IpcChannel.System_GetHostname, () => require('os').hostname())
-  ipcMain.handle(IpcChannel.System_ToggleDevTools, (e) => {
+  handleIpc(IpcChannel.System_GetDeviceType, () => (isMac ? 'mac' : isWin ? 'windows' : 'linux'))
+  handleIpc(IpcChannel.System_GetHostname, () => require('os').hostname())
+  handleIpc(IpcChannel.System_GetIdleTime, () => powerMonitor.getSystemIdleTime())
+  handleIpc(IpcChannel.System_ToggleDevTools, (e) => {
     const win = BrowserWindow.fromWebContents(e.sender)
     win && win.webContents.toggleDevTools()
   })
 
   // backup
-  ipcMain.handle(IpcChannel.Backup_Backup, backupManager.backup)
-  ipcMain.handle(IpcChannel.Backup_Restore, backupManager.restore)
-  ipcMain.handle(IpcChannel.Backup_BackupToWebdav, backupManager.backupToWebdav)
-  ipcMain.handle(IpcChannel.Backup_RestoreFromWebdav, backupManager.restoreFromWebdav)
-  ipcMain.handle(IpcChannel.Backup_ListWebdavFiles, backupManager.listWebdavFiles)
-  ipcMain.handle(IpcChannel.Backup_CheckConnection, backupManager.checkConnection)
-  ipcMain.handle(IpcChannel.Backup_CreateDirectory, backupManager.createDirectory)
-  ipcMain.handle(IpcChannel.Backup_DeleteWebdavFile, backupManager.deleteWebdavFile)
+  handleIpc(IpcChannel.Backup_Backup, backupManager.backup)
+  handleIpc(IpcChannel.Backup_Restore, backupManager.restore)
+  handleIpc(IpcChannel.Backup_BackupToWebdav, backupManager.backupToWebdav)
+  handleIpc(IpcChannel.Backup_RestoreFromWebdav, backupManager.restoreFromWebdav)
+  handleIpc(IpcChannel.Backup_ListWebdavFiles, backupManager.listWebdavFiles)
+  handleIpc(IpcChannel.Backup_CheckConnection, backupManager.checkConnection)
+  handleIpc(IpcChannel.Backup_CreateDirectory, backupManager.createDirectory)
+  handleIpc(IpcChannel.Backup_DeleteWebdavFile, backupManager.deleteWebdavFile)
 
   // file
-  ipcMain.handle(IpcChannel.File_Open, fileManager.open)
-  ipcMain.handle(IpcChannel.File_OpenPath, fileManager.openPath)
-  ipcMain.handle(IpcChannel.File_Save, fileManager.save)
-  ipcMain.handle(IpcChannel.File_Select, fileManager.selectFile)
-  ipcMain.handle(IpcChannel.File_Upload, fileManager.uploadFile)
-  ipcMain.handle(IpcChannel.File_Clear, fileManager.clear)
-  ipcMain.handle(IpcChannel.File_Read, fileManager.readFile)
-  ipcMain.handle(IpcChannel.File_Delete, fileManager.deleteFile)
-  ipcMain.handle(IpcChannel.File_Get, fileManager.getFile)
-  ipcMain.handle(IpcChannel.File_SelectFolder, fileManager.selectFolder)
-  ipcMain.handle(IpcChannel.File_Create, fileManager.createTempFile)
-  ipcMain.handle(IpcChannel.File_Write, fileManager.writeFile)
-  ipcMain.handle(IpcChannel.File_WriteWithId, fileManager.writeFileWithId)
-  ipcMain.handle(IpcChannel.File_SaveImage, fileManager.saveImage)
-  ipcMain.handle(IpcChannel.File_Base64Image, fileManager.base64Image)
-  ipcMain.handle(IpcChannel.File_SaveBase64Image, fileManager.saveBase64Image)
-  ipcMain.handle(IpcChannel.File_Base64File, fileManager.base64File)
-  ipcMain.handle(IpcChannel.File_GetPdfInfo, fileManager.pdfPageCount)
-  ipcMain.handle(IpcChannel.File_Download, fileManager.downloadFile)
-  ipcMain.handle(IpcChannel.File_Copy, fileManager.copyFile)
-  ipcMain.handle(IpcChannel.File_BinaryImage, fileManager.binaryImage)
+  handleIpc(IpcChannel.File_Open, fileManager.open)
+  handleIpc(IpcChannel.File_OpenPath, fileManager.openPath)
+  handleIpc(IpcChannel.File_Save, fileManager.save)
+  handleIpc(IpcChannel.File_Select, fileManager.selectFile)
+  handleIpc(IpcChannel.File_Upload, fileManager.uploadFile)
+  handleIpc(IpcChannel.File_Clear, fileManager.clear)
+  handleIpc(IpcChannel.File_Read, fileManager.readFile)
+  handleIpc(IpcChannel.File_Delete, fileManager.deleteFile)
+  handleIpc(IpcChannel.File_Get, fileManager.getFile)
+  handleIpc(IpcChannel.File_SelectFolder, fileManager.selectFolder)
+  handleIpc(IpcChannel.File_Create, fileManager.createTempFile)
+  handleIpc(IpcChannel.File_Write, fileManager.writeFile)
+  handleIpc(IpcChannel.File_WriteWithId, fileManager.writeFileWithId)
+  handleIpc(IpcChannel.File_SaveImage, fileManager.saveImage)
+  handleIpc(IpcChannel.File_Base64Image, fileManager.base64Image)
+  handleIpc(IpcChannel.File_SaveBase64Image, fileManager.saveBase64Image)
+  handleIpc(IpcChannel.File_Base64File, fileManager.base64File)
+  handleIpc(IpcChannel.File_GetPdfInfo, fileManager.pdfPageCount)
+  handleIpc(IpcChannel.File_Download, fileManager.downloadFile)
+  handleIpc(IpcChannel.File_Copy, fileManager.copyFile)
+  handleIpc(IpcChannel.File_BinaryImage, fileManager.binaryImage)
 
   // fs
-  ipcMain.handle(IpcChannel.Fs_Read, FileService.readFile)
+  handleIpc(IpcChannel.Fs_Read, FileService.readFile)
 
   // export
-  ipcMain.handle(IpcChannel.Export_Word, exportService.exportToWord)
+  handleIpc(IpcChannel.Export_Word, exportService.exportToWord)
 
   // open path
-  ipcMain.handle(IpcChannel.Open_Path, async (_, path: string) => {
+  handleIpc(IpcChannel.Open_Path, async (_, path: string) => {
     await shell.openPath(path)
   })
 
   // shortcuts
-  ipcMain.handle(IpcChannel.Shortcuts_Update, (_, shortcuts: Shortcut[]) => {
+  handleIpc(IpcChannel.Shortcuts_Update, (_, shortcuts: Shortcut[]) => {
     configManager.setShortcuts(shortcuts)
     // Refresh shortcuts registration
     if (mainWindow) {
@@ -319,20 +604,23 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
   })
 
   // knowledge base
-  ipcMain.handle(IpcChannel.KnowledgeBase_Create, KnowledgeService.create)
-  ipcMain.handle(IpcChannel.KnowledgeBase_Reset, KnowledgeService.reset)
-  ipcMain.handle(IpcChannel.KnowledgeBase_Delete, KnowledgeService.delete)
-  ipcMain.handle(IpcChannel.KnowledgeBase_Add, KnowledgeService.add)
-  ipcMain.handle(IpcChannel.KnowledgeBase_Remove, KnowledgeService.remove)
-  ipcMain.handle(IpcChannel.KnowledgeBase_Search, KnowledgeService.search)
-  ipcMain.handle(IpcChannel.KnowledgeBase_Rerank, KnowledgeService.rerank)
+  handleIpc(IpcChannel.KnowledgeBase_Create, KnowledgeService.create)
+  handleIpc(IpcChannel.KnowledgeBase_Reset, KnowledgeService.reset)
+  handleIpc(IpcChannel.KnowledgeBase_Delete, KnowledgeService.delete)
+  handleIpc(IpcChannel.KnowledgeBase_Add, KnowledgeService.add)
+  handleIpc(IpcChannel.KnowledgeBase_Remove, KnowledgeService.remove)
+  handleIpc(IpcChannel.KnowledgeBase_Search, KnowledgeService.search)
+  handleIpc(IpcChannel.KnowledgeBase_Rerank, KnowledgeService.rerank)
+  handleIpc(IpcChannel.KnowledgeBase_SyncSource, KnowledgeService.syncSource)
+  handleIpc(IpcChannel.KnowledgeBase_ResolveCitation, citationService.resolveCitation)
+  handleIpc(IpcChannel.KnowledgeBase_OpenCitation, citationService.openCitation)
 
   // window
-  ipcMain.handle(IpcChannel.Windows_SetMinimumSize, (_, width: number, height: number) => {
+  handleIpc(IpcChannel.Windows_SetMinimumSize, (_, width: number, height: number) => {
     mainWindow?.setMinimumSize(width, height)
   })
 
-  ipcMain.handle(IpcChannel.Windows_ResetMinimumSize, () => {
+  handleIpc(IpcChannel.Windows_ResetMinimumSize, () => {
     mainWindow?.setMinimumSize(1080, 600)
     const [width, height] = mainWindow?.getSize() ?? [1080, 600]
     if (width < 1080) {
@@ -341,86 +629,370 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
   })
 
   // VertexAI
-  ipcMain.handle(IpcChannel.VertexAI_GetAuthHeaders, async (_, params) => {
+  handleIpc(IpcChannel.VertexAI_GetAuthHeaders, async (_, params) => {
     return vertexAIService.getAuthHeaders(params)
   })
 
-  ipcMain.handle(IpcChannel.VertexAI_ClearAuthCache, async (_, projectId: string, clientEmail?: string) => {
+  handleIpc(IpcChannel.VertexAI_ClearAuthCache, async (_, projectId: string, clientEmail?: string) => {
     vertexAIService.clearAuthCache(projectId, clientEmail)
   })
 
+  // AWS Bedrock
+  handleIpc(IpcChannel.AwsBedrock_SetCredentials, (_, credentials: AwsCredentials) =>
+    awsBedrockService.setCredentials(credentials)
+  )
+  handleIpc(IpcChannel.AwsBedrock_ClearCredentials, () => awsBedrockService.clearCredentials())
+  handleIpc(IpcChannel.AwsBedrock_GetAccessKeyId, () => awsBedrockService.getAccessKeyId())
+  handleIpc(IpcChannel.AwsBedrock_SignRequest, (_, request: SignableRequest, region: string) =>
+    awsBedrockService.signRequest(request, region)
+  )
+
+  // local API server
+  handleIpc(IpcChannel.ApiServer_GetStatus, () => apiServer.getStatus())
+  handleIpc(IpcChannel.ApiServer_SetEnabled, (_, enabled: boolean) => apiServer.setEnabled(enabled))
+  handleIpc(IpcChannel.ApiServer_SetPort, (_, port: number) => apiServer.setPort(port))
+  handleIpc(IpcChannel.ApiServer_RegenerateToken, () => apiServer.regenerateToken())
+  handleIpc(IpcChannel.ApiServer_Response, (_, requestId: string, message: ApiServerResponseMessage) =>
+    apiServer.handleRendererResponse(requestId, message)
+  )
+  handleIpc(IpcChannel.ApiServer_Publish, (_, topic: string, event: string, data: any) =>
+    pushChannel.publish(topic, event, data)
+  )
+  handleIpc(IpcChannel.ApiServer_Ready, () => apiServer.markRendererReady())
+
+  // inbound webhooks
+  const handleWebhook = createManagerHandler('WebhookManager')
+  handleWebhook(IpcChannel.Webhook_List, () => webhookManager.list())
+  handleWebhook(IpcChannel.Webhook_Create, (_, input: WebhookInput) => webhookManager.create(input))
+  handleWebhook(IpcChannel.Webhook_Update, (_, id: string, patch: Partial<WebhookInput>) =>
+    webhookManager.update(id, patch)
+  )
+  handleWebhook(IpcChannel.Webhook_Remove, (_, id: string) => webhookManager.remove(id))
+  handleWebhook(IpcChannel.Webhook_RegenerateSecret, (_, id: string) => webhookManager.regenerateSecret(id))
+  handleWebhook(IpcChannel.Webhook_GetLogs, (_, id?: string) => webhookManager.getLogs(id))
+
+  // outbound integrations
+  const handleOutbound = createManagerHandler('OutboundIntegrationManager')
+  handleOutbound(IpcChannel.Outbound_List, () => outboundIntegrationManager.list())
+  handleOutbound(IpcChannel.Outbound_Save, (_, integration: OutboundIntegration, secret?: string) =>
+    outboundIntegrationManager.save(integration, secret)
+  )
+  handleOutbound(IpcChannel.Outbound_Remove, (_, id: string) => outboundIntegrationManager.remove(id))
+  handleOutbound(IpcChannel.Outbound_Test, (_, id: string) => outboundIntegrationManager.test(id))
+  handleOutbound(IpcChannel.Outbound_Emit, (_, event: OutboundEvent, data: Record<string, any>) =>
+    outboundIntegrationManager.emit(event, data)
+  )
+  handleOutbound(IpcChannel.Outbound_GetLogs, (_, id?: string) => outboundIntegrationManager.getLogs(id))
+
+  // csv, tsv and xlsx previews
+  const handleTabular = createManagerHandler('TabularService')
+  handleTabular(IpcChannel.Tabular_Parse, (_, filePath: string, options?: TabularParseOptions) =>
+    tabularService.parse(filePath, options)
+  )
+
+  // line ranges of large text files
+  const handleTextPreview = createManagerHandler('TextPreviewService')
+  handleTextPreview(IpcChannel.TextPreview_ReadLines, (_, filePath: string, startLine?: number, count?: number) =>
+    textPreviewService.readLines(filePath, startLine, count)
+  )
+
+  // unsaved drafts journaled for recovery after a crash
+  const handleSession = createManagerHandler('SessionJournal')
+  handleSession(IpcChannel.Session_SaveDraftState, (_, state: SessionDraftState) =>
+    sessionJournal.saveDraftState(state)
+  )
+  handleSession(IpcChannel.Session_GetRecovered, () => sessionJournal.getRecoveredSession())
+  handleSession(IpcChannel.Session_DiscardRecovered, () => sessionJournal.discardRecoveredSession())
+
+  // print the calling view to pdf
+  const handlePrint = createManagerHandler('PrintService')
+  handlePrint(IpcChannel.Print_ToPdf, (event, outputPath?: string, options?: PdfPrintOptions) =>
+    printService.printToPdf(event.sender, outputPath, options)
+  )
+
+  // user templates
+  const handleTemplate = createManagerHandler('TemplateManager')
+  handleTemplate(IpcChannel.Template_List, () => templateManager.list())
+  handleTemplate(
+    IpcChannel.Template_Save,
+    (_, input: Pick<UserTemplate, 'name' | 'content' | 'description'> & { id?: string }) => templateManager.save(input)
+  )
+  handleTemplate(IpcChannel.Template_Remove, (_, id: string) => templateManager.remove(id))
+  handleTemplate(IpcChannel.Template_Render, (_, idOrName: string, context: unknown) =>
+    templateManager.render(idOrName, context)
+  )
+  handleTemplate(IpcChannel.Template_RenderSource, (_, source: string, context: unknown) =>
+    templateManager.renderSource(source, context)
+  )
+  handleTemplate(IpcChannel.Template_ExpandIncludes, (_, source: string) => templateManager.expandIncludes(source))
+
+  // share target
+  handleIpc(IpcChannel.Share_TakePending, () => shareManager.takePending())
+
+  // smart paste
+  handleIpc(IpcChannel.Clipboard_Classify, () => clipboardManager.classify())
+
+  // link previews
+  const handleLinkPreview = createManagerHandler('LinkPreviewManager')
+  handleLinkPreview(IpcChannel.LinkPreview_Fetch, (_, url: string) => linkPreviewManager.fetch(url))
+
+  // transcripts opened from the OS
+  handleIpc(IpcChannel.Transcript_TakePending, () => transcriptManager.takePending())
+
+  // conversation sharing
+  const handleConversationShare = createManagerHandler('ConversationShareManager')
+  handleConversationShare(
+    IpcChannel.ConversationShare_Share,
+    (_, topicId: string, title: string, messages: SharedConversationMessage[], options?: ShareConversationOptions) =>
+      conversationShareManager.share(topicId, title, messages, options)
+  )
+  handleConversationShare(IpcChannel.ConversationShare_List, () => conversationShareManager.list())
+  handleConversationShare(IpcChannel.ConversationShare_Revoke, (_, id: string) => conversationShareManager.revoke(id))
+
+  // scheduled messages
+  const handleScheduler = createManagerHandler('SchedulerManager')
+  handleScheduler(IpcChannel.Scheduler_Schedule, (_, input: ScheduleMessageInput) => schedulerManager.schedule(input))
+  handleScheduler(IpcChannel.Scheduler_List, (_, topicId?: string) => schedulerManager.list(topicId))
+  handleScheduler(IpcChannel.Scheduler_Cancel, (_, id: string) => schedulerManager.cancel(id))
+
+  // folders mounted to conversations
+  const handleConversationWorkspace = createManagerHandler('ConversationWorkspaceManager')
+  handleConversationWorkspace(IpcChannel.ConversationWorkspace_Mount, (_, topicId: string, dirPath: string) =>
+    conversationWorkspaceManager.mount(topicId, dirPath)
+  )
+  handleConversationWorkspace(IpcChannel.ConversationWorkspace_Unmount, (_, topicId: string) =>
+    conversationWorkspaceManager.unmount(topicId)
+  )
+  handleConversationWorkspace(IpcChannel.ConversationWorkspace_List, () => conversationWorkspaceManager.list())
+  handleConversationWorkspace(IpcChannel.ConversationWorkspace_GetChanges, (_, topicId: string) =>
+    conversationWorkspaceManager.getChanges(topicId)
+  )
+  handleConversationWorkspace(
+    IpcChannel.ConversationWorkspace_ComputeDiff,
+    (_, oldText: string, newText: string, mode?: TextDiffMode) => computeDiff(oldText, newText, mode)
+  )
+  handleConversationWorkspace(
+    IpcChannel.ConversationWorkspace_ApplyPatch,
+    (_, topicId: string, filePath: string | undefined, unifiedDiff: string, dryRun?: boolean) =>
+      conversationWorkspaceManager.applyPatch(topicId, filePath, unifiedDiff, dryRun)
+  )
+
+  // browser extension bridge
+  handleIpc(IpcChannel.BrowserBridge_GetStatus, () => browserBridge.getStatus())
+  handleIpc(IpcChannel.BrowserBridge_Install, (_, browser: BridgeBrowser, extensionId: string) =>
+    browserBridge.install(browser, extensionId)
+  )
+  handleIpc(IpcChannel.BrowserBridge_Uninstall, (_, browser: BridgeBrowser) => browserBridge.uninstall(browser))
+
   // mini window
-  ipcMain.handle(IpcChannel.MiniWindow_Show, () => windowService.showMiniWindow())
-  ipcMain.handle(IpcChannel.MiniWindow_Hide, () => windowService.hideMiniWindow())
-  ipcMain.handle(IpcChannel.MiniWindow_Close, () => windowService.closeMiniWindow())
-  ipcMain.handle(IpcChannel.MiniWindow_Toggle, () => windowService.toggleMiniWindow())
-  ipcMain.handle(IpcChannel.MiniWindow_SetPin, (_, isPinned) => windowService.setPinMiniWindow(isPinned))
+  handleIpc(IpcChannel.MiniWindow_Show, (_, listen?: boolean) => windowService.showMiniWindow(!!listen))
+  handleIpc(IpcChannel.MiniWindow_Hide, () => windowService.hideMiniWindow())
+  handleIpc(IpcChannel.MiniWindow_Close, () => windowService.closeMiniWindow())
+  handleIpc(IpcChannel.MiniWindow_Toggle, () => windowService.toggleMiniWindow())
+  handleIpc(IpcChannel.MiniWindow_SetPin, (_, isPinned) => windowService.setPinMiniWindow(isPinned))
+
+  // tiling layouts of the main window and the quick assistant
+  const handleSnapLayout = createManagerHandler('SnapLayoutManager')
+  handleSnapLayout(IpcChannel.SnapLayout_List, () => snapLayoutManager.listLayouts())
+  handleSnapLayout(IpcChannel.SnapLayout_Apply, (_, name: string) => snapLayoutManager.applyLayout(name))
+  handleSnapLayout(IpcChannel.SnapLayout_Save, (_, name: string) => snapLayoutManager.saveLayout(name))
+  handleSnapLayout(IpcChannel.SnapLayout_Remove, (_, name: string) => snapLayoutManager.removeLayout(name))
+
+  // live transcription overlay
+  handleIpc(IpcChannel.Transcription_Open, () => windowService.showTranscriptionWindow())
+  handleIpc(IpcChannel.Transcription_Close, () => windowService.closeTranscriptionWindow())
+  handleIpc(IpcChannel.Transcription_SendToChat, (_, text: string) => shareManager.share({ text, files: [] }))
+
+  // read-only full-screen presentation of a conversation
+  handleIpc(IpcChannel.Presentation_Enter, (_, topicId: string) => windowService.enterPresentationMode(topicId))
+  handleIpc(IpcChannel.Presentation_Exit, () => windowService.exitPresentationMode())
 
   // aes
-  ipcMain.handle(IpcChannel.Aes_Encrypt, (_, text: string, secretKey: string, iv: string) =>
-    encrypt(text, secretKey, iv)
-  )
-  ipcMain.handle(IpcChannel.Aes_Decrypt, (_, encryptedData: string, iv: string, secretKey: string) =>
+  handleIpc(IpcChannel.Aes_Encrypt, (_, text: string, secretKey: string, iv: string) => encrypt(text, secretKey, iv))
+  handleIpc(IpcChannel.Aes_Decrypt, (_, encryptedData: string, iv: string, secretKey: string) =>
     decrypt(encryptedData, iv, secretKey)
   )
 
   // Register MCP handlers
-  ipcMain.handle(IpcChannel.Mcp_RemoveServer, mcpService.removeServer)
-  ipcMain.handle(IpcChannel.Mcp_RestartServer, mcpService.restartServer)
-  ipcMain.handle(IpcChannel.Mcp_StopServer, mcpService.stopServer)
-  ipcMain.handle(IpcChannel.Mcp_ListTools, mcpService.listTools)
-  ipcMain.handle(IpcChannel.Mcp_CallTool, mcpService.callTool)
-  ipcMain.handle(IpcChannel.Mcp_ListPrompts, mcpService.listPrompts)
-  ipcMain.handle(IpcChannel.Mcp_GetPrompt, mcpService.getPrompt)
-  ipcMain.handle(IpcChannel.Mcp_ListResources, mcpService.listResources)
-  ipcMain.handle(IpcChannel.Mcp_GetResource, mcpService.getResource)
-  ipcMain.handle(IpcChannel.Mcp_GetInstallInfo, mcpService.getInstallInfo)
-  ipcMain.handle(IpcChannel.Mcp_CheckConnectivity, mcpService.checkMcpConnectivity)
-
-  ipcMain.handle(IpcChannel.App_IsBinaryExist, (_, name: string) => isBinaryExists(name))
-  ipcMain.handle(IpcChannel.App_GetBinaryPath, (_, name: string) => getBinaryPath(name))
-  ipcMain.handle(IpcChannel.App_InstallUvBinary, () => runInstallScript('install-uv.js'))
-  ipcMain.handle(IpcChannel.App_InstallBunBinary, () => runInstallScript('install-bun.js'))
+  handleIpc(IpcChannel.Mcp_RemoveServer, mcpService.removeServer)
+  handleIpc(IpcChannel.Mcp_RestartServer, mcpService.restartServer)
+  handleIpc(IpcChannel.Mcp_StopServer, mcpService.stopServer)
+  handleIpc(IpcChannel.Mcp_ListTools, mcpService.listTools)
+  handleIpc(IpcChannel.Mcp_CallTool, mcpService.callTool)
+  handleIpc(IpcChannel.Mcp_ListPrompts, mcpService.listPrompts)
+  handleIpc(IpcChannel.Mcp_GetPrompt, mcpService.getPrompt)
+  handleIpc(IpcChannel.Mcp_ListResources, mcpService.listResources)
+  handleIpc(IpcChannel.Mcp_GetResource, mcpService.getResource)
+  handleIpc(IpcChannel.Mcp_GetInstallInfo, mcpService.getInstallInfo)
+  handleIpc(IpcChannel.Mcp_CheckConnectivity, mcpService.checkMcpConnectivity)
+
+  // MCP marketplace
+  const handleMcpRegistry = createManagerHandler('MCPRegistryManager')
+  handleMcpRegistry(IpcChannel.Mcp_GetRegistry, (_, refresh?: boolean) => mcpRegistryManager.getRegistry(refresh))
+  handleMcpRegistry(IpcChannel.Mcp_CheckDependencies, () => mcpRegistryManager.checkDependencies())
+  handleMcpRegistry(IpcChannel.Mcp_GetInstalled, () => mcpRegistryManager.getInstalled())
+  handleMcpRegistry(IpcChannel.Mcp_Install, (_, id: string) => mcpRegistryManager.install(id))
+  handleMcpRegistry(IpcChannel.Mcp_Uninstall, (_, id: string) => mcpRegistryManager.uninstall(id))
+  handleMcpRegistry(IpcChannel.Mcp_CheckHealth, () => mcpRegistryManager.checkHealth())
+  handleMcpRegistry(IpcChannel.Mcp_CheckUpdates, () => mcpRegistryManager.checkUpdates())
+  handleMcpRegistry(IpcChannel.Mcp_Update, (_, id: string) => mcpRegistryManager.update(id))
+  handleMcpRegistry(IpcChannel.Mcp_GetAutoUpdate, () => configManager.getMcpAutoUpdate())
+  handleMcpRegistry(IpcChannel.Mcp_SetAutoUpdate, (_, enabled: boolean) => configManager.setMcpAutoUpdate(enabled))
+
+  const handleToolCalls = createManagerHandler('ToolCallRecorder')
+  handleToolCalls(IpcChannel.Mcp_ListToolCalls, (_, query?: ToolCallQuery) => toolCallRecorder.list(query))
+  handleToolCalls(IpcChannel.Mcp_GetToolCall, (_, id: string) => toolCallRecorder.get(id))
+  handleToolCalls(IpcChannel.Mcp_ReplayToolCall, mcpService.replayToolCall)
+  handleToolCalls(IpcChannel.Mcp_ClearToolCalls, (_, topicId?: string) => toolCallRecorder.clear(topicId))
+
+  handleIpc(IpcChannel.App_IsBinaryExist, (_, name: string) => isBinaryExists(name))
+  handleIpc(IpcChannel.App_GetBinaryPath, (_, name: string) => getBinaryPath(name))
+  handleIpc(IpcChannel.App_InstallUvBinary, () => runInstallScript('install-uv.js'))
+  handleIpc(IpcChannel.App_InstallBunBinary, () => runInstallScript('install-bun.js'))
 
   //copilot
-  ipcMain.handle(IpcChannel.Copilot_GetAuthMessage, CopilotService.getAuthMessage)
-  ipcMain.handle(IpcChannel.Copilot_GetCopilotToken, CopilotService.getCopilotToken)
-  ipcMain.handle(IpcChannel.Copilot_SaveCopilotToken, CopilotService.saveCopilotToken)
-  ipcMain.handle(IpcChannel.Copilot_GetToken, CopilotService.getToken)
-  ipcMain.handle(IpcChannel.Copilot_Logout, CopilotService.logout)
-  ipcMain.handle(IpcChannel.Copilot_GetUser, CopilotService.getUser)
+  handleIpc(IpcChannel.Copilot_GetAuthMessage, CopilotService.getAuthMessage)
+  handleIpc(IpcChannel.Copilot_GetCopilotToken, CopilotService.getCopilotToken)
+  handleIpc(IpcChannel.Copilot_SaveCopilotToken, CopilotService.saveCopilotToken)
+  handleIpc(IpcChannel.Copilot_GetToken, CopilotService.getToken)
+  handleIpc(IpcChannel.Copilot_Logout, CopilotService.logout)
+  handleIpc(IpcChannel.Copilot_GetUser, CopilotService.getUser)
 
   // Obsidian service
-  ipcMain.handle(IpcChannel.Obsidian_GetVaults, () => {
+  handleIpc(IpcChannel.Obsidian_GetVaults, () => {
     return obsidianVaultService.getVaults()
   })
 
-  ipcMain.handle(IpcChannel.Obsidian_GetFiles, (_event, vaultName) => {
+  handleIpc(IpcChannel.Obsidian_GetFiles, (_event, vaultName) => {
     return obsidianVaultService.getFilesByVaultName(vaultName)
   })
 
   // nutstore
-  ipcMain.handle(IpcChannel.Nutstore_GetSsoUrl, NutstoreService.getNutstoreSSOUrl)
-  ipcMain.handle(IpcChannel.Nutstore_DecryptToken, (_, token: string) => NutstoreService.decryptToken(token))
-  ipcMain.handle(IpcChannel.Nutstore_GetDirectoryContents, (_, token: string, path: string) =>
+  handleIpc(IpcChannel.Nutstore_GetSsoUrl, NutstoreService.getNutstoreSSOUrl)
+  handleIpc(IpcChannel.Nutstore_DecryptToken, (_, token: string) => NutstoreService.decryptToken(token))
+  handleIpc(IpcChannel.Nutstore_GetDirectoryContents, (_, token: string, path: string) =>
     NutstoreService.getDirectoryContents(token, path)
   )
 
   // search window
-  ipcMain.handle(IpcChannel.SearchWindow_Open, async (_, uid: string) => {
+  handleIpc(IpcChannel.SearchWindow_Open, async (_, uid: string) => {
     await searchService.openSearchWindow(uid)
   })
-  ipcMain.handle(IpcChannel.SearchWindow_Close, async (_, uid: string) => {
+  handleIpc(IpcChannel.SearchWindow_Close, async (_, uid: string) => {
     await searchService.closeSearchWindow(uid)
   })
-  ipcMain.handle(IpcChannel.SearchWindow_OpenUrl, async (_, uid: string, url: string) => {
+  handleIpc(IpcChannel.SearchWindow_OpenUrl, async (_, uid: string, url: string) => {
     return await searchService.openUrlInSearchWindow(uid, url)
   })
 
   // webview
-  ipcMain.handle(IpcChannel.Webview_SetOpenLinkExternal, (_, webviewId: number, isExternal: boolean) =>
+  const handleWebview = createManagerHandler('WebviewService')
+  handleWebview(IpcChannel.Webview_SetOpenLinkExternal, (_, webviewId: number, isExternal: boolean) =>
     setOpenLinkExternal(webviewId, isExternal)
   )
+  handleWebview(IpcChannel.Webview_ClearEphemeralSession, (_, partition: string) => clearEphemeralSession(partition))
+  handleWebview(IpcChannel.Webview_SetShortcutPassThrough, (_, webviewId: number, shortcuts: string[][]) =>
+    setShortcutPassThrough(webviewId, shortcuts)
+  )
+  handleWebview(IpcChannel.Webview_SetContextMenu, (_, webviewId: number, actions: WebviewContextMenuAction[]) =>
+    setWebviewContextMenu(webviewId, actions)
+  )
+  handleWebview(IpcChannel.Webview_Suspend, (_, webviewId: number) => suspendWebview(webviewId))
+  handleWebview(IpcChannel.Webview_Resume, (_, webviewId: number) => resumeWebview(webviewId))
+
+  // ad and tracker blocking of the webviews
+  const handleContentBlocker = createManagerHandler('ContentBlocker')
+  handleContentBlocker(IpcChannel.Webview_SetContentBlocking, (_, config: ContentBlockingConfig) =>
+    contentBlocker.configure(config)
+  )
+  handleContentBlocker(IpcChannel.Webview_UpdateFilterLists, () => contentBlocker.update())
+
+  // mini app data handoff
+  const handleMiniApp = createManagerHandler('MiniAppManager')
+  handleMiniApp(IpcChannel.MiniApp_RegisterWebview, (_, appId: string, webviewId: number) =>
+    miniAppManager.registerWebview(appId, webviewId)
+  )
+  handleMiniApp(IpcChannel.MiniApp_UnregisterWebview, (_, appId: string) => miniAppManager.unregisterWebview(appId))
+  handleMiniApp(IpcChannel.MiniApp_GetIntents, (_, appId: string) => miniAppManager.getIntents(appId))
+  handleMiniApp(IpcChannel.MiniApp_SendIntent, (_, appId: string, intent: string, payload: unknown) =>
+    miniAppManager.send(appId, intent, payload)
+  )
+  handleMiniApp(IpcChannel.MiniApp_Navigate, (_, appId: string, url: string) => miniAppManager.navigate(appId, url))
+  handleMiniApp(IpcChannel.MiniApp_GoBack, (_, appId: string) => miniAppManager.goBack(appId))
+  handleMiniApp(IpcChannel.MiniApp_GoForward, (_, appId: string) => miniAppManager.goForward(appId))
+  handleMiniApp(IpcChannel.MiniApp_Reload, (_, appId: string, ignoreCache?: boolean) =>
+    miniAppManager.reload(appId, ignoreCache)
+  )
+  handleMiniApp(IpcChannel.MiniApp_GetNavigationState, (_, appId: string) => miniAppManager.getNavigationState(appId))
+  handleMiniApp(IpcChannel.MiniApp_SetZoom, (_, appId: string, factor: number) => miniAppManager.setZoom(appId, factor))
+  handleMiniApp(IpcChannel.MiniApp_GetZoom, (_, appId: string) => miniAppManager.getZoom(appId))
+  handleMiniApp(IpcChannel.MiniApp_Capture, (_, appId: string, options?: MiniAppCaptureOptions) =>
+    miniAppManager.capture(appId, options)
+  )
+  handleMiniApp(IpcChannel.MiniApp_PrintToPdf, (_, appId: string, outputPath?: string, options?: PdfPrintOptions) =>
+    miniAppManager.printToPdf(appId, outputPath, options)
+  )
+  handleMiniApp(IpcChannel.MiniApp_GetCookies, (_, appId: string, url?: string) =>
+    miniAppManager.getCookies(appId, url)
+  )
+  handleMiniApp(IpcChannel.MiniApp_SetCookie, (_, appId: string, cookie: MiniAppCookieInput) =>
+    miniAppManager.setCookie(appId, cookie)
+  )
+  handleMiniApp(IpcChannel.MiniApp_ClearCookies, (_, appId: string) => miniAppManager.clearCookies(appId))
+  handleMiniApp(IpcChannel.MiniApp_RespondPermission, (_, requestId: string, granted: boolean, remember?: boolean) =>
+    miniAppManager.respondPermission(requestId, granted, remember)
+  )
+  handleMiniApp(IpcChannel.MiniApp_GetPermissions, () => miniAppManager.getPermissions())
+  handleMiniApp(IpcChannel.MiniApp_ResetPermissions, (_, appId?: string) => miniAppManager.resetPermissions(appId))
+  handleMiniApp(IpcChannel.MiniApp_GetStats, (_, appId: string) => miniAppManager.getStats(appId))
+  handleMiniApp(IpcChannel.MiniApp_SetMuted, (_, appId: string, muted: boolean) =>
+    miniAppManager.setMuted(appId, muted)
+  )
+  handleMiniApp(IpcChannel.MiniApp_GetAudioState, (_, appId: string) => miniAppManager.getAudioState(appId))
+  handleMiniApp(IpcChannel.MiniApp_PreparePreload, (_, appId: string, files: string[]) =>
+    miniAppManager.preparePreload(appId, files)
+  )
+  handleMiniApp(IpcChannel.MiniApp_PostBridgeMessage, (_, appId: string, data: unknown) =>
+    miniAppManager.postBridgeMessage(appId, data)
+  )
+  // called by mini app pages through `window.metheus`, the sender is the mini app's webview
+  handleMiniApp(IpcChannel.MiniApp_BridgeInvoke, (event, command: string, ...args: unknown[]) =>
+    miniAppBridge.invoke(event.sender, command, args)
+  )
+  handleMiniApp(IpcChannel.MiniApp_SetVisible, (_, appId: string, visible: boolean) =>
+    miniAppManager.setVisible(appId, visible)
+  )
+  handleMiniApp(IpcChannel.MiniApp_GetAll, () => miniAppManager.getAll())
+  handleMiniApp(IpcChannel.MiniApp_HideAll, () => miniAppManager.hideAll())
+  handleMiniApp(IpcChannel.MiniApp_DestroyAll, () => miniAppManager.destroyAll())
+  handleMiniApp(IpcChannel.MiniApp_RestoreLayout, () => miniAppManager.restoreLayout())
+  handleMiniApp(IpcChannel.MiniApp_GetManifestApps, () => miniAppManager.getManifestApps())
+
+  // signed mini app packages
+  const handleMiniAppRegistry = createManagerHandler('MiniAppRegistryManager')
+  handleMiniAppRegistry(IpcChannel.MiniApp_GetRegistry, (_, refresh?: boolean) =>
+    miniAppRegistryManager.getRegistry(refresh)
+  )
+  handleMiniAppRegistry(IpcChannel.MiniApp_GetInstalledPackages, () => miniAppRegistryManager.getInstalled())
+  handleMiniAppRegistry(IpcChannel.MiniApp_InstallPackage, (_, id: string, confirmed?: boolean) =>
+    miniAppRegistryManager.install(id, confirmed)
+  )
+  handleMiniAppRegistry(IpcChannel.MiniApp_InstallFromUrl, (_, url: string, confirmed?: boolean) =>
+    miniAppRegistryManager.installFromUrl(url, undefined, undefined, confirmed)
+  )
+  handleMiniAppRegistry(IpcChannel.MiniApp_UninstallPackage, (_, id: string) => miniAppRegistryManager.uninstall(id))
+  handleMiniAppRegistry(IpcChannel.MiniApp_GetTrustedKeys, () => miniAppRegistryManager.getTrustedKeys())
+  handleMiniAppRegistry(IpcChannel.MiniApp_SetTrustedKeys, (_, keys: string[]) =>
+    miniAppRegistryManager.setTrustedKeys(keys)
+  )
+  handleMiniAppRegistry(IpcChannel.MiniApp_CheckUpdates, () => miniAppRegistryManager.checkUpdates())
+  handleMiniAppRegistry(IpcChannel.MiniApp_UpdatePackage, (_, id: string, confirmed?: boolean) =>
+    miniAppRegistryManager.update(id, confirmed)
+  )
+  handleMiniAppRegistry(IpcChannel.MiniApp_GetAutoUpdate, () => configManager.getMiniAppAutoUpdate())
+  handleMiniAppRegistry(IpcChannel.MiniApp_SetAutoUpdate, (_, enabled: boolean) =>
+    configManager.setMiniAppAutoUpdate(enabled)
+  )
 
   // store sync
   storeSyncService.registerIpcHandler()
@@ -428,5 +1000,5 @@ export function registerIpc(mainWindow: BrowserWindow, app: Electron.App) {
   // selection assistant
   SelectionService.registerIpcHandler()
 
-  ipcMain.handle(IpcChannel.App_QuoteToMain, (_, text: string) => windowService.quoteToMainWindow(text))
+  handleIpc(IpcChannel.App_QuoteToMain, (_, text: string) => windowService.quoteToMainWindow(text))
 }
@@ -0,0 +1,142 @@
+import { SourceConnector, SourceDocument } from './types'
+
+const NOTION_API = 'https://api.notion.com/v1'
+const NOTION_VERSION = '2022-06-28'
+
+/**
+ * Imports the pages shared with a Notion integration, optionally limited to the subtree of one page
+ */
+export class NotionConnector implements SourceConnector {
+  readonly key: string
+
+  constructor(
+    private readonly token: string,
+    private readonly rootPageId?: string
+  ) {
+    this.key = `notion:${rootPageId || 'workspace'}`
+  }
+
+  async *fetchDocuments(): AsyncGenerator<SourceDocument> {
+    const pages = this.rootPageId ? await this.listSubtree(this.rootPageId) : await this.searchPages()
+
+    for (const page of pages) {
+      const lines = await this.renderBlocks(page.id, 0)
+      yield {
+        id: page.id,
+        title: this.getTitle(page),
+        content: lines.join('\n'),
+        source: page.url,
+        version: page.last_edited_time
+      }
+    }
+  }
+
+  private async request(endpoint: string, init?: { method?: string; body?: unknown }) {
+    const response = await fetch(`${NOTION_API}${endpoint}`, {
+      method: init?.method || 'GET',
+      headers: {
+        Authorization: `Bearer ${this.token}`,
+        'Notion-Version': NOTION_VERSION,
+        'Content-Type': 'application/json'
+      },
+      body: init?.body ? JSON.stringify(init.body) : undefined
+    })
+    if (!response.ok) {
+      throw new Error(`Notion API error ${response.status}: ${await response.text()}`)
+    }
+    return response.json()
+  }
+
+  private async searchPages(): Promise<any[]> {
+    const pages: any[] = []
+    let cursor: string | undefined
+    do {
+      const data = await this.request('/search', {
+        method: 'POST',
+        body: { filter: { property: 'object', value: 'page' }, start_cursor: cursor, page_size: 100 }
+      })
+      pages.push(...data.results)
+      cursor = data.has_more ? data.next_cursor : undefined
+    } while (cursor)
+    return pages
+  }
+
+  private async listSubtree(pageId: string): Promise<any[]> {
+    const page = await this.request(`/pages/${pageId}`)
+    const pages = [page]
+    for (const block of await this.listChildren(pageId)) {
+      if (block.type === 'child_page') {
+        pages.push(...(await this.listSubtree(block.id)))
+      }
+    }
+    return pages
+  }
+
+  private async listChildren(blockId: string): Promise<any[]> {
+    const blocks: any[] = []
+    let cursor: string | undefined
+    do {
+      const query = cursor ? `?start_cursor=${cursor}&page_size=100` : '?page_size=100'
+      const data = await this.request(`/blocks/${blockId}/children${query}`)
+      blocks.push(...data.results)
+      cursor = data.has_more ? data.next_cursor : undefined
+    } while (cursor)
+    return blocks
+  }
+
+  private async renderBlocks(blockId: string, depth: number): Promise<string[]> {
+    const lines: string[] = []
+    const indent = '  '.repeat(depth)
+
+    for (const block of await this.listChildren(blockId)) {
+      // child pages are imported as separate documents
+      if (block.type === 'child_page') continue
+
+      const value = block[block.type] || {}
+      const text = this.richText(value.rich_text)
+      switch (block.type) {
+        case 'heading_1':
+          lines.push(`# ${text}`)
+          break
+        case 'heading_2':
+          lines.push(`## ${text}`)
+          break
+        case 'heading_3':
+          lines.push(`### ${text}`)
+          break
+        case 'bulleted_list_item':
+          lines.push(`${indent}- ${text}`)
+          break
+        case 'numbered_list_item':
+          lines.push(`${indent}1. ${text}`)
+          break
+        case 'to_do':
+          lines.push(`${indent}- [${value.checked ? 'x' : ' '}] ${text}`)
+          break
+        case 'quote':
+        case 'callout':
+          lines.push(`> ${text}`)
+          break
+        case 'code':
+          lines.push('```' + (value.language || ''), text, '```')
+          break
+        default:
+          if (text) lines.push(indent + text)
+      }
+
+      if (block.has_children) {
+        lines.push(...(await this.renderBlocks(block.id, depth + 1)))
+      }
+    }
+    return lines
+  }
+
+  private richText(items?: any[]): string {
+    return (items || []).map((item) => item.plain_text).join('')
+  }
+
+  private getTitle(page: any): string {
+    const titleProperty: any = Object.values(page.properties || {}).find((property: any) => property.type === 'title')
+    return this.richText(titleProperty?.title) || 'Untitled'
+  }
+}
@@ -0,0 +1,82 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { SourceConnector, SourceDocument } from './types'
+
+const MARKDOWN_EXTS = ['.md', '.markdown']
+const IGNORED_DIRS = ['.obsidian', '.trash', '.git', 'node_modules']
+
+// [[Target]], [[Target|Alias]], [[Target#Heading]] and embeds ![[Target]]
+const WIKI_LINK_REGEX = /!?\[\[([^\]|#]+)(#[^\]|]*)?(?:\|([^\]]+))?\]\]/g
+
+/**
+ * Imports an Obsidian vault (or any folder of Markdown files).
+ * Wiki links are rewritten to plain text and the resolved targets are listed at the end of each document,
+ * so that retrieval can follow the connections between notes.
+ */
+export class ObsidianConnector implements SourceConnector {
+  readonly key: string
+
+  constructor(private readonly vaultPath: string) {
+    this.key = `obsidian:${vaultPath}`
+  }
+
+  async *fetchDocuments(): AsyncGenerator<SourceDocument> {
+    const files = this.listMarkdownFiles(this.vaultPath)
+
+    // Obsidian resolves links by file name first, then by vault-relative path
+    const index = new Map<string, string>()
+    for (const file of files) {
+      const relative = path.relative(this.vaultPath, file)
+      const withoutExt = relative.replace(/\.(md|markdown)$/i, '')
+      index.set(withoutExt.toLowerCase(), relative)
+      const basename = path.basename(withoutExt).toLowerCase()
+      if (!index.has(basename)) index.set(basename, relative)
+    }
+
+    for (const file of files) {
+      const relative = path.relative(this.vaultPath, file)
+      const stats = await fs.promises.stat(file)
+      const raw = await fs.promises.readFile(file, 'utf-8')
+
+      yield {
+        id: relative,
+        title: path.basename(relative).replace(/\.(md|markdown)$/i, ''),
+        content: this.resolveWikiLinks(this.stripFrontmatter(raw), index),
+        source: file,
+        version: `${stats.mtimeMs}:${stats.size}`
+      }
+    }
+  }
+
+  private resolveWikiLinks(content: string, index: Map<string, string>): string {
+    const links = new Set<string>()
+
+    const text = content.replace(WIKI_LINK_REGEX, (_, target: string, heading: string | undefined, alias?: string) => {
+      const resolved = index.get(target.trim().toLowerCase())
+      if (resolved) links.add(resolved)
+      return alias?.trim() || target.trim() + (heading ? ` ${heading.slice(1)}` : '')
+    })
+
+    if (links.size === 0) return text
+    return `${text}\n\nLinked notes: ${[...links].join(', ')}`
+  }
+
+  private stripFrontmatter(content: string): string {
+    return content.replace(/^---\r?\n[\s\S]*?\r?\n---\r?\n/, '')
+  }
+
+  private listMarkdownFiles(dir: string): string[] {
+    const files: string[] = []
+    for (const entry of fs.readdirSync(dir, { withFileTypes: true })) {
+      if (entry.name.startsWith('.') && entry.isDirectory()) continue
+      const fullPath = path.join(dir, entry.name)
+      if (entry.isDirectory()) {
+        if (!IGNORED_DIRS.includes(entry.name)) files.push(...this.listMarkdownFiles(fullPath))
+      } else if (MARKDOWN_EXTS.includes(path.extname(entry.name).toLowerCase())) {
+        files.push(fullPath)
+      }
+    }
+    return files
+  }
+}
@@ -0,0 +1,98 @@
+import crypto from 'node:crypto'
+
+import { JSDOM } from 'jsdom'
+import TurndownService from 'turndown'
+
+import { SourceConnector, SourceDocument } from './types'
+
+const DEFAULT_MAX_PAGES = 50
+const DEFAULT_MAX_DEPTH = 3
+const USER_AGENT =
+  'Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36'
+
+/**
+ * Breadth-first crawl of a website, bounded by page count, link depth and the start URL's origin and path
+ */
+export class WebCrawlConnector implements SourceConnector {
+  readonly key: string
+  readonly gone = new Set<string>()
+  incomplete = false
+  private readonly turndown = new TurndownService()
+
+  constructor(
+    private readonly startUrl: string,
+    private readonly maxPages: number = DEFAULT_MAX_PAGES,
+    private readonly maxDepth: number = DEFAULT_MAX_DEPTH
+  ) {
+    this.key = `crawl:${startUrl}`
+  }
+
+  async *fetchDocuments(): AsyncGenerator<SourceDocument> {
+    const start = new URL(this.startUrl)
+    const scope = start.pathname.endsWith('/') ? start.pathname : start.pathname.replace(/[^/]*$/, '')
+    const visited = new Set<string>()
+    const queue: Array<{ url: string; depth: number }> = [{ url: this.normalize(start), depth: 0 }]
+    this.gone.clear()
+    this.incomplete = false
+
+    while (queue.length > 0 && visited.size < this.maxPages) {
+      const { url, depth } = queue.shift()!
+      if (visited.has(url)) continue
+      visited.add(url)
+
+      // a page that failed to load may still exist, and the links on it weren't followed either
+      let html: string
+      try {
+        const response = await fetch(url, { headers: { 'User-Agent': USER_AGENT } })
+        if (response.status === 404 || response.status === 410) {
+          this.gone.add(url)
+          continue
+        }
+        if (!response.ok) {
+          this.incomplete = true
+          continue
+        }
+        if (!response.headers.get('content-type')?.includes('text/html')) {
+          this.gone.add(url)
+          continue
+        }
+        html = await response.text()
+      } catch {
+        this.incomplete = true
+        continue
+      }
+
+      const document = new JSDOM(html, { url }).window.document
+
+      if (depth < this.maxDepth) {
+        for (const anchor of Array.from(document.querySelectorAll('a[href]'))) {
+          try {
+            const link = new URL((anchor as HTMLAnchorElement).href)
+            if (link.origin !== start.origin || !link.pathname.startsWith(scope)) continue
+            const normalized = this.normalize(link)
+            if (!visited.has(normalized)) queue.push({ url: normalized, depth: depth + 1 })
+          } catch {
+            // ignore invalid links
+          }
+        }
+      }
+
+      document.querySelectorAll('script, style, nav, footer, noscript').forEach((element) => element.remove())
+      const content = this.turndown.turndown(document.body?.innerHTML || '')
+
+      yield {
+        id: url,
+        title: document.title || url,
+        content,
+        source: url,
+        version: crypto.createHash('sha256').update(content).digest('hex')
+      }
+    }
+  }
+
+  private normalize(url: URL): string {
+    const normalized = new URL(url.href)
+    normalized.hash = ''
+    return normalized.href
+  }
+}
@@ -0,0 +1,17 @@
+import { NotionConnector } from './NotionConnector'
+import { ObsidianConnector } from './ObsidianConnector'
+import { SourceConnector, SourceConnectorConfig } from './types'
+import { WebCrawlConnector } from './WebCrawlConnector'
+
+export * from './types'
+
+export function createSourceConnector(config: SourceConnectorConfig): SourceConnector {
+  switch (config.type) {
+    case 'obsidian':
+      return new ObsidianConnector(config.path)
+    case 'notion':
+      return new NotionConnector(config.token, config.rootPageId)
+    case 'crawl':
+      return new WebCrawlConnector(config.url, config.maxPages, config.maxDepth)
+  }
+}
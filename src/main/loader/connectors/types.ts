@@ -0,0 +1,27 @@
+/**
+ * A document produced by a source connector, independent of where it came from
+ */
+export interface SourceDocument {
+  // stable id within the source, e.g. a vault-relative path, a Notion page id or a URL
+  id: string
+  title: string
+  content: string
+  source: string
+  // changes whenever the document changes, used for incremental re-sync
+  version: string
+}
+
+export interface SourceConnector {
+  // identifies this source within a knowledge base, so that several sources can be synced independently
+  readonly key: string
+  // set when some documents could not be fetched in the last run, documents not seen then aren't necessarily gone
+  readonly incomplete?: boolean
+  // ids confirmed deleted at the source in the last run, e.g. pages answering 404
+  readonly gone?: ReadonlySet<string>
+  fetchDocuments(): AsyncGenerator<SourceDocument>
+}
+
+export type SourceConnectorConfig =
+  | { type: 'obsidian'; path: string }
+  | { type: 'notion'; token: string; rootPageId?: string }
+  | { type: 'crawl'; url: string; maxPages?: number; maxDepth?: number }
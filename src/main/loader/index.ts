@@ -3,6 +3,7 @@ import * as fs from 'node:fs'
 import { JsonLoader, LocalPathLoader, RAGApplication, TextLoader } from '@cherrystudio/embedjs'
 import type { AddLoaderReturn } from '@cherrystudio/embedjs-interfaces'
 import { WebLoader } from '@cherrystudio/embedjs-loader-web'
+import { ChunkLocation, citationService } from '@main/services/CitationService'
 import { LoaderReturn } from '@shared/config/types'
 import { FileType, KnowledgeBaseParams } from '@types'
 import Logger from 'electron-log'
@@ -36,6 +37,20 @@ const FILE_LOADER_MAP: Record<string, string> = {
   // 其他类型默认为文本类型
 }
 
+/**
+ * 在每个分块的 metadata 中记录它在源文件中的位置（行号、字节偏移或页码），引用时无需重新搜索文件
+ */
+function withChunkLocations<T>(loader: T, locate: (chunk: string) => ChunkLocation | undefined): T {
+  const getUnfilteredChunks = (loader as any).getUnfilteredChunks.bind(loader)
+  return Object.assign(loader as any, {
+    async *getUnfilteredChunks() {
+      for await (const chunk of getUnfilteredChunks()) {
+        yield { ...chunk, metadata: { ...chunk.metadata, ...locate(chunk.pageContent) } }
+      }
+    }
+  })
+}
+
 export async function addOdLoader(
   ragApplication: RAGApplication,
   file: FileType,
@@ -72,6 +87,13 @@ export async function addFileLoader(
   const loaderType = FILE_LOADER_MAP[file.ext.toLowerCase()] || 'text'
   let loaderReturn: AddLoaderReturn
 
+  const locate = await citationService.createLocator(file.path).catch((error) => {
+    Logger.warn(`[KnowledgeBase] failed to read ${file.path} for citation locations:`, error)
+    return undefined
+  })
+  const addLoader = (loader: any) =>
+    ragApplication.addLoader(locate ? withChunkLocations(loader, locate) : loader, forceReload)
+
   // JSON类型处理
   let jsonObject = {}
   let jsonParsed = true
@@ -79,13 +101,12 @@ export async function addFileLoader(
   switch (loaderType) {
     case 'common':
       // 内置类型处理
-      loaderReturn = await ragApplication.addLoader(
+      loaderReturn = await addLoader(
         new LocalPathLoader({
           path: file.path,
           chunkSize: base.chunkSize,
           chunkOverlap: base.chunkOverlap
-        }) as any,
-        forceReload
+        })
       )
       break
 
@@ -112,13 +133,12 @@ export async function addFileLoader(
 
     case 'html':
       // HTML类型处理
-      loaderReturn = await ragApplication.addLoader(
+      loaderReturn = await addLoader(
         new WebLoader({
           urlOrContent: fs.readFileSync(file.path, 'utf-8'),
           chunkSize: base.chunkSize,
           chunkOverlap: base.chunkOverlap
-        }) as any,
-        forceReload
+        })
       )
       break
 
@@ -138,13 +158,12 @@ export async function addFileLoader(
     default:
       // 文本类型处理（默认）
       // 如果是其他文本类型且尚未读取文件，则读取文件
-      loaderReturn = await ragApplication.addLoader(
+      loaderReturn = await addLoader(
         new TextLoader({
           text: fs.readFileSync(file.path, 'utf-8'),
           chunkSize: base.chunkSize,
           chunkOverlap: base.chunkOverlap
-        }) as any,
-        forceReload
+        })
       )
       break
   }
@@ -1,6 +1,8 @@
 import { Server } from '@modelcontextprotocol/sdk/server/index.js'
 import Logger from 'electron-log'
 
+import { conversationWorkspaceManager } from '../services/ConversationWorkspaceManager'
+
 import BraveSearchServer from './brave-search'
 import DifyKnowledgeServer from './dify-knowledge'
 import FetchServer from './fetch'
@@ -27,6 +29,15 @@ export function createInMemoryMCPServer(name: string, args: string[] = [], envs:
     case '@cherry/filesystem': {
       return new FileSystemServer(args).server
     }
+    case '@cherry/workspace': {
+      // filesystem server of a conversation, the root comes from the mount rather than from the args
+      const workspace = conversationWorkspaceManager.get(envs.TOPIC_ID)
+      if (!workspace) {
+        throw new Error(`No folder mounted to conversation: ${envs.TOPIC_ID}`)
+      }
+      const track = (file: string) => conversationWorkspaceManager.track(workspace.topicId, file)
+      return new FileSystemServer([workspace.path], track).server
+    }
     case '@cherry/dify-knowledge': {
       const difyKey = envs.DIFY_KEY
       return new DifyKnowledgeServer(difyKey, args).server
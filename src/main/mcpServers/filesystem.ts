@@ -1,5 +1,12 @@
 // port https://github.com/modelcontextprotocol/servers/blob/main/src/filesystem/index.ts
 
+import { isInsideDirectory } from '@main/utils/audit'
+import {
+  getStructuredFormat,
+  readStructured,
+  updateStructured,
+  validateStructured
+} from '@main/utils/structured'
 import { Server } from '@modelcontextprotocol/sdk/server/index.js'
 import { CallToolRequestSchema, ListToolsRequestSchema, ToolSchema } from '@modelcontextprotocol/sdk/types.js'
 import { createTwoFilesPatch } from 'diff'
@@ -32,7 +39,7 @@ async function validatePath(allowedDirectories: string[], requestedPath: string)
   const normalizedRequested = normalizePath(absolute)
 
   // Check if path is within allowed directories
-  const isAllowed = allowedDirectories.some((dir) => normalizedRequested.startsWith(dir))
+  const isAllowed = allowedDirectories.some((dir) => isInsideDirectory(normalizedRequested, dir))
   if (!isAllowed) {
     throw new Error(
       `Access denied - path outside allowed directories: ${absolute} not in ${allowedDirectories.join(', ')}`
@@ -43,7 +50,7 @@ async function validatePath(allowedDirectories: string[], requestedPath: string)
   try {
     const realPath = await fs.realpath(absolute)
     const normalizedReal = normalizePath(realPath)
-    const isRealPathAllowed = allowedDirectories.some((dir) => normalizedReal.startsWith(dir))
+    const isRealPathAllowed = allowedDirectories.some((dir) => isInsideDirectory(normalizedReal, dir))
     if (!isRealPathAllowed) {
       throw new Error('Access denied - symlink target outside allowed directories')
     }
@@ -54,7 +61,7 @@ async function validatePath(allowedDirectories: string[], requestedPath: string)
     try {
       const realParentPath = await fs.realpath(parentDir)
       const normalizedParent = normalizePath(realParentPath)
-      const isParentAllowed = allowedDirectories.some((dir) => normalizedParent.startsWith(dir))
+      const isParentAllowed = allowedDirectories.some((dir) => isInsideDirectory(normalizedParent, dir))
       if (!isParentAllowed) {
         throw new Error('Access denied - parent directory outside allowed directories')
       }
@@ -90,6 +97,25 @@ const EditFileArgsSchema = z.object({
   dryRun: z.boolean().default(false).describe('Preview changes using git-style diff format')
 })
 
+const ReadStructuredArgsSchema = z.object({
+  path: z.string(),
+  pointer: z
+    .string()
+    .default('')
+    .describe('JSON Pointer of the value to read, e.g. /scripts/build, empty for the whole file')
+})
+
+const WriteStructuredArgsSchema = z.object({
+  path: z.string(),
+  pointer: z
+    .string()
+    .describe('JSON Pointer of the value to set, e.g. /compilerOptions/strict, use /items/- to append to an array'),
+  value: z.any().optional().describe('New value, any JSON value'),
+  remove: z.boolean().default(false).describe('Remove the value at the pointer instead of setting it'),
+  schema: z.record(z.any()).optional().describe('JSON Schema the edited document must match'),
+  dryRun: z.boolean().default(false).describe('Preview changes using git-style diff format')
+})
+
 const CreateDirectoryArgsSchema = z.object({
   path: z.string()
 })
@@ -282,15 +308,20 @@ async function applyFileEdits(
   return formattedDiff
 }
 
+// called with the resolved path before a tool writes, edits, moves or creates it
+export type BeforeModifyHook = (filePath: string) => Promise<void>
+
 class FileSystemServer {
   public server: Server
   private allowedDirectories: string[]
-  constructor(allowedDirs: string[]) {
+  private onBeforeModify?: BeforeModifyHook
+  constructor(allowedDirs: string[], onBeforeModify?: BeforeModifyHook) {
     if (!Array.isArray(allowedDirs) || allowedDirs.length === 0) {
       throw new Error('No allowed directories provided, please specify at least one directory in args')
     }
 
     this.allowedDirectories = allowedDirs.map((dir) => normalizePath(path.resolve(expandHome(dir))))
+    this.onBeforeModify = onBeforeModify
 
     // Validate that all directories exist and are accessible
     this.validateDirs().catch((error) => {
@@ -370,6 +401,22 @@ class FileSystemServer {
               'Only works within allowed directories.',
             inputSchema: zodToJsonSchema(EditFileArgsSchema) as ToolInput
           },
+          {
+            name: 'read_structured',
+            description:
+              'Read a value from a JSON or YAML file addressed by a JSON Pointer. ' +
+              'Returns the value as JSON. Only works within allowed directories.',
+            inputSchema: zodToJsonSchema(ReadStructuredArgsSchema) as ToolInput
+          },
+          {
+            name: 'write_structured',
+            description:
+              'Set or remove a single value in a JSON or YAML file addressed by a JSON Pointer, ' +
+              'instead of replacing text. JSON keeps its indentation and YAML keeps its comments and formatting. ' +
+              'The edited document is validated, against the given JSON Schema too, and nothing is written ' +
+              'when it is invalid. Returns a git-style diff. Only works within allowed directories.',
+            inputSchema: zodToJsonSchema(WriteStructuredArgsSchema) as ToolInput
+          },
           {
             name: 'create_directory',
             description:
@@ -485,6 +532,7 @@ class FileSystemServer {
               throw new Error(`Invalid arguments for write_file: ${parsed.error}`)
             }
             const validPath = await validatePath(this.allowedDirectories, parsed.data.path)
+            await this.onBeforeModify?.(validPath)
             await fs.writeFile(validPath, parsed.data.content, 'utf-8')
             return {
               content: [{ type: 'text', text: `Successfully wrote to ${parsed.data.path}` }]
@@ -497,12 +545,55 @@ class FileSystemServer {
               throw new Error(`Invalid arguments for edit_file: ${parsed.error}`)
             }
             const validPath = await validatePath(this.allowedDirectories, parsed.data.path)
+            if (!parsed.data.dryRun) await this.onBeforeModify?.(validPath)
             const result = await applyFileEdits(validPath, parsed.data.edits, parsed.data.dryRun)
             return {
               content: [{ type: 'text', text: result }]
             }
           }
 
+          case 'read_structured': {
+            const parsed = ReadStructuredArgsSchema.safeParse(args)
+            if (!parsed.success) {
+              throw new Error(`Invalid arguments for read_structured: ${parsed.error}`)
+            }
+            const validPath = await validatePath(this.allowedDirectories, parsed.data.path)
+            const content = await fs.readFile(validPath, 'utf-8')
+            const value = readStructured(content, getStructuredFormat(validPath), parsed.data.pointer)
+            return {
+              content: [{ type: 'text', text: JSON.stringify(value, null, 2) }]
+            }
+          }
+
+          case 'write_structured': {
+            const parsed = WriteStructuredArgsSchema.safeParse(args)
+            if (!parsed.success) {
+              throw new Error(`Invalid arguments for write_structured: ${parsed.error}`)
+            }
+            const { pointer, value, remove, schema, dryRun } = parsed.data
+            if (!remove && value === undefined) {
+              throw new Error('A value is required unless remove is true')
+            }
+            const validPath = await validatePath(this.allowedDirectories, parsed.data.path)
+            const content = await fs.readFile(validPath, 'utf-8')
+            const format = getStructuredFormat(validPath)
+            const result = updateStructured(content, format, pointer, remove ? undefined : value)
+
+            const problems = validateStructured(result.data, validPath, schema)
+            if (problems.length) {
+              throw new Error(`The edited document is invalid, nothing was written:\n${problems.join('\n')}`)
+            }
+
+            const diff = createTwoFilesPatch(validPath, validPath, content, result.content, 'original', 'modified')
+            if (!dryRun) {
+              await this.onBeforeModify?.(validPath)
+              await fs.writeFile(validPath, result.content, 'utf-8')
+            }
+            return {
+              content: [{ type: 'text', text: diff }]
+            }
+          }
+
           case 'create_directory': {
             const parsed = CreateDirectoryArgsSchema.safeParse(args)
             if (!parsed.success) {
@@ -582,6 +673,8 @@ class FileSystemServer {
             }
             const validSourcePath = await validatePath(this.allowedDirectories, parsed.data.source)
             const validDestPath = await validatePath(this.allowedDirectories, parsed.data.destination)
+            await this.onBeforeModify?.(validSourcePath)
+            await this.onBeforeModify?.(validDestPath)
             await fs.rename(validSourcePath, validDestPath)
             return {
               content: [
@@ -1,3 +1,4 @@
+import { perfManager } from '@main/services/PerfManager'
 import { getConfigDir } from '@main/utils/file'
 import { Server } from '@modelcontextprotocol/sdk/server/index.js'
 import { CallToolRequestSchema, ErrorCode, ListToolsRequestSchema, McpError } from '@modelcontextprotocol/sdk/types.js'
@@ -111,7 +112,7 @@ class KnowledgeGraphManager {
 
   // Persist the current in-memory graph to disk using a mutex
   private async _persistGraph(): Promise<void> {
-    const release = await this.fileMutex.acquire()
+    const release = await perfManager.acquire('memory-graph-file', this.fileMutex)
     try {
       const graphData: KnowledgeGraph = {
         entities: Array.from(this.entities.values()),
@@ -0,0 +1,75 @@
+import { ExtractChunkData } from '@cherrystudio/embedjs-interfaces'
+import { localInferenceManager } from '@main/services/LocalInferenceManager'
+import { modelFileManager } from '@main/services/ModelFileManager'
+import { workerPool } from '@main/services/WorkerPool'
+import { KnowledgeBaseParams } from '@types'
+
+import BaseReranker from './BaseReranker'
+
+/**
+ * Cross-encoder reranking on this machine with a GGUF reranker model (e.g. bge-reranker-v2-m3),
+ * `rerankModel` is the id of a file registered in the ModelFileManager
+ */
+export default class LocalReranker extends BaseReranker {
+  // a single reranker stays loaded, switching to another rerank model frees the previous one
+  private static loaded: { modelId: string; resources: Promise<{ model: any; context: any }> } | null = null
+
+  constructor(base: KnowledgeBaseParams) {
+    super(base)
+  }
+
+  private async getContext() {
+    const modelId = this.base.rerankModel!
+    if (LocalReranker.loaded?.modelId !== modelId) {
+      const previous = LocalReranker.loaded
+      const resources = LocalReranker.release(previous).then(() => LocalReranker.load(modelId))
+      LocalReranker.loaded = { modelId, resources }
+      resources.catch(() => {
+        if (LocalReranker.loaded?.resources === resources) LocalReranker.loaded = null
+      })
+    }
+    return (await LocalReranker.loaded!.resources).context
+  }
+
+  private static async load(modelId: string) {
+    const modelFile = modelFileManager.getModel(modelId)
+    if (!modelFile || modelFile.status !== 'ready' || modelFile.format !== 'gguf') {
+      throw new Error(`Local rerank model is not available: ${modelId}`)
+    }
+
+    const llama = await localInferenceManager.getLlama()
+    const model = await llama.loadModel({ modelPath: modelFile.path })
+    try {
+      const context = await model.createRankingContext({ threads: workerPool.getThreadCount() })
+      modelFileManager.markUsed(modelId)
+      return { model, context }
+    } catch (error) {
+      await model.dispose()
+      throw error
+    }
+  }
+
+  private static async release(loaded: typeof LocalReranker.loaded) {
+    const resources = await loaded?.resources.catch(() => null)
+    await resources?.context.dispose()
+    await resources?.model.dispose()
+  }
+
+  /**
+   * Free the loaded reranker, called when the app quits
+   */
+  public static async dispose() {
+    const loaded = LocalReranker.loaded
+    LocalReranker.loaded = null
+    await LocalReranker.release(loaded)
+  }
+
+  public rerank = async (query: string, searchResults: ExtractChunkData[]): Promise<ExtractChunkData[]> => {
+    const context = await this.getContext()
+    const scores: number[] = await context.rankAll(query, searchResults.map((doc) => doc.pageContent))
+
+    const rerankResults = scores.map((relevance_score, index) => ({ index, relevance_score }))
+    const results = this.getRerankResult(searchResults, rerankResults)
+    return this.base.documentCount ? results.slice(0, this.base.documentCount) : results
+  }
+}
@@ -1,12 +1,14 @@
 import type { ExtractChunkData } from '@cherrystudio/embedjs-interfaces'
 import { KnowledgeBaseParams } from '@types'
 
+import BaseReranker from './BaseReranker'
 import GeneralReranker from './GeneralReranker'
+import LocalReranker from './LocalReranker'
 
 export default class Reranker {
-  private sdk: GeneralReranker
+  private sdk: BaseReranker
   constructor(base: KnowledgeBaseParams) {
-    this.sdk = new GeneralReranker(base)
+    this.sdk = base.rerankModelProvider === 'local' ? new LocalReranker(base) : new GeneralReranker(base)
   }
   public async rerank(query: string, searchResults: ExtractChunkData[]): Promise<ExtractChunkData[]> {
     return this.sdk.rerank(query, searchResults)
@@ -0,0 +1,75 @@
+import { IpcChannel } from '@shared/IpcChannel'
+import { app, BrowserWindow, nativeTheme, systemPreferences } from 'electron'
+
+export type AnnouncePoliteness = 'polite' | 'assertive'
+
+export interface AccessibilityState {
+  screenReader: boolean
+  reducedMotion: boolean
+  highContrast: boolean
+  invertedColors: boolean
+  reducedTransparency: boolean
+}
+
+class AccessibilityManager {
+  private state: AccessibilityState
+
+  constructor() {
+    this.state = this.readState()
+  }
+
+  public init() {
+    app.on('accessibility-support-changed', () => this.refresh())
+    nativeTheme.on('updated', () => this.refresh())
+  }
+
+  public getAccessibilityState(): AccessibilityState {
+    return this.state
+  }
+
+  /**
+   * Announce text to assistive technology without moving focus.
+   * The AnnouncementHandler of the focused window writes it into an ARIA live region,
+   * which Chromium forwards to the platform accessibility API (NSAccessibility, UIA, AT-SPI).
+   */
+  public announce(text: string, politeness: AnnouncePoliteness = 'polite') {
+    if (!text) return
+
+    const window = BrowserWindow.getFocusedWindow() ?? BrowserWindow.getAllWindows()[0]
+    if (!window || window.isDestroyed()) return
+
+    window.webContents.send(IpcChannel.Accessibility_Announce, { text, politeness })
+  }
+
+  private refresh() {
+    const next = this.readState()
+    const changed = (Object.keys(next) as (keyof AccessibilityState)[]).some((key) => next[key] !== this.state[key])
+    if (!changed) return
+
+    this.state = next
+    BrowserWindow.getAllWindows().forEach((win) => {
+      if (!win.isDestroyed()) {
+        win.webContents.send(IpcChannel.Accessibility_StateChanged, next)
+      }
+    })
+  }
+
+  private readState(): AccessibilityState {
+    let reducedMotion = false
+    try {
+      reducedMotion = systemPreferences.getAnimationSettings().prefersReducedMotion
+    } catch {
+      // not supported on this platform
+    }
+
+    return {
+      screenReader: app.isAccessibilitySupportEnabled(),
+      reducedMotion,
+      highContrast: nativeTheme.shouldUseHighContrastColors,
+      invertedColors: nativeTheme.shouldUseInvertedColorScheme,
+      reducedTransparency: nativeTheme.prefersReducedTransparency
+    }
+  }
+}
+
+export const accessibilityManager = new AccessibilityManager()
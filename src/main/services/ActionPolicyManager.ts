@@ -0,0 +1,144 @@
+import { describeToolCall, findRule, getDomain } from '@main/utils/actionPolicy'
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import { AgentAction, ConfirmationRule, PendingApproval } from '@types'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import { configManager } from './ConfigManager'
+import { pushChannel } from './PushChannel'
+import { windowService } from './WindowService'
+
+// an approval that is not answered within this time is denied
+const APPROVAL_TIMEOUT = 5 * 60 * 1000
+
+const KINDS = ['file_write', 'network']
+const CONDITIONS = ['always', 'outside_workspace', 'new_domain']
+
+// shipped disabled, turning them on is up to the user
+const DEFAULT_RULES: ConfirmationRule[] = [
+  {
+    id: 'file-write-outside-workspace',
+    name: 'File writes outside the workspace',
+    enabled: false,
+    kind: 'file_write',
+    condition: 'outside_workspace'
+  },
+  {
+    id: 'network-new-domain',
+    name: 'Network calls to new domains',
+    enabled: false,
+    kind: 'network',
+    condition: 'new_domain'
+  }
+]
+
+interface Pending {
+  approval: PendingApproval
+  resolve: (approved: boolean) => void
+  timer: NodeJS.Timeout
+}
+
+/**
+ * Confirmation policies for tool calls of agents.
+ *
+ * Before an MCP tool runs, its call is described by its effects (files written, urls requested) and checked against
+ * the user's rules in order. A matching call waits in the approval queue until the user approves or denies it, the
+ * queue is sent to the main window and published on the push channel as `approval`. Approving a network call
+ * remembers its domains, so they are no longer new.
+ */
+class ActionPolicyManager {
+  private pending = new Map<string, Pending>()
+
+  public getRules(): ConfirmationRule[] {
+    return configManager.getConfirmationRules(DEFAULT_RULES)
+  }
+
+  public setRules(rules: ConfirmationRule[]) {
+    const ids = new Set<string>()
+    for (const rule of rules) {
+      if (!rule.id || ids.has(rule.id)) {
+        throw new ManagerError('ActionPolicyManager', 'invalid_argument', `Rule ids must be unique: ${rule.id}`)
+      }
+      if (!KINDS.includes(rule.kind) || !CONDITIONS.includes(rule.condition)) {
+        throw new ManagerError('ActionPolicyManager', 'invalid_argument', `Invalid rule: ${rule.name}`)
+      }
+      ids.add(rule.id)
+    }
+    configManager.setConfirmationRules(rules)
+  }
+
+  public listPending(): PendingApproval[] {
+    return [...this.pending.values()].map((item) => item.approval)
+  }
+
+  /**
+   * Resolves when the tool call may run, throws permission_denied when the user denied it
+   */
+  public async authorizeToolCall(server: string, tool: string, args: unknown) {
+    const action = describeToolCall(server, tool, args)
+    if (action.kinds.length === 0) return
+
+    const rule = findRule(this.getRules(), action, {
+      workspaces: configManager.getConversationWorkspaces().map((workspace) => workspace.path),
+      knownDomains: configManager.getApprovedDomains()
+    })
+    if (!rule) return
+
+    if (!(await this.requestApproval(action, rule))) {
+      const message = `${tool} of ${server} was denied: ${rule.name}`
+      throw new ManagerError('ActionPolicyManager', 'permission_denied', message)
+    }
+  }
+
+  public approve(id: string) {
+    const pending = this.take(id)
+    const domains = pending.approval.action.urls.map(getDomain).filter((domain): domain is string => !!domain)
+    if (domains.length > 0) {
+      configManager.setApprovedDomains([...new Set([...configManager.getApprovedDomains(), ...domains])])
+    }
+    this.resolve(pending, true)
+  }
+
+  public deny(id: string) {
+    this.resolve(this.take(id), false)
+  }
+
+  private requestApproval(action: AgentAction, rule: ConfirmationRule): Promise<boolean> {
+    const approval: PendingApproval = {
+      id: uuidv4(),
+      ruleId: rule.id,
+      ruleName: rule.name,
+      action,
+      requestedAt: Date.now()
+    }
+    Logger.info(`[ActionPolicyManager] ${action.tool} of ${action.server} waits for approval (${rule.name})`)
+
+    return new Promise((resolve) => {
+      const timer = setTimeout(() => this.pending.has(approval.id) && this.deny(approval.id), APPROVAL_TIMEOUT)
+      this.pending.set(approval.id, { approval, resolve, timer })
+      windowService.getMainWindow()?.webContents.send(IpcChannel.ActionPolicy_ApprovalRequested, approval)
+      pushChannel.publish('approval', 'approval.requested', approval)
+    })
+  }
+
+  private take(id: string): Pending {
+    const pending = this.pending.get(id)
+    if (!pending) {
+      throw new ManagerError('ActionPolicyManager', 'not_found', `No pending approval: ${id}`)
+    }
+    this.pending.delete(id)
+    clearTimeout(pending.timer)
+    return pending
+  }
+
+  private resolve(pending: Pending, approved: boolean) {
+    const { id, action } = pending.approval
+    Logger.info(`[ActionPolicyManager] ${action.tool} of ${action.server} ${approved ? 'approved' : 'denied'}`)
+    windowService.getMainWindow()?.webContents.send(IpcChannel.ActionPolicy_ApprovalResolved, { id, approved })
+    pushChannel.publish('approval', approved ? 'approval.approved' : 'approval.denied', { id })
+    pending.resolve(approved)
+  }
+}
+
+export const actionPolicyManager = new ActionPolicyManager()
@@ -0,0 +1,547 @@
+import crypto from 'node:crypto'
+import fs from 'node:fs'
+import http from 'node:http'
+import { Duplex } from 'node:stream'
+
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import { ApiServerStatus } from '@types'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import { commandRegistry } from './CommandRegistry'
+import { configManager } from './ConfigManager'
+import { conversationShareManager, SHARE_PATH_PREFIX } from './ConversationShareManager'
+import { pushChannel } from './PushChannel'
+import { WEBHOOK_PATH_PREFIX, webhookManager } from './WebhookManager'
+import { windowService } from './WindowService'
+
+export const API_SERVER_HOST = '127.0.0.1'
+
+const MAX_BODY_SIZE = 10 * 1024 * 1024
+const PUSH_CHANNEL_PATH = '/v1/events'
+// the renderer answers within this time or the request fails, streaming responses reset it on every chunk
+const RENDERER_TIMEOUT = 5 * 60 * 1000
+// how long a request waits for a starting renderer to register its handlers
+const RENDERER_READY_TIMEOUT = 30 * 1000
+
+/**
+ * Messages sent back by the renderer for a forwarded request
+ */
+export type ApiServerResponseMessage =
+  | { type: 'chunk'; data: any }
+  | { type: 'result'; data: any }
+  | { type: 'error'; status?: number; code?: string; message: string }
+
+interface PendingRequest {
+  onChunk?: (data: any) => void
+  resolve: (data: any) => void
+  reject: (error: ApiServerError) => void
+  timer: NodeJS.Timeout
+}
+
+export class ApiServerError extends Error {
+  constructor(
+    public status: number,
+    public code: string,
+    message: string
+  ) {
+    super(message)
+  }
+}
+
+/**
+ * Decode a path segment, malformed percent-encoding is a client error
+ */
+function decodePathSegment(segment: string): string {
+  try {
+    return decodeURIComponent(segment)
+  } catch {
+    throw new ApiServerError(400, 'invalid_request', `Malformed path segment: ${segment}`)
+  }
+}
+
+type RouteHandler = (
+  req: http.IncomingMessage,
+  res: http.ServerResponse,
+  body: any,
+  params: Record<string, string>
+) => Promise<void>
+
+interface Route {
+  method: string
+  pattern: RegExp
+  keys: string[]
+  handler: RouteHandler
+}
+
+/**
+ * Local HTTP server exposing the app behind OpenAI-compatible endpoints.
+ * Only binds to loopback and requires the bearer token shown in settings.
+ * Completions are forwarded to the main window so they go through the same
+ * provider routing, middlewares and guardrails as regular chats.
+ */
+class ApiServer {
+  private server: http.Server | null = null
+  private error: string | undefined
+  private pending: Map<string, PendingRequest> = new Map()
+  private routes: Route[] = []
+  private rendererReady = false
+  private readyListeners: Set<() => void> = new Set()
+
+  constructor() {
+    this.addRoute('GET', '/v1/models', (_req, res) => this.handleModels(res))
+    this.addRoute('POST', '/v1/chat/completions', (req, res, body) => this.handleChatCompletions(req, res, body))
+    this.addRoute('GET', '/v1/conversations/:id/export', (req, res, _body, params) =>
+      this.handleConversationExport(req, res, params.id)
+    )
+    this.addRoute('POST', '/v1/conversations/:id/context', (_req, res, body, params) =>
+      this.handleConversationContext(res, params.id, body)
+    )
+    this.addRoute('GET', '/v1/commands', (req, res) => this.handleListCommands(req, res))
+    this.addRoute('POST', '/v1/commands/:id/invoke', (_req, res, body, params) =>
+      this.handleInvokeCommand(res, params.id, body)
+    )
+  }
+
+  public init() {
+    if (configManager.getApiServerEnabled()) {
+      this.start().catch(() => {})
+    }
+  }
+
+  public getStatus(): ApiServerStatus {
+    const port = configManager.getApiServerPort()
+    return {
+      enabled: configManager.getApiServerEnabled(),
+      running: !!this.server?.listening,
+      port,
+      url: `http://${API_SERVER_HOST}:${port}/v1`,
+      token: this.getToken(),
+      error: this.error
+    }
+  }
+
+  public async setEnabled(enabled: boolean): Promise<ApiServerStatus> {
+    configManager.setApiServerEnabled(enabled)
+    if (enabled) {
+      await this.start().catch(() => {})
+    } else {
+      await this.stop()
+    }
+    return this.getStatus()
+  }
+
+  public async setPort(port: number): Promise<ApiServerStatus> {
+    if (!Number.isInteger(port) || port < 1024 || port > 65535) {
+      throw new Error(`Invalid port: ${port}`)
+    }
+    configManager.setApiServerPort(port)
+    if (this.server) {
+      await this.stop()
+      await this.start().catch(() => {})
+    }
+    return this.getStatus()
+  }
+
+  public regenerateToken(): ApiServerStatus {
+    configManager.setApiServerToken(this.createToken())
+    return this.getStatus()
+  }
+
+  public isAuthorized(authorization: string | undefined): boolean {
+    const match = /^Bearer\s+(.+)$/i.exec(authorization ?? '')
+    if (!match) return false
+
+    const expected = Buffer.from(this.getToken())
+    const actual = Buffer.from(match[1].trim())
+    return expected.length === actual.length && crypto.timingSafeEqual(expected, actual)
+  }
+
+  public async start() {
+    if (this.server) return
+
+    const port = configManager.getApiServerPort()
+    const server = http.createServer((req, res) => this.handleRequest(req, res))
+    server.on('upgrade', (req, socket, head) => this.handleUpgrade(req, socket, head))
+
+    try {
+      await new Promise<void>((resolve, reject) => {
+        server.once('error', reject)
+        server.listen(port, API_SERVER_HOST, () => {
+          server.off('error', reject)
+          resolve()
+        })
+      })
+    } catch (error: any) {
+      this.error = error.message
+      Logger.error(`[ApiServer] Failed to listen on port ${port}:`, error)
+      throw error
+    }
+
+    server.on('error', (error) => Logger.error('[ApiServer] Server error:', error))
+    this.server = server
+    this.error = undefined
+    Logger.info(`[ApiServer] Listening on http://${API_SERVER_HOST}:${port}`)
+  }
+
+  public async stop() {
+    const server = this.server
+    if (!server) return
+
+    this.server = null
+    pushChannel.closeAll()
+    for (const requestId of [...this.pending.keys()]) {
+      this.settle(requestId, { type: 'error', status: 503, code: 'server_stopped', message: 'Server stopped' })
+    }
+    const closed = new Promise<void>((resolve) => server.close(() => resolve()))
+    server.closeAllConnections()
+    await closed
+    Logger.info('[ApiServer] Stopped')
+  }
+
+  /**
+   * Called by the renderer once its request handlers are registered
+   */
+  public markRendererReady() {
+    this.rendererReady = true
+    this.readyListeners.forEach((listener) => listener())
+    this.readyListeners.clear()
+  }
+
+  /**
+   * Requests wait for the renderer again, used when the main window is recreated
+   */
+  public resetRendererReady() {
+    this.rendererReady = false
+  }
+
+  /**
+   * Resolves once the renderer can answer forwarded requests, used when the app is still starting
+   */
+  public waitForRenderer(timeout = RENDERER_READY_TIMEOUT): Promise<void> {
+    if (this.rendererReady) return Promise.resolve()
+
+    return new Promise((resolve, reject) => {
+      const listener = () => {
+        clearTimeout(timer)
+        resolve()
+      }
+      const timer = setTimeout(() => {
+        this.readyListeners.delete(listener)
+        reject(new ApiServerError(503, 'app_not_ready', 'The app is still starting, try again later'))
+      }, timeout)
+      this.readyListeners.add(listener)
+    })
+  }
+
+  /**
+   * Called by the renderer with the result of a forwarded request
+   */
+  public handleRendererResponse(requestId: string, message: ApiServerResponseMessage) {
+    const pending = this.pending.get(requestId)
+    if (!pending) return
+
+    if (message.type === 'chunk') {
+      pending.timer.refresh()
+      pending.onChunk?.(message.data)
+      return
+    }
+    this.settle(requestId, message)
+  }
+
+  private settle(requestId: string, message: Exclude<ApiServerResponseMessage, { type: 'chunk' }>) {
+    const pending = this.pending.get(requestId)
+    if (!pending) return
+
+    clearTimeout(pending.timer)
+    this.pending.delete(requestId)
+    if (message.type === 'result') {
+      pending.resolve(message.data)
+    } else {
+      pending.reject(new ApiServerError(message.status ?? 500, message.code ?? 'internal_error', message.message))
+    }
+  }
+
+  /**
+   * Forward a request to the main window renderer, which owns providers, assistants and knowledge bases
+   */
+  public async callRenderer<T = any>(
+    method: string,
+    params: any,
+    options: { onChunk?: (data: any) => void; signal?: AbortSignal } = {}
+  ): Promise<T> {
+    await this.waitForRenderer()
+
+    const mainWindow = windowService.getMainWindow()
+    if (!mainWindow || mainWindow.isDestroyed()) {
+      throw new ApiServerError(503, 'app_not_ready', 'Main window is not available')
+    }
+
+    const requestId = uuidv4()
+    return new Promise<T>((resolve, reject) => {
+      const timer = setTimeout(
+        () => this.settle(requestId, { type: 'error', status: 504, code: 'timeout', message: 'Request timed out' }),
+        RENDERER_TIMEOUT
+      )
+      this.pending.set(requestId, { onChunk: options.onChunk, resolve, reject, timer })
+
+      options.signal?.addEventListener('abort', () => {
+        if (!this.pending.has(requestId)) return
+        mainWindow.webContents.send(IpcChannel.ApiServer_Abort, requestId)
+        this.settle(requestId, { type: 'error', status: 499, code: 'aborted', message: 'Request aborted' })
+      })
+
+      mainWindow.webContents.send(IpcChannel.ApiServer_Request, { requestId, method, params })
+    })
+  }
+
+  /**
+   * Register a route, path segments starting with `:` are passed to the handler as params
+   */
+  private addRoute(method: string, path: string, handler: RouteHandler) {
+    const keys: string[] = []
+    const source = path
+      .split('/')
+      .map((segment) => {
+        if (!segment.startsWith(':')) return segment.replace(/[.*+?^${}()|[\]\\]/g, '\\$&')
+        keys.push(segment.slice(1))
+        return '([^/]+)'
+      })
+      .join('/')
+    this.routes.push({ method, pattern: new RegExp(`^${source}$`), keys, handler })
+  }
+
+  private matchRoute(method: string, pathname: string) {
+    for (const route of this.routes) {
+      const match = route.method === method ? route.pattern.exec(pathname) : null
+      if (match) {
+        const params = Object.fromEntries(route.keys.map((key, index) => [key, decodePathSegment(match[index + 1])]))
+        return { handler: route.handler, params }
+      }
+    }
+    return undefined
+  }
+
+  private getToken(): string {
+    let token = configManager.getApiServerToken()
+    if (!token) {
+      token = this.createToken()
+      configManager.setApiServerToken(token)
+    }
+    return token
+  }
+
+  private createToken(): string {
+    return `cs-${crypto.randomBytes(24).toString('hex')}`
+  }
+
+  private async handleRequest(req: http.IncomingMessage, res: http.ServerResponse) {
+    const url = new URL(req.url ?? '/', `http://${API_SERVER_HOST}`)
+
+    try {
+      // webhooks authenticate with the HMAC signature of their body instead of the bearer token
+      if (req.method === 'POST' && url.pathname.startsWith(WEBHOOK_PATH_PREFIX)) {
+        await this.handleWebhook(req, res, decodePathSegment(url.pathname.slice(WEBHOOK_PATH_PREFIX.length)))
+        return
+      }
+      // shared conversations are protected by the expiring token in their link
+      if (req.method === 'GET' && url.pathname.startsWith(SHARE_PATH_PREFIX)) {
+        await this.handleSharedConversation(res, url.pathname.slice(SHARE_PATH_PREFIX.length))
+        return
+      }
+      if (!this.isAuthorized(req.headers.authorization)) {
+        throw new ApiServerError(401, 'invalid_api_key', 'Invalid or missing bearer token')
+      }
+      const route = this.matchRoute(req.method ?? 'GET', url.pathname.replace(/\/$/, ''))
+      if (!route) {
+        throw new ApiServerError(404, 'not_found', `Unknown endpoint: ${req.method} ${url.pathname}`)
+      }
+
+      const body = req.method === 'POST' ? await this.readBody(req) : undefined
+      await route.handler(req, res, body, route.params)
+    } catch (error: any) {
+      const status = error instanceof ApiServerError ? error.status : 500
+      const code = error instanceof ApiServerError ? error.code : 'internal_error'
+      if (status >= 500) {
+        Logger.error(`[ApiServer] ${req.method} ${url.pathname} failed:`, error)
+      }
+
+      if (res.headersSent) {
+        // the stream has already started, report the error in-band
+        this.writeEvent(res, { error: { message: error.message, type: code, code } })
+        res.end()
+      } else {
+        this.sendJson(res, status, { error: { message: error.message, type: code, code } })
+      }
+    }
+  }
+
+  /**
+   * WebSocket clients authenticate with the same token, browsers cannot set headers
+   * on WebSocket requests so it may also be passed as the `token` query parameter
+   */
+  private handleUpgrade(req: http.IncomingMessage, socket: Duplex, head: Buffer) {
+    const url = new URL(req.url ?? '/', `http://${API_SERVER_HOST}`)
+    const queryToken = url.searchParams.get('token')
+    const authorization = req.headers.authorization ?? (queryToken ? `Bearer ${queryToken}` : undefined)
+
+    if (url.pathname !== PUSH_CHANNEL_PATH) {
+      socket.end('HTTP/1.1 404 Not Found\r\n\r\n')
+      return
+    }
+    if (!this.isAuthorized(authorization)) {
+      socket.end('HTTP/1.1 401 Unauthorized\r\n\r\n')
+      return
+    }
+    pushChannel.handleUpgrade(req, socket, head)
+  }
+
+  private readRawBody(req: http.IncomingMessage): Promise<Buffer> {
+    return new Promise((resolve, reject) => {
+      const chunks: Buffer[] = []
+      let size = 0
+
+      req.on('data', (chunk: Buffer) => {
+        size += chunk.length
+        if (size > MAX_BODY_SIZE) {
+          reject(new ApiServerError(413, 'payload_too_large', 'Request body is too large'))
+          req.destroy()
+          return
+        }
+        chunks.push(chunk)
+      })
+      req.on('end', () => resolve(Buffer.concat(chunks)))
+      req.on('error', reject)
+    })
+  }
+
+  private async readBody(req: http.IncomingMessage): Promise<any> {
+    const body = await this.readRawBody(req)
+    try {
+      return JSON.parse(body.toString('utf8') || '{}')
+    } catch {
+      throw new ApiServerError(400, 'invalid_json', 'Request body is not valid JSON')
+    }
+  }
+
+  private sendJson(res: http.ServerResponse, status: number, data: any) {
+    res.writeHead(status, { 'Content-Type': 'application/json' })
+    res.end(JSON.stringify(data))
+  }
+
+  private writeEvent(res: http.ServerResponse, data: any) {
+    res.write(`data: ${JSON.stringify(data)}\n\n`)
+  }
+
+  private async handleModels(res: http.ServerResponse) {
+    const models = await this.callRenderer('models', {})
+    this.sendJson(res, 200, { object: 'list', data: models })
+  }
+
+  private async handleChatCompletions(req: http.IncomingMessage, res: http.ServerResponse, body: any) {
+    if (!body?.model || !Array.isArray(body.messages) || body.messages.length === 0) {
+      throw new ApiServerError(400, 'invalid_request', '`model` and `messages` are required')
+    }
+
+    const controller = new AbortController()
+    res.on('close', () => {
+      if (!res.writableFinished) controller.abort()
+    })
+
+    if (!body.stream) {
+      const completion = await this.callRenderer('chat.completions', body, { signal: controller.signal })
+      this.sendJson(res, 200, completion)
+      return
+    }
+
+    res.writeHead(200, {
+      'Content-Type': 'text/event-stream',
+      'Cache-Control': 'no-cache',
+      Connection: 'keep-alive'
+    })
+    await this.callRenderer('chat.completions', body, {
+      signal: controller.signal,
+      onChunk: (chunk) => this.writeEvent(res, chunk)
+    })
+    if (!req.destroyed) {
+      res.end('data: [DONE]\n\n')
+    }
+  }
+
+  private async handleWebhook(req: http.IncomingMessage, res: http.ServerResponse, id: string) {
+    const body = await this.readRawBody(req)
+    const { status, body: data } = webhookManager.deliver(id, req.headers, body, async (assistantId, prompt) => {
+      const completion = await this.callRenderer('chat.completions', {
+        model: `assistant:${assistantId}`,
+        messages: [{ role: 'user', content: prompt }]
+      })
+      return completion?.choices?.[0]?.message?.content ?? ''
+    })
+    this.sendJson(res, status, data)
+  }
+
+  private async handleSharedConversation(res: http.ServerResponse, token: string) {
+    const filePath = conversationShareManager.resolve(token)
+    if (!filePath) {
+      throw new ApiServerError(404, 'not_found', 'This link has expired')
+    }
+
+    const html = await fs.promises.readFile(filePath)
+    res.writeHead(200, { 'Content-Type': 'text/html; charset=utf-8', 'Cache-Control': 'no-store' })
+    res.end(html)
+  }
+
+  private async handleConversationExport(req: http.IncomingMessage, res: http.ServerResponse, id: string) {
+    const url = new URL(req.url ?? '/', `http://${API_SERVER_HOST}`)
+    const format = url.searchParams.get('format') ?? 'markdown'
+    if (!['markdown', 'text', 'json'].includes(format)) {
+      throw new ApiServerError(400, 'invalid_request', '`format` must be one of markdown, text or json')
+    }
+
+    const content = await this.callRenderer<string>('conversation.export', { id, format })
+    res.writeHead(200, {
+      'Content-Type': format === 'json' ? 'application/json' : format === 'markdown' ? 'text/markdown' : 'text/plain'
+    })
+    res.end(content)
+  }
+
+  private async handleConversationContext(res: http.ServerResponse, id: string, body: any) {
+    if (typeof body?.message !== 'string' || !body.message.trim()) {
+      throw new ApiServerError(400, 'invalid_request', '`message` is required')
+    }
+    if (body.max_tokens !== undefined && !(Number.isInteger(body.max_tokens) && body.max_tokens > 0)) {
+      throw new ApiServerError(400, 'invalid_request', '`max_tokens` must be a positive integer')
+    }
+
+    const context = await this.callRenderer('conversation.context', {
+      id,
+      message: body.message,
+      maxTokens: body.max_tokens
+    })
+    this.sendJson(res, 200, context)
+  }
+
+  private async handleListCommands(req: http.IncomingMessage, res: http.ServerResponse) {
+    const url = new URL(req.url ?? '/', `http://${API_SERVER_HOST}`)
+    const commands = commandRegistry.list(url.searchParams.get('filter') ?? undefined)
+    this.sendJson(res, 200, { object: 'list', data: commands })
+  }
+
+  private async handleInvokeCommand(res: http.ServerResponse, id: string, body: any) {
+    try {
+      const result = await commandRegistry.invoke(id, body?.args)
+      this.sendJson(res, 200, { id, result })
+    } catch (error) {
+      if (error instanceof ManagerError && error.code === 'not_found') {
+        throw new ApiServerError(404, 'not_found', error.message)
+      }
+      if (error instanceof ManagerError && error.code === 'invalid_argument') {
+        throw new ApiServerError(400, 'invalid_request', error.message)
+      }
+      throw error
+    }
+  }
+}
+
+export const apiServer = new ApiServer()
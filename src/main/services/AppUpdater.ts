@@ -2,8 +2,9 @@ import { isWin } from '@main/constant'
 import { locales } from '@main/utils/locales'
 import { FeedUrl } from '@shared/config/constant'
 import { IpcChannel } from '@shared/IpcChannel'
+import { InstallationRepairResult } from '@types'
 import { UpdateInfo } from 'builder-util-runtime'
-import { app, BrowserWindow, dialog } from 'electron'
+import { app, BrowserWindow, dialog, shell } from 'electron'
 import logger from 'electron-log'
 import { AppUpdater as _AppUpdater, autoUpdater, NsisUpdater } from 'electron-updater'
 import path from 'path'
@@ -11,6 +12,8 @@ import path from 'path'
 import icon from '../../../build/icon.png?asset'
 import { configManager } from './ConfigManager'
 
+const DOWNLOAD_PAGE = 'https://github.com/CherryHQ/cherry-studio/releases/latest'
+
 export default class AppUpdater {
   autoUpdater: _AppUpdater = autoUpdater
   private releaseInfo: UpdateInfo | undefined
@@ -133,6 +136,29 @@ export default class AppUpdater {
     }
   }
 
+  /**
+   * Replace a damaged installation with a fresh copy, by downloading the latest release when it is newer.
+   * The updater does not reinstall the running version, so the download page is opened instead.
+   */
+  public async repairInstallation(): Promise<InstallationRepairResult> {
+    if (!(isWin && 'PORTABLE_EXECUTABLE_DIR' in process.env)) {
+      try {
+        const update = await this.autoUpdater.checkForUpdates()
+        if (update?.isUpdateAvailable) {
+          if (!this.autoUpdater.autoDownload) {
+            this.autoUpdater.downloadUpdate()
+          }
+          return 'updating'
+        }
+      } catch (error) {
+        logger.error('Failed to check for update to repair installation:', error)
+      }
+    }
+
+    await shell.openExternal(DOWNLOAD_PAGE)
+    return 'manual'
+  }
+
   public async showUpdateDialog(mainWindow: BrowserWindow) {
     if (!this.releaseInfo) {
       return
@@ -0,0 +1,127 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { AUDIT_RULES, AuditCategory, isInsideDirectory, summarizeArgs } from '@main/utils/audit'
+import { app, WebContents } from 'electron'
+import Logger from 'electron-log'
+
+export interface AuditEntry {
+  time: number
+  category: AuditCategory
+  channel: string
+  caller: {
+    type: string
+    id: number
+    url: string
+  }
+  args: string
+  // false when the capability check rejected the call
+  allowed: boolean
+}
+
+export interface AuditFilter {
+  from?: number
+  to?: number
+  category?: AuditCategory
+  channel?: string
+  limit?: number
+}
+
+const DEFAULT_LIMIT = 500
+
+/**
+ * Append-only log of privileged IPC calls.
+ *
+ * File writes outside the app data directory, process spawns, credential reads and setting changes are
+ * recorded with the calling window or webview, a summary of the arguments and the time. Entries are
+ * appended as JSON lines to `Data/Audit/audit.log` and never rewritten.
+ */
+class AuditLog {
+  private logFile = path.join(getDataPath(), 'Audit', 'audit.log')
+  // appends run one at a time so entries keep their order
+  private queue: Promise<void> = Promise.resolve()
+
+  public record(contents: WebContents, channel: string, args: unknown[], allowed: boolean) {
+    const rule = AUDIT_RULES[channel]
+    if (!rule) return
+
+    if (rule.pathArg !== undefined) {
+      const target = args[rule.pathArg]
+      if (typeof target === 'string' && isInsideDirectory(target, app.getPath('userData'))) return
+    }
+
+    const entry: AuditEntry = {
+      time: Date.now(),
+      category: rule.category,
+      channel,
+      caller: { type: contents.getType(), id: contents.id, url: this.getCallerUrl(contents) },
+      args: summarizeArgs(args, rule.redact),
+      allowed
+    }
+
+    this.queue = this.queue
+      .then(async () => {
+        await fs.promises.mkdir(path.dirname(this.logFile), { recursive: true })
+        await fs.promises.appendFile(this.logFile, JSON.stringify(entry) + '\n', 'utf-8')
+      })
+      .catch((error) => Logger.error('[AuditLog] Failed to append entry:', error))
+  }
+
+  /**
+   * Matching entries, newest first
+   */
+  public async getEntries(filter: AuditFilter = {}): Promise<AuditEntry[]> {
+    await this.queue
+
+    let content: string
+    try {
+      content = await fs.promises.readFile(this.logFile, 'utf-8')
+    } catch {
+      return []
+    }
+
+    const entries: AuditEntry[] = []
+    for (const line of content.split('\n').reverse()) {
+      if (!line.trim()) continue
+      try {
+        const entry: AuditEntry = JSON.parse(line)
+        if (this.matches(entry, filter)) entries.push(entry)
+      } catch {
+        Logger.warn('[AuditLog] Skipped malformed entry')
+      }
+      if (entries.length >= (filter.limit ?? DEFAULT_LIMIT)) break
+    }
+    return entries
+  }
+
+  /**
+   * Export matching entries as JSON lines, written to `filePath` when given
+   */
+  public async export(filter: AuditFilter = {}, filePath?: string): Promise<string> {
+    const entries = await this.getEntries({ ...filter, limit: filter.limit ?? Infinity })
+    const data = entries
+      .reverse()
+      .map((entry) => JSON.stringify(entry))
+      .join('\n')
+    if (filePath) {
+      await fs.promises.writeFile(filePath, data, 'utf-8')
+    }
+    return data
+  }
+
+  private matches(entry: AuditEntry, filter: AuditFilter): boolean {
+    if (filter.from !== undefined && entry.time < filter.from) return false
+    if (filter.to !== undefined && entry.time > filter.to) return false
+    if (filter.category && entry.category !== filter.category) return false
+    if (filter.channel && entry.channel !== filter.channel) return false
+    return true
+  }
+
+  // query strings may carry tokens, only keep the page location
+  private getCallerUrl(contents: WebContents): string {
+    return contents.getURL().split(/[?#]/)[0]
+  }
+}
+
+export const auditLog = new AuditLog()
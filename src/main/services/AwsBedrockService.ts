@@ -0,0 +1,81 @@
+import { safeStorage } from 'electron'
+import Logger from 'electron-log'
+
+import { AwsCredentials, SignableRequest, signAwsRequest } from '../utils/sigv4'
+import { configManager } from './ConfigManager'
+
+const BEDROCK_SERVICE = 'bedrock'
+
+/**
+ * 保存 AWS 凭证并为 Bedrock 请求签名
+ * 凭证加密后只保存在主进程中，渲染进程拿到的只有签名后的请求头
+ */
+class AwsBedrockService {
+  private static instance: AwsBedrockService
+  private credentials?: AwsCredentials
+
+  static getInstance(): AwsBedrockService {
+    if (!AwsBedrockService.instance) {
+      AwsBedrockService.instance = new AwsBedrockService()
+    }
+    return AwsBedrockService.instance
+  }
+
+  private loadCredentials(): AwsCredentials | undefined {
+    if (this.credentials) {
+      return this.credentials
+    }
+
+    const encrypted = configManager.getAwsBedrockCredentials()
+    if (!encrypted) {
+      return undefined
+    }
+
+    try {
+      this.credentials = JSON.parse(safeStorage.decryptString(Buffer.from(encrypted, 'base64')))
+      return this.credentials
+    } catch (error) {
+      Logger.error('[AwsBedrockService] Failed to decrypt credentials:', error)
+      return undefined
+    }
+  }
+
+  setCredentials(credentials: AwsCredentials) {
+    if (!credentials.accessKeyId || !credentials.secretAccessKey) {
+      throw new Error('Access key ID and secret access key are required')
+    }
+    if (!safeStorage.isEncryptionAvailable()) {
+      throw new Error('Secure storage is not available on this system')
+    }
+
+    const value: AwsCredentials = {
+      accessKeyId: credentials.accessKeyId.trim(),
+      secretAccessKey: credentials.secretAccessKey.trim(),
+      sessionToken: credentials.sessionToken?.trim() || undefined
+    }
+    configManager.setAwsBedrockCredentials(safeStorage.encryptString(JSON.stringify(value)).toString('base64'))
+    this.credentials = value
+  }
+
+  clearCredentials() {
+    configManager.setAwsBedrockCredentials('')
+    this.credentials = undefined
+  }
+
+  /**
+   * 返回已保存的 Access Key ID，用于在设置页中展示
+   */
+  getAccessKeyId(): string | undefined {
+    return this.loadCredentials()?.accessKeyId
+  }
+
+  signRequest(request: SignableRequest, region: string): Record<string, string> {
+    const credentials = this.loadCredentials()
+    if (!credentials) {
+      throw new Error('AWS Bedrock credentials are not configured')
+    }
+    return signAwsRequest(request, credentials, region, BEDROCK_SERVICE)
+  }
+}
+
+export default AwsBedrockService
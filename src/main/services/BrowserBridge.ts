@@ -0,0 +1,279 @@
+import { execFile } from 'node:child_process'
+import fs from 'node:fs'
+import net from 'node:net'
+import os from 'node:os'
+import path from 'node:path'
+import { promisify } from 'node:util'
+
+import { isMac, isWin } from '@main/constant'
+import { getResourcePath } from '@main/utils'
+import { app } from 'electron'
+import Logger from 'electron-log'
+
+import { apiServer } from './ApiServer'
+import { configManager } from './ConfigManager'
+import { windowService } from './WindowService'
+
+const execFileAsync = promisify(execFile)
+
+export const BRIDGE_HOST_NAME = 'com.cherrystudio.bridge'
+// a message longer than this without a line break closes the connection
+const MAX_LINE_LENGTH = 2 * 1024 * 1024
+
+export type BridgeBrowser = 'chrome' | 'firefox'
+
+interface BridgeRequest {
+  id?: string | number
+  type: 'ping' | 'send' | 'summarize'
+  text?: string
+  url?: string
+  title?: string
+}
+
+export interface BrowserBridgeStatus {
+  running: boolean
+  installed: Record<BridgeBrowser, boolean>
+}
+
+/**
+ * Local endpoint for the companion browser extension.
+ *
+ * The browser launches the native messaging host (resources/browser-bridge/host.js) which
+ * relays extension messages over a local socket in a private folder, only reachable by the current user.
+ * Selected text is quoted into the active conversation, summaries are generated by the
+ * renderer and sent back to the extension.
+ */
+class BrowserBridge {
+  private server: net.Server | null = null
+
+  public init() {
+    if (configManager.getBrowserBridgeEnabled()) {
+      this.start()
+    }
+  }
+
+  public getStatus(): BrowserBridgeStatus {
+    return {
+      running: !!this.server?.listening,
+      installed: {
+        chrome: fs.existsSync(this.getManifestPath('chrome')),
+        firefox: fs.existsSync(this.getManifestPath('firefox'))
+      }
+    }
+  }
+
+  public getSocketPath(): string {
+    if (isWin) {
+      return '\\\\.\\pipe\\cherry-studio-browser-bridge'
+    }
+    return path.join(this.getBridgeDir(), 'bridge.sock')
+  }
+
+  private getBridgeDir(): string {
+    return path.join(app.getPath('userData'), 'BrowserBridge')
+  }
+
+  /**
+   * Register the native messaging host for a browser, allowing only the given extension to use it
+   */
+  public async install(browser: BridgeBrowser, extensionId: string): Promise<BrowserBridgeStatus> {
+    if (!/^[\w@.{}-]+$/.test(extensionId)) {
+      throw new Error(`Invalid extension id: ${extensionId}`)
+    }
+
+    const manifestPath = this.getManifestPath(browser)
+    const manifest = {
+      name: BRIDGE_HOST_NAME,
+      description: 'Cherry Studio browser bridge',
+      path: this.writeHostLauncher(),
+      type: 'stdio',
+      ...(browser === 'chrome'
+        ? { allowed_origins: [`chrome-extension://${extensionId}/`] }
+        : { allowed_extensions: [extensionId] })
+    }
+
+    fs.mkdirSync(path.dirname(manifestPath), { recursive: true })
+    fs.writeFileSync(manifestPath, JSON.stringify(manifest, null, 2))
+
+    if (isWin) {
+      await execFileAsync('reg', ['add', this.getRegistryKey(browser), '/ve', '/t', 'REG_SZ', '/d', manifestPath, '/f'])
+    }
+
+    configManager.setBrowserBridgeEnabled(true)
+    this.start()
+    Logger.info(`[BrowserBridge] Installed native messaging host for ${browser}`)
+    return this.getStatus()
+  }
+
+  public async uninstall(browser: BridgeBrowser): Promise<BrowserBridgeStatus> {
+    fs.rmSync(this.getManifestPath(browser), { force: true })
+
+    if (isWin) {
+      await execFileAsync('reg', ['delete', this.getRegistryKey(browser), '/f']).catch(() => undefined)
+    }
+
+    const status = this.getStatus()
+    if (!status.installed.chrome && !status.installed.firefox) {
+      configManager.setBrowserBridgeEnabled(false)
+      this.stop()
+    }
+    return this.getStatus()
+  }
+
+  public start() {
+    if (this.server) return
+
+    const socketPath = this.getSocketPath()
+    if (!isWin) {
+      // the socket is created with the umask permissions, a private folder keeps other users out before the chmod
+      fs.mkdirSync(this.getBridgeDir(), { recursive: true, mode: 0o700 })
+      fs.chmodSync(this.getBridgeDir(), 0o700)
+      // remove a stale socket left behind by a crash
+      fs.rmSync(socketPath, { force: true })
+    }
+    // launchers written by older versions point to another socket
+    const { installed } = this.getStatus()
+    if (installed.chrome || installed.firefox) {
+      this.writeHostLauncher()
+    }
+
+    const server = net.createServer((socket) => this.onConnection(socket))
+    server.on('error', (error) => {
+      Logger.error('[BrowserBridge] Socket error:', error)
+      this.server = null
+    })
+    server.listen(socketPath, () => {
+      if (!isWin) fs.chmodSync(socketPath, 0o600)
+      Logger.info(`[BrowserBridge] Listening on ${socketPath}`)
+    })
+    this.server = server
+  }
+
+  public stop() {
+    this.server?.close()
+    this.server = null
+  }
+
+  private onConnection(socket: net.Socket) {
+    let buffer = ''
+
+    socket.on('data', (data) => {
+      buffer += data.toString('utf8')
+      let index: number
+      while ((index = buffer.indexOf('\n')) >= 0) {
+        const line = buffer.slice(0, index)
+        buffer = buffer.slice(index + 1)
+        if (line.trim()) {
+          this.handleLine(line).then((response) => {
+            if (!socket.destroyed) socket.write(JSON.stringify(response) + '\n')
+          })
+        }
+      }
+      // what is left is an unfinished line
+      if (buffer.length > MAX_LINE_LENGTH) {
+        Logger.warn('[BrowserBridge] Message too long, closing the connection')
+        buffer = ''
+        socket.destroy()
+      }
+    })
+    socket.on('error', (error) => Logger.warn('[BrowserBridge] Connection error:', error))
+  }
+
+  private async handleLine(line: string): Promise<Record<string, any>> {
+    let request: BridgeRequest
+    try {
+      request = JSON.parse(line)
+    } catch {
+      return { ok: false, error: 'Invalid message' }
+    }
+
+    try {
+      return { id: request.id, ok: true, ...(await this.handleRequest(request)) }
+    } catch (error: any) {
+      Logger.error(`[BrowserBridge] ${request.type} failed:`, error)
+      return { id: request.id, ok: false, error: error.message }
+    }
+  }
+
+  private async handleRequest(request: BridgeRequest): Promise<Record<string, any>> {
+    switch (request.type) {
+      case 'ping':
+        return { version: app.getVersion() }
+      case 'send': {
+        if (!request.text?.trim()) throw new Error('`text` is required')
+        const link = request.title ? `[${request.title}](${request.url})` : request.url
+        windowService.quoteToMainWindow(request.url ? `${request.text.trim()}\n\n— ${link}` : request.text.trim())
+        return {}
+      }
+      case 'summarize': {
+        if (!request.text?.trim()) throw new Error('`text` is required')
+        const summary = await apiServer.callRenderer<string>('browser.summarize', {
+          text: request.text,
+          url: request.url,
+          title: request.title
+        })
+        return { summary }
+      }
+      default:
+        throw new Error(`Unknown message type: ${(request as any).type}`)
+    }
+  }
+
+  /**
+   * The browser can only start executables, so write a small launcher that runs
+   * the host script with the app's bundled Node runtime
+   */
+  private writeHostLauncher(): string {
+    const dir = this.getBridgeDir()
+    // resources are unpacked from the asar archive, an external process cannot read inside it
+    const hostScript = path
+      .join(getResourcePath(), 'browser-bridge', 'host.js')
+      .replace(`app.asar${path.sep}`, `app.asar.unpacked${path.sep}`)
+    const socketPath = this.getSocketPath()
+    fs.mkdirSync(dir, { recursive: true, mode: 0o700 })
+
+    if (isWin) {
+      const launcher = path.join(dir, 'host.bat')
+      const script = [
+        '@echo off',
+        'set ELECTRON_RUN_AS_NODE=1',
+        `"${process.execPath}" "${hostScript}" "${socketPath}" %*`
+      ].join('\r\n')
+      fs.writeFileSync(launcher, script)
+      return launcher
+    }
+
+    const launcher = path.join(dir, 'host.sh')
+    const script = [
+      '#!/bin/sh',
+      `ELECTRON_RUN_AS_NODE=1 exec "${process.execPath}" "${hostScript}" "${socketPath}" "$@"`
+    ].join('\n')
+    fs.writeFileSync(launcher, script + '\n', { mode: 0o755 })
+    return launcher
+  }
+
+  private getRegistryKey(browser: BridgeBrowser): string {
+    const vendor = browser === 'chrome' ? 'Google\\Chrome' : 'Mozilla'
+    return `HKCU\\Software\\${vendor}\\NativeMessagingHosts\\${BRIDGE_HOST_NAME}`
+  }
+
+  private getManifestPath(browser: BridgeBrowser): string {
+    const home = os.homedir()
+    const file = `${BRIDGE_HOST_NAME}.json`
+
+    if (isWin) {
+      // on Windows the manifest can live anywhere, the registry points to it
+      return path.join(app.getPath('userData'), 'BrowserBridge', `${browser}-${file}`)
+    }
+    if (isMac) {
+      return browser === 'chrome'
+        ? path.join(home, 'Library', 'Application Support', 'Google', 'Chrome', 'NativeMessagingHosts', file)
+        : path.join(home, 'Library', 'Application Support', 'Mozilla', 'NativeMessagingHosts', file)
+    }
+    return browser === 'chrome'
+      ? path.join(home, '.config', 'google-chrome', 'NativeMessagingHosts', file)
+      : path.join(home, '.mozilla', 'native-messaging-hosts', file)
+  }
+}
+
+export const browserBridge = new BrowserBridge()
@@ -0,0 +1,150 @@
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import { WebContents } from 'electron'
+import Logger from 'electron-log'
+
+/**
+ * - app: app state, settings sync and other low risk channels
+ * - file: read or write local files, backups and exports
+ * - agent: run tools, models and agents on behalf of the caller
+ * - system: change the app or OS configuration, e.g. launch on boot, shortcuts, encryption
+ * - miniapp: call the mini app bridge, whose commands check the calling mini app's own permissions
+ */
+export type Capability = 'app' | 'file' | 'agent' | 'system' | 'miniapp'
+
+export const ALL_CAPABILITIES: Capability[] = ['app', 'file', 'agent', 'system', 'miniapp']
+
+// required capability by channel prefix, channels without a match require 'app'
+const CHANNEL_PREFIX_CAPABILITIES: [string, Capability][] = [
+  ['file:', 'file'],
+  ['fs:', 'file'],
+  ['zip:', 'file'],
+  ['backup:', 'file'],
+  ['export:', 'file'],
+  ['obsidian:', 'file'],
+  ['nutstore:', 'file'],
+  ['open:', 'file'],
+  ['archive:', 'file'],
+  ['download:', 'file'],
+  ['model-file:', 'file'],
+  ['workspace:', 'file'],
+  ['conversation-share:', 'file'],
+  ['conversation-workspace:', 'file'],
+  ['tabular:', 'file'],
+  ['text-preview:', 'file'],
+  ['print:', 'file'],
+  ['session:', 'file'],
+  ['mcp:', 'agent'],
+  ['api-server:', 'agent'],
+  ['webhook:', 'agent'],
+  ['outbound:', 'agent'],
+  ['scheduler:', 'agent'],
+  ['local-inference:', 'agent'],
+  ['knowledge-base:', 'agent'],
+  ['memory:', 'agent'],
+  ['browser-bridge:', 'agent'],
+  ['copilot:', 'agent'],
+  ['gemini:', 'agent'],
+  ['vertexai:', 'agent'],
+  ['aws-bedrock:', 'agent'],
+  ['app:', 'system'],
+  ['system:', 'system'],
+  ['shortcuts:', 'system'],
+  ['config:', 'system'],
+  ['aes:', 'system'],
+  ['policy:', 'system'],
+  ['action-policy:', 'system'],
+  ['template:', 'system'],
+  ['audit:', 'system'],
+  ['perf:', 'system'],
+  ['log:', 'system'],
+  ['command:', 'system'],
+  ['worker-pool:', 'system'],
+  ['snap-layout:', 'system'],
+  ['secure-topic:', 'system']
+]
+
+// channels that differ from their prefix
+const CHANNEL_CAPABILITIES: Partial<Record<string, Capability>> = {
+  [IpcChannel.App_Info]: 'app',
+  [IpcChannel.Open_Website]: 'app',
+  [IpcChannel.MiniApp_Capture]: 'file',
+  [IpcChannel.MiniApp_PrintToPdf]: 'file',
+  [IpcChannel.MiniApp_GetCookies]: 'system',
+  [IpcChannel.MiniApp_SetCookie]: 'system',
+  [IpcChannel.MiniApp_ClearCookies]: 'system',
+  [IpcChannel.MiniApp_RespondPermission]: 'system',
+  [IpcChannel.MiniApp_ResetPermissions]: 'system',
+  [IpcChannel.MiniApp_PreparePreload]: 'file',
+  [IpcChannel.MiniApp_InstallPackage]: 'system',
+  [IpcChannel.MiniApp_InstallFromUrl]: 'system',
+  [IpcChannel.MiniApp_UninstallPackage]: 'system',
+  [IpcChannel.MiniApp_SetTrustedKeys]: 'system',
+  [IpcChannel.MiniApp_UpdatePackage]: 'system',
+  [IpcChannel.MiniApp_SetAutoUpdate]: 'system',
+  [IpcChannel.MiniApp_BridgeInvoke]: 'miniapp',
+  // presentation windows only hold 'app' and still need to read secure conversations
+  [IpcChannel.SecureTopic_Open]: 'app'
+}
+
+/**
+ * Capability check for IPC channels.
+ *
+ * Every channel requires a capability, the caller's webContents must hold it in the grant table.
+ * App windows hold all capabilities, webviews (mini apps) only hold `miniapp` unless granted explicitly,
+ * so a mini app page that reaches the preload api still cannot read files or run agents. What it may do through
+ * the bridge is limited to the permissions its manifest declares and the user granted.
+ */
+class CapabilityManager {
+  private grants = new Map<number, Set<Capability>>()
+
+  public getRequiredCapability(channel: string): Capability {
+    const capability = CHANNEL_CAPABILITIES[channel]
+    if (capability) return capability
+
+    const match = CHANNEL_PREFIX_CAPABILITIES.find(([prefix]) => channel.startsWith(prefix))
+    return match ? match[1] : 'app'
+  }
+
+  public getGrants(contents: WebContents): Set<Capability> {
+    const grants = this.grants.get(contents.id)
+    if (grants) return grants
+    return new Set<Capability>(contents.getType() === 'webview' ? ['miniapp'] : ALL_CAPABILITIES)
+  }
+
+  /**
+   * Replace the capabilities of a window or webview, dropped once it is destroyed
+   */
+  public grant(contents: WebContents, capabilities: Capability[]) {
+    if (!this.grants.has(contents.id)) {
+      const id = contents.id
+      contents.once('destroyed', () => this.grants.delete(id))
+    }
+    this.grants.set(contents.id, new Set(capabilities))
+  }
+
+  public revoke(contents: WebContents) {
+    this.grant(contents, [])
+  }
+
+  public isAllowed(contents: WebContents, channel: string): boolean {
+    return this.getGrants(contents).has(this.getRequiredCapability(channel))
+  }
+
+  /**
+   * Throws a permission_denied error when the sender does not hold the channel's capability
+   */
+  public assert(contents: WebContents, channel: string) {
+    if (this.isAllowed(contents, channel)) return
+
+    const capability = this.getRequiredCapability(channel)
+    Logger.warn(`[CapabilityManager] Denied ${channel} for ${contents.getType()} ${contents.getURL()}`)
+    throw new ManagerError(
+      'CapabilityManager',
+      'permission_denied',
+      `Missing capability "${capability}" for ${channel}`
+    )
+  }
+}
+
+export const capabilityManager = new CapabilityManager()
@@ -0,0 +1,227 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import type { ExtractChunkData } from '@cherrystudio/embedjs-interfaces'
+import { getDataPath } from '@main/utils'
+import {
+  createChunkLocator,
+  createPositionIndex,
+  findChunk,
+  getByteRange,
+  getLineRange,
+  locateChunk,
+  normalizeWhitespace
+} from '@main/utils/citation'
+import { shell } from 'electron'
+import Logger from 'electron-log'
+import { getDocument } from 'officeparser/pdfjs-dist-build/pdf.js'
+
+export interface Citation {
+  chunkId: string
+  source: string
+  type: 'file' | 'pdf' | 'url' | 'unknown'
+  page?: number
+  startLine?: number
+  endLine?: number
+  byteStart?: number
+  byteEnd?: number
+  preview: string
+  resolvedAt: number
+}
+
+// location stored in the chunk metadata when the file is indexed
+export type ChunkLocation = Pick<Citation, 'page' | 'startLine' | 'endLine' | 'byteStart' | 'byteEnd'> & {
+  // loaders of in-memory text don't keep the path as the source
+  sourcePath: string
+  locatedAt: number
+}
+
+const PREVIEW_LENGTH = 200
+// file types whose loaders keep the text as-is, so offsets map back to the file
+const TEXT_EXTS = ['.txt', '.md', '.mdx', '.json', '.csv', '.html', '.htm', '.xml', '.yaml', '.yml', '.log']
+
+class CitationService {
+  private cacheFile = path.join(getDataPath(), 'KnowledgeBase', 'citations.json')
+  private cache: Record<string, Citation> | null = null
+
+  public resolveCitation = async (_: Electron.IpcMainInvokeEvent, chunk: ExtractChunkData): Promise<Citation> => {
+    const chunkId = chunk.metadata.id
+    const cached = this.getCache()[chunkId]
+    if (cached && !this.isStale(cached)) return cached
+
+    const source: string = chunk.metadata.sourcePath ?? chunk.metadata.source
+    const citation: Citation = {
+      chunkId,
+      source,
+      type: 'unknown',
+      preview: chunk.pageContent.replace(/\s+/g, ' ').trim().slice(0, PREVIEW_LENGTH),
+      resolvedAt: Date.now()
+    }
+
+    // offsets stored at index time, the file is only searched again when it changed since
+    const { locatedAt, page, startLine, endLine, byteStart, byteEnd } = chunk.metadata as Partial<ChunkLocation>
+    if (locatedAt && !this.isModifiedSince(source, locatedAt)) {
+      const type = path.extname(source).toLowerCase() === '.pdf' ? 'pdf' : 'file'
+      const located: Citation = { ...citation, type, page, startLine, endLine, byteStart, byteEnd }
+      // cached as well, only cited sources can be opened
+      if (cached?.source !== source) {
+        this.getCache()[chunkId] = located
+        this.saveCache()
+      }
+      return located
+    }
+
+    try {
+      if (/^https?:\/\//i.test(source)) {
+        citation.type = 'url'
+      } else if (path.extname(source).toLowerCase() === '.pdf') {
+        citation.type = 'pdf'
+        citation.page = await this.findPdfPage(source, chunk.pageContent)
+      } else if (TEXT_EXTS.includes(path.extname(source).toLowerCase()) && fs.existsSync(source)) {
+        citation.type = 'file'
+        const content = await fs.promises.readFile(source, 'utf-8')
+        const range = locateChunk(content, chunk.pageContent)
+        if (range) {
+          const bytes = getByteRange(content, range)
+          Object.assign(citation, getLineRange(content, range), { byteStart: bytes.start, byteEnd: bytes.end })
+        }
+      } else if (fs.existsSync(source)) {
+        citation.type = 'file'
+      }
+    } catch (error) {
+      Logger.warn(`[CitationService] Failed to resolve citation in ${source}:`, error)
+    }
+
+    this.getCache()[chunkId] = citation
+    this.saveCache()
+    return citation
+  }
+
+  /**
+   * Locator for the chunks of a file being indexed, undefined for files whose text doesn't map back to the file.
+   * Chunks arrive in order, so every search starts where the previous chunk was found.
+   */
+  public async createLocator(filePath: string): Promise<((chunk: string) => ChunkLocation | undefined) | undefined> {
+    const ext = path.extname(filePath).toLowerCase()
+    const locatedAt = Date.now()
+
+    if (ext === '.pdf') {
+      const pages = (await this.getPdfPageTexts(filePath)).map(normalizeWhitespace)
+      let current = 0
+      return (chunk) => {
+        const index = pages.findIndex((page, i) => i >= current && findChunk(page, chunk))
+        if (index < 0) return undefined
+        current = index
+        return { page: index + 1, sourcePath: filePath, locatedAt }
+      }
+    }
+
+    if (TEXT_EXTS.includes(ext)) {
+      const content = await fs.promises.readFile(filePath, 'utf-8')
+      const locateRange = createChunkLocator(content)
+      const positions = createPositionIndex(content)
+      return (chunk) => {
+        const range = locateRange(chunk)
+        if (!range) return undefined
+        const bytes = positions.getByteRange(range)
+        const lines = positions.getLineRange(range)
+        return { ...lines, byteStart: bytes.start, byteEnd: bytes.end, sourcePath: filePath, locatedAt }
+      }
+    }
+
+    return undefined
+  }
+
+  /**
+   * Open the cited source, jumping to the page or text fragment where the viewer supports it.
+   * Only sources of citations resolved from a knowledge base search are opened.
+   */
+  public openCitation = async (_: Electron.IpcMainInvokeEvent, citation: Citation): Promise<void> => {
+    const resolved = this.getCache()[citation.chunkId]
+    if (!resolved || resolved.source !== citation.source) {
+      Logger.warn(`[CitationService] Refused to open ${citation.source}, it is not a cited knowledge base source`)
+      return
+    }
+
+    switch (resolved.type) {
+      case 'url': {
+        const fragment = resolved.preview.split(' ').slice(0, 8).join(' ')
+        await shell.openExternal(`${resolved.source.split('#')[0]}#:~:text=${encodeURIComponent(fragment)}`)
+        break
+      }
+      case 'pdf': {
+        const page = Number(citation.page)
+        if (Number.isInteger(page) && page > 0) {
+          const url = new URL('file://')
+          url.pathname = resolved.source
+          url.hash = `page=${page}`
+          await shell.openExternal(url.toString())
+        } else {
+          await shell.openPath(resolved.source)
+        }
+        break
+      }
+      default:
+        await shell.openPath(resolved.source)
+    }
+  }
+
+  /**
+   * Offsets are only valid for the file contents they were computed from
+   */
+  private isStale(citation: Citation): boolean {
+    return citation.type !== 'url' && this.isModifiedSince(citation.source, citation.resolvedAt)
+  }
+
+  private isModifiedSince(filePath: string, time: number): boolean {
+    try {
+      return fs.statSync(filePath).mtimeMs > time
+    } catch {
+      return true
+    }
+  }
+
+  private async findPdfPage(filePath: string, text: string): Promise<number | undefined> {
+    const pages = await this.getPdfPageTexts(filePath)
+    const index = pages.findIndex((pageText) => locateChunk(pageText, text))
+    return index < 0 ? undefined : index + 1
+  }
+
+  private async getPdfPageTexts(filePath: string): Promise<string[]> {
+    const doc = await getDocument({ data: await fs.promises.readFile(filePath) }).promise
+    try {
+      const pages: string[] = []
+      for (let i = 1; i <= doc.numPages; i++) {
+        const page = await doc.getPage(i)
+        const content = await page.getTextContent()
+        pages.push(content.items.map((item: any) => item.str ?? '').join(' '))
+      }
+      return pages
+    } finally {
+      await doc.destroy()
+    }
+  }
+
+  private getCache(): Record<string, Citation> {
+    if (!this.cache) {
+      try {
+        this.cache = fs.existsSync(this.cacheFile) ? JSON.parse(fs.readFileSync(this.cacheFile, 'utf-8')) : {}
+      } catch (error) {
+        Logger.error('[CitationService] Failed to load citation cache:', error)
+        this.cache = {}
+      }
+    }
+    return this.cache!
+  }
+
+  private saveCache() {
+    try {
+      fs.mkdirSync(path.dirname(this.cacheFile), { recursive: true })
+      fs.writeFileSync(this.cacheFile, JSON.stringify(this.cache))
+    } catch (error) {
+      Logger.error('[CitationService] Failed to save citation cache:', error)
+    }
+  }
+}
+
+export const citationService = new CitationService()
@@ -0,0 +1,18 @@
+import { classifyClipboard } from '@main/utils/clipboard'
+import { ClipboardDescriptor } from '@types'
+import { clipboard } from 'electron'
+
+/**
+ * Smart paste: tells what the clipboard holds (prose, code, a url, a table or an image) with its content
+ * normalized, e.g. the rows of a table, so the chat input can offer paste actions matching the content.
+ */
+class ClipboardManager {
+  public classify(): ClipboardDescriptor {
+    const text = clipboard.readText()
+    // text wins over images, so large images are only decoded when there is no text
+    const image = text.trim() ? undefined : clipboard.readImage()
+    return classifyClipboard({ text, image: image && !image.isEmpty() ? image.getSize() : undefined })
+  }
+}
+
+export const clipboardManager = new ClipboardManager()
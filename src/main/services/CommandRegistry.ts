@@ -0,0 +1,86 @@
+import { COMMAND_ID_REGEX, filterCommands, validateCommandArgs } from '@main/utils/commands'
+import { ManagerError } from '@shared/ManagerError'
+import { CommandInfo } from '@types'
+import Logger from 'electron-log'
+
+export interface CommandDefinition extends Omit<CommandInfo, 'args' | 'source'> {
+  args?: CommandInfo['args']
+  run: (args: Record<string, any>) => unknown
+}
+
+/**
+ * Registry of the actions the app can run by id, with a title and an argument schema so the command palette and
+ * the CLI can discover and run every capability the same way.
+ *
+ * Main process modules register their commands directly. The renderer registers the metadata of its commands
+ * once it is loaded, invoking them is forwarded to the main window like the local API requests.
+ */
+class CommandRegistry {
+  private commands: Map<string, { info: CommandInfo; run: CommandDefinition['run'] }> = new Map()
+
+  public register(definition: CommandDefinition) {
+    const { run, ...rest } = definition
+    if (!COMMAND_ID_REGEX.test(rest.id)) {
+      throw new ManagerError('CommandRegistry', 'invalid_argument', `Invalid command id: ${rest.id}`)
+    }
+    if (this.commands.has(rest.id)) {
+      Logger.warn(`[CommandRegistry] Command ${rest.id} is registered again`)
+    }
+    this.commands.set(rest.id, { info: { ...rest, args: rest.args ?? [], source: 'main' }, run })
+  }
+
+  public unregister(id: string) {
+    this.commands.delete(id)
+  }
+
+  /**
+   * Replace the commands of the renderer, called again when the main window is reloaded.
+   * `forward` runs a command in the main window.
+   */
+  public registerRenderer(
+    commands: Omit<CommandInfo, 'source'>[],
+    forward: (id: string, args: Record<string, unknown>) => Promise<unknown>
+  ) {
+    for (const [id, command] of this.commands) {
+      if (command.info.source === 'renderer') this.commands.delete(id)
+    }
+    for (const command of commands) {
+      if (!COMMAND_ID_REGEX.test(command.id) || this.commands.has(command.id)) {
+        Logger.warn(`[CommandRegistry] Ignored renderer command ${command.id}`)
+        continue
+      }
+      this.commands.set(command.id, {
+        info: { ...command, args: command.args ?? [], source: 'renderer' },
+        run: (args) => forward(command.id, args)
+      })
+    }
+  }
+
+  public list(filter?: string): CommandInfo[] {
+    return filterCommands(
+      [...this.commands.values()].map(({ info }) => info),
+      filter
+    )
+  }
+
+  public async invoke(id: string, args?: Record<string, unknown>): Promise<unknown> {
+    const command = this.commands.get(id)
+    if (!command) {
+      throw new ManagerError('CommandRegistry', 'not_found', `Unknown command: ${id}`)
+    }
+
+    const { values, errors } = validateCommandArgs(command.info.args, args)
+    if (errors.length) {
+      throw new ManagerError('CommandRegistry', 'invalid_argument', errors.join(', '), { details: { id, errors } })
+    }
+
+    Logger.info(`[CommandRegistry] Invoking ${id}`)
+    try {
+      return (await command.run(values)) ?? null
+    } catch (error) {
+      throw ManagerError.from('CommandRegistry', error)
+    }
+  }
+}
+
+export const commandRegistry = new CommandRegistry()
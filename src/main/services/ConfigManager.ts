@@ -1,9 +1,26 @@
 import { defaultLanguage, FeedUrl, ZOOM_SHORTCUTS } from '@shared/config/constant'
-import { LanguageVarious, Shortcut, ThemeMode } from '@types'
+import {
+  ConfirmationRule,
+  ConversationWorkspace,
+  InstalledMCPServer,
+  InstalledMiniAppPackage,
+  LanguageVarious,
+  MiniAppLayout,
+  MiniAppPermissionDecisions,
+  MiniAppPermissionScopes,
+  OutboundIntegration,
+  ScheduledMessage,
+  Shortcut,
+  SnapLayout,
+  ThemeMode,
+  UserTemplate,
+  Webhook
+} from '@types'
 import { app } from 'electron'
 import Store from 'electron-store'
 
 import { locales } from '../utils/locales'
+import { policyService } from './PolicyService'
 
 export enum ConfigKeys {
   Language = 'language',
@@ -23,7 +40,46 @@ export enum ConfigKeys {
   SelectionAssistantFollowToolbar = 'selectionAssistantFollowToolbar',
   SelectionAssistantRemeberWinSize = 'selectionAssistantRemeberWinSize',
   SelectionAssistantFilterMode = 'selectionAssistantFilterMode',
-  SelectionAssistantFilterList = 'selectionAssistantFilterList'
+  SelectionAssistantFilterList = 'selectionAssistantFilterList',
+  SelectionAssistantPermissionGranted = 'selectionAssistantPermissionGranted',
+  FeedbackSoundEnabled = 'feedbackSoundEnabled',
+  FeedbackSoundTheme = 'feedbackSoundTheme',
+  FeedbackEnabledEvents = 'feedbackEnabledEvents',
+  FeedbackRespectDoNotDisturb = 'feedbackRespectDoNotDisturb',
+  ContentProtection = 'privacy.contentProtection',
+  LockPassphraseHash = 'lockPassphraseHash',
+  LockIdleTimeout = 'lockIdleTimeout',
+  LockOnSystemLock = 'lockOnSystemLock',
+  MemoryHalfLifeDays = 'memoryHalfLifeDays',
+  AwsBedrockCredentials = 'awsBedrockCredentials',
+  ApiServerEnabled = 'apiServerEnabled',
+  ApiServerPort = 'apiServerPort',
+  ApiServerToken = 'apiServerToken',
+  Webhooks = 'webhooks',
+  OutboundIntegrations = 'outboundIntegrations',
+  Vault = 'vault',
+  ScheduledMessages = 'scheduledMessages',
+  ConversationWorkspaces = 'conversationWorkspaces',
+  BrowserBridgeEnabled = 'browserBridgeEnabled',
+  Templates = 'templates',
+  MiniAppZoomFactors = 'miniAppZoomFactors',
+  MiniAppPermissions = 'miniAppPermissions',
+  MiniAppPermissionScopes = 'miniAppPermissionScopes',
+  WorkerPoolSize = 'workerPoolSize',
+  DownloadDirectory = 'downloadDirectory',
+  SnapLayouts = 'snapLayouts',
+  ConfirmationRules = 'confirmationRules',
+  ApprovedDomains = 'approvedDomains',
+  MiniAppLayout = 'miniAppLayout',
+  McpRegistryUrl = 'mcpRegistryUrl',
+  McpInstalledServers = 'mcpInstalledServers',
+  McpAutoUpdate = 'mcpAutoUpdate',
+  MiniAppRegistryUrl = 'miniAppRegistryUrl',
+  MiniAppTrustedKeys = 'miniAppTrustedKeys',
+  MiniAppInstalledPackages = 'miniAppInstalledPackages',
+  MiniAppAutoUpdate = 'miniAppAutoUpdate',
+  LogRedactionPatterns = 'logRedactionPatterns',
+  WorkspaceTrustedSigners = 'workspaceTrustedSigners'
 }
 
 export class ConfigManager {
@@ -209,16 +265,343 @@ export class ConfigManager {
     this.setAndNotify(ConfigKeys.SelectionAssistantFilterList, value)
   }
 
+  // Selection Assistant: user consented to monitoring text selection in other apps,
+  // users who enabled the assistant before the consent prompt existed have already agreed
+  getSelectionAssistantPermissionGranted(): boolean {
+    return this.get<boolean>(ConfigKeys.SelectionAssistantPermissionGranted, this.getSelectionAssistantEnabled())
+  }
+
+  setSelectionAssistantPermissionGranted(value: boolean) {
+    this.setAndNotify(ConfigKeys.SelectionAssistantPermissionGranted, value)
+  }
+
+  getFeedbackSoundEnabled(): boolean {
+    return this.get<boolean>(ConfigKeys.FeedbackSoundEnabled, false)
+  }
+
+  setFeedbackSoundEnabled(value: boolean) {
+    this.set(ConfigKeys.FeedbackSoundEnabled, value)
+  }
+
+  getFeedbackSoundTheme(): string {
+    return this.get<string>(ConfigKeys.FeedbackSoundTheme, 'system')
+  }
+
+  setFeedbackSoundTheme(value: string) {
+    this.set(ConfigKeys.FeedbackSoundTheme, value)
+  }
+
+  getFeedbackEnabledEvents(): string[] {
+    return this.get<string[]>(ConfigKeys.FeedbackEnabledEvents, ['message-received', 'error', 'agent-finished'])
+  }
+
+  setFeedbackEnabledEvents(value: string[]) {
+    this.set(ConfigKeys.FeedbackEnabledEvents, value)
+  }
+
+  getFeedbackRespectDoNotDisturb(): boolean {
+    return this.get<boolean>(ConfigKeys.FeedbackRespectDoNotDisturb, true)
+  }
+
+  setFeedbackRespectDoNotDisturb(value: boolean) {
+    this.set(ConfigKeys.FeedbackRespectDoNotDisturb, value)
+  }
+
+  getContentProtection(): boolean {
+    return this.get<boolean>(ConfigKeys.ContentProtection, false)
+  }
+
+  setContentProtection(value: boolean) {
+    this.setAndNotify(ConfigKeys.ContentProtection, value)
+  }
+
+  getLockPassphraseHash(): string {
+    return this.get<string>(ConfigKeys.LockPassphraseHash, '')
+  }
+
+  setLockPassphraseHash(value: string) {
+    this.set(ConfigKeys.LockPassphraseHash, value)
+  }
+
+  // App lock: idle minutes before auto-lock, 0 disables it
+  getLockIdleTimeout(): number {
+    return this.get<number>(ConfigKeys.LockIdleTimeout, 0)
+  }
+
+  setLockIdleTimeout(value: number) {
+    this.set(ConfigKeys.LockIdleTimeout, value)
+  }
+
+  getLockOnSystemLock(): boolean {
+    return this.get<boolean>(ConfigKeys.LockOnSystemLock, true)
+  }
+
+  setLockOnSystemLock(value: boolean) {
+    this.set(ConfigKeys.LockOnSystemLock, value)
+  }
+
+  // Long-term memory: days until an unused memory loses half its confidence, 0 disables decay
+  getMemoryHalfLifeDays(): number {
+    return this.get<number>(ConfigKeys.MemoryHalfLifeDays, 90)
+  }
+
+  setMemoryHalfLifeDays(value: number) {
+    this.set(ConfigKeys.MemoryHalfLifeDays, value)
+  }
+
+  getAwsBedrockCredentials(): string {
+    return this.get<string>(ConfigKeys.AwsBedrockCredentials, '')
+  }
+
+  setAwsBedrockCredentials(value: string) {
+    this.set(ConfigKeys.AwsBedrockCredentials, value)
+  }
+
+  // Local API server: OpenAI-compatible endpoints on loopback for external tools
+  getApiServerEnabled(): boolean {
+    return this.get<boolean>(ConfigKeys.ApiServerEnabled, false)
+  }
+
+  setApiServerEnabled(value: boolean) {
+    this.set(ConfigKeys.ApiServerEnabled, value)
+  }
+
+  getApiServerPort(): number {
+    return this.get<number>(ConfigKeys.ApiServerPort, 23333)
+  }
+
+  setApiServerPort(value: number) {
+    this.set(ConfigKeys.ApiServerPort, value)
+  }
+
+  getApiServerToken(): string {
+    return this.get<string>(ConfigKeys.ApiServerToken, '')
+  }
+
+  setApiServerToken(value: string) {
+    this.set(ConfigKeys.ApiServerToken, value)
+  }
+
+  getWebhooks(): Webhook[] {
+    return this.get<Webhook[]>(ConfigKeys.Webhooks, [])
+  }
+
+  setWebhooks(value: Webhook[]) {
+    this.set(ConfigKeys.Webhooks, value)
+  }
+
+  getOutboundIntegrations(): OutboundIntegration[] {
+    return this.get<OutboundIntegration[]>(ConfigKeys.OutboundIntegrations, [])
+  }
+
+  setOutboundIntegrations(value: OutboundIntegration[]) {
+    this.set(ConfigKeys.OutboundIntegrations, value)
+  }
+
+  // Secrets encrypted with the OS keychain, see SecretVault
+  getVault(): Record<string, string> {
+    return this.get<Record<string, string>>(ConfigKeys.Vault, {})
+  }
+
+  setVault(value: Record<string, string>) {
+    this.set(ConfigKeys.Vault, value)
+  }
+
+  getScheduledMessages(): ScheduledMessage[] {
+    return this.get<ScheduledMessage[]>(ConfigKeys.ScheduledMessages, [])
+  }
+
+  setScheduledMessages(value: ScheduledMessage[]) {
+    this.set(ConfigKeys.ScheduledMessages, value)
+  }
+
+  getConversationWorkspaces(): ConversationWorkspace[] {
+    return this.get<ConversationWorkspace[]>(ConfigKeys.ConversationWorkspaces, [])
+  }
+
+  setConversationWorkspaces(value: ConversationWorkspace[]) {
+    this.set(ConfigKeys.ConversationWorkspaces, value)
+  }
+
+  getBrowserBridgeEnabled(): boolean {
+    return this.get<boolean>(ConfigKeys.BrowserBridgeEnabled, false)
+  }
+
+  setBrowserBridgeEnabled(value: boolean) {
+    this.set(ConfigKeys.BrowserBridgeEnabled, value)
+  }
+
+  getTemplates(): UserTemplate[] {
+    return this.get<UserTemplate[]>(ConfigKeys.Templates, [])
+  }
+
+  setTemplates(value: UserTemplate[]) {
+    this.set(ConfigKeys.Templates, value)
+  }
+
+  getMiniAppZoomFactors(): Record<string, number> {
+    return this.get<Record<string, number>>(ConfigKeys.MiniAppZoomFactors, {})
+  }
+
+  setMiniAppZoomFactors(value: Record<string, number>) {
+    this.set(ConfigKeys.MiniAppZoomFactors, value)
+  }
+
+  // remembered permission decisions by mini app id
+  getMiniAppPermissions(): Record<string, MiniAppPermissionDecisions> {
+    return this.get<Record<string, MiniAppPermissionDecisions>>(ConfigKeys.MiniAppPermissions, {})
+  }
+
+  setMiniAppPermissions(value: Record<string, MiniAppPermissionDecisions>) {
+    this.set(ConfigKeys.MiniAppPermissions, value)
+  }
+
+  // folders and hosts the remembered bridge decisions were given for, by mini app id
+  getMiniAppPermissionScopes(): Record<string, MiniAppPermissionScopes> {
+    return this.get<Record<string, MiniAppPermissionScopes>>(ConfigKeys.MiniAppPermissionScopes, {})
+  }
+
+  setMiniAppPermissionScopes(value: Record<string, MiniAppPermissionScopes>) {
+    this.set(ConfigKeys.MiniAppPermissionScopes, value)
+  }
+
+  getWorkerPoolSize(): number {
+    return this.get<number>(ConfigKeys.WorkerPoolSize, 0)
+  }
+
+  setWorkerPoolSize(value: number) {
+    this.set(ConfigKeys.WorkerPoolSize, value)
+  }
+
+  getDownloadDirectory(): string {
+    return this.get<string>(ConfigKeys.DownloadDirectory, app.getPath('downloads'))
+  }
+
+  setDownloadDirectory(value: string) {
+    this.set(ConfigKeys.DownloadDirectory, value)
+  }
+
+  // user saved layouts, the built-in ones are not stored
+  getSnapLayouts(): SnapLayout[] {
+    return this.get<SnapLayout[]>(ConfigKeys.SnapLayouts, [])
+  }
+
+  setSnapLayouts(value: SnapLayout[]) {
+    this.set(ConfigKeys.SnapLayouts, value)
+  }
+
+  getConfirmationRules(defaultValue: ConfirmationRule[]): ConfirmationRule[] {
+    return this.get<ConfirmationRule[]>(ConfigKeys.ConfirmationRules, defaultValue)
+  }
+
+  setConfirmationRules(value: ConfirmationRule[]) {
+    this.set(ConfigKeys.ConfirmationRules, value)
+  }
+
+  // domains network calls of tools were approved for
+  getApprovedDomains(): string[] {
+    return this.get<string[]>(ConfigKeys.ApprovedDomains, [])
+  }
+
+  setApprovedDomains(value: string[]) {
+    this.set(ConfigKeys.ApprovedDomains, value)
+  }
+
+  getMiniAppLayout(): MiniAppLayout | null {
+    return this.get<MiniAppLayout | null>(ConfigKeys.MiniAppLayout, null)
+  }
+
+  setMiniAppLayout(value: MiniAppLayout) {
+    this.set(ConfigKeys.MiniAppLayout, value)
+  }
+
+  // registry of the MCP marketplace, empty for the one shipped with the app
+  getMcpRegistryUrl(): string {
+    return this.get<string>(ConfigKeys.McpRegistryUrl, '')
+  }
+
+  setMcpRegistryUrl(value: string) {
+    this.set(ConfigKeys.McpRegistryUrl, value)
+  }
+
+  getMcpInstalledServers(): InstalledMCPServer[] {
+    return this.get<InstalledMCPServer[]>(ConfigKeys.McpInstalledServers, [])
+  }
+
+  setMcpInstalledServers(value: InstalledMCPServer[]) {
+    this.set(ConfigKeys.McpInstalledServers, value)
+  }
+
+  getMcpAutoUpdate(): boolean {
+    return this.get<boolean>(ConfigKeys.McpAutoUpdate, false)
+  }
+
+  setMcpAutoUpdate(value: boolean) {
+    this.set(ConfigKeys.McpAutoUpdate, value)
+  }
+
+  getMiniAppRegistryUrl(): string {
+    return this.get<string>(ConfigKeys.MiniAppRegistryUrl, '')
+  }
+
+  setMiniAppRegistryUrl(value: string) {
+    this.set(ConfigKeys.MiniAppRegistryUrl, value)
+  }
+
+  getMiniAppTrustedKeys(): string[] {
+    return this.get<string[]>(ConfigKeys.MiniAppTrustedKeys, [])
+  }
+
+  setMiniAppTrustedKeys(value: string[]) {
+    this.set(ConfigKeys.MiniAppTrustedKeys, value)
+  }
+
+  getWorkspaceTrustedSigners(): string[] {
+    return this.get<string[]>(ConfigKeys.WorkspaceTrustedSigners, [])
+  }
+
+  setWorkspaceTrustedSigners(value: string[]) {
+    this.set(ConfigKeys.WorkspaceTrustedSigners, value)
+  }
+
+  getMiniAppInstalledPackages(): InstalledMiniAppPackage[] {
+    return this.get<InstalledMiniAppPackage[]>(ConfigKeys.MiniAppInstalledPackages, [])
+  }
+
+  setMiniAppInstalledPackages(value: InstalledMiniAppPackage[]) {
+    this.set(ConfigKeys.MiniAppInstalledPackages, value)
+  }
+
+  getMiniAppAutoUpdate(): boolean {
+    return this.get<boolean>(ConfigKeys.MiniAppAutoUpdate, false)
+  }
+
+  setMiniAppAutoUpdate(value: boolean) {
+    this.set(ConfigKeys.MiniAppAutoUpdate, value)
+  }
+
+  getLogRedactionPatterns(): string[] {
+    return this.get<string[]>(ConfigKeys.LogRedactionPatterns, [])
+  }
+
+  setLogRedactionPatterns(value: string[]) {
+    this.setAndNotify(ConfigKeys.LogRedactionPatterns, value)
+  }
+
   setAndNotify(key: string, value: unknown) {
     this.set(key, value, true)
   }
 
   set(key: string, value: unknown, isNotify: boolean = false) {
+    policyService.assertUnlocked(key)
     this.store.set(key, value)
     isNotify && this.notifySubscribers(key, value)
   }
 
   get<T>(key: string, defaultValue?: T) {
+    if (policyService.isLocked(key)) {
+      return policyService.getLockedValue<T>(key) as T
+    }
     return this.store.get(key, defaultValue) as T
   }
 }
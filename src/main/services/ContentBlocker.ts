@@ -0,0 +1,142 @@
+import crypto from 'node:crypto'
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getCacheDir } from '@main/utils/file'
+import { FilterEngine, FilterRule, parseFilterList } from '@main/utils/filterList'
+import { ContentBlockingConfig, ContentBlockingStatus, FilterListStatus } from '@types'
+import { session, webContents } from 'electron'
+import Logger from 'electron-log'
+import { isEqual } from 'lodash'
+
+import aoxisProxy from './AxiosProxy'
+
+// downloaded lists older than this are downloaded again when they are loaded
+const LIST_TTL = 4 * 24 * 60 * 60 * 1000
+const TIMEOUT = 30 * 1000
+const MAX_LIST_SIZE = 20 * 1024 * 1024
+
+/**
+ * Ad and tracker blocking for the webviews of mini apps.
+ *
+ * Requests of the webview sessions are checked against EasyList style filter lists, the lists are downloaded to
+ * `Cache/filter-lists` and refreshed every few days. Blocking is off until the renderer enables it, pages of the
+ * hosts the user turned it off for load everything.
+ */
+class ContentBlocker {
+  private config: ContentBlockingConfig = { enabled: false, lists: [], allowedHosts: [] }
+  private engine: FilterEngine | null = null
+  private lists: FilterListStatus[] = []
+  private loading: Promise<void> | null = null
+  private sessions = new WeakSet<Electron.Session>()
+  private blocked = 0
+
+  /**
+   * Check the requests of the partition's session, done once for each session
+   */
+  public attach(partition = 'persist:webview') {
+    const wvSession = session.fromPartition(partition)
+    if (this.sessions.has(wvSession)) return
+
+    this.sessions.add(wvSession)
+    wvSession.webRequest.onBeforeRequest((details, callback) => {
+      const cancel = this.shouldBlock(details)
+      if (cancel) this.blocked++
+      callback({ cancel })
+    })
+  }
+
+  public async configure(config: ContentBlockingConfig): Promise<ContentBlockingStatus> {
+    const listsChanged = !isEqual(config.lists, this.config.lists)
+    this.config = { ...config, allowedHosts: config.allowedHosts.map((host) => host.toLowerCase()) }
+
+    if (!config.enabled) {
+      this.engine = null
+      this.lists = []
+    } else if (listsChanged || !this.engine) {
+      await this.load(false)
+    }
+    return this.getStatus()
+  }
+
+  /**
+   * Download the lists again regardless of their age
+   */
+  public async update(): Promise<ContentBlockingStatus> {
+    if (this.config.enabled) await this.load(true)
+    return this.getStatus()
+  }
+
+  public getStatus(): ContentBlockingStatus {
+    return { enabled: this.config.enabled, lists: this.lists, blocked: this.blocked }
+  }
+
+  private shouldBlock(details: Electron.OnBeforeRequestListenerDetails): boolean {
+    if (!this.engine || details.resourceType === 'mainFrame') return false
+
+    const pageUrl = (details.webContentsId && webContents.fromId(details.webContentsId)?.getURL()) || details.referrer
+    if (this.isAllowed(pageUrl)) return false
+
+    return !!this.engine.match({ url: details.url, pageUrl, resourceType: details.resourceType })
+  }
+
+  private isAllowed(pageUrl: string): boolean {
+    if (!pageUrl || this.config.allowedHosts.length === 0) return false
+    try {
+      const host = new URL(pageUrl).hostname.toLowerCase()
+      return this.config.allowedHosts.some((allowed) => host === allowed || host.endsWith(`.${allowed}`))
+    } catch {
+      return false
+    }
+  }
+
+  // loads are serialized, a later configuration waits for the running load and loads its own lists
+  private async load(refresh: boolean) {
+    const previous = this.loading
+    const loading = (async () => {
+      await previous?.catch(() => undefined)
+      const urls = this.config.lists
+      const results = await Promise.all(urls.map((url) => this.loadList(url, refresh)))
+      if (!isEqual(urls, this.config.lists) || !this.config.enabled) return
+
+      this.lists = results.map((result) => result.status)
+      this.engine = new FilterEngine(results.flatMap((result) => result.rules))
+      Logger.info(`[ContentBlocker] Loaded ${this.engine.size} rules from ${urls.length} filter lists`)
+    })()
+    this.loading = loading
+    try {
+      await loading
+    } finally {
+      if (this.loading === loading) this.loading = null
+    }
+  }
+
+  private async loadList(url: string, refresh: boolean): Promise<{ rules: FilterRule[]; status: FilterListStatus }> {
+    const file = path.join(getCacheDir(), 'filter-lists', `${crypto.createHash('md5').update(url).digest('hex')}.txt`)
+    let stats = await fs.promises.stat(file).catch(() => null)
+    let error: string | undefined
+
+    if (refresh || !stats || Date.now() - stats.mtimeMs > LIST_TTL) {
+      try {
+        const response = await aoxisProxy.axios.get<string>(url, {
+          responseType: 'text',
+          timeout: TIMEOUT,
+          maxContentLength: MAX_LIST_SIZE
+        })
+        await fs.promises.mkdir(path.dirname(file), { recursive: true })
+        await fs.promises.writeFile(file, String(response.data))
+        stats = await fs.promises.stat(file)
+      } catch (e: any) {
+        // a stale copy is better than none
+        error = e.message
+        Logger.warn(`[ContentBlocker] Failed to download ${url}: ${error}`)
+      }
+    }
+
+    if (!stats) return { rules: [], status: { url, rules: 0, error } }
+    const rules = parseFilterList(await fs.promises.readFile(file, 'utf-8'))
+    return { rules, status: { url, rules: rules.length, updatedAt: stats.mtimeMs, error } }
+  }
+}
+
+export const contentBlocker = new ContentBlocker()
@@ -1,9 +1,14 @@
-import { Menu, MenuItemConstructorOptions } from 'electron'
+import { IpcChannel } from '@shared/IpcChannel'
+import { WebviewContextMenuAction } from '@types'
+import { clipboard, Menu, MenuItemConstructorOptions, shell, WebContents } from 'electron'
 
 import { locales } from '../utils/locales'
 import { configManager } from './ConfigManager'
 
 class ContextMenu {
+  // host actions enabled in the right-click menu of each webview
+  private webviewActions = new Map<number, WebviewContextMenuAction[]>()
+
   public contextMenu(w: Electron.BrowserWindow) {
     w.webContents.on('context-menu', (_event, properties) => {
       const template: MenuItemConstructorOptions[] = this.createEditMenuItems(properties)
@@ -15,6 +20,89 @@ class ContextMenu {
     })
   }
 
+  /**
+   * Right-click menu of a mini app webview with the edit items and the host actions enabled for the mini app.
+   * Selected host actions are sent to the window hosting the webview
+   */
+  public webviewContextMenu(webview: WebContents, actions: WebviewContextMenuAction[]) {
+    if (!this.webviewActions.has(webview.id)) {
+      webview.on('context-menu', (_event, properties) => {
+        const editItems = this.createEditMenuItems(properties).filter((item) => item.visible !== false)
+        const actionItems = this.createWebviewMenuItems(webview, properties, this.webviewActions.get(webview.id) ?? [])
+        const template: MenuItemConstructorOptions[] =
+          editItems.length > 0 && actionItems.length > 0
+            ? [...editItems, { type: 'separator' }, ...actionItems]
+            : [...editItems, ...actionItems]
+        if (template.length > 0) {
+          Menu.buildFromTemplate(template).popup()
+        }
+      })
+      webview.once('destroyed', () => this.webviewActions.delete(webview.id))
+    }
+    this.webviewActions.set(webview.id, actions)
+  }
+
+  private createWebviewMenuItems(
+    webview: WebContents,
+    properties: Electron.ContextMenuParams,
+    actions: WebviewContextMenuAction[]
+  ): MenuItemConstructorOptions[] {
+    const locale = locales[configManager.getLanguage()]
+    const { webview: labels } = locale.translation.minapp
+    const hasText = properties.selectionText.trim().length > 0
+    const isImage = properties.mediaType === 'image' && !!properties.srcURL
+    const externalURL = properties.linkURL || properties.pageURL
+
+    const emit = (action: WebviewContextMenuAction) => {
+      webview.hostWebContents?.send(IpcChannel.Webview_ContextMenuAction, {
+        webviewId: webview.id,
+        action,
+        selectionText: properties.selectionText,
+        linkURL: properties.linkURL,
+        srcURL: properties.srcURL,
+        pageURL: properties.pageURL
+      })
+    }
+
+    const items: Record<WebviewContextMenuAction, MenuItemConstructorOptions> = {
+      ask: {
+        id: 'ask',
+        label: labels.ask,
+        visible: hasText,
+        click: () => emit('ask')
+      },
+      save_image: {
+        id: 'save_image',
+        label: labels.save_image,
+        visible: isImage,
+        click: () => {
+          webview.downloadURL(properties.srcURL)
+          emit('save_image')
+        }
+      },
+      copy_link: {
+        id: 'copy_link',
+        label: labels.copy_link,
+        visible: !!properties.linkURL,
+        click: () => {
+          clipboard.writeText(properties.linkURL)
+          emit('copy_link')
+        }
+      },
+      open_external: {
+        id: 'open_external',
+        label: labels.open_external,
+        visible: /^https?:\/\//.test(externalURL),
+        click: () => {
+          shell.openExternal(externalURL)
+          emit('open_external')
+        }
+      }
+    }
+
+    return actions.map((action) => items[action]).filter((item) => item.visible !== false)
+  }
+
   private createInspectMenuItems(w: Electron.BrowserWindow): MenuItemConstructorOptions[] {
     const locale = locales[configManager.getLanguage()]
     const { common } = locale.translation
@@ -0,0 +1,224 @@
+import fs from 'node:fs'
+import path from 'node:path'
+import { promisify } from 'node:util'
+import zlib from 'node:zlib'
+
+import { getDataPath } from '@main/utils'
+import { ManagerError } from '@shared/ManagerError'
+import Logger from 'electron-log'
+
+const gzip = promisify(zlib.gzip)
+const gunzip = promisify(zlib.gunzip)
+
+// an operation running longer than this is considered stuck
+const STALL_TIMEOUT = 2 * 60 * 1000
+
+export interface ArchivedConversation {
+  // topic metadata without messages
+  topic: { id: string; assistantId: string; name: string; createdAt: string; updatedAt: string; [key: string]: any }
+  messages: any[]
+  blocks: any[]
+  archivedAt: number
+}
+
+export interface ArchiveIndexEntry {
+  topicId: string
+  assistantId: string
+  name: string
+  // archive file the conversation lives in, e.g. 2025-03
+  month: string
+  messageCount: number
+  createdAt: string
+  updatedAt: string
+  archivedAt: number
+}
+
+/**
+ * Cold storage for old conversations.
+ *
+ * Archived conversations are removed from the renderer database and grouped by the month of
+ * their last update into gzip compressed files (`Data/Archives/2025-03.json.gz`). A small
+ * index keeps the metadata so archived conversations can be listed without decompressing them.
+ */
+class ConversationArchive {
+  private archiveDir = path.join(getDataPath(), 'Archives')
+  private indexFile = path.join(this.archiveDir, 'index.json')
+  // archive files are read-modify-write, so operations run one at a time
+  private queue: Promise<unknown> = Promise.resolve()
+  private busySince: number | null = null
+  // bumped by reset, operations of an older generation may no longer write
+  private generation = 0
+  // aborted by reset, cancels the operations waiting behind the stuck one
+  private abortController = new AbortController()
+
+  public list(): Promise<ArchiveIndexEntry[]> {
+    return this.enqueue(async () => {
+      const index = await this.readIndex()
+      return index.sort((a, b) => b.updatedAt.localeCompare(a.updatedAt))
+    })
+  }
+
+  public archive(conversation: ArchivedConversation): Promise<ArchiveIndexEntry> {
+    return this.enqueue(async (generation) => {
+      const { topic } = conversation
+      const month = (topic.updatedAt || topic.createdAt || new Date().toISOString()).slice(0, 7)
+
+      const archive = await this.readArchive(month)
+      archive[topic.id] = conversation
+      await this.writeArchive(generation, month, archive)
+
+      const entry: ArchiveIndexEntry = {
+        topicId: topic.id,
+        assistantId: topic.assistantId,
+        name: topic.name,
+        month,
+        messageCount: conversation.messages.length,
+        createdAt: topic.createdAt,
+        updatedAt: topic.updatedAt,
+        archivedAt: conversation.archivedAt
+      }
+      const index = (await this.readIndex()).filter((item) => item.topicId !== topic.id)
+      await this.writeIndex(generation, [...index, entry])
+
+      Logger.info(`[ConversationArchive] Archived topic ${topic.id} to ${month}`)
+      return entry
+    })
+  }
+
+  public get(topicId: string): Promise<ArchivedConversation> {
+    return this.enqueue(async () => {
+      const entry = await this.getEntry(topicId)
+      const conversation = (await this.readArchive(entry.month))[topicId]
+      if (!conversation) {
+        throw new ManagerError(
+          'ConversationArchive',
+          'not_found',
+          `Topic ${topicId} is missing from archive ${entry.month}`
+        )
+      }
+      return conversation
+    })
+  }
+
+  /**
+   * Drop a conversation from the archive, called once the renderer has restored it
+   */
+  public remove(topicId: string): Promise<void> {
+    return this.enqueue(async (generation) => {
+      const entry = await this.getEntry(topicId)
+      const archive = await this.readArchive(entry.month)
+      delete archive[topicId]
+      await this.writeArchive(generation, entry.month, archive)
+
+      const index = await this.readIndex()
+      await this.writeIndex(generation, index.filter((item) => item.topicId !== topicId))
+      Logger.info(`[ConversationArchive] Removed topic ${topicId} from ${entry.month}`)
+    })
+  }
+
+  private async getEntry(topicId: string): Promise<ArchiveIndexEntry> {
+    const entry = (await this.readIndex()).find((item) => item.topicId === topicId)
+    if (!entry) {
+      throw new ManagerError('ConversationArchive', 'not_found', `Topic ${topicId} is not archived`)
+    }
+    return entry
+  }
+
+  /**
+   * Whether the current operation has been running for too long and blocks the queue
+   */
+  public isStalled(): boolean {
+    return this.busySince !== null && Date.now() - this.busySince > STALL_TIMEOUT
+  }
+
+  /**
+   * Drop the stuck operation so new operations can run, the operations queued behind it are cancelled.
+   * The dropped operation may still be running but fails at its next write, so it can't overwrite newer data.
+   */
+  public reset() {
+    Logger.warn('[ConversationArchive] Resetting stalled operation queue')
+    this.generation++
+    this.abortController.abort()
+    this.abortController = new AbortController()
+    this.queue = Promise.resolve()
+    this.busySince = null
+  }
+
+  private enqueue<T>(task: (generation: number) => Promise<T>): Promise<T> {
+    const generation = this.generation
+    const { signal } = this.abortController
+    const previous = this.queue
+    const turn = new Promise<void>((resolve, reject) => {
+      const onAbort = () => reject(new ManagerError('ConversationArchive', 'cancelled', 'The archive queue was reset'))
+      signal.addEventListener('abort', onAbort, { once: true })
+      previous.then(() => {
+        signal.removeEventListener('abort', onAbort)
+        resolve()
+      })
+    })
+    const result = turn.then(async () => {
+      this.busySince = Date.now()
+      try {
+        return await task(generation)
+      } finally {
+        if (generation === this.generation) this.busySince = null
+      }
+    })
+    this.queue = result.catch(() => undefined)
+    return result
+  }
+
+  private assertCurrent(generation: number) {
+    if (generation !== this.generation) {
+      throw new ManagerError('ConversationArchive', 'cancelled', 'The operation was dropped by a queue reset')
+    }
+  }
+
+  private getArchiveFile(month: string) {
+    return path.join(this.archiveDir, `${month}.json.gz`)
+  }
+
+  private async readArchive(month: string): Promise<Record<string, ArchivedConversation>> {
+    const file = this.getArchiveFile(month)
+    if (!fs.existsSync(file)) return {}
+    const data = await gunzip(await fs.promises.readFile(file))
+    return JSON.parse(data.toString('utf-8'))
+  }
+
+  private async writeArchive(generation: number, month: string, archive: Record<string, ArchivedConversation>) {
+    const file = this.getArchiveFile(month)
+    if (Object.keys(archive).length === 0) {
+      this.assertCurrent(generation)
+      await fs.promises.rm(file, { force: true })
+      return
+    }
+    await this.writeAtomic(generation, file, await gzip(JSON.stringify(archive)))
+  }
+
+  private async readIndex(): Promise<ArchiveIndexEntry[]> {
+    if (!fs.existsSync(this.indexFile)) return []
+    return JSON.parse(await fs.promises.readFile(this.indexFile, 'utf-8'))
+  }
+
+  private async writeIndex(generation: number, index: ArchiveIndexEntry[]) {
+    await this.writeAtomic(generation, this.indexFile, JSON.stringify(index))
+  }
+
+  /**
+   * The temp file is per generation so a dropped operation never writes into the one of a newer operation
+   */
+  private async writeAtomic(generation: number, file: string, data: string | Buffer) {
+    await fs.promises.mkdir(this.archiveDir, { recursive: true })
+    const tempFile = `${file}.${generation}.tmp`
+    await fs.promises.writeFile(tempFile, data)
+    try {
+      this.assertCurrent(generation)
+    } catch (error) {
+      await fs.promises.rm(tempFile, { force: true })
+      throw error
+    }
+    await fs.promises.rename(tempFile, file)
+  }
+}
+
+export const conversationArchive = new ConversationArchive()
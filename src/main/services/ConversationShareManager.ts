@@ -0,0 +1,104 @@
+import crypto from 'node:crypto'
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { renderConversationHtml } from '@main/utils/shareHtml'
+import { ManagerError } from '@shared/ManagerError'
+import { ShareConversationOptions, SharedConversation, SharedConversationMessage } from '@types'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import { configManager } from './ConfigManager'
+
+export const SHARE_PATH_PREFIX = '/share/'
+
+const DEFAULT_TTL = 60
+const MAX_TTL = 7 * 24 * 60
+
+interface ServedShare {
+  share: SharedConversation
+  timer: NodeJS.Timeout
+}
+
+/**
+ * Shares conversations as self-contained HTML files stored in `Data/Shares`.
+ * A shared file can also be served on the API server at `/share/<token>` until the link expires,
+ * the random token replaces the bearer token so the link can be opened in any browser.
+ */
+class ConversationShareManager {
+  private dir = path.join(getDataPath(), 'Shares')
+  // served shares by link token, links don't survive a restart
+  private served = new Map<string, ServedShare>()
+
+  public async share(
+    topicId: string,
+    title: string,
+    messages: SharedConversationMessage[],
+    options: ShareConversationOptions = {}
+  ): Promise<SharedConversation> {
+    if (!messages.length) {
+      throw new ManagerError('ConversationShareManager', 'invalid_argument', 'Conversation has no messages')
+    }
+    const ttl = options.ttl ?? DEFAULT_TTL
+    if (options.serve) {
+      this.validateLink(ttl)
+    }
+
+    const id = uuidv4()
+    const filePath = path.join(this.dir, `${id}.html`)
+    await fs.promises.mkdir(this.dir, { recursive: true })
+    await fs.promises.writeFile(filePath, renderConversationHtml(title, messages, options.redactNames), 'utf-8')
+
+    const share: SharedConversation = { id, topicId, filePath, createdAt: Date.now() }
+    if (options.serve) {
+      this.serve(share, ttl)
+    }
+    Logger.info(`[ConversationShareManager] Shared conversation ${topicId} as ${id}${share.url ? ' with a link' : ''}`)
+    return share
+  }
+
+  /**
+   * Active links, newest first
+   */
+  public list(): SharedConversation[] {
+    return [...this.served.values()].map(({ share }) => share).sort((a, b) => b.createdAt - a.createdAt)
+  }
+
+  public revoke(id: string) {
+    for (const [token, served] of this.served) {
+      if (served.share.id === id) {
+        clearTimeout(served.timer)
+        this.served.delete(token)
+      }
+    }
+  }
+
+  /**
+   * File of a served share, undefined when the token is unknown or expired
+   */
+  public resolve(token: string): string | undefined {
+    const served = this.served.get(token)
+    if (!served || (served.share.expiresAt ?? 0) < Date.now()) return undefined
+    return served.share.filePath
+  }
+
+  private validateLink(ttl: number) {
+    if (!configManager.getApiServerEnabled()) {
+      throw new ManagerError('ConversationShareManager', 'unavailable', 'Enable the API server to share links')
+    }
+    if (!Number.isInteger(ttl) || ttl <= 0 || ttl > MAX_TTL) {
+      throw new ManagerError('ConversationShareManager', 'invalid_argument', `Invalid link lifetime: ${ttl}`)
+    }
+  }
+
+  private serve(share: SharedConversation, ttl: number) {
+    const token = crypto.randomBytes(24).toString('base64url')
+    share.expiresAt = Date.now() + ttl * 60 * 1000
+    share.url = `http://127.0.0.1:${configManager.getApiServerPort()}${SHARE_PATH_PREFIX}${token}`
+    const timer = setTimeout(() => this.served.delete(token), ttl * 60 * 1000)
+    this.served.set(token, { share, timer })
+  }
+}
+
+export const conversationShareManager = new ConversationShareManager()
@@ -0,0 +1,228 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { isInsideDirectory } from '@main/utils/audit'
+import { applyParsedPatch, getPatchPath, parseSinglePatch } from '@main/utils/patch'
+import { ManagerError } from '@shared/ManagerError'
+import { ConversationWorkspace, PatchResult, WorkspaceFileChange } from '@types'
+import { createTwoFilesPatch } from 'diff'
+import Logger from 'electron-log'
+
+import { configManager } from './ConfigManager'
+
+// larger files are tracked as changed or not, without a diff
+const MAX_DIFF_SIZE = 1024 * 1024
+
+interface TrackedFile {
+  existed: boolean
+  // original text, missing for binary or large files
+  content?: string
+  size?: number
+  mtimeMs?: number
+}
+
+/**
+ * Folders mounted to conversations.
+ *
+ * The renderer gives the tools of a conversation a `@cherry/workspace` filesystem server rooted at the mounted
+ * folder, so they cannot reach files outside it. Before the server modifies a file its original state is
+ * recorded in `Data/ConversationWorkspaces/<topicId>.json`, which gives the list of changed files with their diffs.
+ * Unmounting, e.g. when the conversation is archived, drops the record; the folder itself is left untouched.
+ *
+ * Patches proposed in the conversation are applied here too, limited to the mounted folder and tracked the same way.
+ */
+class ConversationWorkspaceManager {
+  private trackDir = path.join(getDataPath(), 'ConversationWorkspaces')
+  private tracked = new Map<string, Record<string, TrackedFile>>()
+
+  public list(): ConversationWorkspace[] {
+    return configManager.getConversationWorkspaces()
+  }
+
+  public get(topicId: string): ConversationWorkspace | null {
+    return this.list().find((workspace) => workspace.topicId === topicId) ?? null
+  }
+
+  public async mount(topicId: string, dirPath: string): Promise<ConversationWorkspace> {
+    if (!topicId) {
+      throw new ManagerError('ConversationWorkspaceManager', 'invalid_argument', 'Conversation is required')
+    }
+
+    const root = path.resolve(dirPath)
+    const stat = await fs.promises.stat(root).catch(() => null)
+    if (!stat) {
+      throw new ManagerError('ConversationWorkspaceManager', 'not_found', `Folder not found: ${root}`)
+    }
+    if (!stat.isDirectory()) {
+      throw new ManagerError('ConversationWorkspaceManager', 'invalid_argument', `Not a folder: ${root}`)
+    }
+    if (isInsideDirectory(root, getDataPath())) {
+      throw new ManagerError('ConversationWorkspaceManager', 'permission_denied', 'Cannot mount the app data folder')
+    }
+
+    // mounting another folder starts a new change history
+    if (this.get(topicId)) await this.unmount(topicId)
+
+    const workspace: ConversationWorkspace = { topicId, path: await fs.promises.realpath(root), mountedAt: Date.now() }
+    configManager.setConversationWorkspaces([...this.list(), workspace])
+    Logger.info(`[ConversationWorkspaceManager] Mounted ${workspace.path} to ${topicId}`)
+    return workspace
+  }
+
+  public async unmount(topicId: string) {
+    const workspaces = this.list()
+    if (!workspaces.some((workspace) => workspace.topicId === topicId)) return
+
+    configManager.setConversationWorkspaces(workspaces.filter((workspace) => workspace.topicId !== topicId))
+    this.tracked.delete(topicId)
+    await fs.promises.rm(this.getTrackFile(topicId), { force: true })
+    Logger.info(`[ConversationWorkspaceManager] Unmounted workspace of ${topicId}`)
+  }
+
+  /**
+   * Record the original state of a file before the workspace server modifies it, only the first call counts
+   */
+  public async track(topicId: string, filePath: string) {
+    const workspace = this.get(topicId)
+    if (!workspace || !isInsideDirectory(filePath, workspace.path)) return
+
+    const files = await this.loadTracked(topicId)
+    const relative = path.relative(workspace.path, filePath)
+    if (files[relative]) return
+
+    files[relative] = await this.readState(filePath)
+    await fs.promises.mkdir(this.trackDir, { recursive: true })
+    await fs.promises.writeFile(this.getTrackFile(topicId), JSON.stringify(files))
+  }
+
+  public async getChanges(topicId: string): Promise<WorkspaceFileChange[]> {
+    const workspace = this.get(topicId)
+    if (!workspace) {
+      throw new ManagerError('ConversationWorkspaceManager', 'not_found', `No folder mounted to ${topicId}`)
+    }
+
+    const changes: WorkspaceFileChange[] = []
+    for (const [relative, original] of Object.entries(await this.loadTracked(topicId))) {
+      const current = await this.readState(path.join(workspace.path, relative))
+
+      if (!original.existed && !current.existed) continue
+      if (!current.existed) {
+        changes.push({ path: relative, status: 'deleted', diff: this.diff(relative, original.content, '') })
+        continue
+      }
+      if (!original.existed) {
+        changes.push({ path: relative, status: 'added', diff: this.diff(relative, '', current.content) })
+        continue
+      }
+
+      const same =
+        original.content !== undefined && current.content !== undefined
+          ? original.content === current.content
+          : original.size === current.size && original.mtimeMs === current.mtimeMs
+      if (!same) {
+        const diff = this.diff(relative, original.content, current.content)
+        changes.push({ path: relative, status: 'modified', diff })
+      }
+    }
+    return changes.sort((a, b) => a.path.localeCompare(b.path))
+  }
+
+  /**
+   * Apply a unified diff to a file of the workspace, the path defaults to the one in the patch headers.
+   * A dry run checks the patch applies cleanly without touching the file.
+   */
+  public async applyPatch(topicId: string, filePath: string | undefined, unifiedDiff: string, dryRun = false) {
+    const workspace = this.get(topicId)
+    if (!workspace) {
+      throw new ManagerError('ConversationWorkspaceManager', 'not_found', `No folder mounted to ${topicId}`)
+    }
+
+    const patch = parseSinglePatch(unifiedDiff)
+    const target = filePath || getPatchPath(patch)
+    if (!target) {
+      throw new ManagerError('ConversationWorkspaceManager', 'invalid_argument', 'The patch has no file name')
+    }
+
+    const absolute = await this.resolveInside(workspace, target)
+    const current = await this.readState(absolute)
+    if (current.existed && current.content === undefined) {
+      throw new ManagerError('ConversationWorkspaceManager', 'invalid_argument', 'Cannot patch binary or large files')
+    }
+
+    const patched = applyParsedPatch(current.existed ? current.content! : null, patch)
+    const result: PatchResult = {
+      path: path.relative(workspace.path, absolute),
+      status: patched.status,
+      additions: patched.additions,
+      deletions: patched.deletions,
+      applied: !dryRun
+    }
+    if (dryRun) return result
+
+    await this.track(topicId, absolute)
+    if (patched.content === null) {
+      await fs.promises.rm(absolute)
+    } else {
+      await fs.promises.mkdir(path.dirname(absolute), { recursive: true })
+      await fs.promises.writeFile(absolute, patched.content)
+    }
+    Logger.info(`[ConversationWorkspaceManager] Patched ${result.path} in the workspace of ${topicId}`)
+    return result
+  }
+
+  /**
+   * Resolve a path relative to the workspace, refusing paths that leave it directly or through symlinks
+   */
+  private async resolveInside(workspace: ConversationWorkspace, filePath: string) {
+    const absolute = path.resolve(workspace.path, filePath)
+    if (!isInsideDirectory(absolute, workspace.path)) {
+      throw new ManagerError('ConversationWorkspaceManager', 'permission_denied', `Outside the workspace: ${filePath}`)
+    }
+
+    // the closest existing ancestor must still be inside once symlinks are resolved
+    let existing = absolute
+    while (!fs.existsSync(existing) && existing !== workspace.path) {
+      existing = path.dirname(existing)
+    }
+    if (!isInsideDirectory(await fs.promises.realpath(existing), workspace.path)) {
+      throw new ManagerError('ConversationWorkspaceManager', 'permission_denied', `Outside the workspace: ${filePath}`)
+    }
+    return absolute
+  }
+
+  private diff(relative: string, before?: string, after?: string) {
+    if (before === undefined || after === undefined) return ''
+    return createTwoFilesPatch(relative, relative, before, after, 'original', 'modified')
+  }
+
+  private async readState(filePath: string): Promise<TrackedFile> {
+    const stat = await fs.promises.stat(filePath).catch(() => null)
+    if (!stat?.isFile()) return { existed: false }
+
+    const state: TrackedFile = { existed: true, size: stat.size, mtimeMs: stat.mtimeMs }
+    if (stat.size <= MAX_DIFF_SIZE) {
+      const buffer = await fs.promises.readFile(filePath)
+      if (!buffer.subarray(0, 8000).includes(0)) state.content = buffer.toString('utf-8')
+    }
+    return state
+  }
+
+  private async loadTracked(topicId: string): Promise<Record<string, TrackedFile>> {
+    let files = this.tracked.get(topicId)
+    if (!files) {
+      files = await fs.promises
+        .readFile(this.getTrackFile(topicId), 'utf-8')
+        .then((data) => JSON.parse(data))
+        .catch(() => ({}))
+      this.tracked.set(topicId, files!)
+    }
+    return files!
+  }
+
+  private getTrackFile(topicId: string) {
+    return path.join(this.trackDir, `${encodeURIComponent(topicId)}.json`)
+  }
+}
+
+export const conversationWorkspaceManager = new ConversationWorkspaceManager()
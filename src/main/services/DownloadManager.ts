@@ -0,0 +1,555 @@
+import { EventEmitter } from 'node:events'
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import { BrowserWindow, DownloadItem } from 'electron'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import { configManager } from './ConfigManager'
+import { workerPool } from './WorkerPool'
+
+export type DownloadStatus = 'queued' | 'downloading' | 'paused' | 'completed' | 'failed' | 'cancelled'
+
+export interface DownloadOptions {
+  headers?: Record<string, string>
+  // expected digest of the finished file, hex encoded
+  checksum?: { algorithm: 'md5' | 'sha1' | 'sha256' | 'sha512'; value: string }
+  // bytes per second, 0 means unlimited
+  speedLimit?: number
+  // number of parallel range requests, only used when the server supports ranges
+  segments?: number
+  overwrite?: boolean
+  // free-form tag so callers (model pulls, mini-app installs, webviews) can find their tasks
+  source?: string
+}
+
+interface DownloadSegment {
+  start: number
+  // inclusive, -1 when the total size is unknown
+  end: number
+  received: number
+}
+
+export interface DownloadTask {
+  id: string
+  url: string
+  dest: string
+  options: DownloadOptions
+  status: DownloadStatus
+  totalBytes: number
+  receivedBytes: number
+  resumable: boolean
+  segments: DownloadSegment[]
+  speed: number
+  error?: string
+  createdAt: number
+  updatedAt: number
+}
+
+const MAX_CONCURRENT_DOWNLOADS = 3
+const PROGRESS_INTERVAL = 500
+
+// tag of the downloads started by web pages, e.g. a file link clicked in a mini app
+export const WEBVIEW_DOWNLOAD_SOURCE = 'webview'
+
+class DownloadManager extends EventEmitter {
+  private tasks: Map<string, DownloadTask> = new Map()
+  private controllers: Map<string, AbortController> = new Map()
+  // part files being written, closed before a cancelled task removes them
+  private writers: Map<string, Set<fs.WriteStream>> = new Map()
+  // downloads done by chromium, started by a page instead of a url passed to startDownload
+  private items: Map<string, DownloadItem> = new Map()
+  private queueFile: string
+  private lastBroadcast = 0
+
+  constructor() {
+    super()
+    this.queueFile = path.join(getDataPath(), 'downloads.json')
+    this.loadQueue()
+  }
+
+  /**
+   * Resume the downloads that were running when the app quit
+   */
+  public init() {
+    this.processQueue()
+  }
+
+  public startDownload(url: string, dest: string, options: DownloadOptions = {}): DownloadTask {
+    if (fs.existsSync(dest) && !options.overwrite) {
+      throw new ManagerError('DownloadManager', 'already_exists', `Destination already exists: ${dest}`)
+    }
+
+    const now = Date.now()
+    const task: DownloadTask = {
+      id: uuidv4(),
+      url,
+      dest,
+      options,
+      status: 'queued',
+      totalBytes: 0,
+      receivedBytes: 0,
+      resumable: false,
+      segments: [],
+      speed: 0,
+      createdAt: now,
+      updatedAt: now
+    }
+
+    this.tasks.set(task.id, task)
+    this.saveQueue()
+    this.processQueue()
+    return task
+  }
+
+  /**
+   * Take over a download started by a page, it is saved to the download directory under a free name
+   * and reported, paused and cancelled like any other task
+   */
+  public track(item: DownloadItem, source = WEBVIEW_DOWNLOAD_SOURCE): DownloadTask {
+    const now = Date.now()
+    const task: DownloadTask = {
+      id: uuidv4(),
+      url: item.getURL(),
+      dest: this.getFreePath(configManager.getDownloadDirectory(), item.getFilename()),
+      options: { source },
+      status: 'downloading',
+      totalBytes: item.getTotalBytes(),
+      receivedBytes: 0,
+      resumable: item.canResume(),
+      segments: [],
+      speed: 0,
+      createdAt: now,
+      updatedAt: now
+    }
+    item.setSavePath(task.dest)
+    this.tasks.set(task.id, task)
+    this.items.set(task.id, item)
+
+    item.on('updated', (_, state) => {
+      task.totalBytes = item.getTotalBytes()
+      task.receivedBytes = item.getReceivedBytes()
+      task.resumable = item.canResume()
+      task.speed = item.getCurrentBytesPerSecond()
+      if (state === 'interrupted') {
+        task.error = 'Download interrupted'
+        this.updateStatus(task, 'failed')
+      } else if (item.isPaused() !== (task.status === 'paused')) {
+        this.updateStatus(task, item.isPaused() ? 'paused' : 'downloading')
+      } else {
+        this.reportProgress(task)
+      }
+    })
+    item.once('done', (_, state) => {
+      this.items.delete(task.id)
+      task.receivedBytes = item.getReceivedBytes()
+      if (state === 'completed') {
+        this.updateStatus(task, 'completed')
+      } else if (state === 'cancelled') {
+        if (task.status !== 'cancelled') this.updateStatus(task, 'cancelled')
+      } else {
+        task.error = 'Download interrupted'
+        this.updateStatus(task, 'failed')
+      }
+    })
+
+    Logger.info(`[DownloadManager] Saving ${task.url} to ${task.dest}`)
+    this.updateStatus(task, 'downloading')
+    return task
+  }
+
+  public getDirectory(): string {
+    return configManager.getDownloadDirectory()
+  }
+
+  public async setDirectory(directory: string): Promise<string> {
+    if (!path.isAbsolute(directory)) {
+      throw new ManagerError('DownloadManager', 'invalid_argument', `Path must be absolute: ${directory}`)
+    }
+    const stat = await fs.promises.stat(directory).catch(() => null)
+    if (!stat?.isDirectory()) {
+      throw new ManagerError('DownloadManager', 'not_found', `Directory not found: ${directory}`)
+    }
+    configManager.setDownloadDirectory(directory)
+    return directory
+  }
+
+  public pause(id: string) {
+    const task = this.tasks.get(id)
+    if (!task || (task.status !== 'downloading' && task.status !== 'queued')) return
+
+    const item = this.items.get(id)
+    if (item) {
+      item.pause()
+      return
+    }
+
+    this.updateStatus(task, 'paused')
+    this.controllers.get(id)?.abort()
+  }
+
+  public resume(id: string) {
+    const task = this.tasks.get(id)
+    if (!task || (task.status !== 'paused' && task.status !== 'failed')) return
+
+    task.error = undefined
+    // a page download that can't be resumed by chromium, e.g. after a restart, is fetched again from its url
+    const item = this.items.get(id)
+    if (item?.canResume()) {
+      item.resume()
+      return
+    }
+
+    this.items.delete(id)
+    item?.cancel()
+    this.updateStatus(task, 'queued')
+    this.processQueue()
+  }
+
+  public async cancel(id: string) {
+    const task = this.tasks.get(id)
+    if (!task) return
+
+    this.updateStatus(task, 'cancelled')
+    this.controllers.get(id)?.abort()
+    this.items.get(id)?.cancel()
+    // an open stream would write the part file again after it was removed
+    await this.closeWriters(id)
+    this.removePartFiles(task)
+  }
+
+  /**
+   * Forget a finished task. Running tasks must be cancelled first.
+   */
+  public remove(id: string) {
+    const task = this.tasks.get(id)
+    if (!task || task.status === 'downloading') return
+
+    this.removePartFiles(task)
+    this.tasks.delete(id)
+    this.saveQueue()
+  }
+
+  public getTask(id: string): DownloadTask | undefined {
+    return this.tasks.get(id)
+  }
+
+  public listTasks(source?: string): DownloadTask[] {
+    const tasks = [...this.tasks.values()]
+    return source ? tasks.filter((task) => task.options.source === source) : tasks
+  }
+
+  /**
+   * Resolve with the destination path once the task completes
+   */
+  public waitFor(id: string): Promise<string> {
+    return new Promise((resolve, reject) => {
+      const check = (task: DownloadTask) => {
+        if (task.id !== id) return false
+        if (task.status === 'completed') resolve(task.dest)
+        else if (task.status === 'failed' || task.status === 'cancelled') {
+          const code = task.status === 'cancelled' ? 'cancelled' : 'unavailable'
+          reject(new ManagerError('DownloadManager', code, task.error || `Download ${task.status}`))
+        } else return false
+        this.off('status', check)
+        return true
+      }
+
+      const task = this.tasks.get(id)
+      if (!task) return reject(new ManagerError('DownloadManager', 'not_found', `Download not found: ${id}`))
+      if (!check(task)) this.on('status', check)
+    })
+  }
+
+  private processQueue() {
+    const running = this.listTasks().filter((task) => task.status === 'downloading' && !this.items.has(task.id)).length
+    const queued = this.listTasks()
+      .filter((task) => task.status === 'queued')
+      .sort((a, b) => a.createdAt - b.createdAt)
+
+    queued.slice(0, Math.max(0, MAX_CONCURRENT_DOWNLOADS - running)).forEach((task) => this.run(task))
+  }
+
+  private async run(task: DownloadTask) {
+    const controller = new AbortController()
+    this.controllers.set(task.id, controller)
+    this.updateStatus(task, 'downloading')
+
+    try {
+      if (task.segments.length === 0) {
+        await this.prepare(task)
+      }
+
+      if (!task.resumable) {
+        // servers without range support always start over
+        task.receivedBytes = 0
+        task.segments.forEach((segment) => (segment.received = 0))
+      }
+
+      const limiter = this.createLimiter(task)
+      await Promise.all(
+        task.segments.map((segment, index) => this.downloadSegment(task, segment, index, controller.signal, limiter))
+      )
+
+      await this.finalize(task)
+      this.updateStatus(task, 'completed')
+    } catch (error: any) {
+      if (controller.signal.aborted) {
+        // paused or cancelled, status has already been updated
+        this.saveQueue()
+      } else {
+        Logger.error(`[DownloadManager] Download failed: ${task.url}`, error)
+        task.error = error.message
+        this.updateStatus(task, 'failed')
+      }
+    } finally {
+      this.controllers.delete(task.id)
+      this.processQueue()
+    }
+  }
+
+  private async prepare(task: DownloadTask) {
+    let totalBytes = 0
+    let acceptRanges = false
+
+    try {
+      const response = await fetch(task.url, { method: 'HEAD', headers: task.options.headers })
+      totalBytes = Number(response.headers.get('content-length')) || 0
+      acceptRanges = response.headers.get('accept-ranges') === 'bytes'
+    } catch (error) {
+      Logger.warn(`[DownloadManager] HEAD request failed, falling back to a single stream: ${task.url}`)
+    }
+
+    task.totalBytes = totalBytes
+    task.resumable = acceptRanges && totalBytes > 0
+
+    const count = task.resumable ? Math.max(1, Math.min(task.options.segments ?? 1, 16)) : 1
+    if (!task.resumable) {
+      task.segments = [{ start: 0, end: -1, received: 0 }]
+      return
+    }
+
+    // files smaller than the segment count get fewer segments, every segment has at least one byte
+    const size = Math.ceil(totalBytes / count)
+    task.segments = Array.from({ length: Math.ceil(totalBytes / size) }, (_, i) => ({
+      start: i * size,
+      end: Math.min(totalBytes, (i + 1) * size) - 1,
+      received: 0
+    })).filter((segment) => segment.start <= segment.end)
+  }
+
+  private async downloadSegment(
+    task: DownloadTask,
+    segment: DownloadSegment,
+    index: number,
+    signal: AbortSignal,
+    limiter: (bytes: number) => Promise<void>
+  ) {
+    if (segment.end >= 0 && segment.start + segment.received > segment.end) return
+
+    const partFile = this.getPartFile(task, index)
+    const headers: Record<string, string> = { ...task.options.headers }
+    if (task.resumable) {
+      headers['Range'] = `bytes=${segment.start + segment.received}-${segment.end}`
+    }
+
+    const response = await fetch(task.url, { headers, signal })
+    if (!response.ok || !response.body) {
+      throw new ManagerError('DownloadManager', 'unavailable', `HTTP error! status: ${response.status}`)
+    }
+    if (task.resumable && response.status !== 206) {
+      throw new ManagerError('DownloadManager', 'unavailable', 'Server ignored the range request')
+    }
+
+    const writer = fs.createWriteStream(partFile, { flags: task.resumable ? 'a' : 'w' })
+    const reader = response.body.getReader()
+    const writers = this.writers.get(task.id) ?? new Set()
+    this.writers.set(task.id, writers.add(writer))
+
+    try {
+      for (;;) {
+        const { done, value } = await reader.read()
+        if (done) break
+
+        if (!writer.write(value)) {
+          // a cancel destroys the writer instead of draining it
+          await new Promise((resolve) => writer.once('drain', resolve).once('close', resolve))
+          signal.throwIfAborted()
+        }
+        segment.received += value.length
+        task.receivedBytes += value.length
+        this.reportProgress(task)
+        await limiter(value.length)
+      }
+    } finally {
+      writers.delete(writer)
+      if (writers.size === 0 && this.writers.get(task.id) === writers) this.writers.delete(task.id)
+      if (!writer.destroyed) await new Promise((resolve) => writer.end(resolve))
+    }
+  }
+
+  private async closeWriters(id: string) {
+    const writers = [...(this.writers.get(id) ?? [])]
+    this.writers.delete(id)
+    await Promise.all(
+      writers.map(
+        (writer) =>
+          new Promise<void>((resolve) => {
+            if (writer.closed) return resolve()
+            writer.once('close', () => resolve())
+            writer.destroy()
+          })
+      )
+    )
+  }
+
+  /**
+   * Shared limiter for all the segments of a task
+   */
+  private createLimiter(task: DownloadTask) {
+    const startedAt = Date.now()
+    const startBytes = task.receivedBytes
+    let transferred = 0
+
+    return async (bytes: number) => {
+      transferred += bytes
+      const elapsed = (Date.now() - startedAt) / 1000
+      task.speed = elapsed > 0 ? Math.round(transferred / elapsed) : 0
+
+      const limit = task.options.speedLimit ?? 0
+      if (limit <= 0) return
+
+      const expected = ((task.receivedBytes - startBytes) / limit) * 1000
+      const delay = expected - (Date.now() - startedAt)
+      if (delay > 0) {
+        await new Promise((resolve) => setTimeout(resolve, delay))
+      }
+    }
+  }
+
+  private async finalize(task: DownloadTask) {
+    await fs.promises.mkdir(path.dirname(task.dest), { recursive: true })
+
+    const tmpFile = `${task.dest}.download`
+    const output = fs.createWriteStream(tmpFile)
+    for (let i = 0; i < task.segments.length; i++) {
+      await new Promise<void>((resolve, reject) => {
+        const input = fs.createReadStream(this.getPartFile(task, i))
+        input.on('error', reject)
+        input.on('end', () => resolve())
+        input.pipe(output, { end: false })
+      })
+    }
+    await new Promise((resolve) => output.end(resolve))
+
+    const { checksum } = task.options
+    if (checksum) {
+      const digest = await this.hashFile(tmpFile, checksum.algorithm)
+      if (digest.toLowerCase() !== checksum.value.toLowerCase()) {
+        await fs.promises.rm(tmpFile, { force: true })
+        this.removePartFiles(task)
+        task.segments = []
+        task.receivedBytes = 0
+        throw new ManagerError(
+          'DownloadManager',
+          'internal',
+          `Checksum mismatch: expected ${checksum.value}, got ${digest}`
+        )
+      }
+    }
+
+    await fs.promises.rename(tmpFile, task.dest)
+    this.removePartFiles(task)
+  }
+
+  public hashFile(filePath: string, algorithm: string): Promise<string> {
+    return workerPool.run('hashFile', { filePath, algorithm })
+  }
+
+  // `report.pdf`, then `report (1).pdf` and so on, also skipping names of downloads not finished yet
+  private getFreePath(directory: string, fileName: string) {
+    const { name, ext } = path.parse(path.basename(fileName) || 'download')
+    const taken = new Set(
+      [...this.tasks.values()]
+        .filter((task) => ['queued', 'downloading', 'paused'].includes(task.status))
+        .map((task) => task.dest)
+    )
+    for (let i = 0; ; i++) {
+      const candidate = path.join(directory, i === 0 ? `${name}${ext}` : `${name} (${i})${ext}`)
+      if (!taken.has(candidate) && !fs.existsSync(candidate)) return candidate
+    }
+  }
+
+  private getPartFile(task: DownloadTask, index: number) {
+    return `${task.dest}.${task.id.slice(0, 8)}.part${index}`
+  }
+
+  private removePartFiles(task: DownloadTask) {
+    task.segments.forEach((_, index) => fs.rmSync(this.getPartFile(task, index), { force: true }))
+  }
+
+  private updateStatus(task: DownloadTask, status: DownloadStatus) {
+    task.status = status
+    task.updatedAt = Date.now()
+    if (status !== 'downloading') task.speed = 0
+
+    this.saveQueue()
+    this.emit('status', task)
+    this.broadcast(task)
+  }
+
+  private reportProgress(task: DownloadTask) {
+    const now = Date.now()
+    if (now - this.lastBroadcast < PROGRESS_INTERVAL) return
+
+    this.lastBroadcast = now
+    task.updatedAt = now
+    this.emit('progress', task)
+    this.broadcast(task)
+    this.saveQueue()
+  }
+
+  private broadcast(task: DownloadTask) {
+    BrowserWindow.getAllWindows().forEach((win) => {
+      if (!win.isDestroyed()) {
+        win.webContents.send(IpcChannel.Download_Updated, task)
+      }
+    })
+  }
+
+  private loadQueue() {
+    try {
+      if (!fs.existsSync(this.queueFile)) return
+      const tasks: DownloadTask[] = JSON.parse(fs.readFileSync(this.queueFile, 'utf-8'))
+      tasks.forEach((task) => {
+        // tasks interrupted by a quit are picked up again by init()
+        if (task.status === 'downloading') task.status = 'queued'
+        task.speed = 0
+        // the part files are the source of truth for what has been received
+        task.segments.forEach((segment, index) => {
+          const partFile = this.getPartFile(task, index)
+          segment.received = fs.existsSync(partFile) ? fs.statSync(partFile).size : 0
+        })
+        task.receivedBytes = task.segments.reduce((sum, segment) => sum + segment.received, 0)
+        this.tasks.set(task.id, task)
+      })
+    } catch (error) {
+      Logger.error('[DownloadManager] Failed to load download queue:', error)
+    }
+  }
+
+  private saveQueue() {
+    try {
+      const tasks = [...this.tasks.values()].filter((task) => task.status !== 'cancelled')
+      fs.writeFileSync(this.queueFile, JSON.stringify(tasks, null, 2))
+    } catch (error) {
+      Logger.error('[DownloadManager] Failed to save download queue:', error)
+    }
+  }
+}
+
+export const downloadManager = new DownloadManager()
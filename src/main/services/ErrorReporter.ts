@@ -0,0 +1,30 @@
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import { BrowserWindow } from 'electron'
+import Logger from 'electron-log'
+
+import { pushChannel } from './PushChannel'
+
+/**
+ * Reports failures of background work that no IPC call is waiting for, e.g. an MCP server that exited.
+ *
+ * Errors are sent to every window as `app:error` in the serialized `ManagerError` form, so the renderer
+ * localizes them by code and offers recovery actions the same way as for failed calls. External clients
+ * receive them on the `app` topic of the push channel.
+ */
+class ErrorReporter {
+  public report(manager: string, error: unknown) {
+    const managerError = ManagerError.from(manager, error)
+    Logger.error(`[${managerError.manager}] Background failure (${managerError.code}):`, managerError.message)
+
+    const data = managerError.toJSON()
+    BrowserWindow.getAllWindows().forEach((win) => {
+      if (!win.isDestroyed()) {
+        win.webContents.send(IpcChannel.App_Error, data)
+      }
+    })
+    pushChannel.publish('app', 'app.error', data)
+  }
+}
+
+export const errorReporter = new ErrorReporter()
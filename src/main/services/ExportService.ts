@@ -7,6 +7,7 @@ import {
   Document,
   ExternalHyperlink,
   HeadingLevel,
+  ImageRun,
   Packer,
   Paragraph,
   ShadingType,
@@ -17,12 +18,16 @@ import {
   VerticalAlign,
   WidthType
 } from 'docx'
-import { dialog } from 'electron'
+import { dialog, nativeImage } from 'electron'
 import Logger from 'electron-log'
 import MarkdownIt from 'markdown-it'
+import { fileURLToPath } from 'url'
 
 import FileStorage from './FileStorage'
 
+// 嵌入图片的最大宽度（像素），更宽的图片按比例缩小
+const MAX_IMAGE_WIDTH = 600
+
 export class ExportService {
   private fileManager: FileStorage
   private md: MarkdownIt
@@ -30,6 +35,9 @@ export class ExportService {
   constructor(fileManager: FileStorage) {
     this.fileManager = fileManager
     this.md = new MarkdownIt()
+    // 导出的图片附件使用 file:// 地址，markdown-it 默认不允许
+    const validateLink = this.md.validateLink
+    this.md.validateLink = (url) => url.startsWith('file://') || validateLink(url)
   }
 
   private convertMarkdownToDocxElements(markdown: string) {
@@ -41,9 +49,10 @@ export class ExportService {
     let isHeaderRow = false
     let tableColumnCount = 0
     let tableRows: TableRow[] = [] // Store rows temporarily
+    let headingLevel = 0 // 上一个标题的级别，标题不能跳级，否则无障碍检查会报错
 
-    const processInlineTokens = (tokens: any[], isHeaderRow: boolean): (TextRun | ExternalHyperlink)[] => {
-      const runs: (TextRun | ExternalHyperlink)[] = []
+    const processInlineTokens = (tokens: any[], isHeaderRow: boolean): (TextRun | ExternalHyperlink | ImageRun)[] => {
+      const runs: (TextRun | ExternalHyperlink | ImageRun)[] = []
       let linkText = ''
       let linkUrl = ''
       let insideLink = false
@@ -109,13 +118,15 @@ export class ExportService {
             runs.push(
               new TextRun({
                 text: token.content,
-                font: 'Consolas',
-                size: 20,
+                style: 'CodeChar',
                 bold: isHeaderRow || boldStack > 0,
                 italics: italicStack > 0
               })
             )
             break
+          case 'image':
+            runs.push(this.createImageRun(token.attrGet('src') || '', token.content))
+            break
         }
       }
       return runs
@@ -126,7 +137,8 @@ export class ExportService {
       switch (token.type) {
         case 'heading_open':
           // 获取标题级别 (h1 -> h6)
-          const level = parseInt(token.tag.slice(1)) as 1 | 2 | 3 | 4 | 5 | 6
+          const level = Math.min(parseInt(token.tag.slice(1)), headingLevel + 1) as 1 | 2 | 3 | 4 | 5 | 6
+          headingLevel = level
           const headingText = tokens[i + 1].content
           elements.push(
             new Paragraph({
@@ -181,32 +193,11 @@ export class ExportService {
           break
 
         case 'fence': // 代码块
-          const codeLines = token.content.split('\n')
+          const codeLines = token.content.replace(/\n$/, '').split('\n')
           elements.push(
             new Paragraph({
-              children: codeLines.map(
-                (line) =>
-                  new TextRun({
-                    text: line + '\n',
-                    font: 'Consolas',
-                    size: 20,
-                    break: 1
-                  })
-              ),
-              shading: {
-                type: ShadingType.SOLID,
-                color: 'F5F5F5'
-              },
-              spacing: {
-                before: 120,
-                after: 120
-              },
-              border: {
-                top: { style: BorderStyle.SINGLE, size: 1, color: 'DDDDDD' },
-                bottom: { style: BorderStyle.SINGLE, size: 1, color: 'DDDDDD' },
-                left: { style: BorderStyle.SINGLE, size: 1, color: 'DDDDDD' },
-                right: { style: BorderStyle.SINGLE, size: 1, color: 'DDDDDD' }
-              }
+              style: 'CodeBlock',
+              children: codeLines.map((line, index) => new TextRun({ text: line, break: index > 0 ? 1 : 0 }))
             })
           )
           break
@@ -364,11 +355,44 @@ export class ExportService {
     return elements
   }
 
-  public exportToWord = async (_: Electron.IpcMainInvokeEvent, markdown: string, fileName: string): Promise<void> => {
+  /**
+   * 嵌入本地或 data URL 图片，替代文本来自 Markdown 图片的描述
+   * 无法读取的图片（如网络图片）以替代文本代替
+   */
+  private createImageRun(src: string, alt: string): ImageRun | TextRun {
+    try {
+      const image = src.startsWith('data:')
+        ? nativeImage.createFromDataURL(src)
+        : nativeImage.createFromPath(src.startsWith('file://') ? fileURLToPath(src) : src)
+      if (!image.isEmpty()) {
+        const { width, height } = image.getSize()
+        const scale = Math.min(1, MAX_IMAGE_WIDTH / width)
+        return new ImageRun({
+          type: 'png',
+          data: image.toPNG(),
+          transformation: { width: Math.round(width * scale), height: Math.round(height * scale) },
+          altText: { name: alt, title: alt, description: alt }
+        })
+      }
+    } catch (error) {
+      Logger.warn('[ExportService] Failed to embed image:', error)
+    }
+    return new TextRun({ text: `[${alt || src}]`, italics: true })
+  }
+
+  public exportToWord = async (
+    _: Electron.IpcMainInvokeEvent,
+    markdown: string,
+    fileName: string,
+    options: { title?: string } = {}
+  ): Promise<void> => {
     try {
       const elements = this.convertMarkdownToDocxElements(markdown)
 
       const doc = new Document({
+        // 文档标题供屏幕阅读器和无障碍检查使用
+        title: options.title || fileName,
+        creator: 'Cherry Studio',
         styles: {
           paragraphStyles: [
             {
@@ -378,6 +402,41 @@ export class ExportService {
                 size: 24,
                 font: 'Arial'
               }
+            },
+            {
+              id: 'CodeBlock',
+              name: 'Code Block',
+              basedOn: 'Normal',
+              run: {
+                size: 20,
+                font: 'Consolas'
+              },
+              paragraph: {
+                shading: {
+                  type: ShadingType.SOLID,
+                  color: 'F5F5F5'
+                },
+                spacing: {
+                  before: 120,
+                  after: 120
+                },
+                border: {
+                  top: { style: BorderStyle.SINGLE, size: 1, color: 'DDDDDD' },
+                  bottom: { style: BorderStyle.SINGLE, size: 1, color: 'DDDDDD' },
+                  left: { style: BorderStyle.SINGLE, size: 1, color: 'DDDDDD' },
+                  right: { style: BorderStyle.SINGLE, size: 1, color: 'DDDDDD' }
+                }
+              }
+            }
+          ],
+          characterStyles: [
+            {
+              id: 'CodeChar',
+              name: 'Code Char',
+              run: {
+                size: 20,
+                font: 'Consolas'
+              }
             }
           ]
         },
@@ -0,0 +1,136 @@
+import { spawn } from 'node:child_process'
+import fs from 'node:fs'
+import os from 'node:os'
+import path from 'node:path'
+
+import { isLinux, isMac, isWin } from '@main/constant'
+import { getDataPath, getResourcePath } from '@main/utils'
+import Logger from 'electron-log'
+
+import { configManager } from './ConfigManager'
+
+export type FeedbackEvent = 'message-received' | 'error' | 'agent-finished'
+
+const SOUND_EXTS = ['.wav', '.aiff', '.mp3', '.ogg']
+
+// sounds shipped with the OS, used by the "system" theme and as a fallback
+const SYSTEM_SOUNDS: Record<FeedbackEvent, string> = isMac
+  ? {
+      'message-received': '/System/Library/Sounds/Glass.aiff',
+      error: '/System/Library/Sounds/Basso.aiff',
+      'agent-finished': '/System/Library/Sounds/Hero.aiff'
+    }
+  : isWin
+    ? {
+        'message-received': 'C:\\Windows\\Media\\Windows Notify Messaging.wav',
+        error: 'C:\\Windows\\Media\\Windows Critical Stop.wav',
+        'agent-finished': 'C:\\Windows\\Media\\tada.wav'
+      }
+    : {
+        'message-received': '/usr/share/sounds/freedesktop/stereo/message.oga',
+        error: '/usr/share/sounds/freedesktop/stereo/dialog-error.oga',
+        'agent-finished': '/usr/share/sounds/freedesktop/stereo/complete.oga'
+      }
+
+class FeedbackManager {
+  /**
+   * Sound themes are folders containing one file per event, e.g. `message-received.wav`.
+   * User themes live in `Data/Sounds`, bundled themes in `resources/sounds`.
+   */
+  private getThemeDirs(): string[] {
+    return [path.join(getDataPath(), 'Sounds'), path.join(getResourcePath(), 'sounds')]
+  }
+
+  public listThemes(): string[] {
+    const themes = new Set<string>(['system'])
+    for (const dir of this.getThemeDirs()) {
+      if (!fs.existsSync(dir)) continue
+      fs.readdirSync(dir, { withFileTypes: true })
+        .filter((entry) => entry.isDirectory())
+        .forEach((entry) => themes.add(entry.name))
+    }
+    return [...themes]
+  }
+
+  public async playSound(eventName: FeedbackEvent): Promise<boolean> {
+    if (!configManager.getFeedbackSoundEnabled()) return false
+    if (!configManager.getFeedbackEnabledEvents().includes(eventName)) return false
+    if (configManager.getFeedbackRespectDoNotDisturb() && this.isDoNotDisturb()) return false
+
+    const soundPath = this.resolveSound(configManager.getFeedbackSoundTheme(), eventName)
+    if (!soundPath) {
+      Logger.warn(`[FeedbackManager] No sound found for event: ${eventName}`)
+      return false
+    }
+
+    try {
+      await this.play(soundPath)
+      return true
+    } catch (error) {
+      Logger.error(`[FeedbackManager] Failed to play ${soundPath}:`, error)
+      return false
+    }
+  }
+
+  private resolveSound(theme: string, eventName: FeedbackEvent): string | null {
+    if (theme !== 'system') {
+      for (const dir of this.getThemeDirs()) {
+        for (const ext of SOUND_EXTS) {
+          const file = path.join(dir, theme, eventName + ext)
+          if (fs.existsSync(file)) return file
+        }
+      }
+    }
+
+    const systemSound = SYSTEM_SOUNDS[eventName]
+    return fs.existsSync(systemSound) ? systemSound : null
+  }
+
+  private play(file: string): Promise<void> {
+    let command: string
+    let args: string[]
+
+    if (isMac) {
+      command = 'afplay'
+      args = [file]
+    } else if (isWin) {
+      command = 'powershell'
+      args = ['-NoProfile', '-Command', `(New-Object Media.SoundPlayer '${file.replace(/'/g, "''")}').PlaySync()`]
+    } else {
+      command = 'paplay'
+      args = [file]
+    }
+
+    return new Promise((resolve, reject) => {
+      const child = spawn(command, args, { stdio: 'ignore', windowsHide: true })
+      child.on('error', (error) => {
+        // fall back to ALSA when PulseAudio is not available
+        if (isLinux && command === 'paplay') {
+          spawn('aplay', ['-q', file], { stdio: 'ignore' }).on('close', () => resolve())
+        } else {
+          reject(error)
+        }
+      })
+      child.on('close', () => resolve())
+    })
+  }
+
+  /**
+   * Best-effort detection of the OS focus / do-not-disturb mode
+   */
+  private isDoNotDisturb(): boolean {
+    try {
+      if (isMac) {
+        const assertions = path.join(os.homedir(), 'Library/DoNotDisturb/DB/Assertions.json')
+        if (!fs.existsSync(assertions)) return false
+        const data = JSON.parse(fs.readFileSync(assertions, 'utf-8'))
+        return (data?.data?.[0]?.storeAssertionRecords?.length ?? 0) > 0
+      }
+    } catch (error) {
+      Logger.warn('[FeedbackManager] Failed to read do-not-disturb state:', error)
+    }
+    return false
+  }
+}
+
+export const feedbackManager = new FeedbackManager()
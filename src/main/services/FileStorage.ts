@@ -1,7 +1,6 @@
 import { getFilesDir, getFileType, getTempDir } from '@main/utils/file'
 import { documentExts, imageExts, MB } from '@shared/config/constant'
 import { FileType } from '@types'
-import * as crypto from 'crypto'
 import {
   dialog,
   OpenDialogOptions,
@@ -20,6 +19,9 @@ import * as path from 'path'
 import { chdir } from 'process'
 import { v4 as uuidv4 } from 'uuid'
 
+import { secureTopicManager } from './SecureTopicManager'
+import { workerPool } from './WorkerPool'
+
 class FileStorage {
   private storageDir = getFilesDir()
   private tempDir = getTempDir()
@@ -43,13 +45,7 @@ class FileStorage {
   }
 
   private getFileHash = async (filePath: string): Promise<string> => {
-    return new Promise((resolve, reject) => {
-      const hash = crypto.createHash('md5')
-      const stream = fs.createReadStream(filePath)
-      stream.on('data', (data) => hash.update(data))
-      stream.on('end', () => resolve(hash.digest('hex')))
-      stream.on('error', reject)
-    })
+    return workerPool.run('hashFile', { filePath, algorithm: 'md5' })
   }
 
   findDuplicateFile = async (filePath: string): Promise<FileType | null> => {
@@ -219,22 +215,28 @@ class FileStorage {
 
   public readFile = async (_: Electron.IpcMainInvokeEvent, id: string): Promise<string> => {
     const filePath = path.join(this.storageDir, id)
+    const content = await secureTopicManager.readFile(filePath)
 
     if (documentExts.includes(path.extname(filePath))) {
+      // the parser reads from disk, attachments of secure conversations are opened into a temp file first
+      const parsePath = path.join(this.tempDir, `temp_file_${uuidv4()}_${id}`)
       const originalCwd = process.cwd()
       try {
+        await fs.promises.writeFile(parsePath, content)
         chdir(this.tempDir)
-        const data = await officeParser.parseOfficeAsync(filePath)
+        const data = await officeParser.parseOfficeAsync(parsePath)
         chdir(originalCwd)
         return data
       } catch (error) {
         chdir(originalCwd)
         logger.error(error)
         throw error
+      } finally {
+        await fs.promises.rm(parsePath, { force: true })
       }
     }
 
-    return fs.readFileSync(filePath, 'utf8')
+    return content.toString('utf8')
   }
 
   public createTempFile = async (_: Electron.IpcMainInvokeEvent, fileName: string): Promise<string> => {
@@ -258,7 +260,7 @@ class FileStorage {
     id: string
   ): Promise<{ mime: string; base64: string; data: string }> => {
     const filePath = path.join(this.storageDir, id)
-    const data = await fs.promises.readFile(filePath)
+    const data = await secureTopicManager.readFile(filePath)
     const base64 = data.toString('base64')
     const ext = path.extname(filePath).slice(1) == 'jpg' ? 'jpeg' : path.extname(filePath).slice(1)
     const mime = `image/${ext}`
@@ -316,7 +318,7 @@ class FileStorage {
 
   public base64File = async (_: Electron.IpcMainInvokeEvent, id: string): Promise<{ data: string; mime: string }> => {
     const filePath = path.join(this.storageDir, id)
-    const buffer = await fs.promises.readFile(filePath)
+    const buffer = await secureTopicManager.readFile(filePath)
     const base64 = buffer.toString('base64')
     const mime = `application/${path.extname(filePath).slice(1)}`
     return { data: base64, mime }
@@ -324,7 +326,7 @@ class FileStorage {
 
   public pdfPageCount = async (_: Electron.IpcMainInvokeEvent, id: string): Promise<number> => {
     const filePath = path.join(this.storageDir, id)
-    const buffer = await fs.promises.readFile(filePath)
+    const buffer = await secureTopicManager.readFile(filePath)
 
     const doc = await getDocument({ data: buffer }).promise
     const pages = doc.numPages
@@ -334,7 +336,7 @@ class FileStorage {
 
   public binaryImage = async (_: Electron.IpcMainInvokeEvent, id: string): Promise<{ data: Buffer; mime: string }> => {
     const filePath = path.join(this.storageDir, id)
-    const data = await fs.promises.readFile(filePath)
+    const data = await secureTopicManager.readFile(filePath)
     const mime = `image/${path.extname(filePath).slice(1)}`
     return { data, mime }
   }
@@ -536,8 +538,8 @@ class FileStorage {
         await fs.promises.mkdir(destDir, { recursive: true })
       }
 
-      // 复制文件
-      await fs.promises.copyFile(sourcePath, destPath)
+      // 复制文件，加密话题的附件复制解密后的内容
+      await fs.promises.writeFile(destPath, await secureTopicManager.readFile(sourcePath))
       logger.info('[FileStorage] File copied successfully:', { from: sourcePath, to: destPath })
     } catch (error) {
       logger.error('[FileStorage] Copy file failed:', error)
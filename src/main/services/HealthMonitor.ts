@@ -0,0 +1,169 @@
+import { IpcChannel } from '@shared/IpcChannel'
+import { app, BrowserWindow, WebContents, webContents } from 'electron'
+import Logger from 'electron-log'
+
+import { isWebviewSuspended } from './WebviewService'
+
+// how often webviews and managers are probed
+const CHECK_INTERVAL = 30 * 1000
+// a renderer that does not answer the heartbeat within this time misses a beat
+const HEARTBEAT_TIMEOUT = 5 * 1000
+// consecutive missed heartbeats before a renderer is considered stuck
+const MAX_MISSED_HEARTBEATS = 2
+
+export type ComponentKind = 'window' | 'webview' | 'manager'
+
+export interface ComponentHealthEvent {
+  component: string
+  kind: ComponentKind
+  reason: string
+  recovered: boolean
+  time: number
+}
+
+export interface HealthProbe {
+  name: string
+  // resolves false when the manager is stuck, e.g. a queued task never settled
+  check: () => boolean | Promise<boolean>
+  // reset the manager state so new work can run again
+  recover?: () => void | Promise<void>
+}
+
+/**
+ * Watchdog for stuck renderers and deadlocked managers.
+ *
+ * Every window and webview gets a JS heartbeat on an interval; webviews that stop answering or whose
+ * renderer process is gone are reloaded. Managers register probes that detect stalled queues and
+ * recover by resetting their state. Unhealthy components are logged and broadcast to the renderer.
+ */
+class HealthMonitor {
+  private probes = new Map<string, HealthProbe>()
+  private missedHeartbeats = new Map<number, number>()
+  private events: ComponentHealthEvent[] = []
+  private timer: NodeJS.Timeout | null = null
+
+  public init() {
+    app.on('web-contents-created', (_, contents) => {
+      contents.on('render-process-gone', (_, details) => {
+        if (details.reason === 'clean-exit') return
+        this.recoverWebContents(contents, `renderer process gone: ${details.reason}`)
+      })
+      contents.on('destroyed', () => this.missedHeartbeats.delete(contents.id))
+    })
+
+    this.timer = setInterval(() => this.check(), CHECK_INTERVAL)
+  }
+
+  public stop() {
+    if (this.timer) {
+      clearInterval(this.timer)
+      this.timer = null
+    }
+  }
+
+  public registerProbe(probe: HealthProbe) {
+    this.probes.set(probe.name, probe)
+  }
+
+  public unregisterProbe(name: string) {
+    this.probes.delete(name)
+  }
+
+  /**
+   * Recent unhealthy events, newest last
+   */
+  public getReport(): ComponentHealthEvent[] {
+    return [...this.events]
+  }
+
+  private async check() {
+    await Promise.all([
+      ...webContents.getAllWebContents().map((contents) => this.checkWebContents(contents)),
+      ...[...this.probes.values()].map((probe) => this.checkProbe(probe))
+    ])
+  }
+
+  private async checkWebContents(contents: WebContents) {
+    const type = contents.getType()
+    if (contents.isDestroyed() || contents.isLoading() || (type !== 'window' && type !== 'webview')) return
+    // frozen on purpose, a heartbeat would only time out and reload it
+    if (isWebviewSuspended(contents.id)) {
+      this.missedHeartbeats.delete(contents.id)
+      return
+    }
+
+    // a renderer that fails to run the script misses the beat too
+    const alive = await Promise.race([
+      contents.executeJavaScript('1').then(
+        () => true,
+        () => false
+      ),
+      new Promise<boolean>((resolve) => setTimeout(() => resolve(false), HEARTBEAT_TIMEOUT))
+    ])
+
+    if (alive) {
+      this.missedHeartbeats.delete(contents.id)
+      return
+    }
+
+    const missed = (this.missedHeartbeats.get(contents.id) ?? 0) + 1
+    this.missedHeartbeats.set(contents.id, missed)
+    if (missed >= MAX_MISSED_HEARTBEATS) {
+      this.missedHeartbeats.delete(contents.id)
+      this.recoverWebContents(contents, `no heartbeat for ${missed} checks`)
+    }
+  }
+
+  /**
+   * Webviews are reloaded, app windows only report since reloading them would drop unsaved state
+   * unless their renderer process is already gone
+   */
+  private recoverWebContents(contents: WebContents, reason: string) {
+    if (contents.isDestroyed()) return
+
+    const kind: ComponentKind = contents.getType() === 'webview' ? 'webview' : 'window'
+    const recoverable = kind === 'webview' || contents.isCrashed()
+    if (recoverable) {
+      contents.reload()
+    }
+    this.report({ component: contents.getURL() || `webContents:${contents.id}`, kind, reason, recovered: recoverable })
+  }
+
+  private async checkProbe(probe: HealthProbe) {
+    let healthy: boolean
+    try {
+      healthy = await probe.check()
+    } catch (error: any) {
+      healthy = false
+      Logger.error(`[HealthMonitor] Probe ${probe.name} failed:`, error)
+    }
+    if (healthy) return
+
+    let recovered = false
+    if (probe.recover) {
+      try {
+        await probe.recover()
+        recovered = true
+      } catch (error) {
+        Logger.error(`[HealthMonitor] Failed to recover ${probe.name}:`, error)
+      }
+    }
+    this.report({ component: probe.name, kind: 'manager', reason: 'probe failed', recovered })
+  }
+
+  private report(event: Omit<ComponentHealthEvent, 'time'>) {
+    const entry = { ...event, time: Date.now() }
+    Logger.warn(
+      `[HealthMonitor] ${entry.kind} ${entry.component} is unhealthy (${entry.reason}), recovered: ${entry.recovered}`
+    )
+
+    this.events = [...this.events.slice(-99), entry]
+    BrowserWindow.getAllWindows().forEach((win) => {
+      if (!win.isDestroyed()) {
+        win.webContents.send(IpcChannel.Health_ComponentUnhealthy, entry)
+      }
+    })
+  }
+}
+
+export const healthMonitor = new HealthMonitor()
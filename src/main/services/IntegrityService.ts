@@ -0,0 +1,92 @@
+import { createHash } from 'node:crypto'
+import path from 'node:path'
+
+import { ManagerError } from '@shared/ManagerError'
+import { InstallationIntegrityReport } from '@types'
+import { app } from 'electron'
+import Logger from 'electron-log'
+// the patched fs reads app.asar as a directory, its bytes are only readable through original-fs
+import fs from 'original-fs'
+
+// written next to app.asar by scripts/after-pack.js
+const MANIFEST_FILE = 'integrity.json'
+
+interface IntegrityManifest {
+  version: string
+  algorithm: string
+  // paths relative to the resources directory, always with forward slashes
+  files: Record<string, string>
+}
+
+/**
+ * Verifies the installed app resources against the checksum manifest generated at packaging,
+ * to detect files that were tampered with or corrupted, e.g. by an update that did not complete.
+ */
+class IntegrityService {
+  private running: Promise<InstallationIntegrityReport> | null = null
+
+  public verifyInstallation(): Promise<InstallationIntegrityReport> {
+    if (!this.running) {
+      this.running = this.verify().finally(() => (this.running = null))
+    }
+    return this.running
+  }
+
+  private async verify(): Promise<InstallationIntegrityReport> {
+    if (!app.isPackaged) {
+      throw new ManagerError('IntegrityService', 'unavailable', 'Only packaged builds can be verified')
+    }
+
+    const manifest = await this.readManifest()
+    const missing: string[] = []
+    const modified: string[] = []
+
+    for (const [file, checksum] of Object.entries(manifest.files)) {
+      const filePath = path.join(process.resourcesPath, ...file.split('/'))
+      const actual = await this.hashFile(filePath, manifest.algorithm).catch(() => null)
+      if (actual === null) missing.push(file)
+      else if (actual !== checksum) modified.push(file)
+    }
+
+    const report: InstallationIntegrityReport = {
+      appVersion: app.getVersion(),
+      manifestVersion: manifest.version,
+      checkedFiles: Object.keys(manifest.files).length,
+      missing,
+      modified,
+      ok: !missing.length && !modified.length && manifest.version === app.getVersion(),
+      checkedAt: Date.now()
+    }
+    if (!report.ok) {
+      Logger.warn('[IntegrityService] Installation check failed', report)
+    }
+    return report
+  }
+
+  private async readManifest(): Promise<IntegrityManifest> {
+    const manifestPath = path.join(process.resourcesPath, MANIFEST_FILE)
+    let manifest: IntegrityManifest
+    try {
+      manifest = JSON.parse(await fs.promises.readFile(manifestPath, 'utf-8'))
+    } catch (error) {
+      const code = (error as NodeJS.ErrnoException).code === 'ENOENT' ? 'not_found' : 'internal'
+      throw new ManagerError('IntegrityService', code, `Integrity manifest is missing or unreadable: ${manifestPath}`)
+    }
+    if (!manifest.files || typeof manifest.files !== 'object') {
+      throw new ManagerError('IntegrityService', 'internal', 'Integrity manifest has no file list')
+    }
+    return manifest
+  }
+
+  private hashFile(filePath: string, algorithm: string): Promise<string> {
+    return new Promise((resolve, reject) => {
+      const hash = createHash(algorithm)
+      fs.createReadStream(filePath)
+        .on('error', reject)
+        .on('data', (chunk) => hash.update(chunk))
+        .on('end', () => resolve(hash.digest('hex')))
+    })
+  }
+}
+
+export const integrityService = new IntegrityService()
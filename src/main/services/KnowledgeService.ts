@@ -23,6 +23,7 @@ import { SitemapLoader } from '@cherrystudio/embedjs-loader-sitemap'
 import { WebLoader } from '@cherrystudio/embedjs-loader-web'
 import Embeddings from '@main/embeddings/Embeddings'
 import { addFileLoader } from '@main/loader'
+import { createSourceConnector, SourceConnectorConfig } from '@main/loader/connectors'
 import Reranker from '@main/reranker/Reranker'
 import { windowService } from '@main/services/WindowService'
 import { getDataPath } from '@main/utils'
@@ -46,6 +47,19 @@ interface KnowledgeBaseAddItemOptionsNonNullableAttribute {
   forceReload: boolean
 }
 
+export interface SourceSyncResult {
+  added: number
+  updated: number
+  removed: number
+  unchanged: number
+  // some documents couldn't be fetched, documents missing from this sync were kept
+  incomplete: boolean
+  uniqueIds: string[]
+}
+
+// per connector key: document id -> version and the loader that indexed it
+type SourceSyncState = Record<string, Record<string, { version: string; uniqueId: string }>>
+
 interface EvaluateTaskWorkload {
   workload: number
 }
@@ -117,7 +131,7 @@ class KnowledgeService {
     dimensions
   }: KnowledgeBaseParams): Promise<RAGApplication> => {
     let ragApplication: RAGApplication
-    const embeddings = new Embeddings({
+    const embeddings = Embeddings.get({
       model,
       provider,
       apiKey,
@@ -153,6 +167,7 @@ class KnowledgeService {
     if (fs.existsSync(dbPath)) {
       fs.rmSync(dbPath, { recursive: true })
     }
+    fs.rmSync(this.getSourceStatePath(id), { force: true })
   }
 
   private maximumLoad() {
@@ -481,6 +496,95 @@ class KnowledgeService {
     return await ragApplication.search(search)
   }
 
+  /**
+   * Pull documents from an external source and only re-index the ones that changed since the last sync
+   */
+  public syncSource = async (
+    _: Electron.IpcMainInvokeEvent,
+    { base, source }: { base: KnowledgeBaseParams; source: SourceConnectorConfig }
+  ): Promise<SourceSyncResult> => {
+    const connector = createSourceConnector(source)
+    const ragApplication = await this.getRagApplication(base)
+    const state = this.readSourceState(base.id)
+    const previous = state[connector.key] || {}
+    const current: SourceSyncState[string] = { ...previous }
+    const seen = new Set<string>()
+    const result: SourceSyncResult = {
+      added: 0,
+      updated: 0,
+      removed: 0,
+      unchanged: 0,
+      incomplete: false,
+      uniqueIds: []
+    }
+    // written after every change, a sync that fails halfway leaves no loader the next sync doesn't know about
+    const saveState = () => {
+      state[connector.key] = current
+      this.writeSourceState(base.id, state)
+    }
+
+    for await (const doc of connector.fetchDocuments()) {
+      seen.add(doc.id)
+      const existing = previous[doc.id]
+      if (existing && existing.version === doc.version) {
+        result.unchanged++
+        continue
+      }
+
+      if (existing) {
+        await ragApplication.deleteLoader(existing.uniqueId)
+        delete current[doc.id]
+        saveState()
+      }
+      const { uniqueId } = (await ragApplication.addLoader(
+        new TextLoader({
+          text: `# ${doc.title}\n\nSource: ${doc.source}\n\n${doc.content}`,
+          chunkSize: base.chunkSize,
+          chunkOverlap: base.chunkOverlap
+        }),
+        true
+      )) as LoaderReturn
+      current[doc.id] = { version: doc.version, uniqueId }
+      saveState()
+      existing ? result.updated++ : result.added++
+    }
+
+    // after failed fetches only the documents the source reported as deleted are removed, the others are kept
+    // until a sync that reaches them
+    for (const [id, entry] of Object.entries(previous)) {
+      if (seen.has(id)) continue
+      if (connector.incomplete && !connector.gone?.has(id)) continue
+      await ragApplication.deleteLoader(entry.uniqueId)
+      delete current[id]
+      saveState()
+      result.removed++
+    }
+
+    result.incomplete = !!connector.incomplete
+    result.uniqueIds = Object.values(current).map((entry) => entry.uniqueId)
+    Logger.info(`[KnowledgeService] Synced ${connector.key}:`, result)
+    return result
+  }
+
+  private getSourceStatePath(id: string) {
+    return path.join(this.storageDir, `${id}.sources.json`)
+  }
+
+  private readSourceState(id: string): SourceSyncState {
+    const statePath = this.getSourceStatePath(id)
+    if (!fs.existsSync(statePath)) return {}
+    try {
+      return JSON.parse(fs.readFileSync(statePath, 'utf-8'))
+    } catch (error) {
+      Logger.error(`[KnowledgeService] Failed to read source state ${statePath}:`, error)
+      return {}
+    }
+  }
+
+  private writeSourceState(id: string, state: SourceSyncState) {
+    fs.writeFileSync(this.getSourceStatePath(id), JSON.stringify(state, null, 2))
+  }
+
   public rerank = async (
     _: Electron.IpcMainInvokeEvent,
     { search, base, results }: { search: string; base: KnowledgeBaseParams; results: ExtractChunkData[] }
@@ -0,0 +1,224 @@
+import crypto from 'node:crypto'
+import dns from 'node:dns'
+import fs from 'node:fs'
+import http from 'node:http'
+import https from 'node:https'
+import net from 'node:net'
+import path from 'node:path'
+
+import { getCacheDir } from '@main/utils/file'
+import { isPrivateAddress, parseLinkMetadata } from '@main/utils/linkPreview'
+import { ManagerError } from '@shared/ManagerError'
+import { LinkPreview } from '@types'
+import { AxiosRequestConfig, AxiosResponse } from 'axios'
+import Logger from 'electron-log'
+
+import aoxisProxy from './AxiosProxy'
+import { CacheService } from './CacheService'
+import { proxyManager } from './ProxyManager'
+
+const CACHE_TTL = 60 * 60 * 1000
+// requests to one domain are spaced by this much
+const DOMAIN_INTERVAL = 2000
+const TIMEOUT = 10 * 1000
+const MAX_PAGE_SIZE = 2 * 1024 * 1024
+const MAX_IMAGE_SIZE = 5 * 1024 * 1024
+const MAX_REDIRECTS = 5
+const USER_AGENT = 'Mozilla/5.0 (compatible; CherryStudio link preview)'
+const IMAGE_EXTENSIONS: Record<string, string> = {
+  'image/png': '.png',
+  'image/jpeg': '.jpg',
+  'image/gif': '.gif',
+  'image/webp': '.webp',
+  'image/svg+xml': '.svg',
+  'image/x-icon': '.ico',
+  'image/vnd.microsoft.icon': '.ico'
+}
+
+/**
+ * Resolves like dns.lookup but fails for private addresses. The socket connects to the address checked here, a host
+ * that resolves to a public address for the check and to a private one for the connection (DNS rebinding) is refused.
+ */
+const publicLookup: net.LookupFunction = (hostname, options, callback) => {
+  dns.lookup(hostname, { ...options, all: true, verbatim: true }, (error, addresses) => {
+    if (error) return callback(error, '', 0)
+    if (!addresses.length || addresses.some(({ address }) => isPrivateAddress(address))) {
+      const denied = new ManagerError('LinkPreviewManager', 'permission_denied', `${hostname} is not a public address`)
+      return callback(denied as NodeJS.ErrnoException, '', 0)
+    }
+    if (options.all) return (callback as any)(null, addresses)
+    callback(null, addresses[0].address, addresses[0].family)
+  })
+}
+
+const pinnedAgents = {
+  httpAgent: new http.Agent({ lookup: publicLookup }),
+  httpsAgent: new https.Agent({ lookup: publicLookup })
+}
+
+/**
+ * Link previews: title, description, OpenGraph image and favicon of a web page, fetched by the main process so the
+ * renderer needs no CORS workarounds. Images are downloaded to `Cache/link-previews`, previews are kept for an hour.
+ * Page requests to the same domain are spaced out, whether previews are fetched at all is a privacy setting of the
+ * renderer, off by default. Pages, images and every redirect must resolve to public addresses, so a link can't reach
+ * the local network.
+ */
+class LinkPreviewManager {
+  // tail of the queue of each domain
+  private domainQueues = new Map<string, Promise<void>>()
+  private inflight = new Map<string, Promise<LinkPreview>>()
+
+  public async fetch(url: string): Promise<LinkPreview> {
+    let target: URL
+    try {
+      target = new URL(url)
+    } catch {
+      throw new ManagerError('LinkPreviewManager', 'invalid_argument', `Invalid url: ${url}`)
+    }
+    if (!['http:', 'https:'].includes(target.protocol)) {
+      throw new ManagerError('LinkPreviewManager', 'invalid_argument', `Only web pages have previews: ${url}`)
+    }
+
+    const key = `link-preview:${target.toString()}`
+    const cached = CacheService.get<LinkPreview>(key)
+    if (cached) return cached
+
+    let request = this.inflight.get(key)
+    if (!request) {
+      request = this.load(target)
+        .then((preview) => {
+          CacheService.set(key, preview, CACHE_TTL)
+          return preview
+        })
+        .finally(() => this.inflight.delete(key))
+      this.inflight.set(key, request)
+    }
+    return request
+  }
+
+  private async load(target: URL): Promise<LinkPreview> {
+    await this.waitForDomain(target.hostname)
+
+    let result: { response: AxiosResponse<string>; url: string }
+    try {
+      result = await this.get<string>(target.toString(), {
+        responseType: 'text',
+        timeout: TIMEOUT,
+        maxContentLength: MAX_PAGE_SIZE,
+        headers: { 'User-Agent': USER_AGENT, Accept: 'text/html,application/xhtml+xml' }
+      })
+    } catch (error: any) {
+      if (error instanceof ManagerError) throw error
+      throw new ManagerError('LinkPreviewManager', 'unavailable', `Failed to load ${target}: ${error.message}`)
+    }
+
+    const { response, url: pageUrl } = result
+    const contentType = String(response.headers['content-type'] ?? '')
+    if (!contentType.includes('html')) {
+      throw new ManagerError('LinkPreviewManager', 'unavailable', `Not a web page: ${target}`)
+    }
+
+    const metadata = parseLinkMetadata(String(response.data), pageUrl)
+    const [image, favicon] = await Promise.all([
+      metadata.image && this.download(metadata.image),
+      metadata.favicon && this.download(metadata.favicon)
+    ])
+
+    return {
+      url: pageUrl,
+      title: metadata.title,
+      description: metadata.description,
+      siteName: metadata.siteName,
+      image: image || undefined,
+      favicon: favicon || undefined
+    }
+  }
+
+  /**
+   * Download an image to the cache, named by the hash of its url so it is downloaded once.
+   * Missing images leave the card without one instead of failing the preview.
+   */
+  private async download(url: string): Promise<string | undefined> {
+    const dir = path.join(getCacheDir(), 'link-previews')
+    const name = crypto.createHash('sha256').update(url).digest('hex').slice(0, 32)
+    const existing = (await fs.promises.readdir(dir).catch(() => [] as string[])).find((file) => file.startsWith(name))
+    if (existing) return path.join(dir, existing)
+
+    try {
+      const { response } = await this.get<ArrayBuffer>(url, {
+        responseType: 'arraybuffer',
+        timeout: TIMEOUT,
+        maxContentLength: MAX_IMAGE_SIZE,
+        headers: { 'User-Agent': USER_AGENT }
+      })
+      const contentType = String(response.headers['content-type'] ?? '').split(';')[0].trim()
+      const extension = IMAGE_EXTENSIONS[contentType]
+      if (!extension) return undefined
+
+      const filePath = path.join(dir, name + extension)
+      await fs.promises.mkdir(dir, { recursive: true })
+      await fs.promises.writeFile(filePath, Buffer.from(response.data))
+      return filePath
+    } catch (error: any) {
+      Logger.warn(`[LinkPreviewManager] Failed to download ${url}: ${error.message}`)
+      return undefined
+    }
+  }
+
+  /**
+   * GET that follows redirects itself, so the host of every hop is checked before it is requested
+   */
+  private async get<T>(url: string, config: AxiosRequestConfig): Promise<{ response: AxiosResponse<T>; url: string }> {
+    let current = url
+    for (let hop = 0; hop <= MAX_REDIRECTS; hop++) {
+      await this.assertPublicHost(current)
+      // without a proxy the connection resolves the host again, through the checking lookup
+      const agents = proxyManager.getProxyUrl() ? {} : pinnedAgents
+      const response = await aoxisProxy.axios.get<T>(current, {
+        ...config,
+        ...agents,
+        maxRedirects: 0,
+        validateStatus: (status) => status >= 200 && status < 400
+      })
+      const location = response.headers['location']
+      if (response.status < 300 || !location) return { response, url: current }
+
+      current = new URL(String(location), current).toString()
+      if (!/^https?:/i.test(current)) {
+        throw new ManagerError('LinkPreviewManager', 'permission_denied', `Redirect to a non web url: ${current}`)
+      }
+    }
+    throw new ManagerError('LinkPreviewManager', 'unavailable', `Too many redirects: ${url}`)
+  }
+
+  /**
+   * Every address the host resolves to must be public, e.g. not localhost, the router or a cloud metadata service
+   */
+  private async assertPublicHost(url: string) {
+    const hostname = new URL(url).hostname.replace(/^\[|\]$/g, '')
+    let addresses: dns.LookupAddress[]
+    try {
+      addresses = await dns.promises.lookup(hostname, { all: true, verbatim: true })
+    } catch (error: any) {
+      throw new ManagerError('LinkPreviewManager', 'unavailable', `Failed to resolve ${hostname}: ${error.message}`)
+    }
+    if (!addresses.length || addresses.some(({ address }) => isPrivateAddress(address))) {
+      throw new ManagerError('LinkPreviewManager', 'permission_denied', `${hostname} is not a public address`)
+    }
+  }
+
+  /**
+   * Wait for the turn of the domain, each request starts at least DOMAIN_INTERVAL after the previous one
+   */
+  private async waitForDomain(domain: string) {
+    const previous = this.domainQueues.get(domain) ?? Promise.resolve()
+    const next = previous.then(() => new Promise<void>((resolve) => setTimeout(resolve, DOMAIN_INTERVAL)))
+    this.domainQueues.set(domain, next)
+    next.then(() => {
+      if (this.domainQueues.get(domain) === next) this.domainQueues.delete(domain)
+    })
+    await previous
+  }
+}
+
+export const linkPreviewManager = new LinkPreviewManager()
@@ -0,0 +1,258 @@
+import { getGgufModelInfo, GgufModelInfo, GgufModelKind } from '@main/utils/gguf'
+import { StreamBatcher } from '@main/utils/stream'
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import Logger from 'electron-log'
+
+import { ModelFile, modelFileManager } from './ModelFileManager'
+import { workerPool } from './WorkerPool'
+
+export interface LocalChatMessage {
+  role: 'system' | 'user' | 'assistant'
+  content: string
+}
+
+export interface LocalChatCompletionParams {
+  modelId: string
+  messages: LocalChatMessage[]
+  temperature?: number
+  topP?: number
+  maxTokens?: number
+  stop?: string[]
+}
+
+export interface LocalChatCompletion {
+  content: string
+  usage: { prompt_tokens: number; completion_tokens: number; total_tokens: number }
+}
+
+export interface LocalBackendInfo {
+  available: boolean
+  gpu: string | false
+  vram?: { total: number; used: number; free: number }
+  loadedModelId?: string
+  error?: string
+}
+
+// node-llama-cpp is an optional dependency without prebuilt binaries for every platform, it is resolved at runtime only
+const LLAMA_MODULE = 'node-llama-cpp'
+
+class LocalInferenceManager {
+  private llamaModule: any = null
+  private llama: any = null
+  private model: any = null
+  private context: any = null
+  private loadedModelId: string | null = null
+  private controllers: Map<string, AbortController> = new Map()
+  private batchers: Map<string, StreamBatcher> = new Map()
+  // keyed by path and size, a file replaced on disk is read again
+  private modelInfos: Map<string, GgufModelInfo> = new Map()
+
+  private async getModule() {
+    if (this.llamaModule) return this.llamaModule
+
+    try {
+      this.llamaModule = await import(/* @vite-ignore */ LLAMA_MODULE)
+    } catch (error) {
+      throw new ManagerError('LocalInferenceManager', 'unavailable', 'Local inference runtime is not installed')
+    }
+    return this.llamaModule
+  }
+
+  /**
+   * Lazily load the llama.cpp runtime, shared by chat and embedding models
+   */
+  public async getLlama() {
+    if (this.llama) return this.llama
+
+    const llamaModule = await this.getModule()
+    this.llama = await llamaModule.getLlama()
+    return this.llama
+  }
+
+  /**
+   * Reads the GGUF header only, the runtime doesn't have to be loaded for it
+   */
+  private async getModelInfo(modelFile: ModelFile): Promise<GgufModelInfo> {
+    const key = `${modelFile.path}:${modelFile.size}`
+    const cached = this.modelInfos.get(key)
+    if (cached) return cached
+
+    const llamaModule = await this.getModule()
+    const { metadata } = await llamaModule.readGgufFileInfo(modelFile.path, { readTensorInfo: false })
+    const info = getGgufModelInfo(metadata)
+    this.modelInfos.set(key, info)
+    return info
+  }
+
+  /**
+   * Ready GGUF models of one kind, files with an unreadable header are left out
+   */
+  public async listModels(kind: GgufModelKind): Promise<ModelFile[]> {
+    const files = modelFileManager.listModels().filter((file) => file.format === 'gguf' && file.status === 'ready')
+    const models: ModelFile[] = []
+    for (const file of files) {
+      try {
+        if ((await this.getModelInfo(file)).kind === kind) models.push(file)
+      } catch (error) {
+        if (error instanceof ManagerError) throw error
+        Logger.warn(`[LocalInferenceManager] Failed to read the GGUF header of ${file.path}:`, error)
+      }
+    }
+    return models
+  }
+
+  public async getEmbeddingDimensions(modelId: string): Promise<number> {
+    const modelFile = modelFileManager.getModel(modelId)
+    if (!modelFile || modelFile.status !== 'ready' || modelFile.format !== 'gguf') {
+      throw new ManagerError('LocalInferenceManager', 'not_found', `Model is not available: ${modelId}`)
+    }
+
+    const info = await this.getModelInfo(modelFile)
+    if (info.kind !== 'embedding' || !info.embeddingLength) {
+      throw new ManagerError('LocalInferenceManager', 'invalid_argument', `Not an embedding model: ${modelFile.name}`)
+    }
+    return info.embeddingLength
+  }
+
+  public async getBackendInfo(): Promise<LocalBackendInfo> {
+    try {
+      const llama = await this.getLlama()
+      const vram = llama.gpu ? await llama.getVramState() : undefined
+      return {
+        available: true,
+        gpu: llama.gpu,
+        vram: vram && { total: vram.total, used: vram.used, free: vram.free },
+        loadedModelId: this.loadedModelId ?? undefined
+      }
+    } catch (error: any) {
+      return { available: false, gpu: false, error: error.message }
+    }
+  }
+
+  public async loadModel(modelId: string) {
+    if (this.loadedModelId === modelId) return
+
+    const modelFile = modelFileManager.getModel(modelId)
+    if (!modelFile || modelFile.status !== 'ready') {
+      throw new ManagerError('LocalInferenceManager', 'not_found', `Model is not available: ${modelId}`)
+    }
+    if (modelFile.format !== 'gguf') {
+      throw new ManagerError(
+        'LocalInferenceManager',
+        'invalid_argument',
+        `Only GGUF models can be used for chat: ${modelFile.name}`
+      )
+    }
+
+    const llama = await this.getLlama()
+    await this.unloadModel()
+
+    Logger.info(`[LocalInferenceManager] Loading ${modelFile.path}`)
+    this.model = await llama.loadModel({ modelPath: modelFile.path })
+    // a few sequences so that concurrent conversations don't block each other
+    this.context = await this.model.createContext({ sequences: 4, threads: workerPool.getThreadCount() })
+    this.loadedModelId = modelId
+    modelFileManager.markUsed(modelId)
+  }
+
+  public async unloadModel() {
+    this.controllers.forEach((controller) => controller.abort())
+    await this.context?.dispose()
+    await this.model?.dispose()
+    this.context = null
+    this.model = null
+    this.loadedModelId = null
+  }
+
+  /**
+   * Same shape as an OpenAI chat completion; when `onChunk` is given the text is streamed as it is generated
+   */
+  public async chatCompletion(
+    requestId: string,
+    params: LocalChatCompletionParams,
+    onChunk?: (text: string) => void
+  ): Promise<LocalChatCompletion> {
+    await this.loadModel(params.modelId)
+
+    const messages = [...params.messages]
+    const last = messages.pop()
+    if (!last || last.role !== 'user') {
+      throw new ManagerError('LocalInferenceManager', 'invalid_argument', 'The last message must be a user message')
+    }
+
+    const sequence = this.context.getSequence()
+    const session = new this.llamaModule.LlamaChatSession({ contextSequence: sequence })
+    session.setChatHistory(
+      messages.map((message) =>
+        message.role === 'assistant'
+          ? { type: 'model', response: [message.content] }
+          : { type: message.role, text: message.content }
+      )
+    )
+
+    const controller = new AbortController()
+    this.controllers.set(requestId, controller)
+
+    try {
+      const promptTokens = sequence.tokenMeter.usedInputTokens
+      const content: string = await session.prompt(last.content, {
+        temperature: params.temperature,
+        topP: params.topP,
+        maxTokens: params.maxTokens,
+        customStopTriggers: params.stop,
+        signal: controller.signal,
+        stopOnAbortSignal: true,
+        onTextChunk: onChunk
+      })
+
+      const prompt_tokens = sequence.tokenMeter.usedInputTokens - promptTokens
+      const completion_tokens = this.model.tokenize(content).length
+      return {
+        content,
+        usage: { prompt_tokens, completion_tokens, total_tokens: prompt_tokens + completion_tokens }
+      }
+    } finally {
+      this.controllers.delete(requestId)
+      session.dispose({ disposeSequence: true })
+    }
+  }
+
+  public abort(requestId: string) {
+    this.controllers.get(requestId)?.abort()
+  }
+
+  /**
+   * The renderer acknowledges every chunk it handled, the batch size follows how fast it keeps up
+   */
+  public ackChunk(requestId: string, seq: number) {
+    this.batchers.get(requestId)?.ack(seq)
+  }
+
+  /**
+   * Streams the text in batches instead of a message per token, all chunks are sent before the completion resolves
+   */
+  public chatCompletionHandler = async (
+    event: Electron.IpcMainInvokeEvent,
+    requestId: string,
+    params: LocalChatCompletionParams
+  ) => {
+    const batcher = new StreamBatcher(({ seq, text }) => {
+      if (!event.sender.isDestroyed()) {
+        event.sender.send(IpcChannel.LocalInference_Chunk, { requestId, seq, text })
+      }
+    })
+    this.batchers.set(requestId, batcher)
+
+    try {
+      const completion = await this.chatCompletion(requestId, params, (text) => batcher.push(text))
+      batcher.flush()
+      return completion
+    } finally {
+      batcher.dispose()
+      this.batchers.delete(requestId)
+    }
+  }
+}
+
+export const localInferenceManager = new LocalInferenceManager()
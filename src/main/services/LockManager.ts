@@ -0,0 +1,258 @@
+import crypto from 'node:crypto'
+
+import { is } from '@electron-toolkit/utils'
+import { isMac } from '@main/constant'
+import { IpcChannel } from '@shared/IpcChannel'
+import { app, BrowserWindow, powerMonitor, systemPreferences } from 'electron'
+import Logger from 'electron-log'
+import { join } from 'path'
+
+import { configManager } from './ConfigManager'
+
+const IDLE_CHECK_INTERVAL = 30 * 1000
+// wrong passphrases after these are answered slower and slower, up to MAX_UNLOCK_DELAY
+const FREE_UNLOCK_ATTEMPTS = 3
+const MAX_UNLOCK_DELAY = 60 * 1000
+
+export function getUnlockDelay(failedAttempts: number): number {
+  if (failedAttempts < FREE_UNLOCK_ATTEMPTS) return 0
+  return Math.min(1000 * 2 ** (failedAttempts - FREE_UNLOCK_ATTEMPTS), MAX_UNLOCK_DELAY)
+}
+
+class LockManager {
+  private locked = false
+  private lockWindow: BrowserWindow | null = null
+  private hiddenWindows: BrowserWindow[] = []
+  private idleTimer: NodeJS.Timeout | null = null
+  private failedAttempts = 0
+  private unlocking = false
+
+  public init() {
+    // windows created while locked, e.g. the selection toolbar or an action window, stay hidden until unlocked
+    app.on('browser-window-created', (_, win) => this.guardWindow(win))
+    BrowserWindow.getAllWindows().forEach((win) => this.guardWindow(win))
+
+    powerMonitor.on('lock-screen', () => {
+      if (this.hasPassphrase() && configManager.getLockOnSystemLock()) {
+        this.lockApp()
+      }
+    })
+    this.startIdleMonitor()
+  }
+
+  public isLocked(): boolean {
+    return this.locked
+  }
+
+  public hasPassphrase(): boolean {
+    return !!configManager.getLockPassphraseHash()
+  }
+
+  public canUseBiometric(): boolean {
+    return isMac && systemPreferences.canPromptTouchID()
+  }
+
+  /**
+   * Whether `passphrase` is the unlock passphrase, false when none is set
+   */
+  public checkPassphrase(passphrase: string): boolean {
+    return this.hasPassphrase() && this.verifyPassphrase(passphrase)
+  }
+
+  /**
+   * Set or clear the unlock passphrase. Changing an existing passphrase requires the current one.
+   */
+  public setPassphrase(passphrase: string | null, currentPassphrase?: string): boolean {
+    if (this.hasPassphrase() && !this.verifyPassphrase(currentPassphrase ?? '')) {
+      return false
+    }
+
+    if (!passphrase) {
+      configManager.setLockPassphraseHash('')
+      return true
+    }
+
+    const salt = crypto.randomBytes(16).toString('hex')
+    const hash = crypto.scryptSync(passphrase, salt, 64).toString('hex')
+    configManager.setLockPassphraseHash(`${salt}:${hash}`)
+    return true
+  }
+
+  public setIdleTimeout(minutes: number) {
+    configManager.setLockIdleTimeout(Math.max(0, minutes))
+    this.startIdleMonitor()
+  }
+
+  public lockApp(): boolean {
+    if (this.locked) {
+      this.lockWindow?.focus()
+      return true
+    }
+
+    if (!this.hasPassphrase()) {
+      Logger.warn('[LockManager] Cannot lock app without a passphrase')
+      return false
+    }
+
+    this.locked = true
+    this.hiddenWindows = BrowserWindow.getAllWindows().filter((win) => !win.isDestroyed() && win.isVisible())
+    this.hiddenWindows.forEach((win) => win.hide())
+    this.broadcast(true)
+
+    this.lockWindow = this.createLockWindow()
+    Logger.info('[LockManager] App locked')
+    return true
+  }
+
+  /**
+   * One attempt at a time, each is delayed by the number of wrong passphrases before it
+   */
+  public async unlock(passphrase: string): Promise<boolean> {
+    if (!this.locked) return true
+    if (this.unlocking) return false
+
+    this.unlocking = true
+    try {
+      const delay = getUnlockDelay(this.failedAttempts)
+      if (delay > 0) await new Promise((resolve) => setTimeout(resolve, delay))
+
+      if (!this.verifyPassphrase(passphrase)) {
+        this.failedAttempts++
+        Logger.warn(`[LockManager] Wrong passphrase, ${this.failedAttempts} failed attempts`)
+        return false
+      }
+
+      this.doUnlock()
+      return true
+    } finally {
+      this.unlocking = false
+    }
+  }
+
+  public async unlockWithBiometric(): Promise<boolean> {
+    if (!this.locked) return true
+    if (!this.canUseBiometric()) return false
+
+    try {
+      await systemPreferences.promptTouchID('unlock Cherry Studio')
+      this.doUnlock()
+      return true
+    } catch (error) {
+      Logger.warn('[LockManager] Biometric unlock failed:', error)
+      return false
+    }
+  }
+
+  public focusLockWindow() {
+    if (this.lockWindow && !this.lockWindow.isDestroyed()) {
+      this.lockWindow.show()
+      this.lockWindow.focus()
+    }
+  }
+
+  private guardWindow(win: BrowserWindow) {
+    const hide = () => {
+      if (!this.locked || win.isDestroyed() || win === this.lockWindow) return
+      win.hide()
+      if (!this.hiddenWindows.includes(win)) this.hiddenWindows.push(win)
+      this.focusLockWindow()
+    }
+    win.on('show', hide)
+    // windows created visible have already been shown
+    if (win.isVisible()) hide()
+  }
+
+  private doUnlock() {
+    this.locked = false
+    this.failedAttempts = 0
+
+    const lockWindow = this.lockWindow
+    this.lockWindow = null
+    if (lockWindow && !lockWindow.isDestroyed()) {
+      lockWindow.destroy()
+    }
+
+    this.hiddenWindows.filter((win) => !win.isDestroyed()).forEach((win) => win.show())
+    this.hiddenWindows = []
+    this.broadcast(false)
+    Logger.info('[LockManager] App unlocked')
+  }
+
+  private verifyPassphrase(passphrase: string): boolean {
+    const stored = configManager.getLockPassphraseHash()
+    if (!stored) return true
+
+    const [salt, hash] = stored.split(':')
+    const expected = Buffer.from(hash, 'hex')
+    const actual = crypto.scryptSync(passphrase, salt, 64)
+    return expected.length === actual.length && crypto.timingSafeEqual(expected, actual)
+  }
+
+  private startIdleMonitor() {
+    if (this.idleTimer) {
+      clearInterval(this.idleTimer)
+      this.idleTimer = null
+    }
+
+    const timeoutMinutes = configManager.getLockIdleTimeout()
+    if (timeoutMinutes <= 0) return
+
+    this.idleTimer = setInterval(() => {
+      if (this.locked || !this.hasPassphrase()) return
+      if (powerMonitor.getSystemIdleTime() >= timeoutMinutes * 60) {
+        this.lockApp()
+      }
+    }, IDLE_CHECK_INTERVAL)
+  }
+
+  private broadcast(locked: boolean) {
+    BrowserWindow.getAllWindows().forEach((win) => {
+      if (!win.isDestroyed()) {
+        win.webContents.send(IpcChannel.Lock_StateChanged, locked)
+      }
+    })
+  }
+
+  private createLockWindow(): BrowserWindow {
+    const lockWindow = new BrowserWindow({
+      width: 420,
+      height: 360,
+      show: false,
+      center: true,
+      resizable: false,
+      minimizable: false,
+      maximizable: false,
+      fullscreenable: false,
+      closable: false,
+      alwaysOnTop: true,
+      autoHideMenuBar: true,
+      titleBarStyle: 'hidden',
+      webPreferences: {
+        preload: join(__dirname, '../preload/index.js'),
+        sandbox: false,
+        devTools: is.dev
+      }
+    })
+
+    lockWindow.setContentProtection(true)
+    lockWindow.on('ready-to-show', () => {
+      lockWindow.show()
+      lockWindow.focus()
+    })
+
+    if (is.dev && process.env['ELECTRON_RENDERER_URL']) {
+      lockWindow.loadURL(process.env['ELECTRON_RENDERER_URL'] + '/lockScreen.html')
+    } else {
+      lockWindow.loadFile(join(__dirname, '../renderer/lockScreen.html'))
+    }
+
+    // the lock window is not closable, so quitting must tear it down explicitly
+    app.once('before-quit', () => {
+      if (!lockWindow.isDestroyed()) lockWindow.destroy()
+    })
+
+    return lockWindow
+  }
+}
+
+export const lockManager = new LockManager()
@@ -0,0 +1,117 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import {
+  BUILTIN_REDACTION_PATTERNS,
+  compileRedactionPatterns,
+  RedactionPattern,
+  RedactionResult,
+  redactText,
+  redactValue
+} from '@main/utils/redaction'
+import { ManagerError } from '@shared/ManagerError'
+import archiver from 'archiver'
+import { app, dialog } from 'electron'
+import Logger from 'electron-log'
+
+import { ConfigKeys, configManager } from './ConfigManager'
+import { secretVault } from './SecretVault'
+
+// nested log arguments deeper than this are written as a placeholder
+const MAX_DEPTH = 5
+
+/**
+ * Scrubs vault values, bearer tokens, well known key formats and the configured patterns from every log line
+ * before electron-log writes it, and from the log files put in a support bundle.
+ */
+class LogRedactor {
+  private secrets: string[] = []
+  private patterns: RedactionPattern[] = BUILTIN_REDACTION_PATTERNS
+
+  public init() {
+    this.refreshSecrets()
+    this.refreshPatterns()
+    secretVault.onChange(() => this.refreshSecrets())
+    configManager.subscribe(ConfigKeys.LogRedactionPatterns, () => this.refreshPatterns())
+    const redact = (text: string) => this.redact(text).text
+    Logger.hooks.push((message) => ({
+      ...message,
+      data: message.data.map((item) => redactValue(item, redact, MAX_DEPTH))
+    }))
+  }
+
+  public redact(text: string): RedactionResult {
+    return redactText(text, this.secrets, this.patterns)
+  }
+
+  public getPatterns(): string[] {
+    return configManager.getLogRedactionPatterns()
+  }
+
+  public setPatterns(sources: string[]) {
+    const { invalid } = compileRedactionPatterns(sources)
+    if (invalid.length) {
+      throw new ManagerError('LogRedactor', 'invalid_argument', `Invalid regular expression: ${invalid[0]}`)
+    }
+    configManager.setLogRedactionPatterns(sources.map((source) => source.trim()).filter(Boolean))
+  }
+
+  /**
+   * Zip the redacted log files with basic information about the app, returns the path or null when canceled
+   */
+  public async exportSupportBundle(): Promise<string | null> {
+    const { canceled, filePath } = await dialog.showSaveDialog({
+      defaultPath: `cherry-studio-support-${new Date().toISOString().slice(0, 10)}.zip`,
+      filters: [{ name: 'Zip', extensions: ['zip'] }]
+    })
+    if (canceled || !filePath) return null
+
+    const logsDir = path.dirname(Logger.transports.file.getFile().path)
+    const logFiles = (await fs.promises.readdir(logsDir).catch(() => [] as string[])).filter((name) =>
+      name.endsWith('.log')
+    )
+
+    const archive = archiver('zip', { zlib: { level: 9 } })
+    const output = fs.createWriteStream(filePath)
+    const done = new Promise<void>((resolve, reject) => {
+      output.on('close', resolve)
+      archive.on('error', reject)
+    })
+    archive.pipe(output)
+
+    const info = {
+      version: app.getVersion(),
+      platform: process.platform,
+      arch: process.arch,
+      electron: process.versions.electron,
+      locale: app.getLocale(),
+      createdAt: new Date().toISOString()
+    }
+    archive.append(JSON.stringify(info, null, 2), { name: 'app-info.json' })
+    // lines written before the current patterns existed are scrubbed again
+    for (const name of logFiles) {
+      const content = await fs.promises.readFile(path.join(logsDir, name), 'utf-8')
+      archive.append(this.redact(content).text, { name: `logs/${name}` })
+    }
+
+    await archive.finalize()
+    await done
+    Logger.info(`[LogRedactor] Exported support bundle with ${logFiles.length} log files to ${filePath}`)
+    return filePath
+  }
+
+  private refreshSecrets() {
+    try {
+      this.secrets = secretVault.values()
+    } catch {
+      this.secrets = []
+    }
+  }
+
+  private refreshPatterns() {
+    const { patterns } = compileRedactionPatterns(configManager.getLogRedactionPatterns())
+    this.patterns = [...BUILTIN_REDACTION_PATTERNS, ...patterns]
+  }
+}
+
+export const logRedactor = new LogRedactor()
@@ -0,0 +1,370 @@
+import { ChildProcess, spawn } from 'node:child_process'
+import fs from 'node:fs'
+import os from 'node:os'
+import path from 'node:path'
+
+import { isWin } from '@main/constant'
+import { getResourcePath } from '@main/utils'
+import { isInsideDirectory } from '@main/utils/audit'
+import { compareVersions, diffPermissions, getEntryBin, parseRegistry, resolveNodeBin } from '@main/utils/mcpRegistry'
+import { getBinaryPath, isBinaryExists, isCommandOnPath } from '@main/utils/process'
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import {
+  InstalledMCPServer,
+  MCPDependencies,
+  MCPRegistryEntry,
+  MCPServer,
+  MCPServerHealth,
+  MCPServerUpdate
+} from '@types'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import aoxisProxy from './AxiosProxy'
+import { CacheService } from './CacheService'
+import { configManager } from './ConfigManager'
+import { windowService } from './WindowService'
+
+const REGISTRY_TTL = 60 * 60 * 1000
+const REGISTRY_TIMEOUT = 15 * 1000
+// an install running longer than this is stuck, the health monitor kills it
+const INSTALL_TIMEOUT = 10 * 60 * 1000
+const UPDATE_INTERVAL = 24 * 60 * 60 * 1000
+// the first update check waits for the startup to settle
+const UPDATE_DELAY = 60 * 1000
+
+interface InstallTask {
+  startedAt: number
+  child?: ChildProcess
+}
+
+function getToolsDir() {
+  return path.join(os.homedir(), '.cherrystudio', 'mcp')
+}
+
+/**
+ * Folder of a server (or one of its versions) in the tools folder. Everything removed or installed goes through
+ * here, a registry entry must never point bun, uv or `rm` outside of it.
+ */
+function getToolPath(...segments: string[]) {
+  const root = getToolsDir()
+  const dir = path.resolve(root, ...segments)
+  if (dir === root || !isInsideDirectory(dir, root)) {
+    throw new ManagerError('MCPRegistryManager', 'invalid_argument', `Invalid tool path: ${segments.join('/')}`)
+  }
+  return dir
+}
+
+/**
+ * Marketplace of MCP servers.
+ *
+ * Servers of the curated registry (shipped with the app or fetched from the configured url) are installed with bun
+ * or uv into `~/.cherrystudio/mcp/<id>/<version>`, so they don't depend on the global packages of the system and
+ * run without downloading anything. Updates are listed with the permissions they add or remove. With auto update
+ * enabled, updates that don't ask for new permissions are applied daily, the others wait for the user.
+ */
+class MCPRegistryManager {
+  private tasks = new Map<string, InstallTask>()
+  private timer: NodeJS.Timeout | null = null
+
+  public init() {
+    this.timer = setTimeout(() => {
+      this.autoUpdate()
+      this.timer = setInterval(() => this.autoUpdate(), UPDATE_INTERVAL)
+    }, UPDATE_DELAY)
+  }
+
+  public stop() {
+    if (this.timer) {
+      clearTimeout(this.timer)
+      this.timer = null
+    }
+  }
+
+  public async getRegistry(refresh = false): Promise<MCPRegistryEntry[]> {
+    const url = configManager.getMcpRegistryUrl()
+    const key = `mcp-registry:${url}`
+    const cached = CacheService.get<MCPRegistryEntry[]>(key)
+    if (cached && !refresh) return cached
+
+    let data: unknown
+    if (!url) {
+      const file = path.join(getResourcePath(), 'data', 'mcp-registry.json')
+      data = JSON.parse(await fs.promises.readFile(file, 'utf-8'))
+    } else {
+      try {
+        data = (await aoxisProxy.axios.get(url, { timeout: REGISTRY_TIMEOUT })).data
+      } catch (error: any) {
+        const message = `Failed to load the registry ${url}: ${error.message}`
+        throw new ManagerError('MCPRegistryManager', 'unavailable', message)
+      }
+    }
+
+    const entries = parseRegistry(data)
+    CacheService.set(key, entries, REGISTRY_TTL)
+    return entries
+  }
+
+  public async checkDependencies(): Promise<MCPDependencies> {
+    const [bun, uv] = await Promise.all([isBinaryExists('bun'), isBinaryExists('uv')])
+    return { bun, uv, node: isCommandOnPath('node'), python: isCommandOnPath(isWin ? 'python' : 'python3') }
+  }
+
+  public getInstalled(): InstalledMCPServer[] {
+    return configManager.getMcpInstalledServers()
+  }
+
+  /**
+   * Install a server of the registry, resolves with the MCP server to add, inactive until the user enabled it
+   */
+  public async install(id: string): Promise<MCPServer> {
+    const entry = await this.getEntry(id)
+    if (this.getInstalled().some((item) => item.id === id)) {
+      throw new ManagerError('MCPRegistryManager', 'conflict', `${entry.name} is already installed`)
+    }
+
+    const installed = await this.installEntry(entry, uuidv4())
+    this.save([...this.getInstalled(), installed])
+    Logger.info(`[MCPRegistryManager] Installed ${entry.package}@${entry.version}`)
+
+    return {
+      id: installed.serverId,
+      name: entry.name,
+      description: entry.description,
+      type: 'stdio',
+      command: installed.command,
+      args: installed.args,
+      env: Object.fromEntries(Object.keys(entry.env ?? {}).map((name) => [name, ''])),
+      isActive: false,
+      provider: 'Marketplace',
+      providerUrl: entry.homepage,
+      tags: entry.tags
+    }
+  }
+
+  public async uninstall(id: string) {
+    const installed = this.getInstalled()
+    if (!installed.some((item) => item.id === id)) {
+      throw new ManagerError('MCPRegistryManager', 'not_found', `Not installed: ${id}`)
+    }
+    if (this.tasks.has(id)) {
+      throw new ManagerError('MCPRegistryManager', 'busy', `${id} is being installed`)
+    }
+
+    await fs.promises.rm(getToolPath(id), { recursive: true, force: true })
+    this.save(installed.filter((item) => item.id !== id))
+    Logger.info(`[MCPRegistryManager] Uninstalled ${id}`)
+  }
+
+  /**
+   * Whether the files of each install are still there and whether the registry has a newer version
+   */
+  public async checkHealth(): Promise<MCPServerHealth[]> {
+    const registry = await this.getRegistry().catch(() => [] as MCPRegistryEntry[])
+    return this.getInstalled().map((item) => {
+      const files = [item.dir, item.command, ...item.args].filter((file) => file.startsWith(item.dir))
+      const entry = registry.find((candidate) => candidate.id === item.id)
+      const status = !files.every((file) => fs.existsSync(file))
+        ? 'missing'
+        : entry && compareVersions(entry.version, item.version) > 0
+          ? 'outdated'
+          : 'ok'
+      return { id: item.id, status, checkedAt: Date.now() }
+    })
+  }
+
+  public async checkUpdates(): Promise<MCPServerUpdate[]> {
+    const registry = await this.getRegistry(true)
+    return this.getInstalled().flatMap((item) => {
+      const entry = registry.find((candidate) => candidate.id === item.id)
+      if (!entry || compareVersions(entry.version, item.version) <= 0) return []
+
+      const { added, removed } = diffPermissions(item.permissions, entry.permissions)
+      return [
+        {
+          id: item.id,
+          name: entry.name,
+          from: item.version,
+          to: entry.version,
+          addedPermissions: added,
+          removedPermissions: removed
+        }
+      ]
+    })
+  }
+
+  /**
+   * Install the registry's version next to the current one and switch the server to it. The previous and the new
+   * install are sent to the main window as `mcp:server-updated`, which points the server to the new files.
+   */
+  public async update(id: string): Promise<InstalledMCPServer> {
+    const previous = this.getInstalled().find((item) => item.id === id)
+    if (!previous) {
+      throw new ManagerError('MCPRegistryManager', 'not_found', `Not installed: ${id}`)
+    }
+    const entry = await this.getEntry(id)
+    if (compareVersions(entry.version, previous.version) <= 0) return previous
+
+    const installed = await this.installEntry(entry, previous.serverId, previous)
+    this.save(this.getInstalled().map((item) => (item.id === id ? installed : item)))
+
+    windowService.getMainWindow()?.webContents.send(IpcChannel.Mcp_ServerUpdated, { previous, installed })
+    Logger.info(`[MCPRegistryManager] Updated ${entry.package} from ${previous.version} to ${entry.version}`)
+    return installed
+  }
+
+  public isStalled(): boolean {
+    return [...this.tasks.values()].some((task) => Date.now() - task.startedAt > INSTALL_TIMEOUT)
+  }
+
+  /**
+   * Kill installs that hang, e.g. on a package manager waiting for the network
+   */
+  public reset() {
+    this.tasks.forEach((task, id) => {
+      if (Date.now() - task.startedAt <= INSTALL_TIMEOUT) return
+      Logger.warn(`[MCPRegistryManager] Killing stalled install of ${id}`)
+      task.child?.kill()
+    })
+  }
+
+  private async autoUpdate() {
+    if (!configManager.getMcpAutoUpdate()) return
+
+    try {
+      for (const update of await this.checkUpdates()) {
+        if (update.addedPermissions.length > 0) {
+          Logger.info(`[MCPRegistryManager] ${update.id} ${update.to} asks for new permissions, waiting for the user`)
+          continue
+        }
+        await this.update(update.id).catch((error) =>
+          Logger.warn(`[MCPRegistryManager] Failed to update ${update.id}:`, error)
+        )
+      }
+    } catch (error) {
+      Logger.warn('[MCPRegistryManager] Failed to check for updates:', error)
+    }
+  }
+
+  private async getEntry(id: string): Promise<MCPRegistryEntry> {
+    const entry = (await this.getRegistry()).find((item) => item.id === id)
+    if (!entry) {
+      throw new ManagerError('MCPRegistryManager', 'not_found', `No server in the registry: ${id}`)
+    }
+    return entry
+  }
+
+  private async installEntry(
+    entry: MCPRegistryEntry,
+    serverId: string,
+    previous?: InstalledMCPServer
+  ): Promise<InstalledMCPServer> {
+    if (this.tasks.has(entry.id)) {
+      throw new ManagerError('MCPRegistryManager', 'busy', `${entry.name} is being installed`)
+    }
+    const tool = entry.runtime === 'node' ? 'bun' : 'uv'
+    if (!(await isBinaryExists(tool))) {
+      throw new ManagerError('MCPRegistryManager', 'unavailable', `${tool} is required to install ${entry.name}`)
+    }
+
+    const dir = getToolPath(entry.id, entry.version)
+    this.tasks.set(entry.id, { startedAt: Date.now() })
+    try {
+      await fs.promises.rm(dir, { recursive: true, force: true })
+      await fs.promises.mkdir(dir, { recursive: true })
+      const { command, args } =
+        entry.runtime === 'node' ? await this.installNode(entry, dir) : await this.installPython(entry, dir)
+      await this.removeOldVersions(entry.id, [dir, previous?.dir])
+
+      return {
+        id: entry.id,
+        serverId,
+        version: entry.version,
+        permissions: entry.permissions,
+        dir,
+        command,
+        args,
+        installedAt: previous?.installedAt ?? Date.now(),
+        updatedAt: previous ? Date.now() : undefined
+      }
+    } catch (error) {
+      await fs.promises.rm(dir, { recursive: true, force: true }).catch(() => undefined)
+      throw ManagerError.from('MCPRegistryManager', error)
+    } finally {
+      this.tasks.delete(entry.id)
+    }
+  }
+
+  /**
+   * Keep the new and the previous version only, the previous one may still run until its server restarts
+   */
+  private async removeOldVersions(id: string, keep: (string | undefined)[]) {
+    const versions = await fs.promises.readdir(getToolPath(id)).catch(() => [] as string[])
+    await Promise.all(
+      versions
+        .map((version) => getToolPath(id, version))
+        .filter((dir) => !keep.includes(dir))
+        .map((dir) => fs.promises.rm(dir, { recursive: true, force: true }).catch(() => undefined))
+    )
+  }
+
+  private async installNode(entry: MCPRegistryEntry, dir: string) {
+    const bun = await getBinaryPath('bun')
+    const manifestFile = path.join(dir, 'package.json')
+    await fs.promises.writeFile(manifestFile, JSON.stringify({ name: `mcp-${entry.id}`, private: true }))
+    await this.run(entry.id, bun, ['add', '--exact', '--', `${entry.package}@${entry.version}`], dir)
+
+    const packageDir = path.join(dir, 'node_modules', ...entry.package.split('/'))
+    const manifest = JSON.parse(await fs.promises.readFile(path.join(packageDir, 'package.json'), 'utf-8'))
+    const script = resolveNodeBin(packageDir, manifest, getEntryBin(entry))
+    if (!script || !fs.existsSync(script)) {
+      throw new ManagerError('MCPRegistryManager', 'not_found', `${entry.package} has no executable`)
+    }
+    return { command: bun, args: [script, ...(entry.args ?? [])] }
+  }
+
+  private async installPython(entry: MCPRegistryEntry, dir: string) {
+    const binDir = path.join(dir, 'bin')
+    const uv = await getBinaryPath('uv')
+    await this.run(entry.id, uv, ['tool', 'install', '--', `${entry.package}==${entry.version}`], dir, {
+      UV_TOOL_DIR: path.join(dir, 'tools'),
+      UV_TOOL_BIN_DIR: binDir
+    })
+
+    const command = path.join(binDir, isWin ? `${getEntryBin(entry)}.exe` : getEntryBin(entry))
+    if (!fs.existsSync(command)) {
+      throw new ManagerError('MCPRegistryManager', 'not_found', `${entry.package} has no executable`)
+    }
+    return { command, args: entry.args ?? [] }
+  }
+
+  private run(id: string, command: string, args: string[], cwd: string, env: Record<string, string> = {}) {
+    const name = path.basename(command)
+    return new Promise<void>((resolve, reject) => {
+      const child = spawn(command, args, { cwd, env: { ...process.env, ...env }, windowsHide: true })
+      const task = this.tasks.get(id)
+      if (task) task.child = child
+
+      // the tail of the output explains failures
+      let output = ''
+      const collect = (data: Buffer) => (output = (output + data.toString()).slice(-2000).trim())
+      child.stdout?.on('data', collect)
+      child.stderr?.on('data', collect)
+
+      child.on('error', (error) => {
+        reject(new ManagerError('MCPRegistryManager', 'unavailable', `Failed to run ${name}: ${error.message}`))
+      })
+      child.on('close', (code) => {
+        if (code === 0) resolve()
+        else reject(new ManagerError('MCPRegistryManager', 'unavailable', `${name} exited with ${code}: ${output}`))
+      })
+    })
+  }
+
+  private save(installed: InstalledMCPServer[]) {
+    configManager.setMcpInstalledServers(installed)
+  }
+}
+
+export const mcpRegistryManager = new MCPRegistryManager()
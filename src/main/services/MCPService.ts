@@ -6,6 +6,7 @@ import { createInMemoryMCPServer } from '@main/mcpServers/factory'
 import { makeSureDirExists } from '@main/utils'
 import { buildFunctionCallToolName } from '@main/utils/mcp'
 import { getBinaryName, getBinaryPath } from '@main/utils/process'
+import { getToolCallError, stripBinaryContent } from '@main/utils/toolCalls'
 import { Client } from '@modelcontextprotocol/sdk/client/index.js'
 import { SSEClientTransport, SSEClientTransportOptions } from '@modelcontextprotocol/sdk/client/sse.js'
 import { StdioClientTransport } from '@modelcontextprotocol/sdk/client/stdio.js'
@@ -15,6 +16,7 @@ import {
 } from '@modelcontextprotocol/sdk/client/streamableHttp'
 import { InMemoryTransport } from '@modelcontextprotocol/sdk/inMemory'
 import { nanoid } from '@reduxjs/toolkit'
+import { ManagerError } from '@shared/ManagerError'
 import {
   GetMCPPromptResponse,
   GetResourceResponse,
@@ -22,17 +24,23 @@ import {
   MCPPrompt,
   MCPResource,
   MCPServer,
-  MCPTool
+  MCPTool,
+  ToolCallContext,
+  ToolCallRecord
 } from '@types'
 import { app } from 'electron'
 import Logger from 'electron-log'
 import { EventEmitter } from 'events'
 import { memoize } from 'lodash'
 
+import { actionPolicyManager } from './ActionPolicyManager'
 import { CacheService } from './CacheService'
+import { errorReporter } from './ErrorReporter'
 import { CallBackServer } from './mcp/oauth/callback'
 import { McpOAuthClientProvider } from './mcp/oauth/provider'
 import getLoginShellEnvironment from './mcp/shell-env'
+import { reduxService } from './ReduxService'
+import { toolCallRecorder } from './ToolCallRecorder'
 
 // Generic type for caching wrapped functions
 type CachedFunction<T extends unknown[], R> = (...args: T) => Promise<R>
@@ -76,6 +84,7 @@ class McpService {
     this.initClient = this.initClient.bind(this)
     this.listTools = this.listTools.bind(this)
     this.callTool = this.callTool.bind(this)
+    this.replayToolCall = this.replayToolCall.bind(this)
     this.listPrompts = this.listPrompts.bind(this)
     this.getPrompt = this.getPrompt.bind(this)
     this.listResources = this.listResources.bind(this)
@@ -330,6 +339,18 @@ class McpService {
 
           // Store the new client in the cache
           this.clients.set(serverKey, client)
+          // servers closed by us are removed from the cache first, anything else exited or dropped the connection
+          client.onclose = () => {
+            if (this.clients.get(serverKey) !== client) return
+            this.clients.delete(serverKey)
+            CacheService.remove(`mcp:list_tool:${serverKey}`)
+            errorReporter.report(
+              'MCPService',
+              new ManagerError('MCPService', 'unavailable', `MCP server ${server.name} stopped unexpectedly`, {
+                details: { serverId: server.id, serverName: server.name }
+              })
+            )
+          }
 
           Logger.info(`[MCP] Activated server: ${server.name}`)
           return client
@@ -352,10 +373,10 @@ class McpService {
   async closeClient(serverKey: string) {
     const client = this.clients.get(serverKey)
     if (client) {
-      // Remove the client from the cache
+      // Remove the client from the cache before closing, so closing is not reported as a failure
+      this.clients.delete(serverKey)
       await client.close()
       Logger.info(`[MCP] Closed server: ${serverKey}`)
-      this.clients.delete(serverKey)
       CacheService.remove(`mcp:list_tool:${serverKey}`)
       Logger.info(`[MCP] Cleared cache for server: ${serverKey}`)
     } else {
@@ -455,25 +476,69 @@ class McpService {
    */
   public async callTool(
     _: Electron.IpcMainInvokeEvent,
-    { server, name, args }: { server: MCPServer; name: string; args: any }
+    { server, name, args, context }: { server: MCPServer; name: string; args: any; context?: ToolCallContext }
   ): Promise<MCPCallToolResponse> {
-    try {
-      Logger.info('[MCP] Calling:', server.name, name, args)
-      if (typeof args === 'string') {
-        try {
-          args = JSON.parse(args)
-        } catch (e) {
-          Logger.error('[MCP] args parse error', args)
-        }
+    Logger.info('[MCP] Calling:', server.name, name, args)
+    if (typeof args === 'string') {
+      try {
+        args = JSON.parse(args)
+      } catch (e) {
+        Logger.error('[MCP] args parse error', args)
       }
+    }
+    return this.runTool(server, name, args, { topicId: context?.topicId, messageId: context?.messageId })
+  }
+
+  /**
+   * Call a recorded tool call again with its recorded arguments, the server is looked up by its id
+   */
+  public async replayToolCall(_: Electron.IpcMainInvokeEvent, callId: string): Promise<MCPCallToolResponse> {
+    const call = await toolCallRecorder.get(callId)
+    const servers = await reduxService.select<MCPServer[]>('state.mcp.servers')
+    const server = servers?.find((item) => item.id === call.serverId)
+    if (!server) {
+      throw new ManagerError('MCPService', 'not_found', `Server of the tool call not found: ${call.serverName}`)
+    }
+    Logger.info('[MCP] Replaying:', server.name, call.toolName, callId)
+    return this.runTool(server, call.toolName, call.args, {
+      topicId: call.topicId,
+      messageId: call.messageId,
+      replayOf: call.replayOf ?? call.id
+    })
+  }
+
+  private async runTool(
+    server: MCPServer,
+    name: string,
+    args: any,
+    record: Pick<ToolCallRecord, 'topicId' | 'messageId' | 'replayOf'>
+  ): Promise<MCPCallToolResponse> {
+    const startedAt = Date.now()
+    let result: MCPCallToolResponse | undefined
+    let error: unknown
+    try {
+      await actionPolicyManager.authorizeToolCall(server.name, name, args)
       const client = await this.initClient(server)
-      const result = await client.callTool({ name, arguments: args }, undefined, {
+      result = (await client.callTool({ name, arguments: args }, undefined, {
         timeout: server.timeout ? server.timeout * 1000 : 60000 // Default timeout of 1 minute
+      })) as MCPCallToolResponse
+      return result
+    } catch (e) {
+      error = e
+      Logger.error(`[MCP] Error calling tool ${name} on ${server.name}:`, e)
+      throw e
+    } finally {
+      toolCallRecorder.record({
+        ...record,
+        serverId: server.id,
+        serverName: server.name,
+        toolName: name,
+        args,
+        result: result && stripBinaryContent(result),
+        error: getToolCallError(result, error),
+        startedAt,
+        duration: Date.now() - startedAt
       })
-      return result as MCPCallToolResponse
-    } catch (error) {
-      Logger.error(`[MCP] Error calling tool ${name} on ${server.name}:`, error)
-      throw error
     }
   }
 
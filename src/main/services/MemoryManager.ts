@@ -0,0 +1,260 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import Embeddings from '@main/embeddings/Embeddings'
+import { getDataPath } from '@main/utils'
+import { ManagerError } from '@shared/ManagerError'
+import { KnowledgeBaseParams } from '@types'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import { configManager } from './ConfigManager'
+
+export interface MemorySource {
+  assistantId?: string
+  topicId?: string
+  messageId?: string
+}
+
+export interface Memory {
+  id: string
+  content: string
+  category: 'fact' | 'preference'
+  source: MemorySource
+  // 0..1, as reported by the extraction model or the user
+  confidence: number
+  embedding?: number[]
+  embeddingModel?: string
+  createdAt: number
+  lastAccessedAt: number
+  accessCount: number
+  expiresAt?: number
+}
+
+export interface AddMemoryInput {
+  content: string
+  category?: Memory['category']
+  source?: MemorySource
+  confidence?: number
+  expiresAt?: number
+}
+
+export interface MemorySearchResult {
+  memory: Omit<Memory, 'embedding'>
+  score: number
+}
+
+const DAY = 24 * 60 * 60 * 1000
+// memories whose decayed confidence drops below this are forgotten
+const FORGET_THRESHOLD = 0.1
+// a new memory this close to an existing one reinforces it instead of being stored twice
+const DUPLICATE_SIMILARITY = 0.92
+
+class MemoryManager {
+  private memoryFile = path.join(getDataPath(), 'Memory', 'memories.json')
+  private memories: Map<string, Memory> = new Map()
+
+  constructor() {
+    this.load()
+  }
+
+  public init() {
+    this.applyDecay()
+    setInterval(() => this.applyDecay(), DAY)
+  }
+
+  /**
+   * Store an extracted fact. When an embedding model is given it is used for similarity, otherwise
+   * memories are matched lexically.
+   */
+  public async addMemory(input: AddMemoryInput, embeddingParams?: KnowledgeBaseParams): Promise<Memory> {
+    const content = input.content.trim()
+    if (!content) {
+      throw new ManagerError('MemoryManager', 'invalid_argument', 'Memory content is empty')
+    }
+
+    const embedding = embeddingParams ? await this.embed(content, embeddingParams) : undefined
+    const embeddingModel = embeddingParams?.model
+
+    const duplicate = this.findSimilar(content, embedding, embeddingModel)
+      .filter(({ score }) => score >= DUPLICATE_SIMILARITY)
+      .shift()
+    if (duplicate) {
+      const memory = this.memories.get(duplicate.memory.id)!
+      memory.confidence = Math.min(1, Math.max(memory.confidence, input.confidence ?? 0.5) + 0.1)
+      memory.lastAccessedAt = Date.now()
+      this.save()
+      return memory
+    }
+
+    const now = Date.now()
+    const memory: Memory = {
+      id: uuidv4(),
+      content,
+      category: input.category ?? 'fact',
+      source: input.source ?? {},
+      confidence: Math.min(1, Math.max(0, input.confidence ?? 0.5)),
+      embedding,
+      embeddingModel,
+      createdAt: now,
+      lastAccessedAt: now,
+      accessCount: 0,
+      expiresAt: input.expiresAt
+    }
+    this.memories.set(memory.id, memory)
+    this.save()
+    return memory
+  }
+
+  /**
+   * Most relevant memories for a query, weighted by their decayed confidence
+   */
+  public async searchMemories(
+    query: string,
+    limit: number = 5,
+    embeddingParams?: KnowledgeBaseParams
+  ): Promise<MemorySearchResult[]> {
+    const embedding = embeddingParams ? await this.embed(query, embeddingParams) : undefined
+    const results = this.findSimilar(query, embedding, embeddingParams?.model)
+      .map(({ memory, score }) => ({ memory, score: score * this.getEffectiveConfidence(memory) }))
+      .filter(({ score }) => score > 0)
+      .sort((a, b) => b.score - a.score)
+      .slice(0, limit)
+
+    // recalled memories stay fresh
+    const now = Date.now()
+    results.forEach(({ memory }) => {
+      memory.lastAccessedAt = now
+      memory.accessCount++
+    })
+    if (results.length > 0) this.save()
+
+    return results.map(({ memory, score }) => ({ memory: this.strip(memory), score }))
+  }
+
+  public listMemories(): Omit<Memory, 'embedding'>[] {
+    return [...this.memories.values()].sort((a, b) => b.createdAt - a.createdAt).map((memory) => this.strip(memory))
+  }
+
+  public deleteMemory(id: string): boolean {
+    const deleted = this.memories.delete(id)
+    if (deleted) this.save()
+    return deleted
+  }
+
+  public clearMemories() {
+    this.memories.clear()
+    this.save()
+  }
+
+  /**
+   * Export all memories as JSON, written to `filePath` when given
+   */
+  public async exportMemories(filePath?: string): Promise<string> {
+    const data = JSON.stringify({ version: 1, exportedAt: Date.now(), memories: this.listMemories() }, null, 2)
+    if (filePath) {
+      await fs.promises.writeFile(filePath, data, 'utf-8')
+    }
+    return data
+  }
+
+  /**
+   * Confidence halves every half-life since the memory was last recalled
+   */
+  private getEffectiveConfidence(memory: Memory): number {
+    const halfLife = configManager.getMemoryHalfLifeDays()
+    if (halfLife <= 0) return memory.confidence
+    const age = (Date.now() - memory.lastAccessedAt) / DAY
+    return memory.confidence * Math.pow(0.5, age / halfLife)
+  }
+
+  private applyDecay() {
+    const now = Date.now()
+    let removed = 0
+    for (const memory of [...this.memories.values()]) {
+      const expired = memory.expiresAt !== undefined && memory.expiresAt <= now
+      if (expired || this.getEffectiveConfidence(memory) < FORGET_THRESHOLD) {
+        this.memories.delete(memory.id)
+        removed++
+      }
+    }
+    if (removed > 0) {
+      Logger.info(`[MemoryManager] Forgot ${removed} memories`)
+      this.save()
+    }
+  }
+
+  private findSimilar(text: string, embedding?: number[], embeddingModel?: string) {
+    const now = Date.now()
+    return [...this.memories.values()]
+      .filter((memory) => memory.expiresAt === undefined || memory.expiresAt > now)
+      .map((memory) => ({
+        memory,
+        score:
+          embedding && memory.embedding && memory.embeddingModel === embeddingModel
+            ? this.cosineSimilarity(embedding, memory.embedding)
+            : this.lexicalSimilarity(text, memory.content)
+      }))
+      .sort((a, b) => b.score - a.score)
+  }
+
+  private async embed(text: string, params: KnowledgeBaseParams): Promise<number[] | undefined> {
+    try {
+      const embeddings = Embeddings.get(params)
+      await embeddings.init()
+      return await embeddings.embedQuery(text)
+    } catch (error) {
+      Logger.warn('[MemoryManager] Failed to embed memory, falling back to lexical matching:', error)
+      return undefined
+    }
+  }
+
+  private cosineSimilarity(a: number[], b: number[]): number {
+    if (a.length !== b.length) return 0
+    let dot = 0
+    let normA = 0
+    let normB = 0
+    for (let i = 0; i < a.length; i++) {
+      dot += a[i] * b[i]
+      normA += a[i] * a[i]
+      normB += b[i] * b[i]
+    }
+    return normA && normB ? dot / Math.sqrt(normA * normB) : 0
+  }
+
+  private lexicalSimilarity(a: string, b: string): number {
+    const tokenize = (text: string) => new Set(text.toLowerCase().match(/[\p{L}\p{N}]+/gu) ?? [])
+    const tokensA = tokenize(a)
+    const tokensB = tokenize(b)
+    if (tokensA.size === 0 || tokensB.size === 0) return 0
+    const overlap = [...tokensA].filter((token) => tokensB.has(token)).length
+    return overlap / Math.sqrt(tokensA.size * tokensB.size)
+  }
+
+  private strip(memory: Memory): Omit<Memory, 'embedding'> {
+    // eslint-disable-next-line @typescript-eslint/no-unused-vars
+    const { embedding, ...rest } = memory
+    return rest
+  }
+
+  private load() {
+    try {
+      if (!fs.existsSync(this.memoryFile)) return
+      const memories: Memory[] = JSON.parse(fs.readFileSync(this.memoryFile, 'utf-8'))
+      memories.forEach((memory) => this.memories.set(memory.id, memory))
+    } catch (error) {
+      Logger.error('[MemoryManager] Failed to load memories:', error)
+    }
+  }
+
+  private save() {
+    try {
+      fs.mkdirSync(path.dirname(this.memoryFile), { recursive: true })
+      fs.writeFileSync(this.memoryFile, JSON.stringify([...this.memories.values()]))
+    } catch (error) {
+      Logger.error('[MemoryManager] Failed to save memories:', error)
+    }
+  }
+}
+
+export const memoryManager = new MemoryManager()
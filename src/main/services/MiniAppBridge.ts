@@ -0,0 +1,221 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import {
+  getDeclaredPermissions,
+  getPermissionScope,
+  isHostAllowed,
+  isPathAllowed
+} from '@main/utils/miniAppPermissions'
+import { ManagerError } from '@shared/ManagerError'
+import { MiniAppPermission, MiniAppPermissionManifest } from '@types'
+import { clipboard, Notification, WebContents } from 'electron'
+import Logger from 'electron-log'
+
+import aoxisProxy from './AxiosProxy'
+import { miniAppManager } from './MiniAppManager'
+
+const MAX_FILE_SIZE = 20 * 1024 * 1024
+const FETCH_TIMEOUT = 30 * 1000
+const MAX_RESPONSE_SIZE = 20 * 1024 * 1024
+
+export interface MiniAppFetchInit {
+  method?: string
+  headers?: Record<string, string>
+  body?: string
+}
+
+export interface MiniAppFetchResponse {
+  status: number
+  headers: Record<string, string>
+  body: string
+}
+
+export interface MiniAppDirEntry {
+  name: string
+  isDirectory: boolean
+}
+
+type FileEncoding = 'utf-8' | 'base64'
+
+interface Caller {
+  appId: string
+  contents: WebContents
+  manifest: MiniAppPermissionManifest
+}
+
+type Command = (caller: Caller, ...args: any[]) => Promise<unknown>
+
+/**
+ * Commands mini apps call with `window.metheus`, e.g. `metheus.fs.readFile(path)` or `metheus.fetch(url)`.
+ *
+ * A command runs when the mini app declares its permission in the `permissions` of its manifest, the file is inside
+ * one of its folders or the url on one of its hosts, and the user allowed it. The user is asked on first use in the
+ * window hosting the mini app, remembered answers are kept with the other permission answers of mini apps.
+ * Mini apps without a manifest declare nothing and can't use any command.
+ */
+class MiniAppBridge {
+  // values of the `state` commands, shared by the mini apps holding `sharedState` until the app quits
+  private state = new Map<string, unknown>()
+  private commands = new Map<string, Command>([
+    ['fs.readFile', (caller, file: string, encoding?: FileEncoding) => this.readFile(caller, file, encoding)],
+    [
+      'fs.writeFile',
+      (caller, file: string, data: string, encoding?: FileEncoding) => this.writeFile(caller, file, data, encoding)
+    ],
+    ['fs.readDir', (caller, dir: string) => this.readDir(caller, dir)],
+    ['fetch', (caller, url: string, init?: MiniAppFetchInit) => this.fetch(caller, url, init)],
+    ['notify', (caller, title: string, body?: string) => this.notify(caller, title, body)],
+    ['clipboard.readText', (caller) => this.readClipboard(caller)],
+    ['clipboard.writeText', (caller, text: string) => this.writeClipboard(caller, text)],
+    ['state.get', (caller, key: string) => this.getState(caller, key)],
+    ['state.set', (caller, key: string, value: unknown) => this.setState(caller, key, value)]
+  ])
+
+  public async invoke(contents: WebContents, command: string, args: unknown[]): Promise<unknown> {
+    const handler = this.commands.get(command)
+    if (!handler) throw new ManagerError('MiniAppBridge', 'not_found', `Unknown bridge command: ${command}`)
+
+    const app = await miniAppManager.getCallerApp(contents)
+    return handler({ appId: app.id, contents, manifest: app.permissions ?? {} }, ...args)
+  }
+
+  private async readFile(caller: Caller, file: string, encoding: FileEncoding = 'utf-8'): Promise<string> {
+    const target = await this.authorizePath(caller, file)
+    const stats = await fs.promises.stat(target).catch(() => null)
+    if (!stats?.isFile()) throw new ManagerError('MiniAppBridge', 'not_found', `Not a file: ${file}`)
+    if (stats.size > MAX_FILE_SIZE) {
+      throw new ManagerError('MiniAppBridge', 'invalid_argument', `File is larger than 20 MB: ${file}`)
+    }
+    return fs.promises.readFile(target, encoding === 'base64' ? 'base64' : 'utf-8')
+  }
+
+  private async writeFile(caller: Caller, file: string, data: string, encoding: FileEncoding = 'utf-8') {
+    if (typeof data !== 'string') throw new ManagerError('MiniAppBridge', 'invalid_argument', 'Data must be a string')
+    const target = await this.authorizePath(caller, file)
+    try {
+      await fs.promises.writeFile(target, data, encoding === 'base64' ? 'base64' : 'utf-8')
+    } catch (error: any) {
+      throw new ManagerError('MiniAppBridge', 'permission_denied', `Failed to write ${file}: ${error.message}`)
+    }
+    Logger.info(`[MiniAppBridge] ${caller.appId} wrote ${target}`)
+  }
+
+  private async readDir(caller: Caller, dir: string): Promise<MiniAppDirEntry[]> {
+    const target = await this.authorizePath(caller, dir)
+    try {
+      const entries = await fs.promises.readdir(target, { withFileTypes: true })
+      return entries.map((entry) => ({ name: entry.name, isDirectory: entry.isDirectory() }))
+    } catch (error: any) {
+      throw new ManagerError('MiniAppBridge', 'not_found', `Failed to read ${dir}: ${error.message}`)
+    }
+  }
+
+  /**
+   * Redirects are not followed, the mini app fetches the `location` again so the new host is checked too
+   */
+  private async fetch(caller: Caller, url: string, init: MiniAppFetchInit = {}): Promise<MiniAppFetchResponse> {
+    if (typeof url !== 'string' || !isHostAllowed(url, caller.manifest.network ?? [])) {
+      throw new ManagerError('MiniAppBridge', 'permission_denied', `${caller.appId} may not fetch ${url}`)
+    }
+    await this.authorize(caller, 'network', new URL(url).host)
+
+    try {
+      const response = await aoxisProxy.axios.request<string>({
+        url,
+        method: init.method ?? 'GET',
+        headers: init.headers,
+        data: init.body,
+        timeout: FETCH_TIMEOUT,
+        maxRedirects: 0,
+        maxContentLength: MAX_RESPONSE_SIZE,
+        responseType: 'text',
+        transformResponse: (data) => data,
+        validateStatus: () => true
+      })
+      const headers = Object.fromEntries(
+        Object.entries(response.headers).flatMap(([name, value]) => (value == null ? [] : [[name, String(value)]]))
+      )
+      return { status: response.status, headers, body: response.data ?? '' }
+    } catch (error: any) {
+      throw new ManagerError('MiniAppBridge', 'unavailable', `Failed to fetch ${url}: ${error.message}`)
+    }
+  }
+
+  private async notify(caller: Caller, title: string, body?: string) {
+    if (typeof title !== 'string' || !title) {
+      throw new ManagerError('MiniAppBridge', 'invalid_argument', 'Notification title is required')
+    }
+    await this.authorize(caller, 'notifications')
+    if (!Notification.isSupported()) {
+      throw new ManagerError('MiniAppBridge', 'unavailable', 'Notifications are not supported')
+    }
+    new Notification({ title, body: typeof body === 'string' ? body : undefined }).show()
+  }
+
+  private async readClipboard(caller: Caller): Promise<string> {
+    await this.authorize(caller, 'clipboard')
+    return clipboard.readText()
+  }
+
+  private async writeClipboard(caller: Caller, text: string) {
+    if (typeof text !== 'string') throw new ManagerError('MiniAppBridge', 'invalid_argument', 'Text must be a string')
+    await this.authorize(caller, 'clipboard')
+    clipboard.writeText(text)
+  }
+
+  private async getState(caller: Caller, key: string): Promise<unknown> {
+    await this.authorize(caller, 'shared-state')
+    return this.state.get(String(key))
+  }
+
+  /**
+   * Set a shared value, undefined removes it
+   */
+  private async setState(caller: Caller, key: string, value: unknown) {
+    await this.authorize(caller, 'shared-state')
+    if (value === undefined) {
+      this.state.delete(String(key))
+    } else {
+      this.state.set(String(key), value)
+    }
+  }
+
+  private async authorize(caller: Caller, permission: MiniAppPermission, detail?: string) {
+    if (!getDeclaredPermissions(caller.manifest).includes(permission)) {
+      const message = `${caller.appId} does not declare the ${permission} permission`
+      throw new ManagerError('MiniAppBridge', 'permission_denied', message)
+    }
+    const scope = getPermissionScope(caller.manifest, permission)
+    await miniAppManager.assertPermission(caller.contents, permission, detail, scope)
+  }
+
+  /**
+   * Resolve links before checking the folders, so a link inside a folder can't lead outside of it
+   */
+  private async authorizePath(caller: Caller, file: string): Promise<string> {
+    if (typeof file !== 'string' || !path.isAbsolute(file)) {
+      throw new ManagerError('MiniAppBridge', 'invalid_argument', `Path must be absolute: ${file}`)
+    }
+    const target = await realPath(file)
+    const folders = await Promise.all((caller.manifest.filesystem ?? []).map(realPath))
+    if (!isPathAllowed(target, folders)) {
+      throw new ManagerError('MiniAppBridge', 'permission_denied', `${caller.appId} may not access ${file}`)
+    }
+    await this.authorize(caller, 'filesystem', target)
+    return target
+  }
+}
+
+// a file that does not exist yet is resolved through its folder
+async function realPath(file: string): Promise<string> {
+  const resolved = path.resolve(file)
+  try {
+    return await fs.promises.realpath(resolved)
+  } catch {
+    const dir = await fs.promises.realpath(path.dirname(resolved)).catch(() => path.dirname(resolved))
+    return path.join(dir, path.basename(resolved))
+  }
+}
+
+export const miniAppBridge = new MiniAppBridge()
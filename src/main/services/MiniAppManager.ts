@@ -0,0 +1,1055 @@
+import crypto from 'node:crypto'
+import fs from 'node:fs'
+import path from 'node:path'
+import { fileURLToPath, pathToFileURL } from 'node:url'
+
+import { isMac } from '@main/constant'
+import { getDataPath } from '@main/utils'
+import { toLogicalRect } from '@main/utils/display'
+import { getTempDir } from '@main/utils/file'
+import { MANIFEST_FILE, parseMiniAppManifest } from '@main/utils/miniAppManifest'
+import {
+  BRIDGE_PERMISSIONS,
+  coversScope,
+  getDeclaredPermissions,
+  getPermissionScope,
+  isAppPage
+} from '@main/utils/miniAppPermissions'
+import { buildMiniAppPreload, isValidScript, MiniAppPreloadScript } from '@main/utils/miniAppPreload'
+import { MINIAPP_BRIDGE_CHANNEL, ZOOM_LEVELS } from '@shared/config/constant'
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import {
+  MinAppType,
+  MiniAppAudioState,
+  MiniAppCapture,
+  MiniAppCaptureOptions,
+  MiniAppCookie,
+  MiniAppCookieInput,
+  MiniAppLayout,
+  MiniAppLifecycleEvent,
+  MiniAppLifecycleEventType,
+  MiniAppNavigationState,
+  MiniAppPermission,
+  MiniAppPermissionDecisions,
+  MiniAppPermissionRequest,
+  MiniAppStats,
+  MiniAppWebviewInfo,
+  PdfExport,
+  PdfPrintOptions
+} from '@types'
+import { app, BrowserWindow, Cookie, screen, Session, systemPreferences, WebContents, webContents } from 'electron'
+import Logger from 'electron-log'
+import { omit } from 'lodash'
+import { v4 as uuidv4 } from 'uuid'
+
+import { configManager } from './ConfigManager'
+import { printService } from './PrintService'
+import { pushChannel } from './PushChannel'
+
+// a mini app that does not acknowledge a payload within this time fails the handoff
+const DELIVERY_TIMEOUT = 10 * 1000
+// a permission prompt that is not answered within this time is denied
+const PERMISSION_TIMEOUT = 60 * 1000
+// how often the hosts of open mini apps are sent their resource usage
+const STATS_INTERVAL = 5 * 1000
+// logos of manifest apps are inlined as data urls
+const MAX_LOGO_SIZE = 512 * 1024
+const LOGO_TYPES: Record<string, string> = {
+  '.png': 'image/png',
+  '.jpg': 'image/jpeg',
+  '.jpeg': 'image/jpeg',
+  '.svg': 'image/svg+xml',
+  '.webp': 'image/webp',
+  '.ico': 'image/x-icon'
+}
+
+export interface IntentAck {
+  accepted: boolean
+  // value returned by the mini app's intent handler
+  result?: unknown
+  error?: string
+}
+
+interface PendingPermission {
+  webviewId: number
+  permissions: MiniAppPermission[]
+  // folders or hosts the answer is given for
+  scope?: string[]
+  resolve: (granted: boolean) => void
+  timer: NodeJS.Timeout
+}
+
+/**
+ * Injected into every mini app page. Pages register the intents they accept with
+ * `window.cherryMiniApp.registerIntent('receive_markdown', (payload) => ...)`, payloads are also posted to the
+ * page as `{ type: 'cherry-miniapp:intent', intent, payload }` messages. The handler's return value is the ack.
+ */
+const SHIM = `(() => {
+  if (window.cherryMiniApp) return
+  const intents = new Map()
+  window.cherryMiniApp = {
+    registerIntent: (intent, handler) => intents.set(intent, handler || null),
+    unregisterIntent: (intent) => intents.delete(intent),
+    getIntents: () => [...intents.keys()]
+  }
+  window.__cherryMiniAppDeliver = async (intent, payload) => {
+    if (!intents.has(intent)) return { accepted: false, error: 'unsupported_intent' }
+    window.postMessage({ type: 'cherry-miniapp:intent', intent, payload }, '*')
+    try {
+      const handler = intents.get(intent)
+      return { accepted: true, result: handler ? await handler(payload) : undefined }
+    } catch (error) {
+      return { accepted: false, error: String(error && error.message ? error.message : error) }
+    }
+  }
+})()`
+
+/**
+ * Structured handoff of chat content to mini apps.
+ *
+ * Mini app webviews get a small shim on every page load, the renderer registers which webview hosts which
+ * mini app, and the chat side sends payloads to an intent the mini app registered, e.g. `receive_markdown`.
+ *
+ * The registered webviews can also be navigated by the host, every navigation is reported to the window hosting
+ * the webview with `MiniApp_NavigationChanged` so toolbars stay in sync with the page.
+ *
+ * Each mini app keeps its own zoom factor, independent of the main window's, saved by app id so it is applied again
+ * whenever the app's webview is recreated.
+ *
+ * Cookies of a mini app can be read, set and cleared by app id. Mini apps share one session, clearing only removes
+ * the cookies of the sites the app visited since it was opened, which logs it out without touching the other apps.
+ *
+ * Pages asking for the camera, the microphone or the location are answered by the user in the window hosting the
+ * webview, with `MiniApp_PermissionRequested`. Answers can be remembered per mini app. Other permissions keep
+ * Electron's default of being granted. Bridge commands of manifest apps (files, network, notifications, clipboard,
+ * shared state) are asked for the same way on first use, see `MiniAppBridge`.
+ *
+ * Mini apps can be muted by app id. Whether an app plays sound or is muted is reported to its host window with
+ * `MiniApp_AudioStateChanged`, so noisy apps can be found and silenced without closing them.
+ *
+ * The memory and CPU usage of each mini app's renderer process can be read by app id, and is sent to the windows
+ * hosting mini apps every few seconds with `MiniApp_StatsUpdated` for task manager views and the suspension policy.
+ *
+ * Mini apps can bring their own preload scripts. They are built into one preload that exposes `window.metheus` and
+ * runs the scripts before the page loads, webviews can only be attached with a preload built here.
+ *
+ * Lifecycle events of mini apps (created, shown, hidden, load-finished with its duration, destroyed) are sent to the
+ * host window with `MiniApp_Lifecycle` and published on the push channel under `miniapp:<appId>`. Showing and
+ * hiding happen in the host window's page, which reports them with `MiniApp_SetVisible`.
+ */
+class MiniAppManager {
+  private webviews = new Map<string, number>()
+  // webviews whose navigation events are already reported
+  private observed = new Set<number>()
+  // hosts each mini app visited, e.g. its own site and the sign in page it was sent to
+  private hosts = new Map<string, Set<string>>()
+  // sessions whose permission requests are already handled
+  private permissionSessions = new WeakSet<Session>()
+  private pendingPermissions = new Map<string, PendingPermission>()
+  private statsTimer: NodeJS.Timeout | null = null
+  // apps the user muted, kept muted when their webview is recreated
+  private muted = new Set<string>()
+  // preloads built by `preparePreload`
+  private preloads = new Set<string>()
+  // apps the host window currently shows
+  private visible = new Set<string>()
+  // the app shown last, restored as the current one
+  private active: string | null = null
+  // when each webview started its current load, and its last finished load
+  private loadStarts = new Map<number, number>()
+  private lastLoads = new Map<number, { url: string; loadDuration: number }>()
+  // apps of the manifests in the miniapps folder, read once at startup
+  private manifestApps: Promise<MinAppType[]> | null = null
+
+  public init() {
+    this.manifestApps = this.loadManifests()
+    app.on('web-contents-created', (_, contents) => {
+      if (contents.getType() !== 'webview') {
+        contents.on('will-attach-webview', (_, webPreferences) => this.checkPreload(webPreferences))
+        return
+      }
+      this.handlePermissions(contents.session)
+      contents.on('dom-ready', () => {
+        contents.executeJavaScript(SHIM).catch((error) => Logger.warn('[MiniAppManager] Failed to inject shim:', error))
+      })
+      this.watchLoads(contents)
+    })
+  }
+
+  public registerWebview(appId: string, webviewId: number) {
+    const created = this.webviews.get(appId) !== webviewId
+    this.webviews.set(appId, webviewId)
+    this.watchStats()
+
+    const webview = webContents.fromId(webviewId)
+    if (!webview) return
+    if (created) {
+      this.emitLifecycle(webview.hostWebContents, appId, 'created')
+      // the first load finishes before the host registers the webview
+      const load = this.lastLoads.get(webviewId)
+      if (load) this.emitLifecycle(webview.hostWebContents, appId, 'load-finished', load)
+      if (this.visible.has(appId)) this.emitLifecycle(webview.hostWebContents, appId, 'shown')
+    }
+    this.applyZoom(appId, webview)
+    webview.setAudioMuted(this.muted.has(appId))
+    this.recordHost(appId, webview.getURL())
+    if (this.observed.has(webviewId)) return
+
+    this.observed.add(webviewId)
+    // the webview can't be read anymore once destroyed
+    const host = webview.hostWebContents
+    // the webview may host another mini app by now, e.g. when recycled by the warm pool
+    const currentAppId = () => this.getAppId(webviewId)
+    const report = () => {
+      const current = currentAppId()
+      if (!current || webview.isDestroyed()) return
+      webview.hostWebContents?.send(IpcChannel.MiniApp_NavigationChanged, this.getNavigationState(current))
+    }
+    // chromium keeps zoom per origin, navigating to another site would drop the app's zoom
+    webview.on('did-navigate', (_, url) => {
+      const current = currentAppId()
+      if (current && !webview.isDestroyed()) this.applyZoom(current, webview)
+      if (current) this.recordHost(current, url)
+    })
+    webview.on('did-navigate', report)
+    webview.on('did-navigate-in-page', report)
+    webview.on('did-start-loading', report)
+    webview.on('did-stop-loading', report)
+    webview.on('page-title-updated', report)
+    webview.on('audio-state-changed', () => {
+      const current = currentAppId()
+      if (current && !webview.isDestroyed()) this.reportAudioState(current, webview)
+    })
+    webview.once('destroyed', () => {
+      this.observed.delete(webviewId)
+      // the host normally unregisters before, this covers webviews removed without closing the app
+      const current = currentAppId()
+      if (current) {
+        this.webviews.delete(current)
+        this.visible.delete(current)
+        this.emitLifecycle(host, current, 'destroyed')
+      }
+    })
+  }
+
+  public unregisterWebview(appId: string) {
+    const id = this.webviews.get(appId)
+    this.webviews.delete(appId)
+    this.visible.delete(appId)
+    const webview = id !== undefined ? webContents.fromId(id) : undefined
+    if (webview) this.emitLifecycle(webview.hostWebContents, appId, 'destroyed')
+  }
+
+  /**
+   * Called by the host window when it shows or hides a mini app, emits shown or hidden when it changed
+   */
+  public setVisible(appId: string, visible: boolean) {
+    if (this.visible.has(appId) === visible) return
+    if (visible) {
+      this.visible.add(appId)
+      this.active = appId
+    } else {
+      this.visible.delete(appId)
+    }
+    const id = this.webviews.get(appId)
+    const webview = id !== undefined ? webContents.fromId(id) : undefined
+    if (webview) this.emitLifecycle(webview.hostWebContents, appId, visible ? 'shown' : 'hidden')
+  }
+
+  /**
+   * All registered mini app webviews, the bounds are read from the host page
+   */
+  public async getAll(): Promise<MiniAppWebviewInfo[]> {
+    const open = [...this.webviews]
+      .map(([appId, id]): [string, WebContents | undefined] => [appId, webContents.fromId(id)])
+      .filter((entry): entry is [string, WebContents] => !!entry[1] && !entry[1].isDestroyed())
+    return Promise.all(
+      open.map(async ([appId, webview]) => ({
+        appId,
+        webviewId: webview.id,
+        url: webview.getURL(),
+        title: webview.getTitle(),
+        visible: this.visible.has(appId),
+        bounds: await this.getBounds(webview)
+      }))
+    )
+  }
+
+  /**
+   * Save the open mini apps with their current pages, called on quit. Webviews that are gone are left out.
+   */
+  public saveLayout() {
+    const apps = [...this.webviews].flatMap(([appId, id]) => {
+      const webview = webContents.fromId(id)
+      return webview && !webview.isDestroyed() ? [{ appId, url: webview.getURL() }] : []
+    })
+    const active = apps.some((item) => item.appId === this.active) ? this.active : null
+    configManager.setMiniAppLayout({
+      apps,
+      activeAppId: active ?? undefined,
+      visible: !!active && this.visible.has(active),
+      savedAt: Date.now()
+    })
+    Logger.info(`[MiniAppManager] Saved layout of ${apps.length} mini apps`)
+  }
+
+  /**
+   * The layout saved on the last quit, the renderer reopens the apps from it
+   */
+  public restoreLayout(): MiniAppLayout | null {
+    return configManager.getMiniAppLayout()
+  }
+
+  /**
+   * Mini apps of the `manifest.json` files in `Data/miniapps/<folder>`, the renderer adds them to its apps
+   */
+  public getManifestApps(): Promise<MinAppType[]> {
+    return (this.manifestApps ??= this.loadManifests())
+  }
+
+  /**
+   * Scan the miniapps folder again after a package is installed or removed
+   */
+  public reloadManifestApps(): Promise<MinAppType[]> {
+    this.manifestApps = this.loadManifests()
+    return this.manifestApps
+  }
+
+  private async loadManifests(): Promise<MinAppType[]> {
+    const root = path.join(getDataPath(), 'miniapps')
+    const entries = await fs.promises.readdir(root, { withFileTypes: true }).catch(() => [])
+    const apps: MinAppType[] = []
+
+    // dot folders are packages being installed or replaced
+    const folders = entries.filter((item) => item.isDirectory() && !item.name.startsWith('.'))
+    for (const entry of folders.sort((a, b) => a.name.localeCompare(b.name))) {
+      const dir = path.join(root, entry.name)
+      const file = path.join(dir, MANIFEST_FILE)
+      try {
+        const result = parseMiniAppManifest(JSON.parse(await fs.promises.readFile(file, 'utf-8')), dir)
+        if ('error' in result) {
+          Logger.warn(`[MiniAppManager] Skipped ${file}: ${result.error}`)
+          continue
+        }
+        if (apps.some((item) => item.id === result.app.id)) {
+          Logger.warn(`[MiniAppManager] Skipped ${file}: mini app ${result.app.id} is already registered`)
+          continue
+        }
+        if (result.app.url.startsWith('file:') && !fs.existsSync(fileURLToPath(result.app.url))) {
+          Logger.warn(`[MiniAppManager] Skipped ${file}: page ${result.app.url} does not exist`)
+          continue
+        }
+        apps.push({ ...result.app, logo: result.logoFile ? await this.readLogo(result.logoFile) : result.app.logo })
+      } catch (error: any) {
+        if (error.code !== 'ENOENT') Logger.warn(`[MiniAppManager] Skipped ${file}: ${error.message}`)
+      }
+    }
+
+    if (apps.length) Logger.info(`[MiniAppManager] Loaded ${apps.length} mini apps from ${root}`)
+    this.pruneDecisions(apps)
+    return apps
+  }
+
+  private async readLogo(file: string): Promise<string | undefined> {
+    const type = LOGO_TYPES[path.extname(file).toLowerCase()]
+    const stats = await fs.promises.stat(file).catch(() => null)
+    if (!type || !stats?.isFile() || stats.size > MAX_LOGO_SIZE) {
+      Logger.warn(`[MiniAppManager] Ignored logo ${file}, it must be an image of at most 512 KB`)
+      return undefined
+    }
+    return `data:${type};base64,${(await fs.promises.readFile(file)).toString('base64')}`
+  }
+
+  /**
+   * Hide every visible mini app, the hosts are asked to hide their popups. Returns the ids of the hidden apps.
+   */
+  public hideAll(): string[] {
+    const hidden = [...this.visible]
+    hidden.forEach((appId) => this.setVisible(appId, false))
+    this.getHosts().forEach((host) => host.send(IpcChannel.MiniApp_HideAllRequested))
+    return hidden
+  }
+
+  /**
+   * Close every mini app. The bookkeeping is dropped here first, so the hosts unregistering their webviews
+   * afterwards does not emit destroyed twice. Returns the ids of the closed apps.
+   */
+  public destroyAll(): string[] {
+    const hosts = this.getHosts()
+    const closed = [...this.webviews.keys()]
+    closed.forEach((appId) => {
+      const host = this.getHost(appId)
+      this.webviews.delete(appId)
+      this.visible.delete(appId)
+      this.emitLifecycle(host, appId, 'destroyed')
+    })
+    hosts.forEach((host) => host.send(IpcChannel.MiniApp_DestroyAllRequested))
+    return closed
+  }
+
+  /**
+   * Build the preload of a mini app from its preload scripts, returns the file url for the webview's `preload`.
+   * Scripts that don't parse are skipped with a warning.
+   */
+  public async preparePreload(appId: string, files: string[]): Promise<string> {
+    const scripts: MiniAppPreloadScript[] = []
+    for (const file of files) {
+      if (!path.isAbsolute(file)) {
+        throw new ManagerError('MiniAppManager', 'invalid_argument', `Path must be absolute: ${file}`)
+      }
+      let code: string
+      try {
+        code = await fs.promises.readFile(file, 'utf-8')
+      } catch (error: any) {
+        throw new ManagerError('MiniAppManager', 'not_found', `Failed to read ${file}: ${error.message}`)
+      }
+      if (!isValidScript(code)) {
+        Logger.warn(`[MiniAppManager] Skipped preload ${file} of ${appId}, it does not parse`)
+        continue
+      }
+      scripts.push({ name: path.basename(file), code })
+    }
+
+    const source = buildMiniAppPreload(appId, scripts)
+    const dir = path.join(getTempDir(), 'minapp-preload')
+    const file = path.join(dir, `${crypto.createHash('sha256').update(source).digest('hex').slice(0, 16)}.js`)
+    await fs.promises.mkdir(dir, { recursive: true })
+    await fs.promises.writeFile(file, source)
+    this.preloads.add(file)
+    return pathToFileURL(file).toString()
+  }
+
+  /**
+   * Send data to the mini app's `window.metheus.onMessage` listeners
+   */
+  public postBridgeMessage(appId: string, data: unknown) {
+    this.getWebview(appId).send(MINIAPP_BRIDGE_CHANNEL, data)
+  }
+
+  public async getIntents(appId: string): Promise<string[]> {
+    const webview = this.getWebview(appId)
+    return webview.executeJavaScript('window.cherryMiniApp ? window.cherryMiniApp.getIntents() : []')
+  }
+
+  public async send(appId: string, intent: string, payload: unknown): Promise<IntentAck> {
+    const webview = this.getWebview(appId)
+    const script = `window.__cherryMiniAppDeliver(${JSON.stringify(intent)}, ${JSON.stringify(payload ?? null)})`
+
+    let timer: NodeJS.Timeout | undefined
+    const timeout = new Promise<never>((_, reject) => {
+      timer = setTimeout(
+        () => reject(new ManagerError('MiniAppManager', 'unavailable', `Mini app ${appId} did not acknowledge`)),
+        DELIVERY_TIMEOUT
+      )
+    })
+
+    try {
+      const ack: IntentAck = await Promise.race([webview.executeJavaScript(script), timeout])
+      Logger.info(`[MiniAppManager] Sent ${intent} to ${appId}, accepted: ${ack.accepted}`)
+      return ack
+    } finally {
+      clearTimeout(timer)
+    }
+  }
+
+  public getNavigationState(appId: string): MiniAppNavigationState {
+    const webview = this.getWebview(appId)
+    return {
+      appId,
+      url: webview.getURL(),
+      title: webview.getTitle(),
+      canGoBack: webview.navigationHistory.canGoBack(),
+      canGoForward: webview.navigationHistory.canGoForward(),
+      loading: webview.isLoading()
+    }
+  }
+
+  /**
+   * Load a url in the mini app, only http and https pages are allowed
+   */
+  public async navigate(appId: string, url: string): Promise<MiniAppNavigationState> {
+    const webview = this.getWebview(appId)
+    const parsed = this.parseUrl(url)
+
+    // a page that fails to load is still shown with its error, the state reports where the webview ended up
+    await webview.loadURL(parsed.toString()).catch((error) => {
+      Logger.warn(`[MiniAppManager] Failed to load ${parsed} in ${appId}:`, error)
+    })
+    return this.getNavigationState(appId)
+  }
+
+  public goBack(appId: string): MiniAppNavigationState {
+    const webview = this.getWebview(appId)
+    if (webview.navigationHistory.canGoBack()) webview.navigationHistory.goBack()
+    return this.getNavigationState(appId)
+  }
+
+  public goForward(appId: string): MiniAppNavigationState {
+    const webview = this.getWebview(appId)
+    if (webview.navigationHistory.canGoForward()) webview.navigationHistory.goForward()
+    return this.getNavigationState(appId)
+  }
+
+  /**
+   * Reload the current page of the mini app, ignoring the cache when asked to
+   */
+  public reload(appId: string, ignoreCache = false): MiniAppNavigationState {
+    const webview = this.getWebview(appId)
+    if (ignoreCache) {
+      webview.reloadIgnoringCache()
+    } else {
+      webview.reload()
+    }
+    return this.getNavigationState(appId)
+  }
+
+  /**
+   * Render what the mini app currently shows to a png, written to a file and/or returned as base64.
+   * Region and size are in physical pixels of the display the host window is on unless logical pixels are asked for.
+   */
+  public async capture(appId: string, options: MiniAppCaptureOptions = {}): Promise<MiniAppCapture> {
+    const webview = this.getWebview(appId)
+    if (options.path && !path.isAbsolute(options.path)) {
+      throw new ManagerError('MiniAppManager', 'invalid_argument', `Path must be absolute: ${options.path}`)
+    }
+    if (options.rect && !(options.rect.width > 0 && options.rect.height > 0)) {
+      throw new ManagerError('MiniAppManager', 'invalid_argument', 'Capture region must not be empty')
+    }
+
+    const space = options.space ?? 'physical'
+    const scaleFactor = this.getScaleFactor(webview)
+    let image = await webview.capturePage(options.rect && toLogicalRect(options.rect, space, scaleFactor))
+    // hidden and suspended webviews have nothing painted
+    if (image.isEmpty()) {
+      throw new ManagerError('MiniAppManager', 'unavailable', `Mini app is not visible: ${appId}`)
+    }
+
+    // the page is painted in physical pixels
+    const fullWidth = space === 'logical' ? image.getSize().width / scaleFactor : image.getSize().width
+    const targetWidth = Math.min(fullWidth, options.maxWidth || Infinity)
+    if (targetWidth < image.getSize().width) {
+      image = image.resize({ width: Math.max(1, Math.floor(targetWidth)), quality: 'good' })
+    }
+
+    const png = image.toPNG()
+    const { width, height } = image.getSize()
+    const result: MiniAppCapture = { width, height, scaleFactor }
+    if (options.path) {
+      try {
+        await fs.promises.writeFile(options.path, png)
+      } catch (error: any) {
+        const message = `Failed to write ${options.path}: ${error.message}`
+        throw new ManagerError('MiniAppManager', 'permission_denied', message)
+      }
+      result.path = options.path
+    }
+    if (options.base64 || !options.path) {
+      result.base64 = png.toString('base64')
+    }
+    return result
+  }
+
+  /**
+   * Print the mini app's page to a PDF at the output path, or one picked in a save dialog
+   */
+  public printToPdf(appId: string, outputPath?: string, options?: PdfPrintOptions): Promise<PdfExport | null> {
+    return printService.printToPdf(this.getWebview(appId), outputPath, options)
+  }
+
+  /**
+   * Cookies the mini app's session sends to the url, the url of the current page by default
+   */
+  public async getCookies(appId: string, url?: string): Promise<MiniAppCookie[]> {
+    const webview = this.getWebview(appId)
+    const cookies = await webview.session.cookies.get({ url: this.parseUrl(url ?? webview.getURL()).toString() })
+    return cookies.map(toMiniAppCookie)
+  }
+
+  public async setCookie(appId: string, cookie: MiniAppCookieInput): Promise<void> {
+    const webview = this.getWebview(appId)
+    const url = this.parseUrl(cookie.url).toString()
+    try {
+      await webview.session.cookies.set({ ...cookie, url })
+    } catch (error: any) {
+      const message = `Failed to set cookie ${cookie.name}: ${error.message}`
+      throw new ManagerError('MiniAppManager', 'invalid_argument', message)
+    }
+  }
+
+  /**
+   * Remove the cookies of every site the mini app visited, including parent domain cookies, e.g. `.example.com`
+   * for `app.example.com`. Returns the number of removed cookies.
+   */
+  public async clearCookies(appId: string): Promise<number> {
+    const webview = this.getWebview(appId)
+    this.recordHost(appId, webview.getURL())
+    const hosts = [...(this.hosts.get(appId) ?? [])]
+
+    const cookies = await webview.session.cookies.get({})
+    const matches = cookies.filter((cookie) => hosts.some((host) => matchesHost(cookie.domain ?? '', host)))
+    await Promise.all(matches.map((cookie) => webview.session.cookies.remove(getCookieUrl(cookie), cookie.name)))
+    await webview.session.cookies.flushStore()
+
+    Logger.info(`[MiniAppManager] Cleared ${matches.length} cookies of ${appId}`)
+    return matches.length
+  }
+
+  public getZoom(appId: string): number {
+    return configManager.getMiniAppZoomFactors()[appId] ?? 1
+  }
+
+  public getAudioState(appId: string): MiniAppAudioState {
+    const webview = this.getWebview(appId)
+    return { appId, muted: webview.isAudioMuted(), audible: webview.isCurrentlyAudible() }
+  }
+
+  /**
+   * Silence the mini app without closing it, it stays muted until unmuted or the app restarts
+   */
+  public setMuted(appId: string, muted: boolean): MiniAppAudioState {
+    const webview = this.getWebview(appId)
+    if (muted) {
+      this.muted.add(appId)
+    } else {
+      this.muted.delete(appId)
+    }
+    webview.setAudioMuted(muted)
+    return this.reportAudioState(appId, webview)
+  }
+
+  /**
+   * Zoom the mini app, the factor is saved even when the app isn't open and applied when it opens
+   */
+  public setZoom(appId: string, factor: number): number {
+    const min = ZOOM_LEVELS[0]
+    const max = ZOOM_LEVELS[ZOOM_LEVELS.length - 1]
+    if (!Number.isFinite(factor) || factor < min || factor > max) {
+      throw new ManagerError('MiniAppManager', 'invalid_argument', `Zoom factor must be between ${min} and ${max}`)
+    }
+
+    const factors = omit(configManager.getMiniAppZoomFactors(), appId)
+    configManager.setMiniAppZoomFactors(factor === 1 ? factors : { ...factors, [appId]: factor })
+
+    const id = this.webviews.get(appId)
+    const webview = id !== undefined ? webContents.fromId(id) : undefined
+    if (webview && !webview.isDestroyed()) this.applyZoom(appId, webview)
+    return factor
+  }
+
+  /**
+   * Mini app of the webview calling the bridge, only the pages of a registered mini app may use it
+   */
+  public async getCallerApp(contents: WebContents): Promise<MinAppType> {
+    const appId = contents.getType() === 'webview' ? this.getAppId(contents.id) : undefined
+    if (!appId) throw new ManagerError('MiniAppManager', 'permission_denied', 'Only mini apps can use the bridge')
+
+    const app = (await this.getManifestApps()).find((item) => item.id === appId)
+    if (!app) return { id: appId, name: appId, url: contents.getURL() }
+    if (!isAppPage(app.url, contents.getURL())) {
+      const message = `Page ${contents.getURL()} is not part of mini app ${appId}`
+      throw new ManagerError('MiniAppManager', 'permission_denied', message)
+    }
+    return app
+  }
+
+  /**
+   * Ask for a permission on behalf of a bridge command, the user is prompted unless an answer is remembered.
+   * `detail` is shown with the prompt, e.g. the file or url the command wants to use. `scope` holds the folders or
+   * hosts of the manifest, an answer remembered for fewer of them does not count.
+   */
+  public async assertPermission(
+    contents: WebContents,
+    permission: MiniAppPermission,
+    detail?: string,
+    scope?: string[]
+  ) {
+    if (await this.requestPermissions(contents, [permission], contents.getURL(), detail, scope)) return
+    throw new ManagerError('MiniAppManager', 'permission_denied', `Permission ${permission} was denied`)
+  }
+
+  /**
+   * Answer a permission prompt, a remembered answer applies to the same permissions of the mini app from now on
+   */
+  public respondPermission(requestId: string, granted: boolean, remember = false) {
+    const pending = this.pendingPermissions.get(requestId)
+    if (!pending) return
+
+    this.pendingPermissions.delete(requestId)
+    clearTimeout(pending.timer)
+    const appId = this.getAppId(pending.webviewId)
+    if (remember && appId) {
+      const permissions = configManager.getMiniAppPermissions()
+      const decisions = { ...permissions[appId] }
+      pending.permissions.forEach((permission) => (decisions[permission] = granted))
+      configManager.setMiniAppPermissions({ ...permissions, [appId]: decisions })
+
+      const scopes = configManager.getMiniAppPermissionScopes()
+      const appScopes = { ...scopes[appId] }
+      pending.permissions.forEach((permission) => {
+        if (pending.scope) {
+          appScopes[permission] = pending.scope
+        } else {
+          delete appScopes[permission]
+        }
+      })
+      configManager.setMiniAppPermissionScopes({ ...scopes, [appId]: appScopes })
+    }
+    pending.resolve(granted)
+  }
+
+  public getPermissions(): Record<string, MiniAppPermissionDecisions> {
+    return configManager.getMiniAppPermissions()
+  }
+
+  /**
+   * Forget the remembered answers of a mini app, or of all mini apps
+   */
+  public resetPermissions(appId?: string) {
+    configManager.setMiniAppPermissions(appId ? omit(configManager.getMiniAppPermissions(), appId) : {})
+    configManager.setMiniAppPermissionScopes(appId ? omit(configManager.getMiniAppPermissionScopes(), appId) : {})
+  }
+
+  /**
+   * Forget the bridge answers of permissions a manifest no longer declares or now declares for more folders or
+   * hosts, e.g. after an update, so they are asked for again
+   */
+  private pruneDecisions(apps: MinAppType[]) {
+    const permissions = { ...configManager.getMiniAppPermissions() }
+    const scopes = { ...configManager.getMiniAppPermissionScopes() }
+    let changed = false
+
+    for (const app of apps) {
+      const decisions = { ...permissions[app.id] }
+      const appScopes = { ...scopes[app.id] }
+      const declared = getDeclaredPermissions(app.permissions)
+      for (const permission of BRIDGE_PERMISSIONS) {
+        if (decisions[permission] === undefined) continue
+        const scope = getPermissionScope(app.permissions, permission)
+        if (declared.includes(permission) && coversScope(appScopes[permission], scope)) continue
+
+        delete decisions[permission]
+        delete appScopes[permission]
+        changed = true
+        Logger.info(`[MiniAppManager] ${app.id} declares ${permission} differently, it will be asked for again`)
+      }
+      permissions[app.id] = decisions
+      scopes[app.id] = appScopes
+    }
+
+    if (!changed) return
+    configManager.setMiniAppPermissions(permissions)
+    configManager.setMiniAppPermissionScopes(scopes)
+  }
+
+  public getStats(appId: string): MiniAppStats {
+    const webview = this.getWebview(appId)
+    const stats = this.collectStats([[appId, webview]])
+    if (stats.length === 0) {
+      throw new ManagerError('MiniAppManager', 'unavailable', `Mini app has no renderer process: ${appId}`)
+    }
+    return stats[0]
+  }
+
+  // each host window gets the stats of the mini apps it shows, sampled while any mini app is open
+  private watchStats() {
+    if (this.statsTimer) return
+    this.statsTimer = setInterval(() => {
+      const open = [...this.webviews]
+        .map(([appId, id]): [string, WebContents | undefined] => [appId, webContents.fromId(id)])
+        .filter((entry): entry is [string, WebContents] => !!entry[1] && !entry[1].isDestroyed())
+      if (open.length === 0 && this.statsTimer) {
+        clearInterval(this.statsTimer)
+        this.statsTimer = null
+        return
+      }
+
+      const byHost = new Map<WebContents, MiniAppStats[]>()
+      const stats = this.collectStats(open)
+      for (const item of stats) {
+        const host = webContents.fromId(item.webviewId)?.hostWebContents
+        if (!host || host.isDestroyed()) continue
+        byHost.set(host, [...(byHost.get(host) ?? []), item])
+      }
+      byHost.forEach((items, host) => host.send(IpcChannel.MiniApp_StatsUpdated, items))
+    }, STATS_INTERVAL)
+  }
+
+  // a suspended or crashed webview has no process in the metrics and is left out
+  private collectStats(entries: [string, WebContents][]): MiniAppStats[] {
+    const time = Date.now()
+    const metrics = new Map(app.getAppMetrics().map((metric) => [metric.pid, metric]))
+    return entries.flatMap(([appId, webview]) => {
+      const pid = webview.getOSProcessId()
+      const metric = metrics.get(pid)
+      if (!metric) return []
+      return [
+        {
+          appId,
+          webviewId: webview.id,
+          pid,
+          memory: metric.memory.workingSetSize,
+          privateMemory: metric.memory.privateBytes,
+          cpu: metric.cpu.percentCPUUsage,
+          time
+        }
+      ]
+    })
+  }
+
+  private handlePermissions(session: Session) {
+    if (this.permissionSessions.has(session)) return
+
+    this.permissionSessions.add(session)
+    session.setPermissionRequestHandler((contents, permission, callback, details) => {
+      const permissions = getRequestedPermissions(permission, details)
+      if (!permissions) return callback(true)
+      this.requestPermissions(contents, permissions, details.requestingUrl).then(callback, (error) => {
+        Logger.error('[MiniAppManager] Failed to request permissions:', error)
+        callback(false)
+      })
+    })
+  }
+
+  private async requestPermissions(
+    contents: WebContents,
+    permissions: MiniAppPermission[],
+    url: string,
+    detail?: string,
+    scope?: string[]
+  ) {
+    const appId = this.getAppId(contents.id)
+    const decisions = (appId && configManager.getMiniAppPermissions()[appId]) || {}
+    const scopes = (appId && configManager.getMiniAppPermissionScopes()[appId]) || {}
+    // an answer given for fewer folders or hosts than asked for now does not count
+    const decision = (permission: MiniAppPermission) =>
+      coversScope(scopes[permission], scope) ? decisions[permission] : undefined
+    if (permissions.some((permission) => decision(permission) === false)) return false
+
+    const granted =
+      permissions.every((permission) => decision(permission) === true) ||
+      (await this.promptPermissions(contents, appId, permissions, url, detail, scope))
+    return granted && (await askSystemAccess(permissions))
+  }
+
+  private promptPermissions(
+    contents: WebContents,
+    appId: string | undefined,
+    permissions: MiniAppPermission[],
+    url: string,
+    detail?: string,
+    scope?: string[]
+  ): Promise<boolean> {
+    const host = contents.hostWebContents
+    if (!host || host.isDestroyed()) return Promise.resolve(false)
+
+    const requestId = uuidv4()
+    return new Promise((resolve) => {
+      const timer = setTimeout(() => this.respondPermission(requestId, false), PERMISSION_TIMEOUT)
+      this.pendingPermissions.set(requestId, { webviewId: contents.id, permissions, scope, resolve, timer })
+      contents.once('destroyed', () => this.respondPermission(requestId, false))
+
+      const request: MiniAppPermissionRequest = {
+        requestId,
+        webviewId: contents.id,
+        appId,
+        origin: getOrigin(url),
+        permissions,
+        detail
+      }
+      host.send(IpcChannel.MiniApp_PermissionRequested, request)
+    })
+  }
+
+  /**
+   * Drop preloads a page set on a webview itself, only the ones built by `preparePreload` are kept
+   */
+  private checkPreload(webPreferences: Electron.WebPreferences) {
+    const preload = webPreferences.preload
+    if (!preload) return
+    const file = path.resolve(preload.startsWith('file:') ? fileURLToPath(preload) : preload)
+    if (this.preloads.has(file)) return
+    Logger.warn(`[MiniAppManager] Removed preload of webview: ${preload}`)
+    delete webPreferences.preload
+  }
+
+  private getHost(appId: string): WebContents | null {
+    const id = this.webviews.get(appId)
+    const webview = id !== undefined ? webContents.fromId(id) : undefined
+    return webview && !webview.isDestroyed() ? webview.hostWebContents : null
+  }
+
+  // windows hosting at least one mini app
+  private getHosts(): WebContents[] {
+    const hosts = new Set<WebContents>()
+    this.webviews.forEach((_, appId) => {
+      const host = this.getHost(appId)
+      if (host && !host.isDestroyed()) hosts.add(host)
+    })
+    return [...hosts]
+  }
+
+  // the webview element knows its place in the host page, the guest only knows its own size
+  private async getBounds(webview: WebContents): Promise<MiniAppWebviewInfo['bounds']> {
+    const host = webview.hostWebContents
+    if (!host || host.isDestroyed()) return undefined
+    try {
+      return await host.executeJavaScript(`(() => {
+        // webviews that are not attached yet throw
+        const attached = (w) => { try { return w.getWebContentsId() === ${webview.id} } catch { return false } }
+        const element = [...document.querySelectorAll('webview')].find(attached)
+        if (!element) return undefined
+        const { x, y, width, height } = element.getBoundingClientRect()
+        return { x, y, width, height }
+      })()`)
+    } catch (error: any) {
+      Logger.warn(`[MiniAppManager] Failed to read the bounds of webview ${webview.id}: ${error.message}`)
+      return undefined
+    }
+  }
+
+  private getAppId(webviewId: number): string | undefined {
+    return [...this.webviews].find(([, id]) => id === webviewId)?.[0]
+  }
+
+  private watchLoads(webview: WebContents) {
+    const id = webview.id
+    webview.on('did-start-loading', () => this.loadStarts.set(id, Date.now()))
+    webview.on('did-finish-load', () => {
+      const started = this.loadStarts.get(id)
+      const load = { url: webview.getURL(), loadDuration: started ? Date.now() - started : 0 }
+      this.lastLoads.set(id, load)
+      const appId = this.getAppId(id)
+      if (appId) this.emitLifecycle(webview.hostWebContents, appId, 'load-finished', load)
+    })
+    webview.once('destroyed', () => {
+      this.loadStarts.delete(id)
+      this.lastLoads.delete(id)
+    })
+  }
+
+  private emitLifecycle(
+    host: WebContents | null,
+    appId: string,
+    type: MiniAppLifecycleEventType,
+    details?: Pick<MiniAppLifecycleEvent, 'url' | 'loadDuration'>
+  ) {
+    const event: MiniAppLifecycleEvent = { type, appId, timestamp: Date.now(), ...details }
+    if (host && !host.isDestroyed()) host.send(IpcChannel.MiniApp_Lifecycle, event)
+    pushChannel.publish(`miniapp:${appId}`, `miniapp.${type}`, event)
+  }
+
+  // muting does not change whether the page plays sound, so both changes are reported the same way
+  private reportAudioState(appId: string, webview: WebContents): MiniAppAudioState {
+    const state = { appId, muted: webview.isAudioMuted(), audible: webview.isCurrentlyAudible() }
+    webview.hostWebContents?.send(IpcChannel.MiniApp_AudioStateChanged, state)
+    return state
+  }
+
+  private applyZoom(appId: string, webview: WebContents) {
+    webview.setZoomFactor(this.getZoom(appId))
+  }
+
+  private recordHost(appId: string, url: string) {
+    try {
+      const { protocol, hostname } = new URL(url)
+      if (!['http:', 'https:'].includes(protocol)) return
+      this.hosts.set(appId, (this.hosts.get(appId) ?? new Set()).add(hostname))
+    } catch {
+      // about:blank and other pages without a host
+    }
+  }
+
+  // only http and https pages are allowed
+  private parseUrl(url: string): URL {
+    let parsed: URL
+    try {
+      parsed = new URL(url)
+    } catch {
+      throw new ManagerError('MiniAppManager', 'invalid_argument', `Invalid url: ${url}`)
+    }
+    if (!['http:', 'https:'].includes(parsed.protocol)) {
+      throw new ManagerError('MiniAppManager', 'invalid_argument', `Only http and https urls are allowed: ${url}`)
+    }
+    return parsed
+  }
+
+  /**
+   * Scale factor of the display the window hosting the webview is on, which may differ from the primary display
+   */
+  private getScaleFactor(webview: WebContents): number {
+    const host = webview.hostWebContents && BrowserWindow.fromWebContents(webview.hostWebContents)
+    const display = host ? screen.getDisplayMatching(host.getBounds()) : screen.getPrimaryDisplay()
+    return display.scaleFactor
+  }
+
+  private getWebview(appId: string): WebContents {
+    const id = this.webviews.get(appId)
+    const webview = id !== undefined ? webContents.fromId(id) : undefined
+    if (!webview || webview.isDestroyed()) {
+      this.webviews.delete(appId)
+      throw new ManagerError('MiniAppManager', 'not_found', `Mini app is not open: ${appId}`)
+    }
+    return webview
+  }
+}
+
+function toMiniAppCookie(cookie: Cookie): MiniAppCookie {
+  return {
+    name: cookie.name,
+    value: cookie.value,
+    domain: cookie.domain ?? '',
+    path: cookie.path ?? '/',
+    secure: cookie.secure ?? false,
+    httpOnly: cookie.httpOnly ?? false,
+    sameSite: cookie.sameSite,
+    expirationDate: cookie.session ? undefined : cookie.expirationDate
+  }
+}
+
+// the cookie domain is the host, one of its parent domains or one of its subdomains
+function matchesHost(domain: string, host: string) {
+  const name = domain.replace(/^\./, '')
+  return name === host || host.endsWith(`.${name}`) || name.endsWith(`.${host}`)
+}
+
+// only devices and the location are asked for
+function getRequestedPermissions(
+  permission: string,
+  details: Electron.PermissionRequest | Electron.MediaAccessPermissionRequest
+): MiniAppPermission[] | null {
+  if (permission === 'geolocation') return ['geolocation']
+  if (permission !== 'media') return null
+
+  const mediaTypes = 'mediaTypes' in details ? (details.mediaTypes ?? []) : []
+  const permissions: MiniAppPermission[] = []
+  if (mediaTypes.includes('video')) permissions.push('camera')
+  if (mediaTypes.includes('audio')) permissions.push('microphone')
+  return permissions.length > 0 ? permissions : null
+}
+
+// macOS asks once whether the app may use the camera and microphone, pages get no devices while it is denied
+async function askSystemAccess(permissions: MiniAppPermission[]): Promise<boolean> {
+  if (!isMac) return true
+  for (const media of ['camera', 'microphone'] as const) {
+    if (!permissions.includes(media) || systemPreferences.getMediaAccessStatus(media) === 'granted') continue
+    if (!(await systemPreferences.askForMediaAccess(media))) return false
+  }
+  return true
+}
+
+function getOrigin(url: string) {
+  try {
+    // local pages have no origin, their url is shown instead
+    const { origin } = new URL(url)
+    return origin === 'null' ? url : origin
+  } catch {
+    return url
+  }
+}
+
+function getCookieUrl(cookie: Cookie) {
+  return `${cookie.secure ? 'https' : 'http'}://${(cookie.domain ?? '').replace(/^\./, '')}${cookie.path ?? '/'}`
+}
+
+export const miniAppManager = new MiniAppManager()
@@ -0,0 +1,418 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { getTempDir } from '@main/utils/file'
+import { compareVersions } from '@main/utils/mcpRegistry'
+import { MANIFEST_FILE, parseMiniAppManifest, resolveInside } from '@main/utils/miniAppManifest'
+import {
+  getSignatureUrl,
+  MiniAppUpdateInfo,
+  parseMiniAppRegistry,
+  parseMiniAppUpdateInfo,
+  parseTrustedKey,
+  verifyPackageSignature
+} from '@main/utils/miniAppPackage'
+import { getAddedPermissions } from '@main/utils/miniAppPermissions'
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import { InstalledMiniAppPackage, MinAppType, MiniAppRegistryEntry, MiniAppUpdate } from '@types'
+import Logger from 'electron-log'
+import StreamZip from 'node-stream-zip'
+import { v4 as uuidv4 } from 'uuid'
+
+import aoxisProxy from './AxiosProxy'
+import { CacheService } from './CacheService'
+import { configManager } from './ConfigManager'
+import { errorReporter } from './ErrorReporter'
+import { miniAppManager } from './MiniAppManager'
+import { windowService } from './WindowService'
+
+const REGISTRY_TTL = 60 * 60 * 1000
+const DOWNLOAD_TIMEOUT = 60 * 1000
+const MAX_PACKAGE_SIZE = 50 * 1024 * 1024
+const UPDATE_INTERVAL = 24 * 60 * 60 * 1000
+// the first update check waits for the startup to settle
+const UPDATE_DELAY = 60 * 1000
+
+function getMiniAppsDir() {
+  return path.join(getDataPath(), 'miniapps')
+}
+
+/**
+ * Install of mini app packages from the configured registry or any https url.
+ *
+ * A package is a zip archive with a `manifest.json` at its root. It is only installed when its detached Ed25519
+ * signature (given by the registry entry or published at `<url>.sig`) verifies against one of the trusted keys.
+ * Packages are extracted to `Data/miniapps/<id>` where they are loaded like hand-made manifest apps.
+ *
+ * Updates come from the registry entry of an installed package or from the `updateUrl` of its manifest. A newer
+ * version goes through the same verification and replaces the installed folder in a single rename, so a failed
+ * update leaves the previous version in place. An update must be newer than the installed version, and one that
+ * declares new permissions or more folders or hosts is only applied once the user confirmed it. With auto update
+ * enabled, updates are applied daily and the ones needing a confirmation are reported instead.
+ */
+class MiniAppRegistryManager {
+  private installing = new Set<string>()
+  private timer: NodeJS.Timeout | null = null
+
+  public init() {
+    this.timer = setTimeout(() => {
+      this.autoUpdate()
+      this.timer = setInterval(() => this.autoUpdate(), UPDATE_INTERVAL)
+    }, UPDATE_DELAY)
+  }
+
+  public stop() {
+    if (this.timer) {
+      clearTimeout(this.timer)
+      this.timer = null
+    }
+  }
+
+  public async getRegistry(refresh = false): Promise<MiniAppRegistryEntry[]> {
+    const url = configManager.getMiniAppRegistryUrl()
+    if (!url) return []
+    const key = `miniapp-registry:${url}`
+    const cached = CacheService.get<MiniAppRegistryEntry[]>(key)
+    if (cached && !refresh) return cached
+
+    let data: unknown
+    try {
+      data = (await aoxisProxy.axios.get(url, { timeout: DOWNLOAD_TIMEOUT })).data
+    } catch (error: any) {
+      const message = `Failed to load the registry ${url}: ${error.message}`
+      throw new ManagerError('MiniAppRegistryManager', 'unavailable', message)
+    }
+
+    const entries = parseMiniAppRegistry(data)
+    CacheService.set(key, entries, REGISTRY_TTL)
+    return entries
+  }
+
+  public getInstalled(): InstalledMiniAppPackage[] {
+    return configManager.getMiniAppInstalledPackages()
+  }
+
+  public getTrustedKeys(): string[] {
+    return configManager.getMiniAppTrustedKeys()
+  }
+
+  public setTrustedKeys(keys: string[]) {
+    const invalid = keys.find((key) => !parseTrustedKey(key))
+    if (invalid !== undefined) {
+      throw new ManagerError('MiniAppRegistryManager', 'invalid_argument', `Not an Ed25519 public key: ${invalid}`)
+    }
+    configManager.setMiniAppTrustedKeys(keys.map((key) => key.trim()))
+  }
+
+  public async install(id: string, confirmed = false): Promise<MinAppType> {
+    const entry = (await this.getRegistry()).find((item) => item.id === id)
+    if (!entry) {
+      throw new ManagerError('MiniAppRegistryManager', 'not_found', `No mini app in the registry: ${id}`)
+    }
+    return this.installFromUrl(entry.url, entry.signature, entry.version, confirmed)
+  }
+
+  /**
+   * Download, verify and register the package at `url`. A package of an installed mini app goes through the same
+   * checks as an update: no older version, and new permissions are refused unless `confirmed`.
+   */
+  public installFromUrl(url: string, signature?: string, version?: string, confirmed = false): Promise<MinAppType> {
+    return this.installPackage(url, signature, version, { confirmed })
+  }
+
+  /**
+   * Installed packages with a newer version in the registry or at their update url
+   */
+  public async checkUpdates(): Promise<MiniAppUpdate[]> {
+    const installed = this.getInstalled()
+    if (!installed.length) return []
+
+    const registry = await this.getRegistry(true).catch((error) => {
+      Logger.warn('[MiniAppRegistryManager] Failed to load the registry:', error)
+      return [] as MiniAppRegistryEntry[]
+    })
+    const apps = await miniAppManager.getManifestApps()
+    const updates = await Promise.all(
+      installed.map(async (item): Promise<MiniAppUpdate[]> => {
+        const update = await this.findUpdate(item, registry).catch((error) => {
+          Logger.warn(`[MiniAppRegistryManager] Failed to check ${item.id} for updates:`, error)
+          return undefined
+        })
+        if (!update) return []
+        const name =
+          registry.find((entry) => entry.id === item.id)?.name ?? apps.find((app) => app.id === item.id)?.name
+        return [{ id: item.id, name: name ?? item.id, from: item.version, to: update.version }]
+      })
+    )
+    return updates.flat()
+  }
+
+  /**
+   * Install the newer version of a package in place of the current one, the open mini app is reloaded.
+   * Without `confirmed`, an update declaring new permissions is refused with the permissions in its details.
+   */
+  public async update(id: string, confirmed = false): Promise<MinAppType> {
+    const previous = this.getInstalled().find((item) => item.id === id)
+    if (!previous) {
+      throw new ManagerError('MiniAppRegistryManager', 'not_found', `Mini app ${id} was not installed from a package`)
+    }
+
+    const registry = await this.getRegistry().catch(() => [] as MiniAppRegistryEntry[])
+    const update = await this.findUpdate(previous, registry)
+    if (!update) {
+      const current = (await miniAppManager.getManifestApps()).find((app) => app.id === id)
+      if (current) return current
+      throw new ManagerError('MiniAppRegistryManager', 'not_found', `No update for mini app ${id}`)
+    }
+
+    const app = await this.installPackage(update.url, update.signature, update.version, { updateOf: id, confirmed })
+    try {
+      miniAppManager.reload(id, true)
+    } catch {
+      // the mini app is not open
+    }
+    windowService.getMainWindow()?.webContents.send(IpcChannel.MiniApp_PackageUpdated, app)
+    Logger.info(`[MiniAppRegistryManager] Updated ${id} from ${previous.version ?? 'unknown'} to ${update.version}`)
+    return app
+  }
+
+  /**
+   * `updateOf` is the mini app being updated, a package of another mini app is refused
+   */
+  private async installPackage(
+    url: string,
+    signature?: string,
+    version?: string,
+    { updateOf, confirmed = false }: { updateOf?: string; confirmed?: boolean } = {}
+  ): Promise<MinAppType> {
+    if (!/^https:\/\//i.test(url)) {
+      throw new ManagerError('MiniAppRegistryManager', 'invalid_argument', 'Packages must be downloaded over https')
+    }
+    const trustedKeys = configManager.getMiniAppTrustedKeys()
+    if (!trustedKeys.length) {
+      throw new ManagerError('MiniAppRegistryManager', 'permission_denied', 'No trusted key to verify packages with')
+    }
+    if (this.installing.has(url)) {
+      throw new ManagerError('MiniAppRegistryManager', 'busy', `${url} is being installed`)
+    }
+
+    this.installing.add(url)
+    const workDir = path.join(getTempDir(), `miniapp-${uuidv4()}`)
+    try {
+      const [data, detached] = await Promise.all([
+        this.download(url),
+        signature ? Promise.resolve(signature) : this.download(getSignatureUrl(url)).then((sig) => sig.toString())
+      ])
+      const fingerprint = verifyPackageSignature(data, detached, trustedKeys)
+      if (!fingerprint) {
+        throw new ManagerError('MiniAppRegistryManager', 'permission_denied', 'Package signature is not trusted')
+      }
+
+      const contentDir = path.join(workDir, 'content')
+      await this.extract(data, workDir, contentDir)
+      const manifest = await fs.promises
+        .readFile(path.join(contentDir, MANIFEST_FILE), 'utf-8')
+        .then((content) => JSON.parse(content))
+        .catch(() => undefined)
+      const result = parseMiniAppManifest(manifest, contentDir)
+      if ('error' in result) {
+        throw new ManagerError('MiniAppRegistryManager', 'invalid_argument', `Invalid manifest: ${result.error}`)
+      }
+
+      const { id } = result.app
+      if (updateOf && id !== updateOf) {
+        const message = `Package is not an update of ${updateOf}`
+        throw new ManagerError('MiniAppRegistryManager', 'invalid_argument', message)
+      }
+      const installed = this.getInstalled()
+      const previous = installed.find((item) => item.id === id)
+      // the signed manifest is trusted over the registry
+      const nextVersion = result.app.version ?? version
+      // reinstalling the same version is allowed from the install path, an update must be newer
+      if (previous) await this.assertUpdate(previous, result.app, nextVersion, confirmed, !updateOf)
+      const dir = path.join(getMiniAppsDir(), id)
+      // a folder the user made by hand is never overwritten
+      if (!previous && fs.existsSync(dir)) {
+        throw new ManagerError('MiniAppRegistryManager', 'conflict', `Mini app ${id} is already installed`)
+      }
+
+      await this.replaceFolder(contentDir, dir)
+
+      const record: InstalledMiniAppPackage = {
+        id,
+        url,
+        version: nextVersion,
+        fingerprint,
+        updateUrl: result.updateUrl,
+        installedAt: previous?.installedAt ?? Date.now(),
+        updatedAt: previous ? Date.now() : undefined
+      }
+      configManager.setMiniAppInstalledPackages([...installed.filter((item) => item.id !== id), record])
+      Logger.info(`[MiniAppRegistryManager] Installed ${id} from ${url}, signed by ${fingerprint}`)
+
+      const app = (await miniAppManager.reloadManifestApps()).find((item) => item.id === id)
+      if (!app) {
+        throw new ManagerError('MiniAppRegistryManager', 'invalid_argument', `Mini app ${id} could not be loaded`)
+      }
+      return app
+    } catch (error) {
+      throw ManagerError.from('MiniAppRegistryManager', error)
+    } finally {
+      this.installing.delete(url)
+      await fs.promises.rm(workDir, { recursive: true, force: true }).catch(() => undefined)
+    }
+  }
+
+  /**
+   * Refuse a package that would roll the mini app back, or declares new permissions the user did not confirm
+   */
+  private async assertUpdate(
+    previous: InstalledMiniAppPackage,
+    next: MinAppType,
+    nextVersion: string | undefined,
+    confirmed: boolean,
+    allowSameVersion: boolean
+  ) {
+    const order = previous.version && nextVersion ? compareVersions(nextVersion, previous.version) : undefined
+    if (previous.version && (order === undefined || order < 0 || (order === 0 && !allowSameVersion))) {
+      const message = `Package version ${nextVersion ?? 'unknown'} is not newer than ${previous.version}`
+      throw new ManagerError('MiniAppRegistryManager', 'invalid_argument', message)
+    }
+    if (confirmed) return
+
+    const current = (await miniAppManager.getManifestApps()).find((app) => app.id === previous.id)
+    const permissions = getAddedPermissions(current?.permissions, next.permissions)
+    if (permissions.length) {
+      const message = `Update of ${previous.id} to ${nextVersion} asks for new permissions: ${permissions.join(', ')}`
+      throw new ManagerError('MiniAppRegistryManager', 'permission_denied', message, {
+        details: { id: previous.id, version: nextVersion, permissions }
+      })
+    }
+  }
+
+  public async uninstall(id: string) {
+    const installed = this.getInstalled()
+    if (!installed.some((item) => item.id === id)) {
+      throw new ManagerError('MiniAppRegistryManager', 'not_found', `Mini app ${id} was not installed from a package`)
+    }
+    await fs.promises.rm(path.join(getMiniAppsDir(), id), { recursive: true, force: true })
+    configManager.setMiniAppInstalledPackages(installed.filter((item) => item.id !== id))
+    await miniAppManager.reloadManifestApps()
+  }
+
+  private async autoUpdate() {
+    if (!configManager.getMiniAppAutoUpdate()) return
+
+    try {
+      for (const update of await this.checkUpdates()) {
+        await this.update(update.id).catch((error) => {
+          // held until the user confirms the new permissions in the settings
+          if (error instanceof ManagerError && error.details?.permissions) {
+            errorReporter.report('MiniAppRegistryManager', error)
+          } else {
+            Logger.warn(`[MiniAppRegistryManager] Failed to update ${update.id}:`, error)
+          }
+        })
+      }
+    } catch (error) {
+      Logger.warn('[MiniAppRegistryManager] Failed to check for updates:', error)
+    }
+  }
+
+  /**
+   * Newer version of an installed package, from its registry entry or else from the update url of its manifest.
+   * A package without a recorded version is always offered the published one.
+   */
+  private async findUpdate(
+    item: InstalledMiniAppPackage,
+    registry: MiniAppRegistryEntry[]
+  ): Promise<MiniAppUpdateInfo | undefined> {
+    const entry = registry.find((candidate) => candidate.id === item.id)
+    let latest: MiniAppUpdateInfo | undefined = entry?.version
+      ? { version: entry.version, url: entry.url, signature: entry.signature }
+      : undefined
+
+    if (!latest && item.updateUrl) {
+      let data: unknown
+      try {
+        data = (await aoxisProxy.axios.get(item.updateUrl, { timeout: DOWNLOAD_TIMEOUT })).data
+      } catch (error: any) {
+        const message = `Failed to load ${item.updateUrl}: ${error.message}`
+        throw new ManagerError('MiniAppRegistryManager', 'unavailable', message)
+      }
+      latest = parseMiniAppUpdateInfo(data)
+      if (!latest) {
+        throw new ManagerError('MiniAppRegistryManager', 'unavailable', `Malformed update info at ${item.updateUrl}`)
+      }
+    }
+
+    if (!latest || (item.version && compareVersions(latest.version, item.version) <= 0)) return undefined
+    return latest
+  }
+
+  /**
+   * Swap the content into the mini app folder with renames inside the miniapps folder, the previous version is
+   * restored when the swap fails. Folders starting with a dot are skipped when the mini apps are loaded.
+   */
+  private async replaceFolder(contentDir: string, dir: string) {
+    const root = getMiniAppsDir()
+    const suffix = uuidv4()
+    const staging = path.join(root, `.${path.basename(dir)}-${suffix}`)
+    const backup = path.join(root, `.${path.basename(dir)}-${suffix}.old`)
+
+    await fs.promises.mkdir(root, { recursive: true })
+    await fs.promises.cp(contentDir, staging, { recursive: true })
+    const hasPrevious = fs.existsSync(dir)
+    try {
+      if (hasPrevious) await fs.promises.rename(dir, backup)
+      await fs.promises.rename(staging, dir)
+    } catch (error) {
+      if (hasPrevious && !fs.existsSync(dir)) await fs.promises.rename(backup, dir).catch(() => undefined)
+      await fs.promises.rm(staging, { recursive: true, force: true }).catch(() => undefined)
+      throw error
+    }
+    await fs.promises.rm(backup, { recursive: true, force: true }).catch(() => undefined)
+  }
+
+  private async download(url: string): Promise<Buffer> {
+    try {
+      const response = await aoxisProxy.axios.get(url, {
+        responseType: 'arraybuffer',
+        timeout: DOWNLOAD_TIMEOUT,
+        maxContentLength: MAX_PACKAGE_SIZE
+      })
+      return Buffer.from(response.data)
+    } catch (error: any) {
+      throw new ManagerError('MiniAppRegistryManager', 'unavailable', `Failed to download ${url}: ${error.message}`)
+    }
+  }
+
+  /**
+   * Extract the archive, entries that would land outside of the content folder are refused
+   */
+  private async extract(data: Buffer, workDir: string, contentDir: string) {
+    const file = path.join(workDir, 'package.zip')
+    await fs.promises.mkdir(contentDir, { recursive: true })
+    await fs.promises.writeFile(file, data)
+
+    let zip: InstanceType<typeof StreamZip.async> | undefined
+    try {
+      zip = new StreamZip.async({ file })
+      const entries = Object.values(await zip.entries())
+      const unsafe = entries.find((entry) => !resolveInside(contentDir, entry.name))
+      if (unsafe) {
+        throw new ManagerError('MiniAppRegistryManager', 'invalid_argument', `Unsafe path in package: ${unsafe.name}`)
+      }
+      await zip.extract(null, contentDir)
+    } catch (error: any) {
+      if (error instanceof ManagerError) throw error
+      throw new ManagerError('MiniAppRegistryManager', 'invalid_argument', `Not a mini app package: ${error.message}`)
+    } finally {
+      await zip?.close().catch(() => undefined)
+    }
+  }
+}
+
+export const miniAppRegistryManager = new MiniAppRegistryManager()
@@ -0,0 +1,262 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { isInsideDirectory } from '@main/utils/audit'
+import { ManagerError } from '@shared/ManagerError'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import { downloadManager } from './DownloadManager'
+
+export type ModelFileFormat = 'gguf' | 'onnx'
+
+export interface ModelFile {
+  id: string
+  name: string
+  format: ModelFileFormat
+  path: string
+  size: number
+  sha256?: string
+  sourceUrl?: string
+  // id of the DownloadManager task while the file is being pulled
+  downloadId?: string
+  status: 'downloading' | 'ready' | 'corrupted' | 'missing'
+  addedAt: number
+  lastUsedAt?: number
+}
+
+const MODEL_EXTS: Record<string, ModelFileFormat> = {
+  '.gguf': 'gguf',
+  '.onnx': 'onnx'
+}
+
+class ModelFileManager {
+  private modelsDir: string
+  private indexFile: string
+  private models: Map<string, ModelFile> = new Map()
+
+  constructor() {
+    this.modelsDir = path.join(getDataPath(), 'Models')
+    this.indexFile = path.join(this.modelsDir, 'index.json')
+    this.load()
+  }
+
+  public getModelsDir(): string {
+    return this.modelsDir
+  }
+
+  /**
+   * Inventory for the model selection UI, refreshed against the file system
+   */
+  public listModels(): ModelFile[] {
+    for (const model of this.models.values()) {
+      if (model.status === 'downloading') continue
+      if (!fs.existsSync(model.path)) {
+        model.status = 'missing'
+      } else if (model.status === 'missing') {
+        model.status = 'ready'
+      }
+    }
+    this.save()
+    return [...this.models.values()].sort((a, b) => (b.lastUsedAt ?? b.addedAt) - (a.lastUsedAt ?? a.addedAt))
+  }
+
+  public getModel(id: string): ModelFile | undefined {
+    return this.models.get(id)
+  }
+
+  public async downloadModel(url: string, fileName?: string, sha256?: string): Promise<ModelFile> {
+    const name = this.getFileName(url, fileName)
+    const format = this.detectFormat(name)
+    if (!format) {
+      throw new ManagerError('ModelFileManager', 'invalid_argument', `Unsupported model file: ${name}`)
+    }
+
+    const dest = path.join(this.modelsDir, name)
+    if (!isInsideDirectory(dest, this.modelsDir) || path.resolve(dest) === path.resolve(this.modelsDir)) {
+      throw new ManagerError('ModelFileManager', 'invalid_argument', `Invalid model file name: ${name}`)
+    }
+    const task = downloadManager.startDownload(url, dest, {
+      checksum: sha256 ? { algorithm: 'sha256', value: sha256 } : undefined,
+      segments: 4,
+      source: 'model'
+    })
+
+    const model: ModelFile = {
+      id: uuidv4(),
+      name,
+      format,
+      path: dest,
+      size: 0,
+      sha256,
+      sourceUrl: url,
+      downloadId: task.id,
+      status: 'downloading',
+      addedAt: Date.now()
+    }
+    this.models.set(model.id, model)
+    this.save()
+    this.trackDownload(model)
+
+    return model
+  }
+
+  /**
+   * Register a model file that already exists on disk, without copying it
+   */
+  public async importModel(filePath: string): Promise<ModelFile> {
+    const format = this.detectFormat(filePath)
+    if (!format) {
+      throw new ManagerError('ModelFileManager', 'invalid_argument', `Unsupported model file: ${filePath}`)
+    }
+
+    const existing = [...this.models.values()].find((model) => model.path === filePath)
+    if (existing) return existing
+
+    const stats = await fs.promises.stat(filePath)
+    const model: ModelFile = {
+      id: uuidv4(),
+      name: path.basename(filePath),
+      format,
+      path: filePath,
+      size: stats.size,
+      sha256: await downloadManager.hashFile(filePath, 'sha256'),
+      status: 'ready',
+      addedAt: Date.now()
+    }
+    this.models.set(model.id, model)
+    this.save()
+    return model
+  }
+
+  /**
+   * Re-hash the file and compare it with the recorded digest
+   */
+  public async verifyModel(id: string): Promise<boolean> {
+    const model = this.models.get(id)
+    if (!model || model.status === 'downloading') return false
+
+    if (!fs.existsSync(model.path)) {
+      model.status = 'missing'
+      this.save()
+      return false
+    }
+
+    const digest = await downloadManager.hashFile(model.path, 'sha256')
+    const valid = !model.sha256 || digest === model.sha256
+    model.status = valid ? 'ready' : 'corrupted'
+    model.sha256 = model.sha256 || digest
+    this.save()
+    return valid
+  }
+
+  public markUsed(id: string) {
+    const model = this.models.get(id)
+    if (!model) return
+    model.lastUsedAt = Date.now()
+    this.save()
+  }
+
+  public async removeModel(id: string, deleteFile: boolean = true) {
+    const model = this.models.get(id)
+    if (!model) return
+
+    if (model.downloadId) {
+      await downloadManager.cancel(model.downloadId)
+    }
+    // never delete files that were imported from outside the models directory
+    if (deleteFile && isInsideDirectory(model.path, this.modelsDir)) {
+      await fs.promises.rm(model.path, { force: true })
+    }
+    this.models.delete(id)
+    this.save()
+  }
+
+  /**
+   * Delete models that have not been used for the given number of days
+   * @returns the removed models
+   */
+  public async cleanupUnused(days: number): Promise<ModelFile[]> {
+    const threshold = Date.now() - days * 24 * 60 * 60 * 1000
+    const unused = [...this.models.values()].filter(
+      (model) => model.status !== 'downloading' && (model.lastUsedAt ?? model.addedAt) < threshold
+    )
+
+    for (const model of unused) {
+      await this.removeModel(model.id)
+    }
+    return unused
+  }
+
+  /**
+   * Only the last segment of the given name or of the url path is kept, so downloads always land in the models folder
+   */
+  private getFileName(url: string, fileName?: string): string {
+    let name = fileName
+    if (!name) {
+      try {
+        name = decodeURIComponent(new URL(url).pathname.split('/').pop() || 'model.gguf')
+      } catch {
+        throw new ManagerError('ModelFileManager', 'invalid_argument', `Invalid model url: ${url}`)
+      }
+    }
+    // a decoded segment may hold either separator
+    const base = path.basename(name.replace(/\\/g, '/'))
+    if (!base || base === '.' || base === '..') {
+      throw new ManagerError('ModelFileManager', 'invalid_argument', `Invalid model file name: ${name}`)
+    }
+    return base
+  }
+
+  private detectFormat(fileName: string): ModelFileFormat | undefined {
+    return MODEL_EXTS[path.extname(fileName).toLowerCase()]
+  }
+
+  private load() {
+    try {
+      if (!fs.existsSync(this.modelsDir)) {
+        fs.mkdirSync(this.modelsDir, { recursive: true })
+      }
+      if (!fs.existsSync(this.indexFile)) return
+
+      const models: ModelFile[] = JSON.parse(fs.readFileSync(this.indexFile, 'utf-8'))
+      models.forEach((model) => this.models.set(model.id, model))
+
+      // downloads survive restarts in the DownloadManager queue, so re-attach to them
+      models.filter((model) => model.status === 'downloading').forEach((model) => this.trackDownload(model))
+    } catch (error) {
+      Logger.error('[ModelFileManager] Failed to load model index:', error)
+    }
+  }
+
+  private trackDownload(model: ModelFile) {
+    if (!model.downloadId) return
+
+    downloadManager
+      .waitFor(model.downloadId)
+      .then(async () => {
+        model.size = (await fs.promises.stat(model.path)).size
+        model.sha256 = model.sha256 || (await downloadManager.hashFile(model.path, 'sha256'))
+        model.status = 'ready'
+      })
+      .catch((error) => {
+        Logger.error(`[ModelFileManager] Failed to download ${model.sourceUrl}:`, error)
+        this.models.delete(model.id)
+      })
+      .finally(() => {
+        model.downloadId = undefined
+        this.save()
+      })
+  }
+
+  private save() {
+    try {
+      fs.writeFileSync(this.indexFile, JSON.stringify([...this.models.values()], null, 2))
+    } catch (error) {
+      Logger.error('[ModelFileManager] Failed to save model index:', error)
+    }
+  }
+}
+
+export const modelFileManager = new ModelFileManager()
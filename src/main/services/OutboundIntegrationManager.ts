@@ -0,0 +1,137 @@
+import { buildOutboundRequest, DEFAULT_OUTBOUND_TEMPLATES, renderOutboundTemplate } from '@main/utils/outbound'
+import { ManagerError } from '@shared/ManagerError'
+import { OutboundDeliveryLog, OutboundEvent, OutboundIntegration } from '@types'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import { configManager } from './ConfigManager'
+import { secretVault } from './SecretVault'
+import { templateManager } from './TemplateManager'
+
+const MAX_LOG_ENTRIES = 200
+const REQUEST_TIMEOUT = 10 * 1000
+
+const secretKey = (id: string) => `outbound:${id}`
+
+/**
+ * Outbound integrations post a message to Slack, Discord, Telegram or a generic HTTP endpoint when an
+ * event they subscribe to fires (agent finished, job failed, backup completed).
+ * Delivery is fire and forget, failures are only logged so a broken integration never blocks the event source.
+ */
+class OutboundIntegrationManager {
+  private logs: OutboundDeliveryLog[] = []
+
+  public list(): OutboundIntegration[] {
+    return configManager
+      .getOutboundIntegrations()
+      .map((item) => ({ ...item, hasSecret: secretVault.has(secretKey(item.id)) }))
+  }
+
+  /**
+   * Create or update an integration, the secret is only replaced when given
+   */
+  public save(input: Omit<OutboundIntegration, 'id' | 'hasSecret'> & { id?: string }, secret?: string) {
+    if (!input.name?.trim()) {
+      throw new ManagerError('OutboundIntegrationManager', 'invalid_argument', 'Integration name is required')
+    }
+    if (input.type === 'telegram' && !input.chatId?.trim()) {
+      throw new ManagerError('OutboundIntegrationManager', 'invalid_argument', 'Telegram chat id is required')
+    }
+    if (secret && input.type !== 'telegram' && !/^https?:\/\//i.test(secret)) {
+      throw new ManagerError('OutboundIntegrationManager', 'invalid_argument', 'Webhook URL must be http(s)')
+    }
+
+    const integrations = configManager.getOutboundIntegrations()
+    if (input.id && !integrations.some((item) => item.id === input.id)) {
+      throw new ManagerError('OutboundIntegrationManager', 'not_found', `Integration not found: ${input.id}`)
+    }
+
+    const id = input.id ?? uuidv4()
+    if (secret) {
+      secretVault.set(secretKey(id), secret)
+    }
+
+    const integration: OutboundIntegration = { ...input, id, hasSecret: secretVault.has(secretKey(id)) }
+    configManager.setOutboundIntegrations(
+      input.id ? integrations.map((item) => (item.id === id ? integration : item)) : [...integrations, integration]
+    )
+    return integration
+  }
+
+  public remove(id: string) {
+    configManager.setOutboundIntegrations(configManager.getOutboundIntegrations().filter((item) => item.id !== id))
+    secretVault.delete(secretKey(id))
+  }
+
+  /**
+   * Send a test message, unlike events the error is returned to the caller
+   */
+  public async test(id: string) {
+    const integration = this.get(id)
+    await this.deliver(integration, 'test', {})
+  }
+
+  /**
+   * Fire an event, every enabled integration subscribed to it gets a message
+   */
+  public emit(event: OutboundEvent, data: Record<string, any>) {
+    for (const integration of configManager.getOutboundIntegrations()) {
+      if (!integration.enabled || !integration.events.includes(event)) continue
+      this.deliver(integration, event, data).catch((error) => {
+        Logger.warn(`[OutboundIntegrationManager] ${integration.name} failed to deliver ${event}:`, error.message)
+      })
+    }
+  }
+
+  /**
+   * Recent deliveries, newest first
+   */
+  public getLogs(id?: string): OutboundDeliveryLog[] {
+    return this.logs.filter((entry) => !id || entry.integrationId === id).reverse()
+  }
+
+  private async deliver(integration: OutboundIntegration, event: OutboundEvent | 'test', data: Record<string, any>) {
+    const secret = secretVault.get(secretKey(integration.id))
+    if (!secret) {
+      this.log({ integrationId: integration.id, event, ok: false, error: 'Missing secret' })
+      throw new ManagerError('OutboundIntegrationManager', 'invalid_argument', `${integration.name} has no secret`)
+    }
+
+    const template = (event !== 'test' && integration.template) || DEFAULT_OUTBOUND_TEMPLATES[event]
+    const text = renderOutboundTemplate(template, data, templateManager.getPartials())
+    const request = buildOutboundRequest(integration, secret, event, text, data)
+
+    try {
+      const response = await fetch(request.url, {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify(request.body),
+        signal: AbortSignal.timeout(REQUEST_TIMEOUT)
+      })
+      this.log({ integrationId: integration.id, event, ok: response.ok, status: response.status })
+      if (!response.ok) {
+        throw new ManagerError('OutboundIntegrationManager', 'unavailable', `HTTP ${response.status}`)
+      }
+    } catch (error: any) {
+      if (error instanceof ManagerError) throw error
+      // the request URL may contain the secret, only keep the message
+      this.log({ integrationId: integration.id, event, ok: false, error: error.message })
+      throw new ManagerError('OutboundIntegrationManager', 'unavailable', error.message)
+    }
+  }
+
+  private log(entry: Omit<OutboundDeliveryLog, 'time'>) {
+    this.logs.push({ time: Date.now(), ...entry })
+    this.logs = this.logs.slice(-MAX_LOG_ENTRIES)
+  }
+
+  private get(id: string): OutboundIntegration {
+    const integration = configManager.getOutboundIntegrations().find((item) => item.id === id)
+    if (!integration) {
+      throw new ManagerError('OutboundIntegrationManager', 'not_found', `Integration not found: ${id}`)
+    }
+    return integration
+  }
+}
+
+export const outboundIntegrationManager = new OutboundIntegrationManager()
@@ -0,0 +1,141 @@
+import path from 'node:path'
+import { IntervalHistogram, monitorEventLoopDelay, performance } from 'node:perf_hooks'
+
+import { ManagerError } from '@shared/ManagerError'
+import { PerfLockMetric, PerfSnapshot, PerfWebviewMetric } from '@types'
+import { MutexInterface } from 'async-mutex'
+import { app, contentTracing, webContents } from 'electron'
+import Logger from 'electron-log'
+
+// sampling resolution of the event loop delay histogram
+const LOOP_RESOLUTION = 20
+// a forgotten trace is stopped and saved after this time, traces grow quickly
+const MAX_TRACE_DURATION = 2 * 60 * 1000
+// sampled js stacks are what DevTools and Perfetto draw the flame charts from
+const TRACE_CATEGORIES = [
+  'blink',
+  'v8',
+  'devtools.timeline',
+  'disabled-by-default-devtools.timeline',
+  'disabled-by-default-devtools.timeline.frame',
+  'disabled-by-default-v8.cpu_profiler'
+]
+
+const toMs = (nanoseconds: number) => (Number.isFinite(nanoseconds) ? Math.round(nanoseconds / 1e4) / 100 : 0)
+
+/**
+ * Memory and performance probes for diagnosing slowdowns.
+ *
+ * Snapshots combine the memory of every app process and webview, the main process event loop lag and the
+ * contention of mutexes acquired through `acquire`. Traces are opt-in Chromium traces with sampled stacks,
+ * saved as json that DevTools' performance panel or Perfetto show as flame charts.
+ */
+class PerfManager {
+  private loopDelay: IntervalHistogram | null = null
+  private locks = new Map<string, PerfLockMetric>()
+  private traceTimer: NodeJS.Timeout | null = null
+
+  public init() {
+    this.loopDelay = monitorEventLoopDelay({ resolution: LOOP_RESOLUTION })
+    this.loopDelay.enable()
+  }
+
+  public getSnapshot(): PerfSnapshot {
+    const metrics = app.getAppMetrics()
+    const memoryByPid = new Map(metrics.map((metric) => [metric.pid, metric.memory.workingSetSize]))
+    const { rss, heapUsed, heapTotal, external } = process.memoryUsage()
+
+    const webviews: PerfWebviewMetric[] = webContents
+      .getAllWebContents()
+      .filter((contents) => !contents.isDestroyed())
+      .map((contents) => {
+        const pid = contents.getOSProcessId()
+        return {
+          id: contents.id,
+          type: contents.getType(),
+          url: contents.getURL(),
+          title: contents.getTitle(),
+          pid,
+          memory: memoryByPid.get(pid)
+        }
+      })
+
+    return {
+      time: Date.now(),
+      main: { rss, heapUsed, heapTotal, external },
+      eventLoopLag: this.readLoopDelay(),
+      processes: metrics.map((metric) => ({
+        pid: metric.pid,
+        type: metric.type,
+        name: metric.name ?? metric.serviceName,
+        memory: metric.memory.workingSetSize,
+        privateMemory: metric.memory.privateBytes,
+        cpu: metric.cpu.percentCPUUsage
+      })),
+      webviews,
+      locks: [...this.locks.values()].map((lock) => ({ ...lock })),
+      tracing: this.traceTimer !== null
+    }
+  }
+
+  /**
+   * Acquire a mutex and count how often and how long callers waited for it under the given name
+   */
+  public async acquire(name: string, mutex: MutexInterface): Promise<MutexInterface.Releaser> {
+    const contended = mutex.isLocked()
+    const start = performance.now()
+    const release = await mutex.acquire()
+    const waited = performance.now() - start
+
+    const lock = this.locks.get(name) ?? { name, acquisitions: 0, contended: 0, waitMs: 0, maxWaitMs: 0 }
+    lock.acquisitions++
+    if (contended) lock.contended++
+    lock.waitMs += waited
+    lock.maxWaitMs = Math.max(lock.maxWaitMs, waited)
+    this.locks.set(name, lock)
+    return release
+  }
+
+  public async startTrace(): Promise<void> {
+    if (this.traceTimer) {
+      throw new ManagerError('PerfManager', 'busy', 'A trace is already being recorded')
+    }
+    await contentTracing.startRecording({ included_categories: TRACE_CATEGORIES, excluded_categories: ['*'] })
+    this.traceTimer = setTimeout(() => {
+      this.stopTrace().catch((error) => Logger.error('[PerfManager] Failed to stop trace:', error))
+    }, MAX_TRACE_DURATION)
+  }
+
+  /**
+   * Stop recording and save the trace, to the logs directory when no path is given
+   */
+  public async stopTrace(outputPath?: string): Promise<string> {
+    if (!this.traceTimer) {
+      throw new ManagerError('PerfManager', 'conflict', 'No trace is being recorded')
+    }
+    if (outputPath && !path.isAbsolute(outputPath)) {
+      throw new ManagerError('PerfManager', 'invalid_argument', `Path must be absolute: ${outputPath}`)
+    }
+    clearTimeout(this.traceTimer)
+    this.traceTimer = null
+
+    const filePath = outputPath || path.join(app.getPath('logs'), `trace-${Date.now()}.json`)
+    const savedPath = await contentTracing.stopRecording(filePath)
+    Logger.info('[PerfManager] Trace saved to', savedPath)
+    return savedPath
+  }
+
+  private readLoopDelay(): PerfSnapshot['eventLoopLag'] {
+    if (!this.loopDelay) return { mean: 0, p50: 0, p99: 0, max: 0 }
+    const lag = {
+      mean: toMs(this.loopDelay.mean),
+      p50: toMs(this.loopDelay.percentile(50)),
+      p99: toMs(this.loopDelay.percentile(99)),
+      max: toMs(this.loopDelay.max)
+    }
+    this.loopDelay.reset()
+    return lag
+  }
+}
+
+export const perfManager = new PerfManager()
@@ -0,0 +1,162 @@
+import { execFileSync } from 'node:child_process'
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { isMac, isWin } from '@main/constant'
+import { ManagerError } from '@shared/ManagerError'
+import Logger from 'electron-log'
+
+/**
+ * Machine-level policy, usually deployed by an administrator or parental control tool.
+ *
+ * {
+ *   "settings": { "enableDataCollection": false, "autoUpdate": false },
+ *   "proxy": "http://proxy.corp:8080",
+ *   "allowedProviders": ["openai", "azure-openai"]
+ * }
+ *
+ * Locked settings are enforced here and by the config manager, allowed providers by the renderer which owns the
+ * providers.
+ */
+export interface Policy {
+  settings: Record<string, unknown>
+  proxy?: string
+  allowedProviders?: string[]
+}
+
+const POLICY_FILE_NAME = 'policy.json'
+const MAC_BUNDLE_ID = 'com.kangfenmao.CherryStudio'
+const WIN_REGISTRY_KEY = 'HKLM\\Software\\Policies\\CherryStudio'
+
+export class PolicyViolationError extends ManagerError {
+  constructor(key: string) {
+    super('PolicyService', 'permission_denied', `Setting "${key}" is locked by policy`, { details: { key } })
+  }
+}
+
+class PolicyService {
+  private policy: Policy = { settings: {} }
+
+  constructor() {
+    this.load()
+  }
+
+  /**
+   * Reload the policy from every known source, later sources override earlier ones
+   */
+  load() {
+    const policy: Policy = { settings: {} }
+
+    for (const source of [this.readJsonPolicy(), this.readPlatformPolicy()]) {
+      if (!source) continue
+      policy.settings = { ...policy.settings, ...(source.settings || {}) }
+      if (source.proxy !== undefined) policy.proxy = source.proxy
+      if (source.allowedProviders !== undefined) policy.allowedProviders = source.allowedProviders
+    }
+
+    this.policy = policy
+
+    const lockedKeys = this.getLockedKeys()
+    if (lockedKeys.length > 0 || policy.proxy || policy.allowedProviders) {
+      Logger.info('[PolicyService] Policy loaded, locked settings:', lockedKeys)
+    }
+  }
+
+  getPolicy(): Policy {
+    return this.policy
+  }
+
+  isLocked(key: string): boolean {
+    return Object.prototype.hasOwnProperty.call(this.policy.settings, key)
+  }
+
+  getLockedKeys(): string[] {
+    return Object.keys(this.policy.settings)
+  }
+
+  getLockedValue<T>(key: string): T | undefined {
+    return this.policy.settings[key] as T | undefined
+  }
+
+  /**
+   * Throw before a setting is applied anywhere, so a locked setting leaves no side effect behind
+   */
+  assertUnlocked(key: string) {
+    if (this.isLocked(key)) {
+      throw new PolicyViolationError(key)
+    }
+  }
+
+  getForcedProxy(): string | undefined {
+    return this.policy.proxy
+  }
+
+  private getPolicyFilePath(): string {
+    if (isWin) {
+      return path.join(process.env.PROGRAMDATA || 'C:\\ProgramData', 'CherryStudio', POLICY_FILE_NAME)
+    }
+    if (isMac) {
+      return path.join('/Library/Application Support/CherryStudio', POLICY_FILE_NAME)
+    }
+    return path.join('/etc/cherry-studio', POLICY_FILE_NAME)
+  }
+
+  private readJsonPolicy(): Partial<Policy> | null {
+    const filePath = this.getPolicyFilePath()
+    try {
+      if (!fs.existsSync(filePath)) return null
+      return JSON.parse(fs.readFileSync(filePath, 'utf-8'))
+    } catch (error) {
+      Logger.error(`[PolicyService] Failed to read policy file ${filePath}:`, error)
+      return null
+    }
+  }
+
+  private readPlatformPolicy(): Partial<Policy> | null {
+    try {
+      if (isMac) return this.readMacManagedPreferences()
+      if (isWin) return this.readWindowsRegistry()
+    } catch (error) {
+      Logger.error('[PolicyService] Failed to read platform policy:', error)
+    }
+    return null
+  }
+
+  /**
+   * MDM profiles are delivered as managed preferences plist files
+   */
+  private readMacManagedPreferences(): Partial<Policy> | null {
+    const plistPath = `/Library/Managed Preferences/${MAC_BUNDLE_ID}.plist`
+    if (!fs.existsSync(plistPath)) return null
+
+    const output = execFileSync('plutil', ['-convert', 'json', '-o', '-', plistPath], { encoding: 'utf-8' })
+    return JSON.parse(output)
+  }
+
+  /**
+   * Group policy writes REG_SZ values; the "Settings" value holds a JSON object
+   */
+  private readWindowsRegistry(): Partial<Policy> | null {
+    let output: string
+    try {
+      output = execFileSync('reg', ['query', WIN_REGISTRY_KEY], { encoding: 'utf-8', windowsHide: true })
+    } catch {
+      // key does not exist
+      return null
+    }
+
+    const values: Record<string, string> = {}
+    for (const line of output.split(/\r?\n/)) {
+      const match = line.trim().match(/^(\S+)\s+REG_\w+\s+(.*)$/)
+      if (match) values[match[1]] = match[2]
+    }
+
+    return {
+      settings: values.Settings ? JSON.parse(values.Settings) : {},
+      proxy: values.Proxy,
+      allowedProviders: values.AllowedProviders?.split(',').map((id) => id.trim())
+    }
+  }
+}
+
+export const policyService = new PolicyService()
@@ -0,0 +1,98 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getTempDir } from '@main/utils/file'
+import { ManagerError } from '@shared/ManagerError'
+import { PdfExport, PdfPageSize, PdfPrintOptions } from '@types'
+import { BrowserWindow, dialog, WebContents } from 'electron'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+const PAGE_SIZES: PdfPageSize[] = ['A3', 'A4', 'A5', 'Legal', 'Letter', 'Tabloid']
+const DEFAULT_MARGIN = 0.4
+// margins are in inches, anything larger leaves no room for content on the smallest page
+const MAX_MARGIN = 2
+
+/**
+ * Prints web contents, mini app webviews or the chat view, to PDF files.
+ *
+ * The PDF is written to the temp directory first and moved to the output path once complete, so a failed or
+ * interrupted print never leaves a partial file behind.
+ */
+class PrintService {
+  /**
+   * Print to the output path, or to a path picked in a save dialog when there is none.
+   * Resolves null when the dialog is cancelled.
+   */
+  public async printToPdf(
+    contents: WebContents,
+    outputPath: string | undefined,
+    options: PdfPrintOptions = {}
+  ): Promise<PdfExport | null> {
+    const margins = this.getMargins(options)
+    const pageSize = options.pageSize ?? 'A4'
+    if (!PAGE_SIZES.includes(pageSize)) {
+      throw new ManagerError('PrintService', 'invalid_argument', `Unsupported page size: ${pageSize}`)
+    }
+    if (outputPath && !path.isAbsolute(outputPath)) {
+      throw new ManagerError('PrintService', 'invalid_argument', `Path must be absolute: ${outputPath}`)
+    }
+
+    const filePath = outputPath || (await this.pickPath(contents, options.defaultName || contents.getTitle()))
+    if (!filePath) return null
+
+    const data = await contents.printToPDF({
+      pageSize,
+      landscape: options.landscape ?? false,
+      printBackground: options.printBackground ?? true,
+      margins
+    })
+
+    await fs.promises.mkdir(getTempDir(), { recursive: true })
+    const tempPath = path.join(getTempDir(), `print_${uuidv4()}.pdf`)
+    try {
+      await fs.promises.writeFile(tempPath, data)
+      await this.move(tempPath, filePath)
+    } catch (error: any) {
+      await fs.promises.rm(tempPath, { force: true })
+      Logger.error('[PrintService] Failed to write PDF:', error)
+      throw new ManagerError('PrintService', 'permission_denied', `Failed to write ${filePath}: ${error.message}`)
+    }
+    return { path: filePath, size: data.length }
+  }
+
+  private getMargins(options: PdfPrintOptions) {
+    const margins = { top: DEFAULT_MARGIN, bottom: DEFAULT_MARGIN, left: DEFAULT_MARGIN, right: DEFAULT_MARGIN }
+    for (const [side, value] of Object.entries(options.margins ?? {})) {
+      if (!(side in margins) || value === undefined) continue
+      if (!Number.isFinite(value) || value < 0 || value > MAX_MARGIN) {
+        throw new ManagerError('PrintService', 'invalid_argument', `Margins must be between 0 and ${MAX_MARGIN} inches`)
+      }
+      margins[side as keyof typeof margins] = value
+    }
+    return margins
+  }
+
+  private async pickPath(contents: WebContents, name: string): Promise<string | undefined> {
+    const window = BrowserWindow.fromWebContents(contents.hostWebContents ?? contents)
+    const saveOptions = {
+      defaultPath: `${name.replace(/[\\/:*?"<>|]/g, '_').trim() || 'page'}.pdf`,
+      filters: [{ name: 'PDF', extensions: ['pdf'] }]
+    }
+    const result = window ? await dialog.showSaveDialog(window, saveOptions) : await dialog.showSaveDialog(saveOptions)
+    return result.canceled ? undefined : result.filePath
+  }
+
+  // rename fails across devices, e.g. from the temp directory to another drive
+  private async move(from: string, to: string) {
+    try {
+      await fs.promises.rename(from, to)
+    } catch (error: any) {
+      if (error.code !== 'EXDEV') throw error
+      await fs.promises.copyFile(from, to)
+      await fs.promises.rm(from, { force: true })
+    }
+  }
+}
+
+export const printService = new PrintService()
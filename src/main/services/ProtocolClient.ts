@@ -6,6 +6,7 @@ import { promisify } from 'node:util'
 import { app } from 'electron'
 import Logger from 'electron-log'
 
+import { shareManager } from './ShareManager'
 import { handleProvidersProtocolUrl } from './urlschema/handle-providers'
 import { handleMcpProtocolUrl } from './urlschema/mcp-install'
 import { windowService } from './WindowService'
@@ -38,6 +39,9 @@ export function handleProtocolUrl(url: string) {
     case 'providers':
       handleProvidersProtocolUrl(urlObj)
       return
+    case 'share':
+      shareManager.share(shareManager.parseUrl(urlObj))
+      return
   }
 
   // You can send the data to your renderer process
@@ -0,0 +1,144 @@
+import http from 'node:http'
+import { Duplex } from 'node:stream'
+
+import Logger from 'electron-log'
+import { WebSocket, WebSocketServer } from 'ws'
+
+const HEARTBEAT_INTERVAL = 30 * 1000
+const MAX_MESSAGE_SIZE = 64 * 1024
+
+export interface PushEvent {
+  type: 'event'
+  topic: string
+  event: string
+  data: any
+  timestamp: number
+}
+
+type ClientMessage = { type: 'subscribe' | 'unsubscribe'; topics: string[] } | { type: 'ping' }
+
+interface Client {
+  topics: Set<string>
+  alive: boolean
+}
+
+/**
+ * WebSocket channel of the local API server, pushes app events to external clients.
+ *
+ * Topics are hierarchical, a client subscribed to `job` receives events published to
+ * `job` and `job:<id>`. Built-in topics are `conversation`, `agent` and `job`.
+ * Authentication is done by the API server before the upgrade is handed over.
+ */
+class PushChannel {
+  private wss = new WebSocketServer({ noServer: true, maxPayload: MAX_MESSAGE_SIZE })
+  private clients: Map<WebSocket, Client> = new Map()
+  private heartbeat: NodeJS.Timeout | null = null
+
+  public handleUpgrade(req: http.IncomingMessage, socket: Duplex, head: Buffer) {
+    this.wss.handleUpgrade(req, socket, head, (ws) => this.onConnection(ws))
+  }
+
+  public getClientCount(): number {
+    return this.clients.size
+  }
+
+  public publish(topic: string, event: string, data: any) {
+    if (this.clients.size === 0) return
+
+    const message: PushEvent = { type: 'event', topic, event, data, timestamp: Date.now() }
+    let payload: string | undefined
+
+    for (const [ws, client] of this.clients) {
+      if (ws.readyState !== WebSocket.OPEN || !this.isSubscribed(client, topic)) continue
+      payload ??= JSON.stringify(message)
+      ws.send(payload)
+    }
+  }
+
+  public closeAll() {
+    for (const ws of this.clients.keys()) {
+      ws.close(1001, 'Server stopped')
+    }
+    this.clients.clear()
+    this.stopHeartbeat()
+  }
+
+  private isSubscribed(client: Client, topic: string): boolean {
+    if (client.topics.has('*') || client.topics.has(topic)) return true
+
+    const separator = topic.indexOf(':')
+    return separator > 0 && client.topics.has(topic.slice(0, separator))
+  }
+
+  private onConnection(ws: WebSocket) {
+    const client: Client = { topics: new Set(), alive: true }
+    this.clients.set(ws, client)
+    this.startHeartbeat()
+
+    ws.on('pong', () => {
+      client.alive = true
+    })
+    ws.on('message', (raw) => this.onMessage(ws, client, raw.toString()))
+    ws.on('close', () => {
+      this.clients.delete(ws)
+      if (this.clients.size === 0) this.stopHeartbeat()
+    })
+    ws.on('error', (error) => Logger.warn('[PushChannel] Client error:', error))
+  }
+
+  private onMessage(ws: WebSocket, client: Client, raw: string) {
+    let message: ClientMessage
+    try {
+      message = JSON.parse(raw)
+    } catch {
+      ws.send(JSON.stringify({ type: 'error', message: 'Message is not valid JSON' }))
+      return
+    }
+
+    switch (message.type) {
+      case 'subscribe':
+      case 'unsubscribe': {
+        const topics = Array.isArray(message.topics) ? message.topics.filter((t) => typeof t === 'string') : []
+        for (const topic of topics) {
+          if (message.type === 'subscribe') {
+            client.topics.add(topic)
+          } else {
+            client.topics.delete(topic)
+          }
+        }
+        ws.send(JSON.stringify({ type: 'subscribed', topics: [...client.topics] }))
+        break
+      }
+      case 'ping':
+        ws.send(JSON.stringify({ type: 'pong' }))
+        break
+      default:
+        ws.send(JSON.stringify({ type: 'error', message: `Unknown message type: ${(message as any).type}` }))
+    }
+  }
+
+  private startHeartbeat() {
+    if (this.heartbeat) return
+
+    // drop clients that went away without closing the connection
+    this.heartbeat = setInterval(() => {
+      for (const [ws, client] of this.clients) {
+        if (!client.alive) {
+          ws.terminate()
+          continue
+        }
+        client.alive = false
+        ws.ping()
+      }
+    }, HEARTBEAT_INTERVAL)
+  }
+
+  private stopHeartbeat() {
+    if (this.heartbeat) {
+      clearInterval(this.heartbeat)
+      this.heartbeat = null
+    }
+  }
+}
+
+export const pushChannel = new PushChannel()
@@ -1,5 +1,5 @@
+import { handleIpc, onIpc } from '@main/utils/ipc'
 import { IpcChannel } from '@shared/IpcChannel'
-import { ipcMain } from 'electron'
 import { EventEmitter } from 'events'
 
 import { windowService } from './WindowService'
@@ -20,13 +20,13 @@ export class ReduxService extends EventEmitter {
 
   private setupIpcHandlers() {
     // 监听 store 就绪事件
-    ipcMain.handle(IpcChannel.ReduxStoreReady, () => {
+    handleIpc(IpcChannel.ReduxStoreReady, () => {
       this.isReady = true
       this.emit('ready')
     })
 
-    // 监听 store 状态变化
-    ipcMain.on(IpcChannel.ReduxStateChange, (_, newState) => {
+    // 监听 store 状态变化，只接受持有 app 权限的窗口发送的状态
+    onIpc(IpcChannel.ReduxStateChange, (_, newState) => {
       this.stateCache = newState
       this.emit(this.STATUS_CHANGE_EVENT, newState)
     })
@@ -0,0 +1,163 @@
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import { ScheduledMessage } from '@types'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import { apiServer } from './ApiServer'
+import { configManager } from './ConfigManager'
+import { pushChannel } from './PushChannel'
+import { windowService } from './WindowService'
+
+// setTimeout overflows above this delay, longer schedules are re-armed
+const MAX_TIMER_DELAY = 2 ** 31 - 1
+// finished messages kept in the list
+const MAX_FINISHED = 100
+
+export interface ScheduleMessageInput {
+  topicId: string
+  assistantId: string
+  content: string
+  sendAt: number
+}
+
+/**
+ * Sends user messages to a conversation at a later time ("send later").
+ *
+ * Messages are persisted in the config and armed again on startup, the ones due while the app was not running
+ * are sent right away. At the due time the message goes through the renderer bridge of the API server so it uses
+ * the same provider routing as regular chats; a closed main window is recreated hidden for that.
+ * Completion is sent to the renderer and published on the push channel as `scheduled:<topicId>`.
+ */
+class SchedulerManager {
+  private timers = new Map<string, NodeJS.Timeout>()
+
+  public init() {
+    const messages = this.load().map((message) =>
+      // the app quit while the message was running, its answer is lost
+      message.status === 'running' ? { ...message, status: 'failed' as const, error: 'Interrupted' } : message
+    )
+    this.save(messages)
+    messages.filter((message) => message.status === 'pending').forEach((message) => this.arm(message))
+  }
+
+  public schedule(input: ScheduleMessageInput): ScheduledMessage {
+    if (!input.topicId || !input.assistantId) {
+      throw new ManagerError('SchedulerManager', 'invalid_argument', 'Conversation is required')
+    }
+    if (!input.content?.trim()) {
+      throw new ManagerError('SchedulerManager', 'invalid_argument', 'Message is empty')
+    }
+    if (!Number.isFinite(input.sendAt) || input.sendAt <= Date.now()) {
+      throw new ManagerError('SchedulerManager', 'invalid_argument', 'Send time must be in the future')
+    }
+
+    const message: ScheduledMessage = {
+      id: uuidv4(),
+      topicId: input.topicId,
+      assistantId: input.assistantId,
+      content: input.content,
+      sendAt: input.sendAt,
+      status: 'pending',
+      createdAt: Date.now()
+    }
+    this.save([...this.load(), message])
+    this.arm(message)
+    Logger.info(`[SchedulerManager] Scheduled message ${message.id} at ${new Date(message.sendAt).toISOString()}`)
+    return message
+  }
+
+  /**
+   * Scheduled messages sorted by send time, optionally of one conversation
+   */
+  public list(topicId?: string): ScheduledMessage[] {
+    return this.load()
+      .filter((message) => !topicId || message.topicId === topicId)
+      .sort((a, b) => a.sendAt - b.sendAt)
+  }
+
+  public cancel(id: string) {
+    const message = this.load().find((item) => item.id === id)
+    if (!message) {
+      throw new ManagerError('SchedulerManager', 'not_found', `Scheduled message not found: ${id}`)
+    }
+    if (message.status !== 'pending') {
+      throw new ManagerError('SchedulerManager', 'invalid_argument', `Message is already ${message.status}`)
+    }
+
+    clearTimeout(this.timers.get(id))
+    this.timers.delete(id)
+    this.update(id, { status: 'cancelled' })
+  }
+
+  private arm(message: ScheduledMessage) {
+    const delay = Math.max(0, message.sendAt - Date.now())
+    const timer = setTimeout(
+      () => (delay > MAX_TIMER_DELAY ? this.arm(message) : this.run(message.id)),
+      Math.min(delay, MAX_TIMER_DELAY)
+    )
+    this.timers.set(message.id, timer)
+  }
+
+  private async run(id: string) {
+    this.timers.delete(id)
+    const message = this.load().find((item) => item.id === id)
+    if (message?.status !== 'pending') return
+
+    this.update(id, { status: 'running' })
+    try {
+      this.ensureMainWindow()
+      const result = await apiServer.callRenderer<{ messageId: string; text: string }>('conversation.send', {
+        topicId: message.topicId,
+        assistantId: message.assistantId,
+        content: message.content
+      })
+      this.finish(this.update(id, { status: 'sent', sentAt: Date.now(), messageId: result.messageId }), result.text)
+    } catch (error: any) {
+      Logger.error(`[SchedulerManager] Scheduled message ${id} failed:`, error)
+      this.finish(this.update(id, { status: 'failed', sentAt: Date.now(), error: error.message }))
+    }
+  }
+
+  private finish(message: ScheduledMessage, text?: string) {
+    windowService.getMainWindow()?.webContents.send(IpcChannel.Scheduler_Completed, message)
+    pushChannel.publish(`scheduled:${message.topicId}`, `scheduled_message.${message.status}`, { ...message, text })
+  }
+
+  private ensureMainWindow() {
+    const mainWindow = windowService.getMainWindow()
+    if (mainWindow && !mainWindow.isDestroyed()) return
+
+    apiServer.resetRendererReady()
+    windowService.createMainWindow({ hidden: true })
+  }
+
+  private update(id: string, patch: Partial<ScheduledMessage>): ScheduledMessage {
+    let updated: ScheduledMessage | undefined
+    this.save(
+      this.load().map((item) => {
+        if (item.id !== id) return item
+        updated = { ...item, ...patch }
+        return updated
+      })
+    )
+    if (!updated) {
+      throw new ManagerError('SchedulerManager', 'not_found', `Scheduled message not found: ${id}`)
+    }
+    return updated
+  }
+
+  private load(): ScheduledMessage[] {
+    return configManager.getScheduledMessages()
+  }
+
+  private save(messages: ScheduledMessage[]) {
+    const active = messages.filter((message) => message.status === 'pending' || message.status === 'running')
+    const finished = messages
+      .filter((message) => message.status !== 'pending' && message.status !== 'running')
+      .slice(-MAX_FINISHED)
+    configManager.setScheduledMessages([...active, ...finished])
+  }
+}
+
+export const schedulerManager = new SchedulerManager()
@@ -0,0 +1,62 @@
+import { ManagerError } from '@shared/ManagerError'
+import { safeStorage } from 'electron'
+import Logger from 'electron-log'
+
+import { configManager } from './ConfigManager'
+
+/**
+ * Secrets of integrations (webhook URLs, bot tokens), encrypted with the OS keychain through safeStorage.
+ * Values never leave the main process, callers store them by a namespaced key like `outbound:<id>`.
+ */
+class SecretVault {
+  private listeners: Array<() => void> = []
+
+  public set(key: string, value: string) {
+    if (!safeStorage.isEncryptionAvailable()) {
+      throw new ManagerError('SecretVault', 'unavailable', 'Secure storage is not available on this system')
+    }
+    const encrypted = safeStorage.encryptString(value).toString('base64')
+    configManager.setVault({ ...configManager.getVault(), [key]: encrypted })
+    this.notify()
+  }
+
+  public get(key: string): string | undefined {
+    const encrypted = configManager.getVault()[key]
+    if (!encrypted) return undefined
+
+    try {
+      return safeStorage.decryptString(Buffer.from(encrypted, 'base64'))
+    } catch (error) {
+      Logger.error(`[SecretVault] Failed to decrypt ${key}:`, error)
+      return undefined
+    }
+  }
+
+  public has(key: string): boolean {
+    return key in configManager.getVault()
+  }
+
+  public delete(key: string) {
+    const vault = { ...configManager.getVault() }
+    delete vault[key]
+    configManager.setVault(vault)
+    this.notify()
+  }
+
+  /**
+   * Decrypted values of all secrets, only for scrubbing them from logs
+   */
+  public values(): string[] {
+    return Object.keys(configManager.getVault()).flatMap((key) => this.get(key) ?? [])
+  }
+
+  public onChange(listener: () => void) {
+    this.listeners.push(listener)
+  }
+
+  private notify() {
+    this.listeners.forEach((listener) => listener())
+  }
+}
+
+export const secretVault = new SecretVault()
@@ -0,0 +1,145 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getFilesDir } from '@main/utils/file'
+import { generateSealKey, getSealedTopicId, openBuffer, openText, sealBuffer, sealText } from '@main/utils/seal'
+import { ManagerError } from '@shared/ManagerError'
+import { systemPreferences } from 'electron'
+import Logger from 'electron-log'
+
+import { lockManager } from './LockManager'
+import { secretVault } from './SecretVault'
+
+const keyName = (topicId: string) => `topic:${topicId}`
+
+/**
+ * Per-conversation keys of secure conversations.
+ *
+ * Each secure conversation gets its own random AES-256-GCM key, wrapped by the OS keychain in the secret vault.
+ * The renderer stores message blocks sealed and asks this manager to seal and open their payloads, keys never leave
+ * the main process. Attachment files are sealed in place and opened by `readFile` when the file storage reads them.
+ * Removing a key makes the conversation unreadable, so it is only done after its content was opened again or the
+ * conversation was deleted.
+ */
+class SecureTopicManager {
+  // unwrapped keys, the keychain is slow to ask for every message
+  private keys = new Map<string, Buffer>()
+
+  public seal(topicId: string, payloads: string[]): string[] {
+    const key = this.getKey(topicId, true)!
+    return payloads.map((payload) => sealText(key, payload))
+  }
+
+  /**
+   * Open sealed payloads, a payload that can't be opened is returned as null
+   */
+  public open(topicId: string, payloads: string[]): (string | null)[] {
+    const key = this.getKey(topicId, false)
+    if (!key) {
+      Logger.warn(`[SecureTopicManager] No key for topic ${topicId}`)
+      return payloads.map(() => null)
+    }
+    return payloads.map((payload) => {
+      try {
+        return openText(key, payload)
+      } catch (error) {
+        Logger.error(`[SecureTopicManager] Failed to open a payload of topic ${topicId}:`, error)
+        return null
+      }
+    })
+  }
+
+  /**
+   * Encrypt attachment files of the file storage in place, files that are already sealed are left alone
+   */
+  public async sealFiles(topicId: string, names: string[]) {
+    const key = this.getKey(topicId, true)!
+    for (const file of this.resolveFiles(names)) {
+      const data = await fs.promises.readFile(file).catch(() => undefined)
+      if (!data || getSealedTopicId(data) !== undefined) continue
+      await writeAtomic(file, sealBuffer(key, topicId, data))
+    }
+  }
+
+  /**
+   * Decrypt the attachment files of the conversation in place, before it stops being secure
+   */
+  public async openFiles(topicId: string, names: string[]) {
+    const key = this.getKey(topicId, false)
+    if (!key) return
+    for (const file of this.resolveFiles(names)) {
+      const data = await fs.promises.readFile(file).catch(() => undefined)
+      if (!data || getSealedTopicId(data) !== topicId) continue
+      await writeAtomic(file, openBuffer(key, data))
+    }
+  }
+
+  /**
+   * Content of a file, opened with the key of its conversation when it is sealed
+   */
+  public async readFile(file: string): Promise<Buffer> {
+    const data = await fs.promises.readFile(file)
+    const topicId = getSealedTopicId(data)
+    if (topicId === undefined) return data
+
+    const key = this.getKey(topicId, false)
+    if (!key) {
+      const message = `No key to open ${path.basename(file)} of a secure conversation`
+      throw new ManagerError('SecureTopicManager', 'permission_denied', message)
+    }
+    return openBuffer(key, data)
+  }
+
+  public removeKey(topicId: string) {
+    this.keys.delete(topicId)
+    secretVault.delete(keyName(topicId))
+  }
+
+  /**
+   * Check the user before a secure conversation leaves the app, with Touch ID where available and the app lock
+   * passphrase elsewhere. Without either there is nothing to check the user against and the unlock is refused.
+   */
+  public async unlock(passphrase?: string): Promise<boolean> {
+    if (lockManager.canUseBiometric()) {
+      try {
+        await systemPreferences.promptTouchID('export a secure conversation')
+        return true
+      } catch (error) {
+        Logger.warn('[SecureTopicManager] Unlock failed:', error)
+        return false
+      }
+    }
+    if (!lockManager.hasPassphrase()) {
+      const message = 'Set an app lock passphrase to export secure conversations'
+      throw new ManagerError('SecureTopicManager', 'unavailable', message)
+    }
+    return lockManager.checkPassphrase(passphrase ?? '')
+  }
+
+  // only files of the file storage, by name
+  private resolveFiles(names: string[]): string[] {
+    return names.map((name) => path.join(getFilesDir(), path.basename(name)))
+  }
+
+  private getKey(topicId: string, create: boolean): Buffer | undefined {
+    const cached = this.keys.get(topicId)
+    if (cached) return cached
+
+    const stored = secretVault.get(keyName(topicId))
+    let key = stored ? Buffer.from(stored, 'base64') : undefined
+    if (!key && create) {
+      key = generateSealKey()
+      secretVault.set(keyName(topicId), key.toString('base64'))
+    }
+    if (key) this.keys.set(topicId, key)
+    return key
+  }
+}
+
+async function writeAtomic(file: string, data: Buffer) {
+  const tempFile = `${file}.sealing`
+  await fs.promises.writeFile(tempFile, data)
+  await fs.promises.rename(tempFile, file)
+}
+
+export const secureTopicManager = new SecureTopicManager()
@@ -1,7 +1,8 @@
 import { SELECTION_FINETUNED_LIST, SELECTION_PREDEFINED_BLACKLIST } from '@main/configs/SelectionConfig'
 import { isDev, isWin } from '@main/constant'
+import { handleIpc } from '@main/utils/ipc'
 import { IpcChannel } from '@shared/IpcChannel'
-import { BrowserWindow, ipcMain, screen } from 'electron'
+import { BrowserWindow, screen } from 'electron'
 import Logger from 'electron-log'
 import { join } from 'path'
 import type {
@@ -264,6 +265,12 @@ export class SelectionService {
       return false
     }
 
+    // monitoring other apps requires the explicit consent given in settings
+    if (!configManager.getSelectionAssistantPermissionGranted()) {
+      this.logInfo('SelectionService not started: permission not granted', true)
+      return false
+    }
+
     try {
       //make sure the toolbar window is ready
       this.createToolbarWindow()
@@ -1210,61 +1217,65 @@ export class SelectionService {
   public static registerIpcHandler(): void {
     if (this.isIpcHandlerRegistered) return
 
-    ipcMain.handle(IpcChannel.Selection_ToolbarHide, () => {
+    handleIpc(IpcChannel.Selection_ToolbarHide, () => {
       selectionService?.hideToolbar()
     })
 
-    ipcMain.handle(IpcChannel.Selection_WriteToClipboard, (_, text: string) => {
+    handleIpc(IpcChannel.Selection_WriteToClipboard, (_, text: string) => {
       return selectionService?.writeToClipboard(text) ?? false
     })
 
-    ipcMain.handle(IpcChannel.Selection_ToolbarDetermineSize, (_, width: number, height: number) => {
+    handleIpc(IpcChannel.Selection_ToolbarDetermineSize, (_, width: number, height: number) => {
       selectionService?.determineToolbarSize(width, height)
     })
 
-    ipcMain.handle(IpcChannel.Selection_SetEnabled, (_, enabled: boolean) => {
+    handleIpc(IpcChannel.Selection_SetEnabled, (_, enabled: boolean) => {
       configManager.setSelectionAssistantEnabled(enabled)
     })
 
-    ipcMain.handle(IpcChannel.Selection_SetTriggerMode, (_, triggerMode: string) => {
+    handleIpc(IpcChannel.Selection_SetPermissionGranted, (_, granted: boolean) => {
+      configManager.setSelectionAssistantPermissionGranted(granted)
+    })
+
+    handleIpc(IpcChannel.Selection_SetTriggerMode, (_, triggerMode: string) => {
       configManager.setSelectionAssistantTriggerMode(triggerMode)
     })
 
-    ipcMain.handle(IpcChannel.Selection_SetFollowToolbar, (_, isFollowToolbar: boolean) => {
+    handleIpc(IpcChannel.Selection_SetFollowToolbar, (_, isFollowToolbar: boolean) => {
       configManager.setSelectionAssistantFollowToolbar(isFollowToolbar)
     })
 
-    ipcMain.handle(IpcChannel.Selection_SetRemeberWinSize, (_, isRemeberWinSize: boolean) => {
+    handleIpc(IpcChannel.Selection_SetRemeberWinSize, (_, isRemeberWinSize: boolean) => {
       configManager.setSelectionAssistantRemeberWinSize(isRemeberWinSize)
     })
 
-    ipcMain.handle(IpcChannel.Selection_SetFilterMode, (_, filterMode: string) => {
+    handleIpc(IpcChannel.Selection_SetFilterMode, (_, filterMode: string) => {
       configManager.setSelectionAssistantFilterMode(filterMode)
     })
 
-    ipcMain.handle(IpcChannel.Selection_SetFilterList, (_, filterList: string[]) => {
+    handleIpc(IpcChannel.Selection_SetFilterList, (_, filterList: string[]) => {
       configManager.setSelectionAssistantFilterList(filterList)
     })
 
-    ipcMain.handle(IpcChannel.Selection_ProcessAction, (_, actionItem: ActionItem) => {
+    handleIpc(IpcChannel.Selection_ProcessAction, (_, actionItem: ActionItem) => {
       selectionService?.processAction(actionItem)
     })
 
-    ipcMain.handle(IpcChannel.Selection_ActionWindowClose, (event) => {
+    handleIpc(IpcChannel.Selection_ActionWindowClose, (event) => {
       const actionWindow = BrowserWindow.fromWebContents(event.sender)
       if (actionWindow) {
         selectionService?.closeActionWindow(actionWindow)
       }
     })
 
-    ipcMain.handle(IpcChannel.Selection_ActionWindowMinimize, (event) => {
+    handleIpc(IpcChannel.Selection_ActionWindowMinimize, (event) => {
       const actionWindow = BrowserWindow.fromWebContents(event.sender)
       if (actionWindow) {
         selectionService?.minimizeActionWindow(actionWindow)
       }
     })
 
-    ipcMain.handle(IpcChannel.Selection_ActionWindowPin, (event, isPinned: boolean) => {
+    handleIpc(IpcChannel.Selection_ActionWindowPin, (event, isPinned: boolean) => {
       const actionWindow = BrowserWindow.fromWebContents(event.sender)
       if (actionWindow) {
         selectionService?.pinActionWindow(actionWindow, isPinned)
@@ -1308,6 +1319,18 @@ export function initSelectionService(): boolean {
     }
   })
 
+  configManager.subscribe(ConfigKeys.SelectionAssistantPermissionGranted, (granted: boolean) => {
+    const ss = SelectionService.getInstance()
+    if (!ss) return
+
+    // revoking the permission stops monitoring right away
+    if (!granted) {
+      ss.stop()
+    } else if (configManager.getSelectionAssistantEnabled()) {
+      ss.start()
+    }
+  })
+
   if (!configManager.getSelectionAssistantEnabled()) return false
 
   const ss = SelectionService.getInstance()
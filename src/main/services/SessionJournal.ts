@@ -0,0 +1,79 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { ManagerError } from '@shared/ManagerError'
+import { RecoveredSession, SessionDraftState } from '@types'
+import Logger from 'electron-log'
+
+// drafts are small, a larger state is a renderer bug rather than something worth persisting
+const MAX_STATE_BYTES = 1024 * 1024
+
+/**
+ * Journal of the unsaved renderer state: unsent inputs, the current page and the open mini apps.
+ *
+ * The renderer writes it whenever that state changes. A marker file exists while the app runs and is removed
+ * on a clean quit, so when it is still there at startup the last run crashed or was killed and its journal is
+ * kept as the recovered session until the user restores or discards it.
+ */
+class SessionJournal {
+  private dir = path.join(getDataPath(), 'Session')
+  private journalFile = path.join(this.dir, 'journal.json')
+  private recoveredFile = path.join(this.dir, 'recovered.json')
+  private runningFile = path.join(this.dir, 'running')
+  // writes are chained so an older state never replaces a newer one
+  private writing: Promise<void> = Promise.resolve()
+
+  public init() {
+    fs.mkdirSync(this.dir, { recursive: true })
+
+    if (fs.existsSync(this.runningFile) && fs.existsSync(this.journalFile)) {
+      fs.renameSync(this.journalFile, this.recoveredFile)
+      Logger.warn('[SessionJournal] The last session did not exit cleanly, its drafts can be recovered')
+    } else {
+      fs.rmSync(this.journalFile, { force: true })
+    }
+    fs.writeFileSync(this.runningFile, String(process.pid))
+  }
+
+  public saveDraftState(state: SessionDraftState): Promise<void> {
+    const data = JSON.stringify({ ...state, savedAt: Date.now() })
+    if (Buffer.byteLength(data) > MAX_STATE_BYTES) {
+      throw new ManagerError('SessionJournal', 'invalid_argument', 'Draft state is too large')
+    }
+
+    this.writing = this.writing
+      .then(async () => {
+        const tempFile = `${this.journalFile}.tmp`
+        await fs.promises.writeFile(tempFile, data)
+        await fs.promises.rename(tempFile, this.journalFile)
+      })
+      .catch((error) => Logger.error('[SessionJournal] Failed to write journal:', error))
+    return this.writing
+  }
+
+  public getRecoveredSession(): RecoveredSession | null {
+    if (!fs.existsSync(this.recoveredFile)) return null
+    try {
+      return JSON.parse(fs.readFileSync(this.recoveredFile, 'utf-8'))
+    } catch (error) {
+      Logger.error('[SessionJournal] Failed to read recovered session:', error)
+      this.discardRecoveredSession()
+      return null
+    }
+  }
+
+  public discardRecoveredSession() {
+    fs.rmSync(this.recoveredFile, { force: true })
+  }
+
+  /**
+   * Mark the exit as clean, the journal of this session is not needed anymore
+   */
+  public close() {
+    fs.rmSync(this.journalFile, { force: true })
+    fs.rmSync(this.runningFile, { force: true })
+  }
+}
+
+export const sessionJournal = new SessionJournal()
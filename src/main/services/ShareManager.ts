@@ -0,0 +1,166 @@
+import { execFile } from 'node:child_process'
+import fs from 'node:fs'
+import path from 'node:path'
+import { fileURLToPath } from 'node:url'
+import { promisify } from 'node:util'
+
+import { isLinux, isWin } from '@main/constant'
+import { IpcChannel } from '@shared/IpcChannel'
+import { SharePayload } from '@types'
+import { app } from 'electron'
+import Logger from 'electron-log'
+
+import { pushChannel } from './PushChannel'
+import { windowService } from './WindowService'
+
+const execFileAsync = promisify(execFile)
+
+export const SHARE_ARG = '--share'
+export const SHARE_TEXT_ARG = '--share-text'
+
+const SHARE_DESKTOP_FILE = 'cherrystudio-share.desktop'
+const SHARE_MIME_TYPES = ['text/plain', 'text/markdown', 'text/html', 'application/pdf', 'image/png', 'image/jpeg']
+const WINDOWS_SHELL_KEY = 'HKCU\\Software\\Classes\\*\\shell\\CherryStudioShare'
+
+/**
+ * Receiving end of "Send to Cherry Studio".
+ *
+ * Shared content arrives from several OS integrations and is normalized into a SharePayload:
+ * - command line: `--share <file|url>...` (Windows Explorer menu, Linux .desktop with %U)
+ *   and `--share-text <text>`
+ * - macOS: files dropped on the Dock icon or opened with the app (`open-file`). There is no Services menu entry,
+ *   Electron can't provide the handler macOS calls for it.
+ * - protocol: `cherrystudio://share?text=...&url=...` (e.g. from macOS Shortcuts)
+ *
+ * Files are only taken from the OS entry points. Any web page can open a protocol url, so files in it are ignored.
+ *
+ * Payloads are queued until the renderer has registered its listener, then sent to
+ * the main window which opens them in a new conversation.
+ */
+class ShareManager {
+  private pending: SharePayload[] = []
+  private rendererListening = false
+
+  public init() {
+    this.registerOsIntegration().catch((error) => Logger.warn('[ShareManager] Failed to register share target:', error))
+  }
+
+  /**
+   * Extract shared content from a command line, returns undefined when there is nothing shared
+   */
+  public parseArgv(argv: string[]): SharePayload | undefined {
+    const payload: SharePayload = { files: [] }
+    let sharing = false
+
+    for (let i = 0; i < argv.length; i++) {
+      const arg = argv[i]
+      if (arg === SHARE_ARG) {
+        sharing = true
+      } else if (arg.startsWith(`${SHARE_TEXT_ARG}=`)) {
+        payload.text = arg.slice(SHARE_TEXT_ARG.length + 1)
+      } else if (arg === SHARE_TEXT_ARG) {
+        payload.text = argv[++i]
+      } else if (sharing && !arg.startsWith('--')) {
+        this.addTarget(payload, arg)
+      }
+    }
+
+    return this.isEmpty(payload) ? undefined : payload
+  }
+
+  /**
+   * Extract shared content from a `cherrystudio://share` url, only text and web links are taken from it
+   */
+  public parseUrl(url: URL): SharePayload | undefined {
+    const link = url.searchParams.get('url') ?? undefined
+    const payload: SharePayload = {
+      text: url.searchParams.get('text') ?? undefined,
+      url: link && /^https?:\/\//i.test(link) ? link : undefined,
+      files: []
+    }
+    if (url.searchParams.has('file')) {
+      Logger.warn('[ShareManager] Ignoring files of a share url, files can only be shared from the OS')
+    }
+    return this.isEmpty(payload) ? undefined : payload
+  }
+
+  public share(payload: SharePayload | undefined) {
+    if (!payload) return
+
+    Logger.info(`[ShareManager] Received ${payload.files.length} file(s)${payload.text ? ' and text' : ''}`)
+    pushChannel.publish('share', 'share.received', payload)
+
+    const mainWindow = windowService.getMainWindow()
+    if (!this.rendererListening || !mainWindow || mainWindow.isDestroyed()) {
+      this.pending.push(payload)
+      return
+    }
+
+    windowService.showMainWindow()
+    mainWindow.webContents.send(IpcChannel.Share_Received, payload)
+  }
+
+  /**
+   * Called by the renderer once it listens for shared content, returns what arrived before
+   */
+  public takePending(): SharePayload[] {
+    this.rendererListening = true
+    const pending = this.pending
+    this.pending = []
+    return pending
+  }
+
+  private addTarget(payload: SharePayload, target: string) {
+    if (/^https?:\/\//i.test(target)) {
+      payload.url = target
+      return
+    }
+
+    const filePath = target.startsWith('file://') ? fileURLToPath(target) : path.resolve(target)
+    if (fs.existsSync(filePath) && fs.statSync(filePath).isFile()) {
+      payload.files.push(filePath)
+    } else {
+      Logger.warn(`[ShareManager] Ignoring missing file: ${filePath}`)
+    }
+  }
+
+  private isEmpty(payload: SharePayload): boolean {
+    return !payload.text?.trim() && !payload.url && payload.files.length === 0
+  }
+
+  /**
+   * Register the "Send to Cherry Studio" entry of the file manager.
+   * The macOS integration is declared in the app bundle (electron-builder.yml).
+   */
+  private async registerOsIntegration() {
+    if (!app.isPackaged) return
+
+    const exePath = app.getPath('exe')
+
+    if (isWin) {
+      await execFileAsync('reg', ['add', WINDOWS_SHELL_KEY, '/v', 'MUIVerb', '/d', 'Send to Cherry Studio', '/f'])
+      await execFileAsync('reg', ['add', WINDOWS_SHELL_KEY, '/v', 'Icon', '/d', exePath, '/f'])
+      const command = `"${exePath}" ${SHARE_ARG} "%1"`
+      await execFileAsync('reg', ['add', `${WINDOWS_SHELL_KEY}\\command`, '/ve', '/d', command, '/f'])
+      return
+    }
+
+    if (isLinux) {
+      // the AppImage path changes between versions, so the entry is rewritten on every start
+      const appPath = process.env.APPIMAGE || exePath
+      const applicationsDir = path.join(app.getPath('home'), '.local', 'share', 'applications')
+      const content = `[Desktop Entry]
+Name=Send to Cherry Studio
+Exec='${appPath.replace(/'/g, "'\\''")}' ${SHARE_ARG} %U
+Terminal=false
+Type=Application
+MimeType=${SHARE_MIME_TYPES.join(';')};
+NoDisplay=true
+`
+      await fs.promises.mkdir(applicationsDir, { recursive: true })
+      await fs.promises.writeFile(path.join(applicationsDir, SHARE_DESKTOP_FILE), content, 'utf-8')
+    }
+  }
+}
+
+export const shareManager = new ShareManager()
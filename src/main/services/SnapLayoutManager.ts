@@ -0,0 +1,139 @@
+import { isValidSnapRect, sortDisplays, toBounds, toSnapRect } from '@main/utils/snap'
+import { ManagerError } from '@shared/ManagerError'
+import { SnapLayout, SnapRect, SnapWindow } from '@types'
+import { BrowserWindow, screen } from 'electron'
+import Logger from 'electron-log'
+
+import { configManager } from './ConfigManager'
+import { windowService } from './WindowService'
+
+const BUILTIN_LAYOUTS: SnapLayout[] = [
+  { name: 'maximize', builtin: true, windows: { main: { display: 0, rect: { x: 0, y: 0, width: 1, height: 1 } } } },
+  { name: 'left-half', builtin: true, windows: { main: { display: 0, rect: { x: 0, y: 0, width: 0.5, height: 1 } } } },
+  {
+    name: 'right-half',
+    builtin: true,
+    windows: { main: { display: 0, rect: { x: 0.5, y: 0, width: 0.5, height: 1 } } }
+  },
+  {
+    name: 'main-and-assistant',
+    builtin: true,
+    windows: {
+      main: { display: 0, rect: { x: 0, y: 0, width: 2 / 3, height: 1 } },
+      assistant: { display: 0, rect: { x: 2 / 3, y: 0, width: 1 / 3, height: 1 } }
+    }
+  },
+  {
+    name: 'assistant-on-second-display',
+    builtin: true,
+    windows: {
+      main: { display: 0, rect: { x: 0, y: 0, width: 1, height: 1 } },
+      assistant: { display: 1, rect: { x: 0.25, y: 0.1, width: 0.5, height: 0.8 } }
+    }
+  }
+]
+
+const MAX_NAME_LENGTH = 64
+
+/**
+ * Tiling layouts for the main window and the quick assistant.
+ *
+ * A layout places each window on a display as fractions of the display's work area, so it fits any resolution.
+ * Displays are numbered from the primary one; a layout made for more displays than are connected puts those
+ * windows on the last one. The current arrangement can be saved as a named layout.
+ */
+class SnapLayoutManager {
+  public listLayouts(): SnapLayout[] {
+    return [...BUILTIN_LAYOUTS, ...configManager.getSnapLayouts()]
+  }
+
+  public applyLayout(name: string): SnapLayout {
+    const layout = this.listLayouts().find((item) => item.name === name)
+    if (!layout) {
+      throw new ManagerError('SnapLayoutManager', 'not_found', `Layout not found: ${name}`)
+    }
+
+    // showing the main window hides the assistant, so the main window goes first
+    const { main, assistant } = layout.windows
+    if (main) {
+      windowService.showMainWindow()
+      const mainWindow = windowService.getMainWindow()
+      if (mainWindow) this.place(mainWindow, main.display, main.rect)
+    }
+    if (assistant && configManager.getEnableQuickAssistant()) {
+      windowService.showMiniWindow()
+      const miniWindow = windowService.getMiniWindow()
+      if (miniWindow) {
+        // a tiled assistant stays open when the main window gets the focus
+        windowService.pinMiniWindow()
+        // a new window is centered when it is ready to show
+        if (miniWindow.isVisible()) {
+          this.place(miniWindow, assistant.display, assistant.rect)
+        } else {
+          miniWindow.once('show', () => this.place(miniWindow, assistant.display, assistant.rect))
+        }
+      }
+    }
+
+    Logger.info(`[SnapLayoutManager] Applied layout ${name}`)
+    return layout
+  }
+
+  /**
+   * Save where the visible windows are now, replacing a saved layout of the same name
+   */
+  public saveLayout(name: string): SnapLayout {
+    const trimmed = name.trim()
+    if (!trimmed || trimmed.length > MAX_NAME_LENGTH) {
+      throw new ManagerError('SnapLayoutManager', 'invalid_argument', 'Layout name is empty or too long')
+    }
+    if (BUILTIN_LAYOUTS.some((item) => item.name === trimmed)) {
+      throw new ManagerError('SnapLayoutManager', 'conflict', `Built-in layouts can't be replaced: ${trimmed}`)
+    }
+
+    const layout: SnapLayout = { name: trimmed, builtin: false, windows: {} }
+    const windows: [SnapWindow, BrowserWindow | null][] = [
+      ['main', windowService.getMainWindow()],
+      ['assistant', windowService.getMiniWindow()]
+    ]
+    for (const [key, window] of windows) {
+      if (!window || window.isDestroyed() || !window.isVisible() || window.isMinimized()) continue
+      layout.windows[key] = this.locate(window)
+    }
+    if (Object.keys(layout.windows).length === 0) {
+      throw new ManagerError('SnapLayoutManager', 'unavailable', 'No visible window to save')
+    }
+
+    const layouts = configManager.getSnapLayouts().filter((item) => item.name !== trimmed)
+    configManager.setSnapLayouts([...layouts, layout])
+    return layout
+  }
+
+  public removeLayout(name: string) {
+    configManager.setSnapLayouts(configManager.getSnapLayouts().filter((item) => item.name !== name))
+  }
+
+  private getDisplays() {
+    return sortDisplays(screen.getAllDisplays(), screen.getPrimaryDisplay().id)
+  }
+
+  private place(window: BrowserWindow, display: number, rect: SnapRect) {
+    if (window.isDestroyed() || !isValidSnapRect(rect)) return
+
+    const displays = this.getDisplays()
+    const { workArea } = displays[Math.min(Math.max(0, display), displays.length - 1)]
+    if (window.isFullScreen()) window.setFullScreen(false)
+    if (window.isMaximized()) window.unmaximize()
+    window.setBounds(toBounds(workArea, rect))
+  }
+
+  private locate(window: BrowserWindow): { display: number; rect: SnapRect } {
+    const bounds = window.getBounds()
+    const displays = this.getDisplays()
+    const nearest = screen.getDisplayMatching(bounds)
+    const display = Math.max(0, displays.findIndex((item) => item.id === nearest.id))
+    return { display, rect: toSnapRect(displays[display].workArea, bounds) }
+  }
+}
+
+export const snapLayoutManager = new SnapLayoutManager()
@@ -0,0 +1,124 @@
+import { performance } from 'node:perf_hooks'
+
+import { StartupReport, StartupSpan, StartupStage } from '@types'
+import { BrowserWindow } from 'electron'
+import Logger from 'electron-log'
+
+// deferred work still runs when the window never paints, e.g. the renderer failed to load
+const FIRST_PAINT_TIMEOUT = 10 * 1000
+
+interface DeferredTask {
+  name: string
+  run: () => unknown
+}
+
+/**
+ * Timing of the app startup and staged initialization.
+ *
+ * Critical initialization is measured with `measure` as it runs. Work that is not needed to show the main window
+ * is queued with `defer` and runs one task at a time once the window painted, so it doesn't compete with the
+ * first render. All times are relative to the process start, so cold start regressions can be compared.
+ */
+class StartupProfiler {
+  private milestones: Record<string, number> = {}
+  private spans: StartupSpan[] = []
+  private deferred: DeferredTask[] = []
+  private painted = false
+
+  public mark(name: string) {
+    this.milestones[name] ??= Math.round(performance.now())
+  }
+
+  /**
+   * Run and time a step, the result is returned unchanged, promises are timed until they settle
+   */
+  public measure<T>(name: string, run: () => T, stage: StartupStage = 'critical'): T {
+    const start = performance.now()
+    const finish = (error?: unknown) => {
+      this.spans.push({
+        name,
+        stage,
+        start: Math.round(start),
+        duration: Math.round((performance.now() - start) * 10) / 10,
+        error: error === undefined ? undefined : String((error as Error)?.message ?? error)
+      })
+    }
+
+    try {
+      const result = run()
+      if (result instanceof Promise) {
+        return result.then(
+          (value) => {
+            finish()
+            return value
+          },
+          (error) => {
+            finish(error)
+            throw error
+          }
+        ) as T
+      }
+      finish()
+      return result
+    } catch (error) {
+      finish(error)
+      throw error
+    }
+  }
+
+  /**
+   * Queue work until the first paint, or run it right away when the window already painted
+   */
+  public defer(name: string, run: () => unknown) {
+    this.deferred.push({ name, run })
+    if (this.painted && this.deferred.length === 1) {
+      this.runDeferred()
+    }
+  }
+
+  /**
+   * Start the deferred work once the window emits ready-to-show, which follows its first paint
+   */
+  public waitForFirstPaint(window: BrowserWindow) {
+    const timer = setTimeout(() => this.onFirstPaint(), FIRST_PAINT_TIMEOUT)
+    window.once('ready-to-show', () => {
+      clearTimeout(timer)
+      this.mark('first-paint')
+      this.onFirstPaint()
+    })
+  }
+
+  public getReport(): StartupReport {
+    return {
+      milestones: { ...this.milestones },
+      spans: [...this.spans],
+      pending: this.deferred.map((task) => task.name)
+    }
+  }
+
+  private onFirstPaint() {
+    if (this.painted) return
+    this.painted = true
+    this.runDeferred()
+  }
+
+  private async runDeferred() {
+    while (this.deferred.length) {
+      const task = this.deferred[0]
+      // yield between tasks so input and IPC from the freshly shown window are handled first
+      await new Promise((resolve) => setImmediate(resolve))
+      try {
+        await this.measure(task.name, task.run, 'deferred')
+      } catch (error) {
+        Logger.error(`[StartupProfiler] Deferred ${task.name} failed:`, error)
+      }
+      this.deferred.shift()
+    }
+    if (!this.milestones['deferred-done']) {
+      this.mark('deferred-done')
+      Logger.info('[StartupProfiler] Startup finished', this.milestones)
+    }
+  }
+}
+
+export const startupProfiler = new StartupProfiler()
@@ -1,6 +1,7 @@
+import { handleIpc } from '@main/utils/ipc'
 import { IpcChannel } from '@shared/IpcChannel'
 import type { StoreSyncAction } from '@types'
-import { BrowserWindow, ipcMain } from 'electron'
+import { BrowserWindow } from 'electron'
 
 /**
  * StoreSyncService class manages Redux store synchronization between multiple windows in the main process
@@ -73,21 +74,21 @@ export class StoreSyncService {
   public registerIpcHandler(): void {
     if (this.isIpcHandlerRegistered) return
 
-    ipcMain.handle(IpcChannel.StoreSync_Subscribe, (event) => {
+    handleIpc(IpcChannel.StoreSync_Subscribe, (event) => {
       const windowId = BrowserWindow.fromWebContents(event.sender)?.id
       if (windowId) {
         this.subscribe(windowId)
       }
     })
 
-    ipcMain.handle(IpcChannel.StoreSync_Unsubscribe, (event) => {
+    handleIpc(IpcChannel.StoreSync_Unsubscribe, (event) => {
       const windowId = BrowserWindow.fromWebContents(event.sender)?.id
       if (windowId) {
         this.unsubscribe(windowId)
       }
     })
 
-    ipcMain.handle(IpcChannel.StoreSync_OnUpdate, (event, action: StoreSyncAction) => {
+    handleIpc(IpcChannel.StoreSync_OnUpdate, (event, action: StoreSyncAction) => {
       const sourceWindowId = BrowserWindow.fromWebContents(event.sender)?.id
 
       if (!sourceWindowId) return
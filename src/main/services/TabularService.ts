@@ -0,0 +1,72 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { TabularFile } from '@main/utils/tabular'
+import { ManagerError } from '@shared/ManagerError'
+import { TabularFormat, TabularParseOptions, TabularPreview } from '@types'
+
+import { workerPool } from './WorkerPool'
+
+const FORMATS: Record<string, TabularFormat> = { '.csv': 'csv', '.tsv': 'tsv', '.tab': 'tsv', '.xlsx': 'xlsx' }
+const MAX_FILE_SIZE = 50 * 1024 * 1024
+const DEFAULT_LIMIT = 100
+const MAX_LIMIT = 1000
+// parsed tables kept for paging through recently previewed files
+const CACHE_SIZE = 4
+
+/**
+ * Structured previews of csv, tsv and xlsx files.
+ *
+ * The whole file is parsed once in the worker pool, typed and summarized, and the renderer only receives the
+ * column schema, statistics and the page of rows it asks for.
+ */
+class TabularService {
+  private cache = new Map<string, TabularFile>()
+
+  public async parse(filePath: string, options: TabularParseOptions = {}): Promise<TabularPreview> {
+    const format = FORMATS[path.extname(filePath).toLowerCase()]
+    if (!format) {
+      throw new ManagerError('TabularService', 'invalid_argument', `Unsupported file type: ${path.basename(filePath)}`)
+    }
+
+    const stat = await fs.promises.stat(filePath).catch(() => null)
+    if (!stat?.isFile()) {
+      throw new ManagerError('TabularService', 'not_found', `File not found: ${filePath}`)
+    }
+    if (stat.size > MAX_FILE_SIZE) {
+      throw new ManagerError(
+        'TabularService',
+        'invalid_argument',
+        `Files larger than ${MAX_FILE_SIZE / 1024 / 1024} MB can't be previewed`
+      )
+    }
+
+    const header = options.header ?? true
+    const key = JSON.stringify([filePath, stat.mtimeMs, options.sheet, options.delimiter, header])
+    let parsed = this.cache.get(key)
+    if (!parsed) {
+      parsed = await workerPool.run('parseTabularFile', {
+        filePath,
+        format,
+        options: { sheet: options.sheet, delimiter: options.delimiter, header }
+      })
+      this.cache.set(key, parsed)
+      if (this.cache.size > CACHE_SIZE) this.cache.delete(this.cache.keys().next().value!)
+    }
+
+    const offset = Math.max(0, Math.floor(options.offset ?? 0))
+    const limit = Math.min(MAX_LIMIT, Math.max(1, Math.floor(options.limit ?? DEFAULT_LIMIT)))
+    return {
+      path: filePath,
+      format,
+      sheets: parsed.sheets,
+      sheet: parsed.sheet,
+      columns: parsed.table.columns,
+      rows: parsed.table.rows.slice(offset, offset + limit),
+      offset,
+      totalRows: parsed.table.rows.length
+    }
+  }
+}
+
+export const tabularService = new TabularService()
@@ -0,0 +1,106 @@
+import { parseTemplate, renderTemplate } from '@main/utils/template'
+import { ManagerError } from '@shared/ManagerError'
+import { UserTemplate } from '@types'
+import { v4 as uuidv4 } from 'uuid'
+
+import { configManager } from './ConfigManager'
+
+// names are used in {{> name}} tags
+const NAME_PATTERN = /^[\w.-]+$/
+const INCLUDE_TAG = /\{\{\s*>\s*([\w.-]+)\s*\}\}/g
+const MAX_INCLUDE_DEPTH = 8
+
+/**
+ * User templates shared by the features producing text: topic exports, outbound integration messages,
+ * webhook prompts and system prompts.
+ *
+ * Templates are rendered with a JSON context by the sandboxed engine in `utils/template.ts` and can include each
+ * other by name, e.g. an outbound message template can `{{> report-footer}}`.
+ */
+class TemplateManager {
+  public list(): UserTemplate[] {
+    return configManager.getTemplates()
+  }
+
+  public save(input: Pick<UserTemplate, 'name' | 'content' | 'description'> & { id?: string }): UserTemplate {
+    const name = input.name.trim()
+    if (!NAME_PATTERN.test(name)) {
+      throw new ManagerError(
+        'TemplateManager',
+        'invalid_argument',
+        'Template names may only contain letters, digits, dots, dashes and underscores'
+      )
+    }
+    parseTemplate(input.content)
+
+    const templates = this.list()
+    if (templates.some((template) => template.name === name && template.id !== input.id)) {
+      throw new ManagerError('TemplateManager', 'already_exists', `A template named ${name} already exists`)
+    }
+
+    const existing = input.id ? templates.find((template) => template.id === input.id) : undefined
+    if (input.id && !existing) {
+      throw new ManagerError('TemplateManager', 'not_found', `Template not found: ${input.id}`)
+    }
+
+    const now = Date.now()
+    const template: UserTemplate = {
+      id: existing?.id ?? uuidv4(),
+      name,
+      description: input.description?.trim() || undefined,
+      content: input.content,
+      createdAt: existing?.createdAt ?? now,
+      updatedAt: now
+    }
+    configManager.setTemplates(
+      existing ? templates.map((item) => (item.id === template.id ? template : item)) : [...templates, template]
+    )
+    return template
+  }
+
+  public remove(id: string) {
+    configManager.setTemplates(this.list().filter((template) => template.id !== id))
+  }
+
+  /**
+   * Templates by name, passed as partials to every render
+   */
+  public getPartials(): Record<string, string> {
+    return Object.fromEntries(this.list().map((template) => [template.name, template.content]))
+  }
+
+  /**
+   * Render a saved template, found by id or name
+   */
+  public render(idOrName: string, context: unknown): string {
+    const template = this.list().find((item) => item.id === idOrName || item.name === idOrName)
+    if (!template) {
+      throw new ManagerError('TemplateManager', 'not_found', `Template not found: ${idOrName}`)
+    }
+    return renderTemplate(template.content, context, { partials: this.getPartials() })
+  }
+
+  /**
+   * Render template source that isn't saved, e.g. to preview it while editing
+   */
+  public renderSource(source: string, context: unknown): string {
+    return renderTemplate(source, context, { partials: this.getPartials() })
+  }
+
+  /**
+   * Replace only the `{{> name}}` tags with the content of the templates, leaving everything else as it is.
+   * System prompts have their own `{{date}}` style variables filled later, so they aren't rendered as a whole.
+   */
+  public expandIncludes(source: string): string {
+    const partials = this.getPartials()
+    let result = source
+    for (let depth = 0; depth < MAX_INCLUDE_DEPTH; depth++) {
+      const expanded = result.replace(INCLUDE_TAG, (tag, name: string) => partials[name] ?? tag)
+      if (expanded === result) break
+      result = expanded
+    }
+    return result
+  }
+}
+
+export const templateManager = new TemplateManager()
@@ -0,0 +1,61 @@
+import fs from 'node:fs'
+
+import { LineIndex, readLines } from '@main/utils/lines'
+import { ManagerError } from '@shared/ManagerError'
+import { TextLines } from '@types'
+
+import { workerPool } from './WorkerPool'
+
+const MAX_COUNT = 1000
+// line indexes kept for scrolling through recently previewed files
+const CACHE_SIZE = 8
+
+/**
+ * Line based previews of large text files such as logs.
+ *
+ * A file is indexed once in the worker pool, keeping the offset of every 1000th line, then each request only reads
+ * the bytes of the lines it asks for. The renderer never holds more than the visible lines, so files of any size
+ * can be scrolled. The index is rebuilt when the file changed, e.g. a log that is still written to.
+ */
+class TextPreviewService {
+  private indexes = new Map<string, LineIndex>()
+
+  public async readLines(filePath: string, startLine = 0, count = 100): Promise<TextLines> {
+    const stat = await fs.promises.stat(filePath).catch(() => null)
+    if (!stat?.isFile()) {
+      throw new ManagerError('TextPreviewService', 'not_found', `File not found: ${filePath}`)
+    }
+    if (!Number.isInteger(startLine) || startLine < 0 || !Number.isInteger(count) || count < 0) {
+      throw new ManagerError('TextPreviewService', 'invalid_argument', 'Start line and count must be positive integers')
+    }
+
+    const index = await this.getIndex(filePath, stat)
+    const range = await readLines(filePath, index, startLine, Math.min(count, MAX_COUNT))
+    return {
+      path: filePath,
+      startLine,
+      lines: range.lines,
+      totalLines: index.lineCount,
+      size: index.size,
+      truncated: range.truncated
+    }
+  }
+
+  private async getIndex(filePath: string, stat: fs.Stats): Promise<LineIndex> {
+    const cached = this.indexes.get(filePath)
+    if (cached && cached.size === stat.size && cached.mtimeMs === stat.mtimeMs) {
+      // most recently used last
+      this.indexes.delete(filePath)
+      this.indexes.set(filePath, cached)
+      return cached
+    }
+
+    const index = await workerPool.run('indexTextFile', { filePath })
+    this.indexes.delete(filePath)
+    this.indexes.set(filePath, index)
+    if (this.indexes.size > CACHE_SIZE) this.indexes.delete(this.indexes.keys().next().value!)
+    return index
+  }
+}
+
+export const textPreviewService = new TextPreviewService()
@@ -0,0 +1,144 @@
+import path from 'node:path'
+
+import { Client, createClient, Row } from '@libsql/client'
+import { getDataPath } from '@main/utils'
+import { buildToolCallQuery } from '@main/utils/toolCalls'
+import { ManagerError } from '@shared/ManagerError'
+import { ToolCallQuery, ToolCallRecord } from '@types'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+// calls older than this are removed when the transcript is opened
+const RETENTION = 30 * 24 * 60 * 60 * 1000
+
+const SCHEMA = [
+  `CREATE TABLE IF NOT EXISTS tool_calls (
+    id TEXT PRIMARY KEY,
+    topic_id TEXT,
+    message_id TEXT,
+    server_id TEXT NOT NULL,
+    server_name TEXT NOT NULL,
+    tool_name TEXT NOT NULL,
+    args TEXT,
+    result TEXT,
+    error TEXT,
+    started_at INTEGER NOT NULL,
+    duration INTEGER NOT NULL,
+    replay_of TEXT
+  )`,
+  'CREATE INDEX IF NOT EXISTS tool_calls_topic ON tool_calls (topic_id, started_at)',
+  'CREATE INDEX IF NOT EXISTS tool_calls_message ON tool_calls (message_id)',
+  'CREATE INDEX IF NOT EXISTS tool_calls_started ON tool_calls (started_at)'
+]
+
+const parseJson = (value: unknown) => (typeof value === 'string' ? JSON.parse(value) : undefined)
+const optional = (value: unknown) => (value === null || value === undefined ? undefined : String(value))
+
+function toRecord(row: Row): ToolCallRecord {
+  return {
+    id: String(row.id),
+    topicId: optional(row.topic_id),
+    messageId: optional(row.message_id),
+    serverId: String(row.server_id),
+    serverName: String(row.server_name),
+    toolName: String(row.tool_name),
+    args: parseJson(row.args),
+    result: parseJson(row.result),
+    error: optional(row.error),
+    startedAt: Number(row.started_at),
+    duration: Number(row.duration),
+    replayOf: optional(row.replay_of)
+  }
+}
+
+/**
+ * Transcript of the MCP tool calls, kept in `Data/ToolCalls.db`.
+ *
+ * Every call is recorded with its arguments, result, error and duration, linked to the message it was made
+ * for, so agent runs can be inspected and single calls replayed. Recording never fails a call.
+ */
+class ToolCallRecorder {
+  private client: Promise<Client> | null = null
+
+  private getClient(): Promise<Client> {
+    if (!this.client) {
+      this.client = this.open().catch((error) => {
+        this.client = null
+        throw error
+      })
+    }
+    return this.client
+  }
+
+  private async open(): Promise<Client> {
+    const file = path.join(getDataPath(), 'ToolCalls.db')
+    const client = createClient({ url: `file:${file}` })
+    await client.batch(SCHEMA, 'write')
+    await client.execute({ sql: 'DELETE FROM tool_calls WHERE started_at < ?', args: [Date.now() - RETENTION] })
+    return client
+  }
+
+  public async record(call: Omit<ToolCallRecord, 'id'>): Promise<string | undefined> {
+    const id = uuidv4()
+    try {
+      const client = await this.getClient()
+      await client.execute({
+        sql: `INSERT INTO tool_calls (id, topic_id, message_id, server_id, server_name, tool_name, args, result,
+          error, started_at, duration, replay_of) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)`,
+        args: [
+          id,
+          call.topicId ?? null,
+          call.messageId ?? null,
+          call.serverId,
+          call.serverName,
+          call.toolName,
+          JSON.stringify(call.args ?? null),
+          call.result ? JSON.stringify(call.result) : null,
+          call.error ?? null,
+          call.startedAt,
+          call.duration,
+          call.replayOf ?? null
+        ]
+      })
+      return id
+    } catch (error) {
+      Logger.error('[ToolCallRecorder] Failed to record a tool call:', error)
+      return undefined
+    }
+  }
+
+  public async list(query?: ToolCallQuery): Promise<ToolCallRecord[]> {
+    const client = await this.getClient()
+    const { rows } = await client.execute(buildToolCallQuery(query))
+    return rows.map(toRecord)
+  }
+
+  public async get(id: string): Promise<ToolCallRecord> {
+    const client = await this.getClient()
+    const { rows } = await client.execute({ sql: 'SELECT * FROM tool_calls WHERE id = ?', args: [id] })
+    if (!rows.length) {
+      throw new ManagerError('ToolCallRecorder', 'not_found', `Tool call not found: ${id}`)
+    }
+    return toRecord(rows[0])
+  }
+
+  /**
+   * Remove the calls of a topic, or all calls
+   */
+  public async clear(topicId?: string) {
+    const client = await this.getClient()
+    if (topicId) {
+      await client.execute({ sql: 'DELETE FROM tool_calls WHERE topic_id = ?', args: [topicId] })
+    } else {
+      await client.execute('DELETE FROM tool_calls')
+    }
+  }
+
+  public async close() {
+    const client = await this.client?.catch(() => null)
+    client?.close()
+    this.client = null
+  }
+}
+
+export const toolCallRecorder = new ToolCallRecorder()
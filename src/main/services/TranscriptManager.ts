@@ -0,0 +1,79 @@
+import fs from 'node:fs'
+import path from 'node:path'
+import { fileURLToPath } from 'node:url'
+
+import { TRANSCRIPT_EXTENSION } from '@shared/config/constant'
+import { IpcChannel } from '@shared/IpcChannel'
+import { TranscriptFile } from '@types'
+import Logger from 'electron-log'
+
+import { windowService } from './WindowService'
+
+// transcripts are JSON, anything larger is not a transcript we exported
+const MAX_TRANSCRIPT_SIZE = 100 * 1024 * 1024
+
+/**
+ * Receiving end of the `.metheuschat` file association.
+ *
+ * Transcripts arrive as a file path on the command line (Windows/Linux, also through `second-instance`)
+ * or through `open-file` on macOS. The file is read here and handed to the main window, which imports it
+ * as a new read-only conversation. Like shared content, files are queued until the renderer listens.
+ */
+class TranscriptManager {
+  private pending: TranscriptFile[] = []
+  private rendererListening = false
+
+  public isTranscript(filePath: string): boolean {
+    return path.extname(filePath).toLowerCase() === TRANSCRIPT_EXTENSION
+  }
+
+  /**
+   * Transcript files found in a command line
+   */
+  public parseArgv(argv: string[]): string[] {
+    return argv
+      .filter((arg) => !arg.startsWith('--') && this.isTranscript(arg))
+      .map((arg) => (arg.startsWith('file://') ? fileURLToPath(arg) : path.resolve(arg)))
+      .filter((filePath) => fs.existsSync(filePath) && fs.statSync(filePath).isFile())
+  }
+
+  public async open(filePaths: string[]) {
+    for (const filePath of filePaths) {
+      try {
+        const { size } = await fs.promises.stat(filePath)
+        if (size > MAX_TRANSCRIPT_SIZE) {
+          Logger.warn(`[TranscriptManager] Ignoring oversized transcript: ${filePath}`)
+          continue
+        }
+        this.send({ path: filePath, content: await fs.promises.readFile(filePath, 'utf-8') })
+      } catch (error) {
+        Logger.error(`[TranscriptManager] Failed to read transcript ${filePath}:`, error)
+      }
+    }
+  }
+
+  /**
+   * Called by the renderer once it listens for transcripts, returns what arrived before
+   */
+  public takePending(): TranscriptFile[] {
+    this.rendererListening = true
+    const pending = this.pending
+    this.pending = []
+    return pending
+  }
+
+  private send(file: TranscriptFile) {
+    Logger.info(`[TranscriptManager] Opening transcript ${file.path}`)
+
+    const mainWindow = windowService.getMainWindow()
+    if (!this.rendererListening || !mainWindow || mainWindow.isDestroyed()) {
+      this.pending.push(file)
+      return
+    }
+
+    windowService.showMainWindow()
+    mainWindow.webContents.send(IpcChannel.Transcript_Opened, file)
+  }
+}
+
+export const transcriptManager = new TranscriptManager()
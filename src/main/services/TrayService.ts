@@ -1,21 +1,36 @@
 import { isLinux, isMac, isWin } from '@main/constant'
 import { locales } from '@main/utils/locales'
+import { TrayStatus } from '@types'
 import { app, Menu, MenuItemConstructorOptions, nativeImage, nativeTheme, Tray } from 'electron'
 
 import icon from '../../../build/tray_icon.png?asset'
 import iconDark from '../../../build/tray_icon_dark.png?asset'
 import iconLight from '../../../build/tray_icon_light.png?asset'
 import { ConfigKeys, configManager } from './ConfigManager'
+import { downloadManager } from './DownloadManager'
 import selectionService from './SelectionService'
 import { windowService } from './WindowService'
 
+const TOOLTIP_TITLE = 'Cherry Studio'
+// refreshes the "last backup 10m ago" line
+const TOOLTIP_INTERVAL = 60 * 1000
+
+/**
+ * The tray tooltip shows what the app is doing: chat requests being answered, batch jobs, downloads and the
+ * backup sync. The renderer reports its activity with `setStatus`, downloads are followed here. `setTooltip`
+ * replaces the title line, e.g. with the name of a running agent.
+ */
 export class TrayService {
   private static instance: TrayService
   private tray: Tray | null = null
   private contextMenu: Menu | null = null
+  private tooltip: string | null = null
+  private status: TrayStatus = { generating: 0, jobs: 0, syncing: false, lastBackupTime: null }
+  private downloads = 0
 
   constructor() {
     this.watchConfigChanges()
+    this.watchActivity()
     this.updateTray()
     TrayService.instance = this
   }
@@ -51,7 +66,7 @@ export class TrayService {
       this.tray.setContextMenu(this.contextMenu)
     }
 
-    this.tray.setToolTip('Cherry Studio')
+    this.updateTooltip()
 
     this.tray.on('right-click', () => {
       if (this.contextMenu) {
@@ -84,6 +99,10 @@ export class TrayService {
         label: trayLocale.show_mini_window,
         click: () => windowService.showMiniWindow()
       },
+      {
+        label: trayLocale.show_transcription_window,
+        click: () => windowService.showTranscriptionWindow()
+      },
       isWin && {
         label: selectionLocale.name + (selectionAssistantEnabled ? ' - On' : ' - Off'),
         // type: 'checkbox',
@@ -105,6 +124,46 @@ export class TrayService {
     this.contextMenu = Menu.buildFromTemplate(template)
   }
 
+  /**
+   * Replace the title line of the tooltip, null restores the app name
+   */
+  public setTooltip(text: string | null) {
+    this.tooltip = text?.trim() || null
+    this.updateTooltip()
+  }
+
+  public setStatus(status: Partial<TrayStatus>) {
+    this.status = { ...this.status, ...status }
+    this.updateTooltip()
+  }
+
+  private updateTooltip() {
+    if (!this.tray || this.tray.isDestroyed()) return
+
+    const { tooltip } = locales[configManager.getLanguage()].translation.tray
+    const { generating, jobs, syncing, lastBackupTime } = this.status
+    const lines = [
+      this.tooltip ?? TOOLTIP_TITLE,
+      generating > 0 && tooltip.generating.replace('{{count}}', String(generating)),
+      jobs > 0 && tooltip.jobs.replace('{{count}}', String(jobs)),
+      this.downloads > 0 && tooltip.downloads.replace('{{count}}', String(this.downloads)),
+      syncing
+        ? tooltip.syncing
+        : lastBackupTime && tooltip.last_backup.replace('{{time}}', formatElapsed(Date.now() - lastBackupTime))
+    ].filter(Boolean)
+    this.tray.setToolTip(lines.join('\n'))
+  }
+
+  private watchActivity() {
+    downloadManager.on('status', () => {
+      const downloads = downloadManager.listTasks().filter((task) => task.status === 'downloading').length
+      if (downloads === this.downloads) return
+      this.downloads = downloads
+      this.updateTooltip()
+    })
+    setInterval(() => this.status.lastBackupTime && this.updateTooltip(), TOOLTIP_INTERVAL)
+  }
+
   private updateTray() {
     const showTray = configManager.getTray()
     if (showTray) {
@@ -126,6 +185,7 @@ export class TrayService {
 
     configManager.subscribe(ConfigKeys.Language, () => {
       this.updateContextMenu()
+      this.updateTooltip()
     })
 
     configManager.subscribe(ConfigKeys.EnableQuickAssistant, () => {
@@ -141,3 +201,11 @@ export class TrayService {
     app.quit()
   }
 }
+
+// e.g. 10m, 3h or 2d
+function formatElapsed(ms: number) {
+  const minutes = Math.max(1, Math.round(ms / 60000))
+  if (minutes < 60) return `${minutes}m`
+  const hours = Math.round(minutes / 60)
+  return hours < 24 ? `${hours}h` : `${Math.round(hours / 24)}d`
+}
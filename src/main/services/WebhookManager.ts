@@ -0,0 +1,210 @@
+import crypto from 'node:crypto'
+import http from 'node:http'
+
+import {
+  renderWebhookPrompt,
+  SlidingWindowLimiter,
+  verifyWebhookSignature,
+  WEBHOOK_SIGNATURE_HEADER,
+  WEBHOOK_TIMESTAMP_HEADER
+} from '@main/utils/webhook'
+import { ManagerError } from '@shared/ManagerError'
+import { Webhook, WebhookAction, WebhookLogEntry } from '@types'
+import Logger from 'electron-log'
+import { v4 as uuidv4 } from 'uuid'
+
+import { configManager } from './ConfigManager'
+import { pushChannel } from './PushChannel'
+import { templateManager } from './TemplateManager'
+
+export const WEBHOOK_PATH_PREFIX = '/hooks/'
+
+// log entries kept per hook
+const MAX_LOG_ENTRIES = 100
+
+export interface WebhookInput {
+  name: string
+  action: WebhookAction
+  rateLimit?: number
+  enabled?: boolean
+}
+
+export interface WebhookResponse {
+  status: number
+  body: Record<string, any>
+}
+
+// runs an assistant with a prompt and returns its answer, provided by the API server
+export type WebhookAgentRunner = (assistantId: string, prompt: string) => Promise<string>
+
+/**
+ * Inbound webhooks of the local API server.
+ *
+ * External systems (CI, monitoring) POST to `/hooks/<id>` with `<timestamp>.<body>` signed by the hook secret in
+ * the `X-Cherry-Signature: sha256=<hmac>` header and the Unix timestamp in `X-Cherry-Timestamp`, the bearer token
+ * is not needed. A delivery either runs an
+ * assistant with a prompt built from the payload or is published on the push channel as `webhook:<id>`.
+ * Agent runs are answered with 202 right away, their result is published as `webhook.completed`.
+ */
+class WebhookManager {
+  private limiter = new SlidingWindowLimiter()
+  private logs = new Map<string, WebhookLogEntry[]>()
+
+  public list(): Webhook[] {
+    return configManager.getWebhooks()
+  }
+
+  public create(input: WebhookInput): Webhook {
+    this.validate(input)
+    const hook: Webhook = {
+      id: crypto.randomBytes(8).toString('hex'),
+      name: input.name,
+      secret: this.createSecret(),
+      action: input.action,
+      rateLimit: input.rateLimit ?? 60,
+      enabled: input.enabled ?? true,
+      createdAt: Date.now()
+    }
+    configManager.setWebhooks([...this.list(), hook])
+    Logger.info(`[WebhookManager] Created webhook ${hook.id} (${hook.action.type})`)
+    return hook
+  }
+
+  public update(id: string, patch: Partial<WebhookInput>): Webhook {
+    const hook = this.get(id)
+    const updated: Webhook = { ...hook, ...patch }
+    this.validate(updated)
+    this.save(updated)
+    this.limiter.reset(id)
+    return updated
+  }
+
+  public remove(id: string) {
+    this.get(id)
+    configManager.setWebhooks(this.list().filter((hook) => hook.id !== id))
+    this.logs.delete(id)
+    this.limiter.reset(id)
+  }
+
+  public regenerateSecret(id: string): Webhook {
+    const hook = { ...this.get(id), secret: this.createSecret() }
+    this.save(hook)
+    return hook
+  }
+
+  /**
+   * Recent deliveries, newest first
+   */
+  public getLogs(id?: string): WebhookLogEntry[] {
+    const entries = id ? (this.logs.get(id) ?? []) : [...this.logs.values()].flat()
+    return [...entries].sort((a, b) => b.time - a.time)
+  }
+
+  /**
+   * Handle a delivery to `/hooks/<id>`, the signature is checked before the rate limit
+   */
+  public deliver(
+    id: string,
+    headers: http.IncomingHttpHeaders,
+    body: Buffer,
+    runAgent: WebhookAgentRunner
+  ): WebhookResponse {
+    const hook = this.list().find((item) => item.id === id)
+    // unknown and disabled hooks look the same to the caller
+    if (!hook || !hook.enabled) {
+      return { status: 404, body: { error: { code: 'not_found', message: 'Unknown webhook' } } }
+    }
+
+    if (
+      !verifyWebhookSignature(hook.secret, body, headers[WEBHOOK_SIGNATURE_HEADER], headers[WEBHOOK_TIMESTAMP_HEADER])
+    ) {
+      this.log({ hookId: id, result: 'rejected', status: 401, error: 'Invalid or expired signature' })
+      return { status: 401, body: { error: { code: 'invalid_signature', message: 'Invalid or expired signature' } } }
+    }
+
+    // only signed deliveries count, so forged requests can't use up the limit of the sender
+    if (!this.limiter.take(id, hook.rateLimit)) {
+      this.log({ hookId: id, result: 'rate_limited', status: 429 })
+      return { status: 429, body: { error: { code: 'rate_limited', message: 'Too many deliveries' } } }
+    }
+
+    const payload = this.parsePayload(body, headers['content-type'])
+    const deliveryId = uuidv4()
+
+    if (hook.action.type === 'event') {
+      pushChannel.publish(`webhook:${id}`, 'webhook.received', { hookId: id, deliveryId, payload })
+      this.log({ hookId: id, deliveryId, result: 'completed', status: 202 })
+      return { status: 202, body: { id: deliveryId, accepted: true } }
+    }
+
+    this.log({ hookId: id, deliveryId, result: 'accepted', status: 202 })
+    this.runAgent(hook, hook.action, deliveryId, payload, runAgent)
+    return { status: 202, body: { id: deliveryId, accepted: true } }
+  }
+
+  private async runAgent(
+    hook: Webhook,
+    action: Extract<WebhookAction, { type: 'agent' }>,
+    deliveryId: string,
+    payload: unknown,
+    runAgent: WebhookAgentRunner
+  ) {
+    const topic = `webhook:${hook.id}`
+    try {
+      const prompt = renderWebhookPrompt(action.template, payload, templateManager.getPartials())
+      const text = await runAgent(action.assistantId, prompt)
+      pushChannel.publish(topic, 'webhook.completed', { hookId: hook.id, deliveryId, text })
+      this.log({ hookId: hook.id, deliveryId, result: 'completed', status: 200 })
+    } catch (error: any) {
+      Logger.error(`[WebhookManager] Webhook ${hook.id} failed to run the assistant:`, error)
+      pushChannel.publish(topic, 'webhook.failed', { hookId: hook.id, deliveryId, error: error.message })
+      this.log({ hookId: hook.id, deliveryId, result: 'failed', status: error.status ?? 500, error: error.message })
+    }
+  }
+
+  private parsePayload(body: Buffer, contentType: string | undefined): unknown {
+    const text = body.toString('utf8')
+    if (!contentType?.includes('json')) return text
+    try {
+      return JSON.parse(text || '{}')
+    } catch {
+      return text
+    }
+  }
+
+  private log(entry: Omit<WebhookLogEntry, 'time'>) {
+    const entries = this.logs.get(entry.hookId) ?? []
+    entries.push({ time: Date.now(), ...entry })
+    this.logs.set(entry.hookId, entries.slice(-MAX_LOG_ENTRIES))
+  }
+
+  private get(id: string): Webhook {
+    const hook = this.list().find((item) => item.id === id)
+    if (!hook) {
+      throw new ManagerError('WebhookManager', 'not_found', `Webhook not found: ${id}`)
+    }
+    return hook
+  }
+
+  private save(hook: Webhook) {
+    configManager.setWebhooks(this.list().map((item) => (item.id === hook.id ? hook : item)))
+  }
+
+  private validate(input: Partial<WebhookInput>) {
+    if (!input.name?.trim()) {
+      throw new ManagerError('WebhookManager', 'invalid_argument', 'Webhook name is required')
+    }
+    if (input.action?.type === 'agent' && !input.action.assistantId) {
+      throw new ManagerError('WebhookManager', 'invalid_argument', 'Webhook assistant is required')
+    }
+    if (input.rateLimit !== undefined && (!Number.isInteger(input.rateLimit) || input.rateLimit < 0)) {
+      throw new ManagerError('WebhookManager', 'invalid_argument', `Invalid rate limit: ${input.rateLimit}`)
+    }
+  }
+
+  private createSecret(): string {
+    return `whsec_${crypto.randomBytes(24).toString('hex')}`
+  }
+}
+
+export const webhookManager = new WebhookManager()
@@ -1,17 +1,58 @@
+import { matchShortcut } from '@main/utils/shortcut'
+import { EPHEMERAL_WEBVIEW_PARTITION_PREFIX } from '@shared/config/constant'
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import { WebviewContextMenuAction } from '@types'
 import { session, shell, webContents } from 'electron'
+import Logger from 'electron-log'
+
+import { contextMenu } from './ContextMenu'
+import { downloadManager } from './DownloadManager'
+
+// before-input-event listeners of the webviews that route shortcuts to their host
+const shortcutHandlers = new Map<number, (event: Electron.Event, input: Electron.Input) => void>()
+// suspended webviews, true when the debugger was attached for the suspension and must be detached on resume
+const suspendedWebviews = new Map<number, boolean>()
+// webview sessions whose downloads already go to the download manager
+const downloadSessions = new WeakSet<Electron.Session>()
 
 /**
  * init the useragent of the webview session
  * remove the CherryStudio and Electron from the useragent
  */
-export function initSessionUserAgent() {
-  const wvSession = session.fromPartition('persist:webview')
+export function initSessionUserAgent(partition = 'persist:webview') {
+  const wvSession = session.fromPartition(partition)
   const originUA = wvSession.getUserAgent()
   const newUA = originUA.replace(/CherryStudio\/\S+\s/, '').replace(/Electron\/\S+\s/, '')
 
   wvSession.setUserAgent(newUA)
 }
 
+/**
+ * Save the files downloaded by pages of the partition to the download directory, tracked by the download manager
+ */
+export function interceptWebviewDownloads(partition = 'persist:webview') {
+  const wvSession = session.fromPartition(partition)
+  if (downloadSessions.has(wvSession)) return
+
+  downloadSessions.add(wvSession)
+  wvSession.on('will-download', (_, item) => downloadManager.track(item))
+}
+
+/**
+ * Wipe the in-memory session of an incognito mini app so the next launch starts without cookies or storage
+ */
+export async function clearEphemeralSession(partition: string) {
+  if (!partition.startsWith(EPHEMERAL_WEBVIEW_PARTITION_PREFIX)) {
+    throw new ManagerError('WebviewService', 'invalid_argument', `Not an incognito partition: ${partition}`)
+  }
+
+  const wvSession = session.fromPartition(partition)
+  await wvSession.clearStorageData()
+  await wvSession.clearCache()
+  await wvSession.clearAuthCache()
+}
+
 /**
  * WebviewService handles the behavior of links opened from webview elements
  * It controls whether links should be opened within the application or in an external browser
@@ -29,3 +70,94 @@ export function setOpenLinkExternal(webviewId: number, isExternal: boolean) {
     }
   })
 }
+
+/**
+ * Host shortcuts pressed while a webview has focus are kept from the page and forwarded to the window hosting
+ * the webview, an empty list lets the mini app handle every shortcut itself
+ */
+export function setShortcutPassThrough(webviewId: number, shortcuts: string[][]) {
+  const webview = webContents.fromId(webviewId)
+  if (!webview) return
+
+  const previous = shortcutHandlers.get(webviewId)
+  if (previous) {
+    webview.off('before-input-event', previous)
+    shortcutHandlers.delete(webviewId)
+  }
+  if (!shortcuts.length) return
+
+  const handler = (event: Electron.Event, input: Electron.Input) => {
+    if (input.type !== 'keyDown' || !shortcuts.some((shortcut) => matchShortcut(input, shortcut))) return
+
+    event.preventDefault()
+    const { key, code, control, meta, shift, alt } = input
+    webview.hostWebContents?.send(IpcChannel.Webview_ShortcutForwarded, { key, code, control, meta, shift, alt })
+  }
+
+  if (!previous) {
+    webview.once('destroyed', () => shortcutHandlers.delete(webviewId))
+  }
+  webview.on('before-input-event', handler)
+  shortcutHandlers.set(webviewId, handler)
+}
+
+/**
+ * Set the host actions shown in the right-click menu of a webview
+ */
+export function setWebviewContextMenu(webviewId: number, actions: WebviewContextMenuAction[]) {
+  const webview = webContents.fromId(webviewId)
+  if (!webview) return
+
+  contextMenu.webviewContextMenu(webview, actions)
+}
+
+/**
+ * Freeze a hidden webview: its garbage is collected and the page stops running scripts and timers until resumed,
+ * letting Chromium reclaim the memory and CPU of mini apps left in the background
+ */
+export async function suspendWebview(webviewId: number) {
+  const webview = webContents.fromId(webviewId)
+  if (!webview) {
+    throw new ManagerError('WebviewService', 'not_found', `Webview not found: ${webviewId}`)
+  }
+  if (suspendedWebviews.has(webviewId)) return
+
+  const attached = !webview.debugger.isAttached()
+  try {
+    if (attached) webview.debugger.attach('1.3')
+    await webview.debugger.sendCommand('HeapProfiler.collectGarbage')
+    await webview.debugger.sendCommand('Page.setWebLifecycleState', { state: 'frozen' })
+  } catch (error) {
+    if (attached && webview.debugger.isAttached()) webview.debugger.detach()
+    throw new ManagerError('WebviewService', 'unavailable', `Cannot suspend webview ${webviewId}: ${error}`)
+  }
+
+  webview.setBackgroundThrottling(true)
+  suspendedWebviews.set(webviewId, attached)
+  webview.once('destroyed', () => suspendedWebviews.delete(webviewId))
+  Logger.info(`[WebviewService] Suspended webview ${webviewId}`)
+}
+
+/**
+ * A suspended webview runs no scripts, so it can't answer heartbeats until resumed
+ */
+export function isWebviewSuspended(webviewId: number): boolean {
+  return suspendedWebviews.has(webviewId)
+}
+
+/**
+ * Resume a webview frozen by suspendWebview, does nothing for running webviews
+ */
+export async function resumeWebview(webviewId: number) {
+  const webview = webContents.fromId(webviewId)
+  const attached = suspendedWebviews.get(webviewId)
+  if (!webview || attached === undefined) return
+
+  suspendedWebviews.delete(webviewId)
+  try {
+    await webview.debugger.sendCommand('Page.setWebLifecycleState', { state: 'active' })
+  } finally {
+    if (attached && webview.debugger.isAttached()) webview.debugger.detach()
+  }
+  Logger.info(`[WebviewService] Resumed webview ${webviewId}`)
+}
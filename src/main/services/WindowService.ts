@@ -4,6 +4,7 @@ import './ThemeService'
 import { is } from '@electron-toolkit/utils'
 import { isDev, isLinux, isMac, isWin } from '@main/constant'
 import { getFilesDir } from '@main/utils/file'
+import { EPHEMERAL_WEBVIEW_PARTITION_PREFIX } from '@shared/config/constant'
 import { IpcChannel } from '@shared/IpcChannel'
 import { app, BrowserWindow, nativeTheme, shell } from 'electron'
 import Logger from 'electron-log'
@@ -12,19 +13,26 @@ import { join } from 'path'
 
 import icon from '../../../build/icon.png?asset'
 import { titleBarOverlayDark, titleBarOverlayLight } from '../config'
+import { capabilityManager } from './CapabilityManager'
 import { configManager } from './ConfigManager'
+import { contentBlocker } from './ContentBlocker'
 import { contextMenu } from './ContextMenu'
-import { initSessionUserAgent } from './WebviewService'
+import { lockManager } from './LockManager'
+import { initSessionUserAgent, interceptWebviewDownloads } from './WebviewService'
 
 export class WindowService {
   private static instance: WindowService | null = null
   private mainWindow: BrowserWindow | null = null
   private miniWindow: BrowserWindow | null = null
+  private transcriptionWindow: BrowserWindow | null = null
+  private presentationWindow: BrowserWindow | null = null
   private isPinnedMiniWindow: boolean = false
   //hacky-fix: store the focused status of mainWindow before miniWindow shows
   //to restore the focus status when miniWindow hides
   private wasMainWindowFocused: boolean = false
   private lastRendererProcessCrashTime: number = 0
+  //keep the main window hidden when it only serves the local API (CLI commands)
+  private startHidden: boolean = false
 
   public static getInstance(): WindowService {
     if (!WindowService.instance) {
@@ -33,7 +41,9 @@ export class WindowService {
     return WindowService.instance
   }
 
-  public createMainWindow(): BrowserWindow {
+  public createMainWindow(options: { hidden?: boolean } = {}): BrowserWindow {
+    this.startHidden = !!options.hidden
+
     if (this.mainWindow && !this.mainWindow.isDestroyed()) {
       this.mainWindow.show()
       this.mainWindow.focus()
@@ -80,7 +90,7 @@ export class WindowService {
 
     //preload miniWindow to resolve series of issues about miniWindow in Mac
     const enableQuickAssistant = configManager.getEnableQuickAssistant()
-    if (enableQuickAssistant && !this.miniWindow) {
+    if (enableQuickAssistant && !this.miniWindow && !this.startHidden) {
       this.miniWindow = this.createMiniWindow(true)
     }
 
@@ -138,7 +148,8 @@ export class WindowService {
     // Dangerous API
     if (isDev) {
       mainWindow.webContents.on('will-attach-webview', (_, webPreferences) => {
-        webPreferences.preload = join(__dirname, '../preload/index.js')
+        // keep the preload of mini apps that bring their own scripts
+        webPreferences.preload ??= join(__dirname, '../preload/index.js')
       })
     }
   }
@@ -149,7 +160,7 @@ export class WindowService {
 
       // show window only when laucn to tray not set
       const isLaunchToTray = configManager.getLaunchToTray()
-      if (!isLaunchToTray) {
+      if (!isLaunchToTray && !this.startHidden) {
         //[mac]hacky-fix: miniWindow set visibleOnFullScreen:true will cause dock icon disappeared
         app.dock?.show()
         mainWindow.show()
@@ -214,6 +225,14 @@ export class WindowService {
   }
 
   private setupWebContentsHandlers(mainWindow: BrowserWindow) {
+    mainWindow.webContents.on('will-attach-webview', (_, webPreferences) => {
+      if (webPreferences.partition?.startsWith(EPHEMERAL_WEBVIEW_PARTITION_PREFIX)) {
+        initSessionUserAgent(webPreferences.partition)
+      }
+      interceptWebviewDownloads(webPreferences.partition || 'persist:webview')
+      contentBlocker.attach(webPreferences.partition || 'persist:webview')
+    })
+
     mainWindow.webContents.on('will-navigate', (event, url) => {
       if (url.includes('localhost:5173')) {
         return
@@ -340,6 +359,11 @@ export class WindowService {
   }
 
   public showMainWindow() {
+    if (lockManager.isLocked()) {
+      lockManager.focusLockWindow()
+      return
+    }
+
     if (this.miniWindow && !this.miniWindow.isDestroyed()) {
       this.miniWindow.hide()
     }
@@ -485,10 +509,13 @@ export class WindowService {
     return this.miniWindow
   }
 
-  public showMiniWindow() {
+  /**
+   * @param listen start capturing a spoken question right away, e.g. after the wake word
+   */
+  public showMiniWindow(listen = false) {
     const enableQuickAssistant = configManager.getEnableQuickAssistant()
 
-    if (!enableQuickAssistant) {
+    if (!enableQuickAssistant || lockManager.isLocked()) {
       return
     }
 
@@ -499,10 +526,16 @@ export class WindowService {
         this.miniWindow.restore()
       }
       this.miniWindow.show()
+      if (listen) this.miniWindow.webContents.send(IpcChannel.MiniWindowListen)
       return
     }
 
     this.miniWindow = this.createMiniWindow()
+    if (listen) {
+      this.miniWindow.webContents.once('did-finish-load', () => {
+        this.miniWindow?.webContents.send(IpcChannel.MiniWindowListen)
+      })
+    }
   }
 
   public hideMiniWindow() {
@@ -539,6 +572,125 @@ export class WindowService {
     this.isPinnedMiniWindow = isPinned
   }
 
+  /**
+   * Pin from the main process, e.g. when a snap layout tiles the quick assistant next to the main window
+   */
+  public pinMiniWindow() {
+    this.isPinnedMiniWindow = true
+    this.miniWindow?.webContents.send(IpcChannel.MiniWindowPinChanged, true)
+  }
+
+  public getMiniWindow(): BrowserWindow | null {
+    return this.miniWindow
+  }
+
+  /**
+   * Always-on-top overlay showing live transcripts, e.g. during a meeting
+   */
+  public showTranscriptionWindow() {
+    if (lockManager.isLocked()) return
+
+    if (this.transcriptionWindow && !this.transcriptionWindow.isDestroyed()) {
+      this.transcriptionWindow.show()
+      this.transcriptionWindow.focus()
+      return
+    }
+
+    this.transcriptionWindow = new BrowserWindow({
+      width: 420,
+      height: 520,
+      minWidth: 320,
+      minHeight: 240,
+      show: false,
+      autoHideMenuBar: true,
+      frame: false,
+      alwaysOnTop: true,
+      skipTaskbar: true,
+      minimizable: false,
+      maximizable: false,
+      fullscreenable: false,
+      webPreferences: {
+        preload: join(__dirname, '../preload/index.js'),
+        sandbox: false,
+        backgroundThrottling: false
+      }
+    })
+
+    this.transcriptionWindow.setVisibleOnAllWorkspaces(true, { visibleOnFullScreen: true })
+    this.transcriptionWindow.setAlwaysOnTop(true, 'floating')
+    this.applyContentProtection(this.transcriptionWindow)
+
+    this.transcriptionWindow.on('ready-to-show', () => this.transcriptionWindow?.show())
+    this.transcriptionWindow.on('closed', () => {
+      this.transcriptionWindow = null
+    })
+
+    if (is.dev && process.env['ELECTRON_RENDERER_URL']) {
+      this.transcriptionWindow.loadURL(process.env['ELECTRON_RENDERER_URL'] + '/transcription.html')
+    } else {
+      this.transcriptionWindow.loadFile(join(__dirname, '../renderer/transcription.html'))
+    }
+  }
+
+  public closeTranscriptionWindow() {
+    this.transcriptionWindow?.close()
+  }
+
+  /**
+   * Full-screen read-only window showing one conversation, for demos and screen sharing.
+   * The window only holds the 'app' capability, so it cannot write files, run agents or change settings.
+   */
+  public enterPresentationMode(topicId: string) {
+    if (lockManager.isLocked()) return
+
+    this.exitPresentationMode()
+
+    const window = new BrowserWindow({
+      show: false,
+      fullscreen: true,
+      autoHideMenuBar: true,
+      frame: false,
+      backgroundColor: nativeTheme.shouldUseDarkColors ? '#181818' : '#FFFFFF',
+      webPreferences: {
+        preload: join(__dirname, '../preload/index.js'),
+        sandbox: false
+      }
+    })
+    this.presentationWindow = window
+
+    capabilityManager.grant(window.webContents, ['app'])
+    this.applyContentProtection(window)
+
+    window.on('ready-to-show', () => window.show())
+    window.on('closed', () => {
+      if (this.presentationWindow === window) this.presentationWindow = null
+    })
+
+    if (is.dev && process.env['ELECTRON_RENDERER_URL']) {
+      window.loadURL(`${process.env['ELECTRON_RENDERER_URL']}/presentation.html?topicId=${encodeURIComponent(topicId)}`)
+    } else {
+      window.loadFile(join(__dirname, '../renderer/presentation.html'), { query: { topicId } })
+    }
+  }
+
+  public exitPresentationMode() {
+    this.presentationWindow?.close()
+  }
+
+  /**
+   * Exclude every app window from screenshots and screen sharing.
+   * Windows created later pick up the setting in `applyContentProtection`.
+   */
+  public setContentProtection(enabled: boolean) {
+    configManager.setContentProtection(enabled)
+    BrowserWindow.getAllWindows().forEach((window) => this.applyContentProtection(window))
+  }
+
+  public applyContentProtection(window: BrowserWindow) {
+    if (window.isDestroyed()) return
+    window.setContentProtection(configManager.getContentProtection())
+  }
+
   /**
    * 引用文本到主窗口
    * @param text 原始文本（未格式化）
@@ -0,0 +1,180 @@
+import os from 'node:os'
+import { Worker } from 'node:worker_threads'
+
+import { ManagerError } from '@shared/ManagerError'
+import { WorkerPoolStatus } from '@types'
+import { powerMonitor } from 'electron'
+import Logger from 'electron-log'
+
+import createPoolWorker from '../workers/pool.worker?nodeWorker'
+import type { WorkerRequest, WorkerResponse } from '../workers/pool.worker'
+import type { WorkerTasks, WorkerTaskType } from '../workers/tasks'
+import { configManager } from './ConfigManager'
+
+const MAX_SIZE = 16
+// idle workers are stopped after this time, a new one starts with the next task
+const IDLE_TIMEOUT = 60 * 1000
+
+type TaskArgs<T extends WorkerTaskType> = Parameters<WorkerTasks[T]>[0]
+type TaskResult<T extends WorkerTaskType> = Awaited<ReturnType<WorkerTasks[T]>>
+
+interface Job {
+  id: number
+  type: WorkerTaskType
+  args: unknown
+  resolve: (result: any) => void
+  reject: (error: Error) => void
+}
+
+interface PoolWorker {
+  worker: Worker
+  job: Job | null
+  idleTimer?: NodeJS.Timeout
+}
+
+/**
+ * Shared worker threads for CPU heavy work such as hashing and parsing large files.
+ *
+ * Modules submit named tasks instead of spawning their own threads, tasks queue once every worker is busy.
+ * The size comes from the settings, by default one less than the CPUs so the main process stays responsive,
+ * and drops to a single worker on battery power. Native code with its own threads, i.e. llama.cpp for local
+ * embeddings, reranking and chat, is limited to the same number of threads through `getThreadCount`.
+ */
+class WorkerPool {
+  private workers: PoolWorker[] = []
+  private queue: Job[] = []
+  private nextId = 1
+  private onBattery = false
+
+  public init() {
+    this.onBattery = powerMonitor.isOnBatteryPower()
+    powerMonitor.on('on-battery', () => this.setOnBattery(true))
+    powerMonitor.on('on-ac', () => this.setOnBattery(false))
+  }
+
+  public run<T extends WorkerTaskType>(type: T, args: TaskArgs<T>): Promise<TaskResult<T>> {
+    return new Promise((resolve, reject) => {
+      this.queue.push({ id: this.nextId++, type, args, resolve, reject })
+      this.dispatch()
+    })
+  }
+
+  /**
+   * Threads a native runtime may use for a new context, the pool size at the time it is created
+   */
+  public getThreadCount(): number {
+    return this.getSize()
+  }
+
+  public getStatus(): WorkerPoolStatus {
+    const busy = this.workers.filter((slot) => slot.job).length
+    return {
+      size: this.getSize(),
+      configuredSize: configManager.getWorkerPoolSize(),
+      defaultSize: this.getDefaultSize(),
+      maxSize: MAX_SIZE,
+      onBattery: this.onBattery,
+      busy,
+      idle: this.workers.length - busy,
+      queued: this.queue.length
+    }
+  }
+
+  /**
+   * Change the number of workers, 0 for the default. Busy workers above the new size finish their task first.
+   */
+  public setSize(size: number): WorkerPoolStatus {
+    if (!Number.isInteger(size) || size < 0 || size > MAX_SIZE) {
+      throw new ManagerError('WorkerPool', 'invalid_argument', `Size must be between 0 and ${MAX_SIZE}`)
+    }
+    configManager.setWorkerPoolSize(size)
+    this.trim()
+    this.dispatch()
+    return this.getStatus()
+  }
+
+  private getDefaultSize() {
+    return Math.max(1, Math.min(4, os.availableParallelism() - 1))
+  }
+
+  private getSize() {
+    if (this.onBattery) return 1
+    return configManager.getWorkerPoolSize() || this.getDefaultSize()
+  }
+
+  private setOnBattery(onBattery: boolean) {
+    this.onBattery = onBattery
+    this.trim()
+    this.dispatch()
+  }
+
+  private dispatch() {
+    while (this.queue.length) {
+      const slot = this.workers.find((item) => !item.job) ?? (this.workers.length < this.getSize() && this.spawn())
+      if (!slot) return
+
+      const job = this.queue.shift()!
+      clearTimeout(slot.idleTimer)
+      slot.job = job
+      slot.worker.postMessage({ id: job.id, type: job.type, args: job.args } satisfies WorkerRequest)
+    }
+  }
+
+  private spawn(): PoolWorker {
+    const slot: PoolWorker = { worker: createPoolWorker({}), job: null }
+
+    slot.worker.on('message', (response: WorkerResponse) => {
+      const job = slot.job
+      if (!job || job.id !== response.id) return
+      slot.job = null
+      if (response.error) {
+        job.reject(ManagerError.fromJSON(response.error))
+      } else {
+        job.resolve(response.result)
+      }
+      this.release(slot)
+    })
+    slot.worker.on('error', (error) => Logger.error('[WorkerPool] Worker failed:', error))
+    // a crashed worker fails its task, the next one gets a new worker
+    slot.worker.on('exit', () => {
+      this.remove(slot)
+      slot.job?.reject(new ManagerError('WorkerPool', 'internal', `Worker stopped while running ${slot.job.type}`))
+      slot.job = null
+      this.dispatch()
+    })
+    // workers never keep the app from quitting
+    slot.worker.unref()
+
+    this.workers.push(slot)
+    return slot
+  }
+
+  private release(slot: PoolWorker) {
+    if (this.workers.length > this.getSize()) {
+      this.stop(slot)
+    } else {
+      slot.idleTimer = setTimeout(() => this.stop(slot), IDLE_TIMEOUT)
+    }
+    this.dispatch()
+  }
+
+  // stop idle workers above the current size
+  private trim() {
+    this.workers
+      .filter((slot) => !slot.job)
+      .slice(0, Math.max(0, this.workers.length - this.getSize()))
+      .forEach((slot) => this.stop(slot))
+  }
+
+  private stop(slot: PoolWorker) {
+    clearTimeout(slot.idleTimer)
+    this.remove(slot)
+    slot.worker.terminate().catch(() => {})
+  }
+
+  private remove(slot: PoolWorker) {
+    this.workers = this.workers.filter((item) => item !== slot)
+  }
+}
+
+export const workerPool = new WorkerPool()
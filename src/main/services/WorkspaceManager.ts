@@ -0,0 +1,119 @@
+import crypto, { KeyObject } from 'node:crypto'
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { getKeyFingerprint, openBundle, sealBundle } from '@main/utils/bundle'
+import { ManagerError } from '@shared/ManagerError'
+import { dialog } from 'electron'
+import Logger from 'electron-log'
+
+import { configManager } from './ConfigManager'
+
+export interface WorkspaceExportResult {
+  filePath: string
+  // fingerprint of this installation's signing key
+  fingerprint: string
+}
+
+export interface WorkspaceImportResult {
+  // workspace payload produced by the renderer, JSON
+  data: string
+  // fingerprint of the key that signed the bundle
+  fingerprint: string
+  // whether this installation signed the bundle
+  own: boolean
+  // whether the signer was pinned on this installation, other bundles are only self-signed
+  trusted: boolean
+  createdAt: number
+}
+
+/**
+ * Export and import of the workspace as a single signed, encrypted bundle.
+ *
+ * The renderer collects the workspace (settings, assistants, agents, mini apps, quick phrases and optionally
+ * conversations), this manager seals it with the passphrase and signs it with the installation's Ed25519 key,
+ * stored in `Data/Workspace/signing-key.pem`. Imports verify the signature and report the signer's fingerprint
+ * so a team can check a bundle came from the expected admin, whose fingerprint can then be pinned as trusted.
+ */
+class WorkspaceManager {
+  private keyFile = path.join(getDataPath(), 'Workspace', 'signing-key.pem')
+  private privateKey: KeyObject | null = null
+
+  public getFingerprint(): string {
+    return getKeyFingerprint(crypto.createPublicKey(this.getPrivateKey()))
+  }
+
+  /**
+   * Seal and write the workspace, asks for the destination when no path is given. Returns null when cancelled
+   */
+  public async export(data: string, passphrase: string, filePath?: string): Promise<WorkspaceExportResult | null> {
+    if (!passphrase) {
+      throw new ManagerError('WorkspaceManager', 'invalid_argument', 'Passphrase is required')
+    }
+
+    if (!filePath) {
+      const result = await dialog.showSaveDialog({
+        defaultPath: `workspace-${new Date().toISOString().slice(0, 10)}.cherryworkspace`,
+        filters: [{ name: 'Cherry Studio Workspace', extensions: ['cherryworkspace'] }]
+      })
+      if (result.canceled || !result.filePath) return null
+      filePath = result.filePath
+    }
+
+    const bundle = sealBundle(Buffer.from(data, 'utf-8'), passphrase, this.getPrivateKey())
+    await fs.promises.writeFile(filePath, JSON.stringify(bundle))
+    Logger.info(`[WorkspaceManager] Exported workspace to ${filePath}`)
+    return { filePath, fingerprint: this.getFingerprint() }
+  }
+
+  public async import(filePath: string, passphrase: string): Promise<WorkspaceImportResult> {
+    let bundle: unknown
+    try {
+      bundle = JSON.parse(await fs.promises.readFile(filePath, 'utf-8'))
+    } catch (error: any) {
+      if (error?.code === 'ENOENT') {
+        throw new ManagerError('WorkspaceManager', 'not_found', `File not found: ${filePath}`)
+      }
+      throw new ManagerError('WorkspaceManager', 'invalid_argument', 'Not a workspace bundle')
+    }
+
+    const own = this.getFingerprint()
+    const { payload, fingerprint, trusted, createdAt } = openBundle(bundle, passphrase, [
+      own,
+      ...configManager.getWorkspaceTrustedSigners()
+    ])
+    Logger.info(`[WorkspaceManager] Opened workspace bundle signed by ${fingerprint}, trusted: ${trusted}`)
+    return { data: payload.toString('utf-8'), fingerprint, own: fingerprint === own, trusted, createdAt }
+  }
+
+  /**
+   * Pin a signer, its bundles are reported as trusted from now on
+   */
+  public trustSigner(fingerprint: string) {
+    if (!/^([0-9a-f]{4}:){7}[0-9a-f]{4}$/.test(fingerprint)) {
+      throw new ManagerError('WorkspaceManager', 'invalid_argument', `Invalid fingerprint: ${fingerprint}`)
+    }
+    const signers = configManager.getWorkspaceTrustedSigners()
+    if (!signers.includes(fingerprint)) {
+      configManager.setWorkspaceTrustedSigners([...signers, fingerprint])
+    }
+  }
+
+  private getPrivateKey(): KeyObject {
+    if (this.privateKey) return this.privateKey
+
+    if (fs.existsSync(this.keyFile)) {
+      this.privateKey = crypto.createPrivateKey(fs.readFileSync(this.keyFile, 'utf-8'))
+      return this.privateKey
+    }
+
+    const { privateKey } = crypto.generateKeyPairSync('ed25519')
+    fs.mkdirSync(path.dirname(this.keyFile), { recursive: true })
+    fs.writeFileSync(this.keyFile, privateKey.export({ type: 'pkcs8', format: 'pem' }), { mode: 0o600 })
+    this.privateKey = privateKey
+    return privateKey
+  }
+}
+
+export const workspaceManager = new WorkspaceManager()
@@ -0,0 +1,72 @@
+import { IpcChannel } from '@shared/IpcChannel'
+import { ManagerError } from '@shared/ManagerError'
+import type { WebContents } from 'electron'
+import { describe, expect, it, vi } from 'vitest'
+
+import { capabilityManager } from '../CapabilityManager'
+
+vi.mock('electron-log', () => ({
+  default: { warn: vi.fn() }
+}))
+
+let nextId = 1
+
+function mockContents(type: 'window' | 'webview'): WebContents {
+  return {
+    id: nextId++,
+    getType: () => type,
+    getURL: () => 'https://example.com',
+    once: vi.fn()
+  } as unknown as WebContents
+}
+
+describe('CapabilityManager', () => {
+  it('should map channels to their required capability', () => {
+    expect(capabilityManager.getRequiredCapability(IpcChannel.File_Read)).toBe('file')
+    expect(capabilityManager.getRequiredCapability(IpcChannel.Mcp_CallTool)).toBe('agent')
+    expect(capabilityManager.getRequiredCapability(IpcChannel.App_SetLaunchOnBoot)).toBe('system')
+    expect(capabilityManager.getRequiredCapability(IpcChannel.App_Info)).toBe('app')
+    expect(capabilityManager.getRequiredCapability(IpcChannel.Notification_Send)).toBe('app')
+  })
+
+  it('should allow every channel for app windows', () => {
+    const contents = mockContents('window')
+    expect(capabilityManager.isAllowed(contents, IpcChannel.File_Read)).toBe(true)
+    expect(capabilityManager.isAllowed(contents, IpcChannel.Mcp_CallTool)).toBe(true)
+  })
+
+  it('should deny webviews without grants', () => {
+    const contents = mockContents('webview')
+    expect(capabilityManager.isAllowed(contents, IpcChannel.App_Info)).toBe(false)
+    expect(() => capabilityManager.assert(contents, IpcChannel.File_Read)).toThrow(ManagerError)
+    expect(() => capabilityManager.assert(contents, IpcChannel.Mcp_CallTool)).toThrow(
+      expect.objectContaining({ code: 'permission_denied' })
+    )
+  })
+
+  it('should let webviews reach the mini app bridge only', () => {
+    const contents = mockContents('webview')
+    expect(capabilityManager.getRequiredCapability(IpcChannel.MiniApp_BridgeInvoke)).toBe('miniapp')
+    expect(capabilityManager.isAllowed(contents, IpcChannel.MiniApp_BridgeInvoke)).toBe(true)
+    expect(capabilityManager.isAllowed(contents, IpcChannel.MiniApp_PostBridgeMessage)).toBe(false)
+  })
+
+  it('should use explicit grants over the defaults', () => {
+    const webview = mockContents('webview')
+    capabilityManager.grant(webview, ['app'])
+    expect(capabilityManager.isAllowed(webview, IpcChannel.App_Info)).toBe(true)
+    expect(capabilityManager.isAllowed(webview, IpcChannel.File_Read)).toBe(false)
+
+    const window = mockContents('window')
+    capabilityManager.revoke(window)
+    expect(capabilityManager.isAllowed(window, IpcChannel.App_Info)).toBe(false)
+  })
+
+  it('should keep read-only windows to reading', () => {
+    const window = mockContents('window')
+    capabilityManager.grant(window, ['app'])
+    expect(capabilityManager.isAllowed(window, IpcChannel.SecureTopic_Open)).toBe(true)
+    expect(capabilityManager.isAllowed(window, IpcChannel.SecureTopic_RemoveKey)).toBe(false)
+    expect(capabilityManager.isAllowed(window, IpcChannel.File_Write)).toBe(false)
+  })
+})
@@ -0,0 +1,62 @@
+import fs from 'node:fs'
+import os from 'node:os'
+import path from 'node:path'
+
+import { afterAll, describe, expect, it, vi } from 'vitest'
+
+import { citationService } from '../CitationService'
+
+vi.mock('@main/utils', () => ({
+  getDataPath: () => os.tmpdir()
+}))
+
+vi.mock('electron', () => ({
+  shell: { openExternal: vi.fn(), openPath: vi.fn() }
+}))
+
+vi.mock('electron-log', () => ({
+  default: { warn: vi.fn(), error: vi.fn() }
+}))
+
+vi.mock('officeparser/pdfjs-dist-build/pdf.js', () => ({
+  getDocument: vi.fn()
+}))
+
+describe('CitationService', () => {
+  const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'citation-'))
+
+  afterAll(() => {
+    fs.rmSync(dir, { recursive: true, force: true })
+  })
+
+  it('should locate overlapping chunks of a text file in order', async () => {
+    const file = path.join(dir, 'notes.md')
+    fs.writeFileSync(file, ['alpha beta', 'gamma delta', 'alpha beta', 'epsilon'].join('\n'))
+
+    const locate = (await citationService.createLocator(file))!
+    expect(locate('alpha beta gamma')).toMatchObject({ startLine: 1, endLine: 2, sourcePath: file })
+    expect(locate('gamma delta')).toMatchObject({ startLine: 2, endLine: 2 })
+    // the repeated text is found after the previous chunk, not at the start of the file
+    expect(locate('alpha beta epsilon')).toMatchObject({ startLine: 3, endLine: 4 })
+    expect(locate('not in the file')).toBeUndefined()
+  })
+
+  it('should not locate chunks of files whose text is extracted', async () => {
+    expect(await citationService.createLocator(path.join(dir, 'slides.pptx'))).toBeUndefined()
+  })
+  it('should only open sources of resolved citations', async () => {
+    const { shell } = await import('electron')
+    const file = path.join(dir, 'cited.md')
+    fs.writeFileSync(file, 'cited text')
+
+    const chunk = { pageContent: 'cited text', metadata: { id: 'cited-chunk', source: file } } as any
+    const citation = await citationService.resolveCitation({} as any, chunk)
+    await citationService.openCitation({} as any, citation)
+    expect(shell.openPath).toHaveBeenCalledWith(file)
+
+    vi.mocked(shell.openPath).mockClear()
+    await citationService.openCitation({} as any, { ...citation, source: path.join(dir, 'other.md') })
+    await citationService.openCitation({} as any, { ...citation, chunkId: 'unknown-chunk' })
+    expect(shell.openPath).not.toHaveBeenCalled()
+  })
+})
@@ -0,0 +1,59 @@
+import fs from 'node:fs'
+
+import { getDataPath } from '@main/utils'
+import { afterAll, describe, expect, it, vi } from 'vitest'
+
+import { conversationArchive } from '../ConversationArchive'
+
+vi.mock('@main/utils', async () => {
+  const fs = await import('node:fs')
+  const os = await import('node:os')
+  const path = await import('node:path')
+  const dataPath = fs.mkdtempSync(path.join(os.tmpdir(), 'archive-'))
+  return { getDataPath: () => dataPath }
+})
+
+vi.mock('electron-log', () => ({
+  default: { info: vi.fn(), warn: vi.fn() }
+}))
+
+function conversation(id: string, name: string) {
+  const date = '2025-03-01T00:00:00.000Z'
+  return {
+    topic: { id, assistantId: 'assistant', name, createdAt: date, updatedAt: date },
+    messages: [],
+    blocks: [],
+    archivedAt: Date.now()
+  }
+}
+
+describe('ConversationArchive', () => {
+  afterAll(() => {
+    fs.rmSync(getDataPath(), { recursive: true, force: true })
+  })
+
+  it('should cancel queued operations and drop the writes of the stuck one on reset', async () => {
+    const writeFile = fs.promises.writeFile
+    let release: () => void = () => {}
+    const spy = vi.spyOn(fs.promises, 'writeFile').mockImplementationOnce(async (...args) => {
+      await new Promise<void>((resolve) => (release = resolve))
+      return writeFile(...args)
+    })
+
+    const stuck = conversationArchive.archive(conversation('stuck', 'Stuck'))
+    const queued = conversationArchive.list()
+    await vi.waitFor(() => expect(spy).toHaveBeenCalled())
+
+    conversationArchive.reset()
+    await expect(queued).rejects.toMatchObject({ code: 'cancelled' })
+
+    await conversationArchive.archive(conversation('fresh', 'Fresh'))
+    release()
+    await expect(stuck).rejects.toMatchObject({ code: 'cancelled' })
+
+    const index = await conversationArchive.list()
+    expect(index.map((entry) => entry.topicId)).toEqual(['fresh'])
+    expect((await conversationArchive.get('fresh')).topic.name).toBe('Fresh')
+    spy.mockRestore()
+  })
+})
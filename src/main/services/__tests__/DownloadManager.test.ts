@@ -0,0 +1,95 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { afterAll, afterEach, describe, expect, it, vi } from 'vitest'
+
+import { downloadManager } from '../DownloadManager'
+
+vi.mock('@main/utils', async () => {
+  const fs = await import('node:fs')
+  const os = await import('node:os')
+  const path = await import('node:path')
+  const dataPath = fs.mkdtempSync(path.join(os.tmpdir(), 'downloads-'))
+  return { getDataPath: () => dataPath }
+})
+
+vi.mock('electron', () => ({
+  BrowserWindow: { getAllWindows: () => [] }
+}))
+
+vi.mock('electron-log', () => ({
+  default: { info: vi.fn(), warn: vi.fn(), error: vi.fn() }
+}))
+
+vi.mock('../ConfigManager', () => ({
+  configManager: { getDownloadDirectory: vi.fn() }
+}))
+
+vi.mock('../WorkerPool', () => ({
+  workerPool: { run: vi.fn() }
+}))
+
+// serves `content` with range support, the body of a range request stays open after `stall` bytes when given
+function serve(content: Buffer, stall?: number) {
+  return vi.fn(async (_: string, init: RequestInit = {}) => {
+    if (init.method === 'HEAD') {
+      return new Response(null, { headers: { 'content-length': String(content.length), 'accept-ranges': 'bytes' } })
+    }
+    const [start, end] = (init.headers as Record<string, string>).Range.slice(6).split('-').map(Number)
+    const body = new ReadableStream({
+      start(controller) {
+        const chunk = content.subarray(start, end + 1)
+        if (stall === undefined) {
+          controller.enqueue(chunk)
+          controller.close()
+          return
+        }
+        controller.enqueue(chunk.subarray(0, stall))
+        init.signal?.addEventListener('abort', () => controller.error(init.signal?.reason))
+      }
+    })
+    return new Response(body, { status: 206 })
+  })
+}
+
+describe('DownloadManager', () => {
+  const partFiles = () => fs.readdirSync(getDataPath()).filter((name) => name.includes('.part'))
+
+  afterEach(() => {
+    vi.unstubAllGlobals()
+  })
+
+  afterAll(() => {
+    fs.rmSync(getDataPath(), { recursive: true, force: true })
+  })
+
+  it('should split a file smaller than the segment count into one byte segments', async () => {
+    vi.stubGlobal('fetch', serve(Buffer.from('abc')))
+    const dest = path.join(getDataPath(), 'small.txt')
+
+    const task = downloadManager.startDownload('https://example.com/small.txt', dest, { segments: 4 })
+    await expect(downloadManager.waitFor(task.id)).resolves.toBe(dest)
+
+    expect(task.segments.map(({ start, end }) => [start, end])).toEqual([
+      [0, 0],
+      [1, 1],
+      [2, 2]
+    ])
+    expect(fs.readFileSync(dest, 'utf8')).toBe('abc')
+    expect(partFiles()).toEqual([])
+  })
+
+  it('should close the part files before removing them on cancel', async () => {
+    vi.stubGlobal('fetch', serve(Buffer.from('0123456789'), 2))
+    const dest = path.join(getDataPath(), 'stalled.txt')
+
+    const task = downloadManager.startDownload('https://example.com/stalled.txt', dest, { segments: 2 })
+    await vi.waitFor(() => expect(task.receivedBytes).toBe(4))
+
+    await downloadManager.cancel(task.id)
+    expect(task.status).toBe('cancelled')
+    expect(partFiles()).toEqual([])
+    expect(fs.existsSync(dest)).toBe(false)
+  })
+})
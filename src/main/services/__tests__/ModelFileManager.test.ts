@@ -0,0 +1,68 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { afterAll, beforeEach, describe, expect, it, vi } from 'vitest'
+
+import { downloadManager } from '../DownloadManager'
+import { modelFileManager } from '../ModelFileManager'
+
+vi.mock('@main/utils', async () => {
+  const fs = await import('node:fs')
+  const os = await import('node:os')
+  const path = await import('node:path')
+  const dataPath = fs.mkdtempSync(path.join(os.tmpdir(), 'model-files-'))
+  return { getDataPath: () => dataPath }
+})
+
+vi.mock('../DownloadManager', () => ({
+  downloadManager: {
+    startDownload: vi.fn(() => ({ id: 'task' })),
+    // downloads never finish in these tests
+    waitFor: vi.fn(() => new Promise(() => {})),
+    cancel: vi.fn(),
+    hashFile: vi.fn()
+  }
+}))
+
+vi.mock('electron-log', () => ({
+  default: { warn: vi.fn(), error: vi.fn() }
+}))
+
+describe('ModelFileManager', () => {
+  const modelsDir = () => path.join(getDataPath(), 'Models')
+
+  beforeEach(() => {
+    vi.mocked(downloadManager.startDownload).mockClear()
+  })
+
+  afterAll(() => {
+    fs.rmSync(getDataPath(), { recursive: true, force: true })
+  })
+
+  it('should keep downloads with a traversal url inside the models folder', async () => {
+    const model = await modelFileManager.downloadModel('https://example.com/models/%2F..%2F..%2Fevil.gguf')
+    expect(model.name).toBe('evil.gguf')
+    expect(model.path).toBe(path.join(modelsDir(), 'evil.gguf'))
+    expect(downloadManager.startDownload).toHaveBeenCalledWith(
+      'https://example.com/models/%2F..%2F..%2Fevil.gguf',
+      path.join(modelsDir(), 'evil.gguf'),
+      expect.anything()
+    )
+  })
+
+  it('should keep only the last segment of a given file name', async () => {
+    const model = await modelFileManager.downloadModel('https://example.com/model', '../../x.gguf')
+    expect(model.path).toBe(path.join(modelsDir(), 'x.gguf'))
+  })
+
+  it('should reject names without a file', async () => {
+    await expect(modelFileManager.downloadModel('https://example.com/a.gguf', '..')).rejects.toMatchObject({
+      code: 'invalid_argument'
+    })
+    await expect(modelFileManager.downloadModel('https://example.com/%2F..')).rejects.toMatchObject({
+      code: 'invalid_argument'
+    })
+    expect(downloadManager.startDownload).not.toHaveBeenCalled()
+  })
+})
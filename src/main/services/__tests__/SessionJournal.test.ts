@@ -0,0 +1,53 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { getDataPath } from '@main/utils'
+import { afterAll, describe, expect, it, vi } from 'vitest'
+
+import { sessionJournal } from '../SessionJournal'
+
+vi.mock('@main/utils', async () => {
+  const fs = await import('node:fs')
+  const os = await import('node:os')
+  const path = await import('node:path')
+  const dataPath = fs.mkdtempSync(path.join(os.tmpdir(), 'session-journal-'))
+  return { getDataPath: () => dataPath }
+})
+
+vi.mock('electron-log', () => ({
+  default: { warn: vi.fn(), error: vi.fn() }
+}))
+
+describe('SessionJournal', () => {
+  const state = { drafts: { inputbar: 'unsent' }, route: '/translate', minapps: ['openai'] }
+
+  afterAll(() => {
+    fs.rmSync(getDataPath(), { recursive: true, force: true })
+  })
+
+  it('should not recover anything after a clean exit', async () => {
+    sessionJournal.init()
+    await sessionJournal.saveDraftState(state)
+    sessionJournal.close()
+
+    sessionJournal.init()
+    expect(sessionJournal.getRecoveredSession()).toBeNull()
+  })
+
+  it('should recover the journal after an unclean exit until it is discarded', async () => {
+    await sessionJournal.saveDraftState(state)
+
+    // the app was killed, the running marker is still there
+    sessionJournal.init()
+    expect(sessionJournal.getRecoveredSession()).toMatchObject(state)
+    expect(fs.existsSync(path.join(getDataPath(), 'Session', 'journal.json'))).toBe(false)
+
+    sessionJournal.discardRecoveredSession()
+    expect(sessionJournal.getRecoveredSession()).toBeNull()
+  })
+
+  it('should reject an oversized state', () => {
+    const drafts = { inputbar: 'x'.repeat(2 * 1024 * 1024) }
+    expect(() => sessionJournal.saveDraftState({ drafts, minapps: [] })).toThrow('too large')
+  })
+})
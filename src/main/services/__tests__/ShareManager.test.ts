@@ -0,0 +1,52 @@
+import fs from 'node:fs'
+import os from 'node:os'
+import path from 'node:path'
+
+import { afterAll, describe, expect, it, vi } from 'vitest'
+
+import { SHARE_ARG, shareManager } from '../ShareManager'
+
+vi.mock('electron', () => ({
+  app: { isPackaged: false }
+}))
+
+vi.mock('electron-log', () => ({
+  default: { info: vi.fn(), warn: vi.fn() }
+}))
+
+vi.mock('../PushChannel', () => ({
+  pushChannel: { publish: vi.fn() }
+}))
+
+vi.mock('../WindowService', () => ({
+  windowService: { getMainWindow: vi.fn(), showMainWindow: vi.fn() }
+}))
+
+describe('ShareManager', () => {
+  const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'share-'))
+  const file = path.join(dir, 'notes.md')
+  fs.writeFileSync(file, '# notes')
+
+  afterAll(() => {
+    fs.rmSync(dir, { recursive: true, force: true })
+  })
+
+  it('should take files from the command line', () => {
+    expect(shareManager.parseArgv(['app', SHARE_ARG, file])).toEqual({ files: [file] })
+  })
+
+  it('should ignore files of share urls', () => {
+    const url = new URL(`cherrystudio://share?file=${encodeURIComponent(file)}`)
+    expect(shareManager.parseUrl(url)).toBeUndefined()
+
+    const withText = new URL(`cherrystudio://share?text=hello&file=${encodeURIComponent(file)}`)
+    expect(shareManager.parseUrl(withText)).toEqual({ text: 'hello', url: undefined, files: [] })
+  })
+
+  it('should only take web links from share urls', () => {
+    expect(shareManager.parseUrl(new URL('cherrystudio://share?url=https%3A%2F%2Fexample.com'))?.url).toBe(
+      'https://example.com'
+    )
+    expect(shareManager.parseUrl(new URL('cherrystudio://share?url=file%3A%2F%2F%2Fetc%2Fpasswd'))).toBeUndefined()
+  })
+})
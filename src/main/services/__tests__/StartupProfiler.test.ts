@@ -0,0 +1,57 @@
+import type { BrowserWindow } from 'electron'
+import { describe, expect, it, vi } from 'vitest'
+
+import { startupProfiler } from '../StartupProfiler'
+
+vi.mock('electron', () => ({}))
+
+vi.mock('electron-log', () => ({
+  default: { info: vi.fn(), error: vi.fn() }
+}))
+
+describe('StartupProfiler', () => {
+  it('should time sync and async steps and keep their results', async () => {
+    expect(startupProfiler.measure('sync-step', () => 42)).toBe(42)
+    await expect(startupProfiler.measure('async-step', async () => 'done')).resolves.toBe('done')
+    expect(() =>
+      startupProfiler.measure('failing-step', () => {
+        throw new Error('boom')
+      })
+    ).toThrow('boom')
+
+    const spans = startupProfiler.getReport().spans
+    expect(spans.map((span) => [span.name, span.stage, span.error])).toEqual([
+      ['sync-step', 'critical', undefined],
+      ['async-step', 'critical', undefined],
+      ['failing-step', 'critical', 'boom']
+    ])
+    expect(spans.every((span) => span.start >= 0 && span.duration >= 0)).toBe(true)
+  })
+
+  it('should run deferred work in order after the first paint', async () => {
+    let paint: () => void = () => {}
+    const window = { once: (_: string, listener: () => void) => (paint = listener) } as unknown as BrowserWindow
+    const order: string[] = []
+
+    startupProfiler.waitForFirstPaint(window)
+    startupProfiler.defer('first', () => order.push('first'))
+    startupProfiler.defer('broken', () => {
+      throw new Error('failed')
+    })
+    startupProfiler.defer('second', async () => order.push('second'))
+    expect(startupProfiler.getReport().pending).toEqual(['first', 'broken', 'second'])
+
+    paint()
+    await vi.waitFor(() => expect(startupProfiler.getReport().pending).toEqual([]))
+
+    const report = startupProfiler.getReport()
+    expect(order).toEqual(['first', 'second'])
+    expect(report.milestones['first-paint']).toBeGreaterThanOrEqual(0)
+    expect(report.milestones['deferred-done']).toBeGreaterThanOrEqual(report.milestones['first-paint'])
+    expect(report.spans.filter((span) => span.stage === 'deferred').map((span) => span.name)).toEqual([
+      'first',
+      'broken',
+      'second'
+    ])
+  })
+})
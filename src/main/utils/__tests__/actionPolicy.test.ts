@@ -0,0 +1,77 @@
+import path from 'node:path'
+
+import { ConfirmationRule } from '@types'
+import { describe, expect, it } from 'vitest'
+
+import { describeToolCall, findRule, matchesRule } from '../actionPolicy'
+
+const workspace = path.resolve('/work/project')
+const context = { workspaces: [workspace], knownDomains: ['example.com'] }
+
+const outsideWorkspace: ConfirmationRule = {
+  id: 'r1',
+  name: 'Writes outside the workspace',
+  enabled: true,
+  kind: 'file_write',
+  condition: 'outside_workspace'
+}
+
+const newDomains: ConfirmationRule = {
+  id: 'r2',
+  name: 'New domains',
+  enabled: true,
+  kind: 'network',
+  condition: 'new_domain'
+}
+
+describe('actionPolicy', () => {
+  describe('describeToolCall', () => {
+    it('should collect the paths of writing tools', () => {
+      const action = describeToolCall('fs', 'move_file', { source: '/work/project/a.txt', destination: '/tmp/a.txt' })
+      expect(action.kinds).toEqual(['file_write'])
+      expect(action.paths).toEqual([path.resolve('/work/project/a.txt'), path.resolve('/tmp/a.txt')])
+    })
+
+    it('should not treat reads and dry runs as writes', () => {
+      expect(describeToolCall('fs', 'read_file', { path: '/etc/hosts' }).kinds).toEqual([])
+      expect(describeToolCall('fs', 'edit_file', { path: '/tmp/a.txt', dryRun: true }).kinds).toEqual([])
+    })
+
+    it('should collect urls from nested arguments', () => {
+      const action = describeToolCall('fetch', 'fetch_html', { url: 'https://a.com/x', headers: { referer: 'nope' } })
+      expect(action.kinds).toEqual(['network'])
+      expect(action.urls).toEqual(['https://a.com/x'])
+    })
+  })
+
+  describe('matchesRule', () => {
+    it('should only confirm writes outside the mounted folders', () => {
+      const inside = describeToolCall('fs', 'write_file', { path: '/work/project/src/a.ts', content: '' })
+      const outside = describeToolCall('fs', 'write_file', { path: '/work/other/a.ts', content: '' })
+      expect(matchesRule(outsideWorkspace, inside, context)).toBe(false)
+      expect(matchesRule(outsideWorkspace, outside, context)).toBe(true)
+    })
+
+    it('should only confirm calls to unknown domains', () => {
+      const known = describeToolCall('fetch', 'fetch_html', { url: 'https://example.com/page' })
+      const unknown = describeToolCall('fetch', 'fetch_html', { url: 'https://unknown.org/page' })
+      expect(matchesRule(newDomains, known, context)).toBe(false)
+      expect(matchesRule(newDomains, unknown, context)).toBe(true)
+    })
+
+    it('should skip disabled rules and other servers', () => {
+      const action = describeToolCall('fetch', 'fetch_html', { url: 'https://unknown.org' })
+      expect(matchesRule({ ...newDomains, enabled: false }, action, context)).toBe(false)
+      expect(matchesRule({ ...newDomains, servers: ['search'] }, action, context)).toBe(false)
+    })
+  })
+
+  describe('findRule', () => {
+    it('should return the first matching rule', () => {
+      const always: ConfirmationRule = { ...newDomains, id: 'r3', condition: 'always' }
+      const action = describeToolCall('fetch', 'fetch_html', { url: 'https://example.com' })
+      expect(findRule([newDomains, always], action, context)?.id).toBe('r3')
+      expect(findRule([outsideWorkspace], action, context)).toBeUndefined()
+    })
+  })
+})
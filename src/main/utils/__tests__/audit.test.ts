@@ -0,0 +1,46 @@
+import { describe, expect, it } from 'vitest'
+
+import { isInsideDirectory, summarizeArg, summarizeArgs } from '../audit'
+
+describe('audit', () => {
+  describe('summarizeArg', () => {
+    it('should describe objects, arrays and binary data without their content', () => {
+      expect(summarizeArg({ url: 'x', token: 'secret' })).toBe('{url, token}')
+      expect(summarizeArg([1, 2, 3])).toBe('[3 items]')
+      expect(summarizeArg(new Uint8Array(16))).toBe('<16 bytes>')
+    })
+
+    it('should truncate long strings', () => {
+      const summary = summarizeArg('a'.repeat(200))
+      expect(summary.length).toBeLessThanOrEqual(81)
+      expect(summary.endsWith('…')).toBe(true)
+    })
+
+    it('should keep primitives', () => {
+      expect(summarizeArg('/tmp/a.txt')).toBe('"/tmp/a.txt"')
+      expect(summarizeArg(42)).toBe('42')
+      expect(summarizeArg(null)).toBe('null')
+      expect(summarizeArg(undefined)).toBe('undefined')
+    })
+  })
+
+  describe('summarizeArgs', () => {
+    it('should only record argument types when redacted', () => {
+      expect(summarizeArgs(['token', 1, null], true)).toBe('<string>, <number>, <null>')
+      expect(summarizeArgs(['a', true])).toBe('"a", true')
+    })
+  })
+
+  describe('isInsideDirectory', () => {
+    it('should detect paths inside the directory', () => {
+      expect(isInsideDirectory('/data/app/Files/a.txt', '/data/app')).toBe(true)
+      expect(isInsideDirectory('/data/app', '/data/app')).toBe(true)
+      expect(isInsideDirectory('/data/app/../other/a.txt', '/data/app')).toBe(false)
+      expect(isInsideDirectory('/home/user/a.txt', '/data/app')).toBe(false)
+    })
+
+    it('should not match sibling directories sharing a prefix', () => {
+      expect(isInsideDirectory('/data/app-backup/a.txt', '/data/app')).toBe(false)
+    })
+  })
+})
@@ -0,0 +1,50 @@
+import crypto from 'node:crypto'
+
+import { describe, expect, it } from 'vitest'
+
+import { getKeyFingerprint, openBundle, sealBundle } from '../bundle'
+
+const { privateKey, publicKey } = crypto.generateKeyPairSync('ed25519')
+const payload = Buffer.from(JSON.stringify({ assistants: [{ id: 'a', name: 'Writer' }] }))
+
+describe('bundle', () => {
+  it('should round trip the payload with the signer fingerprint', () => {
+    const bundle = sealBundle(payload, 'secret', privateKey)
+    const opened = openBundle(JSON.parse(JSON.stringify(bundle)), 'secret')
+    expect(opened.payload.equals(payload)).toBe(true)
+    expect(opened.fingerprint).toBe(getKeyFingerprint(publicKey))
+    expect(opened.createdAt).toBe(bundle.createdAt)
+  })
+
+  it('should only trust pinned signers', () => {
+    const bundle = sealBundle(payload, 'secret', privateKey)
+    expect(openBundle(bundle, 'secret').trusted).toBe(false)
+    expect(openBundle(bundle, 'secret', [getKeyFingerprint(publicKey)]).trusted).toBe(true)
+  })
+
+  it('should not contain the payload in clear text', () => {
+    const bundle = sealBundle(payload, 'secret', privateKey)
+    expect(JSON.stringify(bundle)).not.toContain('Writer')
+  })
+
+  it('should reject a wrong passphrase', () => {
+    const bundle = sealBundle(payload, 'secret', privateKey)
+    expect(() => openBundle(bundle, 'other')).toThrow(expect.objectContaining({ code: 'permission_denied' }))
+  })
+
+  it('should reject tampered bundles', () => {
+    const bundle = sealBundle(payload, 'secret', privateKey)
+    const other = sealBundle(payload, 'secret', crypto.generateKeyPairSync('ed25519').privateKey)
+    const tampered = { ...bundle, data: other.data, salt: other.salt, iv: other.iv, tag: other.tag }
+    expect(() => openBundle(tampered, 'secret')).toThrow(expect.objectContaining({ code: 'invalid_argument' }))
+  })
+
+  it('should reject files that are not bundles', () => {
+    expect(() => openBundle({ foo: 1 }, 'secret')).toThrow(expect.objectContaining({ code: 'invalid_argument' }))
+    expect(() => openBundle(null, 'secret')).toThrow(expect.objectContaining({ code: 'invalid_argument' }))
+  })
+
+  it('should format fingerprints in groups', () => {
+    expect(getKeyFingerprint(publicKey)).toMatch(/^([0-9a-f]{4}:){7}[0-9a-f]{4}$/)
+  })
+})
@@ -0,0 +1,66 @@
+import { describe, expect, it } from 'vitest'
+
+import { createChunkLocator, createPositionIndex, getByteRange, getLineRange, locateChunk } from '../citation'
+
+const source = ['# Title', '', 'First paragraph   with extra', 'spaces and a line break.', '', '最后一段'].join('\n')
+
+describe('citation', () => {
+  describe('locateChunk', () => {
+    it('should locate a chunk ignoring whitespace differences', () => {
+      const range = locateChunk(source, 'First paragraph with extra spaces')
+      expect(range).not.toBeNull()
+      expect(source.slice(range!.start, range!.end)).toBe('First paragraph   with extra\nspaces')
+    })
+
+    it('should fall back to the chunk prefix when the rest does not match', () => {
+      const range = locateChunk(source, 'First paragraph with extra spaces and a different ending')
+      expect(range).not.toBeNull()
+      expect(range!.start).toBe(source.indexOf('First'))
+    })
+
+    it('should return null when the chunk is not in the source', () => {
+      expect(locateChunk(source, 'not in the document')).toBeNull()
+      expect(locateChunk(source, '   ')).toBeNull()
+    })
+  })
+
+  describe('getLineRange', () => {
+    it('should return 1-based inclusive line numbers', () => {
+      const range = locateChunk(source, 'First paragraph with extra spaces and a line break.')!
+      expect(getLineRange(source, range)).toEqual({ startLine: 3, endLine: 4 })
+    })
+  })
+
+  describe('getByteRange', () => {
+    it('should count multi-byte characters', () => {
+      const range = locateChunk(source, '最后一段')!
+      const bytes = getByteRange(source, range)
+      expect(bytes.end - bytes.start).toBe(12)
+    })
+  })
+  describe('createChunkLocator', () => {
+    it('should search overlapping chunks forward from the previous match', () => {
+      const text = ['alpha beta', 'gamma', 'alpha beta', 'delta'].join('\n')
+      const locate = createChunkLocator(text)
+      expect(locate('alpha beta gamma')!.start).toBe(0)
+      expect(locate('alpha beta delta')!.start).toBe(text.lastIndexOf('alpha'))
+      // out of order chunks are still found
+      expect(locate('gamma')!.start).toBe(text.indexOf('gamma'))
+      expect(locate('not in the document')).toBeNull()
+    })
+  })
+
+  describe('createPositionIndex', () => {
+    it('should match the line and byte ranges computed from the source', () => {
+      const text = `${'文字 emoji 😀 '.repeat(300)}\n${source}\n${'x'.repeat(3000)}`
+      const index = createPositionIndex(text)
+      for (const chunk of ['First paragraph with extra spaces', '最后一段', 'emoji 😀', 'xxx']) {
+        const range = locateChunk(text, chunk)!
+        expect(index.getLineRange(range)).toEqual(getLineRange(text, range))
+        expect(index.getByteRange(range)).toEqual(getByteRange(text, range))
+      }
+      const end = { start: text.length - 10, end: text.length }
+      expect(index.getByteRange(end)).toEqual(getByteRange(text, end))
+    })
+  })
+})
@@ -0,0 +1,53 @@
+import { describe, expect, it } from 'vitest'
+
+import { classifyClipboard, guessLanguage, parseTable } from '../clipboard'
+
+describe('clipboard', () => {
+  it('should tell prose from code', () => {
+    expect(classifyClipboard({ text: 'Hello, could we move the meeting to Friday (after lunch)?' }).kind).toBe('text')
+    expect(classifyClipboard({ text: 'This is prose.\nWith two lines; and a semicolon.\nAnd more.' }).kind).toBe('text')
+    expect(classifyClipboard({ text: 'def add(x):\r\n    return x + 1\r\n' })).toEqual({
+      kind: 'code',
+      text: 'def add(x):\n    return x + 1\n',
+      language: 'python'
+    })
+    expect(classifyClipboard({ text: 'npm install react' })).toMatchObject({ kind: 'code', language: 'shell' })
+  })
+
+  it('should recognize urls', () => {
+    expect(classifyClipboard({ text: '  https://example.com/a?b=1\n' })).toEqual({
+      kind: 'url',
+      text: 'https://example.com/a?b=1'
+    })
+    expect(classifyClipboard({ text: 'see https://example.com' }).kind).toBe('text')
+  })
+
+  it('should parse tables copied from spreadsheets', () => {
+    expect(parseTable('name\tage\nBob\t3\nAl\t4\n')).toEqual([
+      ['name', 'age'],
+      ['Bob', '3'],
+      ['Al', '4']
+    ])
+    expect(parseTable('a\tb\nc\n')).toBeUndefined()
+    expect(parseTable('a\tb')).toBeUndefined()
+    expect(classifyClipboard({ text: 'x\ty\n1\t2' }).kind).toBe('table')
+  })
+
+  it('should only report images without text', () => {
+    expect(classifyClipboard({ text: '', image: { width: 20, height: 10 } })).toEqual({
+      kind: 'image',
+      width: 20,
+      height: 10
+    })
+    expect(classifyClipboard({ text: 'caption', image: { width: 20, height: 10 } }).kind).toBe('text')
+    expect(classifyClipboard({ text: ' \n' })).toEqual({ kind: 'empty' })
+  })
+
+  it('should guess languages', () => {
+    expect(guessLanguage('{"a": [1, 2]}')).toBe('json')
+    expect(guessLanguage('fn main() {\n    let mut x = 5;\n}')).toBe('rust')
+    expect(guessLanguage('SELECT id FROM users')).toBe('sql')
+    expect(guessLanguage('const a = 1')).toBe('javascript')
+    expect(guessLanguage('just words')).toBeUndefined()
+  })
+})
@@ -0,0 +1,72 @@
+import { CommandInfo } from '@types'
+import { describe, expect, it } from 'vitest'
+
+import { COMMAND_ID_REGEX, filterCommands, validateCommandArgs } from '../commands'
+
+const command = (id: string, title: string, category = 'App', description?: string): CommandInfo => ({
+  id,
+  title,
+  category,
+  description,
+  args: [],
+  source: 'main'
+})
+
+describe('commands', () => {
+  it('should accept dotted lowercase ids', () => {
+    expect(COMMAND_ID_REGEX.test('miniapp.install-from-url')).toBe(true)
+    expect(COMMAND_ID_REGEX.test('reload')).toBe(false)
+    expect(COMMAND_ID_REGEX.test('Topic.New')).toBe(false)
+  })
+
+  it('should coerce string values from the command line', () => {
+    const schema = [
+      { name: 'url', type: 'string' as const, required: true },
+      { name: 'limit', type: 'number' as const },
+      { name: 'force', type: 'boolean' as const },
+      { name: 'format', type: 'string' as const, enum: ['markdown', 'json'] }
+    ]
+    expect(validateCommandArgs(schema, { url: 'https://x.com', limit: '5', force: 'true', format: 'json' })).toEqual({
+      values: { url: 'https://x.com', limit: 5, force: true, format: 'json' },
+      errors: []
+    })
+    expect(validateCommandArgs(schema, { url: 'https://x.com', limit: 5, force: false })).toEqual({
+      values: { url: 'https://x.com', limit: 5, force: false },
+      errors: []
+    })
+  })
+
+  it('should report missing, invalid and unknown arguments', () => {
+    const schema = [
+      { name: 'url', type: 'string' as const, required: true },
+      { name: 'limit', type: 'number' as const },
+      { name: 'format', type: 'string' as const, enum: ['markdown', 'json'] }
+    ]
+    expect(validateCommandArgs(schema, { limit: 'lots', format: 'pdf', urll: 'x' }).errors).toEqual([
+      'Unknown argument `urll`',
+      '`url` is required',
+      '`limit` must be a number',
+      '`format` must be one of markdown, json'
+    ])
+    expect(validateCommandArgs(schema, ['x']).errors).toEqual(['Arguments must be an object'])
+    expect(validateCommandArgs([], undefined)).toEqual({ values: {}, errors: [] })
+  })
+
+  it('should rank exact ids and title prefixes first', () => {
+    const commands = [
+      command('logs.export-support-bundle', 'Export support bundle', 'Logs', 'Zip the redacted logs'),
+      command('topic.new', 'New topic', 'Chat'),
+      command('topic.clear', 'Clear messages', 'Chat', 'Remove the messages of the new topic'),
+      command('settings.open', 'Open settings', 'App')
+    ]
+    expect(filterCommands(commands, 'topic.new').map((item) => item.id)).toEqual(['topic.new'])
+    expect(filterCommands(commands, 'new').map((item) => item.id)).toEqual(['topic.new', 'topic.clear'])
+    expect(filterCommands(commands, 'EXPORT logs').map((item) => item.id)).toEqual(['logs.export-support-bundle'])
+    expect(filterCommands(commands, 'missing')).toEqual([])
+  })
+
+  it('should list all commands by category and title without a filter', () => {
+    const commands = [command('b.b', 'B', 'Chat'), command('a.a', 'A', 'App'), command('a.b', 'A', 'Chat')]
+    expect(filterCommands(commands).map((item) => item.id)).toEqual(['a.a', 'a.b', 'b.b'])
+  })
+})
@@ -0,0 +1,30 @@
+import { describe, expect, it } from 'vitest'
+
+import { toLogicalLength, toLogicalRect } from '../display'
+
+describe('display', () => {
+  it('should keep logical rects', () => {
+    const rect = { x: 10, y: 20, width: 300, height: 200 }
+    expect(toLogicalRect(rect, 'logical', 2)).toBe(rect)
+  })
+
+  it('should cover the whole physical rect in logical pixels', () => {
+    expect(toLogicalRect({ x: 20, y: 40, width: 600, height: 400 }, 'physical', 2)).toEqual({
+      x: 10,
+      y: 20,
+      width: 300,
+      height: 200
+    })
+    expect(toLogicalRect({ x: 3, y: 3, width: 10, height: 10 }, 'physical', 1.5)).toEqual({
+      x: 2,
+      y: 2,
+      width: 7,
+      height: 7
+    })
+  })
+
+  it('should convert lengths', () => {
+    expect(toLogicalLength(1200, 'physical', 1.5)).toBe(800)
+    expect(toLogicalLength(1200, 'logical', 1.5)).toBe(1200)
+  })
+})
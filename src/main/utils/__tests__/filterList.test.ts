@@ -0,0 +1,62 @@
+import { describe, expect, it } from 'vitest'
+
+import { FilterEngine, getBaseDomain, parseFilter, parseFilterList } from '../filterList'
+
+const LIST = `[Adblock Plus 2.0]
+! Title: test list
+||ads.example.net^
+/banner/*/ad_
+||tracker.org^$third-party
+||cdn.site.com/widget.js$script,domain=news.com|~sport.news.com
+@@||ads.example.net/allowed/
+example.com##.sidebar-ad
+||popup.net^$popup
+/\\/pixel\\d+\\.gif/$image`
+
+const engine = new FilterEngine(parseFilterList(LIST))
+
+const blocked = (url: string, pageUrl = 'https://news.com/', resourceType = 'script') =>
+  !!engine.match({ url, pageUrl, resourceType })
+
+describe('filterList', () => {
+  it('should skip comments, element hiding and unsupported options', () => {
+    expect(engine.size).toBe(6)
+    expect(parseFilter('! comment')).toBeUndefined()
+    expect(parseFilter('example.com##.ad')).toBeUndefined()
+    expect(parseFilter('||a.com^$redirect=noopjs')).toBeUndefined()
+  })
+
+  it('should anchor domain rules to the host and its subdomains', () => {
+    expect(blocked('https://ads.example.net/x.js')).toBe(true)
+    expect(blocked('https://cdn.ads.example.net/x.js')).toBe(true)
+    expect(blocked('https://notads.example.net/x.js')).toBe(false)
+    expect(blocked('https://example.net/ads.example.net')).toBe(false)
+  })
+
+  it('should match wildcards and regular expressions', () => {
+    expect(blocked('https://a.com/banner/top/ad_1.png')).toBe(true)
+    expect(blocked('https://a.com/banner/ad.png')).toBe(false)
+    expect(blocked('https://a.com/pixel42.gif', undefined, 'image')).toBe(true)
+    expect(blocked('https://a.com/pixel42.gif', undefined, 'script')).toBe(false)
+  })
+
+  it('should apply party, type and domain options', () => {
+    expect(blocked('https://tracker.org/t.js', 'https://news.com/')).toBe(true)
+    expect(blocked('https://tracker.org/t.js', 'https://www.tracker.org/')).toBe(false)
+    expect(blocked('https://cdn.site.com/widget.js', 'https://news.com/')).toBe(true)
+    expect(blocked('https://cdn.site.com/widget.js', 'https://sport.news.com/')).toBe(false)
+    expect(blocked('https://cdn.site.com/widget.js', 'https://other.com/')).toBe(false)
+    expect(blocked('https://cdn.site.com/widget.js', 'https://news.com/', 'image')).toBe(false)
+  })
+
+  it('should let exceptions win and never block documents', () => {
+    expect(blocked('https://ads.example.net/allowed/x.js')).toBe(false)
+    expect(blocked('https://ads.example.net/', undefined, 'mainFrame')).toBe(false)
+  })
+
+  it('should approximate registrable domains', () => {
+    expect(getBaseDomain('a.b.example.com')).toBe('example.com')
+    expect(getBaseDomain('news.bbc.co.uk')).toBe('bbc.co.uk')
+    expect(getBaseDomain('127.0.0.1')).toBe('127.0.0.1')
+  })
+})
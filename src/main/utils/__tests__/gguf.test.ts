@@ -0,0 +1,26 @@
+import { describe, expect, it } from 'vitest'
+
+import { getGgufModelInfo } from '../gguf'
+
+describe('gguf', () => {
+  describe('getGgufModelInfo', () => {
+    it('should detect rerankers by their pooling type', () => {
+      const metadata = { general: { architecture: 'bert' }, bert: { pooling_type: 4, embedding_length: 1024 } }
+      expect(getGgufModelInfo(metadata)).toEqual({ kind: 'rerank' })
+    })
+
+    it('should detect embedding models by pooling type or architecture', () => {
+      const pooled = { general: { architecture: 'qwen3' }, qwen3: { pooling_type: 3, embedding_length: 1024 } }
+      expect(getGgufModelInfo(pooled)).toEqual({ kind: 'embedding', embeddingLength: 1024 })
+
+      const encoder = { general: { architecture: 'nomic-bert' }, 'nomic-bert': { embedding_length: 768 } }
+      expect(getGgufModelInfo(encoder)).toEqual({ kind: 'embedding', embeddingLength: 768 })
+    })
+
+    it('should treat other models as chat models', () => {
+      const metadata = { general: { architecture: 'llama' }, llama: { embedding_length: 4096 } }
+      expect(getGgufModelInfo(metadata)).toEqual({ kind: 'chat' })
+      expect(getGgufModelInfo({})).toEqual({ kind: 'chat' })
+    })
+  })
+})
@@ -0,0 +1,58 @@
+import fs from 'node:fs'
+import os from 'node:os'
+import path from 'node:path'
+
+import { afterAll, beforeAll, describe, expect, it } from 'vitest'
+
+import { indexLines, readLines } from '../lines'
+
+describe('lines', () => {
+  let dir: string
+  const write = (name: string, content: string) => {
+    const filePath = path.join(dir, name)
+    fs.writeFileSync(filePath, content)
+    return filePath
+  }
+
+  beforeAll(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'lines-'))
+  })
+
+  afterAll(() => {
+    fs.rmSync(dir, { recursive: true, force: true })
+  })
+
+  it('should count lines with and without a trailing newline', async () => {
+    expect((await indexLines(write('a.txt', 'a\nb\n'))).lineCount).toBe(2)
+    expect((await indexLines(write('b.txt', 'a\nb'))).lineCount).toBe(2)
+    expect((await indexLines(write('c.txt', ''))).lineCount).toBe(0)
+  })
+
+  it('should read a range across checkpoints', async () => {
+    const lines = Array.from({ length: 2500 }, (_, i) => `line ${i}`)
+    const filePath = write('log.txt', lines.join('\r\n') + '\r\n')
+    const index = await indexLines(filePath)
+    expect(index.lineCount).toBe(2500)
+    expect(index.checkpoints).toHaveLength(3)
+
+    expect((await readLines(filePath, index, 0, 3)).lines).toEqual(['line 0', 'line 1', 'line 2'])
+    expect((await readLines(filePath, index, 998, 4)).lines).toEqual(lines.slice(998, 1002))
+    expect((await readLines(filePath, index, 2498, 10)).lines).toEqual(['line 2498', 'line 2499'])
+    expect((await readLines(filePath, index, 2500, 10)).lines).toEqual([])
+  })
+
+  it('should read the last line without a trailing newline', async () => {
+    const filePath = write('d.txt', 'first\nsecond')
+    const index = await indexLines(filePath)
+    expect((await readLines(filePath, index, 1, 5)).lines).toEqual(['second'])
+  })
+
+  it('should cut very long lines', async () => {
+    const filePath = write('e.txt', 'x'.repeat(100 * 1024) + '\nnext\n')
+    const index = await indexLines(filePath)
+    const range = await readLines(filePath, index, 0, 2)
+    expect(range.truncated).toBe(true)
+    expect(range.lines[0]).toHaveLength(16 * 1024)
+    expect(range.lines[1]).toBe('next')
+  })
+})
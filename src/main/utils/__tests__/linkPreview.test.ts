@@ -0,0 +1,59 @@
+import { describe, expect, it } from 'vitest'
+
+import { decodeEntities, isPrivateAddress, parseLinkMetadata } from '../linkPreview'
+
+describe('linkPreview', () => {
+  it('should prefer OpenGraph tags and resolve urls', () => {
+    const html = `<html><head>
+      <title>Plain title</title>
+      <meta content="Open &amp; Graph" property="og:title">
+      <meta name="description" content="Plain description">
+      <meta property="og:image" content="/cover.png" />
+      <meta property="og:site_name" content='Example'>
+      <link rel="shortcut icon" href="/static/icon.svg">
+    </head><body><meta property="og:title" content="Body"></body></html>`
+
+    expect(parseLinkMetadata(html, 'https://example.com/posts/1')).toEqual({
+      title: 'Open & Graph',
+      description: 'Plain description',
+      siteName: 'Example',
+      image: 'https://example.com/cover.png',
+      favicon: 'https://example.com/static/icon.svg'
+    })
+  })
+
+  it('should fall back to the title and the default favicon', () => {
+    expect(parseLinkMetadata('<title>\n  A   page\n</title>', 'http://example.com/a')).toEqual({
+      title: 'A page',
+      description: undefined,
+      siteName: undefined,
+      image: undefined,
+      favicon: 'http://example.com/favicon.ico'
+    })
+  })
+
+  it('should drop images that are not http', () => {
+    const html = '<meta property="og:image" content="javascript:alert(1)">'
+    expect(parseLinkMetadata(html, 'https://example.com').image).toBeUndefined()
+  })
+
+  it('should decode entities', () => {
+    expect(decodeEntities('&lt;a&gt; &#39;b&#x27; &unknown;')).toBe("<a> 'b' &unknown;")
+  })
+
+  it('should keep entities past the last code point', () => {
+    expect(decodeEntities('&#x10FFFF;')).toBe('\u{10ffff}')
+    expect(decodeEntities('a &#x110000; &#99999999999; b')).toBe('a &#x110000; &#99999999999; b')
+  })
+
+  it('should tell private addresses from public ones', () => {
+    for (const address of ['127.0.0.1', '10.1.2.3', '172.20.0.1', '192.168.1.1', '169.254.169.254', '0.0.0.0']) {
+      expect(isPrivateAddress(address)).toBe(true)
+    }
+    for (const address of ['::1', '::', 'fe80::1', 'fd00::1', '::ffff:127.0.0.1', 'not an address']) {
+      expect(isPrivateAddress(address)).toBe(true)
+    }
+    expect(isPrivateAddress('93.184.216.34')).toBe(false)
+    expect(isPrivateAddress('2606:2800:220:1:248:1893:25c8:1946')).toBe(false)
+  })
+})
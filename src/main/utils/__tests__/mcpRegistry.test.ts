@@ -0,0 +1,92 @@
+import path from 'node:path'
+
+import { describe, expect, it } from 'vitest'
+
+import {
+  compareVersions,
+  diffPermissions,
+  getEntryBin,
+  isValidPackage,
+  isValidVersion,
+  parseRegistry,
+  resolveNodeBin
+} from '../mcpRegistry'
+
+describe('mcpRegistry', () => {
+  describe('parseRegistry', () => {
+    it('should skip malformed entries', () => {
+      const entries = parseRegistry({
+        servers: [
+          { id: 'fs', package: '@modelcontextprotocol/server-filesystem', version: '1.0.0', runtime: 'node' },
+          { id: 'no-version', package: 'a', runtime: 'node' },
+          { id: 'bad runtime', package: 'b', version: '1', runtime: 'ruby' },
+          { id: '../escape', package: 'c', version: '1', runtime: 'python' },
+          null
+        ]
+      })
+      expect(entries).toHaveLength(1)
+      expect(entries[0]).toMatchObject({ id: 'fs', name: 'fs', permissions: [] })
+    })
+
+    it('should skip entries whose version, package or bin could escape the install folder or the command line', () => {
+      const entries = parseRegistry([
+        { id: 'a', package: 'mcp-a', version: '../../1.0.0', runtime: 'node' },
+        { id: 'b', package: '--registry=http://evil', version: '1.0.0', runtime: 'node' },
+        { id: 'c', package: '-e git+https://evil', version: '1.0.0', runtime: 'python' },
+        { id: 'd', package: 'mcp-d', version: '1.0.0', runtime: 'python', bin: '../../sh' },
+        { id: 'e', package: 'mcp-server-fetch[proxy]', version: '1.0.0-rc.1+build.5', runtime: 'python' }
+      ])
+      expect(entries.map((entry) => entry.id)).toEqual(['e'])
+    })
+
+    it('should accept a bare array', () => {
+      const entries = parseRegistry([{ id: 'git', package: 'mcp-server-git', version: '0.6.2', runtime: 'python' }])
+      expect(entries.map((entry) => entry.id)).toEqual(['git'])
+      expect(parseRegistry('nope')).toEqual([])
+    })
+  })
+
+  it('should validate versions as strict semver', () => {
+    expect(isValidVersion('2025.1.14')).toBe(true)
+    expect(isValidVersion('1.2.0-beta.1')).toBe(true)
+    expect(isValidVersion('1.2')).toBe(false)
+    expect(isValidVersion('v1.2.0')).toBe(false)
+    expect(isValidVersion('01.2.0')).toBe(false)
+    expect(isValidVersion('1.0.0/..')).toBe(false)
+  })
+
+  it('should validate package names by runtime', () => {
+    expect(isValidPackage('node', '@modelcontextprotocol/server-filesystem')).toBe(true)
+    expect(isValidPackage('node', 'Uppercase')).toBe(false)
+    expect(isValidPackage('node', '.hidden')).toBe(false)
+    expect(isValidPackage('node', '@scope/../x')).toBe(false)
+    expect(isValidPackage('python', 'Mcp_Server.Git')).toBe(true)
+    expect(isValidPackage('python', 'mcp-server-git-')).toBe(false)
+    expect(isValidPackage('python', 'pkg @ https://evil/pkg.whl')).toBe(false)
+  })
+
+  it('should compare versions numerically', () => {
+    expect(compareVersions('1.10.0', '1.9.9')).toBe(1)
+    expect(compareVersions('v2.0', '2.0.0')).toBe(0)
+    expect(compareVersions('1.2.0-beta', '1.2.0')).toBe(-1)
+    expect(compareVersions('2025.1.14', '2025.3.1')).toBe(-1)
+  })
+
+  it('should diff permissions', () => {
+    expect(diffPermissions(['file_read', 'network'], ['file_read', 'file_write'])).toEqual({
+      added: ['file_write'],
+      removed: ['network']
+    })
+  })
+
+  it('should resolve executables', () => {
+    const entry = { id: 'a', name: 'a', description: '', version: '1', permissions: [] }
+    expect(getEntryBin({ ...entry, package: '@scope/server-a', runtime: 'node' })).toBe('server-a')
+    expect(getEntryBin({ ...entry, package: 'mcp-server-b[cli]', runtime: 'python' })).toBe('mcp-server-b')
+
+    const dir = path.join('/tools', 'node_modules', 'server-a')
+    expect(resolveNodeBin(dir, { bin: 'dist/index.js' }, 'server-a')).toBe(path.join(dir, 'dist/index.js'))
+    expect(resolveNodeBin(dir, { bin: { other: 'cli.js' } }, 'server-a')).toBe(path.join(dir, 'cli.js'))
+    expect(resolveNodeBin(dir, { bin: { x: 'x.js', y: 'y.js' } }, 'server-a')).toBeUndefined()
+  })
+})
@@ -0,0 +1,99 @@
+import os from 'node:os'
+import path from 'node:path'
+import { pathToFileURL } from 'node:url'
+
+import { describe, expect, it } from 'vitest'
+
+import { parseMiniAppManifest, resolveInside } from '../miniAppManifest'
+
+const dir = path.resolve('/data/miniapps/notes')
+
+describe('miniAppManifest', () => {
+  it('should resolve files inside the folder only', () => {
+    expect(resolveInside(dir, 'index.html')).toBe(path.join(dir, 'index.html'))
+    expect(resolveInside(dir, '../other/index.html')).toBeUndefined()
+    expect(resolveInside(dir, '.')).toBeUndefined()
+  })
+
+  it('should accept a web app', () => {
+    const result = parseMiniAppManifest(
+      { id: 'notes', name: ' Notes ', url: 'https://notes.example.com', contextMenu: ['ask', 'hack'], extra: 1 },
+      dir
+    )
+    expect(result).toEqual({
+      app: expect.objectContaining({
+        id: 'notes',
+        name: 'Notes',
+        url: 'https://notes.example.com',
+        contextMenu: ['ask'],
+        type: 'Manifest'
+      }),
+      logoFile: undefined
+    })
+  })
+
+  it('should resolve local pages, logos and preloads', () => {
+    const result = parseMiniAppManifest(
+      { id: 'notes', name: 'Notes', url: 'index.html', logo: 'logo.png', preloads: ['bridge.js'] },
+      dir
+    )
+    expect(result).toMatchObject({
+      app: { url: pathToFileURL(path.join(dir, 'index.html')).toString(), preloads: [path.join(dir, 'bridge.js')] },
+      logoFile: path.join(dir, 'logo.png')
+    })
+  })
+
+  it('should read the version and the https update url of a package', () => {
+    const manifest = { id: 'notes', name: 'Notes', url: 'https://notes.example.com', version: '1.2.0' }
+    expect(parseMiniAppManifest({ ...manifest, updateUrl: 'https://example.com/notes.json' }, dir)).toMatchObject({
+      app: { version: '1.2.0' },
+      updateUrl: 'https://example.com/notes.json'
+    })
+    expect(parseMiniAppManifest({ ...manifest, updateUrl: 'http://example.com/notes.json' }, dir)).toHaveProperty('error')
+  })
+
+  it('should resolve the declared permissions', () => {
+    const manifest = { id: 'notes', name: 'Notes', url: 'index.html' }
+    const result = parseMiniAppManifest(
+      {
+        ...manifest,
+        permissions: {
+          filesystem: ['.', 'vault', '~/Documents/notes', path.resolve('/srv/shared')],
+          network: ['API.example.com', '*.cdn.example.com'],
+          clipboard: true
+        }
+      },
+      dir
+    )
+    expect(result).toMatchObject({
+      app: {
+        permissions: {
+          filesystem: [
+            dir,
+            path.join(dir, 'vault'),
+            path.join(os.homedir(), 'Documents', 'notes'),
+            path.resolve('/srv/shared')
+          ],
+          network: ['api.example.com', '*.cdn.example.com'],
+          clipboard: true
+        }
+      }
+    })
+
+    const invalid = (permissions: unknown) => parseMiniAppManifest({ ...manifest, permissions }, dir)
+    expect(invalid([])).toHaveProperty('error')
+    expect(invalid({ filesystem: ['../other'] })).toHaveProperty('error')
+    expect(invalid({ filesystem: [path.resolve('/')] })).toHaveProperty('error')
+    expect(invalid({ network: ['https://example.com'] })).toHaveProperty('error')
+    expect(invalid({ clipboard: 'yes' })).toHaveProperty('error')
+  })
+
+  it('should reject invalid manifests', () => {
+    expect(parseMiniAppManifest([], dir)).toHaveProperty('error')
+    expect(parseMiniAppManifest({ id: '../x', name: 'X', url: 'https://x.com' }, dir)).toHaveProperty('error')
+    expect(parseMiniAppManifest({ id: 'x', url: 'https://x.com' }, dir)).toHaveProperty('error')
+    expect(parseMiniAppManifest({ id: 'x', name: 'X', url: '../../etc/page.html' }, dir)).toHaveProperty('error')
+    const outside = { id: 'x', name: 'X', url: 'https://x.com', preloads: ['/tmp/a.js'] }
+    expect(parseMiniAppManifest(outside, dir)).toHaveProperty('error')
+  })
+})
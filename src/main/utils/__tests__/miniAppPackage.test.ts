@@ -0,0 +1,80 @@
+import crypto from 'node:crypto'
+
+import { describe, expect, it } from 'vitest'
+
+import { getKeyFingerprint } from '../bundle'
+import {
+  getSignatureUrl,
+  parseMiniAppRegistry,
+  parseMiniAppUpdateInfo,
+  parseTrustedKey,
+  verifyPackageSignature
+} from '../miniAppPackage'
+
+const { publicKey, privateKey } = crypto.generateKeyPairSync('ed25519')
+const derKey = publicKey.export({ type: 'spki', format: 'der' }).toString('base64')
+const pemKey = publicKey.export({ type: 'spki', format: 'pem' }).toString()
+const other = crypto.generateKeyPairSync('ed25519').publicKey.export({ type: 'spki', format: 'der' }).toString('base64')
+
+const data = Buffer.from('package contents')
+const signature = crypto.sign(null, data, privateKey).toString('base64')
+
+describe('miniAppPackage', () => {
+  it('should parse PEM and DER Ed25519 keys only', () => {
+    expect(parseTrustedKey(derKey)).toBeDefined()
+    expect(parseTrustedKey(pemKey)).toBeDefined()
+    expect(parseTrustedKey('not a key')).toBeUndefined()
+    const rsa = crypto.generateKeyPairSync('rsa', { modulusLength: 1024 }).publicKey
+    expect(parseTrustedKey(rsa.export({ type: 'spki', format: 'pem' }).toString())).toBeUndefined()
+  })
+
+  it('should return the fingerprint of the key that signed the package', () => {
+    expect(verifyPackageSignature(data, signature, [other, derKey])).toBe(getKeyFingerprint(derKey))
+    expect(verifyPackageSignature(data, signature, [pemKey])).toBe(getKeyFingerprint(derKey))
+  })
+
+  it('should reject tampered packages and untrusted signers', () => {
+    expect(verifyPackageSignature(Buffer.from('tampered'), signature, [derKey])).toBeUndefined()
+    expect(verifyPackageSignature(data, signature, [other])).toBeUndefined()
+    expect(verifyPackageSignature(data, signature, [])).toBeUndefined()
+    expect(verifyPackageSignature(data, 'c2hvcnQ=', [derKey])).toBeUndefined()
+  })
+
+  it('should publish the signature next to the archive', () => {
+    expect(getSignatureUrl('https://example.com/apps/notes.zip?v=2')).toBe('https://example.com/apps/notes.zip.sig?v=2')
+  })
+
+  it('should skip malformed registry entries', () => {
+    const entries = parseMiniAppRegistry({
+      apps: [
+        { id: 'notes', name: 'Notes', url: 'https://example.com/notes.zip', version: '1.0.0' },
+        { id: '../x', url: 'https://example.com/x.zip' },
+        { id: 'plain', url: 'http://example.com/plain.zip' },
+        null
+      ]
+    })
+    expect(entries).toEqual([
+      {
+        id: 'notes',
+        name: 'Notes',
+        description: '',
+        version: '1.0.0',
+        url: 'https://example.com/notes.zip',
+        signature: undefined,
+        homepage: undefined
+      }
+    ])
+    expect(parseMiniAppRegistry('nope')).toEqual([])
+  })
+
+  it('should parse update documents', () => {
+    expect(parseMiniAppUpdateInfo({ version: '1.1.0', url: 'https://example.com/notes-1.1.0.zip' })).toEqual({
+      version: '1.1.0',
+      url: 'https://example.com/notes-1.1.0.zip',
+      signature: undefined
+    })
+    expect(parseMiniAppUpdateInfo({ version: '1.1.0', url: 'http://example.com/notes.zip' })).toBeUndefined()
+    expect(parseMiniAppUpdateInfo({ url: 'https://example.com/notes.zip' })).toBeUndefined()
+    expect(parseMiniAppUpdateInfo([])).toBeUndefined()
+  })
+})
@@ -0,0 +1,85 @@
+import path from 'node:path'
+import { pathToFileURL } from 'node:url'
+
+import { describe, expect, it } from 'vitest'
+
+import {
+  coversScope,
+  getAddedPermissions,
+  getDeclaredPermissions,
+  getPermissionScope,
+  isAppPage,
+  isHostAllowed,
+  isHostPattern,
+  isPathAllowed
+} from '../miniAppPermissions'
+
+const folder = path.resolve('/data/notes')
+
+describe('miniAppPermissions', () => {
+  it('should list the declared permissions', () => {
+    expect(getDeclaredPermissions()).toEqual([])
+    const manifest = { filesystem: [folder], network: [], clipboard: true, notifications: false, sharedState: true }
+    expect(getDeclaredPermissions(manifest)).toEqual(['filesystem', 'clipboard', 'shared-state'])
+  })
+
+  it('should ask again when the manifest widens the folders or hosts', () => {
+    const manifest = { filesystem: [folder], network: ['api.example.com'], clipboard: true }
+    expect(getPermissionScope(manifest, 'filesystem')).toEqual([folder])
+    expect(getPermissionScope(manifest, 'network')).toEqual(['api.example.com'])
+    expect(getPermissionScope(manifest, 'clipboard')).toBeUndefined()
+
+    expect(coversScope(undefined, undefined)).toBe(true)
+    expect(coversScope(['a.com', 'b.com'], ['a.com'])).toBe(true)
+    expect(coversScope(['a.com'], ['a.com', 'b.com'])).toBe(false)
+    // answers remembered before scopes were kept are asked again
+    expect(coversScope(undefined, ['a.com'])).toBe(false)
+  })
+
+  it('should list the permissions an update adds or widens', () => {
+    const previous = { network: ['api.example.com'], clipboard: true }
+    expect(getAddedPermissions(previous, previous)).toEqual([])
+    expect(getAddedPermissions(previous, { network: ['api.example.com'] })).toEqual([])
+    expect(getAddedPermissions(previous, { network: ['api.example.com', 'evil.com'], clipboard: true })).toEqual([
+      'network'
+    ])
+    expect(getAddedPermissions(undefined, { filesystem: [folder], notifications: true })).toEqual([
+      'filesystem',
+      'notifications'
+    ])
+  })
+
+  it('should allow files inside the folders only', () => {
+    expect(isPathAllowed(folder, [folder])).toBe(true)
+    expect(isPathAllowed(path.join(folder, 'a', 'b.md'), [folder])).toBe(true)
+    expect(isPathAllowed(path.join(folder, '..', 'secret.txt'), [folder])).toBe(false)
+    expect(isPathAllowed(`${folder}-backup`, [folder])).toBe(false)
+    expect(isPathAllowed(path.join(folder, 'a.md'), [])).toBe(false)
+  })
+
+  it('should match hosts and wildcard subdomains', () => {
+    expect(isHostPattern('api.example.com')).toBe(true)
+    expect(isHostPattern('*.example.com')).toBe(true)
+    expect(isHostPattern('https://example.com')).toBe(false)
+    expect(isHostPattern('*')).toBe(false)
+
+    const hosts = ['api.example.com', '*.cdn.example.com']
+    expect(isHostAllowed('https://API.example.com/v1', hosts)).toBe(true)
+    expect(isHostAllowed('https://img.cdn.example.com/a.png', hosts)).toBe(true)
+    expect(isHostAllowed('https://cdn.example.com/a.png', hosts)).toBe(false)
+    expect(isHostAllowed('https://evilcdn.example.com', ['*.cdn.example.com'])).toBe(false)
+    expect(isHostAllowed('https://example.com', hosts)).toBe(false)
+    expect(isHostAllowed('file:///etc/passwd', hosts)).toBe(false)
+    expect(isHostAllowed('not a url', hosts)).toBe(false)
+  })
+
+  it('should tell the pages of the mini app from the ones it navigated to', () => {
+    expect(isAppPage('https://notes.example.com/app', 'https://notes.example.com/other?page=2')).toBe(true)
+    expect(isAppPage('https://notes.example.com', 'https://evil.example.com')).toBe(false)
+
+    const page = pathToFileURL(path.join(folder, 'index.html')).toString()
+    expect(isAppPage(page, pathToFileURL(path.join(folder, 'views', 'edit.html')).toString())).toBe(true)
+    expect(isAppPage(page, pathToFileURL(path.resolve('/data/other/index.html')).toString())).toBe(false)
+    expect(isAppPage(page, 'https://notes.example.com')).toBe(false)
+  })
+})
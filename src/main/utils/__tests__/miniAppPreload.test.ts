@@ -0,0 +1,24 @@
+import { IpcChannel } from '@shared/IpcChannel'
+import { describe, expect, it } from 'vitest'
+
+import { buildMiniAppPreload, isValidScript, MINI_APP_BRIDGE_VERSION } from '../miniAppPreload'
+
+describe('miniAppPreload', () => {
+  it('should detect scripts that do not parse', () => {
+    expect(isValidScript('window.answer = 42')).toBe(true)
+    expect(isValidScript('window.answer = ')).toBe(false)
+  })
+
+  it('should expose the bridge before running the scripts in order', () => {
+    const source = buildMiniAppPreload('app"1', [
+      { name: 'a.js', code: 'window.a = 1' },
+      { name: 'b.js', code: 'window.b = 2' }
+    ])
+    expect(source.indexOf("exposeInMainWorld('metheus'")).toBeLessThan(source.indexOf('window.a = 1'))
+    expect(source.indexOf('window.a = 1')).toBeLessThan(source.indexOf('window.b = 2'))
+    expect(source).toContain(`version: ${MINI_APP_BRIDGE_VERSION}`)
+    expect(source).toContain('appId: "app\\"1"')
+    expect(source).toContain(JSON.stringify(IpcChannel.MiniApp_BridgeInvoke))
+    expect(isValidScript(source)).toBe(true)
+  })
+})
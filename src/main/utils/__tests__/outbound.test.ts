@@ -0,0 +1,45 @@
+import { describe, expect, it } from 'vitest'
+
+import { buildOutboundRequest, renderOutboundTemplate } from '../outbound'
+
+describe('outbound', () => {
+  describe('renderOutboundTemplate', () => {
+    it('should replace fields and nested fields', () => {
+      const data = { assistant: 'Coder', job: { type: 'translate', completed: 3 } }
+      expect(renderOutboundTemplate('{{assistant}} {{ job.type }} {{job.completed}}', data)).toBe('Coder translate 3')
+    })
+
+    it('should leave unknown fields empty', () => {
+      expect(renderOutboundTemplate('a{{missing}}b{{x.y}}c', {})).toBe('abc')
+    })
+  })
+
+  describe('buildOutboundRequest', () => {
+    const data = { fileName: 'backup.zip' }
+
+    it('should build chat webhook payloads', () => {
+      const url = 'https://hooks.slack.com/services/x'
+      expect(buildOutboundRequest({ type: 'slack' }, url, 'backup.completed', 'done', data)).toEqual({
+        url,
+        body: { text: 'done' }
+      })
+      expect(buildOutboundRequest({ type: 'discord' }, url, 'backup.completed', 'x'.repeat(3000), data).body).toEqual(
+        { content: 'x'.repeat(2000) }
+      )
+    })
+
+    it('should use the bot token and chat id for Telegram', () => {
+      const integration = { type: 'telegram' as const, chatId: '42' }
+      expect(buildOutboundRequest(integration, 'abc:123', 'job.failed', 'failed', data)).toEqual({
+        url: 'https://api.telegram.org/botabc:123/sendMessage',
+        body: { chat_id: '42', text: 'failed' }
+      })
+    })
+
+    it('should include the event data for HTTP endpoints', () => {
+      const request = buildOutboundRequest({ type: 'http' }, 'https://example.com', 'backup.completed', 'done', data)
+      expect(request.url).toBe('https://example.com')
+      expect(request.body).toMatchObject({ event: 'backup.completed', text: 'done', data })
+    })
+  })
+})
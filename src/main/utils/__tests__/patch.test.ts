@@ -0,0 +1,67 @@
+import { createTwoFilesPatch } from 'diff'
+import { describe, expect, it } from 'vitest'
+
+import { applyParsedPatch, computeDiff, getPatchPath, parseSinglePatch } from '../patch'
+
+const original = 'one\ntwo\nthree\n'
+const modified = 'one\n2\nthree\nfour\n'
+
+describe('patch', () => {
+  describe('computeDiff', () => {
+    it('should create a unified diff with line counts', () => {
+      const diff = computeDiff(original, modified)
+      expect(diff.unified).toContain('-two')
+      expect(diff.unified).toContain('+2')
+      expect(diff.additions).toBe(2)
+      expect(diff.deletions).toBe(1)
+    })
+
+    it('should list the changed words', () => {
+      const diff = computeDiff('hello world', 'hello there', 'words')
+      expect(diff.unified).toBeUndefined()
+      expect(diff.changes?.filter((change) => change.added).map((change) => change.value)).toEqual(['there'])
+      expect(diff.changes?.filter((change) => change.removed).map((change) => change.value)).toEqual(['world'])
+    })
+  })
+
+  describe('parseSinglePatch', () => {
+    it('should take the file name from the headers', () => {
+      const patch = parseSinglePatch(createTwoFilesPatch('a/src/main.ts', 'b/src/main.ts', original, modified))
+      expect(getPatchPath(patch)).toBe('src/main.ts')
+    })
+
+    it('should reject patches for several files', () => {
+      const unified =
+        createTwoFilesPatch('a.txt', 'a.txt', 'a\n', 'b\n') + createTwoFilesPatch('b.txt', 'b.txt', 'a\n', 'b\n')
+      expect(() => parseSinglePatch(unified)).toThrow('exactly one file')
+    })
+  })
+
+  describe('applyParsedPatch', () => {
+    it('should apply a patch matching the content', () => {
+      const patch = parseSinglePatch(createTwoFilesPatch('a.txt', 'a.txt', original, modified))
+      expect(applyParsedPatch(original, patch)).toEqual({
+        content: modified,
+        status: 'modified',
+        additions: 2,
+        deletions: 1
+      })
+    })
+
+    it('should report a conflict when the content changed', () => {
+      const patch = parseSinglePatch(createTwoFilesPatch('a.txt', 'a.txt', original, modified))
+      expect(() => applyParsedPatch('one\nTWO\nthree\n', patch)).toThrow('does not match')
+      expect(() => applyParsedPatch(null, patch)).toThrow('does not exist')
+    })
+
+    it('should create and delete files', () => {
+      const create = parseSinglePatch(createTwoFilesPatch('/dev/null', 'b/new.txt', '', 'new\n'))
+      expect(applyParsedPatch(null, create)).toMatchObject({ content: 'new\n', status: 'added' })
+      expect(() => applyParsedPatch('exists\n', create)).toThrow('already exists')
+
+      const remove = parseSinglePatch(createTwoFilesPatch('a/old.txt', '/dev/null', 'old\n', ''))
+      expect(getPatchPath(remove)).toBe('old.txt')
+      expect(applyParsedPatch('old\n', remove)).toMatchObject({ content: null, status: 'deleted' })
+    })
+  })
+})
@@ -0,0 +1,35 @@
+import { describe, expect, it } from 'vitest'
+
+import { generateSealKey, getSealedTopicId, openBuffer, openText, sealBuffer, sealText } from '../seal'
+
+describe('seal', () => {
+  const key = generateSealKey()
+
+  it('should seal and open text', () => {
+    const payload = JSON.stringify({ content: '你好 🌟 secret', file: { name: 'notes.md' } })
+    const sealed = sealText(key, payload)
+    expect(sealed).not.toContain('secret')
+    expect(sealed).not.toContain('notes.md')
+    expect(openText(key, sealed)).toBe(payload)
+  })
+
+  it('should use a new iv for every seal', () => {
+    expect(sealText(key, 'same')).not.toBe(sealText(key, 'same'))
+  })
+
+  it('should reject a wrong key or modified text', () => {
+    const sealed = sealText(key, 'text')
+    expect(() => openText(generateSealKey(), sealed)).toThrow()
+    expect(() => openText(key, sealed.slice(0, -4) + 'AAAA')).toThrow()
+  })
+
+  it('should seal file content with the topic id in the clear', () => {
+    const data = Buffer.from('%PDF-1.7 secret')
+    const sealed = sealBuffer(key, 'topic-1', data)
+    expect(sealed.includes('secret')).toBe(false)
+    expect(getSealedTopicId(sealed)).toBe('topic-1')
+    expect(getSealedTopicId(data)).toBeUndefined()
+    expect(openBuffer(key, sealed).equals(data)).toBe(true)
+    expect(() => openBuffer(generateSealKey(), sealed)).toThrow()
+  })
+})
@@ -0,0 +1,37 @@
+import { SharedConversationMessage } from '@types'
+import { describe, expect, it } from 'vitest'
+
+import { escapeHtml, renderConversationHtml } from '../shareHtml'
+
+const messages: SharedConversationMessage[] = [
+  { role: 'user', name: 'Alice', content: 'Hello <script>alert(1)</script>', createdAt: '2025-01-01 10:00' },
+  { role: 'assistant', name: 'Translator', content: '**Bonjour**', createdAt: '2025-01-01 10:01' }
+]
+
+describe('shareHtml', () => {
+  it('should escape html', () => {
+    expect(escapeHtml(`<a href="x">'&'</a>`)).toBe('&#60;a href=&#34;x&#34;&#62;&#39;&#38;&#39;&#60;/a&#62;')
+  })
+
+  it('should render messages with their names', () => {
+    const html = renderConversationHtml('Greetings <1>', messages)
+    expect(html).toContain('<title>Greetings &#60;1&#62;</title>')
+    expect(html).toContain('Alice')
+    expect(html).toContain('Translator')
+    expect(html).toContain('<strong>Bonjour</strong>')
+  })
+
+  it('should not keep raw html from messages', () => {
+    const html = renderConversationHtml('Test', messages)
+    expect(html).not.toContain('<script>')
+    expect(html).toContain('&lt;script&gt;')
+  })
+
+  it('should replace names with roles when redacting', () => {
+    const html = renderConversationHtml('Test', messages, true)
+    expect(html).not.toContain('Alice')
+    expect(html).not.toContain('Translator')
+    expect(html).toContain('User · 2025-01-01 10:00')
+    expect(html).toContain('Assistant · 2025-01-01 10:01')
+  })
+})
@@ -0,0 +1,31 @@
+import { describe, expect, it } from 'vitest'
+
+import { matchShortcut, ShortcutInput } from '../shortcut'
+
+function input(key: string, code: string, modifiers: Partial<ShortcutInput> = {}): ShortcutInput {
+  return { key, code, control: false, meta: false, shift: false, alt: false, ...modifiers }
+}
+
+describe('shortcut', () => {
+  describe('matchShortcut', () => {
+    it('should match the key and modifiers', () => {
+      expect(matchShortcut(input('f', 'KeyF', { control: true, shift: true }), ['Ctrl', 'Shift', 'F'])).toBe(true)
+      expect(matchShortcut(input(',', 'Comma', { meta: true }), ['Command', ','])).toBe(true)
+    })
+
+    it('should require the exact modifiers', () => {
+      expect(matchShortcut(input('f', 'KeyF', { control: true }), ['Ctrl', 'Shift', 'F'])).toBe(false)
+      expect(matchShortcut(input('n', 'KeyN', { control: true, alt: true }), ['Ctrl', 'N'])).toBe(false)
+    })
+
+    it('should fall back to the physical key when modifiers change the character', () => {
+      expect(matchShortcut(input('ƒ', 'KeyF', { alt: true }), ['Alt', 'F'])).toBe(true)
+      expect(matchShortcut(input('!', 'Digit1', { shift: true }), ['Shift', '1'])).toBe(true)
+    })
+
+    it('should ignore shortcuts without a key', () => {
+      expect(matchShortcut(input('Control', 'ControlLeft', { control: true }), ['Ctrl'])).toBe(false)
+      expect(matchShortcut(input('a', 'KeyA'), [])).toBe(false)
+    })
+  })
+})
@@ -0,0 +1,75 @@
+import { describe, expect, it } from 'vitest'
+
+import { signAwsRequest } from '../sigv4'
+
+// 测试向量来自 AWS Signature Version 4 test suite
+const credentials = {
+  accessKeyId: 'AKIDEXAMPLE',
+  secretAccessKey: 'wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY'
+}
+const date = new Date('2015-08-30T12:36:00Z')
+const credentialScope = 'Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request'
+
+describe('sigv4', () => {
+  it('should sign a plain GET request', () => {
+    const headers = signAwsRequest(
+      { method: 'GET', url: 'https://example.amazonaws.com/' },
+      credentials,
+      'us-east-1',
+      'service',
+      date
+    )
+
+    expect(headers['x-amz-date']).toBe('20150830T123600Z')
+    expect(headers['authorization']).toBe(
+      `AWS4-HMAC-SHA256 ${credentialScope}, SignedHeaders=host;x-amz-date, Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31`
+    )
+  })
+
+  it('should sort query parameters', () => {
+    const headers = signAwsRequest(
+      { method: 'GET', url: 'https://example.amazonaws.com/?Param2=value2&Param1=value1' },
+      credentials,
+      'us-east-1',
+      'service',
+      date
+    )
+
+    expect(headers['authorization']).toContain(
+      'Signature=b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500'
+    )
+  })
+
+  it('should sign the body and extra headers', () => {
+    const headers = signAwsRequest(
+      {
+        method: 'POST',
+        url: 'https://example.amazonaws.com/',
+        headers: { 'Content-Type': 'application/x-www-form-urlencoded' },
+        body: 'Param1=value1'
+      },
+      credentials,
+      'us-east-1',
+      'service',
+      date
+    )
+
+    expect(headers['authorization']).toBe(
+      `AWS4-HMAC-SHA256 ${credentialScope}, SignedHeaders=content-type;host;x-amz-date, Signature=ff11897932ad3f4e8b18135d722051e5ac45fc38421b1da7b9d196a0fe09473a`
+    )
+  })
+
+  it('should include the session token and omit the host header', () => {
+    const headers = signAwsRequest(
+      { method: 'GET', url: 'https://example.amazonaws.com/' },
+      { ...credentials, sessionToken: 'token' },
+      'us-east-1',
+      'service',
+      date
+    )
+
+    expect(headers['x-amz-security-token']).toBe('token')
+    expect(headers['authorization']).toContain('SignedHeaders=host;x-amz-date;x-amz-security-token')
+    expect(headers['host']).toBeUndefined()
+  })
+})
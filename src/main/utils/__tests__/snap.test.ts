@@ -0,0 +1,42 @@
+import { describe, expect, it } from 'vitest'
+
+import { isValidSnapRect, sortDisplays, toBounds, toSnapRect } from '../snap'
+
+describe('snap', () => {
+  const workArea = { x: 1920, y: 25, width: 1440, height: 875 }
+
+  it('should place fractions of the work area without gaps between tiles', () => {
+    const left = toBounds(workArea, { x: 0, y: 0, width: 2 / 3, height: 1 })
+    const right = toBounds(workArea, { x: 2 / 3, y: 0, width: 1 / 3, height: 1 })
+    expect(left).toEqual({ x: 1920, y: 25, width: 960, height: 875 })
+    expect(right.x).toBe(left.x + left.width)
+    expect(right.x + right.width).toBe(workArea.x + workArea.width)
+  })
+
+  it('should convert bounds back to fractions clamped to the work area', () => {
+    expect(toSnapRect(workArea, { x: 1920, y: 25, width: 720, height: 875 })).toEqual({
+      x: 0,
+      y: 0,
+      width: 0.5,
+      height: 1
+    })
+    expect(toSnapRect(workArea, { x: 1800, y: 0, width: 360, height: 2000 })).toEqual({
+      x: 0,
+      y: 0,
+      width: 0.167,
+      height: 1
+    })
+  })
+
+  it('should order the primary display first, then from left to right', () => {
+    const display = (id: number, x: number) => ({ id, bounds: { x, y: 0, width: 1920, height: 1080 } })
+    const sorted = sortDisplays([display(1, 0), display(2, -1920), display(3, 1920)], 3)
+    expect(sorted.map((item) => item.id)).toEqual([3, 2, 1])
+  })
+
+  it('should reject rects outside the work area', () => {
+    expect(isValidSnapRect({ x: 0.5, y: 0, width: 0.5, height: 1 })).toBe(true)
+    expect(isValidSnapRect({ x: 0.6, y: 0, width: 0.5, height: 1 })).toBe(false)
+    expect(isValidSnapRect({ x: 0, y: 0, width: 0, height: 1 })).toBe(false)
+  })
+})
@@ -0,0 +1,84 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest'
+
+import { StreamBatch, StreamBatcher } from '../stream'
+
+describe('StreamBatcher', () => {
+  let batches: StreamBatch[]
+
+  beforeEach(() => {
+    vi.useFakeTimers()
+    batches = []
+  })
+
+  afterEach(() => {
+    vi.useRealTimers()
+  })
+
+  it('should join text pushed within the delay', () => {
+    const batcher = new StreamBatcher((batch) => batches.push(batch), { minDelay: 20 })
+    batcher.push('Hel')
+    batcher.push('lo')
+    expect(batches).toEqual([])
+
+    vi.advanceTimersByTime(20)
+    expect(batches).toEqual([{ seq: 1, text: 'Hello' }])
+  })
+
+  it('should send once the batch reaches the byte limit', () => {
+    const batcher = new StreamBatcher((batch) => batches.push(batch), { maxBytes: 4 })
+    batcher.push('ab')
+    batcher.push('cd')
+    expect(batches).toEqual([{ seq: 1, text: 'abcd' }])
+  })
+
+  it('should pause while too many batches are unacknowledged', () => {
+    const batcher = new StreamBatcher((batch) => batches.push(batch), { minDelay: 10, maxInFlight: 1 })
+    batcher.push('a')
+    vi.advanceTimersByTime(10)
+    batcher.push('b')
+    vi.advanceTimersByTime(10)
+    batcher.push('c')
+    vi.advanceTimersByTime(5)
+    expect(batches).toEqual([{ seq: 1, text: 'a' }])
+
+    batcher.ack(1)
+    expect(batches).toEqual([
+      { seq: 1, text: 'a' },
+      { seq: 2, text: 'bc' }
+    ])
+  })
+
+  it('should stop waiting for acknowledgements that never arrive', () => {
+    const batcher = new StreamBatcher((batch) => batches.push(batch), { minDelay: 10, maxInFlight: 1 })
+    batcher.push('a')
+    vi.advanceTimersByTime(10)
+    batcher.push('b')
+    vi.advanceTimersByTime(1100)
+    expect(batches.map((batch) => batch.text)).toEqual(['a', 'b'])
+  })
+
+  it('should follow the acknowledgement latency', () => {
+    const batcher = new StreamBatcher((batch) => batches.push(batch), { minDelay: 10, maxDelay: 100 })
+    expect(batcher.getDelay()).toBe(10)
+
+    batcher.push('a')
+    vi.advanceTimersByTime(10)
+    vi.advanceTimersByTime(60)
+    batcher.ack(1)
+    expect(batcher.getDelay()).toBe(60)
+
+    batcher.push('b')
+    vi.advanceTimersByTime(60)
+    vi.advanceTimersByTime(500)
+    batcher.ack(2)
+    expect(batcher.getDelay()).toBe(100)
+  })
+
+  it('should send the rest on flush regardless of acknowledgements', () => {
+    const batcher = new StreamBatcher((batch) => batches.push(batch), { maxInFlight: 1, maxBytes: 1 })
+    batcher.push('a')
+    batcher.push('b')
+    batcher.flush()
+    expect(batches.map((batch) => batch.text)).toEqual(['a', 'b'])
+  })
+})
@@ -0,0 +1,83 @@
+import { ManagerError } from '@shared/ManagerError'
+import { describe, expect, it } from 'vitest'
+
+import { getStructuredFormat, parsePointer, readStructured, updateStructured, validateStructured } from '../structured'
+
+describe('structured', () => {
+  describe('getStructuredFormat', () => {
+    it('should detect the format from the extension', () => {
+      expect(getStructuredFormat('/app/config.JSON')).toBe('json')
+      expect(getStructuredFormat('/app/config.yml')).toBe('yaml')
+    })
+
+    it('should reject TOML files with a manager error', () => {
+      expect(() => getStructuredFormat('/app/Cargo.toml')).toThrow(ManagerError)
+      expect(() => getStructuredFormat('/app/Cargo.toml')).toThrow('TOML files are not supported')
+      expect(() => getStructuredFormat('/app/notes.txt')).toThrow('Unsupported structured file')
+    })
+  })
+
+  describe('parsePointer', () => {
+    it('should split and unescape tokens', () => {
+      expect(parsePointer('')).toEqual([])
+      expect(parsePointer('/a~1b/c~0d/0')).toEqual(['a/b', 'c~d', '0'])
+    })
+
+    it('should reject pointers without a leading slash', () => {
+      expect(() => parsePointer('a/b')).toThrow('Invalid JSON Pointer')
+    })
+  })
+
+  describe('json', () => {
+    const content = '{\n    "name": "app",\n    "tags": ["a"]\n}\n'
+
+    it('should read values', () => {
+      expect(readStructured(content, 'json', '/tags/0')).toBe('a')
+      expect(readStructured(content, 'json')).toEqual({ name: 'app', tags: ['a'] })
+      expect(() => readStructured(content, 'json', '/missing')).toThrow('Path does not exist')
+    })
+
+    it('should keep indentation and the trailing newline', () => {
+      const result = updateStructured(content, 'json', '/version', '1.0.0')
+      expect(result.content).toBe(
+        '{\n    "name": "app",\n    "tags": [\n        "a"\n    ],\n    "version": "1.0.0"\n}\n'
+      )
+    })
+
+    it('should append to arrays and remove values', () => {
+      expect(updateStructured(content, 'json', '/tags/-', 'b').data).toEqual({ name: 'app', tags: ['a', 'b'] })
+      expect(updateStructured(content, 'json', '/tags/0', undefined).data).toEqual({ name: 'app', tags: [] })
+      expect(updateStructured(content, 'json', '/name', undefined).data).toEqual({ tags: ['a'] })
+    })
+
+    it('should not create missing parents', () => {
+      expect(() => updateStructured(content, 'json', '/a/b', 1)).toThrow('Path does not exist: /a')
+      expect(() => updateStructured(content, 'json', '/tags/5', 1)).toThrow('out of range')
+    })
+  })
+
+  describe('yaml', () => {
+    it('should keep comments and untouched formatting', () => {
+      const content = '# settings\nname: app # inline\nlist:\n  - a\n'
+      const result = updateStructured(content, 'yaml', '/list/-', 'b')
+      expect(result.content).toBe('# settings\nname: app # inline\nlist:\n  - a\n  - b\n')
+      expect(result.data).toEqual({ name: 'app', list: ['a', 'b'] })
+      expect(readStructured(result.content, 'yaml', '/list/1')).toBe('b')
+    })
+  })
+
+  describe('validateStructured', () => {
+    it('should check the json schema', () => {
+      const schema = { type: 'object', properties: { port: { type: 'integer' } } }
+      expect(validateStructured({ port: 80 }, 'config.json', schema)).toEqual([])
+      expect(validateStructured({ port: '80' }, 'config.json', schema)).toEqual(['/port must be integer'])
+    })
+
+    it('should keep package.json dependencies as version strings', () => {
+      expect(validateStructured({ dependencies: { a: '^1.0.0' } }, '/app/package.json')).toEqual([])
+      expect(validateStructured({ dependencies: { a: 1 } }, '/app/package.json')).toEqual([
+        'dependencies.a must be a version string'
+      ])
+    })
+  })
+})
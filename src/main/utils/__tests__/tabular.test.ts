@@ -0,0 +1,108 @@
+import { describe, expect, it } from 'vitest'
+
+import {
+  buildTable,
+  detectDelimiter,
+  parseDelimited,
+  parseSharedStrings,
+  parseWorkbook,
+  parseWorksheet
+} from '../tabular'
+
+describe('tabular', () => {
+  describe('parseDelimited', () => {
+    it('should split rows and handle quoted cells', () => {
+      const text = '\ufeffname,note\r\n"Smith, J","said ""hi""\nthen left"\n'
+      expect(parseDelimited(text, ',')).toEqual([
+        ['name', 'note'],
+        ['Smith, J', 'said "hi"\nthen left']
+      ])
+    })
+
+    it('should keep empty cells', () => {
+      expect(parseDelimited('a\t\tc\n\t\t', '\t')).toEqual([
+        ['a', '', 'c'],
+        ['', '', '']
+      ])
+    })
+  })
+
+  describe('detectDelimiter', () => {
+    it('should pick the most used delimiter of the first line', () => {
+      expect(detectDelimiter('a;b;"c,d"\n1;2;3')).toBe(';')
+      expect(detectDelimiter('a\tb\n1\t2')).toBe('\t')
+      expect(detectDelimiter('single')).toBe(',')
+    })
+  })
+
+  describe('buildTable', () => {
+    it('should infer column types and statistics', () => {
+      const table = buildTable([
+        ['id', 'price', 'active', 'day', 'city', ''],
+        ['1', '2.5', 'true', '2024-01-02', 'Paris', ''],
+        ['2', '', 'FALSE', '2023-12-31', 'Paris', ''],
+        ['', '', '', '', '', ''],
+        ['3', '-1e2', 'true', '2024-03-01', 'Rome']
+      ])
+
+      expect(table.columns.map((column) => [column.name, column.type])).toEqual([
+        ['id', 'integer'],
+        ['price', 'number'],
+        ['active', 'boolean'],
+        ['day', 'date'],
+        ['city', 'string'],
+        ['column_6', 'empty']
+      ])
+      expect(table.columns[0]).toMatchObject({ count: 3, missing: 0, distinct: 3, min: 1, max: 3, mean: 2 })
+      expect(table.columns[1]).toMatchObject({ count: 2, missing: 1, min: -100, max: 2.5 })
+      expect(table.columns[3]).toMatchObject({ min: '2023-12-31', max: '2024-03-01' })
+      expect(table.columns[4].top).toEqual([
+        { value: 'Paris', count: 2 },
+        { value: 'Rome', count: 1 }
+      ])
+      expect(table.rows).toEqual([
+        [1, 2.5, true, '2024-01-02', 'Paris', null],
+        [2, null, false, '2023-12-31', 'Paris', null],
+        [3, -100, true, '2024-03-01', 'Rome', null]
+      ])
+    })
+
+    it('should name columns when there is no header', () => {
+      const table = buildTable([['a', '1']], false)
+      expect(table.columns.map((column) => column.name)).toEqual(['column_1', 'column_2'])
+      expect(table.rows).toEqual([['a', 1]])
+    })
+  })
+
+  describe('xlsx', () => {
+    it('should list worksheets with their paths', () => {
+      const workbook = `<workbook xmlns:r="r"><sheets>
+        <sheet name="Sales" sheetId="1" r:id="rId2"/><sheet name="Notes" sheetId="2" r:id="rId1"/>
+      </sheets></workbook>`
+      const rels = `<Relationships>
+        <Relationship Id="rId1" Target="worksheets/sheet2.xml"/>
+        <Relationship Id="rId2" Target="/xl/worksheets/sheet1.xml"/>
+      </Relationships>`
+      expect(parseWorkbook(workbook, rels)).toEqual([
+        { name: 'Sales', path: 'xl/worksheets/sheet1.xml' },
+        { name: 'Notes', path: 'xl/worksheets/sheet2.xml' }
+      ])
+    })
+
+    it('should read shared, inline, boolean and numeric cells', () => {
+      const shared = parseSharedStrings(
+        '<sst><si><t>name</t></si><si><r><t>Rich </t></r><r><t xml:space="preserve">text</t></r></si></sst>'
+      )
+      expect(shared).toEqual(['name', 'Rich text'])
+
+      const sheet = `<worksheet><sheetData>
+        <row r="1"><c r="A1" t="s"><v>0</v></c><c r="C1" t="inlineStr"><is><t>flag</t></is></c></row>
+        <row r="2"><c r="A2" t="s"><v>1</v></c><c r="B2"><f>1+1</f><v>2</v></c><c r="C2" t="b"><v>1</v></c></row>
+      </sheetData></worksheet>`
+      expect(parseWorksheet(sheet, shared)).toEqual([
+        ['name', '', 'flag'],
+        ['Rich text', '2', 'true']
+      ])
+    })
+  })
+})
@@ -0,0 +1,62 @@
+import { describe, expect, it } from 'vitest'
+
+import { parseTemplate, renderTemplate } from '../template'
+
+describe('template', () => {
+  describe('renderTemplate', () => {
+    it('should render values by path', () => {
+      const context = { topic: { name: 'Weekly' }, count: 3, tags: ['a'] }
+      expect(renderTemplate('{{topic.name}}: {{count}} {{tags}} {{missing}}', context)).toBe('Weekly: 3 ["a"] ')
+    })
+
+    it('should call helpers with paths and literals', () => {
+      const context = { name: ' Alice ', list: ['a', 'b'] }
+      expect(renderTemplate('{{upper name}}|{{trim name}}|{{join list " + "}}', context)).toBe(' ALICE |Alice|a + b')
+      expect(renderTemplate('{{truncate "abcdef" 3}}|{{default missing "none"}}', {})).toBe('abc…|none')
+      expect(renderTemplate('{{date "2025-01-02T03:04:00" "YYYY/MM/DD"}}', {})).toBe('2025/01/02')
+      expect(() => renderTemplate('{{shout name}}', context)).toThrow('Unknown helper: shout')
+    })
+
+    it('should render if, unless and else branches', () => {
+      const source = '{{#if items}}has{{else}}empty{{/if}} {{#unless done}}todo{{/unless}}'
+      expect(renderTemplate(source, { items: [1], done: false })).toBe('has todo')
+      expect(renderTemplate(source, { items: [], done: true })).toBe('empty ')
+    })
+
+    it('should loop over arrays and objects', () => {
+      const context = { messages: [{ role: 'user' }, { role: 'assistant' }], meta: { a: 1, b: 2 }, title: 'T' }
+      const source = '{{#each messages}}{{@index}}:{{role}}@{{title}}{{#unless @last}},{{/unless}}{{/each}}'
+      expect(renderTemplate(source, context)).toBe('0:user@T,1:assistant@T')
+      expect(renderTemplate('{{#each meta}}{{@key}}={{this}};{{/each}}', context)).toBe('a=1;b=2;')
+      expect(renderTemplate('{{#each none}}x{{else}}nothing{{/each}}', context)).toBe('nothing')
+    })
+
+    it('should render with blocks and partials', () => {
+      const partials = { footer: '-- {{author.name}}', signed: '{{> footer}}!' }
+      expect(renderTemplate('{{#with author}}{{name}}{{/with}}', { author: { name: 'Bob' } })).toBe('Bob')
+      expect(renderTemplate('Hi {{> signed}}', { author: { name: 'Bob' } }, { partials })).toBe('Hi -- Bob!')
+      expect(() => renderTemplate('{{> missing}}', {}, { partials })).toThrow('Unknown partial: missing')
+      expect(() => renderTemplate('{{> loop}}', {}, { partials: { loop: '{{> loop}}' } })).toThrow('nested too deeply')
+    })
+
+    it('should only read own properties of the context', () => {
+      const context = { name: 'x' }
+      expect(renderTemplate('{{name.constructor}}{{constructor.name}}{{__proto__}}{{name.length}}', context)).toBe('1')
+      expect(renderTemplate('{{toString}}{{hasOwnProperty}}', context)).toBe('')
+    })
+
+    it('should use the given format for values', () => {
+      const format = (value: unknown) => (value === undefined ? '' : JSON.stringify(value))
+      expect(renderTemplate('{{name}}{{missing}}', { name: 'x' }, { format })).toBe('"x"')
+    })
+  })
+
+  describe('parseTemplate', () => {
+    it('should reject unbalanced blocks', () => {
+      expect(() => parseTemplate('{{#if a}}')).toThrow('Unclosed {{#if}}')
+      expect(() => parseTemplate('{{#if a}}{{/each}}')).toThrow('Unexpected {{/each}}')
+      expect(() => parseTemplate('{{else}}')).toThrow('Unexpected {{else}}')
+      expect(() => parseTemplate('{{#loop a}}{{/loop}}')).toThrow('Unknown block')
+    })
+  })
+})
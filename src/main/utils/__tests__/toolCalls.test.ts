@@ -0,0 +1,56 @@
+import { describe, expect, it } from 'vitest'
+
+import { buildToolCallQuery, getToolCallError, stripBinaryContent } from '../toolCalls'
+
+describe('toolCalls', () => {
+  describe('buildToolCallQuery', () => {
+    it('should list the newest calls without filters', () => {
+      expect(buildToolCallQuery()).toEqual({
+        sql: 'SELECT * FROM tool_calls ORDER BY started_at DESC LIMIT 100 OFFSET 0',
+        args: []
+      })
+    })
+
+    it('should combine filters', () => {
+      const { sql, args } = buildToolCallQuery({
+        topicId: 't1',
+        toolName: 'read_file',
+        status: 'error',
+        since: 10,
+        limit: 5,
+        offset: 5
+      })
+      expect(sql).toBe(
+        'SELECT * FROM tool_calls WHERE topic_id = ? AND tool_name = ? AND error IS NOT NULL AND started_at >= ? ' +
+          'ORDER BY started_at DESC LIMIT 5 OFFSET 5'
+      )
+      expect(args).toEqual(['t1', 'read_file', 10])
+    })
+
+    it('should clamp the page', () => {
+      expect(buildToolCallQuery({ limit: 1e6, offset: -3 }).sql).toContain('LIMIT 1000 OFFSET 0')
+      expect(buildToolCallQuery({ limit: 0 }).sql).toContain('LIMIT 1 ')
+    })
+  })
+
+  it('should drop binary content', () => {
+    const result = stripBinaryContent({
+      content: [
+        { type: 'text', text: 'hi' },
+        { type: 'image', data: 'AAAA', mimeType: 'image/png' },
+        { type: 'resource', resource: { uri: 'file:///a', blob: 'BBBB' } }
+      ]
+    })
+    expect(result.content).toEqual([
+      { type: 'text', text: 'hi' },
+      { type: 'image', data: '', mimeType: 'image/png' },
+      { type: 'resource', resource: { uri: 'file:///a', blob: '' } }
+    ])
+  })
+
+  it('should read errors of calls and results', () => {
+    expect(getToolCallError(undefined, new Error('denied'))).toBe('denied')
+    expect(getToolCallError({ content: [{ type: 'text', text: 'ok' }] })).toBeUndefined()
+    expect(getToolCallError({ isError: true, content: [{ type: 'text', text: 'no such file' }] })).toBe('no such file')
+  })
+})
@@ -0,0 +1,74 @@
+import crypto from 'node:crypto'
+
+import { describe, expect, it } from 'vitest'
+
+import { renderWebhookPrompt, SlidingWindowLimiter, verifyWebhookSignature } from '../webhook'
+
+const sign = (secret: string, body: string, timestamp: string) =>
+  `sha256=${crypto.createHmac('sha256', secret).update(`${timestamp}.${body}`).digest('hex')}`
+
+describe('webhook', () => {
+  describe('verifyWebhookSignature', () => {
+    const body = Buffer.from('{"status":"failed"}')
+    const now = 1_700_000_000_000
+    const timestamp = String(now / 1000)
+    const signature = sign('secret', body.toString(), timestamp)
+
+    it('should accept a valid signature', () => {
+      expect(verifyWebhookSignature('secret', body, signature, timestamp, now)).toBe(true)
+    })
+
+    it('should reject wrong secrets, modified bodies and malformed headers', () => {
+      expect(verifyWebhookSignature('other', body, signature, timestamp, now)).toBe(false)
+      expect(verifyWebhookSignature('secret', Buffer.from('{}'), signature, timestamp, now)).toBe(false)
+      expect(verifyWebhookSignature('secret', body, 'sha256=abc', timestamp, now)).toBe(false)
+      expect(verifyWebhookSignature('secret', body, undefined, timestamp, now)).toBe(false)
+      expect(verifyWebhookSignature('secret', body, signature, undefined, now)).toBe(false)
+    })
+
+    it('should reject stale timestamps and timestamps that were not signed', () => {
+      expect(verifyWebhookSignature('secret', body, signature, timestamp, now + 6 * 60 * 1000)).toBe(false)
+      expect(verifyWebhookSignature('secret', body, signature, String(now / 1000 + 60), now)).toBe(false)
+    })
+  })
+
+  describe('renderWebhookPrompt', () => {
+    const payload = { build: { id: 42, status: 'failed' }, log: 'error: test failed' }
+
+    it('should use the payload when there is no template', () => {
+      expect(renderWebhookPrompt(undefined, 'hello')).toBe('hello')
+      expect(renderWebhookPrompt('', { a: 1 })).toBe('{\n  "a": 1\n}')
+    })
+
+    it('should replace nested fields', () => {
+      expect(renderWebhookPrompt('Build {{payload.build.id}} {{ payload.build.status }}', payload)).toBe(
+        'Build 42 failed'
+      )
+      expect(renderWebhookPrompt('Log: {{payload.log}}, missing: {{payload.x.y}}', payload)).toBe(
+        'Log: error: test failed, missing: '
+      )
+    })
+
+    it('should insert the whole payload', () => {
+      expect(renderWebhookPrompt('Payload: {{payload}}', { a: 1 })).toBe('Payload: {\n  "a": 1\n}')
+    })
+  })
+
+  describe('SlidingWindowLimiter', () => {
+    it('should limit hits per window', () => {
+      const limiter = new SlidingWindowLimiter(1000)
+      expect(limiter.take('a', 2, 0)).toBe(true)
+      expect(limiter.take('a', 2, 100)).toBe(true)
+      expect(limiter.take('a', 2, 200)).toBe(false)
+      expect(limiter.take('b', 2, 200)).toBe(true)
+      expect(limiter.take('a', 2, 1050)).toBe(true)
+    })
+
+    it('should not limit when the limit is 0', () => {
+      const limiter = new SlidingWindowLimiter(1000)
+      for (let i = 0; i < 100; i++) {
+        expect(limiter.take('a', 0, i)).toBe(true)
+      }
+    })
+  })
+})
@@ -0,0 +1,102 @@
+import os from 'node:os'
+import path from 'node:path'
+
+import { AgentAction, AgentActionKind, ConfirmationRule } from '@types'
+
+import { isInsideDirectory } from './audit'
+
+// tools whose names contain these words change files
+const WRITE_TOOL_PATTERN = /write|edit|create|move|rename|delete|remove|append|save|mkdir|copy|patch/i
+// arguments holding file paths, in the naming of the common filesystem servers
+const PATH_KEYS = ['path', 'paths', 'file', 'filepath', 'file_path', 'source', 'destination', 'target', 'directory']
+const URL_PATTERN = /^https?:\/\/\S+$/i
+
+export interface PolicyContext {
+  // folders mounted to conversations, writes inside them are in the workspace
+  workspaces: string[]
+  // domains network calls were approved for
+  knownDomains: string[]
+}
+
+function expandPath(file: string): string {
+  return path.resolve(file === '~' || file.startsWith('~/') ? path.join(os.homedir(), file.slice(1)) : file)
+}
+
+function collectStrings(value: unknown, key: string, found: { key: string; value: string }[], depth = 0) {
+  if (depth > 4) return
+  if (typeof value === 'string') {
+    found.push({ key, value })
+  } else if (Array.isArray(value)) {
+    value.forEach((item) => collectStrings(item, key, found, depth + 1))
+  } else if (value && typeof value === 'object') {
+    Object.entries(value).forEach(([child, item]) => collectStrings(item, child.toLowerCase(), found, depth + 1))
+  }
+}
+
+export function getDomain(url: string): string | undefined {
+  try {
+    return new URL(url).hostname.toLowerCase()
+  } catch {
+    return undefined
+  }
+}
+
+/**
+ * Describe a tool call by its effects: files it writes and urls it requests.
+ * Only tools named like writers count as file writes, dry runs don't.
+ */
+export function describeToolCall(server: string, tool: string, args: unknown): AgentAction {
+  const strings: { key: string; value: string }[] = []
+  collectStrings(args, '', strings)
+
+  const urls = [...new Set(strings.filter(({ value }) => URL_PATTERN.test(value.trim())).map(({ value }) => value))]
+  const dryRun = !!args && typeof args === 'object' && (args as Record<string, unknown>).dryRun === true
+  const paths =
+    WRITE_TOOL_PATTERN.test(tool) && !dryRun
+      ? [...new Set(strings.filter(({ key }) => PATH_KEYS.includes(key)).map(({ value }) => expandPath(value)))]
+      : []
+
+  const kinds: AgentActionKind[] = []
+  if (paths.length > 0) kinds.push('file_write')
+  if (urls.length > 0) kinds.push('network')
+  return { kinds, server, tool, paths, urls }
+}
+
+/**
+ * Whether the action needs confirmation under the rule
+ */
+export function matchesRule(rule: ConfirmationRule, action: AgentAction, context: PolicyContext): boolean {
+  if (!rule.enabled || !action.kinds.includes(rule.kind)) return false
+  if (rule.servers?.length && !rule.servers.includes(action.server)) return false
+
+  switch (rule.condition) {
+    case 'always':
+      return true
+    case 'outside_workspace':
+      return (
+        rule.kind === 'file_write' &&
+        action.paths.some((file) => !context.workspaces.some((dir) => isInsideDirectory(file, dir)))
+      )
+    case 'new_domain':
+      return (
+        rule.kind === 'network' &&
+        action.urls.some((url) => {
+          const domain = getDomain(url)
+          return !domain || !context.knownDomains.includes(domain)
+        })
+      )
+    default:
+      return false
+  }
+}
+
+/**
+ * The first rule that asks for confirmation of the action
+ */
+export function findRule(
+  rules: ConfirmationRule[],
+  action: AgentAction,
+  context: PolicyContext
+): ConfirmationRule | undefined {
+  return rules.find((rule) => matchesRule(rule, action, context))
+}
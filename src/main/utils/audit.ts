@@ -0,0 +1,107 @@
+import path from 'node:path'
+
+import { IpcChannel } from '@shared/IpcChannel'
+
+export type AuditCategory = 'file_write' | 'process_spawn' | 'credential_read' | 'setting_change'
+
+export interface AuditRule {
+  category: AuditCategory
+  // index of the destination path argument, writes inside the app data directory are not recorded
+  pathArg?: number
+  // arguments carry secrets, only their types are recorded
+  redact?: boolean
+}
+
+export const AUDIT_RULES: Partial<Record<string, AuditRule>> = {
+  [IpcChannel.File_Write]: { category: 'file_write', pathArg: 0 },
+  [IpcChannel.File_Copy]: { category: 'file_write', pathArg: 1 },
+  [IpcChannel.File_Save]: { category: 'file_write' },
+  [IpcChannel.File_SaveImage]: { category: 'file_write' },
+  [IpcChannel.Export_Word]: { category: 'file_write' },
+  [IpcChannel.ConversationShare_Share]: { category: 'file_write' },
+  [IpcChannel.SecureTopic_OpenFiles]: { category: 'file_write' },
+  [IpcChannel.ConversationWorkspace_ApplyPatch]: { category: 'file_write' },
+  [IpcChannel.MiniApp_Capture]: { category: 'file_write' },
+  [IpcChannel.MiniApp_PrintToPdf]: { category: 'file_write', pathArg: 1 },
+  [IpcChannel.Print_ToPdf]: { category: 'file_write', pathArg: 0 },
+  [IpcChannel.Perf_StopTrace]: { category: 'file_write', pathArg: 0 },
+  [IpcChannel.Log_ExportSupportBundle]: { category: 'file_write' },
+  [IpcChannel.Workspace_Export]: { category: 'file_write', pathArg: 2, redact: true },
+  [IpcChannel.Open_Path]: { category: 'process_spawn' },
+  [IpcChannel.File_OpenPath]: { category: 'process_spawn' },
+  [IpcChannel.App_InstallUvBinary]: { category: 'process_spawn' },
+  [IpcChannel.App_InstallBunBinary]: { category: 'process_spawn' },
+  [IpcChannel.Mcp_RestartServer]: { category: 'process_spawn' },
+  [IpcChannel.Mcp_CallTool]: { category: 'process_spawn' },
+  [IpcChannel.Mcp_ReplayToolCall]: { category: 'process_spawn' },
+  [IpcChannel.Copilot_GetToken]: { category: 'credential_read', redact: true },
+  [IpcChannel.Copilot_GetCopilotToken]: { category: 'credential_read', redact: true },
+  [IpcChannel.MiniApp_GetCookies]: { category: 'credential_read' },
+  [IpcChannel.Nutstore_DecryptToken]: { category: 'credential_read', redact: true },
+  [IpcChannel.Aes_Decrypt]: { category: 'credential_read', redact: true },
+  [IpcChannel.SecureTopic_Unlock]: { category: 'credential_read' },
+  [IpcChannel.VertexAI_GetAuthHeaders]: { category: 'credential_read', redact: true },
+  [IpcChannel.AwsBedrock_GetAccessKeyId]: { category: 'credential_read' },
+  [IpcChannel.AwsBedrock_SignRequest]: { category: 'credential_read', redact: true },
+  [IpcChannel.AwsBedrock_SetCredentials]: { category: 'setting_change', redact: true },
+  [IpcChannel.Copilot_SaveCopilotToken]: { category: 'setting_change', redact: true },
+  [IpcChannel.Lock_SetPassphrase]: { category: 'setting_change', redact: true },
+  [IpcChannel.SecureTopic_RemoveKey]: { category: 'setting_change' },
+  [IpcChannel.Config_Set]: { category: 'setting_change' },
+  [IpcChannel.App_SetAppDataPath]: { category: 'setting_change' },
+  [IpcChannel.App_SetLaunchOnBoot]: { category: 'setting_change' },
+  [IpcChannel.App_SetAutoUpdate]: { category: 'setting_change' },
+  [IpcChannel.App_SetFeedUrl]: { category: 'setting_change' },
+  [IpcChannel.Download_SetDirectory]: { category: 'setting_change' },
+  [IpcChannel.App_Proxy]: { category: 'setting_change' },
+  [IpcChannel.Webhook_List]: { category: 'credential_read' },
+  [IpcChannel.Webhook_Create]: { category: 'setting_change' },
+  [IpcChannel.Webhook_Update]: { category: 'setting_change' },
+  [IpcChannel.Webhook_Remove]: { category: 'setting_change' },
+  [IpcChannel.Webhook_RegenerateSecret]: { category: 'credential_read' },
+  [IpcChannel.Outbound_Save]: { category: 'setting_change', redact: true },
+  [IpcChannel.Outbound_Remove]: { category: 'setting_change' },
+  [IpcChannel.Template_Save]: { category: 'setting_change' },
+  [IpcChannel.Template_Remove]: { category: 'setting_change' },
+  [IpcChannel.MiniApp_SetCookie]: { category: 'setting_change', redact: true },
+  [IpcChannel.MiniApp_ClearCookies]: { category: 'setting_change' },
+  [IpcChannel.MiniApp_RespondPermission]: { category: 'setting_change' },
+  [IpcChannel.MiniApp_ResetPermissions]: { category: 'setting_change' },
+  [IpcChannel.MiniApp_InstallPackage]: { category: 'setting_change' },
+  [IpcChannel.MiniApp_InstallFromUrl]: { category: 'setting_change' },
+  [IpcChannel.MiniApp_UninstallPackage]: { category: 'setting_change' },
+  [IpcChannel.MiniApp_SetTrustedKeys]: { category: 'setting_change' },
+  [IpcChannel.MiniApp_UpdatePackage]: { category: 'setting_change' },
+  [IpcChannel.MiniApp_SetAutoUpdate]: { category: 'setting_change' },
+  [IpcChannel.SnapLayout_Save]: { category: 'setting_change' },
+  [IpcChannel.SnapLayout_Remove]: { category: 'setting_change' },
+  [IpcChannel.WorkerPool_SetSize]: { category: 'setting_change' },
+  [IpcChannel.Log_SetRedactionPatterns]: { category: 'setting_change' },
+  [IpcChannel.Command_Invoke]: { category: 'setting_change' },
+  [IpcChannel.ConversationWorkspace_Mount]: { category: 'setting_change' }
+}
+
+const MAX_ARG_LENGTH = 80
+
+/**
+ * Short, single line description of an IPC argument
+ */
+export function summarizeArg(value: unknown): string {
+  if (value === undefined) return 'undefined'
+  if (value === null) return 'null'
+  if (value instanceof Uint8Array) return `<${value.byteLength} bytes>`
+  if (Array.isArray(value)) return `[${value.length} items]`
+  if (typeof value === 'object') return `{${Object.keys(value).join(', ')}}`
+
+  const text = typeof value === 'string' ? JSON.stringify(value) : String(value)
+  return text.length > MAX_ARG_LENGTH ? `${text.slice(0, MAX_ARG_LENGTH)}…` : text
+}
+
+export function summarizeArgs(args: unknown[], redact = false): string {
+  return args.map((arg) => (redact ? `<${arg === null ? 'null' : typeof arg}>` : summarizeArg(arg))).join(', ')
+}
+
+export function isInsideDirectory(filePath: string, directory: string): boolean {
+  const relative = path.relative(directory, path.resolve(filePath))
+  return !relative.startsWith('..') && !path.isAbsolute(relative)
+}
@@ -0,0 +1,142 @@
+import crypto, { KeyObject } from 'node:crypto'
+import zlib from 'node:zlib'
+
+import { ManagerError } from '@shared/ManagerError'
+
+export const BUNDLE_FORMAT = 'cherry-workspace'
+export const BUNDLE_VERSION = 1
+// a decompressed payload above this is refused instead of exhausting memory
+const MAX_PAYLOAD_SIZE = 512 * 1024 * 1024
+
+/**
+ * A workspace bundle file.
+ *
+ * The payload is gzipped and encrypted with AES-256-GCM under a key derived from the passphrase with scrypt.
+ * The exporter signs the encrypted fields with its Ed25519 key, the public key travels with the bundle so
+ * the importer can show who signed it and detect tampering before decrypting. Such a bundle is self-signed:
+ * anyone can produce one, the signer is only trusted when its fingerprint was pinned by the importer.
+ */
+export interface WorkspaceBundle {
+  format: typeof BUNDLE_FORMAT
+  version: number
+  createdAt: number
+  // base64 DER (spki) of the signer's Ed25519 public key
+  publicKey: string
+  salt: string
+  iv: string
+  tag: string
+  data: string
+  signature: string
+}
+
+export interface OpenedBundle {
+  payload: Buffer
+  fingerprint: string
+  // the signer is one of the pinned fingerprints
+  trusted: boolean
+  createdAt: number
+}
+
+function deriveKey(passphrase: string, salt: Buffer): Buffer {
+  return crypto.scryptSync(passphrase, salt, 32)
+}
+
+function signedContent(bundle: Omit<WorkspaceBundle, 'signature'>): Buffer {
+  const { format, version, createdAt, publicKey, salt, iv, tag, data } = bundle
+  return Buffer.from([format, version, createdAt, publicKey, salt, iv, tag, data].join('.'))
+}
+
+/**
+ * Short, human comparable fingerprint of a signing key, e.g. `3f2a:91c0:...`
+ */
+export function getKeyFingerprint(publicKey: KeyObject | string): string {
+  const der =
+    typeof publicKey === 'string'
+      ? Buffer.from(publicKey, 'base64')
+      : publicKey.export({ type: 'spki', format: 'der' })
+  const hash = crypto.createHash('sha256').update(der).digest('hex').slice(0, 32)
+  return hash.match(/.{4}/g)!.join(':')
+}
+
+export function sealBundle(payload: Buffer, passphrase: string, privateKey: KeyObject): WorkspaceBundle {
+  const salt = crypto.randomBytes(16)
+  const iv = crypto.randomBytes(12)
+  const cipher = crypto.createCipheriv('aes-256-gcm', deriveKey(passphrase, salt), iv)
+  const data = Buffer.concat([cipher.update(zlib.gzipSync(payload)), cipher.final()])
+
+  const unsigned = {
+    format: BUNDLE_FORMAT,
+    version: BUNDLE_VERSION,
+    createdAt: Date.now(),
+    publicKey: crypto.createPublicKey(privateKey).export({ type: 'spki', format: 'der' }).toString('base64'),
+    salt: salt.toString('base64'),
+    iv: iv.toString('base64'),
+    tag: cipher.getAuthTag().toString('base64'),
+    data: data.toString('base64')
+  } as const
+
+  const signature = crypto.sign(null, signedContent(unsigned), privateKey).toString('base64')
+  return { ...unsigned, signature }
+}
+
+function isBundle(value: any): value is WorkspaceBundle {
+  return (
+    value?.format === BUNDLE_FORMAT &&
+    ['publicKey', 'salt', 'iv', 'tag', 'data', 'signature'].every((key) => typeof value[key] === 'string')
+  )
+}
+
+/**
+ * Verify the signature and decrypt a bundle, `trustedFingerprints` are the signers the importer pinned
+ */
+export function openBundle(bundle: unknown, passphrase: string, trustedFingerprints: string[] = []): OpenedBundle {
+  if (!isBundle(bundle)) {
+    throw new ManagerError('WorkspaceManager', 'invalid_argument', 'Not a workspace bundle')
+  }
+  if (bundle.version > BUNDLE_VERSION) {
+    throw new ManagerError('WorkspaceManager', 'invalid_argument', `Unsupported bundle version ${bundle.version}`)
+  }
+
+  let verified = false
+  try {
+    const publicKey = crypto.createPublicKey({
+      key: Buffer.from(bundle.publicKey, 'base64'),
+      format: 'der',
+      type: 'spki'
+    })
+    verified = crypto.verify(null, signedContent(bundle), publicKey, Buffer.from(bundle.signature, 'base64'))
+  } catch {
+    verified = false
+  }
+  if (!verified) {
+    throw new ManagerError('WorkspaceManager', 'invalid_argument', 'Bundle signature is invalid')
+  }
+
+  let compressed: Buffer
+  try {
+    const decipher = crypto.createDecipheriv(
+      'aes-256-gcm',
+      deriveKey(passphrase, Buffer.from(bundle.salt, 'base64')),
+      Buffer.from(bundle.iv, 'base64')
+    )
+    decipher.setAuthTag(Buffer.from(bundle.tag, 'base64'))
+    compressed = Buffer.concat([decipher.update(Buffer.from(bundle.data, 'base64')), decipher.final()])
+  } catch {
+    throw new ManagerError('WorkspaceManager', 'permission_denied', 'Wrong passphrase')
+  }
+
+  let payload: Buffer
+  try {
+    payload = zlib.gunzipSync(compressed, { maxOutputLength: MAX_PAYLOAD_SIZE })
+  } catch {
+    throw new ManagerError('WorkspaceManager', 'invalid_argument', 'Bundle payload is corrupted or too large')
+  }
+
+  const fingerprint = getKeyFingerprint(bundle.publicKey)
+  return {
+    payload,
+    fingerprint,
+    trusted: trustedFingerprints.includes(fingerprint),
+    createdAt: bundle.createdAt
+  }
+}
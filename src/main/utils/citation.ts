@@ -0,0 +1,141 @@
+export interface TextRange {
+  start: number
+  end: number
+}
+
+export interface NormalizedText {
+  text: string
+  // position in the original source of every character of the text
+  offsets: number[]
+}
+
+/**
+ * Collapse whitespace in the source while remembering where every character came from
+ */
+export function normalizeWhitespace(source: string): NormalizedText {
+  const chars: string[] = []
+  const offsets: number[] = []
+  for (let i = 0; i < source.length; i++) {
+    if (/\s/.test(source[i])) {
+      if (chars.length > 0 && chars[chars.length - 1] !== ' ') {
+        chars.push(' ')
+        offsets.push(i)
+      }
+    } else {
+      chars.push(source[i])
+      offsets.push(i)
+    }
+  }
+  return { text: chars.join(''), offsets }
+}
+
+/**
+ * Find a chunk in normalized text, starting at the given index of the text.
+ * Returns the index and length of the match in the normalized text.
+ */
+export function findChunk(normalized: NormalizedText, chunk: string, from = 0): TextRange | null {
+  const needle = chunk.replace(/\s+/g, ' ').trim()
+  if (!needle) return null
+
+  const { text } = normalized
+  let index = text.indexOf(needle, from)
+  let length = needle.length
+  if (index < 0) {
+    // loaders may alter the text (e.g. markdown stripping), anchor on the beginning of the chunk instead
+    const prefix = needle.slice(0, Math.min(100, Math.ceil(needle.length / 2)))
+    index = text.indexOf(prefix, from)
+    if (index < 0) return null
+    length = Math.min(needle.length, text.length - index)
+  }
+  return { start: index, end: index + length }
+}
+
+function toSourceRange(normalized: NormalizedText, found: TextRange): TextRange {
+  return { start: normalized.offsets[found.start], end: normalized.offsets[found.end - 1] + 1 }
+}
+
+/**
+ * Find a chunk inside its source text, ignoring differences in whitespace introduced by the splitter.
+ * Returns character offsets into the original source.
+ */
+export function locateChunk(source: string, chunk: string): TextRange | null {
+  const normalized = normalizeWhitespace(source)
+  const found = findChunk(normalized, chunk)
+  return found && toSourceRange(normalized, found)
+}
+
+/**
+ * Locate the chunks of one source in the order the splitter produced them.
+ * The source is normalized once and every search starts where the previous chunk was found,
+ * falling back to the whole source for chunks that aren't in order.
+ */
+export function createChunkLocator(source: string): (chunk: string) => TextRange | null {
+  const normalized = normalizeWhitespace(source)
+  let cursor = 0
+  return (chunk) => {
+    const found = findChunk(normalized, chunk, cursor) ?? (cursor > 0 ? findChunk(normalized, chunk) : null)
+    if (!found) return null
+    // chunks overlap, the next one starts after the start of this one
+    cursor = found.start + 1
+    return toSourceRange(normalized, found)
+  }
+}
+
+/**
+ * 1-based, inclusive line numbers of a character range
+ */
+export function getLineRange(source: string, range: TextRange): { startLine: number; endLine: number } {
+  const countLines = (text: string) => text.split('\n').length
+  return {
+    startLine: countLines(source.slice(0, range.start)),
+    endLine: countLines(source.slice(0, range.end))
+  }
+}
+
+export function getByteRange(source: string, range: TextRange): TextRange {
+  const start = Buffer.byteLength(source.slice(0, range.start))
+  return { start, end: start + Buffer.byteLength(source.slice(range.start, range.end)) }
+}
+
+// utf-8 length of a utf-16 code unit, a surrogate pair adds up to 4 bytes
+const getUnitBytes = (code: number) => (code < 0x80 ? 1 : code < 0x800 || (code >= 0xd800 && code <= 0xdfff) ? 2 : 3)
+const BYTE_CHECKPOINT = 1024
+
+/**
+ * Line and byte lookups for many ranges of the same source, without slicing the source for every range
+ */
+export function createPositionIndex(source: string) {
+  const lineStarts = [0]
+  // utf-8 length of the source up to every BYTE_CHECKPOINT characters
+  const checkpoints = [0]
+  let bytes = 0
+  for (let i = 0; i < source.length; i++) {
+    if (source.charCodeAt(i) === 10) lineStarts.push(i + 1)
+    bytes += getUnitBytes(source.charCodeAt(i))
+    if ((i + 1) % BYTE_CHECKPOINT === 0) checkpoints.push(bytes)
+  }
+
+  // 1-based number of the line the offset is on, a newline belongs to the line it ends
+  const getLine = (offset: number) => {
+    let low = 0
+    let high = lineStarts.length - 1
+    while (low < high) {
+      const middle = Math.ceil((low + high) / 2)
+      if (lineStarts[middle] <= offset) low = middle
+      else high = middle - 1
+    }
+    return low + 1
+  }
+
+  const getByte = (offset: number) => {
+    const checkpoint = Math.floor(offset / BYTE_CHECKPOINT)
+    let result = checkpoints[checkpoint]
+    for (let i = checkpoint * BYTE_CHECKPOINT; i < offset; i++) result += getUnitBytes(source.charCodeAt(i))
+    return result
+  }
+
+  return {
+    getLineRange: (range: TextRange) => ({ startLine: getLine(range.start), endLine: getLine(range.end) }),
+    getByteRange: (range: TextRange): TextRange => ({ start: getByte(range.start), end: getByte(range.end) })
+  }
+}
@@ -0,0 +1,102 @@
+import { ClipboardDescriptor } from '@types'
+
+import { parseDelimited } from './tabular'
+
+export interface ClipboardContent {
+  text: string
+  image?: { width: number; height: number }
+}
+
+// tables copied from spreadsheets are tab separated, anything larger is pasted as text
+const MAX_TABLE_ROWS = 1000
+const URL_PATTERN = /^https?:\/\/\S+$/i
+
+// first match wins, more specific languages first
+const LANGUAGE_PATTERNS: [string, RegExp][] = [
+  ['html', /^\s*<(!doctype html|html|head|body|div|span|p|ul|table|template|script|style)\b/i],
+  ['sql', /^\s*(select\s[\s\S]+\sfrom\s|insert\s+into\s|update\s+\w+\s+set\s|create\s+table\s)/i],
+  ['shell', /^(#!\/|\$ )|^\s*(sudo|npm|yarn|pnpm|pip|git|cd|ls|curl|docker|brew|apt)\s/m],
+  ['rust', /\bfn\s+\w+\s*[(<]|\blet\s+mut\s|\bimpl\b.*\{|\buse\s+\w+::/],
+  ['go', /^package\s+\w+|\bfunc\s+(\(\w+\s+\*?\w+\)\s*)?\w+\(|:=/m],
+  ['python', /^\s*(def|class)\s+\w+.*:\s*$|^\s*(from\s+[\w.]+\s+)?import\s+[\w.]+(\s+as\s+\w+)?\s*$|\bself\./m],
+  ['java', /\b(public|private|protected)\s+(static\s+)?(class|void|[A-Z]\w*)\s/],
+  ['cpp', /^\s*#include\s*[<"]|\bstd::/m],
+  ['typescript', /:\s*(string|number|boolean|void)\b|\binterface\s+\w+\s*\{|\btype\s+\w+\s*=/],
+  ['javascript', /\b(const|let|var)\s+\w+\s*=|=>|\bfunction\s*\w*\s*\(|\bimport\s.+\sfrom\s|require\(/],
+  ['css', /^[\w.#:\-\s,>[\]="]+\{\s*$[\s\S]*?^\s*[\w-]+\s*:\s*[^;]+;/m]
+]
+
+// lines that look like code rather than prose
+const CODE_LINE = /[;{}]\s*$|^\s*[}\])]|^\s{2,}\S|^\s*(\/\/|#|--|\/\*)|=>|::|\w+\([^)]*\)\s*[;{:]?\s*$/
+
+/**
+ * Rows of tab separated text, undefined unless every row has the same number of cells and there are at least two
+ */
+export function parseTable(text: string): string[][] | undefined {
+  if (!text.includes('\t')) return undefined
+  const rows = parseDelimited(text.replace(/\n+$/, ''), '\t')
+  if (rows.length < 2 || rows.length > MAX_TABLE_ROWS) return undefined
+  const width = rows[0].length
+  return width >= 2 && rows.every((row) => row.length === width) ? rows : undefined
+}
+
+/**
+ * Guess the language of code, undefined when nothing matches
+ */
+export function guessLanguage(code: string): string | undefined {
+  const trimmed = code.trim()
+  if (/^[[{]/.test(trimmed)) {
+    try {
+      JSON.parse(trimmed)
+      return 'json'
+    } catch {
+      // not json, keep guessing
+    }
+  }
+  return LANGUAGE_PATTERNS.find(([, pattern]) => pattern.test(code))?.[0]
+}
+
+/**
+ * Whether text is code: most of its lines look like code, or some do and a language is recognized in it
+ */
+export function isCode(text: string): boolean {
+  const lines = text.split('\n').filter((line) => line.trim())
+  if (lines.length === 0) return false
+  // a single line is too short to tell by its shape, e.g. `npm install react`
+  if (lines.length === 1) return guessLanguage(text) !== undefined
+  const codeLines = lines.filter((line) => CODE_LINE.test(line)).length
+  return codeLines / lines.length >= 0.5 || (codeLines > 0 && guessLanguage(text) !== undefined)
+}
+
+/**
+ * Decide what the clipboard holds. Text wins over images, applications copying text often add a rendered image.
+ */
+export function classifyClipboard(content: ClipboardContent): ClipboardDescriptor {
+  const text = content.text.replace(/\r\n?/g, '\n')
+
+  if (!text.trim()) {
+    return content.image
+      ? { kind: 'image', width: content.image.width, height: content.image.height }
+      : { kind: 'empty' }
+  }
+
+  const trimmed = text.trim()
+  if (URL_PATTERN.test(trimmed)) {
+    try {
+      return { kind: 'url', text: new URL(trimmed).toString() }
+    } catch {
+      // not a valid url, classified as text below
+    }
+  }
+
+  const rows = parseTable(text)
+  if (rows) {
+    return { kind: 'table', text, rows }
+  }
+
+  if (isCode(text)) {
+    return { kind: 'code', text, language: guessLanguage(text) }
+  }
+
+  return { kind: 'text', text }
+}
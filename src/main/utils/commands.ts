@@ -0,0 +1,96 @@
+import { CommandArgSchema, CommandInfo } from '@types'
+
+export const COMMAND_ID_REGEX = /^[a-z0-9][a-z0-9-]*(\.[a-z0-9][a-z0-9-]*)+$/
+
+export interface CommandArgsResult {
+  values: Record<string, unknown>
+  errors: string[]
+}
+
+function coerce(schema: CommandArgSchema, value: unknown): { value?: unknown; error?: string } {
+  // the CLI and query strings pass every value as a string
+  switch (schema.type) {
+    case 'number': {
+      const number = typeof value === 'string' && value.trim() ? Number(value) : value
+      if (typeof number !== 'number' || !Number.isFinite(number)) {
+        return { error: `\`${schema.name}\` must be a number` }
+      }
+      return { value: number }
+    }
+    case 'boolean':
+      if (value === 'true' || value === '') return { value: true }
+      if (value === 'false') return { value: false }
+      if (typeof value !== 'boolean') return { error: `\`${schema.name}\` must be true or false` }
+      return { value }
+    default:
+      if (typeof value !== 'string') return { error: `\`${schema.name}\` must be a string` }
+      if (schema.enum && !schema.enum.includes(value)) {
+        return { error: `\`${schema.name}\` must be one of ${schema.enum.join(', ')}` }
+      }
+      return { value }
+  }
+}
+
+/**
+ * Check the arguments of an invocation against the command schema. Unknown arguments are errors so a typo doesn't
+ * silently run the command with defaults.
+ */
+export function validateCommandArgs(schema: CommandArgSchema[], input: unknown): CommandArgsResult {
+  const values: Record<string, unknown> = {}
+  const errors: string[] = []
+
+  if (input !== undefined && input !== null && (typeof input !== 'object' || Array.isArray(input))) {
+    return { values, errors: ['Arguments must be an object'] }
+  }
+  const args = (input ?? {}) as Record<string, unknown>
+
+  for (const name of Object.keys(args)) {
+    if (!schema.some((item) => item.name === name)) errors.push(`Unknown argument \`${name}\``)
+  }
+  for (const item of schema) {
+    const value = args[item.name]
+    if (value === undefined || value === null) {
+      if (item.required) errors.push(`\`${item.name}\` is required`)
+      continue
+    }
+    const result = coerce(item, value)
+    if (result.error) {
+      errors.push(result.error)
+    } else {
+      values[item.name] = result.value
+    }
+  }
+  return { values, errors }
+}
+
+function matchScore(command: CommandInfo, terms: string[]): number {
+  const id = command.id.toLowerCase()
+  const title = command.title.toLowerCase()
+  const text = [id, title, command.category.toLowerCase(), command.description?.toLowerCase() ?? ''].join(' ')
+  if (!terms.every((term) => text.includes(term))) return 0
+
+  const query = terms.join(' ')
+  if (id === query) return 4
+  if (title.startsWith(query) || id.startsWith(query)) return 3
+  if (terms.every((term) => title.includes(term) || id.includes(term))) return 2
+  return 1
+}
+
+/**
+ * Commands matching every word of the filter, best matches first: the exact id, then titles or ids starting with the
+ * filter, then matches in the title or id, then matches in the description. Without a filter all commands are
+ * returned by category and title.
+ */
+export function filterCommands(commands: CommandInfo[], filter?: string): CommandInfo[] {
+  const terms = (filter ?? '').toLowerCase().split(/\s+/).filter(Boolean)
+  return commands
+    .map((command) => ({ command, score: terms.length ? matchScore(command, terms) : 1 }))
+    .filter(({ score }) => score > 0)
+    .sort(
+      (a, b) =>
+        b.score - a.score ||
+        a.command.category.localeCompare(b.command.category) ||
+        a.command.title.localeCompare(b.command.title)
+    )
+    .map(({ command }) => command)
+}
@@ -0,0 +1,24 @@
+import { CoordinateSpace } from '@types'
+import type { Rectangle } from 'electron'
+
+/**
+ * Convert a rect to logical pixels, the space Electron takes bounds and capture regions in
+ */
+export function toLogicalRect(rect: Rectangle, space: CoordinateSpace, scaleFactor: number): Rectangle {
+  if (space === 'logical') return rect
+  const x = Math.floor(rect.x / scaleFactor)
+  const y = Math.floor(rect.y / scaleFactor)
+  return {
+    x,
+    y,
+    width: Math.ceil((rect.x + rect.width) / scaleFactor) - x,
+    height: Math.ceil((rect.y + rect.height) / scaleFactor) - y
+  }
+}
+
+/**
+ * Length in logical pixels of a length given in the space
+ */
+export function toLogicalLength(length: number, space: CoordinateSpace, scaleFactor: number): number {
+  return space === 'logical' ? length : length / scaleFactor
+}
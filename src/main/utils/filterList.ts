@@ -0,0 +1,249 @@
+// request types of filter options, keyed by the resource type electron reports
+const RESOURCE_TYPES: Record<string, string> = {
+  mainFrame: 'document',
+  subFrame: 'subdocument',
+  stylesheet: 'stylesheet',
+  script: 'script',
+  image: 'image',
+  font: 'font',
+  object: 'object',
+  xhr: 'xmlhttprequest',
+  ping: 'ping',
+  cspReport: 'other',
+  media: 'media',
+  webSocket: 'websocket',
+  other: 'other'
+}
+
+const TYPE_ALIASES: Record<string, string> = {
+  css: 'stylesheet',
+  frame: 'subdocument',
+  xhr: 'xmlhttprequest',
+  beacon: 'ping'
+}
+const TYPES = new Set(Object.values(RESOURCE_TYPES))
+// options that don't change whether a request is blocked
+const IGNORED_OPTIONS = new Set(['important', 'match-case', 'all', 'popunder'])
+const TOKEN_PATTERN = /[a-z0-9%]{2,}/g
+
+export interface FilterRule {
+  exception: boolean
+  pattern: RegExp
+  // longest token every matching url contains, rules without one are checked for every request
+  token?: string
+  thirdParty?: boolean
+  types?: string[]
+  excludedTypes?: string[]
+  domains?: string[]
+  excludedDomains?: string[]
+}
+
+export interface FilterRequest {
+  url: string
+  // page the request was made by, requests without one count as first party
+  pageUrl?: string
+  // resource type reported by electron
+  resourceType: string
+}
+
+function getHost(url?: string): string {
+  if (!url) return ''
+  try {
+    return new URL(url).hostname.toLowerCase()
+  } catch {
+    return ''
+  }
+}
+
+/**
+ * Registrable part of a host, a heuristic in place of the public suffix list: `a.b.co.uk` gives `b.co.uk`
+ */
+export function getBaseDomain(host: string): string {
+  const labels = host.split('.')
+  if (labels.length <= 2 || /^\d+$/.test(labels[labels.length - 1])) return host
+  const count = labels[labels.length - 2].length <= 3 && labels[labels.length - 1].length <= 3 ? 3 : 2
+  return labels.slice(-count).join('.')
+}
+
+function isOnDomain(host: string, domains: string[]): boolean {
+  return domains.some((domain) => host === domain || host.endsWith(`.${domain}`))
+}
+
+function escapeRegExp(text: string): string {
+  return text.replace(/[.+?${}()[\]\\/|]/g, '\\$&')
+}
+
+function compilePattern(body: string, matchCase: boolean): RegExp | undefined {
+  const flags = matchCase ? '' : 'i'
+  if (body.length > 2 && body.startsWith('/') && body.endsWith('/')) {
+    try {
+      return new RegExp(body.slice(1, -1), flags)
+    } catch {
+      return undefined
+    }
+  }
+
+  let source = body
+  let prefix = ''
+  let suffix = ''
+  if (source.startsWith('||')) {
+    prefix = '^[a-z][a-z0-9+.-]*:\\/+(?:[^/?#]*\\.)?'
+    source = source.slice(2)
+  } else if (source.startsWith('|')) {
+    prefix = '^'
+    source = source.slice(1)
+  }
+  if (source.endsWith('|')) {
+    suffix = '$'
+    source = source.slice(0, -1)
+  }
+
+  const pattern = source
+    .split('*')
+    .map((part) => escapeRegExp(part).replace(/\^/g, '(?:[^\\w.%-]|$)'))
+    .join('.*')
+  return new RegExp(prefix + pattern + suffix, flags)
+}
+
+/**
+ * A token of the pattern every matching url contains as a whole word, so only rules whose token appears in the
+ * url have to be tested
+ */
+function findToken(body: string): string | undefined {
+  if (body.startsWith('/') && body.endsWith('/')) return undefined
+
+  const anchoredStart = body.startsWith('|')
+  const anchoredEnd = body.endsWith('|')
+  const text = body.replace(/^\|{1,2}/, '').replace(/\|$/, '').toLowerCase()
+
+  let token: string | undefined
+  for (const match of text.matchAll(TOKEN_PATTERN)) {
+    const start = match.index ?? 0
+    const end = start + match[0].length
+    const bounded =
+      (start > 0 ? text[start - 1] !== '*' : anchoredStart) && (end < text.length ? text[end] !== '*' : anchoredEnd)
+    if (bounded && (!token || match[0].length > token.length)) token = match[0]
+  }
+  return token
+}
+
+/**
+ * Parse one line of an EasyList style filter list, undefined for comments, element hiding rules and network
+ * rules with options this engine does not support
+ */
+export function parseFilter(line: string): FilterRule | undefined {
+  let text = line.trim()
+  if (!text || text.startsWith('!') || text.startsWith('[')) return undefined
+  if (/#[@?$%]?#|#@[$?]#/.test(text)) return undefined
+
+  const exception = text.startsWith('@@')
+  if (exception) text = text.slice(2)
+
+  const rule: Omit<FilterRule, 'pattern'> = { exception }
+  let matchCase = false
+  const optionsStart = text.lastIndexOf('$')
+  if (optionsStart > 0 && !text.endsWith('/')) {
+    const options = text.slice(optionsStart + 1).split(',')
+    text = text.slice(0, optionsStart)
+
+    for (const raw of options) {
+      const option = raw.trim().toLowerCase()
+      const negated = option.startsWith('~')
+      const name = negated ? option.slice(1) : option
+      const type = TYPE_ALIASES[name] ?? name
+
+      if (name === 'third-party' || name === '3p') {
+        rule.thirdParty = !negated
+      } else if (name === 'first-party' || name === '1p') {
+        rule.thirdParty = negated
+      } else if (name.startsWith('domain=')) {
+        const domains = name.slice(7).split('|').filter(Boolean)
+        rule.domains = domains.filter((domain) => !domain.startsWith('~'))
+        rule.excludedDomains = domains.filter((domain) => domain.startsWith('~')).map((domain) => domain.slice(1))
+      } else if (TYPES.has(type) && type !== 'document') {
+        if (negated) rule.excludedTypes = [...(rule.excludedTypes ?? []), type]
+        else rule.types = [...(rule.types ?? []), type]
+      } else if (name === 'match-case') {
+        matchCase = true
+      } else if (!IGNORED_OPTIONS.has(name)) {
+        // document, csp, redirect and the like change more than whether a request is blocked
+        return undefined
+      }
+    }
+  }
+
+  if (!text || text === '*' || text === '|' || text === '||') return undefined
+  const pattern = compilePattern(text, matchCase)
+  if (!pattern) return undefined
+
+  if (rule.domains?.length === 0) delete rule.domains
+  if (rule.excludedDomains?.length === 0) delete rule.excludedDomains
+  return { ...rule, pattern, token: findToken(text) }
+}
+
+export function parseFilterList(text: string): FilterRule[] {
+  return text.split(/\r?\n/).flatMap((line) => parseFilter(line) ?? [])
+}
+
+class RuleIndex {
+  private byToken = new Map<string, FilterRule[]>()
+  private generic: FilterRule[] = []
+
+  public add(rule: FilterRule) {
+    if (!rule.token) {
+      this.generic.push(rule)
+      return
+    }
+    const rules = this.byToken.get(rule.token)
+    if (rules) rules.push(rule)
+    else this.byToken.set(rule.token, [rule])
+  }
+
+  public find(tokens: string[], test: (rule: FilterRule) => boolean): FilterRule | undefined {
+    for (const token of tokens) {
+      const rule = this.byToken.get(token)?.find(test)
+      if (rule) return rule
+    }
+    return this.generic.find(test)
+  }
+}
+
+/**
+ * Network rules of EasyList style filter lists, decides which requests of a page are blocked.
+ * Top level documents are never blocked, exception rules (`@@`) win over blocking rules.
+ */
+export class FilterEngine {
+  public readonly size: number
+  private block = new RuleIndex()
+  private allow = new RuleIndex()
+
+  constructor(rules: FilterRule[]) {
+    rules.forEach((rule) => (rule.exception ? this.allow : this.block).add(rule))
+    this.size = rules.length
+  }
+
+  /**
+   * The blocking rule that applies to the request, undefined when it may load
+   */
+  public match(request: FilterRequest): FilterRule | undefined {
+    const type = RESOURCE_TYPES[request.resourceType] ?? 'other'
+    if (type === 'document' || !/^(https?|wss?):/i.test(request.url)) return undefined
+
+    const host = getHost(request.url)
+    const pageHost = getHost(request.pageUrl)
+    const thirdParty = !!pageHost && getBaseDomain(host) !== getBaseDomain(pageHost)
+    const tokens = [...new Set(request.url.toLowerCase().match(TOKEN_PATTERN) ?? [])]
+
+    const test = (rule: FilterRule) =>
+      (rule.thirdParty === undefined || rule.thirdParty === thirdParty) &&
+      (!rule.types || rule.types.includes(type)) &&
+      (!rule.excludedTypes || !rule.excludedTypes.includes(type)) &&
+      (!rule.domains || isOnDomain(pageHost, rule.domains)) &&
+      (!rule.excludedDomains || !isOnDomain(pageHost, rule.excludedDomains)) &&
+      rule.pattern.test(request.url)
+
+    const rule = this.block.find(tokens, test)
+    if (!rule || this.allow.find(tokens, test)) return undefined
+    return rule
+  }
+}
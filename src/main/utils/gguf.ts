@@ -0,0 +1,36 @@
+/**
+ * What a GGUF model is used for, read from the metadata in the file header: chat models generate text,
+ * embedding models pool their output into a vector, rerankers score a query against a document
+ */
+export type GgufModelKind = 'chat' | 'embedding' | 'rerank'
+
+export interface GgufModelInfo {
+  kind: GgufModelKind
+  // size of the vectors of an embedding model
+  embeddingLength?: number
+}
+
+// llama_pooling_type in llama.cpp, anything but none produces a single vector per sequence
+const POOLING_TYPE_NONE = 0
+const POOLING_TYPE_RANK = 4
+
+// encoder-only architectures that can't generate text even when the file doesn't declare a pooling type
+const EMBEDDING_ARCHITECTURES = ['bert', 'nomic-bert', 'nomic-bert-moe', 'jina-bert-v2', 'modern-bert', 't5encoder']
+
+export function getGgufModelInfo(metadata: Record<string, any>): GgufModelInfo {
+  const architecture: string | undefined = metadata?.general?.architecture
+  const params = (architecture && metadata[architecture]) || {}
+  const poolingType: number | undefined = params.pooling_type
+  const embeddingLength = typeof params.embedding_length === 'number' ? params.embedding_length : undefined
+
+  if (poolingType === POOLING_TYPE_RANK) {
+    return { kind: 'rerank' }
+  }
+  if (
+    (poolingType !== undefined && poolingType !== POOLING_TYPE_NONE) ||
+    (architecture && EMBEDDING_ARCHITECTURES.includes(architecture))
+  ) {
+    return { kind: 'embedding', embeddingLength }
+  }
+  return { kind: 'chat' }
+}
@@ -0,0 +1,62 @@
+import { auditLog } from '@main/services/AuditLog'
+import { capabilityManager } from '@main/services/CapabilityManager'
+import { ManagerError } from '@shared/ManagerError'
+import { ipcMain, IpcMainEvent, IpcMainInvokeEvent } from 'electron'
+import Logger from 'electron-log'
+
+type IpcHandler<Args extends any[], Result> = (
+  event: IpcMainInvokeEvent,
+  ...args: Args
+) => Result | Promise<Result>
+
+/**
+ * Check the sender's capability and record privileged calls in the audit log
+ */
+function authorize(event: IpcMainEvent | IpcMainInvokeEvent, channel: string, args: unknown[]) {
+  auditLog.record(event.sender, channel, args, capabilityManager.isAllowed(event.sender, channel))
+  capabilityManager.assert(event.sender, channel)
+}
+
+/**
+ * Register an IPC handler that only runs when the sender holds the channel's capability
+ */
+export function handleIpc<Args extends any[], Result>(channel: string, handler: IpcHandler<Args, Result>) {
+  ipcMain.handle(channel, (event, ...args) => {
+    authorize(event, channel, args)
+    return handler(event, ...(args as Args))
+  })
+}
+
+/**
+ * Register a listener for a channel the renderer sends to without waiting for a reply, messages of senders without
+ * the channel's capability are dropped
+ */
+export function onIpc<Args extends any[]>(channel: string, listener: (event: IpcMainEvent, ...args: Args) => void) {
+  ipcMain.on(channel, (event, ...args) => {
+    try {
+      authorize(event, channel, args)
+    } catch {
+      return
+    }
+    listener(event, ...(args as Args))
+  })
+}
+
+/**
+ * Create an IPC handler registrar for a manager, failures reach the renderer as a serialized `ManagerError`
+ * instead of a plain message string
+ */
+export function createManagerHandler(manager: string) {
+  return <Args extends any[], Result>(channel: string, handler: IpcHandler<Args, Result>) => {
+    ipcMain.handle(channel, async (event, ...args) => {
+      try {
+        authorize(event, channel, args)
+        return await handler(event, ...(args as Args))
+      } catch (error) {
+        const managerError = ManagerError.from(manager, error)
+        Logger.error(`[${manager}] ${channel} failed (${managerError.code}):`, managerError.message)
+        throw managerError.toIpcError()
+      }
+    })
+  }
+}
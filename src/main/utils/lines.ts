@@ -0,0 +1,114 @@
+import fs from 'node:fs'
+
+// the start offset of every this many lines is kept, reads scan forward from the nearest one
+const CHECKPOINT_INTERVAL = 1000
+const CHUNK_SIZE = 64 * 1024
+// longer lines are cut, e.g. minified files that are a single line
+const MAX_LINE_BYTES = 16 * 1024
+const NEWLINE = 0x0a
+
+export interface LineIndex {
+  size: number
+  mtimeMs: number
+  lineCount: number
+  // byte offset of line `i * CHECKPOINT_INTERVAL`
+  checkpoints: number[]
+}
+
+export interface LineRange {
+  lines: string[]
+  // some lines were longer than the limit and were cut
+  truncated: boolean
+}
+
+/**
+ * Count the lines of a file in one pass without loading it, a last line without newline counts as a line
+ */
+export async function indexLines(filePath: string): Promise<LineIndex> {
+  const stat = await fs.promises.stat(filePath)
+  const checkpoints = [0]
+  let lineCount = 0
+  let offset = 0
+  let lastByte = NEWLINE
+
+  for await (const chunk of fs.createReadStream(filePath, { highWaterMark: CHUNK_SIZE }) as AsyncIterable<Buffer>) {
+    let index = chunk.indexOf(NEWLINE)
+    while (index !== -1) {
+      lineCount++
+      if (lineCount % CHECKPOINT_INTERVAL === 0) checkpoints.push(offset + index + 1)
+      index = chunk.indexOf(NEWLINE, index + 1)
+    }
+    offset += chunk.length
+    lastByte = chunk[chunk.length - 1]
+  }
+  if (offset > 0 && lastByte !== NEWLINE) lineCount++
+
+  return { size: offset, mtimeMs: stat.mtimeMs, lineCount, checkpoints }
+}
+
+/**
+ * Read `count` lines from `startLine` (0 based), only the bytes from the nearest checkpoint on are read
+ */
+export async function readLines(
+  filePath: string,
+  index: LineIndex,
+  startLine: number,
+  count: number
+): Promise<LineRange> {
+  const result: LineRange = { lines: [], truncated: false }
+  if (startLine >= index.lineCount || count <= 0) return result
+
+  const checkpoint = Math.floor(startLine / CHECKPOINT_INTERVAL)
+  let skip = startLine - checkpoint * CHECKPOINT_INTERVAL
+  let position = index.checkpoints[checkpoint]
+  let line: Buffer[] = []
+  let lineBytes = 0
+
+  const pushLine = () => {
+    const text = Buffer.concat(line).toString('utf-8')
+    result.lines.push(text.endsWith('\r') ? text.slice(0, -1) : text)
+    line = []
+    lineBytes = 0
+  }
+  const append = (part: Buffer) => {
+    const room = MAX_LINE_BYTES - lineBytes
+    if (part.length > room) result.truncated = true
+    if (room > 0) {
+      line.push(Buffer.from(part.subarray(0, room)))
+      lineBytes += Math.min(room, part.length)
+    }
+  }
+
+  const handle = await fs.promises.open(filePath, 'r')
+  try {
+    const buffer = Buffer.alloc(CHUNK_SIZE)
+    while (result.lines.length < count && position < index.size) {
+      const { bytesRead } = await handle.read(buffer, 0, CHUNK_SIZE, position)
+      if (bytesRead === 0) break
+      const chunk = buffer.subarray(0, bytesRead)
+      position += bytesRead
+
+      let start = 0
+      while (start < chunk.length && result.lines.length < count) {
+        const end = chunk.indexOf(NEWLINE, start)
+        if (end === -1) {
+          if (skip === 0) append(chunk.subarray(start))
+          break
+        }
+        if (skip > 0) {
+          skip--
+        } else {
+          append(chunk.subarray(start, end))
+          pushLine()
+        }
+        start = end + 1
+      }
+    }
+    // the last line of a file without a trailing newline
+    if (result.lines.length < count && skip === 0 && lineBytes > 0) pushLine()
+  } finally {
+    await handle.close()
+  }
+
+  return result
+}
@@ -0,0 +1,126 @@
+import net from 'node:net'
+
+export interface LinkMetadata {
+  title?: string
+  description?: string
+  siteName?: string
+  // absolute urls
+  image?: string
+  favicon?: string
+}
+
+// longer texts are cut, cards only show a few lines
+const MAX_TITLE_LENGTH = 200
+const MAX_DESCRIPTION_LENGTH = 500
+
+// loopback, link-local, private, shared, multicast and reserved ranges, previews never load from them
+const PRIVATE_IPV4_RANGES: [string, number][] = [
+  ['0.0.0.0', 8],
+  ['10.0.0.0', 8],
+  ['100.64.0.0', 10],
+  ['127.0.0.0', 8],
+  ['169.254.0.0', 16],
+  ['172.16.0.0', 12],
+  ['192.0.0.0', 24],
+  ['192.168.0.0', 16],
+  ['198.18.0.0', 15],
+  ['224.0.0.0', 3]
+]
+// mapped and translated ipv4 addresses are refused as a whole
+const PRIVATE_IPV6_RANGES: [string, number][] = [
+  ['::', 127],
+  ['::ffff:0:0', 96],
+  ['64:ff9b::', 96],
+  ['fc00::', 7],
+  ['fe80::', 10],
+  ['ff00::', 8]
+]
+
+const PRIVATE_RANGES = new net.BlockList()
+PRIVATE_IPV4_RANGES.forEach(([address, prefix]) => PRIVATE_RANGES.addSubnet(address, prefix, 'ipv4'))
+PRIVATE_IPV6_RANGES.forEach(([address, prefix]) => PRIVATE_RANGES.addSubnet(address, prefix, 'ipv6'))
+
+/**
+ * The address is not on the public internet, e.g. 127.0.0.1, 192.168.1.1 or fe80::1
+ */
+export function isPrivateAddress(address: string): boolean {
+  const family = net.isIP(address)
+  if (family === 0) return true
+  return PRIVATE_RANGES.check(address, family === 4 ? 'ipv4' : 'ipv6')
+}
+
+const ENTITIES: Record<string, string> = { amp: '&', lt: '<', gt: '>', quot: '"', apos: "'", nbsp: ' ' }
+
+export function decodeEntities(text: string): string {
+  return text.replace(/&(#x[0-9a-f]+|#\d+|[a-z]+);/gi, (entity, code: string) => {
+    if (code[0] === '#') {
+      const value = code[1] === 'x' || code[1] === 'X' ? parseInt(code.slice(2), 16) : parseInt(code.slice(1), 10)
+      // fromCodePoint throws past the last code point
+      return Number.isNaN(value) || value > 0x10ffff ? entity : String.fromCodePoint(value)
+    }
+    return ENTITIES[code.toLowerCase()] ?? entity
+  })
+}
+
+function parseAttributes(tag: string): Record<string, string> {
+  const attributes: Record<string, string> = {}
+  for (const match of tag.matchAll(/([\w:-]+)\s*=\s*("([^"]*)"|'([^']*)'|([^\s>]+))/g)) {
+    attributes[match[1].toLowerCase()] = decodeEntities(match[3] ?? match[4] ?? match[5] ?? '')
+  }
+  return attributes
+}
+
+function clean(text: string | undefined, maxLength: number): string | undefined {
+  const value = text?.replace(/\s+/g, ' ').trim()
+  if (!value) return undefined
+  return value.length > maxLength ? value.slice(0, maxLength - 1) + '…' : value
+}
+
+function resolve(url: string | undefined, base: string): string | undefined {
+  if (!url) return undefined
+  try {
+    const resolved = new URL(url.trim(), base)
+    return ['http:', 'https:'].includes(resolved.protocol) ? resolved.toString() : undefined
+  } catch {
+    return undefined
+  }
+}
+
+/**
+ * Title, description, OpenGraph image and favicon of a page, OpenGraph and Twitter tags win over plain html.
+ * Only the head is read, urls are resolved against the page url.
+ */
+export function parseLinkMetadata(html: string, pageUrl: string): LinkMetadata {
+  const headEnd = html.search(/<\/head>|<body[\s>]/i)
+  const head = headEnd === -1 ? html : html.slice(0, headEnd)
+
+  const meta: Record<string, string> = {}
+  for (const [tag] of head.matchAll(/<meta\s[^>]*>/gi)) {
+    const attributes = parseAttributes(tag)
+    const key = (attributes.property ?? attributes.name)?.toLowerCase()
+    if (key && attributes.content !== undefined && !(key in meta)) meta[key] = attributes.content
+  }
+
+  let favicon: string | undefined
+  for (const [tag] of head.matchAll(/<link\s[^>]*>/gi)) {
+    const attributes = parseAttributes(tag)
+    const rel = attributes.rel?.toLowerCase().split(/\s+/) ?? []
+    if (rel.includes('icon') && attributes.href) {
+      favicon = attributes.href
+      break
+    }
+  }
+
+  const title = head.match(/<title[^>]*>([\s\S]*?)<\/title>/i)?.[1]
+
+  return {
+    title: clean(meta['og:title'] ?? meta['twitter:title'] ?? (title && decodeEntities(title)), MAX_TITLE_LENGTH),
+    description: clean(
+      meta['og:description'] ?? meta['twitter:description'] ?? meta['description'],
+      MAX_DESCRIPTION_LENGTH
+    ),
+    siteName: clean(meta['og:site_name'], MAX_TITLE_LENGTH),
+    image: resolve(meta['og:image'] ?? meta['og:image:url'] ?? meta['twitter:image'], pageUrl),
+    favicon: resolve(favicon ?? '/favicon.ico', pageUrl)
+  }
+}
@@ -0,0 +1,111 @@
+import path from 'node:path'
+
+import { MCPPermission, MCPRegistryEntry, MCPRuntime } from '@types'
+
+const RUNTIMES: MCPRuntime[] = ['node', 'python']
+const ID_PATTERN = /^[a-z0-9][a-z0-9._-]*$/i
+// Strict semver, the version ends up in the install path and on the bun/uv command line
+const VERSION_PATTERN =
+  /^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)(?:-[0-9a-z-]+(?:\.[0-9a-z-]+)*)?(?:\+[0-9a-z-]+(?:\.[0-9a-z-]+)*)?$/i
+const PACKAGE_PATTERNS: Record<MCPRuntime, RegExp> = {
+  // npm: lowercase, optionally scoped, can't start with a dot, underscore or dash
+  node: /^(?:@[a-z0-9][a-z0-9._~-]*\/)?[a-z0-9][a-z0-9._~-]*$/,
+  // PyPI: letters, digits, `.`, `_` and `-`, starting and ending with a letter or digit, with optional extras
+  python: /^[a-z0-9](?:[a-z0-9._-]*[a-z0-9])?(?:\[[a-z0-9._-]+(?:,[a-z0-9._-]+)*\])?$/i
+}
+
+function isStringArray(value: unknown): value is string[] {
+  return Array.isArray(value) && value.every((item) => typeof item === 'string')
+}
+
+/**
+ * Entries of a registry document, `{ servers: [...] }` or a bare array. Malformed entries are skipped so one bad
+ * entry doesn't hide the whole marketplace.
+ */
+export function parseRegistry(data: unknown): MCPRegistryEntry[] {
+  const servers = Array.isArray(data) ? data : (data as { servers?: unknown })?.servers
+  if (!Array.isArray(servers)) return []
+
+  return servers.flatMap((item): MCPRegistryEntry[] => {
+    if (!item || typeof item !== 'object') return []
+    const { id, name, description, package: pkg, version, runtime, bin, args, env, permissions, homepage, tags } = item
+    if (typeof id !== 'string' || !ID_PATTERN.test(id)) return []
+    if (!RUNTIMES.includes(runtime)) return []
+    if (!isValidPackage(runtime, pkg) || !isValidVersion(version)) return []
+    if (bin && (typeof bin !== 'string' || !ID_PATTERN.test(bin))) return []
+
+    return [
+      {
+        id,
+        name: typeof name === 'string' && name ? name : id,
+        description: typeof description === 'string' ? description : '',
+        package: pkg,
+        version,
+        runtime,
+        bin: bin || undefined,
+        args: isStringArray(args) ? args : undefined,
+        env: env && typeof env === 'object' && !Array.isArray(env) ? env : undefined,
+        permissions: isStringArray(permissions) ? (permissions as MCPPermission[]) : [],
+        homepage: typeof homepage === 'string' ? homepage : undefined,
+        tags: isStringArray(tags) ? tags : undefined
+      }
+    ]
+  })
+}
+
+export function isValidVersion(version: unknown): version is string {
+  return typeof version === 'string' && VERSION_PATTERN.test(version)
+}
+
+export function isValidPackage(runtime: MCPRuntime, pkg: unknown): pkg is string {
+  return typeof pkg === 'string' && pkg.length <= 214 && PACKAGE_PATTERNS[runtime].test(pkg)
+}
+
+/**
+ * Compare dotted versions numerically, pre-release suffixes sort before the release: 1.2.0-beta < 1.2.0 < 1.10.0
+ */
+export function compareVersions(a: string, b: string): number {
+  const parse = (version: string) => {
+    const [main, pre] = version.replace(/^v/, '').split('-', 2)
+    return { parts: main.split('.').map((part) => parseInt(part, 10) || 0), pre }
+  }
+  const left = parse(a)
+  const right = parse(b)
+
+  for (let i = 0; i < Math.max(left.parts.length, right.parts.length); i++) {
+    const diff = (left.parts[i] ?? 0) - (right.parts[i] ?? 0)
+    if (diff !== 0) return diff > 0 ? 1 : -1
+  }
+  if (left.pre === right.pre) return 0
+  if (!left.pre) return 1
+  if (!right.pre) return -1
+  return left.pre > right.pre ? 1 : -1
+}
+
+export function diffPermissions(from: MCPPermission[], to: MCPPermission[]) {
+  return {
+    added: to.filter((permission) => !from.includes(permission)),
+    removed: from.filter((permission) => !to.includes(permission))
+  }
+}
+
+/**
+ * Executable name of an entry, the package name without its scope and, for python, without extras
+ */
+export function getEntryBin(entry: MCPRegistryEntry): string {
+  return entry.bin ?? entry.package.split('/').pop()!.replace(/\[.*\]$/, '')
+}
+
+/**
+ * Script of a node package run for the executable, from the `bin` field of its package.json
+ */
+export function resolveNodeBin(packageDir: string, manifest: { name?: string; bin?: unknown }, bin: string) {
+  let file: unknown
+  if (typeof manifest.bin === 'string') {
+    file = manifest.bin
+  } else if (manifest.bin && typeof manifest.bin === 'object') {
+    const bins = manifest.bin as Record<string, string>
+    file = bins[bin] ?? (Object.keys(bins).length === 1 ? Object.values(bins)[0] : undefined)
+  }
+  return typeof file === 'string' ? path.join(packageDir, file) : undefined
+}
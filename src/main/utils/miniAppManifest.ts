@@ -0,0 +1,154 @@
+import os from 'node:os'
+import path from 'node:path'
+import { pathToFileURL } from 'node:url'
+
+import { MinAppType, MiniAppPermissionManifest, WebviewContextMenuAction } from '@types'
+
+import { isHostPattern } from './miniAppPermissions'
+
+export const MANIFEST_FILE = 'manifest.json'
+
+const ID_PATTERN = /^[a-z0-9][a-z0-9._-]*$/i
+const CONTEXT_MENU_ACTIONS: WebviewContextMenuAction[] = ['ask', 'save_image', 'copy_link', 'open_external']
+
+export type ManifestResult = { app: MinAppType; logoFile?: string; updateUrl?: string } | { error: string }
+
+const isWebUrl = (value: string) => /^https?:\/\//i.test(value)
+
+/**
+ * Absolute path of a file the manifest refers to, undefined when it points outside the mini app's folder
+ */
+export function resolveInside(dir: string, file: string): string | undefined {
+  const root = path.resolve(dir)
+  const resolved = path.resolve(root, file)
+  return resolved.startsWith(root + path.sep) ? resolved : undefined
+}
+
+/**
+ * Absolute path of a folder the manifest asks access to: absolute, under the home folder with `~/`, or the mini app's
+ * folder and folders inside it. The root of a drive is refused.
+ */
+function resolveFolder(dir: string, folder: string): string | undefined {
+  let resolved: string | undefined
+  if (folder === '~' || folder.startsWith('~/')) {
+    resolved = path.join(os.homedir(), folder.slice(1))
+  } else if (path.isAbsolute(folder)) {
+    resolved = path.resolve(folder)
+  } else {
+    const root = path.resolve(dir)
+    resolved = path.resolve(root, folder) === root ? root : resolveInside(dir, folder)
+  }
+  return resolved && path.parse(resolved).root !== resolved ? resolved : undefined
+}
+
+function parsePermissions(data: unknown, dir: string): MiniAppPermissionManifest | { error: string } {
+  if (!data || typeof data !== 'object' || Array.isArray(data)) return { error: '`permissions` must be an object' }
+  const { filesystem, network, notifications, clipboard, sharedState } = data as Record<string, unknown>
+  const isList = (value: unknown): value is string[] =>
+    Array.isArray(value) && value.every((item) => typeof item === 'string' && item)
+
+  const permissions: MiniAppPermissionManifest = {}
+  if (filesystem !== undefined) {
+    if (!isList(filesystem)) return { error: '`permissions.filesystem` must be a list of folders' }
+    permissions.filesystem = []
+    for (const folder of filesystem) {
+      const resolved = resolveFolder(dir, folder)
+      if (!resolved) return { error: `Folder must be absolute, start with ~/ or be inside the mini app: ${folder}` }
+      permissions.filesystem.push(resolved)
+    }
+  }
+  if (network !== undefined) {
+    if (!isList(network)) return { error: '`permissions.network` must be a list of hosts' }
+    const invalid = network.find((host) => !isHostPattern(host))
+    if (invalid) return { error: `Not a host name: ${invalid}` }
+    permissions.network = network.map((host) => host.toLowerCase())
+  }
+  for (const [key, value] of Object.entries({ notifications, clipboard, sharedState })) {
+    if (value === undefined) continue
+    if (typeof value !== 'boolean') return { error: `\`permissions.${key}\` must be true or false` }
+    permissions[key as 'notifications' | 'clipboard' | 'sharedState'] = value
+  }
+  return permissions
+}
+
+/**
+ * Validate the `manifest.json` of a mini app folder.
+ *
+ * `url` is a web page or a page inside the folder, `logo` and `preloads` may be files inside the folder.
+ * A local logo is returned as `logoFile` for the caller to read, unknown fields are ignored.
+ * `updateUrl` is where a packaged mini app publishes its latest version, it must be https.
+ * `permissions` declares what the mini app may ask for through the bridge, see `MiniAppPermissionManifest`.
+ */
+export function parseMiniAppManifest(data: unknown, dir: string): ManifestResult {
+  if (!data || typeof data !== 'object' || Array.isArray(data)) return { error: 'Manifest is not an object' }
+  const manifest = data as Record<string, unknown>
+
+  const { id, name, url, logo } = manifest
+  if (typeof id !== 'string' || !ID_PATTERN.test(id)) return { error: '`id` is missing or invalid' }
+  if (typeof name !== 'string' || !name.trim()) return { error: '`name` is required' }
+  if (typeof url !== 'string' || !url) return { error: '`url` is required' }
+
+  let appUrl = url
+  if (!isWebUrl(url)) {
+    const page = resolveInside(dir, url)
+    if (!page) return { error: '`url` must be a web page or a page inside the mini app folder' }
+    appUrl = pathToFileURL(page).toString()
+  }
+
+  let logoUrl: string | undefined
+  let logoFile: string | undefined
+  if (typeof logo === 'string' && logo) {
+    if (isWebUrl(logo) || logo.startsWith('data:image/')) {
+      logoUrl = logo
+    } else {
+      logoFile = resolveInside(dir, logo)
+      if (!logoFile) return { error: '`logo` must be a URL or a file inside the mini app folder' }
+    }
+  }
+
+  let preloads: string[] | undefined
+  if (manifest.preloads !== undefined) {
+    if (!Array.isArray(manifest.preloads)) return { error: '`preloads` must be a list of files' }
+    preloads = []
+    for (const file of manifest.preloads) {
+      const resolved = typeof file === 'string' ? resolveInside(dir, file) : undefined
+      if (!resolved) return { error: `Preload is not a file inside the mini app folder: ${file}` }
+      preloads.push(resolved)
+    }
+  }
+
+  const contextMenu = Array.isArray(manifest.contextMenu)
+    ? manifest.contextMenu.filter((action) => CONTEXT_MENU_ACTIONS.includes(action))
+    : undefined
+  const optionalString = (value: unknown) => (typeof value === 'string' && value ? value : undefined)
+  const optionalBoolean = (value: unknown) => (typeof value === 'boolean' ? value : undefined)
+  const updateUrl = optionalString(manifest.updateUrl)
+  if (updateUrl && !/^https:\/\//i.test(updateUrl)) return { error: '`updateUrl` must be an https URL' }
+
+  let permissions: MiniAppPermissionManifest | undefined
+  if (manifest.permissions !== undefined) {
+    const result = parsePermissions(manifest.permissions, dir)
+    if ('error' in result) return result
+    permissions = result
+  }
+
+  return {
+    app: {
+      id,
+      name: name.trim(),
+      url: appUrl,
+      version: optionalString(manifest.version),
+      logo: logoUrl,
+      bodered: optionalBoolean(manifest.bodered),
+      background: optionalString(manifest.background),
+      ephemeral: optionalBoolean(manifest.ephemeral),
+      contextMenu,
+      userAgent: optionalString(manifest.userAgent),
+      preloads,
+      permissions,
+      type: 'Manifest'
+    },
+    logoFile,
+    updateUrl
+  }
+}
@@ -0,0 +1,94 @@
+import crypto, { KeyObject } from 'node:crypto'
+
+import { MiniAppRegistryEntry } from '@types'
+
+import { getKeyFingerprint } from './bundle'
+
+const ID_PATTERN = /^[a-z0-9][a-z0-9._-]*$/i
+
+/**
+ * A trusted key is a PEM public key or the base64 DER (spki) of an Ed25519 public key
+ */
+export function parseTrustedKey(key: string): KeyObject | undefined {
+  try {
+    const value = key.trim()
+    const publicKey = value.startsWith('-----BEGIN')
+      ? crypto.createPublicKey(value)
+      : crypto.createPublicKey({ key: Buffer.from(value, 'base64'), format: 'der', type: 'spki' })
+    return publicKey.asymmetricKeyType === 'ed25519' ? publicKey : undefined
+  } catch {
+    return undefined
+  }
+}
+
+/**
+ * Fingerprint of the trusted key that signed the package, undefined when none of them did
+ */
+export function verifyPackageSignature(data: Buffer, signature: string, trustedKeys: string[]): string | undefined {
+  const signatureBytes = Buffer.from(signature.trim(), 'base64')
+  for (const key of trustedKeys) {
+    const publicKey = parseTrustedKey(key)
+    if (!publicKey) continue
+    try {
+      if (crypto.verify(null, data, publicKey, signatureBytes)) {
+        return getKeyFingerprint(publicKey)
+      }
+    } catch {
+      // a signature of the wrong length throws instead of failing
+    }
+  }
+  return undefined
+}
+
+/**
+ * Where the detached signature of a package is published, next to the archive
+ */
+export function getSignatureUrl(url: string): string {
+  const parsed = new URL(url)
+  parsed.pathname += '.sig'
+  return parsed.toString()
+}
+
+/**
+ * Entries of a registry document, `{ apps: [...] }` or a bare array. Malformed entries are skipped.
+ */
+export function parseMiniAppRegistry(data: unknown): MiniAppRegistryEntry[] {
+  const apps = Array.isArray(data) ? data : (data as { apps?: unknown })?.apps
+  if (!Array.isArray(apps)) return []
+
+  return apps.flatMap((item): MiniAppRegistryEntry[] => {
+    if (!item || typeof item !== 'object') return []
+    const { id, name, description, version, url, signature, homepage } = item
+    if (typeof id !== 'string' || !ID_PATTERN.test(id)) return []
+    if (typeof url !== 'string' || !/^https:\/\//i.test(url)) return []
+
+    return [
+      {
+        id,
+        name: typeof name === 'string' && name ? name : id,
+        description: typeof description === 'string' ? description : '',
+        version: typeof version === 'string' && version ? version : undefined,
+        url,
+        signature: typeof signature === 'string' && signature ? signature : undefined,
+        homepage: typeof homepage === 'string' ? homepage : undefined
+      }
+    ]
+  })
+}
+
+export interface MiniAppUpdateInfo {
+  version: string
+  url: string
+  signature?: string
+}
+
+/**
+ * Document published at the update url of a package: `{ version, url, signature? }`, undefined when malformed
+ */
+export function parseMiniAppUpdateInfo(data: unknown): MiniAppUpdateInfo | undefined {
+  if (!data || typeof data !== 'object' || Array.isArray(data)) return undefined
+  const { version, url, signature } = data as Record<string, unknown>
+  if (typeof version !== 'string' || !version) return undefined
+  if (typeof url !== 'string' || !/^https:\/\//i.test(url)) return undefined
+  return { version, url, signature: typeof signature === 'string' && signature ? signature : undefined }
+}
@@ -0,0 +1,118 @@
+import path from 'node:path'
+
+import { MiniAppPermission, MiniAppPermissionManifest } from '@types'
+
+const HOST_PATTERN = /^(\*\.)?[a-z0-9-]+(\.[a-z0-9-]+)*$/i
+
+// permissions asked for by bridge commands rather than by pages
+export const BRIDGE_PERMISSIONS: MiniAppPermission[] = [
+  'filesystem',
+  'network',
+  'notifications',
+  'clipboard',
+  'shared-state'
+]
+
+export function isHostPattern(value: string): boolean {
+  return HOST_PATTERN.test(value)
+}
+
+/**
+ * Permissions the manifest declares, the bridge denies the others without asking the user
+ */
+export function getDeclaredPermissions(manifest: MiniAppPermissionManifest = {}): MiniAppPermission[] {
+  const permissions: MiniAppPermission[] = []
+  if (manifest.filesystem?.length) permissions.push('filesystem')
+  if (manifest.network?.length) permissions.push('network')
+  if (manifest.notifications) permissions.push('notifications')
+  if (manifest.clipboard) permissions.push('clipboard')
+  if (manifest.sharedState) permissions.push('shared-state')
+  return permissions
+}
+
+/**
+ * Folders or hosts a permission is declared for, undefined for permissions without a scope
+ */
+export function getPermissionScope(
+  manifest: MiniAppPermissionManifest | undefined,
+  permission: MiniAppPermission
+): string[] | undefined {
+  if (permission === 'filesystem') return manifest?.filesystem ?? []
+  if (permission === 'network') return manifest?.network ?? []
+  return undefined
+}
+
+/**
+ * An answer given for the granted folders or hosts also holds for the scope, which must not have any other entry
+ */
+export function coversScope(granted: string[] | undefined, scope: string[] | undefined): boolean {
+  if (!scope) return true
+  return !!granted && scope.every((entry) => granted.includes(entry))
+}
+
+/**
+ * Permissions the next manifest declares that the previous one did not, or declares for more folders or hosts
+ */
+export function getAddedPermissions(
+  previous: MiniAppPermissionManifest | undefined,
+  next: MiniAppPermissionManifest | undefined
+): MiniAppPermission[] {
+  const declared = getDeclaredPermissions(previous)
+  return getDeclaredPermissions(next).filter(
+    (permission) =>
+      !declared.includes(permission) ||
+      !coversScope(getPermissionScope(previous, permission), getPermissionScope(next, permission))
+  )
+}
+
+/**
+ * The file is one of the folders or inside one of them, both are expected to be resolved already
+ */
+export function isPathAllowed(file: string, folders: string[]): boolean {
+  const target = path.resolve(file)
+  return folders.some((folder) => {
+    const root = path.resolve(folder)
+    return target === root || target.startsWith(root.endsWith(path.sep) ? root : root + path.sep)
+  })
+}
+
+/**
+ * An http or https url whose host is listed, `*.example.com` matches the subdomains of example.com only
+ */
+export function isHostAllowed(url: string, hosts: string[]): boolean {
+  let parsed: URL
+  try {
+    parsed = new URL(url)
+  } catch {
+    return false
+  }
+  if (!['http:', 'https:'].includes(parsed.protocol)) return false
+
+  const hostname = parsed.hostname.toLowerCase()
+  return hosts.some((host) => {
+    const pattern = host.toLowerCase()
+    return pattern.startsWith('*.') ? hostname.endsWith(pattern.slice(1)) : hostname === pattern
+  })
+}
+
+/**
+ * The page belongs to the mini app: the same origin as its web page, or a file next to its local page.
+ * Pages the mini app navigated away to keep the bridge but must not use its permissions.
+ */
+export function isAppPage(appUrl: string, pageUrl: string): boolean {
+  let app: URL
+  let page: URL
+  try {
+    app = new URL(appUrl)
+    page = new URL(pageUrl)
+  } catch {
+    return false
+  }
+
+  if (app.protocol === 'file:') {
+    if (page.protocol !== 'file:') return false
+    const folder = path.posix.dirname(app.pathname)
+    return page.pathname.startsWith(folder.endsWith('/') ? folder : folder + '/')
+  }
+  return ['http:', 'https:'].includes(app.protocol) && app.origin === page.origin
+}
@@ -0,0 +1,78 @@
+import vm from 'node:vm'
+
+import { MINIAPP_BRIDGE_CHANNEL } from '@shared/config/constant'
+import { IpcChannel } from '@shared/IpcChannel'
+
+// bumped when the bridge API changes in a way mini apps can't ignore
+export const MINI_APP_BRIDGE_VERSION = 1
+
+export interface MiniAppPreloadScript {
+  name: string
+  code: string
+}
+
+/**
+ * Scripts that don't parse are left out, one broken script must not break the bridge or the other scripts
+ */
+export function isValidScript(code: string): boolean {
+  try {
+    new vm.Script(`(function () {\n${code}\n})`)
+    return true
+  } catch {
+    return false
+  }
+}
+
+/**
+ * Source of the preload script attached to a mini app's webview.
+ *
+ * It exposes `window.metheus` to the page and then runs the mini app's own preload scripts in the page's world,
+ * in order and before any script of the page. Each script runs in its own function, errors are logged in the page.
+ * The `fs`, `fetch`, `notify`, `clipboard` and `state` commands need the permissions of the mini app's manifest.
+ */
+export function buildMiniAppPreload(appId: string, scripts: MiniAppPreloadScript[]): string {
+  const channel = JSON.stringify(MINIAPP_BRIDGE_CHANNEL)
+  const invokeChannel = JSON.stringify(IpcChannel.MiniApp_BridgeInvoke)
+  const bridge = `const { contextBridge, ipcRenderer } = require('electron')
+const listeners = new Set()
+const invoke = (command, ...args) => ipcRenderer.invoke(${invokeChannel}, command, ...args)
+ipcRenderer.on(${channel}, (_, data) => listeners.forEach((listener) => listener(data)))
+contextBridge.exposeInMainWorld('metheus', {
+  version: ${MINI_APP_BRIDGE_VERSION},
+  appId: ${JSON.stringify(appId)},
+  postMessage: (data) => ipcRenderer.sendToHost(${channel}, data),
+  onMessage: (listener) => {
+    listeners.add(listener)
+    return () => listeners.delete(listener)
+  },
+  fs: {
+    readFile: (path, encoding) => invoke('fs.readFile', path, encoding),
+    writeFile: (path, data, encoding) => invoke('fs.writeFile', path, data, encoding),
+    readDir: (path) => invoke('fs.readDir', path)
+  },
+  fetch: (url, init) => invoke('fetch', url, init),
+  notify: (title, body) => invoke('notify', title, body),
+  clipboard: {
+    readText: () => invoke('clipboard.readText'),
+    writeText: (text) => invoke('clipboard.writeText', text)
+  },
+  state: {
+    get: (key) => invoke('state.get', key),
+    set: (key, value) => invoke('state.set', key, value)
+  }
+})`
+
+  const injected = scripts.map(
+    (script) => `contextBridge.executeInMainWorld({
+  func: function () {
+    try {
+${script.code}
+    } catch (error) {
+      console.error(${JSON.stringify(`[metheus] Preload ${script.name} failed:`)}, error)
+    }
+  }
+})`
+  )
+
+  return [bridge, ...injected].join('\n\n') + '\n'
+}
@@ -0,0 +1,52 @@
+import { OutboundEvent, OutboundIntegration } from '@types'
+
+import { renderTemplate } from './template'
+
+export const DEFAULT_OUTBOUND_TEMPLATES: Record<OutboundEvent | 'test', string> = {
+  'agent.finished': '{{assistant}} finished in "{{topic}}":\n{{text}}',
+  'job.failed': 'Job {{type}} failed after {{completed}}/{{total}} items: {{error}}',
+  'backup.completed': 'Backup completed: {{fileName}} ({{target}})',
+  test: 'Test message from Cherry Studio'
+}
+
+export interface OutboundRequest {
+  url: string
+  body: Record<string, any>
+}
+
+/**
+ * Render a message template with the event data, unknown fields become empty
+ */
+export function renderOutboundTemplate(
+  template: string,
+  data: Record<string, any>,
+  partials?: Record<string, string>
+): string {
+  return renderTemplate(template, data, { partials })
+}
+
+/**
+ * Request for an integration, the secret is the webhook URL or the Telegram bot token
+ */
+export function buildOutboundRequest(
+  integration: Pick<OutboundIntegration, 'type' | 'chatId'>,
+  secret: string,
+  event: OutboundEvent | 'test',
+  text: string,
+  data: Record<string, any>
+): OutboundRequest {
+  switch (integration.type) {
+    case 'slack':
+      return { url: secret, body: { text } }
+    case 'discord':
+      // Discord rejects messages longer than 2000 characters
+      return { url: secret, body: { content: text.slice(0, 2000) } }
+    case 'telegram':
+      return {
+        url: `https://api.telegram.org/bot${secret}/sendMessage`,
+        body: { chat_id: integration.chatId, text: text.slice(0, 4096) }
+      }
+    case 'http':
+      return { url: secret, body: { event, text, data, timestamp: Date.now() } }
+  }
+}
@@ -0,0 +1,89 @@
+import { ManagerError } from '@shared/ManagerError'
+import { TextDiff, TextDiffMode, WorkspaceFileChangeStatus } from '@types'
+import { applyPatch, createTwoFilesPatch, diffLines, diffWordsWithSpace, ParsedDiff, parsePatch } from 'diff'
+
+const DEV_NULL = '/dev/null'
+
+/**
+ * Diff two texts, as a unified diff or as the list of changed parts by lines or words
+ */
+export function computeDiff(oldText: string, newText: string, mode: TextDiffMode = 'unified'): TextDiff {
+  const changes = mode === 'words' ? diffWordsWithSpace(oldText, newText) : diffLines(oldText, newText)
+  const count = (removed: boolean) =>
+    changes
+      .filter((change) => (removed ? change.removed : change.added))
+      .reduce((total, change) => total + (change.count ?? 1), 0)
+  const diff: TextDiff = { mode, additions: count(false), deletions: count(true) }
+
+  if (mode === 'unified') {
+    diff.unified = createTwoFilesPatch('original', 'modified', oldText, newText)
+  } else {
+    diff.changes = changes.map(({ value, added, removed }) => ({ value, added, removed }))
+  }
+  return diff
+}
+
+/**
+ * Parse a unified diff touching exactly one file
+ */
+export function parseSinglePatch(unifiedDiff: string): ParsedDiff {
+  let patches: ParsedDiff[]
+  try {
+    patches = parsePatch(unifiedDiff)
+  } catch (error) {
+    throw new ManagerError('ConversationWorkspaceManager', 'invalid_argument', `Invalid patch: ${error}`)
+  }
+
+  const files = patches.filter((patch) => patch.hunks.length > 0)
+  if (files.length !== 1) {
+    throw new ManagerError(
+      'ConversationWorkspaceManager',
+      'invalid_argument',
+      `A patch must change exactly one file, got ${files.length}`
+    )
+  }
+  return files[0]
+}
+
+/**
+ * The file a patch applies to, taken from its headers without the git a/ and b/ prefixes
+ */
+export function getPatchPath(patch: ParsedDiff): string | undefined {
+  const name = [patch.newFileName, patch.oldFileName].find((file) => file && file !== DEV_NULL)
+  return name?.replace(/^[ab]\//, '')
+}
+
+/**
+ * Apply a parsed patch to the current content of a file, null when the file doesn't exist.
+ *
+ * Hunks must match the content exactly, a file changed since the patch was made is reported as a conflict
+ * instead of being patched at a guessed position. Returns null as the content when the patch deletes the file.
+ */
+export function applyParsedPatch(content: string | null, patch: ParsedDiff) {
+  const creates = patch.oldFileName === DEV_NULL
+  const deletes = patch.newFileName === DEV_NULL
+
+  if (creates && content !== null) {
+    throw new ManagerError('ConversationWorkspaceManager', 'conflict', 'The file to create already exists')
+  }
+  if (!creates && content === null) {
+    throw new ManagerError('ConversationWorkspaceManager', 'conflict', 'The file to patch does not exist')
+  }
+
+  const patched = applyPatch(content ?? '', patch)
+  if (patched === false) {
+    throw new ManagerError('ConversationWorkspaceManager', 'conflict', 'The patch does not match the file content')
+  }
+  if (deletes && patched !== '') {
+    throw new ManagerError('ConversationWorkspaceManager', 'conflict', 'The file to delete has other content')
+  }
+
+  const lines = patch.hunks.flatMap((hunk) => hunk.lines)
+  const status: WorkspaceFileChangeStatus = creates ? 'added' : deletes ? 'deleted' : 'modified'
+  return {
+    content: deletes ? null : patched,
+    status,
+    additions: lines.filter((line) => line.startsWith('+')).length,
+    deletions: lines.filter((line) => line.startsWith('-')).length
+  }
+}
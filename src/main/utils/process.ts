@@ -57,3 +57,12 @@ export async function isBinaryExists(name: string): Promise<boolean> {
   const cmd = await getBinaryPath(name)
   return await fs.existsSync(cmd)
 }
+
+/**
+ * Whether the command is found in a directory of PATH, without running it
+ */
+export function isCommandOnPath(name: string): boolean {
+  const extensions = process.platform === 'win32' ? (process.env.PATHEXT || '.EXE;.CMD;.BAT').split(';') : ['']
+  const dirs = (process.env.PATH || '').split(path.delimiter).filter(Boolean)
+  return dirs.some((dir) => extensions.some((extension) => fs.existsSync(path.join(dir, name + extension))))
+}
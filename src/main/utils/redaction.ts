@@ -0,0 +1,107 @@
+export const REDACTED = '[REDACTED]'
+export const TRUNCATED = '[Truncated]'
+
+// short values would scrub ordinary words from the logs
+const MIN_SECRET_LENGTH = 8
+
+export interface RedactionPattern {
+  name: string
+  // only the match is replaced, keep context like `Bearer ` in lookbehinds
+  regex: RegExp
+}
+
+export interface RedactionResult {
+  text: string
+  // number of replacements by pattern name, `secret` for vault values
+  hits: Record<string, number>
+}
+
+export const BUILTIN_REDACTION_PATTERNS: RedactionPattern[] = [
+  { name: 'bearer', regex: /(?<=\bBearer\s+)[A-Za-z0-9\-._~+/]{8,}=*/gi },
+  { name: 'basic_auth', regex: /(?<=\bBasic\s+)[A-Za-z0-9+/]{8,}=*/g },
+  {
+    name: 'api_key',
+    regex: /(?<=\b(?:api[_-]?key|access[_-]?token|secret|password|token)["']?\s*[:=]\s*["']?)[^\s"'&,;]{6,}/gi
+  },
+  { name: 'url_credentials', regex: /(?<=\b[a-z][a-z0-9+.-]*:\/\/[^\s:/@]+:)[^\s@/]+(?=@)/gi },
+  { name: 'provider_key', regex: /\b(?:sk|pk|rk)-[A-Za-z0-9_-]{16,}/g },
+  { name: 'github_token', regex: /\b(?:ghp|gho|ghu|ghs|ghr|github_pat)_[A-Za-z0-9_]{20,}/g },
+  { name: 'slack_token', regex: /\bxox[abposr]-[A-Za-z0-9-]{10,}/g },
+  { name: 'jwt', regex: /\beyJ[A-Za-z0-9_-]{8,}\.[A-Za-z0-9_-]{8,}\.[A-Za-z0-9_-]{8,}/g }
+]
+
+function escapeRegExp(value: string): string {
+  return value.replace(/[.*+?^${}()|[\]\\]/g, '\\$&')
+}
+
+/**
+ * Compile user patterns, one regular expression per entry. Invalid expressions are returned separately so the
+ * settings can point them out instead of failing the logger.
+ */
+export function compileRedactionPatterns(sources: string[]): { patterns: RedactionPattern[]; invalid: string[] } {
+  const patterns: RedactionPattern[] = []
+  const invalid: string[] = []
+  for (const source of sources.map((item) => item.trim()).filter(Boolean)) {
+    try {
+      patterns.push({ name: 'custom', regex: new RegExp(source, 'g') })
+    } catch {
+      invalid.push(source)
+    }
+  }
+  return { patterns, invalid }
+}
+
+/**
+ * Replace secrets and pattern matches in a text. Longer secrets are replaced first so a secret that is a prefix of
+ * another one doesn't leave the rest of it behind.
+ */
+export function redactText(text: string, secrets: string[], patterns: RedactionPattern[]): RedactionResult {
+  const hits: Record<string, number> = {}
+  const count = (name: string) => (hits[name] = (hits[name] ?? 0) + 1)
+
+  let result = text
+  const values = [...new Set(secrets)].filter((value) => value.length >= MIN_SECRET_LENGTH)
+  if (values.length) {
+    values.sort((a, b) => b.length - a.length)
+    result = result.replace(new RegExp(values.map(escapeRegExp).join('|'), 'g'), () => {
+      count('secret')
+      return REDACTED
+    })
+  }
+
+  for (const { name, regex } of patterns) {
+    result = result.replace(regex, (match) => {
+      // a value already replaced by an earlier pass
+      if (match.includes(REDACTED)) return match
+      count(name)
+      return REDACTED
+    })
+  }
+
+  return { text: result, hits }
+}
+
+/**
+ * Redact the strings of a log argument. Errors keep their type with the message, stack and own fields redacted,
+ * other objects are copied with their own enumerable fields. Nesting deeper than `maxDepth` is replaced by a
+ * placeholder instead of being written unredacted.
+ */
+export function redactValue(value: unknown, redact: (text: string) => string, maxDepth: number, depth = 0): unknown {
+  if (typeof value === 'string') return redact(value)
+  if (!value || typeof value !== 'object') return value
+  // no text to scrub, and copying the bytes of a buffer field by field would flood the log
+  if (value instanceof Date || ArrayBuffer.isView(value) || value instanceof ArrayBuffer) return value
+  if (depth >= maxDepth) return TRUNCATED
+
+  const redactItem = (item: unknown) => redactValue(item, redact, maxDepth, depth + 1)
+  if (Array.isArray(value)) return value.map(redactItem)
+
+  const fields = Object.fromEntries(Object.entries(value).map(([key, item]) => [key, redactItem(item)]))
+  if (value instanceof Error) {
+    const error = Object.assign(new Error(redact(value.message)), fields)
+    error.name = value.name
+    error.stack = value.stack && redact(value.stack)
+    return error
+  }
+  return fields
+}
@@ -0,0 +1,65 @@
+import crypto from 'node:crypto'
+
+const IV_LENGTH = 12
+const TAG_LENGTH = 16
+// start of sealed files, followed by the length and the id of the topic whose key sealed them
+const SEALED_FILE_MAGIC = Buffer.from('CSSEALED1')
+
+export function generateSealKey(): Buffer {
+  return crypto.randomBytes(32)
+}
+
+function encrypt(key: Buffer, data: Buffer): Buffer {
+  const iv = crypto.randomBytes(IV_LENGTH)
+  const cipher = crypto.createCipheriv('aes-256-gcm', key, iv)
+  const encrypted = Buffer.concat([cipher.update(data), cipher.final()])
+  return Buffer.concat([iv, cipher.getAuthTag(), encrypted])
+}
+
+function decrypt(key: Buffer, raw: Buffer): Buffer {
+  const decipher = crypto.createDecipheriv('aes-256-gcm', key, raw.subarray(0, IV_LENGTH))
+  decipher.setAuthTag(raw.subarray(IV_LENGTH, IV_LENGTH + TAG_LENGTH))
+  return Buffer.concat([decipher.update(raw.subarray(IV_LENGTH + TAG_LENGTH)), decipher.final()])
+}
+
+/**
+ * Encrypt text with AES-256-GCM, the result is base64
+ */
+export function sealText(key: Buffer, text: string): string {
+  return encrypt(key, Buffer.from(text, 'utf8')).toString('base64')
+}
+
+/**
+ * Decrypt text sealed by sealText. Throws when the key is wrong or the text was modified
+ */
+export function openText(key: Buffer, value: string): string {
+  return decrypt(key, Buffer.from(value, 'base64')).toString('utf8')
+}
+
+/**
+ * Encrypt the content of a file, the topic id stays readable so the file can be opened without knowing its topic
+ */
+export function sealBuffer(key: Buffer, topicId: string, data: Buffer): Buffer {
+  const id = Buffer.from(topicId, 'utf8')
+  if (id.length > 255) throw new Error(`Topic id is too long: ${topicId}`)
+  return Buffer.concat([SEALED_FILE_MAGIC, Buffer.from([id.length]), id, encrypt(key, data)])
+}
+
+/**
+ * Topic whose key sealed the file content, undefined for plain content
+ */
+export function getSealedTopicId(data: Buffer): string | undefined {
+  const magic = data.subarray(0, SEALED_FILE_MAGIC.length)
+  if (data.length <= SEALED_FILE_MAGIC.length || !magic.equals(SEALED_FILE_MAGIC)) return undefined
+  const length = data[SEALED_FILE_MAGIC.length]
+  const start = SEALED_FILE_MAGIC.length + 1
+  return data.subarray(start, start + length).toString('utf8')
+}
+
+/**
+ * Decrypt file content sealed by sealBuffer. Throws when the key is wrong or the content was modified
+ */
+export function openBuffer(key: Buffer, data: Buffer): Buffer {
+  const length = data[SEALED_FILE_MAGIC.length]
+  return decrypt(key, data.subarray(SEALED_FILE_MAGIC.length + 1 + length))
+}
@@ -0,0 +1,73 @@
+import { SharedConversationMessage } from '@types'
+import MarkdownIt from 'markdown-it'
+
+// raw HTML in messages is escaped, the page must not run anything from the conversation
+const md = new MarkdownIt({ html: false, linkify: true, breaks: true })
+
+const ROLE_NAMES: Record<SharedConversationMessage['role'], string> = {
+  user: 'User',
+  assistant: 'Assistant',
+  system: 'System'
+}
+
+const CSP = "default-src 'none'; img-src data: https:; style-src 'unsafe-inline'"
+
+const STYLE = `
+body { margin: 0; background: #f7f7f8; color: #1f1f1f; font: 15px/1.6 -apple-system, 'Segoe UI', sans-serif; }
+main { max-width: 820px; margin: 0 auto; padding: 32px 20px; }
+h1 { font-size: 22px; }
+.message { background: #fff; border-radius: 10px; padding: 14px 18px; margin: 14px 0; border: 1px solid #e5e5e5; }
+.message.user { background: #eef4ff; }
+.meta { font-size: 12px; color: #888; margin-bottom: 6px; }
+pre { background: #f2f2f2; padding: 10px; border-radius: 6px; overflow-x: auto; }
+code { font-family: ui-monospace, Menlo, monospace; font-size: 13px; }
+img { max-width: 100%; }
+table { border-collapse: collapse; } td, th { border: 1px solid #ddd; padding: 4px 8px; }
+@media (prefers-color-scheme: dark) {
+  body { background: #181818; color: #e5e5e5; }
+  .message { background: #232323; border-color: #333; } .message.user { background: #1f2a3d; }
+  pre { background: #2b2b2b; }
+}`
+
+export function escapeHtml(text: string): string {
+  return text.replace(/[&<>"']/g, (char) => `&#${char.charCodeAt(0)};`)
+}
+
+/**
+ * Render a conversation into a self-contained HTML page, with `redactNames` the names are replaced by the roles
+ */
+export function renderConversationHtml(
+  title: string,
+  messages: SharedConversationMessage[],
+  redactNames = false
+): string {
+  const body = messages
+    .map((message) => {
+      const name = redactNames ? ROLE_NAMES[message.role] : message.name || ROLE_NAMES[message.role]
+      return [
+        `<section class="message ${message.role}">`,
+        `<div class="meta">${escapeHtml(name)} · ${escapeHtml(message.createdAt)}</div>`,
+        md.render(message.content),
+        '</section>'
+      ].join('\n')
+    })
+    .join('\n')
+
+  return `<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<meta http-equiv="Content-Security-Policy" content="${CSP}">
+<title>${escapeHtml(title)}</title>
+<style>${STYLE}</style>
+</head>
+<body>
+<main>
+<h1>${escapeHtml(title)}</h1>
+${body}
+</main>
+</body>
+</html>
+`
+}
@@ -0,0 +1,58 @@
+import { isMac } from '@main/constant'
+
+export interface ShortcutInput {
+  key: string
+  code: string
+  control: boolean
+  meta: boolean
+  shift: boolean
+  alt: boolean
+}
+
+/**
+ * Match a key input against a shortcut recorded by the renderer, e.g. ['Command', 'Shift', 'F']
+ */
+export function matchShortcut(input: ShortcutInput, shortcut: string[]): boolean {
+  const modifiers = { control: false, meta: false, shift: false, alt: false }
+  let key = ''
+
+  for (const part of shortcut) {
+    switch (part.toLowerCase()) {
+      case 'command':
+      case 'cmd':
+      case 'meta':
+        modifiers.meta = true
+        break
+      case 'ctrl':
+      case 'control':
+        modifiers.control = true
+        break
+      case 'commandorcontrol':
+        modifiers[isMac ? 'meta' : 'control'] = true
+        break
+      case 'shift':
+        modifiers.shift = true
+        break
+      case 'alt':
+      case 'option':
+        modifiers.alt = true
+        break
+      default:
+        key = part.toLowerCase()
+    }
+  }
+
+  if (!key) return false
+  if (
+    input.control !== modifiers.control ||
+    input.meta !== modifiers.meta ||
+    input.shift !== modifiers.shift ||
+    input.alt !== modifiers.alt
+  ) {
+    return false
+  }
+
+  // shift and alt change the produced character, fall back to the physical key
+  const code = input.code.toLowerCase()
+  return input.key.toLowerCase() === key || code === `key${key}` || code === `digit${key}`
+}
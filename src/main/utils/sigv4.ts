@@ -0,0 +1,88 @@
+import * as crypto from 'crypto'
+
+export interface AwsCredentials {
+  accessKeyId: string
+  secretAccessKey: string
+  sessionToken?: string
+}
+
+export interface SignableRequest {
+  method: string
+  url: string
+  headers?: Record<string, string>
+  body?: string
+}
+
+// AWS 要求按 RFC 3986 编码，encodeURIComponent 不会编码 !'()*
+function encodeRfc3986(value: string): string {
+  return encodeURIComponent(value).replace(/[!'()*]/g, (c) => `%${c.charCodeAt(0).toString(16).toUpperCase()}`)
+}
+
+function sha256(data: string): string {
+  return crypto.createHash('sha256').update(data).digest('hex')
+}
+
+function hmac(key: crypto.BinaryLike, data: string): Buffer {
+  return crypto.createHmac('sha256', key).update(data).digest()
+}
+
+/**
+ * 使用 AWS Signature Version 4 对请求签名
+ * @returns 需要附加到请求上的请求头（不含 host）
+ */
+export function signAwsRequest(
+  request: SignableRequest,
+  credentials: AwsCredentials,
+  region: string,
+  service: string,
+  date: Date = new Date()
+): Record<string, string> {
+  const url = new URL(request.url)
+  const amzDate = date.toISOString().replace(/[:-]|\.\d{3}/g, '')
+  const dateStamp = amzDate.slice(0, 8)
+
+  const headers: Record<string, string> = {}
+  for (const [name, value] of Object.entries(request.headers ?? {})) {
+    headers[name.toLowerCase()] = String(value).trim().replace(/\s+/g, ' ')
+  }
+  headers['host'] = url.host
+  headers['x-amz-date'] = amzDate
+  if (credentials.sessionToken) {
+    headers['x-amz-security-token'] = credentials.sessionToken
+  }
+
+  const headerNames = Object.keys(headers).sort()
+  const canonicalHeaders = headerNames.map((name) => `${name}:${headers[name]}\n`).join('')
+  const signedHeaders = headerNames.join(';')
+
+  // 除 S3 外，路径中的每一段需要再编码一次
+  const canonicalUri = url.pathname.split('/').map(encodeRfc3986).join('/')
+  const canonicalQuery = [...url.searchParams]
+    .map(([key, value]) => `${encodeRfc3986(key)}=${encodeRfc3986(value)}`)
+    .sort()
+    .join('&')
+
+  const canonicalRequest = [
+    request.method.toUpperCase(),
+    canonicalUri,
+    canonicalQuery,
+    canonicalHeaders,
+    signedHeaders,
+    sha256(request.body ?? '')
+  ].join('\n')
+
+  const scope = `${dateStamp}/${region}/${service}/aws4_request`
+  const stringToSign = ['AWS4-HMAC-SHA256', amzDate, scope, sha256(canonicalRequest)].join('\n')
+
+  const signingKey = [dateStamp, region, service, 'aws4_request'].reduce<crypto.BinaryLike>(
+    (key, part) => hmac(key, part),
+    `AWS4${credentials.secretAccessKey}`
+  )
+  const signature = crypto.createHmac('sha256', signingKey).update(stringToSign).digest('hex')
+
+  delete headers['host']
+  headers['authorization'] =
+    `AWS4-HMAC-SHA256 Credential=${credentials.accessKeyId}/${scope}, SignedHeaders=${signedHeaders}, Signature=${signature}`
+
+  return headers
+}
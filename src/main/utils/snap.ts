@@ -0,0 +1,50 @@
+import { SnapRect } from '@types'
+import type { Rectangle } from 'electron'
+
+/**
+ * Place a rect given as fractions of a display's work area in screen pixels
+ */
+export function toBounds(workArea: Rectangle, rect: SnapRect): Rectangle {
+  const x = Math.round(workArea.x + rect.x * workArea.width)
+  const y = Math.round(workArea.y + rect.y * workArea.height)
+  return {
+    x,
+    y,
+    width: Math.round(workArea.x + (rect.x + rect.width) * workArea.width) - x,
+    height: Math.round(workArea.y + (rect.y + rect.height) * workArea.height) - y
+  }
+}
+
+/**
+ * Fractions of the work area covered by the bounds, clamped to the work area so saved layouts always fit
+ */
+export function toSnapRect(workArea: Rectangle, bounds: Rectangle): SnapRect {
+  const clamp = (value: number) => Math.min(1, Math.max(0, value))
+  const round = (value: number) => Math.round(value * 1000) / 1000
+  const left = clamp((bounds.x - workArea.x) / workArea.width)
+  const top = clamp((bounds.y - workArea.y) / workArea.height)
+  const right = clamp((bounds.x + bounds.width - workArea.x) / workArea.width)
+  const bottom = clamp((bounds.y + bounds.height - workArea.y) / workArea.height)
+  return { x: round(left), y: round(top), width: round(right - left), height: round(bottom - top) }
+}
+
+/**
+ * Displays in a stable order for layouts: the primary display first, then the others from left to right
+ */
+export function sortDisplays<T extends { id: number; bounds: Rectangle }>(displays: T[], primaryId: number): T[] {
+  return [...displays].sort((a, b) => {
+    if (a.id === primaryId || b.id === primaryId) return a.id === primaryId ? -1 : 1
+    return a.bounds.x - b.bounds.x || a.bounds.y - b.bounds.y
+  })
+}
+
+export function isValidSnapRect(rect: SnapRect): boolean {
+  const values = [rect?.x, rect?.y, rect?.width, rect?.height]
+  return (
+    values.every((value) => typeof value === 'number' && Number.isFinite(value) && value >= 0 && value <= 1) &&
+    rect.width > 0 &&
+    rect.height > 0 &&
+    rect.x + rect.width <= 1 &&
+    rect.y + rect.height <= 1
+  )
+}
@@ -0,0 +1,123 @@
+// batches the renderer didn't acknowledge within this time are counted as handled, e.g. after a reload
+const ACK_TIMEOUT = 1000
+// weight of the newest ack latency in the smoothed latency
+const LATENCY_WEIGHT = 0.2
+
+export interface StreamBatch {
+  seq: number
+  text: string
+}
+
+export interface StreamBatcherOptions {
+  // text is held at least this long before it is sent, longer while the receiver is slow to acknowledge
+  minDelay?: number
+  maxDelay?: number
+  // a batch is sent right away once it holds this many bytes
+  maxBytes?: number
+  // sent batches that may wait for their acknowledgement before sending pauses
+  maxInFlight?: number
+}
+
+/**
+ * Batches streamed text so fast producers don't send a message per token.
+ *
+ * Text is held for a delay that follows the receiver's acknowledgement latency, between `minDelay` and `maxDelay`,
+ * or until `maxBytes` accumulated. While `maxInFlight` batches are unacknowledged nothing is sent and the text
+ * keeps accumulating, so a busy receiver gets fewer, larger batches.
+ */
+export class StreamBatcher {
+  private readonly minDelay: number
+  private readonly maxDelay: number
+  private readonly maxBytes: number
+  private readonly maxInFlight: number
+
+  private buffer = ''
+  private bytes = 0
+  private seq = 0
+  // send time by sequence number of the unacknowledged batches
+  private inFlight = new Map<number, number>()
+  private latency = 0
+  private timer?: NodeJS.Timeout
+  private blocked = false
+
+  constructor(
+    private readonly emit: (batch: StreamBatch) => void,
+    options: StreamBatcherOptions = {}
+  ) {
+    this.minDelay = options.minDelay ?? 16
+    this.maxDelay = options.maxDelay ?? 250
+    this.maxBytes = options.maxBytes ?? 16 * 1024
+    this.maxInFlight = options.maxInFlight ?? 2
+  }
+
+  public push(text: string) {
+    if (!text) return
+    this.buffer += text
+    this.bytes += Buffer.byteLength(text)
+
+    if (this.bytes >= this.maxBytes && !this.blocked) {
+      this.send()
+    } else {
+      this.timer ??= setTimeout(() => this.send(), this.getDelay())
+    }
+  }
+
+  /**
+   * Acknowledge a batch and every batch before it
+   */
+  public ack(seq: number) {
+    const sentAt = this.inFlight.get(seq)
+    if (sentAt === undefined) return
+
+    const sample = Date.now() - sentAt
+    this.latency = this.latency ? this.latency * (1 - LATENCY_WEIGHT) + sample * LATENCY_WEIGHT : sample
+    for (const key of this.inFlight.keys()) {
+      if (key <= seq) this.inFlight.delete(key)
+    }
+
+    if (this.blocked) this.send()
+  }
+
+  /**
+   * Send what is left right away, e.g. when the stream ends
+   */
+  public flush() {
+    this.send(true)
+  }
+
+  public dispose() {
+    clearTimeout(this.timer)
+    this.timer = undefined
+    this.buffer = ''
+    this.bytes = 0
+    this.inFlight.clear()
+  }
+
+  public getDelay() {
+    return Math.min(this.maxDelay, Math.max(this.minDelay, Math.round(this.latency)))
+  }
+
+  private send(force = false) {
+    clearTimeout(this.timer)
+    this.timer = undefined
+    this.blocked = false
+    if (!this.buffer) return
+
+    const now = Date.now()
+    for (const [seq, sentAt] of this.inFlight) {
+      if (now - sentAt > ACK_TIMEOUT) this.inFlight.delete(seq)
+    }
+    if (!force && this.inFlight.size >= this.maxInFlight) {
+      // sent again on the next ack, or once the oldest batch timed out
+      this.blocked = true
+      this.timer = setTimeout(() => this.send(), this.getDelay())
+      return
+    }
+
+    const batch = { seq: ++this.seq, text: this.buffer }
+    this.buffer = ''
+    this.bytes = 0
+    this.inFlight.set(batch.seq, now)
+    this.emit(batch)
+  }
+}
@@ -0,0 +1,211 @@
+import path from 'node:path'
+
+import { ManagerError } from '@shared/ManagerError'
+import Ajv from 'ajv'
+import { parseDocument } from 'yaml'
+
+export type StructuredFormat = 'json' | 'yaml'
+
+/**
+ * Checks an edited document before it is written, returns the problems found
+ */
+export type StructuredValidator = (document: unknown, filePath: string) => string[]
+
+type PathKey = string | number
+
+const FORMATS: Record<string, StructuredFormat> = { '.json': 'json', '.yaml': 'yaml', '.yml': 'yaml' }
+// config formats that are recognized but can't be edited yet, there is no parser that keeps their formatting
+const UNSUPPORTED_FORMATS: Record<string, string> = { '.toml': 'toml' }
+
+const validators: { matches: (filePath: string) => boolean; validate: StructuredValidator }[] = []
+
+const ajv = new Ajv({ allErrors: true, strict: false })
+
+const isObject = (value: unknown): value is Record<string, unknown> =>
+  typeof value === 'object' && value !== null && !Array.isArray(value)
+
+export function getStructuredFormat(filePath: string): StructuredFormat {
+  const ext = path.extname(filePath).toLowerCase()
+  const unsupported = UNSUPPORTED_FORMATS[ext]
+  if (unsupported) {
+    const message = `${unsupported.toUpperCase()} files are not supported, only JSON and YAML files can be edited`
+    throw new ManagerError('FileSystemServer', 'invalid_argument', message, { details: { format: unsupported } })
+  }
+  const format = FORMATS[ext]
+  if (!format) {
+    throw new Error(`Unsupported structured file: ${path.basename(filePath)}, only JSON and YAML files can be edited`)
+  }
+  return format
+}
+
+/**
+ * Split a JSON Pointer (RFC 6901) into its reference tokens, the empty pointer is the whole document
+ */
+export function parsePointer(pointer: string): string[] {
+  if (pointer === '') return []
+  if (!pointer.startsWith('/')) {
+    throw new Error(`Invalid JSON Pointer "${pointer}", it must be empty or start with "/"`)
+  }
+  return pointer
+    .slice(1)
+    .split('/')
+    .map((token) => token.replace(/~1/g, '/').replace(/~0/g, '~'))
+}
+
+function parseContent(content: string, format: StructuredFormat) {
+  if (format === 'json') {
+    return { data: content.trim() ? JSON.parse(content) : null }
+  }
+  const document = parseDocument(content)
+  if (document.errors.length) {
+    throw new Error(`Invalid YAML: ${document.errors[0].message}`)
+  }
+  return { data: document.toJS() as unknown, document }
+}
+
+function arrayIndex(token: string, length: number, allowEnd: boolean): number {
+  const index = token === '-' ? length : /^(0|[1-9]\d*)$/.test(token) ? Number(token) : NaN
+  if (Number.isNaN(index) || index > length || (index === length && !allowEnd)) {
+    throw new Error(`Array index "${token}" is out of range`)
+  }
+  return index
+}
+
+/**
+ * Turn the tokens into keys, numbers for arrays, checking every parent exists
+ */
+function resolvePath(data: unknown, tokens: string[], allowEnd: boolean): PathKey[] {
+  const keys: PathKey[] = []
+  let current = data
+  tokens.forEach((token, position) => {
+    const last = position === tokens.length - 1
+    if (Array.isArray(current)) {
+      const index = arrayIndex(token, current.length, last && allowEnd)
+      keys.push(index)
+      current = current[index]
+    } else if (isObject(current)) {
+      if (!last && !Object.prototype.hasOwnProperty.call(current, token)) {
+        throw new Error(`Path does not exist: /${tokens.slice(0, position + 1).join('/')}`)
+      }
+      keys.push(token)
+      current = current[token]
+    } else {
+      throw new Error(`Cannot address "${token}" inside a ${current === null ? 'null' : typeof current} value`)
+    }
+  })
+  return keys
+}
+
+function getIn(data: unknown, keys: PathKey[]): unknown {
+  return keys.reduce<unknown>((value, key) => (value as Record<PathKey, unknown>)?.[key], data)
+}
+
+/**
+ * Read the value a JSON Pointer refers to
+ */
+export function readStructured(content: string, format: StructuredFormat, pointer = ''): unknown {
+  const { data } = parseContent(content, format)
+  const tokens = parsePointer(pointer)
+  const keys = resolvePath(data, tokens, false)
+  const value = getIn(data, keys)
+  if (value === undefined) {
+    throw new Error(`Path does not exist: ${pointer}`)
+  }
+  return value
+}
+
+/**
+ * Set the value a JSON Pointer refers to, or remove it when the value is undefined.
+ *
+ * Object members are added or replaced, array items are replaced and appended with the index after the last item
+ * or `-`. JSON keeps its indentation, YAML keeps comments, key order and styles of untouched nodes.
+ */
+export function updateStructured(
+  content: string,
+  format: StructuredFormat,
+  pointer: string,
+  value: unknown
+): { content: string; data: unknown } {
+  const { data, document } = parseContent(content, format)
+  const tokens = parsePointer(pointer)
+  const remove = value === undefined
+  const keys = resolvePath(data, tokens, !remove)
+  const parentKeys = keys.slice(0, -1)
+  const key = keys[keys.length - 1]
+  const parent = getIn(data, parentKeys)
+  const appending = Array.isArray(parent) && key === parent.length
+
+  if (remove && !keys.length) {
+    throw new Error('The whole document cannot be removed')
+  }
+  if (remove && getIn(data, keys) === undefined) {
+    throw new Error(`Path does not exist: ${pointer}`)
+  }
+
+  if (document) {
+    if (!keys.length) {
+      document.contents = document.createNode(value) as typeof document.contents
+    } else if (remove) {
+      document.deleteIn(keys)
+    } else if (appending) {
+      document.addIn(parentKeys, document.createNode(value))
+    } else {
+      document.setIn(keys, document.createNode(value))
+    }
+    return { content: document.toString(), data: document.toJS() }
+  }
+
+  let next: unknown = value
+  if (keys.length) {
+    next = data
+    if (remove && Array.isArray(parent)) parent.splice(key as number, 1)
+    else if (remove) delete (parent as Record<string, unknown>)[key]
+    else (parent as Record<PathKey, unknown>)[key] = value
+  }
+  const indent = content.match(/^([ \t]+)\S/m)?.[1] ?? 2
+  const newline = content.endsWith('\n') ? '\n' : ''
+  return { content: JSON.stringify(next, null, indent) + newline, data: next }
+}
+
+/**
+ * Add a validator run on every structured edit of the matching files, e.g. to keep a config file loadable
+ */
+export function registerStructuredValidator(
+  matches: RegExp | ((filePath: string) => boolean),
+  validate: StructuredValidator
+) {
+  validators.push({
+    matches: matches instanceof RegExp ? (filePath) => matches.test(path.basename(filePath)) : matches,
+    validate
+  })
+}
+
+/**
+ * Problems of an edited document, from the registered validators and an optional JSON Schema
+ */
+export function validateStructured(document: unknown, filePath: string, schema?: object): string[] {
+  const problems = validators
+    .filter((validator) => validator.matches(filePath))
+    .flatMap((validator) => validator.validate(document, filePath))
+
+  if (schema) {
+    const validate = ajv.compile(schema)
+    if (!validate(document)) {
+      problems.push(...(validate.errors ?? []).map((error) => `${error.instancePath || '/'} ${error.message}`))
+    }
+  }
+  return problems
+}
+
+// dependency maps of package.json must stay maps of names to version strings
+registerStructuredValidator(/^package\.json$/, (document) => {
+  if (!isObject(document)) return ['package.json must contain an object']
+  return ['dependencies', 'devDependencies', 'peerDependencies', 'optionalDependencies'].flatMap((field) => {
+    const dependencies = document[field]
+    if (dependencies === undefined) return []
+    if (!isObject(dependencies)) return [`${field} must be an object`]
+    return Object.entries(dependencies)
+      .filter(([, version]) => typeof version !== 'string')
+      .map(([name]) => `${field}.${name} must be a version string`)
+  })
+})
@@ -0,0 +1,268 @@
+import fs from 'node:fs'
+import path from 'node:path'
+
+import { ManagerError } from '@shared/ManagerError'
+import { TabularColumn, TabularColumnType, TabularFormat, TabularValue } from '@types'
+import { XMLParser } from 'fast-xml-parser'
+import StreamZip from 'node-stream-zip'
+
+// workbooks without any text cells don't have it
+const SHARED_STRINGS = 'xl/sharedStrings.xml'
+// distinct values are only counted up to this many per column
+const MAX_DISTINCT = 10000
+const TOP_VALUES = 3
+
+const INTEGER = /^[-+]?\d+$/
+const NUMBER = /^[-+]?(\d+\.?\d*|\.\d+)([eE][-+]?\d+)?$/
+const BOOLEAN = /^(true|false)$/i
+const DATE = /^\d{4}-\d{2}-\d{2}([T ]\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:?\d{2})?)?$/
+
+// narrowest first, a column gets the first type matching all of its cells
+const TYPE_CHECKS: [TabularColumnType, (cell: string) => boolean][] = [
+  ['boolean', (cell) => BOOLEAN.test(cell)],
+  ['integer', (cell) => INTEGER.test(cell)],
+  ['number', (cell) => NUMBER.test(cell)],
+  ['date', (cell) => DATE.test(cell) && !Number.isNaN(Date.parse(cell))]
+]
+
+export interface Table {
+  columns: TabularColumn[]
+  rows: TabularValue[][]
+}
+
+export interface TabularFile {
+  sheets: string[]
+  sheet?: string
+  table: Table
+}
+
+export interface ReadTabularOptions {
+  sheet?: string
+  delimiter?: string
+  header: boolean
+}
+
+export interface WorksheetEntry {
+  name: string
+  // path of the worksheet xml inside the xlsx archive
+  path: string
+}
+
+/**
+ * Guess the delimiter of csv content from its first line, ignoring quoted text
+ */
+export function detectDelimiter(text: string): string {
+  const firstLine = text.slice(0, text.search(/\r?\n|$/)).replace(/"[^"]*"/g, '')
+  const counts = [',', ';', '\t', '|'].map((delimiter) => [delimiter, firstLine.split(delimiter).length - 1] as const)
+  const [delimiter, count] = counts.reduce((best, item) => (item[1] > best[1] ? item : best))
+  return count > 0 ? delimiter : ','
+}
+
+/**
+ * Split csv or tsv content into rows of cells, quoted cells may contain delimiters, quotes and line breaks
+ */
+export function parseDelimited(text: string, delimiter: string): string[][] {
+  const rows: string[][] = []
+  let row: string[] = []
+  let cell = ''
+  let quoted = false
+
+  const source = text.charCodeAt(0) === 0xfeff ? text.slice(1) : text
+  for (let i = 0; i < source.length; i++) {
+    const char = source[i]
+    if (quoted) {
+      if (char === '"' && source[i + 1] === '"') {
+        cell += '"'
+        i++
+      } else if (char === '"') {
+        quoted = false
+      } else {
+        cell += char
+      }
+    } else if (char === '"' && cell === '') {
+      quoted = true
+    } else if (char === delimiter) {
+      row.push(cell)
+      cell = ''
+    } else if (char === '\n' || char === '\r') {
+      if (char === '\r' && source[i + 1] === '\n') i++
+      row.push(cell)
+      rows.push(row)
+      row = []
+      cell = ''
+    } else {
+      cell += char
+    }
+  }
+  if (cell !== '' || row.length) {
+    row.push(cell)
+    rows.push(row)
+  }
+  return rows
+}
+
+const xmlParser = new XMLParser({
+  ignoreAttributes: false,
+  attributeNamePrefix: '',
+  removeNSPrefix: true,
+  parseTagValue: false,
+  parseAttributeValue: false,
+  trimValues: false,
+  // `r` is also the reference attribute of rows and cells
+  isArray: (name, _jpath, _isLeafNode, isAttribute) =>
+    !isAttribute && ['sheet', 'Relationship', 'si', 'r', 'row', 'c'].includes(name)
+})
+
+// text of an element, which is an object when it has attributes such as xml:space
+const xmlText = (node: unknown): string =>
+  node === undefined || node === null
+    ? ''
+    : typeof node === 'object'
+      ? String((node as Record<string, unknown>)['#text'] ?? '')
+      : String(node)
+
+/**
+ * Worksheets of a workbook in their order, with the paths of their xml files
+ */
+export function parseWorkbook(workbookXml: string, relsXml: string): WorksheetEntry[] {
+  const targets = new Map<string, string>()
+  for (const relationship of xmlParser.parse(relsXml).Relationships?.Relationship ?? []) {
+    const target: string = relationship.Target
+    targets.set(relationship.Id, target.startsWith('/') ? target.slice(1) : `xl/${target}`)
+  }
+  return (xmlParser.parse(workbookXml).workbook?.sheets?.sheet ?? [])
+    .map((sheet: Record<string, string>) => ({ name: sheet.name, path: targets.get(sheet.id) ?? '' }))
+    .filter((sheet: WorksheetEntry) => sheet.path)
+}
+
+export function parseSharedStrings(xml: string): string[] {
+  return (xmlParser.parse(xml).sst?.si ?? []).map((item: Record<string, unknown>) =>
+    item.r ? (item.r as Record<string, unknown>[]).map((run) => xmlText(run.t)).join('') : xmlText(item.t)
+  )
+}
+
+// zero based index of the column letters of a cell reference, e.g. AB12
+function columnIndex(reference: string): number {
+  const letters = reference.match(/^[A-Z]+/)?.[0] ?? ''
+  return [...letters].reduce((index, letter) => index * 26 + letter.charCodeAt(0) - 64, 0) - 1
+}
+
+/**
+ * Cells of a worksheet as text, the same as a csv would have them
+ */
+export function parseWorksheet(xml: string, sharedStrings: string[]): string[][] {
+  const rows: string[][] = []
+  for (const row of xmlParser.parse(xml).worksheet?.sheetData?.row ?? []) {
+    const cells: string[] = []
+    for (const cell of row.c ?? []) {
+      const index = cell.r ? columnIndex(cell.r) : cells.length
+      const value = xmlText(cell.v)
+      if (cell.t === 's') cells[index] = sharedStrings[Number(value)] ?? ''
+      else if (cell.t === 'inlineStr') cells[index] = xmlText(cell.is?.t)
+      else if (cell.t === 'b') cells[index] = value === '1' ? 'true' : 'false'
+      else cells[index] = value
+    }
+    rows.push(Array.from(cells, (cell) => cell ?? ''))
+  }
+  return rows
+}
+
+function inferType(cells: string[]): TabularColumnType {
+  if (!cells.length) return 'empty'
+  return TYPE_CHECKS.find(([, check]) => cells.every(check))?.[0] ?? 'string'
+}
+
+function toValue(cell: string, type: TabularColumnType): TabularValue {
+  if (cell === '') return null
+  if (type === 'boolean') return cell.toLowerCase() === 'true'
+  if (type === 'integer' || type === 'number') return Number(cell)
+  return cell
+}
+
+function summarize(name: string, cells: string[]): TabularColumn {
+  const present = cells.map((cell) => cell.trim()).filter((cell) => cell !== '')
+  const type = inferType(present)
+
+  const counts = new Map<string, number>()
+  for (const cell of present) {
+    if (counts.size < MAX_DISTINCT || counts.has(cell)) counts.set(cell, (counts.get(cell) ?? 0) + 1)
+  }
+  const column: TabularColumn = {
+    name,
+    type,
+    count: present.length,
+    missing: cells.length - present.length,
+    distinct: counts.size
+  }
+
+  if (type === 'integer' || type === 'number') {
+    const numbers = present.map(Number)
+    column.min = numbers.reduce((a, b) => Math.min(a, b))
+    column.max = numbers.reduce((a, b) => Math.max(a, b))
+    column.mean = numbers.reduce((a, b) => a + b, 0) / numbers.length
+  } else if (type === 'date') {
+    const byTime = (a: string, b: string) => Date.parse(a) - Date.parse(b)
+    column.min = present.reduce((a, b) => (byTime(a, b) <= 0 ? a : b))
+    column.max = present.reduce((a, b) => (byTime(a, b) >= 0 ? a : b))
+  } else if (type === 'string') {
+    column.top = [...counts]
+      .sort((a, b) => b[1] - a[1])
+      .slice(0, TOP_VALUES)
+      .map(([value, count]) => ({ value, count }))
+  }
+  return column
+}
+
+/**
+ * Turn rows of text cells into a table with typed columns and their statistics.
+ *
+ * Rows without any value are dropped and short rows are padded, so every row has a value for each column.
+ */
+export function buildTable(cells: string[][], header = true): Table {
+  const rows = cells.filter((row) => row.some((cell) => cell.trim() !== ''))
+  const headerRow = header ? (rows.shift() ?? []) : []
+  const width = rows.reduce((max, row) => Math.max(max, row.length), headerRow.length)
+
+  const names = Array.from({ length: width }, (_, index) => headerRow[index]?.trim() || `column_${index + 1}`)
+  const columns = names.map((name, index) => summarize(name, rows.map((row) => row[index] ?? '')))
+  return {
+    columns,
+    rows: rows.map((row) => columns.map((column, index) => toValue((row[index] ?? '').trim(), column.type)))
+  }
+}
+
+/**
+ * Read and parse a whole csv, tsv or xlsx file into a typed table, only the selected worksheet of a workbook
+ */
+export async function readTabularFile(
+  filePath: string,
+  format: TabularFormat,
+  options: ReadTabularOptions
+): Promise<TabularFile> {
+  if (format !== 'xlsx') {
+    const text = await fs.promises.readFile(filePath, 'utf-8')
+    const cells = parseDelimited(text, options.delimiter || (format === 'tsv' ? '\t' : detectDelimiter(text)))
+    return { sheets: [], table: buildTable(cells, options.header) }
+  }
+
+  const zip = new StreamZip.async({ file: filePath })
+  try {
+    const read = async (entry: string) => (await zip.entryData(entry)).toString('utf-8')
+    const entries = await zip.entries()
+
+    const sheets = parseWorkbook(await read('xl/workbook.xml'), await read('xl/_rels/workbook.xml.rels'))
+    const sheet = options.sheet ? sheets.find((item) => item.name === options.sheet) : sheets[0]
+    if (!sheet || !entries[sheet.path]) {
+      throw new ManagerError('TabularService', 'not_found', `Worksheet not found: ${options.sheet ?? ''}`)
+    }
+
+    const sharedStrings = entries[SHARED_STRINGS] ? parseSharedStrings(await read(SHARED_STRINGS)) : []
+    const cells = parseWorksheet(await read(sheet.path), sharedStrings)
+    return { sheets: sheets.map((item) => item.name), sheet: sheet.name, table: buildTable(cells, options.header) }
+  } catch (error) {
+    if (error instanceof ManagerError) throw error
+    throw new ManagerError('TabularService', 'invalid_argument', `Not a valid xlsx file: ${path.basename(filePath)}`)
+  } finally {
+    await zip.close().catch(() => {})
+  }
+}
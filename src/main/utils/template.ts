@@ -0,0 +1,232 @@
+import { ManagerError } from '@shared/ManagerError'
+import dayjs from 'dayjs'
+
+// rendered text above this size is refused, e.g. a template looping over a huge context
+const MAX_OUTPUT = 1024 * 1024
+// nested partials, also stops partials including themselves
+const MAX_PARTIAL_DEPTH = 8
+// only own properties of the context are readable, these never are
+const FORBIDDEN_KEYS = new Set(['__proto__', 'prototype', 'constructor'])
+
+const TAG = /\{\{\s*([#/>]?)\s*(.*?)\s*\}\}/gs
+const ARG = /"((?:[^"\\]|\\.)*)"|'([^']*)'|(\S+)/g
+const BLOCKS = ['if', 'unless', 'each', 'with'] as const
+
+type BlockName = (typeof BLOCKS)[number]
+
+interface BlockNode {
+  type: 'block'
+  name: BlockName
+  expression: string
+  children: TemplateNode[]
+  // rendered after {{else}}, or for an empty each
+  inverse: TemplateNode[]
+}
+
+type TemplateNode =
+  | { type: 'text'; value: string }
+  | { type: 'value'; expression: string }
+  | { type: 'partial'; name: string }
+  | BlockNode
+
+interface Scope {
+  data: unknown
+  // @index, @key, @first and @last inside each blocks
+  locals: Record<string, unknown>
+}
+
+export interface RenderTemplateOptions {
+  // templates included with {{> name}}
+  partials?: Record<string, string>
+  // how values are written, strings as they are and other values as JSON by default
+  format?: (value: unknown) => string
+}
+
+const formatValue = (value: unknown) =>
+  value === undefined || value === null ? '' : typeof value === 'string' ? value : JSON.stringify(value)
+
+const isTruthy = (value: unknown) => (Array.isArray(value) ? value.length > 0 : !!value)
+
+const toText = (value: unknown) => (value === undefined || value === null ? '' : String(value))
+
+/**
+ * Helpers callable as `{{helper arg1 "text" 3}}`, they only transform their arguments
+ */
+export const TEMPLATE_HELPERS: Record<string, (...args: unknown[]) => unknown> = {
+  upper: (value) => toText(value).toUpperCase(),
+  lower: (value) => toText(value).toLowerCase(),
+  trim: (value) => toText(value).trim(),
+  truncate: (value, length = 100) => {
+    const text = toText(value)
+    return text.length > Number(length) ? `${text.slice(0, Number(length))}…` : text
+  },
+  default: (value, fallback) => (value === undefined || value === null || value === '' ? fallback : value),
+  json: (value, indent) => JSON.stringify(value ?? null, null, indent === undefined ? undefined : Number(indent)),
+  date: (value, format = 'YYYY-MM-DD HH:mm') =>
+    dayjs(value === undefined ? undefined : (value as string | number)).format(String(format)),
+  now: (format = 'YYYY-MM-DD HH:mm') => dayjs().format(String(format)),
+  join: (list, separator = ', ') => (Array.isArray(list) ? list.map(toText).join(String(separator)) : toText(list)),
+  length: (value) => (Array.isArray(value) || typeof value === 'string' ? value.length : 0),
+  eq: (a, b) => a === b,
+  not: (value) => !isTruthy(value)
+}
+
+function templateError(message: string) {
+  return new ManagerError('TemplateManager', 'invalid_argument', message)
+}
+
+/**
+ * Parse a template into its nodes, throws on unbalanced blocks
+ */
+export function parseTemplate(source: string): TemplateNode[] {
+  const root: TemplateNode[] = []
+  const stack: { node: BlockNode; inverse: boolean }[] = []
+  const current = () => {
+    const top = stack[stack.length - 1]
+    return top ? (top.inverse ? top.node.inverse : top.node.children) : root
+  }
+
+  let last = 0
+  for (const match of source.matchAll(TAG)) {
+    const [tag, sigil, body] = match
+    if (match.index! > last) current().push({ type: 'text', value: source.slice(last, match.index) })
+    last = match.index! + tag.length
+
+    if (sigil === '#') {
+      const [name, ...rest] = body.split(/\s+/)
+      if (!BLOCKS.includes(name as BlockName)) throw templateError(`Unknown block: {{#${name}}}`)
+      const node: BlockNode = {
+        type: 'block',
+        name: name as BlockName,
+        expression: rest.join(' '),
+        children: [],
+        inverse: []
+      }
+      current().push(node)
+      stack.push({ node, inverse: false })
+    } else if (sigil === '/') {
+      const top = stack.pop()
+      if (top?.node.name !== body) throw templateError(`Unexpected {{/${body}}}`)
+    } else if (sigil === '>') {
+      current().push({ type: 'partial', name: body })
+    } else if (body === 'else') {
+      const top = stack[stack.length - 1]
+      if (!top || top.inverse) throw templateError('Unexpected {{else}}')
+      top.inverse = true
+    } else if (body) {
+      current().push({ type: 'value', expression: body })
+    }
+  }
+  if (last < source.length) current().push({ type: 'text', value: source.slice(last) })
+  if (stack.length) throw templateError(`Unclosed {{#${stack[stack.length - 1].node.name}}}`)
+  return root
+}
+
+function getProperty(target: unknown, key: string): unknown {
+  if (target === undefined || target === null || FORBIDDEN_KEYS.has(key)) return undefined
+  if ((typeof target === 'string' || Array.isArray(target)) && key === 'length') return target.length
+  if (typeof target !== 'object' || !Object.prototype.hasOwnProperty.call(target, key)) return undefined
+  return (target as Record<string, unknown>)[key]
+}
+
+/**
+ * Look a dotted path up in the scopes, from the innermost each/with block outwards
+ */
+function resolvePath(path: string, scopes: Scope[]): unknown {
+  const inner = scopes[scopes.length - 1]
+  if (path === 'this' || path === '.') return inner.data
+  if (path.startsWith('@')) return inner.locals[path.slice(1)]
+
+  const [first, ...rest] = path.replace(/^this\./, '').split('.')
+  const scope = path.startsWith('this.')
+    ? inner
+    : [...scopes].reverse().find((item) => getProperty(item.data, first) !== undefined)
+  return rest.reduce(getProperty, getProperty(scope?.data, first))
+}
+
+function evaluateArgument(token: RegExpMatchArray, scopes: Scope[]): unknown {
+  const [, doubleQuoted, singleQuoted, word] = token
+  if (doubleQuoted !== undefined) return doubleQuoted.replace(/\\(.)/g, '$1')
+  if (singleQuoted !== undefined) return singleQuoted
+  if (/^-?\d+(\.\d+)?$/.test(word)) return Number(word)
+  if (word === 'true' || word === 'false') return word === 'true'
+  if (word === 'null') return null
+  return resolvePath(word, scopes)
+}
+
+function evaluate(expression: string, scopes: Scope[]): unknown {
+  const tokens = [...expression.matchAll(ARG)]
+  const name = tokens[0]?.[3]
+  const helper =
+    name !== undefined && Object.prototype.hasOwnProperty.call(TEMPLATE_HELPERS, name) ? TEMPLATE_HELPERS[name] : null
+
+  if (tokens.length > 1) {
+    if (!helper) throw templateError(`Unknown helper: ${name}`)
+    return helper(...tokens.slice(1).map((token) => evaluateArgument(token, scopes)))
+  }
+  const value = tokens[0] ? evaluateArgument(tokens[0], scopes) : undefined
+  // a helper without arguments, e.g. {{now}}, unless the context has a field with that name
+  return value === undefined && helper ? helper() : value
+}
+
+/**
+ * Render a template with a JSON context.
+ *
+ * Supports `{{path.to.value}}`, helpers (`{{upper name}}`), `{{#if}}`/`{{#unless}}` with `{{else}}`,
+ * `{{#each list}}` with `this`, `@index`, `@key`, `@first` and `@last`, `{{#with object}}` and partials
+ * (`{{> name}}`). Templates can't run code or reach anything but the context's own properties.
+ */
+export function renderTemplate(source: string, context: unknown, options: RenderTemplateOptions = {}): string {
+  const format = options.format ?? formatValue
+  let output = ''
+
+  const write = (text: string) => {
+    output += text
+    if (output.length > MAX_OUTPUT) throw templateError('Rendered template is too large')
+  }
+
+  const render = (nodes: TemplateNode[], scopes: Scope[], depth: number) => {
+    for (const node of nodes) {
+      switch (node.type) {
+        case 'text':
+          write(node.value)
+          break
+        case 'value':
+          write(format(evaluate(node.expression, scopes)))
+          break
+        case 'partial': {
+          const partial = options.partials?.[node.name]
+          if (partial === undefined) throw templateError(`Unknown partial: ${node.name}`)
+          if (depth >= MAX_PARTIAL_DEPTH) throw templateError(`Partials nested too deeply: ${node.name}`)
+          render(parseTemplate(partial), scopes, depth + 1)
+          break
+        }
+        case 'block': {
+          const value = evaluate(node.expression, scopes)
+          if (node.name === 'each') {
+            const entries = Array.isArray(value)
+              ? value.map((item, index) => [index, item] as const)
+              : value && typeof value === 'object'
+                ? Object.entries(value).filter(([key]) => !FORBIDDEN_KEYS.has(key))
+                : []
+            if (!entries.length) render(node.inverse, scopes, depth)
+            entries.forEach(([key, item], index) => {
+              const locals = { index, key, first: index === 0, last: index === entries.length - 1 }
+              render(node.children, [...scopes, { data: item, locals }], depth)
+            })
+          } else if (node.name === 'with') {
+            if (isTruthy(value)) render(node.children, [...scopes, { data: value, locals: {} }], depth)
+            else render(node.inverse, scopes, depth)
+          } else {
+            const show = node.name === 'if' ? isTruthy(value) : !isTruthy(value)
+            render(show ? node.children : node.inverse, scopes, depth)
+          }
+          break
+        }
+      }
+    }
+  }
+
+  render(parseTemplate(source), [{ data: context, locals: {} }], 0)
+  return output
+}
@@ -0,0 +1,65 @@
+import { MCPCallToolResponse, ToolCallQuery } from '@types'
+
+const DEFAULT_LIMIT = 100
+const MAX_LIMIT = 1000
+
+/**
+ * WHERE clause and arguments of a tool call query, newest calls first
+ */
+export function buildToolCallQuery(query: ToolCallQuery = {}): { sql: string; args: (string | number)[] } {
+  const conditions: string[] = []
+  const args: (string | number)[] = []
+
+  const equal = (column: string, value?: string) => {
+    if (value === undefined) return
+    conditions.push(`${column} = ?`)
+    args.push(value)
+  }
+  equal('topic_id', query.topicId)
+  equal('message_id', query.messageId)
+  equal('server_id', query.serverId)
+  equal('tool_name', query.toolName)
+
+  if (query.status) conditions.push(query.status === 'error' ? 'error IS NOT NULL' : 'error IS NULL')
+  if (query.since !== undefined) {
+    conditions.push('started_at >= ?')
+    args.push(query.since)
+  }
+  if (query.until !== undefined) {
+    conditions.push('started_at < ?')
+    args.push(query.until)
+  }
+
+  const limit = Math.min(Math.max(Math.floor(query.limit ?? DEFAULT_LIMIT), 1), MAX_LIMIT)
+  const offset = Math.max(Math.floor(query.offset ?? 0), 0)
+  const where = conditions.length ? ` WHERE ${conditions.join(' AND ')}` : ''
+
+  return {
+    sql: `SELECT * FROM tool_calls${where} ORDER BY started_at DESC LIMIT ${limit} OFFSET ${offset}`,
+    args
+  }
+}
+
+/**
+ * Result as it is recorded, images, audio and blobs are dropped to keep the transcript small
+ */
+export function stripBinaryContent(result: MCPCallToolResponse): MCPCallToolResponse {
+  return {
+    ...result,
+    content: (result.content ?? []).map((item) => {
+      if (item.data) return { ...item, data: '' }
+      if (item.resource?.blob) return { ...item, resource: { ...item.resource, blob: '' } }
+      return item
+    })
+  }
+}
+
+/**
+ * Error of a call, results flagged with `isError` are failures of the tool as well
+ */
+export function getToolCallError(result?: MCPCallToolResponse, error?: unknown): string | undefined {
+  if (error !== undefined) return error instanceof Error ? error.message : String(error)
+  if (!result?.isError) return undefined
+  const text = result.content?.find((item) => item.type === 'text')?.text
+  return text || 'Tool returned an error'
+}
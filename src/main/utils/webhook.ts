@@ -0,0 +1,77 @@
+import crypto from 'node:crypto'
+
+import { renderTemplate } from './template'
+
+export const WEBHOOK_SIGNATURE_HEADER = 'x-cherry-signature'
+export const WEBHOOK_TIMESTAMP_HEADER = 'x-cherry-timestamp'
+// deliveries signed longer ago than this (or this far in the future) are replays or badly skewed clocks
+export const WEBHOOK_TIMESTAMP_TOLERANCE = 5 * 60 * 1000
+
+const getHeader = (header: string | string[] | undefined) => (Array.isArray(header) ? header[0] : (header ?? ''))
+
+/**
+ * Check the `X-Cherry-Signature: sha256=<hex>` header, the HMAC-SHA256 of `<timestamp>.<raw body>` with the
+ * hook secret where `<timestamp>` is the `X-Cherry-Timestamp` header in Unix seconds. Signing the timestamp
+ * keeps a captured delivery from being replayed once it is older than the tolerance.
+ */
+export function verifyWebhookSignature(
+  secret: string,
+  body: Buffer,
+  header: string | string[] | undefined,
+  timestampHeader: string | string[] | undefined,
+  now = Date.now()
+): boolean {
+  const match = /^sha256=([0-9a-f]{64})$/i.exec(getHeader(header))
+  const timestamp = getHeader(timestampHeader)
+  if (!match || !/^\d{1,12}$/.test(timestamp)) return false
+  if (Math.abs(now - Number(timestamp) * 1000) > WEBHOOK_TIMESTAMP_TOLERANCE) return false
+
+  const expected = crypto.createHmac('sha256', secret).update(`${timestamp}.`).update(body).digest()
+  const actual = Buffer.from(match[1], 'hex')
+  return crypto.timingSafeEqual(expected, actual)
+}
+
+/**
+ * Fill a prompt template with the webhook payload, `{{payload}}` is the whole payload and
+ * `{{payload.a.b}}` a nested field. Without a template the payload itself is the prompt.
+ */
+export function renderWebhookPrompt(
+  template: string | undefined,
+  payload: unknown,
+  partials?: Record<string, string>
+): string {
+  const format = (value: unknown) =>
+    value === undefined ? '' : typeof value === 'string' ? value : JSON.stringify(value, null, 2)
+
+  if (!template) return format(payload)
+  return renderTemplate(template, { payload }, { partials, format })
+}
+
+/**
+ * Sliding window rate limiter keyed by hook id
+ */
+export class SlidingWindowLimiter {
+  private hits = new Map<string, number[]>()
+
+  constructor(private windowMs = 60 * 1000) {}
+
+  /**
+   * Record a hit, returns false when the key already reached the limit in the current window
+   */
+  public take(key: string, limit: number, now = Date.now()): boolean {
+    if (limit <= 0) return true
+
+    const recent = (this.hits.get(key) ?? []).filter((time) => now - time < this.windowMs)
+    if (recent.length >= limit) {
+      this.hits.set(key, recent)
+      return false
+    }
+    recent.push(now)
+    this.hits.set(key, recent)
+    return true
+  }
+
+  public reset(key: string) {
+    this.hits.delete(key)
+  }
+}
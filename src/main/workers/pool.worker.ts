@@ -0,0 +1,30 @@
+import { parentPort } from 'node:worker_threads'
+
+import { ManagerError, SerializedManagerError } from '@shared/ManagerError'
+
+import { workerTasks, WorkerTaskType } from './tasks'
+
+export interface WorkerRequest {
+  id: number
+  type: WorkerTaskType
+  args: unknown
+}
+
+export interface WorkerResponse {
+  id: number
+  result?: unknown
+  // manager errors keep their manager and code across the thread boundary
+  error?: SerializedManagerError
+}
+
+parentPort?.on('message', async ({ id, type, args }: WorkerRequest) => {
+  const response: WorkerResponse = { id }
+  try {
+    const task = workerTasks[type] as (args: unknown) => unknown
+    if (!task) throw new ManagerError('WorkerPool', 'invalid_argument', `Unknown task: ${type}`)
+    response.result = await task(args)
+  } catch (error) {
+    response.error = ManagerError.from('WorkerPool', error).toJSON()
+  }
+  parentPort?.postMessage(response)
+})
@@ -0,0 +1,42 @@
+import crypto from 'node:crypto'
+import fs from 'node:fs'
+
+import { indexLines, LineIndex } from '@main/utils/lines'
+import { ReadTabularOptions, readTabularFile, TabularFile } from '@main/utils/tabular'
+import { TabularFormat } from '@types'
+
+// runs inside worker threads, nothing here may import electron or the main process services
+
+function hashFile({ filePath, algorithm }: { filePath: string; algorithm: string }): Promise<string> {
+  return new Promise((resolve, reject) => {
+    const hash = crypto.createHash(algorithm)
+    fs.createReadStream(filePath)
+      .on('error', reject)
+      .on('data', (chunk) => hash.update(chunk))
+      .on('end', () => resolve(hash.digest('hex')))
+  })
+}
+
+function parseTabularFile(args: {
+  filePath: string
+  format: TabularFormat
+  options: ReadTabularOptions
+}): Promise<TabularFile> {
+  return readTabularFile(args.filePath, args.format, args.options)
+}
+
+function indexTextFile({ filePath }: { filePath: string }): Promise<LineIndex> {
+  return indexLines(filePath)
+}
+
+/**
+ * CPU heavy tasks the worker pool runs, by name. Arguments and results are structured cloned between threads.
+ */
+export const workerTasks = {
+  hashFile,
+  indexTextFile,
+  parseTabularFile
+}
+
+export type WorkerTasks = typeof workerTasks
+export type WorkerTaskType = keyof WorkerTasks
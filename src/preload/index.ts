@@ -2,7 +2,86 @@ import type { ExtractChunkData } from '@cherrystudio/embedjs-interfaces'
 import { electronAPI } from '@electron-toolkit/preload'
 import { FeedUrl } from '@shared/config/constant'
 import { IpcChannel } from '@shared/IpcChannel'
-import { FileType, KnowledgeBaseParams, KnowledgeItem, MCPServer, Shortcut, ThemeMode, WebDavConfig } from '@types'
+import { SerializedManagerError } from '@shared/ManagerError'
+import {
+  ApiServerRequest,
+  ApiServerStatus,
+  ClipboardDescriptor,
+  CommandInfo,
+  ConfirmationRule,
+  ContentBlockingConfig,
+  ContentBlockingStatus,
+  ConversationWorkspace,
+  FileType,
+  InstallationIntegrityReport,
+  InstallationRepairResult,
+  InstalledMCPServer,
+  InstalledMiniAppPackage,
+  KnowledgeBaseParams,
+  KnowledgeCitation,
+  KnowledgeItem,
+  LinkPreview,
+  LocalBackendInfo,
+  LocalModelFile,
+  MachinePolicy,
+  MCPCallToolResponse,
+  MCPDependencies,
+  MCPRegistryEntry,
+  MCPServer,
+  MCPServerHealth,
+  MCPServerUpdate,
+  MinAppType,
+  MiniAppAudioState,
+  MiniAppCapture,
+  MiniAppCaptureOptions,
+  MiniAppCookie,
+  MiniAppCookieInput,
+  MiniAppLayout,
+  MiniAppLifecycleEvent,
+  MiniAppNavigationState,
+  MiniAppPermissionDecisions,
+  MiniAppPermissionRequest,
+  MiniAppRegistryEntry,
+  MiniAppStats,
+  MiniAppUpdate,
+  MiniAppWebviewInfo,
+  OutboundDeliveryLog,
+  OutboundEvent,
+  OutboundIntegration,
+  PatchResult,
+  PdfExport,
+  PdfPrintOptions,
+  PendingApproval,
+  PerfSnapshot,
+  RecoveredSession,
+  ScheduledMessage,
+  SessionDraftState,
+  ShareConversationOptions,
+  SharedConversation,
+  SharedConversationMessage,
+  SharePayload,
+  Shortcut,
+  SnapLayout,
+  StartupReport,
+  TabularParseOptions,
+  TabularPreview,
+  TextDiff,
+  TextDiffMode,
+  TextLines,
+  ThemeMode,
+  ToolCallContext,
+  ToolCallQuery,
+  ToolCallRecord,
+  TranscriptFile,
+  TrayStatus,
+  UserTemplate,
+  WebDavConfig,
+  Webhook,
+  WebhookAction,
+  WebhookLogEntry,
+  WorkerPoolStatus,
+  WorkspaceFileChange
+} from '@types'
 import { contextBridge, ipcRenderer, OpenDialogOptions, shell, webUtils } from 'electron'
 import { Notification } from 'src/renderer/src/types/notification'
 import { CreateDirectoryOptions } from 'webdav'
@@ -16,12 +95,17 @@ const api = {
   setProxy: (proxy: string | undefined) => ipcRenderer.invoke(IpcChannel.App_Proxy, proxy),
   checkForUpdate: () => ipcRenderer.invoke(IpcChannel.App_CheckForUpdate),
   showUpdateDialog: () => ipcRenderer.invoke(IpcChannel.App_ShowUpdateDialog),
+  verifyInstallation: (): Promise<InstallationIntegrityReport> => ipcRenderer.invoke(IpcChannel.App_VerifyInstallation),
+  repairInstallation: (): Promise<InstallationRepairResult> => ipcRenderer.invoke(IpcChannel.App_RepairInstallation),
   setLanguage: (lang: string) => ipcRenderer.invoke(IpcChannel.App_SetLanguage, lang),
   setLaunchOnBoot: (isActive: boolean) => ipcRenderer.invoke(IpcChannel.App_SetLaunchOnBoot, isActive),
   setLaunchToTray: (isActive: boolean) => ipcRenderer.invoke(IpcChannel.App_SetLaunchToTray, isActive),
   setTray: (isActive: boolean) => ipcRenderer.invoke(IpcChannel.App_SetTray, isActive),
   setTrayOnClose: (isActive: boolean) => ipcRenderer.invoke(IpcChannel.App_SetTrayOnClose, isActive),
+  setTrayTooltip: (text: string | null) => ipcRenderer.invoke(IpcChannel.App_SetTrayTooltip, text),
+  setTrayStatus: (status: Partial<TrayStatus>) => ipcRenderer.invoke(IpcChannel.App_SetTrayStatus, status),
   setFeedUrl: (feedUrl: FeedUrl) => ipcRenderer.invoke(IpcChannel.App_SetFeedUrl, feedUrl),
+  setContentProtection: (enabled: boolean) => ipcRenderer.invoke(IpcChannel.App_SetContentProtection, enabled),
   setTheme: (theme: ThemeMode) => ipcRenderer.invoke(IpcChannel.App_SetTheme, theme),
   handleZoomFactor: (delta: number, reset: boolean = false) =>
     ipcRenderer.invoke(IpcChannel.App_HandleZoomFactor, delta, reset),
@@ -35,12 +119,20 @@ const api = {
   openWebsite: (url: string) => ipcRenderer.invoke(IpcChannel.Open_Website, url),
   getCacheSize: () => ipcRenderer.invoke(IpcChannel.App_GetCacheSize),
   clearCache: () => ipcRenderer.invoke(IpcChannel.App_ClearCache),
+  onAppError: (callback: (error: SerializedManagerError) => void) => {
+    const listener = (_event: Electron.IpcRendererEvent, error: SerializedManagerError) => callback(error)
+    ipcRenderer.on(IpcChannel.App_Error, listener)
+    return () => {
+      ipcRenderer.off(IpcChannel.App_Error, listener)
+    }
+  },
   notification: {
     send: (notification: Notification) => ipcRenderer.invoke(IpcChannel.Notification_Send, notification)
   },
   system: {
     getDeviceType: () => ipcRenderer.invoke(IpcChannel.System_GetDeviceType),
-    getHostname: () => ipcRenderer.invoke(IpcChannel.System_GetHostname)
+    getHostname: () => ipcRenderer.invoke(IpcChannel.System_GetHostname),
+    getIdleTime: (): Promise<number> => ipcRenderer.invoke(IpcChannel.System_GetIdleTime)
   },
   devTools: {
     toggle: () => ipcRenderer.invoke(IpcChannel.System_ToggleDevTools)
@@ -96,7 +188,8 @@ const api = {
     read: (pathOrUrl: string, encoding?: BufferEncoding) => ipcRenderer.invoke(IpcChannel.Fs_Read, pathOrUrl, encoding)
   },
   export: {
-    toWord: (markdown: string, fileName: string) => ipcRenderer.invoke(IpcChannel.Export_Word, markdown, fileName)
+    toWord: (markdown: string, fileName: string, options?: { title?: string }) =>
+      ipcRenderer.invoke(IpcChannel.Export_Word, markdown, fileName, options)
   },
   openPath: (path: string) => ipcRenderer.invoke(IpcChannel.Open_Path, path),
   shortcuts: {
@@ -120,7 +213,13 @@ const api = {
     search: ({ search, base }: { search: string; base: KnowledgeBaseParams }) =>
       ipcRenderer.invoke(IpcChannel.KnowledgeBase_Search, { search, base }),
     rerank: ({ search, base, results }: { search: string; base: KnowledgeBaseParams; results: ExtractChunkData[] }) =>
-      ipcRenderer.invoke(IpcChannel.KnowledgeBase_Rerank, { search, base, results })
+      ipcRenderer.invoke(IpcChannel.KnowledgeBase_Rerank, { search, base, results }),
+    syncSource: ({ base, source }: { base: KnowledgeBaseParams; source: Record<string, any> }) =>
+      ipcRenderer.invoke(IpcChannel.KnowledgeBase_SyncSource, { base, source }),
+    resolveCitation: (chunk: ExtractChunkData): Promise<KnowledgeCitation> =>
+      ipcRenderer.invoke(IpcChannel.KnowledgeBase_ResolveCitation, chunk),
+    openCitation: (citation: KnowledgeCitation): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.KnowledgeBase_OpenCitation, citation)
   },
   window: {
     setMinimumSize: (width: number, height: number) =>
@@ -142,18 +241,399 @@ const api = {
     clearAuthCache: (projectId: string, clientEmail?: string) =>
       ipcRenderer.invoke(IpcChannel.VertexAI_ClearAuthCache, projectId, clientEmail)
   },
+  awsBedrock: {
+    setCredentials: (credentials: { accessKeyId: string; secretAccessKey: string; sessionToken?: string }) =>
+      ipcRenderer.invoke(IpcChannel.AwsBedrock_SetCredentials, credentials),
+    clearCredentials: () => ipcRenderer.invoke(IpcChannel.AwsBedrock_ClearCredentials),
+    getAccessKeyId: (): Promise<string | undefined> => ipcRenderer.invoke(IpcChannel.AwsBedrock_GetAccessKeyId),
+    signRequest: (
+      request: { method: string; url: string; headers?: Record<string, string>; body?: string },
+      region: string
+    ): Promise<Record<string, string>> => ipcRenderer.invoke(IpcChannel.AwsBedrock_SignRequest, request, region)
+  },
+  apiServer: {
+    getStatus: (): Promise<ApiServerStatus> => ipcRenderer.invoke(IpcChannel.ApiServer_GetStatus),
+    setEnabled: (enabled: boolean): Promise<ApiServerStatus> =>
+      ipcRenderer.invoke(IpcChannel.ApiServer_SetEnabled, enabled),
+    setPort: (port: number): Promise<ApiServerStatus> => ipcRenderer.invoke(IpcChannel.ApiServer_SetPort, port),
+    regenerateToken: (): Promise<ApiServerStatus> => ipcRenderer.invoke(IpcChannel.ApiServer_RegenerateToken),
+    respond: (requestId: string, message: Record<string, any>) =>
+      ipcRenderer.invoke(IpcChannel.ApiServer_Response, requestId, message),
+    publish: (topic: string, event: string, data: any) =>
+      ipcRenderer.invoke(IpcChannel.ApiServer_Publish, topic, event, data),
+    ready: () => ipcRenderer.invoke(IpcChannel.ApiServer_Ready),
+    onRequest: (callback: (request: ApiServerRequest) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, request: ApiServerRequest) => callback(request)
+      ipcRenderer.on(IpcChannel.ApiServer_Request, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.ApiServer_Request, listener)
+      }
+    },
+    onAbort: (callback: (requestId: string) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, requestId: string) => callback(requestId)
+      ipcRenderer.on(IpcChannel.ApiServer_Abort, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.ApiServer_Abort, listener)
+      }
+    }
+  },
+  webhook: {
+    list: (): Promise<Webhook[]> => ipcRenderer.invoke(IpcChannel.Webhook_List),
+    create: (input: { name: string; action: WebhookAction; rateLimit?: number; enabled?: boolean }): Promise<Webhook> =>
+      ipcRenderer.invoke(IpcChannel.Webhook_Create, input),
+    update: (id: string, patch: Partial<Omit<Webhook, 'id' | 'secret' | 'createdAt'>>): Promise<Webhook> =>
+      ipcRenderer.invoke(IpcChannel.Webhook_Update, id, patch),
+    remove: (id: string) => ipcRenderer.invoke(IpcChannel.Webhook_Remove, id),
+    regenerateSecret: (id: string): Promise<Webhook> => ipcRenderer.invoke(IpcChannel.Webhook_RegenerateSecret, id),
+    getLogs: (id?: string): Promise<WebhookLogEntry[]> => ipcRenderer.invoke(IpcChannel.Webhook_GetLogs, id)
+  },
+  outbound: {
+    list: (): Promise<OutboundIntegration[]> => ipcRenderer.invoke(IpcChannel.Outbound_List),
+    save: (
+      integration: Omit<OutboundIntegration, 'id' | 'hasSecret'> & { id?: string },
+      secret?: string
+    ): Promise<OutboundIntegration> => ipcRenderer.invoke(IpcChannel.Outbound_Save, integration, secret),
+    remove: (id: string) => ipcRenderer.invoke(IpcChannel.Outbound_Remove, id),
+    test: (id: string): Promise<void> => ipcRenderer.invoke(IpcChannel.Outbound_Test, id),
+    emit: (event: OutboundEvent, data: Record<string, any>) =>
+      ipcRenderer.invoke(IpcChannel.Outbound_Emit, event, data),
+    getLogs: (id?: string): Promise<OutboundDeliveryLog[]> => ipcRenderer.invoke(IpcChannel.Outbound_GetLogs, id)
+  },
+  tabular: {
+    parse: (filePath: string, options?: TabularParseOptions): Promise<TabularPreview> =>
+      ipcRenderer.invoke(IpcChannel.Tabular_Parse, filePath, options)
+  },
+  textPreview: {
+    readLines: (filePath: string, startLine?: number, count?: number): Promise<TextLines> =>
+      ipcRenderer.invoke(IpcChannel.TextPreview_ReadLines, filePath, startLine, count)
+  },
+  session: {
+    saveDraftState: (state: SessionDraftState): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.Session_SaveDraftState, state),
+    getRecovered: (): Promise<RecoveredSession | null> => ipcRenderer.invoke(IpcChannel.Session_GetRecovered),
+    discardRecovered: (): Promise<void> => ipcRenderer.invoke(IpcChannel.Session_DiscardRecovered)
+  },
+  template: {
+    list: (): Promise<UserTemplate[]> => ipcRenderer.invoke(IpcChannel.Template_List),
+    save: (template: Pick<UserTemplate, 'name' | 'content' | 'description'> & { id?: string }): Promise<UserTemplate> =>
+      ipcRenderer.invoke(IpcChannel.Template_Save, template),
+    remove: (id: string) => ipcRenderer.invoke(IpcChannel.Template_Remove, id),
+    render: (idOrName: string, context: unknown): Promise<string> =>
+      ipcRenderer.invoke(IpcChannel.Template_Render, idOrName, context),
+    renderSource: (source: string, context: unknown): Promise<string> =>
+      ipcRenderer.invoke(IpcChannel.Template_RenderSource, source, context),
+    expandIncludes: (source: string): Promise<string> => ipcRenderer.invoke(IpcChannel.Template_ExpandIncludes, source)
+  },
+  share: {
+    takePending: (): Promise<SharePayload[]> => ipcRenderer.invoke(IpcChannel.Share_TakePending),
+    onReceived: (callback: (payload: SharePayload) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, payload: SharePayload) => callback(payload)
+      ipcRenderer.on(IpcChannel.Share_Received, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Share_Received, listener)
+      }
+    }
+  },
+  clipboard: {
+    classify: (): Promise<ClipboardDescriptor> => ipcRenderer.invoke(IpcChannel.Clipboard_Classify)
+  },
+  linkPreview: {
+    fetch: (url: string): Promise<LinkPreview> => ipcRenderer.invoke(IpcChannel.LinkPreview_Fetch, url)
+  },
+  transcript: {
+    takePending: (): Promise<TranscriptFile[]> => ipcRenderer.invoke(IpcChannel.Transcript_TakePending),
+    onOpened: (callback: (file: TranscriptFile) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, file: TranscriptFile) => callback(file)
+      ipcRenderer.on(IpcChannel.Transcript_Opened, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Transcript_Opened, listener)
+      }
+    }
+  },
+  conversationShare: {
+    share: (
+      topicId: string,
+      title: string,
+      messages: SharedConversationMessage[],
+      options?: ShareConversationOptions
+    ): Promise<SharedConversation> =>
+      ipcRenderer.invoke(IpcChannel.ConversationShare_Share, topicId, title, messages, options),
+    list: (): Promise<SharedConversation[]> => ipcRenderer.invoke(IpcChannel.ConversationShare_List),
+    revoke: (id: string) => ipcRenderer.invoke(IpcChannel.ConversationShare_Revoke, id)
+  },
+  scheduler: {
+    schedule: (input: {
+      topicId: string
+      assistantId: string
+      content: string
+      sendAt: number
+    }): Promise<ScheduledMessage> => ipcRenderer.invoke(IpcChannel.Scheduler_Schedule, input),
+    list: (topicId?: string): Promise<ScheduledMessage[]> => ipcRenderer.invoke(IpcChannel.Scheduler_List, topicId),
+    cancel: (id: string) => ipcRenderer.invoke(IpcChannel.Scheduler_Cancel, id),
+    onCompleted: (callback: (message: ScheduledMessage) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, message: ScheduledMessage) => callback(message)
+      ipcRenderer.on(IpcChannel.Scheduler_Completed, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Scheduler_Completed, listener)
+      }
+    }
+  },
+  conversationWorkspace: {
+    mount: (topicId: string, dirPath: string): Promise<ConversationWorkspace> =>
+      ipcRenderer.invoke(IpcChannel.ConversationWorkspace_Mount, topicId, dirPath),
+    unmount: (topicId: string) => ipcRenderer.invoke(IpcChannel.ConversationWorkspace_Unmount, topicId),
+    list: (): Promise<ConversationWorkspace[]> => ipcRenderer.invoke(IpcChannel.ConversationWorkspace_List),
+    getChanges: (topicId: string): Promise<WorkspaceFileChange[]> =>
+      ipcRenderer.invoke(IpcChannel.ConversationWorkspace_GetChanges, topicId),
+    computeDiff: (oldText: string, newText: string, mode?: TextDiffMode): Promise<TextDiff> =>
+      ipcRenderer.invoke(IpcChannel.ConversationWorkspace_ComputeDiff, oldText, newText, mode),
+    applyPatch: (
+      topicId: string,
+      filePath: string | undefined,
+      unifiedDiff: string,
+      dryRun?: boolean
+    ): Promise<PatchResult> =>
+      ipcRenderer.invoke(IpcChannel.ConversationWorkspace_ApplyPatch, topicId, filePath, unifiedDiff, dryRun)
+  },
+  browserBridge: {
+    getStatus: () => ipcRenderer.invoke(IpcChannel.BrowserBridge_GetStatus),
+    install: (browser: 'chrome' | 'firefox', extensionId: string) =>
+      ipcRenderer.invoke(IpcChannel.BrowserBridge_Install, browser, extensionId),
+    uninstall: (browser: 'chrome' | 'firefox') => ipcRenderer.invoke(IpcChannel.BrowserBridge_Uninstall, browser)
+  },
   config: {
     set: (key: string, value: any, isNotify: boolean = false) =>
       ipcRenderer.invoke(IpcChannel.Config_Set, key, value, isNotify),
     get: (key: string) => ipcRenderer.invoke(IpcChannel.Config_Get, key)
   },
+  accessibility: {
+    getState: () => ipcRenderer.invoke(IpcChannel.Accessibility_GetState),
+    announce: (text: string, politeness?: 'polite' | 'assertive') =>
+      ipcRenderer.invoke(IpcChannel.Accessibility_Announce, text, politeness),
+    onStateChanged: (callback: (state: any) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, state: any) => callback(state)
+      ipcRenderer.on(IpcChannel.Accessibility_StateChanged, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Accessibility_StateChanged, listener)
+      }
+    },
+    onAnnounce: (callback: (data: { text: string; politeness: 'polite' | 'assertive' }) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, data: any) => callback(data)
+      ipcRenderer.on(IpcChannel.Accessibility_Announce, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Accessibility_Announce, listener)
+      }
+    }
+  },
+  lock: {
+    lock: () => ipcRenderer.invoke(IpcChannel.Lock_Lock),
+    unlock: (passphrase: string) => ipcRenderer.invoke(IpcChannel.Lock_Unlock, passphrase),
+    unlockWithBiometric: () => ipcRenderer.invoke(IpcChannel.Lock_UnlockWithBiometric),
+    getState: () => ipcRenderer.invoke(IpcChannel.Lock_GetState),
+    setPassphrase: (passphrase: string | null, currentPassphrase?: string) =>
+      ipcRenderer.invoke(IpcChannel.Lock_SetPassphrase, passphrase, currentPassphrase),
+    setIdleTimeout: (minutes: number) => ipcRenderer.invoke(IpcChannel.Lock_SetIdleTimeout, minutes),
+    onStateChanged: (callback: (locked: boolean) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, locked: boolean) => callback(locked)
+      ipcRenderer.on(IpcChannel.Lock_StateChanged, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Lock_StateChanged, listener)
+      }
+    }
+  },
+  health: {
+    getReport: () => ipcRenderer.invoke(IpcChannel.Health_GetReport),
+    onComponentUnhealthy: (callback: (event: any) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, event: any) => callback(event)
+      ipcRenderer.on(IpcChannel.Health_ComponentUnhealthy, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Health_ComponentUnhealthy, listener)
+      }
+    }
+  },
+  secureTopic: {
+    seal: (topicId: string, payloads: string[]): Promise<string[]> =>
+      ipcRenderer.invoke(IpcChannel.SecureTopic_Seal, topicId, payloads),
+    open: (topicId: string, payloads: string[]): Promise<(string | null)[]> =>
+      ipcRenderer.invoke(IpcChannel.SecureTopic_Open, topicId, payloads),
+    sealFiles: (topicId: string, names: string[]): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.SecureTopic_SealFiles, topicId, names),
+    openFiles: (topicId: string, names: string[]): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.SecureTopic_OpenFiles, topicId, names),
+    removeKey: (topicId: string): Promise<void> => ipcRenderer.invoke(IpcChannel.SecureTopic_RemoveKey, topicId),
+    unlock: (passphrase?: string): Promise<boolean> => ipcRenderer.invoke(IpcChannel.SecureTopic_Unlock, passphrase)
+  },
+  perf: {
+    getSnapshot: (): Promise<PerfSnapshot> => ipcRenderer.invoke(IpcChannel.Perf_GetSnapshot),
+    startTrace: (): Promise<void> => ipcRenderer.invoke(IpcChannel.Perf_StartTrace),
+    stopTrace: (outputPath?: string): Promise<string> => ipcRenderer.invoke(IpcChannel.Perf_StopTrace, outputPath),
+    getStartupReport: (): Promise<StartupReport> => ipcRenderer.invoke(IpcChannel.Perf_GetStartupReport)
+  },
+  log: {
+    previewRedaction: (text: string): Promise<{ text: string; hits: Record<string, number> }> =>
+      ipcRenderer.invoke(IpcChannel.Log_PreviewRedaction, text),
+    getRedactionPatterns: (): Promise<string[]> => ipcRenderer.invoke(IpcChannel.Log_GetRedactionPatterns),
+    setRedactionPatterns: (patterns: string[]): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.Log_SetRedactionPatterns, patterns),
+    exportSupportBundle: (): Promise<string | null> => ipcRenderer.invoke(IpcChannel.Log_ExportSupportBundle)
+  },
+  commands: {
+    list: (filter?: string): Promise<CommandInfo[]> => ipcRenderer.invoke(IpcChannel.Command_List, filter),
+    invoke: (id: string, args?: Record<string, unknown>): Promise<unknown> =>
+      ipcRenderer.invoke(IpcChannel.Command_Invoke, id, args),
+    registerRenderer: (commands: Omit<CommandInfo, 'source'>[]): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.Command_RegisterRenderer, commands)
+  },
+  workerPool: {
+    getStatus: (): Promise<WorkerPoolStatus> => ipcRenderer.invoke(IpcChannel.WorkerPool_GetStatus),
+    setSize: (size: number): Promise<WorkerPoolStatus> => ipcRenderer.invoke(IpcChannel.WorkerPool_SetSize, size)
+  },
+  audit: {
+    getLog: (filter?: Record<string, any>) => ipcRenderer.invoke(IpcChannel.Audit_GetLog, filter),
+    export: (filter?: Record<string, any>, filePath?: string) =>
+      ipcRenderer.invoke(IpcChannel.Audit_Export, filter, filePath)
+  },
+  workspace: {
+    export: (
+      data: string,
+      passphrase: string,
+      filePath?: string
+    ): Promise<{ filePath: string; fingerprint: string } | null> =>
+      ipcRenderer.invoke(IpcChannel.Workspace_Export, data, passphrase, filePath),
+    import: (
+      filePath: string,
+      passphrase: string
+    ): Promise<{ data: string; fingerprint: string; own: boolean; trusted: boolean; createdAt: number }> =>
+      ipcRenderer.invoke(IpcChannel.Workspace_Import, filePath, passphrase),
+    getFingerprint: (): Promise<string> => ipcRenderer.invoke(IpcChannel.Workspace_GetFingerprint),
+    trustSigner: (fingerprint: string): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.Workspace_TrustSigner, fingerprint)
+  },
+  download: {
+    start: (url: string, dest: string, options?: Record<string, any>) =>
+      ipcRenderer.invoke(IpcChannel.Download_Start, url, dest, options),
+    pause: (id: string) => ipcRenderer.invoke(IpcChannel.Download_Pause, id),
+    resume: (id: string) => ipcRenderer.invoke(IpcChannel.Download_Resume, id),
+    cancel: (id: string) => ipcRenderer.invoke(IpcChannel.Download_Cancel, id),
+    remove: (id: string) => ipcRenderer.invoke(IpcChannel.Download_Remove, id),
+    list: (source?: string) => ipcRenderer.invoke(IpcChannel.Download_List, source),
+    getDirectory: (): Promise<string> => ipcRenderer.invoke(IpcChannel.Download_GetDirectory),
+    setDirectory: (directory: string): Promise<string> =>
+      ipcRenderer.invoke(IpcChannel.Download_SetDirectory, directory),
+    onUpdated: (callback: (task: any) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, task: any) => callback(task)
+      ipcRenderer.on(IpcChannel.Download_Updated, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Download_Updated, listener)
+      }
+    }
+  },
+  modelFile: {
+    list: (): Promise<LocalModelFile[]> => ipcRenderer.invoke(IpcChannel.ModelFile_List),
+    download: (url: string, fileName?: string, sha256?: string): Promise<LocalModelFile> =>
+      ipcRenderer.invoke(IpcChannel.ModelFile_Download, url, fileName, sha256),
+    import: (filePath: string): Promise<LocalModelFile> => ipcRenderer.invoke(IpcChannel.ModelFile_Import, filePath),
+    verify: (id: string): Promise<boolean> => ipcRenderer.invoke(IpcChannel.ModelFile_Verify, id),
+    remove: (id: string, deleteFile?: boolean): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.ModelFile_Remove, id, deleteFile),
+    cleanup: (days: number): Promise<LocalModelFile[]> => ipcRenderer.invoke(IpcChannel.ModelFile_Cleanup, days)
+  },
+  localInference: {
+    getBackendInfo: (): Promise<LocalBackendInfo> => ipcRenderer.invoke(IpcChannel.LocalInference_GetBackendInfo),
+    loadModel: (modelId: string): Promise<void> => ipcRenderer.invoke(IpcChannel.LocalInference_LoadModel, modelId),
+    unloadModel: (): Promise<void> => ipcRenderer.invoke(IpcChannel.LocalInference_UnloadModel),
+    // chat, embedding or rerank models, told apart by the metadata in the GGUF header
+    listModels: (kind: 'chat' | 'embedding' | 'rerank'): Promise<LocalModelFile[]> =>
+      ipcRenderer.invoke(IpcChannel.LocalInference_ListModels, kind),
+    getEmbeddingDimensions: (modelId: string): Promise<number> =>
+      ipcRenderer.invoke(IpcChannel.LocalInference_GetEmbeddingDimensions, modelId),
+    chatCompletion: (requestId: string, params: Record<string, any>) =>
+      ipcRenderer.invoke(IpcChannel.LocalInference_ChatCompletion, requestId, params),
+    abort: (requestId: string) => ipcRenderer.invoke(IpcChannel.LocalInference_Abort, requestId),
+    onChunk: (requestId: string, callback: (text: string) => void | Promise<void>) => {
+      // acknowledged once handled, the main process sends larger batches while the renderer is busy
+      const listener = async (
+        _event: Electron.IpcRendererEvent,
+        data: { requestId: string; seq: number; text: string }
+      ) => {
+        if (data.requestId !== requestId) return
+        await callback(data.text)
+        ipcRenderer.invoke(IpcChannel.LocalInference_ChunkAck, data.requestId, data.seq).catch(() => {})
+      }
+      ipcRenderer.on(IpcChannel.LocalInference_Chunk, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.LocalInference_Chunk, listener)
+      }
+    }
+  },
+  feedback: {
+    playSound: (eventName: 'message-received' | 'error' | 'agent-finished') =>
+      ipcRenderer.invoke(IpcChannel.Feedback_PlaySound, eventName),
+    listThemes: () => ipcRenderer.invoke(IpcChannel.Feedback_ListThemes)
+  },
+  memory: {
+    add: (input: Record<string, any>, embeddingParams?: KnowledgeBaseParams) =>
+      ipcRenderer.invoke(IpcChannel.Memory_Add, input, embeddingParams),
+    search: (query: string, limit?: number, embeddingParams?: KnowledgeBaseParams) =>
+      ipcRenderer.invoke(IpcChannel.Memory_Search, query, limit, embeddingParams),
+    list: () => ipcRenderer.invoke(IpcChannel.Memory_List),
+    delete: (id: string) => ipcRenderer.invoke(IpcChannel.Memory_Delete, id),
+    clear: () => ipcRenderer.invoke(IpcChannel.Memory_Clear),
+    export: (filePath?: string) => ipcRenderer.invoke(IpcChannel.Memory_Export, filePath)
+  },
+  archive: {
+    list: () => ipcRenderer.invoke(IpcChannel.Archive_List),
+    add: (conversation: Record<string, any>) => ipcRenderer.invoke(IpcChannel.Archive_Add, conversation),
+    get: (topicId: string) => ipcRenderer.invoke(IpcChannel.Archive_Get, topicId),
+    remove: (topicId: string) => ipcRenderer.invoke(IpcChannel.Archive_Remove, topicId)
+  },
+  policy: {
+    get: (): Promise<MachinePolicy> => ipcRenderer.invoke(IpcChannel.Policy_Get)
+  },
+  actionPolicy: {
+    getRules: (): Promise<ConfirmationRule[]> => ipcRenderer.invoke(IpcChannel.ActionPolicy_GetRules),
+    setRules: (rules: ConfirmationRule[]): Promise<void> => ipcRenderer.invoke(IpcChannel.ActionPolicy_SetRules, rules),
+    listPending: (): Promise<PendingApproval[]> => ipcRenderer.invoke(IpcChannel.ActionPolicy_ListPending),
+    approve: (id: string): Promise<void> => ipcRenderer.invoke(IpcChannel.ActionPolicy_Approve, id),
+    deny: (id: string): Promise<void> => ipcRenderer.invoke(IpcChannel.ActionPolicy_Deny, id),
+    onApprovalRequested: (callback: (approval: PendingApproval) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, approval: PendingApproval) => callback(approval)
+      ipcRenderer.on(IpcChannel.ActionPolicy_ApprovalRequested, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.ActionPolicy_ApprovalRequested, listener)
+      }
+    },
+    onApprovalResolved: (callback: (result: { id: string; approved: boolean }) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, result: { id: string; approved: boolean }) =>
+        callback(result)
+      ipcRenderer.on(IpcChannel.ActionPolicy_ApprovalResolved, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.ActionPolicy_ApprovalResolved, listener)
+      }
+    }
+  },
   miniWindow: {
-    show: () => ipcRenderer.invoke(IpcChannel.MiniWindow_Show),
+    show: (listen?: boolean) => ipcRenderer.invoke(IpcChannel.MiniWindow_Show, listen),
     hide: () => ipcRenderer.invoke(IpcChannel.MiniWindow_Hide),
     close: () => ipcRenderer.invoke(IpcChannel.MiniWindow_Close),
     toggle: () => ipcRenderer.invoke(IpcChannel.MiniWindow_Toggle),
     setPin: (isPinned: boolean) => ipcRenderer.invoke(IpcChannel.MiniWindow_SetPin, isPinned)
   },
+  snapLayout: {
+    list: (): Promise<SnapLayout[]> => ipcRenderer.invoke(IpcChannel.SnapLayout_List),
+    apply: (name: string): Promise<SnapLayout> => ipcRenderer.invoke(IpcChannel.SnapLayout_Apply, name),
+    save: (name: string): Promise<SnapLayout> => ipcRenderer.invoke(IpcChannel.SnapLayout_Save, name),
+    remove: (name: string): Promise<void> => ipcRenderer.invoke(IpcChannel.SnapLayout_Remove, name)
+  },
+  transcription: {
+    open: () => ipcRenderer.invoke(IpcChannel.Transcription_Open),
+    close: () => ipcRenderer.invoke(IpcChannel.Transcription_Close),
+    sendToChat: (text: string) => ipcRenderer.invoke(IpcChannel.Transcription_SendToChat, text)
+  },
+  presentation: {
+    enter: (topicId: string) => ipcRenderer.invoke(IpcChannel.Presentation_Enter, topicId),
+    exit: () => ipcRenderer.invoke(IpcChannel.Presentation_Exit)
+  },
   aes: {
     encrypt: (text: string, secretKey: string, iv: string) =>
       ipcRenderer.invoke(IpcChannel.Aes_Encrypt, text, secretKey, iv),
@@ -165,8 +645,17 @@ const api = {
     restartServer: (server: MCPServer) => ipcRenderer.invoke(IpcChannel.Mcp_RestartServer, server),
     stopServer: (server: MCPServer) => ipcRenderer.invoke(IpcChannel.Mcp_StopServer, server),
     listTools: (server: MCPServer) => ipcRenderer.invoke(IpcChannel.Mcp_ListTools, server),
-    callTool: ({ server, name, args }: { server: MCPServer; name: string; args: any }) =>
-      ipcRenderer.invoke(IpcChannel.Mcp_CallTool, { server, name, args }),
+    callTool: ({
+      server,
+      name,
+      args,
+      context
+    }: {
+      server: MCPServer
+      name: string
+      args: any
+      context?: ToolCallContext
+    }) => ipcRenderer.invoke(IpcChannel.Mcp_CallTool, { server, name, args, context }),
     listPrompts: (server: MCPServer) => ipcRenderer.invoke(IpcChannel.Mcp_ListPrompts, server),
     getPrompt: ({ server, name, args }: { server: MCPServer; name: string; args?: Record<string, any> }) =>
       ipcRenderer.invoke(IpcChannel.Mcp_GetPrompt, { server, name, args }),
@@ -174,7 +663,23 @@ const api = {
     getResource: ({ server, uri }: { server: MCPServer; uri: string }) =>
       ipcRenderer.invoke(IpcChannel.Mcp_GetResource, { server, uri }),
     getInstallInfo: () => ipcRenderer.invoke(IpcChannel.Mcp_GetInstallInfo),
-    checkMcpConnectivity: (server: any) => ipcRenderer.invoke(IpcChannel.Mcp_CheckConnectivity, server)
+    checkMcpConnectivity: (server: any) => ipcRenderer.invoke(IpcChannel.Mcp_CheckConnectivity, server),
+    getRegistry: (refresh?: boolean): Promise<MCPRegistryEntry[]> =>
+      ipcRenderer.invoke(IpcChannel.Mcp_GetRegistry, refresh),
+    checkDependencies: (): Promise<MCPDependencies> => ipcRenderer.invoke(IpcChannel.Mcp_CheckDependencies),
+    getInstalled: (): Promise<InstalledMCPServer[]> => ipcRenderer.invoke(IpcChannel.Mcp_GetInstalled),
+    install: (id: string): Promise<MCPServer> => ipcRenderer.invoke(IpcChannel.Mcp_Install, id),
+    uninstall: (id: string): Promise<void> => ipcRenderer.invoke(IpcChannel.Mcp_Uninstall, id),
+    checkHealth: (): Promise<MCPServerHealth[]> => ipcRenderer.invoke(IpcChannel.Mcp_CheckHealth),
+    checkUpdates: (): Promise<MCPServerUpdate[]> => ipcRenderer.invoke(IpcChannel.Mcp_CheckUpdates),
+    update: (id: string): Promise<InstalledMCPServer> => ipcRenderer.invoke(IpcChannel.Mcp_Update, id),
+    getAutoUpdate: (): Promise<boolean> => ipcRenderer.invoke(IpcChannel.Mcp_GetAutoUpdate),
+    setAutoUpdate: (enabled: boolean): Promise<void> => ipcRenderer.invoke(IpcChannel.Mcp_SetAutoUpdate, enabled),
+    listToolCalls: (query?: ToolCallQuery): Promise<ToolCallRecord[]> =>
+      ipcRenderer.invoke(IpcChannel.Mcp_ListToolCalls, query),
+    getToolCall: (id: string): Promise<ToolCallRecord> => ipcRenderer.invoke(IpcChannel.Mcp_GetToolCall, id),
+    replayToolCall: (id: string): Promise<MCPCallToolResponse> => ipcRenderer.invoke(IpcChannel.Mcp_ReplayToolCall, id),
+    clearToolCalls: (topicId?: string): Promise<void> => ipcRenderer.invoke(IpcChannel.Mcp_ClearToolCalls, topicId)
   },
   shell: {
     openExternal: (url: string, options?: Electron.OpenExternalOptions) => shell.openExternal(url, options)
@@ -218,7 +723,157 @@ const api = {
   },
   webview: {
     setOpenLinkExternal: (webviewId: number, isExternal: boolean) =>
-      ipcRenderer.invoke(IpcChannel.Webview_SetOpenLinkExternal, webviewId, isExternal)
+      ipcRenderer.invoke(IpcChannel.Webview_SetOpenLinkExternal, webviewId, isExternal),
+    clearEphemeralSession: (partition: string) =>
+      ipcRenderer.invoke(IpcChannel.Webview_ClearEphemeralSession, partition),
+    setShortcutPassThrough: (webviewId: number, shortcuts: string[][]) =>
+      ipcRenderer.invoke(IpcChannel.Webview_SetShortcutPassThrough, webviewId, shortcuts),
+    onShortcutForwarded: (callback: (input: Record<string, any>) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, input: Record<string, any>) => callback(input)
+      ipcRenderer.on(IpcChannel.Webview_ShortcutForwarded, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Webview_ShortcutForwarded, listener)
+      }
+    },
+    setContextMenu: (webviewId: number, actions: string[]) =>
+      ipcRenderer.invoke(IpcChannel.Webview_SetContextMenu, webviewId, actions),
+    onContextMenuAction: (callback: (event: Record<string, any>) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, event: Record<string, any>) => callback(event)
+      ipcRenderer.on(IpcChannel.Webview_ContextMenuAction, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.Webview_ContextMenuAction, listener)
+      }
+    },
+    suspend: (webviewId: number) => ipcRenderer.invoke(IpcChannel.Webview_Suspend, webviewId),
+    resume: (webviewId: number) => ipcRenderer.invoke(IpcChannel.Webview_Resume, webviewId),
+    setContentBlocking: (config: ContentBlockingConfig): Promise<ContentBlockingStatus> =>
+      ipcRenderer.invoke(IpcChannel.Webview_SetContentBlocking, config),
+    updateFilterLists: (): Promise<ContentBlockingStatus> => ipcRenderer.invoke(IpcChannel.Webview_UpdateFilterLists)
+  },
+  miniApp: {
+    registerWebview: (appId: string, webviewId: number) =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_RegisterWebview, appId, webviewId),
+    unregisterWebview: (appId: string) => ipcRenderer.invoke(IpcChannel.MiniApp_UnregisterWebview, appId),
+    getIntents: (appId: string): Promise<string[]> => ipcRenderer.invoke(IpcChannel.MiniApp_GetIntents, appId),
+    send: (appId: string, intent: string, payload: unknown) =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_SendIntent, appId, intent, payload),
+    navigate: (appId: string, url: string): Promise<MiniAppNavigationState> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_Navigate, appId, url),
+    goBack: (appId: string): Promise<MiniAppNavigationState> => ipcRenderer.invoke(IpcChannel.MiniApp_GoBack, appId),
+    goForward: (appId: string): Promise<MiniAppNavigationState> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_GoForward, appId),
+    reload: (appId: string, ignoreCache?: boolean): Promise<MiniAppNavigationState> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_Reload, appId, ignoreCache),
+    getNavigationState: (appId: string): Promise<MiniAppNavigationState> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_GetNavigationState, appId),
+    onNavigationChanged: (callback: (state: MiniAppNavigationState) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, state: MiniAppNavigationState) => callback(state)
+      ipcRenderer.on(IpcChannel.MiniApp_NavigationChanged, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.MiniApp_NavigationChanged, listener)
+      }
+    },
+    setZoom: (appId: string, factor: number): Promise<number> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_SetZoom, appId, factor),
+    getZoom: (appId: string): Promise<number> => ipcRenderer.invoke(IpcChannel.MiniApp_GetZoom, appId),
+    capture: (appId: string, options?: MiniAppCaptureOptions): Promise<MiniAppCapture> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_Capture, appId, options),
+    printToPdf: (appId: string, outputPath?: string, options?: PdfPrintOptions): Promise<PdfExport | null> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_PrintToPdf, appId, outputPath, options),
+    getCookies: (appId: string, url?: string): Promise<MiniAppCookie[]> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_GetCookies, appId, url),
+    setCookie: (appId: string, cookie: MiniAppCookieInput): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_SetCookie, appId, cookie),
+    clearCookies: (appId: string): Promise<number> => ipcRenderer.invoke(IpcChannel.MiniApp_ClearCookies, appId),
+    onPermissionRequested: (callback: (request: MiniAppPermissionRequest) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, request: MiniAppPermissionRequest) => callback(request)
+      ipcRenderer.on(IpcChannel.MiniApp_PermissionRequested, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.MiniApp_PermissionRequested, listener)
+      }
+    },
+    respondPermission: (requestId: string, granted: boolean, remember?: boolean): Promise<void> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_RespondPermission, requestId, granted, remember),
+    getPermissions: (): Promise<Record<string, MiniAppPermissionDecisions>> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_GetPermissions),
+    resetPermissions: (appId?: string): Promise<void> => ipcRenderer.invoke(IpcChannel.MiniApp_ResetPermissions, appId),
+    getStats: (appId: string): Promise<MiniAppStats> => ipcRenderer.invoke(IpcChannel.MiniApp_GetStats, appId),
+    onStatsUpdated: (callback: (stats: MiniAppStats[]) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, stats: MiniAppStats[]) => callback(stats)
+      ipcRenderer.on(IpcChannel.MiniApp_StatsUpdated, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.MiniApp_StatsUpdated, listener)
+      }
+    },
+    setMuted: (appId: string, muted: boolean): Promise<MiniAppAudioState> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_SetMuted, appId, muted),
+    getAudioState: (appId: string): Promise<MiniAppAudioState> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_GetAudioState, appId),
+    onAudioStateChanged: (callback: (state: MiniAppAudioState) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, state: MiniAppAudioState) => callback(state)
+      ipcRenderer.on(IpcChannel.MiniApp_AudioStateChanged, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.MiniApp_AudioStateChanged, listener)
+      }
+    },
+    preparePreload: (appId: string, files: string[]): Promise<string> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_PreparePreload, appId, files),
+    postBridgeMessage: (appId: string, data: unknown) =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_PostBridgeMessage, appId, data),
+    setVisible: (appId: string, visible: boolean) => ipcRenderer.invoke(IpcChannel.MiniApp_SetVisible, appId, visible),
+    onLifecycle: (callback: (event: MiniAppLifecycleEvent) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, event: MiniAppLifecycleEvent) => callback(event)
+      ipcRenderer.on(IpcChannel.MiniApp_Lifecycle, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.MiniApp_Lifecycle, listener)
+      }
+    },
+    getAll: (): Promise<MiniAppWebviewInfo[]> => ipcRenderer.invoke(IpcChannel.MiniApp_GetAll),
+    hideAll: (): Promise<string[]> => ipcRenderer.invoke(IpcChannel.MiniApp_HideAll),
+    destroyAll: (): Promise<string[]> => ipcRenderer.invoke(IpcChannel.MiniApp_DestroyAll),
+    restoreLayout: (): Promise<MiniAppLayout | null> => ipcRenderer.invoke(IpcChannel.MiniApp_RestoreLayout),
+    getManifestApps: (): Promise<MinAppType[]> => ipcRenderer.invoke(IpcChannel.MiniApp_GetManifestApps),
+    getRegistry: (refresh?: boolean): Promise<MiniAppRegistryEntry[]> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_GetRegistry, refresh),
+    getInstalledPackages: (): Promise<InstalledMiniAppPackage[]> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_GetInstalledPackages),
+    installPackage: (id: string, confirmed?: boolean): Promise<MinAppType> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_InstallPackage, id, confirmed),
+    installFromUrl: (url: string, confirmed?: boolean): Promise<MinAppType> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_InstallFromUrl, url, confirmed),
+    uninstallPackage: (id: string): Promise<void> => ipcRenderer.invoke(IpcChannel.MiniApp_UninstallPackage, id),
+    getTrustedKeys: (): Promise<string[]> => ipcRenderer.invoke(IpcChannel.MiniApp_GetTrustedKeys),
+    setTrustedKeys: (keys: string[]): Promise<void> => ipcRenderer.invoke(IpcChannel.MiniApp_SetTrustedKeys, keys),
+    checkUpdates: (): Promise<MiniAppUpdate[]> => ipcRenderer.invoke(IpcChannel.MiniApp_CheckUpdates),
+    updatePackage: (id: string, confirmed?: boolean): Promise<MinAppType> =>
+      ipcRenderer.invoke(IpcChannel.MiniApp_UpdatePackage, id, confirmed),
+    getAutoUpdate: (): Promise<boolean> => ipcRenderer.invoke(IpcChannel.MiniApp_GetAutoUpdate),
+    setAutoUpdate: (enabled: boolean): Promise<void> => ipcRenderer.invoke(IpcChannel.MiniApp_SetAutoUpdate, enabled),
+    onPackageUpdated: (callback: (app: MinAppType) => void) => {
+      const listener = (_event: Electron.IpcRendererEvent, app: MinAppType) => callback(app)
+      ipcRenderer.on(IpcChannel.MiniApp_PackageUpdated, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.MiniApp_PackageUpdated, listener)
+      }
+    },
+    onHideAllRequested: (callback: () => void) => {
+      const listener = () => callback()
+      ipcRenderer.on(IpcChannel.MiniApp_HideAllRequested, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.MiniApp_HideAllRequested, listener)
+      }
+    },
+    onDestroyAllRequested: (callback: () => void) => {
+      const listener = () => callback()
+      ipcRenderer.on(IpcChannel.MiniApp_DestroyAllRequested, listener)
+      return () => {
+        ipcRenderer.off(IpcChannel.MiniApp_DestroyAllRequested, listener)
+      }
+    }
+  },
+  print: {
+    toPdf: (outputPath?: string, options?: PdfPrintOptions): Promise<PdfExport | null> =>
+      ipcRenderer.invoke(IpcChannel.Print_ToPdf, outputPath, options)
   },
   storeSync: {
     subscribe: () => ipcRenderer.invoke(IpcChannel.StoreSync_Subscribe),
@@ -231,6 +886,7 @@ const api = {
     determineToolbarSize: (width: number, height: number) =>
       ipcRenderer.invoke(IpcChannel.Selection_ToolbarDetermineSize, width, height),
     setEnabled: (enabled: boolean) => ipcRenderer.invoke(IpcChannel.Selection_SetEnabled, enabled),
+    setPermissionGranted: (granted: boolean) => ipcRenderer.invoke(IpcChannel.Selection_SetPermissionGranted, granted),
     setTriggerMode: (triggerMode: string) => ipcRenderer.invoke(IpcChannel.Selection_SetTriggerMode, triggerMode),
     setFollowToolbar: (isFollowToolbar: boolean) =>
       ipcRenderer.invoke(IpcChannel.Selection_SetFollowToolbar, isFollowToolbar),
@@ -12,6 +12,7 @@ import { CodeStyleProvider } from './context/CodeStyleProvider'
 import { NotificationProvider } from './context/NotificationProvider'
 import StyleSheetManager from './context/StyleSheetManager'
 import { ThemeProvider } from './context/ThemeProvider'
+import AnnouncementHandler from './handler/AnnouncementHandler'
 import NavigationHandler from './handler/NavigationHandler'
 import AgentsPage from './pages/agents/AgentsPage'
 import AppsPage from './pages/apps/AppsPage'
@@ -32,6 +33,7 @@ function App(): React.ReactElement {
               <CodeStyleProvider>
                 <PersistGate loading={null} persistor={persistor}>
                   <TopViewContainer>
+                    <AnnouncementHandler />
                     <HashRouter>
                       <NavigationHandler />
                       <Sidebar />
@@ -151,6 +151,10 @@ export class AihubmixAPIClient extends BaseApiClient {
     return client.getEmbeddingDimensions(model)
   }
 
+  override async embed(texts: string[], model: Model): Promise<number[][]> {
+    return this.getClient(model).embed(texts, model)
+  }
+
   async listModels(): Promise<SdkModel[]> {
     // 可以聚合所有client的模型，或者使用默认client
     return this.defaultClient.listModels()
@@ -1,10 +1,14 @@
+import { isProviderAllowed } from '@renderer/services/PolicyService'
 import { Provider } from '@renderer/types'
 
 import { AihubmixAPIClient } from './AihubmixAPIClient'
 import { AnthropicAPIClient } from './anthropic/AnthropicAPIClient'
+import { AwsBedrockAPIClient } from './aws/AwsBedrockAPIClient'
 import { BaseApiClient } from './BaseApiClient'
 import { GeminiAPIClient } from './gemini/GeminiAPIClient'
 import { VertexAPIClient } from './gemini/VertexAPIClient'
+import { LocalInferenceAPIClient } from './local/LocalInferenceAPIClient'
+import { AzureOpenAIAPIClient } from './openai/AzureOpenAIAPIClient'
 import { OpenAIAPIClient } from './openai/OpenAIApiClient'
 import { OpenAIResponseAPIClient } from './openai/OpenAIResponseAPIClient'
 
@@ -18,6 +22,10 @@ export class ApiClientFactory {
    * 为给定的提供者创建ApiClient实例
    */
   static create(provider: Provider): BaseApiClient {
+    if (!isProviderAllowed(provider.id)) {
+      throw new Error(`Provider ${provider.id} is not allowed by the machine policy`)
+    }
+
     console.log(`[ApiClientFactory] Creating ApiClient for provider:`, {
       id: provider.id,
       type: provider.type
@@ -35,10 +43,12 @@ export class ApiClientFactory {
     // 然后检查标准的provider type
     switch (provider.type) {
       case 'openai':
-      case 'azure-openai':
         console.log(`[ApiClientFactory] Creating OpenAIApiClient for provider: ${provider.id}`)
         instance = new OpenAIAPIClient(provider) as BaseApiClient
         break
+      case 'azure-openai':
+        instance = new AzureOpenAIAPIClient(provider) as BaseApiClient
+        break
       case 'openai-response':
         instance = new OpenAIResponseAPIClient(provider) as BaseApiClient
         break
@@ -51,6 +61,12 @@ export class ApiClientFactory {
       case 'anthropic':
         instance = new AnthropicAPIClient(provider) as BaseApiClient
         break
+      case 'aws-bedrock':
+        instance = new AwsBedrockAPIClient(provider) as BaseApiClient
+        break
+      case 'local':
+        instance = new LocalInferenceAPIClient(provider) as BaseApiClient
+        break
       default:
         console.log(`[ApiClientFactory] Using default OpenAIApiClient for provider: ${provider.id}`)
         instance = new OpenAIAPIClient(provider) as BaseApiClient
@@ -85,6 +85,11 @@ export abstract class BaseApiClient<
 
   abstract getEmbeddingDimensions(model?: Model): Promise<number>
 
+  // 批量向量化，不支持的 provider 直接报错
+  public async embed(texts: string[], model: Model): Promise<number[][]> {
+    throw new Error(`Provider ${this.provider.id} does not support embeddings for ${model.id}`)
+  }
+
   abstract listModels(): Promise<SdkModel[]>
 
   abstract getSdkInstance(): Promise<TSdkInstance> | TSdkInstance
@@ -0,0 +1,134 @@
+import { getAwsBedrockRegion } from '@renderer/hooks/useAwsBedrock'
+import { Model, Provider } from '@renderer/types'
+import OpenAI from 'openai'
+
+import { OpenAIAPIClient } from '../openai/OpenAIApiClient'
+import {
+  getBedrockModelFamily,
+  parseBedrockResponse,
+  toBedrockRequest,
+  toOpenAICompletion,
+  toOpenAIStream
+} from './bedrock'
+
+/**
+ * AWS Bedrock 客户端
+ * 复用 OpenAI 客户端的中间件逻辑，通过自定义 fetch 把 Chat Completions 请求转换为 InvokeModel 请求
+ * 请求由主进程使用保存的凭证进行 SigV4 签名
+ */
+export class AwsBedrockAPIClient extends OpenAIAPIClient {
+  constructor(provider: Provider) {
+    super(provider)
+  }
+
+  override getBaseURL(): string {
+    return this.provider.apiHost || `https://bedrock-runtime.${getAwsBedrockRegion()}.amazonaws.com`
+  }
+
+  override async getSdkInstance() {
+    if (this.sdkInstance) {
+      return this.sdkInstance
+    }
+
+    this.sdkInstance = new OpenAI({
+      dangerouslyAllowBrowser: true,
+      // 实际的鉴权由 SigV4 签名完成
+      apiKey: 'aws-sigv4',
+      baseURL: this.getBaseURL(),
+      fetch: this.bedrockFetch
+    })
+    return this.sdkInstance
+  }
+
+  private async signedFetch(url: string, init: { method?: string; body?: string; signal?: AbortSignal | null } = {}) {
+    const method = init.method || 'POST'
+    const headers = await window.api.awsBedrock.signRequest(
+      { method, url, headers: { accept: 'application/json', 'content-type': 'application/json' }, body: init.body },
+      getAwsBedrockRegion()
+    )
+    return fetch(url, { method, headers, body: init.body, signal: init.signal })
+  }
+
+  private bedrockFetch = async (input: RequestInfo | URL, init?: RequestInit): Promise<Response> => {
+    const url = new URL(input instanceof Request ? input.url : input.toString())
+    if (!url.pathname.endsWith('/chat/completions')) {
+      throw new Error(`AWS Bedrock does not support ${url.pathname}`)
+    }
+
+    const params = JSON.parse(String(init?.body)) as OpenAI.Chat.Completions.ChatCompletionCreateParams
+    const family = getBedrockModelFamily(params.model)
+    if (!family) {
+      throw new Error(`Unsupported AWS Bedrock model: ${params.model}`)
+    }
+
+    const action = params.stream ? 'invoke-with-response-stream' : 'invoke'
+    const endpoint = `${this.getBaseURL()}/model/${encodeURIComponent(params.model)}/${action}`
+    const response = await this.signedFetch(endpoint, {
+      body: JSON.stringify(toBedrockRequest(family, params)),
+      signal: init?.signal
+    })
+
+    // 错误响应原样返回，由 OpenAI SDK 生成对应的错误
+    if (!response.ok || !response.body) {
+      return response
+    }
+
+    if (!params.stream) {
+      const completion = toOpenAICompletion(params.model, parseBedrockResponse(family, await response.json()))
+      return new Response(JSON.stringify(completion), { headers: { 'content-type': 'application/json' } })
+    }
+
+    return new Response(toOpenAIStream(family, params.model, response.body), {
+      headers: { 'content-type': 'text/event-stream' }
+    })
+  }
+
+  private async invoke(modelId: string, body: Record<string, any>): Promise<any> {
+    const response = await this.signedFetch(`${this.getBaseURL()}/model/${encodeURIComponent(modelId)}/invoke`, {
+      body: JSON.stringify(body)
+    })
+    const data = await response.json()
+    if (!response.ok) {
+      throw new Error(data.message || response.statusText)
+    }
+    return data
+  }
+
+  override async embed(texts: string[], model: Model): Promise<number[][]> {
+    if (model.id.startsWith('cohere.embed')) {
+      const data = await this.invoke(model.id, { texts, input_type: 'search_document' })
+      return data.embeddings
+    }
+
+    // Titan Embeddings 每次请求只接受一条文本
+    return Promise.all(texts.map(async (inputText) => (await this.invoke(model.id, { inputText })).embedding))
+  }
+
+  override async getEmbeddingDimensions(model: Model): Promise<number> {
+    const [embedding] = await this.embed(['hi'], model)
+    return embedding.length
+  }
+
+  override async listModels(): Promise<OpenAI.Models.Model[]> {
+    try {
+      const endpoint = `https://bedrock.${getAwsBedrockRegion()}.amazonaws.com/foundation-models`
+      const response = await this.signedFetch(endpoint, { method: 'GET' })
+      const data = await response.json()
+      if (!response.ok) {
+        throw new Error(data.message || response.statusText)
+      }
+
+      return (data.modelSummaries || [])
+        .filter((model: any) => getBedrockModelFamily(model.modelId) || model.outputModalities?.includes('EMBEDDING'))
+        .map((model: any) => ({
+          id: model.modelId,
+          object: 'model',
+          created: 0,
+          owned_by: model.providerName
+        }))
+    } catch (error) {
+      console.error('Error listing models:', error)
+      return []
+    }
+  }
+}
@@ -0,0 +1,181 @@
+import { describe, expect, it } from 'vitest'
+
+import {
+  decodeBedrockEvent,
+  EventStreamDecoder,
+  getBedrockModelFamily,
+  parseBedrockResponse,
+  parseBedrockStreamChunk,
+  toBedrockRequest
+} from '../bedrock'
+
+// 构造一条 event stream 消息，只包含 string 类型的头部，CRC 填 0
+function encodeMessage(headers: Record<string, string>, payload: string): Uint8Array {
+  const encoder = new TextEncoder()
+  const headerBytes: number[] = []
+  for (const [name, value] of Object.entries(headers)) {
+    const nameBytes = encoder.encode(name)
+    const valueBytes = encoder.encode(value)
+    headerBytes.push(nameBytes.length, ...nameBytes, 7, valueBytes.length >> 8, valueBytes.length & 0xff, ...valueBytes)
+  }
+  const payloadBytes = encoder.encode(payload)
+  const totalLength = 12 + headerBytes.length + payloadBytes.length + 4
+
+  const message = new Uint8Array(totalLength)
+  const view = new DataView(message.buffer)
+  view.setUint32(0, totalLength)
+  view.setUint32(4, headerBytes.length)
+  message.set(headerBytes, 12)
+  message.set(payloadBytes, 12 + headerBytes.length)
+  return message
+}
+
+function encodeChunk(chunk: Record<string, any>): Uint8Array {
+  const payload = JSON.stringify({ bytes: btoa(JSON.stringify(chunk)) })
+  return encodeMessage({ ':event-type': 'chunk', ':message-type': 'event' }, payload)
+}
+
+describe('bedrock', () => {
+  describe('getBedrockModelFamily', () => {
+    it('should detect the model family', () => {
+      expect(getBedrockModelFamily('anthropic.claude-3-5-haiku-20241022-v1:0')).toBe('anthropic')
+      expect(getBedrockModelFamily('meta.llama3-1-70b-instruct-v1:0')).toBe('meta')
+      expect(getBedrockModelFamily('mistral.mistral-large-2407-v1:0')).toBe('mistral')
+      expect(getBedrockModelFamily('amazon.nova-pro-v1:0')).toBe('amazon')
+    })
+
+    it('should handle cross-region inference profiles', () => {
+      expect(getBedrockModelFamily('us.anthropic.claude-3-7-sonnet-20250219-v1:0')).toBe('anthropic')
+      expect(getBedrockModelFamily('eu.amazon.nova-lite-v1:0')).toBe('amazon')
+    })
+
+    it('should return undefined for unsupported models', () => {
+      expect(getBedrockModelFamily('amazon.titan-text-express-v1')).toBeUndefined()
+      expect(getBedrockModelFamily('cohere.command-r-v1:0')).toBeUndefined()
+    })
+  })
+
+  describe('toBedrockRequest', () => {
+    const messages: any[] = [
+      { role: 'system', content: 'Be brief' },
+      { role: 'user', content: 'Hello' },
+      { role: 'user', content: [{ type: 'image_url', image_url: { url: 'data:image/png;base64,AAAA' } }] },
+      { role: 'assistant', content: 'Hi' },
+      { role: 'user', content: 'Bye' }
+    ]
+
+    it('should map anthropic requests and merge consecutive turns', () => {
+      const body = toBedrockRequest('anthropic', { model: 'anthropic.claude', messages, temperature: 0.5 })
+
+      expect(body.anthropic_version).toBe('bedrock-2023-05-31')
+      expect(body.system).toBe('Be brief')
+      expect(body.max_tokens).toBe(4096)
+      expect(body.temperature).toBe(0.5)
+      expect(body.messages).toHaveLength(3)
+      expect(body.messages[0].content).toEqual([
+        { type: 'text', text: 'Hello' },
+        { type: 'image', source: { type: 'base64', media_type: 'image/png', data: 'AAAA' } }
+      ])
+    })
+
+    it('should build a llama prompt', () => {
+      const body = toBedrockRequest('meta', { model: 'meta.llama3', messages, max_tokens: 100 })
+
+      const prompt: string = body.prompt
+
+      expect(body.max_gen_len).toBe(100)
+      expect(prompt.startsWith('<|begin_of_text|><|start_header_id|>system<|end_header_id|>\n\nBe brief')).toBe(true)
+      expect(prompt.endsWith('Bye<|eot_id|><|start_header_id|>assistant<|end_header_id|>\n\n')).toBe(true)
+    })
+
+    it('should build a mistral prompt with the system prompt in the first turn', () => {
+      const body = toBedrockRequest('mistral', { model: 'mistral.large', messages })
+
+      expect(body.prompt).toBe('<s>[INST] Be brief\n\nHello [/INST]Hi</s>[INST] Bye [/INST]')
+    })
+
+    it('should map nova requests', () => {
+      const body = toBedrockRequest('amazon', { model: 'amazon.nova-pro-v1:0', messages, top_p: 0.9 })
+
+      expect(body.system).toEqual([{ text: 'Be brief' }])
+      expect(body.messages[0].content[1]).toEqual({ image: { format: 'png', source: { bytes: 'AAAA' } } })
+      expect(body.inferenceConfig.topP).toBe(0.9)
+    })
+  })
+
+  describe('parseBedrockResponse', () => {
+    it('should parse anthropic responses', () => {
+      const result = parseBedrockResponse('anthropic', {
+        content: [
+          { type: 'thinking', thinking: 'hmm' },
+          { type: 'text', text: 'Answer' }
+        ],
+        stop_reason: 'end_turn',
+        usage: { input_tokens: 10, output_tokens: 5 }
+      })
+
+      expect(result).toEqual({
+        text: 'Answer',
+        reasoning: 'hmm',
+        finishReason: 'end_turn',
+        promptTokens: 10,
+        completionTokens: 5
+      })
+    })
+
+    it('should parse nova responses', () => {
+      const result = parseBedrockResponse('amazon', {
+        output: { message: { content: [{ text: 'Answer' }] } },
+        stopReason: 'end_turn',
+        usage: { inputTokens: 3, outputTokens: 2 }
+      })
+
+      expect(result.text).toBe('Answer')
+      expect(result.promptTokens).toBe(3)
+    })
+  })
+
+  describe('event stream', () => {
+    it('should decode messages split across chunks', () => {
+      const first = encodeChunk({ type: 'content_block_delta', delta: { type: 'text_delta', text: 'Hel' } })
+      const second = encodeChunk({ type: 'content_block_delta', delta: { type: 'text_delta', text: 'lo' } })
+      const bytes = new Uint8Array([...first, ...second])
+
+      const decoder = new EventStreamDecoder()
+      const messages = [...decoder.push(bytes.slice(0, 20)), ...decoder.push(bytes.slice(20))]
+
+      expect(messages).toHaveLength(2)
+      expect(messages[0].headers[':event-type']).toBe('chunk')
+      expect(decodeBedrockEvent(messages[1]).delta.text).toBe('lo')
+    })
+
+    it('should throw on exception messages', () => {
+      const message = encodeMessage(
+        { ':exception-type': 'throttlingException', ':message-type': 'exception' },
+        JSON.stringify({ message: 'Too many requests' })
+      )
+      const [decoded] = new EventStreamDecoder().push(message)
+
+      expect(() => decodeBedrockEvent(decoded)).toThrow('throttlingException: Too many requests')
+    })
+
+    it('should collect anthropic usage and stop reason from stream chunks', () => {
+      const state = { promptTokens: 0, completionTokens: 0 }
+
+      parseBedrockStreamChunk('anthropic', { type: 'message_start', message: { usage: { input_tokens: 12 } } }, state)
+      const delta = parseBedrockStreamChunk(
+        'anthropic',
+        { type: 'content_block_delta', delta: { type: 'thinking_delta', thinking: 'hmm' } },
+        state
+      )
+      parseBedrockStreamChunk(
+        'anthropic',
+        { type: 'message_delta', delta: { stop_reason: 'max_tokens' }, usage: { output_tokens: 7 } },
+        state
+      )
+
+      expect(delta).toEqual({ reasoning: 'hmm' })
+      expect(state).toEqual({ promptTokens: 12, completionTokens: 7, finishReason: 'max_tokens' })
+    })
+  })
+})
@@ -0,0 +1,480 @@
+import OpenAI from 'openai'
+
+/**
+ * Bedrock 各模型家族的请求 / 响应格式不同，这里负责和 OpenAI Chat Completions 格式互相转换
+ */
+export type BedrockModelFamily = 'anthropic' | 'meta' | 'mistral' | 'amazon'
+
+type ChatParams = OpenAI.Chat.Completions.ChatCompletionCreateParams
+type ChatMessage = OpenAI.Chat.Completions.ChatCompletionMessageParam
+
+type ContentPart = { type: 'text'; text: string } | { type: 'image'; mediaType: string; data: string }
+
+interface Turn {
+  role: 'user' | 'assistant'
+  parts: ContentPart[]
+}
+
+export interface BedrockResult {
+  text: string
+  reasoning?: string
+  finishReason?: string
+  promptTokens: number
+  completionTokens: number
+}
+
+export interface BedrockStreamState {
+  finishReason?: string
+  promptTokens: number
+  completionTokens: number
+}
+
+export interface EventStreamMessage {
+  headers: Record<string, string>
+  payload: Uint8Array
+}
+
+const ANTHROPIC_VERSION = 'bedrock-2023-05-31'
+const DEFAULT_MAX_TOKENS = 4096
+
+// 跨区域推理配置文件的前缀，例如 us.anthropic.claude-3-7-sonnet-20250219-v1:0
+const CROSS_REGION_PREFIXES = ['us', 'us-gov', 'eu', 'apac']
+
+export function getBedrockModelFamily(modelId: string): BedrockModelFamily | undefined {
+  const parts = modelId.split('.')
+  const vendor = CROSS_REGION_PREFIXES.includes(parts[0]) && parts.length > 2 ? parts[1] : parts[0]
+
+  switch (vendor) {
+    case 'anthropic':
+    case 'meta':
+    case 'mistral':
+      return vendor
+    case 'amazon':
+      // Titan 文本模型已停止更新，只支持 Nova
+      return modelId.includes('.nova-') ? 'amazon' : undefined
+    default:
+      return undefined
+  }
+}
+
+function getContentParts(content: ChatMessage['content']): ContentPart[] {
+  if (!content) {
+    return []
+  }
+  if (typeof content === 'string') {
+    return [{ type: 'text', text: content }]
+  }
+
+  return (content as OpenAI.Chat.Completions.ChatCompletionContentPart[]).flatMap((part): ContentPart[] => {
+    if (part.type === 'text' && part.text) {
+      return [{ type: 'text', text: part.text }]
+    }
+    if (part.type === 'image_url') {
+      const match = /^data:(image\/[\w.+-]+);base64,(.*)$/.exec(part.image_url.url)
+      if (match) {
+        return [{ type: 'image', mediaType: match[1], data: match[2] }]
+      }
+    }
+    return []
+  })
+}
+
+function getText(parts: ContentPart[]): string {
+  return parts
+    .filter((part) => part.type === 'text')
+    .map((part) => part.text)
+    .join('\n')
+}
+
+function splitMessages(messages: ChatMessage[]): { system: string; turns: Turn[] } {
+  const system: string[] = []
+  const turns: Turn[] = []
+
+  for (const message of messages) {
+    const parts = getContentParts(message.content)
+
+    if (message.role === 'system' || message.role === 'developer') {
+      system.push(getText(parts))
+      continue
+    }
+    if (parts.length === 0) {
+      continue
+    }
+
+    const role = message.role === 'assistant' ? 'assistant' : 'user'
+    const last = turns[turns.length - 1]
+
+    // Claude 和 Nova 要求 user / assistant 交替出现，合并连续的同角色消息
+    if (last && last.role === role) {
+      last.parts.push(...parts)
+    } else {
+      turns.push({ role, parts })
+    }
+  }
+
+  return { system: system.filter(Boolean).join('\n\n'), turns }
+}
+
+function toLlamaPrompt(system: string, turns: Turn[]): string {
+  let prompt = '<|begin_of_text|>'
+  if (system) {
+    prompt += `<|start_header_id|>system<|end_header_id|>\n\n${system}<|eot_id|>`
+  }
+  for (const turn of turns) {
+    prompt += `<|start_header_id|>${turn.role}<|end_header_id|>\n\n${getText(turn.parts)}<|eot_id|>`
+  }
+  return prompt + '<|start_header_id|>assistant<|end_header_id|>\n\n'
+}
+
+function toMistralPrompt(system: string, turns: Turn[]): string {
+  let prompt = '<s>'
+  turns.forEach((turn, index) => {
+    const text = getText(turn.parts)
+    if (turn.role === 'user') {
+      // Mistral 没有 system 角色，拼接到第一条用户消息前
+      prompt += `[INST] ${index === 0 && system ? `${system}\n\n${text}` : text} [/INST]`
+    } else {
+      prompt += `${text}</s>`
+    }
+  })
+  return prompt
+}
+
+/**
+ * 把 OpenAI 格式的请求转换为对应模型家族的 InvokeModel 请求体
+ * 工具调用等扩展参数不会被转换
+ */
+export function toBedrockRequest(family: BedrockModelFamily, params: ChatParams): Record<string, any> {
+  const { system, turns } = splitMessages(params.messages)
+  const maxTokens = params.max_completion_tokens ?? params.max_tokens ?? undefined
+  const temperature = params.temperature ?? undefined
+  const topP = params.top_p ?? undefined
+
+  switch (family) {
+    case 'anthropic':
+      return {
+        anthropic_version: ANTHROPIC_VERSION,
+        max_tokens: maxTokens || DEFAULT_MAX_TOKENS,
+        system: system || undefined,
+        messages: turns.map((turn) => ({
+          role: turn.role,
+          content: turn.parts.map((part) =>
+            part.type === 'text'
+              ? { type: 'text', text: part.text }
+              : { type: 'image', source: { type: 'base64', media_type: part.mediaType, data: part.data } }
+          )
+        })),
+        temperature,
+        top_p: topP
+      }
+    case 'meta':
+      return {
+        prompt: toLlamaPrompt(system, turns),
+        max_gen_len: maxTokens,
+        temperature,
+        top_p: topP
+      }
+    case 'mistral':
+      return {
+        prompt: toMistralPrompt(system, turns),
+        max_tokens: maxTokens,
+        temperature,
+        top_p: topP
+      }
+    case 'amazon':
+      return {
+        schemaVersion: 'messages-v1',
+        system: system ? [{ text: system }] : undefined,
+        messages: turns.map((turn) => ({
+          role: turn.role,
+          content: turn.parts.map((part) =>
+            part.type === 'text'
+              ? { text: part.text }
+              : { image: { format: part.mediaType.split('/')[1], source: { bytes: part.data } } }
+          )
+        })),
+        inferenceConfig: { maxTokens, temperature, topP }
+      }
+  }
+}
+
+export function parseBedrockResponse(family: BedrockModelFamily, body: any): BedrockResult {
+  switch (family) {
+    case 'anthropic': {
+      const content: any[] = body.content || []
+      return {
+        text: content
+          .filter((block) => block.type === 'text')
+          .map((block) => block.text)
+          .join(''),
+        reasoning: content
+          .filter((block) => block.type === 'thinking')
+          .map((block) => block.thinking)
+          .join(''),
+        finishReason: body.stop_reason,
+        promptTokens: body.usage?.input_tokens || 0,
+        completionTokens: body.usage?.output_tokens || 0
+      }
+    }
+    case 'meta':
+      return {
+        text: body.generation || '',
+        finishReason: body.stop_reason,
+        promptTokens: body.prompt_token_count || 0,
+        completionTokens: body.generation_token_count || 0
+      }
+    case 'mistral':
+      return {
+        text: body.outputs?.[0]?.text || '',
+        finishReason: body.outputs?.[0]?.stop_reason,
+        promptTokens: 0,
+        completionTokens: 0
+      }
+    case 'amazon':
+      return {
+        text: (body.output?.message?.content || []).map((block: any) => block.text || '').join(''),
+        finishReason: body.stopReason,
+        promptTokens: body.usage?.inputTokens || 0,
+        completionTokens: body.usage?.outputTokens || 0
+      }
+  }
+}
+
+/**
+ * 解析流式响应中的一个 chunk，用量和结束原因记录在 state 中，流结束时统一返回
+ */
+export function parseBedrockStreamChunk(
+  family: BedrockModelFamily,
+  chunk: any,
+  state: BedrockStreamState
+): { text?: string; reasoning?: string } {
+  // 所有模型家族的最后一个 chunk 都会带上调用统计
+  const metrics = chunk['amazon-bedrock-invocationMetrics']
+  if (metrics) {
+    state.promptTokens = metrics.inputTokenCount ?? state.promptTokens
+    state.completionTokens = metrics.outputTokenCount ?? state.completionTokens
+  }
+
+  switch (family) {
+    case 'anthropic':
+      if (chunk.type === 'message_start') {
+        state.promptTokens = chunk.message?.usage?.input_tokens || state.promptTokens
+      }
+      if (chunk.type === 'message_delta') {
+        state.finishReason = chunk.delta?.stop_reason || state.finishReason
+        state.completionTokens = chunk.usage?.output_tokens || state.completionTokens
+      }
+      if (chunk.type === 'content_block_delta') {
+        if (chunk.delta?.type === 'thinking_delta') {
+          return { reasoning: chunk.delta.thinking }
+        }
+        if (chunk.delta?.type === 'text_delta') {
+          return { text: chunk.delta.text }
+        }
+      }
+      return {}
+    case 'meta':
+      state.finishReason = chunk.stop_reason || state.finishReason
+      return { text: chunk.generation }
+    case 'mistral':
+      state.finishReason = chunk.outputs?.[0]?.stop_reason || state.finishReason
+      return { text: chunk.outputs?.[0]?.text }
+    case 'amazon':
+      if (chunk.messageStop) {
+        state.finishReason = chunk.messageStop.stopReason
+      }
+      if (chunk.metadata?.usage) {
+        state.promptTokens = chunk.metadata.usage.inputTokens || state.promptTokens
+        state.completionTokens = chunk.metadata.usage.outputTokens || state.completionTokens
+      }
+      return { text: chunk.contentBlockDelta?.delta?.text }
+  }
+}
+
+function toFinishReason(reason?: string): 'stop' | 'length' {
+  return reason === 'max_tokens' || reason === 'length' ? 'length' : 'stop'
+}
+
+export function toOpenAICompletion(model: string, result: BedrockResult): OpenAI.Chat.Completions.ChatCompletion {
+  const message: OpenAI.Chat.Completions.ChatCompletionMessage & { reasoning_content?: string } = {
+    role: 'assistant',
+    content: result.text,
+    refusal: null
+  }
+  if (result.reasoning) {
+    message.reasoning_content = result.reasoning
+  }
+
+  return {
+    id: `bedrock-${Date.now()}`,
+    object: 'chat.completion',
+    created: Math.floor(Date.now() / 1000),
+    model,
+    choices: [{ index: 0, message, finish_reason: toFinishReason(result.finishReason), logprobs: null }],
+    usage: {
+      prompt_tokens: result.promptTokens,
+      completion_tokens: result.completionTokens,
+      total_tokens: result.promptTokens + result.completionTokens
+    }
+  }
+}
+
+function decodeHeaders(bytes: Uint8Array): Record<string, string> {
+  const view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength)
+  const decoder = new TextDecoder()
+  const headers: Record<string, string> = {}
+  let offset = 0
+
+  while (offset < bytes.length) {
+    const nameLength = bytes[offset++]
+    const name = decoder.decode(bytes.subarray(offset, offset + nameLength))
+    offset += nameLength
+    const type = bytes[offset++]
+
+    switch (type) {
+      // boolean true / false，没有值
+      case 0:
+      case 1:
+        headers[name] = String(type === 0)
+        break
+      case 2:
+        offset += 1
+        break
+      case 3:
+        offset += 2
+        break
+      case 4:
+        offset += 4
+        break
+      case 5:
+      case 8:
+        offset += 8
+        break
+      // bytes / string，2 字节长度前缀
+      case 6:
+      case 7: {
+        const length = view.getUint16(offset)
+        offset += 2
+        if (type === 7) {
+          headers[name] = decoder.decode(bytes.subarray(offset, offset + length))
+        }
+        offset += length
+        break
+      }
+      case 9:
+        offset += 16
+        break
+      default:
+        throw new Error(`Unknown event stream header type: ${type}`)
+    }
+  }
+
+  return headers
+}
+
+/**
+ * 解码 AWS event stream（application/vnd.amazon.eventstream）
+ * 每条消息：总长度(4) + 头部长度(4) + prelude CRC(4) + 头部 + 负载 + 消息 CRC(4)
+ * 传输完整性由 TLS 保证，这里不校验 CRC
+ */
+export class EventStreamDecoder {
+  private buffer = new Uint8Array(0)
+
+  push(chunk: Uint8Array): EventStreamMessage[] {
+    const merged = new Uint8Array(this.buffer.length + chunk.length)
+    merged.set(this.buffer)
+    merged.set(chunk, this.buffer.length)
+    this.buffer = merged
+
+    const messages: EventStreamMessage[] = []
+    while (this.buffer.length >= 12) {
+      const view = new DataView(this.buffer.buffer, this.buffer.byteOffset, this.buffer.byteLength)
+      const totalLength = view.getUint32(0)
+      if (this.buffer.length < totalLength) {
+        break
+      }
+
+      const headersLength = view.getUint32(4)
+      messages.push({
+        headers: decodeHeaders(this.buffer.subarray(12, 12 + headersLength)),
+        payload: this.buffer.slice(12 + headersLength, totalLength - 4)
+      })
+      this.buffer = this.buffer.slice(totalLength)
+    }
+
+    return messages
+  }
+}
+
+/**
+ * 取出 InvokeModelWithResponseStream 事件中的模型输出，非 chunk 事件返回 undefined
+ */
+export function decodeBedrockEvent(message: EventStreamMessage): any {
+  const payload = JSON.parse(new TextDecoder().decode(message.payload))
+  const messageType = message.headers[':message-type']
+
+  if (messageType === 'exception' || messageType === 'error') {
+    const type = message.headers[':exception-type'] || message.headers[':error-code']
+    throw new Error(`${type}: ${payload.message || message.headers[':error-message']}`)
+  }
+  if (message.headers[':event-type'] !== 'chunk' || !payload.bytes) {
+    return undefined
+  }
+
+  const bytes = Uint8Array.from(atob(payload.bytes), (c) => c.charCodeAt(0))
+  return JSON.parse(new TextDecoder().decode(bytes))
+}
+
+/**
+ * 把 Bedrock 的 event stream 转换为 OpenAI 的 SSE 流，供 OpenAI SDK 直接消费
+ */
+export function toOpenAIStream(
+  family: BedrockModelFamily,
+  model: string,
+  body: ReadableStream<Uint8Array>
+): ReadableStream<Uint8Array> {
+  const decoder = new EventStreamDecoder()
+  const encoder = new TextEncoder()
+  const state: BedrockStreamState = { promptTokens: 0, completionTokens: 0 }
+  const base = {
+    id: `bedrock-${Date.now()}`,
+    object: 'chat.completion.chunk',
+    created: Math.floor(Date.now() / 1000),
+    model
+  }
+
+  const toEvent = (data: Record<string, any>) => encoder.encode(`data: ${JSON.stringify({ ...base, ...data })}\n\n`)
+
+  return body.pipeThrough(
+    new TransformStream<Uint8Array, Uint8Array>({
+      transform(chunk, controller) {
+        for (const message of decoder.push(chunk)) {
+          const event = decodeBedrockEvent(message)
+          if (!event) continue
+
+          const { text, reasoning } = parseBedrockStreamChunk(family, event, state)
+          if (reasoning) {
+            controller.enqueue(toEvent({ choices: [{ index: 0, delta: { reasoning_content: reasoning } }] }))
+          }
+          if (text) {
+            controller.enqueue(toEvent({ choices: [{ index: 0, delta: { content: text } }] }))
+          }
+        }
+      },
+      flush(controller) {
+        // 用量需要和 finish_reason 一起发送
+        controller.enqueue(
+          toEvent({
+            choices: [{ index: 0, delta: {}, finish_reason: toFinishReason(state.finishReason) }],
+            usage: {
+              prompt_tokens: state.promptTokens,
+              completion_tokens: state.completionTokens,
+              total_tokens: state.promptTokens + state.completionTokens
+            }
+          })
+        )
+        controller.enqueue(encoder.encode('data: [DONE]\n\n'))
+      }
+    })
+  )
+}
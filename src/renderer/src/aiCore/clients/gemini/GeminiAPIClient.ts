@@ -155,6 +155,16 @@ export class GeminiAPIClient extends BaseApiClient<
     return data.embeddings?.[0]?.values?.length || 0
   }
 
+  override async embed(texts: string[], model: Model): Promise<number[][]> {
+    const sdk = await this.getSdkInstance()
+
+    const data = await sdk.models.embedContent({
+      model: model.id,
+      contents: texts.map((text) => ({ role: 'user', parts: [{ text }] }))
+    })
+    return (data.embeddings || []).map((embedding) => embedding.values || [])
+  }
+
   override async listModels(): Promise<GeminiModel[]> {
     const sdk = await this.getSdkInstance()
     const response = await sdk.models.list()
@@ -0,0 +1,108 @@
+import { Model, Provider } from '@renderer/types'
+import OpenAI from 'openai'
+import { v4 as uuidv4 } from 'uuid'
+
+import { OpenAIAPIClient } from '../openai/OpenAIApiClient'
+import { createChunkEncoder, LocalChatCompletion, toLocalRequest, toOpenAICompletion } from './local'
+
+/**
+ * 本地推理客户端
+ * 复用 OpenAI 客户端的中间件逻辑，通过自定义 fetch 把 Chat Completions 请求交给主进程的 llama.cpp 运行时
+ * 模型是本地模型设置中已下载的 GGUF 文件，同一时间只加载一个模型
+ */
+export class LocalInferenceAPIClient extends OpenAIAPIClient {
+  constructor(provider: Provider) {
+    super(provider)
+  }
+
+  override getBaseURL(): string {
+    return 'http://local.inference'
+  }
+
+  override async getSdkInstance() {
+    if (this.sdkInstance) {
+      return this.sdkInstance
+    }
+
+    this.sdkInstance = new OpenAI({
+      dangerouslyAllowBrowser: true,
+      apiKey: 'local',
+      baseURL: this.getBaseURL(),
+      fetch: this.localFetch
+    })
+    return this.sdkInstance
+  }
+
+  private localFetch = async (input: RequestInfo | URL, init?: RequestInit): Promise<Response> => {
+    const url = new URL(input instanceof Request ? input.url : input.toString())
+    if (!url.pathname.endsWith('/chat/completions')) {
+      throw new Error(`Local inference does not support ${url.pathname}`)
+    }
+
+    const params = JSON.parse(String(init?.body)) as OpenAI.Chat.Completions.ChatCompletionCreateParams
+    const requestId = uuidv4()
+    const request = toLocalRequest(params)
+    init?.signal?.addEventListener('abort', () => window.api.localInference.abort(requestId))
+
+    if (!params.stream) {
+      const completion: LocalChatCompletion = await window.api.localInference.chatCompletion(requestId, request)
+      return new Response(JSON.stringify(toOpenAICompletion(params.model, completion)), {
+        headers: { 'content-type': 'application/json' }
+      })
+    }
+
+    const events = createChunkEncoder(params.model)
+    let removeListener: (() => void) | undefined
+    let resume: (() => void) | undefined
+    const body = new ReadableStream<Uint8Array>({
+      start(controller) {
+        removeListener = window.api.localInference.onChunk(requestId, (text) => {
+          controller.enqueue(events.text(text))
+          // 读取方取走之后才确认，主进程在等待确认时把后续文本合并成更大的批次
+          if ((controller.desiredSize ?? 1) > 0) return
+          return new Promise<void>((resolve) => (resume = resolve))
+        })
+        window.api.localInference
+          .chatCompletion(requestId, request)
+          .then((completion: LocalChatCompletion) => {
+            events.finish(completion).forEach((event) => controller.enqueue(event))
+            controller.close()
+          })
+          .catch((error: unknown) => controller.error(error))
+          .finally(() => removeListener?.())
+      },
+      pull() {
+        resume?.()
+        resume = undefined
+      },
+      cancel() {
+        resume?.()
+        removeListener?.()
+        window.api.localInference.abort(requestId)
+      }
+    })
+
+    return new Response(body, { headers: { 'content-type': 'text/event-stream' } })
+  }
+
+  override async embed(): Promise<number[][]> {
+    throw new Error('Local embedding models are used by knowledge bases only')
+  }
+
+  override async getEmbeddingDimensions(model: Model): Promise<number> {
+    return window.api.localInference.getEmbeddingDimensions(model.id)
+  }
+
+  // 嵌入和重排序模型在知识库中选择，这里只列出对话模型
+  override async listModels(): Promise<OpenAI.Models.Model[]> {
+    const files = await window.api.localInference.listModels('chat')
+    return files.map((file) => ({
+      id: file.id,
+      // 模型 id 是文件 id，列表中显示文件名
+      display_name: file.name,
+      object: 'model' as const,
+      created: Math.floor(file.addedAt / 1000),
+      owned_by: 'local'
+    }))
+  }
+}
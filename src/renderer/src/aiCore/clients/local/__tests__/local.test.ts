@@ -0,0 +1,58 @@
+import { describe, expect, it } from 'vitest'
+
+import { createChunkEncoder, toLocalRequest, toOpenAICompletion } from '../local'
+
+describe('local', () => {
+  describe('toLocalRequest', () => {
+    it('should convert messages to text and merge consecutive roles', () => {
+      const request = toLocalRequest({
+        model: 'model-id',
+        messages: [
+          { role: 'developer', content: 'be brief' },
+          { role: 'user', content: 'hello' },
+          {
+            role: 'user',
+            content: [
+              { type: 'text', text: 'look at this' },
+              { type: 'image_url', image_url: { url: 'data:image/png;base64,AAAA' } }
+            ]
+          },
+          { role: 'assistant', content: 'hi' },
+          { role: 'tool', content: 'result', tool_call_id: 'call' },
+          { role: 'user', content: 'bye' }
+        ],
+        temperature: 0.5,
+        max_tokens: 100,
+        stop: 'END'
+      })
+
+      expect(request).toEqual({
+        modelId: 'model-id',
+        messages: [
+          { role: 'system', content: 'be brief' },
+          { role: 'user', content: 'hello\n\nlook at this' },
+          { role: 'assistant', content: 'hi' },
+          { role: 'user', content: 'bye' }
+        ],
+        temperature: 0.5,
+        topP: undefined,
+        maxTokens: 100,
+        stop: ['END']
+      })
+    })
+  })
+
+  it('should convert completions to the OpenAI format', () => {
+    const usage = { prompt_tokens: 3, completion_tokens: 2, total_tokens: 5 }
+    const completion = toOpenAICompletion('model-id', { content: 'answer', usage })
+    expect(completion.choices[0].message.content).toBe('answer')
+    expect(completion.usage).toEqual(usage)
+
+    const decoder = new TextDecoder()
+    const events = createChunkEncoder('model-id')
+    expect(decoder.decode(events.text('an'))).toContain('"delta":{"content":"an"}')
+    const [finish, done] = events.finish({ content: 'answer', usage })
+    expect(JSON.parse(decoder.decode(finish).slice('data: '.length)).usage).toEqual(usage)
+    expect(decoder.decode(done)).toBe('data: [DONE]\n\n')
+  })
+})
@@ -0,0 +1,116 @@
+import OpenAI from 'openai'
+
+/**
+ * 本地推理（主进程中的 llama.cpp）的请求 / 响应格式和 OpenAI Chat Completions 格式互相转换
+ */
+type ChatParams = OpenAI.Chat.Completions.ChatCompletionCreateParams
+type ChatMessage = OpenAI.Chat.Completions.ChatCompletionMessageParam
+
+export interface LocalChatMessage {
+  role: 'system' | 'user' | 'assistant'
+  content: string
+}
+
+export interface LocalChatCompletionParams {
+  modelId: string
+  messages: LocalChatMessage[]
+  temperature?: number
+  topP?: number
+  maxTokens?: number
+  stop?: string[]
+}
+
+export interface LocalChatCompletion {
+  content: string
+  usage: { prompt_tokens: number; completion_tokens: number; total_tokens: number }
+}
+
+function getText(content: ChatMessage['content']): string {
+  if (!content) {
+    return ''
+  }
+  if (typeof content === 'string') {
+    return content
+  }
+  // 本地模型只接受文本，图片等内容被忽略
+  return (content as OpenAI.Chat.Completions.ChatCompletionContentPart[])
+    .map((part) => (part.type === 'text' ? part.text : ''))
+    .filter(Boolean)
+    .join('\n')
+}
+
+/**
+ * 工具调用相关的消息和参数不会被转换
+ */
+export function toLocalRequest(params: ChatParams): LocalChatCompletionParams {
+  const messages: LocalChatMessage[] = []
+  for (const message of params.messages) {
+    const content = getText(message.content)
+    if (!content) continue
+
+    const role = message.role === 'system' || message.role === 'developer' ? 'system' : message.role
+    if (role !== 'system' && role !== 'user' && role !== 'assistant') continue
+
+    // 连续的同角色消息合并为一条，聊天模板要求 user / assistant 交替出现
+    const last = messages[messages.length - 1]
+    if (last && last.role === role) {
+      last.content += `\n\n${content}`
+    } else {
+      messages.push({ role, content })
+    }
+  }
+
+  const stop = typeof params.stop === 'string' ? [params.stop] : (params.stop ?? undefined)
+  return {
+    modelId: params.model,
+    messages,
+    temperature: params.temperature ?? undefined,
+    topP: params.top_p ?? undefined,
+    maxTokens: params.max_completion_tokens ?? params.max_tokens ?? undefined,
+    stop
+  }
+}
+
+export function toOpenAICompletion(
+  model: string,
+  completion: LocalChatCompletion
+): OpenAI.Chat.Completions.ChatCompletion {
+  return {
+    id: `local-${Date.now()}`,
+    object: 'chat.completion',
+    created: Math.floor(Date.now() / 1000),
+    model,
+    choices: [
+      {
+        index: 0,
+        message: { role: 'assistant', content: completion.content, refusal: null },
+        finish_reason: 'stop',
+        logprobs: null
+      }
+    ],
+    usage: completion.usage
+  }
+}
+
+/**
+ * 把本地推理的文本块转换为 OpenAI 的 SSE 事件，供 OpenAI SDK 直接消费
+ */
+export function createChunkEncoder(model: string) {
+  const encoder = new TextEncoder()
+  const base = {
+    id: `local-${Date.now()}`,
+    object: 'chat.completion.chunk',
+    created: Math.floor(Date.now() / 1000),
+    model
+  }
+  const toEvent = (data: Record<string, any>) => encoder.encode(`data: ${JSON.stringify({ ...base, ...data })}\n\n`)
+
+  return {
+    text: (text: string) => toEvent({ choices: [{ index: 0, delta: { content: text } }] }),
+    // 用量需要和 finish_reason 一起发送
+    finish: (completion: LocalChatCompletion) => [
+      toEvent({ choices: [{ index: 0, delta: {}, finish_reason: 'stop' }], usage: completion.usage }),
+      encoder.encode('data: [DONE]\n\n')
+    ]
+  }
+}
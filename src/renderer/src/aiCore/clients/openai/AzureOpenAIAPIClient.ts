@@ -0,0 +1,34 @@
+import { Model, Provider } from '@renderer/types'
+import { OpenAISdkParams, OpenAISdkRawOutput } from '@renderer/types/sdk'
+import OpenAI from 'openai'
+
+import { OpenAIAPIClient } from './OpenAIApiClient'
+
+/**
+ * Azure OpenAI 按部署名路由请求
+ * 模型可以单独配置部署名，未配置时部署名与模型 ID 相同
+ */
+export class AzureOpenAIAPIClient extends OpenAIAPIClient {
+  constructor(provider: Provider) {
+    super(provider)
+  }
+
+  private getDeployment(modelId: string): string {
+    return this.provider.models.find((model) => model.id === modelId)?.deployment || modelId
+  }
+
+  override async createCompletions(
+    payload: OpenAISdkParams,
+    options?: OpenAI.RequestOptions
+  ): Promise<OpenAISdkRawOutput> {
+    return super.createCompletions({ ...payload, model: this.getDeployment(payload.model) }, options)
+  }
+
+  override async embed(texts: string[], model: Model): Promise<number[][]> {
+    return super.embed(texts, { ...model, id: this.getDeployment(model.id) })
+  }
+
+  override async getEmbeddingDimensions(model: Model): Promise<number> {
+    return super.getEmbeddingDimensions({ ...model, id: this.getDeployment(model.id) })
+  }
+}
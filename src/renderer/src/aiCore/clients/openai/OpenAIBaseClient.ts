@@ -5,6 +5,7 @@ import {
   isSupportedModel,
   isSupportedReasoningEffortOpenAIModel
 } from '@renderer/config/models'
+import { AZURE_OPENAI_DEFAULT_API_VERSION } from '@renderer/config/providers'
 import { getStoreSetting } from '@renderer/hooks/useSettings'
 import { getAssistantSettings } from '@renderer/services/AssistantService'
 import store from '@renderer/store'
@@ -94,6 +95,17 @@ export abstract class OpenAIBaseClient<
     return data.data[0].embedding.length
   }
 
+  override async embed(texts: string[], model: Model): Promise<number[][]> {
+    const sdk = await this.getSdkInstance()
+
+    const data = await sdk.embeddings.create({
+      model: model.id,
+      input: texts,
+      encoding_format: 'float'
+    })
+    return [...data.data].sort((a, b) => a.index - b.index).map((item) => item.embedding)
+  }
+
   override async listModels(): Promise<OpenAI.Models.Model[]> {
     try {
       const sdk = await this.getSdkInstance()
@@ -149,7 +161,7 @@ export abstract class OpenAIBaseClient<
       this.sdkInstance = new AzureOpenAI({
         dangerouslyAllowBrowser: true,
         apiKey: apiKeyForSdkInstance,
-        apiVersion: this.provider.apiVersion,
+        apiVersion: this.provider.apiVersion || AZURE_OPENAI_DEFAULT_API_VERSION,
         endpoint: this.provider.apiHost
       }) as TSdkInstance
     } else {
@@ -1,6 +1,7 @@
 import { ApiClientFactory } from '@renderer/aiCore/clients/ApiClientFactory'
 import { BaseApiClient } from '@renderer/aiCore/clients/BaseApiClient'
 import { isDedicatedImageGenerationModel, isFunctionCallingModel } from '@renderer/config/models'
+import { withEmbeddingCache } from '@renderer/services/ResponseCacheService'
 import type { GenerateImageParams, Model, Provider } from '@renderer/types'
 import { RequestOptions, SdkModel } from '@renderer/types/sdk'
 import { isEnabledToolUse } from '@renderer/utils/mcp-tools'
@@ -118,6 +119,10 @@ export default class AiProvider {
     }
   }
 
+  public async embed(texts: string[], model: Model): Promise<number[][]> {
+    return withEmbeddingCache(model, texts, (missing) => this.apiClient.embed(missing, model))
+  }
+
   public async generateImage(params: GenerateImageParams): Promise<string[]> {
     return this.apiClient.generateImage(params)
   }
@@ -0,0 +1,70 @@
+import { acquireProviderSlot, hasProviderLimits } from '@renderer/services/RateLimitService'
+import { estimateTextTokens } from '@renderer/services/TokenService'
+import { addAbortController, removeAbortController } from '@renderer/utils/abortController'
+import { getMainTextContent } from '@renderer/utils/messageUtils/find'
+
+import { CompletionsParams, CompletionsResult } from '../schemas'
+import type { CompletionsContext, CompletionsMiddleware } from '../types'
+
+export const MIDDLEWARE_NAME = 'RateLimitMiddleware'
+
+function estimateRequestTokens(params: CompletionsParams): number {
+  const content =
+    typeof params.messages === 'string' ? params.messages : params.messages.map(getMainTextContent).join('\n')
+  return estimateTextTokens(`${params.assistant.prompt ?? ''}\n${content}`) + (params.maxTokens ?? 0)
+}
+
+/**
+ * 排队等待时的中止信号
+ * 递归调用使用顶层请求的中止信号，顶层请求此时还没有创建 AbortController，按用户消息 ID 注册一个临时的
+ */
+function getWaitSignal(ctx: CompletionsContext, params: CompletionsParams) {
+  const signal = ctx._internal.flowControl?.abortSignal
+  const messageId =
+    typeof params.messages === 'string' ? undefined : params.messages.findLast((m) => m.role === 'user')?.id
+  if (signal || !messageId) return { signal, done: () => {} }
+
+  const controller = new AbortController()
+  const abortFn = () => controller.abort()
+  addAbortController(messageId, abortFn)
+  return { signal: controller.signal, done: () => removeAbortController(messageId, abortFn) }
+}
+
+/**
+ * 限流中间件
+ *
+ * 按 provider 配置的 RPM / TPM / 最大并发排队等待，直到响应流被完全消费后才释放额度，
+ * 并用实际的 usage 替换预估的 token 数
+ * 工具调用的递归请求同样计入 RPM / TPM，但共用顶层请求的并发额度，避免并发为 1 时死锁
+ * 排队中的请求在用户中止时立即结束等待
+ */
+export const RateLimitMiddleware: CompletionsMiddleware =
+  () =>
+  (next) =>
+  async (ctx: CompletionsContext, params: CompletionsParams): Promise<CompletionsResult> => {
+    const provider = ctx.apiClientInstance.provider
+    if (!hasProviderLimits(provider)) {
+      return next(ctx, params)
+    }
+
+    const nested = !!(
+      params._internal?.toolProcessingState?.isRecursiveCall || ctx._internal.toolProcessingState?.isRecursiveCall
+    )
+    const lane = params.callType ?? 'chat'
+    const { signal, done } = getWaitSignal(ctx, params)
+    let release: (tokens?: number) => void
+    try {
+      release = await acquireProviderSlot(provider, lane, estimateRequestTokens(params), { nested, signal })
+    } finally {
+      done()
+    }
+
+    try {
+      const result = await next(ctx, params)
+      // 递归请求的用量累计在同一个 observer 中，由顶层请求统一记录
+      release(nested ? 0 : ctx._internal.observer?.usage?.total_tokens || undefined)
+      return result
+    } finally {
+      release()
+    }
+  }
@@ -0,0 +1,92 @@
+import Logger from '@renderer/config/logger'
+import {
+  getCachedResponse,
+  getResponseCacheKey,
+  isResponseCacheEnabled,
+  isSampledResponseCacheEnabled,
+  setCachedResponse
+} from '@renderer/services/ResponseCacheService'
+import { ChunkType } from '@renderer/types/chunk'
+import { findFileBlocks, findImageBlocks, getMainTextContent } from '@renderer/utils/messageUtils/find'
+
+import { CompletionsParams, CompletionsResult } from '../schemas'
+import type { CompletionsContext, CompletionsMiddleware } from '../types'
+
+export const MIDDLEWARE_NAME = 'ResponseCacheMiddleware'
+
+/**
+ * 只缓存温度为 0 的非对话调用（包括话题命名等摘要调用），温度大于 0 的调用需要用户选择后才缓存
+ */
+function isCacheable(params: CompletionsParams): boolean {
+  if (!isResponseCacheEnabled()) return false
+  if (!params.callType || params.callType === 'chat' || params.callType === 'check') return false
+  if (params.mcpTools?.length || params.enableWebSearch || params.enableGenerateImage) return false
+
+  if (typeof params.messages !== 'string') {
+    const hasAttachments = params.messages.some((m) => findFileBlocks(m).length || findImageBlocks(m).length)
+    if (hasAttachments) return false
+  }
+
+  // 没有设置温度时使用服务商的默认值，通常大于 0
+  const temperature = params.temperature ?? params.assistant.settings?.temperature
+  return temperature === 0 || isSampledResponseCacheEnabled()
+}
+
+async function buildCacheKey(params: CompletionsParams): Promise<string> {
+  const { assistant } = params
+  const messages =
+    typeof params.messages === 'string'
+      ? params.messages.trim()
+      : params.messages.map((m) => ({ role: m.role, content: getMainTextContent(m).trim() }))
+
+  return getResponseCacheKey({
+    provider: assistant.model?.provider,
+    model: assistant.model?.id,
+    prompt: assistant.prompt?.trim(),
+    messages,
+    temperature: params.temperature ?? assistant.settings?.temperature,
+    topP: params.topP ?? assistant.settings?.topP,
+    maxTokens: params.maxTokens,
+    enableReasoning: params.enableReasoning,
+    reasoningEffort: assistant.settings?.reasoning_effort,
+    responseSchema: params.responseSchema
+  })
+}
+
+/**
+ * 响应缓存中间件
+ *
+ * 位于中间件链最外层，命中缓存时直接返回，不再请求 provider
+ */
+export const ResponseCacheMiddleware: CompletionsMiddleware =
+  () =>
+  (next) =>
+  async (ctx: CompletionsContext, params: CompletionsParams): Promise<CompletionsResult> => {
+    if (!isCacheable(params)) {
+      return next(ctx, params)
+    }
+
+    let key: string | undefined
+    try {
+      key = await buildCacheKey(params)
+      const cached = await getCachedResponse(key)
+      if (cached !== null) {
+        Logger.debug(`[${MIDDLEWARE_NAME}] Cache hit for ${params.callType}`)
+        params.onChunk?.({ type: ChunkType.TEXT_COMPLETE, text: cached })
+        params.onResponse?.(cached, true)
+        return { getText: () => cached }
+      }
+    } catch (error) {
+      Logger.warn(`[${MIDDLEWARE_NAME}] Failed to read cache:`, error)
+    }
+
+    const result = await next(ctx, params)
+
+    if (key) {
+      setCachedResponse(key, params.assistant.model?.id ?? '', result.getText()).catch((error) =>
+        Logger.warn(`[${MIDDLEWARE_NAME}] Failed to write cache:`, error)
+      )
+    }
+
+    return result
+  }
@@ -0,0 +1,67 @@
+import Logger from '@renderer/config/logger'
+import { validateStructuredResponse } from '@renderer/utils/structuredOutput'
+
+import { CompletionsParams, CompletionsResult } from '../schemas'
+import type { CompletionsContext, CompletionsMiddleware } from '../types'
+
+export const MIDDLEWARE_NAME = 'StructuredOutputMiddleware'
+
+const DEFAULT_MAX_RETRIES = 2
+
+export class StructuredOutputError extends Error {
+  constructor(
+    public readonly errors: string[],
+    public readonly text: string
+  ) {
+    super(`Structured output validation failed: ${errors.join('; ')}`)
+    this.name = 'StructuredOutputError'
+  }
+}
+
+function withInstruction(params: CompletionsParams, instruction: string): CompletionsParams {
+  const prompt = params.assistant.prompt ? `${params.assistant.prompt}\n\n${instruction}` : instruction
+  return { ...params, assistant: { ...params.assistant, prompt } }
+}
+
+/**
+ * 结构化输出中间件
+ *
+ * 要求模型按 responseSchema 返回 JSON，校验失败时把错误反馈给模型重试，
+ * 成功后在 result.parsed 中返回解析后的对象
+ */
+export const StructuredOutputMiddleware: CompletionsMiddleware =
+  () =>
+  (next) =>
+  async (ctx: CompletionsContext, params: CompletionsParams): Promise<CompletionsResult> => {
+    const schema = params.responseSchema
+    if (!schema) {
+      return next(ctx, params)
+    }
+
+    const schemaInstruction =
+      'Respond only with a JSON value that conforms to the following JSON Schema, without any other text:\n' +
+      JSON.stringify(schema)
+    const maxRetries = params.maxValidationRetries ?? DEFAULT_MAX_RETRIES
+
+    let attemptParams = withInstruction(params, schemaInstruction)
+    for (let attempt = 0; ; attempt++) {
+      const result = await next(ctx, attemptParams)
+      const text = result.getText()
+      const validation = validateStructuredResponse(schema, text)
+
+      if (validation.valid) {
+        return { ...result, parsed: validation.data }
+      }
+
+      Logger.warn(`[${MIDDLEWARE_NAME}] Attempt ${attempt + 1} failed validation:`, validation.errors)
+      if (attempt >= maxRetries) {
+        throw new StructuredOutputError(validation.errors, text)
+      }
+
+      const correction =
+        `Your previous response was:\n${text}\n\n` +
+        `It is invalid for these reasons:\n${validation.errors.map((error) => `- ${error}`).join('\n')}\n` +
+        'Return a corrected response.'
+      attemptParams = withInstruction(params, `${schemaInstruction}\n\n${correction}`)
+    }
+  }
@@ -0,0 +1,32 @@
+import { recordStat } from '@renderer/services/StatsManager'
+
+import { CompletionsParams, CompletionsResult } from '../schemas'
+import type { CompletionsContext, CompletionsMiddleware } from '../types'
+
+export const MIDDLEWARE_NAME = 'UsageStatsMiddleware'
+
+/**
+ * 使用统计中间件
+ *
+ * 位于最终消费者之外，响应流消费完成后按模型记录 token 用量，工具调用的递归请求已累加在顶层请求中
+ */
+export const UsageStatsMiddleware: CompletionsMiddleware =
+  () =>
+  (next) =>
+  async (ctx: CompletionsContext, params: CompletionsParams): Promise<CompletionsResult> => {
+    const isRecursiveCall =
+      params._internal?.toolProcessingState?.isRecursiveCall ||
+      ctx._internal?.toolProcessingState?.isRecursiveCall ||
+      false
+    const result = await next(ctx, params)
+
+    const usage = ctx._internal.observer?.usage
+    const model = params.assistant.model
+    if (!isRecursiveCall && usage && model) {
+      const key = `${model.provider}/${model.id}`
+      recordStat('prompt_tokens', key, usage.prompt_tokens || 0)
+      recordStat('completion_tokens', key, usage.completion_tokens || 0)
+    }
+
+    return result
+  }
@@ -0,0 +1,131 @@
+import Logger from '@renderer/config/logger'
+import i18n from '@renderer/i18n'
+import {
+  checkContent,
+  getContentFilterAction,
+  getContentFilterSettings,
+  logContentFilterHits
+} from '@renderer/services/ContentFilterService'
+import { ChunkType, TextCompleteChunk, TextDeltaChunk } from '@renderer/types/chunk'
+import {
+  ContentFilterAction,
+  ContentFilterDirection,
+  ContentFilterHit,
+  filterContent
+} from '@renderer/utils/contentFilter'
+import { getMainTextContent } from '@renderer/utils/messageUtils/find'
+
+import { CompletionsParams, CompletionsResult, GenericChunk } from '../schemas'
+import type { CompletionsContext, CompletionsMiddleware } from '../types'
+
+export const MIDDLEWARE_NAME = 'ContentFilterMiddleware'
+
+export class ContentFilterError extends Error {
+  constructor(public readonly hits: ContentFilterHit[]) {
+    super(i18n.t('settings.content_filter.blocked', { rules: hits.map((hit) => hit.rule).join(', ') }))
+    this.name = 'ContentFilterError'
+  }
+}
+
+function getLastUserText(params: CompletionsParams): string {
+  if (typeof params.messages === 'string') return params.messages
+  const message = params.messages.findLast((m) => m.role === 'user')
+  return message ? getMainTextContent(message) : ''
+}
+
+function getTopicId(params: CompletionsParams): string | undefined {
+  return typeof params.messages === 'string' ? undefined : params.messages[0]?.topicId
+}
+
+function reportHits(
+  params: CompletionsParams,
+  direction: ContentFilterDirection,
+  action: ContentFilterAction,
+  hits: ContentFilterHit[]
+) {
+  Logger.warn(`[${MIDDLEWARE_NAME}] Filter hits in ${direction} message:`, hits)
+  logContentFilterHits({ direction, action, assistant: params.assistant, topicId: getTopicId(params), hits })
+
+  if (action === 'warn') {
+    const rules = hits.map((hit) => hit.rule).join(', ')
+    window.message.warning({
+      content: i18n.t(`settings.content_filter.warning.${direction}`, { rules }),
+      key: 'content-filter-warning'
+    })
+  }
+}
+
+/**
+ * 内容过滤中间件
+ *
+ * 对话请求发出前检查用户消息，响应完成后检查模型输出，命中规则时按设置提示、替换或阻止。
+ * 流式输出过程中一旦命中规则，后续片段不再转发，最终以处理后的完整文本替换消息内容。
+ */
+export const ContentFilterMiddleware: CompletionsMiddleware =
+  () =>
+  (next) =>
+  async (ctx: CompletionsContext, params: CompletionsParams): Promise<CompletionsResult> => {
+    const action = params.callType === 'chat' ? getContentFilterAction(params.assistant) : null
+    if (!action) {
+      return next(ctx, params)
+    }
+
+    const { inbound, outbound, rules } = getContentFilterSettings()
+    // 工具调用的递归请求只追加了工具结果，用户消息已经检查过
+    const isRecursiveCall = ctx._internal.toolProcessingState?.isRecursiveCall
+
+    if (outbound && !isRecursiveCall && ctx._internal.sdkPayload) {
+      const { value, hits, blocked } = await checkContent(ctx._internal.sdkPayload, getLastUserText(params), action)
+      if (hits.length > 0) {
+        reportHits(params, 'outbound', action, hits)
+        if (blocked) {
+          throw new ContentFilterError(hits)
+        }
+        ctx._internal.sdkPayload = value
+      }
+    }
+
+    const result = await next(ctx, params)
+    if (!inbound || !(result.stream instanceof ReadableStream)) {
+      return result
+    }
+
+    let accumulatedText = ''
+    let suppressed = false
+    const filteredStream = (result.stream as ReadableStream<GenericChunk>).pipeThrough(
+      new TransformStream<GenericChunk, GenericChunk>({
+        async transform(chunk, controller) {
+          if (chunk.type === ChunkType.TEXT_DELTA) {
+            accumulatedText += (chunk as TextDeltaChunk).text
+            if (!suppressed && action !== 'warn') {
+              suppressed = filterContent(accumulatedText, rules, false).hits.length > 0
+            }
+            if (!suppressed) {
+              controller.enqueue(chunk)
+            }
+            return
+          }
+
+          if (chunk.type === ChunkType.TEXT_COMPLETE) {
+            const text = (chunk as TextCompleteChunk).text
+            const { value, hits, blocked } = await checkContent(text, text, action)
+            let finalText = text
+            if (hits.length > 0) {
+              reportHits(params, 'inbound', action, hits)
+              finalText = blocked ? i18n.t('settings.content_filter.blocked_response') : value
+            }
+
+            ctx._internal.customState!.accumulatedText = finalText
+            controller.enqueue({ ...chunk, text: finalText })
+            accumulatedText = ''
+            suppressed = false
+            return
+          }
+
+          controller.enqueue(chunk)
+        }
+      })
+    )
+
+    return { ...result, stream: filteredStream }
+  }
@@ -0,0 +1,109 @@
+import Logger from '@renderer/config/logger'
+import i18n from '@renderer/i18n'
+import store from '@renderer/store'
+import { ContentGuardFinding, redactContent } from '@renderer/utils/contentGuard'
+
+import { CompletionsParams, CompletionsResult } from '../schemas'
+import type { CompletionsContext, CompletionsMiddleware } from '../types'
+
+export const MIDDLEWARE_NAME = 'ContentGuardMiddleware'
+
+export class ContentGuardError extends Error {
+  constructor(public readonly findings: ContentGuardFinding[]) {
+    super(i18n.t('settings.content_guard.blocked', { items: findings.map((f) => f.name).join(', ') }))
+    this.name = 'ContentGuardError'
+  }
+}
+
+// MCP 服务器的环境变量里只有名称像密钥的才当作密钥，路径、端口等普通配置不处理
+const SECRET_ENV_KEY = /(_KEY|_TOKEN)$|SECRET|PASSWORD/i
+
+// API 服务、定时消息等没有用户在场时发往的话题，这些话题的请求不弹窗询问
+const headlessTopics = new Map<string, number>()
+
+/**
+ * 在没有用户在场的情况下向话题发送消息，期间内容防护的询问模式按直接脱敏处理
+ * @param {string} topicId 话题 ID
+ * @param {() => Promise<T>} run 发送消息并等待回复
+ * @returns {Promise<T>} run 的结果
+ */
+export async function runHeadless<T>(topicId: string, run: () => Promise<T>): Promise<T> {
+  headlessTopics.set(topicId, (headlessTopics.get(topicId) ?? 0) + 1)
+  try {
+    return await run()
+  } finally {
+    const count = headlessTopics.get(topicId)! - 1
+    if (count > 0) {
+      headlessTopics.set(topicId, count)
+    } else {
+      headlessTopics.delete(topicId)
+    }
+  }
+}
+
+/**
+ * 收集应用中保存的密钥：模型服务商、搜索服务商的 API Key 以及 MCP 服务器中名称像密钥的环境变量
+ */
+export function getStoredSecrets(): string[] {
+  const state = store.getState()
+  const providerKeys = state.llm.providers.flatMap((provider) => (provider.apiKey || '').split(','))
+  const webSearchKeys = state.websearch.providers.map((provider) => provider.apiKey || '')
+  const mcpEnvValues = state.mcp.servers.flatMap((server) =>
+    Object.entries(server.env || {})
+      .filter(([key]) => SECRET_ENV_KEY.test(key))
+      .map(([, value]) => value)
+  )
+  return [...providerKeys, ...webSearchKeys, ...mcpEnvValues].filter(Boolean)
+}
+
+function confirmSend(findings: ContentGuardFinding[]): Promise<boolean> {
+  const items = findings
+    .map((finding) => `${i18n.t(`settings.content_guard.finding.${finding.name}`, finding.name)} × ${finding.count}`)
+    .join(', ')
+
+  return new Promise((resolve) => {
+    window.modal.confirm({
+      title: i18n.t('settings.content_guard.confirm.title'),
+      content: i18n.t('settings.content_guard.confirm.content', { items }),
+      okText: i18n.t('settings.content_guard.confirm.ok'),
+      cancelText: i18n.t('common.cancel'),
+      centered: true,
+      onOk: () => resolve(true),
+      onCancel: () => resolve(false)
+    })
+  })
+}
+
+/**
+ * 内容防护中间件
+ *
+ * 在请求发出前扫描 SDK 参数，发现已保存的密钥或匹配敏感规则的内容时，
+ * 按设置直接脱敏、询问用户后脱敏或阻止请求。只防止密钥和敏感信息外泄，不检测提示词注入
+ *
+ * 询问只对用户在界面上发起的请求生效，API 服务、定时消息、翻译等请求没有人确认，直接脱敏
+ */
+export const ContentGuardMiddleware: CompletionsMiddleware =
+  () =>
+  (next) =>
+  async (ctx: CompletionsContext, params: CompletionsParams): Promise<CompletionsResult> => {
+    const { enabled, action, patterns } = store.getState().settings.contentGuard ?? {}
+    if (!enabled || !ctx._internal.sdkPayload) {
+      return next(ctx, params)
+    }
+
+    const { value, findings } = redactContent(ctx._internal.sdkPayload, getStoredSecrets(), patterns)
+    if (findings.length === 0) {
+      return next(ctx, params)
+    }
+
+    Logger.warn(`[${MIDDLEWARE_NAME}] Sensitive content found in outgoing request:`, findings)
+
+    const topicId = params.toolCallContext?.topicId
+    const interactive = params.interactive && !(topicId && headlessTopics.has(topicId))
+    if (action === 'block' || (action === 'confirm' && interactive && !(await confirmSend(findings)))) {
+      throw new ContentGuardError(findings)
+    }
+
+    ctx._internal.sdkPayload = value
+    return next(ctx, params)
+  }
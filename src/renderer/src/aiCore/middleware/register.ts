@@ -2,6 +2,10 @@ import * as AbortHandlerModule from './common/AbortHandlerMiddleware'
 import * as ErrorHandlerModule from './common/ErrorHandlerMiddleware'
 import * as FinalChunkConsumerModule from './common/FinalChunkConsumerMiddleware'
 import * as LoggingModule from './common/LoggingMiddleware'
+import * as RateLimitModule from './common/RateLimitMiddleware'
+import * as ResponseCacheModule from './common/ResponseCacheMiddleware'
+import * as StructuredOutputModule from './common/StructuredOutputMiddleware'
+import * as UsageStatsModule from './common/UsageStatsMiddleware'
 import * as McpToolChunkModule from './core/McpToolChunkMiddleware'
 import * as RawStreamListenerModule from './core/RawStreamListenerMiddleware'
 import * as ResponseTransformModule from './core/ResponseTransformMiddleware'
@@ -11,6 +15,8 @@ import * as TextChunkModule from './core/TextChunkMiddleware'
 import * as ThinkChunkModule from './core/ThinkChunkMiddleware'
 import * as TransformCoreToSdkParamsModule from './core/TransformCoreToSdkParamsMiddleware'
 import * as WebSearchModule from './core/WebSearchMiddleware'
+import * as ContentFilterModule from './feat/ContentFilterMiddleware'
+import * as ContentGuardModule from './feat/ContentGuardMiddleware'
 import * as ImageGenerationModule from './feat/ImageGenerationMiddleware'
 import * as ThinkingTagExtractionModule from './feat/ThinkingTagExtractionMiddleware'
 import * as ToolUseExtractionMiddleware from './feat/ToolUseExtractionMiddleware'
@@ -33,12 +39,36 @@ export const MiddlewareRegistry = {
     name: FinalChunkConsumerModule.MIDDLEWARE_NAME,
     middleware: FinalChunkConsumerModule.default
   },
+  [ResponseCacheModule.MIDDLEWARE_NAME]: {
+    name: ResponseCacheModule.MIDDLEWARE_NAME,
+    middleware: ResponseCacheModule.ResponseCacheMiddleware
+  },
+  [RateLimitModule.MIDDLEWARE_NAME]: {
+    name: RateLimitModule.MIDDLEWARE_NAME,
+    middleware: RateLimitModule.RateLimitMiddleware
+  },
+  [StructuredOutputModule.MIDDLEWARE_NAME]: {
+    name: StructuredOutputModule.MIDDLEWARE_NAME,
+    middleware: StructuredOutputModule.StructuredOutputMiddleware
+  },
+  [UsageStatsModule.MIDDLEWARE_NAME]: {
+    name: UsageStatsModule.MIDDLEWARE_NAME,
+    middleware: UsageStatsModule.UsageStatsMiddleware
+  },
 
   // 核心流程中间件
   [TransformCoreToSdkParamsModule.MIDDLEWARE_NAME]: {
     name: TransformCoreToSdkParamsModule.MIDDLEWARE_NAME,
     middleware: TransformCoreToSdkParamsModule.TransformCoreToSdkParamsMiddleware
   },
+  [ContentGuardModule.MIDDLEWARE_NAME]: {
+    name: ContentGuardModule.MIDDLEWARE_NAME,
+    middleware: ContentGuardModule.ContentGuardMiddleware
+  },
+  [ContentFilterModule.MIDDLEWARE_NAME]: {
+    name: ContentFilterModule.MIDDLEWARE_NAME,
+    middleware: ContentFilterModule.ContentFilterMiddleware
+  },
   // [SdkCallModule.MIDDLEWARE_NAME]: {
   //   name: SdkCallModule.MIDDLEWARE_NAME,
   //   middleware: SdkCallModule.SdkCallMiddleware
@@ -108,9 +138,15 @@ export function getRegisteredMiddlewareNames(): string[] {
  * 默认的 Completions 中间件配置 - NamedMiddleware 格式，用于 MiddlewareBuilder
  */
 export const DefaultCompletionsNamedMiddlewares = [
+  MiddlewareRegistry[StructuredOutputModule.MIDDLEWARE_NAME], // 结构化输出校验
+  MiddlewareRegistry[ResponseCacheModule.MIDDLEWARE_NAME], // 响应缓存
+  MiddlewareRegistry[RateLimitModule.MIDDLEWARE_NAME], // 限流
+  MiddlewareRegistry[UsageStatsModule.MIDDLEWARE_NAME], // 使用统计
   MiddlewareRegistry[FinalChunkConsumerModule.MIDDLEWARE_NAME], // 最终消费者
   MiddlewareRegistry[ErrorHandlerModule.MIDDLEWARE_NAME], // 错误处理
   MiddlewareRegistry[TransformCoreToSdkParamsModule.MIDDLEWARE_NAME], // 参数转换
+  MiddlewareRegistry[ContentGuardModule.MIDDLEWARE_NAME], // 内容防护
+  MiddlewareRegistry[ContentFilterModule.MIDDLEWARE_NAME], // 内容过滤
   MiddlewareRegistry[AbortHandlerModule.MIDDLEWARE_NAME], // 中止处理
   MiddlewareRegistry[McpToolChunkModule.MIDDLEWARE_NAME], // 工具处理
   MiddlewareRegistry[TextChunkModule.MIDDLEWARE_NAME], // 文本处理
@@ -136,14 +172,20 @@ export const DefaultMethodMiddlewares = {
  */
 export {
   AbortHandlerModule,
+  ContentFilterModule,
+  ContentGuardModule,
   FinalChunkConsumerModule,
   LoggingModule,
   McpToolChunkModule,
+  RateLimitModule,
+  ResponseCacheModule,
   ResponseTransformModule,
   StreamAdapterModule,
+  StructuredOutputModule,
   TextChunkModule,
   ThinkChunkModule,
   ThinkingTagExtractionModule,
   TransformCoreToSdkParamsModule,
+  UsageStatsModule,
   WebSearchModule
 }
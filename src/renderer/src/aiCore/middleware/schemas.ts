@@ -1,7 +1,8 @@
-import { Assistant, MCPTool } from '@renderer/types'
+import { Assistant, MCPTool, ToolCallContext } from '@renderer/types'
 import { Chunk } from '@renderer/types/chunk'
 import { Message } from '@renderer/types/newMessage'
 import { SdkRawChunk, SdkRawOutput } from '@renderer/types/sdk'
+import { JSONSchema } from '@renderer/utils/structuredOutput'
 
 import { ProcessingState } from './types'
 
@@ -38,8 +39,13 @@ export interface CompletionsParams {
   onError?: (error: Error) => void
   shouldThrow?: boolean
 
+  // 用户在界面上发起、正在等待回复的请求，只有这类请求可以弹窗询问用户
+  interactive?: boolean
+
   // 工具相关
   mcpTools?: MCPTool[]
+  // 工具调用记录关联的消息
+  toolCallContext?: ToolCallContext
 
   // 生成参数
   temperature?: number
@@ -55,6 +61,10 @@ export interface CompletionsParams {
   // 上下文控制
   contextCount?: number
 
+  // 结构化输出：模型必须返回符合 schema 的 JSON，校验失败时带纠正提示重试
+  responseSchema?: JSONSchema
+  maxValidationRetries?: number
+
   _internal?: ProcessingState
 }
 
@@ -64,6 +74,8 @@ export interface CompletionsResult {
   controller?: AbortController
 
   getText: () => string
+  // 设置了 responseSchema 时，校验通过的结构化结果
+  parsed?: any
 }
 
 // ============================================================================
@@ -1,12 +1,24 @@
 import { LoadingOutlined } from '@ant-design/icons'
 import CodeEditor from '@renderer/components/CodeEditor'
 import { CodeTool, CodeToolbar, TOOL_SPECS, useCodeTool } from '@renderer/components/CodeToolbar'
+import { useConversationWorkspaces } from '@renderer/hooks/useConversationWorkspaces'
 import { useSettings } from '@renderer/hooks/useSettings'
+import { applyConversationWorkspacePatch } from '@renderer/services/ConversationWorkspaceService'
 import { pyodideService } from '@renderer/services/PyodideService'
 import { extractTitle } from '@renderer/utils/formats'
 import { getExtensionByLanguage, isValidPlantUML } from '@renderer/utils/markdown'
 import dayjs from 'dayjs'
-import { CirclePlay, CodeXml, Copy, Download, Eye, Square, SquarePen, SquareSplitHorizontal } from 'lucide-react'
+import {
+  CirclePlay,
+  CodeXml,
+  Copy,
+  Download,
+  Eye,
+  FileDiff,
+  Square,
+  SquarePen,
+  SquareSplitHorizontal
+} from 'lucide-react'
 import React, { memo, useCallback, useEffect, useMemo, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import styled from 'styled-components'
@@ -23,6 +35,8 @@ type ViewMode = 'source' | 'special' | 'split'
 interface Props {
   children: string
   language: string
+  // the conversation of the message, patches can be applied to the folder mounted to it
+  topicId?: string
   onSave?: (newContent: string) => void
 }
 
@@ -38,11 +52,13 @@ interface Props {
  * - special: 特殊视图模式（Mermaid、PlantUML、SVG）
  * - split: 分屏模式（源代码和特殊视图并排显示）
  *
+ * 话题挂载了文件夹时，diff 代码块可以作为补丁应用到文件夹
+ *
  * 顶部 sticky 工具栏：
  * - quick 工具
  * - core 工具
  */
-const CodeBlockView: React.FC<Props> = ({ children, language, onSave }) => {
+const CodeBlockView: React.FC<Props> = ({ children, language, topicId, onSave }) => {
   const { t } = useTranslation()
   const { codeEditor, codeExecution } = useSettings()
   const [viewMode, setViewMode] = useState<ViewMode>('special')
@@ -56,6 +72,9 @@ const CodeBlockView: React.FC<Props> = ({ children, language, onSave }) => {
     return codeExecution.enabled && language === 'python'
   }, [codeExecution.enabled, language])
 
+  const workspaces = useConversationWorkspaces()
+  const canApplyPatch = ['diff', 'patch'].includes(language) && !!topicId && !!workspaces[topicId]
+
   const hasSpecialView = useMemo(() => ['mermaid', 'plantuml', 'svg'].includes(language), [language])
 
   const isInSpecialView = useMemo(() => {
@@ -177,6 +196,20 @@ const CodeBlockView: React.FC<Props> = ({ children, language, onSave }) => {
     return () => isExecutable && removeTool(TOOL_SPECS.run.id)
   }, [isExecutable, isRunning, handleRunScript, registerTool, removeTool, t])
 
+  // 应用补丁按钮
+  useEffect(() => {
+    if (!canApplyPatch) return
+
+    registerTool({
+      ...TOOL_SPECS['apply-patch'],
+      icon: <FileDiff className="icon" />,
+      tooltip: t('code_block.patch.apply'),
+      onClick: () => applyConversationWorkspacePatch(topicId!, children)
+    })
+
+    return () => removeTool(TOOL_SPECS['apply-patch'].id)
+  }, [canApplyPatch, children, topicId, registerTool, removeTool, t])
+
   // 源代码视图组件
   const sourceView = useMemo(() => {
     if (codeEditor.enabled) {
@@ -43,6 +43,11 @@ export const TOOL_SPECS: Record<string, CodeToolSpec> = {
     type: 'quick',
     order: 11
   },
+  'apply-patch': {
+    id: 'apply-patch',
+    type: 'quick',
+    order: 12
+  },
   wrap: {
     id: 'wrap',
     type: 'quick',
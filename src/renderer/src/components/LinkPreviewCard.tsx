@@ -0,0 +1,102 @@
+import Logger from '@renderer/config/logger'
+import { LinkPreview } from '@renderer/types'
+import { FC, useEffect, useState } from 'react'
+import styled from 'styled-components'
+
+/**
+ * 链接预览卡片，预览由主进程获取，获取失败时不显示
+ */
+const LinkPreviewCard: FC<{ url: string }> = ({ url }) => {
+  const [preview, setPreview] = useState<LinkPreview | null>(null)
+
+  useEffect(() => {
+    let cancelled = false
+    window.api.linkPreview
+      .fetch(url)
+      .then((result) => !cancelled && setPreview(result))
+      .catch((error) => Logger.warn(`[LinkPreviewCard] No preview for ${url}:`, error.message))
+    return () => {
+      cancelled = true
+    }
+  }, [url])
+
+  if (!preview || (!preview.title && !preview.description)) return null
+
+  return (
+    <Card href={preview.url} target="_blank" rel="noreferrer">
+      <Content>
+        <Site>
+          {preview.favicon && <Favicon src={`file://${preview.favicon}`} alt="" />}
+          <span>{preview.siteName || new URL(preview.url).hostname}</span>
+        </Site>
+        {preview.title && <Title>{preview.title}</Title>}
+        {preview.description && <Description>{preview.description}</Description>}
+      </Content>
+      {preview.image && <Image src={`file://${preview.image}`} alt="" />}
+    </Card>
+  )
+}
+
+const Card = styled.a`
+  display: flex;
+  gap: 12px;
+  max-width: 520px;
+  margin-top: 8px;
+  padding: 10px 12px;
+  border: 0.5px solid var(--color-border);
+  border-radius: 8px;
+  background-color: var(--color-background-soft);
+  color: var(--color-text);
+  text-decoration: none;
+  &:hover {
+    background-color: var(--color-background-mute);
+  }
+`
+
+const Content = styled.div`
+  display: flex;
+  flex: 1;
+  flex-direction: column;
+  gap: 4px;
+  min-width: 0;
+`
+
+const Site = styled.div`
+  display: flex;
+  align-items: center;
+  gap: 6px;
+  font-size: 12px;
+  color: var(--color-text-3);
+`
+
+const Favicon = styled.img`
+  width: 14px;
+  height: 14px;
+  border-radius: 2px;
+`
+
+const Title = styled.div`
+  font-weight: 500;
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+`
+
+const Description = styled.div`
+  display: -webkit-box;
+  -webkit-line-clamp: 2;
+  -webkit-box-orient: vertical;
+  overflow: hidden;
+  font-size: 12px;
+  color: var(--color-text-2);
+`
+
+const Image = styled.img`
+  width: 96px;
+  height: 64px;
+  flex-shrink: 0;
+  object-fit: cover;
+  border-radius: 6px;
+`
+
+export default LinkPreviewCard
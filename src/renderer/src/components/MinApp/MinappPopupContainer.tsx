@@ -1,29 +1,45 @@
 import {
   ArrowLeftOutlined,
   ArrowRightOutlined,
+  CameraOutlined,
   CloseOutlined,
   CodeOutlined,
   CopyOutlined,
   ExportOutlined,
+  EyeInvisibleOutlined,
+  FilePdfOutlined,
   LinkOutlined,
+  LogoutOutlined,
   MinusOutlined,
+  MutedOutlined,
   PushpinOutlined,
-  ReloadOutlined
+  ReloadOutlined,
+  SoundOutlined,
+  ZoomInOutlined
 } from '@ant-design/icons'
 import { isLinux, isMac, isWindows } from '@renderer/config/constant'
 import { DEFAULT_MIN_APPS } from '@renderer/config/minapps'
 import { useBridge } from '@renderer/hooks/useBridge'
+import { useContentBlocking } from '@renderer/hooks/useContentBlocking'
 import { useMinappPopup } from '@renderer/hooks/useMinappPopup'
 import { useMinapps } from '@renderer/hooks/useMinapps'
 import useNavBackgroundColor from '@renderer/hooks/useNavBackgroundColor'
 import { useRuntime } from '@renderer/hooks/useRuntime'
 import { useSettings } from '@renderer/hooks/useSettings'
+import { useShortcuts } from '@renderer/hooks/useShortcuts'
+import { useWebviewSuspension } from '@renderer/hooks/useWebviewSuspension'
+import NavigationService from '@renderer/services/NavigationService'
+import { setWebviewPoolHost } from '@renderer/services/WebviewPoolService'
 import { useAppDispatch } from '@renderer/store'
+import { setMinappAudioState } from '@renderer/store/runtime'
 import { setMinappsOpenLinkExternal } from '@renderer/store/settings'
-import { MinAppType } from '@renderer/types'
+import { MiniAppNavigationState, MinAppType, WebviewContextMenuAction } from '@renderer/types'
 import { delay } from '@renderer/utils'
-import { Avatar, Drawer, Tooltip } from 'antd'
+import { getErrorMessage } from '@renderer/utils/error'
+import { ZOOM_OPTIONS } from '@shared/config/constant'
+import { Avatar, Checkbox, Drawer, Dropdown, Tooltip } from 'antd'
 import { WebviewTag } from 'electron'
+import { omit } from 'lodash'
 import { useEffect, useMemo, useRef, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import BeatLoader from 'react-spinners/BeatLoader'
@@ -39,22 +55,31 @@ interface AppExtraInfo {
 
 type AppInfo = MinAppType & AppExtraInfo
 
+/** shortcuts that keep working for the host while a minapp has focus */
+const HOST_SHORTCUTS = ['show_settings', 'search_message', 'new_topic']
+
+const DEFAULT_CONTEXT_MENU: WebviewContextMenuAction[] = ['ask', 'save_image', 'copy_link', 'open_external']
+
 /** The main container for MinApp popup */
 const MinappPopupContainer: React.FC = () => {
-  const { openedKeepAliveMinapps, openedOneOffMinapp, currentMinappId, minappShow } = useRuntime()
-  const { closeMinapp, hideMinappPopup } = useMinappPopup()
+  const { openedKeepAliveMinapps, openedOneOffMinapp, currentMinappId, minappShow, minappAudioStates } = useRuntime()
+  const { closeMinapp, closeAllMinapps, hideMinappPopup } = useMinappPopup()
   const { pinned, updatePinnedMinapps } = useMinapps()
   const { t } = useTranslation()
   const backgroundColor = useNavBackgroundColor()
   const dispatch = useAppDispatch()
 
-  /** control the drawer open or close */
-  const [isPopupShow, setIsPopupShow] = useState(true)
+  /** control the drawer open or close, the container also stays mounted without apps to hold the warm pool */
+  const [isPopupShow, setIsPopupShow] = useState(minappShow)
   /** whether the current minapp is ready */
   const [isReady, setIsReady] = useState(false)
   /** the current REAL url of the minapp
    * different from the app preset url, because user may navigate in minapp */
   const [currentUrl, setCurrentUrl] = useState<string | null>(null)
+  /** the navigation state of the minapps reported by the main process */
+  const [navigationStates, setNavigationStates] = useState<Record<string, MiniAppNavigationState>>({})
+  /** the zoom factor of each minapp, saved by the main process and independent of the main window */
+  const [zoomFactors, setZoomFactors] = useState<Record<string, number>>({})
 
   /** store the last minapp id and show status */
   const lastMinappId = useRef<string | null>(null)
@@ -65,11 +90,14 @@ const MinappPopupContainer: React.FC = () => {
   /** indicate whether the webview has loaded  */
   const webviewLoadedRefs = useRef<Map<string, boolean>>(new Map())
   /** whether the minapps open link external is enabled */
-  const { minappsOpenLinkExternal } = useSettings()
+  const { minappsOpenLinkExternal, minappsShortcutOptOut, minappsUserAgent } = useSettings()
+  const { shortcuts } = useShortcuts()
 
   const isInDevelopment = process.env.NODE_ENV === 'development'
 
   useBridge()
+  useContentBlocking()
+  useWebviewSuspension(webviewRefs, currentMinappId, minappShow)
 
   /** set the popup display status */
   useEffect(() => {
@@ -120,10 +148,90 @@ const MinappPopupContainer: React.FC = () => {
         const webviewId = webviewRefs.current.get(appid)?.getWebContentsId()
         if (webviewId) {
           window.api.webview.setOpenLinkExternal(webviewId, minappsOpenLinkExternal)
+          window.api.webview.setShortcutPassThrough(webviewId, getPassThroughShortcuts(appid))
         }
       }
     })
-  }, [currentMinappId, minappsOpenLinkExternal])
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [currentMinappId, minappsOpenLinkExternal, minappsShortcutOptOut, shortcuts])
+
+  /** replay the host shortcuts pressed inside a minapp once the popup is hidden */
+  useEffect(() => {
+    return window.api.webview.onShortcutForwarded(async (input) => {
+      await handlePopupMinimize()
+      document.dispatchEvent(
+        new KeyboardEvent('keydown', {
+          key: input.key,
+          code: input.code,
+          ctrlKey: input.control,
+          metaKey: input.meta,
+          shiftKey: input.shift,
+          altKey: input.alt,
+          bubbles: true
+        })
+      )
+    })
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [minappShow, openedOneOffMinapp])
+
+  /** keep the url and the history buttons in sync with every navigation of the minapps */
+  useEffect(() => {
+    return window.api.miniApp.onNavigationChanged((state) => {
+      setNavigationStates((states) => ({ ...states, [state.appId]: state }))
+      if (state.appId === currentMinappId) {
+        setCurrentUrl(state.url)
+      }
+    })
+  }, [currentMinappId])
+
+  /** whether the minapps play sound or are muted, the sidebar shows it on the tabs */
+  useEffect(() => {
+    return window.api.miniApp.onAudioStateChanged((state) => dispatch(setMinappAudioState(state)))
+  }, [dispatch])
+
+  /** files downloaded from the minapps are saved to the download directory, report when they start and finish */
+  useEffect(() => {
+    const started = new Set<string>()
+    return window.api.download.onUpdated((task) => {
+      if (task.options?.source !== 'webview') return
+      const name = task.dest.split(/[\\/]/).pop()
+      const key = `minapp-download-${task.id}`
+      if (task.status === 'downloading' && !started.has(task.id)) {
+        started.add(task.id)
+        window.message.loading({ content: t('minapp.popup.download_started', { name }), key })
+      } else if (task.status === 'completed') {
+        window.message.success({ content: t('minapp.popup.download_completed', { name }), key })
+      } else if (task.status === 'failed') {
+        window.message.error({ content: t('minapp.popup.download_failed', { name }), key })
+      }
+    })
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [])
+
+  /** handle the host actions picked in the right-click menu of a minapp */
+  useEffect(() => {
+    return window.api.webview.onContextMenuAction(async ({ action, selectionText }) => {
+      switch (action) {
+        case 'ask':
+          await handlePopupMinimize()
+          NavigationService.navigate?.('/')
+          window.api.quoteToMainWindow(selectionText)
+          break
+        case 'copy_link':
+          window.message.success({ content: t('message.copied'), key: 'copy-link' })
+          break
+      }
+    })
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [minappShow, openedOneOffMinapp])
+
+  /** the host shortcuts routed out of the minapp, none when the minapp opted out */
+  const getPassThroughShortcuts = (appid: string) => {
+    if (minappsShortcutOptOut.includes(appid)) return []
+    return shortcuts
+      .filter((shortcut) => HOST_SHORTCUTS.includes(shortcut.key) && shortcut.enabled && shortcut.shortcut.length)
+      .map((shortcut) => shortcut.shortcut)
+  }
 
   /** only the keepalive minapp can be minimized */
   const canMinimize = !(openedOneOffMinapp && openedOneOffMinapp.id == currentMinappId)
@@ -133,6 +241,49 @@ const MinappPopupContainer: React.FC = () => {
     return [...openedKeepAliveMinapps, ...(openedOneOffMinapp ? [openedOneOffMinapp] : [])]
   }, [openedKeepAliveMinapps, openedOneOffMinapp])
 
+  /** report which minapp is on screen, the main process emits the shown and hidden lifecycle events */
+  useEffect(() => {
+    combinedApps.forEach((app) => window.api.miniApp.setVisible(app.id, minappShow && app.id === currentMinappId))
+  }, [combinedApps, currentMinappId, minappShow])
+
+  /** hide or close all minapps when asked by the main process, e.g. from an external client */
+  useEffect(() => {
+    const offHide = window.api.miniApp.onHideAllRequested(hideMinappPopup)
+    const offDestroy = window.api.miniApp.onDestroyAllRequested(closeAllMinapps)
+    return () => {
+      offHide()
+      offDestroy()
+    }
+  }, [closeAllMinapps, hideMinappPopup])
+
+  /** pages and bridge commands asking for a permission wait for the user, the answer can be remembered */
+  useEffect(() => {
+    return window.api.miniApp.onPermissionRequested((request) => {
+      // 页面在小程序注册前请求时只显示来源
+      const name = combinedApps.find((item) => item.id === request.appId)?.name ?? request.origin
+      const permissions = request.permissions.map((permission) => t(`minapp.popup.permission.${permission}`))
+      let remember = false
+
+      window.modal.confirm({
+        title: t('minapp.popup.permission.title', { name, permissions: permissions.join(', ') }),
+        content: (
+          <>
+            <div>{request.origin}</div>
+            {request.detail && <div>{request.detail}</div>}
+            <Checkbox style={{ marginTop: 8 }} onChange={(e) => (remember = e.target.checked)}>
+              {t('minapp.popup.permission.remember')}
+            </Checkbox>
+          </>
+        ),
+        okText: t('minapp.popup.permission.allow'),
+        cancelText: t('minapp.popup.permission.deny'),
+        centered: true,
+        onOk: () => window.api.miniApp.respondPermission(request.requestId, true, remember),
+        onCancel: () => window.api.miniApp.respondPermission(request.requestId, false, remember)
+      })
+    })
+  }, [combinedApps, t])
+
   /** get the extra info of the apps */
   const appsExtraInfo = useMemo(() => {
     return combinedApps.reduce(
@@ -160,6 +311,8 @@ const MinappPopupContainer: React.FC = () => {
     setIsPopupShow(false)
     await delay(0.3)
     webviewLoadedRefs.current.delete(appid)
+    setNavigationStates((states) => omit(states, appid))
+    window.api.miniApp.unregisterWebview(appid)
     closeMinapp(appid)
   }
 
@@ -192,6 +345,11 @@ const MinappPopupContainer: React.FC = () => {
     const webviewId = webviewRefs.current.get(appid)?.getWebContentsId()
     if (webviewId) {
       window.api.webview.setOpenLinkExternal(webviewId, minappsOpenLinkExternal)
+      window.api.webview.setShortcutPassThrough(webviewId, getPassThroughShortcuts(appid))
+      const app = combinedApps.find((item) => item.id === appid)
+      window.api.webview.setContextMenu(webviewId, app?.contextMenu ?? DEFAULT_CONTEXT_MENU)
+      window.api.miniApp.registerWebview(appid, webviewId)
+      window.api.miniApp.getZoom(appid).then((factor) => setZoomFactors((prev) => ({ ...prev, [appid]: factor })))
     }
     if (appid == currentMinappId) {
       setTimeout(() => setIsReady(true), 200)
@@ -245,23 +403,75 @@ const MinappPopupContainer: React.FC = () => {
 
   /** navigate back in webview history */
   const handleGoBack = (appid: string) => {
-    const webview = webviewRefs.current.get(appid)
-    if (webview && webview.canGoBack()) {
-      webview.goBack()
-    }
+    window.api.miniApp.goBack(appid).catch((error) => console.error('Failed to go back:', error))
   }
 
   /** navigate forward in webview history */
   const handleGoForward = (appid: string) => {
-    const webview = webviewRefs.current.get(appid)
-    if (webview && webview.canGoForward()) {
-      webview.goForward()
+    window.api.miniApp.goForward(appid).catch((error) => console.error('Failed to go forward:', error))
+  }
+
+  /** save what the minapp currently shows as a png */
+  const handleCapture = async (appInfo: AppInfo) => {
+    try {
+      const { base64 } = await window.api.miniApp.capture(appInfo.id, { base64: true })
+      await window.api.file.saveImage(appInfo.name, `data:image/png;base64,${base64}`)
+    } catch (error) {
+      window.message.error({ content: getErrorMessage(error), key: 'minapp-capture' })
+    }
+  }
+
+  /** export the page the minapp currently shows to a pdf, the path is picked in a save dialog */
+  const handlePrintToPdf = async (appInfo: AppInfo) => {
+    try {
+      const result = await window.api.miniApp.printToPdf(appInfo.id, undefined, { defaultName: appInfo.name })
+      if (result) {
+        window.message.success({ content: t('minapp.popup.print_pdf_success'), key: 'minapp-print-pdf' })
+      }
+    } catch (error) {
+      window.message.error({ content: getErrorMessage(error), key: 'minapp-print-pdf' })
     }
   }
 
+  /** log out of the minapp by clearing the cookies of the sites it visited, then load its start page again */
+  const handleSignOut = (appInfo: AppInfo) => {
+    window.modal.confirm({
+      title: t('minapp.popup.sign_out'),
+      content: t('minapp.popup.sign_out_confirm', { name: appInfo.name }),
+      okText: t('common.confirm'),
+      cancelText: t('common.cancel'),
+      centered: true,
+      onOk: async () => {
+        try {
+          await window.api.miniApp.clearCookies(appInfo.id)
+          handleReload(appInfo.id)
+          window.message.success({ content: t('minapp.popup.sign_out_success'), key: 'minapp-sign-out' })
+        } catch (error) {
+          window.message.error({ content: getErrorMessage(error), key: 'minapp-sign-out' })
+        }
+      }
+    })
+  }
+
+  /** zoom the minapp, the factor is kept for the next time it opens */
+  const handleSetZoom = (appid: string, factor: number) => {
+    window.api.miniApp
+      .setZoom(appid, factor)
+      .then((value) => setZoomFactors((prev) => ({ ...prev, [appid]: value })))
+      .catch((error) => console.error('Failed to set zoom:', error))
+  }
+
+  /** mute the minapp without closing it */
+  const handleToggleMute = (appid: string) => {
+    window.api.miniApp
+      .setMuted(appid, !minappAudioStates[appid]?.muted)
+      .catch((error) => console.error('Failed to mute:', error))
+  }
+
   /** Title bar of the popup */
   const Title = ({ appInfo, url }: { appInfo: AppInfo | null; url: string | null }) => {
     if (!appInfo) return null
+    const zoom = zoomFactors[appInfo.id] ?? 1
 
     const handleCopyUrl = (event: any, url: string) => {
       //don't show app-wide context menu
@@ -295,6 +505,14 @@ const MinappPopupContainer: React.FC = () => {
           }}>
           <TitleText onContextMenu={(e) => handleCopyUrl(e, url ?? appInfo.url)}>{appInfo.name}</TitleText>
         </Tooltip>
+        {appInfo.ephemeral && (
+          <Tooltip title={t('minapp.popup.incognito_tip')} mouseEnterDelay={0.8} placement="bottom">
+            <IncognitoBadge>
+              <EyeInvisibleOutlined />
+              {t('minapp.popup.incognito')}
+            </IncognitoBadge>
+          </Tooltip>
+        )}
         {appInfo.canOpenExternalLink && (
           <Tooltip title={t('minapp.popup.openExternal')} mouseEnterDelay={0.8} placement="bottom">
             <Button onClick={() => handleOpenLink(url ?? appInfo.url)}>
@@ -305,12 +523,16 @@ const MinappPopupContainer: React.FC = () => {
         <Spacer />
         <ButtonsGroup className={isWindows || isLinux ? 'windows' : ''}>
           <Tooltip title={t('minapp.popup.goBack')} mouseEnterDelay={0.8} placement="bottom">
-            <Button onClick={() => handleGoBack(appInfo.id)}>
+            <Button
+              onClick={() => handleGoBack(appInfo.id)}
+              className={navigationStates[appInfo.id]?.canGoBack === false ? 'disabled' : ''}>
               <ArrowLeftOutlined />
             </Button>
           </Tooltip>
           <Tooltip title={t('minapp.popup.goForward')} mouseEnterDelay={0.8} placement="bottom">
-            <Button onClick={() => handleGoForward(appInfo.id)}>
+            <Button
+              onClick={() => handleGoForward(appInfo.id)}
+              className={navigationStates[appInfo.id]?.canGoForward === false ? 'disabled' : ''}>
               <ArrowRightOutlined />
             </Button>
           </Tooltip>
@@ -319,6 +541,47 @@ const MinappPopupContainer: React.FC = () => {
               <ReloadOutlined />
             </Button>
           </Tooltip>
+          <Tooltip title={t('minapp.popup.capture')} mouseEnterDelay={0.8} placement="bottom">
+            <Button onClick={() => handleCapture(appInfo)}>
+              <CameraOutlined />
+            </Button>
+          </Tooltip>
+          <Tooltip title={t('minapp.popup.print_pdf')} mouseEnterDelay={0.8} placement="bottom">
+            <Button onClick={() => handlePrintToPdf(appInfo)}>
+              <FilePdfOutlined />
+            </Button>
+          </Tooltip>
+          {!appInfo.ephemeral && (
+            <Tooltip title={t('minapp.popup.sign_out')} mouseEnterDelay={0.8} placement="bottom">
+              <Button onClick={() => handleSignOut(appInfo)}>
+                <LogoutOutlined />
+              </Button>
+            </Tooltip>
+          )}
+          <Tooltip
+            title={minappAudioStates[appInfo.id]?.muted ? t('minapp.popup.unmute') : t('minapp.popup.mute')}
+            mouseEnterDelay={0.8}
+            placement="bottom">
+            <Button
+              onClick={() => handleToggleMute(appInfo.id)}
+              className={minappAudioStates[appInfo.id]?.muted ? 'muted' : ''}>
+              {minappAudioStates[appInfo.id]?.muted ? <MutedOutlined /> : <SoundOutlined />}
+            </Button>
+          </Tooltip>
+          <Dropdown
+            trigger={['click']}
+            menu={{
+              items: ZOOM_OPTIONS.map(({ value, label }) => ({ key: String(value), label })),
+              selectedKeys: [String(zoom)],
+              onClick: ({ key }) => handleSetZoom(appInfo.id, Number(key))
+            }}>
+            <Tooltip title={t('minapp.popup.zoom')} mouseEnterDelay={0.8} placement="bottom">
+              <Button className={zoom !== 1 ? 'zoomed' : ''}>
+                <ZoomInOutlined />
+                {zoom !== 1 && <ZoomLabel>{Math.round(zoom * 100)}%</ZoomLabel>}
+              </Button>
+            </Tooltip>
+          </Dropdown>
           {appInfo.canPinned && (
             <Tooltip
               title={appInfo.isPinned ? t('minapp.sidebar.remove.title') : t('minapp.sidebar.add.title')}
@@ -365,13 +628,22 @@ const MinappPopupContainer: React.FC = () => {
     )
   }
 
+  /** the app's own user agent, then the one of its definition (saved apps may predate it), then the default */
+  const getUserAgent = (app: MinAppType) =>
+    app.userAgent || DEFAULT_MIN_APPS.find((item) => item.id === app.id)?.userAgent || minappsUserAgent || undefined
+
   /** group the webview containers with Memo, one of the key to make them keepalive */
   const WebviewContainerGroup = useMemo(() => {
     return combinedApps.map((app) => (
       <WebviewContainer
-        key={app.id}
+        // the partition can't change once the webview is loaded, remount when switching to incognito
+        key={app.ephemeral ? `${app.id}:incognito` : app.id}
         appid={app.id}
         url={app.url}
+        ephemeral={app.ephemeral}
+        userAgent={getUserAgent(app)}
+        preloads={app.preloads ?? DEFAULT_MIN_APPS.find((item) => item.id === app.id)?.preloads}
+        bridge={!!app.permissions}
         onSetRefCallback={handleWebviewSetRef}
         onLoadedCallback={handleWebviewLoaded}
         onNavigateCallback={handleWebviewNavigate}
@@ -380,7 +652,7 @@ const MinappPopupContainer: React.FC = () => {
 
     // because the combinedApps is enough
     // eslint-disable-next-line react-hooks/exhaustive-deps
-  }, [combinedApps])
+  }, [combinedApps, minappsUserAgent])
 
   return (
     <Drawer
@@ -389,6 +661,7 @@ const MinappPopupContainer: React.FC = () => {
       onClose={handlePopupMinimize}
       open={isPopupShow}
       destroyOnClose={false}
+      forceRender
       mask={false}
       rootClassName="minapp-drawer"
       maskClassName="minapp-mask"
@@ -410,6 +683,8 @@ const MinappPopupContainer: React.FC = () => {
         </EmptyView>
       )}
       {WebviewContainerGroup}
+      {/* the pooled webviews are shown in place, moving them would recreate the page */}
+      <div ref={setWebviewPoolHost} />
     </Drawer>
   )
 }
@@ -436,6 +711,16 @@ const TitleText = styled.div`
   margin-right: 5px;
 `
 
+const IncognitoBadge = styled.div`
+  display: flex;
+  align-items: center;
+  gap: 4px;
+  font-size: 12px;
+  color: var(--color-text-2);
+  -webkit-app-region: no-drag;
+  margin-right: 5px;
+`
+
 const TitleTextTooltip = styled.span`
   font-size: 0.8rem;
 
@@ -485,6 +770,25 @@ const Button = styled.div`
     color: var(--color-primary);
     background-color: var(--color-primary-bg);
   }
+  &.muted {
+    color: var(--color-primary);
+    background-color: var(--color-primary-bg);
+  }
+  &.zoomed {
+    width: auto;
+    padding: 0 6px;
+    gap: 3px;
+    color: var(--color-primary);
+  }
+  &.disabled {
+    cursor: default;
+    color: var(--color-text-3);
+    background-color: transparent;
+  }
+`
+
+const ZoomLabel = styled.span`
+  font-size: 11px;
 `
 
 const EmptyView = styled.div`
@@ -1,9 +1,11 @@
 import MinappPopupContainer from '@renderer/components/MinApp/MinappPopupContainer'
 import { useRuntime } from '@renderer/hooks/useRuntime'
+import { useSettings } from '@renderer/hooks/useSettings'
 
 const TopViewMinappContainer = () => {
   const { openedKeepAliveMinapps, openedOneOffMinapp } = useRuntime()
-  const isCreate = openedKeepAliveMinapps.length > 0 || openedOneOffMinapp !== null
+  const { minappsWarmPoolSize } = useSettings()
+  const isCreate = openedKeepAliveMinapps.length > 0 || openedOneOffMinapp !== null || minappsWarmPoolSize > 0
 
   return <>{isCreate && <MinappPopupContainer />}</>
 }
@@ -1,26 +1,63 @@
+import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
+import { acquireWebview, releaseWebview } from '@renderer/services/WebviewPoolService'
+import { EPHEMERAL_WEBVIEW_PARTITION_PREFIX, MINIAPP_BRIDGE_CHANNEL } from '@shared/config/constant'
 import { WebviewTag } from 'electron'
-import { memo, useEffect, useRef } from 'react'
+import { memo, useEffect, useRef, useState } from 'react'
 
 /**
  * WebviewContainer is a component that renders a webview element.
  * It is used in the MinAppPopupContainer component.
  * The webcontent can be remain in memory
+ * When the warm pool has an idle webview it is used in place, the component then renders nothing
+ * Mini apps with preload scripts wait for their preload to be built, it can't be set once the webview is attached
+ * Mini apps declaring permissions get the built preload too, it carries the `window.metheus` bridge
  */
 const WebviewContainer = memo(
   ({
     appid,
     url,
+    ephemeral,
+    userAgent,
+    preloads,
+    bridge,
     onSetRefCallback,
     onLoadedCallback,
     onNavigateCallback
   }: {
     appid: string
     url: string
+    ephemeral?: boolean
+    userAgent?: string
+    preloads?: string[]
+    bridge?: boolean
     onSetRefCallback: (appid: string, element: WebviewTag | null) => void
     onLoadedCallback: (appid: string) => void
     onNavigateCallback: (appid: string, url: string) => void
   }) => {
     const webviewRef = useRef<WebviewTag | null>(null)
+    const partition = ephemeral ? `${EPHEMERAL_WEBVIEW_PARTITION_PREFIX}${appid}` : 'persist:webview'
+
+    /** the pooled webview is taken once, the pool only holds the default partition without a custom user agent */
+    const needsPreload = !!preloads?.length || !!bridge
+    const [pooledWebview] = useState(() => (ephemeral || userAgent || needsPreload ? null : acquireWebview()))
+    if (pooledWebview) {
+      webviewRef.current = pooledWebview
+    }
+
+    /** file url of the built preload, undefined while it is built, empty when the app needs none */
+    const [preload, setPreload] = useState<string | undefined>(needsPreload ? undefined : '')
+    const preloadFiles = preloads?.join('\n') ?? ''
+
+    useEffect(() => {
+      if (!needsPreload) return
+      window.api.miniApp
+        .preparePreload(appid, preloadFiles ? preloadFiles.split('\n') : [])
+        .then(setPreload)
+        .catch((error) => {
+          console.error(`Failed to build the preload of mini app ${appid}:`, error)
+          setPreload('')
+        })
+    }, [appid, preloadFiles, needsPreload])
 
     const setRef = (appid: string) => {
       onSetRefCallback(appid, null)
@@ -35,6 +72,17 @@ const WebviewContainer = memo(
       }
     }
 
+    // must run before the effect below, which loads the url into the webview
+    useEffect(() => {
+      if (!pooledWebview) return
+      onSetRefCallback(appid, pooledWebview)
+      return () => {
+        onSetRefCallback(appid, null)
+        releaseWebview(pooledWebview)
+      }
+      // eslint-disable-next-line react-hooks/exhaustive-deps
+    }, [appid, pooledWebview])
+
     useEffect(() => {
       if (!webviewRef.current) return
 
@@ -46,8 +94,14 @@ const WebviewContainer = memo(
         onNavigateCallback(appid, event.url)
       }
 
+      const handleBridgeMessage = (event: any) => {
+        if (event.channel !== MINIAPP_BRIDGE_CHANNEL) return
+        EventEmitter.emit(EVENT_NAMES.MINIAPP_BRIDGE_MESSAGE, { appId: appid, data: event.args[0] })
+      }
+
       webviewRef.current.addEventListener('did-finish-load', handleLoaded)
       webviewRef.current.addEventListener('did-navigate-in-page', handleNavigate)
+      webviewRef.current.addEventListener('ipc-message', handleBridgeMessage)
 
       // we set the url when the webview is ready
       webviewRef.current.src = url
@@ -55,10 +109,21 @@ const WebviewContainer = memo(
       return () => {
         webviewRef.current?.removeEventListener('did-finish-load', handleLoaded)
         webviewRef.current?.removeEventListener('did-navigate-in-page', handleNavigate)
+        webviewRef.current?.removeEventListener('ipc-message', handleBridgeMessage)
       }
-      // because the appid and url are enough, no need to add onLoadedCallback
+      // because the appid, url and preload are enough, no need to add onLoadedCallback
       // eslint-disable-next-line react-hooks/exhaustive-deps
-    }, [appid, url])
+    }, [appid, url, preload])
+
+    // wipe the incognito session once the webview is closed
+    useEffect(() => {
+      if (!ephemeral) return
+      return () => {
+        window.api.webview.clearEphemeralSession(partition)
+      }
+    }, [ephemeral, partition])
+
+    if (pooledWebview || preload === undefined) return null
 
     return (
       <webview
@@ -66,12 +131,9 @@ const WebviewContainer = memo(
         ref={setRef(appid)}
         style={WebviewStyle}
         allowpopups={'true' as any}
-        partition="persist:webview"
-        useragent={
-          appid === 'google'
-            ? 'Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko)  Safari/537.36'
-            : undefined
-        }
+        partition={partition}
+        useragent={userAgent}
+        preload={preload || undefined}
       />
     )
   }
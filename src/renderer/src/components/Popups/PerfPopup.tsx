@@ -0,0 +1,298 @@
+import {
+  PerfLockMetric,
+  PerfProcessMetric,
+  PerfSnapshot,
+  PerfWebviewMetric,
+  StartupReport,
+  StartupSpan
+} from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { formatFileSize } from '@renderer/utils/file'
+import { Button, Descriptions, Empty, Modal, Spin, Table, Tabs, Tag } from 'antd'
+import { useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { TopView } from '../TopView'
+
+interface Props {
+  resolve: (data: any) => void
+}
+
+// 打开期间定时刷新快照
+const REFRESH_INTERVAL = 2000
+
+// 进程内存以 KB 为单位
+const formatKB = (value?: number) => (value === undefined ? '-' : formatFileSize(value * 1024))
+
+const PopupContainer: React.FC<Props> = ({ resolve }) => {
+  const [open, setOpen] = useState(true)
+  const [snapshot, setSnapshot] = useState<PerfSnapshot | null>(null)
+  const [tracing, setTracing] = useState(false)
+  const [startup, setStartup] = useState<StartupReport | null>(null)
+  const { t } = useTranslation()
+
+  const refresh = useCallback(async () => {
+    try {
+      const next = await window.api.perf.getSnapshot()
+      setSnapshot(next)
+      setTracing(next.tracing)
+    } catch (error) {
+      window.message.error({ content: getErrorMessage(error), key: 'perf-snapshot' })
+    }
+  }, [])
+
+  useEffect(() => {
+    window.api.perf.getStartupReport().then(setStartup)
+  }, [])
+
+  useEffect(() => {
+    refresh()
+    const timer = setInterval(refresh, REFRESH_INTERVAL)
+    return () => clearInterval(timer)
+  }, [refresh])
+
+  // 录制 Chromium trace，保存后可在 DevTools 性能面板或 Perfetto 中以火焰图查看
+  const toggleTrace = async () => {
+    try {
+      if (tracing) {
+        const tracePath = await window.api.perf.stopTrace()
+        setTracing(false)
+        window.message.success({ content: t('settings.about.perf.trace.saved', { path: tracePath }), duration: 5 })
+      } else {
+        await window.api.perf.startTrace()
+        setTracing(true)
+      }
+    } catch (error) {
+      window.message.error({ content: getErrorMessage(error), key: 'perf-trace' })
+    }
+  }
+
+  const onCancel = () => {
+    setOpen(false)
+  }
+
+  const onClose = () => {
+    resolve({})
+  }
+
+  PerfPopup.hide = onCancel
+
+  const emptyText = { emptyText: <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} /> }
+
+  return (
+    <Modal
+      title={t('settings.about.perf.title')}
+      open={open}
+      onCancel={onCancel}
+      afterClose={onClose}
+      footer={
+        <Button type={tracing ? 'primary' : 'default'} danger={tracing} onClick={toggleTrace}>
+          {tracing ? t('settings.about.perf.trace.stop') : t('settings.about.perf.trace.start')}
+        </Button>
+      }
+      width={760}
+      transitionName="animation-move-down"
+      centered>
+      {!snapshot && <Spin />}
+      {snapshot && (
+        <>
+          <Descriptions size="small" column={4} bordered>
+            <Descriptions.Item label={t('settings.about.perf.rss')}>
+              {formatFileSize(snapshot.main.rss)}
+            </Descriptions.Item>
+            <Descriptions.Item label={t('settings.about.perf.heap')}>
+              {formatFileSize(snapshot.main.heapUsed)} / {formatFileSize(snapshot.main.heapTotal)}
+            </Descriptions.Item>
+            <Descriptions.Item label={t('settings.about.perf.lag')}>
+              {snapshot.eventLoopLag.p50} / {snapshot.eventLoopLag.p99} ms
+            </Descriptions.Item>
+            <Descriptions.Item label={t('settings.about.perf.lag_max')}>
+              {snapshot.eventLoopLag.max} ms
+            </Descriptions.Item>
+          </Descriptions>
+          <Tabs
+            size="small"
+            items={[
+              {
+                key: 'processes',
+                label: t('settings.about.perf.processes'),
+                children: (
+                  <Table<PerfProcessMetric>
+                    size="small"
+                    rowKey="pid"
+                    pagination={false}
+                    scroll={{ y: '40vh' }}
+                    locale={emptyText}
+                    dataSource={[...snapshot.processes].sort((a, b) => b.memory - a.memory)}
+                    columns={[
+                      { key: 'type', title: t('settings.about.perf.type'), dataIndex: 'type' },
+                      { key: 'name', title: t('settings.about.perf.name'), dataIndex: 'name' },
+                      { key: 'pid', title: 'PID', dataIndex: 'pid', align: 'right' },
+                      {
+                        key: 'memory',
+                        title: t('settings.about.perf.memory'),
+                        align: 'right',
+                        render: (_, item) => formatKB(item.memory)
+                      },
+                      { key: 'cpu', title: 'CPU', align: 'right', render: (_, item) => `${item.cpu.toFixed(1)}%` }
+                    ]}
+                  />
+                )
+              },
+              {
+                key: 'webviews',
+                label: t('settings.about.perf.webviews'),
+                children: (
+                  <Table<PerfWebviewMetric>
+                    size="small"
+                    rowKey="id"
+                    pagination={false}
+                    scroll={{ y: '40vh' }}
+                    locale={emptyText}
+                    dataSource={snapshot.webviews}
+                    columns={[
+                      { key: 'type', title: t('settings.about.perf.type'), dataIndex: 'type', width: 100 },
+                      {
+                        key: 'title',
+                        title: t('settings.about.perf.page'),
+                        render: (_, item) => <PageCell title={item.url}>{item.title || item.url}</PageCell>
+                      },
+                      { key: 'pid', title: 'PID', dataIndex: 'pid', align: 'right', width: 80 },
+                      {
+                        key: 'memory',
+                        title: t('settings.about.perf.memory'),
+                        align: 'right',
+                        width: 100,
+                        render: (_, item) => formatKB(item.memory)
+                      }
+                    ]}
+                  />
+                )
+              },
+              {
+                key: 'locks',
+                label: t('settings.about.perf.locks'),
+                children: (
+                  <Table<PerfLockMetric>
+                    size="small"
+                    rowKey="name"
+                    pagination={false}
+                    scroll={{ y: '40vh' }}
+                    locale={emptyText}
+                    dataSource={snapshot.locks}
+                    columns={[
+                      { key: 'name', title: t('settings.about.perf.name'), dataIndex: 'name' },
+                      {
+                        key: 'acquisitions',
+                        title: t('settings.about.perf.acquisitions'),
+                        dataIndex: 'acquisitions',
+                        align: 'right'
+                      },
+                      {
+                        key: 'contended',
+                        title: t('settings.about.perf.contended'),
+                        dataIndex: 'contended',
+                        align: 'right'
+                      },
+                      {
+                        key: 'wait',
+                        title: t('settings.about.perf.wait'),
+                        align: 'right',
+                        render: (_, item) => `${item.waitMs.toFixed(1)} / ${item.maxWaitMs.toFixed(1)} ms`
+                      }
+                    ]}
+                  />
+                )
+              },
+              {
+                key: 'startup',
+                label: t('settings.about.perf.startup'),
+                children: startup && (
+                  <>
+                    <Milestones>
+                      {Object.entries(startup.milestones).map(([name, time]) => (
+                        <Tag key={name}>
+                          {name}: {time} ms
+                        </Tag>
+                      ))}
+                    </Milestones>
+                    <Table<StartupSpan>
+                      size="small"
+                      rowKey={(item) => `${item.stage}-${item.name}`}
+                      pagination={false}
+                      scroll={{ y: '36vh' }}
+                      locale={emptyText}
+                      dataSource={startup.spans}
+                      columns={[
+                        { key: 'name', title: t('settings.about.perf.name'), dataIndex: 'name' },
+                        {
+                          key: 'stage',
+                          title: t('settings.about.perf.stage'),
+                          render: (_, item) => (
+                            <Tag color={item.stage === 'critical' ? 'orange' : 'blue'}>
+                              {t(`settings.about.perf.stages.${item.stage}`)}
+                            </Tag>
+                          )
+                        },
+                        { key: 'start', title: t('settings.about.perf.start'), align: 'right', dataIndex: 'start' },
+                        {
+                          key: 'duration',
+                          title: t('settings.about.perf.duration'),
+                          align: 'right',
+                          render: (_, item) => (
+                            <span title={item.error} style={item.error ? { color: 'var(--color-error)' } : undefined}>
+                              {item.duration} ms
+                            </span>
+                          )
+                        }
+                      ]}
+                    />
+                  </>
+                )
+              }
+            ]}
+          />
+        </>
+      )}
+    </Modal>
+  )
+}
+
+const Milestones = styled.div`
+  display: flex;
+  flex-wrap: wrap;
+  gap: 6px 0;
+  margin-bottom: 10px;
+`
+
+const PageCell = styled.span`
+  display: inline-block;
+  max-width: 360px;
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+  vertical-align: bottom;
+`
+
+const TopViewKey = 'PerfPopup'
+
+export default class PerfPopup {
+  static topviewId = 0
+  static hide() {
+    TopView.hide(TopViewKey)
+  }
+  static show() {
+    return new Promise<any>((resolve) => {
+      TopView.show(
+        <PopupContainer
+          resolve={(v) => {
+            resolve(v)
+            TopView.hide(TopViewKey)
+          }}
+        />,
+        TopViewKey
+      )
+    })
+  }
+}
@@ -0,0 +1,119 @@
+import { shareConversation } from '@renderer/services/ConversationShareService'
+import { Topic } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Checkbox, Modal, Radio, Select } from 'antd'
+import dayjs from 'dayjs'
+import { useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { TopView } from '../TopView'
+
+interface Props {
+  topic: Topic
+  resolve: (data: any) => void
+}
+
+const TTL_OPTIONS = [15, 60, 24 * 60, 7 * 24 * 60]
+
+const PopupContainer: React.FC<Props> = ({ topic, resolve }) => {
+  const [open, setOpen] = useState(true)
+  const [redactNames, setRedactNames] = useState(false)
+  const [mode, setMode] = useState<'file' | 'link'>('file')
+  const [ttl, setTtl] = useState(60)
+  const [loading, setLoading] = useState(false)
+  const { t } = useTranslation()
+
+  const onOk = async () => {
+    setLoading(true)
+    try {
+      const shared = await shareConversation(topic, { redactNames, serve: mode === 'link', ttl })
+      window.message.success({
+        content: shared.url
+          ? t('chat.topics.share.link_copied', { time: dayjs(shared.expiresAt).format('MM/DD HH:mm') })
+          : t('chat.topics.share.path_copied'),
+        key: 'share-conversation'
+      })
+      setOpen(false)
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'share-conversation' })
+    } finally {
+      setLoading(false)
+    }
+  }
+
+  const onCancel = () => {
+    setOpen(false)
+  }
+
+  const onClose = () => {
+    resolve({})
+  }
+
+  ShareConversationPopup.hide = onCancel
+
+  return (
+    <Modal
+      title={t('chat.topics.share.title')}
+      open={open}
+      onOk={onOk}
+      onCancel={onCancel}
+      afterClose={onClose}
+      okButtonProps={{ loading }}
+      transitionName="animation-move-down"
+      centered>
+      <Fields>
+        <Radio.Group value={mode} onChange={(e) => setMode(e.target.value)}>
+          <Radio value="file">{t('chat.topics.share.file')}</Radio>
+          <Radio value="link">{t('chat.topics.share.link')}</Radio>
+        </Radio.Group>
+        {mode === 'link' && (
+          <Select
+            value={ttl}
+            onChange={setTtl}
+            options={TTL_OPTIONS.map((value) => ({ value, label: t(`chat.topics.share.ttl.${value}`) }))}
+          />
+        )}
+        <Checkbox checked={redactNames} onChange={(e) => setRedactNames(e.target.checked)}>
+          {t('chat.topics.share.redact_names')}
+        </Checkbox>
+        {mode === 'link' && <Hint>{t('chat.topics.share.link_hint')}</Hint>}
+      </Fields>
+    </Modal>
+  )
+}
+
+const Fields = styled.div`
+  display: flex;
+  flex-direction: column;
+  gap: 12px;
+  padding: 8px 0;
+`
+
+const Hint = styled.div`
+  font-size: 12px;
+  color: var(--color-text-3);
+`
+
+const TopViewKey = 'ShareConversationPopup'
+
+export default class ShareConversationPopup {
+  static topviewId = 0
+  static hide() {
+    TopView.hide(TopViewKey)
+  }
+  static show(topic: Topic) {
+    return new Promise<any>((resolve) => {
+      TopView.show(
+        <PopupContainer
+          topic={topic}
+          resolve={(v) => {
+            resolve(v)
+            TopView.hide(TopViewKey)
+          }}
+        />,
+        TopViewKey
+      )
+    })
+  }
+}
@@ -0,0 +1,206 @@
+import { TabularColumn, TabularPreview, TabularValue } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Empty, Modal, Segmented, Spin, Table, Tabs, Tag } from 'antd'
+import { useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { TopView } from '../TopView'
+
+interface Props {
+  path: string
+  title: string
+  resolve: (data: any) => void
+}
+
+const PAGE_SIZE = 50
+
+const formatValue = (value: TabularValue | undefined) =>
+  value === null || value === undefined ? '' : typeof value === 'number' ? value.toLocaleString() : String(value)
+
+const isNumeric = (column: TabularColumn) => column.type === 'integer' || column.type === 'number'
+
+const PopupContainer: React.FC<Props> = ({ path, title, resolve }) => {
+  const [open, setOpen] = useState(true)
+  const [preview, setPreview] = useState<TabularPreview | null>(null)
+  const [sheet, setSheet] = useState<string>()
+  const [page, setPage] = useState(1)
+  const [loading, setLoading] = useState(true)
+  const [error, setError] = useState<string | null>(null)
+  const { t } = useTranslation()
+
+  // 文件只在主进程解析一次，翻页时只取当前页的行
+  useEffect(() => {
+    setLoading(true)
+    setError(null)
+    window.api.tabular
+      .parse(path, { sheet, offset: (page - 1) * PAGE_SIZE, limit: PAGE_SIZE })
+      .then(setPreview)
+      .catch((error) => setError(getErrorMessage(error)))
+      .finally(() => setLoading(false))
+  }, [path, sheet, page])
+
+  const onCancel = () => {
+    setOpen(false)
+  }
+
+  const onClose = () => {
+    resolve({})
+  }
+
+  TabularPreviewPopup.hide = onCancel
+
+  const renderSummary = (column: TabularColumn) => {
+    if (isNumeric(column)) {
+      return t('files.tabular.summary.number', {
+        min: formatValue(column.min as number),
+        max: formatValue(column.max as number),
+        mean: formatValue(Number(column.mean?.toFixed(2)))
+      })
+    }
+    if (column.type === 'date') {
+      return t('files.tabular.summary.date', { min: column.min, max: column.max })
+    }
+    return column.top?.map((item) => `${item.value} (${item.count})`).join(', ')
+  }
+
+  return (
+    <Modal
+      title={title}
+      open={open}
+      onCancel={onCancel}
+      afterClose={onClose}
+      footer={null}
+      width="80vw"
+      transitionName="animation-move-down"
+      centered>
+      {!preview && !error && <Spin />}
+      {error && <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={error} />}
+      {preview && !error && (
+        <>
+          {preview.sheets.length > 1 && (
+            <Segmented
+              size="small"
+              options={preview.sheets}
+              value={preview.sheet}
+              onChange={(value) => {
+                setSheet(value as string)
+                setPage(1)
+              }}
+              style={{ marginBottom: 12 }}
+            />
+          )}
+          <Tabs
+            size="small"
+            items={[
+              {
+                key: 'rows',
+                label: t('files.tabular.rows', { count: preview.totalRows }),
+                children: (
+                  <Table
+                    size="small"
+                    loading={loading}
+                    scroll={{ x: 'max-content', y: '55vh' }}
+                    rowKey={(_, index) => String(preview.offset + (index ?? 0))}
+                    dataSource={preview.rows}
+                    columns={preview.columns.map((column, index) => ({
+                      key: String(index),
+                      title: (
+                        <ColumnTitle>
+                          {column.name}
+                          <Tag>{t(`files.tabular.types.${column.type}`)}</Tag>
+                        </ColumnTitle>
+                      ),
+                      align: isNumeric(column) ? ('right' as const) : ('left' as const),
+                      render: (_, row: TabularValue[]) => <Cell>{formatValue(row[index])}</Cell>
+                    }))}
+                    pagination={{
+                      current: page,
+                      pageSize: PAGE_SIZE,
+                      total: preview.totalRows,
+                      showSizeChanger: false,
+                      onChange: setPage
+                    }}
+                    locale={{ emptyText: <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} /> }}
+                  />
+                )
+              },
+              {
+                key: 'columns',
+                label: t('files.tabular.columns', { count: preview.columns.length }),
+                children: (
+                  <Table
+                    size="small"
+                    rowKey="name"
+                    pagination={false}
+                    scroll={{ y: '55vh' }}
+                    dataSource={preview.columns}
+                    columns={[
+                      { key: 'name', title: t('files.tabular.column.name'), dataIndex: 'name' },
+                      {
+                        key: 'type',
+                        title: t('files.tabular.column.type'),
+                        render: (_, column: TabularColumn) => <Tag>{t(`files.tabular.types.${column.type}`)}</Tag>
+                      },
+                      ...(['count', 'missing', 'distinct'] as const).map((key) => ({
+                        key,
+                        title: t(`files.tabular.column.${key}`),
+                        dataIndex: key,
+                        align: 'right' as const
+                      })),
+                      {
+                        key: 'summary',
+                        title: t('files.tabular.column.summary'),
+                        render: (_, column: TabularColumn) => renderSummary(column)
+                      }
+                    ]}
+                  />
+                )
+              }
+            ]}
+          />
+        </>
+      )}
+    </Modal>
+  )
+}
+
+const ColumnTitle = styled.span`
+  display: inline-flex;
+  align-items: center;
+  gap: 6px;
+  white-space: nowrap;
+`
+
+const Cell = styled.span`
+  display: inline-block;
+  max-width: 320px;
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+  vertical-align: bottom;
+`
+
+const TopViewKey = 'TabularPreviewPopup'
+
+export default class TabularPreviewPopup {
+  static topviewId = 0
+  static hide() {
+    TopView.hide(TopViewKey)
+  }
+  static show(path: string, title: string) {
+    return new Promise<any>((resolve) => {
+      TopView.show(
+        <PopupContainer
+          path={path}
+          title={title}
+          resolve={(v) => {
+            resolve(v)
+            TopView.hide(TopViewKey)
+          }}
+        />,
+        TopViewKey
+      )
+    })
+  }
+}
@@ -0,0 +1,172 @@
+import { formatFileSize } from '@renderer/utils'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Empty, Modal, Spin, Typography } from 'antd'
+import { useCallback, useEffect, useRef, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { FixedSizeList, ListChildComponentProps, ListOnItemsRenderedProps } from 'react-window'
+import styled from 'styled-components'
+
+import { TopView } from '../TopView'
+
+interface Props {
+  path: string
+  title: string
+  resolve: (data: any) => void
+}
+
+// 每次从主进程读取的行数
+const PAGE_SIZE = 200
+const LINE_HEIGHT = 20
+const LIST_HEIGHT = 480
+
+interface FileInfo {
+  totalLines: number
+  size: number
+}
+
+const PopupContainer: React.FC<Props> = ({ path, title, resolve }) => {
+  const [open, setOpen] = useState(true)
+  const [info, setInfo] = useState<FileInfo | null>(null)
+  const [truncated, setTruncated] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+  // 只保留已读取的页，按页号索引
+  const [pages, setPages] = useState<Record<number, string[]>>({})
+  const loadingPages = useRef(new Set<number>())
+  const { t } = useTranslation()
+
+  const loadPage = useCallback(
+    (page: number) => {
+      if (loadingPages.current.has(page)) return
+      loadingPages.current.add(page)
+      window.api.textPreview
+        .readLines(path, page * PAGE_SIZE, PAGE_SIZE)
+        .then((result) => {
+          setInfo({ totalLines: result.totalLines, size: result.size })
+          setTruncated((value) => value || result.truncated)
+          setPages((prev) => ({ ...prev, [page]: result.lines }))
+        })
+        .catch((error) => {
+          loadingPages.current.delete(page)
+          setError(getErrorMessage(error))
+        })
+    },
+    [path]
+  )
+
+  useEffect(() => {
+    loadPage(0)
+  }, [loadPage])
+
+  const onItemsRendered = ({ visibleStartIndex, visibleStopIndex }: ListOnItemsRenderedProps) => {
+    const first = Math.floor(visibleStartIndex / PAGE_SIZE)
+    const last = Math.floor(visibleStopIndex / PAGE_SIZE)
+    for (let page = first; page <= last; page++) {
+      if (!pages[page]) loadPage(page)
+    }
+  }
+
+  const onCancel = () => {
+    setOpen(false)
+  }
+
+  const onClose = () => {
+    resolve({})
+  }
+
+  TextPreviewPopup.hide = onCancel
+
+  return (
+    <Modal
+      title={title}
+      open={open}
+      onCancel={onCancel}
+      afterClose={onClose}
+      footer={null}
+      width="80vw"
+      transitionName="animation-move-down"
+      centered>
+      {!info && !error && <Spin />}
+      {error && <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={error} />}
+      {info && !error && (
+        <>
+          <Typography.Text type="secondary">
+            {t('files.text_preview.lines', { count: info.totalLines })} · {formatFileSize(info.size)}
+            {truncated && ` · ${t('files.text_preview.truncated')}`}
+          </Typography.Text>
+          <ListContainer>
+            <FixedSizeList
+              height={Math.min(LIST_HEIGHT, Math.max(1, info.totalLines) * LINE_HEIGHT)}
+              width="100%"
+              itemCount={info.totalLines}
+              itemSize={LINE_HEIGHT}
+              overscanCount={20}
+              itemData={pages}
+              onItemsRendered={onItemsRendered}>
+              {Row}
+            </FixedSizeList>
+          </ListContainer>
+        </>
+      )}
+    </Modal>
+  )
+}
+
+// 未读取到的行先显示为空行
+const Row = ({ index, style, data }: ListChildComponentProps<Record<number, string[]>>) => (
+  <Line style={style}>
+    <LineNumber>{index + 1}</LineNumber>
+    <LineText>{data[Math.floor(index / PAGE_SIZE)]?.[index % PAGE_SIZE] ?? ''}</LineText>
+  </Line>
+)
+
+const ListContainer = styled.div`
+  margin-top: 8px;
+  border: 0.5px solid var(--color-border);
+  border-radius: 6px;
+  font-family: var(--code-font-family);
+  font-size: 12px;
+`
+
+const Line = styled.div`
+  display: flex;
+  line-height: ${LINE_HEIGHT}px;
+  white-space: pre;
+`
+
+const LineNumber = styled.span`
+  flex-shrink: 0;
+  width: 64px;
+  padding-right: 12px;
+  text-align: right;
+  color: var(--color-text-3);
+  user-select: none;
+`
+
+const LineText = styled.span`
+  overflow: hidden;
+  text-overflow: ellipsis;
+`
+
+const TopViewKey = 'TextPreviewPopup'
+
+export default class TextPreviewPopup {
+  static topviewId = 0
+  static hide() {
+    TopView.hide(TopViewKey)
+  }
+  static show(path: string, title: string) {
+    return new Promise<any>((resolve) => {
+      TopView.show(
+        <PopupContainer
+          path={path}
+          title={title}
+          resolve={(v) => {
+            resolve(v)
+            TopView.hide(TopViewKey)
+          }}
+        />,
+        TopViewKey
+      )
+    })
+  }
+}
@@ -0,0 +1,135 @@
+import { ConversationWorkspace, WorkspaceFileChange } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Collapse, Empty, Modal, Spin, Tag } from 'antd'
+import { useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { TopView } from '../TopView'
+
+interface Props {
+  workspace: ConversationWorkspace
+  resolve: (data: any) => void
+}
+
+const STATUS_COLORS = { added: 'green', modified: 'blue', deleted: 'red' }
+
+const PopupContainer: React.FC<Props> = ({ workspace, resolve }) => {
+  const [open, setOpen] = useState(true)
+  const [changes, setChanges] = useState<WorkspaceFileChange[] | null>(null)
+  const { t } = useTranslation()
+
+  useEffect(() => {
+    window.api.conversationWorkspace
+      .getChanges(workspace.topicId)
+      .then(setChanges)
+      .catch((error) => {
+        window.message.error({ content: getErrorMessage(error), key: 'workspace-changes' })
+        setChanges([])
+      })
+  }, [workspace.topicId])
+
+  const onCancel = () => {
+    setOpen(false)
+  }
+
+  const onClose = () => {
+    resolve({})
+  }
+
+  WorkspaceChangesPopup.hide = onCancel
+
+  return (
+    <Modal
+      title={t('chat.topics.workspace.changes')}
+      open={open}
+      onCancel={onCancel}
+      afterClose={onClose}
+      footer={null}
+      width={760}
+      transitionName="animation-move-down"
+      centered>
+      <Folder>{workspace.path}</Folder>
+      {!changes && <Spin />}
+      {changes?.length === 0 && <Empty description={t('chat.topics.workspace.no_changes')} />}
+      {!!changes?.length && (
+        <Collapse
+          size="small"
+          items={changes.map((change) => ({
+            key: change.path,
+            label: (
+              <span>
+                <Tag color={STATUS_COLORS[change.status]}>{t(`chat.topics.workspace.status.${change.status}`)}</Tag>
+                {change.path}
+              </span>
+            ),
+            children: change.diff ? (
+              <Diff>
+                {change.diff
+                  .split('\n')
+                  .slice(4)
+                  .map((line, index) => (
+                    <DiffLine key={index} $type={line[0]}>
+                      {line || ' '}
+                    </DiffLine>
+                  ))}
+              </Diff>
+            ) : (
+              <Hint>{t('chat.topics.workspace.no_diff')}</Hint>
+            )
+          }))}
+        />
+      )}
+    </Modal>
+  )
+}
+
+const Folder = styled.div`
+  font-size: 12px;
+  color: var(--color-text-3);
+  margin-bottom: 12px;
+  word-break: break-all;
+`
+
+const Diff = styled.pre`
+  margin: 0;
+  max-height: 400px;
+  overflow: auto;
+  font-size: 12px;
+  font-family: var(--code-font-family);
+`
+
+const DiffLine = styled.div<{ $type?: string }>`
+  white-space: pre;
+  color: ${({ $type }) => ($type === '@' ? 'var(--color-text-3)' : 'inherit')};
+  background-color: ${({ $type }) =>
+    $type === '+' ? 'rgba(46, 160, 67, 0.15)' : $type === '-' ? 'rgba(248, 81, 73, 0.15)' : 'transparent'};
+`
+
+const Hint = styled.div`
+  font-size: 12px;
+  color: var(--color-text-3);
+`
+
+const TopViewKey = 'WorkspaceChangesPopup'
+
+export default class WorkspaceChangesPopup {
+  static topviewId = 0
+  static hide() {
+    TopView.hide(TopViewKey)
+  }
+  static show(workspace: ConversationWorkspace) {
+    return new Promise<any>((resolve) => {
+      TopView.show(
+        <PopupContainer
+          workspace={workspace}
+          resolve={(v) => {
+            resolve(v)
+            TopView.hide(TopViewKey)
+          }}
+        />,
+        TopViewKey
+      )
+    })
+  }
+}
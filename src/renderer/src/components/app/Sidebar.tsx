@@ -26,7 +26,9 @@ import {
   Settings,
   Sparkle,
   Sun,
-  SunMoon
+  SunMoon,
+  Volume2,
+  VolumeX
 } from 'lucide-react'
 import { FC, useEffect } from 'react'
 import { useTranslation } from 'react-i18next'
@@ -191,7 +193,7 @@ const MainMenus: FC = () => {
 
 /** Tabs of opened minapps in sidebar */
 const SidebarOpenedMinappTabs: FC = () => {
-  const { minappShow, openedKeepAliveMinapps, currentMinappId } = useRuntime()
+  const { minappShow, openedKeepAliveMinapps, currentMinappId, minappAudioStates } = useRuntime()
   const { openMinappKeepAlive, hideMinappPopup, closeMinapp, closeAllMinapps } = useMinappPopup()
   const { showOpenedMinappsInSidebar } = useSettings() // 获取控制显示的设置
   const { theme } = useTheme()
@@ -241,7 +243,15 @@ const SidebarOpenedMinappTabs: FC = () => {
       <TabsWrapper>
         <Menus>
           {openedKeepAliveMinapps.map((app) => {
+            const audio = minappAudioStates[app.id]
             const menuItems: MenuProps['items'] = [
+              {
+                key: 'toggleMute',
+                label: audio?.muted ? t('minapp.popup.unmute') : t('minapp.popup.mute'),
+                onClick: () => {
+                  window.api.miniApp.setMuted(app.id, !audio?.muted)
+                }
+              },
               {
                 key: 'closeApp',
                 label: t('minapp.sidebar.close.title'),
@@ -268,6 +278,9 @@ const SidebarOpenedMinappTabs: FC = () => {
                       onClick={() => handleOnClick(app)}
                       className={`${isActive ? 'opened-active' : ''}`}>
                       <MinAppIcon size={20} app={app} style={{ borderRadius: 6 }} sidebar />
+                      {(audio?.muted || audio?.audible) && (
+                        <AudioBadge>{audio.muted ? <VolumeX size={9} /> : <Volume2 size={9} />}</AudioBadge>
+                      )}
                     </Icon>
                   </Dropdown>
                 </StyledLink>
@@ -364,6 +377,7 @@ const Menus = styled.div`
 `
 
 const Icon = styled.div<{ theme: string }>`
+  position: relative;
   width: 35px;
   height: 35px;
   display: flex;
@@ -472,6 +486,21 @@ const TabsContainer = styled.div`
   }
 `
 
+const AudioBadge = styled.div`
+  position: absolute;
+  right: 1px;
+  bottom: 1px;
+  display: flex;
+  align-items: center;
+  justify-content: center;
+  width: 14px;
+  height: 14px;
+  border-radius: 50%;
+  color: var(--color-text);
+  background-color: var(--color-background);
+  border: 0.5px solid var(--color-border);
+`
+
 const TabsWrapper = styled.div`
   background-color: rgba(128, 128, 128, 0.1);
   border-radius: 20px;
@@ -57,6 +57,10 @@ import OpenAiProviderLogo from '@renderer/assets/images/providers/openai.png?url
 import SiliconFlowProviderLogo from '@renderer/assets/images/providers/silicon.png?url'
 import { MinAppType } from '@renderer/types'
 
+// Google 对默认 UA 返回简化版页面
+const GOOGLE_USER_AGENT =
+  'Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko)  Safari/537.36'
+
 // 加载自定义小应用
 const loadCustomMiniApp = async (): Promise<MinAppType[]> => {
   try {
@@ -72,18 +76,33 @@ const loadCustomMiniApp = async (): Promise<MinAppType[]> => {
     const customApps = JSON.parse(content)
     const now = new Date().toISOString()
 
-    return customApps.map((app: any) => ({
+    const apps: MinAppType[] = customApps.map((app: any) => ({
       ...app,
       type: 'Custom',
       logo: app.logo && app.logo !== '' ? app.logo : ApplicationLogo,
       addTime: app.addTime || now
     }))
+    return [...apps, ...(await loadManifestMiniApps(apps))]
   } catch (error) {
     console.error('Failed to load custom mini apps:', error)
     return []
   }
 }
 
+// 数据目录 miniapps 文件夹中 manifest.json 声明的小应用，与已有小应用重名的跳过
+const loadManifestMiniApps = async (customApps: MinAppType[]): Promise<MinAppType[]> => {
+  try {
+    const taken = new Set([...ORIGIN_DEFAULT_MIN_APPS, ...customApps].map((app) => app.id))
+    const apps = await window.api.miniApp.getManifestApps()
+    return apps
+      .filter((app) => !taken.has(app.id))
+      .map((app) => ({ ...app, logo: app.logo || ApplicationLogo }))
+  } catch (error) {
+    console.error('Failed to load mini app manifests:', error)
+    return []
+  }
+}
+
 // 初始化默认小应用
 const ORIGIN_DEFAULT_MIN_APPS: MinAppType[] = [
   {
@@ -186,6 +205,7 @@ const ORIGIN_DEFAULT_MIN_APPS: MinAppType[] = [
     url: 'https://google.com/',
     logo: GoogleAppLogo,
     bodered: true,
+    userAgent: GOOGLE_USER_AGENT,
     style: {
       padding: 5
     }
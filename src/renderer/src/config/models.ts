@@ -223,6 +223,9 @@ export const RERANKING_REGEX = /(?:rerank|re-rank|re-ranker|re-ranking|retrieval
 
 export const NOT_SUPPORTED_REGEX = /(?:^tts|whisper|speech)/i
 
+// Speech to text models
+export const TRANSCRIPTION_REGEX = /(?:whisper|transcribe|sensevoice|paraformer|\basr\b)/i
+
 // Tool calling models
 export const FUNCTION_CALLING_MODELS = [
   'gpt-4o',
@@ -2205,7 +2208,39 @@ export const SYSTEM_MODELS: Record<string, Model[]> = {
       group: 'DeepSeek'
     }
   ],
-  lanyun: []
+  lanyun: [],
+  'aws-bedrock': [
+    {
+      id: 'us.anthropic.claude-3-7-sonnet-20250219-v1:0',
+      provider: 'aws-bedrock',
+      name: 'Claude 3.7 Sonnet',
+      group: 'Claude 3.7'
+    },
+    {
+      id: 'anthropic.claude-3-5-haiku-20241022-v1:0',
+      provider: 'aws-bedrock',
+      name: 'Claude 3.5 Haiku',
+      group: 'Claude 3.5'
+    },
+    {
+      id: 'amazon.nova-pro-v1:0',
+      provider: 'aws-bedrock',
+      name: 'Nova Pro',
+      group: 'Amazon Nova'
+    },
+    {
+      id: 'meta.llama3-1-70b-instruct-v1:0',
+      provider: 'aws-bedrock',
+      name: 'Llama 3.1 70B Instruct',
+      group: 'Llama 3.1'
+    },
+    {
+      id: 'amazon.titan-embed-text-v2:0',
+      provider: 'aws-bedrock',
+      name: 'Titan Text Embeddings V2',
+      group: 'Amazon Titan'
+    }
+  ]
 }
 
 export const TEXT_TO_IMAGES_MODELS = [
@@ -2344,6 +2379,10 @@ export function isRerankModel(model: Model): boolean {
   return model ? RERANKING_REGEX.test(model.id) || false : false
 }
 
+export function isTranscriptionModel(model: Model): boolean {
+  return model ? TRANSCRIPTION_REGEX.test(model.id) : false
+}
+
 export function isVisionModel(model: Model): boolean {
   if (!model) {
     return false
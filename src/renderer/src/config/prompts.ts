@@ -49,6 +49,9 @@ As [role name], with [list skills], strictly adhering to [list constraints], usi
 export const SUMMARIZE_PROMPT =
   "You are an assistant skilled in conversation. You need to summarize the user's conversation into a title within 10 words. The language of the title should be consistent with the user's primary language. Do not use punctuation marks or other special symbols"
 
+export const BATCH_SUMMARIZE_PROMPT =
+  'Summarize the content provided by the user in a few sentences. Keep the key facts, names and numbers, use the same language as the content and output only the summary'
+
 // https://github.com/ItzCrazyKns/Perplexica/blob/master/src/lib/prompts/webSearch.ts
 export const SEARCH_SUMMARY_PROMPT = `
   You are an AI question rephraser. Your role is to rephrase follow-up queries from a conversation into standalone queries that can be used by another LLM to retrieve information, either through web search or from a knowledge base.
@@ -113,6 +113,9 @@ export function getProviderLogo(providerId: string) {
 export const NOT_SUPPORTED_REANK_PROVIDERS = ['ollama']
 export const ONLY_SUPPORTED_DIMENSION_PROVIDERS = ['ollama', 'infini']
 
+// 未填写 API 版本时使用的最新 GA 版本
+export const AZURE_OPENAI_DEFAULT_API_VERSION = '2024-10-21'
+
 export const PROVIDER_CONFIG = {
   '302ai': {
     api: {
@@ -664,5 +667,16 @@ export const PROVIDER_CONFIG = {
       docs: 'https://cloud.google.com/vertex-ai/generative-ai/docs',
       models: 'https://cloud.google.com/vertex-ai/generative-ai/docs/learn/models'
     }
+  },
+  'aws-bedrock': {
+    api: {
+      url: 'https://bedrock-runtime.us-east-1.amazonaws.com'
+    },
+    websites: {
+      official: 'https://aws.amazon.com/bedrock/',
+      apiKey: 'https://console.aws.amazon.com/iam/home#/security_credentials',
+      docs: 'https://docs.aws.amazon.com/bedrock/latest/userguide/',
+      models: 'https://docs.aws.amazon.com/bedrock/latest/userguide/models-supported.html'
+    }
   }
 }
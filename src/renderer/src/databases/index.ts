@@ -1,4 +1,14 @@
-import { FileType, KnowledgeItem, QuickPhrase, TranslateHistory } from '@renderer/types'
+import {
+  ContentFilterLog,
+  FileType,
+  Job,
+  KnowledgeItem,
+  LLMCacheEntry,
+  QuickPhrase,
+  StatsCounter,
+  TranslateCacheEntry,
+  TranslateHistory
+} from '@renderer/types'
 // Import necessary types for blocks and new message structure
 import type { Message as NewMessage, MessageBlock } from '@renderer/types/newMessage'
 import { Dexie, type EntityTable } from 'dexie'
@@ -14,6 +24,11 @@ export const db = new Dexie('CherryStudio') as Dexie & {
   translate_history: EntityTable<TranslateHistory, 'id'>
   quick_phrases: EntityTable<QuickPhrase, 'id'>
   message_blocks: EntityTable<MessageBlock, 'id'> // Correct type for message_blocks
+  llm_cache: EntityTable<LLMCacheEntry, 'key'>
+  jobs: EntityTable<Job, 'id'>
+  translate_cache: EntityTable<TranslateCacheEntry, 'key'>
+  content_filter_logs: EntityTable<ContentFilterLog, 'id'>
+  stats: EntityTable<StatsCounter, 'id'>
 }
 
 db.version(1).stores({
@@ -74,4 +89,69 @@ db.version(7)
   })
   .upgrade((tx) => upgradeToV7(tx))
 
+db.version(8).stores({
+  files: 'id, name, origin_name, path, size, ext, type, created_at, count',
+  topics: '&id',
+  settings: '&id, value',
+  knowledge_notes: '&id, baseId, type, content, created_at, updated_at',
+  translate_history: '&id, sourceText, targetText, sourceLanguage, targetLanguage, createdAt',
+  quick_phrases: 'id',
+  message_blocks: 'id, messageId, file.id',
+  llm_cache: '&key, createdAt, expiresAt'
+})
+
+db.version(9).stores({
+  files: 'id, name, origin_name, path, size, ext, type, created_at, count',
+  topics: '&id',
+  settings: '&id, value',
+  knowledge_notes: '&id, baseId, type, content, created_at, updated_at',
+  translate_history: '&id, sourceText, targetText, sourceLanguage, targetLanguage, createdAt',
+  quick_phrases: 'id',
+  message_blocks: 'id, messageId, file.id',
+  llm_cache: '&key, createdAt, expiresAt',
+  jobs: '&id, type, status, createdAt'
+})
+
+db.version(10).stores({
+  files: 'id, name, origin_name, path, size, ext, type, created_at, count',
+  topics: '&id',
+  settings: '&id, value',
+  knowledge_notes: '&id, baseId, type, content, created_at, updated_at',
+  translate_history: '&id, sourceText, targetText, sourceLanguage, targetLanguage, createdAt',
+  quick_phrases: 'id',
+  message_blocks: 'id, messageId, file.id',
+  llm_cache: '&key, createdAt, expiresAt',
+  jobs: '&id, type, status, createdAt',
+  translate_cache: '&key, createdAt'
+})
+
+db.version(11).stores({
+  files: 'id, name, origin_name, path, size, ext, type, created_at, count',
+  topics: '&id',
+  settings: '&id, value',
+  knowledge_notes: '&id, baseId, type, content, created_at, updated_at',
+  translate_history: '&id, sourceText, targetText, sourceLanguage, targetLanguage, createdAt',
+  quick_phrases: 'id',
+  message_blocks: 'id, messageId, file.id',
+  llm_cache: '&key, createdAt, expiresAt',
+  jobs: '&id, type, status, createdAt',
+  translate_cache: '&key, createdAt',
+  content_filter_logs: '&id, direction, assistantId, createdAt'
+})
+
+db.version(12).stores({
+  files: 'id, name, origin_name, path, size, ext, type, created_at, count',
+  topics: '&id',
+  settings: '&id, value',
+  knowledge_notes: '&id, baseId, type, content, created_at, updated_at',
+  translate_history: '&id, sourceText, targetText, sourceLanguage, targetLanguage, createdAt',
+  quick_phrases: 'id',
+  message_blocks: 'id, messageId, file.id',
+  llm_cache: '&key, createdAt, expiresAt',
+  jobs: '&id, type, status, createdAt',
+  translate_cache: '&key, createdAt',
+  content_filter_logs: '&id, direction, assistantId, createdAt',
+  stats: '&id, date, metric'
+})
+
 export default db
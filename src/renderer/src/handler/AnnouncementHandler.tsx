@@ -0,0 +1,52 @@
+import { useEffect, useState } from 'react'
+import styled from 'styled-components'
+
+type Politeness = 'polite' | 'assertive'
+
+/**
+ * 主进程 AccessibilityManager.announce 的文本写入 ARIA live 区域，由读屏软件朗读，不移动焦点
+ */
+const AnnouncementHandler: React.FC = () => {
+  const [messages, setMessages] = useState<Record<Politeness, string>>({ polite: '', assertive: '' })
+
+  useEffect(() => {
+    let timer: ReturnType<typeof setTimeout> | undefined
+    const removeListener = window.api.accessibility.onAnnounce(({ text, politeness }) => {
+      const key: Politeness = politeness === 'assertive' ? 'assertive' : 'polite'
+      // 先清空再写入，相同的文本连续播报时也会被朗读
+      setMessages((items) => ({ ...items, [key]: '' }))
+      clearTimeout(timer)
+      timer = setTimeout(() => setMessages((items) => ({ ...items, [key]: text })), 100)
+    })
+    return () => {
+      clearTimeout(timer)
+      removeListener()
+    }
+  }, [])
+
+  return (
+    <>
+      <LiveRegion role="status" aria-live="polite" aria-atomic="true">
+        {messages.polite}
+      </LiveRegion>
+      <LiveRegion role="alert" aria-live="assertive" aria-atomic="true">
+        {messages.assertive}
+      </LiveRegion>
+    </>
+  )
+}
+
+// 视觉上隐藏，读屏软件仍然可以访问
+const LiveRegion = styled.div`
+  position: absolute;
+  width: 1px;
+  height: 1px;
+  margin: -1px;
+  padding: 0;
+  overflow: hidden;
+  clip: rect(0 0 0 0);
+  white-space: nowrap;
+  border: 0;
+`
+
+export default AnnouncementHandler
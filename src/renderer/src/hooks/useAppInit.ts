@@ -4,6 +4,25 @@ import { useTheme } from '@renderer/context/ThemeProvider'
 import db from '@renderer/databases'
 import i18n from '@renderer/i18n'
 import KnowledgeQueue from '@renderer/queue/KnowledgeQueue'
+import { initActionApprovals } from '@renderer/services/ActionApprovalService'
+import { initAgentSync } from '@renderer/services/AgentSyncService'
+import { initApiServerService } from '@renderer/services/ApiServerService'
+import { initAppErrorService } from '@renderer/services/AppErrorService'
+import { initArchiveService } from '@renderer/services/ArchiveService'
+import { resumeBatchJobs } from '@renderer/services/BatchService'
+import { initCommandService } from '@renderer/services/CommandService'
+import { initConversationWorkspaces } from '@renderer/services/ConversationWorkspaceService'
+import { initDatabaseMaintenance } from '@renderer/services/DatabaseMaintenanceService'
+import { initOutboundIntegrations } from '@renderer/services/OutboundIntegrationService'
+import { initScheduledMessages } from '@renderer/services/ScheduledMessageService'
+import { initSessionRecovery } from '@renderer/services/SessionRecoveryService'
+import { initShareService } from '@renderer/services/ShareService'
+import { initStatsManager } from '@renderer/services/StatsManager'
+import { initTranscriptService } from '@renderer/services/TranscriptService'
+import { initTranslateManager } from '@renderer/services/TranslateManager'
+import { initTrayStatus } from '@renderer/services/TrayStatusService'
+import { initWakeWord } from '@renderer/services/WakeWordService'
+import { initWebviewPool } from '@renderer/services/WebviewPoolService'
 import { useAppDispatch } from '@renderer/store'
 import { setAvatar, setFilesPath, setResourcesPath, setUpdateState } from '@renderer/store/runtime'
 import { delay, runAsyncFunction } from '@renderer/utils'
@@ -93,7 +112,34 @@ export function useAppInit() {
   }, [dispatch])
 
   useEffect(() => {
-    KnowledgeQueue.checkAllBases()
+    initTranslateManager()
+    initAppErrorService()
+    initActionApprovals()
+    initApiServerService()
+    initCommandService()
+    initShareService()
+    initTranscriptService()
+    initSessionRecovery()
+    initArchiveService()
+    initScheduledMessages()
+    initWakeWord()
+    initConversationWorkspaces()
+
+    // 非关键的初始化等首屏渲染完成、主线程空闲时再执行，避免拖慢冷启动
+    const idle = window.requestIdleCallback(
+      () => {
+        KnowledgeQueue.checkAllBases()
+        resumeBatchJobs()
+        initDatabaseMaintenance()
+        initStatsManager()
+        initAgentSync()
+        initOutboundIntegrations()
+        initWebviewPool()
+        initTrayStatus()
+      },
+      { timeout: 5000 }
+    )
+    return () => window.cancelIdleCallback(idle)
   }, [])
 
   useEffect(() => {
@@ -0,0 +1,17 @@
+import store, { useAppSelector } from '@renderer/store'
+import { setAwsBedrockRegion } from '@renderer/store/llm'
+import { useDispatch } from 'react-redux'
+
+export function useAwsBedrockSettings() {
+  const settings = useAppSelector((state) => state.llm.settings.awsBedrock)
+  const dispatch = useDispatch()
+
+  return {
+    ...settings,
+    setRegion: (region: string) => dispatch(setAwsBedrockRegion(region))
+  }
+}
+
+export function getAwsBedrockRegion() {
+  return store.getState().llm.settings.awsBedrock?.region || 'us-east-1'
+}
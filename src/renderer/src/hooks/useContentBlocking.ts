@@ -0,0 +1,41 @@
+import Logger from '@renderer/config/logger'
+import { DEFAULT_MIN_APPS } from '@renderer/config/minapps'
+import { useEffect, useMemo } from 'react'
+
+import { useMinapps } from './useMinapps'
+import { useSettings } from './useSettings'
+
+/**
+ * 把广告拦截设置同步给主进程
+ * 关闭拦截的小程序按其网址的域名放行，页面跳转到其他网站后仍然拦截
+ */
+export function useContentBlocking() {
+  const { minappsContentBlocking, minappsContentBlockingOptOut, minappsFilterLists } = useSettings()
+  const { minapps, disabled } = useMinapps()
+
+  const allowedHosts = useMemo(() => {
+    const apps = [...minapps, ...disabled, ...DEFAULT_MIN_APPS]
+    const hosts = minappsContentBlockingOptOut.flatMap((id) => {
+      const app = apps.find((item) => item.id === id)
+      try {
+        return app ? [new URL(app.url).hostname] : []
+      } catch {
+        return []
+      }
+    })
+    return [...new Set(hosts)].sort()
+  }, [minapps, disabled, minappsContentBlockingOptOut])
+
+  const hostsKey = allowedHosts.join(',')
+  const listsKey = minappsFilterLists.join('\n')
+
+  useEffect(() => {
+    window.api.webview
+      .setContentBlocking({
+        enabled: minappsContentBlocking,
+        lists: listsKey ? listsKey.split('\n') : [],
+        allowedHosts: hostsKey ? hostsKey.split(',') : []
+      })
+      .catch((error) => Logger.error('[useContentBlocking] Failed to configure content blocking:', error))
+  }, [minappsContentBlocking, listsKey, hostsKey])
+}
@@ -0,0 +1,14 @@
+import { getConversationWorkspaces } from '@renderer/services/ConversationWorkspaceService'
+import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
+import { useEffect, useState } from 'react'
+
+export function useConversationWorkspaces() {
+  const [workspaces, setWorkspaces] = useState(getConversationWorkspaces)
+
+  useEffect(() => {
+    const onChanged = () => setWorkspaces(getConversationWorkspaces())
+    return EventEmitter.on(EVENT_NAMES.CONVERSATION_WORKSPACES_CHANGED, onChanged)
+  }, [])
+
+  return workspaces
+}
@@ -1,7 +1,7 @@
 import { createSelector } from '@reduxjs/toolkit'
 import store, { useAppDispatch, useAppSelector } from '@renderer/store'
 import { addMCPServer, deleteMCPServer, setMCPServers, updateMCPServer } from '@renderer/store/mcp'
-import { MCPServer } from '@renderer/types'
+import { InstalledMCPServer, MCPServer } from '@renderer/types'
 import { IpcChannel } from '@shared/IpcChannel'
 
 // Listen for server changes from main process
@@ -11,6 +11,21 @@ window.electron.ipcRenderer.on(IpcChannel.Mcp_ServersChanged, (_event, servers)
 window.electron.ipcRenderer.on(IpcChannel.Mcp_AddServer, (_event, server: MCPServer) => {
   store.dispatch(addMCPServer(server))
 })
+// 市场安装的服务更新后指向新版本的文件，运行中的服务重启
+window.electron.ipcRenderer.on(
+  IpcChannel.Mcp_ServerUpdated,
+  (_event, { previous, installed }: { previous: InstalledMCPServer; installed: InstalledMCPServer }) => {
+    const server = store.getState().mcp.servers.find((item) => item.id === installed.serverId)
+    if (!server) return
+
+    const args = (server.args ?? []).map((arg) => (arg.startsWith(previous.dir) ? installed.args[0] : arg))
+    const updated = { ...server, command: installed.command, args }
+    store.dispatch(updateMCPServer(updated))
+    if (server.isActive) {
+      window.api.mcp.restartServer(updated)
+    }
+  }
+)
 
 const selectMcpServers = (state) => state.mcp.servers
 const selectActiveMcpServers = createSelector([selectMcpServers], (servers) =>
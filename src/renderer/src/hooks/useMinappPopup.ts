@@ -17,7 +17,7 @@ import { useCallback } from 'react'
  *     import { useMinappPopup } from '@renderer/hooks/useMinappPopup'
  *
  *   in the component:
- *     const { openMinapp, openMinappKeepAlive, openMinappById,
+ *     const { openMinapp, openMinappKeepAlive, openMinappEphemeral, openMinappById,
  *             closeMinapp, hideMinappPopup, closeAllMinapps } = useMinappPopup()
  *
  *   To use some key states of the minapp popup:
@@ -71,6 +71,18 @@ export const useMinappPopup = () => {
     [openMinapp]
   )
 
+  /** Open a minapp in incognito as one-off minapp, nothing is kept after it closes */
+  const openMinappEphemeral = useCallback(
+    (app: MinAppType) => {
+      // the same app can't be loaded twice, drop the persistent instance first
+      if (openedKeepAliveMinapps.some((item) => item.id === app.id)) {
+        dispatch(setOpenedKeepAliveMinapps(openedKeepAliveMinapps.filter((item) => item.id !== app.id)))
+      }
+      openMinapp({ ...app, ephemeral: true })
+    },
+    [dispatch, openMinapp, openedKeepAliveMinapps]
+  )
+
   /** Open a minapp by id (look up the minapp in DEFAULT_MIN_APPS) */
   const openMinappById = useCallback(
     (id: string, keepAlive: boolean = false) => {
@@ -122,6 +134,7 @@ export const useMinappPopup = () => {
   return {
     openMinapp,
     openMinappKeepAlive,
+    openMinappEphemeral,
     openMinappById,
     closeMinapp,
     hideMinappPopup,
@@ -1,8 +1,19 @@
-import { DEFAULT_MIN_APPS } from '@renderer/config/minapps'
-import { RootState, useAppDispatch, useAppSelector } from '@renderer/store'
+import {
+  DEFAULT_MIN_APPS,
+  loadCustomMiniApp,
+  ORIGIN_DEFAULT_MIN_APPS,
+  updateDefaultMinApps
+} from '@renderer/config/minapps'
+import store, { RootState, useAppDispatch, useAppSelector } from '@renderer/store'
 import { setDisabledMinApps, setMinApps, setPinnedMinApps } from '@renderer/store/minapps'
 import { MinAppType } from '@renderer/types'
 
+// 安装包自动更新后重新加载小程序列表，列表中的小程序按 id 指向新版本
+window.api.miniApp.onPackageUpdated(async () => {
+  updateDefaultMinApps([...ORIGIN_DEFAULT_MIN_APPS, ...(await loadCustomMiniApp())])
+  store.dispatch(setMinApps(store.getState().minapps.enabled))
+})
+
 export const useMinapps = () => {
   const { enabled, disabled, pinned } = useAppSelector((state: RootState) => state.minapps)
   const dispatch = useAppDispatch()
@@ -1,5 +1,6 @@
 import { createSelector } from '@reduxjs/toolkit'
-import store, { useAppDispatch, useAppSelector } from '@renderer/store'
+import { isProviderAllowed } from '@renderer/services/PolicyService'
+import store, { RootState, useAppDispatch, useAppSelector } from '@renderer/store'
 import {
   addModel,
   addProvider,
@@ -14,9 +15,10 @@ import { IpcChannel } from '@shared/IpcChannel'
 
 import { useDefaultModel } from './useAssistant'
 
+// providers the machine policy does not allow are never offered
 const selectEnabledProviders = createSelector(
-  (state) => state.llm.providers,
-  (providers) => providers.filter((p) => p.enabled)
+  [(state: RootState) => state.llm.providers, (state: RootState) => state.runtime.policy],
+  (providers, policy) => providers.filter((p) => p.enabled && isProviderAllowed(p.id, policy))
 )
 
 export function useProviders() {
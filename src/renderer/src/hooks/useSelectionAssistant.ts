@@ -5,9 +5,12 @@ import {
   setFilterList,
   setFilterMode,
   setIsAutoClose,
+  setIsAutoCopyResult,
   setIsAutoPin,
   setIsCompact,
   setIsFollowToolbar,
+  setIsNotifyResult,
+  setIsPermissionGranted,
   setIsRemeberWinSize,
   setSelectionEnabled,
   setTriggerMode
@@ -24,6 +27,10 @@ export function useSelectionAssistant() {
       dispatch(setSelectionEnabled(enabled))
       window.api.selection.setEnabled(enabled)
     },
+    setIsPermissionGranted: (granted: boolean) => {
+      dispatch(setIsPermissionGranted(granted))
+      window.api.selection.setPermissionGranted(granted)
+    },
     setTriggerMode: (mode: TriggerMode) => {
       dispatch(setTriggerMode(mode))
       window.api.selection.setTriggerMode(mode)
@@ -56,6 +63,12 @@ export function useSelectionAssistant() {
     setActionWindowOpacity: (opacity: number) => {
       dispatch(setActionWindowOpacity(opacity))
     },
+    setIsAutoCopyResult: (isAutoCopyResult: boolean) => {
+      dispatch(setIsAutoCopyResult(isAutoCopyResult))
+    },
+    setIsNotifyResult: (isNotifyResult: boolean) => {
+      dispatch(setIsNotifyResult(isNotifyResult))
+    },
     setActionItems: (items: ActionItem[]) => {
       dispatch(setActionItems(items))
     }
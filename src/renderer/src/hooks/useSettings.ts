@@ -1,3 +1,4 @@
+import { isSettingLocked } from '@renderer/services/PolicyService'
 import store, { useAppDispatch, useAppSelector } from '@renderer/store'
 import {
   AssistantIconType,
@@ -20,8 +21,17 @@ import {
   setWindowStyle
 } from '@renderer/store/settings'
 import { SidebarIcon, ThemeMode, TranslateLanguageVarious } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
 import { FeedUrl } from '@shared/config/constant'
 
+// 被策略锁定的设置保持不变，主进程应用成功后才更新界面状态
+function applySetting(key: string, apply: () => Promise<unknown>, commit: () => void) {
+  if (isSettingLocked(key)) return
+  apply()
+    .then(commit)
+    .catch((error) => window.message.error(getErrorMessage(error)))
+}
+
 export function useSettings() {
   const settings = useAppSelector((state) => state.settings)
   const dispatch = useAppDispatch()
@@ -39,25 +49,33 @@ export function useSettings() {
       }
 
       if (isLaunchToTray !== undefined) {
-        dispatch(setLaunchToTray(isLaunchToTray))
-        window.api.setLaunchToTray(isLaunchToTray)
+        applySetting(
+          'launchToTray',
+          () => window.api.setLaunchToTray(isLaunchToTray),
+          () => dispatch(setLaunchToTray(isLaunchToTray))
+        )
       }
     },
 
     setTray(isShowTray: boolean | undefined, isTrayOnClose: boolean | undefined = undefined) {
       if (isShowTray !== undefined) {
-        dispatch(_setTray(isShowTray))
-        window.api.setTray(isShowTray)
+        applySetting('tray', () => window.api.setTray(isShowTray), () => dispatch(_setTray(isShowTray)))
       }
       if (isTrayOnClose !== undefined) {
-        dispatch(setTrayOnClose(isTrayOnClose))
-        window.api.setTrayOnClose(isTrayOnClose)
+        applySetting(
+          'trayOnClose',
+          () => window.api.setTrayOnClose(isTrayOnClose),
+          () => dispatch(setTrayOnClose(isTrayOnClose))
+        )
       }
     },
 
     setAutoCheckUpdate(isAutoUpdate: boolean) {
-      dispatch(_setAutoCheckUpdate(isAutoUpdate))
-      window.api.setAutoUpdate(isAutoUpdate)
+      applySetting(
+        'autoUpdate',
+        () => window.api.setAutoUpdate(isAutoUpdate),
+        () => dispatch(_setAutoCheckUpdate(isAutoUpdate))
+      )
     },
 
     setEarlyAccess(isEarlyAccess: boolean) {
@@ -1,5 +1,6 @@
 import db from '@renderer/databases'
 import i18n from '@renderer/i18n'
+import { unmountConversationWorkspace } from '@renderer/services/ConversationWorkspaceService'
 import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
 import { deleteMessageFiles } from '@renderer/services/MessagesService'
 import store from '@renderer/store'
@@ -183,6 +184,8 @@ export const TopicManager = {
     }
 
     db.topics.delete(id)
+    await unmountConversationWorkspace(id)
+    await window.api.mcp.clearToolCalls(id).catch(() => undefined)
   },
 
   async clearTopicMessages(id: string) {
@@ -196,6 +199,7 @@ export const TopicManager = {
       topic.messages = []
 
       await db.topics.update(id, topic)
+      await window.api.mcp.clearToolCalls(id).catch(() => undefined)
     }
   }
 }
@@ -0,0 +1,89 @@
+import Logger from '@renderer/config/logger'
+import { WebviewTag } from 'electron'
+import { MutableRefObject, useEffect, useRef } from 'react'
+
+import { useSettings } from './useSettings'
+
+const CHECK_INTERVAL = 60 * 1000
+/** 占用内存超过此值（KB）的小程序隐藏后不等待设置的时间直接冻结 */
+const HEAVY_MEMORY = 512 * 1024
+
+/**
+ * 冻结长时间隐藏的小程序 webview 以回收内存
+ * 按最近显示时间判断，隐藏超过设置的分钟数就冻结，再次显示时恢复
+ * 占用内存较多的小程序隐藏超过一个检查周期就冻结
+ */
+export function useWebviewSuspension(
+  webviewRefs: MutableRefObject<Map<string, WebviewTag | null>>,
+  currentMinappId: string,
+  visible: boolean
+) {
+  const { minappsSuspendMinutes } = useSettings()
+  /** 每个小程序最近一次显示的时间 */
+  const lastShown = useRef<Map<string, number>>(new Map())
+  const suspended = useRef<Set<string>>(new Set())
+  /** 每个小程序渲染进程最近一次上报的内存 */
+  const memory = useRef<Map<string, number>>(new Map())
+
+  const shownId = visible ? currentMinappId : ''
+
+  // 显示的小程序记录时间并恢复运行
+  useEffect(() => {
+    if (!shownId) return
+    lastShown.current.set(shownId, Date.now())
+
+    const webviewId = webviewRefs.current.get(shownId)?.getWebContentsId()
+    if (webviewId && suspended.current.delete(shownId)) {
+      window.api.webview.resume(webviewId).catch((error) => {
+        Logger.error(`[WebviewSuspension] Failed to resume ${shownId}:`, error)
+      })
+    }
+  }, [shownId, webviewRefs])
+
+  useEffect(() => {
+    return window.api.miniApp.onStatsUpdated((stats) => {
+      stats.forEach((item) => memory.current.set(item.appId, item.memory))
+    })
+  }, [])
+
+  useEffect(() => {
+    if (!minappsSuspendMinutes) return
+
+    const check = () => {
+      const now = Date.now()
+      webviewRefs.current.forEach((webview, appid) => {
+        if (appid === shownId) {
+          lastShown.current.set(appid, now)
+          return
+        }
+        if (!webview || suspended.current.has(appid)) return
+
+        const last = lastShown.current.get(appid)
+        if (last === undefined) {
+          lastShown.current.set(appid, now)
+          return
+        }
+        const heavy = (memory.current.get(appid) ?? 0) > HEAVY_MEMORY
+        if (!heavy && now - last < minappsSuspendMinutes * 60 * 1000) return
+
+        suspended.current.add(appid)
+        window.api.webview.suspend(webview.getWebContentsId()).catch((error) => {
+          suspended.current.delete(appid)
+          Logger.error(`[WebviewSuspension] Failed to suspend ${appid}:`, error)
+        })
+      })
+
+      // 已关闭的小程序不再记录
+      for (const appid of lastShown.current.keys()) {
+        if (!webviewRefs.current.has(appid)) {
+          lastShown.current.delete(appid)
+          suspended.current.delete(appid)
+          memory.current.delete(appid)
+        }
+      }
+    }
+
+    const timer = setInterval(check, CHECK_INTERVAL)
+    return () => clearInterval(timer)
+  }, [minappsSuspendMinutes, shownId, webviewRefs])
+}
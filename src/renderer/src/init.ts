@@ -2,6 +2,7 @@ import KeyvStorage from '@kangfenmao/keyv-storage'
 
 import { startAutoSync } from './services/BackupService'
 import { startNutstoreAutoSync } from './services/NutstoreService'
+import { initPolicy } from './services/PolicyService'
 import storeSyncService from './services/StoreSyncService'
 import store from './store'
 
@@ -28,5 +29,6 @@ function initStoreSync() {
 }
 
 initKeyv()
+initPolicy()
 initAutoSync()
 initStoreSync()
@@ -4,6 +4,7 @@ import { getDefaultModel } from '@renderer/services/AssistantService'
 import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
 import { Agent } from '@renderer/types'
 import { uuid } from '@renderer/utils'
+import { getAgentVersion } from '@renderer/utils/agentSync'
 import { Button, Form, Input, Modal, Radio, Space } from 'antd'
 import { useState } from 'react'
 import { useTranslation } from 'react-i18next'
@@ -65,7 +66,13 @@ const PopupContainer: React.FC<Props> = ({ resolve }) => {
           topics: [],
           messages: [],
           defaultModel: getDefaultModel(),
-          regularPhrases: agent.regularPhrases || []
+          regularPhrases: agent.regularPhrases || [],
+          version: agent.version,
+          // agents imported from a url can be updated from it later
+          source:
+            importType === 'url' && values.url
+              ? { url: values.url, id: agent.id || agent.name, version: getAgentVersion(agent) }
+              : undefined
         }
         addAgent(newAgent)
       }
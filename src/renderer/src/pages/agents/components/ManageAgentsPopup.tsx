@@ -3,7 +3,11 @@ import DragableList from '@renderer/components/DragableList'
 import { Box, HStack } from '@renderer/components/Layout'
 import { TopView } from '@renderer/components/TopView'
 import { useAgents } from '@renderer/hooks/useAgents'
-import { Empty, Modal } from 'antd'
+import { useRuntime } from '@renderer/hooks/useRuntime'
+import { checkAgentUpdates, setAgentPinned, updateAgentFromUpstream } from '@renderer/services/AgentSyncService'
+import { Agent, AgentUpdate } from '@renderer/types'
+import { Button, Empty, Modal, Tag, Tooltip } from 'antd'
+import { Pin, PinOff } from 'lucide-react'
 import { useEffect, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import styled from 'styled-components'
@@ -12,6 +16,8 @@ const PopupContainer: React.FC = () => {
   const [open, setOpen] = useState(true)
   const { t } = useTranslation()
   const { agents, updateAgents } = useAgents()
+  const { agentUpdates } = useRuntime()
+  const [checking, setChecking] = useState(false)
 
   const onOk = () => {
     setOpen(false)
@@ -25,6 +31,37 @@ const PopupContainer: React.FC = () => {
     ManageAgentsPopup.hide()
   }
 
+  const onCheckUpdates = async () => {
+    setChecking(true)
+    try {
+      const updates = await checkAgentUpdates()
+      if (Object.keys(updates).length === 0) {
+        window.message.success({ content: t('agents.manage.update.latest'), key: 'agents-update' })
+      }
+    } finally {
+      setChecking(false)
+    }
+  }
+
+  const onUpdate = (agent: Agent, update: AgentUpdate) => {
+    window.modal.confirm({
+      title: t('agents.manage.update.confirm', { name: agent.name, version: update.version }),
+      content: (
+        <ChangeList>
+          {update.changes.map((change) => (
+            <li key={change.field}>
+              {t(`agents.manage.update.fields.${change.field}`)}: {change.before || '-'} → {change.after || '-'}
+            </li>
+          ))}
+          {update.promptChanged && <li>{t('agents.manage.update.prompt_changed')}</li>}
+          {update.changes.length === 0 && !update.promptChanged && <li>{t('agents.manage.update.no_changes')}</li>}
+        </ChangeList>
+      ),
+      centered: true,
+      onOk: () => updateAgentFromUpstream(agent.id)
+    })
+  }
+
   useEffect(() => {
     if (agents.length === 0) {
       setOpen(false)
@@ -41,6 +78,11 @@ const PopupContainer: React.FC = () => {
       footer={null}
       transitionName="animation-move-down"
       centered>
+      <HStack justifyContent="flex-end">
+        <Button size="small" loading={checking} onClick={onCheckUpdates}>
+          {t('agents.manage.update.check')}
+        </Button>
+      </HStack>
       <Container>
         {agents.length > 0 && (
           <DragableList list={agents} onUpdate={updateAgents}>
@@ -49,7 +91,20 @@ const PopupContainer: React.FC = () => {
                 <Box mr={8}>
                   {item.emoji} {item.name}
                 </Box>
-                <HStack gap="15px">
+                <HStack gap="15px" alignItems="center">
+                  {item.source && <Tag>{item.version ?? item.source.version}</Tag>}
+                  {agentUpdates[item.id] && (
+                    <Button size="small" type="link" onClick={() => onUpdate(item, agentUpdates[item.id])}>
+                      {t('agents.manage.update.button')}
+                    </Button>
+                  )}
+                  {item.source && (
+                    <Tooltip title={t(item.source.pinned ? 'agents.manage.unpin' : 'agents.manage.pin')}>
+                      <PinButton onClick={() => setAgentPinned(item.id, !item.source?.pinned)}>
+                        {item.source.pinned ? <PinOff size={14} /> : <Pin size={14} />}
+                      </PinButton>
+                    </Tooltip>
+                  )}
                   <MenuOutlined style={{ cursor: 'move' }} />
                 </HStack>
               </AgentItem>
@@ -90,6 +145,18 @@ const AgentItem = styled.div`
   }
 `
 
+const ChangeList = styled.ul`
+  margin: 8px 0 0;
+  padding-left: 18px;
+  word-break: break-all;
+`
+
+const PinButton = styled.div`
+  display: flex;
+  cursor: pointer;
+  color: var(--color-icon);
+`
+
 export default class ManageAgentsPopup {
   static topviewId = 0
   static hide() {
@@ -2,6 +2,9 @@ import MinAppIcon from '@renderer/components/Icons/MinAppIcon'
 import { loadCustomMiniApp, ORIGIN_DEFAULT_MIN_APPS, updateDefaultMinApps } from '@renderer/config/minapps'
 import { useMinappPopup } from '@renderer/hooks/useMinappPopup'
 import { useMinapps } from '@renderer/hooks/useMinapps'
+import { useSettings } from '@renderer/hooks/useSettings'
+import { useAppDispatch } from '@renderer/store'
+import { setMinappsContentBlockingOptOut, setMinappsShortcutOptOut } from '@renderer/store/settings'
 import { MinAppType } from '@renderer/types'
 import type { MenuProps } from 'antd'
 import { Dropdown, message } from 'antd'
@@ -17,10 +20,14 @@ interface Props {
 }
 
 const App: FC<Props> = ({ app, onClick, size = 60, isLast }) => {
-  const { openMinappKeepAlive } = useMinappPopup()
+  const { openMinappKeepAlive, openMinappEphemeral } = useMinappPopup()
   const { t } = useTranslation()
   const { minapps, pinned, disabled, updateMinapps, updateDisabledMinapps, updatePinnedMinapps } = useMinapps()
+  const { minappsShortcutOptOut, minappsContentBlocking, minappsContentBlockingOptOut } = useSettings()
+  const dispatch = useAppDispatch()
   const isPinned = pinned.some((p) => p.id === app.id)
+  const isShortcutOptOut = minappsShortcutOptOut.includes(app.id)
+  const isContentBlockingOptOut = minappsContentBlockingOptOut.includes(app.id)
   const isVisible = minapps.some((m) => m.id === app.id)
 
   const handleClick = () => {
@@ -29,6 +36,39 @@ const App: FC<Props> = ({ app, onClick, size = 60, isLast }) => {
   }
 
   const menuItems: MenuProps['items'] = [
+    {
+      key: 'incognito',
+      label: t('minapp.sidebar.incognito.title'),
+      onClick: () => openMinappEphemeral(app)
+    },
+    {
+      key: 'shortcutOptOut',
+      label: isShortcutOptOut
+        ? t('minapp.sidebar.shortcut_pass_through.title')
+        : t('minapp.sidebar.shortcut_opt_out.title'),
+      onClick: () => {
+        const newOptOut = isShortcutOptOut
+          ? minappsShortcutOptOut.filter((id) => id !== app.id)
+          : [...minappsShortcutOptOut, app.id]
+        dispatch(setMinappsShortcutOptOut(newOptOut))
+      }
+    },
+    ...(minappsContentBlocking
+      ? [
+          {
+            key: 'contentBlockingOptOut',
+            label: isContentBlockingOptOut
+              ? t('minapp.sidebar.content_blocking_on.title')
+              : t('minapp.sidebar.content_blocking_off.title'),
+            onClick: () => {
+              const newOptOut = isContentBlockingOptOut
+                ? minappsContentBlockingOptOut.filter((id) => id !== app.id)
+                : [...minappsContentBlockingOptOut, app.id]
+              dispatch(setMinappsContentBlockingOptOut(newOptOut))
+            }
+          }
+        ]
+      : []),
     {
       key: 'togglePin',
       label: isPinned ? t('minapp.sidebar.remove.title') : t('minapp.sidebar.add.title'),
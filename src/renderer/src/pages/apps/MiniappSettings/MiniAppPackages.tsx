@@ -0,0 +1,309 @@
+import { loadCustomMiniApp, ORIGIN_DEFAULT_MIN_APPS, updateDefaultMinApps } from '@renderer/config/minapps'
+import { useMinapps } from '@renderer/hooks/useMinapps'
+import { SettingDescription, SettingDivider, SettingRow, SettingRowTitle } from '@renderer/pages/settings'
+import { InstalledMiniAppPackage, MinAppType, MiniAppRegistryEntry, MiniAppUpdate } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { ManagerError } from '@shared/ManagerError'
+import { Button, Input, message, Switch } from 'antd'
+import { FC, useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+interface Props {
+  visibleMiniApps: MinAppType[]
+  disabledMiniApps: MinAppType[]
+  setVisibleMiniApps: (apps: MinAppType[]) => void
+  setDisabledMiniApps: (apps: MinAppType[]) => void
+}
+
+/**
+ * 从签名的安装包安装小程序：可信公钥、按地址安装、注册表中的小程序和已安装的安装包
+ * 已安装的安装包可以检查并安装新版本，开启自动更新后每天检查一次
+ */
+const MiniAppPackages: FC<Props> = ({ visibleMiniApps, disabledMiniApps, setVisibleMiniApps, setDisabledMiniApps }) => {
+  const { t } = useTranslation()
+  const { pinned, updateMinapps, updateDisabledMinapps, updatePinnedMinapps } = useMinapps()
+  const [trustedKeys, setTrustedKeys] = useState('')
+  const [url, setUrl] = useState('')
+  const [registry, setRegistry] = useState<MiniAppRegistryEntry[]>([])
+  const [installed, setInstalled] = useState<InstalledMiniAppPackage[]>([])
+  const [updates, setUpdates] = useState<MiniAppUpdate[]>([])
+  const [autoUpdate, setAutoUpdate] = useState(false)
+  const [busy, setBusy] = useState<string | null>(null)
+
+  const refresh = useCallback(async () => {
+    setInstalled(await window.api.miniApp.getInstalledPackages())
+  }, [])
+
+  useEffect(() => {
+    refresh()
+    window.api.miniApp.getTrustedKeys().then((keys) => setTrustedKeys(keys.join('\n')))
+    window.api.miniApp.getAutoUpdate().then(setAutoUpdate)
+    window.api.miniApp
+      .getRegistry()
+      .then(setRegistry)
+      .catch((error) => console.error('Failed to load the mini app registry:', error))
+  }, [refresh])
+
+  const saveTrustedKeys = async () => {
+    const keys = trustedKeys
+      .split('\n')
+      .map((line) => line.trim())
+      .filter(Boolean)
+    try {
+      await window.api.miniApp.setTrustedKeys(keys)
+      setTrustedKeys(keys.join('\n'))
+    } catch (error) {
+      message.error(getErrorMessage(error))
+    }
+  }
+
+  // 重新加载小程序列表，新安装的小程序显示在末尾
+  const reloadApps = async (installedApp?: MinAppType) => {
+    updateDefaultMinApps([...ORIGIN_DEFAULT_MIN_APPS, ...(await loadCustomMiniApp())])
+    if (installedApp && ![...visibleMiniApps, ...disabledMiniApps].some((app) => app.id === installedApp.id)) {
+      const apps = [...visibleMiniApps, installedApp]
+      setVisibleMiniApps(apps)
+      updateMinapps(apps)
+    }
+  }
+
+  const confirmPermissions = (name: string, permissions: string[], onOk: () => void) =>
+    window.modal.confirm({
+      centered: true,
+      title: t('settings.miniapps.packages.confirm_permissions', { name }),
+      content: permissions.map((permission) => t(`minapp.popup.permission.${permission}`)).join(', '),
+      onOk
+    })
+
+  // 已安装的小程序重新安装时和更新一样检查，声明了新的权限需要用户确认
+  const install = async (key: string, run: (confirmed: boolean) => Promise<MinAppType>, confirmed = false) => {
+    setBusy(key)
+    try {
+      const app = await run(confirmed)
+      await reloadApps(app)
+      await refresh()
+      message.success(t('settings.miniapps.packages.installed', { name: app.name }))
+    } catch (error) {
+      const details = ManagerError.parse(error)?.details ?? {}
+      if (!confirmed && Array.isArray(details.permissions)) {
+        confirmPermissions(String(details.id), details.permissions, () => install(key, run, true))
+      } else {
+        message.error(getErrorMessage(error))
+      }
+    } finally {
+      setBusy(null)
+    }
+  }
+
+  const uninstall = async (id: string) => {
+    setBusy(id)
+    try {
+      await window.api.miniApp.uninstallPackage(id)
+      await reloadApps()
+      const visible = visibleMiniApps.filter((app) => app.id !== id)
+      const disabled = disabledMiniApps.filter((app) => app.id !== id)
+      setVisibleMiniApps(visible)
+      setDisabledMiniApps(disabled)
+      updateMinapps(visible)
+      updateDisabledMinapps(disabled)
+      updatePinnedMinapps(pinned.filter((app) => app.id !== id))
+      await refresh()
+    } catch (error) {
+      message.error(getErrorMessage(error))
+    } finally {
+      setBusy(null)
+    }
+  }
+
+  const checkUpdates = async () => {
+    setBusy('check')
+    try {
+      const items = await window.api.miniApp.checkUpdates()
+      setUpdates(items)
+      if (!items.length) message.info(t('settings.miniapps.packages.no_updates'))
+    } catch (error) {
+      message.error(getErrorMessage(error))
+    } finally {
+      setBusy(null)
+    }
+  }
+
+  // 新版本声明了新的权限时，需要用户确认后才会安装
+  const update = async (id: string, confirmed = false) => {
+    setBusy(`update:${id}`)
+    try {
+      const app = await window.api.miniApp.updatePackage(id, confirmed)
+      await reloadApps()
+      setUpdates((items) => items.filter((item) => item.id !== id))
+      await refresh()
+      message.success(t('settings.miniapps.packages.updated', { name: app.name }))
+    } catch (error) {
+      const permissions = ManagerError.parse(error)?.details?.permissions
+      if (!confirmed && Array.isArray(permissions)) {
+        confirmPermissions(id, permissions, () => update(id, true))
+      } else {
+        message.error(getErrorMessage(error))
+      }
+    } finally {
+      setBusy(null)
+    }
+  }
+
+  const onAutoUpdateChange = async (enabled: boolean) => {
+    setAutoUpdate(enabled)
+    await window.api.miniApp.setAutoUpdate(enabled)
+  }
+
+  const isInstalled = (id: string) => installed.some((item) => item.id === id)
+  const getUpdate = (id: string) => updates.find((item) => item.id === id)
+
+  return (
+    <>
+      <SettingRowTitle>{t('settings.miniapps.packages.title')}</SettingRowTitle>
+      <SettingDescription>{t('settings.miniapps.packages.description')}</SettingDescription>
+      <Input.TextArea
+        style={{ marginTop: 10 }}
+        value={trustedKeys}
+        autoSize={{ minRows: 2, maxRows: 6 }}
+        placeholder={t('settings.miniapps.packages.trusted_keys_placeholder')}
+        onChange={(e) => setTrustedKeys(e.target.value)}
+        onBlur={saveTrustedKeys}
+      />
+      <UrlRow>
+        <Input
+          value={url}
+          placeholder="https://example.com/notes.zip"
+          onChange={(e) => setUrl(e.target.value)}
+          allowClear
+        />
+        <Button
+          loading={busy === 'url'}
+          disabled={!url.trim() || !!busy || !trustedKeys.trim()}
+          onClick={() => install('url', (confirmed) => window.api.miniApp.installFromUrl(url.trim(), confirmed))}>
+          {t('settings.miniapps.packages.install')}
+        </Button>
+      </UrlRow>
+      {registry.length > 0 && (
+        <>
+          <SettingDivider />
+          <SettingRowTitle>{t('settings.miniapps.packages.registry')}</SettingRowTitle>
+          <PackageList>
+            {registry.map((entry) => (
+              <PackageItem key={entry.id}>
+                <PackageName title={entry.description}>{entry.name}</PackageName>
+                <span>{entry.version ?? '-'}</span>
+                <Button
+                  size="small"
+                  loading={busy === entry.id}
+                  disabled={!!busy}
+                  onClick={() =>
+                    install(entry.id, (confirmed) => window.api.miniApp.installPackage(entry.id, confirmed))
+                  }>
+                  {isInstalled(entry.id)
+                    ? t('settings.miniapps.packages.reinstall')
+                    : t('settings.miniapps.packages.install')}
+                </Button>
+              </PackageItem>
+            ))}
+          </PackageList>
+        </>
+      )}
+      {installed.length > 0 && (
+        <>
+          <SettingDivider />
+          <SettingRow>
+            <SettingRowTitle>{t('settings.miniapps.packages.installed_list')}</SettingRowTitle>
+            <Button size="small" loading={busy === 'check'} disabled={!!busy} onClick={checkUpdates}>
+              {t('settings.miniapps.packages.check_updates')}
+            </Button>
+          </SettingRow>
+          <SettingRow style={{ marginTop: 10 }}>
+            <SettingDescription style={{ marginTop: 0 }}>
+              {t('settings.miniapps.packages.auto_update')}
+            </SettingDescription>
+            <Switch size="small" checked={autoUpdate} onChange={onAutoUpdateChange} />
+          </SettingRow>
+          <PackageList>
+            {installed.map((item) => {
+              const available = getUpdate(item.id)
+              return (
+                <PackageItem key={item.id}>
+                  <PackageName title={item.url}>
+                    {item.id}
+                    {item.version && <PackageVersion>{item.version}</PackageVersion>}
+                  </PackageName>
+                  <span title={t('settings.miniapps.packages.signed_by')}>{item.fingerprint.slice(0, 14)}</span>
+                  <PackageActions>
+                    {available && (
+                      <Button
+                        size="small"
+                        type="primary"
+                        loading={busy === `update:${item.id}`}
+                        disabled={!!busy}
+                        onClick={() => update(item.id)}>
+                        {t('settings.miniapps.packages.update', { version: available.to })}
+                      </Button>
+                    )}
+                    <Button
+                      size="small"
+                      danger
+                      loading={busy === item.id}
+                      disabled={!!busy}
+                      onClick={() => uninstall(item.id)}>
+                      {t('settings.miniapps.packages.uninstall')}
+                    </Button>
+                  </PackageActions>
+                </PackageItem>
+              )
+            })}
+          </PackageList>
+        </>
+      )}
+    </>
+  )
+}
+
+const UrlRow = styled.div`
+  display: flex;
+  gap: 10px;
+  margin-top: 10px;
+`
+
+const PackageList = styled.div`
+  display: flex;
+  flex-direction: column;
+  max-height: 300px;
+  overflow-y: auto;
+  margin-top: 10px;
+`
+
+const PackageItem = styled.div`
+  display: grid;
+  grid-template-columns: 1fr 120px auto;
+  align-items: center;
+  gap: 8px;
+  padding: 6px 0;
+  font-size: 12px;
+  color: var(--color-text-2);
+  border-bottom: 0.5px solid var(--color-border);
+`
+
+const PackageName = styled.span`
+  color: var(--color-text-1);
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+`
+
+const PackageVersion = styled.span`
+  margin-left: 6px;
+  color: var(--color-text-3);
+`
+
+const PackageActions = styled.div`
+  display: flex;
+  gap: 6px;
+`
+
+export default MiniAppPackages
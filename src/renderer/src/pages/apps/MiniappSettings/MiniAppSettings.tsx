@@ -2,20 +2,28 @@ import { UndoOutlined } from '@ant-design/icons' // 导入重置图标
 import { DEFAULT_MIN_APPS } from '@renderer/config/minapps'
 import { useMinapps } from '@renderer/hooks/useMinapps'
 import { useSettings } from '@renderer/hooks/useSettings'
+import { getErrorMessage } from '@renderer/utils/error'
 import { SettingDescription, SettingDivider, SettingRowTitle, SettingTitle } from '@renderer/pages/settings'
 import { useAppDispatch } from '@renderer/store'
 import {
   setMaxKeepAliveMinapps,
+  setMinappsContentBlocking,
+  setMinappsFilterLists,
   setMinappsOpenLinkExternal,
+  setMinappsRestoreLayout,
+  setMinappsSuspendMinutes,
+  setMinappsUserAgent,
+  setMinappsWarmPoolSize,
   setShowOpenedMinappsInSidebar
 } from '@renderer/store/settings'
-import { Button, message, Slider, Switch, Tooltip } from 'antd'
+import { Button, Input, message, Slider, Switch, Tooltip } from 'antd'
 import { FC, useCallback, useEffect, useRef, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import { useNavigate } from 'react-router'
 import styled from 'styled-components'
 
 import MiniAppIconsManager from './MiniAppIconsManager'
+import MiniAppPackages from './MiniAppPackages'
 
 // 默认小程序缓存数量
 const DEFAULT_MAX_KEEPALIVE = 3
@@ -23,12 +31,26 @@ const DEFAULT_MAX_KEEPALIVE = 3
 const MiniAppSettings: FC = () => {
   const { t } = useTranslation()
   const dispatch = useAppDispatch()
-  const { maxKeepAliveMinapps, showOpenedMinappsInSidebar, minappsOpenLinkExternal } = useSettings()
+  const {
+    maxKeepAliveMinapps,
+    showOpenedMinappsInSidebar,
+    minappsOpenLinkExternal,
+    minappsRestoreLayout,
+    minappsWarmPoolSize,
+    minappsSuspendMinutes,
+    minappsUserAgent,
+    minappsContentBlocking,
+    minappsFilterLists
+  } = useSettings()
   const { minapps, disabled, updateMinapps, updateDisabledMinapps } = useMinapps()
   const navigate = useNavigate()
 
   const [visibleMiniApps, setVisibleMiniApps] = useState(minapps)
   const [disabledMiniApps, setDisabledMiniApps] = useState(disabled || [])
+  const [userAgent, setUserAgent] = useState(minappsUserAgent)
+  const [filterLists, setFilterLists] = useState(minappsFilterLists.join('\n'))
+  const [updatingLists, setUpdatingLists] = useState(false)
+  const [permissionCount, setPermissionCount] = useState(0)
   const [messageApi, contextHolder] = message.useMessage()
   const debounceTimerRef = useRef<NodeJS.Timeout | null>(null)
 
@@ -62,6 +84,45 @@ const MiniAppSettings: FC = () => {
     [dispatch, messageApi, t]
   )
 
+  // 记住了权限选择的小程序数量
+  useEffect(() => {
+    window.api.miniApp.getPermissions().then((permissions) => setPermissionCount(Object.keys(permissions).length))
+  }, [])
+
+  const handleResetPermissions = useCallback(async () => {
+    await window.api.miniApp.resetPermissions()
+    setPermissionCount(0)
+    messageApi.success(t('settings.miniapps.permissions_reset_success'))
+  }, [messageApi, t])
+
+  const handleFilterListsBlur = useCallback(() => {
+    const lists = filterLists
+      .split('\n')
+      .map((line) => line.trim())
+      .filter(Boolean)
+    setFilterLists(lists.join('\n'))
+    dispatch(setMinappsFilterLists([...new Set(lists)]))
+  }, [dispatch, filterLists])
+
+  // 重新下载过滤规则列表
+  const handleUpdateFilterLists = useCallback(async () => {
+    setUpdatingLists(true)
+    try {
+      const status = await window.api.webview.updateFilterLists()
+      const failed = status.lists.filter((list) => list.error)
+      const rules = status.lists.reduce((count, list) => count + list.rules, 0)
+      if (failed.length > 0) {
+        messageApi.warning(t('settings.miniapps.content_blocking_update_failed', { count: failed.length }))
+      } else {
+        messageApi.success(t('settings.miniapps.content_blocking_updated', { count: rules }))
+      }
+    } catch (error) {
+      messageApi.error(getErrorMessage(error))
+    } finally {
+      setUpdatingLists(false)
+    }
+  }, [messageApi, t])
+
   // 组件卸载时清除定时器
   useEffect(() => {
     return () => {
@@ -100,6 +161,15 @@ const MiniAppSettings: FC = () => {
         />
       </SettingRow>
       <SettingDivider />
+      {/* 启动时恢复小程序 */}
+      <SettingRow style={{ alignItems: 'center' }}>
+        <SettingLabelGroup>
+          <SettingRowTitle>{t('settings.miniapps.restore_layout_title')}</SettingRowTitle>
+          <SettingDescription>{t('settings.miniapps.restore_layout_description')}</SettingDescription>
+        </SettingLabelGroup>
+        <Switch checked={minappsRestoreLayout} onChange={(checked) => dispatch(setMinappsRestoreLayout(checked))} />
+      </SettingRow>
+      <SettingDivider />
       {/* 缓存小程序数量设置 */}
       <SettingRow>
         <SettingLabelGroup>
@@ -129,6 +199,107 @@ const MiniAppSettings: FC = () => {
         </CacheSettingControls>
       </SettingRow>
       <SettingDivider />
+      {/* 预热池大小设置 */}
+      <SettingRow>
+        <SettingLabelGroup>
+          <SettingRowTitle>{t('settings.miniapps.warm_pool_title')}</SettingRowTitle>
+          <SettingDescription>{t('settings.miniapps.warm_pool_description')}</SettingDescription>
+        </SettingLabelGroup>
+        <CacheSettingControls>
+          <SliderWithResetContainer>
+            <Slider
+              min={0}
+              max={5}
+              value={minappsWarmPoolSize}
+              onChange={(value) => dispatch(setMinappsWarmPoolSize(value))}
+              marks={{ 0: '0', 5: '5' }}
+              tooltip={{ formatter: (value) => `${value}` }}
+            />
+          </SliderWithResetContainer>
+        </CacheSettingControls>
+      </SettingRow>
+      <SettingDivider />
+      {/* 冻结隐藏小程序设置 */}
+      <SettingRow>
+        <SettingLabelGroup>
+          <SettingRowTitle>{t('settings.miniapps.suspend_title')}</SettingRowTitle>
+          <SettingDescription>{t('settings.miniapps.suspend_description')}</SettingDescription>
+        </SettingLabelGroup>
+        <CacheSettingControls>
+          <SliderWithResetContainer>
+            <Slider
+              min={0}
+              max={60}
+              step={5}
+              value={minappsSuspendMinutes}
+              onChange={(value) => dispatch(setMinappsSuspendMinutes(value))}
+              marks={{ 0: t('settings.miniapps.suspend_never'), 30: '30', 60: '60' }}
+              tooltip={{ formatter: (value) => t('settings.miniapps.suspend_minutes', { count: value }) }}
+            />
+          </SliderWithResetContainer>
+        </CacheSettingControls>
+      </SettingRow>
+      <SettingDivider />
+      {/* 默认 UA 设置，单个小程序可以自定义 */}
+      <SettingRow>
+        <SettingLabelGroup>
+          <SettingRowTitle>{t('settings.miniapps.user_agent_title')}</SettingRowTitle>
+          <SettingDescription>{t('settings.miniapps.user_agent_description')}</SettingDescription>
+        </SettingLabelGroup>
+        <Input
+          style={{ width: 240 }}
+          value={userAgent}
+          placeholder={t('settings.miniapps.user_agent_placeholder')}
+          onChange={(e) => setUserAgent(e.target.value)}
+          onBlur={() => dispatch(setMinappsUserAgent(userAgent.trim()))}
+          allowClear
+        />
+      </SettingRow>
+      <SettingDivider />
+      {/* 广告和跟踪拦截，单个小程序可以在右键菜单中关闭 */}
+      <SettingRow style={{ alignItems: 'center' }}>
+        <SettingLabelGroup>
+          <SettingRowTitle>{t('settings.miniapps.content_blocking_title')}</SettingRowTitle>
+          <SettingDescription>{t('settings.miniapps.content_blocking_description')}</SettingDescription>
+        </SettingLabelGroup>
+        <Switch checked={minappsContentBlocking} onChange={(checked) => dispatch(setMinappsContentBlocking(checked))} />
+      </SettingRow>
+      {minappsContentBlocking && (
+        <SettingRow style={{ marginTop: 10 }}>
+          <Input.TextArea
+            value={filterLists}
+            autoSize={{ minRows: 2, maxRows: 6 }}
+            placeholder={t('settings.miniapps.filter_lists_placeholder')}
+            onChange={(e) => setFilterLists(e.target.value)}
+            onBlur={handleFilterListsBlur}
+          />
+          <Button loading={updatingLists} onClick={handleUpdateFilterLists}>
+            {t('settings.miniapps.filter_lists_update')}
+          </Button>
+        </SettingRow>
+      )}
+      <SettingDivider />
+      {/* 摄像头、麦克风和位置权限 */}
+      <SettingRow>
+        <SettingLabelGroup>
+          <SettingRowTitle>{t('settings.miniapps.permissions_title')}</SettingRowTitle>
+          <SettingDescription>
+            {t('settings.miniapps.permissions_description', { count: permissionCount })}
+          </SettingDescription>
+        </SettingLabelGroup>
+        <Button disabled={permissionCount === 0} onClick={handleResetPermissions}>
+          {t('common.reset')}
+        </Button>
+      </SettingRow>
+      <SettingDivider />
+      {/* 签名的小程序安装包 */}
+      <MiniAppPackages
+        visibleMiniApps={visibleMiniApps}
+        disabledMiniApps={disabledMiniApps}
+        setVisibleMiniApps={setVisibleMiniApps}
+        setDisabledMiniApps={setDisabledMiniApps}
+      />
+      <SettingDivider />
       <SettingRow>
         <SettingLabelGroup>
           <SettingRowTitle>{t('settings.miniapps.sidebar_title')}</SettingRowTitle>
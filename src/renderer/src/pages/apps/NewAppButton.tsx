@@ -12,6 +12,15 @@ interface Props {
   size?: number
 }
 
+/** 每行一个预加载脚本的绝对路径 */
+const parsePreloads = (value?: string) => {
+  const preloads = (value ?? '')
+    .split('\n')
+    .map((line) => line.trim())
+    .filter(Boolean)
+  return preloads.length ? preloads : undefined
+}
+
 const NewAppButton: FC<Props> = ({ size = 60 }) => {
   const { t } = useTranslation()
   const [isModalVisible, setIsModalVisible] = useState(false)
@@ -46,6 +55,8 @@ const NewAppButton: FC<Props> = ({ size = 60 }) => {
         name: values.name,
         url: values.url,
         logo: form.getFieldValue('logo') || '',
+        userAgent: values.userAgent?.trim() || undefined,
+        preloads: parsePreloads(values.preloads),
         type: 'Custom',
         addTime: new Date().toISOString()
       }
@@ -123,6 +134,12 @@ const NewAppButton: FC<Props> = ({ size = 60 }) => {
             rules={[{ required: true, message: t('settings.miniapps.custom.url_error') }]}>
             <Input placeholder={t('settings.miniapps.custom.url_placeholder')} />
           </Form.Item>
+          <Form.Item name="userAgent" label={t('settings.miniapps.custom.user_agent')}>
+            <Input placeholder={t('settings.miniapps.custom.user_agent_placeholder')} />
+          </Form.Item>
+          <Form.Item name="preloads" label={t('settings.miniapps.custom.preloads')}>
+            <Input.TextArea rows={2} placeholder={t('settings.miniapps.custom.preloads_placeholder')} />
+          </Form.Item>
           <Form.Item label={t('settings.miniapps.custom.logo')}>
             <Radio.Group value={logoType} onChange={handleLogoTypeChange}>
               <Radio value="url">{t('settings.miniapps.custom.logo_url')}</Radio>
@@ -1,6 +1,7 @@
 import db from '@renderer/databases'
 import useScrollPosition from '@renderer/hooks/useScrollPosition'
 import { getTopicById } from '@renderer/hooks/useTopic'
+import { isSealedBlock } from '@renderer/services/SecureTopicService'
 import { Topic } from '@renderer/types'
 import { type Message, MessageBlockType } from '@renderer/types/newMessage'
 import { List, Typography } from 'antd'
@@ -60,7 +61,7 @@ const SearchResults: FC<Props> = ({ keywords, onMessageClick, onTopicClick, ...p
 
     const blocksArray = await db.message_blocks.toArray()
     const blocks = blocksArray
-      .filter((block) => block.type === MessageBlockType.MAIN_TEXT)
+      .filter((block) => block.type === MessageBlockType.MAIN_TEXT && !isSealedBlock(block))
       .filter((block) => newSearchTerms.some((term) => block.content.toLowerCase().includes(term)))
 
     const messages = topics?.map((topic) => topic.messages).flat()
@@ -7,10 +7,11 @@ import { useSettings } from '@renderer/hooks/useSettings'
 import { useShortcut } from '@renderer/hooks/useShortcuts'
 import { useShowTopics } from '@renderer/hooks/useStore'
 import { Assistant, Topic } from '@renderer/types'
-import { Flex } from 'antd'
+import { Alert, Flex } from 'antd'
 import { debounce } from 'lodash'
 import React, { FC, useMemo, useState } from 'react'
 import { useHotkeys } from 'react-hotkeys-hook'
+import { useTranslation } from 'react-i18next'
 import styled from 'styled-components'
 
 import Inputbar from './Inputbar/Inputbar'
@@ -28,6 +29,7 @@ const Chat: FC<Props> = (props) => {
   const { assistant } = useAssistant(props.assistant.id)
   const { topicPosition, messageStyle, showAssistants } = useSettings()
   const { showTopics } = useShowTopics()
+  const { t } = useTranslation()
   const { isMultiSelectMode } = useChatContext(props.activeTopic)
 
   const mainRef = React.useRef<HTMLDivElement>(null)
@@ -124,7 +126,11 @@ const Chat: FC<Props> = (props) => {
           onFirstUpdate={messagesComponentFirstUpdateHandler}
         />
         <QuickPanelProvider>
-          <Inputbar assistant={assistant} setActiveTopic={props.setActiveTopic} topic={props.activeTopic} />
+          {props.activeTopic.readonly ? (
+            <ReadonlyNotice type="info" showIcon message={t('chat.topics.transcript.readonly')} />
+          ) : (
+            <Inputbar assistant={assistant} setActiveTopic={props.setActiveTopic} topic={props.activeTopic} />
+          )}
           {isMultiSelectMode && <MultiSelectActionPopup topic={props.activeTopic} />}
         </QuickPanelProvider>
       </Main>
@@ -154,4 +160,8 @@ const Main = styled(Flex)`
   position: relative;
 `
 
+const ReadonlyNotice = styled(Alert)`
+  margin: 0 18px 18px;
+`
+
 export default Chat
@@ -13,6 +13,8 @@ import {
   LinkOutlined
 } from '@ant-design/icons'
 import CustomTag from '@renderer/components/CustomTag'
+import TabularPreviewPopup from '@renderer/components/Popups/TabularPreviewPopup'
+import TextPreviewPopup from '@renderer/components/Popups/TextPreviewPopup'
 import FileManager from '@renderer/services/FileManager'
 import { FileType } from '@renderer/types'
 import { formatFileSize } from '@renderer/utils'
@@ -85,6 +87,12 @@ export const FileNameRender: FC<{ file: FileType }> = ({ file }) => {
   const isImage = (ext: string) => {
     return ['.png', '.jpg', '.jpeg', '.gif', '.bmp', '.webp'].includes(ext)
   }
+  const isTabular = (ext: string) => {
+    return ['.csv', '.tsv', '.tab', '.xlsx'].includes(ext.toLowerCase())
+  }
+  const isText = (ext: string) => {
+    return ['.txt', '.log', '.out', '.jsonl', '.ndjson'].includes(ext.toLowerCase())
+  }
 
   const fullName = FileManager.formatFileName(file)
   const displayName = truncateFileName(fullName)
@@ -121,6 +129,14 @@ export const FileNameRender: FC<{ file: FileType }> = ({ file }) => {
             return
           }
           const path = FileManager.getSafePath(file)
+          if (path && isTabular(file.ext)) {
+            TabularPreviewPopup.show(path, fullName)
+            return
+          }
+          if (path && isText(file.ext)) {
+            TextPreviewPopup.show(path, fullName)
+            return
+          }
           if (path) {
             window.api.file.openPath(path)
           }
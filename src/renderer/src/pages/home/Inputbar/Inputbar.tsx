@@ -24,13 +24,15 @@ import FileManager from '@renderer/services/FileManager'
 import { checkRateLimit, getUserMessage } from '@renderer/services/MessagesService'
 import { getModelUniqId } from '@renderer/services/ModelService'
 import PasteService from '@renderer/services/PasteService'
+import { saveDraft, takeRecoveredDraft } from '@renderer/services/SessionRecoveryService'
+import { getShareText, takePendingShare } from '@renderer/services/ShareService'
 import { estimateTextTokens as estimateTxtTokens, estimateUserPromptUsage } from '@renderer/services/TokenService'
 import { translateText } from '@renderer/services/TranslateService'
 import WebSearchService from '@renderer/services/WebSearchService'
 import { useAppDispatch, useAppSelector } from '@renderer/store'
 import { setSearching } from '@renderer/store/runtime'
 import { sendMessage as _sendMessage } from '@renderer/store/thunk/messageThunk'
-import { Assistant, FileType, KnowledgeBase, KnowledgeItem, Model, Topic } from '@renderer/types'
+import { Assistant, ClipboardDescriptor, FileType, KnowledgeBase, KnowledgeItem, Model, Topic } from '@renderer/types'
 import type { MessageInputBaseParams } from '@renderer/types/newMessage'
 import { classNames, delay, formatFileSize, getFileExtension } from '@renderer/utils'
 import { formatQuotedText } from '@renderer/utils/formats'
@@ -52,8 +54,11 @@ import AttachmentPreview from './AttachmentPreview'
 import InputbarTools, { InputbarToolsRef } from './InputbarTools'
 import KnowledgeBaseInput from './KnowledgeBaseInput'
 import MentionModelsInput from './MentionModelsInput'
+import PasteActions, { getPasteAction } from './PasteActions'
+import SendLaterButton from './SendLaterButton'
 import SendMessageButton from './SendMessageButton'
 import TokenCount from './TokenCount'
+import VoiceInputButton from './VoiceInputButton'
 
 interface Props {
   assistant: Assistant
@@ -77,13 +82,15 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
     showInputEstimatedTokens,
     autoTranslateWithSpace,
     enableQuickPanelTriggers,
-    enableBackspaceDeleteModel
+    enableBackspaceDeleteModel,
+    voiceInput
   } = useSettings()
   const [expended, setExpend] = useState(false)
   const [estimateTokenCount, setEstimateTokenCount] = useState(0)
   const [contextCount, setContextCount] = useState({ current: 0, max: 0 })
   const textareaRef = useRef<TextAreaRef>(null)
   const [files, setFiles] = useState<FileType[]>(_files)
+  const [pasted, setPasted] = useState<{ text: string; descriptor: ClipboardDescriptor } | null>(null)
   const { t } = useTranslation()
   const containerRef = useRef(null)
   const { searching } = useRuntime()
@@ -101,6 +108,7 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
   const startDragY = useRef<number>(0)
   const startHeight = useRef<number>(0)
   const currentMessageId = useRef<string>('')
+  const voiceSendRef = useRef(false)
   const isVision = useMemo(() => isVisionModel(model), [model])
   const supportExts = useMemo(() => [...textExts, ...documentExts, ...(isVision ? imageExts : [])], [isVision])
   const { bases: knowledgeBases } = useKnowledgeBases()
@@ -129,6 +137,10 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
     debouncedEstimate(text)
   }, [text, debouncedEstimate])
 
+  useEffect(() => {
+    saveDraft('inputbar', text)
+  }, [text])
+
   const inputTokenCount = showInputEstimatedTokens ? tokenCount : 0
 
   const newTopicShortcut = useShortcutDisplay('new_topic')
@@ -192,6 +204,7 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
       // Clear input
       setText('')
       setFiles([])
+      setPasted(null)
       setTimeout(() => setText(''), 500)
       setTimeout(() => resizeTextArea(), 0)
       setExpend(false)
@@ -200,6 +213,18 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
     }
   }, [assistant, dispatch, files, inputEmpty, loading, mentionModels, resizeTextArea, text, topic])
 
+  // 语音输入的文本追加到输入框后自动发送，正在生成时留在输入框中和下一段一起发送
+  const onVoiceTranscript = useCallback((transcript: string) => {
+    voiceSendRef.current = true
+    setText((prev) => (prev.trim() ? `${prev} ${transcript}` : transcript))
+  }, [])
+
+  useEffect(() => {
+    if (!voiceSendRef.current) return
+    voiceSendRef.current = false
+    sendMessage()
+  }, [text, sendMessage])
+
   const translate = useCallback(async () => {
     if (isTranslating) {
       return
@@ -413,6 +438,39 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
     [resizeTextArea]
   )
 
+  // 系统分享的内容在新话题中打开
+  // 异常退出前未发送的内容
+  const onSessionRecovered = useCallback(() => {
+    const draft = takeRecoveredDraft('inputbar')
+    if (draft) {
+      setText(draft)
+      setTimeout(() => resizeTextArea(), 0)
+    }
+  }, [resizeTextArea])
+
+  const onShare = useCallback(async () => {
+    const payload = takePendingShare()
+    if (!payload) return
+
+    await addNewTopic()
+
+    const shareText = getShareText(payload)
+    if (shareText) {
+      setText(shareText)
+      setTimeout(() => resizeTextArea(), 0)
+    }
+
+    const sharedFiles = await Promise.all(payload.files.map((filePath) => window.api.file.get(filePath)))
+    const supportedFiles = sharedFiles.filter(
+      (file): file is FileType => !!file && supportExts.includes(getFileExtension(file.path))
+    )
+    setFiles(supportedFiles)
+    if (sharedFiles.length > supportedFiles.length) {
+      window.message.info({ key: 'file_not_supported', content: t('chat.input.file_not_supported') })
+    }
+    textareaRef.current?.focus()
+  }, [addNewTopic, resizeTextArea, supportExts, t])
+
   const onPause = async () => {
     await pauseMessages()
   }
@@ -468,7 +526,8 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
 
   const onPaste = useCallback(
     async (event: ClipboardEvent) => {
-      return await PasteService.handlePaste(
+      const pastedText = event.clipboardData?.getData('text')
+      const handled = await PasteService.handlePaste(
         event,
         isVisionModel(model),
         isGenerateImageModel(model),
@@ -481,10 +540,28 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
         resizeTextArea,
         t
       )
+      // 文本按默认方式粘贴后，根据剪贴板内容的类型提供其他粘贴方式
+      if (!handled && pastedText?.trim()) {
+        window.api.clipboard
+          .classify()
+          .then((descriptor) => setPasted(getPasteAction(descriptor) ? { text: pastedText, descriptor } : null))
+          .catch((error) => Logger.warn('[Inputbar] Failed to classify clipboard:', error))
+      }
+      return handled
     },
     [model, pasteLongTextAsFile, pasteLongTextThreshold, resizeTextArea, supportExts, t, text]
   )
 
+  const onApplyPaste = (formatted: string) => {
+    if (!pasted) return
+    const index = text.lastIndexOf(pasted.text)
+    if (index !== -1) {
+      setText(text.slice(0, index) + formatted + text.slice(index + pasted.text.length))
+      setTimeout(() => resizeTextArea(), 0)
+    }
+    setPasted(null)
+  }
+
   const handleDragOver = (e: React.DragEvent<HTMLDivElement>) => {
     e.preventDefault()
     e.stopPropagation()
@@ -617,7 +694,9 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
         _setEstimateTokenCount(tokensCount)
         setContextCount({ current: contextCount.current, max: contextCount.max }) // 现在contextCount是一个对象而不是单个数值
       }),
-      EventEmitter.on(EVENT_NAMES.ADD_NEW_TOPIC, addNewTopic)
+      EventEmitter.on(EVENT_NAMES.ADD_NEW_TOPIC, addNewTopic),
+      EventEmitter.on(EVENT_NAMES.SHARE_RECEIVED, onShare),
+      EventEmitter.on(EVENT_NAMES.SESSION_RECOVERED, onSessionRecovered)
     ]
 
     // 监听引用事件
@@ -630,7 +709,14 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
       unsubscribes.forEach((unsub) => unsub())
       quoteFromAnywhereRemover?.()
     }
-  }, [addNewTopic, onQuote])
+  }, [addNewTopic, onQuote, onShare, onSessionRecovered])
+
+  useEffect(() => {
+    // 分享或恢复时输入框尚未挂载，挂载后再打开
+    onShare()
+    onSessionRecovered()
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [])
 
   useEffect(() => {
     if (!document.querySelector('.topview-fullscreen-container')) {
@@ -770,6 +856,9 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
           {mentionModels.length > 0 && (
             <MentionModelsInput selectedModels={mentionModels} onRemoveModel={handleRemoveModel} />
           )}
+          {pasted && text.includes(pasted.text) && (
+            <PasteActions descriptor={pasted.descriptor} onApply={onApplyPaste} onClose={() => setPasted(null)} />
+          )}
           <Textarea
             value={text}
             onChange={onChange}
@@ -840,6 +929,14 @@ const Inputbar: FC<Props> = ({ assistant: _assistant, setActiveTopic, topic }) =
                 onClick={onNewContext}
               />
               <TranslateButton text={text} onTranslated={onTranslated} isLoading={isTranslating} />
+              {voiceInput && <VoiceInputButton onTranscript={onVoiceTranscript} ToolbarButton={ToolbarButton} />}
+              <SendLaterButton
+                text={text}
+                assistant={assistant}
+                topic={topic}
+                onScheduled={() => setText('')}
+                ToolbarButton={ToolbarButton}
+              />
               {loading && (
                 <Tooltip placement="top" title={t('chat.input.pause')} arrow>
                   <ToolbarButton type="text" onClick={onPause} style={{ marginRight: -2, marginTop: 1 }}>
@@ -0,0 +1,62 @@
+import { CloseOutlined } from '@ant-design/icons'
+import { ClipboardDescriptor } from '@renderer/types'
+import { toCodeBlock, toMarkdownTable } from '@renderer/utils/markdown'
+import { Button } from 'antd'
+import { FC } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+/**
+ * 按剪贴板内容的类型给出的粘贴方式，返回替换粘贴文本的内容
+ */
+export function getPasteAction(descriptor: ClipboardDescriptor): { label: string; format: () => string } | null {
+  const { kind, text = '', language, rows } = descriptor
+  switch (kind) {
+    case 'code':
+      return { label: 'chat.input.paste.as_code', format: () => toCodeBlock(text, language) }
+    case 'table':
+      return rows ? { label: 'chat.input.paste.as_table', format: () => toMarkdownTable(rows) } : null
+    case 'url':
+      return { label: 'chat.input.paste.as_link', format: () => `[${new URL(text).hostname}](${text})` }
+    default:
+      return null
+  }
+}
+
+const PasteActions: FC<{
+  descriptor: ClipboardDescriptor
+  onApply: (formatted: string) => void
+  onClose: () => void
+}> = ({ descriptor, onApply, onClose }) => {
+  const { t } = useTranslation()
+  const action = getPasteAction(descriptor)
+  if (!action) return null
+
+  return (
+    <Container>
+      <Kind>
+        {t(`chat.input.paste.kind.${descriptor.kind}`)}
+        {descriptor.language && ` · ${descriptor.language}`}
+      </Kind>
+      <Button size="small" type="link" onClick={() => onApply(action.format())}>
+        {t(action.label)}
+      </Button>
+      <Button size="small" type="text" icon={<CloseOutlined />} onClick={onClose} />
+    </Container>
+  )
+}
+
+const Container = styled.div`
+  width: 100%;
+  padding: 5px 15px 0 15px;
+  display: flex;
+  align-items: center;
+  gap: 4px;
+  font-size: 12px;
+`
+
+const Kind = styled.span`
+  color: var(--color-text-3);
+`
+
+export default PasteActions
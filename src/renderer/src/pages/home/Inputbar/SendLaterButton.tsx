@@ -0,0 +1,142 @@
+import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
+import {
+  cancelScheduledMessage,
+  listScheduledMessages,
+  scheduleMessage
+} from '@renderer/services/ScheduledMessageService'
+import { Assistant, ScheduledMessage, Topic } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Button, DatePicker, Popover, Tooltip } from 'antd'
+import dayjs, { Dayjs } from 'dayjs'
+import { Clock, X } from 'lucide-react'
+import { FC, useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+interface Props {
+  text: string
+  assistant: Assistant
+  topic: Topic
+  onScheduled: () => void
+  ToolbarButton: any
+}
+
+const SendLaterButton: FC<Props> = ({ text, assistant, topic, onScheduled, ToolbarButton }) => {
+  const { t } = useTranslation()
+  const [open, setOpen] = useState(false)
+  const [sendAt, setSendAt] = useState<Dayjs | null>(null)
+  const [pending, setPending] = useState<ScheduledMessage[]>([])
+
+  const load = useCallback(async () => {
+    const messages = await listScheduledMessages(topic.id)
+    setPending(messages.filter((message) => message.status === 'pending'))
+  }, [topic.id])
+
+  useEffect(() => {
+    load()
+    const off = EventEmitter.on(EVENT_NAMES.SCHEDULED_MESSAGE_COMPLETED, load)
+    return () => off()
+  }, [load])
+
+  const onSchedule = async () => {
+    if (!sendAt || !text.trim()) return
+    try {
+      await scheduleMessage(topic.id, assistant.id, text, sendAt.valueOf())
+      window.message.success({
+        content: t('chat.input.send_later.scheduled', { time: sendAt.format('MM/DD HH:mm') }),
+        key: 'send-later'
+      })
+      setOpen(false)
+      setSendAt(null)
+      onScheduled()
+      load()
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'send-later' })
+    }
+  }
+
+  const onCancel = async (id: string) => {
+    try {
+      await cancelScheduledMessage(id)
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'send-later' })
+    }
+    load()
+  }
+
+  const content = (
+    <Content>
+      <DatePicker
+        showTime={{ format: 'HH:mm' }}
+        format="YYYY-MM-DD HH:mm"
+        value={sendAt}
+        onChange={setSendAt}
+        disabledDate={(date) => date.isBefore(dayjs(), 'day')}
+        placeholder={t('chat.input.send_later.time')}
+      />
+      <Button type="primary" disabled={!sendAt || !text.trim()} onClick={onSchedule}>
+        {t('chat.input.send_later.schedule')}
+      </Button>
+      {pending.length > 0 && (
+        <PendingList>
+          <PendingTitle>{t('chat.input.send_later.pending')}</PendingTitle>
+          {pending.map((message) => (
+            <PendingItem key={message.id}>
+              <span>{dayjs(message.sendAt).format('MM/DD HH:mm')}</span>
+              <PendingText title={message.content}>{message.content}</PendingText>
+              <Tooltip title={t('common.cancel')}>
+                <Button type="text" size="small" icon={<X size={14} />} onClick={() => onCancel(message.id)} />
+              </Tooltip>
+            </PendingItem>
+          ))}
+        </PendingList>
+      )}
+    </Content>
+  )
+
+  return (
+    <Popover open={open} onOpenChange={setOpen} content={content} trigger="click" placement="topRight">
+      <Tooltip placement="top" title={t('chat.input.send_later.title')} arrow>
+        <ToolbarButton type="text">
+          <Clock size={18} color={pending.length ? 'var(--color-primary)' : undefined} />
+        </ToolbarButton>
+      </Tooltip>
+    </Popover>
+  )
+}
+
+const Content = styled.div`
+  display: flex;
+  flex-direction: column;
+  gap: 8px;
+  width: 260px;
+`
+
+const PendingList = styled.div`
+  display: flex;
+  flex-direction: column;
+  gap: 4px;
+  margin-top: 4px;
+`
+
+const PendingTitle = styled.div`
+  font-size: 12px;
+  color: var(--color-text-3);
+`
+
+const PendingItem = styled.div`
+  display: flex;
+  align-items: center;
+  gap: 6px;
+  font-size: 12px;
+`
+
+const PendingText = styled.span`
+  flex: 1;
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+  color: var(--color-text-2);
+`
+
+export default SendLaterButton
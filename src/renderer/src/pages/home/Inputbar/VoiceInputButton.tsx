@@ -0,0 +1,79 @@
+import Logger from '@renderer/config/logger'
+import { useSettings } from '@renderer/hooks/useSettings'
+import { audioManager } from '@renderer/services/AudioManager'
+import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
+import { transcribeSegment } from '@renderer/services/TranscriptionService'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Tooltip } from 'antd'
+import { Mic, MicOff } from 'lucide-react'
+import { FC, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+
+interface Props {
+  onTranscript: (text: string) => void
+  ToolbarButton: any
+}
+
+/**
+ * 免按键语音输入：麦克风输入经过 VAD 切分，每个语音段转写后交给输入框发送
+ */
+const VoiceInputButton: FC<Props> = ({ onTranscript, ToolbarButton }) => {
+  const { t } = useTranslation()
+  const { transcriptionModel } = useSettings()
+  const [listening, setListening] = useState(false)
+  const [speaking, setSpeaking] = useState(false)
+
+  useEffect(() => {
+    if (!listening || !transcriptionModel) return
+
+    // 唤醒词检测已经打开麦克风时只注册处理函数，关闭时不停止采集
+    const wasListening = audioManager.listening
+    const offStarted = EventEmitter.on(EVENT_NAMES.SPEECH_STARTED, () => setSpeaking(true))
+    const offEnded = EventEmitter.on(EVENT_NAMES.SPEECH_ENDED, () => setSpeaking(false))
+    const offSegment = audioManager.onSegment(async (segment, sampleRate) => {
+      const text = await transcribeSegment(transcriptionModel, segment, sampleRate).catch((error) => {
+        window.message.error({ content: getErrorMessage(error), key: 'voice-input' })
+        return ''
+      })
+      text && onTranscript(text)
+    })
+
+    audioManager.start({ hangoverMs: 800 }).catch((error) => {
+      Logger.error('[VoiceInput] Failed to start the microphone:', error)
+      window.message.error({ content: getErrorMessage(error), key: 'voice-input' })
+      setListening(false)
+    })
+
+    return () => {
+      offStarted()
+      offEnded()
+      offSegment()
+      setSpeaking(false)
+      if (!wasListening) audioManager.stop()
+    }
+  }, [listening, onTranscript, transcriptionModel])
+
+  const onClick = () => {
+    if (!transcriptionModel) {
+      window.message.warning({ content: t('chat.input.voice.no_model'), key: 'voice-input' })
+      return
+    }
+    setListening((value) => !value)
+  }
+
+  const title = listening ? t('chat.input.voice.stop') : t('chat.input.voice.start')
+
+  return (
+    <Tooltip placement="top" title={title} arrow>
+      <ToolbarButton type="text" onClick={onClick}>
+        {listening ? (
+          <Mic size={18} style={{ color: speaking ? 'var(--color-primary)' : 'var(--color-error)' }} />
+        ) : (
+          <MicOff size={18} />
+        )}
+      </ToolbarButton>
+    </Tooltip>
+  )
+}
+
+export default VoiceInputButton
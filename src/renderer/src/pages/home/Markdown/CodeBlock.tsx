@@ -5,11 +5,12 @@ interface Props {
   children: string
   className?: string
   id?: string
+  topicId?: string
   onSave?: (id: string, newContent: string) => void
   [key: string]: any
 }
 
-const CodeBlock: React.FC<Props> = ({ children, className, id, onSave }) => {
+const CodeBlock: React.FC<Props> = ({ children, className, id, topicId, onSave }) => {
   const match = /language-([\w-+]+)/.exec(className || '') || children?.includes('\n')
   const language = match?.[1] ?? 'text'
 
@@ -23,7 +24,7 @@ const CodeBlock: React.FC<Props> = ({ children, className, id, onSave }) => {
   )
 
   return match ? (
-    <CodeBlockView language={language} onSave={handleSave}>
+    <CodeBlockView language={language} topicId={topicId} onSave={handleSave}>
       {children}
     </CodeBlockView>
   ) : (
@@ -6,6 +6,7 @@ import ImageViewer from '@renderer/components/ImageViewer'
 import MarkdownShadowDOMRenderer from '@renderer/components/MarkdownShadowDOMRenderer'
 import { useSettings } from '@renderer/hooks/useSettings'
 import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
+import { useAppSelector } from '@renderer/store'
 import type { MainTextMessageBlock, ThinkingMessageBlock, TranslationMessageBlock } from '@renderer/types/newMessage'
 import { parseJSON } from '@renderer/utils'
 import { escapeBrackets, removeSvgEmptyLines } from '@renderer/utils/formats'
@@ -39,6 +40,8 @@ interface Props {
 const Markdown: FC<Props> = ({ block }) => {
   const { t } = useTranslation()
   const { mathEngine } = useSettings()
+  // code blocks apply patches to the folder mounted to the conversation
+  const topicId = useAppSelector((state) => state.messages.entities[block.messageId]?.topicId)
 
   const remarkPlugins = useMemo(() => {
     const plugins = [remarkGfm, remarkCjkFriendly, remarkDisableConstructs(['codeIndented'])]
@@ -83,7 +86,12 @@ const Markdown: FC<Props> = ({ block }) => {
     return {
       a: (props: any) => <Link {...props} citationData={parseJSON(findCitationInChildren(props.children))} />,
       code: (props: any) => (
-        <CodeBlock {...props} id={getCodeBlockId(props?.node?.position?.start)} onSave={onSaveCodeBlock} />
+        <CodeBlock
+          {...props}
+          id={getCodeBlockId(props?.node?.position?.start)}
+          topicId={topicId}
+          onSave={onSaveCodeBlock}
+        />
       ),
       table: (props: any) => <Table {...props} blockId={block.id} />,
       img: (props: any) => <ImageViewer style={{ maxWidth: 500, maxHeight: 500 }} {...props} />,
@@ -94,7 +102,7 @@ const Markdown: FC<Props> = ({ block }) => {
         return <p {...props} />
       }
     } as Partial<Components>
-  }, [onSaveCodeBlock, block.id])
+  }, [onSaveCodeBlock, block.id, topicId])
 
   if (messageContent.includes('<style>')) {
     components.style = MarkdownShadowDOMRenderer as any
@@ -1,7 +1,9 @@
 import ImageViewer from '@renderer/components/ImageViewer'
+import Logger from '@renderer/config/logger'
+import { isSecureBlock } from '@renderer/services/SecureTopicService'
 import { type ImageMessageBlock, MessageBlockStatus } from '@renderer/types/newMessage'
 import { Skeleton } from 'antd'
-import React from 'react'
+import React, { useEffect, useState } from 'react'
 import styled from 'styled-components'
 
 interface Props {
@@ -9,13 +11,26 @@ interface Props {
 }
 
 const ImageBlock: React.FC<Props> = ({ block }) => {
+  const secure = isSecureBlock(block.id)
+  const [secureSrc, setSecureSrc] = useState<string>()
+
+  // 加密话题的图片文件已加密，通过主进程解密后显示
+  useEffect(() => {
+    if (!secure || !block.file) return
+    window.api.file
+      .base64Image(block.file.id + block.file.ext)
+      .then(({ data }) => setSecureSrc(data))
+      .catch((error) => Logger.error('[ImageBlock] Failed to load image:', error))
+  }, [secure, block.file])
+
   if (block.status === MessageBlockStatus.STREAMING || block.status === MessageBlockStatus.PROCESSING)
     return <Skeleton.Image active style={{ width: 200, height: 200 }} />
   if (block.status === MessageBlockStatus.SUCCESS) {
+    const fileSrc = secure ? secureSrc : block?.file?.path && `file://${block?.file?.path}`
     const images = block.metadata?.generateImageResponse?.images?.length
       ? block.metadata?.generateImageResponse?.images
-      : block?.file?.path
-        ? [`file://${block?.file?.path}`]
+      : fileSrc
+        ? [fileSrc]
         : []
     return (
       <Container style={{ marginBottom: 8 }}>
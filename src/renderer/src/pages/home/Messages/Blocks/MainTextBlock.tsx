@@ -1,4 +1,5 @@
 import { GroundingSupport } from '@google/genai'
+import LinkPreviewCard from '@renderer/components/LinkPreviewCard'
 import { useSettings } from '@renderer/hooks/useSettings'
 import { getModelUniqId } from '@renderer/services/ModelService'
 import type { RootState } from '@renderer/store'
@@ -6,6 +7,7 @@ import { selectFormattedCitationsByBlockId } from '@renderer/store/messageBlock'
 import { type Model, WebSearchSource } from '@renderer/types'
 import type { MainTextMessageBlock, Message } from '@renderer/types/newMessage'
 import { cleanMarkdownContent, encodeHTML } from '@renderer/utils/formats'
+import { extractStandaloneUrls } from '@renderer/utils/linkConverter'
 import { Flex } from 'antd'
 import React, { useMemo } from 'react'
 import { useSelector } from 'react-redux'
@@ -24,7 +26,7 @@ const toolUseRegex = /<tool_use>([\s\S]*?)<\/tool_use>/g
 
 const MainTextBlock: React.FC<Props> = ({ block, citationBlockId, role, mentions = [] }) => {
   // Use the passed citationBlockId directly in the selector
-  const { renderInputMessageAsMarkdown } = useSettings()
+  const { renderInputMessageAsMarkdown, linkPreviews } = useSettings()
 
   const rawCitations = useSelector((state: RootState) => selectFormattedCitationsByBlockId(state, citationBlockId))
 
@@ -136,6 +138,11 @@ const MainTextBlock: React.FC<Props> = ({ block, citationBlockId, role, mentions
     return content
   }, [block.content, block.citationReferences, citationBlockId, formattedCitations])
 
+  // 用户粘贴的独占一行的链接显示预览卡片
+  const previewUrls = useMemo(() => {
+    return role === 'user' && linkPreviews ? extractStandaloneUrls(block.content) : []
+  }, [role, linkPreviews, block.content])
+
   const ignoreToolUse = useMemo(() => {
     return processedContent.replace(toolUseRegex, '')
   }, [processedContent])
@@ -157,6 +164,9 @@ const MainTextBlock: React.FC<Props> = ({ block, citationBlockId, role, mentions
       ) : (
         <Markdown block={{ ...block, content: ignoreToolUse }} />
       )}
+      {previewUrls.map((url) => (
+        <LinkPreviewCard key={url} url={url} />
+      ))}
     </>
   )
 }
@@ -1,6 +1,7 @@
 import ContextMenu from '@renderer/components/ContextMenu'
 import Favicon from '@renderer/components/Icons/FallbackFavicon'
 import { HStack } from '@renderer/components/Layout'
+import { KnowledgeCitation as CitationLocation } from '@renderer/types'
 import { fetchWebContent } from '@renderer/utils/fetch'
 import { cleanMarkdownContent } from '@renderer/utils/formats'
 import { QueryClient, QueryClientProvider, useQuery } from '@tanstack/react-query'
@@ -159,15 +160,32 @@ const WebSearchCitation: React.FC<{ citation: Citation }> = ({ citation }) => {
 }
 
 const KnowledgeCitation: React.FC<{ citation: Citation }> = ({ citation }) => {
+  const { t } = useTranslation()
+  const location: CitationLocation | undefined = citation.metadata?.citation
+
+  // 有位置信息时跳转到源文件中被引用的页或行
+  const handleClick = (event: React.MouseEvent) => {
+    if (!location) return handleLinkClick(citation.url, event)
+    event.preventDefault()
+    window.api.knowledgeBase.openCitation(location)
+  }
+
+  const locationLabel = location?.page
+    ? t('message.citation.location.page', { page: location.page })
+    : location?.startLine
+      ? t('message.citation.location.lines', { start: location.startLine, end: location.endLine })
+      : undefined
+
   return (
     <WebSearchCard>
       <ContextMenu>
         <WebSearchCardHeader>
           <CitationIndex>{citation.number}</CitationIndex>
           {citation.showFavicon && <FileSearch width={16} />}
-          <CitationLink className="text-nowrap" href={citation.url} onClick={(e) => handleLinkClick(citation.url, e)}>
+          <CitationLink className="text-nowrap" href={citation.url} onClick={handleClick}>
             {citation.title}
           </CitationLink>
+          {locationLabel && <CitationLocationLabel>{locationLabel}</CitationLocationLabel>}
           {citation.content && <CopyButton content={citation.content} />}
         </WebSearchCardHeader>
         <WebSearchCardContent className="selectable-text">
@@ -219,6 +237,12 @@ const CitationIndex = styled.div`
   margin-right: 8px;
 `
 
+const CitationLocationLabel = styled.span`
+  flex-shrink: 0;
+  font-size: 12px;
+  color: var(--color-text-3);
+`
+
 const CitationLink = styled.a`
   font-size: 14px;
   line-height: 1.6;
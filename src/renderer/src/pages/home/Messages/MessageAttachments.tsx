@@ -1,4 +1,5 @@
 import FileManager from '@renderer/services/FileManager'
+import { isSecureBlock } from '@renderer/services/SecureTopicService'
 import type { FileMessageBlock } from '@renderer/types/newMessage'
 import { Upload } from 'antd'
 import { FC } from 'react'
@@ -72,7 +73,8 @@ const MessageAttachments: FC<Props> = ({ block }) => {
         fileList={[
           {
             uid: block.file.id,
-            url: 'file://' + FileManager.getSafePath(block.file),
+            // 加密话题的附件已加密，不提供直接打开的链接
+            url: isSecureBlock(block.id) ? undefined : 'file://' + FileManager.getSafePath(block.file),
             status: 'done' as const,
             name: FileManager.formatFileName(block.file)
           }
@@ -5,6 +5,7 @@ import { TranslateLanguageOptions } from '@renderer/config/translate'
 import { useMessageEditing } from '@renderer/context/MessageEditingContext'
 import { useChatContext } from '@renderer/hooks/useChatContext'
 import { useMessageOperations, useTopicLoading } from '@renderer/hooks/useMessageOperations'
+import { useRuntime } from '@renderer/hooks/useRuntime'
 import { useMessageStyle } from '@renderer/hooks/useSettings'
 import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
 import { getMessageTitle } from '@renderer/services/MessagesService'
@@ -16,11 +17,13 @@ import type { Assistant, Topic } from '@renderer/types'
 import type { Message } from '@renderer/types/newMessage'
 import { captureScrollableDivAsBlob, captureScrollableDivAsDataURL } from '@renderer/utils'
 import { copyMessageAsPlainText } from '@renderer/utils/copy'
+import { getErrorMessage } from '@renderer/utils/error'
 import {
   exportMarkdownToJoplin,
   exportMarkdownToSiyuan,
   exportMarkdownToYuque,
   exportMessageAsMarkdown,
+  exportMessageAsWord,
   exportMessageToNotion,
   messageToMarkdown
 } from '@renderer/utils/export'
@@ -29,7 +32,7 @@ import { removeTrailingDoubleSpaces } from '@renderer/utils/markdown'
 import { findMainTextBlocks, findTranslationBlocks, getMainTextContent } from '@renderer/utils/messageUtils/find'
 import { Dropdown, Popconfirm, Tooltip } from 'antd'
 import dayjs from 'dayjs'
-import { AtSign, Copy, Languages, Menu, RefreshCw, Save, Share, Split, ThumbsUp, Trash } from 'lucide-react'
+import { AppWindow, AtSign, Copy, Languages, Menu, RefreshCw, Save, Share, Split, ThumbsUp, Trash } from 'lucide-react'
 import { FilePenLine } from 'lucide-react'
 import { FC, memo, useCallback, useMemo, useState } from 'react'
 import { useTranslation } from 'react-i18next'
@@ -76,6 +79,12 @@ const MessageMenubar: FC<Props> = (props) => {
   const isUserMessage = message.role === 'user'
 
   const exportMenuOptions = useSelector((state: RootState) => state.settings.exportMenuOptions)
+  const { openedKeepAliveMinapps, openedOneOffMinapp } = useRuntime()
+
+  const openedMinapps = useMemo(
+    () => [...openedKeepAliveMinapps, ...(openedOneOffMinapp ? [openedOneOffMinapp] : [])],
+    [openedKeepAliveMinapps, openedOneOffMinapp]
+  )
 
   // const processedMessage = useMemo(() => {
   //   if (message.role === 'assistant' && message.model && isReasoningModel(message.model)) {
@@ -92,6 +101,24 @@ const MessageMenubar: FC<Props> = (props) => {
     return getMainTextContent(message)
   }, [message])
 
+  const onSendToMinapp = useCallback(
+    async (appId: string) => {
+      try {
+        const ack = await window.api.miniApp.send(appId, 'receive_markdown', { markdown: mainTextContent })
+        if (ack.accepted) {
+          window.message.success({ content: t('chat.message.send_to_minapp.success'), key: 'send-to-minapp' })
+        } else if (ack.error === 'unsupported_intent') {
+          window.message.warning({ content: t('chat.message.send_to_minapp.unsupported'), key: 'send-to-minapp' })
+        } else {
+          window.message.error({ content: ack.error, key: 'send-to-minapp' })
+        }
+      } catch (error) {
+        window.message.error({ content: getErrorMessage(error), key: 'send-to-minapp' })
+      }
+    },
+    [mainTextContent, t]
+  )
+
   const onCopy = useCallback(
     (e: React.MouseEvent) => {
       e.stopPropagation()
@@ -200,6 +227,20 @@ const MessageMenubar: FC<Props> = (props) => {
           toggleMultiSelectMode(true)
         }
       },
+      ...(openedMinapps.length
+        ? [
+            {
+              label: t('chat.message.send_to_minapp.title'),
+              key: 'send-to-minapp',
+              icon: <AppWindow size={16} />,
+              children: openedMinapps.map((app) => ({
+                label: app.name,
+                key: `send-to-minapp-${app.id}`,
+                onClick: () => onSendToMinapp(app.id)
+              }))
+            }
+          ]
+        : []),
       {
         label: t('chat.topics.export.title'),
         key: 'export',
@@ -245,11 +286,7 @@ const MessageMenubar: FC<Props> = (props) => {
           exportMenuOptions.docx && {
             label: t('chat.topics.export.word'),
             key: 'word',
-            onClick: async () => {
-              const markdown = messageToMarkdown(message)
-              const title = await getMessageTitle(message)
-              window.api.export.toWord(markdown, title)
-            }
+            onClick: () => exportMessageAsWord(message)
           },
           exportMenuOptions.notion && {
             label: t('chat.topics.export.notion'),
@@ -306,6 +343,8 @@ const MessageMenubar: FC<Props> = (props) => {
       message,
       mainTextContent,
       toggleMultiSelectMode,
+      openedMinapps,
+      onSendToMinapp,
       messageContainerRef,
       topic.name
     ]
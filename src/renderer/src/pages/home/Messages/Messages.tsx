@@ -173,6 +173,7 @@ const Messages: React.FC<MessagesProps> = ({ assistant, topic, setActiveTopic, o
       EventEmitter.on(EVENT_NAMES.NEW_BRANCH, async (index: number) => {
         const newTopic = getDefaultTopic(assistant.id)
         newTopic.name = topic.name
+        newTopic.secure = topic.secure
         const currentMessages = messagesRef.current
 
         if (index < 0 || index > currentMessages.length) {
@@ -5,17 +5,19 @@ import { DEFAULT_CONTEXTCOUNT, DEFAULT_MAX_TOKENS, DEFAULT_TEMPERATURE } from '@
 import {
   isOpenAIModel,
   isSupportedFlexServiceTier,
-  isSupportedReasoningEffortOpenAIModel
+  isSupportedReasoningEffortOpenAIModel,
+  isTranscriptionModel
 } from '@renderer/config/models'
 import { useCodeStyle } from '@renderer/context/CodeStyleProvider'
 import { useTheme } from '@renderer/context/ThemeProvider'
 import { useAssistant } from '@renderer/hooks/useAssistant'
-import { useProvider } from '@renderer/hooks/useProvider'
+import { useAllProviders, useProvider } from '@renderer/hooks/useProvider'
 import { useSettings } from '@renderer/hooks/useSettings'
 import { SettingDivider, SettingRow, SettingRowTitle } from '@renderer/pages/settings'
 import AssistantSettingsPopup from '@renderer/pages/settings/AssistantSettings'
 import { CollapsibleSettingGroup } from '@renderer/pages/settings/SettingGroup'
 import { getDefaultModel } from '@renderer/services/AssistantService'
+import { getModelUniqId } from '@renderer/services/ModelService'
 import { useAppDispatch } from '@renderer/store'
 import {
   SendMessageShortcut,
@@ -42,7 +44,9 @@ import {
   setShowPrompt,
   setShowTokens,
   setShowTranslateConfirm,
-  setThoughtAutoCollapse
+  setThoughtAutoCollapse,
+  setTranscriptionModel,
+  setVoiceInput
 } from '@renderer/store/settings'
 import {
   Assistant,
@@ -110,9 +114,17 @@ const SettingsTab: FC<Props> = (props) => {
     enableQuickPanelTriggers,
     enableBackspaceDeleteModel,
     showTranslateConfirm,
-    showTokens
+    showTokens,
+    voiceInput,
+    transcriptionModel
   } = useSettings()
 
+  const providers = useAllProviders()
+  const transcriptionModels = useMemo(
+    () => providers.flatMap((item) => item.models.filter(isTranscriptionModel)),
+    [providers]
+  )
+
   const onUpdateAssistantSettings = (settings: Partial<AssistantSettings>) => {
     updateAssistantSettings(settings)
   }
@@ -675,6 +687,35 @@ const SettingsTab: FC<Props> = (props) => {
               style={{ width: 135 }}
             />
           </SettingRow>
+          <SettingDivider />
+          <SettingRow>
+            <SettingRowTitleSmall>
+              {t('settings.messages.input.voice_input')}
+              <Tooltip title={t('settings.messages.input.voice_input_tip')}>
+                <CircleHelp size={14} style={{ marginLeft: 4 }} color="var(--color-text-2)" />
+              </Tooltip>
+            </SettingRowTitleSmall>
+            <Switch size="small" checked={voiceInput} onChange={(checked) => dispatch(setVoiceInput(checked))} />
+          </SettingRow>
+          {voiceInput && (
+            <>
+              <SettingDivider />
+              <SettingRow>
+                <SettingRowTitleSmall>{t('settings.messages.input.voice_input_model')}</SettingRowTitleSmall>
+                <StyledSelect
+                  size="small"
+                  value={getModelUniqId(transcriptionModel) || undefined}
+                  placeholder={t('transcription.select_model')}
+                  menuItemSelectedIcon={<CheckOutlined />}
+                  options={transcriptionModels.map((model) => ({ value: getModelUniqId(model), label: model.name }))}
+                  onChange={(id) =>
+                    dispatch(setTranscriptionModel(transcriptionModels.find((model) => getModelUniqId(model) === id)))
+                  }
+                  style={{ width: 135 }}
+                />
+              </SettingRow>
+            </>
+          )}
         </SettingGroup>
       </CollapsibleSettingGroup>
     </Container>
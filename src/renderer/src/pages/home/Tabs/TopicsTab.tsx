@@ -3,36 +3,55 @@ import {
   CloseOutlined,
   DeleteOutlined,
   EditOutlined,
+  EyeOutlined,
+  FullscreenOutlined,
+  FolderOpenOutlined,
   FolderOutlined,
+  InboxOutlined,
+  LockOutlined,
   MenuOutlined,
   PushpinOutlined,
   QuestionCircleOutlined,
+  ShareAltOutlined,
+  UnlockOutlined,
   UploadOutlined
 } from '@ant-design/icons'
 import DragableList from '@renderer/components/DragableList'
 import CopyIcon from '@renderer/components/Icons/CopyIcon'
 import ObsidianExportPopup from '@renderer/components/Popups/ObsidianExportPopup'
 import PromptPopup from '@renderer/components/Popups/PromptPopup'
+import ShareConversationPopup from '@renderer/components/Popups/ShareConversationPopup'
+import WorkspaceChangesPopup from '@renderer/components/Popups/WorkspaceChangesPopup'
 import Scrollbar from '@renderer/components/Scrollbar'
 import { isMac } from '@renderer/config/constant'
 import { useAssistant, useAssistants } from '@renderer/hooks/useAssistant'
+import { useConversationWorkspaces } from '@renderer/hooks/useConversationWorkspaces'
 import { modelGenerating } from '@renderer/hooks/useRuntime'
 import { useSettings } from '@renderer/hooks/useSettings'
 import { finishTopicRenaming, startTopicRenaming, TopicManager } from '@renderer/hooks/useTopic'
 import { fetchMessagesSummary } from '@renderer/services/ApiService'
+import { archiveTopic } from '@renderer/services/ArchiveService'
+import {
+  mountConversationWorkspace,
+  unmountConversationWorkspace
+} from '@renderer/services/ConversationWorkspaceService'
 import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
+import { setTopicSecure, unlockSecureTopic } from '@renderer/services/SecureTopicService'
+import { exportTopicAsTranscript } from '@renderer/services/TranscriptService'
 import store from '@renderer/store'
 import { RootState } from '@renderer/store'
 import { setGenerating } from '@renderer/store/runtime'
-import { Assistant, Topic } from '@renderer/types'
-import { removeSpecialCharactersForFileName } from '@renderer/utils'
+import { Assistant, Topic, UserTemplate } from '@renderer/types'
 import { copyTopicAsMarkdown, copyTopicAsPlainText } from '@renderer/utils/copy'
+import { getErrorMessage } from '@renderer/utils/error'
 import {
   exportMarkdownToJoplin,
   exportMarkdownToSiyuan,
   exportMarkdownToYuque,
   exportTopicAsMarkdown,
+  exportTopicAsWord,
   exportTopicToNotion,
+  exportTopicWithTemplate,
   topicToMarkdown
 } from '@renderer/utils/export'
 import { hasTopicPendingRequests } from '@renderer/utils/queue'
@@ -129,6 +148,7 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
       const index = findIndex(assistant.topics, (t) => t.id === topic.id)
       setActiveTopic(assistant.topics[index + 1 === assistant.topics.length ? index - 1 : index + 1])
       removeTopic(topic)
+      topic.secure && window.api.secureTopic.removeKey(topic.id)
       setDeletingTopicId(null)
     },
     [assistant.topics, onClearMessages, removeTopic, setActiveTopic]
@@ -150,10 +170,20 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
         setActiveTopic(assistant.topics[index + 1 === assistant.topics.length ? index - 1 : index + 1])
       }
       removeTopic(topic)
+      topic.secure && window.api.secureTopic.removeKey(topic.id)
     },
     [assistant.topics, removeTopic, setActiveTopic, activeTopic]
   )
 
+  const onToggleSecure = useCallback(
+    async (topic: Topic) => {
+      const secure = !topic.secure
+      await setTopicSecure(topic, secure)
+      topic.id === activeTopic.id && setActiveTopic({ ...topic, secure })
+    },
+    [activeTopic.id, setActiveTopic]
+  )
+
   const onMoveTopic = useCallback(
     async (topic: Topic, toAssistant: Assistant) => {
       await modelGenerating()
@@ -164,6 +194,26 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
     [assistant.topics, moveTopic, setActiveTopic]
   )
 
+  const onArchiveTopic = useCallback(
+    async (topic: Topic) => {
+      await modelGenerating()
+      if (hasTopicPendingRequests(topic.id)) {
+        return
+      }
+      if (topic.id === activeTopic?.id) {
+        const index = findIndex(assistant.topics, (t) => t.id === topic.id)
+        setActiveTopic(assistant.topics[index + 1 === assistant.topics.length ? index - 1 : index + 1])
+      }
+      try {
+        await archiveTopic(topic)
+        window.message.success({ content: t('chat.topics.archived'), key: 'archive-topic' })
+      } catch (error: any) {
+        window.message.error({ content: getErrorMessage(error), key: 'archive-topic' })
+      }
+    },
+    [assistant.topics, setActiveTopic, activeTopic, t]
+  )
+
   const onSwitchTopic = useCallback(
     async (topic: Topic) => {
       // await modelGenerating()
@@ -174,13 +224,37 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
     [setActiveTopic]
   )
 
+  const workspaces = useConversationWorkspaces()
+
+  const onMountWorkspace = useCallback(
+    async (topic: Topic) => {
+      try {
+        const workspace = await mountConversationWorkspace(topic.id)
+        workspace && window.message.success({ content: t('chat.topics.workspace.mounted'), key: 'workspace-mount' })
+      } catch (error) {
+        window.message.error({ content: getErrorMessage(error), key: 'workspace-mount' })
+      }
+    },
+    [t]
+  )
+
   const exportMenuOptions = useSelector((state: RootState) => state.settings.exportMenuOptions)
 
   const [_targetTopic, setTargetTopic] = useState<Topic | null>(null)
   const targetTopic = useDeferredValue(_targetTopic)
+  const [templates, setTemplates] = useState<UserTemplate[]>([])
+
+  const onTopicContextMenu = useCallback((topic: Topic) => {
+    setTargetTopic(topic)
+    window.api.template
+      .list()
+      .then(setTemplates)
+      .catch(() => setTemplates([]))
+  }, [])
   const getTopicMenuItems = useMemo(() => {
     const topic = targetTopic
     if (!topic) return []
+    const workspace = workspaces[topic.id]
 
     const menus: MenuProps['items'] = [
       {
@@ -259,6 +333,14 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
           onPinTopic(topic)
         }
       },
+      {
+        label: topic.secure ? t('chat.topics.secure.disable') : t('chat.topics.secure.enable'),
+        key: 'secure',
+        icon: topic.secure ? <UnlockOutlined /> : <LockOutlined />,
+        onClick() {
+          onToggleSecure(topic)
+        }
+      },
       {
         label: t('chat.topics.clear.title'),
         key: 'clear-messages',
@@ -288,7 +370,7 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
           }
         ]
       },
-      {
+      requireUnlock(topic, {
         label: t('chat.topics.copy.title'),
         key: 'copy',
         icon: <CopyIcon />,
@@ -309,8 +391,8 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
             onClick: () => copyTopicAsPlainText(topic)
           }
         ]
-      },
-      {
+      }),
+      requireUnlock(topic, {
         label: t('chat.topics.export.title'),
         key: 'export',
         icon: <UploadOutlined />,
@@ -333,10 +415,12 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
           exportMenuOptions.docx && {
             label: t('chat.topics.export.word'),
             key: 'word',
-            onClick: async () => {
-              const markdown = await topicToMarkdown(topic)
-              window.api.export.toWord(markdown, removeSpecialCharactersForFileName(topic.name))
-            }
+            onClick: () => exportTopicAsWord(topic)
+          },
+          {
+            label: t('chat.topics.export.transcript'),
+            key: 'transcript',
+            onClick: () => exportTopicAsTranscript(topic)
           },
           exportMenuOptions.notion && {
             label: t('chat.topics.export.notion'),
@@ -375,8 +459,60 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
               const markdown = await topicToMarkdown(topic)
               exportMarkdownToSiyuan(topic.name, markdown)
             }
+          },
+          templates.length > 0 && {
+            label: t('chat.topics.export.template'),
+            key: 'template',
+            children: templates.map((template) => ({
+              label: template.name,
+              key: `template-${template.id}`,
+              onClick: () => exportTopicWithTemplate(topic, template)
+            }))
           }
         ].filter(Boolean) as ItemType<MenuItemType>[]
+      }),
+      requireUnlock(topic, {
+        label: t('chat.topics.presentation'),
+        key: 'presentation',
+        icon: <FullscreenOutlined />,
+        onClick: () => window.api.presentation.enter(topic.id)
+      }),
+      requireUnlock(topic, {
+        label: t('chat.topics.share.title'),
+        key: 'share',
+        icon: <ShareAltOutlined />,
+        onClick: () => ShareConversationPopup.show(topic)
+      }),
+      {
+        label: t('chat.topics.workspace.title'),
+        key: 'workspace',
+        icon: <FolderOpenOutlined />,
+        children: workspace
+          ? [
+              {
+                label: t('chat.topics.workspace.changes'),
+                key: 'workspace-changes',
+                onClick: () => WorkspaceChangesPopup.show(workspace)
+              },
+              {
+                label: t('chat.topics.workspace.open'),
+                key: 'workspace-open',
+                onClick: () => window.api.file.openPath(workspace.path)
+              },
+              {
+                label: t('chat.topics.workspace.unmount'),
+                key: 'workspace-unmount',
+                danger: true,
+                onClick: () => unmountConversationWorkspace(topic.id)
+              }
+            ]
+          : [
+              {
+                label: t('chat.topics.workspace.mount'),
+                key: 'workspace-mount',
+                onClick: () => onMountWorkspace(topic)
+              }
+            ]
       }
     ]
 
@@ -396,6 +532,15 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
     }
 
     if (assistant.topics.length > 1 && !topic.pinned) {
+      menus.push({
+        label: t('chat.topics.archive'),
+        key: 'archive',
+        icon: <InboxOutlined />,
+        // 加密话题不归档
+        disabled: topic.secure,
+        title: topic.secure ? t('chat.topics.secure.no_archive') : undefined,
+        onClick: () => onArchiveTopic(topic)
+      })
       menus.push({ type: 'divider' })
       menus.push({
         label: t('common.delete'),
@@ -420,16 +565,21 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
     exportMenuOptions.obsidian,
     exportMenuOptions.joplin,
     exportMenuOptions.siyuan,
+    templates,
     assistants,
     assistant,
     updateTopic,
     activeTopic.id,
     setActiveTopic,
     onPinTopic,
+    onToggleSecure,
     onClearMessages,
     setTopicPosition,
     onMoveTopic,
-    onDeleteTopic
+    onArchiveTopic,
+    onDeleteTopic,
+    onMountWorkspace,
+    workspaces
   ])
 
   // Sort topics based on pinned status if pinTopicsToTop is enabled
@@ -462,7 +612,7 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
 
             return (
               <TopicListItem
-                onContextMenu={() => setTargetTopic(topic)}
+                onContextMenu={() => onTopicContextMenu(topic)}
                 className={isActive ? 'active' : ''}
                 onClick={() => onSwitchTopic(topic)}
                 style={{ borderRadius }}>
@@ -501,6 +651,16 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
                       </MenuButton>
                     </Tooltip>
                   )}
+                  {topic.secure && (
+                    <MenuButton className="pin">
+                      <LockOutlined />
+                    </MenuButton>
+                  )}
+                  {topic.readonly && (
+                    <MenuButton className="pin">
+                      <EyeOutlined />
+                    </MenuButton>
+                  )}
                   {topic.pinned && (
                     <MenuButton className="pin">
                       <PushpinOutlined />
@@ -525,6 +685,17 @@ const Topics: FC<Props> = ({ assistant: _assistant, activeTopic, setActiveTopic
   )
 }
 
+/**
+ * 加密话题的复制、导出和分享需要先解锁
+ */
+function requireUnlock(topic: Topic, item: any): ItemType<MenuItemType> {
+  return {
+    ...item,
+    onClick: item.onClick && (async () => (await unlockSecureTopic(topic)) && item.onClick()),
+    children: item.children?.map((child: any) => requireUnlock(topic, child))
+  }
+}
+
 const Container = styled(Scrollbar)`
   display: flex;
   flex-direction: column;
@@ -7,14 +7,19 @@ import { NOT_SUPPORTED_REANK_PROVIDERS } from '@renderer/config/providers'
 import { useKnowledgeBases } from '@renderer/hooks/useKnowledge'
 import { useProviders } from '@renderer/hooks/useProvider'
 import { SettingHelpText } from '@renderer/pages/settings'
-import { getKnowledgeBaseParams } from '@renderer/services/KnowledgeService'
+import { getProviderByModel } from '@renderer/services/AssistantService'
+import {
+  getKnowledgeBaseParams,
+  getLocalEmbeddingModels,
+  getLocalRerankModels
+} from '@renderer/services/KnowledgeService'
 import { getModelUniqId } from '@renderer/services/ModelService'
 import { KnowledgeBase, Model } from '@renderer/types'
 import { getErrorMessage } from '@renderer/utils/error'
 import { Flex, Form, Input, InputNumber, Modal, Select, Slider, Switch } from 'antd'
 import { find, sortBy } from 'lodash'
 import { nanoid } from 'nanoid'
-import { useMemo, useRef, useState } from 'react'
+import { useEffect, useMemo, useRef, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 
 interface ShowParams {
@@ -42,20 +47,29 @@ const PopupContainer: React.FC<Props> = ({ title, resolve }) => {
   const { t } = useTranslation()
   const { providers } = useProviders()
   const { addKnowledgeBase } = useKnowledgeBases()
+  const [localEmbeddingModels, setLocalEmbeddingModels] = useState<Model[]>([])
+  const [localRerankModels, setLocalRerankModels] = useState<Model[]>([])
+
+  useEffect(() => {
+    getLocalEmbeddingModels().then(setLocalEmbeddingModels)
+    getLocalRerankModels().then(setLocalRerankModels)
+  }, [])
 
   const embeddingModels = useMemo(() => {
     return providers
       .map((p) => p.models)
       .flat()
       .filter((model) => isEmbeddingModel(model))
-  }, [providers])
+      .concat(localEmbeddingModels)
+  }, [providers, localEmbeddingModels])
 
   const rerankModels = useMemo(() => {
     return providers
       .map((p) => p.models)
       .flat()
       .filter((model) => isRerankModel(model))
-  }, [providers])
+      .concat(localRerankModels)
+  }, [providers, localRerankModels])
 
   const nameInputRef = useRef<any>(null)
 
@@ -74,8 +88,18 @@ const PopupContainer: React.FC<Props> = ({ title, resolve }) => {
             modelId: m.id
           }))
       }))
+      .concat({
+        label: t('knowledge.local_models'),
+        title: t('knowledge.local_models'),
+        options: localEmbeddingModels.map((m) => ({
+          label: m.name,
+          value: getModelUniqId(m),
+          providerId: m.provider,
+          modelId: m.id
+        }))
+      })
       .filter((group) => group.options.length > 0)
-  }, [providers, t])
+  }, [providers, localEmbeddingModels, t])
 
   const rerankSelectOptions = useMemo(() => {
     return providers
@@ -91,8 +115,13 @@ const PopupContainer: React.FC<Props> = ({ title, resolve }) => {
             value: getModelUniqId(m)
           }))
       }))
+      .concat({
+        label: t('knowledge.local_models'),
+        title: t('knowledge.local_models'),
+        options: localRerankModels.map((m) => ({ label: m.name, value: getModelUniqId(m) }))
+      })
       .filter((group) => group.options.length > 0)
-  }, [providers, t])
+  }, [providers, localRerankModels, t])
 
   const onOk = async () => {
     try {
@@ -105,7 +134,8 @@ const PopupContainer: React.FC<Props> = ({ title, resolve }) => {
 
       if (selectedEmbeddingModel) {
         setLoading(true)
-        const provider = providers.find((p) => p.id === selectedEmbeddingModel.provider)
+        // 本地嵌入模型的服务商是默认不启用的本地推理
+        const provider = getProviderByModel(selectedEmbeddingModel)
 
         if (!provider) {
           return
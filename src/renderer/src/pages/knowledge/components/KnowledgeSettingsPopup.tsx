@@ -8,8 +8,9 @@ import { NOT_SUPPORTED_REANK_PROVIDERS } from '@renderer/config/providers'
 import { useKnowledge } from '@renderer/hooks/useKnowledge'
 import { useProviders } from '@renderer/hooks/useProvider'
 import { SettingHelpText } from '@renderer/pages/settings'
+import { getLocalEmbeddingModels, getLocalRerankModels } from '@renderer/services/KnowledgeService'
 import { getModelUniqId } from '@renderer/services/ModelService'
-import { KnowledgeBase } from '@renderer/types'
+import { KnowledgeBase, Model } from '@renderer/types'
 import { Alert, Form, Input, InputNumber, Modal, Select, Slider } from 'antd'
 import { sortBy } from 'lodash'
 import { useEffect, useState } from 'react'
@@ -42,11 +43,18 @@ const PopupContainer: React.FC<Props> = ({ base: _base, resolve }) => {
   const { t } = useTranslation()
   const { providers } = useProviders()
   const { base, updateKnowledgeBase } = useKnowledge(_base.id)
+  const [localEmbeddingModels, setLocalEmbeddingModels] = useState<Model[]>([])
+  const [localRerankModels, setLocalRerankModels] = useState<Model[]>([])
 
   useEffect(() => {
     form.setFieldsValue({ documentCount: base?.documentCount || 6 })
   }, [base, form])
 
+  useEffect(() => {
+    getLocalEmbeddingModels().then(setLocalEmbeddingModels)
+    getLocalRerankModels().then(setLocalRerankModels)
+  }, [])
+
   if (!base) {
     resolve(null)
     return null
@@ -64,6 +72,11 @@ const PopupContainer: React.FC<Props> = ({ base: _base, resolve }) => {
           value: getModelUniqId(m)
         }))
     }))
+    .concat({
+      label: t('knowledge.local_models'),
+      title: t('knowledge.local_models'),
+      options: localEmbeddingModels.map((m) => ({ label: m.name, value: getModelUniqId(m) }))
+    })
     .filter((group) => group.options.length > 0)
 
   const rerankSelectOptions = providers
@@ -79,6 +92,11 @@ const PopupContainer: React.FC<Props> = ({ base: _base, resolve }) => {
           value: getModelUniqId(m)
         }))
     }))
+    .concat({
+      label: t('knowledge.local_models'),
+      title: t('knowledge.local_models'),
+      options: localRerankModels.map((m) => ({ label: m.name, value: getModelUniqId(m) }))
+    })
     .filter((group) => group.options.length > 0)
 
   const onOk = async () => {
@@ -93,7 +111,9 @@ const PopupContainer: React.FC<Props> = ({ base: _base, resolve }) => {
         chunkOverlap: values.chunkOverlap,
         threshold: values.threshold ?? undefined,
         rerankModel: values.rerankModel
-          ? providers.flatMap((p) => p.models).find((m) => getModelUniqId(m) === values.rerankModel)
+          ? [...providers.flatMap((p) => p.models), ...localRerankModels].find(
+              (m) => getModelUniqId(m) === values.rerankModel
+            )
           : undefined
       }
       updateKnowledgeBase(newBase)
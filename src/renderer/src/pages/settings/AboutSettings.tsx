@@ -1,18 +1,20 @@
 import { GithubOutlined } from '@ant-design/icons'
 import IndicatorLight from '@renderer/components/IndicatorLight'
 import { HStack } from '@renderer/components/Layout'
+import PerfPopup from '@renderer/components/Popups/PerfPopup'
 import { APP_NAME, AppLogo } from '@renderer/config/env'
 import { useTheme } from '@renderer/context/ThemeProvider'
 import { useMinappPopup } from '@renderer/hooks/useMinappPopup'
 import { useRuntime } from '@renderer/hooks/useRuntime'
 import { useSettings } from '@renderer/hooks/useSettings'
+import { isSettingLocked } from '@renderer/services/PolicyService'
 import { useAppDispatch } from '@renderer/store'
 import { setUpdateState } from '@renderer/store/runtime'
 import { ThemeMode } from '@renderer/types'
-import { compareVersions, runAsyncFunction } from '@renderer/utils'
+import { compareVersions, getErrorMessage, runAsyncFunction } from '@renderer/utils'
 import { Avatar, Button, Progress, Row, Switch, Tag, Tooltip } from 'antd'
 import { debounce } from 'lodash'
-import { Bug, FileCheck, Github, Globe, Mail, Rss } from 'lucide-react'
+import { Activity, Bug, FileCheck, Github, Globe, Mail, Rss, ShieldCheck } from 'lucide-react'
 import { FC, useEffect, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import Markdown from 'react-markdown'
@@ -24,6 +26,7 @@ import { SettingContainer, SettingDivider, SettingGroup, SettingRow, SettingTitl
 const AboutSettings: FC = () => {
   const [version, setVersion] = useState('')
   const [isPortable, setIsPortable] = useState(false)
+  const [verifying, setVerifying] = useState(false)
   const { t } = useTranslation()
   const { autoCheckUpdate, setAutoCheckUpdate, earlyAccess, setEarlyAccess } = useSettings()
   const { theme } = useTheme()
@@ -93,6 +96,42 @@ const AboutSettings: FC = () => {
     })
   }
 
+  const repairInstallation = async () => {
+    const result = await window.api.repairInstallation()
+    window.message.info(t(`settings.about.integrity.repair.${result}`))
+  }
+
+  // 校验安装文件，损坏时提示通过更新或重新下载修复
+  const verifyInstallation = async () => {
+    setVerifying(true)
+    try {
+      const report = await window.api.verifyInstallation()
+      if (report.ok) {
+        window.message.success(t('settings.about.integrity.ok', { count: report.checkedFiles }))
+        return
+      }
+      const content = t('settings.about.integrity.failed.content', {
+        missing: report.missing.length,
+        modified: report.modified.length
+      })
+      const version =
+        report.manifestVersion !== report.appVersion
+          ? ` ${t('settings.about.integrity.failed.version', { version: report.manifestVersion })}`
+          : ''
+      window.modal.confirm({
+        title: t('settings.about.integrity.failed.title'),
+        content: content + version,
+        okText: t('settings.about.integrity.repair'),
+        centered: true,
+        onOk: repairInstallation
+      })
+    } catch (error) {
+      window.message.error(`${t('settings.about.integrity.error')}: ${getErrorMessage(error)}`)
+    } finally {
+      setVerifying(false)
+    }
+  }
+
   const hasNewVersion = update?.info?.version && version ? compareVersions(update.info.version, version) > 0 : false
 
   useEffect(() => {
@@ -161,7 +200,11 @@ const AboutSettings: FC = () => {
             <SettingDivider />
             <SettingRow>
               <SettingRowTitle>{t('settings.general.auto_check_update.title')}</SettingRowTitle>
-              <Switch value={autoCheckUpdate} onChange={(v) => setAutoCheckUpdate(v)} />
+              <Switch
+                value={autoCheckUpdate}
+                disabled={isSettingLocked('autoUpdate')}
+                onChange={(v) => setAutoCheckUpdate(v)}
+              />
             </SettingRow>
             <SettingDivider />
             <SettingRow>
@@ -225,6 +268,16 @@ const AboutSettings: FC = () => {
           <Button onClick={showLicense}>{t('settings.about.license.button')}</Button>
         </SettingRow>
         <SettingDivider />
+        <SettingRow>
+          <SettingRowTitle>
+            <ShieldCheck size={18} />
+            {t('settings.about.integrity.title')}
+          </SettingRowTitle>
+          <Button onClick={verifyInstallation} loading={verifying}>
+            {t('settings.about.integrity.button')}
+          </Button>
+        </SettingRow>
+        <SettingDivider />
         <SettingRow>
           <SettingRowTitle>
             <Mail size={18} />
@@ -240,6 +293,14 @@ const AboutSettings: FC = () => {
           </SettingRowTitle>
           <Button onClick={debug}>{t('settings.about.debug.open')}</Button>
         </SettingRow>
+        <SettingDivider />
+        <SettingRow>
+          <SettingRowTitle>
+            <Activity size={18} />
+            {t('settings.about.perf.title')}
+          </SettingRowTitle>
+          <Button onClick={() => PerfPopup.show()}>{t('settings.about.debug.open')}</Button>
+        </SettingRow>
       </SettingGroup>
     </SettingContainer>
   )
@@ -25,6 +25,8 @@ const AssistantModelSettings: FC<Props> = ({ assistant, updateAssistant, updateA
   const [maxTokens, setMaxTokens] = useState(assistant?.settings?.maxTokens ?? 0)
   const [streamOutput, setStreamOutput] = useState(assistant?.settings?.streamOutput ?? true)
   const [toolUseMode, setToolUseMode] = useState(assistant?.settings?.toolUseMode ?? 'prompt')
+  const [contentFilter, setContentFilter] = useState(assistant?.settings?.contentFilter ?? 'default')
+  const [routing, setRouting] = useState(assistant?.settings?.routing ?? true)
   const [defaultModel, setDefaultModel] = useState(assistant?.defaultModel)
   const [topP, setTopP] = useState(assistant?.settings?.topP ?? 1)
   const [customParameters, setCustomParameters] = useState<AssistantSettingCustomParameters[]>(
@@ -151,6 +153,8 @@ const AssistantModelSettings: FC<Props> = ({ assistant, updateAssistant, updateA
     setTopP(1)
     setCustomParameters([])
     setToolUseMode('prompt')
+    setContentFilter('default')
+    setRouting(true)
     updateAssistantSettings({
       temperature: DEFAULT_TEMPERATURE,
       contextCount: DEFAULT_CONTEXTCOUNT,
@@ -159,7 +163,9 @@ const AssistantModelSettings: FC<Props> = ({ assistant, updateAssistant, updateA
       streamOutput: true,
       topP: 1,
       customParameters: [],
-      toolUseMode: 'prompt'
+      toolUseMode: 'prompt',
+      contentFilter: undefined,
+      routing: undefined
     })
   }
 
@@ -394,6 +400,33 @@ const AssistantModelSettings: FC<Props> = ({ assistant, updateAssistant, updateA
         </Select>
       </SettingRow>
       <Divider style={{ margin: '10px 0' }} />
+      <SettingRow style={{ minHeight: 30 }}>
+        <Label>{t('settings.content_filter.title')}</Label>
+        <Select
+          value={contentFilter}
+          style={{ width: 160 }}
+          onChange={(value) => {
+            setContentFilter(value)
+            updateAssistantSettings({ contentFilter: value === 'default' ? undefined : value })
+          }}
+          options={(['default', 'off', 'warn', 'redact', 'block'] as const).map((value) => ({
+            value,
+            label: t(`settings.content_filter.action.${value}`)
+          }))}
+        />
+      </SettingRow>
+      <Divider style={{ margin: '10px 0' }} />
+      <SettingRow style={{ minHeight: 30 }}>
+        <Label>{t('settings.routing.assistant')}</Label>
+        <Switch
+          checked={routing}
+          onChange={(checked) => {
+            setRouting(checked)
+            updateAssistantSettings({ routing: checked ? undefined : false })
+          }}
+        />
+      </SettingRow>
+      <Divider style={{ margin: '10px 0' }} />
       <SettingRow style={{ minHeight: 30 }}>
         <Label>{t('models.custom_parameters')}</Label>
         <Button icon={<PlusOutlined />} onClick={onAddCustomParameter}>
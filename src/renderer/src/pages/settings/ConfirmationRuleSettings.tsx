@@ -0,0 +1,111 @@
+import { DeleteOutlined, PlusOutlined } from '@ant-design/icons'
+import Logger from '@renderer/config/logger'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { useMCPServers } from '@renderer/hooks/useMCPServers'
+import { AgentActionKind, ConfirmationCondition, ConfirmationRule } from '@renderer/types'
+import { uuid } from '@renderer/utils'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Button, Input, Select, Switch } from 'antd'
+import { FC, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { SettingDescription, SettingDivider, SettingGroup, SettingRow, SettingSubtitle, SettingTitle } from '.'
+
+const CONDITIONS: Record<AgentActionKind, ConfirmationCondition[]> = {
+  file_write: ['always', 'outside_workspace'],
+  network: ['always', 'new_domain']
+}
+
+/**
+ * 智能体工具调用的确认规则，规则保存在主进程，按顺序匹配，匹配的调用需要用户批准后才执行
+ */
+const ConfirmationRuleSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const { mcpServers } = useMCPServers()
+  const [rules, setRules] = useState<ConfirmationRule[]>([])
+
+  useEffect(() => {
+    window.api.actionPolicy.getRules().then(setRules)
+  }, [])
+
+  const save = async (next: ConfirmationRule[]) => {
+    setRules(next)
+    try {
+      await window.api.actionPolicy.setRules(next)
+    } catch (error) {
+      Logger.error('[ConfirmationRuleSettings] Failed to save rules:', error)
+      window.message.error({ content: getErrorMessage(error), key: 'confirmation-rules' })
+    }
+  }
+
+  const updateRule = (id: string, changes: Partial<ConfirmationRule>) => {
+    save(rules.map((rule) => (rule.id === id ? { ...rule, ...changes } : rule)))
+  }
+
+  const addRule = () => {
+    save([...rules, { id: uuid(), name: '', enabled: true, kind: 'file_write', condition: 'always' }])
+  }
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.confirmation.title')}</SettingTitle>
+      <SettingDescription>{t('settings.confirmation.description')}</SettingDescription>
+      <SettingDivider />
+      <SettingRow>
+        <SettingSubtitle style={{ marginTop: 0 }}>{t('settings.confirmation.rules')}</SettingSubtitle>
+        <Button icon={<PlusOutlined />} onClick={addRule}>
+          {t('settings.confirmation.add')}
+        </Button>
+      </SettingRow>
+      {rules.map((rule) => (
+        <RuleRow key={rule.id}>
+          <Input
+            style={{ width: 180 }}
+            value={rule.name}
+            placeholder={t('settings.confirmation.name')}
+            onChange={(e) => updateRule(rule.id, { name: e.target.value })}
+          />
+          <Select
+            style={{ width: 120 }}
+            value={rule.kind}
+            onChange={(kind) => updateRule(rule.id, { kind, condition: 'always' })}
+            options={(['file_write', 'network'] as const).map((kind) => ({
+              value: kind,
+              label: t(`settings.confirmation.kind.${kind}`)
+            }))}
+          />
+          <Select
+            style={{ width: 160 }}
+            value={rule.condition}
+            onChange={(condition) => updateRule(rule.id, { condition })}
+            options={CONDITIONS[rule.kind].map((condition) => ({
+              value: condition,
+              label: t(`settings.confirmation.condition.${condition}`)
+            }))}
+          />
+          <Select
+            style={{ flex: 1 }}
+            mode="multiple"
+            value={rule.servers ?? []}
+            placeholder={t('settings.confirmation.all_servers')}
+            onChange={(servers) => updateRule(rule.id, { servers })}
+            options={mcpServers.map((server) => ({ value: server.name, label: server.name }))}
+          />
+          <Switch checked={rule.enabled} onChange={(enabled) => updateRule(rule.id, { enabled })} />
+          <Button icon={<DeleteOutlined />} danger onClick={() => save(rules.filter((item) => item.id !== rule.id))} />
+        </RuleRow>
+      ))}
+    </SettingGroup>
+  )
+}
+
+const RuleRow = styled.div`
+  display: flex;
+  align-items: center;
+  gap: 8px;
+  margin-top: 10px;
+`
+
+export default ConfirmationRuleSettings
@@ -0,0 +1,198 @@
+import { DeleteOutlined, PlusOutlined } from '@ant-design/icons'
+import SelectModelPopup from '@renderer/components/Popups/SelectModelPopup'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import db from '@renderer/databases'
+import { useAssistants } from '@renderer/hooks/useAssistant'
+import { useSettings } from '@renderer/hooks/useSettings'
+import { clearContentFilterLogs } from '@renderer/services/ContentFilterService'
+import { useAppDispatch } from '@renderer/store'
+import { setContentFilterSettings, SettingsState } from '@renderer/store/settings'
+import { uuid } from '@renderer/utils'
+import { CLASSIFIER_RULE, ContentFilterRule } from '@renderer/utils/contentFilter'
+import { Button, Empty, Input, Popconfirm, Select, Space, Switch } from 'antd'
+import dayjs from 'dayjs'
+import { useLiveQuery } from 'dexie-react-hooks'
+import { FC } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import {
+  SettingDescription,
+  SettingDivider,
+  SettingGroup,
+  SettingRow,
+  SettingRowTitle,
+  SettingSubtitle,
+  SettingTitle
+} from '.'
+
+const ContentFilterSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const dispatch = useAppDispatch()
+  const { contentFilter } = useSettings()
+  const { assistants } = useAssistants()
+  const logs = useLiveQuery(() => db.content_filter_logs.orderBy('createdAt').reverse().limit(50).toArray(), [])
+
+  const update = (settings: Partial<SettingsState['contentFilter']>) => dispatch(setContentFilterSettings(settings))
+
+  const updateRule = (id: string, changes: Partial<ContentFilterRule>) => {
+    update({ rules: contentFilter.rules.map((rule) => (rule.id === id ? { ...rule, ...changes } : rule)) })
+  }
+
+  const addRule = () => {
+    const rule: ContentFilterRule = { id: uuid(), name: '', type: 'keyword', pattern: '', enabled: true }
+    update({ rules: [...contentFilter.rules, rule] })
+  }
+
+  const onSelectClassifier = async () => {
+    const model = await SelectModelPopup.show({ model: contentFilter.classifierModel })
+    if (model) {
+      update({ classifierModel: model })
+    }
+  }
+
+  const getAssistantName = (id: string) => assistants.find((assistant) => assistant.id === id)?.name ?? id
+
+  const getRuleName = (rule: string) =>
+    rule === CLASSIFIER_RULE ? t('settings.content_filter.classifier.title') : rule
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.content_filter.title')}</SettingTitle>
+      <SettingDescription>{t('settings.content_filter.description')}</SettingDescription>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.content_filter.enabled')}</SettingRowTitle>
+        <Space>
+          <Select
+            style={{ width: 160 }}
+            value={contentFilter.action}
+            disabled={!contentFilter.enabled}
+            onChange={(action) => update({ action })}
+            options={(['warn', 'redact', 'block'] as const).map((action) => ({
+              value: action,
+              label: t(`settings.content_filter.action.${action}`)
+            }))}
+          />
+          <Switch checked={contentFilter.enabled} onChange={(enabled) => update({ enabled })} />
+        </Space>
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.content_filter.outbound')}</SettingRowTitle>
+        <Switch checked={contentFilter.outbound} onChange={(outbound) => update({ outbound })} />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.content_filter.inbound')}</SettingRowTitle>
+        <Switch checked={contentFilter.inbound} onChange={(inbound) => update({ inbound })} />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.content_filter.classifier.title')}</SettingRowTitle>
+        <Space>
+          <Button onClick={onSelectClassifier}>
+            {contentFilter.classifierModel?.name ?? t('settings.content_filter.classifier.select')}
+          </Button>
+          {contentFilter.classifierModel && (
+            <Button icon={<DeleteOutlined />} onClick={() => update({ classifierModel: undefined })} />
+          )}
+        </Space>
+      </SettingRow>
+      <SettingDescription>{t('settings.content_filter.classifier.description')}</SettingDescription>
+      <SettingDivider />
+      <SettingRow>
+        <SettingSubtitle style={{ marginTop: 0 }}>{t('settings.content_filter.rules.title')}</SettingSubtitle>
+        <Button icon={<PlusOutlined />} onClick={addRule}>
+          {t('settings.content_filter.rules.add')}
+        </Button>
+      </SettingRow>
+      {contentFilter.rules.map((rule) => (
+        <RuleRow key={rule.id}>
+          <Input
+            style={{ width: 140 }}
+            value={rule.name}
+            placeholder={t('settings.content_filter.rules.name')}
+            onChange={(e) => updateRule(rule.id, { name: e.target.value })}
+          />
+          <Select
+            style={{ width: 110 }}
+            value={rule.type}
+            onChange={(type) => updateRule(rule.id, { type })}
+            options={(['keyword', 'regex'] as const).map((type) => ({
+              value: type,
+              label: t(`settings.content_filter.rules.type.${type}`)
+            }))}
+          />
+          <Input
+            style={{ flex: 1 }}
+            value={rule.pattern}
+            spellCheck={false}
+            placeholder={t(`settings.content_filter.rules.placeholder.${rule.type}`)}
+            onChange={(e) => updateRule(rule.id, { pattern: e.target.value })}
+          />
+          <Switch checked={rule.enabled} onChange={(enabled) => updateRule(rule.id, { enabled })} />
+          <Button
+            icon={<DeleteOutlined />}
+            danger
+            onClick={() => update({ rules: contentFilter.rules.filter((item) => item.id !== rule.id) })}
+          />
+        </RuleRow>
+      ))}
+      <SettingDivider />
+      <SettingRow>
+        <SettingSubtitle style={{ marginTop: 0 }}>{t('settings.content_filter.log.title')}</SettingSubtitle>
+        <Popconfirm
+          title={t('settings.content_filter.log.clear_confirm')}
+          onConfirm={clearContentFilterLogs}
+          okText={t('common.confirm')}
+          cancelText={t('common.cancel')}>
+          <Button disabled={!logs?.length}>{t('settings.content_filter.log.clear')}</Button>
+        </Popconfirm>
+      </SettingRow>
+      {logs?.length ? (
+        <LogList>
+          {logs.map((log) => (
+            <LogItem key={log.id}>
+              <span>{dayjs(log.createdAt).format('MM/DD HH:mm:ss')}</span>
+              <span>{t(`settings.content_filter.log.direction.${log.direction}`)}</span>
+              <span>{t(`settings.content_filter.action.${log.action}`)}</span>
+              <span>{getAssistantName(log.assistantId)}</span>
+              <span>{log.hits.map((hit) => `${getRuleName(hit.rule)} × ${hit.count}`).join(', ')}</span>
+            </LogItem>
+          ))}
+        </LogList>
+      ) : (
+        <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={t('settings.content_filter.log.empty')} />
+      )}
+    </SettingGroup>
+  )
+}
+
+const RuleRow = styled.div`
+  display: flex;
+  align-items: center;
+  gap: 8px;
+  margin-top: 10px;
+`
+
+const LogList = styled.div`
+  display: flex;
+  flex-direction: column;
+  max-height: 240px;
+  overflow-y: auto;
+  margin-top: 10px;
+`
+
+const LogItem = styled.div`
+  display: grid;
+  grid-template-columns: 110px 70px 90px 120px 1fr;
+  gap: 8px;
+  padding: 4px 0;
+  font-size: 12px;
+  color: var(--color-text-2);
+  border-bottom: 0.5px solid var(--color-border);
+`
+
+export default ContentFilterSettings
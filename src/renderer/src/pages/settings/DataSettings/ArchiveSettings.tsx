@@ -0,0 +1,128 @@
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { useAssistants } from '@renderer/hooks/useAssistant'
+import { useSettings } from '@renderer/hooks/useSettings'
+import { autoArchiveTopics, listArchivedTopics, unarchiveTopic } from '@renderer/services/ArchiveService'
+import { useAppDispatch } from '@renderer/store'
+import { setArchiveAfterDays } from '@renderer/store/settings'
+import { ArchivedTopic } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Button, Empty, Select } from 'antd'
+import dayjs from 'dayjs'
+import { FC, useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { SettingDivider, SettingGroup, SettingHelpText, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+const RETENTION_OPTIONS = [0, 30, 90, 180, 365]
+
+const ArchiveSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const dispatch = useAppDispatch()
+  const { archiveAfterDays } = useSettings()
+  const { assistants } = useAssistants()
+  const [topics, setTopics] = useState<ArchivedTopic[]>([])
+  const [restoring, setRestoring] = useState<string | null>(null)
+
+  const refresh = useCallback(() => listArchivedTopics().then(setTopics), [])
+
+  useEffect(() => {
+    refresh()
+  }, [refresh])
+
+  const onChangeRetention = async (days: number) => {
+    dispatch(setArchiveAfterDays(days))
+    if (days > 0 && (await autoArchiveTopics()) > 0) {
+      refresh()
+    }
+  }
+
+  const onRestore = async (topic: ArchivedTopic) => {
+    setRestoring(topic.topicId)
+    try {
+      await unarchiveTopic(topic.topicId)
+      window.message.success({ content: t('settings.data.archive.restored'), key: 'archive-restore' })
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'archive-restore' })
+    } finally {
+      setRestoring(null)
+      refresh()
+    }
+  }
+
+  const getAssistantName = (id: string) => assistants.find((assistant) => assistant.id === id)?.name ?? '-'
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.data.archive.title')}</SettingTitle>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.archive.retention')}</SettingRowTitle>
+        <Select
+          style={{ width: 160 }}
+          value={archiveAfterDays}
+          onChange={onChangeRetention}
+          options={RETENTION_OPTIONS.map((days) => ({
+            value: days,
+            label: days ? t('settings.data.archive.days', { count: days }) : t('settings.data.archive.off')
+          }))}
+        />
+      </SettingRow>
+      <SettingRow>
+        <SettingHelpText>{t('settings.data.archive.retention_help')}</SettingHelpText>
+      </SettingRow>
+      <SettingDivider />
+      <SettingRowTitle>{t('settings.data.archive.list')}</SettingRowTitle>
+      {topics.length ? (
+        <TopicList>
+          {topics.map((topic) => (
+            <TopicItem key={topic.topicId}>
+              <TopicName title={topic.name}>{topic.name}</TopicName>
+              <span>{getAssistantName(topic.assistantId)}</span>
+              <span>{t('settings.data.archive.messages', { count: topic.messageCount })}</span>
+              <span>{dayjs(topic.updatedAt).format('YYYY/MM/DD')}</span>
+              <Button
+                size="small"
+                loading={restoring === topic.topicId}
+                disabled={!!restoring}
+                onClick={() => onRestore(topic)}>
+                {t('settings.data.archive.restore')}
+              </Button>
+            </TopicItem>
+          ))}
+        </TopicList>
+      ) : (
+        <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={t('settings.data.archive.empty')} />
+      )}
+    </SettingGroup>
+  )
+}
+
+const TopicList = styled.div`
+  display: flex;
+  flex-direction: column;
+  max-height: 400px;
+  overflow-y: auto;
+  margin-top: 10px;
+`
+
+const TopicItem = styled.div`
+  display: grid;
+  grid-template-columns: 1fr 120px 80px 90px auto;
+  align-items: center;
+  gap: 8px;
+  padding: 6px 0;
+  font-size: 12px;
+  color: var(--color-text-2);
+  border-bottom: 0.5px solid var(--color-border);
+`
+
+const TopicName = styled.span`
+  color: var(--color-text-1);
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+`
+
+export default ArchiveSettings
@@ -0,0 +1,107 @@
+import { HStack } from '@renderer/components/Layout'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { AuditCategory, AuditLogEntry } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Button, Empty, Select, Tag } from 'antd'
+import dayjs from 'dayjs'
+import { FC, useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { SettingDivider, SettingGroup, SettingHelpText, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+const CATEGORIES: AuditCategory[] = ['file_write', 'process_spawn', 'credential_read', 'setting_change']
+
+const AuditLogSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const [category, setCategory] = useState<AuditCategory | 'all'>('all')
+  const [entries, setEntries] = useState<AuditLogEntry[]>([])
+
+  const getFilter = useCallback(() => (category === 'all' ? {} : { category }), [category])
+
+  useEffect(() => {
+    window.api.audit.getLog({ ...getFilter(), limit: 200 }).then(setEntries)
+  }, [getFilter])
+
+  const onExport = async () => {
+    try {
+      const data = await window.api.audit.export(getFilter())
+      await window.api.file.save(`audit-${dayjs().format('YYYYMMDDHHmm')}.jsonl`, data)
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'audit-export' })
+    }
+  }
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.data.audit.title')}</SettingTitle>
+      <SettingDivider />
+      <SettingRow>
+        <SettingHelpText>{t('settings.data.audit.help')}</SettingHelpText>
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.audit.category')}</SettingRowTitle>
+        <HStack gap="8px" alignItems="center">
+          <Select
+            style={{ width: 160 }}
+            value={category}
+            onChange={setCategory}
+            options={[
+              { value: 'all', label: t('settings.data.audit.all') },
+              ...CATEGORIES.map((value) => ({ value, label: t(`settings.data.audit.categories.${value}`) }))
+            ]}
+          />
+          <Button onClick={onExport}>{t('settings.data.audit.export')}</Button>
+        </HStack>
+      </SettingRow>
+      {entries.length ? (
+        <EntryList>
+          {entries.map((entry, index) => (
+            <EntryItem key={`${entry.time}-${index}`}>
+              <span>{dayjs(entry.time).format('MM/DD HH:mm:ss')}</span>
+              <EntryChannel title={entry.args}>{entry.channel}</EntryChannel>
+              <span title={entry.caller.url}>{entry.caller.type}</span>
+              {entry.allowed ? (
+                <Tag>{t(`settings.data.audit.categories.${entry.category}`)}</Tag>
+              ) : (
+                <Tag color="red">{t('settings.data.audit.denied')}</Tag>
+              )}
+            </EntryItem>
+          ))}
+        </EntryList>
+      ) : (
+        <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={t('settings.data.audit.empty')} />
+      )}
+    </SettingGroup>
+  )
+}
+
+const EntryList = styled.div`
+  display: flex;
+  flex-direction: column;
+  max-height: 400px;
+  overflow-y: auto;
+  margin-top: 10px;
+`
+
+const EntryItem = styled.div`
+  display: grid;
+  grid-template-columns: 110px 1fr 70px auto;
+  align-items: center;
+  gap: 8px;
+  padding: 6px 0;
+  font-size: 12px;
+  color: var(--color-text-2);
+  border-bottom: 0.5px solid var(--color-border);
+`
+
+const EntryChannel = styled.span`
+  color: var(--color-text-1);
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+`
+
+export default AuditLogSettings
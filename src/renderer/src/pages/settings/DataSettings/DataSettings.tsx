@@ -14,13 +14,26 @@ import BackupPopup from '@renderer/components/Popups/BackupPopup'
 import RestorePopup from '@renderer/components/Popups/RestorePopup'
 import { useTheme } from '@renderer/context/ThemeProvider'
 import { useKnowledgeFiles } from '@renderer/hooks/useKnowledgeFiles'
+import { useSettings } from '@renderer/hooks/useSettings'
 import { reset } from '@renderer/services/BackupService'
+import { clearLLMCache } from '@renderer/services/ResponseCacheService'
 import store, { useAppDispatch } from '@renderer/store'
-import { setSkipBackupFile as _setSkipBackupFile } from '@renderer/store/settings'
+import { setLLMCacheSettings, setSkipBackupFile as _setSkipBackupFile } from '@renderer/store/settings'
 import { AppInfo } from '@renderer/types'
 import { formatFileSize } from '@renderer/utils'
 import { Button, Progress, Switch, Typography } from 'antd'
-import { FileText, FolderCog, FolderInput, Sparkle } from 'lucide-react'
+import {
+  Archive,
+  BellRing,
+  FileCode,
+  FileText,
+  FolderCog,
+  FolderInput,
+  GraduationCap,
+  Package,
+  ShieldCheck,
+  Sparkle
+} from 'lucide-react'
 import { FC, useEffect, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import styled from 'styled-components'
@@ -35,14 +48,22 @@ import {
   SettingTitle
 } from '..'
 import AgentsSubscribeUrlSettings from './AgentsSubscribeUrlSettings'
+import ArchiveSettings from './ArchiveSettings'
+import AuditLogSettings from './AuditLogSettings'
+import DatabaseMaintenanceSettings from './DatabaseMaintenanceSettings'
 import ExportMenuOptions from './ExportMenuSettings'
 import JoplinSettings from './JoplinSettings'
+import LogRedactionSettings from './LogRedactionSettings'
 import MarkdownExportSettings from './MarkdownExportSettings'
 import NotionSettings from './NotionSettings'
 import NutstoreSettings from './NutstoreSettings'
 import ObsidianSettings from './ObsidianSettings'
+import OutboundIntegrationSettings from './OutboundIntegrationSettings'
 import SiyuanSettings from './SiyuanSettings'
+import TemplateSettings from './TemplateSettings'
+import TrainingDataSettings from './TrainingDataSettings'
 import WebDavSettings from './WebDavSettings'
+import WorkspaceSettings from './WorkspaceSettings'
 import YuqueSettings from './YuqueSettings'
 
 const DataSettings: FC = () => {
@@ -52,6 +73,7 @@ const DataSettings: FC = () => {
   const { size, removeAllFiles } = useKnowledgeFiles()
   const { theme } = useTheme()
   const [menu, setMenu] = useState<string>('data')
+  const { llmCache } = useSettings()
 
   const _skipBackupFile = store.getState().settings.skipBackupFile
   const [skipBackupFile, setSkipBackupFile] = useState<boolean>(_skipBackupFile)
@@ -84,6 +106,9 @@ const DataSettings: FC = () => {
   const menuItems = [
     { key: 'divider_0', isDivider: true, text: t('settings.data.divider.basic') },
     { key: 'data', title: 'settings.data.data.title', icon: <FolderCog size={16} /> },
+    { key: 'archive', title: 'settings.data.archive.title', icon: <Archive size={16} /> },
+    { key: 'audit', title: 'settings.data.audit.title', icon: <ShieldCheck size={16} /> },
+    { key: 'workspace', title: 'settings.data.workspace.title', icon: <Package size={16} /> },
     { key: 'divider_1', isDivider: true, text: t('settings.data.divider.cloud_storage') },
     { key: 'webdav', title: 'settings.data.webdav.title', icon: <CloudSyncOutlined style={{ fontSize: 16 }} /> },
     { key: 'nutstore', title: 'settings.data.nutstore.title', icon: <NutstoreIcon /> },
@@ -98,6 +123,8 @@ const DataSettings: FC = () => {
       title: 'settings.data.markdown_export.title',
       icon: <FileText size={16} />
     },
+    { key: 'templates', title: 'settings.data.templates.title', icon: <FileCode size={16} /> },
+    { key: 'training', title: 'settings.data.training.title', icon: <GraduationCap size={16} /> },
 
     { key: 'divider_3', isDivider: true, text: t('settings.data.divider.third_party') },
     { key: 'notion', title: 'settings.data.notion.title', icon: <i className="iconfont icon-notion" /> },
@@ -121,6 +148,7 @@ const DataSettings: FC = () => {
       title: 'settings.data.siyuan.title',
       icon: <SiyuanIcon />
     },
+    { key: 'outbound', title: 'settings.data.outbound.title', icon: <BellRing size={16} /> },
     {
       key: 'agentssubscribe_url',
       title: 'agents.settings.title',
@@ -155,6 +183,7 @@ const DataSettings: FC = () => {
       onOk: async () => {
         try {
           await window.api.clearCache()
+          await clearLLMCache()
           await window.api.getCacheSize().then(setCacheSize)
           window.message.success(t('settings.data.clear_cache.success'))
         } catch (error) {
@@ -164,6 +193,11 @@ const DataSettings: FC = () => {
     })
   }
 
+  const handleClearLLMCache = async () => {
+    await clearLLMCache()
+    window.message.success(t('settings.data.llm_cache.cleared'))
+  }
+
   const handleRemoveAllFiles = () => {
     window.modal.confirm({
       centered: true,
@@ -544,6 +578,35 @@ const DataSettings: FC = () => {
                 </HStack>
               </SettingRow>
               <SettingDivider />
+              <SettingRow>
+                <SettingRowTitle>{t('settings.data.llm_cache.title')}</SettingRowTitle>
+                <HStack alignItems="center" gap="10px">
+                  <Switch
+                    checked={llmCache.enabled}
+                    onChange={(enabled) => dispatch(setLLMCacheSettings({ enabled }))}
+                  />
+                  <Button onClick={handleClearLLMCache}>{t('settings.data.llm_cache.clear')}</Button>
+                </HStack>
+              </SettingRow>
+              <SettingRow>
+                <SettingHelpText>{t('settings.data.llm_cache.tip')}</SettingHelpText>
+              </SettingRow>
+              {llmCache.enabled && (
+                <>
+                  <SettingDivider />
+                  <SettingRow>
+                    <SettingRowTitle>{t('settings.data.llm_cache.include_sampled')}</SettingRowTitle>
+                    <Switch
+                      checked={llmCache.includeSampled}
+                      onChange={(includeSampled) => dispatch(setLLMCacheSettings({ includeSampled }))}
+                    />
+                  </SettingRow>
+                  <SettingRow>
+                    <SettingHelpText>{t('settings.data.llm_cache.include_sampled_tip')}</SettingHelpText>
+                  </SettingRow>
+                </>
+              )}
+              <SettingDivider />
               <SettingRow>
                 <SettingRowTitle>
                   {t('settings.data.clear_cache.title')}
@@ -556,17 +619,25 @@ const DataSettings: FC = () => {
                 </HStack>
               </SettingRow>
             </SettingGroup>
+            <DatabaseMaintenanceSettings />
+            <LogRedactionSettings />
           </>
         )}
+        {menu === 'archive' && <ArchiveSettings />}
+        {menu === 'audit' && <AuditLogSettings />}
+        {menu === 'workspace' && <WorkspaceSettings />}
         {menu === 'webdav' && <WebDavSettings />}
         {menu === 'nutstore' && <NutstoreSettings />}
         {menu === 'export_menu' && <ExportMenuOptions />}
         {menu === 'markdown_export' && <MarkdownExportSettings />}
+        {menu === 'templates' && <TemplateSettings />}
+        {menu === 'training' && <TrainingDataSettings />}
         {menu === 'notion' && <NotionSettings />}
         {menu === 'yuque' && <YuqueSettings />}
         {menu === 'joplin' && <JoplinSettings />}
         {menu === 'obsidian' && <ObsidianSettings />}
         {menu === 'siyuan' && <SiyuanSettings />}
+        {menu === 'outbound' && <OutboundIntegrationSettings />}
         {menu === 'agentssubscribe_url' && <AgentsSubscribeUrlSettings />}
       </SettingContainer>
     </Container>
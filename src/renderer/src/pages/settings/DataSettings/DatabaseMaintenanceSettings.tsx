@@ -0,0 +1,149 @@
+import { HStack } from '@renderer/components/Layout'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { useSettings } from '@renderer/hooks/useSettings'
+import {
+  checkDatabaseIntegrity,
+  DatabaseHealthReport,
+  DatabaseMaintenanceTask,
+  getDatabaseHealth,
+  rebuildSearchIndex,
+  vacuumDatabase
+} from '@renderer/services/DatabaseMaintenanceService'
+import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
+import { useAppDispatch } from '@renderer/store'
+import { setAutoDatabaseMaintenance } from '@renderer/store/settings'
+import { formatFileSize } from '@renderer/utils/file'
+import { Button, Progress, Switch } from 'antd'
+import dayjs from 'dayjs'
+import { FC, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { SettingDivider, SettingGroup, SettingHelpText, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+const DatabaseMaintenanceSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const dispatch = useAppDispatch()
+  const { autoDatabaseMaintenance } = useSettings()
+  const [health, setHealth] = useState<DatabaseHealthReport>()
+  const [task, setTask] = useState<DatabaseMaintenanceTask | null>(null)
+  const [progress, setProgress] = useState(0)
+
+  useEffect(() => {
+    getDatabaseHealth().then(setHealth)
+    return EventEmitter.on(
+      EVENT_NAMES.DATABASE_MAINTENANCE_PROGRESS,
+      ({ completed, total }: { completed: number; total: number }) => {
+        setProgress(total ? Math.round((completed / total) * 100) : 100)
+      }
+    )
+  }, [])
+
+  const run = async (name: DatabaseMaintenanceTask, action: () => Promise<unknown>) => {
+    setTask(name)
+    setProgress(0)
+    try {
+      await action()
+      window.message.success({ content: t(`settings.data.database.${name}.success`), key: 'database-maintenance' })
+    } catch (error: any) {
+      window.message.error({ content: error.message, key: 'database-maintenance' })
+    } finally {
+      setTask(null)
+      setHealth(await getDatabaseHealth())
+    }
+  }
+
+  // 清理会删除孤立的话题和消息块，先检查一遍再让用户确认
+  const onVacuum = async () => {
+    setTask('vacuum')
+    try {
+      const report = await checkDatabaseIntegrity()
+      setHealth(report)
+      window.modal.confirm({
+        centered: true,
+        title: t('settings.data.database.vacuum.confirm', {
+          orphanTopics: report.orphanTopics,
+          orphanBlocks: report.orphanBlocks,
+          missingBlocks: report.missingBlocks
+        }),
+        onOk: () => run('vacuum', vacuumDatabase)
+      })
+    } catch (error: any) {
+      window.message.error({ content: error.message, key: 'database-maintenance' })
+    } finally {
+      setTask(null)
+    }
+  }
+
+  const issues = health ? health.orphanBlocks + health.missingBlocks + health.orphanTopics : 0
+  const checkedAt = health ? dayjs(health.checkedAt).format('YYYY/MM/DD HH:mm') : ''
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.data.database.title')}</SettingTitle>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.database.maintenance')}</SettingRowTitle>
+        <HStack gap="5px">
+          {(['check', 'vacuum', 'rebuild'] as const).map((name) => (
+            <Button
+              key={name}
+              loading={task === name}
+              disabled={!!task && task !== name}
+              onClick={() =>
+                name === 'vacuum'
+                  ? onVacuum()
+                  : run(name, { check: checkDatabaseIntegrity, rebuild: rebuildSearchIndex }[name])
+              }>
+              {t(`settings.data.database.${name}.button`)}
+            </Button>
+          ))}
+        </HStack>
+      </SettingRow>
+      {task && <Progress percent={progress} size="small" />}
+      {health && (
+        <Report>
+          <span>{t('settings.data.database.checked_at', { time: checkedAt })}</span>
+          {health.storageUsage !== undefined && (
+            <span>{t('settings.data.database.storage', { size: formatFileSize(health.storageUsage) })}</span>
+          )}
+          <span>
+            {t('settings.data.database.records', {
+              topics: health.tables.topics ?? 0,
+              blocks: health.tables.message_blocks ?? 0
+            })}
+          </span>
+          <span style={{ color: issues ? 'var(--color-warning)' : undefined }}>
+            {issues
+              ? t('settings.data.database.issues', {
+                  orphanBlocks: health.orphanBlocks,
+                  missingBlocks: health.missingBlocks,
+                  orphanTopics: health.orphanTopics
+                })
+              : t('settings.data.database.healthy')}
+          </span>
+        </Report>
+      )}
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.database.auto')}</SettingRowTitle>
+        <Switch checked={autoDatabaseMaintenance} onChange={(value) => dispatch(setAutoDatabaseMaintenance(value))} />
+      </SettingRow>
+      <SettingRow>
+        <SettingHelpText>{t('settings.data.database.auto_help')}</SettingHelpText>
+      </SettingRow>
+    </SettingGroup>
+  )
+}
+
+const Report = styled.div`
+  display: flex;
+  flex-direction: column;
+  gap: 4px;
+  margin-top: 10px;
+  font-size: 12px;
+  color: var(--color-text-2);
+`
+
+export default DatabaseMaintenanceSettings
@@ -0,0 +1,128 @@
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Button, Input } from 'antd'
+import { FC, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { SettingDivider, SettingGroup, SettingHelpText, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+/**
+ * 日志脱敏规则、脱敏预览和导出脱敏后的支持包
+ */
+const LogRedactionSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const [patterns, setPatterns] = useState('')
+  const [sample, setSample] = useState('')
+  const [preview, setPreview] = useState<{ text: string; hits: Record<string, number> } | null>(null)
+  const [exporting, setExporting] = useState(false)
+
+  useEffect(() => {
+    window.api.log.getRedactionPatterns().then((items) => setPatterns(items.join('\n')))
+  }, [])
+
+  const savePatterns = async () => {
+    const items = patterns
+      .split('\n')
+      .map((line) => line.trim())
+      .filter(Boolean)
+    try {
+      await window.api.log.setRedactionPatterns(items)
+      setPatterns(items.join('\n'))
+      if (sample) setPreview(await window.api.log.previewRedaction(sample))
+    } catch (error) {
+      window.message.error({ content: getErrorMessage(error), key: 'log-redaction' })
+    }
+  }
+
+  const onPreview = async () => {
+    setPreview(await window.api.log.previewRedaction(sample))
+  }
+
+  const onExport = async () => {
+    setExporting(true)
+    try {
+      const filePath = await window.api.log.exportSupportBundle()
+      if (filePath) {
+        window.message.success({ content: t('settings.data.log_redaction.exported'), key: 'support-bundle' })
+      }
+    } catch (error) {
+      window.message.error({ content: getErrorMessage(error), key: 'support-bundle' })
+    } finally {
+      setExporting(false)
+    }
+  }
+
+  const hits = Object.entries(preview?.hits ?? {})
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.data.log_redaction.title')}</SettingTitle>
+      <SettingDivider />
+      <SettingRow>
+        <SettingHelpText>{t('settings.data.log_redaction.help')}</SettingHelpText>
+      </SettingRow>
+      <Input.TextArea
+        style={{ marginTop: 10 }}
+        value={patterns}
+        autoSize={{ minRows: 2, maxRows: 6 }}
+        placeholder={t('settings.data.log_redaction.patterns_placeholder')}
+        onChange={(e) => setPatterns(e.target.value)}
+        onBlur={savePatterns}
+      />
+      <SettingDivider />
+      <SettingRowTitle>{t('settings.data.log_redaction.preview')}</SettingRowTitle>
+      <PreviewRow>
+        <Input.TextArea
+          value={sample}
+          autoSize={{ minRows: 2, maxRows: 6 }}
+          placeholder={t('settings.data.log_redaction.preview_placeholder')}
+          onChange={(e) => setSample(e.target.value)}
+        />
+        <Button disabled={!sample} onClick={onPreview}>
+          {t('settings.data.log_redaction.preview')}
+        </Button>
+      </PreviewRow>
+      {preview && (
+        <PreviewResult>
+          <pre>{preview.text}</pre>
+          <SettingHelpText>
+            {hits.length
+              ? hits.map(([name, count]) => `${name} × ${count}`).join(', ')
+              : t('settings.data.log_redaction.no_hits')}
+          </SettingHelpText>
+        </PreviewResult>
+      )}
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.log_redaction.support_bundle')}</SettingRowTitle>
+        <Button loading={exporting} onClick={onExport}>
+          {t('settings.data.log_redaction.export')}
+        </Button>
+      </SettingRow>
+    </SettingGroup>
+  )
+}
+
+const PreviewRow = styled.div`
+  display: flex;
+  gap: 10px;
+  margin-top: 10px;
+`
+
+const PreviewResult = styled.div`
+  margin-top: 10px;
+
+  pre {
+    margin: 0 0 6px;
+    padding: 8px;
+    font-size: 12px;
+    white-space: pre-wrap;
+    word-break: break-all;
+    border-radius: 6px;
+    background-color: var(--color-background-soft);
+  }
+`
+
+export default LogRedactionSettings
@@ -0,0 +1,180 @@
+import { HStack } from '@renderer/components/Layout'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { OutboundEvent, OutboundIntegration, OutboundIntegrationType } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Button, Empty, Input, Modal, Select, Switch, Tag } from 'antd'
+import { omit } from 'lodash'
+import { FC, useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { SettingDivider, SettingGroup, SettingHelpText, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+const TYPES: OutboundIntegrationType[] = ['slack', 'discord', 'telegram', 'http']
+const EVENTS: OutboundEvent[] = ['agent.finished', 'job.failed', 'backup.completed']
+
+type Draft = Omit<OutboundIntegration, 'id' | 'hasSecret'> & { id?: string; secret: string }
+
+const newDraft = (): Draft => ({ name: '', type: 'slack', events: ['agent.finished'], enabled: true, secret: '' })
+
+const OutboundIntegrationSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const [integrations, setIntegrations] = useState<OutboundIntegration[]>([])
+  const [draft, setDraft] = useState<Draft | null>(null)
+
+  const load = useCallback(() => {
+    window.api.outbound.list().then(setIntegrations)
+  }, [])
+
+  useEffect(load, [load])
+
+  const onSave = async () => {
+    if (!draft) return
+    const { secret, ...integration } = draft
+    try {
+      await window.api.outbound.save(integration, secret.trim() || undefined)
+      setDraft(null)
+      load()
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'outbound-save' })
+    }
+  }
+
+  const onToggle = async (integration: OutboundIntegration, enabled: boolean) => {
+    await window.api.outbound.save({ ...omit(integration, 'hasSecret'), enabled })
+    load()
+  }
+
+  const onTest = async (id: string) => {
+    try {
+      await window.api.outbound.test(id)
+      window.message.success({ content: t('settings.data.outbound.test_success'), key: 'outbound-test' })
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'outbound-test' })
+    }
+  }
+
+  const onRemove = (integration: OutboundIntegration) => {
+    window.modal.confirm({
+      title: t('settings.data.outbound.remove_confirm', { name: integration.name }),
+      centered: true,
+      onOk: async () => {
+        await window.api.outbound.remove(integration.id)
+        load()
+      }
+    })
+  }
+
+  const secretLabel = draft?.type === 'telegram' ? 'settings.data.outbound.token' : 'settings.data.outbound.url'
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>
+        {t('settings.data.outbound.title')}
+        <Button size="small" onClick={() => setDraft(newDraft())}>
+          {t('settings.data.outbound.add')}
+        </Button>
+      </SettingTitle>
+      <SettingDivider />
+      <SettingRow>
+        <SettingHelpText>{t('settings.data.outbound.help')}</SettingHelpText>
+      </SettingRow>
+      {integrations.length ? (
+        integrations.map((integration) => (
+          <div key={integration.id}>
+            <SettingDivider />
+            <SettingRow>
+              <SettingRowTitle>
+                {integration.name}
+                <Tag style={{ marginLeft: 8 }}>{t(`settings.data.outbound.types.${integration.type}`)}</Tag>
+                {!integration.hasSecret && <Tag color="orange">{t('settings.data.outbound.no_secret')}</Tag>}
+              </SettingRowTitle>
+              <HStack gap="8px" alignItems="center">
+                <Switch size="small" checked={integration.enabled} onChange={(value) => onToggle(integration, value)} />
+                <Button size="small" onClick={() => onTest(integration.id)}>
+                  {t('settings.data.outbound.test')}
+                </Button>
+                <Button size="small" onClick={() => setDraft({ ...omit(integration, 'hasSecret'), secret: '' })}>
+                  {t('common.edit')}
+                </Button>
+                <Button size="small" danger onClick={() => onRemove(integration)}>
+                  {t('common.delete')}
+                </Button>
+              </HStack>
+            </SettingRow>
+            <EventList>
+              {integration.events.map((event) => (
+                <Tag key={event}>{t(`settings.data.outbound.events.${event}`)}</Tag>
+              ))}
+            </EventList>
+          </div>
+        ))
+      ) : (
+        <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={t('settings.data.outbound.empty')} />
+      )}
+      <Modal
+        open={!!draft}
+        title={t(draft?.id ? 'settings.data.outbound.edit' : 'settings.data.outbound.add')}
+        centered
+        onOk={onSave}
+        onCancel={() => setDraft(null)}>
+        {draft && (
+          <Fields>
+            <Input
+              placeholder={t('settings.data.outbound.name')}
+              value={draft.name}
+              onChange={(e) => setDraft({ ...draft, name: e.target.value })}
+            />
+            <Select
+              value={draft.type}
+              onChange={(type) => setDraft({ ...draft, type })}
+              options={TYPES.map((value) => ({ value, label: t(`settings.data.outbound.types.${value}`) }))}
+            />
+            <Input.Password
+              placeholder={draft.id ? t('settings.data.outbound.secret_unchanged') : t(secretLabel)}
+              value={draft.secret}
+              onChange={(e) => setDraft({ ...draft, secret: e.target.value })}
+            />
+            {draft.type === 'telegram' && (
+              <Input
+                placeholder={t('settings.data.outbound.chat_id')}
+                value={draft.chatId}
+                onChange={(e) => setDraft({ ...draft, chatId: e.target.value })}
+              />
+            )}
+            <Select
+              mode="multiple"
+              placeholder={t('settings.data.outbound.events.title')}
+              value={draft.events}
+              onChange={(events) => setDraft({ ...draft, events })}
+              options={EVENTS.map((value) => ({ value, label: t(`settings.data.outbound.events.${value}`) }))}
+            />
+            <Input.TextArea
+              rows={3}
+              placeholder={t('settings.data.outbound.template_placeholder')}
+              value={draft.template}
+              onChange={(e) => setDraft({ ...draft, template: e.target.value || undefined })}
+            />
+            <SettingHelpText>{t('settings.data.outbound.template_help')}</SettingHelpText>
+          </Fields>
+        )}
+      </Modal>
+    </SettingGroup>
+  )
+}
+
+const EventList = styled.div`
+  display: flex;
+  flex-wrap: wrap;
+  gap: 4px;
+  margin-top: 6px;
+`
+
+const Fields = styled.div`
+  display: flex;
+  flex-direction: column;
+  gap: 10px;
+`
+
+export default OutboundIntegrationSettings
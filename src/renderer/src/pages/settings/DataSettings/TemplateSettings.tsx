@@ -0,0 +1,176 @@
+import { HStack } from '@renderer/components/Layout'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { UserTemplate } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Button, Empty, Input, Modal } from 'antd'
+import { FC, useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { SettingDivider, SettingGroup, SettingHelpText, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+type Draft = Pick<UserTemplate, 'name' | 'content' | 'description'> & { id?: string }
+
+const newDraft = (): Draft => ({ name: '', content: '' })
+
+// 预览时使用的示例上下文，字段与导出话题时一致
+const PREVIEW_CONTEXT = {
+  topic: { id: 'preview', name: 'Example topic', createdAt: '2025-01-01T09:00:00Z', updatedAt: '2025-01-01T09:05:00Z' },
+  messages: [
+    { id: '1', role: 'user', content: 'Hello', createdAt: '2025-01-01T09:00:00Z' },
+    { id: '2', role: 'assistant', content: 'Hi! How can I help?', model: 'gpt-4o', createdAt: '2025-01-01T09:00:05Z' }
+  ],
+  exportedAt: '2025-01-01T09:10:00Z'
+}
+
+const TemplateSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const [templates, setTemplates] = useState<UserTemplate[]>([])
+  const [draft, setDraft] = useState<Draft | null>(null)
+  const [preview, setPreview] = useState<string | null>(null)
+
+  const load = useCallback(() => {
+    window.api.template.list().then(setTemplates)
+  }, [])
+
+  useEffect(load, [load])
+
+  const onSave = async () => {
+    if (!draft) return
+    try {
+      await window.api.template.save(draft)
+      setDraft(null)
+      setPreview(null)
+      load()
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'template-save' })
+    }
+  }
+
+  const onPreview = async () => {
+    if (!draft) return
+    try {
+      setPreview(await window.api.template.renderSource(draft.content, PREVIEW_CONTEXT))
+    } catch (error: any) {
+      setPreview(null)
+      window.message.error({ content: getErrorMessage(error), key: 'template-preview' })
+    }
+  }
+
+  const onEdit = (value: Draft | null) => {
+    setDraft(value)
+    setPreview(null)
+  }
+
+  const onRemove = (template: UserTemplate) => {
+    window.modal.confirm({
+      title: t('settings.data.templates.remove_confirm', { name: template.name }),
+      centered: true,
+      onOk: async () => {
+        await window.api.template.remove(template.id)
+        load()
+      }
+    })
+  }
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>
+        {t('settings.data.templates.title')}
+        <Button size="small" onClick={() => onEdit(newDraft())}>
+          {t('settings.data.templates.add')}
+        </Button>
+      </SettingTitle>
+      <SettingDivider />
+      <SettingRow>
+        <SettingHelpText>{t('settings.data.templates.help', { skipInterpolation: true })}</SettingHelpText>
+      </SettingRow>
+      {templates.length ? (
+        templates.map((template) => (
+          <div key={template.id}>
+            <SettingDivider />
+            <SettingRow>
+              <SettingRowTitle>
+                {template.name}
+                {template.description && <Description>{template.description}</Description>}
+              </SettingRowTitle>
+              <HStack gap="8px" alignItems="center">
+                <Button size="small" onClick={() => onEdit({ ...template })}>
+                  {t('common.edit')}
+                </Button>
+                <Button size="small" danger onClick={() => onRemove(template)}>
+                  {t('common.delete')}
+                </Button>
+              </HStack>
+            </SettingRow>
+          </div>
+        ))
+      ) : (
+        <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={t('settings.data.templates.empty')} />
+      )}
+      <Modal
+        open={!!draft}
+        title={t(draft?.id ? 'settings.data.templates.edit' : 'settings.data.templates.add')}
+        centered
+        width={640}
+        onOk={onSave}
+        onCancel={() => onEdit(null)}
+        footer={(_, { OkBtn, CancelBtn }) => (
+          <>
+            <Button onClick={onPreview}>{t('settings.data.templates.preview')}</Button>
+            <CancelBtn />
+            <OkBtn />
+          </>
+        )}>
+        {draft && (
+          <Fields>
+            <Input
+              placeholder={t('settings.data.templates.name')}
+              value={draft.name}
+              onChange={(e) => setDraft({ ...draft, name: e.target.value })}
+            />
+            <Input
+              placeholder={t('settings.data.templates.description')}
+              value={draft.description}
+              onChange={(e) => setDraft({ ...draft, description: e.target.value })}
+            />
+            <Input.TextArea
+              rows={10}
+              placeholder={t('settings.data.templates.content_placeholder', { skipInterpolation: true })}
+              value={draft.content}
+              onChange={(e) => setDraft({ ...draft, content: e.target.value })}
+            />
+            <SettingHelpText>{t('settings.data.templates.syntax_help', { skipInterpolation: true })}</SettingHelpText>
+            {preview !== null && <Preview>{preview}</Preview>}
+          </Fields>
+        )}
+      </Modal>
+    </SettingGroup>
+  )
+}
+
+const Description = styled.span`
+  margin-left: 8px;
+  font-size: 12px;
+  color: var(--color-text-3);
+`
+
+const Fields = styled.div`
+  display: flex;
+  flex-direction: column;
+  gap: 10px;
+`
+
+const Preview = styled.pre`
+  max-height: 240px;
+  margin: 0;
+  padding: 8px;
+  overflow: auto;
+  white-space: pre-wrap;
+  font-size: 12px;
+  border-radius: 6px;
+  background-color: var(--color-background-soft);
+`
+
+export default TemplateSettings
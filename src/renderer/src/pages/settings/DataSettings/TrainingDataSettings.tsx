@@ -0,0 +1,173 @@
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { useAssistants } from '@renderer/hooks/useAssistant'
+import { exportTrainingData, importTrainingData, selectTrainingTopics } from '@renderer/services/TrainingDataService'
+import { getErrorMessage } from '@renderer/utils/error'
+import { PII_REDACTIONS, PiiRedaction, TrainingDataFormat } from '@renderer/utils/trainingData'
+import { Button, Checkbox, Input, Select, Switch } from 'antd'
+import dayjs from 'dayjs'
+import { FC, useMemo, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+
+import { SettingDivider, SettingGroup, SettingHelpText, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+const DEFAULT_ROLE_PLACEHOLDERS: Record<TrainingDataFormat, { user: string; assistant: string }> = {
+  openai: { user: 'user', assistant: 'assistant' },
+  sharegpt: { user: 'human', assistant: 'gpt' }
+}
+
+const TrainingDataSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const { assistants } = useAssistants()
+  const [assistantIds, setAssistantIds] = useState<string[]>([])
+  const [format, setFormat] = useState<TrainingDataFormat>('openai')
+  const [includeSystemPrompt, setIncludeSystemPrompt] = useState(true)
+  const [userRole, setUserRole] = useState('')
+  const [assistantRole, setAssistantRole] = useState('')
+  const [redactions, setRedactions] = useState<PiiRedaction[]>(PII_REDACTIONS)
+  const [exporting, setExporting] = useState(false)
+  const [importAssistantId, setImportAssistantId] = useState<string>()
+  const [importing, setImporting] = useState(false)
+
+  // 空选择表示导出全部助手的话题
+  const filter = useMemo(() => ({ assistantIds: assistantIds.length ? assistantIds : undefined }), [assistantIds])
+  const topicCount = useMemo(() => selectTrainingTopics(filter).length, [filter])
+
+  const onExport = async () => {
+    setExporting(true)
+    try {
+      const result = await exportTrainingData(filter, {
+        format,
+        includeSystemPrompt,
+        roleNames: { user: userRole.trim() || undefined, assistant: assistantRole.trim() || undefined },
+        redactions
+      })
+      if (!result.count) {
+        window.message.warning({ content: t('settings.data.training.empty'), key: 'training-export' })
+        return
+      }
+      const fileName = `training-${format}-${dayjs().format('YYYYMMDDHHmm')}.jsonl`
+      if (await window.api.file.save(fileName, result.content)) {
+        window.message.success({
+          content: t('settings.data.training.exported', { count: result.count, skipped: result.skipped }),
+          key: 'training-export'
+        })
+      }
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'training-export' })
+    } finally {
+      setExporting(false)
+    }
+  }
+
+  // 每条记录导入为所选助手下的一个新话题
+  const onImport = async () => {
+    const assistantId = importAssistantId ?? assistants[0]?.id
+    if (!assistantId) return
+    const file = await window.api.file.open({
+      filters: [{ name: 'JSONL', extensions: ['jsonl', 'json'] }]
+    })
+    if (!file) return
+
+    setImporting(true)
+    try {
+      const result = await importTrainingData(new TextDecoder('utf-8').decode(file.content), assistantId)
+      if (!result.count) {
+        window.message.warning({ content: t('settings.data.training.import_empty'), key: 'training-import' })
+        return
+      }
+      window.message.success({ content: t('settings.data.training.imported', result), key: 'training-import' })
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'training-import' })
+    } finally {
+      setImporting(false)
+    }
+  }
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.data.training.title')}</SettingTitle>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.training.assistants')}</SettingRowTitle>
+        <Select
+          mode="multiple"
+          allowClear
+          style={{ width: 300 }}
+          value={assistantIds}
+          onChange={setAssistantIds}
+          placeholder={t('settings.data.training.all_assistants')}
+          options={assistants.map((assistant) => ({ value: assistant.id, label: assistant.name }))}
+        />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.training.format')}</SettingRowTitle>
+        <Select
+          style={{ width: 160 }}
+          value={format}
+          onChange={setFormat}
+          options={[
+            { value: 'openai', label: 'OpenAI' },
+            { value: 'sharegpt', label: 'ShareGPT' }
+          ]}
+        />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.training.include_system_prompt')}</SettingRowTitle>
+        <Switch checked={includeSystemPrompt} onChange={setIncludeSystemPrompt} />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.training.role_names')}</SettingRowTitle>
+        <Input
+          style={{ width: 120, marginLeft: 'auto' }}
+          value={userRole}
+          onChange={(e) => setUserRole(e.target.value)}
+          placeholder={DEFAULT_ROLE_PLACEHOLDERS[format].user}
+        />
+        <Input
+          style={{ width: 120, marginLeft: 8 }}
+          value={assistantRole}
+          onChange={(e) => setAssistantRole(e.target.value)}
+          placeholder={DEFAULT_ROLE_PLACEHOLDERS[format].assistant}
+        />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRowTitle>{t('settings.data.training.redact')}</SettingRowTitle>
+      <Checkbox.Group
+        style={{ marginTop: 10 }}
+        value={redactions}
+        onChange={(values) => setRedactions(values as PiiRedaction[])}
+        options={PII_REDACTIONS.map((name) => ({ value: name, label: t(`settings.data.training.pii.${name}`) }))}
+      />
+      <SettingDivider />
+      <SettingRow>
+        <SettingHelpText>{t('settings.data.training.help', { count: topicCount })}</SettingHelpText>
+        <Button type="primary" loading={exporting} disabled={!topicCount} onClick={onExport}>
+          {t('settings.data.training.export')}
+        </Button>
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.training.import_assistant')}</SettingRowTitle>
+        <Select
+          style={{ width: 300 }}
+          value={importAssistantId ?? assistants[0]?.id}
+          onChange={setImportAssistantId}
+          options={assistants.map((assistant) => ({ value: assistant.id, label: assistant.name }))}
+        />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingHelpText>{t('settings.data.training.import_help')}</SettingHelpText>
+        <Button loading={importing} disabled={!assistants.length} onClick={onImport}>
+          {t('settings.data.training.import')}
+        </Button>
+      </SettingRow>
+    </SettingGroup>
+  )
+}
+
+export default TrainingDataSettings
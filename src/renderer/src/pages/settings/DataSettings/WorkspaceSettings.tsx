@@ -0,0 +1,165 @@
+import { HStack } from '@renderer/components/Layout'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { exportWorkspace, importWorkspace, WorkspaceSigner } from '@renderer/services/WorkspaceService'
+import { WorkspaceMergeStrategy } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { Alert, Button, Checkbox, Input, Select, Switch } from 'antd'
+import dayjs from 'dayjs'
+import { FC, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+
+import { SettingDivider, SettingGroup, SettingHelpText, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+const MERGE_STRATEGIES: WorkspaceMergeStrategy[] = ['merge', 'keep_existing', 'replace']
+
+const WorkspaceSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const [fingerprint, setFingerprint] = useState('')
+  const [exportPassphrase, setExportPassphrase] = useState('')
+  const [includeConversations, setIncludeConversations] = useState(false)
+  const [importPassphrase, setImportPassphrase] = useState('')
+  const [strategy, setStrategy] = useState<WorkspaceMergeStrategy>('merge')
+  const [loading, setLoading] = useState(false)
+
+  useEffect(() => {
+    window.api.workspace.getFingerprint().then(setFingerprint)
+  }, [])
+
+  const onExport = async () => {
+    setLoading(true)
+    try {
+      const result = await exportWorkspace(exportPassphrase, includeConversations)
+      if (result) {
+        window.message.success({ content: t('settings.data.workspace.exported'), key: 'workspace' })
+      }
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'workspace' })
+    } finally {
+      setLoading(false)
+    }
+  }
+
+  // 非本机且未信任的签名者只是自签名，可以在确认时信任它
+  const confirmSigner = (signer: WorkspaceSigner) =>
+    new Promise<boolean>((resolve) => {
+      let trustSigner = false
+      window.modal.confirm({
+        title: t('settings.data.workspace.confirm.title'),
+        content: (
+          <>
+            {signer.own ? (
+              <p>{t('settings.data.workspace.confirm.own')}</p>
+            ) : signer.trusted ? (
+              <p>{t('settings.data.workspace.confirm.trusted')}</p>
+            ) : (
+              <Alert type="warning" showIcon message={t('settings.data.workspace.confirm.untrusted')} />
+            )}
+            <p>
+              {t('settings.data.workspace.fingerprint')}: <code>{signer.fingerprint}</code>
+            </p>
+            <p>
+              {t('settings.data.workspace.confirm.created_at')}: {dayjs(signer.createdAt).format('YYYY-MM-DD HH:mm')}
+            </p>
+            {!signer.trusted && (
+              <Checkbox onChange={(e) => (trustSigner = e.target.checked)}>
+                {t('settings.data.workspace.confirm.trust_signer')}
+              </Checkbox>
+            )}
+          </>
+        ),
+        centered: true,
+        onOk: async () => {
+          if (trustSigner) {
+            await window.api.workspace.trustSigner(signer.fingerprint)
+          }
+          resolve(true)
+        },
+        onCancel: () => resolve(false)
+      })
+    })
+
+  const onImport = async () => {
+    const file = await window.api.file.open({
+      filters: [{ name: t('settings.data.workspace.file_filter'), extensions: ['cherryworkspace'] }]
+    })
+    if (!file) return
+
+    setLoading(true)
+    try {
+      const summary = await importWorkspace(file.filePath, importPassphrase, strategy, confirmSigner)
+      if (summary) {
+        window.message.success({ content: t('settings.data.workspace.imported', summary), key: 'workspace' })
+      }
+    } catch (error: any) {
+      window.message.error({ content: getErrorMessage(error), key: 'workspace' })
+    } finally {
+      setLoading(false)
+    }
+  }
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.data.workspace.title')}</SettingTitle>
+      <SettingDivider />
+      <SettingRow>
+        <SettingHelpText>{t('settings.data.workspace.help')}</SettingHelpText>
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.workspace.fingerprint')}</SettingRowTitle>
+        <code>{fingerprint}</code>
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.workspace.include_conversations')}</SettingRowTitle>
+        <Switch checked={includeConversations} onChange={setIncludeConversations} />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.workspace.export')}</SettingRowTitle>
+        <HStack gap="8px" alignItems="center">
+          <Input.Password
+            style={{ width: 200 }}
+            value={exportPassphrase}
+            onChange={(e) => setExportPassphrase(e.target.value)}
+            placeholder={t('settings.data.workspace.passphrase')}
+          />
+          <Button onClick={onExport} loading={loading} disabled={!exportPassphrase}>
+            {t('settings.data.workspace.export')}
+          </Button>
+        </HStack>
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.workspace.strategy.title')}</SettingRowTitle>
+        <Select
+          style={{ width: 200 }}
+          value={strategy}
+          onChange={setStrategy}
+          options={MERGE_STRATEGIES.map((value) => ({
+            value,
+            label: t(`settings.data.workspace.strategy.${value}`)
+          }))}
+        />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.data.workspace.import')}</SettingRowTitle>
+        <HStack gap="8px" alignItems="center">
+          <Input.Password
+            style={{ width: 200 }}
+            value={importPassphrase}
+            onChange={(e) => setImportPassphrase(e.target.value)}
+            placeholder={t('settings.data.workspace.passphrase')}
+          />
+          <Button onClick={onImport} loading={loading} disabled={!importPassphrase}>
+            {t('settings.data.workspace.import')}
+          </Button>
+        </HStack>
+      </SettingRow>
+    </SettingGroup>
+  )
+}
+
+export default WorkspaceSettings
@@ -25,6 +25,7 @@ import styled from 'styled-components'
 
 import { SettingContainer, SettingDivider, SettingGroup, SettingRow, SettingRowTitle, SettingTitle } from '..'
 import SidebarIconsManager from './SidebarIconsManager'
+import SnapLayoutSettings from './SnapLayoutSettings'
 
 const ColorCircleWrapper = styled.div`
   width: 24px;
@@ -233,6 +234,7 @@ const DisplaySettings: FC = () => {
           </ZoomButtonGroup>
         </SettingRow>
       </SettingGroup>
+      <SnapLayoutSettings />
       <SettingGroup theme={theme}>
         <SettingTitle>{t('settings.display.topic.title')}</SettingTitle>
         <SettingDivider />
@@ -0,0 +1,95 @@
+import { DeleteOutlined } from '@ant-design/icons'
+import PromptPopup from '@renderer/components/Popups/PromptPopup'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { SnapLayout } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils'
+import { Button, Popconfirm, Select, Space } from 'antd'
+import { FC, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+
+import { SettingDescription, SettingDivider, SettingGroup, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+/**
+ * 主窗口和快捷助手的窗口布局，可以保存当前的窗口位置
+ */
+const SnapLayoutSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const [layouts, setLayouts] = useState<SnapLayout[]>([])
+  const [selected, setSelected] = useState('main-and-assistant')
+
+  const current = layouts.find((layout) => layout.name === selected)
+  const getLabel = (layout: SnapLayout) =>
+    layout.builtin ? t(`settings.display.snap_layout.builtin.${layout.name}`) : layout.name
+
+  const loadLayouts = () => window.api.snapLayout.list().then(setLayouts)
+
+  useEffect(() => {
+    loadLayouts()
+  }, [])
+
+  const onApply = async () => {
+    try {
+      await window.api.snapLayout.apply(selected)
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+    }
+  }
+
+  const onSave = async () => {
+    const name = await PromptPopup.show({
+      title: t('settings.display.snap_layout.save'),
+      message: t('settings.display.snap_layout.save_description'),
+      defaultValue: ''
+    })
+    if (!name) return
+    try {
+      const layout = await window.api.snapLayout.save(name)
+      await loadLayouts()
+      setSelected(layout.name)
+      window.message.success(t('settings.display.snap_layout.saved'))
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+    }
+  }
+
+  const onRemove = async () => {
+    await window.api.snapLayout.remove(selected)
+    await loadLayouts()
+    setSelected('main-and-assistant')
+  }
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.display.snap_layout.title')}</SettingTitle>
+      <SettingDescription>{t('settings.display.snap_layout.description')}</SettingDescription>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.display.snap_layout.layout')}</SettingRowTitle>
+        <Space>
+          <Select
+            style={{ width: 220 }}
+            value={selected}
+            onChange={setSelected}
+            options={layouts.map((layout) => ({ value: layout.name, label: getLabel(layout) }))}
+          />
+          <Button type="primary" onClick={onApply} disabled={!current}>
+            {t('settings.display.snap_layout.apply')}
+          </Button>
+          {current && !current.builtin && (
+            <Popconfirm title={t('settings.display.snap_layout.remove_confirm')} onConfirm={onRemove}>
+              <Button danger icon={<DeleteOutlined />} />
+            </Popconfirm>
+          )}
+        </Space>
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.display.snap_layout.save')}</SettingRowTitle>
+        <Button onClick={onSave}>{t('common.save')}</Button>
+      </SettingRow>
+    </SettingGroup>
+  )
+}
+
+export default SnapLayoutSettings
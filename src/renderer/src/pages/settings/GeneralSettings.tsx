@@ -1,19 +1,39 @@
+import { FolderOpenOutlined } from '@ant-design/icons'
+import { HStack } from '@renderer/components/Layout'
 import { useTheme } from '@renderer/context/ThemeProvider'
 import { useSettings } from '@renderer/hooks/useSettings'
 import i18n from '@renderer/i18n'
+import { getPolicy, isSettingLocked } from '@renderer/services/PolicyService'
 import { RootState, useAppDispatch } from '@renderer/store'
-import { setEnableDataCollection, setLanguage, setNotificationSettings } from '@renderer/store/settings'
+import {
+  setContentGuardSettings,
+  setEnableDataCollection,
+  setFeedbackSound,
+  setLanguage,
+  setLinkPreviews,
+  setNotificationSettings
+} from '@renderer/store/settings'
 import { setProxyMode, setProxyUrl as _setProxyUrl } from '@renderer/store/settings'
-import { LanguageVarious } from '@renderer/types'
+import { LanguageVarious, WorkerPoolStatus } from '@renderer/types'
 import { NotificationSource } from '@renderer/types/notification'
-import { isValidProxyUrl } from '@renderer/utils'
+import { getErrorMessage, isValidProxyUrl } from '@renderer/utils'
 import { defaultLanguage } from '@shared/config/constant'
-import { Input, Select, Space, Switch } from 'antd'
-import { FC, useState } from 'react'
+import { Button, Input, Select, Space, Switch } from 'antd'
+import { FC, useEffect, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import { useSelector } from 'react-redux'
 
-import { SettingContainer, SettingDivider, SettingGroup, SettingRow, SettingRowTitle, SettingTitle } from '.'
+import {
+  SettingContainer,
+  SettingDivider,
+  SettingGroup,
+  SettingHelpText,
+  SettingRow,
+  SettingRowTitle,
+  SettingTitle
+} from '.'
+import ConfirmationRuleSettings from './ConfirmationRuleSettings'
+import ContentFilterSettings from './ContentFilterSettings'
 
 const GeneralSettings: FC = () => {
   const {
@@ -26,9 +46,14 @@ const GeneralSettings: FC = () => {
     trayOnClose,
     tray,
     proxyMode: storeProxyMode,
-    enableDataCollection
+    enableDataCollection,
+    feedbackSound,
+    linkPreviews,
+    contentGuard
   } = useSettings()
   const [proxyUrl, setProxyUrl] = useState<string | undefined>(storeProxyUrl)
+  const [workerPool, setWorkerPool] = useState<WorkerPoolStatus | null>(null)
+  const [downloadDirectory, setDownloadDirectory] = useState('')
   const { theme } = useTheme()
 
   const updateTray = (isShowTray: boolean) => {
@@ -62,13 +87,39 @@ const GeneralSettings: FC = () => {
   const dispatch = useAppDispatch()
   const { t } = useTranslation()
 
-  const onSelectLanguage = (value: LanguageVarious) => {
+  const onSelectLanguage = async (value: LanguageVarious) => {
+    try {
+      await window.api.setLanguage(value)
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+      return
+    }
     dispatch(setLanguage(value))
     localStorage.setItem('language', value)
-    window.api.setLanguage(value)
     i18n.changeLanguage(value)
   }
 
+  const onEnableDataCollectionChange = async (enabled: boolean) => {
+    try {
+      await window.api.config.set('enableDataCollection', enabled)
+      dispatch(setEnableDataCollection(enabled))
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+    }
+  }
+
+  const onFeedbackSoundChange = async (enabled: boolean) => {
+    try {
+      await window.api.config.set('feedbackSoundEnabled', enabled)
+      dispatch(setFeedbackSound(enabled))
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+    }
+  }
+
+  // 策略强制的代理会覆盖代理设置
+  const isProxyForced = getPolicy().proxy !== undefined
+
   const onSetProxyUrl = () => {
     if (proxyUrl && !isValidProxyUrl(proxyUrl)) {
       window.message.error({ content: t('message.error.invalid.proxy.url'), key: 'proxy-error' })
@@ -108,6 +159,29 @@ const GeneralSettings: FC = () => {
     { value: 'pt-PT', label: 'Português', flag: '🇵🇹' }
   ]
 
+  useEffect(() => {
+    window.api.workerPool.getStatus().then(setWorkerPool)
+    window.api.download.getDirectory().then(setDownloadDirectory)
+  }, [])
+
+  const onWorkerPoolSizeChange = async (size: number) => {
+    try {
+      setWorkerPool(await window.api.workerPool.setSize(size))
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+    }
+  }
+
+  const onSelectDownloadDirectory = async () => {
+    const directory = await window.api.file.selectFolder()
+    if (!directory) return
+    try {
+      setDownloadDirectory(await window.api.download.setDirectory(directory))
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+    }
+  }
+
   const notificationSettings = useSelector((state: RootState) => state.settings.notification)
 
   const handleNotificationChange = (type: NotificationSource, value: boolean) => {
@@ -121,7 +195,11 @@ const GeneralSettings: FC = () => {
         <SettingDivider />
         <SettingRow>
           <SettingRowTitle>{t('common.language')}</SettingRowTitle>
-          <Select defaultValue={language || defaultLanguage} style={{ width: 180 }} onChange={onSelectLanguage}>
+          <Select
+            defaultValue={language || defaultLanguage}
+            style={{ width: 180 }}
+            disabled={isSettingLocked('language')}
+            onChange={onSelectLanguage}>
             {languagesOptions.map((lang) => (
               <Select.Option key={lang.value} value={lang.value}>
                 <Space.Compact direction="horizontal" block>
@@ -140,6 +218,7 @@ const GeneralSettings: FC = () => {
           <Select
             value={storeProxyMode}
             style={{ width: 180 }}
+            disabled={isProxyForced}
             onChange={onProxyModeChange}
             options={proxyModeOptions}
           />
@@ -154,6 +233,7 @@ const GeneralSettings: FC = () => {
                 value={proxyUrl}
                 onChange={(e) => setProxyUrl(e.target.value)}
                 style={{ width: 180 }}
+                disabled={isProxyForced}
                 onBlur={() => onSetProxyUrl()}
                 type="url"
               />
@@ -181,6 +261,11 @@ const GeneralSettings: FC = () => {
             onChange={(v) => handleNotificationChange('knowledgeEmbed', v)}
           />
         </SettingRow>
+        <SettingDivider />
+        <SettingRow>
+          <SettingRowTitle>{t('settings.notification.sound')}</SettingRowTitle>
+          <Switch checked={feedbackSound} onChange={onFeedbackSoundChange} />
+        </SettingRow>
       </SettingGroup>
       <SettingGroup theme={theme}>
         <SettingTitle>{t('settings.launch.title')}</SettingTitle>
@@ -192,7 +277,11 @@ const GeneralSettings: FC = () => {
         <SettingDivider />
         <SettingRow>
           <SettingRowTitle>{t('settings.launch.totray')}</SettingRowTitle>
-          <Switch checked={launchToTray} onChange={(checked) => updateLaunchToTray(checked)} />
+          <Switch
+            checked={launchToTray}
+            disabled={isSettingLocked('launchToTray')}
+            onChange={(checked) => updateLaunchToTray(checked)}
+          />
         </SettingRow>
       </SettingGroup>
       <SettingGroup theme={theme}>
@@ -200,14 +289,57 @@ const GeneralSettings: FC = () => {
         <SettingDivider />
         <SettingRow>
           <SettingRowTitle>{t('settings.tray.show')}</SettingRowTitle>
-          <Switch checked={tray} onChange={(checked) => updateTray(checked)} />
+          <Switch checked={tray} disabled={isSettingLocked('tray')} onChange={(checked) => updateTray(checked)} />
         </SettingRow>
         <SettingDivider />
         <SettingRow>
           <SettingRowTitle>{t('settings.tray.onclose')}</SettingRowTitle>
-          <Switch checked={trayOnClose} onChange={(checked) => updateTrayOnClose(checked)} />
+          <Switch
+            checked={trayOnClose}
+            disabled={isSettingLocked('trayOnClose')}
+            onChange={(checked) => updateTrayOnClose(checked)}
+          />
+        </SettingRow>
+      </SettingGroup>
+      <SettingGroup theme={theme}>
+        <SettingTitle>{t('settings.downloads.title')}</SettingTitle>
+        <SettingDivider />
+        <SettingRow>
+          <SettingRowTitle>{t('settings.downloads.directory')}</SettingRowTitle>
+          <HStack alignItems="center" gap="5px">
+            <Input value={downloadDirectory} readOnly style={{ width: 250 }} />
+            <Button onClick={onSelectDownloadDirectory} icon={<FolderOpenOutlined />}>
+              {t('settings.downloads.select')}
+            </Button>
+          </HStack>
         </SettingRow>
+        <SettingHelpText>{t('settings.downloads.help')}</SettingHelpText>
       </SettingGroup>
+      {workerPool && (
+        <SettingGroup theme={theme}>
+          <SettingTitle>{t('settings.performance.title')}</SettingTitle>
+          <SettingDivider />
+          <SettingRow>
+            <SettingRowTitle>{t('settings.performance.worker_pool')}</SettingRowTitle>
+            <Select
+              style={{ width: 160 }}
+              value={workerPool.configuredSize}
+              onChange={onWorkerPoolSizeChange}
+              options={[
+                {
+                  value: 0,
+                  label: t('settings.performance.worker_pool_auto', { count: workerPool.defaultSize })
+                },
+                ...Array.from({ length: workerPool.maxSize }, (_, index) => ({ value: index + 1, label: index + 1 }))
+              ]}
+            />
+          </SettingRow>
+          {/* 使用电池时只保留一个工作线程 */}
+          {workerPool.onBattery && (
+            <SettingHelpText>{t('settings.performance.worker_pool_battery')}</SettingHelpText>
+          )}
+        </SettingGroup>
+      )}
       <SettingGroup theme={theme}>
         <SettingTitle>{t('settings.privacy.title')}</SettingTitle>
         <SettingDivider />
@@ -215,13 +347,38 @@ const GeneralSettings: FC = () => {
           <SettingRowTitle>{t('settings.privacy.enable_privacy_mode')}</SettingRowTitle>
           <Switch
             value={enableDataCollection}
-            onChange={(v) => {
-              dispatch(setEnableDataCollection(v))
-              window.api.config.set('enableDataCollection', v)
-            }}
+            disabled={isSettingLocked('enableDataCollection')}
+            onChange={onEnableDataCollectionChange}
           />
         </SettingRow>
+        <SettingDivider />
+        <SettingRow>
+          <SettingRowTitle>{t('settings.privacy.link_previews')}</SettingRowTitle>
+          <Switch value={linkPreviews} onChange={(v) => dispatch(setLinkPreviews(v))} />
+        </SettingRow>
+        <SettingDivider />
+        <SettingRow>
+          <SettingRowTitle>{t('settings.content_guard.title')}</SettingRowTitle>
+          <Space>
+            <Select
+              style={{ width: 160 }}
+              value={contentGuard.action}
+              disabled={!contentGuard.enabled}
+              onChange={(action) => dispatch(setContentGuardSettings({ action }))}
+              options={(['redact', 'confirm', 'block'] as const).map((action) => ({
+                value: action,
+                label: t(`settings.content_guard.action.${action}`)
+              }))}
+            />
+            <Switch
+              checked={contentGuard.enabled}
+              onChange={(enabled) => dispatch(setContentGuardSettings({ enabled }))}
+            />
+          </Space>
+        </SettingRow>
       </SettingGroup>
+      <ContentFilterSettings />
+      <ConfirmationRuleSettings />
     </SettingContainer>
   )
 }
@@ -0,0 +1,328 @@
+import { CheckOutlined, DeleteOutlined, DownloadOutlined, ReloadOutlined, SyncOutlined } from '@ant-design/icons'
+import { HStack } from '@renderer/components/Layout'
+import { useMCPServers } from '@renderer/hooks/useMCPServers'
+import {
+  InstalledMCPServer,
+  MCPDependencies,
+  MCPPermission,
+  MCPRegistryEntry,
+  MCPServerHealth,
+  MCPServerUpdate
+} from '@renderer/types'
+import { Alert, Button, Card, Flex, Space, Spin, Switch, Tag, Tooltip, Typography } from 'antd'
+import { type FC, useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { useNavigate } from 'react-router'
+import styled from 'styled-components'
+
+const PERMISSION_COLORS: Record<MCPPermission, string> = {
+  file_read: 'blue',
+  file_write: 'orange',
+  network: 'cyan',
+  shell: 'red',
+  env: 'purple',
+  browser: 'geekblue'
+}
+
+const McpMarketplace: FC = () => {
+  const { t } = useTranslation()
+  const navigate = useNavigate()
+  const { mcpServers, addMCPServer, deleteMCPServer } = useMCPServers()
+  const [registry, setRegistry] = useState<MCPRegistryEntry[]>([])
+  const [installed, setInstalled] = useState<InstalledMCPServer[]>([])
+  const [health, setHealth] = useState<MCPServerHealth[]>([])
+  const [updates, setUpdates] = useState<MCPServerUpdate[]>([])
+  const [dependencies, setDependencies] = useState<MCPDependencies>()
+  const [autoUpdate, setAutoUpdate] = useState(false)
+  const [loading, setLoading] = useState(false)
+  const [pending, setPending] = useState<string>()
+
+  const load = useCallback(
+    async (refresh = false) => {
+      setLoading(true)
+      try {
+        const [entries, deps, items, auto] = await Promise.all([
+          window.api.mcp.getRegistry(refresh),
+          window.api.mcp.checkDependencies(),
+          window.api.mcp.getInstalled(),
+          window.api.mcp.getAutoUpdate()
+        ])
+        setRegistry(entries)
+        setDependencies(deps)
+        setInstalled(items)
+        setAutoUpdate(auto)
+        const [states, available] = await Promise.all([window.api.mcp.checkHealth(), window.api.mcp.checkUpdates()])
+        setHealth(states)
+        setUpdates(available)
+      } catch (error: any) {
+        window.message.error({
+          content: `${t('settings.mcp.marketplace.load_error')}: ${error.message}`,
+          key: 'mcp-marketplace'
+        })
+      } finally {
+        setLoading(false)
+      }
+    },
+    [t]
+  )
+
+  useEffect(() => {
+    load()
+  }, [load])
+
+  const permissionTags = (permissions: MCPPermission[]) =>
+    permissions.map((permission) => (
+      <Tag key={permission} color={PERMISSION_COLORS[permission]} style={{ borderRadius: 100 }}>
+        {t(`settings.mcp.marketplace.permissions.${permission}`)}
+      </Tag>
+    ))
+
+  const isRuntimeMissing = (entry: MCPRegistryEntry) =>
+    !!dependencies && (entry.runtime === 'node' ? !dependencies.bun : !dependencies.uv)
+
+  const onInstall = (entry: MCPRegistryEntry) => {
+    window.modal.confirm({
+      title: t('settings.mcp.marketplace.install_confirm', { name: entry.name }),
+      content: (
+        <Space direction="vertical">
+          <Typography.Text>{t('settings.mcp.marketplace.permissions_hint')}</Typography.Text>
+          <div>
+            {entry.permissions.length > 0
+              ? permissionTags(entry.permissions)
+              : t('settings.mcp.marketplace.no_permissions')}
+          </div>
+        </Space>
+      ),
+      centered: true,
+      onOk: async () => {
+        setPending(entry.id)
+        try {
+          const server = await window.api.mcp.install(entry.id)
+          addMCPServer(server)
+          window.message.success({ content: t('settings.mcp.addSuccess'), key: 'mcp-marketplace' })
+          await load()
+        } catch (error: any) {
+          window.message.error({
+            content: `${t('settings.mcp.marketplace.install_error')}: ${error.message}`,
+            key: 'mcp-marketplace'
+          })
+        } finally {
+          setPending(undefined)
+        }
+      }
+    })
+  }
+
+  const onUninstall = (entry: MCPRegistryEntry, item: InstalledMCPServer) => {
+    window.modal.confirm({
+      title: t('settings.mcp.marketplace.uninstall_confirm', { name: entry.name }),
+      centered: true,
+      okButtonProps: { danger: true },
+      onOk: async () => {
+        setPending(entry.id)
+        try {
+          const server = mcpServers.find((s) => s.id === item.serverId)
+          if (server) {
+            await window.api.mcp.removeServer(server)
+            deleteMCPServer(server.id)
+          }
+          await window.api.mcp.uninstall(entry.id)
+          await load()
+        } catch (error: any) {
+          window.message.error({
+            content: `${t('settings.mcp.marketplace.uninstall_error')}: ${error.message}`,
+            key: 'mcp-marketplace'
+          })
+        } finally {
+          setPending(undefined)
+        }
+      }
+    })
+  }
+
+  const onUpdate = (update: MCPServerUpdate) => {
+    window.modal.confirm({
+      title: t('settings.mcp.marketplace.update_confirm', { name: update.name, from: update.from, to: update.to }),
+      content: (
+        <Space direction="vertical">
+          {update.addedPermissions.length > 0 && (
+            <div>
+              <Typography.Text type="warning">{t('settings.mcp.marketplace.added_permissions')}</Typography.Text>
+              <div>{permissionTags(update.addedPermissions)}</div>
+            </div>
+          )}
+          {update.removedPermissions.length > 0 && (
+            <div>
+              <Typography.Text type="secondary">{t('settings.mcp.marketplace.removed_permissions')}</Typography.Text>
+              <div>{permissionTags(update.removedPermissions)}</div>
+            </div>
+          )}
+        </Space>
+      ),
+      centered: true,
+      onOk: async () => {
+        setPending(update.id)
+        try {
+          await window.api.mcp.update(update.id)
+          window.message.success({ content: t('settings.mcp.marketplace.update_success'), key: 'mcp-marketplace' })
+          await load()
+        } catch (error: any) {
+          window.message.error({
+            content: `${t('settings.mcp.marketplace.update_error')}: ${error.message}`,
+            key: 'mcp-marketplace'
+          })
+        } finally {
+          setPending(undefined)
+        }
+      }
+    })
+  }
+
+  const onAutoUpdateChange = async (enabled: boolean) => {
+    await window.api.mcp.setAutoUpdate(enabled)
+    setAutoUpdate(enabled)
+  }
+
+  const missingDependencies = dependencies && (!dependencies.bun || !dependencies.uv)
+
+  return (
+    <Container>
+      <HStack alignItems="center" justifyContent="space-between" style={{ maxWidth: 1200, width: '100%' }}>
+        <Typography.Title level={4} style={{ margin: 0 }}>
+          {t('settings.mcp.marketplace.title')}
+        </Typography.Title>
+        <HStack alignItems="center" gap={10}>
+          <Tooltip title={t('settings.mcp.marketplace.auto_update_tooltip')}>
+            <HStack alignItems="center" gap={6}>
+              <Typography.Text>{t('settings.mcp.marketplace.auto_update')}</Typography.Text>
+              <Switch size="small" checked={autoUpdate} onChange={onAutoUpdateChange} />
+            </HStack>
+          </Tooltip>
+          <Button icon={<ReloadOutlined />} loading={loading} onClick={() => load(true)}>
+            {t('settings.mcp.marketplace.refresh')}
+          </Button>
+        </HStack>
+      </HStack>
+      {missingDependencies && (
+        <Alert
+          type="warning"
+          showIcon
+          style={{ maxWidth: 1200, width: '100%' }}
+          message={t('settings.mcp.marketplace.missing_dependencies')}
+          action={
+            <Button size="small" onClick={() => navigate('/settings/mcp/mcp-install')}>
+              {t('settings.mcp.install')}
+            </Button>
+          }
+        />
+      )}
+      {loading && registry.length === 0 && <Spin />}
+      <ResultList>
+        {registry.map((entry) => {
+          const item = installed.find((i) => i.id === entry.id)
+          const state = health.find((h) => h.id === entry.id)
+          const update = updates.find((u) => u.id === entry.id)
+          const busy = pending === entry.id
+
+          return (
+            <Card
+              size="small"
+              key={entry.id}
+              style={{ borderRadius: 'var(--list-item-border-radius)' }}
+              title={
+                <Typography.Title level={5} style={{ margin: 0 }} className="selectable">
+                  {entry.name}
+                </Typography.Title>
+              }
+              extra={
+                <Flex align="center">
+                  <Tag color="success" style={{ borderRadius: 100 }}>
+                    v{item?.version ?? entry.version}
+                  </Tag>
+                  <Tag style={{ borderRadius: 100 }}>{entry.runtime}</Tag>
+                  {state && state.status !== 'ok' && (
+                    <Tag color={state.status === 'missing' ? 'error' : 'warning'} style={{ borderRadius: 100 }}>
+                      {t(`settings.mcp.marketplace.health.${state.status}`)}
+                    </Tag>
+                  )}
+                  {update && (
+                    <Tooltip title={t('settings.mcp.marketplace.update')}>
+                      <Button
+                        type="text"
+                        size="small"
+                        icon={<SyncOutlined spin={busy} />}
+                        disabled={!!pending}
+                        onClick={() => onUpdate(update)}
+                      />
+                    </Tooltip>
+                  )}
+                  {item ? (
+                    <>
+                      <CheckOutlined style={{ color: 'var(--color-primary)', margin: '0 6px' }} />
+                      <Tooltip title={t('settings.mcp.marketplace.uninstall')}>
+                        <Button
+                          type="text"
+                          size="small"
+                          danger
+                          icon={<DeleteOutlined />}
+                          disabled={!!pending}
+                          onClick={() => onUninstall(entry, item)}
+                        />
+                      </Tooltip>
+                    </>
+                  ) : (
+                    <Tooltip title={t('settings.mcp.marketplace.install')}>
+                      <Button
+                        type="text"
+                        size="small"
+                        icon={<DownloadOutlined />}
+                        loading={busy}
+                        disabled={(!!pending && !busy) || isRuntimeMissing(entry)}
+                        onClick={() => onInstall(entry)}
+                      />
+                    </Tooltip>
+                  )}
+                </Flex>
+              }>
+              <Space direction="vertical" size="small">
+                <Typography.Text className="selectable">{entry.description}</Typography.Text>
+                <Typography.Text type="secondary" className="selectable">
+                  {entry.package}
+                </Typography.Text>
+                {entry.permissions.length > 0 && <div>{permissionTags(entry.permissions)}</div>}
+                {entry.homepage && (
+                  <Typography.Link href={entry.homepage} target="_blank" rel="noopener noreferrer">
+                    {entry.homepage}
+                  </Typography.Link>
+                )}
+              </Space>
+            </Card>
+          )
+        })}
+      </ResultList>
+    </Container>
+  )
+}
+
+const Container = styled.div`
+  display: flex;
+  flex: 1;
+  flex-direction: column;
+  align-items: center;
+  gap: 16px;
+  padding-top: 20px;
+`
+
+const ResultList = styled.div`
+  flex: 1;
+  display: grid;
+  grid-template-columns: repeat(2, 1fr);
+  gap: 16px;
+  width: 100%;
+  padding-right: 4px;
+  overflow-y: auto;
+  max-width: 1200px;
+  margin: 0 auto;
+`
+
+export default McpMarketplace
@@ -3,7 +3,7 @@ import { HStack } from '@renderer/components/Layout'
 import { isLinux, isWindows } from '@renderer/config/constant'
 import { useFullscreen } from '@renderer/hooks/useFullscreen'
 import { Button, Dropdown, Menu, type MenuProps } from 'antd'
-import { ChevronDown, Search } from 'lucide-react'
+import { ChevronDown, Search, Store } from 'lucide-react'
 import { useTranslation } from 'react-i18next'
 import { useNavigate } from 'react-router'
 
@@ -76,6 +76,15 @@ export const McpSettingsNavbar = () => {
   return (
     <NavbarRight style={{ paddingRight: useFullscreen() ? '12px' : isWindows ? 150 : isLinux ? 120 : 12 }}>
       <HStack alignItems="center" gap={5}>
+        <Button
+          size="small"
+          type="text"
+          onClick={() => navigate('/settings/mcp/marketplace')}
+          icon={<Store size={14} />}
+          className="nodrag"
+          style={{ fontSize: 13, height: 28, borderRadius: 20 }}>
+          {t('settings.mcp.marketplace.title')}
+        </Button>
         <Button
           size="small"
           type="text"
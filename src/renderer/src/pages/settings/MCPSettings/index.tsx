@@ -8,6 +8,7 @@ import styled from 'styled-components'
 
 import { SettingContainer } from '..'
 import InstallNpxUv from './InstallNpxUv'
+import McpMarketplace from './McpMarketplace'
 import McpServersList from './McpServersList'
 import McpSettings from './McpSettings'
 import NpxSearch from './NpxSearch'
@@ -41,6 +42,14 @@ const MCPSettings: FC = () => {
               </SettingContainer>
             }
           />
+          <Route
+            path="marketplace"
+            element={
+              <SettingContainer theme={theme}>
+                <McpMarketplace />
+              </SettingContainer>
+            }
+          />
           <Route
             path="mcp-install"
             element={
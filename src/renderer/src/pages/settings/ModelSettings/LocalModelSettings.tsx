@@ -0,0 +1,296 @@
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { LocalBackendInfo, LocalModelFile } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { formatFileSize } from '@renderer/utils/file'
+import { Button, Input, Tag } from 'antd'
+import dayjs from 'dayjs'
+import { FC, useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import { SettingDescription, SettingDivider, SettingGroup, SettingRow, SettingRowTitle, SettingTitle } from '..'
+
+// 清理超过这个天数没有使用的模型
+const CLEANUP_DAYS = 30
+
+const STATUS_COLORS: Record<LocalModelFile['status'], string> = {
+  downloading: 'processing',
+  ready: 'success',
+  corrupted: 'error',
+  missing: 'warning'
+}
+
+/**
+ * 本地 GGUF/ONNX 模型文件：从地址下载、导入已有文件、校验完整性、删除和清理长期未使用的模型
+ * 本地推理运行时的 GPU/CPU 信息在点击检测后才加载，首次加载 llama.cpp 比较慢
+ */
+const LocalModelSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const [models, setModels] = useState<LocalModelFile[]>([])
+  const [url, setUrl] = useState('')
+  const [sha256, setSha256] = useState('')
+  const [progress, setProgress] = useState<Record<string, number>>({})
+  const [busy, setBusy] = useState<string | null>(null)
+  const [backend, setBackend] = useState<LocalBackendInfo | null>(null)
+
+  const refresh = useCallback(async () => {
+    setModels(await window.api.modelFile.list())
+  }, [])
+
+  useEffect(() => {
+    refresh()
+  }, [refresh])
+
+  // 下载完成后还要计算文件哈希，下载中的模型定时刷新状态
+  const downloading = models.some((model) => model.status === 'downloading')
+  useEffect(() => {
+    if (!downloading) return
+    const timer = setInterval(refresh, 3000)
+    return () => clearInterval(timer)
+  }, [downloading, refresh])
+
+  useEffect(() => {
+    return window.api.download.onUpdated((task) => {
+      if (task.options?.source !== 'model' || !task.totalBytes) return
+      setProgress((items) => ({ ...items, [task.id]: Math.floor((task.receivedBytes / task.totalBytes) * 100) }))
+    })
+  }, [])
+
+  const run = async (key: string, action: () => Promise<unknown>) => {
+    setBusy(key)
+    try {
+      await action()
+      await refresh()
+    } catch (error) {
+      window.message.error({ content: getErrorMessage(error), key: 'local-models' })
+    } finally {
+      setBusy(null)
+    }
+  }
+
+  const onDownload = () =>
+    run('download', async () => {
+      await window.api.modelFile.download(url.trim(), undefined, sha256.trim() || undefined)
+      setUrl('')
+      setSha256('')
+    })
+
+  const onImport = async () => {
+    const files = await window.api.file.select({
+      properties: ['openFile'],
+      filters: [{ name: t('settings.local_models.title'), extensions: ['gguf', 'onnx'] }]
+    })
+    if (!files?.length) return
+    await run('import', () => window.api.modelFile.import(files[0].path))
+  }
+
+  const onVerify = (model: LocalModelFile) =>
+    run(model.id, async () => {
+      const valid = await window.api.modelFile.verify(model.id)
+      const content = t(`settings.local_models.${valid ? 'verify_passed' : 'verify_failed'}`, { name: model.name })
+      if (valid) {
+        window.message.success({ content, key: model.id })
+      } else {
+        window.message.error({ content, key: model.id })
+      }
+    })
+
+  const onRemove = (model: LocalModelFile) =>
+    window.modal.confirm({
+      centered: true,
+      title: t('settings.local_models.remove_confirm', { name: model.name }),
+      onOk: () => run(model.id, () => window.api.modelFile.remove(model.id))
+    })
+
+  const onCleanup = () =>
+    window.modal.confirm({
+      centered: true,
+      title: t('settings.local_models.cleanup_confirm', { days: CLEANUP_DAYS }),
+      onOk: () =>
+        run('cleanup', async () => {
+          const removed = await window.api.modelFile.cleanup(CLEANUP_DAYS)
+          const content = t('settings.local_models.cleaned', { count: removed.length })
+          window.message.info({ content, key: 'cleanup' })
+        })
+    })
+
+  const onCheckBackend = () => run('backend', async () => setBackend(await window.api.localInference.getBackendInfo()))
+
+  // 同一时间只加载一个对话模型，加载新模型会先卸载当前模型
+  const onLoad = (model: LocalModelFile) =>
+    run(`load:${model.id}`, async () => {
+      await window.api.localInference.loadModel(model.id)
+      setBackend(await window.api.localInference.getBackendInfo())
+    })
+
+  const onUnload = () =>
+    run('unload', async () => {
+      await window.api.localInference.unloadModel()
+      setBackend(await window.api.localInference.getBackendInfo())
+    })
+
+  const getBackendText = () => {
+    if (!backend) return t('settings.local_models.runtime.unknown')
+    if (!backend.available) return t('settings.local_models.runtime.unavailable')
+    const device = backend.gpu ? backend.gpu.toUpperCase() : 'CPU'
+    return backend.vram
+      ? `${device} · ${formatFileSize(backend.vram.used)} / ${formatFileSize(backend.vram.total)}`
+      : device
+  }
+
+  const loadedModel = models.find((model) => model.id === backend?.loadedModelId)
+
+  const getSize = (model: LocalModelFile) => {
+    if (model.status !== 'downloading') return formatFileSize(model.size)
+    const percent = model.downloadId ? progress[model.downloadId] : undefined
+    return percent === undefined ? '-' : `${percent}%`
+  }
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.local_models.title')}</SettingTitle>
+      <SettingDescription>{t('settings.local_models.description')}</SettingDescription>
+      <SettingDivider />
+      <DownloadRow>
+        <Input
+          value={url}
+          placeholder="https://huggingface.co/.../model-Q4_K_M.gguf"
+          onChange={(e) => setUrl(e.target.value)}
+          allowClear
+        />
+        <Input
+          style={{ width: 200 }}
+          value={sha256}
+          placeholder={t('settings.local_models.sha256_placeholder')}
+          onChange={(e) => setSha256(e.target.value)}
+          allowClear
+        />
+        <Button loading={busy === 'download'} disabled={!url.trim() || !!busy} onClick={onDownload}>
+          {t('settings.local_models.download')}
+        </Button>
+      </DownloadRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.local_models.runtime.title')}</SettingRowTitle>
+        <Actions>
+          <RuntimeInfo title={backend?.error}>{getBackendText()}</RuntimeInfo>
+          <Button size="small" loading={busy === 'backend'} disabled={!!busy} onClick={onCheckBackend}>
+            {t('settings.local_models.runtime.check')}
+          </Button>
+        </Actions>
+      </SettingRow>
+      {backend?.available && (
+        <SettingRow style={{ marginTop: 10 }}>
+          <SettingDescription style={{ marginTop: 0 }}>
+            {loadedModel
+              ? t('settings.local_models.runtime.loaded', { name: loadedModel.name })
+              : t('settings.local_models.runtime.no_model')}
+          </SettingDescription>
+          {loadedModel && (
+            <Button size="small" loading={busy === 'unload'} disabled={!!busy} onClick={onUnload}>
+              {t('settings.local_models.runtime.unload')}
+            </Button>
+          )}
+        </SettingRow>
+      )}
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.local_models.inventory')}</SettingRowTitle>
+        <Actions>
+          <Button size="small" loading={busy === 'import'} disabled={!!busy} onClick={onImport}>
+            {t('settings.local_models.import')}
+          </Button>
+          <Button size="small" loading={busy === 'cleanup'} disabled={!!busy || !models.length} onClick={onCleanup}>
+            {t('settings.local_models.cleanup')}
+          </Button>
+        </Actions>
+      </SettingRow>
+      {models.length === 0 ? (
+        <SettingDescription>{t('settings.local_models.empty')}</SettingDescription>
+      ) : (
+        <ModelList>
+          {models.map((model) => (
+            <ModelItem key={model.id}>
+              <ModelName title={model.sourceUrl ? `${model.path}\n${model.sourceUrl}` : model.path}>
+                {model.name}
+              </ModelName>
+              <span>{model.format.toUpperCase()}</span>
+              <span>{getSize(model)}</span>
+              <span>
+                {model.lastUsedAt
+                  ? dayjs(model.lastUsedAt).format('YYYY/MM/DD')
+                  : t('settings.local_models.never_used')}
+              </span>
+              <Tag color={STATUS_COLORS[model.status]}>{t(`settings.local_models.status.${model.status}`)}</Tag>
+              <Actions>
+                {model.format === 'gguf' && model.status === 'ready' && model.id !== loadedModel?.id && (
+                  <Button
+                    size="small"
+                    loading={busy === `load:${model.id}`}
+                    disabled={!!busy}
+                    onClick={() => onLoad(model)}>
+                    {t('settings.local_models.runtime.load')}
+                  </Button>
+                )}
+                <Button
+                  size="small"
+                  loading={busy === model.id}
+                  disabled={!!busy || model.status === 'downloading'}
+                  onClick={() => onVerify(model)}>
+                  {t('settings.local_models.verify')}
+                </Button>
+                <Button size="small" danger disabled={!!busy} onClick={() => onRemove(model)}>
+                  {t('common.delete')}
+                </Button>
+              </Actions>
+            </ModelItem>
+          ))}
+        </ModelList>
+      )}
+    </SettingGroup>
+  )
+}
+
+const DownloadRow = styled.div`
+  display: flex;
+  gap: 10px;
+`
+
+const Actions = styled.div`
+  display: flex;
+  gap: 6px;
+`
+
+const RuntimeInfo = styled.span`
+  font-size: 12px;
+  color: var(--color-text-2);
+`
+
+const ModelList = styled.div`
+  display: flex;
+  flex-direction: column;
+  max-height: 320px;
+  overflow-y: auto;
+  margin-top: 10px;
+`
+
+const ModelItem = styled.div`
+  display: grid;
+  grid-template-columns: 1fr 50px 80px 90px 80px auto;
+  align-items: center;
+  gap: 8px;
+  padding: 6px 0;
+  font-size: 12px;
+  color: var(--color-text-2);
+  border-bottom: 0.5px solid var(--color-border);
+`
+
+const ModelName = styled.span`
+  color: var(--color-text-1);
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+`
+
+export default LocalModelSettings
@@ -23,6 +23,8 @@ import styled from 'styled-components'
 
 import { SettingContainer, SettingDescription, SettingGroup, SettingTitle } from '..'
 import DefaultAssistantSettings from './DefaultAssistantSettings'
+import LocalModelSettings from './LocalModelSettings'
+import RoutingSettings from './RoutingSettings'
 import TopicNamingModalPopup from './TopicNamingModalPopup'
 
 const ModelSettings: FC = () => {
@@ -216,6 +218,8 @@ const ModelSettings: FC = () => {
         )}
         <SettingDescription>{t('settings.models.quick_assistant_model_description')}</SettingDescription>
       </SettingGroup>
+      <RoutingSettings />
+      <LocalModelSettings />
     </SettingContainer>
   )
 }
@@ -0,0 +1,235 @@
+import { DeleteOutlined, PlusOutlined } from '@ant-design/icons'
+import SelectModelPopup from '@renderer/components/Popups/SelectModelPopup'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { useSettings } from '@renderer/hooks/useSettings'
+import { evaluateRoute } from '@renderer/services/RoutingManager'
+import { estimateTextTokens } from '@renderer/services/TokenService'
+import { useAppDispatch } from '@renderer/store'
+import { setRoutingSettings, SettingsState } from '@renderer/store/settings'
+import { uuid } from '@renderer/utils'
+import {
+  ROUTING_LANGUAGES,
+  RoutingCheck,
+  RoutingConditions,
+  RoutingDecision,
+  RoutingRule
+} from '@renderer/utils/routing'
+import { Button, Input, InputNumber, Select, Space, Switch } from 'antd'
+import { FC, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+import {
+  SettingDescription,
+  SettingDivider,
+  SettingGroup,
+  SettingRow,
+  SettingRowTitle,
+  SettingSubtitle,
+  SettingTitle
+} from '..'
+
+/**
+ * 按消息长度、语言、图片、代码和费用选择模型的路由规则，可以试运行查看规则的判断过程
+ */
+const RoutingSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const dispatch = useAppDispatch()
+  const { routing } = useSettings()
+  const [testText, setTestText] = useState('')
+  const [testImages, setTestImages] = useState(false)
+  const [decision, setDecision] = useState<RoutingDecision | null>(null)
+
+  const update = (settings: Partial<SettingsState['routing']>) => dispatch(setRoutingSettings(settings))
+
+  const updateRule = (id: string, changes: Partial<RoutingRule>) => {
+    update({ rules: routing.rules.map((rule) => (rule.id === id ? { ...rule, ...changes } : rule)) })
+  }
+
+  const updateConditions = (rule: RoutingRule, changes: Partial<RoutingConditions>) => {
+    updateRule(rule.id, { conditions: { ...rule.conditions, ...changes } })
+  }
+
+  const addRule = () => {
+    const rule: RoutingRule = { id: uuid(), name: '', enabled: true, conditions: {} }
+    update({ rules: [...routing.rules, rule] })
+  }
+
+  const onSelectModel = async (rule: RoutingRule) => {
+    const model = await SelectModelPopup.show({ model: rule.model })
+    if (model) {
+      updateRule(rule.id, { model })
+    }
+  }
+
+  const onTest = () => {
+    setDecision(evaluateRoute({ text: testText, images: testImages ? 1 : 0, tokens: estimateTextTokens(testText) }))
+  }
+
+  // 是否包含图片和代码的条件：不限、是、否
+  const toFlag = (value?: boolean) => (value === undefined ? 'any' : value ? 'yes' : 'no')
+  const fromFlag = (value: string) => (value === 'any' ? undefined : value === 'yes')
+  const flagOptions = (condition: 'images' | 'code') =>
+    (['any', 'yes', 'no'] as const).map((value) => ({
+      value,
+      label: `${t(`settings.routing.condition.${condition}`)}: ${t(`settings.routing.flag.${value}`)}`
+    }))
+
+  const formatValue = (value: RoutingCheck['actual']) => {
+    if (typeof value === 'boolean') return t(`settings.routing.flag.${value ? 'yes' : 'no'}`)
+    return String(value)
+  }
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.routing.title')}</SettingTitle>
+      <SettingDescription>{t('settings.routing.description')}</SettingDescription>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.routing.enabled')}</SettingRowTitle>
+        <Switch checked={routing.enabled} onChange={(enabled) => update({ enabled })} />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingSubtitle style={{ marginTop: 0 }}>{t('settings.routing.rules.title')}</SettingSubtitle>
+        <Button icon={<PlusOutlined />} onClick={addRule}>
+          {t('settings.routing.rules.add')}
+        </Button>
+      </SettingRow>
+      {routing.rules.map((rule) => (
+        <RuleCard key={rule.id}>
+          <RuleRow>
+            <Input
+              style={{ flex: 1 }}
+              value={rule.name}
+              placeholder={t('settings.routing.rules.name')}
+              onChange={(e) => updateRule(rule.id, { name: e.target.value })}
+            />
+            <Button onClick={() => onSelectModel(rule)}>{rule.model?.name ?? t('settings.routing.rules.model')}</Button>
+            <Switch checked={rule.enabled} onChange={(enabled) => updateRule(rule.id, { enabled })} />
+            <Button
+              icon={<DeleteOutlined />}
+              danger
+              onClick={() => update({ rules: routing.rules.filter((item) => item.id !== rule.id) })}
+            />
+          </RuleRow>
+          <RuleRow>
+            <InputNumber
+              min={0}
+              style={{ width: 110 }}
+              value={rule.conditions.minLength}
+              placeholder={t('settings.routing.condition.min_length')}
+              onChange={(value) => updateConditions(rule, { minLength: value ?? undefined })}
+            />
+            <InputNumber
+              min={0}
+              style={{ width: 110 }}
+              value={rule.conditions.maxLength}
+              placeholder={t('settings.routing.condition.max_length')}
+              onChange={(value) => updateConditions(rule, { maxLength: value ?? undefined })}
+            />
+            <Select
+              allowClear
+              style={{ width: 110 }}
+              value={rule.conditions.language}
+              placeholder={t('settings.routing.condition.language')}
+              onChange={(language) => updateConditions(rule, { language })}
+              options={ROUTING_LANGUAGES.map((language) => ({
+                value: language,
+                label: t(`settings.routing.language.${language}`)
+              }))}
+            />
+            <Select
+              style={{ width: 140 }}
+              value={toFlag(rule.conditions.hasImages)}
+              onChange={(value) => updateConditions(rule, { hasImages: fromFlag(value) })}
+              options={flagOptions('images')}
+            />
+            <Select
+              style={{ width: 140 }}
+              value={toFlag(rule.conditions.hasCode)}
+              onChange={(value) => updateConditions(rule, { hasCode: fromFlag(value) })}
+              options={flagOptions('code')}
+            />
+            <InputNumber
+              min={0}
+              step={0.001}
+              style={{ width: 120 }}
+              value={rule.conditions.maxCost}
+              placeholder={t('settings.routing.condition.cost')}
+              onChange={(value) => updateConditions(rule, { maxCost: value ?? undefined })}
+            />
+          </RuleRow>
+        </RuleCard>
+      ))}
+      <SettingDivider />
+      <SettingSubtitle style={{ marginTop: 0 }}>{t('settings.routing.test.title')}</SettingSubtitle>
+      <Input.TextArea
+        rows={3}
+        style={{ marginTop: 10 }}
+        value={testText}
+        placeholder={t('settings.routing.test.placeholder')}
+        onChange={(e) => setTestText(e.target.value)}
+      />
+      <RuleRow>
+        <Space>
+          <Switch size="small" checked={testImages} onChange={setTestImages} />
+          {t('settings.routing.test.images')}
+        </Space>
+        <div style={{ flex: 1 }} />
+        <Button onClick={onTest}>{t('settings.routing.test.run')}</Button>
+      </RuleRow>
+      {decision && (
+        <Explanation>
+          <div>
+            {decision.model
+              ? t('settings.routing.test.matched', { rule: decision.rule?.name, model: decision.model.name })
+              : t('settings.routing.test.unmatched')}
+          </div>
+          {decision.explanations.map((item) => (
+            <div key={item.ruleId}>
+              <strong>{item.name || t('settings.routing.rules.unnamed')}</strong>
+              {item.checks.map((check) => (
+                <CheckItem key={check.condition} style={check.passed ? undefined : { color: 'var(--color-error)' }}>
+                  {check.passed ? '✓' : '✗'} {t(`settings.routing.condition.${check.condition}`)}:{' '}
+                  {formatValue(check.actual)} / {formatValue(check.expected)}
+                </CheckItem>
+              ))}
+            </div>
+          ))}
+        </Explanation>
+      )}
+    </SettingGroup>
+  )
+}
+
+const RuleCard = styled.div`
+  padding: 0 10px 10px;
+  margin-top: 10px;
+  border: 0.5px solid var(--color-border);
+  border-radius: 8px;
+`
+
+const RuleRow = styled.div`
+  display: flex;
+  align-items: center;
+  flex-wrap: wrap;
+  gap: 8px;
+  margin-top: 10px;
+`
+
+const Explanation = styled.div`
+  display: flex;
+  flex-direction: column;
+  gap: 8px;
+  margin-top: 10px;
+  font-size: 12px;
+  color: var(--color-text-2);
+`
+
+const CheckItem = styled.div`
+  padding-left: 12px;
+`
+
+export default RoutingSettings
@@ -0,0 +1,126 @@
+import { HStack } from '@renderer/components/Layout'
+import { PROVIDER_CONFIG } from '@renderer/config/providers'
+import { useAwsBedrockSettings } from '@renderer/hooks/useAwsBedrock'
+import { Alert, Button, Input } from 'antd'
+import { FC, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+
+import { SettingHelpLink, SettingHelpText, SettingHelpTextRow, SettingSubtitle } from '..'
+
+const AwsBedrockSettings: FC = () => {
+  const { t } = useTranslation()
+  const { region, setRegion } = useAwsBedrockSettings()
+
+  const providerConfig = PROVIDER_CONFIG['aws-bedrock']
+  const apiKeyWebsite = providerConfig?.websites?.apiKey
+
+  const [localRegion, setLocalRegion] = useState(region)
+  const [accessKeyId, setAccessKeyId] = useState('')
+  const [secretAccessKey, setSecretAccessKey] = useState('')
+  const [sessionToken, setSessionToken] = useState('')
+  const [savedAccessKeyId, setSavedAccessKeyId] = useState<string>()
+
+  useEffect(() => {
+    window.api.awsBedrock.getAccessKeyId().then((id) => {
+      setSavedAccessKeyId(id)
+      setAccessKeyId(id || '')
+    })
+  }, [])
+
+  const handleRegionBlur = () => {
+    const value = localRegion.trim()
+    if (value) {
+      setRegion(value)
+    } else {
+      setLocalRegion(region)
+    }
+  }
+
+  const onSaveCredentials = async () => {
+    try {
+      await window.api.awsBedrock.setCredentials({ accessKeyId, secretAccessKey, sessionToken })
+      setSavedAccessKeyId(accessKeyId.trim())
+      setSecretAccessKey('')
+      setSessionToken('')
+      window.message.success(t('settings.provider.aws_bedrock.saved'))
+    } catch (error) {
+      console.error('[AwsBedrockSettings] Failed to save credentials:', error)
+      window.message.error(t('settings.provider.aws_bedrock.save_failed'))
+    }
+  }
+
+  const onClearCredentials = async () => {
+    await window.api.awsBedrock.clearCredentials()
+    setSavedAccessKeyId(undefined)
+    setAccessKeyId('')
+    setSecretAccessKey('')
+    setSessionToken('')
+    window.message.success(t('settings.provider.aws_bedrock.cleared'))
+  }
+
+  return (
+    <>
+      <Alert type="info" style={{ marginTop: 5 }} message={t('settings.provider.aws_bedrock.description')} showIcon />
+
+      <SettingSubtitle style={{ marginTop: 5 }}>{t('settings.provider.aws_bedrock.access_key_id')}</SettingSubtitle>
+      <Input
+        value={accessKeyId}
+        placeholder="AKIA..."
+        onChange={(e) => setAccessKeyId(e.target.value)}
+        style={{ marginTop: 5 }}
+        spellCheck={false}
+      />
+
+      <SettingSubtitle style={{ marginTop: 5 }}>{t('settings.provider.aws_bedrock.secret_access_key')}</SettingSubtitle>
+      <Input.Password
+        value={secretAccessKey}
+        placeholder={savedAccessKeyId ? '••••••••' : t('settings.provider.aws_bedrock.secret_access_key')}
+        onChange={(e) => setSecretAccessKey(e.target.value)}
+        style={{ marginTop: 5 }}
+        spellCheck={false}
+      />
+
+      <SettingSubtitle style={{ marginTop: 5 }}>{t('settings.provider.aws_bedrock.session_token')}</SettingSubtitle>
+      <Input.Password
+        value={sessionToken}
+        placeholder={t('settings.provider.aws_bedrock.session_token_placeholder')}
+        onChange={(e) => setSessionToken(e.target.value)}
+        style={{ marginTop: 5 }}
+        spellCheck={false}
+      />
+      <SettingHelpTextRow style={{ justifyContent: 'space-between' }}>
+        <HStack>
+          {apiKeyWebsite && (
+            <SettingHelpLink target="_blank" href={apiKeyWebsite}>
+              {t('settings.provider.get_api_key')}
+            </SettingHelpLink>
+          )}
+        </HStack>
+        <HStack gap={8}>
+          {savedAccessKeyId && (
+            <Button size="small" danger onClick={onClearCredentials}>
+              {t('settings.provider.aws_bedrock.clear')}
+            </Button>
+          )}
+          <Button size="small" type="primary" onClick={onSaveCredentials} disabled={!accessKeyId || !secretAccessKey}>
+            {t('settings.provider.aws_bedrock.save')}
+          </Button>
+        </HStack>
+      </SettingHelpTextRow>
+
+      <SettingSubtitle style={{ marginTop: 5 }}>{t('settings.provider.aws_bedrock.region')}</SettingSubtitle>
+      <Input
+        value={localRegion}
+        placeholder="us-east-1"
+        onChange={(e) => setLocalRegion(e.target.value)}
+        onBlur={handleRegionBlur}
+        style={{ marginTop: 5 }}
+      />
+      <SettingHelpTextRow>
+        <SettingHelpText>{t('settings.provider.aws_bedrock.region_help')}</SettingHelpText>
+      </SettingHelpTextRow>
+    </>
+  )
+}
+
+export default AwsBedrockSettings
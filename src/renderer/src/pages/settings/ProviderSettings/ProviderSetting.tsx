@@ -4,7 +4,7 @@ import OpenAIAlert from '@renderer/components/Alert/OpenAIAlert'
 import { StreamlineGoodHealthAndWellBeing } from '@renderer/components/Icons/SVGIcon'
 import { HStack } from '@renderer/components/Layout'
 import { isEmbeddingModel, isRerankModel } from '@renderer/config/models'
-import { PROVIDER_CONFIG } from '@renderer/config/providers'
+import { AZURE_OPENAI_DEFAULT_API_VERSION, PROVIDER_CONFIG } from '@renderer/config/providers'
 import { useTheme } from '@renderer/context/ThemeProvider'
 import { useAllProviders, useProvider, useProviders } from '@renderer/hooks/useProvider'
 import i18n from '@renderer/i18n'
@@ -32,6 +32,7 @@ import {
   SettingTitle
 } from '..'
 import ApiCheckPopup from './ApiCheckPopup'
+import AwsBedrockSettings from './AwsBedrockSettings'
 import DMXAPISettings from './DMXAPISettings'
 import GithubCopilotSettings from './GithubCopilotSettings'
 import GPUStackSettings from './GPUStackSettings'
@@ -336,7 +337,8 @@ const ProviderSetting: FC<Props> = ({ provider: _provider }) => {
       )}
       {provider.id === 'openai' && <OpenAIAlert />}
       {isDmxapi && <DMXAPISettings provider={provider} setApiKey={setApiKey} />}
-      {provider.id !== 'vertexai' && (
+      {provider.id === 'local' && <SettingHelpText>{t('settings.provider.local.description')}</SettingHelpText>}
+      {!['vertexai', 'aws-bedrock', 'local'].includes(provider.id) && (
         <>
           <SettingSubtitle style={{ marginTop: 5 }}>{t('settings.provider.api_key')}</SettingSubtitle>
           <Space.Compact style={{ width: '100%', marginTop: 5 }}>
@@ -414,7 +416,7 @@ const ProviderSetting: FC<Props> = ({ provider: _provider }) => {
           <Space.Compact style={{ width: '100%', marginTop: 5 }}>
             <Input
               value={apiVersion}
-              placeholder="2024-xx-xx-preview"
+              placeholder={AZURE_OPENAI_DEFAULT_API_VERSION}
               onChange={(e) => setApiVersion(e.target.value)}
               onBlur={onUpdateApiVersion}
             />
@@ -425,6 +427,7 @@ const ProviderSetting: FC<Props> = ({ provider: _provider }) => {
       {provider.id === 'gpustack' && <GPUStackSettings />}
       {provider.id === 'copilot' && <GithubCopilotSettings provider={provider} setApiKey={setApiKey} />}
       {provider.id === 'vertexai' && <VertexAISettings />}
+      {provider.id === 'aws-bedrock' && <AwsBedrockSettings />}
       <SettingSubtitle style={{ marginBottom: 5 }}>
         <Space align="center" style={{ width: '100%', justifyContent: 'space-between' }}>
           <HStack alignItems="center" gap={8} mb={5}>
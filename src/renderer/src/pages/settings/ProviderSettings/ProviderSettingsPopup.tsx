@@ -1,9 +1,10 @@
 import { TopView } from '@renderer/components/TopView'
 import { useProvider } from '@renderer/hooks/useProvider'
-import { Provider } from '@renderer/types'
-import { Checkbox, Modal } from 'antd'
+import { Provider, ProviderLimits } from '@renderer/types'
+import { Checkbox, InputNumber, Modal } from 'antd'
 import { useState } from 'react'
 import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
 
 interface ShowParams {
   provider: Provider
@@ -21,6 +22,10 @@ const PopupContainer: React.FC<Props> = ({ resolve, ...props }) => {
 
   const { t } = useTranslation()
 
+  const updateLimits = (limits: ProviderLimits) => {
+    updateProvider({ ...provider, limits: { ...provider.limits, ...limits } })
+  }
+
   const onOk = () => {
     setOpen(false)
   }
@@ -52,10 +57,35 @@ const PopupContainer: React.FC<Props> = ({ resolve, ...props }) => {
         }}>
         {t('settings.provider.is_not_support_array_content')}
       </Checkbox>
+      <LimitsTitle>{t('settings.provider.limits.title')}</LimitsTitle>
+      {(['rpm', 'tpm', 'maxConcurrency'] as const).map((key) => (
+        <LimitRow key={key}>
+          <span>{t(`settings.provider.limits.${key}`)}</span>
+          <InputNumber
+            min={0}
+            precision={0}
+            value={provider.limits?.[key]}
+            placeholder={t('settings.provider.limits.unlimited')}
+            onChange={(value) => updateLimits({ [key]: value ?? undefined })}
+          />
+        </LimitRow>
+      ))}
     </Modal>
   )
 }
 
+const LimitsTitle = styled.div`
+  margin: 16px 0 8px;
+  font-weight: 500;
+`
+
+const LimitRow = styled.div`
+  display: flex;
+  align-items: center;
+  justify-content: space-between;
+  margin-bottom: 8px;
+`
+
 const TopViewKey = 'ProviderSettingsPopup'
 
 export default class ProviderSettingsPopup {
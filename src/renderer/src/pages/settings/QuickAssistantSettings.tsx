@@ -14,6 +14,7 @@ import { useTranslation } from 'react-i18next'
 import styled from 'styled-components'
 
 import { SettingContainer, SettingDivider, SettingGroup, SettingRow, SettingRowTitle, SettingTitle } from '.'
+import WakeWordSettings from './WakeWordSettings'
 
 const QuickAssistantSettings: FC = () => {
   const { t } = useTranslation()
@@ -86,6 +87,7 @@ const QuickAssistantSettings: FC = () => {
           </>
         )}
       </SettingGroup>
+      {enableQuickAssistant && <WakeWordSettings />}
       {enableQuickAssistant && (
         <AssistantContainer>
           <HomeWindow />
@@ -27,6 +27,7 @@ const SelectionAssistantSettings: FC = () => {
   const { t } = useTranslation()
   const {
     selectionEnabled,
+    isPermissionGranted,
     triggerMode,
     isCompact,
     isAutoClose,
@@ -35,9 +36,12 @@ const SelectionAssistantSettings: FC = () => {
     isRemeberWinSize,
     actionItems,
     actionWindowOpacity,
+    isAutoCopyResult,
+    isNotifyResult,
     filterMode,
     filterList,
     setSelectionEnabled,
+    setIsPermissionGranted,
     setTriggerMode,
     setIsCompact,
     setIsAutoClose,
@@ -45,6 +49,8 @@ const SelectionAssistantSettings: FC = () => {
     setIsFollowToolbar,
     setIsRemeberWinSize,
     setActionWindowOpacity,
+    setIsAutoCopyResult,
+    setIsNotifyResult,
     setActionItems,
     setFilterMode,
     setFilterList
@@ -58,6 +64,30 @@ const SelectionAssistantSettings: FC = () => {
     }
   }, [selectionEnabled, setSelectionEnabled])
 
+  // the assistant reads selected text in every application, ask for explicit consent first
+  const onEnableChange = (checked: boolean) => {
+    if (!checked || isPermissionGranted) {
+      setSelectionEnabled(checked)
+      return
+    }
+
+    window.modal.confirm({
+      title: t('selection.settings.permission.title'),
+      content: t('selection.settings.permission.content'),
+      okText: t('selection.settings.permission.grant'),
+      centered: true,
+      onOk: () => {
+        setIsPermissionGranted(true)
+        setSelectionEnabled(true)
+      }
+    })
+  }
+
+  const onRevokePermission = () => {
+    setSelectionEnabled(false)
+    setIsPermissionGranted(false)
+  }
+
   return (
     <SettingContainer theme={theme}>
       <SettingGroup>
@@ -80,11 +110,24 @@ const SelectionAssistantSettings: FC = () => {
           </SettingLabel>
           <Switch
             checked={isWindows && selectionEnabled}
-            onChange={(checked) => setSelectionEnabled(checked)}
+            onChange={onEnableChange}
             disabled={!isWindows}
           />
         </SettingRow>
 
+        {isWindows && isPermissionGranted && (
+          <>
+            <SettingDivider />
+            <SettingRow>
+              <SettingLabel>
+                <SettingRowTitle>{t('selection.settings.permission.granted')}</SettingRowTitle>
+                <SettingDescription>{t('selection.settings.permission.revoke_description')}</SettingDescription>
+              </SettingLabel>
+              <Button onClick={onRevokePermission}>{t('selection.settings.permission.revoke')}</Button>
+            </SettingRow>
+          </>
+        )}
+
         {!selectionEnabled && (
           <DemoContainer>
             <SelectionToolbar demo />
@@ -206,6 +249,26 @@ const SelectionAssistantSettings: FC = () => {
                 tooltip={{ open: false }}
               />
             </SettingRow>
+
+            <SettingDivider />
+
+            <SettingRow>
+              <SettingLabel>
+                <SettingRowTitle>{t('selection.settings.window.auto_copy_result.title')}</SettingRowTitle>
+                <SettingDescription>{t('selection.settings.window.auto_copy_result.description')}</SettingDescription>
+              </SettingLabel>
+              <Switch checked={isAutoCopyResult} onChange={(checked) => setIsAutoCopyResult(checked)} />
+            </SettingRow>
+
+            <SettingDivider />
+
+            <SettingRow>
+              <SettingLabel>
+                <SettingRowTitle>{t('selection.settings.window.notify_result.title')}</SettingRowTitle>
+                <SettingDescription>{t('selection.settings.window.notify_result.description')}</SettingDescription>
+              </SettingLabel>
+              <Switch checked={isNotifyResult} onChange={(checked) => setIsNotifyResult(checked)} />
+            </SettingRow>
           </SettingGroup>
 
           <SelectionActionsList actionItems={actionItems} setActionItems={setActionItems} />
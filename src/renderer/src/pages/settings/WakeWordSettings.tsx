@@ -0,0 +1,147 @@
+import { DeleteOutlined } from '@ant-design/icons'
+import { useTheme } from '@renderer/context/ThemeProvider'
+import { useSettings } from '@renderer/hooks/useSettings'
+import {
+  createWakeWordModel,
+  getWakeWordModels,
+  recordSpeechSegment,
+  removeWakeWordModel
+} from '@renderer/services/WakeWordService'
+import { useAppDispatch } from '@renderer/store'
+import { setWakeWordEnabled, setWakeWordModelId, setWakeWordSensitivity } from '@renderer/store/settings'
+import { WakeWordModel } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils'
+import { Button, Input, Select, Slider, Space, Switch } from 'antd'
+import { FC, useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+
+import {
+  SettingDivider,
+  SettingGroup,
+  SettingHelpText,
+  SettingHelpTextRow,
+  SettingRow,
+  SettingRowTitle,
+  SettingTitle
+} from '.'
+
+const ENROLL_SAMPLES = 3
+
+type Sample = Awaited<ReturnType<typeof recordSpeechSegment>>
+
+const WakeWordSettings: FC = () => {
+  const { t } = useTranslation()
+  const { theme } = useTheme()
+  const { wakeWordEnabled, wakeWordSensitivity, wakeWordModelId } = useSettings()
+  const dispatch = useAppDispatch()
+  const [models, setModels] = useState<WakeWordModel[]>([])
+  const [name, setName] = useState('')
+  const [samples, setSamples] = useState<Sample[]>([])
+  const [recording, setRecording] = useState(false)
+
+  const loadModels = useCallback(() => getWakeWordModels().then(setModels), [])
+
+  useEffect(() => {
+    loadModels()
+  }, [loadModels])
+
+  const onRecord = async () => {
+    setRecording(true)
+    try {
+      const next = [...samples, await recordSpeechSegment()]
+      if (next.length < ENROLL_SAMPLES) {
+        setSamples(next)
+        return
+      }
+      const model = await createWakeWordModel(name.trim(), next)
+      setSamples([])
+      setName('')
+      await loadModels()
+      wakeWordModelId || dispatch(setWakeWordModelId(model.id))
+      window.message.success(t('settings.quickAssistant.wake_word.enroll_success'))
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+    } finally {
+      setRecording(false)
+    }
+  }
+
+  const onRemove = async (id: string) => {
+    await removeWakeWordModel(id)
+    await loadModels()
+    id === wakeWordModelId && dispatch(setWakeWordModelId(''))
+  }
+
+  return (
+    <SettingGroup theme={theme}>
+      <SettingTitle>{t('settings.quickAssistant.wake_word.title')}</SettingTitle>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.quickAssistant.wake_word.enable')}</SettingRowTitle>
+        <Switch checked={wakeWordEnabled} onChange={(checked) => dispatch(setWakeWordEnabled(checked))} />
+      </SettingRow>
+      <SettingHelpTextRow>
+        <SettingHelpText>{t('settings.quickAssistant.wake_word.enable_tip')}</SettingHelpText>
+      </SettingHelpTextRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.quickAssistant.wake_word.model')}</SettingRowTitle>
+        <Select
+          style={{ width: 240 }}
+          value={wakeWordModelId || undefined}
+          placeholder={t('settings.quickAssistant.wake_word.model_placeholder')}
+          onChange={(id) => dispatch(setWakeWordModelId(id))}
+          options={models.map((model) => ({
+            value: model.id,
+            label: (
+              <Space style={{ width: '100%', justifyContent: 'space-between' }}>
+                {model.name}
+                <DeleteOutlined
+                  onClick={(event) => {
+                    event.stopPropagation()
+                    onRemove(model.id)
+                  }}
+                />
+              </Space>
+            )
+          }))}
+        />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.quickAssistant.wake_word.sensitivity')}</SettingRowTitle>
+        <Slider
+          style={{ width: 240 }}
+          min={0}
+          max={1}
+          step={0.05}
+          value={wakeWordSensitivity}
+          onChange={(value) => dispatch(setWakeWordSensitivity(value))}
+        />
+      </SettingRow>
+      <SettingDivider />
+      <SettingRow>
+        <SettingRowTitle>{t('settings.quickAssistant.wake_word.enroll')}</SettingRowTitle>
+        <Space>
+          <Input
+            style={{ width: 160 }}
+            value={name}
+            disabled={samples.length > 0}
+            placeholder={t('settings.quickAssistant.wake_word.name_placeholder')}
+            onChange={(e) => setName(e.target.value)}
+          />
+          <Button loading={recording} disabled={!name.trim()} onClick={onRecord}>
+            {t('settings.quickAssistant.wake_word.record', { current: samples.length + 1, total: ENROLL_SAMPLES })}
+          </Button>
+        </Space>
+      </SettingRow>
+      <SettingHelpTextRow>
+        <SettingHelpText>
+          {t('settings.quickAssistant.wake_word.enroll_tip', { count: ENROLL_SAMPLES })}
+        </SettingHelpText>
+      </SettingHelpTextRow>
+    </SettingGroup>
+  )
+}
+
+export default WakeWordSettings
@@ -9,8 +9,16 @@ import { useDefaultModel } from '@renderer/hooks/useAssistant'
 import { useProviders } from '@renderer/hooks/useProvider'
 import { fetchTranslate } from '@renderer/services/ApiService'
 import { getDefaultTranslateAssistant } from '@renderer/services/AssistantService'
+import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
 import { getModelUniqId, hasModel } from '@renderer/services/ModelService'
-import type { Model, TranslateHistory } from '@renderer/types'
+import { saveDraft, takeRecoveredDraft } from '@renderer/services/SessionRecoveryService'
+import {
+  getTranslateApiKey,
+  getTranslateProvider,
+  translate,
+  TRANSLATE_PROVIDERS
+} from '@renderer/services/TranslateManager'
+import type { Model, TranslateHistory, TranslateProvider } from '@renderer/types'
 import { runAsyncFunction, uuid } from '@renderer/utils'
 import {
   createInputScrollHandler,
@@ -18,7 +26,7 @@ import {
   detectLanguage,
   determineTargetLanguage
 } from '@renderer/utils/translate'
-import { Button, Dropdown, Empty, Flex, Modal, Popconfirm, Select, Space, Switch, Tooltip } from 'antd'
+import { Button, Dropdown, Empty, Flex, Input, Modal, Popconfirm, Select, Space, Switch, Tooltip } from 'antd'
 import TextArea, { TextAreaRef } from 'antd/es/input/TextArea'
 import dayjs from 'dayjs'
 import { useLiveQuery } from 'dexie-react-hooks'
@@ -44,6 +52,8 @@ const TranslateSettings: FC<{
   setEnableMarkdown: (value: boolean) => void
   bidirectionalPair: [string, string]
   setBidirectionalPair: (value: [string, string]) => void
+  translateProvider: TranslateProvider
+  setTranslateProvider: (value: TranslateProvider) => void
   translateModel: Model | undefined
   onModelChange: (model: Model) => void
   allModels: Model[]
@@ -59,6 +69,8 @@ const TranslateSettings: FC<{
   setEnableMarkdown,
   bidirectionalPair,
   setBidirectionalPair,
+  translateProvider,
+  setTranslateProvider,
   translateModel,
   onModelChange,
   allModels,
@@ -66,6 +78,8 @@ const TranslateSettings: FC<{
 }) => {
   const { t } = useTranslation()
   const [localPair, setLocalPair] = useState<[string, string]>(bidirectionalPair)
+  const [localProvider, setLocalProvider] = useState<TranslateProvider>(translateProvider)
+  const [apiKey, setApiKey] = useState('')
 
   const defaultTranslateModel = useMemo(
     () => (hasModel(translateModel) ? getModelUniqId(translateModel) : undefined),
@@ -76,6 +90,14 @@ const TranslateSettings: FC<{
     setLocalPair(bidirectionalPair)
   }, [bidirectionalPair, visible])
 
+  useEffect(() => {
+    setLocalProvider(translateProvider)
+  }, [translateProvider, visible])
+
+  useEffect(() => {
+    localProvider !== 'llm' && getTranslateApiKey(localProvider).then(setApiKey)
+  }, [localProvider, visible])
+
   const handleSave = () => {
     if (localPair[0] === localPair[1]) {
       window.message.warning({
@@ -85,7 +107,12 @@ const TranslateSettings: FC<{
       return
     }
     setBidirectionalPair(localPair)
+    setTranslateProvider(localProvider)
     db.settings.put({ id: 'translate:bidirectional:pair', value: localPair })
+    db.settings.put({ id: 'translate:provider', value: localProvider })
+    if (localProvider !== 'llm') {
+      db.settings.put({ id: `translate:api-key:${localProvider}`, value: apiKey.trim() })
+    }
     db.settings.put({ id: 'translate:scroll:sync', value: isScrollSyncEnabled })
     db.settings.put({ id: 'translate:markdown:enabled', value: enableMarkdown })
     window.message.success({
@@ -112,6 +139,34 @@ const TranslateSettings: FC<{
       width={420}>
       <Flex vertical gap={16} style={{ marginTop: 16 }}>
         <div>
+          <div style={{ marginBottom: 8, fontWeight: 500 }}>{t('translate.settings.provider.title')}</div>
+          <Select
+            style={{ width: '100%' }}
+            value={localProvider}
+            onChange={setLocalProvider}
+            options={TRANSLATE_PROVIDERS.map((provider) => ({
+              value: provider,
+              label: t(`translate.settings.provider.${provider}`)
+            }))}
+          />
+          <div style={{ marginTop: 8, fontSize: 12, color: 'var(--color-text-3)' }}>
+            {t('translate.settings.provider.description')}
+          </div>
+        </div>
+
+        {localProvider !== 'llm' && (
+          <div>
+            <div style={{ marginBottom: 8, fontWeight: 500 }}>{t('translate.settings.api_key')}</div>
+            <Input.Password
+              value={apiKey}
+              placeholder={t('translate.settings.api_key')}
+              onChange={(e) => setApiKey(e.target.value)}
+              spellCheck={false}
+            />
+          </div>
+        )}
+
+        <div style={{ display: localProvider === 'llm' ? 'block' : 'none' }}>
           <div style={{ marginBottom: 8, fontWeight: 500 }}>{t('translate.settings.model')}</div>
           <HStack alignItems="center" gap={5}>
             <Select
@@ -227,6 +282,7 @@ const TranslatePage: FC = () => {
   const [isBidirectional, setIsBidirectional] = useState(false)
   const [enableMarkdown, setEnableMarkdown] = useState(false)
   const [bidirectionalPair, setBidirectionalPair] = useState<[string, string]>(['english', 'chinese'])
+  const [translateProvider, setTranslateProvider] = useState<TranslateProvider>('llm')
   const [settingsVisible, setSettingsVisible] = useState(false)
   const [detectedLanguage, setDetectedLanguage] = useState<string | null>(null)
   const [sourceLanguage, setSourceLanguage] = useState<string>('auto') // 添加用户选择的源语言状态
@@ -293,7 +349,7 @@ const TranslatePage: FC = () => {
 
   const onTranslate = async () => {
     if (!text.trim()) return
-    if (!translateModel) {
+    if (translateProvider === 'llm' && !translateModel) {
       window.message.error({
         content: t('translate.error.not_configured'),
         key: 'translate-message'
@@ -335,16 +391,22 @@ const TranslatePage: FC = () => {
         setTargetLanguage(actualTargetLanguage)
       }
 
-      const assistant = getDefaultTranslateAssistant(actualTargetLanguage, text)
       let translatedText = ''
-      await fetchTranslate({
-        content: text,
-        assistant,
-        onResponse: (text) => {
-          translatedText = text.replace(/^\s*\n+/g, '')
-          setResult(translatedText)
-        }
-      })
+      if (translateProvider === 'llm') {
+        const assistant = getDefaultTranslateAssistant(actualTargetLanguage, text)
+        await fetchTranslate({
+          content: text,
+          assistant,
+          onResponse: (text) => {
+            translatedText = text.replace(/^\s*\n+/g, '')
+            setResult(translatedText)
+          }
+        })
+      } else {
+        // 翻译 API 不支持流式输出，按句子缓存后一次返回
+        translatedText = await translate(text, actualTargetLanguage, translateProvider)
+        setResult(translatedText)
+      }
 
       await saveTranslateHistory(text, translatedText, actualSourceLanguage, actualTargetLanguage)
       setLoading(false)
@@ -378,8 +440,19 @@ const TranslatePage: FC = () => {
 
   useEffect(() => {
     isEmpty(text) && setResult('')
+    saveDraft('translate', text)
   }, [text])
 
+  useEffect(() => {
+    // 异常退出前未翻译的内容
+    const onSessionRecovered = () => {
+      const draft = takeRecoveredDraft('translate')
+      draft && setText(draft)
+    }
+    onSessionRecovered()
+    return EventEmitter.on(EVENT_NAMES.SESSION_RECOVERED, onSessionRecovered)
+  }, [])
+
   useEffect(() => {
     runAsyncFunction(async () => {
       const targetLang = await db.settings.get({ id: 'translate:target:language' })
@@ -405,6 +478,8 @@ const TranslatePage: FC = () => {
 
       const markdownSetting = await db.settings.get({ id: 'translate:markdown:enabled' })
       setEnableMarkdown(markdownSetting ? markdownSetting.value : false)
+
+      setTranslateProvider(await getTranslateProvider())
     })
   }, [])
 
@@ -625,6 +700,8 @@ const TranslatePage: FC = () => {
         setEnableMarkdown={setEnableMarkdown}
         bidirectionalPair={bidirectionalPair}
         setBidirectionalPair={setBidirectionalPair}
+        translateProvider={translateProvider}
+        setTranslateProvider={setTranslateProvider}
         translateModel={translateModel}
         onModelChange={handleModelChange}
         allModels={allModels}
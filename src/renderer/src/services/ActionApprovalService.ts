@@ -0,0 +1,51 @@
+import Logger from '@renderer/config/logger'
+import i18n from '@renderer/i18n'
+import { PendingApproval } from '@renderer/types'
+import { createElement } from 'react'
+
+// 打开的确认框，在其他地方处理或超时后关闭
+const dialogs = new Map<string, { destroy: () => void }>()
+
+function describe(approval: PendingApproval): string {
+  const { action } = approval
+  const targets = [...action.paths, ...action.urls]
+  return [`${action.server} / ${action.tool}`, ...targets].join('\n')
+}
+
+/**
+ * 询问用户是否允许确认规则匹配的工具调用
+ */
+function showApproval(approval: PendingApproval) {
+  if (dialogs.has(approval.id)) return
+
+  const answer = (approved: boolean) => {
+    dialogs.delete(approval.id)
+    const request = approved ? window.api.actionPolicy.approve : window.api.actionPolicy.deny
+    request(approval.id).catch((error) => Logger.warn('[ActionApprovalService] Approval already answered:', error))
+  }
+
+  const dialog = window.modal.confirm({
+    title: i18n.t('settings.confirmation.prompt.title', { rule: approval.ruleName }),
+    content: createElement('div', { style: { whiteSpace: 'pre-wrap', wordBreak: 'break-all' } }, describe(approval)),
+    okText: i18n.t('settings.confirmation.prompt.approve'),
+    cancelText: i18n.t('settings.confirmation.prompt.deny'),
+    onOk: () => answer(true),
+    onCancel: () => answer(false)
+  })
+  dialogs.set(approval.id, dialog)
+}
+
+let initialized = false
+
+export function initActionApprovals() {
+  if (initialized) return
+  initialized = true
+
+  window.api.actionPolicy.onApprovalRequested(showApproval)
+  window.api.actionPolicy.onApprovalResolved(({ id }) => {
+    dialogs.get(id)?.destroy()
+    dialogs.delete(id)
+  })
+  // 窗口重新加载前已在等待的调用
+  window.api.actionPolicy.listPending().then((approvals) => approvals.forEach(showApproval))
+}
@@ -0,0 +1,89 @@
+import Logger from '@renderer/config/logger'
+import store from '@renderer/store'
+import { updateAgent } from '@renderer/store/agents'
+import { setAgentUpdates } from '@renderer/store/runtime'
+import { Agent, AgentUpdate } from '@renderer/types'
+import { applyAgentUpdate, findUpstreamAgent, getAgentUpdate } from '@renderer/utils/agentSync'
+import { omit } from 'lodash'
+
+// 检查市场智能体更新的间隔
+const AGENT_SYNC_INTERVAL = 6 * 60 * 60 * 1000
+
+/**
+ * 获取市场中的智能体列表，兼容单个智能体和列表两种格式
+ */
+export async function fetchUpstreamAgents(url: string): Promise<Agent[]> {
+  const response = await fetch(url)
+  if (!response.ok) {
+    throw new Error(`HTTP error! Status: ${response.status}`)
+  }
+  const data = await response.json()
+  return Array.isArray(data) ? data : [data]
+}
+
+/**
+ * 检查已安装智能体的更新，同一来源只请求一次
+ */
+export async function checkAgentUpdates(): Promise<Record<string, AgentUpdate>> {
+  const installed = store.getState().agents.agents.filter((agent) => agent.source && !agent.source.pinned)
+  const urls = [...new Set(installed.map((agent) => agent.source!.url))]
+  const updates: Record<string, AgentUpdate> = {}
+
+  for (const url of urls) {
+    let upstream: Agent[]
+    try {
+      upstream = await fetchUpstreamAgents(url)
+    } catch (error) {
+      Logger.warn(`[AgentSyncService] Failed to fetch agents from ${url}:`, error)
+      continue
+    }
+
+    for (const agent of installed.filter((item) => item.source!.url === url)) {
+      const upstreamAgent = findUpstreamAgent(agent, upstream)
+      const update = upstreamAgent && getAgentUpdate(agent, upstreamAgent)
+      if (update) {
+        updates[agent.id] = update
+      }
+    }
+  }
+
+  store.dispatch(setAgentUpdates(updates))
+  Logger.log(`[AgentSyncService] ${Object.keys(updates).length} agent updates available`)
+  return updates
+}
+
+/**
+ * 更新智能体到市场中的最新版本
+ */
+export function updateAgentFromUpstream(agentId: string) {
+  const agent = store.getState().agents.agents.find((item) => item.id === agentId)
+  const updates = store.getState().runtime.agentUpdates
+  const update = updates[agentId]
+  if (!agent || !update) return
+
+  store.dispatch(updateAgent(applyAgentUpdate(agent, update)))
+  store.dispatch(setAgentUpdates(omit(updates, agentId)))
+}
+
+/**
+ * 固定智能体版本，固定后不再提示更新
+ */
+export function setAgentPinned(agentId: string, pinned: boolean) {
+  const agent = store.getState().agents.agents.find((item) => item.id === agentId)
+  if (!agent?.source) return
+
+  store.dispatch(updateAgent({ ...agent, source: { ...agent.source, pinned } }))
+  if (pinned) {
+    store.dispatch(setAgentUpdates(omit(store.getState().runtime.agentUpdates, agentId)))
+  }
+}
+
+let initialized = false
+
+export function initAgentSync() {
+  if (initialized) return
+  initialized = true
+
+  checkAgentUpdates()
+  setInterval(checkAgentUpdates, AGENT_SYNC_INTERVAL)
+}
@@ -0,0 +1,508 @@
+import { StructuredOutputError } from '@renderer/aiCore/middleware/common/StructuredOutputMiddleware'
+import { runHeadless } from '@renderer/aiCore/middleware/feat/ContentGuardMiddleware'
+import { isEmbeddingModel, isRerankModel } from '@renderer/config/models'
+import { BATCH_SUMMARIZE_PROMPT } from '@renderer/config/prompts'
+import db from '@renderer/databases'
+import store from '@renderer/store'
+import { removeManyBlocks, upsertManyBlocks } from '@renderer/store/messageBlock'
+import { loadTopicMessagesThunk, sendMessage } from '@renderer/store/thunk/messageThunk'
+import { ApiServerRequest, Assistant, Model } from '@renderer/types'
+import { Chunk, ChunkType } from '@renderer/types/chunk'
+import { Message } from '@renderer/types/newMessage'
+import { uuid } from '@renderer/utils'
+import { abortCompletion } from '@renderer/utils/abortController'
+import { topicToMarkdown, topicToPlainText } from '@renderer/utils/export'
+import { createMainTextBlock, createMessage } from '@renderer/utils/messageUtils/create'
+import { getMainTextContent } from '@renderer/utils/messageUtils/find'
+import { JSONSchema } from '@renderer/utils/structuredOutput'
+
+import { fetchChatCompletion, fetchGenerate, fetchStructured } from './ApiService'
+import { getAssistantById, getDefaultAssistant } from './AssistantService'
+import { runRendererCommand } from './CommandService'
+import { buildContext } from './ContextBuilder'
+import { EVENT_NAMES, EventEmitter } from './EventService'
+import { getUserMessage } from './MessagesService'
+import { recordStat } from './StatsManager'
+
+/**
+ * 本地 API 服务的渲染进程部分
+ * 主进程把 OpenAI 格式的请求转发过来，这里解析模型、助手和知识库，
+ * 并复用正常对话的请求流程，因此路由、限额和内容过滤等规则同样生效
+ * 同时把对话、助手输出和任务进度事件转发给主进程的 WebSocket 推送通道
+ *
+ * 模型 ID 格式：
+ * - `assistant:<id>` 使用已配置的助手（提示词、知识库、MCP 等）
+ * - `assistant:default` 使用默认助手
+ * - `<providerId>:<modelId>` 使用指定 provider 的模型
+ * - `<modelId>` 使用第一个启用了该模型的 provider
+ */
+
+const ASSISTANT_PREFIX = 'assistant:'
+
+type OpenAIContent = string | { type: string; text?: string }[] | null
+
+interface ChatCompletionRequest {
+  model: string
+  messages: { role: string; content: OpenAIContent }[]
+  stream?: boolean
+  temperature?: number
+  top_p?: number
+  max_tokens?: number
+  max_completion_tokens?: number
+  // json_schema 时校验模型的输出，不符合 schema 时带着错误提示重试
+  response_format?: { type: string; json_schema?: { name?: string; schema?: JSONSchema } }
+  // 扩展字段：检索指定的知识库
+  knowledge_base_ids?: string[]
+}
+
+class ApiServerRequestError extends Error {
+  constructor(
+    public status: number,
+    public code: string,
+    message: string
+  ) {
+    super(message)
+  }
+}
+
+type RequestHandler = (params: any, onChunk: (data: any) => void, requestId: string) => Promise<any>
+
+const handlers: Record<string, RequestHandler> = {
+  models: async () => listModels(),
+  'chat.completions': (params, onChunk, requestId) => createChatCompletion(params, onChunk, requestId),
+  'browser.summarize': (params) => summarizePage(params),
+  'conversation.export': (params) => exportConversation(params),
+  'conversation.context': (params) => buildConversationContext(params),
+  'conversation.send': (params) => sendToConversation(params),
+  'command.run': (params) => runRendererCommand(params.id, params.args)
+}
+
+function listModels() {
+  const { llm, assistants } = store.getState()
+
+  const models = llm.providers
+    .filter((provider) => provider.enabled)
+    .flatMap((provider) =>
+      provider.models
+        .filter((model) => !isEmbeddingModel(model) && !isRerankModel(model))
+        .map((model) => ({ id: `${provider.id}:${model.id}`, object: 'model', created: 0, owned_by: provider.id }))
+    )
+
+  const assistantModels = assistants.assistants.map((assistant) => ({
+    id: `${ASSISTANT_PREFIX}${assistant.id}`,
+    object: 'model',
+    created: 0,
+    owned_by: 'assistant',
+    name: assistant.name
+  }))
+
+  return [...assistantModels, ...models]
+}
+
+function findModel(modelId: string): Model | undefined {
+  const providers = store.getState().llm.providers.filter((provider) => provider.enabled)
+
+  const separator = modelId.indexOf(':')
+  if (separator > 0) {
+    const provider = providers.find((p) => p.id === modelId.slice(0, separator))
+    const model = provider?.models.find((m) => m.id === modelId.slice(separator + 1))
+    if (model) return model
+  }
+
+  for (const provider of providers) {
+    const model = provider.models.find((m) => m.id === modelId)
+    if (model) return model
+  }
+  return undefined
+}
+
+function getText(content: OpenAIContent): string {
+  if (!content) return ''
+  if (typeof content === 'string') return content
+  return content
+    .filter((part) => part.type === 'text' && part.text)
+    .map((part) => part.text)
+    .join('\n')
+}
+
+function resolveAssistant(request: ChatCompletionRequest): Assistant {
+  let assistant: Assistant
+
+  if (request.model.startsWith(ASSISTANT_PREFIX)) {
+    const assistantId = request.model.slice(ASSISTANT_PREFIX.length)
+    const found =
+      assistantId === 'default' ? store.getState().assistants.defaultAssistant : getAssistantById(assistantId)
+    if (!found) {
+      throw new ApiServerRequestError(404, 'model_not_found', `Assistant not found: ${request.model}`)
+    }
+    assistant = { ...found, settings: { ...found.settings } }
+  } else {
+    const model = findModel(request.model)
+    if (!model) {
+      throw new ApiServerRequestError(404, 'model_not_found', `Model not found: ${request.model}`)
+    }
+    assistant = { ...getDefaultAssistant(), model }
+  }
+
+  // 请求中的 system 消息追加到助手提示词之后
+  const system = request.messages
+    .filter((message) => message.role === 'system' || message.role === 'developer')
+    .map((message) => getText(message.content))
+    .filter(Boolean)
+  assistant.prompt = [assistant.prompt, ...system].filter(Boolean).join('\n\n')
+
+  if (request.knowledge_base_ids?.length) {
+    const bases = store.getState().knowledge.bases.filter((base) => request.knowledge_base_ids!.includes(base.id))
+    assistant.knowledge_bases = [...(assistant.knowledge_bases || []), ...bases]
+  }
+
+  const maxTokens = request.max_completion_tokens ?? request.max_tokens
+  assistant.settings = {
+    ...assistant.settings,
+    temperature: request.temperature ?? assistant.settings?.temperature,
+    topP: request.top_p ?? assistant.settings?.topP,
+    enableMaxTokens: maxTokens !== undefined || assistant.settings?.enableMaxTokens,
+    maxTokens: maxTokens ?? assistant.settings?.maxTokens,
+    streamOutput: !!request.stream,
+    // 上下文由调用方控制，不再截断
+    contextCount: 100
+  }
+
+  return assistant
+}
+
+/**
+ * 把 OpenAI 消息转换为临时消息，正文块需要放进 store 才能被读取，请求结束后删除
+ */
+function createMessages(request: ChatCompletionRequest, assistant: Assistant, requestId: string) {
+  const topicId = `api-server-${requestId}`
+  const conversation = request.messages.filter((message) => message.role === 'user' || message.role === 'assistant')
+  const lastUserIndex = conversation.map((message) => message.role).lastIndexOf('user')
+
+  const blocks = conversation.map((message, index) =>
+    // 最后一条用户消息的 ID 用于中断请求
+    createMainTextBlock(index === lastUserIndex ? requestId : uuid(), getText(message.content))
+  )
+  const messages: Message[] = conversation.map((message, index) =>
+    createMessage(message.role as 'user' | 'assistant', topicId, assistant.id, {
+      id: blocks[index].messageId,
+      blocks: [blocks[index].id]
+    })
+  )
+
+  return { messages, blocks }
+}
+
+async function createChatCompletion(request: ChatCompletionRequest, onChunk: (data: any) => void, requestId: string) {
+  const assistant = resolveAssistant(request)
+  const { messages, blocks } = createMessages(request, assistant, requestId)
+  if (!messages.some((message) => message.role === 'user')) {
+    throw new ApiServerRequestError(400, 'invalid_request', 'At least one user message is required')
+  }
+
+  const id = `chatcmpl-${uuid()}`
+  const created = Math.floor(Date.now() / 1000)
+  const base = { id, object: 'chat.completion.chunk', created, model: request.model }
+
+  let text = ''
+  let reasoning = ''
+  let usage: any
+  let error: any
+
+  const onChunkReceived = (chunk: Chunk) => {
+    switch (chunk.type) {
+      case ChunkType.TEXT_DELTA:
+        text += chunk.text
+        if (request.stream) {
+          onChunk({ ...base, choices: [{ index: 0, delta: { content: chunk.text }, finish_reason: null }] })
+        }
+        break
+      case ChunkType.THINKING_DELTA:
+        reasoning += chunk.text
+        if (request.stream) {
+          onChunk({ ...base, choices: [{ index: 0, delta: { reasoning_content: chunk.text }, finish_reason: null }] })
+        }
+        break
+      case ChunkType.BLOCK_COMPLETE:
+        usage = chunk.response?.usage
+        break
+      case ChunkType.ERROR:
+        error = chunk.error
+        break
+    }
+  }
+
+  const schema =
+    request.response_format?.type === 'json_schema' ? request.response_format.json_schema?.schema : undefined
+
+  store.dispatch(upsertManyBlocks(blocks))
+  try {
+    if (schema) {
+      text = await createStructuredContent(messages, assistant, schema, onChunkReceived)
+      if (request.stream) {
+        onChunk({ ...base, choices: [{ index: 0, delta: { content: text }, finish_reason: null }] })
+      }
+    } else {
+      await fetchChatCompletion({ messages, assistant, onChunkReceived })
+    }
+  } finally {
+    store.dispatch(removeManyBlocks(blocks.map((block) => block.id)))
+  }
+
+  if (error) {
+    throw new ApiServerRequestError(error.status || 502, 'upstream_error', error.message || 'Upstream request failed')
+  }
+
+  if (request.model.startsWith(ASSISTANT_PREFIX)) {
+    recordStat('agent_runs', assistant.id)
+    window.api.apiServer.publish(`agent:${assistant.id}`, 'agent.output', {
+      assistantId: assistant.id,
+      source: 'api',
+      text
+    })
+  }
+
+  const usageData = usage && {
+    prompt_tokens: usage.prompt_tokens,
+    completion_tokens: usage.completion_tokens,
+    total_tokens: usage.total_tokens
+  }
+
+  if (request.stream) {
+    onChunk({ ...base, choices: [{ index: 0, delta: {}, finish_reason: 'stop' }], usage: usageData })
+    return null
+  }
+
+  return {
+    id,
+    object: 'chat.completion',
+    created,
+    model: request.model,
+    choices: [
+      {
+        index: 0,
+        message: { role: 'assistant', content: text, ...(reasoning && { reasoning_content: reasoning }) },
+        finish_reason: 'stop'
+      }
+    ],
+    usage: usageData
+  }
+}
+
+/**
+ * 按 response_format 的 JSON Schema 生成结果，只返回校验通过的 JSON
+ * 重试中无效的输出不会转发给调用方，流式请求在校验通过后一次发送全部内容
+ */
+async function createStructuredContent(
+  messages: Message[],
+  assistant: Assistant,
+  schema: JSONSchema,
+  onChunkReceived: (chunk: Chunk) => void
+) {
+  try {
+    const data = await fetchStructured({
+      messages,
+      assistant,
+      schema,
+      callType: 'chat',
+      onChunk: (chunk) => chunk.type === ChunkType.BLOCK_COMPLETE && onChunkReceived(chunk)
+    })
+    return JSON.stringify(data)
+  } catch (error) {
+    if (error instanceof StructuredOutputError) {
+      throw new ApiServerRequestError(422, 'invalid_structured_output', error.message)
+    }
+    throw new ApiServerRequestError(502, 'upstream_error', (error as Error)?.message || 'Upstream request failed')
+  }
+}
+
+/**
+ * 浏览器扩展发来的页面内容摘要
+ */
+async function summarizePage({ text, url, title }: { text: string; url?: string; title?: string }) {
+  const content = [title && `# ${title}`, url, text].filter(Boolean).join('\n\n')
+  const summary = await fetchGenerate({ prompt: BATCH_SUMMARIZE_PROMPT, content })
+  if (!summary) {
+    throw new ApiServerRequestError(502, 'upstream_error', 'Failed to generate summary')
+  }
+  return summary
+}
+
+/**
+ * 导出话题，话题未打开时消息块不在 store 中，需要临时从数据库加载
+ */
+async function exportConversation({ id, format }: { id: string; format: 'markdown' | 'text' | 'json' }) {
+  const topic = store
+    .getState()
+    .assistants.assistants.flatMap((assistant) => assistant.topics)
+    .find((topic) => topic.id === id)
+  if (!topic) {
+    throw new ApiServerRequestError(404, 'not_found', `Conversation not found: ${id}`)
+  }
+  // 加密话题只能在应用内解锁后导出
+  if (topic.secure) {
+    throw new ApiServerRequestError(403, 'forbidden', `Conversation is secure: ${id}`)
+  }
+
+  const messages = (await db.topics.get(id))?.messages ?? []
+  const loaded = store.getState().messageBlocks.entities
+  const blocks = (
+    await db.message_blocks
+      .where('messageId')
+      .anyOf(messages.map((message) => message.id))
+      .toArray()
+  ).filter((block) => !loaded[block.id])
+
+  store.dispatch(upsertManyBlocks(blocks))
+  try {
+    if (format === 'json') {
+      const data = messages.map((message) => ({
+        id: message.id,
+        role: message.role,
+        assistantId: message.assistantId,
+        model: message.model?.id,
+        createdAt: message.createdAt,
+        content: getMainTextContent(message)
+      }))
+      return JSON.stringify({ id: topic.id, name: topic.name, assistantId: topic.assistantId, messages: data }, null, 2)
+    }
+    return format === 'text' ? await topicToPlainText(topic) : await topicToMarkdown(topic)
+  } finally {
+    store.dispatch(removeManyBlocks(blocks.map((block) => block.id)))
+  }
+}
+
+/**
+ * 预览一条新消息发送时组装的上下文，不调用模型
+ */
+async function buildConversationContext({
+  id,
+  message,
+  maxTokens
+}: {
+  id: string
+  message: string
+  maxTokens?: number
+}) {
+  const topic = store
+    .getState()
+    .assistants.assistants.flatMap((assistant) => assistant.topics)
+    .find((topic) => topic.id === id)
+  if (!topic) {
+    throw new ApiServerRequestError(404, 'not_found', `Conversation not found: ${id}`)
+  }
+  if (topic.secure) {
+    throw new ApiServerRequestError(403, 'forbidden', `Conversation is secure: ${id}`)
+  }
+  return buildContext({ topicId: id, message, maxTokens })
+}
+
+/**
+ * 以用户身份向话题发送消息并等待助手回复完成，供定时消息使用
+ */
+async function sendToConversation({
+  topicId,
+  assistantId,
+  content
+}: {
+  topicId: string
+  assistantId: string
+  content: string
+}) {
+  const assistant = getAssistantById(assistantId)
+  const topic = assistant?.topics.find((topic) => topic.id === topicId)
+  if (!assistant || !topic) {
+    throw new ApiServerRequestError(404, 'not_found', `Conversation not found: ${topicId}`)
+  }
+
+  // 话题未打开时先加载历史消息，保证上下文完整
+  await store.dispatch(loadTopicMessagesThunk(topicId))
+
+  const { message, blocks } = getUserMessage({ assistant, topic, content })
+  const completed = new Promise<MessageCompleteEvent>((resolve) => {
+    const off = EventEmitter.on(EVENT_NAMES.MESSAGE_COMPLETE, (data: MessageCompleteEvent) => {
+      if (data.topicId !== topicId || store.getState().messages.entities[data.id]?.askId !== message.id) return
+      off()
+      resolve(data)
+    })
+  })
+
+  const assistantWithTopicPrompt = topic.prompt
+    ? { ...assistant, prompt: `${assistant.prompt}\n${topic.prompt}` }
+    : assistant
+  const result = await runHeadless(topicId, async () => {
+    await store.dispatch(sendMessage(message, blocks, assistantWithTopicPrompt, topicId))
+    return completed
+  })
+  if (result.status === 'error') {
+    throw new ApiServerRequestError(502, 'completion_failed', result.error ?? 'Completion failed')
+  }
+  const reply = store.getState().messages.entities[result.id]
+  return { messageId: result.id, text: reply ? getMainTextContent(reply) : '' }
+}
+
+async function handleRequest({ requestId, method, params }: ApiServerRequest) {
+  const handler = handlers[method]
+
+  try {
+    if (!handler) {
+      throw new ApiServerRequestError(404, 'not_found', `Unknown method: ${method}`)
+    }
+    const onChunk = (chunk: any) => window.api.apiServer.respond(requestId, { type: 'chunk', data: chunk })
+    const data = await handler(params, onChunk, requestId)
+    await window.api.apiServer.respond(requestId, { type: 'result', data })
+  } catch (error: any) {
+    console.error(`[ApiServerService] ${method} failed:`, error)
+    await window.api.apiServer.respond(requestId, {
+      type: 'error',
+      status: error instanceof ApiServerRequestError ? error.status : 500,
+      code: error instanceof ApiServerRequestError ? error.code : 'internal_error',
+      message: error.message || String(error)
+    })
+  }
+}
+
+/**
+ * 把对话和任务事件推送给通过 WebSocket 订阅的外部客户端
+ */
+interface MessageCompleteEvent {
+  id: string
+  topicId: string
+  status: string
+  error?: string
+}
+
+function forwardEvents() {
+  EventEmitter.on(EVENT_NAMES.MESSAGE_COMPLETE, (data: MessageCompleteEvent) => {
+    const message = store.getState().messages.entities[data.id]
+    const text = message ? getMainTextContent(message) : ''
+
+    window.api.apiServer.publish(`conversation:${data.topicId}`, 'message.completed', { ...data, text })
+    if (message?.assistantId) {
+      window.api.apiServer.publish(`agent:${message.assistantId}`, 'agent.output', {
+        assistantId: message.assistantId,
+        topicId: data.topicId,
+        messageId: data.id,
+        status: data.status,
+        source: 'chat',
+        text
+      })
+    }
+  })
+
+  EventEmitter.on(EVENT_NAMES.JOB_PROGRESS, (job: { id: string }) => {
+    window.api.apiServer.publish(`job:${job.id}`, 'job.progress', job)
+  })
+}
+
+let initialized = false
+
+export function initApiServerService() {
+  if (initialized) return
+  initialized = true
+
+  window.api.apiServer.onRequest(handleRequest)
+  window.api.apiServer.onAbort((requestId) => abortCompletion(requestId))
+  forwardEvents()
+  window.api.apiServer.ready()
+}
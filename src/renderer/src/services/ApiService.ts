@@ -24,6 +24,7 @@ import {
   MCPTool,
   Model,
   Provider,
+  ToolCallContext,
   WebSearchResponse,
   WebSearchSource
 } from '@renderer/types'
@@ -34,6 +35,7 @@ import { removeSpecialCharactersForTopicName } from '@renderer/utils'
 import { isAbortError } from '@renderer/utils/error'
 import { extractInfoFromXML, ExtractResults } from '@renderer/utils/extract'
 import { findFileBlocks, getMainTextContent } from '@renderer/utils/messageUtils/find'
+import { JSONSchema } from '@renderer/utils/structuredOutput'
 import { findLast, isEmpty, takeRight } from 'lodash'
 
 import AiProvider from '../aiCore'
@@ -47,6 +49,7 @@ import {
   getTranslateModel
 } from './AssistantService'
 import { getDefaultAssistant } from './AssistantService'
+import { getConversationWorkspaceServer } from './ConversationWorkspaceService'
 import { processKnowledgeSearch } from './KnowledgeService'
 import {
   filterContextMessages,
@@ -257,6 +260,11 @@ async function fetchExternalTool(
     const assistantMcpServers = assistant.mcpServers || []
 
     const enabledMCPs = activedMcpServers.filter((server) => assistantMcpServers.some((s) => s.id === server.id))
+    // 话题挂载了文件夹时，额外提供只能访问该文件夹的文件工具
+    const workspaceServer = getConversationWorkspaceServer(lastUserMessage?.topicId)
+    if (workspaceServer) {
+      enabledMCPs.push(workspaceServer)
+    }
 
     if (enabledMCPs && enabledMCPs.length > 0) {
       try {
@@ -302,11 +310,15 @@ async function fetchExternalTool(
 export async function fetchChatCompletion({
   messages,
   assistant,
-  onChunkReceived
+  onChunkReceived,
+  toolCallContext,
+  interactive = false
 }: {
   messages: Message[]
   assistant: Assistant
   onChunkReceived: (chunk: Chunk) => void
+  toolCallContext?: ToolCallContext
+  interactive?: boolean
   // TODO
   // onChunkStatus: (status: 'searching' | 'processing' | 'success' | 'error') => void
 }) {
@@ -364,6 +376,8 @@ export async function fetchChatCompletion({
       assistant,
       onChunk: onChunkReceived,
       mcpTools: mcpTools,
+      toolCallContext,
+      interactive,
       maxTokens,
       streamOutput: assistant.settings?.streamOutput || false,
       enableReasoning,
@@ -528,9 +542,47 @@ export async function fetchGenerate({ prompt, content }: { prompt: string; conte
   }
 }
 
+/**
+ * 生成符合 JSON Schema 的结构化结果，校验失败时带着错误提示重试
+ * 重试后仍然无效时抛出 StructuredOutputError，请求失败时抛出原始错误
+ */
+export async function fetchStructured<T = any>({
+  messages,
+  assistant,
+  schema,
+  callType = 'generate',
+  onChunk
+}: {
+  messages: string | Message[]
+  assistant: Assistant
+  schema: JSONSchema
+  callType?: CompletionsParams['callType']
+  onChunk?: (chunk: Chunk) => void
+}): Promise<T> {
+  const AI = new AiProvider(getAssistantProvider(assistant))
+
+  const result = await AI.completions({
+    callType,
+    messages,
+    assistant,
+    onChunk,
+    streamOutput: false,
+    shouldThrow: true,
+    responseSchema: schema
+  })
+  return result.parsed as T
+}
+
 function hasApiKey(provider: Provider) {
   if (!provider) return false
-  if (provider.id === 'ollama' || provider.id === 'lmstudio' || provider.type === 'vertexai') return true
+  if (
+    provider.id === 'ollama' ||
+    provider.id === 'lmstudio' ||
+    provider.type === 'vertexai' ||
+    provider.type === 'aws-bedrock' ||
+    provider.type === 'local'
+  )
+    return true
   return !isEmpty(provider.apiKey)
 }
 
@@ -556,6 +608,8 @@ export function checkApiProvider(provider: Provider): void {
     provider.id !== 'ollama' &&
     provider.id !== 'lmstudio' &&
     provider.type !== 'vertexai' &&
+    provider.type !== 'aws-bedrock' &&
+    provider.type !== 'local' &&
     provider.id !== 'copilot'
   ) {
     if (!provider.apiKey) {
@@ -564,7 +618,7 @@ export function checkApiProvider(provider: Provider): void {
     }
   }
 
-  if (!provider.apiHost && provider.type !== 'vertexai') {
+  if (!provider.apiHost && !['vertexai', 'aws-bedrock', 'local'].includes(provider.type)) {
     window.message.error({ content: i18n.t('message.error.enter.api.host'), key, style })
     throw new Error(i18n.t('message.error.enter.api.host'))
   }
@@ -0,0 +1,61 @@
+import Logger from '@renderer/config/logger'
+import i18n from '@renderer/i18n'
+import store from '@renderer/store'
+import { getManagerErrorTitle } from '@renderer/utils/error'
+import { ManagerError } from '@shared/ManagerError'
+
+interface RecoveryAction {
+  label: string
+  run: () => Promise<unknown>
+}
+
+/**
+ * 可恢复错误的恢复操作，按出错的模块和错误中的 details 查找
+ */
+function getRecoveryAction(error: ManagerError): RecoveryAction | null {
+  if (!error.recoverable) return null
+
+  switch (error.manager) {
+    case 'MCPService': {
+      const server = store.getState().mcp.servers.find((item) => item.id === error.details?.serverId)
+      if (!server) return null
+      return { label: 'error.recovery.restart_mcp_server', run: () => window.api.mcp.restartServer(server) }
+    }
+    default:
+      return null
+  }
+}
+
+/**
+ * 显示主进程后台任务的错误，可恢复的错误提供恢复操作
+ */
+export function showAppError(error: ManagerError) {
+  const title = getManagerErrorTitle(error)
+  const action = getRecoveryAction(error)
+
+  if (!action) {
+    window.message.error({ content: `${title}: ${error.message}`, key: `app-error-${error.manager}` })
+    return
+  }
+
+  window.modal.confirm({
+    title,
+    content: error.message,
+    okText: i18n.t(action.label),
+    cancelText: i18n.t('common.close'),
+    onOk: () =>
+      action.run().catch((cause) => {
+        Logger.error(`[AppErrorService] Recovery of ${error.manager} failed:`, cause)
+        window.message.error({ content: i18n.t('error.recovery.failed'), key: `app-error-${error.manager}` })
+      })
+  })
+}
+
+let initialized = false
+
+export function initAppErrorService() {
+  if (initialized) return
+  initialized = true
+
+  window.api.onAppError((data) => showAppError(ManagerError.fromJSON(data)))
+}
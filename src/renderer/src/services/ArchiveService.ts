@@ -0,0 +1,127 @@
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import i18n from '@renderer/i18n'
+import store from '@renderer/store'
+import { addTopic, removeTopic } from '@renderer/store/assistants'
+import { removeManyBlocks } from '@renderer/store/messageBlock'
+import { newMessagesActions } from '@renderer/store/newMessage'
+import { ArchivedTopic, Topic } from '@renderer/types'
+import { hasTopicPendingRequests } from '@renderer/utils/queue'
+import dayjs from 'dayjs'
+import { omit } from 'lodash'
+
+import { unmountConversationWorkspace } from './ConversationWorkspaceService'
+
+// 自动归档的检查间隔
+const AUTO_ARCHIVE_INTERVAL = 24 * 60 * 60 * 1000
+
+/**
+ * 归档话题
+ * 消息和消息块写入主进程的按月压缩归档文件后，从数据库和话题列表中移除，不再参与查询和搜索。
+ * 消息引用的文件保留，恢复后仍可使用。加密话题不归档，归档文件不受话题密钥保护
+ */
+export async function archiveTopic(topic: Topic): Promise<void> {
+  if (topic.secure) {
+    throw new Error(i18n.t('chat.topics.secure.no_archive'))
+  }
+  const messages = (await db.topics.get(topic.id))?.messages ?? []
+  const messageIds = messages.map((message) => message.id)
+  const blocks = await db.message_blocks.where('messageId').anyOf(messageIds).toArray()
+
+  await window.api.archive.add({
+    topic: omit(topic, 'messages'),
+    messages,
+    blocks,
+    archivedAt: Date.now()
+  })
+
+  await db.transaction('rw', db.topics, db.message_blocks, async () => {
+    await db.message_blocks.bulkDelete(blocks.map((block) => block.id))
+    await db.topics.delete(topic.id)
+  })
+
+  store.dispatch(removeManyBlocks(blocks.map((block) => block.id)))
+  store.dispatch(newMessagesActions.clearTopicMessages(topic.id))
+  store.dispatch(removeTopic({ assistantId: topic.assistantId, topic }))
+  await unmountConversationWorkspace(topic.id)
+  Logger.log(`[ArchiveService] Archived topic ${topic.id} with ${messages.length} messages`)
+}
+
+/**
+ * 恢复归档话题，原助手已删除时恢复到第一个助手
+ * 数据写回数据库后才从归档中删除
+ */
+export async function unarchiveTopic(topicId: string): Promise<Topic> {
+  const { topic, messages, blocks } = await window.api.archive.get(topicId)
+  const assistants = store.getState().assistants.assistants
+  const assistant = assistants.find((item) => item.id === topic.assistantId) ?? assistants[0]
+
+  await db.transaction('rw', db.topics, db.message_blocks, async () => {
+    await db.message_blocks.bulkPut(blocks)
+    await db.topics.put({ id: topic.id, messages })
+  })
+
+  const restored: Topic = { ...topic, assistantId: assistant.id, messages: [] }
+  store.dispatch(addTopic({ assistantId: assistant.id, topic: restored }))
+  await window.api.archive.remove(topicId)
+  return restored
+}
+
+export async function listArchivedTopics(): Promise<ArchivedTopic[]> {
+  return window.api.archive.list()
+}
+
+/**
+ * 获取话题最后活动时间，取话题更新时间和最后一条消息时间中较晚的一个
+ */
+async function getLastActivity(topic: Topic): Promise<dayjs.Dayjs> {
+  const messages = (await db.topics.get(topic.id))?.messages ?? []
+  const lastMessage = messages[messages.length - 1]
+  const updatedAt = dayjs(topic.updatedAt)
+  return lastMessage && dayjs(lastMessage.createdAt).isAfter(updatedAt) ? dayjs(lastMessage.createdAt) : updatedAt
+}
+
+/**
+ * 按保留策略自动归档长时间没有活动的话题
+ * 置顶话题、加密话题和正在请求的话题不归档，每个助手至少保留一个话题
+ */
+export async function autoArchiveTopics(): Promise<number> {
+  const days = store.getState().settings.archiveAfterDays
+  if (!days || days <= 0) return 0
+
+  const threshold = dayjs().subtract(days, 'day')
+  let archived = 0
+
+  for (const assistant of store.getState().assistants.assistants) {
+    let remaining = assistant.topics.length
+
+    for (const topic of assistant.topics) {
+      if (remaining <= 1) break
+      if (topic.pinned || topic.secure || hasTopicPendingRequests(topic.id)) continue
+      if (!(await getLastActivity(topic)).isBefore(threshold)) continue
+
+      try {
+        await archiveTopic(topic)
+        remaining--
+        archived++
+      } catch (error) {
+        Logger.error(`[ArchiveService] Failed to archive topic ${topic.id}:`, error)
+      }
+    }
+  }
+
+  if (archived > 0) {
+    Logger.log(`[ArchiveService] Auto archived ${archived} topics older than ${days} days`)
+  }
+  return archived
+}
+
+let initialized = false
+
+export function initArchiveService() {
+  if (initialized) return
+  initialized = true
+
+  autoArchiveTopics()
+  setInterval(autoArchiveTopics, AUTO_ARCHIVE_INTERVAL)
+}
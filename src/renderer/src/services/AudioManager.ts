@@ -0,0 +1,105 @@
+import Logger from '@renderer/config/logger'
+import { VadOptions, VoiceActivityDetector } from '@renderer/utils/vad'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+
+export type SpeechSegmentHandler = (segment: Float32Array, sampleRate: number) => void | Promise<void>
+
+// 把麦克风采样从音频线程转发到主线程
+const CAPTURE_WORKLET = `
+class CaptureProcessor extends AudioWorkletProcessor {
+  process(inputs) {
+    const channel = inputs[0] && inputs[0][0]
+    if (channel) this.port.postMessage(channel.slice(0))
+    return true
+  }
+}
+registerProcessor('capture-processor', CaptureProcessor)
+`
+
+/**
+ * 免按键语音输入的音频采集
+ * 麦克风输入经过语音活动检测（VAD）自动切分，发出 SPEECH_STARTED、SPEECH_ENDED 事件，
+ * 每个语音段交给通过 onSegment 注册的处理函数（语音识别等）
+ */
+class AudioManager {
+  private context: AudioContext | null = null
+  private stream: MediaStream | null = null
+  private node: AudioWorkletNode | null = null
+  private vad: VoiceActivityDetector | null = null
+  private handlers = new Set<SpeechSegmentHandler>()
+
+  public get listening() {
+    return !!this.context
+  }
+
+  public async start(options: Omit<VadOptions, 'sampleRate'> = {}) {
+    if (this.context) return
+
+    this.stream = await navigator.mediaDevices.getUserMedia({
+      audio: { channelCount: 1, echoCancellation: true, noiseSuppression: true, autoGainControl: true }
+    })
+    const context = new AudioContext()
+    const workletUrl = URL.createObjectURL(new Blob([CAPTURE_WORKLET], { type: 'application/javascript' }))
+    try {
+      await context.audioWorklet.addModule(workletUrl)
+    } finally {
+      URL.revokeObjectURL(workletUrl)
+    }
+
+    this.context = context
+    this.vad = new VoiceActivityDetector({ ...options, sampleRate: context.sampleRate })
+    this.node = new AudioWorkletNode(context, 'capture-processor')
+    this.node.port.onmessage = (event: MessageEvent<Float32Array>) => this.onSamples(event.data)
+    context.createMediaStreamSource(this.stream).connect(this.node)
+    Logger.log(`[AudioManager] Listening at ${context.sampleRate} Hz`)
+  }
+
+  public async stop() {
+    const event = this.vad?.flush()
+    if (event?.type === 'speech-ended' && this.context) {
+      this.emitSegment(event.segment, this.context.sampleRate)
+    }
+
+    this.node?.disconnect()
+    this.stream?.getTracks().forEach((track) => track.stop())
+    await this.context?.close()
+    this.node = null
+    this.stream = null
+    this.context = null
+    this.vad = null
+  }
+
+  /**
+   * 注册语音段处理函数，返回取消注册的函数
+   */
+  public onSegment(handler: SpeechSegmentHandler) {
+    this.handlers.add(handler)
+    return () => {
+      this.handlers.delete(handler)
+    }
+  }
+
+  private onSamples(samples: Float32Array) {
+    if (!this.vad || !this.context) return
+
+    for (const event of this.vad.process(samples)) {
+      if (event.type === 'speech-started') {
+        EventEmitter.emit(EVENT_NAMES.SPEECH_STARTED, { time: event.time })
+      } else {
+        this.emitSegment(event.segment, this.context.sampleRate)
+      }
+    }
+  }
+
+  private emitSegment(segment: Float32Array, sampleRate: number) {
+    EventEmitter.emit(EVENT_NAMES.SPEECH_ENDED, { duration: (segment.length / sampleRate) * 1000 })
+    for (const handler of this.handlers) {
+      Promise.resolve(handler(segment, sampleRate)).catch((error) => {
+        Logger.error('[AudioManager] Speech segment handler failed:', error)
+      })
+    }
+  }
+}
+
+export const audioManager = new AudioManager()
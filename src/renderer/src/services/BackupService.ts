@@ -16,6 +16,7 @@ export async function backup(skipBackupFile: boolean) {
   if (selectFolder) {
     await window.api.backup.backup(filename, fileContnet, selectFolder, skipBackupFile)
     window.message.success({ content: i18n.t('message.backup.success'), key: 'backup' })
+    window.api.outbound.emit('backup.completed', { fileName: filename, target: 'local' })
   }
 }
 
@@ -138,6 +139,7 @@ export async function backupToWebdav({
         source: 'backup'
       })
       showMessage && window.message.success({ content: i18n.t('message.backup.success'), key: 'backup' })
+      window.api.outbound.emit('backup.completed', { fileName: finalFileName, target: 'webdav' })
 
       // 清理旧备份文件
       if (webdavMaxBackups > 0) {
@@ -0,0 +1,140 @@
+import AiProvider from '@renderer/aiCore'
+import { CompletionsParams } from '@renderer/aiCore/middleware/schemas'
+import { BATCH_SUMMARIZE_PROMPT } from '@renderer/config/prompts'
+import { Job, Model } from '@renderer/types'
+
+import { getDefaultAssistant, getProviderByModel } from './AssistantService'
+import { checkpointJob, createJob, isJobPaused, registerJobRunner, resumeInterruptedJobs, runJob } from './JobManager'
+import { acquireProviderSlot, hasProviderLimits } from './RateLimitService'
+import { estimateTextTokens } from './TokenService'
+
+// 单次向量化请求的条数与 token 上限，大多数 provider 都能接受
+const EMBED_CHUNK_SIZE = 64
+const EMBED_CHUNK_TOKENS = 8000
+const DEFAULT_CONCURRENCY = 4
+
+/**
+ * 把未完成的输入按条数和 token 数切分成若干批
+ */
+function chunkPendingIndices(job: Job<string>, maxItems: number, maxTokens: number): number[][] {
+  const chunks: number[][] = []
+  let current: number[] = []
+  let tokens = 0
+
+  job.inputs.forEach((input, index) => {
+    if (job.outputs[index] !== null) return
+
+    const inputTokens = estimateTextTokens(input)
+    if (current.length > 0 && (current.length >= maxItems || tokens + inputTokens > maxTokens)) {
+      chunks.push(current)
+      current = []
+      tokens = 0
+    }
+    current.push(index)
+    tokens += inputTokens
+  })
+
+  if (current.length > 0) {
+    chunks.push(current)
+  }
+  return chunks
+}
+
+export async function runPool(tasks: (() => Promise<void>)[], concurrency: number) {
+  let next = 0
+  const worker = async () => {
+    while (next < tasks.length) {
+      await tasks[next++]()
+    }
+  }
+  await Promise.all(Array.from({ length: Math.min(concurrency, tasks.length) }, worker))
+}
+
+async function runEmbedJob(job: Job<string, number[]>) {
+  const provider = getProviderByModel(job.model)
+  const AI = new AiProvider(provider)
+  const chunks = chunkPendingIndices(job, EMBED_CHUNK_SIZE, EMBED_CHUNK_TOKENS)
+
+  const tasks = chunks.map((indices) => async () => {
+    if (isJobPaused(job.id)) return
+
+    const texts = indices.map((index) => job.inputs[index])
+    const tokens = texts.reduce((sum, text) => sum + estimateTextTokens(text), 0)
+    // 向量化请求不经过 completions 中间件，这里手动占用 provider 的限额
+    const release = hasProviderLimits(provider) ? await acquireProviderSlot(provider, 'embed', tokens) : undefined
+
+    try {
+      const vectors = await AI.embed(texts, job.model)
+      if (vectors.length !== texts.length) {
+        throw new Error(`Expected ${texts.length} embeddings, got ${vectors.length}`)
+      }
+      await checkpointJob(job, indices.map((index, i): [number, number[]] => [index, vectors[i]]))
+    } finally {
+      release?.(tokens)
+    }
+  })
+
+  await runPool(tasks, provider.limits?.maxConcurrency || DEFAULT_CONCURRENCY)
+}
+
+async function runSummarizeJob(job: Job<string, string>) {
+  const provider = getProviderByModel(job.model)
+  const AI = new AiProvider(provider)
+
+  const assistant = getDefaultAssistant()
+  assistant.model = job.model
+  assistant.prompt = BATCH_SUMMARIZE_PROMPT
+
+  // 摘要逐条请求，限额由 RateLimitMiddleware 负责
+  const tasks = job.inputs.map((input, index) => async () => {
+    if (isJobPaused(job.id) || job.outputs[index] !== null) return
+
+    const params: CompletionsParams = {
+      callType: 'summary',
+      messages: input,
+      assistant,
+      streamOutput: false
+    }
+    const { getText } = await AI.completions(params)
+    await checkpointJob(job, [[index, getText().trim()]])
+  })
+
+  await runPool(tasks, provider.limits?.maxConcurrency || DEFAULT_CONCURRENCY)
+}
+
+registerJobRunner('embed', runEmbedJob)
+registerJobRunner('summarize', runSummarizeJob)
+
+async function runBatch<TOutput>(type: Job['type'], inputs: string[], model: Model): Promise<TOutput[]> {
+  const job = await createJob<string, TOutput>(type, model, inputs)
+  const result = await runJob(job)
+
+  if (result.status !== 'completed') {
+    throw new Error(`Batch job ${job.id} was ${result.status}`)
+  }
+  return result.outputs as TOutput[]
+}
+
+/**
+ * 批量向量化，进度会按批保存，应用重启后自动继续
+ * @param texts 待向量化的文本
+ * @param model 向量模型
+ * @returns 与 texts 顺序一致的向量
+ */
+export async function embedBatch(texts: string[], model: Model): Promise<number[][]> {
+  return runBatch<number[]>('embed', texts, model)
+}
+
+/**
+ * 批量摘要，进度会逐条保存，应用重启后自动继续
+ * @param items 待摘要的文本
+ * @param model 使用的模型
+ * @returns 与 items 顺序一致的摘要
+ */
+export async function summarizeBatch(items: string[], model: Model): Promise<string[]> {
+  return runBatch<string>('summarize', items, model)
+}
+
+export function resumeBatchJobs() {
+  return resumeInterruptedJobs()
+}
@@ -0,0 +1,130 @@
+import { CommandInfo } from '@renderer/types'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+import NavigationService from './NavigationService'
+
+/**
+ * 命令面板的渲染进程命令
+ * 命令的元数据注册到主进程的命令注册表，命令面板和 CLI 统一通过主进程列出和调用，
+ * 调用渲染进程命令时主进程通过本地 API 的转发通道交给这里执行
+ */
+
+export interface RendererCommand extends Omit<CommandInfo, 'source' | 'args'> {
+  args?: CommandInfo['args']
+  run: (args: Record<string, any>) => unknown
+}
+
+const PAGES: Record<string, string> = {
+  home: '/',
+  agents: '/agents',
+  paintings: '/paintings',
+  translate: '/translate',
+  files: '/files',
+  knowledge: '/knowledge',
+  apps: '/apps'
+}
+
+const SETTINGS_SECTIONS = [
+  'provider',
+  'model',
+  'web-search',
+  'mcp',
+  'general',
+  'display',
+  'shortcut',
+  'quickAssistant',
+  'selectionAssistant',
+  'data',
+  'about',
+  'quickPhrase'
+]
+
+const commands = new Map<string, RendererCommand>()
+let syncScheduled = false
+
+// 同一轮注册的命令只同步一次
+function scheduleSync() {
+  if (syncScheduled) return
+  syncScheduled = true
+  setTimeout(() => {
+    syncScheduled = false
+    const infos = [...commands.values()].map(({ run: _run, ...info }) => ({ ...info, args: info.args ?? [] }))
+    window.api.commands.registerRenderer(infos).catch((error) => console.error('Failed to register commands:', error))
+  }, 0)
+}
+
+export function registerCommand(command: RendererCommand) {
+  commands.set(command.id, command)
+  scheduleSync()
+}
+
+export function unregisterCommand(id: string) {
+  if (commands.delete(id)) scheduleSync()
+}
+
+/**
+ * 执行渲染进程命令，参数已经由主进程按命令的参数定义校验
+ */
+export async function runRendererCommand(id: string, args: Record<string, any> = {}) {
+  const command = commands.get(id)
+  if (!command) throw new Error(`Unknown command: ${id}`)
+  return (await command.run(args)) ?? null
+}
+
+function navigateHome() {
+  NavigationService.navigate?.('/')
+}
+
+let initialized = false
+
+export function initCommandService() {
+  if (initialized) return
+  initialized = true
+
+  registerCommand({
+    id: 'topic.new',
+    title: 'New topic',
+    category: 'Chat',
+    run: () => {
+      navigateHome()
+      // 输入框可能尚未挂载
+      setTimeout(() => EventEmitter.emit(EVENT_NAMES.ADD_NEW_TOPIC), 100)
+    }
+  })
+  registerCommand({
+    id: 'topic.clear',
+    title: 'Clear messages of the current topic',
+    category: 'Chat',
+    run: () => {
+      navigateHome()
+      setTimeout(() => EventEmitter.emit(EVENT_NAMES.CLEAR_MESSAGES), 100)
+    }
+  })
+  registerCommand({
+    id: 'sidebar.show-assistants',
+    title: 'Show assistants',
+    category: 'Chat',
+    run: () => {
+      navigateHome()
+      setTimeout(() => EventEmitter.emit(EVENT_NAMES.SHOW_ASSISTANTS), 100)
+    }
+  })
+  registerCommand({
+    id: 'app.open-page',
+    title: 'Open page',
+    category: 'App',
+    args: [{ name: 'page', type: 'string', required: true, enum: Object.keys(PAGES) }],
+    run: ({ page }) => {
+      NavigationService.navigate?.(PAGES[page])
+    }
+  })
+  registerCommand({
+    id: 'settings.open',
+    title: 'Open settings',
+    category: 'App',
+    args: [{ name: 'section', type: 'string', enum: SETTINGS_SECTIONS }],
+    run: ({ section }) => {
+      NavigationService.navigate?.(`/settings/${section ?? 'provider'}`)
+    }
+  })
+}
@@ -0,0 +1,122 @@
+import AiProvider from '@renderer/aiCore'
+import { CompletionsParams } from '@renderer/aiCore/middleware/schemas'
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import store from '@renderer/store'
+import { SettingsState } from '@renderer/store/settings'
+import { Assistant, ContentFilterLog } from '@renderer/types'
+import { uuid } from '@renderer/utils'
+import {
+  CLASSIFIER_RULE,
+  ContentFilterAction,
+  ContentFilterDirection,
+  ContentFilterHit,
+  filterContent,
+  isUnsafeClassification
+} from '@renderer/utils/contentFilter'
+
+import { getDefaultAssistant, getProviderByModel } from './AssistantService'
+
+// 审计日志保留的条数上限
+const MAX_LOG_ENTRIES = 5000
+
+export function getContentFilterSettings(): SettingsState['contentFilter'] {
+  return store.getState().settings.contentFilter
+}
+
+/**
+ * 获取助手实际使用的过滤动作，助手设置优先于全局设置
+ * @returns 过滤动作，未启用时返回 null
+ */
+export function getContentFilterAction(assistant: Assistant): ContentFilterAction | null {
+  const settings = getContentFilterSettings()
+  const override = assistant.settings?.contentFilter
+
+  if (override === 'off') return null
+  if (override) return override
+  return settings?.enabled ? settings.action : null
+}
+
+/**
+ * 使用本地安全分类模型判断内容是否安全，模型不可用时视为安全
+ */
+async function classifyContent(text: string): Promise<boolean> {
+  const model = getContentFilterSettings().classifierModel
+  if (!model || !text.trim()) return false
+
+  const assistant = getDefaultAssistant()
+  assistant.model = model
+  assistant.prompt = ''
+
+  const params: CompletionsParams = {
+    callType: 'generate',
+    messages: text,
+    assistant,
+    streamOutput: false
+  }
+
+  try {
+    const { getText } = await new AiProvider(getProviderByModel(model)).completions(params)
+    return isUnsafeClassification(getText())
+  } catch (error) {
+    Logger.warn('[ContentFilterService] Classifier request failed:', error)
+    return false
+  }
+}
+
+/**
+ * 按规则和分类模型检查内容
+ * @param value 文本或请求数据，action 为 redact 时返回替换后的数据
+ * @param classifierText 交给分类模型判断的文本
+ * @returns 处理后的数据、命中情况，以及是否需要阻止
+ */
+export async function checkContent<T>(
+  value: T,
+  classifierText: string,
+  action: ContentFilterAction
+): Promise<{ value: T; hits: ContentFilterHit[]; blocked: boolean }> {
+  const { rules } = getContentFilterSettings()
+  const result = filterContent(value, rules, action === 'redact')
+
+  // 分类模型只能判断整段内容，无法定位到具体片段，redact 时也按阻止处理
+  const unsafe = await classifyContent(classifierText)
+  if (unsafe) {
+    result.hits.push({ rule: CLASSIFIER_RULE, count: 1 })
+  }
+
+  return { ...result, blocked: action === 'block' ? result.hits.length > 0 : unsafe && action === 'redact' }
+}
+
+export async function logContentFilterHits(entry: {
+  direction: ContentFilterDirection
+  action: ContentFilterAction
+  assistant: Assistant
+  topicId?: string
+  hits: ContentFilterHit[]
+}): Promise<void> {
+  const log: ContentFilterLog = {
+    id: uuid(),
+    direction: entry.direction,
+    action: entry.action,
+    assistantId: entry.assistant.id,
+    topicId: entry.topicId,
+    model: entry.assistant.model?.id,
+    hits: entry.hits,
+    createdAt: Date.now()
+  }
+
+  try {
+    await db.content_filter_logs.add(log)
+    const overflow = (await db.content_filter_logs.count()) - MAX_LOG_ENTRIES
+    if (overflow > 0) {
+      const oldest = await db.content_filter_logs.orderBy('createdAt').limit(overflow).primaryKeys()
+      await db.content_filter_logs.bulkDelete(oldest)
+    }
+  } catch (error) {
+    Logger.warn('[ContentFilterService] Failed to write audit log:', error)
+  }
+}
+
+export async function clearContentFilterLogs(): Promise<void> {
+  await db.content_filter_logs.clear()
+}
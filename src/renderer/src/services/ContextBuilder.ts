@@ -0,0 +1,94 @@
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import store from '@renderer/store'
+import { KnowledgeBase } from '@renderer/types'
+import { assembleContext, BuiltContext, ContextHistoryItem, ContextKnowledgeItem } from '@renderer/utils/contextBuilder'
+import { filterContextMessages } from '@renderer/utils/messageUtils/filters'
+import { getMainTextContentFromBlocks } from '@renderer/utils/messageUtils/find'
+
+import { getAssistantSettings } from './AssistantService'
+import { getKnowledgeSourceUrl, searchKnowledgeBase } from './KnowledgeService'
+import { estimateTextTokens } from './TokenService'
+
+// 模型的上下文窗口未知时使用的预算
+export const DEFAULT_CONTEXT_BUDGET = 32000
+const MEMORY_LIMIT = 5
+
+export interface BuildContextOptions {
+  topicId: string
+  message: string
+  maxTokens?: number
+}
+
+/**
+ * 为话题中的一条新消息组装上下文：系统提示词、按预算裁剪的历史、知识库片段和记忆
+ * 供命令行和本地 API 预览上下文，返回组装结果和各部分的 token 统计。界面上的对话仍由 fetchChatCompletion 组装
+ */
+export async function buildContext({ topicId, message, maxTokens }: BuildContextOptions): Promise<BuiltContext> {
+  const assistant = store.getState().assistants.assistants.find((a) => a.topics.some((t) => t.id === topicId))
+  const topic = assistant?.topics.find((t) => t.id === topicId)
+  if (!assistant || !topic) {
+    throw new Error(`Conversation not found: ${topicId}`)
+  }
+  // 加密话题的内容只能在应用内解锁后读取
+  if (topic.secure) {
+    throw new Error(`Conversation is secure: ${topicId}`)
+  }
+
+  // 直接从数据库读取消息块，不写入 store，避免影响界面上正在显示的消息
+  const messages = filterContextMessages((await db.topics.get(topicId))?.messages ?? [])
+  const blocks = await db.message_blocks
+    .where('messageId')
+    .anyOf(messages.map((m) => m.id))
+    .toArray()
+  const blocksById = new Map(blocks.map((block) => [block.id, block]))
+
+  const history: ContextHistoryItem[] = messages
+    .filter((m) => (m.role === 'user' || m.role === 'assistant') && m.status !== 'error')
+    .map((m) => ({
+      id: m.id,
+      role: m.role as ContextHistoryItem['role'],
+      content: getMainTextContentFromBlocks(m, blocksById)
+    }))
+    .filter((item) => item.content.trim())
+
+  const [knowledge, memories] = await Promise.all([
+    searchKnowledge(assistant.knowledge_bases ?? [], message),
+    searchMemories(message)
+  ])
+
+  return assembleContext({
+    systemPrompt: [assistant.prompt, topic.prompt].filter(Boolean).join('\n'),
+    history,
+    message,
+    knowledge,
+    memories,
+    maxTokens: maxTokens ?? DEFAULT_CONTEXT_BUDGET,
+    contextCount: getAssistantSettings(assistant).contextCount,
+    countTokens: estimateTextTokens
+  })
+}
+
+async function searchKnowledge(bases: KnowledgeBase[], query: string): Promise<ContextKnowledgeItem[]> {
+  const results = await Promise.all(bases.map((base) => searchKnowledgeBase(query, base)))
+  return Promise.all(
+    results.flat().map(async (item) => ({
+      content: item.pageContent,
+      sourceUrl: await getKnowledgeSourceUrl(item),
+      score: item.score
+    }))
+  )
+}
+
+async function searchMemories(query: string) {
+  try {
+    const results: { memory: { id: string; content: string }; score: number }[] = await window.api.memory.search(
+      query,
+      MEMORY_LIMIT
+    )
+    return results.map(({ memory, score }) => ({ id: memory.id, content: memory.content, score }))
+  } catch (error) {
+    Logger.error('[ContextBuilder] Failed to search memories:', error)
+    return []
+  }
+}
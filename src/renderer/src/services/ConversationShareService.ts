@@ -0,0 +1,34 @@
+import { TopicManager } from '@renderer/hooks/useTopic'
+import store from '@renderer/store'
+import { ShareConversationOptions, SharedConversation, SharedConversationMessage, Topic } from '@renderer/types'
+import { getMainTextContent } from '@renderer/utils/messageUtils/find'
+import dayjs from 'dayjs'
+
+import { getAssistantById } from './AssistantService'
+
+/**
+ * 把话题渲染成独立的 HTML 文件，复制文件路径，或在 API 服务器上生成有效期内的分享链接并复制链接
+ */
+export async function shareConversation(
+  topic: Topic,
+  options: ShareConversationOptions = {}
+): Promise<SharedConversation> {
+  const { userName } = store.getState().settings
+  const messages = await TopicManager.getTopicMessages(topic.id)
+
+  const sharedMessages: SharedConversationMessage[] = messages
+    .filter((message) => message.role !== 'system')
+    .map((message) => ({
+      role: message.role,
+      name:
+        message.role === 'user'
+          ? userName
+          : (getAssistantById(message.assistantId)?.name ?? message.model?.name ?? ''),
+      content: getMainTextContent(message),
+      createdAt: dayjs(message.createdAt).format('YYYY-MM-DD HH:mm')
+    }))
+
+  const shared = await window.api.conversationShare.share(topic.id, topic.name, sharedMessages, options)
+  await navigator.clipboard.writeText(shared.url ?? shared.filePath)
+  return shared
+}
@@ -0,0 +1,131 @@
+import Logger from '@renderer/config/logger'
+import i18n from '@renderer/i18n'
+import { ConversationWorkspace, MCPServer, PatchResult } from '@renderer/types'
+import { getErrorMessage } from '@renderer/utils/error'
+import { ManagerError } from '@shared/ManagerError'
+import { omit } from 'lodash'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+
+const SERVER_ID_PREFIX = 'workspace:'
+
+let workspaces: Record<string, ConversationWorkspace> = {}
+
+function update(next: Record<string, ConversationWorkspace>) {
+  workspaces = next
+  EventEmitter.emit(EVENT_NAMES.CONVERSATION_WORKSPACES_CHANGED)
+}
+
+/**
+ * 所有话题挂载的文件夹，按话题 ID 索引，每次变化都是新对象
+ */
+export function getConversationWorkspaces(): Record<string, ConversationWorkspace> {
+  return workspaces
+}
+
+/**
+ * 话题挂载的文件夹
+ */
+export function getConversationWorkspace(topicId?: string): ConversationWorkspace | undefined {
+  return topicId ? workspaces[topicId] : undefined
+}
+
+/**
+ * 挂载了文件夹的话题使用的文件系统 MCP 服务器，只能访问挂载的文件夹，修改的文件由主进程记录
+ */
+export function getConversationWorkspaceServer(topicId?: string): MCPServer | undefined {
+  const workspace = getConversationWorkspace(topicId)
+  if (!workspace) return undefined
+
+  return {
+    id: `${SERVER_ID_PREFIX}${workspace.topicId}`,
+    name: '@cherry/workspace',
+    type: 'inMemory',
+    args: [workspace.path],
+    env: { TOPIC_ID: workspace.topicId },
+    isActive: true,
+    provider: 'CherryAI'
+  }
+}
+
+export function getConversationWorkspaceServerById(serverId: string): MCPServer | undefined {
+  if (!serverId.startsWith(SERVER_ID_PREFIX)) return undefined
+  return getConversationWorkspaceServer(serverId.slice(SERVER_ID_PREFIX.length))
+}
+
+/**
+ * 选择文件夹并挂载到话题，取消选择时返回 null
+ */
+export async function mountConversationWorkspace(topicId: string): Promise<ConversationWorkspace | null> {
+  const dirPath = await window.api.file.selectFolder()
+  if (!dirPath) return null
+
+  await unmountConversationWorkspace(topicId)
+  const workspace = await window.api.conversationWorkspace.mount(topicId, dirPath)
+  update({ ...workspaces, [topicId]: workspace })
+  return workspace
+}
+
+/**
+ * 卸载话题的文件夹，关闭对应的 MCP 服务器并丢弃修改记录，文件夹中的文件保持不变
+ */
+export async function unmountConversationWorkspace(topicId: string) {
+  const server = getConversationWorkspaceServer(topicId)
+  if (!server) return
+
+  update(omit(workspaces, topicId))
+  await window.api.mcp.removeServer(server).catch((error) => {
+    Logger.error('[ConversationWorkspace] Failed to stop the workspace server:', error)
+  })
+  await window.api.conversationWorkspace.unmount(topicId)
+}
+
+function showPatchError(error: unknown) {
+  const conflict = ManagerError.parse(error)?.code === 'conflict'
+  const content = conflict ? i18n.t('code_block.patch.conflict') : getErrorMessage(error)
+  window.message.error({ content, key: 'workspace-patch' })
+}
+
+/**
+ * 将对话中的补丁应用到话题挂载的文件夹
+ * 先试运行检查补丁能否干净地应用，用户确认后才写入文件，修改同样记录在变更列表中
+ */
+export async function applyConversationWorkspacePatch(topicId: string, unifiedDiff: string) {
+  let preview: PatchResult
+  try {
+    preview = await window.api.conversationWorkspace.applyPatch(topicId, undefined, unifiedDiff, true)
+  } catch (error) {
+    showPatchError(error)
+    return
+  }
+
+  window.modal.confirm({
+    title: i18n.t('code_block.patch.confirm'),
+    content: i18n.t('code_block.patch.summary', {
+      path: preview.path,
+      status: i18n.t(`chat.topics.workspace.status.${preview.status}`),
+      additions: preview.additions,
+      deletions: preview.deletions
+    }),
+    centered: true,
+    onOk: async () => {
+      try {
+        await window.api.conversationWorkspace.applyPatch(topicId, preview.path, unifiedDiff)
+        window.message.success({ content: i18n.t('code_block.patch.applied'), key: 'workspace-patch' })
+      } catch (error) {
+        showPatchError(error)
+      }
+    }
+  })
+}
+
+let initialized = false
+
+export function initConversationWorkspaces() {
+  if (initialized) return
+  initialized = true
+
+  window.api.conversationWorkspace.list().then((list) => {
+    update(Object.fromEntries(list.map((workspace) => [workspace.topicId, workspace])))
+  })
+}
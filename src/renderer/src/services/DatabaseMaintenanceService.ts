@@ -0,0 +1,260 @@
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import store from '@renderer/store'
+import { MessageBlock } from '@renderer/types/newMessage'
+import { hasTopicPendingRequests } from '@renderer/utils/queue'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+
+export type DatabaseMaintenanceTask = 'check' | 'vacuum' | 'rebuild'
+
+export interface DatabaseHealthReport {
+  checkedAt: number
+  // 最近一次清理时间
+  vacuumedAt?: number
+  // 各表记录数
+  tables: Record<string, number>
+  // 不属于任何消息的消息块
+  orphanBlocks: number
+  // 消息引用但已不存在的消息块
+  missingBlocks: number
+  // 话题列表中已不存在的话题数据
+  orphanTopics: number
+  // 已过期的响应缓存
+  expiredCache: number
+  storageUsage?: number
+  storageQuota?: number
+}
+
+const HEALTH_SETTING_KEY = 'database:health'
+// 重建索引时每批写入的消息块数量
+const REBUILD_BATCH_SIZE = 500
+// 自动维护间隔和触发条件
+const AUTO_MAINTENANCE_INTERVAL = 7 * 24 * 60 * 60 * 1000
+const IDLE_CHECK_INTERVAL = 10 * 60 * 1000
+const IDLE_THRESHOLD_SECONDS = 5 * 60
+
+let running: Promise<unknown> | null = null
+
+function emitProgress(task: DatabaseMaintenanceTask, completed: number, total: number) {
+  EventEmitter.emit(EVENT_NAMES.DATABASE_MAINTENANCE_PROGRESS, { task, completed, total })
+}
+
+/**
+ * 同一时间只运行一个维护任务
+ */
+function exclusive<T>(task: () => Promise<T>): Promise<T> {
+  if (running) {
+    return Promise.reject(new Error('Database maintenance is already running'))
+  }
+  const result = task()
+  running = result
+  return result.finally(() => {
+    running = null
+  })
+}
+
+export function isDatabaseMaintenanceRunning(): boolean {
+  return running !== null
+}
+
+/**
+ * 扫描话题和消息块之间的引用关系
+ * 话题列表以 store 为准，数据库中多出的话题数据无法再被访问，正在请求的话题可能还未写入 store
+ */
+async function scanReferences() {
+  const topicIds = new Set(
+    store.getState().assistants.assistants.flatMap((assistant) => assistant.topics.map((topic) => topic.id))
+  )
+  const rows = await db.topics.toArray()
+
+  const messageIds = new Set<string>()
+  const referencedBlocks = new Map<string, string>()
+  const orphanTopics: string[] = []
+
+  for (const row of rows) {
+    if (!topicIds.has(row.id) && !hasTopicPendingRequests(row.id)) {
+      orphanTopics.push(row.id)
+      continue
+    }
+    for (const message of row.messages ?? []) {
+      messageIds.add(message.id)
+      message.blocks?.forEach((blockId) => referencedBlocks.set(blockId, message.id))
+    }
+  }
+
+  const orphanBlocks: string[] = []
+  const existingBlocks = new Set<string>()
+  await db.message_blocks.each((block) => {
+    existingBlocks.add(block.id)
+    if (!messageIds.has(block.messageId) && !referencedBlocks.has(block.id)) {
+      orphanBlocks.push(block.id)
+    }
+  })
+
+  const missingBlocks = [...referencedBlocks.keys()].filter((id) => !existingBlocks.has(id))
+
+  return { rows, orphanTopics, orphanBlocks, missingBlocks, referencedBlocks }
+}
+
+async function saveHealthReport(report: DatabaseHealthReport) {
+  await db.settings.put({ id: HEALTH_SETTING_KEY, value: report })
+}
+
+export async function getDatabaseHealth(): Promise<DatabaseHealthReport | undefined> {
+  return (await db.settings.get(HEALTH_SETTING_KEY))?.value
+}
+
+/**
+ * 检查数据库完整性，生成健康报告
+ */
+export function checkDatabaseIntegrity(): Promise<DatabaseHealthReport> {
+  return exclusive(async () => {
+    const total = db.tables.length + 2
+    emitProgress('check', 0, total)
+
+    const tables: Record<string, number> = {}
+    for (let i = 0; i < db.tables.length; i++) {
+      tables[db.tables[i].name] = await db.tables[i].count()
+      emitProgress('check', i + 1, total)
+    }
+
+    const { orphanTopics, orphanBlocks, missingBlocks } = await scanReferences()
+    const expiredCache = await db.llm_cache.where('expiresAt').belowOrEqual(Date.now()).count()
+    emitProgress('check', total - 1, total)
+
+    const estimate = await navigator.storage?.estimate?.().catch(() => undefined)
+    const previous = await getDatabaseHealth()
+
+    const report: DatabaseHealthReport = {
+      checkedAt: Date.now(),
+      vacuumedAt: previous?.vacuumedAt,
+      tables,
+      orphanBlocks: orphanBlocks.length,
+      missingBlocks: missingBlocks.length,
+      orphanTopics: orphanTopics.length,
+      expiredCache,
+      storageUsage: estimate?.usage,
+      storageQuota: estimate?.quota
+    }
+
+    await saveHealthReport(report)
+    emitProgress('check', total, total)
+    return report
+  })
+}
+
+/**
+ * 清理数据库
+ * 删除无法访问的话题数据、孤立的消息块和过期缓存，并移除消息中失效的消息块引用。
+ * IndexedDB 会在删除后自行压缩存储文件。
+ */
+export async function vacuumDatabase(): Promise<DatabaseHealthReport> {
+  await exclusive(async () => {
+    const total = 4
+    emitProgress('vacuum', 0, total)
+
+    const { rows, orphanTopics, orphanBlocks, missingBlocks } = await scanReferences()
+    emitProgress('vacuum', 1, total)
+
+    const missing = new Set(missingBlocks)
+
+    // 无法访问的话题的消息块也包含在孤立消息块中
+    await db.transaction('rw', db.topics, db.message_blocks, async () => {
+      await db.topics.bulkDelete(orphanTopics)
+      await db.message_blocks.bulkDelete(orphanBlocks)
+
+      if (missing.size > 0) {
+        for (const row of rows) {
+          if (!row.messages?.some((message) => message.blocks?.some((id) => missing.has(id)))) continue
+          const messages = row.messages.map((message) => ({
+            ...message,
+            blocks: message.blocks.filter((id) => !missing.has(id))
+          }))
+          await db.topics.update(row.id, { messages })
+        }
+      }
+    })
+    emitProgress('vacuum', 2, total)
+
+    await db.llm_cache.where('expiresAt').belowOrEqual(Date.now()).delete()
+    emitProgress('vacuum', 3, total)
+
+    Logger.log(
+      `[DatabaseMaintenance] Removed ${orphanTopics.length} topics, ${orphanBlocks.length} blocks,`,
+      `fixed ${missing.size} missing block references`
+    )
+  })
+
+  const report = await checkDatabaseIntegrity()
+  report.vacuumedAt = Date.now()
+  await saveHealthReport(report)
+  emitProgress('vacuum', 4, 4)
+  return report
+}
+
+/**
+ * 重建消息搜索索引
+ * 历史记录搜索通过消息块的 messageId 找到所属消息，这里按话题中的消息重新校正 messageId，
+ * 并分批重写所有消息块，让 IndexedDB 重新生成索引项。
+ */
+export function rebuildSearchIndex(): Promise<number> {
+  return exclusive(async () => {
+    const { referencedBlocks } = await scanReferences()
+    const keys = (await db.message_blocks.toCollection().primaryKeys()) as string[]
+    let fixed = 0
+
+    emitProgress('rebuild', 0, keys.length)
+    for (let i = 0; i < keys.length; i += REBUILD_BATCH_SIZE) {
+      const blocks = (await db.message_blocks.bulkGet(keys.slice(i, i + REBUILD_BATCH_SIZE))).filter(
+        (block): block is MessageBlock => !!block
+      )
+      const updated = blocks.map((block) => {
+        const messageId = referencedBlocks.get(block.id)
+        if (messageId && messageId !== block.messageId) {
+          fixed++
+          return { ...block, messageId }
+        }
+        return block
+      })
+      await db.message_blocks.bulkPut(updated)
+      emitProgress('rebuild', Math.min(i + REBUILD_BATCH_SIZE, keys.length), keys.length)
+    }
+
+    Logger.log(`[DatabaseMaintenance] Rebuilt index for ${keys.length} blocks, fixed ${fixed}`)
+    return fixed
+  })
+}
+
+/**
+ * 系统空闲时自动检查数据库，每周最多一次
+ * 只生成健康报告，孤立数据需要用户在设置中确认后才清理
+ */
+async function runScheduledMaintenance() {
+  if (!store.getState().settings.autoDatabaseMaintenance || isDatabaseMaintenanceRunning()) return
+
+  const health = await getDatabaseHealth()
+  if (health && Date.now() - health.checkedAt < AUTO_MAINTENANCE_INTERVAL) return
+
+  const idleTime = await window.api.system.getIdleTime()
+  if (idleTime < IDLE_THRESHOLD_SECONDS) return
+
+  try {
+    const report = await checkDatabaseIntegrity()
+    const issues = report.orphanBlocks + report.missingBlocks + report.orphanTopics
+    if (issues > 0) {
+      Logger.warn(`[DatabaseMaintenance] Scheduled check found ${issues} issues, clean up from the settings`)
+    }
+  } catch (error) {
+    Logger.error('[DatabaseMaintenance] Scheduled maintenance failed:', error)
+  }
+}
+
+let initialized = false
+
+export function initDatabaseMaintenance() {
+  if (initialized) return
+  initialized = true
+
+  setInterval(runScheduledMaintenance, IDLE_CHECK_INTERVAL)
+}
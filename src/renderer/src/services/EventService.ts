@@ -28,5 +28,17 @@ export const EVENT_NAMES = {
   RESEND_MESSAGE: 'RESEND_MESSAGE',
   SHOW_MODEL_SELECTOR: 'SHOW_MODEL_SELECTOR',
   EDIT_CODE_BLOCK: 'EDIT_CODE_BLOCK',
-  CHANGE_TOPIC: 'CHANGE_TOPIC'
+  CHANGE_TOPIC: 'CHANGE_TOPIC',
+  RATE_LIMIT_BACKPRESSURE: 'RATE_LIMIT_BACKPRESSURE',
+  JOB_PROGRESS: 'JOB_PROGRESS',
+  SHARE_RECEIVED: 'SHARE_RECEIVED',
+  SESSION_RECOVERED: 'SESSION_RECOVERED',
+  DATABASE_MAINTENANCE_PROGRESS: 'DATABASE_MAINTENANCE_PROGRESS',
+  SCHEDULED_MESSAGE_COMPLETED: 'SCHEDULED_MESSAGE_COMPLETED',
+  SPEECH_STARTED: 'SPEECH_STARTED',
+  SPEECH_ENDED: 'SPEECH_ENDED',
+  WAKE_WORD_DETECTED: 'WAKE_WORD_DETECTED',
+  TRANSCRIPT_PARTIAL: 'TRANSCRIPT_PARTIAL',
+  CONVERSATION_WORKSPACES_CHANGED: 'CONVERSATION_WORKSPACES_CHANGED',
+  MINIAPP_BRIDGE_MESSAGE: 'MINIAPP_BRIDGE_MESSAGE'
 }
@@ -0,0 +1,153 @@
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import { Job, Model } from '@renderer/types'
+import { v4 as uuidv4 } from 'uuid'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+
+type JobRunner = (job: Job) => Promise<void>
+
+const runners = new Map<Job['type'], JobRunner>()
+const running = new Map<string, Promise<Job>>()
+const paused = new Set<string>()
+
+function emitProgress(job: Job) {
+  EventEmitter.emit(EVENT_NAMES.JOB_PROGRESS, {
+    id: job.id,
+    type: job.type,
+    status: job.status,
+    completed: job.completed,
+    total: job.inputs.length,
+    error: job.error
+  })
+}
+
+export function registerJobRunner(type: Job['type'], runner: JobRunner) {
+  runners.set(type, runner)
+}
+
+export async function createJob<TInput, TOutput>(
+  type: Job['type'],
+  model: Model,
+  inputs: TInput[],
+  params?: Job['params']
+): Promise<Job<TInput, TOutput>> {
+  const now = Date.now()
+  const job: Job<TInput, TOutput> = {
+    id: uuidv4(),
+    type,
+    status: 'pending',
+    model,
+    params,
+    inputs,
+    outputs: inputs.map(() => null),
+    completed: 0,
+    createdAt: now,
+    updatedAt: now
+  }
+  await db.jobs.add(job)
+  return job
+}
+
+export async function getJob(id: string): Promise<Job | undefined> {
+  return db.jobs.get(id)
+}
+
+export async function listJobs(): Promise<Job[]> {
+  return db.jobs.orderBy('createdAt').reverse().toArray()
+}
+
+export async function deleteJob(id: string): Promise<void> {
+  paused.add(id)
+  await running.get(id)?.catch(() => undefined)
+  paused.delete(id)
+  await db.jobs.delete(id)
+}
+
+/**
+ * Save the outputs of a finished chunk, the job resumes from here after an interruption
+ */
+export async function checkpointJob<TOutput>(job: Job<any, TOutput>, entries: [number, TOutput][]): Promise<void> {
+  for (const [index, output] of entries) {
+    if (job.outputs[index] === null) {
+      job.completed++
+    }
+    job.outputs[index] = output
+  }
+  job.updatedAt = Date.now()
+  await db.jobs.put(job)
+  emitProgress(job)
+}
+
+export function isJobPaused(id: string): boolean {
+  return paused.has(id)
+}
+
+export function runJob(job: Job): Promise<Job> {
+  const current = running.get(job.id)
+  if (current) {
+    return current
+  }
+
+  const runner = runners.get(job.type)
+  if (!runner) {
+    return Promise.reject(new Error(`No runner registered for job type ${job.type}`))
+  }
+
+  const promise = (async () => {
+    paused.delete(job.id)
+    job.status = 'running'
+    job.error = undefined
+    job.updatedAt = Date.now()
+    await db.jobs.put(job)
+    emitProgress(job)
+
+    try {
+      await runner(job)
+      job.status = paused.has(job.id) ? 'paused' : 'completed'
+    } catch (error: any) {
+      Logger.error(`[JobManager] Job ${job.id} failed:`, error)
+      job.status = 'failed'
+      job.error = error?.message || String(error)
+    }
+
+    job.updatedAt = Date.now()
+    await db.jobs.put(job)
+    emitProgress(job)
+
+    if (job.status === 'failed') {
+      throw new Error(job.error)
+    }
+    return job
+  })().finally(() => running.delete(job.id))
+
+  running.set(job.id, promise)
+  return promise
+}
+
+export async function pauseJob(id: string): Promise<void> {
+  if (running.has(id)) {
+    paused.add(id)
+    await running.get(id)?.catch(() => undefined)
+  }
+}
+
+export async function resumeJob(id: string): Promise<Job> {
+  const job = await getJob(id)
+  if (!job) {
+    throw new Error(`Job ${id} not found`)
+  }
+  return runJob(job)
+}
+
+/**
+ * Restart jobs which were still running when the app was closed
+ */
+export async function resumeInterruptedJobs(): Promise<void> {
+  const jobs = await db.jobs.where('status').anyOf('pending', 'running').toArray()
+
+  for (const job of jobs) {
+    Logger.log(`[JobManager] Resuming ${job.type} job ${job.id} (${job.completed}/${job.inputs.length})`)
+    runJob(job).catch(() => undefined)
+  }
+}
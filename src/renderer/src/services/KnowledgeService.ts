@@ -4,16 +4,43 @@ import { DEFAULT_KNOWLEDGE_DOCUMENT_COUNT, DEFAULT_KNOWLEDGE_THRESHOLD } from '@
 import { getEmbeddingMaxContext } from '@renderer/config/embedings'
 import Logger from '@renderer/config/logger'
 import store from '@renderer/store'
-import { FileType, KnowledgeBase, KnowledgeBaseParams, KnowledgeReference } from '@renderer/types'
+import { FileType, KnowledgeBase, KnowledgeBaseParams, KnowledgeReference, Model } from '@renderer/types'
 import { ExtractResults } from '@renderer/utils/extract'
 import { isEmpty } from 'lodash'
 
 import { getProviderByModel } from './AssistantService'
 import FileManager from './FileManager'
 
+// 本地重排序模型的 provider，模型 id 是模型文件的 id
+export const LOCAL_RERANK_PROVIDER = 'local'
+
+/**
+ * 可以用于重排序的本地 GGUF 模型文件，在知识库设置中和服务商的重排序模型一起选择
+ */
+// 只列出 GGUF 元数据中池化类型为 rank 的重排序模型，对话和嵌入模型不能用来重排序
+export const getLocalRerankModels = async (): Promise<Model[]> => {
+  const files = await window.api.localInference.listModels('rerank').catch(() => [])
+  return files.map((file) => ({
+    id: file.id,
+    provider: LOCAL_RERANK_PROVIDER,
+    name: file.name,
+    group: LOCAL_RERANK_PROVIDER
+  }))
+}
+
+/**
+ * 本地 GGUF 嵌入模型，服务商是本地推理，维度从模型文件中读取
+ */
+export const getLocalEmbeddingModels = async (): Promise<Model[]> => {
+  const files = await window.api.localInference.listModels('embedding').catch(() => [])
+  return files.map((file) => ({ id: file.id, provider: 'local', name: file.name, group: 'local', type: ['embedding'] }))
+}
+
 export const getKnowledgeBaseParams = (base: KnowledgeBase): KnowledgeBaseParams => {
   const provider = getProviderByModel(base.model)
-  const rerankProvider = getProviderByModel(base.rerankModel)
+  const isLocalRerank = base.rerankModel?.provider === LOCAL_RERANK_PROVIDER
+  // 本地重排序不需要服务商的地址和密钥
+  const rerankProvider = getProviderByModel(isLocalRerank ? undefined : base.rerankModel)
   const aiProvider = new AiProvider(provider)
   const rerankAiProvider = new AiProvider(rerankProvider)
 
@@ -48,7 +75,7 @@ export const getKnowledgeBaseParams = (base: KnowledgeBase): KnowledgeBaseParams
     rerankBaseURL: rerankHost,
     rerankApiKey: rerankAiProvider.getApiKey() || 'secret',
     rerankModel: base.rerankModel?.id,
-    rerankModelProvider: rerankProvider.name.toLowerCase()
+    rerankModelProvider: isLocalRerank ? LOCAL_RERANK_PROVIDER : rerankProvider.name.toLowerCase()
     // topN: base.topN
   }
 }
@@ -175,7 +202,11 @@ export const processKnowledgeSearch = async (
             id: index + 1,
             content: item.pageContent,
             sourceUrl: await getKnowledgeSourceUrl(item),
-            type: 'file'
+            type: 'file',
+            citation: await window.api.knowledgeBase.resolveCitation(item).catch((error) => {
+              Logger.warn('[KnowledgeService] Failed to resolve citation:', error)
+              return undefined
+            })
           }) as KnowledgeReference
       )
     )
@@ -0,0 +1,52 @@
+import store from '@renderer/store'
+import { Job } from '@renderer/types'
+import { getMainTextContent } from '@renderer/utils/messageUtils/find'
+
+import { getAssistantById } from './AssistantService'
+import { EVENT_NAMES, EventEmitter } from './EventService'
+
+// 推送到聊天工具的回复最大长度
+const MAX_TEXT_LENGTH = 1500
+
+interface JobProgressEvent {
+  id: string
+  type: Job['type']
+  status: Job['status']
+  completed: number
+  total: number
+  error?: string
+}
+
+let initialized = false
+
+/**
+ * 把智能体完成、任务失败等事件转发给主进程的外部通知集成（Slack、Discord、Telegram 等）
+ */
+export function initOutboundIntegrations() {
+  if (initialized) return
+  initialized = true
+
+  EventEmitter.on(EVENT_NAMES.MESSAGE_COMPLETE, (data: { id: string; topicId: string; status: string }) => {
+    if (data.status !== 'success') return
+
+    const message = store.getState().messages.entities[data.id]
+    if (!message || message.role !== 'assistant') return
+
+    const assistant = getAssistantById(message.assistantId)
+    const topic = assistant?.topics.find((item) => item.id === data.topicId)
+    window.api.outbound.emit('agent.finished', {
+      assistant: assistant?.name ?? '',
+      assistantId: message.assistantId,
+      topic: topic?.name ?? '',
+      topicId: data.topicId,
+      messageId: data.id,
+      text: getMainTextContent(message).slice(0, MAX_TEXT_LENGTH)
+    })
+  })
+
+  EventEmitter.on(EVENT_NAMES.JOB_PROGRESS, (job: JobProgressEvent) => {
+    if (job.status === 'failed') {
+      window.api.outbound.emit('job.failed', job)
+    }
+  })
+}
@@ -0,0 +1,77 @@
+import { ActionCreatorWithPayload } from '@reduxjs/toolkit'
+import i18n from '@renderer/i18n'
+import store, { persistor } from '@renderer/store'
+import { setPolicy } from '@renderer/store/runtime'
+import {
+  setAutoCheckUpdate,
+  setEnableDataCollection,
+  setLanguage,
+  setLaunchToTray,
+  setTray,
+  setTrayOnClose
+} from '@renderer/store/settings'
+import { MachinePolicy } from '@renderer/types'
+
+/**
+ * 管理员部署的机器级策略：锁定的设置不能修改，只能使用允许的服务商
+ * 主进程启动时读取策略，这里在渲染进程启动时加载一次并保存到 runtime 状态中
+ */
+
+// 配置项对应的界面设置，锁定的值在加载策略时写入，界面显示的是实际生效的值
+const LOCKED_SETTING_ACTIONS: Record<string, ActionCreatorWithPayload<any>> = {
+  autoUpdate: setAutoCheckUpdate,
+  enableDataCollection: setEnableDataCollection,
+  language: setLanguage,
+  launchToTray: setLaunchToTray,
+  tray: setTray,
+  trayOnClose: setTrayOnClose
+}
+
+// 持久化的设置恢复之后再写入，否则会被恢复的值覆盖
+function waitForRehydration(): Promise<void> {
+  return new Promise((resolve) => {
+    if (persistor.getState().bootstrapped) return resolve()
+    const unsubscribe = persistor.subscribe(() => {
+      if (!persistor.getState().bootstrapped) return
+      unsubscribe()
+      resolve()
+    })
+  })
+}
+
+function applyLockedSettings(policy: MachinePolicy) {
+  for (const key of policy.lockedKeys) {
+    const action = LOCKED_SETTING_ACTIONS[key]
+    const value = policy.settings[key]
+    if (!action || value === undefined) continue
+
+    store.dispatch(action(value))
+    if (key === 'language') {
+      localStorage.setItem('language', value as string)
+      i18n.changeLanguage(value as string)
+    }
+  }
+}
+
+export async function initPolicy() {
+  try {
+    const policy: MachinePolicy = await window.api.policy.get()
+    store.dispatch(setPolicy(policy))
+    await waitForRehydration()
+    applyLockedSettings(policy)
+  } catch (error) {
+    console.error('Failed to load the machine policy:', error)
+  }
+}
+
+export function getPolicy(): MachinePolicy {
+  return store.getState().runtime.policy
+}
+
+export function isSettingLocked(key: string): boolean {
+  return getPolicy().lockedKeys.includes(key)
+}
+
+export function isProviderAllowed(providerId: string, policy: MachinePolicy = getPolicy()): boolean {
+  return !policy.allowedProviders || policy.allowedProviders.includes(providerId)
+}
@@ -0,0 +1,220 @@
+import Logger from '@renderer/config/logger'
+import { Provider, ProviderLimits } from '@renderer/types'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+
+const WINDOW_MS = 60 * 1000
+
+export interface RateLimitStatus {
+  providerId: string
+  limits: ProviderLimits
+  active: number
+  queued: number
+  requestsInWindow: number
+  tokensInWindow: number
+  // when the next queued request can start, if it is currently blocked by the window
+  retryAt?: number
+}
+
+interface Waiter {
+  tokens: number
+  nested: boolean
+  resolve: (record: UsageRecord) => void
+}
+
+interface UsageRecord {
+  time: number
+  tokens: number
+  // runs inside the concurrency slot of its parent request
+  nested: boolean
+}
+
+/**
+ * Budget of one provider: a sliding one-minute window for requests and tokens plus a concurrency cap.
+ * Waiters are queued per lane (e.g. call type) and served round-robin so a batch job can't starve chat.
+ */
+class ProviderBudget {
+  public limits: ProviderLimits = {}
+  private active = 0
+  private usage: UsageRecord[] = []
+  private lanes: Map<string, Waiter[]> = new Map()
+  private laneOrder: string[] = []
+  private timer: ReturnType<typeof setTimeout> | null = null
+  private blockedUntil?: number
+
+  constructor(public readonly providerId: string) {}
+
+  public acquire(lane: string, tokens: number, nested: boolean, signal?: AbortSignal): Promise<UsageRecord> {
+    return new Promise((resolve, reject) => {
+      if (signal?.aborted) return reject(new DOMException('Request was aborted', 'AbortError'))
+      if (!this.lanes.has(lane)) {
+        this.lanes.set(lane, [])
+        this.laneOrder.push(lane)
+      }
+      const waiters = this.lanes.get(lane)!
+      const onAbort = () => {
+        const index = waiters.indexOf(waiter)
+        if (index >= 0) waiters.splice(index, 1)
+        reject(new DOMException('Request was aborted', 'AbortError'))
+        this.pump()
+      }
+      const waiter: Waiter = {
+        tokens,
+        nested,
+        resolve: (record) => {
+          signal?.removeEventListener('abort', onAbort)
+          resolve(record)
+        }
+      }
+      signal?.addEventListener('abort', onAbort, { once: true })
+      waiters.push(waiter)
+      this.pump()
+    })
+  }
+
+  /**
+   * @param tokens actual tokens used, replaces the estimate recorded when the request started
+   */
+  public release(record: UsageRecord, tokens?: number) {
+    if (!record.nested) this.active = Math.max(0, this.active - 1)
+    if (tokens !== undefined) record.tokens = tokens
+    this.pump()
+  }
+
+  public getStatus(): RateLimitStatus {
+    this.prune()
+    return {
+      providerId: this.providerId,
+      limits: this.limits,
+      active: this.active,
+      queued: [...this.lanes.values()].reduce((sum, waiters) => sum + waiters.length, 0),
+      requestsInWindow: this.usage.length,
+      tokensInWindow: this.usage.reduce((sum, record) => sum + record.tokens, 0),
+      retryAt: this.blockedUntil
+    }
+  }
+
+  private prune() {
+    const threshold = Date.now() - WINDOW_MS
+    while (this.usage.length && this.usage[0].time <= threshold) {
+      this.usage.shift()
+    }
+  }
+
+  /**
+   * Milliseconds until a request of `tokens` fits into the budget, 0 when it can start now
+   */
+  private waitTime(tokens: number, nested: boolean): number {
+    const { rpm, tpm, maxConcurrency } = this.limits
+    if (!nested && maxConcurrency && this.active >= maxConcurrency) return Infinity
+
+    this.prune()
+    const now = Date.now()
+    let wait = 0
+    if (rpm && this.usage.length >= rpm) {
+      wait = Math.max(wait, this.usage[this.usage.length - rpm].time + WINDOW_MS - now)
+    }
+    if (tpm) {
+      let used = this.usage.reduce((sum, record) => sum + record.tokens, 0)
+      // a single request larger than the whole budget only waits for an empty window
+      const needed = Math.min(tokens, tpm)
+      for (const record of this.usage) {
+        if (used + needed <= tpm) break
+        used -= record.tokens
+        wait = Math.max(wait, record.time + WINDOW_MS - now)
+      }
+    }
+    return wait
+  }
+
+  private nextLane(): string | undefined {
+    return this.laneOrder.find((lane) => this.lanes.get(lane)!.length > 0)
+  }
+
+  private pump() {
+    if (this.timer) {
+      clearTimeout(this.timer)
+      this.timer = null
+    }
+
+    let lane = this.nextLane()
+    while (lane) {
+      const waiter = this.lanes.get(lane)![0]
+      const wait = this.waitTime(waiter.tokens, waiter.nested)
+      if (wait > 0) {
+        this.blockedUntil = Number.isFinite(wait) ? Date.now() + wait : undefined
+        EventEmitter.emit(EVENT_NAMES.RATE_LIMIT_BACKPRESSURE, this.getStatus())
+        // concurrency slots are freed by release(), window slots by time
+        if (Number.isFinite(wait)) {
+          this.timer = setTimeout(() => this.pump(), wait)
+        }
+        return
+      }
+
+      this.lanes.get(lane)!.shift()
+      // move the served lane to the back for round-robin fairness
+      this.laneOrder = [...this.laneOrder.filter((item) => item !== lane), lane]
+      if (!waiter.nested) this.active++
+      // usage is recorded when the slot is granted so the next waiter sees it
+      const record = { time: Date.now(), tokens: waiter.tokens, nested: waiter.nested }
+      this.usage.push(record)
+      waiter.resolve(record)
+      lane = this.nextLane()
+    }
+    this.blockedUntil = undefined
+  }
+}
+
+const budgets: Map<string, ProviderBudget> = new Map()
+
+function getBudget(provider: Provider): ProviderBudget {
+  let budget = budgets.get(provider.id)
+  if (!budget) {
+    budget = new ProviderBudget(provider.id)
+    budgets.set(provider.id, budget)
+  }
+  budget.limits = provider.limits ?? {}
+  return budget
+}
+
+export function hasProviderLimits(provider: Provider): boolean {
+  const { rpm, tpm, maxConcurrency } = provider.limits ?? {}
+  return !!(rpm || tpm || maxConcurrency)
+}
+
+/**
+ * Wait for a slot in the provider budget, rejects with an AbortError when the signal aborts while waiting.
+ * Nested requests, e.g. the follow-up requests of tool calls, count against the window but share the
+ * concurrency slot of the request that started them.
+ * @returns a release function, call it with the actual token usage when the request finishes
+ */
+export async function acquireProviderSlot(
+  provider: Provider,
+  lane: string,
+  estimatedTokens: number,
+  { nested = false, signal }: { nested?: boolean; signal?: AbortSignal } = {}
+): Promise<(tokens?: number) => void> {
+  const budget = getBudget(provider)
+  const queuedAt = Date.now()
+  const record = await budget.acquire(lane, estimatedTokens, nested, signal)
+
+  const waited = Date.now() - queuedAt
+  if (waited > 1000) {
+    Logger.log(`[RateLimitService] ${provider.id} request waited ${waited}ms for its budget`)
+  }
+
+  let released = false
+  return (tokens?: number) => {
+    if (released) return
+    released = true
+    budget.release(record, tokens)
+  }
+}
+
+export function getRateLimitStatus(providerId: string): RateLimitStatus | undefined {
+  return budgets.get(providerId)?.getStatus()
+}
+
+export function getAllRateLimitStatus(): RateLimitStatus[] {
+  return [...budgets.values()].map((budget) => budget.getStatus())
+}
@@ -0,0 +1,126 @@
+import db from '@renderer/databases'
+import { getStoreSetting } from '@renderer/hooks/useSettings'
+import { SettingsState } from '@renderer/store/settings'
+import { Model } from '@renderer/types'
+
+// responses larger than this are not worth keeping
+const MAX_ENTRY_LENGTH = 64 * 1024
+
+function stableStringify(value: any): string {
+  if (Array.isArray(value)) {
+    return `[${value.map(stableStringify).join(',')}]`
+  }
+  if (value && typeof value === 'object') {
+    const keys = Object.keys(value)
+      .filter((key) => value[key] !== undefined)
+      .sort()
+    return `{${keys.map((key) => `${JSON.stringify(key)}:${stableStringify(value[key])}`).join(',')}}`
+  }
+  return JSON.stringify(value)
+}
+
+function getCacheSettings(): SettingsState['llmCache'] {
+  return getStoreSetting('llmCache') as SettingsState['llmCache']
+}
+
+export function isResponseCacheEnabled(): boolean {
+  return !!getCacheSettings()?.enabled
+}
+
+export function isSampledResponseCacheEnabled(): boolean {
+  return !!getCacheSettings()?.includeSampled
+}
+
+/**
+ * Content-addressed key, identical requests always produce the same key regardless of property order
+ */
+export async function getResponseCacheKey(request: Record<string, any>): Promise<string> {
+  const data = new TextEncoder().encode(stableStringify(request))
+  const digest = await crypto.subtle.digest('SHA-256', data)
+  return Array.from(new Uint8Array(digest))
+    .map((byte) => byte.toString(16).padStart(2, '0'))
+    .join('')
+}
+
+export async function getCachedResponse(key: string): Promise<string | null> {
+  const entry = await db.llm_cache.get(key)
+  if (!entry) return null
+
+  if (entry.expiresAt <= Date.now()) {
+    await db.llm_cache.delete(key)
+    return null
+  }
+  return entry.text
+}
+
+export async function setCachedResponse(key: string, model: string, text: string): Promise<void> {
+  if (!text || text.length > MAX_ENTRY_LENGTH) return
+
+  const { ttl } = getCacheSettings()
+  const now = Date.now()
+  await db.llm_cache.put({ key, model, text, createdAt: now, expiresAt: now + ttl * 60 * 60 * 1000 })
+  await evictEntries(now)
+}
+
+async function evictEntries(now: number): Promise<void> {
+  const { maxEntries } = getCacheSettings()
+
+  // evict expired entries first, then the oldest ones above the limit
+  await db.llm_cache.where('expiresAt').belowOrEqual(now).delete()
+  const overflow = (await db.llm_cache.count()) - maxEntries
+  if (overflow > 0) {
+    const oldest = await db.llm_cache.orderBy('createdAt').limit(overflow).primaryKeys()
+    await db.llm_cache.bulkDelete(oldest)
+  }
+}
+
+/**
+ * Embeddings are deterministic, they are cached per text and only the missing texts are requested
+ */
+export async function withEmbeddingCache(
+  model: Model,
+  texts: string[],
+  embed: (texts: string[]) => Promise<number[][]>
+): Promise<number[][]> {
+  if (!isResponseCacheEnabled()) {
+    return embed(texts)
+  }
+
+  const keys = await Promise.all(
+    texts.map((text) => getResponseCacheKey({ type: 'embedding', provider: model.provider, model: model.id, text }))
+  )
+  const vectors: (number[] | null)[] = await Promise.all(
+    keys.map(async (key) => {
+      const cached = await getCachedResponse(key).catch(() => null)
+      return cached ? (JSON.parse(cached) as number[]) : null
+    })
+  )
+
+  const missing = texts.map((_, index) => index).filter((index) => !vectors[index])
+  if (missing.length === 0) {
+    return vectors as number[][]
+  }
+
+  const embeddings = await embed(missing.map((index) => texts[index]))
+  const { ttl } = getCacheSettings()
+  const now = Date.now()
+  await db.llm_cache
+    .bulkPut(
+      missing.map((index, i) => ({
+        key: keys[index],
+        model: model.id,
+        text: JSON.stringify(embeddings[i]),
+        createdAt: now,
+        expiresAt: now + ttl * 60 * 60 * 1000
+      }))
+    )
+    .then(() => evictEntries(now))
+    .catch((error) => console.warn('Failed to cache embeddings:', error))
+
+  missing.forEach((index, i) => (vectors[index] = embeddings[i]))
+  return vectors as number[][]
+}
+
+export async function clearLLMCache(): Promise<void> {
+  await db.llm_cache.clear()
+}
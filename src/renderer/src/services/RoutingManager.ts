@@ -0,0 +1,55 @@
+import Logger from '@renderer/config/logger'
+import store from '@renderer/store'
+import { SettingsState } from '@renderer/store/settings'
+import { Assistant, FileTypes } from '@renderer/types'
+import { Message } from '@renderer/types/newMessage'
+import { getFileContent, getMainTextContent } from '@renderer/utils/messageUtils/find'
+import { evaluateRoutingRules, RoutingDecision, RoutingRequest } from '@renderer/utils/routing'
+
+import { estimateUserPromptUsage } from './TokenService'
+
+/**
+ * 按用户设置的规则为每次请求选择模型
+ * 规则按顺序评估，使用第一条命中的规则的模型，没有命中时使用助手的模型
+ */
+
+export function getRoutingSettings(): SettingsState['routing'] {
+  return store.getState().settings.routing
+}
+
+/**
+ * 助手是否使用路由规则，助手设置可以关闭
+ */
+export function isRoutingEnabled(assistant: Assistant): boolean {
+  return getRoutingSettings().enabled && assistant.settings?.routing !== false
+}
+
+/**
+ * 从用户消息构造路由请求
+ */
+export async function getRoutingRequest(message: Message): Promise<RoutingRequest> {
+  const text = getMainTextContent(message)
+  const files = getFileContent(message)
+  const { total_tokens } = await estimateUserPromptUsage({ content: text, files })
+  return { text, images: files.filter((file) => file.type === FileTypes.IMAGE).length, tokens: total_tokens }
+}
+
+/**
+ * 评估路由规则，不会修改任何内容，可用于试运行
+ */
+export function evaluateRoute(request: RoutingRequest): RoutingDecision {
+  return evaluateRoutingRules(request, getRoutingSettings().rules)
+}
+
+/**
+ * 获取回复这条消息时使用的助手，命中规则时替换模型
+ */
+export async function routeAssistant(assistant: Assistant, message: Message): Promise<Assistant> {
+  if (!isRoutingEnabled(assistant)) return assistant
+
+  const decision = evaluateRoute(await getRoutingRequest(message))
+  if (!decision.model || !decision.rule) return assistant
+
+  Logger.log(`[RoutingManager] Rule "${decision.rule.name}" routed the request to ${decision.model.id}`)
+  return { ...assistant, model: decision.model }
+}
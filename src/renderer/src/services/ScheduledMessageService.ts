@@ -0,0 +1,45 @@
+import i18n from '@renderer/i18n'
+import { ScheduledMessage } from '@renderer/types'
+import { uuid } from '@renderer/utils'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+import { NotificationService } from './NotificationService'
+
+/**
+ * 定时发送消息，发送时间到达时由主进程发送，即使窗口已关闭
+ */
+export function scheduleMessage(topicId: string, assistantId: string, content: string, sendAt: number) {
+  return window.api.scheduler.schedule({ topicId, assistantId, content, sendAt })
+}
+
+export function listScheduledMessages(topicId?: string) {
+  return window.api.scheduler.list(topicId)
+}
+
+export function cancelScheduledMessage(id: string) {
+  return window.api.scheduler.cancel(id)
+}
+
+let initialized = false
+
+/**
+ * 定时消息发送完成或失败时通知用户
+ */
+export function initScheduledMessages() {
+  if (initialized) return
+  initialized = true
+
+  const notificationService = NotificationService.getInstance()
+  window.api.scheduler.onCompleted((message: ScheduledMessage) => {
+    EventEmitter.emit(EVENT_NAMES.SCHEDULED_MESSAGE_COMPLETED, message)
+    notificationService.send({
+      id: uuid(),
+      type: message.status === 'sent' ? 'success' : 'error',
+      title: i18n.t(message.status === 'sent' ? 'chat.input.send_later.sent' : 'chat.input.send_later.failed'),
+      message: message.error ?? message.content.slice(0, 100),
+      silent: false,
+      timestamp: Date.now(),
+      source: 'assistant'
+    })
+  })
+}
@@ -0,0 +1,218 @@
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import i18n from '@renderer/i18n'
+import store from '@renderer/store'
+import { updateTopic } from '@renderer/store/assistants'
+import { FileType, Topic } from '@renderer/types'
+import { MessageBlock, MessageBlockType } from '@renderer/types/newMessage'
+import { Input } from 'antd'
+import { omit, pick } from 'lodash'
+import { createElement } from 'react'
+
+/**
+ * 加密话题：消息块用话题自己的密钥加密后保存到数据库，密钥由主进程保存在钥匙串中
+ * 加密的消息块只保留索引和排序需要的字段，其余内容序列化后加密保存在 sealed 字段
+ * 附件文件在文件存储中原地加密，主进程读取时自动解密，多个话题共用的文件保持明文
+ * 加密的内容不参与搜索，导出和复制前需要先解锁
+ */
+
+// 加密后仍保留明文的字段，用于按消息查询和排序
+const PLAIN_FIELDS = ['id', 'messageId', 'type', 'status', 'createdAt'] as const
+
+/**
+ * 消息块是否为加密后保存的形式
+ */
+export function isSealedBlock(block: unknown): boolean {
+  return typeof (block as any)?.sealed === 'string'
+}
+
+/**
+ * 话题是否为加密话题
+ */
+export function isSecureTopic(topicId?: string): boolean {
+  if (!topicId) return false
+  return store
+    .getState()
+    .assistants.assistants.some((assistant) => assistant.topics.some((topic) => topic.id === topicId && topic.secure))
+}
+
+/**
+ * 获取消息块所属的话题
+ */
+function getBlockTopicId(blockId: string): string | undefined {
+  const { messageBlocks, messages } = store.getState()
+  const messageId = messageBlocks.entities[blockId]?.messageId
+  return messageId ? messages.entities[messageId]?.topicId : undefined
+}
+
+/**
+ * 消息块是否属于加密话题，用于界面上避免直接读取附件文件
+ */
+export function isSecureBlock(blockId: string): boolean {
+  return isSecureTopic(getBlockTopicId(blockId))
+}
+
+function getBlockFile(block: Partial<MessageBlock>): FileType | undefined {
+  return (block as any).file
+}
+
+/**
+ * 加密消息块引用的附件文件，被其他话题共用的文件保持明文
+ */
+async function sealBlockFiles(topicId: string, blocks: Partial<MessageBlock>[]) {
+  const files = blocks.flatMap((block) => getBlockFile(block) ?? [])
+  if (files.length === 0) return
+
+  const stored = await db.files.bulkGet(files.map((file) => file.id))
+  const names = files.filter((file, index) => (stored[index]?.count ?? 1) <= 1).map((file) => file.id + file.ext)
+  if (names.length > 0) {
+    await window.api.secureTopic.sealFiles(topicId, names)
+  }
+}
+
+/**
+ * 加密话题的消息块在写入数据库前整体加密，其他话题原样返回
+ * 只包含部分字段的消息块会先与 store 中的完整消息块合并
+ */
+export async function sealBlocks<T extends Partial<MessageBlock>>(topicId: string, blocks: T[]): Promise<T[]> {
+  if (!isSecureTopic(topicId)) return blocks
+
+  const entities = store.getState().messageBlocks.entities
+  const indexes = blocks.flatMap((block, index) => (isSealedBlock(block) ? [] : [index]))
+  if (indexes.length === 0) return blocks
+
+  const full = indexes.map((index) => {
+    const block = blocks[index]
+    return (block.id ? { ...entities[block.id], ...block } : block) as Partial<MessageBlock>
+  })
+  await sealBlockFiles(topicId, full)
+
+  const sealed = await window.api.secureTopic.seal(
+    topicId,
+    full.map((block) => JSON.stringify(omit(block, PLAIN_FIELDS)))
+  )
+  const result = [...blocks]
+  indexes.forEach((index, i) => {
+    const file = getBlockFile(full[i])
+    // file.id 保持明文，数据库按它查找引用附件的消息块
+    const plain = { ...pick(full[i], PLAIN_FIELDS), ...(file && { file: { id: file.id } }) }
+    result[index] = { ...plain, sealed: sealed[i] } as any
+  })
+  return result
+}
+
+/**
+ * 加密单个消息块的更新内容，用于流式输出时的增量写入
+ * 加密后的消息块需要整体写入，返回的是完整的加密消息块
+ */
+export async function sealBlockChanges<T extends Partial<MessageBlock>>(blockId: string, changes: T): Promise<T> {
+  const topicId = getBlockTopicId(blockId)
+  if (!topicId || !isSecureTopic(topicId)) return changes
+  const [sealed] = await sealBlocks(topicId, [{ ...changes, id: blockId }])
+  return sealed
+}
+
+/**
+ * 解密消息块，无法解密的消息块保持加密
+ */
+async function openSealedBlocks(topicId: string, blocks: MessageBlock[]): Promise<MessageBlock[]> {
+  const indexes = blocks.flatMap((block, index) => (isSealedBlock(block) ? [index] : []))
+  if (indexes.length === 0) return blocks
+
+  let opened: (string | null)[] = indexes.map(() => null)
+  try {
+    opened = await window.api.secureTopic.open(topicId, indexes.map((index) => (blocks[index] as any).sealed))
+  } catch (error) {
+    Logger.error(`[SecureTopicService] Failed to open blocks of topic ${topicId}:`, error)
+  }
+
+  const result = [...blocks]
+  indexes.forEach((index, i) => {
+    const payload = opened[i]
+    if (payload !== null) {
+      result[index] = { ...JSON.parse(payload), ...pick(blocks[index], PLAIN_FIELDS) }
+    }
+  })
+  return result
+}
+
+/**
+ * 解密从数据库读取的消息块，无法解密的消息块显示为占位文字
+ */
+export async function openBlocks(topicId: string, blocks: MessageBlock[]): Promise<MessageBlock[]> {
+  const opened = await openSealedBlocks(topicId, blocks)
+  return opened.map((block) =>
+    isSealedBlock(block)
+      ? ({
+          ...pick(block, PLAIN_FIELDS),
+          type: MessageBlockType.MAIN_TEXT,
+          content: i18n.t('chat.topics.secure.unavailable')
+        } as MessageBlock)
+      : block
+  )
+}
+
+/**
+ * 开启或关闭话题加密，已有的消息块和附件会重新加密或解密后写回
+ * 关闭时只有全部内容都解密后才删除话题的密钥
+ */
+export async function setTopicSecure(topic: Topic, secure: boolean) {
+  const stored = await db.topics.get(topic.id)
+  const messageIds = stored?.messages.map((message) => message.id) ?? []
+  const blocks = messageIds.length ? await db.message_blocks.where('messageId').anyOf(messageIds).toArray() : []
+
+  // 先更新话题状态，sealBlocks 按话题状态判断是否需要加密
+  store.dispatch(updateTopic({ assistantId: topic.assistantId, topic: { ...topic, secure } }))
+
+  const updated = secure ? await sealBlocks(topic.id, blocks) : await openSealedBlocks(topic.id, blocks)
+  if (updated !== blocks) {
+    await db.message_blocks.bulkPut(updated)
+  }
+  if (secure) return
+
+  const files = updated.flatMap((block) => getBlockFile(block) ?? [])
+  if (files.length > 0) {
+    await window.api.secureTopic.openFiles(topic.id, files.map((file) => file.id + file.ext))
+  }
+  if (!updated.some(isSealedBlock)) {
+    await window.api.secureTopic.removeKey(topic.id)
+  }
+}
+
+/**
+ * 加密话题在导出或复制前需要解锁，支持 Touch ID 时使用 Touch ID，否则需要输入应用锁密码
+ * 没有设置应用锁密码时无法解锁，普通话题直接返回 true
+ */
+export async function unlockSecureTopic(topic: Topic): Promise<boolean> {
+  if (!topic.secure) return true
+
+  const { canUseBiometric, hasPassphrase } = await window.api.lock.getState()
+  if (!canUseBiometric && !hasPassphrase) {
+    window.message.warning({ content: i18n.t('chat.topics.secure.unlock.no_passphrase'), key: 'secure-unlock' })
+    return false
+  }
+
+  let passphrase = ''
+  const confirmed = await window.modal.confirm({
+    title: i18n.t('chat.topics.secure.unlock.title'),
+    content: canUseBiometric
+      ? i18n.t('chat.topics.secure.unlock.content')
+      : createElement('div', null, [
+          createElement('p', { key: 'content' }, i18n.t('chat.topics.secure.unlock.content')),
+          createElement(Input.Password, {
+            key: 'passphrase',
+            autoFocus: true,
+            placeholder: i18n.t('chat.topics.secure.unlock.passphrase'),
+            onChange: (e) => (passphrase = e.target.value)
+          })
+        ]),
+    centered: true
+  })
+  if (!confirmed) return false
+
+  const unlocked = await window.api.secureTopic.unlock(passphrase)
+  if (!unlocked) {
+    window.message.error({ content: i18n.t('chat.topics.secure.unlock.failed'), key: 'secure-unlock' })
+  }
+  return unlocked
+}
@@ -0,0 +1,132 @@
+import Logger from '@renderer/config/logger'
+import { DEFAULT_MIN_APPS } from '@renderer/config/minapps'
+import i18n from '@renderer/i18n'
+import store from '@renderer/store'
+import { setCurrentMinappId, setMinappShow, setOpenedKeepAliveMinapps } from '@renderer/store/runtime'
+import { MinAppType, RecoveredSession, SessionDraftState } from '@renderer/types'
+import dayjs from 'dayjs'
+import { debounce, isEqual } from 'lodash'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+import NavigationService from './NavigationService'
+
+/**
+ * 异常退出后恢复未保存的内容
+ * 未发送的输入、当前页面和打开的小程序写入主进程的日志，应用没有正常退出时下次启动询问是否恢复
+ * 正常退出时主进程保存打开的小程序及其页面，下次启动直接重新打开
+ */
+
+const state: SessionDraftState = { drafts: {}, minapps: [] }
+let recoveredDrafts: Record<string, string> = {}
+
+const save = debounce(() => {
+  window.api.session.saveDraftState(state).catch((error) => Logger.error('[SessionRecovery] Failed to save:', error))
+}, 1000)
+
+/**
+ * 记录输入框中未发送的内容，内容为空时移除
+ */
+export function saveDraft(key: string, text: string) {
+  if ((state.drafts[key] ?? '') === text) return
+  if (text) {
+    state.drafts[key] = text
+  } else {
+    delete state.drafts[key]
+  }
+  save()
+}
+
+/**
+ * 取出恢复的输入内容，只能被取出一次
+ */
+export function takeRecoveredDraft(key: string): string | undefined {
+  const text = recoveredDrafts[key]
+  delete recoveredDrafts[key]
+  return text
+}
+
+function trackOpenPanels() {
+  const update = () => {
+    const route = window.location.hash.replace(/^#/, '') || '/'
+    const minapps = store
+      .getState()
+      .runtime.openedKeepAliveMinapps.filter((app) => !app.ephemeral)
+      .map((app) => app.id)
+    if (route === state.route && isEqual(minapps, state.minapps)) return
+    state.route = route
+    state.minapps = minapps
+    save()
+  }
+  window.addEventListener('hashchange', update)
+  store.subscribe(update)
+  update()
+}
+
+function restore(session: RecoveredSession) {
+  recoveredDrafts = session.drafts
+  const minapps = session.minapps
+    .map((id) => DEFAULT_MIN_APPS.find((app) => app.id === id))
+    .filter((app): app is MinAppType => !!app)
+  if (minapps.length > 0) {
+    store.dispatch(setOpenedKeepAliveMinapps(minapps))
+  }
+  if (session.route) {
+    NavigationService.navigate?.(session.route)
+  }
+  EventEmitter.emit(EVENT_NAMES.SESSION_RECOVERED)
+}
+
+/**
+ * 重新打开上次正常退出时打开的小程序，回到各自的页面
+ */
+async function restoreMinappLayout() {
+  const { minappsRestoreLayout, maxKeepAliveMinapps } = store.getState().settings
+  if (!minappsRestoreLayout) return
+
+  const layout = await window.api.miniApp.restoreLayout()
+  if (!layout) return
+
+  const minapps = layout.apps
+    .flatMap(({ appId, url }) => {
+      const app = DEFAULT_MIN_APPS.find((item) => item.id === appId)
+      return app ? [{ ...app, url: url || app.url }] : []
+    })
+    .slice(0, maxKeepAliveMinapps)
+  if (minapps.length === 0) return
+
+  store.dispatch(setOpenedKeepAliveMinapps(minapps))
+  const active = minapps.find((app) => app.id === layout.activeAppId)
+  if (active) {
+    store.dispatch(setCurrentMinappId(active.id))
+    store.dispatch(setMinappShow(layout.visible))
+  }
+  Logger.log(`[SessionRecovery] Reopened ${minapps.length} mini apps`)
+}
+
+let initialized = false
+
+export async function initSessionRecovery() {
+  if (initialized) return
+  initialized = true
+
+  const session = await window.api.session.getRecovered()
+  // 异常退出时保存的布局已过时，由恢复会话处理
+  if (!session) await restoreMinappLayout()
+  trackOpenPanels()
+  if (!session) return
+
+  if (Object.keys(session.drafts).length === 0 && session.minapps.length === 0) {
+    window.api.session.discardRecovered()
+    return
+  }
+
+  window.modal.confirm({
+    title: i18n.t('message.session_recovery.title'),
+    content: i18n.t('message.session_recovery.content', { time: dayjs(session.savedAt).format('YYYY-MM-DD HH:mm') }),
+    okText: i18n.t('message.session_recovery.restore'),
+    cancelText: i18n.t('message.session_recovery.discard'),
+    centered: true,
+    onOk: () => restore(session),
+    afterClose: () => window.api.session.discardRecovered()
+  })
+}
@@ -0,0 +1,43 @@
+import { SharePayload } from '@renderer/types'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+import NavigationService from './NavigationService'
+
+/**
+ * 接收系统分享（"发送到 Cherry Studio"）的内容
+ * 跳转到首页后由输入框在新话题中打开，输入框可能尚未挂载，因此内容先暂存
+ */
+
+let pendingShare: SharePayload | null = null
+
+function receive(payload: SharePayload) {
+  pendingShare = payload
+  NavigationService.navigate?.('/')
+  EventEmitter.emit(EVENT_NAMES.SHARE_RECEIVED, payload)
+}
+
+/**
+ * 取出待处理的分享内容，只能被取出一次
+ */
+export function takePendingShare(): SharePayload | null {
+  const payload = pendingShare
+  pendingShare = null
+  return payload
+}
+
+/**
+ * 分享内容转换为输入框文本
+ */
+export function getShareText(payload: SharePayload): string {
+  return [payload.text?.trim(), payload.url].filter(Boolean).join('\n\n')
+}
+
+let initialized = false
+
+export function initShareService() {
+  if (initialized) return
+  initialized = true
+
+  window.api.share.onReceived(receive)
+  window.api.share.takePending().then((payloads) => payloads.forEach(receive))
+}
@@ -0,0 +1,150 @@
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import store from '@renderer/store'
+import { StatsMetric } from '@renderer/types'
+import {
+  aggregateStats,
+  getStatsCounterId,
+  getStatsDate,
+  StatsBucket,
+  StatsGranularity
+} from '@renderer/utils/stats'
+
+/**
+ * 使用统计
+ *
+ * 计数先写入 localStorage 中的预写日志，再定期合并到数据库的每日计数器中，
+ * 应用异常退出时未合并的计数会在下次启动时恢复。查询时只读取每日计数器，不需要扫描历史消息。
+ */
+
+const PENDING_STORAGE_KEY = 'stats:pending'
+const FLUSH_INTERVAL = 30 * 1000
+
+let pending: Record<string, number> | null = null
+let flushing: Promise<void> | null = null
+
+function getPending(): Record<string, number> {
+  if (!pending) {
+    try {
+      pending = JSON.parse(localStorage.getItem(PENDING_STORAGE_KEY) || '{}')
+    } catch {
+      pending = {}
+    }
+  }
+  return pending!
+}
+
+function savePending() {
+  localStorage.setItem(PENDING_STORAGE_KEY, JSON.stringify(getPending()))
+}
+
+/**
+ * 记录一次计数
+ * @param metric 指标
+ * @param key 助手、模型或小程序 ID
+ * @param value 增量，默认为 1
+ */
+export function recordStat(metric: StatsMetric, key: string, value = 1) {
+  if (!value) return
+  const id = getStatsCounterId(getStatsDate(), metric, key)
+  const counters = getPending()
+  counters[id] = (counters[id] ?? 0) + value
+  savePending()
+}
+
+/**
+ * 把预写日志合并到每日计数器
+ */
+export function flushStats(): Promise<void> {
+  if (flushing) return flushing
+
+  const counters = getPending()
+  const entries = Object.entries(counters)
+  if (entries.length === 0) return Promise.resolve()
+
+  flushing = (async () => {
+    try {
+      await db.transaction('rw', db.stats, async () => {
+        const existing = await db.stats.bulkGet(entries.map(([id]) => id))
+        await db.stats.bulkPut(
+          entries.map(([id, value], index) => {
+            const [date, metric, ...key] = id.split('|')
+            const total = (existing[index]?.value ?? 0) + value
+            return { id, date, metric: metric as StatsMetric, key: key.join('|'), value: total }
+          })
+        )
+      })
+
+      // 合并期间新增的计数保留到下次合并
+      for (const [id, value] of entries) {
+        counters[id] -= value
+        if (counters[id] === 0) delete counters[id]
+      }
+      savePending()
+    } catch (error) {
+      Logger.error('[StatsManager] Failed to flush stats:', error)
+    } finally {
+      flushing = null
+    }
+  })()
+
+  return flushing
+}
+
+/**
+ * 查询使用统计
+ * @param range 起止日期（YYYY-MM-DD），包含两端
+ * @param granularity 按天、周或月汇总
+ */
+export async function getStats(
+  range: { from: string; to: string },
+  granularity: StatsGranularity = 'day'
+): Promise<StatsBucket[]> {
+  await flushStats()
+  const counters = await db.stats.where('date').between(range.from, range.to, true, true).toArray()
+  return aggregateStats(counters, granularity)
+}
+
+export async function clearStats(): Promise<void> {
+  pending = {}
+  savePending()
+  await db.stats.clear()
+}
+
+/**
+ * 统计小程序的可见时长，小程序弹窗显示时按当前小程序计时
+ */
+function trackMinappUsage() {
+  let current: string | null = null
+  let startedAt = 0
+
+  const update = () => {
+    const { minappShow, currentMinappId } = store.getState().runtime
+    const visible = minappShow && currentMinappId ? currentMinappId : null
+    if (visible === current) return
+
+    if (current) {
+      recordStat('minapp_seconds', current, Math.round((Date.now() - startedAt) / 1000))
+    }
+    current = visible
+    startedAt = Date.now()
+  }
+
+  store.subscribe(update)
+  window.addEventListener('beforeunload', () => {
+    if (current) {
+      recordStat('minapp_seconds', current, Math.round((Date.now() - startedAt) / 1000))
+    }
+  })
+}
+
+let initialized = false
+
+export function initStatsManager() {
+  if (initialized) return
+  initialized = true
+
+  flushStats()
+  setInterval(flushStats, FLUSH_INTERVAL)
+  trackMinappUsage()
+}
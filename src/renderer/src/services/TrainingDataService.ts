@@ -0,0 +1,114 @@
+import { getStoredSecrets } from '@renderer/aiCore/middleware/feat/ContentGuardMiddleware'
+import db from '@renderer/databases'
+import store from '@renderer/store'
+import { addTopic } from '@renderer/store/assistants'
+import { Topic } from '@renderer/types'
+import { AssistantMessageStatus, Message, MessageBlock, MessageBlockStatus } from '@renderer/types/newMessage'
+import { uuid } from '@renderer/utils'
+import { createMainTextBlock, createMessage } from '@renderer/utils/messageUtils/create'
+import { getMainTextContentFromBlocks } from '@renderer/utils/messageUtils/find'
+import {
+  parseTrainingJsonl,
+  toTrainingJsonl,
+  TrainingConversation,
+  TrainingDataOptions,
+  TrainingDataResult
+} from '@renderer/utils/trainingData'
+
+import { getDefaultTopic } from './AssistantService'
+
+export interface TrainingDataFilter {
+  // 未设置时包含全部助手
+  assistantIds?: string[]
+  topicIds?: string[]
+  // 按话题最后更新时间筛选，毫秒时间戳
+  since?: number
+  until?: number
+}
+
+/**
+ * 找到符合条件的话题，加密话题的内容只能在应用内解锁后读取，不会导出
+ */
+export function selectTrainingTopics(filter: TrainingDataFilter): Topic[] {
+  return store
+    .getState()
+    .assistants.assistants.filter((assistant) => !filter.assistantIds || filter.assistantIds.includes(assistant.id))
+    .flatMap((assistant) => assistant.topics)
+    .filter((topic) => !topic.secure)
+    .filter((topic) => !filter.topicIds || filter.topicIds.includes(topic.id))
+    .filter((topic) => {
+      const updatedAt = new Date(topic.updatedAt).getTime()
+      return (!filter.since || updatedAt >= filter.since) && (!filter.until || updatedAt <= filter.until)
+    })
+}
+
+async function loadConversation(topic: Topic): Promise<TrainingConversation> {
+  const assistant = store.getState().assistants.assistants.find((a) => a.id === topic.assistantId)
+  const messages = ((await db.topics.get(topic.id))?.messages ?? []).filter(
+    (m) => (m.role === 'user' || m.role === 'assistant') && m.status !== 'error'
+  )
+  // 直接从数据库读取消息块，不写入 store
+  const blocks = await db.message_blocks
+    .where('messageId')
+    .anyOf(messages.map((m) => m.id))
+    .toArray()
+  const blocksById = new Map(blocks.map((block) => [block.id, block]))
+
+  return {
+    system: [assistant?.prompt, topic.prompt].filter(Boolean).join('\n'),
+    messages: messages.map((m) => ({
+      role: m.role as 'user' | 'assistant',
+      content: getMainTextContentFromBlocks(m, blocksById)
+    }))
+  }
+}
+
+/**
+ * 把选中的对话导出为 OpenAI 或 ShareGPT 格式的 JSONL 微调数据
+ */
+export async function exportTrainingData(
+  filter: TrainingDataFilter,
+  options: TrainingDataOptions
+): Promise<TrainingDataResult> {
+  const conversations: TrainingConversation[] = []
+  for (const topic of selectTrainingTopics(filter)) {
+    conversations.push(await loadConversation(topic))
+  }
+  return toTrainingJsonl(conversations, { ...options, secrets: options.secrets ?? getStoredSecrets() })
+}
+
+
+/**
+ * 把 OpenAI 或 ShareGPT 格式的 JSONL 导入为助手下的新话题，每条记录一个话题
+ * 记录中的系统提示词作为话题提示词，话题名称取第一条提问
+ */
+export async function importTrainingData(
+  content: string,
+  assistantId: string
+): Promise<{ count: number; skipped: number }> {
+  const { conversations, skipped } = parseTrainingJsonl(content)
+  const topics: Topic[] = []
+  const rows: { id: string; messages: Message[] }[] = []
+  const blocks: MessageBlock[] = []
+
+  for (const conversation of conversations) {
+    const topic = getDefaultTopic(assistantId)
+    const messages = conversation.messages.map(({ role, content }) => {
+      const messageId = uuid()
+      const block = createMainTextBlock(messageId, content, { status: MessageBlockStatus.SUCCESS })
+      blocks.push(block)
+      const message = createMessage(role, topic.id, assistantId, { id: messageId, blocks: [block.id] })
+      return role === 'assistant' ? { ...message, status: AssistantMessageStatus.SUCCESS } : message
+    })
+    const question = conversation.messages.find((message) => message.role === 'user')?.content.trim()
+    topics.push({ ...topic, name: question ? question.slice(0, 50) : topic.name, prompt: conversation.system })
+    rows.push({ id: topic.id, messages })
+  }
+
+  await db.transaction('rw', db.topics, db.message_blocks, async () => {
+    await db.message_blocks.bulkPut(blocks)
+    await db.topics.bulkPut(rows)
+  })
+  topics.forEach((topic) => store.dispatch(addTopic({ assistantId, topic })))
+  return { count: topics.length, skipped }
+}
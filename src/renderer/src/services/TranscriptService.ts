@@ -0,0 +1,68 @@
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import i18n from '@renderer/i18n'
+import store from '@renderer/store'
+import { addTopic } from '@renderer/store/assistants'
+import { Topic, TranscriptFile } from '@renderer/types'
+import { removeSpecialCharactersForFileName } from '@renderer/utils/file'
+import { createTranscript, importTranscript, parseTranscript } from '@renderer/utils/transcript'
+import { TRANSCRIPT_EXTENSION } from '@shared/config/constant'
+
+import NavigationService from './NavigationService'
+import { openBlocks } from './SecureTopicService'
+
+/**
+ * 从系统打开的 .metheuschat 对话文件导入为第一个助手下的只读话题，导入后跳转到该话题
+ */
+async function openTranscript(file: TranscriptFile) {
+  try {
+    const assistant = store.getState().assistants.assistants[0]
+    const { topic, messages, blocks } = importTranscript(parseTranscript(file.content), assistant.id)
+
+    await db.transaction('rw', db.topics, db.message_blocks, async () => {
+      await db.message_blocks.bulkAdd(blocks)
+      await db.topics.add({ id: topic.id, messages })
+    })
+
+    store.dispatch(addTopic({ assistantId: assistant.id, topic }))
+    NavigationService.navigate?.('/', { state: { assistant, topic } })
+    Logger.log(`[TranscriptService] Imported ${file.path} as topic ${topic.id}`)
+  } catch (error) {
+    Logger.error(`[TranscriptService] Failed to import ${file.path}:`, error)
+    window.message.error({ content: i18n.t('chat.topics.transcript.import_failed'), key: 'transcript-import' })
+  }
+}
+
+/**
+ * 导出话题为 .metheuschat 对话文件，加密话题导出解密后的内容
+ */
+export async function exportTopicAsTranscript(topic: Topic) {
+  try {
+    const messages = (await db.topics.get(topic.id))?.messages ?? []
+    const stored = await db.message_blocks
+      .where('messageId')
+      .anyOf(messages.map((message) => message.id))
+      .toArray()
+    const blocks = topic.secure ? await openBlocks(topic.id, stored) : stored
+
+    const fileName = removeSpecialCharactersForFileName(topic.name) + TRANSCRIPT_EXTENSION
+    const content = JSON.stringify(createTranscript(topic, messages, blocks))
+    const result = await window.api.file.save(fileName, content)
+    if (result) {
+      window.message.success({ content: i18n.t('chat.topics.transcript.exported'), key: 'transcript-export' })
+    }
+  } catch (error) {
+    Logger.error('[TranscriptService] Failed to export transcript:', error)
+    window.message.error({ content: i18n.t('chat.topics.transcript.export_failed'), key: 'transcript-export' })
+  }
+}
+
+let initialized = false
+
+export function initTranscriptService() {
+  if (initialized) return
+  initialized = true
+
+  window.api.transcript.onOpened(openTranscript)
+  window.api.transcript.takePending().then((files) => files.forEach(openTranscript))
+}
@@ -0,0 +1,51 @@
+import AiProvider from '@renderer/aiCore'
+import { Model } from '@renderer/types'
+import { encodeWav } from '@renderer/utils/wav'
+
+import { fetchGenerate } from './ApiService'
+import { getProviderByModel } from './AssistantService'
+
+export interface TranscriptSegment {
+  id: string
+  text: string
+  time: number
+}
+
+/**
+ * 通过 OpenAI 兼容的 /audio/transcriptions 接口转写一个语音段
+ */
+export async function transcribeSegment(model: Model, segment: Float32Array, sampleRate: number): Promise<string> {
+  const aiProvider = new AiProvider(getProviderByModel(model))
+  const form = new FormData()
+  form.append('file', new Blob([encodeWav(segment, sampleRate)], { type: 'audio/wav' }), 'segment.wav')
+  form.append('model', model.id)
+  form.append('response_format', 'json')
+
+  const response = await fetch(`${aiProvider.getBaseURL().replace(/\/$/, '')}/audio/transcriptions`, {
+    method: 'POST',
+    headers: { Authorization: `Bearer ${aiProvider.getApiKey()}` },
+    body: form
+  })
+  if (!response.ok) {
+    throw new Error(`Transcription failed: ${response.status} ${await response.text()}`)
+  }
+
+  const { text } = await response.json()
+  return (text ?? '').trim()
+}
+
+export function formatTranscript(segments: TranscriptSegment[]): string {
+  return segments.map((segment) => segment.text).join('\n')
+}
+
+/**
+ * 用默认模型总结目前为止的会议内容
+ */
+export function summarizeTranscript(segments: TranscriptSegment[]): Promise<string> {
+  return fetchGenerate({
+    prompt:
+      'Summarize the meeting transcript so far. List the key points, decisions and action items. ' +
+      'Answer in the language of the transcript.',
+    content: formatTranscript(segments)
+  })
+}
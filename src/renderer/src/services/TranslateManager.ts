@@ -0,0 +1,237 @@
+import { TranslateLanguageOptions } from '@renderer/config/translate'
+import db from '@renderer/databases'
+import i18n from '@renderer/i18n'
+import { Job, TranslateProvider } from '@renderer/types'
+import {
+  splitParagraphs,
+  splitSentences,
+  splitSurroundingWhitespace,
+  toDeepLLanguage,
+  toGoogleLanguage
+} from '@renderer/utils/translate'
+
+import { fetchTranslate } from './ApiService'
+import { getDefaultModel, getDefaultTranslateAssistant, getTranslateModel } from './AssistantService'
+import { runPool } from './BatchService'
+import { checkpointJob, createJob, isJobPaused, registerJobRunner, runJob } from './JobManager'
+import { getResponseCacheKey } from './ResponseCacheService'
+
+// 句子级缓存的条数上限，超出后淘汰最早的条目
+const MAX_CACHE_ENTRIES = 20000
+// 翻译 API 单次请求的句子数，DeepL 限制为 50 条
+const API_BATCH_SIZE = 50
+const LLM_CONCURRENCY = 4
+
+export const TRANSLATE_PROVIDERS: TranslateProvider[] = ['llm', 'deepl', 'google']
+
+export async function getTranslateProvider(): Promise<TranslateProvider> {
+  const setting = await db.settings.get({ id: 'translate:provider' })
+  return setting?.value ?? 'llm'
+}
+
+export async function getTranslateApiKey(provider: TranslateProvider): Promise<string> {
+  const setting = await db.settings.get({ id: `translate:api-key:${provider}` })
+  return setting?.value ?? ''
+}
+
+function getLangCode(targetLanguage: string): string {
+  const option = TranslateLanguageOptions.find((item) => item.value === targetLanguage)
+  if (!option?.langCode) {
+    throw new Error(`Unsupported target language: ${targetLanguage}`)
+  }
+  return option.langCode
+}
+
+async function requireApiKey(provider: TranslateProvider): Promise<string> {
+  const apiKey = await getTranslateApiKey(provider)
+  if (!apiKey) {
+    throw new Error(i18n.t('translate.error.no_api_key'))
+  }
+  return apiKey
+}
+
+async function translateWithDeepL(texts: string[], targetLanguage: string): Promise<string[]> {
+  const apiKey = await requireApiKey('deepl')
+  // 免费版的 key 以 :fx 结尾，使用单独的域名
+  const host = apiKey.endsWith(':fx') ? 'https://api-free.deepl.com' : 'https://api.deepl.com'
+
+  const response = await fetch(`${host}/v2/translate`, {
+    method: 'POST',
+    headers: {
+      Authorization: `DeepL-Auth-Key ${apiKey}`,
+      'Content-Type': 'application/json'
+    },
+    body: JSON.stringify({ text: texts, target_lang: toDeepLLanguage(getLangCode(targetLanguage)) })
+  })
+  if (!response.ok) {
+    throw new Error(`DeepL: ${response.status} ${await response.text()}`)
+  }
+
+  const data = await response.json()
+  return data.translations.map((item: { text: string }) => item.text)
+}
+
+async function translateWithGoogle(texts: string[], targetLanguage: string): Promise<string[]> {
+  const apiKey = await requireApiKey('google')
+
+  const response = await fetch(
+    `https://translation.googleapis.com/language/translate/v2?key=${encodeURIComponent(apiKey)}`,
+    {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      body: JSON.stringify({ q: texts, target: toGoogleLanguage(getLangCode(targetLanguage)), format: 'text' })
+    }
+  )
+  if (!response.ok) {
+    throw new Error(`Google Translate: ${response.status} ${await response.text()}`)
+  }
+
+  const data = await response.json()
+  return data.data.translations.map((item: { translatedText: string }) => item.translatedText)
+}
+
+async function translateWithLLM(texts: string[], targetLanguage: string): Promise<string[]> {
+  if (!getTranslateModel()) {
+    throw new Error(i18n.t('translate.error.not_configured'))
+  }
+
+  const results: string[] = new Array(texts.length)
+  const tasks = texts.map((text, index) => async () => {
+    const assistant = getDefaultTranslateAssistant(targetLanguage, text)
+    const translated = (await fetchTranslate({ content: text, assistant })).trim()
+    if (!translated) {
+      throw new Error(i18n.t('translate.error.failed'))
+    }
+    results[index] = translated
+  })
+
+  await runPool(tasks, LLM_CONCURRENCY)
+  return results
+}
+
+async function translateUncached(texts: string[], targetLanguage: string, provider: TranslateProvider) {
+  if (provider === 'llm') {
+    return translateWithLLM(texts, targetLanguage)
+  }
+
+  const results: string[] = []
+  for (let i = 0; i < texts.length; i += API_BATCH_SIZE) {
+    const batch = texts.slice(i, i + API_BATCH_SIZE)
+    const translated =
+      provider === 'deepl'
+        ? await translateWithDeepL(batch, targetLanguage)
+        : await translateWithGoogle(batch, targetLanguage)
+    results.push(...translated)
+  }
+  return results
+}
+
+function getCacheKey(sentence: string, targetLanguage: string, provider: TranslateProvider) {
+  // 不同模型的译文不同，LLM 的缓存按翻译模型区分
+  const model = provider === 'llm' ? getTranslateModel()?.id : undefined
+  return getResponseCacheKey({ sentence, targetLanguage, provider, model })
+}
+
+async function saveToCache(
+  entries: { key: string; text: string }[],
+  targetLanguage: string,
+  provider: TranslateProvider
+) {
+  const now = Date.now()
+  await db.translate_cache.bulkPut(
+    entries.map(({ key, text }) => ({ key, provider, targetLanguage, text, createdAt: now }))
+  )
+
+  const overflow = (await db.translate_cache.count()) - MAX_CACHE_ENTRIES
+  if (overflow > 0) {
+    const oldest = await db.translate_cache.orderBy('createdAt').limit(overflow).primaryKeys()
+    await db.translate_cache.bulkDelete(oldest)
+  }
+}
+
+/**
+ * 翻译文本，按句子查询本地缓存，只有未命中的句子才会请求翻译服务
+ * @param text 待翻译的文本
+ * @param targetLanguage 目标语言，如 english
+ * @param provider 翻译服务，默认使用翻译设置中选择的服务
+ * @returns 译文，保留原文的空白和换行
+ */
+export async function translate(text: string, targetLanguage: string, provider?: TranslateProvider): Promise<string> {
+  const translateProvider = provider ?? (await getTranslateProvider())
+  const segments = splitSentences(text).map(splitSurroundingWhitespace)
+  const sentences = segments.map(([, sentence]) => sentence)
+
+  const keys = await Promise.all(
+    sentences.map((sentence) => (sentence ? getCacheKey(sentence, targetLanguage, translateProvider) : ''))
+  )
+  const cached = await db.translate_cache.bulkGet(keys)
+
+  const missed = [...new Set(sentences.filter((sentence, index) => sentence && !cached[index]))]
+  const translations = new Map<string, string>()
+
+  if (missed.length > 0) {
+    const results = await translateUncached(missed, targetLanguage, translateProvider)
+    missed.forEach((sentence, index) => translations.set(sentence, results[index]))
+
+    const entries = missed.map((sentence, index) => ({
+      key: keys[sentences.indexOf(sentence)],
+      text: results[index]
+    }))
+    await saveToCache(entries, targetLanguage, translateProvider)
+  }
+
+  return segments
+    .map(([leading, sentence, trailing], index) => {
+      if (!sentence) return leading + trailing
+      return leading + (cached[index]?.text ?? translations.get(sentence)) + trailing
+    })
+    .join('')
+}
+
+export async function clearTranslateCache(): Promise<void> {
+  await db.translate_cache.clear()
+}
+
+async function runTranslateJob(job: Job<string, string>) {
+  const { targetLanguage, provider } = job.params as { targetLanguage: string; provider: TranslateProvider }
+
+  // 逐段翻译，进度按段落保存，句子缓存让重试的段落不会重复请求
+  for (let index = 0; index < job.inputs.length; index++) {
+    if (isJobPaused(job.id)) return
+    if (job.outputs[index] !== null) continue
+
+    const translated = await translate(job.inputs[index], targetLanguage, provider)
+    await checkpointJob(job, [[index, translated]])
+  }
+}
+
+export function initTranslateManager() {
+  registerJobRunner('translate', runTranslateJob)
+}
+
+/**
+ * 批量翻译文档，作为后台任务运行，应用重启后自动继续
+ * @param text 文档内容，按段落拆分
+ * @param targetLanguage 目标语言
+ * @param provider 翻译服务，默认使用翻译设置中选择的服务
+ * @returns 译文，段落之间以空行分隔
+ */
+export async function translateDocument(
+  text: string,
+  targetLanguage: string,
+  provider?: TranslateProvider
+): Promise<string> {
+  const translateProvider = provider ?? (await getTranslateProvider())
+  // 任务记录需要模型，非 LLM 服务不会用到
+  const model = getTranslateModel() || getDefaultModel()
+  const job = await createJob<string, string>('translate', model, splitParagraphs(text), {
+    targetLanguage,
+    provider: translateProvider
+  })
+
+  const result = await runJob(job)
+  if (result.status !== 'completed') {
+    throw new Error(`Translate job ${job.id} was ${result.status}`)
+  }
+  return result.outputs.join('\n\n')
+}
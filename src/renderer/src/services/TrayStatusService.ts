@@ -0,0 +1,44 @@
+import store from '@renderer/store'
+import { Job, TrayStatus } from '@renderer/types'
+import { debounce, isEqual } from 'lodash'
+
+import { EVENT_NAMES, EventEmitter } from './EventService'
+
+/**
+ * 托盘提示显示当前的活动：正在生成的请求、运行中的批量任务和备份同步
+ * 状态变化时汇总后发送给主进程
+ */
+
+const runningJobs = new Set<string>()
+let reported: TrayStatus | null = null
+
+const report = debounce(() => {
+  const { messages, backup } = store.getState()
+  const status: TrayStatus = {
+    generating: Object.values(messages.loadingByTopic).filter(Boolean).length,
+    jobs: runningJobs.size,
+    syncing: backup.webdavSync.syncing,
+    lastBackupTime: backup.webdavSync.lastSyncTime
+  }
+  if (isEqual(status, reported)) return
+  reported = status
+  window.api.setTrayStatus(status)
+}, 500)
+
+let initialized = false
+
+export function initTrayStatus() {
+  if (initialized) return
+  initialized = true
+
+  EventEmitter.on(EVENT_NAMES.JOB_PROGRESS, (job: { id: string; status: Job['status'] }) => {
+    if (job.status === 'running') {
+      runningJobs.add(job.id)
+    } else {
+      runningJobs.delete(job.id)
+    }
+    report()
+  })
+  store.subscribe(report)
+  report()
+}
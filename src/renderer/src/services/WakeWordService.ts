@@ -0,0 +1,124 @@
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import store from '@renderer/store'
+import { WakeWordModel } from '@renderer/types'
+import { computeMfcc, getTemplateSpread, matchWakeWord } from '@renderer/utils/wakeWord'
+
+import { audioManager } from './AudioManager'
+import { EVENT_NAMES, EventEmitter } from './EventService'
+
+const MODELS_KEY = 'wake-word:models'
+// 唤醒词的语音段时长范围，更短或更长的语音段不做匹配，节省计算
+const MIN_SEGMENT_MS = 250
+const MAX_SEGMENT_MS = 2000
+// 触发后的冷却时间，避免一句话重复触发
+const COOLDOWN_MS = 3000
+
+export async function getWakeWordModels(): Promise<WakeWordModel[]> {
+  return (await db.settings.get(MODELS_KEY))?.value ?? []
+}
+
+async function saveWakeWordModels(models: WakeWordModel[]) {
+  await db.settings.put({ id: MODELS_KEY, value: models })
+  if (initialized) await syncWakeWord()
+}
+
+/**
+ * 用录制的样本创建唤醒词，至少需要 3 个样本来估计检测阈值
+ */
+export async function createWakeWordModel(name: string, samples: { segment: Float32Array; sampleRate: number }[]) {
+  const templates = samples.map(({ segment, sampleRate }) => computeMfcc(segment, sampleRate))
+  const model: WakeWordModel = {
+    id: crypto.randomUUID(),
+    name,
+    templates,
+    spread: getTemplateSpread(templates),
+    createdAt: Date.now()
+  }
+  await saveWakeWordModels([...(await getWakeWordModels()), model])
+  return model
+}
+
+export async function removeWakeWordModel(id: string) {
+  await saveWakeWordModels((await getWakeWordModels()).filter((model) => model.id !== id))
+}
+
+/**
+ * 录制一个语音段，用于录入唤醒词样本
+ */
+export function recordSpeechSegment(timeout = 5000) {
+  return new Promise<{ segment: Float32Array; sampleRate: number }>((resolve, reject) => {
+    const wasListening = audioManager.listening
+    const finish = () => {
+      clearTimeout(timer)
+      off()
+      if (!wasListening) audioManager.stop()
+    }
+    const off = audioManager.onSegment((segment, sampleRate) => {
+      finish()
+      resolve({ segment, sampleRate })
+    })
+    const timer = setTimeout(() => {
+      finish()
+      reject(new Error('No speech detected'))
+    }, timeout)
+    audioManager.start({ hangoverMs: 400, maxSegmentMs: MAX_SEGMENT_MS }).catch((error) => {
+      finish()
+      reject(error)
+    })
+  })
+}
+
+let initialized = false
+let removeHandler: (() => void) | null = null
+let lastDetected = 0
+
+/**
+ * 根据设置启动或停止唤醒词检测
+ * 麦克风输入先经过 VAD，只有长度接近唤醒词的语音段才计算特征并匹配，空闲时几乎不占用 CPU
+ */
+async function syncWakeWord() {
+  const { wakeWordEnabled, wakeWordModelId, wakeWordSensitivity } = store.getState().settings
+  const model = (await getWakeWordModels()).find((item) => item.id === wakeWordModelId)
+
+  removeHandler?.()
+  removeHandler = null
+
+  if (!wakeWordEnabled || !model) {
+    if (audioManager.listening) await audioManager.stop()
+    return
+  }
+
+  removeHandler = audioManager.onSegment((segment, sampleRate) => {
+    const duration = (segment.length / sampleRate) * 1000
+    if (duration < MIN_SEGMENT_MS || duration > MAX_SEGMENT_MS || Date.now() - lastDetected < COOLDOWN_MS) return
+
+    if (matchWakeWord(computeMfcc(segment, sampleRate), model, wakeWordSensitivity)) {
+      lastDetected = Date.now()
+      Logger.log(`[WakeWord] Detected "${model.name}"`)
+      EventEmitter.emit(EVENT_NAMES.WAKE_WORD_DETECTED, { modelId: model.id })
+      window.api.miniWindow.show(true)
+    }
+  })
+
+  try {
+    await audioManager.start({ hangoverMs: 400, maxSegmentMs: MAX_SEGMENT_MS })
+  } catch (error) {
+    Logger.error('[WakeWord] Failed to start the microphone:', error)
+  }
+}
+
+export function initWakeWord() {
+  if (initialized) return
+  initialized = true
+
+  let previous = ''
+  store.subscribe(() => {
+    const { wakeWordEnabled, wakeWordModelId, wakeWordSensitivity } = store.getState().settings
+    const current = `${wakeWordEnabled}:${wakeWordModelId}:${wakeWordSensitivity}`
+    if (current === previous) return
+    previous = current
+    syncWakeWord()
+  })
+  syncWakeWord()
+}
@@ -0,0 +1,127 @@
+import Logger from '@renderer/config/logger'
+import store from '@renderer/store'
+import { WebviewTag } from 'electron'
+
+const PARTITION = 'persist:webview'
+const BLANK_URL = 'about:blank'
+
+let host: HTMLElement | null = null
+let poolSize = 0
+/** 已加载空白页、可以直接使用的 webview */
+const idle: WebviewTag[] = []
+/** 正在加载空白页的 webview */
+const warming = new Set<WebviewTag>()
+
+/**
+ * 空白页加载完成后 webview 进入空闲列表
+ */
+function warm(webview: WebviewTag) {
+  warming.add(webview)
+  webview.addEventListener(
+    'did-finish-load',
+    () => {
+      if (!warming.delete(webview)) return
+      webview.clearHistory()
+      idle.push(webview)
+    },
+    { once: true }
+  )
+}
+
+function createWebview(parent: HTMLElement) {
+  const webview = document.createElement('webview') as WebviewTag
+  // 分区必须在第一次导航前设置
+  webview.setAttribute('partition', PARTITION)
+  webview.setAttribute('allowpopups', 'true')
+  Object.assign(webview.style, WebviewPoolStyle, { display: 'none' })
+
+  warm(webview)
+  webview.src = BLANK_URL
+  parent.appendChild(webview)
+}
+
+/**
+ * 按设置补足或缩减预热池
+ */
+function resize() {
+  while (idle.length > poolSize) {
+    idle.pop()?.remove()
+  }
+  if (!host) return
+  for (let count = idle.length + warming.size; count < poolSize; count++) {
+    createWebview(host)
+  }
+}
+
+/**
+ * 预热池所在的元素，webview 一旦挂载就不能移动，否则会重新创建页面进程，
+ * 所以必须是小程序 webview 所在的容器，取出的 webview 原地显示
+ */
+export function setWebviewPoolHost(element: HTMLElement | null) {
+  if (element === host) return
+  if (host) {
+    idle.splice(0).forEach((webview) => webview.remove())
+    warming.forEach((webview) => webview.remove())
+    warming.clear()
+  }
+  host = element
+  resize()
+}
+
+/**
+ * 取出一个已预热的 webview，使用 persist:webview 分区，没有可用的返回 null
+ * 取出后会在后台补充新的 webview
+ */
+export function acquireWebview(): WebviewTag | null {
+  const webview = idle.shift()
+  if (!webview) return null
+
+  webview.style.display = WebviewPoolStyle.display!
+  setTimeout(resize, 0)
+  return webview
+}
+
+/**
+ * 归还 acquireWebview 取出的 webview，池未满时回到空白页等待复用，否则销毁
+ */
+export function releaseWebview(webview: WebviewTag) {
+  if (!host || !host.contains(webview) || idle.length + warming.size >= poolSize) {
+    webview.remove()
+    return
+  }
+
+  webview.style.display = 'none'
+  warm(webview)
+  // 小程序隐藏时可能已被冻结，先恢复再回到空白页
+  window.api.webview
+    .resume(webview.getWebContentsId())
+    .then(() => webview.loadURL(BLANK_URL))
+    .catch((error) => {
+      Logger.error('[WebviewPool] Failed to recycle webview:', error)
+      warming.delete(webview)
+      webview.remove()
+    })
+}
+
+export const WebviewPoolStyle: Partial<CSSStyleDeclaration> = {
+  width: 'calc(100vw - var(--sidebar-width))',
+  height: 'calc(100vh - var(--navbar-height))',
+  backgroundColor: 'var(--color-background)',
+  display: 'inline-flex'
+}
+
+let initialized = false
+
+export function initWebviewPool() {
+  if (initialized) return
+  initialized = true
+
+  poolSize = store.getState().settings.minappsWarmPoolSize
+  store.subscribe(() => {
+    const { minappsWarmPoolSize } = store.getState().settings
+    if (minappsWarmPoolSize === poolSize) return
+    poolSize = minappsWarmPoolSize
+    resize()
+  })
+  resize()
+}
@@ -0,0 +1,183 @@
+import Logger from '@renderer/config/logger'
+import db from '@renderer/databases'
+import store from '@renderer/store'
+import { updateAgents } from '@renderer/store/agents'
+import { updateAssistants } from '@renderer/store/assistants'
+import { setDisabledMinApps, setMinApps, setPinnedMinApps } from '@renderer/store/minapps'
+import { importSettings } from '@renderer/store/settings'
+import { Agent, Assistant, MinAppType, QuickPhrase, WorkspaceMergeStrategy } from '@renderer/types'
+import { MessageBlock } from '@renderer/types/newMessage'
+import { mergeById, mergeWorkspaceSettings, stripWorkspaceSecrets } from '@renderer/utils/workspace'
+
+import { getDefaultTopic } from './AssistantService'
+import { isSealedBlock } from './SecureTopicService'
+
+const WORKSPACE_VERSION = 1
+
+interface WorkspaceData {
+  version: number
+  exportedAt: number
+  settings: Record<string, any>
+  assistants: Assistant[]
+  agents: Agent[]
+  minapps: {
+    enabled: MinAppType[]
+    disabled: MinAppType[]
+    pinned: MinAppType[]
+  }
+  quickPhrases: QuickPhrase[]
+  // only present when conversations are exported
+  conversations?: {
+    topics: { id: string; messages: any[] }[]
+    blocks: MessageBlock[]
+  }
+}
+
+export interface WorkspaceSigner {
+  fingerprint: string
+  own: boolean
+  // 签名者已被信任，否则只是自签名
+  trusted: boolean
+  createdAt: number
+}
+
+export interface WorkspaceImportSummary extends WorkspaceSigner {
+  assistants: number
+  agents: number
+  conversations: number
+}
+
+async function collectWorkspace(includeConversations: boolean): Promise<WorkspaceData> {
+  const state = store.getState()
+  // secure topics never leave the app in a bundle, their keys stay in this installation's keychain
+  const secureTopicIds = new Set(
+    state.assistants.assistants
+      .flatMap((assistant) => assistant.topics)
+      .filter((topic) => topic.secure)
+      .map((topic) => topic.id)
+  )
+
+  const data: WorkspaceData = {
+    version: WORKSPACE_VERSION,
+    exportedAt: Date.now(),
+    settings: stripWorkspaceSecrets(state.settings),
+    // topics belong to the conversations, assistants get a fresh topic on import when they are left out
+    assistants: state.assistants.assistants.map((assistant) =>
+      includeConversations
+        ? { ...assistant, topics: assistant.topics.filter((topic) => !secureTopicIds.has(topic.id)) }
+        : { ...assistant, topics: [] }
+    ),
+    agents: state.agents.agents,
+    minapps: state.minapps,
+    quickPhrases: await db.quick_phrases.toArray()
+  }
+
+  if (includeConversations) {
+    const topics = await db.topics.toArray()
+    const secureMessageIds = new Set(
+      topics.filter((topic) => secureTopicIds.has(topic.id)).flatMap((topic) => topic.messages.map((m) => m.id))
+    )
+    const blocks = await db.message_blocks.toArray()
+    data.conversations = {
+      topics: topics.filter((topic) => !secureTopicIds.has(topic.id)),
+      blocks: blocks.filter((block) => !secureMessageIds.has(block.messageId) && !isSealedBlock(block))
+    }
+  }
+
+  return data
+}
+
+/**
+ * 导出工作区为签名加密的文件，不包含模型服务商和各类凭据
+ * 取消选择文件时返回 null
+ */
+export async function exportWorkspace(passphrase: string, includeConversations: boolean) {
+  const data = await collectWorkspace(includeConversations)
+  return window.api.workspace.export(JSON.stringify(data), passphrase)
+}
+
+function mergeAssistants(local: Assistant[], incoming: Assistant[], strategy: WorkspaceMergeStrategy) {
+  const localById = new Map(local.map((assistant) => [assistant.id, assistant]))
+
+  // keep the local topics of matching assistants so their conversations stay reachable
+  const prepared = incoming.map((assistant) => {
+    const topics = mergeById(localById.get(assistant.id)?.topics ?? [], assistant.topics ?? [], 'merge')
+    return { ...assistant, topics: topics.length ? topics : [getDefaultTopic(assistant.id)] }
+  })
+
+  return mergeById(local, prepared, strategy)
+}
+
+async function importConversations(
+  conversations: NonNullable<WorkspaceData['conversations']>,
+  strategy: WorkspaceMergeStrategy
+) {
+  let { topics, blocks } = conversations
+
+  if (strategy === 'keep_existing') {
+    const topicIds = new Set(await db.topics.toCollection().primaryKeys())
+    const blockIds = new Set(await db.message_blocks.toCollection().primaryKeys())
+    topics = topics.filter((topic) => !topicIds.has(topic.id))
+    blocks = blocks.filter((block) => !blockIds.has(block.id))
+  }
+
+  await db.transaction('rw', db.topics, db.message_blocks, async () => {
+    await db.topics.bulkPut(topics)
+    await db.message_blocks.bulkPut(blocks)
+  })
+
+  return topics.length
+}
+
+/**
+ * 导入工作区文件，按合并策略与本地数据合并
+ * 签名校验通过后先由 confirm 确认签名者，取消时返回 null
+ */
+export async function importWorkspace(
+  filePath: string,
+  passphrase: string,
+  strategy: WorkspaceMergeStrategy,
+  confirm: (signer: WorkspaceSigner) => Promise<boolean>
+): Promise<WorkspaceImportSummary | null> {
+  const { data: raw, fingerprint, own, trusted, createdAt } = await window.api.workspace.import(filePath, passphrase)
+  const data: WorkspaceData = JSON.parse(raw)
+  if (data.version > WORKSPACE_VERSION) {
+    throw new Error(`Unsupported workspace version ${data.version}`)
+  }
+  if (!(await confirm({ fingerprint, own, trusted, createdAt }))) {
+    return null
+  }
+
+  const state = store.getState()
+  let conversations = 0
+
+  // conversations first, assistants reference their topics
+  if (data.conversations) {
+    conversations = await importConversations(data.conversations, strategy)
+  }
+
+  const settings = mergeWorkspaceSettings(state.settings, data.settings, strategy)
+  store.dispatch(importSettings(settings))
+  store.dispatch(updateAssistants(mergeAssistants(state.assistants.assistants, data.assistants, strategy)))
+  store.dispatch(updateAgents(mergeById(state.agents.agents, data.agents, strategy)))
+  store.dispatch(setMinApps(mergeById(state.minapps.enabled, data.minapps.enabled, strategy)))
+  store.dispatch(setDisabledMinApps(mergeById(state.minapps.disabled, data.minapps.disabled, strategy)))
+  store.dispatch(setPinnedMinApps(mergeById(state.minapps.pinned, data.minapps.pinned, strategy)))
+
+  const quickPhrases = mergeById(await db.quick_phrases.toArray(), data.quickPhrases, strategy)
+  await db.transaction('rw', db.quick_phrases, async () => {
+    await db.quick_phrases.clear()
+    await db.quick_phrases.bulkPut(quickPhrases)
+  })
+
+  Logger.log(`[WorkspaceService] Imported workspace signed by ${fingerprint} with strategy ${strategy}`)
+
+  return {
+    fingerprint,
+    own,
+    createdAt,
+    assistants: data.assistants.length,
+    agents: data.agents.length,
+    conversations
+  }
+}
@@ -22,6 +22,9 @@ type LlmSettings = {
     projectId: string
     location: string
   }
+  awsBedrock: {
+    region: string
+  }
 }
 
 export interface LlmState {
@@ -247,6 +250,26 @@ export const INITIAL_PROVIDERS: Provider[] = [
     enabled: false,
     isVertex: true
   },
+  {
+    id: 'aws-bedrock',
+    name: 'AWS Bedrock',
+    type: 'aws-bedrock',
+    apiKey: '',
+    apiHost: '',
+    models: SYSTEM_MODELS['aws-bedrock'],
+    isSystem: true,
+    enabled: false
+  },
+  {
+    id: 'local',
+    name: 'Local',
+    type: 'local',
+    apiKey: '',
+    apiHost: '',
+    models: [],
+    isSystem: true,
+    enabled: false
+  },
   {
     id: 'github',
     name: 'Github Models',
@@ -543,6 +566,9 @@ export const initialState: LlmState = {
       },
       projectId: '',
       location: ''
+    },
+    awsBedrock: {
+      region: 'us-east-1'
     }
   }
 }
@@ -664,6 +690,9 @@ const llmSlice = createSlice({
     setVertexAIServiceAccountClientEmail: (state, action: PayloadAction<string>) => {
       state.settings.vertexai.serviceAccount.clientEmail = action.payload
     },
+    setAwsBedrockRegion: (state, action: PayloadAction<string>) => {
+      state.settings.awsBedrock.region = action.payload
+    },
     updateModel: (
       state,
       action: PayloadAction<{
@@ -700,6 +729,7 @@ export const {
   setVertexAILocation,
   setVertexAIServiceAccountPrivateKey,
   setVertexAIServiceAccountClientEmail,
+  setAwsBedrockRegion,
   updateModel
 } = llmSlice.actions
 
@@ -232,7 +232,8 @@ export const formatCitationsFromBlock = (block: CitationMessageBlock | undefined
           title: title,
           content: result.content,
           showFavicon: showFavicon,
-          type: 'knowledge'
+          type: 'knowledge',
+          metadata: result.citation && { citation: result.citation }
         }
       })
     )
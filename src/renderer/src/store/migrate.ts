@@ -1607,6 +1607,204 @@ const migrateConfig = {
     } catch (error) {
       return state
     }
+  },
+  '115': (state: RootState) => {
+    try {
+      if (!state.settings.llmCache) {
+        state.settings.llmCache = settingsInitialState.llmCache
+      }
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '116': (state: RootState) => {
+    try {
+      if (!state.settings.contentGuard) {
+        state.settings.contentGuard = settingsInitialState.contentGuard
+      }
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '117': (state: RootState) => {
+    try {
+      addProvider(state, 'aws-bedrock')
+      if (!state.llm.settings.awsBedrock) {
+        state.llm.settings.awsBedrock = llmInitialState.settings.awsBedrock
+      }
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '118': (state: RootState) => {
+    try {
+      if (state.selectionStore) {
+        // enabling the assistant before the consent prompt existed counts as consent
+        state.selectionStore.isPermissionGranted = state.selectionStore.selectionEnabled
+        state.selectionStore.isAutoCopyResult = false
+        state.selectionStore.isNotifyResult = false
+      }
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '119': (state: RootState) => {
+    try {
+      if (!state.settings.contentFilter) {
+        state.settings.contentFilter = settingsInitialState.contentFilter
+      }
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '120': (state: RootState) => {
+    try {
+      state.settings.archiveAfterDays = 0
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '121': (state: RootState) => {
+    try {
+      state.settings.autoDatabaseMaintenance = false
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '122': (state: RootState) => {
+    try {
+      state.settings.minappsShortcutOptOut = []
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '123': (state: RootState) => {
+    try {
+      state.settings.wakeWordEnabled = false
+      state.settings.wakeWordSensitivity = 0.5
+      state.settings.wakeWordModelId = ''
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '124': (state: RootState) => {
+    try {
+      state.settings.transcriptionModel = undefined
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '125': (state: RootState) => {
+    try {
+      state.settings.minappsWarmPoolSize = 0
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '126': (state: RootState) => {
+    try {
+      state.settings.minappsSuspendMinutes = 0
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '127': (state: RootState) => {
+    try {
+      state.settings.minappsUserAgent = ''
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '128': (state: RootState) => {
+    try {
+      state.settings.routing = settingsInitialState.routing
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '129': (state: RootState) => {
+    try {
+      state.settings.linkPreviews = false
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '130': (state: RootState) => {
+    try {
+      state.settings.minappsRestoreLayout = true
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '131': (state: RootState) => {
+    try {
+      state.settings.minappsContentBlocking = false
+      state.settings.minappsContentBlockingOptOut = []
+      state.settings.minappsFilterLists = settingsInitialState.minappsFilterLists
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '132': (state: RootState) => {
+    try {
+      addProvider(state, 'local')
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '133': (state: RootState) => {
+    try {
+      state.settings.voiceInput = false
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '134': (state: RootState) => {
+    try {
+      state.settings.feedbackSound = false
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '135': (state: RootState) => {
+    try {
+      // 响应缓存改为默认关闭，之前默认开启时用户没有选择过
+      state.settings.llmCache = { ...settingsInitialState.llmCache, ...state.settings.llmCache }
+      state.settings.llmCache.enabled = false
+      state.settings.llmCache.includeSampled = false
+      return state
+    } catch (error) {
+      return state
+    }
+  },
+  '136': (state: RootState) => {
+    try {
+      // 链接预览会请求消息中的地址，改为默认关闭
+      state.settings.linkPreviews = false
+      return state
+    } catch (error) {
+      return state
+    }
   }
 }
 
@@ -1,6 +1,6 @@
 import { createSlice, PayloadAction } from '@reduxjs/toolkit'
 import { AppLogo, UserAvatar } from '@renderer/config/env'
-import type { MinAppType, Topic } from '@renderer/types'
+import type { AgentUpdate, MachinePolicy, MinAppType, MiniAppAudioState, Topic } from '@renderer/types'
 import type { UpdateInfo } from 'builder-util-runtime'
 
 export interface ChatState {
@@ -39,6 +39,12 @@ export interface RuntimeState {
   update: UpdateState
   export: ExportState
   chat: ChatState
+  /** updates available for installed agents, keyed by agent id */
+  agentUpdates: Record<string, AgentUpdate>
+  /** whether each minapp plays sound or is muted, keyed by app id */
+  minappAudioStates: Record<string, MiniAppAudioState>
+  /** machine policy deployed by an administrator, loaded from the main process at startup */
+  policy: MachinePolicy
 }
 
 export interface ExportState {
@@ -72,7 +78,10 @@ const initialState: RuntimeState = {
     activeTopic: null,
     renamingTopics: [],
     newlyRenamedTopics: []
-  }
+  },
+  agentUpdates: {},
+  minappAudioStates: {},
+  policy: { settings: {}, lockedKeys: [] }
 }
 
 const runtimeSlice = createSlice({
@@ -112,6 +121,15 @@ const runtimeSlice = createSlice({
     setExportState: (state, action: PayloadAction<Partial<ExportState>>) => {
       state.export = { ...state.export, ...action.payload }
     },
+    setAgentUpdates: (state, action: PayloadAction<Record<string, AgentUpdate>>) => {
+      state.agentUpdates = action.payload
+    },
+    setMinappAudioState: (state, action: PayloadAction<MiniAppAudioState>) => {
+      state.minappAudioStates[action.payload.appId] = action.payload
+    },
+    setPolicy: (state, action: PayloadAction<MachinePolicy>) => {
+      state.policy = action.payload
+    },
     // Chat related actions
     toggleMultiSelectMode: (state, action: PayloadAction<boolean>) => {
       state.chat.isMultiSelectMode = action.payload
@@ -146,6 +164,9 @@ export const {
   setResourcesPath,
   setUpdateState,
   setExportState,
+  setAgentUpdates,
+  setMinappAudioState,
+  setPolicy,
   // Chat related actions
   toggleMultiSelectMode,
   setSelectedMessageIds,
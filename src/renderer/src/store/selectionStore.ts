@@ -20,6 +20,7 @@ export const defaultActionItems: ActionItem[] = [
 
 export const initialState: SelectionState = {
   selectionEnabled: false,
+  isPermissionGranted: false,
   triggerMode: 'selected',
   isCompact: false,
   isAutoClose: false,
@@ -29,6 +30,8 @@ export const initialState: SelectionState = {
   filterMode: 'default',
   filterList: [],
   actionWindowOpacity: 100,
+  isAutoCopyResult: false,
+  isNotifyResult: false,
   actionItems: defaultActionItems
 }
 
@@ -39,6 +42,9 @@ const selectionSlice = createSlice({
     setSelectionEnabled: (state, action: PayloadAction<boolean>) => {
       state.selectionEnabled = action.payload
     },
+    setIsPermissionGranted: (state, action: PayloadAction<boolean>) => {
+      state.isPermissionGranted = action.payload
+    },
     setTriggerMode: (state, action: PayloadAction<TriggerMode>) => {
       state.triggerMode = action.payload
     },
@@ -66,6 +72,12 @@ const selectionSlice = createSlice({
     setActionWindowOpacity: (state, action: PayloadAction<number>) => {
       state.actionWindowOpacity = action.payload
     },
+    setIsAutoCopyResult: (state, action: PayloadAction<boolean>) => {
+      state.isAutoCopyResult = action.payload
+    },
+    setIsNotifyResult: (state, action: PayloadAction<boolean>) => {
+      state.isNotifyResult = action.payload
+    },
     setActionItems: (state, action: PayloadAction<ActionItem[]>) => {
       state.actionItems = action.payload
     }
@@ -74,6 +86,7 @@ const selectionSlice = createSlice({
 
 export const {
   setSelectionEnabled,
+  setIsPermissionGranted,
   setTriggerMode,
   setIsCompact,
   setIsAutoClose,
@@ -83,6 +96,8 @@ export const {
   setFilterMode,
   setFilterList,
   setActionWindowOpacity,
+  setIsAutoCopyResult,
+  setIsNotifyResult,
   setActionItems
 } = selectionSlice.actions
 
@@ -5,12 +5,16 @@ import {
   CodeStyleVarious,
   LanguageVarious,
   MathEngine,
+  Model,
   OpenAIServiceTier,
   OpenAISummaryText,
   PaintingProvider,
   ThemeMode,
   TranslateLanguageVarious
 } from '@renderer/types'
+import { ContentFilterAction, ContentFilterRule } from '@renderer/utils/contentFilter'
+import { ContentGuardPattern, DEFAULT_CONTENT_GUARD_PATTERNS } from '@renderer/utils/contentGuard'
+import { RoutingRule } from '@renderer/utils/routing'
 
 import { WebDAVSyncState } from './backup'
 
@@ -153,8 +157,36 @@ export interface SettingsState {
   maxKeepAliveMinapps: number
   showOpenedMinappsInSidebar: boolean
   minappsOpenLinkExternal: boolean
+  // 预先创建的隐藏 webview 数量，打开小程序时直接复用
+  minappsWarmPoolSize: number
+  // 隐藏超过该分钟数的小程序被冻结以回收内存，0 表示不冻结
+  minappsSuspendMinutes: number
+  // 启动时重新打开上次退出时打开的小程序
+  minappsRestoreLayout: boolean
+  // 这些小程序自己处理快捷键，不转发给主窗口
+  minappsShortcutOptOut: string[]
+  // 小程序默认使用的 UA，为空时使用去掉 Electron 标识的默认 UA
+  minappsUserAgent: string
+  // 按过滤规则列表拦截小程序中的广告和跟踪请求
+  minappsContentBlocking: boolean
+  // 这些小程序不拦截广告
+  minappsContentBlockingOptOut: string[]
+  // EasyList 格式的过滤规则列表地址
+  minappsFilterLists: string[]
+  // 唤醒词，检测到后打开快捷助手
+  wakeWordEnabled: boolean
+  wakeWordSensitivity: number
+  wakeWordModelId: string
+  // 实时转写使用的语音识别模型
+  transcriptionModel?: Model
+  // 输入框中的免按键语音输入，语音段转写后自动发送
+  voiceInput: boolean
+  // 回复完成和出错时播放提示音，同步到主进程配置
+  feedbackSound: boolean
   // 隐私设置
   enableDataCollection: boolean
+  // 自动获取消息中链接的预览
+  linkPreviews: boolean
   enableQuickPanelTriggers: boolean
   enableBackspaceDeleteModel: boolean
   exportMenuOptions: {
@@ -181,6 +213,40 @@ export interface SettingsState {
     knowledgeEmbed: boolean
   }
   defaultPaintingProvider: PaintingProvider
+  // Response cache for deterministic LLM calls
+  llmCache: {
+    enabled: boolean
+    // hours
+    ttl: number
+    maxEntries: number
+    // also reuse responses of calls with a temperature above 0, which would otherwise vary
+    includeSampled: boolean
+  }
+  // Scan outgoing requests for stored secrets and sensitive patterns
+  contentGuard: {
+    enabled: boolean
+    action: 'redact' | 'confirm' | 'block'
+    patterns: ContentGuardPattern[]
+  }
+  // Archive conversations without activity for this many days, 0 disables auto archiving
+  archiveAfterDays: number
+  // Check the local database while the system is idle, problems are only reported
+  autoDatabaseMaintenance: boolean
+  // Filter chat messages in both directions, assistants can override the action
+  contentFilter: {
+    enabled: boolean
+    inbound: boolean
+    outbound: boolean
+    action: ContentFilterAction
+    rules: ContentFilterRule[]
+    // local safety classifier such as Llama Guard, not used when unset
+    classifierModel?: Model
+  }
+  // Pick the model for each request by rules, the first matching rule wins
+  routing: {
+    enabled: boolean
+    rules: RoutingRule[]
+  }
 }
 
 export type MultiModelMessageStyle = 'horizontal' | 'vertical' | 'fold' | 'grid'
@@ -297,7 +363,22 @@ export const initialState: SettingsState = {
   maxKeepAliveMinapps: 3,
   showOpenedMinappsInSidebar: true,
   minappsOpenLinkExternal: false,
+  minappsWarmPoolSize: 0,
+  minappsSuspendMinutes: 0,
+  minappsRestoreLayout: true,
+  minappsShortcutOptOut: [],
+  minappsUserAgent: '',
+  minappsContentBlocking: false,
+  minappsContentBlockingOptOut: [],
+  minappsFilterLists: ['https://easylist.to/easylist/easylist.txt', 'https://easylist.to/easylist/easyprivacy.txt'],
+  wakeWordEnabled: false,
+  wakeWordSensitivity: 0.5,
+  wakeWordModelId: '',
+  transcriptionModel: undefined,
+  voiceInput: false,
+  feedbackSound: false,
   enableDataCollection: false,
+  linkPreviews: false,
   enableQuickPanelTriggers: false,
   enableBackspaceDeleteModel: true,
   exportMenuOptions: {
@@ -322,13 +403,41 @@ export const initialState: SettingsState = {
     backup: false,
     knowledgeEmbed: false
   },
-  defaultPaintingProvider: 'aihubmix'
+  defaultPaintingProvider: 'aihubmix',
+  llmCache: {
+    enabled: false,
+    ttl: 24 * 7,
+    maxEntries: 1000,
+    includeSampled: false
+  },
+  contentGuard: {
+    enabled: true,
+    action: 'confirm',
+    patterns: DEFAULT_CONTENT_GUARD_PATTERNS
+  },
+  archiveAfterDays: 0,
+  autoDatabaseMaintenance: false,
+  contentFilter: {
+    enabled: false,
+    inbound: true,
+    outbound: true,
+    action: 'warn',
+    rules: []
+  },
+  routing: {
+    enabled: false,
+    rules: []
+  }
 }
 
 const settingsSlice = createSlice({
   name: 'settings',
   initialState,
   reducers: {
+    // apply settings from an imported workspace
+    importSettings: (state, action: PayloadAction<Partial<SettingsState>>) => {
+      Object.assign(state, action.payload)
+    },
     setShowAssistants: (state, action: PayloadAction<boolean>) => {
       state.showAssistants = action.payload
     },
@@ -654,9 +763,54 @@ const settingsSlice = createSlice({
     setMinappsOpenLinkExternal: (state, action: PayloadAction<boolean>) => {
       state.minappsOpenLinkExternal = action.payload
     },
+    setMinappsWarmPoolSize: (state, action: PayloadAction<number>) => {
+      state.minappsWarmPoolSize = action.payload
+    },
+    setMinappsSuspendMinutes: (state, action: PayloadAction<number>) => {
+      state.minappsSuspendMinutes = action.payload
+    },
+    setMinappsRestoreLayout: (state, action: PayloadAction<boolean>) => {
+      state.minappsRestoreLayout = action.payload
+    },
+    setMinappsShortcutOptOut: (state, action: PayloadAction<string[]>) => {
+      state.minappsShortcutOptOut = action.payload
+    },
+    setMinappsUserAgent: (state, action: PayloadAction<string>) => {
+      state.minappsUserAgent = action.payload
+    },
+    setMinappsContentBlocking: (state, action: PayloadAction<boolean>) => {
+      state.minappsContentBlocking = action.payload
+    },
+    setMinappsContentBlockingOptOut: (state, action: PayloadAction<string[]>) => {
+      state.minappsContentBlockingOptOut = action.payload
+    },
+    setMinappsFilterLists: (state, action: PayloadAction<string[]>) => {
+      state.minappsFilterLists = action.payload
+    },
+    setWakeWordEnabled: (state, action: PayloadAction<boolean>) => {
+      state.wakeWordEnabled = action.payload
+    },
+    setWakeWordSensitivity: (state, action: PayloadAction<number>) => {
+      state.wakeWordSensitivity = action.payload
+    },
+    setWakeWordModelId: (state, action: PayloadAction<string>) => {
+      state.wakeWordModelId = action.payload
+    },
+    setTranscriptionModel: (state, action: PayloadAction<Model | undefined>) => {
+      state.transcriptionModel = action.payload
+    },
+    setVoiceInput: (state, action: PayloadAction<boolean>) => {
+      state.voiceInput = action.payload
+    },
+    setFeedbackSound: (state, action: PayloadAction<boolean>) => {
+      state.feedbackSound = action.payload
+    },
     setEnableDataCollection: (state, action: PayloadAction<boolean>) => {
       state.enableDataCollection = action.payload
     },
+    setLinkPreviews: (state, action: PayloadAction<boolean>) => {
+      state.linkPreviews = action.payload
+    },
     setExportMenuOptions: (state, action: PayloadAction<typeof initialState.exportMenuOptions>) => {
       state.exportMenuOptions = action.payload
     },
@@ -677,11 +831,30 @@ const settingsSlice = createSlice({
     },
     setDefaultPaintingProvider: (state, action: PayloadAction<PaintingProvider>) => {
       state.defaultPaintingProvider = action.payload
+    },
+    setLLMCacheSettings: (state, action: PayloadAction<Partial<SettingsState['llmCache']>>) => {
+      state.llmCache = { ...state.llmCache, ...action.payload }
+    },
+    setContentGuardSettings: (state, action: PayloadAction<Partial<SettingsState['contentGuard']>>) => {
+      state.contentGuard = { ...state.contentGuard, ...action.payload }
+    },
+    setContentFilterSettings: (state, action: PayloadAction<Partial<SettingsState['contentFilter']>>) => {
+      state.contentFilter = { ...state.contentFilter, ...action.payload }
+    },
+    setRoutingSettings: (state, action: PayloadAction<Partial<SettingsState['routing']>>) => {
+      state.routing = { ...state.routing, ...action.payload }
+    },
+    setArchiveAfterDays: (state, action: PayloadAction<number>) => {
+      state.archiveAfterDays = action.payload
+    },
+    setAutoDatabaseMaintenance: (state, action: PayloadAction<boolean>) => {
+      state.autoDatabaseMaintenance = action.payload
     }
   }
 })
 
 export const {
+  importSettings,
   setShowModelNameInMarkdown,
   setShowModelProviderInMarkdown,
   setShowAssistants,
@@ -775,14 +948,35 @@ export const {
   setMaxKeepAliveMinapps,
   setShowOpenedMinappsInSidebar,
   setMinappsOpenLinkExternal,
+  setMinappsWarmPoolSize,
+  setMinappsSuspendMinutes,
+  setMinappsRestoreLayout,
+  setMinappsShortcutOptOut,
+  setMinappsUserAgent,
+  setMinappsContentBlocking,
+  setMinappsContentBlockingOptOut,
+  setMinappsFilterLists,
+  setWakeWordEnabled,
+  setWakeWordSensitivity,
+  setWakeWordModelId,
+  setTranscriptionModel,
+  setVoiceInput,
+  setFeedbackSound,
   setEnableDataCollection,
+  setLinkPreviews,
   setEnableQuickPanelTriggers,
   setExportMenuOptions,
   setEnableBackspaceDeleteModel,
   setOpenAISummaryText,
   setOpenAIServiceTier,
   setNotificationSettings,
-  setDefaultPaintingProvider
+  setDefaultPaintingProvider,
+  setLLMCacheSettings,
+  setContentGuardSettings,
+  setContentFilterSettings,
+  setRoutingSettings,
+  setArchiveAfterDays,
+  setAutoDatabaseMaintenance
 } = settingsSlice.actions
 
 export default settingsSlice.reducer
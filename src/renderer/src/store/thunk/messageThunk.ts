@@ -4,6 +4,9 @@ import { fetchChatCompletion } from '@renderer/services/ApiService'
 import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
 import FileManager from '@renderer/services/FileManager'
 import { NotificationService } from '@renderer/services/NotificationService'
+import { routeAssistant } from '@renderer/services/RoutingManager'
+import { openBlocks, sealBlockChanges, sealBlocks } from '@renderer/services/SecureTopicService'
+import { recordStat } from '@renderer/services/StatsManager'
 import { createStreamProcessor, type StreamProcessorCallbacks } from '@renderer/services/StreamProcessingService'
 import { estimateMessagesUsage } from '@renderer/services/TokenService'
 import store from '@renderer/store'
@@ -52,7 +55,7 @@ import { newMessagesActions, selectMessagesForTopic } from '../newMessage'
 export const saveMessageAndBlocksToDB = async (message: Message, blocks: MessageBlock[], messageIndex: number = -1) => {
   try {
     if (blocks.length > 0) {
-      await db.message_blocks.bulkPut(blocks)
+      await db.message_blocks.bulkPut(await sealBlocks(message.topicId, blocks))
     }
     const topic = await db.topics.get(message.topicId)
     if (topic) {
@@ -82,10 +85,11 @@ const updateExistingMessageAndBlocksInDB = async (
   updatedBlocks: MessageBlock[]
 ) => {
   try {
+    const blocksToSave = await sealBlocks(updatedMessage.topicId, updatedBlocks)
     await db.transaction('rw', db.topics, db.message_blocks, async () => {
       // Always update blocks if provided
-      if (updatedBlocks.length > 0) {
-        await db.message_blocks.bulkPut(updatedBlocks)
+      if (blocksToSave.length > 0) {
+        await db.message_blocks.bulkPut(blocksToSave)
       }
 
       // Check if there are message properties to update beyond id and topicId
@@ -152,7 +156,7 @@ const getBlockThrottler = (id: string) => {
       })
 
       blockUpdateRafs.set(id, rafId)
-      await db.message_blocks.update(id, blockUpdate)
+      await db.message_blocks.update(id, await sealBlockChanges(id, blockUpdate))
     }, 150)
 
     blockUpdateThrottlers.set(id, throttler)
@@ -742,6 +746,14 @@ const fetchAndProcessAssistantResponseImpl = async (
 
         saveUpdatesToDB(assistantMsgId, topicId, messageErrorUpdate, [])
 
+        if (!isErrorTypeAbort) {
+          window.api.feedback.playSound('error')
+          window.api.accessibility.announce(
+            t('notification.announce.failed', { name: assistant.name, error: serializableError.message }),
+            'assertive'
+          )
+        }
+
         EventEmitter.emit(EVENT_NAMES.MESSAGE_COMPLETE, {
           id: assistantMsgId,
           topicId,
@@ -786,6 +798,10 @@ const fetchAndProcessAssistantResponseImpl = async (
             })
           }
 
+          // 提示音是否播放由主进程的设置决定，读屏软件播报回复完成
+          window.api.feedback.playSound('message-received')
+          window.api.accessibility.announce(t('notification.announce.completed', { name: assistant.name }))
+
           // 更新topic的name
           autoRenameTopic(assistant, topicId)
 
@@ -832,7 +848,9 @@ const fetchAndProcessAssistantResponseImpl = async (
     await fetchChatCompletion({
       messages: messagesForContext,
       assistant: assistant,
-      onChunkReceived: streamProcessorCallbacks
+      onChunkReceived: streamProcessorCallbacks,
+      toolCallContext: { topicId, messageId: assistantMsgId },
+      interactive: true
     })
   } catch (error: any) {
     console.error('Error fetching chat completion:', error)
@@ -863,6 +881,7 @@ export const sendMessage =
       if (userMessageBlocks.length > 0) {
         dispatch(upsertManyBlocks(userMessageBlocks))
       }
+      recordStat('messages', assistant.id)
 
       const mentionedModels = userMessage.mentions
       const queue = getTopicQueue(topicId)
@@ -870,15 +889,17 @@ export const sendMessage =
       if (mentionedModels && mentionedModels.length > 0) {
         await dispatchMultiModelResponses(dispatch, getState, topicId, userMessage, assistant, mentionedModels)
       } else {
+        // 路由规则可能为这条消息换用其他模型
+        const routedAssistant = await routeAssistant(assistant, userMessage)
         const assistantMessage = createAssistantMessage(assistant.id, topicId, {
           askId: userMessage.id,
-          model: assistant.model
+          model: routedAssistant.model
         })
         await saveMessageAndBlocksToDB(assistantMessage, [])
         dispatch(newMessagesActions.addMessage({ topicId, message: assistantMessage }))
 
         queue.add(async () => {
-          await fetchAndProcessAssistantResponseImpl(dispatch, getState, topicId, assistant, assistantMessage)
+          await fetchAndProcessAssistantResponseImpl(dispatch, getState, topicId, routedAssistant, assistantMessage)
         })
       }
     } catch (error) {
@@ -914,7 +935,8 @@ export const loadTopicMessagesThunk =
 
       if (messagesFromDB.length > 0) {
         const messageIds = messagesFromDB.map((m) => m.id)
-        const blocks = await db.message_blocks.where('messageId').anyOf(messageIds).toArray()
+        const storedBlocks = await db.message_blocks.where('messageId').anyOf(messageIds).toArray()
+        const blocks = await openBlocks(topicId, storedBlocks)
 
         if (blocks && blocks.length > 0) {
           dispatch(upsertManyBlocks(blocks))
@@ -1284,9 +1306,10 @@ export const initiateTranslationThunk =
       // 3. Update Database
       // Get the final message list from Redux state *after* updates
       const finalMessagesToSave = selectMessagesForTopic(getState(), topicId)
+      const [blockToSave] = await sealBlocks(topicId, [newBlock])
 
       await db.transaction('rw', db.topics, db.message_blocks, async () => {
-        await db.message_blocks.put(newBlock) // Save the initial block
+        await db.message_blocks.put(blockToSave) // Save the initial block
         await db.topics.update(topicId, { messages: finalMessagesToSave }) // Save updated message list
       })
       return newBlock.id // Return the ID
@@ -1313,7 +1336,7 @@ export const updateTranslationBlockThunk =
       dispatch(updateOneBlock({ id: blockId, changes }))
 
       // 更新数据库
-      await db.message_blocks.update(blockId, changes)
+      await db.message_blocks.update(blockId, await sealBlockChanges(blockId, changes))
       // Logger.log(`[updateTranslationBlockThunk] Successfully updated translation block ${blockId}.`)
     } catch (error) {
       console.error(`[updateTranslationBlockThunk] Failed to update translation block ${blockId}:`, error)
@@ -1514,14 +1537,15 @@ export const cloneMessagesToNewTopicThunk =
       }
 
       // 4. Update Database (Atomic Transaction)
+      const blocksToSave = await sealBlocks(newTopic.id, clonedBlocks)
       await db.transaction('rw', db.topics, db.message_blocks, db.files, async () => {
         // Update the NEW topic with the cloned messages
         // Assumes topic entry was added by caller, so we UPDATE.
         await db.topics.put({ id: newTopic.id, messages: clonedMessages })
 
         // Add the NEW blocks
-        if (clonedBlocks.length > 0) {
-          await db.message_blocks.bulkAdd(clonedBlocks)
+        if (blocksToSave.length > 0) {
+          await db.message_blocks.bulkAdd(blocksToSave)
         }
         // Update file counts
         const uniqueFiles = [...new Map(filesToUpdateCount.map((f) => [f.id, f])).values()]
@@ -1588,6 +1612,7 @@ export const updateMessageAndBlocksThunk =
       }
 
       // 2. 更新数据库 (在事务中)
+      const blocksToSave = await sealBlocks(topicId, blockUpdatesList)
       await db.transaction('rw', db.topics, db.message_blocks, async () => {
         // Only update topic.messages if there were actual message changes
         if (messageUpdates && Object.keys(messageUpdates).length > 0) {
@@ -1611,8 +1636,8 @@ export const updateMessageAndBlocksThunk =
           }
         }
 
-        if (blockUpdatesList.length > 0) {
-          await db.message_blocks.bulkPut(blockUpdatesList)
+        if (blocksToSave.length > 0) {
+          await db.message_blocks.bulkPut(blocksToSave)
         }
       })
     } catch (error) {
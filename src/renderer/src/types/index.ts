@@ -63,10 +63,41 @@ export type AssistantSettings = {
   reasoning_effort?: ReasoningEffortOptions
   qwenThinkMode?: boolean
   toolUseMode?: 'function' | 'prompt'
+  // overrides the global content filter action, undefined follows the global setting
+  contentFilter?: 'off' | 'warn' | 'redact' | 'block'
+  // false skips the model routing rules for this assistant
+  routing?: boolean
 }
 
 export type Agent = Omit<Assistant, 'model'> & {
   group?: string[]
+  /** version published by the marketplace, falls back to a content hash when missing */
+  version?: string
+  /** where an installed agent came from, used to check for updates */
+  source?: AgentSource
+}
+
+export type AgentSource = {
+  url: string
+  /** agent id or name in the upstream list */
+  id: string
+  version: string
+  /** pinned agents stay at the installed version */
+  pinned?: boolean
+}
+
+export type AgentPermissionChange = {
+  field: 'mcpServers' | 'knowledge_bases' | 'enableWebSearch' | 'webSearchProviderId' | 'enableGenerateImage'
+  before: string
+  after: string
+}
+
+export type AgentUpdate = {
+  agentId: string
+  version: string
+  upstream: Agent
+  changes: AgentPermissionChange[]
+  promptChanged: boolean
 }
 
 export type LegacyMessage = {
@@ -137,6 +168,37 @@ export type Topic = {
   pinned?: boolean
   prompt?: string
   isNameManuallyEdited?: boolean
+  // message text is stored encrypted with a key of this conversation, export needs an unlock
+  secure?: boolean
+  // imported from a .metheuschat transcript, messages can be read but not sent
+  readonly?: boolean
+}
+
+export interface ArchivedTopic {
+  topicId: string
+  assistantId: string
+  name: string
+  // archive file the conversation lives in, e.g. 2025-03
+  month: string
+  messageCount: number
+  createdAt: string
+  updatedAt: string
+  archivedAt: number
+}
+
+export type AuditCategory = 'file_write' | 'process_spawn' | 'credential_read' | 'setting_change'
+
+export interface AuditLogEntry {
+  time: number
+  category: AuditCategory
+  channel: string
+  caller: {
+    type: string
+    id: number
+    url: string
+  }
+  args: string
+  allowed: boolean
 }
 
 export type User = {
@@ -158,11 +220,19 @@ export type Provider = {
   isSystem?: boolean
   isAuthed?: boolean
   rateLimit?: number
+  limits?: ProviderLimits
   isNotSupportArrayContent?: boolean
   isVertex?: boolean
   notes?: string
 }
 
+// Request budgets enforced by the provider gateway, unset or 0 means unlimited
+export type ProviderLimits = {
+  rpm?: number
+  tpm?: number
+  maxConcurrency?: number
+}
+
 export type ProviderType =
   | 'openai'
   | 'openai-response'
@@ -171,6 +241,8 @@ export type ProviderType =
   | 'qwenlm'
   | 'azure-openai'
   | 'vertexai'
+  | 'aws-bedrock'
+  | 'local'
 
 export type ModelType = 'text' | 'vision' | 'embedding' | 'reasoning' | 'function_calling' | 'web_search'
 
@@ -189,6 +261,8 @@ export type Model = {
   description?: string
   type?: ModelType[]
   pricing?: ModelPricing
+  // Azure OpenAI deployment name, defaults to the model id
+  deployment?: string
 }
 
 export type Suggestion = {
@@ -312,16 +386,401 @@ export interface PaintingsState {
   tokenFluxPaintings: TokenFluxPainting[]
 }
 
+export type WebviewContextMenuAction = 'ask' | 'save_image' | 'copy_link' | 'open_external'
+
 export type MinAppType = {
   id: string
   name: string
   logo?: string
   url: string
+  // version of a packaged mini app, from its manifest
+  version?: string
   bodered?: boolean
   background?: string
   style?: CSSProperties
   addTime?: string
-  type?: 'Custom' | 'Default' // Added the 'type' property
+  // Manifest: loaded from a manifest.json in the miniapps folder of the data directory
+  type?: 'Custom' | 'Default' | 'Manifest'
+  // launched in incognito, the webview uses an in-memory partition wiped on close
+  ephemeral?: boolean
+  // host actions in the right-click menu, defaults to all of them
+  contextMenu?: WebviewContextMenuAction[]
+  // sent instead of the default user agent, e.g. for sites that serve a degraded layout to it
+  userAgent?: string
+  // absolute paths of scripts run before the page loads, next to the `window.metheus` bridge
+  preloads?: string[]
+  // what the mini app may use through the `window.metheus` bridge, from its manifest
+  permissions?: MiniAppPermissionManifest
+}
+
+// a mini app package listed by the configured registry
+export interface MiniAppRegistryEntry {
+  id: string
+  name: string
+  description: string
+  version?: string
+  // zip archive with a manifest.json at its root
+  url: string
+  // base64 Ed25519 signature of the archive, fetched from `<url>.sig` when missing
+  signature?: string
+  homepage?: string
+}
+
+export interface InstalledMiniAppPackage {
+  id: string
+  url: string
+  version?: string
+  // fingerprint of the trusted key that signed the package
+  fingerprint: string
+  // where the package publishes its latest version, from its manifest
+  updateUrl?: string
+  installedAt: number
+  updatedAt?: number
+}
+
+// a newer version of an installed mini app package, from the registry or the update url of the package
+export interface MiniAppUpdate {
+  id: string
+  name: string
+  from?: string
+  to: string
+}
+
+export interface MiniAppNavigationState {
+  appId: string
+  url: string
+  title: string
+  canGoBack: boolean
+  canGoForward: boolean
+  loading: boolean
+}
+
+// logical pixels are CSS pixels, physical pixels are logical pixels times the scale factor of the display
+export type CoordinateSpace = 'logical' | 'physical'
+
+export interface MiniAppCaptureOptions {
+  // absolute path the png is written to
+  path?: string
+  // return the png as base64, always done when there is no path
+  base64?: boolean
+  // scale wider captures down, e.g. for thumbnails
+  maxWidth?: number
+  // region of the page to capture, the whole visible page by default
+  rect?: { x: number; y: number; width: number; height: number }
+  // space of rect, maxWidth and the png size, physical by default
+  space?: CoordinateSpace
+}
+
+export interface MiniAppCapture {
+  path?: string
+  base64?: string
+  width: number
+  height: number
+  // scale factor of the display the mini app was captured on
+  scaleFactor: number
+}
+
+export type MiniAppCookieSameSite = 'unspecified' | 'no_restriction' | 'lax' | 'strict'
+
+export interface MiniAppCookie {
+  name: string
+  value: string
+  // a leading dot means the cookie is sent to subdomains too
+  domain: string
+  path: string
+  secure: boolean
+  httpOnly: boolean
+  sameSite: MiniAppCookieSameSite
+  // seconds since the epoch, session cookies have none
+  expirationDate?: number
+}
+
+export interface MiniAppCookieInput {
+  // the cookie is set as if the page at this url set it
+  url: string
+  name: string
+  value: string
+  domain?: string
+  path?: string
+  secure?: boolean
+  httpOnly?: boolean
+  sameSite?: MiniAppCookieSameSite
+  expirationDate?: number
+}
+
+// devices and the location are asked for by pages, the others by bridge commands of the mini app
+export type MiniAppPermission =
+  | 'camera'
+  | 'microphone'
+  | 'geolocation'
+  | 'filesystem'
+  | 'network'
+  | 'notifications'
+  | 'clipboard'
+  | 'shared-state'
+
+// remembered answers of a mini app, permissions without an answer are asked for again
+export type MiniAppPermissionDecisions = Partial<Record<MiniAppPermission, boolean>>
+
+// the folders or hosts of the manifest a remembered answer was given for, a wider manifest is asked for again
+export type MiniAppPermissionScopes = Partial<Record<MiniAppPermission, string[]>>
+
+// the `permissions` of a mini app manifest, bridge commands of permissions it does not declare are denied
+export interface MiniAppPermissionManifest {
+  // absolute folders the mini app may read and write
+  filesystem?: string[]
+  // hosts the mini app may fetch from, `*.example.com` matches its subdomains
+  network?: string[]
+  notifications?: boolean
+  clipboard?: boolean
+  // values shared between the mini apps holding this permission
+  sharedState?: boolean
+}
+
+// a mini app page or bridge command asked for a permission, answered with `miniApp.respondPermission`
+export interface MiniAppPermissionRequest {
+  requestId: string
+  webviewId: number
+  // undefined when the page asked before its webview was registered
+  appId?: string
+  origin: string
+  permissions: MiniAppPermission[]
+  // the file or url a bridge command wants to use
+  detail?: string
+}
+
+export interface MiniAppAudioState {
+  appId: string
+  muted: boolean
+  // the page is playing sound, also when muted
+  audible: boolean
+}
+
+export type MiniAppLifecycleEventType = 'created' | 'shown' | 'hidden' | 'load-finished' | 'destroyed'
+
+export interface MiniAppLifecycleEvent {
+  type: MiniAppLifecycleEventType
+  appId: string
+  timestamp: number
+  // load-finished only: the loaded url and the time from the start of loading in milliseconds
+  url?: string
+  loadDuration?: number
+}
+
+// a registered mini app webview, as the main process tracks it
+export interface MiniAppWebviewInfo {
+  appId: string
+  webviewId: number
+  url: string
+  title: string
+  visible: boolean
+  // position in the host window in css pixels, undefined when the host can't be asked
+  bounds?: { x: number; y: number; width: number; height: number }
+}
+
+// open mini apps saved on quit, the webviews fill the mini app popup so there are no bounds to keep
+export interface MiniAppLayout {
+  // in the order they were opened
+  apps: { appId: string; url: string }[]
+  // the app shown last, undefined when none was shown
+  activeAppId?: string
+  // whether the active app was on screen when the app quit
+  visible: boolean
+  savedAt: number
+}
+
+// ad and tracker blocking of mini app webviews, sent by the renderer whenever its settings change
+export interface ContentBlockingConfig {
+  enabled: boolean
+  // urls of EasyList style filter lists
+  lists: string[]
+  // hosts of mini apps the user turned blocking off for, their pages load everything
+  allowedHosts: string[]
+}
+
+export interface FilterListStatus {
+  url: string
+  // network rules the engine understood, element hiding rules are skipped
+  rules: number
+  updatedAt?: number
+  error?: string
+}
+
+export interface ContentBlockingStatus {
+  enabled: boolean
+  lists: FilterListStatus[]
+  // requests blocked since the app started
+  blocked: number
+}
+
+// resource usage of the renderer process a mini app's webview runs in
+export interface MiniAppStats {
+  appId: string
+  webviewId: number
+  pid: number
+  // working set and private memory in KB
+  memory: number
+  privateMemory?: number
+  // percent of one core since the previous sample
+  cpu: number
+  time: number
+}
+
+// activity the renderer reports for the tray tooltip
+export interface TrayStatus {
+  // chat requests being answered
+  generating: number
+  // batch jobs running
+  jobs: number
+  syncing: boolean
+  lastBackupTime: number | null
+}
+
+// windows a snap layout can place: the main window and the quick assistant
+export type SnapWindow = 'main' | 'assistant'
+
+// fractions of the display's work area, 0..1
+export interface SnapRect {
+  x: number
+  y: number
+  width: number
+  height: number
+}
+
+export interface SnapLayout {
+  name: string
+  builtin: boolean
+  // windows left out keep their place, display 0 is the primary display, then the others from left to right
+  windows: Partial<Record<SnapWindow, { display: number; rect: SnapRect }>>
+}
+
+export type PdfPageSize = 'A3' | 'A4' | 'A5' | 'Legal' | 'Letter' | 'Tabloid'
+
+export interface PdfPrintOptions {
+  // A4 by default
+  pageSize?: PdfPageSize
+  landscape?: boolean
+  // in inches, 0.4 on every side by default
+  margins?: { top?: number; bottom?: number; left?: number; right?: number }
+  printBackground?: boolean
+  // file name suggested in the save dialog when no output path is given
+  defaultName?: string
+}
+
+export interface PdfExport {
+  path: string
+  size: number
+}
+
+export interface PerfProcessMetric {
+  pid: number
+  // Browser, Tab, GPU, Utility...
+  type: string
+  name?: string
+  // working set and private memory in KB, private is not reported on Linux
+  memory: number
+  privateMemory?: number
+  cpu: number
+}
+
+export interface PerfWebviewMetric {
+  id: number
+  type: string
+  url: string
+  title: string
+  pid: number
+  // KB, missing when the renderer process is shared or not running
+  memory?: number
+}
+
+export interface PerfLockMetric {
+  name: string
+  acquisitions: number
+  // acquisitions that had to wait for another holder
+  contended: number
+  waitMs: number
+  maxWaitMs: number
+}
+
+export interface PerfSnapshot {
+  time: number
+  // main process memory in bytes
+  main: { rss: number; heapUsed: number; heapTotal: number; external: number }
+  // main process event loop delay since the previous snapshot, in ms
+  eventLoopLag: { mean: number; p50: number; p99: number; max: number }
+  processes: PerfProcessMetric[]
+  webviews: PerfWebviewMetric[]
+  locks: PerfLockMetric[]
+  tracing: boolean
+}
+
+// critical work runs before the main window is shown, deferred work after its first paint
+export type StartupStage = 'critical' | 'deferred'
+
+export interface StartupSpan {
+  name: string
+  stage: StartupStage
+  // ms since the process started
+  start: number
+  duration: number
+  error?: string
+}
+
+export interface StartupReport {
+  // ms since the process started, e.g. app-ready, window-created, first-paint, deferred-done
+  milestones: Record<string, number>
+  spans: StartupSpan[]
+  // deferred work that has not run yet
+  pending: string[]
+}
+
+// machine-level policy deployed by an administrator, locked settings can't be changed in the app
+export interface MachinePolicy {
+  settings: Record<string, unknown>
+  // forced proxy, overrides the proxy settings
+  proxy?: string
+  // ids of the only providers that may be used
+  allowedProviders?: string[]
+  lockedKeys: string[]
+}
+
+export interface WorkerPoolStatus {
+  // worker threads allowed right now, reduced to one on battery
+  size: number
+  // 0 picks a size from the number of CPUs
+  configuredSize: number
+  defaultSize: number
+  maxSize: number
+  onBattery: boolean
+  busy: number
+  idle: number
+  queued: number
+}
+
+// a GGUF or ONNX model file in the inventory of the models folder, or imported from elsewhere on disk
+export interface LocalModelFile {
+  id: string
+  name: string
+  format: 'gguf' | 'onnx'
+  path: string
+  size: number
+  sha256?: string
+  sourceUrl?: string
+  // id of the download task while the file is being pulled
+  downloadId?: string
+  status: 'downloading' | 'ready' | 'corrupted' | 'missing'
+  addedAt: number
+  lastUsedAt?: number
+}
+
+// llama.cpp runtime used for chat with local models
+export interface LocalBackendInfo {
+  available: boolean
+  // GPU backend, e.g. metal, cuda or vulkan, false when it runs on the CPU
+  gpu: string | false
+  vram?: { total: number; used: number; free: number }
+  loadedModelId?: string
+  error?: string
 }
 
 export interface FileType {
@@ -480,6 +939,70 @@ export interface TranslateHistory {
   createdAt: string
 }
 
+export interface LLMCacheEntry {
+  // sha256 of the model, normalized messages and generation params
+  key: string
+  model: string
+  text: string
+  createdAt: number
+  expiresAt: number
+}
+
+export interface ContentFilterLog {
+  id: string
+  direction: 'inbound' | 'outbound'
+  action: 'warn' | 'redact' | 'block'
+  assistantId: string
+  topicId?: string
+  model?: string
+  // rule names and match counts, the matched content itself is not stored
+  hits: { rule: string; count: number }[]
+  createdAt: number
+}
+
+export type StatsMetric = 'messages' | 'prompt_tokens' | 'completion_tokens' | 'agent_runs' | 'minapp_seconds'
+
+// daily usage counter, key is an assistant, model or mini app id depending on the metric
+export interface StatsCounter {
+  id: string
+  date: string
+  metric: StatsMetric
+  key: string
+  value: number
+}
+
+export type TranslateProvider = 'llm' | 'deepl' | 'google'
+
+export interface TranslateCacheEntry {
+  // sha256 of the provider, target language and source sentence
+  key: string
+  provider: TranslateProvider
+  targetLanguage: string
+  text: string
+  createdAt: number
+}
+
+export type JobStatus = 'pending' | 'running' | 'paused' | 'completed' | 'failed'
+
+/**
+ * Long running batch job, results are checkpointed per chunk so the job can resume after a restart
+ */
+export interface Job<TInput = any, TOutput = any> {
+  id: string
+  type: 'embed' | 'summarize' | 'translate'
+  status: JobStatus
+  model: Model
+  // runner specific options, e.g. the target language of a translate job
+  params?: Record<string, any>
+  inputs: TInput[]
+  // indexed like inputs, null until the item is processed
+  outputs: (TOutput | null)[]
+  completed: number
+  error?: string
+  createdAt: number
+  updatedAt: number
+}
+
 export type SidebarIcon = 'assistants' | 'agents' | 'paintings' | 'translate' | 'minapp' | 'knowledge' | 'files'
 
 export type ExternalToolResult = {
@@ -546,6 +1069,21 @@ export type KnowledgeReference = {
   sourceUrl: string
   type: KnowledgeItemType
   file?: FileType
+  citation?: KnowledgeCitation
+}
+
+// 引用内容在源文件中的位置，由主进程根据索引时记录的偏移解析
+export type KnowledgeCitation = {
+  chunkId: string
+  source: string
+  type: 'file' | 'pdf' | 'url' | 'unknown'
+  page?: number
+  startLine?: number
+  endLine?: number
+  byteStart?: number
+  byteEnd?: number
+  preview: string
+  resolvedAt: number
 }
 
 export type MCPArgType = 'string' | 'list' | 'number'
@@ -587,6 +1125,68 @@ export interface MCPServer {
   timeout?: number // Timeout in seconds for requests to this server, default is 60 seconds
 }
 
+export type MCPRuntime = 'node' | 'python'
+
+// what a server of the marketplace may do, shown before it is installed or updated
+export type MCPPermission = 'file_read' | 'file_write' | 'network' | 'shell' | 'env' | 'browser'
+
+// a server of the curated MCP registry
+export interface MCPRegistryEntry {
+  id: string
+  name: string
+  description: string
+  // npm package for node servers, PyPI package for python servers
+  package: string
+  version: string
+  runtime: MCPRuntime
+  // executable of the package, defaults to the package name without its scope
+  bin?: string
+  args?: string[]
+  // environment variables the server reads, by name with their description
+  env?: Record<string, string>
+  permissions: MCPPermission[]
+  homepage?: string
+  tags?: string[]
+}
+
+// tools the marketplace installs with, bun for node servers and uv for python servers
+export interface MCPDependencies {
+  bun: boolean
+  uv: boolean
+  node: boolean
+  python: boolean
+}
+
+// a marketplace server installed into its own folder of the tools directory
+export interface InstalledMCPServer {
+  id: string
+  // id of the MCP server added for it
+  serverId: string
+  version: string
+  permissions: MCPPermission[]
+  dir: string
+  command: string
+  args: string[]
+  installedAt: number
+  updatedAt?: number
+}
+
+export interface MCPServerHealth {
+  id: string
+  // missing when files of the install were removed, outdated when the registry has a newer version
+  status: 'ok' | 'missing' | 'outdated'
+  checkedAt: number
+}
+
+export interface MCPServerUpdate {
+  id: string
+  name: string
+  from: string
+  to: string
+  addedPermissions: MCPPermission[]
+  removedPermissions: MCPPermission[]
+}
+
 export interface MCPToolInputSchema {
   type: string
   title: string
@@ -675,6 +1275,42 @@ export interface MCPCallToolResponse {
   isError?: boolean
 }
 
+// message a tool call was made for, links the recorded call to the conversation
+export interface ToolCallContext {
+  topicId: string
+  messageId: string
+}
+
+// a recorded call of an MCP tool
+export interface ToolCallRecord {
+  id: string
+  topicId?: string
+  messageId?: string
+  serverId: string
+  serverName: string
+  toolName: string
+  args: unknown
+  // binary content of the result is not kept
+  result?: MCPCallToolResponse
+  error?: string
+  startedAt: number
+  duration: number
+  // id of the original call when this one is a replay
+  replayOf?: string
+}
+
+export interface ToolCallQuery {
+  topicId?: string
+  messageId?: string
+  serverId?: string
+  toolName?: string
+  status?: 'success' | 'error'
+  since?: number
+  until?: number
+  limit?: number
+  offset?: number
+}
+
 export interface MCPResource {
   serverId: string
   serverName: string
@@ -700,6 +1336,14 @@ export interface QuickPhrase {
   order?: number
 }
 
+/**
+ * How an imported workspace is combined with the local one
+ * - replace: imported lists replace the local ones
+ * - merge: items are matched by id, imported items win
+ * - keep_existing: only items missing locally are added
+ */
+export type WorkspaceMergeStrategy = 'replace' | 'merge' | 'keep_existing'
+
 export interface Citation {
   number: number
   url: string
@@ -719,6 +1363,389 @@ export interface StoreSyncAction {
   }
 }
 
+// Request forwarded from the local API server to the renderer
+export interface ApiServerRequest {
+  requestId: string
+  method: string
+  params: any
+}
+
+export interface ApiServerStatus {
+  enabled: boolean
+  running: boolean
+  port: number
+  url: string
+  token: string
+  error?: string
+}
+
+export type CommandArgType = 'string' | 'number' | 'boolean'
+
+export interface CommandArgSchema {
+  name: string
+  type: CommandArgType
+  description?: string
+  required?: boolean
+  // allowed values of a string argument
+  enum?: string[]
+}
+
+/**
+ * Action of the command registry, listed by the command palette and the CLI and run by id
+ */
+export interface CommandInfo {
+  id: string
+  title: string
+  description?: string
+  category: string
+  args: CommandArgSchema[]
+  // renderer commands are forwarded to the main window when invoked
+  source: 'main' | 'renderer'
+}
+
+/**
+ * What an inbound webhook does with its payload
+ * - agent: run the assistant with the prompt template, `{{payload}}` and `{{payload.<path>}}` are replaced
+ * - event: publish the payload on the push channel topic `webhook:<id>`
+ */
+export type WebhookAction = { type: 'agent'; assistantId: string; template?: string } | { type: 'event' }
+
+// Inbound webhook of the local API server, called at `/hooks/<id>`
+export interface Webhook {
+  id: string
+  name: string
+  // HMAC-SHA256 key for the `X-Cherry-Signature` header
+  secret: string
+  action: WebhookAction
+  // deliveries per minute, 0 for no limit
+  rateLimit: number
+  enabled: boolean
+  createdAt: number
+}
+
+export interface WebhookLogEntry {
+  time: number
+  hookId: string
+  deliveryId?: string
+  result: 'accepted' | 'rejected' | 'rate_limited' | 'completed' | 'failed'
+  status: number
+  error?: string
+}
+
+export type OutboundEvent = 'agent.finished' | 'job.failed' | 'backup.completed'
+
+export type OutboundIntegrationType = 'slack' | 'discord' | 'telegram' | 'http'
+
+/**
+ * Posts a message to a chat or HTTP endpoint when one of its events fires.
+ * The secret (webhook URL, or the bot token for Telegram) is kept in the vault, not in the integration.
+ */
+export interface OutboundIntegration {
+  id: string
+  name: string
+  type: OutboundIntegrationType
+  events: OutboundEvent[]
+  // message template rendered with the event data, each event has a default
+  template?: string
+  // Telegram chat to post to
+  chatId?: string
+  enabled: boolean
+  // whether the secret is stored in the vault
+  hasSecret: boolean
+}
+
+/**
+ * Template rendered with a JSON context, see `src/main/utils/template.ts` for the syntax.
+ * Other templates include it by name with `{{> name}}`.
+ */
+export interface UserTemplate {
+  id: string
+  // unique, used to include the template
+  name: string
+  description?: string
+  content: string
+  createdAt: number
+  updatedAt: number
+}
+
+export interface OutboundDeliveryLog {
+  time: number
+  integrationId: string
+  event: OutboundEvent | 'test'
+  ok: boolean
+  status?: number
+  error?: string
+}
+
+export interface SharedConversationMessage {
+  role: 'user' | 'assistant' | 'system'
+  // assistant or user name shown above the message
+  name: string
+  // markdown
+  content: string
+  createdAt: string
+}
+
+export interface ShareConversationOptions {
+  // replace assistant and user names with their role
+  redactNames?: boolean
+  // serve the file on the API server behind an expiring link
+  serve?: boolean
+  // link lifetime in minutes
+  ttl?: number
+}
+
+export interface SharedConversation {
+  id: string
+  topicId: string
+  filePath: string
+  url?: string
+  expiresAt?: number
+  createdAt: number
+}
+
+export type ScheduledMessageStatus = 'pending' | 'running' | 'sent' | 'failed' | 'cancelled'
+
+/**
+ * User message sent to a conversation at a later time, the assistant of the topic answers it
+ */
+export interface ScheduledMessage {
+  id: string
+  topicId: string
+  assistantId: string
+  content: string
+  sendAt: number
+  status: ScheduledMessageStatus
+  createdAt: number
+  // assistant message of the answer
+  messageId?: string
+  sentAt?: number
+  error?: string
+}
+
+/**
+ * Wake word enrolled by the user, detected locally by comparing speech with the recorded samples
+ */
+export interface WakeWordModel {
+  id: string
+  name: string
+  // MFCC features of each recorded sample
+  templates: number[][][]
+  // largest distance between the samples, base of the detection threshold
+  spread: number
+  createdAt: number
+}
+
+/**
+ * Folder mounted to a conversation, tools of the conversation can only access files inside it
+ */
+export interface ConversationWorkspace {
+  topicId: string
+  path: string
+  mountedAt: number
+}
+
+export type WorkspaceFileChangeStatus = 'added' | 'modified' | 'deleted'
+
+export interface WorkspaceFileChange {
+  // relative to the workspace folder
+  path: string
+  status: WorkspaceFileChangeStatus
+  // unified diff, empty for binary or large files
+  diff: string
+}
+
+export type TextDiffMode = 'unified' | 'lines' | 'words'
+
+export interface TextDiffChange {
+  value: string
+  added?: boolean
+  removed?: boolean
+}
+
+export interface TextDiff {
+  mode: TextDiffMode
+  additions: number
+  deletions: number
+  // unified diff in the unified mode, the changed parts otherwise
+  unified?: string
+  changes?: TextDiffChange[]
+}
+
+export interface PatchResult {
+  // relative to the workspace folder
+  path: string
+  status: WorkspaceFileChangeStatus
+  additions: number
+  deletions: number
+  // false for a dry run, the file is left untouched
+  applied: boolean
+}
+
+// what a tool call does, derived from the tool name and its arguments
+export type AgentActionKind = 'file_write' | 'network'
+
+export interface AgentAction {
+  kinds: AgentActionKind[]
+  server: string
+  tool: string
+  // absolute paths written by the call
+  paths: string[]
+  // urls requested by the call
+  urls: string[]
+}
+
+// always: every action of the kind, outside_workspace: file writes outside the mounted folders,
+// new_domain: network calls to domains that were never approved
+export type ConfirmationCondition = 'always' | 'outside_workspace' | 'new_domain'
+
+export interface ConfirmationRule {
+  id: string
+  name: string
+  enabled: boolean
+  kind: AgentActionKind
+  condition: ConfirmationCondition
+  // limit the rule to these MCP servers, all servers when empty
+  servers?: string[]
+}
+
+// a tool call waiting for the user, answered with `actionPolicy.approve` or `actionPolicy.deny`
+export interface PendingApproval {
+  id: string
+  ruleId: string
+  ruleName: string
+  action: AgentAction
+  requestedAt: number
+}
+
+// metadata of a web page for link cards, images are downloaded to the cache
+export interface LinkPreview {
+  // final url after redirects
+  url: string
+  title?: string
+  description?: string
+  siteName?: string
+  // local paths of the OpenGraph image and the favicon
+  image?: string
+  favicon?: string
+}
+
+// what the clipboard holds, decided by the main process so pasting can offer matching actions
+export type ClipboardKind = 'empty' | 'text' | 'code' | 'url' | 'image' | 'table'
+
+export interface ClipboardDescriptor {
+  kind: ClipboardKind
+  // plain text of the clipboard with line endings normalized, absent for images
+  text?: string
+  // guessed language of code, e.g. python
+  language?: string
+  // cells of a table, the first row is the header
+  rows?: string[][]
+  // size of an image in pixels
+  width?: number
+  height?: number
+}
+
+export type TabularFormat = 'csv' | 'tsv' | 'xlsx'
+
+export type TabularColumnType = 'empty' | 'boolean' | 'integer' | 'number' | 'date' | 'string'
+
+export type TabularValue = string | number | boolean | null
+
+export interface TabularColumn {
+  name: string
+  // the narrowest type matching every non empty cell
+  type: TabularColumnType
+  // non empty and empty cells
+  count: number
+  missing: number
+  // counted up to 10000 values
+  distinct: number
+  // numbers for numeric columns, the original text for dates
+  min?: number | string
+  max?: number | string
+  mean?: number
+  // most frequent values of text columns
+  top?: { value: string; count: number }[]
+}
+
+export interface TabularParseOptions {
+  // worksheet name of xlsx files, the first one by default
+  sheet?: string
+  offset?: number
+  limit?: number
+  // whether the first row holds the column names, true by default
+  header?: boolean
+  // detected from the content by default
+  delimiter?: string
+}
+
+export interface TabularPreview {
+  path: string
+  format: TabularFormat
+  // worksheet names, empty for csv and tsv
+  sheets: string[]
+  sheet?: string
+  columns: TabularColumn[]
+  // rows from offset, values typed by their column
+  rows: TabularValue[][]
+  offset: number
+  totalRows: number
+}
+
+export interface TextLines {
+  path: string
+  // 0 based line number of the first returned line
+  startLine: number
+  lines: string[]
+  totalLines: number
+  size: number
+  // some lines were longer than 16 KB and were cut
+  truncated: boolean
+}
+
+// Unsaved renderer state journaled by the main process, restored after a crash
+export interface SessionDraftState {
+  // unsent text keyed by the input it was typed in, e.g. `inputbar` or `translate`
+  drafts: Record<string, string>
+  // hash route of the current page
+  route?: string
+  // ids of the mini apps kept open
+  minapps: string[]
+}
+
+export interface RecoveredSession extends SessionDraftState {
+  savedAt: number
+}
+
+// Result of checking the installed app resources against the manifest written at packaging
+export interface InstallationIntegrityReport {
+  appVersion: string
+  // version the manifest was generated for, differs after an interrupted update
+  manifestVersion: string
+  checkedFiles: number
+  missing: string[]
+  modified: string[]
+  ok: boolean
+  checkedAt: number
+}
+
+// updating: the latest release is being downloaded, manual: it has to be reinstalled from the download page
+export type InstallationRepairResult = 'updating' | 'manual'
+
+// Content sent to the app from the OS share integrations
+export interface SharePayload {
+  text?: string
+  url?: string
+  files: string[]
+}
+
+// A .metheuschat file opened from the OS, the content is parsed and validated by the renderer
+export interface TranscriptFile {
+  path: string
+  content: string
+}
+
 export type OpenAISummaryText = 'auto' | 'concise' | 'detailed' | 'off'
 export type OpenAIServiceTier = 'auto' | 'default' | 'flex'
 export type { Message } from './newMessage'
@@ -14,6 +14,8 @@ export interface ActionItem {
 
 export interface SelectionState {
   selectionEnabled: boolean
+  // user agreed to let the assistant monitor text selection in other apps
+  isPermissionGranted: boolean
   triggerMode: TriggerMode
   isCompact: boolean
   isAutoClose: boolean
@@ -23,5 +25,7 @@ export interface SelectionState {
   filterMode: FilterMode
   filterList: string[]
   actionWindowOpacity: number
+  isAutoCopyResult: boolean
+  isNotifyResult: boolean
   actionItems: ActionItem[]
 }
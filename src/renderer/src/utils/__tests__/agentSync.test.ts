@@ -0,0 +1,94 @@
+import type { Agent, MCPServer } from '@renderer/types'
+import { describe, expect, it } from 'vitest'
+
+import {
+  applyAgentUpdate,
+  diffAgentPermissions,
+  findUpstreamAgent,
+  getAgentUpdate,
+  getAgentVersion
+} from '../agentSync'
+
+function createAgent(overrides: Partial<Agent> = {}): Agent {
+  return {
+    id: 'local-id',
+    name: 'Translator',
+    prompt: 'Translate the text',
+    topics: [],
+    type: 'agent',
+    ...overrides
+  }
+}
+
+const server = (name: string) => ({ id: name, name, isActive: true }) as MCPServer
+
+describe('agentSync', () => {
+  describe('getAgentVersion', () => {
+    it('should prefer the published version', () => {
+      expect(getAgentVersion(createAgent({ version: '1.2.0' }))).toBe('1.2.0')
+    })
+
+    it('should hash the content when there is no version', () => {
+      const version = getAgentVersion(createAgent())
+      expect(version).toMatch(/^[0-9a-f]{8}$/)
+      expect(getAgentVersion(createAgent({ id: 'other' }))).toBe(version)
+      expect(getAgentVersion(createAgent({ prompt: 'Summarize the text' }))).not.toBe(version)
+    })
+  })
+
+  describe('findUpstreamAgent', () => {
+    it('should match by id and fall back to the name', () => {
+      const agent = createAgent({ source: { url: 'https://a', id: 'translator', version: '1' } })
+      expect(findUpstreamAgent(agent, [createAgent({ id: 'translator' })])?.id).toBe('translator')
+      expect(findUpstreamAgent(agent, [createAgent({ id: 'x', name: 'translator' })])?.id).toBe('x')
+      expect(findUpstreamAgent(createAgent(), [createAgent()])).toBeUndefined()
+    })
+  })
+
+  describe('diffAgentPermissions', () => {
+    it('should list changed permissions only', () => {
+      const current = createAgent({ mcpServers: [server('fetch')], enableWebSearch: false })
+      const upstream = createAgent({ mcpServers: [server('fetch'), server('filesystem')], enableWebSearch: false })
+      expect(diffAgentPermissions(current, upstream)).toEqual([
+        { field: 'mcpServers', before: 'fetch', after: 'fetch, filesystem' }
+      ])
+    })
+
+    it('should detect enabled tools', () => {
+      const changes = diffAgentPermissions(createAgent(), createAgent({ enableGenerateImage: true }))
+      expect(changes).toEqual([{ field: 'enableGenerateImage', before: '', after: 'true' }])
+    })
+  })
+
+  describe('getAgentUpdate', () => {
+    const source = { url: 'https://a', id: 'translator', version: '1.0.0' }
+
+    it('should return an update when the version changed', () => {
+      const agent = createAgent({ source })
+      const update = getAgentUpdate(agent, createAgent({ version: '1.1.0', prompt: 'New prompt' }))
+      expect(update).toMatchObject({ agentId: 'local-id', version: '1.1.0', changes: [], promptChanged: true })
+    })
+
+    it('should skip pinned and up to date agents', () => {
+      const pinned = createAgent({ source: { ...source, pinned: true } })
+      expect(getAgentUpdate(pinned, createAgent({ version: '2' }))).toBe(null)
+      expect(getAgentUpdate(createAgent({ source }), createAgent({ version: '1.0.0' }))).toBe(null)
+      expect(getAgentUpdate(createAgent(), createAgent({ version: '2' }))).toBe(null)
+    })
+  })
+
+  describe('applyAgentUpdate', () => {
+    it('should keep local state and record the new version', () => {
+      const agent = createAgent({
+        source: { url: 'https://a', id: 'translator', version: '1.0.0' },
+        settings: { temperature: 0.2 }
+      })
+      const update = getAgentUpdate(agent, createAgent({ id: 'translator', version: '1.1.0', prompt: 'New' }))!
+      const updated = applyAgentUpdate(agent, update)
+      expect(updated.id).toBe('local-id')
+      expect(updated.prompt).toBe('New')
+      expect(updated.settings).toEqual({ temperature: 0.2 })
+      expect(updated.source).toEqual({ url: 'https://a', id: 'translator', version: '1.1.0' })
+    })
+  })
+})
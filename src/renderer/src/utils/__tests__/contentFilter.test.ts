@@ -0,0 +1,66 @@
+import { describe, expect, it } from 'vitest'
+
+import { compileContentFilterRules, ContentFilterRule, filterContent, isUnsafeClassification } from '../contentFilter'
+import { REDACTED } from '../contentGuard'
+
+const keywordRule: ContentFilterRule = {
+  id: '1',
+  name: 'profanity',
+  type: 'keyword',
+  pattern: 'darn, heck\nfoo bar',
+  enabled: true
+}
+
+const regexRule: ContentFilterRule = {
+  id: '2',
+  name: 'phone',
+  type: 'regex',
+  pattern: '\\b\\d{3}-\\d{4}\\b',
+  enabled: true
+}
+
+describe('contentFilter', () => {
+  describe('compileContentFilterRules', () => {
+    it('should skip disabled, empty and invalid rules', () => {
+      const rules: ContentFilterRule[] = [
+        { ...keywordRule, enabled: false },
+        { ...keywordRule, id: '3', pattern: ' , ' },
+        { ...regexRule, pattern: '(unclosed' },
+        regexRule
+      ]
+      expect(compileContentFilterRules(rules).map((rule) => rule.name)).toEqual(['phone'])
+    })
+  })
+
+  describe('filterContent', () => {
+    it('should report keyword hits case-insensitively without changing the text', () => {
+      const { value, hits } = filterContent('Oh HECK, darn it', [keywordRule], false)
+      expect(value).toBe('Oh HECK, darn it')
+      expect(hits).toEqual([{ rule: 'profanity', count: 2 }])
+    })
+
+    it('should redact matches in nested payloads', () => {
+      const payload = { messages: [{ role: 'user', content: 'call 555-1234 about foo bar' }] }
+      const { value, hits } = filterContent(payload, [keywordRule, regexRule], true)
+      expect(value.messages[0].content).toBe(`call ${REDACTED} about ${REDACTED}`)
+      expect(hits).toEqual([
+        { rule: 'profanity', count: 1 },
+        { rule: 'phone', count: 1 }
+      ])
+      expect(payload.messages[0].content).toBe('call 555-1234 about foo bar')
+    })
+
+    it('should leave data urls untouched', () => {
+      const { hits } = filterContent('data:text/plain;base64,heck', [keywordRule], true)
+      expect(hits).toEqual([])
+    })
+  })
+
+  describe('isUnsafeClassification', () => {
+    it('should parse safety classifier output', () => {
+      expect(isUnsafeClassification('unsafe\nS1')).toBe(true)
+      expect(isUnsafeClassification('  Unsafe')).toBe(true)
+      expect(isUnsafeClassification('safe')).toBe(false)
+    })
+  })
+})
@@ -0,0 +1,55 @@
+import { describe, expect, it } from 'vitest'
+
+import { DEFAULT_CONTENT_GUARD_PATTERNS, isLuhnValid, REDACTED, redactContent } from '../contentGuard'
+
+describe('contentGuard', () => {
+  describe('isLuhnValid', () => {
+    it('should validate credit card numbers', () => {
+      expect(isLuhnValid('4111 1111 1111 1111')).toBe(true)
+      expect(isLuhnValid('4111-1111-1111-1112')).toBe(false)
+      expect(isLuhnValid('12345')).toBe(false)
+    })
+  })
+
+  describe('redactContent', () => {
+    it('should redact stored secrets in nested payloads', () => {
+      const payload = {
+        model: 'gpt-4o',
+        messages: [{ role: 'user', content: [{ type: 'text', text: 'my key is sk-abcdef123456, keep it safe' }] }]
+      }
+      const { value, findings } = redactContent(payload, ['sk-abcdef123456'], [])
+      expect(value.messages[0].content[0].text).toBe(`my key is ${REDACTED}, keep it safe`)
+      expect(value.model).toBe('gpt-4o')
+      expect(findings).toEqual([{ name: 'secret', count: 1 }])
+    })
+
+    it('should not touch the original payload', () => {
+      const payload = { text: 'token: secret-token-value' }
+      redactContent(payload, ['secret-token-value'], [])
+      expect(payload.text).toBe('token: secret-token-value')
+    })
+
+    it('should ignore short secrets', () => {
+      const { value, findings } = redactContent('the key is abc', ['abc'], [])
+      expect(value).toBe('the key is abc')
+      expect(findings).toEqual([])
+    })
+
+    it('should redact valid credit card numbers only', () => {
+      const { value, findings } = redactContent(
+        'card 4111 1111 1111 1111, order 1234 5678 9012 3456',
+        [],
+        DEFAULT_CONTENT_GUARD_PATTERNS
+      )
+      expect(value).toBe(`card ${REDACTED}, order 1234 5678 9012 3456`)
+      expect(findings).toEqual([{ name: 'credit_card', count: 1 }])
+    })
+
+    it('should skip invalid patterns and data urls', () => {
+      const { value } = redactContent(['data:image/png;base64,sk-abcdef123456'], ['sk-abcdef123456'], [
+        { name: 'broken', pattern: '(' }
+      ])
+      expect(value).toEqual(['data:image/png;base64,sk-abcdef123456'])
+    })
+  })
+})
@@ -0,0 +1,87 @@
+import { describe, expect, it } from 'vitest'
+
+import { assembleContext, ContextHistoryItem } from '../contextBuilder'
+
+// 一个字符一个 token，方便计算预算
+const countTokens = (text: string) => text.length
+
+const history: ContextHistoryItem[] = [
+  { id: 'm1', role: 'user', content: 'aaaa' },
+  { id: 'm2', role: 'assistant', content: 'bbbb' },
+  { id: 'm3', role: 'user', content: 'cccc' },
+  { id: 'm4', role: 'assistant', content: 'dddd' }
+]
+
+describe('contextBuilder', () => {
+  it('should keep the newest history within the context count', () => {
+    const { system, messages, report } = assembleContext({
+      systemPrompt: 'sys',
+      history,
+      message: 'hi',
+      maxTokens: 1000,
+      contextCount: 3,
+      countTokens
+    })
+    expect(system).toBe('sys')
+    // m2 is dropped with m1 since history must start with a question
+    expect(messages.map((message) => message.content)).toEqual(['cccc', 'dddd', 'hi'])
+    expect(report.history).toEqual({ tokens: 8, included: 2, dropped: 2 })
+    expect(report.total).toBe(3 + 8 + 2)
+    expect(report.overBudget).toBe(false)
+  })
+
+  it('should trim history to the budget', () => {
+    const { messages, report } = assembleContext({
+      systemPrompt: '',
+      history,
+      message: 'hi',
+      maxTokens: 12,
+      contextCount: 10,
+      countTokens
+    })
+    expect(messages.map((message) => message.content)).toEqual(['cccc', 'dddd', 'hi'])
+    expect(report.history.dropped).toBe(2)
+  })
+
+  it('should add memories and knowledge by score', () => {
+    const { system, messages, report } = assembleContext({
+      systemPrompt: 'sys',
+      history: [],
+      message: 'question',
+      memories: [
+        { id: 'a', content: 'likes tea', score: 0.2 },
+        { id: 'b', content: 'lives in Paris', score: 0.9 }
+      ],
+      knowledge: [
+        { content: 'low', sourceUrl: 'x', score: 0.1 },
+        { content: 'high', sourceUrl: 'y', score: 0.8 }
+      ],
+      maxTokens: 10000,
+      contextCount: 10,
+      countTokens
+    })
+    expect(system).toBe('sys\n\n## Memories about the user:\n\n- lives in Paris\n- likes tea')
+    const content = messages[0].content
+    expect(content).toContain('question')
+    expect(content.indexOf('"high"')).toBeLessThan(content.indexOf('"low"'))
+    expect(report.memories.included).toBe(2)
+    expect(report.knowledge.included).toBe(2)
+  })
+
+  it('should be deterministic and report an exceeded budget', () => {
+    const input = {
+      systemPrompt: 'a long system prompt',
+      history,
+      message: 'a long message',
+      memories: [{ id: 'a', content: 'memory', score: 1 }],
+      maxTokens: 10,
+      contextCount: 10,
+      countTokens
+    }
+    const built = assembleContext(input)
+    expect(assembleContext(input)).toEqual(built)
+    expect(built.messages).toHaveLength(1)
+    expect(built.report.memories).toEqual({ tokens: 0, included: 0, dropped: 1 })
+    expect(built.report.overBudget).toBe(true)
+  })
+})
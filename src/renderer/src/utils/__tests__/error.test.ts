@@ -1,3 +1,4 @@
+import { ManagerError } from '@shared/ManagerError'
 import { describe, expect, it, vi } from 'vitest'
 
 import { formatErrorMessage, formatMessageError, getErrorDetails, getErrorMessage, isAbortError } from '../error'
@@ -195,6 +196,29 @@ describe('error', () => {
       expect(getErrorMessage(undefined)).toBe('')
       expect(getErrorMessage(null)).toBe('')
     })
+
+    it('should unwrap manager errors serialized over IPC', () => {
+      const ipcError = new ManagerError('ConversationArchive', 'not_found', 'Topic t1 is not archived').toIpcError()
+      const error = new Error(`Error invoking remote method 'archive:get': ${ipcError.message}`)
+      expect(getErrorMessage(error)).toBe('Topic t1 is not archived')
+    })
+  })
+
+  describe('ManagerError', () => {
+    it('should treat transient failures as recoverable by default', () => {
+      expect(new ManagerError('MCPService', 'unavailable', 'Server exited').recoverable).toBe(true)
+      expect(new ManagerError('MCPService', 'invalid_argument', 'Bad server').recoverable).toBe(false)
+      expect(new ManagerError('MCPService', 'internal', 'Crashed', { recoverable: true }).recoverable).toBe(true)
+    })
+
+    it('should keep recoverable and details across IPC', () => {
+      const details = { serverId: 's1', serverName: 'fetch' }
+      const ipcError = new ManagerError('MCPService', 'unavailable', 'Server exited', { details }).toIpcError()
+      const restored = ManagerError.parse(ipcError)
+      expect(restored?.code).toBe('unavailable')
+      expect(restored?.recoverable).toBe(true)
+      expect(restored?.details).toEqual(details)
+    })
   })
 
   describe('isAbortError', () => {
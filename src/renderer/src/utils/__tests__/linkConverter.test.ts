@@ -7,6 +7,7 @@ import {
   convertLinksToHunyuan,
   convertLinksToOpenRouter,
   convertLinksToZhipu,
+  extractStandaloneUrls,
   extractUrlsFromMarkdown
 } from '../linkConverter'
 
@@ -186,6 +187,23 @@ describe('linkConverter', () => {
     })
   })
 
+  describe('extractStandaloneUrls', () => {
+    it('should extract urls on their own line', () => {
+      const input = '看看这个\nhttps://example.com/a\n  <https://other.com>  \n还有 https://inline.com 这个'
+      expect(extractStandaloneUrls(input)).toEqual(['https://example.com/a', 'https://other.com'])
+    })
+
+    it('should ignore non web urls and duplicates', () => {
+      const input = 'ftp://example.com\nhttps://example.com\nhttps://example.com'
+      expect(extractStandaloneUrls(input)).toEqual(['https://example.com'])
+    })
+
+    it('should stop at the limit', () => {
+      const input = 'https://a.com\nhttps://b.com\nhttps://c.com'
+      expect(extractStandaloneUrls(input, 2)).toEqual(['https://a.com', 'https://b.com'])
+    })
+  })
+
   describe('cleanLinkCommas', () => {
     it('should remove commas between links', () => {
       const input = '[链接1](https://example.com),[链接2](https://other.com)'
@@ -10,6 +10,8 @@ import {
   getExtensionByLanguage,
   markdownToPlainText,
   removeTrailingDoubleSpaces,
+  toCodeBlock,
+  toMarkdownTable,
   updateCodeBlock
 } from '../markdown'
 
@@ -461,4 +463,25 @@ describe('markdown', () => {
       expect(markdownToPlainText('This is plain text.')).toBe('This is plain text.')
     })
   })
+
+  describe('toCodeBlock', () => {
+    it('should fence code with its language', () => {
+      expect(toCodeBlock('print(1)\n', 'python')).toBe('```python\nprint(1)\n```')
+    })
+
+    it('should use a longer fence than the code contains', () => {
+      expect(toCodeBlock('```js\nx\n```')).toBe('````\n```js\nx\n```\n````')
+    })
+  })
+
+  describe('toMarkdownTable', () => {
+    it('should use the first row as header and escape pipes', () => {
+      expect(
+        toMarkdownTable([
+          ['name', 'note'],
+          ['Bob', 'a|b']
+        ])
+      ).toBe('| name | note |\n| --- | --- |\n| Bob | a\\|b |')
+    })
+  })
 })
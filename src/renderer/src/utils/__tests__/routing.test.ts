@@ -0,0 +1,81 @@
+import { Model } from '@renderer/types'
+import { describe, expect, it } from 'vitest'
+
+import { estimateRoutingCost, evaluateRoutingRules, hasCode, RoutingRequest, RoutingRule } from '../routing'
+
+const cheapModel: Model = {
+  id: 'mini',
+  provider: 'openai',
+  name: 'Mini',
+  group: 'mini',
+  pricing: { input_per_million_tokens: 0.5, output_per_million_tokens: 1 }
+}
+
+const visionModel: Model = { id: 'vision', provider: 'openai', name: 'Vision', group: 'vision' }
+
+const shortRule: RoutingRule = {
+  id: '1',
+  name: 'short',
+  enabled: true,
+  conditions: { maxLength: 20, maxCost: 0.01 },
+  model: cheapModel
+}
+
+const imageRule: RoutingRule = {
+  id: '2',
+  name: 'images',
+  enabled: true,
+  conditions: { hasImages: true },
+  model: visionModel
+}
+
+const request = (text: string, images = 0): RoutingRequest => ({ text, images, tokens: text.length })
+
+describe('routing', () => {
+  describe('hasCode', () => {
+    it('should detect fenced blocks and statements', () => {
+      expect(hasCode('look at this\n```js\nfoo()\n```')).toBe(true)
+      expect(hasCode('def main():\n  pass')).toBe(true)
+      expect(hasCode('what is the capital of France?')).toBe(false)
+    })
+  })
+
+  describe('estimateRoutingCost', () => {
+    it('should price input tokens and treat unpriced models as free', () => {
+      expect(estimateRoutingCost(2_000_000, cheapModel)).toBe(1)
+      expect(estimateRoutingCost(2_000_000, visionModel)).toBe(0)
+    })
+  })
+
+  describe('evaluateRoutingRules', () => {
+    it('should pick the first rule whose conditions all pass', () => {
+      const decision = evaluateRoutingRules(request('hi', 1), [shortRule, imageRule])
+      expect(decision.model).toBe(cheapModel)
+      expect(decision.rule).toEqual({ id: '1', name: 'short' })
+      expect(decision.explanations.map((item) => item.matched)).toEqual([true, false])
+    })
+
+    it('should explain why a rule was not picked', () => {
+      const decision = evaluateRoutingRules(request('a much longer question than twenty characters'), [shortRule])
+      expect(decision.model).toBeUndefined()
+      expect(decision.explanations[0].checks.find((check) => !check.passed)).toMatchObject({
+        condition: 'max_length',
+        expected: 20
+      })
+    })
+
+    it('should skip disabled rules and rules without a model', () => {
+      const rules = [{ ...shortRule, enabled: false }, { ...imageRule, model: undefined }]
+      const decision = evaluateRoutingRules(request('hi', 1), rules)
+      expect(decision.model).toBeUndefined()
+      expect(decision.explanations).toHaveLength(1)
+      expect(decision.explanations[0].checks.at(-1)).toMatchObject({ condition: 'model', passed: false })
+    })
+
+    it('should match the detected language', () => {
+      const rule: RoutingRule = { ...imageRule, conditions: { language: 'zh' } }
+      expect(evaluateRoutingRules(request('你好，请帮我写一首诗'), [rule]).model).toBe(visionModel)
+      expect(evaluateRoutingRules(request('write me a poem'), [rule]).model).toBeUndefined()
+    })
+  })
+})
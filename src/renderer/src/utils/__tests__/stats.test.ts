@@ -0,0 +1,43 @@
+import { StatsCounter } from '@renderer/types'
+import { describe, expect, it } from 'vitest'
+
+import { aggregateStats, getStatsCounterId, getStatsPeriod } from '../stats'
+
+function counter(date: string, metric: StatsCounter['metric'], key: string, value: number): StatsCounter {
+  return { id: getStatsCounterId(date, metric, key), date, metric, key, value }
+}
+
+describe('stats', () => {
+  describe('getStatsPeriod', () => {
+    it('should return the start of the week on monday', () => {
+      expect(getStatsPeriod('2025-06-04', 'week')).toBe('2025-06-02')
+      expect(getStatsPeriod('2025-06-02', 'week')).toBe('2025-06-02')
+      expect(getStatsPeriod('2025-06-08', 'week')).toBe('2025-06-02')
+    })
+
+    it('should return the start of the month', () => {
+      expect(getStatsPeriod('2025-06-18', 'month')).toBe('2025-06-01')
+    })
+
+    it('should keep the date for daily granularity', () => {
+      expect(getStatsPeriod('2025-06-18', 'day')).toBe('2025-06-18')
+    })
+  })
+
+  describe('aggregateStats', () => {
+    it('should sum counters by period, metric and key', () => {
+      const counters = [
+        counter('2025-06-03', 'messages', 'a1', 3),
+        counter('2025-06-01', 'messages', 'a1', 1),
+        counter('2025-06-04', 'messages', 'a1', 2),
+        counter('2025-06-04', 'prompt_tokens', 'openai/gpt-4o', 100),
+        counter('2025-06-05', 'prompt_tokens', 'openai/gpt-4o', 50)
+      ]
+
+      expect(aggregateStats(counters, 'week')).toEqual([
+        { period: '2025-05-26', metrics: { messages: { a1: 1 } } },
+        { period: '2025-06-02', metrics: { messages: { a1: 5 }, prompt_tokens: { 'openai/gpt-4o': 150 } } }
+      ])
+    })
+  })
+})
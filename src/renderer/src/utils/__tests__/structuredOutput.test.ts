@@ -0,0 +1,61 @@
+import { describe, expect, it } from 'vitest'
+
+import { extractJSONText, validateStructuredResponse } from '../structuredOutput'
+
+const schema = {
+  type: 'object',
+  properties: {
+    title: { type: 'string', minLength: 1 },
+    score: { type: 'number', minimum: 0, maximum: 1 },
+    tags: { type: 'array', items: { type: 'string' } },
+    level: { enum: ['low', 'high'] }
+  },
+  required: ['title', 'score'],
+  additionalProperties: false
+}
+
+describe('structuredOutput', () => {
+  describe('extractJSONText', () => {
+    it('should extract JSON from a fenced code block', () => {
+      expect(extractJSONText('Here you go:\n```json\n{"a": 1}\n```')).toBe('{"a": 1}')
+    })
+
+    it('should strip surrounding prose', () => {
+      expect(extractJSONText('Result: {"a": [1, 2]} hope this helps')).toBe('{"a": [1, 2]}')
+    })
+  })
+
+  describe('validateStructuredResponse', () => {
+    it('should return parsed data for a valid response', () => {
+      const result = validateStructuredResponse(schema, '{"title": "Hi", "score": 0.5, "tags": ["a"], "level": "low"}')
+      expect(result.valid).toBe(true)
+      expect(result.data).toEqual({ title: 'Hi', score: 0.5, tags: ['a'], level: 'low' })
+    })
+
+    it('should report invalid JSON', () => {
+      const result = validateStructuredResponse(schema, '{"title": ')
+      expect(result.valid).toBe(false)
+      expect(result.errors[0]).toContain('invalid JSON')
+    })
+
+    it('should report schema violations with their path', () => {
+      const result = validateStructuredResponse(schema, '{"score": 2, "tags": [1], "level": "mid", "extra": true}')
+      expect(result.valid).toBe(false)
+      expect(result.data).toBeUndefined()
+      expect(result.errors).toEqual(
+        expect.arrayContaining([
+          '$.title: is required',
+          '$.score: greater than 1',
+          '$.tags[0]: expected string, got integer',
+          '$.level: must be one of "low", "high"',
+          '$.extra: is not allowed'
+        ])
+      )
+    })
+
+    it('should accept integers for number types', () => {
+      expect(validateStructuredResponse({ type: 'number' }, '3').valid).toBe(true)
+      expect(validateStructuredResponse({ type: 'integer' }, '3.5').valid).toBe(false)
+    })
+  })
+})
@@ -0,0 +1,143 @@
+import { describe, expect, it } from 'vitest'
+
+import { REDACTED } from '../contentGuard'
+import {
+  normalizeTrainingConversation,
+  parseTrainingJsonl,
+  toTrainingJsonl,
+  toTrainingRecord,
+  TrainingConversation
+} from '../trainingData'
+
+const conversation: TrainingConversation = {
+  system: 'You are helpful.',
+  messages: [
+    { role: 'assistant', content: 'Hello! How can I help?' },
+    { role: 'user', content: 'Hi' },
+    { role: 'user', content: 'What is 2 + 2?' },
+    { role: 'assistant', content: '4' },
+    { role: 'user', content: '  ' },
+    { role: 'user', content: 'Thanks' }
+  ]
+}
+
+describe('trainingData', () => {
+  it('should start with a question and end with an answer', () => {
+    expect(normalizeTrainingConversation(conversation)).toEqual([
+      { role: 'user', content: 'Hi\n\nWhat is 2 + 2?' },
+      { role: 'assistant', content: '4' }
+    ])
+    expect(normalizeTrainingConversation({ messages: [{ role: 'user', content: 'Hi' }] })).toEqual([])
+  })
+
+  it('should produce OpenAI records', () => {
+    expect(toTrainingRecord(conversation, { format: 'openai', includeSystemPrompt: true, redactions: [] })).toEqual({
+      messages: [
+        { role: 'system', content: 'You are helpful.' },
+        { role: 'user', content: 'Hi\n\nWhat is 2 + 2?' },
+        { role: 'assistant', content: '4' }
+      ]
+    })
+  })
+
+  it('should produce ShareGPT records with custom role names', () => {
+    const record = toTrainingRecord(conversation, {
+      format: 'sharegpt',
+      includeSystemPrompt: false,
+      roleNames: { assistant: 'model' },
+      redactions: []
+    })
+    expect(record).toEqual({
+      conversations: [
+        { from: 'human', value: 'Hi\n\nWhat is 2 + 2?' },
+        { from: 'model', value: '4' }
+      ]
+    })
+  })
+
+  it('should write one line per conversation and redact personal data', () => {
+    const result = toTrainingJsonl(
+      [
+        {
+          messages: [
+            { role: 'user', content: 'Mail me at jane.doe@example.com from 192.168.1.20, key sk-1234567890abcdef' },
+            { role: 'assistant', content: 'Call +1 415-555-0132 or pay with 4111 1111 1111 1111' }
+          ]
+        },
+        { messages: [{ role: 'user', content: 'unanswered' }] }
+      ],
+      {
+        format: 'openai',
+        includeSystemPrompt: false,
+        redactions: ['email', 'phone', 'ip_address', 'credit_card', 'secret'],
+        secrets: ['sk-1234567890abcdef']
+      }
+    )
+    expect(result.count).toBe(1)
+    expect(result.skipped).toBe(1)
+    expect(result.content.endsWith('\n')).toBe(true)
+
+    const [user, assistant] = JSON.parse(result.content.trim()).messages
+    expect(user.content).toBe(`Mail me at ${REDACTED} from ${REDACTED}, key ${REDACTED}`)
+    expect(assistant.content).toBe(`Call ${REDACTED} or pay with ${REDACTED}`)
+    expect(result.findings.map((finding) => finding.name).sort()).toEqual(
+      ['credit_card', 'email', 'ip_address', 'phone', 'secret'].sort()
+    )
+  })
+
+  it('should keep content when no redaction is selected', () => {
+    const result = toTrainingJsonl(
+      [
+        {
+          messages: [
+            { role: 'user', content: 'jane@example.com' },
+            { role: 'assistant', content: 'ok' }
+          ]
+        }
+      ],
+      { format: 'openai', includeSystemPrompt: false, redactions: [] }
+    )
+    expect(result.content).toContain('jane@example.com')
+    expect(result.findings).toEqual([])
+  })
+
+  it('should parse OpenAI and ShareGPT records back into conversations', () => {
+    const content = [
+      JSON.stringify({
+        messages: [
+          { role: 'system', content: 'Be brief.' },
+          { role: 'user', content: 'Hi' },
+          { role: 'assistant', content: 'Hello' }
+        ]
+      }),
+      '',
+      JSON.stringify({
+        conversations: [
+          { from: 'human', value: 'Ping' },
+          { from: 'gpt', value: 'Pong' }
+        ]
+      }),
+      '{not json',
+      JSON.stringify({ messages: [{ role: 'tool', content: 'x' }] })
+    ].join('\n')
+
+    expect(parseTrainingJsonl(content)).toEqual({
+      conversations: [
+        {
+          system: 'Be brief.',
+          messages: [
+            { role: 'user', content: 'Hi' },
+            { role: 'assistant', content: 'Hello' }
+          ]
+        },
+        {
+          messages: [
+            { role: 'user', content: 'Ping' },
+            { role: 'assistant', content: 'Pong' }
+          ]
+        }
+      ],
+      skipped: 2
+    })
+  })
+})
@@ -0,0 +1,46 @@
+import type { Topic } from '@renderer/types'
+import type { Message, MessageBlock } from '@renderer/types/newMessage'
+import { describe, expect, it } from 'vitest'
+
+import { createTranscript, importTranscript, parseTranscript, TRANSCRIPT_VERSION } from '../transcript'
+
+const topic = { id: 't1', assistantId: 'a1', name: 'Topic', createdAt: '2025-01-01', updatedAt: '2025-01-02' } as Topic
+const messages = [
+  { id: 'm1', role: 'user', topicId: 't1', assistantId: 'a1', blocks: ['b1'] },
+  { id: 'm2', role: 'assistant', topicId: 't1', assistantId: 'a1', askId: 'm1', blocks: ['b2', 'missing'] }
+] as Message[]
+const blocks = [
+  { id: 'b1', messageId: 'm1', content: 'question' },
+  { id: 'b2', messageId: 'm2', content: 'answer' },
+  { id: 'b3', messageId: 'other', content: 'orphan' }
+] as unknown as MessageBlock[]
+
+describe('transcript', () => {
+  it('should round trip through JSON', () => {
+    const transcript = createTranscript(topic, messages, blocks)
+    expect(parseTranscript(JSON.stringify(transcript))).toEqual(transcript)
+  })
+
+  it('should reject other files and newer versions', () => {
+    expect(() => parseTranscript('{"format":"other"}')).toThrow()
+    expect(() => parseTranscript('not json')).toThrow()
+    const newer = { ...createTranscript(topic, messages, blocks), version: TRANSCRIPT_VERSION + 1 }
+    expect(() => parseTranscript(JSON.stringify(newer))).toThrow('Unsupported transcript version')
+  })
+
+  it('should import as a new read-only topic with new ids', () => {
+    const imported = importTranscript(createTranscript(topic, messages, blocks), 'a2')
+    const [question, answer] = imported.messages
+
+    expect(imported.topic).toMatchObject({ name: 'Topic', assistantId: 'a2', readonly: true })
+    expect(imported.topic.id).not.toBe('t1')
+    expect(question.id).not.toBe('m1')
+    expect(question.topicId).toBe(imported.topic.id)
+    expect(answer.askId).toBe(question.id)
+    expect(answer.blocks).toHaveLength(1)
+
+    expect(imported.blocks).toHaveLength(2)
+    expect(imported.blocks[0]).toMatchObject({ id: question.blocks[0], messageId: question.id, content: 'question' })
+    expect(imported.blocks[1]).toMatchObject({ id: answer.blocks[0], messageId: answer.id, content: 'answer' })
+  })
+})
@@ -0,0 +1,61 @@
+import { describe, expect, it } from 'vitest'
+
+import {
+  splitParagraphs,
+  splitSentences,
+  splitSurroundingWhitespace,
+  toDeepLLanguage,
+  toGoogleLanguage
+} from '../translate'
+
+describe('translate', () => {
+  describe('splitSentences', () => {
+    it('should split text into sentences', () => {
+      expect(splitSentences('Hello world. How are you? Fine!')).toEqual(['Hello world. ', 'How are you? ', 'Fine!'])
+    })
+
+    it('should keep the original text when joined', () => {
+      const text = '  First line.\n\nSecond paragraph.  Third one.\n'
+      expect(splitSentences(text).join('')).toBe(text)
+    })
+
+    it('should return an empty array for empty text', () => {
+      expect(splitSentences('')).toEqual([])
+    })
+  })
+
+  describe('splitSurroundingWhitespace', () => {
+    it('should separate leading and trailing whitespace', () => {
+      expect(splitSurroundingWhitespace('\n  Hello world.  ')).toEqual(['\n  ', 'Hello world.', '  '])
+    })
+
+    it('should handle whitespace only segments', () => {
+      const [leading, content, trailing] = splitSurroundingWhitespace('\n\n')
+      expect(content).toBe('')
+      expect(leading + trailing).toBe('\n\n')
+    })
+  })
+
+  describe('splitParagraphs', () => {
+    it('should split on blank lines and drop empty paragraphs', () => {
+      expect(splitParagraphs('First\nline\n\n  \n\nSecond\n\n')).toEqual(['First\nline', 'Second'])
+    })
+  })
+
+  describe('toDeepLLanguage', () => {
+    it('should map language codes to DeepL target languages', () => {
+      expect(toDeepLLanguage('zh-cn')).toBe('ZH-HANS')
+      expect(toDeepLLanguage('zh-tw')).toBe('ZH-HANT')
+      expect(toDeepLLanguage('en-us')).toBe('EN-US')
+      expect(toDeepLLanguage('ja-jp')).toBe('JA')
+    })
+  })
+
+  describe('toGoogleLanguage', () => {
+    it('should map language codes to Google targets', () => {
+      expect(toGoogleLanguage('zh-cn')).toBe('zh-CN')
+      expect(toGoogleLanguage('zh-tw')).toBe('zh-TW')
+      expect(toGoogleLanguage('en-us')).toBe('en')
+    })
+  })
+})
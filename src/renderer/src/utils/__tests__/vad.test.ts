@@ -0,0 +1,59 @@
+import { describe, expect, it } from 'vitest'
+
+import { VoiceActivityDetector } from '../vad'
+
+const SAMPLE_RATE = 16000
+
+// 指定时长和幅度的正弦波，幅度很小时相当于背景噪声
+function tone(ms: number, amplitude: number) {
+  const samples = new Float32Array((SAMPLE_RATE * ms) / 1000)
+  for (let i = 0; i < samples.length; i++) {
+    samples[i] = amplitude * Math.sin((2 * Math.PI * 220 * i) / SAMPLE_RATE)
+  }
+  return samples
+}
+
+describe('vad', () => {
+  it('should emit speech events around a loud segment', () => {
+    const vad = new VoiceActivityDetector({ sampleRate: SAMPLE_RATE })
+    const events = [
+      ...vad.process(tone(600, 0.001)),
+      ...vad.process(tone(1200, 0.3)),
+      ...vad.process(tone(1200, 0.001))
+    ]
+
+    expect(events.map((event) => event.type)).toEqual(['speech-started', 'speech-ended'])
+    expect(events[0].time).toBeGreaterThanOrEqual(540)
+    expect(events[0].time).toBeLessThanOrEqual(660)
+
+    const ended = events[1]
+    if (ended.type !== 'speech-ended') throw new Error('expected speech-ended')
+    // 语音段包含开始前的预留音频和结束后的静音
+    expect(ended.segment.length).toBeGreaterThan((SAMPLE_RATE * 1200) / 1000)
+  })
+
+  it('should ignore short noises', () => {
+    const vad = new VoiceActivityDetector({ sampleRate: SAMPLE_RATE })
+    const events = [...vad.process(tone(600, 0.001)), ...vad.process(tone(60, 0.3)), ...vad.process(tone(600, 0.001))]
+    expect(events).toEqual([])
+  })
+
+  it('should accept input in chunks of any size', () => {
+    const vad = new VoiceActivityDetector({ sampleRate: SAMPLE_RATE })
+    const input = [tone(600, 0.001), tone(1000, 0.3), tone(1200, 0.001)]
+    const events = input.flatMap((samples) => {
+      const chunks: Float32Array[] = []
+      for (let i = 0; i < samples.length; i += 333) chunks.push(samples.slice(i, i + 333))
+      return chunks.flatMap((chunk) => vad.process(chunk))
+    })
+    expect(events.map((event) => event.type)).toEqual(['speech-started', 'speech-ended'])
+  })
+
+  it('should split long speech and end it on flush', () => {
+    const vad = new VoiceActivityDetector({ sampleRate: SAMPLE_RATE, maxSegmentMs: 1000 })
+    const events = [...vad.process(tone(600, 0.001)), ...vad.process(tone(1500, 0.3))]
+    expect(events.map((event) => event.type)).toEqual(['speech-started', 'speech-ended', 'speech-started'])
+    expect(vad.flush()?.type).toBe('speech-ended')
+    expect(vad.flush()).toBeUndefined()
+  })
+})
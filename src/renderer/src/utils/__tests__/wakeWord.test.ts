@@ -0,0 +1,49 @@
+import { WakeWordModel } from '@renderer/types'
+import { describe, expect, it } from 'vitest'
+
+import { computeMfcc, dtwDistance, getTemplateSpread, matchWakeWord } from '../wakeWord'
+
+const SAMPLE_RATE = 16000
+
+// 频率从 from 滑到 to 的音调，用来模拟不同的词
+function sweep(ms: number, from: number, to: number) {
+  const samples = new Float32Array((SAMPLE_RATE * ms) / 1000)
+  let phase = 0
+  for (let i = 0; i < samples.length; i++) {
+    phase += (2 * Math.PI * (from + ((to - from) * i) / samples.length)) / SAMPLE_RATE
+    samples[i] = 0.3 * Math.sin(phase)
+  }
+  return samples
+}
+
+const features = (ms: number, from: number, to: number) => computeMfcc(sweep(ms, from, to), SAMPLE_RATE)
+
+describe('wakeWord', () => {
+  const templates = [features(500, 300, 1200), features(600, 300, 1200), features(550, 320, 1150)]
+  const model: WakeWordModel = {
+    id: 'test',
+    name: 'Test',
+    templates,
+    spread: getTemplateSpread(templates),
+    createdAt: 0
+  }
+
+  it('should compute 13 coefficients every 10ms', () => {
+    const mfcc = features(500, 300, 1200)
+    expect(mfcc.length).toBe(48)
+    expect(mfcc.every((frame) => frame.length === 13)).toBe(true)
+  })
+
+  it('should keep the same word close at different speeds', () => {
+    const word = features(500, 300, 1200)
+    expect(dtwDistance(word, features(650, 300, 1200))).toBeLessThan(dtwDistance(word, features(500, 1500, 400)))
+    expect(dtwDistance(word, [])).toBe(Infinity)
+  })
+
+  it('should match the enrolled word only', () => {
+    expect(matchWakeWord(features(520, 310, 1180), model, 0.5)).toBe(true)
+    expect(matchWakeWord(features(500, 1500, 400), model, 0.5)).toBe(false)
+    expect(matchWakeWord(features(500, 300, 3000), model, 0.5)).toBe(false)
+    expect(matchWakeWord([], model, 1)).toBe(false)
+  })
+})
@@ -0,0 +1,23 @@
+import { describe, expect, it } from 'vitest'
+
+import { encodeWav } from '../wav'
+
+describe('wav', () => {
+  it('should write a 16-bit mono PCM header', () => {
+    const view = new DataView(encodeWav(new Float32Array(100), 16000))
+    const text = (offset: number) => String.fromCharCode(...new Uint8Array(view.buffer, offset, 4))
+
+    expect(view.byteLength).toBe(244)
+    expect(text(0)).toBe('RIFF')
+    expect(text(8)).toBe('WAVE')
+    expect(view.getUint16(22, true)).toBe(1)
+    expect(view.getUint32(24, true)).toBe(16000)
+    expect(view.getUint16(34, true)).toBe(16)
+    expect(view.getUint32(40, true)).toBe(200)
+  })
+
+  it('should clip samples to the 16-bit range', () => {
+    const view = new DataView(encodeWav(new Float32Array([1, -1, 2, -2, 0]), 8000))
+    expect([0, 1, 2, 3, 4].map((i) => view.getInt16(44 + i * 2, true))).toEqual([32767, -32768, 32767, -32768, 0])
+  })
+})
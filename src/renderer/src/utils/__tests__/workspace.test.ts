@@ -0,0 +1,60 @@
+import { describe, expect, it } from 'vitest'
+
+import { mergeById, mergeWorkspaceSettings, stripWorkspaceSecrets } from '../workspace'
+
+const local = [
+  { id: 'a', name: 'local a' },
+  { id: 'b', name: 'local b' }
+]
+const incoming = [
+  { id: 'b', name: 'incoming b' },
+  { id: 'c', name: 'incoming c' }
+]
+
+describe('workspace', () => {
+  describe('mergeById', () => {
+    it('should replace the local list', () => {
+      expect(mergeById(local, incoming, 'replace')).toEqual(incoming)
+    })
+
+    it('should let imported items win and append new ones', () => {
+      expect(mergeById(local, incoming, 'merge')).toEqual([
+        { id: 'a', name: 'local a' },
+        { id: 'b', name: 'incoming b' },
+        { id: 'c', name: 'incoming c' }
+      ])
+    })
+
+    it('should only add missing items when keeping existing ones', () => {
+      expect(mergeById(local, incoming, 'keep_existing')).toEqual([
+        { id: 'a', name: 'local a' },
+        { id: 'b', name: 'local b' },
+        { id: 'c', name: 'incoming c' }
+      ])
+    })
+  })
+
+  describe('stripWorkspaceSecrets', () => {
+    it('should drop credentials', () => {
+      expect(stripWorkspaceSecrets({ language: 'en-US', webdavPass: 'x', notionApiKey: 'y' })).toEqual({
+        language: 'en-US'
+      })
+    })
+  })
+
+  describe('mergeWorkspaceSettings', () => {
+    const settings = { language: 'zh-CN', fontSize: 14, yuqueToken: 'local' }
+
+    it('should apply imported settings and keep local credentials', () => {
+      expect(mergeWorkspaceSettings(settings, { language: 'en-US', yuqueToken: 'other' }, 'merge')).toEqual({
+        language: 'en-US',
+        fontSize: 14,
+        yuqueToken: 'local'
+      })
+    })
+
+    it('should keep local settings when keeping existing ones', () => {
+      expect(mergeWorkspaceSettings(settings, { language: 'en-US' }, 'keep_existing')).toBe(settings)
+    })
+  })
+})
@@ -0,0 +1,109 @@
+import type { Agent, AgentPermissionChange, AgentUpdate } from '@renderer/types'
+
+const PERMISSION_FIELDS: AgentPermissionChange['field'][] = [
+  'mcpServers',
+  'knowledge_bases',
+  'enableWebSearch',
+  'webSearchProviderId',
+  'enableGenerateImage'
+]
+
+/**
+ * 获取市场中智能体的版本，没有版本号时使用内容哈希，内容变化即视为新版本
+ */
+export function getAgentVersion(agent: Agent): string {
+  if (agent.version) return agent.version
+
+  const content = JSON.stringify([
+    agent.name,
+    agent.emoji,
+    agent.prompt,
+    agent.description,
+    agent.group,
+    ...PERMISSION_FIELDS.map((field) => describePermission(agent, field))
+  ])
+
+  // FNV-1a
+  let hash = 0x811c9dc5
+  for (let i = 0; i < content.length; i++) {
+    hash ^= content.charCodeAt(i)
+    hash = Math.imul(hash, 0x01000193)
+  }
+  return (hash >>> 0).toString(16).padStart(8, '0')
+}
+
+/**
+ * 在上游列表中查找已安装智能体对应的条目
+ */
+export function findUpstreamAgent(agent: Agent, upstream: Agent[]): Agent | undefined {
+  const id = agent.source?.id
+  if (!id) return undefined
+  return upstream.find((item) => item.id === id) ?? upstream.find((item) => item.name === id)
+}
+
+function describePermission(agent: Agent, field: AgentPermissionChange['field']): string {
+  switch (field) {
+    case 'mcpServers':
+      return (agent.mcpServers ?? []).map((server) => server.name).join(', ')
+    case 'knowledge_bases':
+      return (agent.knowledge_bases ?? []).map((base) => base.name).join(', ')
+    default:
+      return String(agent[field] ?? '')
+  }
+}
+
+/**
+ * 比较智能体更新前后的权限变化：可用的 MCP 服务、知识库、网络搜索和图片生成
+ */
+export function diffAgentPermissions(current: Agent, upstream: Agent): AgentPermissionChange[] {
+  const changes: AgentPermissionChange[] = []
+  for (const field of PERMISSION_FIELDS) {
+    const before = describePermission(current, field)
+    const after = describePermission(upstream, field)
+    if (before !== after) {
+      changes.push({ field, before, after })
+    }
+  }
+  return changes
+}
+
+/**
+ * 已安装智能体有新版本时返回更新信息，固定版本的智能体不更新
+ */
+export function getAgentUpdate(agent: Agent, upstream: Agent): AgentUpdate | null {
+  if (!agent.source || agent.source.pinned) return null
+
+  const version = getAgentVersion(upstream)
+  if (version === agent.source.version) return null
+
+  return {
+    agentId: agent.id,
+    version,
+    upstream,
+    changes: diffAgentPermissions(agent, upstream),
+    promptChanged: agent.prompt !== upstream.prompt
+  }
+}
+
+/**
+ * 应用更新，保留本地的 id、话题、默认模型和模型设置
+ */
+export function applyAgentUpdate(agent: Agent, update: AgentUpdate): Agent {
+  const { upstream } = update
+  return {
+    ...agent,
+    name: upstream.name || agent.name,
+    emoji: upstream.emoji || agent.emoji,
+    prompt: upstream.prompt,
+    description: upstream.description ?? agent.description,
+    group: upstream.group ?? agent.group,
+    mcpServers: upstream.mcpServers,
+    knowledge_bases: upstream.knowledge_bases,
+    enableWebSearch: upstream.enableWebSearch,
+    webSearchProviderId: upstream.webSearchProviderId,
+    enableGenerateImage: upstream.enableGenerateImage,
+    regularPhrases: upstream.regularPhrases ?? agent.regularPhrases,
+    version: upstream.version,
+    source: agent.source && { ...agent.source, version: update.version }
+  }
+}
@@ -0,0 +1,112 @@
+import { REDACTED } from './contentGuard'
+
+export type ContentFilterAction = 'warn' | 'redact' | 'block'
+
+export type ContentFilterDirection = 'inbound' | 'outbound'
+
+export interface ContentFilterRule {
+  id: string
+  name: string
+  // keyword 规则的 pattern 为逗号或换行分隔的关键词，不区分大小写
+  type: 'keyword' | 'regex'
+  pattern: string
+  enabled: boolean
+}
+
+export interface ContentFilterHit {
+  // 命中的规则名称，classifier 表示本地分类模型判定为不安全
+  rule: string
+  count: number
+}
+
+export interface ContentFilterResult<T> {
+  value: T
+  hits: ContentFilterHit[]
+}
+
+export const CLASSIFIER_RULE = 'classifier'
+
+interface CompiledRule {
+  name: string
+  regex: RegExp
+}
+
+function escapeRegExp(value: string): string {
+  return value.replace(/[.*+?^${}()|[\]\\]/g, '\\$&')
+}
+
+/**
+ * 把规则编译为正则，忽略停用的规则和无效的正则
+ * @param {ContentFilterRule[]} rules 过滤规则
+ * @returns {CompiledRule[]} 编译后的规则
+ */
+export function compileContentFilterRules(rules: ContentFilterRule[]): CompiledRule[] {
+  return rules.flatMap((rule) => {
+    if (!rule.enabled || !rule.pattern.trim()) return []
+
+    if (rule.type === 'keyword') {
+      const keywords = rule.pattern
+        .split(/[,\n，]/)
+        .map((keyword) => keyword.trim())
+        .filter(Boolean)
+        // 先匹配较长的关键词，避免只替换掉前缀
+        .sort((a, b) => b.length - a.length)
+      if (keywords.length === 0) return []
+      return [{ name: rule.name, regex: new RegExp(keywords.map(escapeRegExp).join('|'), 'gi') }]
+    }
+
+    try {
+      return [{ name: rule.name, regex: new RegExp(rule.pattern, 'g') }]
+    } catch (e) {
+      return []
+    }
+  })
+}
+
+/**
+ * 扫描数据中的所有字符串，redact 为 true 时把命中的内容替换为 [REDACTED]
+ * @param {T} value 文本或任意可序列化的请求数据
+ * @param {ContentFilterRule[]} rules 过滤规则
+ * @param {boolean} redact 是否替换命中的内容
+ * @returns {ContentFilterResult<T>} 处理后的数据和命中情况
+ */
+export function filterContent<T>(value: T, rules: ContentFilterRule[], redact: boolean): ContentFilterResult<T> {
+  const compiled = compileContentFilterRules(rules)
+  const counts = new Map<string, number>()
+
+  const filterString = (text: string): string => {
+    // 图片等 data url 不会包含文本内容
+    if (text.startsWith('data:')) return text
+
+    let result = text
+    for (const { name, regex } of compiled) {
+      result = result.replace(regex, (match) => {
+        counts.set(name, (counts.get(name) ?? 0) + 1)
+        return redact ? REDACTED : match
+      })
+    }
+    return result
+  }
+
+  const walk = (item: any): any => {
+    if (typeof item === 'string') return filterString(item)
+    if (Array.isArray(item)) return item.map(walk)
+    if (item && typeof item === 'object' && Object.getPrototypeOf(item) === Object.prototype) {
+      return Object.fromEntries(Object.entries(item).map(([key, child]) => [key, walk(child)]))
+    }
+    return item
+  }
+
+  const filtered = walk(value)
+  return { value: filtered, hits: [...counts].map(([rule, count]) => ({ rule, count })) }
+}
+
+/**
+ * 解析安全分类模型（如 Llama Guard）的输出，首行为 unsafe 时视为命中
+ * @param {string} output 模型输出
+ * @returns {boolean} 是否判定为不安全
+ */
+export function isUnsafeClassification(output: string): boolean {
+  const firstLine = output.trim().split('\n')[0].trim().toLowerCase()
+  return firstLine.startsWith('unsafe')
+}
@@ -0,0 +1,107 @@
+export interface ContentGuardPattern {
+  name: string
+  pattern: string
+}
+
+export interface ContentGuardFinding {
+  // secret 表示命中了已保存的密钥，其余为匹配到的自定义规则名称
+  name: string
+  count: number
+}
+
+export interface ContentGuardResult<T> {
+  value: T
+  findings: ContentGuardFinding[]
+}
+
+export const REDACTED = '[REDACTED]'
+
+// 太短的值容易误伤正常文本，不作为密钥处理
+const MIN_SECRET_LENGTH = 8
+
+export const DEFAULT_CONTENT_GUARD_PATTERNS: ContentGuardPattern[] = [
+  { name: 'credit_card', pattern: '\\b(?:\\d[ -]?){12,18}\\d\\b' },
+  { name: 'private_key', pattern: '-----BEGIN [A-Z ]*PRIVATE KEY-----[\\s\\S]*?-----END [A-Z ]*PRIVATE KEY-----' }
+]
+
+/**
+ * Luhn 校验，过滤掉不是信用卡号的长数字
+ * @param {string} value 数字串，可以包含空格和连字符
+ * @returns {boolean} 是否通过校验
+ */
+export function isLuhnValid(value: string): boolean {
+  const digits = value.replace(/\D/g, '')
+  let sum = 0
+  for (let i = 0; i < digits.length; i++) {
+    let digit = Number(digits[digits.length - 1 - i])
+    if (i % 2 === 1) {
+      digit *= 2
+      if (digit > 9) digit -= 9
+    }
+    sum += digit
+  }
+  return digits.length >= 13 && sum % 10 === 0
+}
+
+function escapeRegExp(value: string): string {
+  return value.replace(/[.*+?^${}()|[\]\\]/g, '\\$&')
+}
+
+/**
+ * 扫描请求中所有字符串，把已保存的密钥和匹配规则的内容替换为 [REDACTED]
+ * @param {T} value 任意可序列化的请求数据
+ * @param {string[]} secrets 已保存的密钥
+ * @param {ContentGuardPattern[]} patterns 自定义正则规则
+ * @returns {ContentGuardResult<T>} 替换后的数据和命中情况
+ */
+export function redactContent<T>(value: T, secrets: string[], patterns: ContentGuardPattern[]): ContentGuardResult<T> {
+  const counts = new Map<string, number>()
+  const count = (name: string) => counts.set(name, (counts.get(name) ?? 0) + 1)
+
+  const uniqueSecrets = [...new Set(secrets.map((secret) => secret.trim()))]
+    .filter((secret) => secret.length >= MIN_SECRET_LENGTH)
+    // 先替换较长的密钥，避免前缀相同的密钥只被部分替换
+    .sort((a, b) => b.length - a.length)
+  const secretRegex = uniqueSecrets.length ? new RegExp(uniqueSecrets.map(escapeRegExp).join('|'), 'g') : null
+
+  const compiled = patterns.flatMap(({ name, pattern }) => {
+    try {
+      return [{ name, regex: new RegExp(pattern, 'g') }]
+    } catch (e) {
+      return []
+    }
+  })
+
+  const redactString = (text: string): string => {
+    // 图片等 data url 不会包含文本内容
+    if (text.startsWith('data:')) return text
+
+    let result = text
+    if (secretRegex) {
+      result = result.replace(secretRegex, () => {
+        count('secret')
+        return REDACTED
+      })
+    }
+    for (const { name, regex } of compiled) {
+      result = result.replace(regex, (match) => {
+        if (name === 'credit_card' && !isLuhnValid(match)) return match
+        count(name)
+        return REDACTED
+      })
+    }
+    return result
+  }
+
+  const walk = (item: any): any => {
+    if (typeof item === 'string') return redactString(item)
+    if (Array.isArray(item)) return item.map(walk)
+    if (item && typeof item === 'object' && Object.getPrototypeOf(item) === Object.prototype) {
+      return Object.fromEntries(Object.entries(item).map(([key, child]) => [key, walk(child)]))
+    }
+    return item
+  }
+
+  const redacted = walk(value)
+  return { value: redacted, findings: [...counts].map(([name, count]) => ({ name, count })) }
+}
@@ -0,0 +1,147 @@
+import { REFERENCE_PROMPT } from '@renderer/config/prompts'
+
+// 记忆和知识库片段最多占用的预算比例，其余留给历史消息
+const MEMORY_SHARE = 0.1
+const KNOWLEDGE_SHARE = 0.4
+
+export interface ContextHistoryItem {
+  id: string
+  role: 'user' | 'assistant'
+  content: string
+}
+
+export interface ContextKnowledgeItem {
+  content: string
+  sourceUrl: string
+  score: number
+}
+
+export interface ContextMemoryItem {
+  id: string
+  content: string
+  score: number
+}
+
+export interface ContextInput {
+  systemPrompt: string
+  // 按时间顺序，最早的在前
+  history: ContextHistoryItem[]
+  message: string
+  knowledge?: ContextKnowledgeItem[]
+  memories?: ContextMemoryItem[]
+  // 组装后的上下文的 token 预算
+  maxTokens: number
+  // 最多保留的历史消息数，同助手的上下文数
+  contextCount: number
+  countTokens?: (text: string) => number
+}
+
+export interface ContextSectionReport {
+  tokens: number
+  included: number
+  dropped: number
+}
+
+export interface ContextReport {
+  budget: number
+  total: number
+  system: number
+  message: number
+  memories: ContextSectionReport
+  knowledge: ContextSectionReport
+  history: ContextSectionReport
+  // 系统提示词和用户消息本身已超出预算
+  overBudget: boolean
+}
+
+export interface BuiltContext {
+  system: string
+  messages: { role: 'user' | 'assistant'; content: string }[]
+  report: ContextReport
+}
+
+const defaultCountTokens = (text: string) => Math.ceil(text.length / 4)
+
+/**
+ * 按顺序取能放进预算的条目，第一个放不下的条目之后都丢弃
+ */
+function takeWithin<T>(items: T[], budget: number, cost: (item: T) => number) {
+  const taken: T[] = []
+  let tokens = 0
+  for (const item of items) {
+    const itemTokens = cost(item)
+    if (tokens + itemTokens > budget) break
+    taken.push(item)
+    tokens += itemTokens
+  }
+  return { taken, tokens, report: { tokens, included: taken.length, dropped: items.length - taken.length } }
+}
+
+/**
+ * 组装发送给模型的上下文
+ *
+ * 系统提示词和用户消息总是保留；记忆并入系统提示词，知识库片段按引用格式并入用户消息，
+ * 剩余预算从最新的消息开始保留历史。相同输入总是得到相同结果，分数相同的条目按原顺序排列。
+ */
+export function assembleContext(input: ContextInput): BuiltContext {
+  const count = input.countTokens ?? defaultCountTokens
+  const systemTokens = count(input.systemPrompt)
+  const messageTokens = count(input.message)
+  const available = Math.max(input.maxTokens - systemTokens - messageTokens, 0)
+
+  const byScore = <T extends { score: number }>(items: T[]) =>
+    items.map((item, index) => ({ item, index })).sort((a, b) => b.item.score - a.item.score || a.index - b.index)
+
+  const memoryLine = (memory: ContextMemoryItem) => `- ${memory.content}`
+  const memories = takeWithin(
+    byScore(input.memories ?? []).map(({ item }) => item),
+    Math.floor(available * MEMORY_SHARE),
+    (memory) => count(memoryLine(memory))
+  )
+
+  const references = byScore(input.knowledge ?? []).map(({ item }) => item)
+  const referenceText = (item: ContextKnowledgeItem, index: number) =>
+    JSON.stringify({ id: index + 1, content: item.content, sourceUrl: item.sourceUrl, type: 'file' })
+  const knowledge = takeWithin(
+    references.map((item, index) => ({ item, text: referenceText(item, index) })),
+    Math.floor(available * KNOWLEDGE_SHARE),
+    ({ text }) => count(text)
+  )
+
+  // 从最新的消息往前保留，开头的助手消息没有对应的提问，一并丢弃
+  const newestFirst = [...input.history].reverse().slice(0, Math.max(input.contextCount, 0))
+  const history = takeWithin(newestFirst, available - memories.tokens - knowledge.tokens, (item) =>
+    count(item.content)
+  )
+  const kept = history.taken.reverse()
+  while (kept.length && kept[0].role !== 'user') kept.shift()
+  const historyTokens = kept.reduce((sum, item) => sum + count(item.content), 0)
+
+  const system = memories.taken.length
+    ? `${input.systemPrompt}\n\n## Memories about the user:\n\n${memories.taken.map(memoryLine).join('\n')}`.trim()
+    : input.systemPrompt
+  const message = knowledge.taken.length
+    ? REFERENCE_PROMPT.replace('{question}', input.message).replace(
+        '{references}',
+        `\`\`\`json\n[\n${knowledge.taken.map(({ text }) => text).join(',\n')}\n]\n\`\`\``
+      )
+    : input.message
+
+  return {
+    system,
+    messages: [
+      ...kept.map(({ role, content }) => ({ role, content })),
+      { role: 'user', content: message }
+    ],
+    report: {
+      budget: input.maxTokens,
+      total: count(system) + historyTokens + count(message),
+      system: systemTokens,
+      message: messageTokens,
+      memories: memories.report,
+      knowledge: knowledge.report,
+      history: { tokens: historyTokens, included: kept.length, dropped: input.history.length - kept.length },
+      overBudget: systemTokens + messageTokens > input.maxTokens
+    }
+  }
+}
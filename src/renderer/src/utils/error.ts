@@ -1,3 +1,4 @@
+import { ManagerError } from '@shared/ManagerError'
 import { t } from 'i18next'
 
 export function getErrorDetails(err: any, seen = new WeakSet()): any {
@@ -67,9 +68,20 @@ export function formatMessageError(error: any): Record<string, any> {
 }
 
 export function getErrorMessage(error: any): string {
+  const managerError = ManagerError.parse(error)
+  if (managerError) {
+    return managerError.message
+  }
   return error?.message || error?.toString() || ''
 }
 
+/**
+ * 按错误码本地化的错误标题，具体原因在 message 中
+ */
+export function getManagerErrorTitle(error: ManagerError): string {
+  return t(`error.manager.${error.code}`, { defaultValue: t('error.unknown') })
+}
+
 export const isAbortError = (error: any): boolean => {
   // 检查错误消息
   if (error?.message === 'Request was aborted.') {
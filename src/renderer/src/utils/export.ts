@@ -4,11 +4,18 @@ import i18n from '@renderer/i18n'
 import { getMessageTitle } from '@renderer/services/MessagesService'
 import store from '@renderer/store'
 import { setExportState } from '@renderer/store/runtime'
-import type { Topic } from '@renderer/types'
+import { FileTypes, type Topic, type UserTemplate } from '@renderer/types'
 import type { Message } from '@renderer/types/newMessage'
+import { getErrorMessage } from '@renderer/utils/error'
 import { removeSpecialCharactersForFileName } from '@renderer/utils/file'
 import { convertMathFormula, markdownToPlainText } from '@renderer/utils/markdown'
-import { getCitationContent, getMainTextContent, getThinkingContent } from '@renderer/utils/messageUtils/find'
+import {
+  findFileBlocks,
+  findImageBlocks,
+  getCitationContent,
+  getMainTextContent,
+  getThinkingContent
+} from '@renderer/utils/messageUtils/find'
 import { markdownToBlocks } from '@tryfabric/martian'
 import dayjs from 'dayjs'
 import { appendBlocks } from 'notion-helper' // 引入 notion-helper 的 appendBlocks 函数
@@ -137,6 +144,40 @@ export const messageToPlainText = (message: Message): string => {
   return markdownToPlainText(content).trim()
 }
 
+/**
+ * 本地文件路径转换为 file:// 地址，兼容 Windows 路径
+ */
+const toFileUrl = (path: string) => {
+  const normalized = path.replace(/\\/g, '/')
+  return encodeURI(normalized.startsWith('/') ? `file://${normalized}` : `file:///${normalized}`)
+}
+
+/**
+ * 消息中图片附件的 Markdown 引用，替代文本使用生成图片的提示词或上传时的文件名
+ */
+const imagesToMarkdown = (message: Message) => {
+  const images = [
+    ...findImageBlocks(message).map((block) => ({
+      src: block.file ? toFileUrl(block.file.path) : block.url,
+      alt: block.metadata?.prompt || block.file?.origin_name
+    })),
+    ...findFileBlocks(message)
+      .filter((block) => block.file.type === FileTypes.IMAGE)
+      .map((block) => ({ src: toFileUrl(block.file.path), alt: block.file.origin_name }))
+  ]
+  return images
+    .filter((image) => image.src)
+    .map((image) => `![${(image.alt || '').replace(/[[\]\n]/g, ' ')}](<${image.src}>)`)
+    .join('\n\n')
+}
+
+/**
+ * 导出 Word 使用的 Markdown，图片附件会嵌入文档并带有替代文本
+ */
+const messageToWordMarkdown = (message: Message) => {
+  return [messageToMarkdown(message), imagesToMarkdown(message)].filter(Boolean).join('\n\n')
+}
+
 const messagesToPlainText = (messages: Message[]): string => {
   return messages.map(formatMessageAsPlainText).join('\n\n')
 }
@@ -196,6 +237,50 @@ export const exportTopicAsMarkdown = async (topic: Topic, exportReasoning?: bool
   }
 }
 
+/**
+ * 用用户模板渲染话题后导出，模板上下文包含话题、消息和导出时间
+ */
+export const exportTopicWithTemplate = async (topic: Topic, template: UserTemplate) => {
+  try {
+    const topicMessages = await db.topics.get(topic.id)
+    const context = {
+      topic: { id: topic.id, name: topic.name, createdAt: topic.createdAt, updatedAt: topic.updatedAt },
+      messages: (topicMessages?.messages ?? []).map((message) => ({
+        id: message.id,
+        role: message.role,
+        content: getMainTextContent(message),
+        reasoning: getThinkingContent(message),
+        model: message.model?.name,
+        createdAt: message.createdAt
+      })),
+      exportedAt: dayjs().toISOString()
+    }
+    const content = await window.api.template.render(template.id, context)
+    const fileName = removeSpecialCharactersForFileName(topic.name) + '.md'
+    const result = await window.api.file.save(fileName, content)
+    if (result) {
+      window.message.success({ content: i18n.t('message.success.template.export'), key: 'template-export' })
+    }
+  } catch (error: any) {
+    window.message.error({
+      content: i18n.t('message.error.template.export', { message: getErrorMessage(error) }),
+      key: 'template-export'
+    })
+  }
+}
+
+export const exportTopicAsWord = async (topic: Topic) => {
+  const topicMessages = await db.topics.get(topic.id)
+  const messages = (topicMessages?.messages ?? []).map(messageToWordMarkdown).join('\n\n---\n\n')
+  const markdown = `# ${topic.name}\n\n${messages}`
+  window.api.export.toWord(markdown, removeSpecialCharactersForFileName(topic.name), { title: topic.name })
+}
+
+export const exportMessageAsWord = async (message: Message) => {
+  const title = await getMessageTitle(message)
+  window.api.export.toWord(messageToWordMarkdown(message), title, { title })
+}
+
 export const exportMessageAsMarkdown = async (message: Message, exportReasoning?: boolean) => {
   const { markdownExportPath } = store.getState().settings
   if (!markdownExportPath) {
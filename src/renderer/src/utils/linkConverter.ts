@@ -345,6 +345,26 @@ export function extractUrlsFromMarkdown(text: string): string[] {
   return Array.from(urlSet)
 }
 
+/**
+ * 提取独占一行的网页链接（粘贴的链接），用于显示链接预览卡片
+ * @param {string} text 文本
+ * @param {number} limit 最多返回的数量
+ * @returns {string[]} 去重后的 http(s) 链接
+ */
+export function extractStandaloneUrls(text: string, limit: number = 3): string[] {
+  const urlSet = new Set<string>()
+
+  for (const line of text.split('\n')) {
+    const url = line.trim().replace(/^<(.+)>$/, '$1')
+    if (/^https?:\/\/\S+$/i.test(url) && isValidUrl(url)) {
+      urlSet.add(url)
+      if (urlSet.size >= limit) break
+    }
+  }
+
+  return Array.from(urlSet)
+}
+
 /**
  * 验证字符串是否是有效的URL
  * @param {string} url 要验证的URL字符串
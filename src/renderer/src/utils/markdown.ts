@@ -149,3 +149,27 @@ export const markdownToPlainText = (markdown: string): string => {
   // 直接用 remove-markdown 库，使用默认的 removeMarkdown 参数
   return removeMarkdown(markdown)
 }
+
+/**
+ * 将代码包裹为 Markdown 代码块，代码中包含反引号时使用更长的围栏
+ * @param code 代码
+ * @param language 代码语言，可选
+ * @returns Markdown 代码块
+ */
+export function toCodeBlock(code: string, language = ''): string {
+  const longest = Math.max(2, ...(code.match(/`+/g) ?? []).map((ticks) => ticks.length))
+  const fence = '`'.repeat(longest + 1)
+  return `${fence}${language}\n${code.replace(/\n+$/, '')}\n${fence}`
+}
+
+/**
+ * 将表格行转换为 Markdown 表格，第一行为表头
+ * @param rows 表格行
+ * @returns Markdown 表格
+ */
+export function toMarkdownTable(rows: string[][]): string {
+  const cell = (value: string) => value.replace(/\|/g, '\\|').replace(/\r?\n/g, '<br>').trim()
+  const line = (row: string[]) => `| ${row.map(cell).join(' | ')} |`
+  const [header, ...body] = rows
+  return [line(header), line(header.map(() => '---')), ...body.map(line)].join('\n')
+}
@@ -2,6 +2,7 @@ import { ContentBlockParam, MessageParam, ToolUnion, ToolUseBlock } from '@anthr
 import { Content, FunctionCall, Part, Tool, Type as GeminiSchemaType } from '@google/genai'
 import Logger from '@renderer/config/logger'
 import { isFunctionCallingModel, isVisionModel } from '@renderer/config/models'
+import { getConversationWorkspaceServerById } from '@renderer/services/ConversationWorkspaceService'
 import store from '@renderer/store'
 import { addMCPServer } from '@renderer/store/mcp'
 import {
@@ -11,6 +12,7 @@ import {
   MCPTool,
   MCPToolResponse,
   Model,
+  ToolCallContext,
   ToolUseResponse
 } from '@renderer/types'
 import type { MCPToolCompleteChunk, MCPToolInProgressChunk } from '@renderer/types/chunk'
@@ -265,7 +267,10 @@ export function openAIToolsToMcpTool(
   return tool
 }
 
-export async function callMCPTool(toolResponse: MCPToolResponse): Promise<MCPCallToolResponse> {
+export async function callMCPTool(
+  toolResponse: MCPToolResponse,
+  context?: ToolCallContext
+): Promise<MCPCallToolResponse> {
   Logger.log(`[MCP] Calling Tool: ${toolResponse.tool.serverName} ${toolResponse.tool.name}`, toolResponse.tool)
   try {
     const server = getMcpServerByTool(toolResponse.tool)
@@ -277,7 +282,8 @@ export async function callMCPTool(toolResponse: MCPToolResponse): Promise<MCPCal
     const resp = await window.api.mcp.callTool({
       server,
       name: toolResponse.tool.name,
-      args: toolResponse.arguments
+      args: toolResponse.arguments,
+      context
     })
     if (toolResponse.tool.serverName === MCP_AUTO_INSTALL_SERVER_NAME) {
       if (resp.data) {
@@ -437,7 +443,7 @@ export function filterMCPTools(
 
 export function getMcpServerByTool(tool: MCPTool) {
   const servers = store.getState().mcp.servers
-  return servers.find((s) => s.id === tool.serverId)
+  return servers.find((s) => s.id === tool.serverId) ?? getConversationWorkspaceServerById(tool.serverId)
 }
 
 export function parseToolUse(content: string, mcpTools: MCPTool[]): ToolUseResponse[] {
@@ -503,7 +509,8 @@ export async function parseAndCallTools<R>(
   onChunk: CompletionsParams['onChunk'],
   convertToMessage: (mcpToolResponse: MCPToolResponse, resp: MCPCallToolResponse, model: Model) => R | undefined,
   model: Model,
-  mcpTools?: MCPTool[]
+  mcpTools?: MCPTool[],
+  context?: ToolCallContext
 ): Promise<SdkMessageParam[]>
 
 export async function parseAndCallTools<R>(
@@ -512,7 +519,8 @@ export async function parseAndCallTools<R>(
   onChunk: CompletionsParams['onChunk'],
   convertToMessage: (mcpToolResponse: MCPToolResponse, resp: MCPCallToolResponse, model: Model) => R | undefined,
   model: Model,
-  mcpTools?: MCPTool[]
+  mcpTools?: MCPTool[],
+  context?: ToolCallContext
 ): Promise<SdkMessageParam[]>
 
 export async function parseAndCallTools<R>(
@@ -521,7 +529,8 @@ export async function parseAndCallTools<R>(
   onChunk: CompletionsParams['onChunk'],
   convertToMessage: (mcpToolResponse: MCPToolResponse, resp: MCPCallToolResponse, model: Model) => R | undefined,
   model: Model,
-  mcpTools?: MCPTool[]
+  mcpTools?: MCPTool[],
+  context?: ToolCallContext
 ): Promise<R[]> {
   const toolResults: R[] = []
   let curToolResponses: MCPToolResponse[] = []
@@ -548,7 +557,7 @@ export async function parseAndCallTools<R>(
 
   const toolPromises = curToolResponses.map(async (toolResponse) => {
     const images: string[] = []
-    const toolCallResponse = await callMCPTool(toolResponse)
+    const toolCallResponse = await callMCPTool(toolResponse, context)
     upsertMCPToolResponse(
       allToolResponses,
       {
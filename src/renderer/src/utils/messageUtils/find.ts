@@ -118,6 +118,21 @@ export const getMainTextContent = (message: Message): string => {
   return textBlocks.map((block) => block.content).join('\n\n')
 }
 
+/**
+ * Same as getMainTextContent, with the blocks looked up in `blocks` instead of the store.
+ * For messages read from the database without loading them into the store.
+ * @param message - The message object.
+ * @param blocks - Blocks of the message by id.
+ * @returns The concatenated content string or an empty string if no main text blocks are found.
+ */
+export const getMainTextContentFromBlocks = (message: Message, blocks: Map<string, MessageBlock>): string => {
+  return (message.blocks ?? [])
+    .map((blockId) => blocks.get(blockId))
+    .filter((block): block is MainTextMessageBlock => block?.type === MessageBlockType.MAIN_TEXT)
+    .map((block) => block.content)
+    .join('\n\n')
+}
+
 /**
  * Gets the concatenated content string from all ThinkingMessageBlocks of a message, in order.
  * @param message
@@ -154,6 +154,15 @@ export const buildSystemPrompt = async (
   assistant?: Assistant
 ): Promise<string> => {
   if (typeof userSystemPrompt === 'string') {
+    // 先展开引用的用户模板，模板里的 {{date}} 等变量随后一起替换
+    if (userSystemPrompt.includes('{{>')) {
+      try {
+        userSystemPrompt = await window.api.template.expandIncludes(userSystemPrompt)
+      } catch (error) {
+        console.error('Failed to expand template includes:', error)
+      }
+    }
+
     const now = new Date()
     if (userSystemPrompt.includes('{{date}}')) {
       const date = now.toLocaleDateString()
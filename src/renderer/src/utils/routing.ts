@@ -0,0 +1,163 @@
+import { Model } from '@renderer/types'
+
+import { detectLanguageByUnicode } from './translate'
+
+export type RoutingLanguage = 'zh' | 'ja' | 'ko' | 'ru' | 'ar' | 'en'
+
+export const ROUTING_LANGUAGES: RoutingLanguage[] = ['zh', 'ja', 'ko', 'ru', 'ar', 'en']
+
+/**
+ * 规则的条件，未设置的条件不参与判断
+ */
+export interface RoutingConditions {
+  // 消息字符数
+  minLength?: number
+  maxLength?: number
+  language?: RoutingLanguage
+  hasImages?: boolean
+  hasCode?: boolean
+  // 按目标模型的输入价格估算的单次请求费用上限，模型没有设置价格时按 0 计算
+  maxCost?: number
+}
+
+export interface RoutingRule {
+  id: string
+  name: string
+  enabled: boolean
+  conditions: RoutingConditions
+  // 命中后使用的模型
+  model?: Model
+}
+
+export interface RoutingRequest {
+  text: string
+  images: number
+  // 预估的输入 token 数
+  tokens: number
+}
+
+export type RoutingConditionName = 'min_length' | 'max_length' | 'language' | 'images' | 'code' | 'cost' | 'model'
+
+export interface RoutingCheck {
+  condition: RoutingConditionName
+  passed: boolean
+  // 请求的实际值
+  actual: string | number | boolean
+  // 规则要求的值
+  expected: string | number | boolean
+}
+
+export interface RoutingRuleExplanation {
+  ruleId: string
+  name: string
+  matched: boolean
+  checks: RoutingCheck[]
+}
+
+export interface RoutingDecision {
+  // 未命中任何规则时为空，使用助手的模型
+  model?: Model
+  rule?: Pick<RoutingRule, 'id' | 'name'>
+  explanations: RoutingRuleExplanation[]
+}
+
+const CODE_PATTERN = /```|^\s*(?:import|export|function|def|class|const|let|var|public|private|#include|SELECT)\s/im
+
+/**
+ * 判断文本中是否包含代码，只识别代码块和常见语句开头
+ */
+export function hasCode(text: string): boolean {
+  return CODE_PATTERN.test(text)
+}
+
+/**
+ * 按模型的输入价格估算请求费用，保留 6 位小数
+ */
+export function estimateRoutingCost(tokens: number, model?: Model): number {
+  const price = model?.pricing?.input_per_million_tokens ?? 0
+  return Math.round(((tokens * price) / 1e6) * 1e6) / 1e6
+}
+
+/**
+ * 逐条检查规则的条件
+ * @param {RoutingRule} rule 路由规则
+ * @param {RoutingRequest} request 请求
+ * @returns {RoutingCheck[]} 每个已设置条件的检查结果
+ */
+export function checkRoutingRule(rule: RoutingRule, request: RoutingRequest): RoutingCheck[] {
+  const { conditions } = rule
+  const length = request.text.length
+  const checks: RoutingCheck[] = []
+
+  if (conditions.minLength !== undefined) {
+    checks.push({
+      condition: 'min_length',
+      passed: length >= conditions.minLength,
+      actual: length,
+      expected: conditions.minLength
+    })
+  }
+  if (conditions.maxLength !== undefined) {
+    checks.push({
+      condition: 'max_length',
+      passed: length <= conditions.maxLength,
+      actual: length,
+      expected: conditions.maxLength
+    })
+  }
+  if (conditions.language) {
+    const language = detectLanguageByUnicode(request.text)
+    checks.push({
+      condition: 'language',
+      passed: language === conditions.language,
+      actual: language,
+      expected: conditions.language
+    })
+  }
+  if (conditions.hasImages !== undefined) {
+    const images = request.images > 0
+    checks.push({
+      condition: 'images',
+      passed: images === conditions.hasImages,
+      actual: images,
+      expected: conditions.hasImages
+    })
+  }
+  if (conditions.hasCode !== undefined) {
+    const code = hasCode(request.text)
+    checks.push({ condition: 'code', passed: code === conditions.hasCode, actual: code, expected: conditions.hasCode })
+  }
+  if (conditions.maxCost !== undefined) {
+    const cost = estimateRoutingCost(request.tokens, rule.model)
+    checks.push({ condition: 'cost', passed: cost <= conditions.maxCost, actual: cost, expected: conditions.maxCost })
+  }
+  checks.push({ condition: 'model', passed: !!rule.model, actual: rule.model?.name ?? '', expected: true })
+
+  return checks
+}
+
+/**
+ * 按顺序评估路由规则，使用第一条所有条件都满足的规则
+ * 停用的规则不参与评估，其余规则都会给出说明，便于了解为什么选中或没有选中
+ * @param {RoutingRequest} request 请求
+ * @param {RoutingRule[]} rules 路由规则
+ * @returns {RoutingDecision} 选中的模型和每条规则的说明
+ */
+export function evaluateRoutingRules(request: RoutingRequest, rules: RoutingRule[]): RoutingDecision {
+  const decision: RoutingDecision = { explanations: [] }
+
+  for (const rule of rules) {
+    if (!rule.enabled) continue
+
+    const checks = checkRoutingRule(rule, request)
+    const matched = !decision.rule && checks.every((check) => check.passed)
+    decision.explanations.push({ ruleId: rule.id, name: rule.name, matched, checks })
+
+    if (matched) {
+      decision.model = rule.model
+      decision.rule = { id: rule.id, name: rule.name }
+    }
+  }
+
+  return decision
+}
@@ -0,0 +1,53 @@
+import { StatsCounter, StatsMetric } from '@renderer/types'
+import dayjs from 'dayjs'
+
+export type StatsGranularity = 'day' | 'week' | 'month'
+
+export interface StatsBucket {
+  // 周期起始日期，YYYY-MM-DD
+  period: string
+  metrics: Partial<Record<StatsMetric, Record<string, number>>>
+}
+
+export function getStatsDate(time: number | string | Date = Date.now()): string {
+  return dayjs(time).format('YYYY-MM-DD')
+}
+
+export function getStatsCounterId(date: string, metric: StatsMetric, key: string): string {
+  return `${date}|${metric}|${key}`
+}
+
+/**
+ * 获取日期所在周期的起始日期，周从周一开始
+ */
+export function getStatsPeriod(date: string, granularity: StatsGranularity): string {
+  const day = dayjs(date)
+  switch (granularity) {
+    case 'week':
+      return day.subtract((day.day() + 6) % 7, 'day').format('YYYY-MM-DD')
+    case 'month':
+      return day.startOf('month').format('YYYY-MM-DD')
+    default:
+      return day.format('YYYY-MM-DD')
+  }
+}
+
+/**
+ * 把每日计数器按周期汇总，结果按周期升序排列
+ */
+export function aggregateStats(counters: StatsCounter[], granularity: StatsGranularity): StatsBucket[] {
+  const buckets = new Map<string, StatsBucket>()
+
+  for (const counter of counters) {
+    const period = getStatsPeriod(counter.date, granularity)
+    let bucket = buckets.get(period)
+    if (!bucket) {
+      bucket = { period, metrics: {} }
+      buckets.set(period, bucket)
+    }
+    const values = (bucket.metrics[counter.metric] ??= {})
+    values[counter.key] = (values[counter.key] ?? 0) + counter.value
+  }
+
+  return [...buckets.values()].sort((a, b) => a.period.localeCompare(b.period))
+}
@@ -0,0 +1,141 @@
+export type JSONSchema = Record<string, any>
+
+export interface StructuredValidationResult<T = any> {
+  valid: boolean
+  data?: T
+  errors: string[]
+}
+
+/**
+ * 从模型输出中提取 JSON 文本，兼容 ```json 代码块和前后多余的说明文字
+ * @param {string} text 模型输出
+ * @returns {string} JSON 文本
+ */
+export function extractJSONText(text: string): string {
+  const fenced = text.match(/```(?:json)?\s*\n?([\s\S]*?)```/i)
+  if (fenced) {
+    return fenced[1].trim()
+  }
+
+  const trimmed = text.trim()
+  const start = trimmed.search(/[[{]/)
+  if (start < 0) return trimmed
+
+  const end = Math.max(trimmed.lastIndexOf('}'), trimmed.lastIndexOf(']'))
+  return end > start ? trimmed.slice(start, end + 1) : trimmed
+}
+
+function typeOf(value: any): string {
+  if (value === null) return 'null'
+  if (Array.isArray(value)) return 'array'
+  if (typeof value === 'number' && Number.isInteger(value)) return 'integer'
+  return typeof value
+}
+
+function matchesType(value: any, type: string): boolean {
+  const actual = typeOf(value)
+  return actual === type || (type === 'number' && actual === 'integer')
+}
+
+function validateValue(value: any, schema: JSONSchema, path: string, errors: string[]) {
+  if (!schema || typeof schema !== 'object') return
+
+  if (schema.type) {
+    const types: string[] = Array.isArray(schema.type) ? schema.type : [schema.type]
+    if (!types.some((type) => matchesType(value, type))) {
+      errors.push(`${path}: expected ${types.join(' | ')}, got ${typeOf(value)}`)
+      return
+    }
+  }
+
+  if (schema.const !== undefined && value !== schema.const) {
+    errors.push(`${path}: must be ${JSON.stringify(schema.const)}`)
+  }
+
+  if (schema.enum && !schema.enum.includes(value)) {
+    errors.push(`${path}: must be one of ${schema.enum.map((item: any) => JSON.stringify(item)).join(', ')}`)
+  }
+
+  if (schema.anyOf || schema.oneOf) {
+    const variants: JSONSchema[] = schema.anyOf || schema.oneOf
+    const matched = variants.filter((variant) => {
+      const variantErrors: string[] = []
+      validateValue(value, variant, path, variantErrors)
+      return variantErrors.length === 0
+    }).length
+    if (matched === 0 || (schema.oneOf && matched > 1)) {
+      errors.push(`${path}: does not match ${schema.oneOf ? 'exactly one' : 'any'} of the allowed schemas`)
+    }
+  }
+
+  if (typeof value === 'string') {
+    if (schema.minLength !== undefined && value.length < schema.minLength) {
+      errors.push(`${path}: shorter than ${schema.minLength} characters`)
+    }
+    if (schema.maxLength !== undefined && value.length > schema.maxLength) {
+      errors.push(`${path}: longer than ${schema.maxLength} characters`)
+    }
+    if (schema.pattern && !new RegExp(schema.pattern).test(value)) {
+      errors.push(`${path}: does not match pattern ${schema.pattern}`)
+    }
+  }
+
+  if (typeof value === 'number') {
+    if (schema.minimum !== undefined && value < schema.minimum) {
+      errors.push(`${path}: less than ${schema.minimum}`)
+    }
+    if (schema.maximum !== undefined && value > schema.maximum) {
+      errors.push(`${path}: greater than ${schema.maximum}`)
+    }
+  }
+
+  if (Array.isArray(value)) {
+    if (schema.minItems !== undefined && value.length < schema.minItems) {
+      errors.push(`${path}: fewer than ${schema.minItems} items`)
+    }
+    if (schema.maxItems !== undefined && value.length > schema.maxItems) {
+      errors.push(`${path}: more than ${schema.maxItems} items`)
+    }
+    if (schema.items) {
+      value.forEach((item, index) => validateValue(item, schema.items, `${path}[${index}]`, errors))
+    }
+  }
+
+  if (typeOf(value) === 'object') {
+    const properties: Record<string, JSONSchema> = schema.properties || {}
+    for (const key of schema.required || []) {
+      if (!(key in value)) {
+        errors.push(`${path}.${key}: is required`)
+      }
+    }
+    for (const [key, propertyValue] of Object.entries(value)) {
+      if (properties[key]) {
+        validateValue(propertyValue, properties[key], `${path}.${key}`, errors)
+      } else if (schema.additionalProperties === false) {
+        errors.push(`${path}.${key}: is not allowed`)
+      } else if (typeof schema.additionalProperties === 'object') {
+        validateValue(propertyValue, schema.additionalProperties, `${path}.${key}`, errors)
+      }
+    }
+  }
+}
+
+/**
+ * 按 JSON Schema 校验模型的结构化输出
+ * 支持常用的关键字：type、enum、const、anyOf、oneOf、properties、required、additionalProperties、items 以及长度和范围限制
+ * @param {JSONSchema} schema JSON Schema
+ * @param {string} text 模型输出
+ * @returns {StructuredValidationResult} 校验结果，成功时 data 为解析后的对象
+ */
+export function validateStructuredResponse<T = any>(schema: JSONSchema, text: string): StructuredValidationResult<T> {
+  let data: any
+  try {
+    data = JSON.parse(extractJSONText(text))
+  } catch (e) {
+    return { valid: false, errors: [`$: invalid JSON (${(e as Error).message})`] }
+  }
+
+  const errors: string[] = []
+  validateValue(data, schema, '$', errors)
+  return errors.length === 0 ? { valid: true, data, errors } : { valid: false, errors }
+}
@@ -0,0 +1,162 @@
+import { ContentGuardFinding, ContentGuardPattern, DEFAULT_CONTENT_GUARD_PATTERNS, redactContent } from './contentGuard'
+
+export type TrainingDataFormat = 'openai' | 'sharegpt'
+
+export type TrainingDataRole = 'system' | 'user' | 'assistant'
+
+export type PiiRedaction = 'email' | 'phone' | 'ip_address' | 'credit_card' | 'private_key' | 'secret'
+
+export interface TrainingConversation {
+  system?: string
+  // 按时间顺序，最早的在前
+  messages: { role: 'user' | 'assistant'; content: string }[]
+}
+
+export interface TrainingDataOptions {
+  format: TrainingDataFormat
+  includeSystemPrompt: boolean
+  // 自定义输出的角色名，未设置时使用格式的默认角色名
+  roleNames?: Partial<Record<TrainingDataRole, string>>
+  redactions: PiiRedaction[]
+  // redactions 包含 secret 时替换的已保存密钥
+  secrets?: string[]
+}
+
+export interface TrainingDataResult {
+  content: string
+  count: number
+  // 没有完整问答的对话不会导出
+  skipped: number
+  findings: ContentGuardFinding[]
+}
+
+export const PII_REDACTIONS: PiiRedaction[] = ['email', 'phone', 'ip_address', 'credit_card', 'private_key', 'secret']
+
+// 信用卡号先于电话号码替换，避免卡号被当作电话号码只替换一部分
+const PII_PATTERNS: ContentGuardPattern[] = [
+  ...DEFAULT_CONTENT_GUARD_PATTERNS,
+  { name: 'email', pattern: '[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\\.[A-Za-z]{2,}' },
+  { name: 'phone', pattern: '(?<![\\w+])\\+?\\d{1,3}[ -]?\\(?\\d{2,4}\\)?[ -]?\\d{3,4}[ -]?\\d{3,4}(?!\\w)' },
+  { name: 'ip_address', pattern: '\\b(?:(?:25[0-5]|2[0-4]\\d|1?\\d?\\d)\\.){3}(?:25[0-5]|2[0-4]\\d|1?\\d?\\d)\\b' }
+]
+
+const DEFAULT_ROLE_NAMES: Record<TrainingDataFormat, Record<TrainingDataRole, string>> = {
+  openai: { system: 'system', user: 'user', assistant: 'assistant' },
+  sharegpt: { system: 'system', user: 'human', assistant: 'gpt' }
+}
+
+// 导入时识别的角色名，包含两种格式常见的写法
+const IMPORT_ROLE_NAMES: Record<string, TrainingDataRole> = {
+  system: 'system',
+  user: 'user',
+  human: 'user',
+  assistant: 'assistant',
+  gpt: 'assistant',
+  chatgpt: 'assistant',
+  model: 'assistant'
+}
+
+/**
+ * 整理对话用于训练：去掉空消息，合并连续的同角色消息，
+ * 从第一条提问开始，到最后一条回答结束
+ */
+export function normalizeTrainingConversation(conversation: TrainingConversation): TrainingConversation['messages'] {
+  const messages: TrainingConversation['messages'] = []
+  for (const message of conversation.messages) {
+    const content = message.content.trim()
+    if (!content) continue
+    const last = messages[messages.length - 1]
+    if (last?.role === message.role) {
+      last.content += `\n\n${content}`
+    } else if (messages.length || message.role === 'user') {
+      messages.push({ role: message.role, content })
+    }
+  }
+  while (messages.length && messages[messages.length - 1].role !== 'assistant') messages.pop()
+  return messages
+}
+
+/**
+ * 转换为一条训练记录，没有完整问答时返回 null
+ */
+export function toTrainingRecord(conversation: TrainingConversation, options: TrainingDataOptions) {
+  const messages = normalizeTrainingConversation(conversation)
+  if (!messages.length) return null
+
+  const roleNames = { ...DEFAULT_ROLE_NAMES[options.format], ...options.roleNames }
+  const system = options.includeSystemPrompt ? conversation.system?.trim() : ''
+  const turns = [...(system ? [{ role: 'system' as const, content: system }] : []), ...messages]
+
+  if (options.format === 'sharegpt') {
+    return { conversations: turns.map(({ role, content }) => ({ from: roleNames[role], value: content })) }
+  }
+  return { messages: turns.map(({ role, content }) => ({ role: roleNames[role], content })) }
+}
+
+/**
+ * 解析一条 OpenAI 或 ShareGPT 格式的训练记录，无法识别时返回 null
+ */
+export function fromTrainingRecord(record: unknown): TrainingConversation | null {
+  const turns: unknown = (record as any)?.messages ?? (record as any)?.conversations
+  if (!Array.isArray(turns)) return null
+
+  const conversation: TrainingConversation = { messages: [] }
+  for (const turn of turns) {
+    const name = turn?.role ?? turn?.from
+    const content = turn?.content ?? turn?.value
+    const role = typeof name === 'string' ? IMPORT_ROLE_NAMES[name.toLowerCase()] : undefined
+    if (!role || typeof content !== 'string') return null
+
+    if (role === 'system') {
+      conversation.system = conversation.system ? `${conversation.system}\n${content}` : content
+    } else {
+      conversation.messages.push({ role, content })
+    }
+  }
+  return conversation.messages.length ? conversation : null
+}
+
+/**
+ * 解析 JSONL 训练数据，每行一条记录，空行忽略，无法识别的行计入 skipped
+ */
+export function parseTrainingJsonl(content: string): { conversations: TrainingConversation[]; skipped: number } {
+  const conversations: TrainingConversation[] = []
+  let skipped = 0
+  for (const line of content.split(/\r?\n/)) {
+    if (!line.trim()) continue
+    let conversation: TrainingConversation | null = null
+    try {
+      conversation = fromTrainingRecord(JSON.parse(line))
+    } catch {
+      // 不是合法的 JSON
+    }
+    if (conversation) {
+      conversations.push(conversation)
+    } else {
+      skipped++
+    }
+  }
+  return { conversations, skipped }
+}
+
+/**
+ * 把对话导出为 JSONL，每行一条训练记录，按选择的规则替换个人信息
+ */
+export function toTrainingJsonl(
+  conversations: TrainingConversation[],
+  options: TrainingDataOptions
+): TrainingDataResult {
+  const records = conversations.map((conversation) => toTrainingRecord(conversation, options))
+  const kept = records.filter((record) => record !== null)
+
+  const patterns = PII_PATTERNS.filter(({ name }) => options.redactions.includes(name as PiiRedaction))
+  const secrets = options.redactions.includes('secret') ? (options.secrets ?? []) : []
+  const { value, findings } = redactContent(kept, secrets, patterns)
+
+  return {
+    content: value.map((record) => JSON.stringify(record)).join('\n') + (value.length ? '\n' : ''),
+    count: value.length,
+    skipped: records.length - kept.length,
+    findings
+  }
+}
@@ -0,0 +1,87 @@
+import type { Topic } from '@renderer/types'
+import type { Message, MessageBlock } from '@renderer/types/newMessage'
+import { uuid } from '@renderer/utils'
+
+/**
+ * .metheuschat 对话文件：导出的话题、消息和消息块，打开后导入为新的只读话题
+ * 消息引用的附件不包含在文件中
+ */
+
+export const TRANSCRIPT_FORMAT = 'metheuschat'
+export const TRANSCRIPT_VERSION = 1
+
+export interface ConversationTranscript {
+  format: typeof TRANSCRIPT_FORMAT
+  version: number
+  exportedAt: string
+  topic: Pick<Topic, 'name' | 'createdAt' | 'updatedAt'>
+  messages: Message[]
+  blocks: MessageBlock[]
+}
+
+export function createTranscript(topic: Topic, messages: Message[], blocks: MessageBlock[]): ConversationTranscript {
+  return {
+    format: TRANSCRIPT_FORMAT,
+    version: TRANSCRIPT_VERSION,
+    exportedAt: new Date().toISOString(),
+    topic: { name: topic.name, createdAt: topic.createdAt, updatedAt: topic.updatedAt },
+    messages,
+    blocks
+  }
+}
+
+/**
+ * 解析对话文件，格式不正确或版本更新时抛出错误
+ */
+export function parseTranscript(content: string): ConversationTranscript {
+  const data = JSON.parse(content)
+  if (data?.format !== TRANSCRIPT_FORMAT) {
+    throw new Error('Not a conversation transcript')
+  }
+  if (typeof data.version !== 'number' || data.version > TRANSCRIPT_VERSION) {
+    throw new Error(`Unsupported transcript version: ${data.version}`)
+  }
+  if (typeof data.topic?.name !== 'string' || !Array.isArray(data.messages) || !Array.isArray(data.blocks)) {
+    throw new Error('Invalid conversation transcript')
+  }
+  return data
+}
+
+/**
+ * 对话文件转换为助手下的新话题，消息和消息块使用新的 id，可以重复导入同一个文件
+ */
+export function importTranscript(
+  transcript: ConversationTranscript,
+  assistantId: string
+): { topic: Topic; messages: Message[]; blocks: MessageBlock[] } {
+  const now = new Date().toISOString()
+  const topic: Topic = {
+    id: uuid(),
+    assistantId,
+    name: transcript.topic.name,
+    createdAt: transcript.topic.createdAt || now,
+    updatedAt: now,
+    messages: [],
+    isNameManuallyEdited: true,
+    readonly: true
+  }
+
+  const messageIds = new Map(transcript.messages.map((message) => [message.id, uuid()]))
+  const blockIds = new Map(transcript.blocks.map((block) => [block.id, uuid()]))
+
+  const messages = transcript.messages.map((message) => ({
+    ...message,
+    id: messageIds.get(message.id)!,
+    topicId: topic.id,
+    assistantId,
+    askId: message.askId ? messageIds.get(message.askId) : undefined,
+    blocks: message.blocks.flatMap((blockId) => blockIds.get(blockId) ?? [])
+  }))
+
+  const blocks = transcript.blocks.flatMap((block) => {
+    const messageId = messageIds.get(block.messageId)
+    return messageId ? [{ ...block, id: blockIds.get(block.id)!, messageId }] : []
+  })
+
+  return { topic, messages, blocks }
+}
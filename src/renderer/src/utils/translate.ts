@@ -228,3 +228,63 @@ export const createOutputScrollHandler = (
     handleScrollSync(e.currentTarget, inputEl, isProgrammaticScrollRef)
   }
 }
+
+/**
+ * 按句子切分文本，各片段拼接后与原文完全一致（保留空白和换行）
+ * @param text 待切分的文本
+ * @returns 句子片段
+ */
+export const splitSentences = (text: string): string[] => {
+  if (!text) return []
+  const segmenter = new Intl.Segmenter(undefined, { granularity: 'sentence' })
+  return Array.from(segmenter.segment(text), (item) => item.segment)
+}
+
+/**
+ * 拆出片段首尾的空白，翻译只针对中间的内容
+ * @param segment 句子片段
+ * @returns [前导空白, 内容, 尾随空白]
+ */
+export const splitSurroundingWhitespace = (segment: string): [string, string, string] => {
+  const match = segment.match(/^(\s*)([\s\S]*?)(\s*)$/)!
+  return [match[1], match[2], match[3]]
+}
+
+/**
+ * 按空行切分文档段落，用于批量翻译
+ * @param text 文档内容
+ * @returns 非空段落
+ */
+export const splitParagraphs = (text: string): string[] => {
+  return text
+    .split(/\n\s*\n/)
+    .map((paragraph) => paragraph.trim())
+    .filter(Boolean)
+}
+
+/**
+ * 转换为 DeepL 的 target_lang，如 en-us -> EN-US, zh-cn -> ZH-HANS
+ * @param langCode 语言代码
+ */
+export const toDeepLLanguage = (langCode: string): string => {
+  switch (langCode) {
+    case 'zh-cn':
+      return 'ZH-HANS'
+    case 'zh-tw':
+      return 'ZH-HANT'
+    case 'en-us':
+    case 'pt-pt':
+      return langCode.toUpperCase()
+    default:
+      return langCode.split('-')[0].toUpperCase()
+  }
+}
+
+/**
+ * 转换为 Google Translate 的 target，如 en-us -> en, zh-tw -> zh-TW
+ * @param langCode 语言代码
+ */
+export const toGoogleLanguage = (langCode: string): string => {
+  const [language, region] = langCode.split('-')
+  return language === 'zh' ? `zh-${region.toUpperCase()}` : language
+}
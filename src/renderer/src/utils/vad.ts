@@ -0,0 +1,158 @@
+export interface VadOptions {
+  sampleRate: number
+  // 每帧时长（毫秒）
+  frameMs?: number
+  // 能量超过噪声基线的倍数时判定为语音
+  threshold?: number
+  // 连续语音达到该时长才触发 speech-started，过滤按键、咳嗽等短噪声
+  minSpeechMs?: number
+  // 连续静音达到该时长才触发 speech-ended
+  hangoverMs?: number
+  // 语音段最大时长，超过时强制切分
+  maxSegmentMs?: number
+  // 语音开始前保留的音频，避免截掉第一个字
+  preRollMs?: number
+  // 开始时只用来估计噪声基线的时长
+  calibrationMs?: number
+}
+
+export type VadEvent =
+  | { type: 'speech-started'; time: number }
+  | { type: 'speech-ended'; time: number; segment: Float32Array }
+
+const MIN_NOISE_FLOOR = 1e-4
+
+/**
+ * 基于短时能量和自适应噪声基线的语音活动检测
+ * 输入单声道 PCM，按帧判断是否有语音，输出语音开始、结束事件以及完整的语音段
+ */
+export class VoiceActivityDetector {
+  private frameSize: number
+  private options: Required<VadOptions>
+  private noiseFloor = MIN_NOISE_FLOOR
+  private speaking = false
+  private speechFrames = 0
+  private silenceFrames = 0
+  private processedFrames = 0
+  private pending = new Float32Array(0)
+  private preRoll: Float32Array[] = []
+  private segment: Float32Array[] = []
+
+  constructor(options: VadOptions) {
+    this.options = {
+      frameMs: 30,
+      threshold: 3,
+      minSpeechMs: 150,
+      hangoverMs: 800,
+      maxSegmentMs: 30000,
+      preRollMs: 300,
+      calibrationMs: 300,
+      ...options
+    }
+    this.frameSize = Math.round((this.options.sampleRate * this.options.frameMs) / 1000)
+  }
+
+  /**
+   * 输入任意长度的采样，返回这批采样中产生的事件
+   */
+  public process(samples: Float32Array): VadEvent[] {
+    const buffer = new Float32Array(this.pending.length + samples.length)
+    buffer.set(this.pending)
+    buffer.set(samples, this.pending.length)
+
+    const events: VadEvent[] = []
+    let offset = 0
+    for (; offset + this.frameSize <= buffer.length; offset += this.frameSize) {
+      const event = this.processFrame(buffer.slice(offset, offset + this.frameSize))
+      if (event) events.push(event)
+    }
+    this.pending = buffer.slice(offset)
+    return events
+  }
+
+  /**
+   * 结束输入，正在进行的语音段立即结束
+   */
+  public flush(): VadEvent | undefined {
+    this.pending = new Float32Array(0)
+    return this.speaking ? this.endSegment() : undefined
+  }
+
+  public reset() {
+    this.noiseFloor = MIN_NOISE_FLOOR
+    this.speaking = false
+    this.speechFrames = 0
+    this.silenceFrames = 0
+    this.processedFrames = 0
+    this.pending = new Float32Array(0)
+    this.preRoll = []
+    this.segment = []
+  }
+
+  private processFrame(frame: Float32Array): VadEvent | undefined {
+    this.processedFrames++
+    const energy = Math.sqrt(frame.reduce((sum, sample) => sum + sample * sample, 0) / frame.length)
+
+    // 校准阶段取能量的累计平均作为噪声基线
+    if (this.processedFrames <= this.framesOf(this.options.calibrationMs)) {
+      this.noiseFloor = Math.max(MIN_NOISE_FLOOR, this.noiseFloor + (energy - this.noiseFloor) / this.processedFrames)
+      return undefined
+    }
+
+    const isSpeech = energy > this.noiseFloor * this.options.threshold
+
+    // 静音时跟踪噪声基线，语音时基线只缓慢上升，避免把持续的语音当成噪声
+    const rate = isSpeech ? 0.001 : 0.05
+    this.noiseFloor = Math.max(MIN_NOISE_FLOOR, this.noiseFloor + (energy - this.noiseFloor) * rate)
+
+    if (!this.speaking) {
+      this.preRoll.push(frame)
+      if (this.preRoll.length > this.framesOf(this.options.preRollMs) + this.framesOf(this.options.minSpeechMs)) {
+        this.preRoll.shift()
+      }
+      this.speechFrames = isSpeech ? this.speechFrames + 1 : 0
+      if (this.speechFrames >= this.framesOf(this.options.minSpeechMs)) {
+        this.speaking = true
+        this.silenceFrames = 0
+        this.segment = this.preRoll
+        this.preRoll = []
+        return { type: 'speech-started', time: this.currentTime(this.speechFrames) }
+      }
+      return undefined
+    }
+
+    this.segment.push(frame)
+    this.silenceFrames = isSpeech ? 0 : this.silenceFrames + 1
+    if (
+      this.silenceFrames >= this.framesOf(this.options.hangoverMs) ||
+      this.segment.length >= this.framesOf(this.options.maxSegmentMs)
+    ) {
+      return this.endSegment()
+    }
+    return undefined
+  }
+
+  private endSegment(): VadEvent {
+    const segment = new Float32Array(this.segment.reduce((length, frame) => length + frame.length, 0))
+    let offset = 0
+    for (const frame of this.segment) {
+      segment.set(frame, offset)
+      offset += frame.length
+    }
+
+    this.speaking = false
+    this.speechFrames = 0
+    this.silenceFrames = 0
+    this.segment = []
+    return { type: 'speech-ended', time: this.currentTime(), segment }
+  }
+
+  private framesOf(ms: number) {
+    return Math.max(1, Math.round(ms / this.options.frameMs))
+  }
+
+  // 当前帧结束的时间（毫秒），可减去若干帧得到更早的时间
+  private currentTime(framesAgo = 0) {
+    return (this.processedFrames - framesAgo) * this.options.frameMs
+  }
+}
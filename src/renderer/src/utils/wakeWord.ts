@@ -0,0 +1,148 @@
+import type { WakeWordModel } from '@renderer/types'
+
+const FRAME_MS = 25
+const HOP_MS = 10
+const MEL_FILTERS = 26
+const MFCC_COEFFICIENTS = 13
+
+/**
+ * 计算语音的 MFCC 特征（每 10ms 一帧，13 维），并做倒谱均值归一化以减小麦克风和音量差异
+ */
+export function computeMfcc(samples: Float32Array, sampleRate: number): number[][] {
+  const frameSize = Math.round((sampleRate * FRAME_MS) / 1000)
+  const hop = Math.round((sampleRate * HOP_MS) / 1000)
+  let fftSize = 1
+  while (fftSize < frameSize) fftSize *= 2
+
+  const filters = melFilterbank(fftSize, sampleRate)
+  const window = Array.from({ length: frameSize }, (_, i) => {
+    return 0.54 - 0.46 * Math.cos((2 * Math.PI * i) / (frameSize - 1))
+  })
+  const features: number[][] = []
+
+  for (let start = 0; start + frameSize <= samples.length; start += hop) {
+    const re = new Float64Array(fftSize)
+    const im = new Float64Array(fftSize)
+    for (let i = 0; i < frameSize; i++) {
+      // 预加重
+      const previous = start + i > 0 ? samples[start + i - 1] : 0
+      re[i] = (samples[start + i] - 0.97 * previous) * window[i]
+    }
+    fft(re, im)
+
+    const power = Array.from({ length: fftSize / 2 + 1 }, (_, k) => (re[k] * re[k] + im[k] * im[k]) / fftSize)
+    const energies = filters.map((filter) =>
+      Math.log(Math.max(1e-10, filter.reduce((sum, weight, k) => sum + weight * power[k], 0)))
+    )
+    features.push(dct(energies))
+  }
+
+  if (!features.length) return features
+  const mean = Array.from({ length: MFCC_COEFFICIENTS }, (_, c) => {
+    return features.reduce((sum, frame) => sum + frame[c], 0) / features.length
+  })
+  return features.map((frame) => frame.map((value, c) => value - mean[c]))
+}
+
+/**
+ * 动态时间规整距离，按路径长度归一化，语速不同的同一个词距离较小
+ */
+export function dtwDistance(a: number[][], b: number[][]): number {
+  if (!a.length || !b.length) return Infinity
+
+  let previous = new Float64Array(b.length + 1).fill(Infinity)
+  previous[0] = 0
+  for (let i = 1; i <= a.length; i++) {
+    const current = new Float64Array(b.length + 1).fill(Infinity)
+    for (let j = 1; j <= b.length; j++) {
+      const cost = euclidean(a[i - 1], b[j - 1])
+      current[j] = cost + Math.min(previous[j], current[j - 1], previous[j - 1])
+    }
+    previous = current
+  }
+  return previous[b.length] / (a.length + b.length)
+}
+
+/**
+ * 录入样本之间的最大距离，作为检测阈值的基准
+ */
+export function getTemplateSpread(templates: number[][][]): number {
+  let spread = 0
+  for (let i = 0; i < templates.length; i++) {
+    for (let j = i + 1; j < templates.length; j++) {
+      spread = Math.max(spread, dtwDistance(templates[i], templates[j]))
+    }
+  }
+  return spread
+}
+
+/**
+ * 判断语音段是否为唤醒词，灵敏度 0～1，越高越容易触发
+ */
+export function matchWakeWord(features: number[][], model: WakeWordModel, sensitivity: number): boolean {
+  if (!features.length || !model.templates.length) return false
+  const threshold = model.spread * (0.8 + Math.min(1, Math.max(0, sensitivity)))
+  return Math.min(...model.templates.map((template) => dtwDistance(features, template))) <= threshold
+}
+
+function euclidean(a: number[], b: number[]) {
+  let sum = 0
+  for (let i = 0; i < a.length; i++) {
+    sum += (a[i] - b[i]) ** 2
+  }
+  return Math.sqrt(sum)
+}
+
+function melFilterbank(fftSize: number, sampleRate: number): number[][] {
+  const toMel = (hz: number) => 2595 * Math.log10(1 + hz / 700)
+  const toHz = (mel: number) => 700 * (10 ** (mel / 2595) - 1)
+  const maxMel = toMel(sampleRate / 2)
+  const bins = Array.from({ length: MEL_FILTERS + 2 }, (_, i) =>
+    Math.floor(((fftSize + 1) * toHz((maxMel * i) / (MEL_FILTERS + 1))) / sampleRate)
+  )
+
+  return Array.from({ length: MEL_FILTERS }, (_, m) => {
+    const filter = new Array(fftSize / 2 + 1).fill(0)
+    const [left, center, right] = [bins[m], bins[m + 1], bins[m + 2]]
+    for (let k = left; k < center; k++) filter[k] = (k - left) / Math.max(1, center - left)
+    for (let k = center; k < right; k++) filter[k] = (right - k) / Math.max(1, right - center)
+    return filter
+  })
+}
+
+function dct(values: number[]): number[] {
+  return Array.from({ length: MFCC_COEFFICIENTS }, (_, k) =>
+    values.reduce((sum, value, n) => sum + value * Math.cos((Math.PI * k * (n + 0.5)) / values.length), 0)
+  )
+}
+
+// 原地基 2 FFT
+function fft(re: Float64Array, im: Float64Array) {
+  const n = re.length
+  for (let i = 1, j = 0; i < n; i++) {
+    let bit = n >> 1
+    for (; j & bit; bit >>= 1) j ^= bit
+    j ^= bit
+    if (i < j) {
+      ;[re[i], re[j]] = [re[j], re[i]]
+      ;[im[i], im[j]] = [im[j], im[i]]
+    }
+  }
+  for (let size = 2; size <= n; size *= 2) {
+    const angle = (-2 * Math.PI) / size
+    for (let start = 0; start < n; start += size) {
+      for (let k = 0; k < size / 2; k++) {
+        const cos = Math.cos(angle * k)
+        const sin = Math.sin(angle * k)
+        const i = start + k
+        const j = i + size / 2
+        const tre = re[j] * cos - im[j] * sin
+        const tim = re[j] * sin + im[j] * cos
+        re[j] = re[i] - tre
+        im[j] = im[i] - tim
+        re[i] += tre
+        im[i] += tim
+      }
+    }
+  }
+}
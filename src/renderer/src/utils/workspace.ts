@@ -0,0 +1,44 @@
+import type { WorkspaceMergeStrategy } from '@renderer/types'
+import { omit } from 'lodash'
+
+// 凭据不随工作区分发，导入时保留本地的值
+export const WORKSPACE_EXCLUDED_SETTINGS = [
+  'webdavUser',
+  'webdavPass',
+  'notionApiKey',
+  'yuqueToken',
+  'joplinToken',
+  'siyuanToken'
+] as const
+
+export function stripWorkspaceSecrets<T extends Record<string, any>>(settings: T): Partial<T> {
+  return omit(settings, WORKSPACE_EXCLUDED_SETTINGS) as Partial<T>
+}
+
+/**
+ * 按 id 合并本地和导入的列表，保持本地顺序，新条目追加在末尾
+ */
+export function mergeById<T extends { id: string }>(
+  local: T[],
+  incoming: T[],
+  strategy: WorkspaceMergeStrategy
+): T[] {
+  if (strategy === 'replace') return incoming
+
+  const incomingById = new Map(incoming.map((item) => [item.id, item]))
+  const localIds = new Set(local.map((item) => item.id))
+  const merged = strategy === 'merge' ? local.map((item) => incomingById.get(item.id) ?? item) : local
+  return [...merged, ...incoming.filter((item) => !localIds.has(item.id))]
+}
+
+/**
+ * 合并设置，导入的设置不含凭据，本地凭据始终保留
+ */
+export function mergeWorkspaceSettings<T extends Record<string, any>>(
+  local: T,
+  incoming: Partial<T>,
+  strategy: WorkspaceMergeStrategy
+): T {
+  if (strategy === 'keep_existing') return local
+  return { ...local, ...stripWorkspaceSecrets(incoming) }
+}
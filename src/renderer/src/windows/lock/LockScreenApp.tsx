@@ -0,0 +1,97 @@
+import { useSettings } from '@renderer/hooks/useSettings'
+import i18n from '@renderer/i18n'
+import { defaultLanguage } from '@shared/config/constant'
+import { Button, Input } from 'antd'
+import { Fingerprint, Lock } from 'lucide-react'
+import { FC, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+const LockScreenApp: FC = () => {
+  const { language } = useSettings()
+  const { t } = useTranslation()
+
+  const [passphrase, setPassphrase] = useState('')
+  const [error, setError] = useState(false)
+  const [loading, setLoading] = useState(false)
+  const [canUseBiometric, setCanUseBiometric] = useState(false)
+
+  useEffect(() => {
+    i18n.changeLanguage(language || navigator.language || defaultLanguage)
+  }, [language])
+
+  useEffect(() => {
+    window.api.lock.getState().then((state) => setCanUseBiometric(state.canUseBiometric))
+  }, [])
+
+  const onUnlock = async () => {
+    setLoading(true)
+    const success = await window.api.lock.unlock(passphrase)
+    setLoading(false)
+    if (!success) {
+      setError(true)
+      setPassphrase('')
+    }
+  }
+
+  const onBiometricUnlock = async () => {
+    await window.api.lock.unlockWithBiometric()
+  }
+
+  return (
+    <Container>
+      <Lock size={40} />
+      <Title>{t('lock.title')}</Title>
+      <Input.Password
+        autoFocus
+        value={passphrase}
+        status={error ? 'error' : undefined}
+        placeholder={t('lock.passphrase_placeholder')}
+        onChange={(e) => {
+          setPassphrase(e.target.value)
+          setError(false)
+        }}
+        onPressEnter={onUnlock}
+      />
+      {error && <ErrorText>{t('lock.wrong_passphrase')}</ErrorText>}
+      <Button type="primary" block loading={loading} disabled={!passphrase} onClick={onUnlock}>
+        {t('lock.unlock')}
+      </Button>
+      {canUseBiometric && (
+        <Button block icon={<Fingerprint size={16} />} onClick={onBiometricUnlock}>
+          {t('lock.unlock_with_biometric')}
+        </Button>
+      )}
+    </Container>
+  )
+}
+
+const Container = styled.div`
+  display: flex;
+  flex-direction: column;
+  align-items: center;
+  justify-content: center;
+  gap: 12px;
+  height: 100%;
+  padding: 40px;
+  -webkit-app-region: drag;
+
+  input,
+  button {
+    -webkit-app-region: no-drag;
+  }
+`
+
+const Title = styled.div`
+  font-size: 16px;
+  font-weight: 500;
+  margin-bottom: 8px;
+`
+
+const ErrorText = styled.div`
+  width: 100%;
+  font-size: 12px;
+  color: var(--color-error);
+`
+
+export default LockScreenApp
@@ -0,0 +1,29 @@
+import '@renderer/assets/styles/index.scss'
+import '@ant-design/v5-patch-for-react-19'
+
+import AntdProvider from '@renderer/context/AntdProvider'
+import { ThemeProvider } from '@renderer/context/ThemeProvider'
+import store, { persistor } from '@renderer/store'
+import { FC } from 'react'
+import { createRoot } from 'react-dom/client'
+import { Provider } from 'react-redux'
+import { PersistGate } from 'redux-persist/integration/react'
+
+import LockScreenApp from './LockScreenApp'
+
+const App: FC = () => {
+  return (
+    <Provider store={store}>
+      <ThemeProvider>
+        <AntdProvider>
+          <PersistGate loading={null} persistor={persistor}>
+            <LockScreenApp />
+          </PersistGate>
+        </AntdProvider>
+      </ThemeProvider>
+    </Provider>
+  )
+}
+
+const root = createRoot(document.getElementById('root') as HTMLElement)
+root.render(<App />)
@@ -10,6 +10,7 @@ import { PersistGate } from 'redux-persist/integration/react'
 import AntdProvider from '../../context/AntdProvider'
 import { CodeStyleProvider } from '../../context/CodeStyleProvider'
 import { ThemeProvider } from '../../context/ThemeProvider'
+import AnnouncementHandler from '../../handler/AnnouncementHandler'
 import HomeWindow from './home/HomeWindow'
 
 // Inner component that uses the hook after Redux is initialized
@@ -45,6 +46,7 @@ function MiniWindow(): React.ReactElement {
           <CodeStyleProvider>
             <PersistGate loading={null} persistor={persistor}>
               {messageContextHolder}
+              <AnnouncementHandler />
               <MiniWindowContent />
             </PersistGate>
           </CodeStyleProvider>
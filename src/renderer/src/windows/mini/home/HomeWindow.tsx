@@ -4,8 +4,10 @@ import { useAssistant } from '@renderer/hooks/useAssistant'
 import { useSettings } from '@renderer/hooks/useSettings'
 import i18n from '@renderer/i18n'
 import { fetchChatCompletion } from '@renderer/services/ApiService'
+import { audioManager } from '@renderer/services/AudioManager'
 import { getDefaultTopic } from '@renderer/services/AssistantService'
 import { getAssistantMessage, getUserMessage } from '@renderer/services/MessagesService'
+import { transcribeSegment } from '@renderer/services/TranscriptionService'
 import store, { useAppSelector } from '@renderer/store'
 import { updateOneBlock, upsertManyBlocks, upsertOneBlock } from '@renderer/store/messageBlock'
 import { newMessagesActions, selectMessagesForTopic } from '@renderer/store/newMessage'
@@ -13,7 +15,7 @@ import { ThemeMode, Topic } from '@renderer/types'
 import { Chunk, ChunkType } from '@renderer/types/chunk'
 import { AssistantMessageStatus, MessageBlockStatus } from '@renderer/types/newMessage'
 import { abortCompletion } from '@renderer/utils/abortController'
-import { isAbortError } from '@renderer/utils/error'
+import { getErrorMessage, isAbortError } from '@renderer/utils/error'
 import { createMainTextBlock, createThinkingBlock } from '@renderer/utils/messageUtils/create'
 import { getMainTextContent } from '@renderer/utils/messageUtils/find'
 import { defaultLanguage } from '@shared/config/constant'
@@ -32,8 +34,11 @@ import FeatureMenus, { FeatureMenusRef } from './components/FeatureMenus'
 import Footer from './components/Footer'
 import InputBar from './components/InputBar'
 
+// 唤醒后等待提问的时长
+const LISTEN_TIMEOUT = 8000
+
 const HomeWindow: FC = () => {
-  const { language, readClipboardAtStartup, windowStyle } = useSettings()
+  const { language, readClipboardAtStartup, windowStyle, transcriptionModel } = useSettings()
   const { theme } = useTheme()
   const { t } = useTranslation()
 
@@ -54,6 +59,9 @@ const HomeWindow: FC = () => {
 
   const [error, setError] = useState<string | null>(null)
 
+  const [listening, setListening] = useState(false)
+  const voiceSendRef = useRef(false)
+
   const { quickAssistantId } = useAppSelector((state) => state.llm)
   const { assistant: currentAssistant } = useAssistant(quickAssistantId)
 
@@ -125,6 +133,11 @@ const HomeWindow: FC = () => {
     window.api.miniWindow.setPin(isPinned)
   }, [isPinned])
 
+  // 窗口布局平铺小助手时由主进程固定
+  useEffect(() => {
+    return window.electron.ipcRenderer.on(IpcChannel.MiniWindowPinChanged, (_, pinned: boolean) => setIsPinned(pinned))
+  }, [])
+
   useEffect(() => {
     window.electron.ipcRenderer.on(IpcChannel.ShowMiniWindow, onWindowShow)
 
@@ -257,6 +270,7 @@ const HomeWindow: FC = () => {
         await fetchChatCompletion({
           messages: messagesForContext,
           assistant: { ...currentAssistant, settings: { streamOutput: true } },
+          interactive: true,
           onChunkReceived: (chunk: Chunk) => {
             switch (chunk.type) {
               case ChunkType.THINKING_DELTA:
@@ -375,6 +389,53 @@ const HomeWindow: FC = () => {
     [userContent, currentAssistant]
   )
 
+  // 转写的问题写入输入框后自动发送
+  useEffect(() => {
+    if (!voiceSendRef.current || !userContent) return
+    voiceSendRef.current = false
+    handleSendMessage()
+  }, [userContent, handleSendMessage])
+
+  // 唤醒词触发时录下接下来的一句话作为问题
+  const listen = useCallback(async () => {
+    if (!transcriptionModel) {
+      window.message.warning(t('miniwindow.voice.no_model'))
+      return
+    }
+    if (audioManager.listening) return
+
+    setListening(true)
+    const finish = () => {
+      clearTimeout(timer)
+      off()
+      setListening(false)
+      audioManager.stop()
+    }
+    const off = audioManager.onSegment(async (segment, sampleRate) => {
+      finish()
+      const text = await transcribeSegment(transcriptionModel, segment, sampleRate).catch((error) => {
+        window.message.error(getErrorMessage(error))
+        return ''
+      })
+      if (!text) return
+      voiceSendRef.current = true
+      setUserInputText(text)
+      setRoute('chat')
+    })
+    const timer = setTimeout(finish, LISTEN_TIMEOUT)
+
+    try {
+      await audioManager.start({ hangoverMs: 800 })
+    } catch (error) {
+      finish()
+      window.message.error(getErrorMessage(error))
+    }
+  }, [t, transcriptionModel])
+
+  useEffect(() => {
+    return window.electron.ipcRenderer.on(IpcChannel.MiniWindowListen, () => listen())
+  }, [listen])
+
   const handlePause = useCallback(() => {
     if (currentAskId.current) {
       abortCompletion(currentAskId.current)
@@ -430,13 +491,16 @@ const HomeWindow: FC = () => {
 
   // Memoize placeholder text
   const inputPlaceholder = useMemo(() => {
+    if (listening) {
+      return t('miniwindow.input.placeholder.listening')
+    }
     if (referenceText && route === 'home') {
       return t('miniwindow.input.placeholder.title')
     }
     return t('miniwindow.input.placeholder.empty', {
       model: quickAssistantId ? currentAssistant.name : currentAssistant.model.name
     })
-  }, [referenceText, route, t, quickAssistantId, currentAssistant])
+  }, [listening, referenceText, route, t, quickAssistantId, currentAssistant])
 
   // Memoize footer props
   const baseFooterProps = useMemo(
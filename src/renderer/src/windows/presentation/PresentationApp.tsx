@@ -0,0 +1,144 @@
+import { useTopicMessages } from '@renderer/hooks/useMessageOperations'
+import { useSettings } from '@renderer/hooks/useSettings'
+import i18n from '@renderer/i18n'
+import MessageContent from '@renderer/pages/home/Messages/MessageContent'
+import MessageErrorBoundary from '@renderer/pages/home/Messages/MessageErrorBoundary'
+import { useAppDispatch, useAppSelector } from '@renderer/store'
+import { loadTopicMessagesThunk } from '@renderer/store/thunk/messageThunk'
+import { defaultLanguage } from '@shared/config/constant'
+import { Empty } from 'antd'
+import { FC, useEffect, useRef, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+const topicId = new URLSearchParams(window.location.search).get('topicId') ?? ''
+
+// 演示时使用的字号，远大于聊天界面的默认字号
+const PRESENTATION_FONT_SIZE = 26
+
+const NEXT_KEYS = ['ArrowRight', 'PageDown', ' ', 'Enter']
+const PREV_KEYS = ['ArrowLeft', 'PageUp', 'Backspace']
+
+/**
+ * 只读的全屏演示窗口，每页显示一条消息，用键盘翻页，Esc 退出
+ */
+const PresentationApp: FC = () => {
+  const { language, messageFont } = useSettings()
+  const { t } = useTranslation()
+  const dispatch = useAppDispatch()
+  const messages = useTopicMessages(topicId)
+  const topic = useAppSelector((state) =>
+    state.assistants.assistants.flatMap((assistant) => assistant.topics).find((topic) => topic.id === topicId)
+  )
+  const [page, setPage] = useState(0)
+  const pageRef = useRef<HTMLDivElement>(null)
+
+  useEffect(() => {
+    i18n.changeLanguage(language || navigator.language || defaultLanguage)
+  }, [language])
+
+  useEffect(() => {
+    dispatch(loadTopicMessagesThunk(topicId, true))
+  }, [dispatch])
+
+  useEffect(() => {
+    const last = Math.max(messages.length - 1, 0)
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        window.api.presentation.exit()
+      } else if (NEXT_KEYS.includes(e.key)) {
+        setPage((page) => Math.min(page + 1, last))
+      } else if (PREV_KEYS.includes(e.key)) {
+        setPage((page) => Math.max(page - 1, 0))
+      } else if (e.key === 'Home') {
+        setPage(0)
+      } else if (e.key === 'End') {
+        setPage(last)
+      } else {
+        return
+      }
+      e.preventDefault()
+    }
+    window.addEventListener('keydown', onKeyDown)
+    return () => window.removeEventListener('keydown', onKeyDown)
+  }, [messages.length])
+
+  useEffect(() => {
+    pageRef.current?.scrollTo({ top: 0 })
+  }, [page])
+
+  const message = messages[page]
+
+  return (
+    <Container>
+      <Header>
+        <span>{topic?.name}</span>
+        <span>{messages.length > 0 && `${page + 1} / ${messages.length}`}</span>
+      </Header>
+      <Page ref={pageRef}>
+        {message ? (
+          <Slide
+            key={message.id}
+            style={{
+              fontFamily: messageFont === 'serif' ? 'var(--font-family-serif)' : 'var(--font-family)',
+              fontSize: PRESENTATION_FONT_SIZE
+            }}>
+            <Author>{message.role === 'user' ? t('common.you') : message.model?.name}</Author>
+            <MessageErrorBoundary>
+              <MessageContent message={message} />
+            </MessageErrorBoundary>
+          </Slide>
+        ) : (
+          <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} />
+        )}
+      </Page>
+      <Footer>{t('presentation.hint')}</Footer>
+    </Container>
+  )
+}
+
+const Container = styled.div`
+  display: flex;
+  flex-direction: column;
+  height: 100%;
+  background-color: var(--color-background);
+  user-select: text;
+`
+
+const Header = styled.div`
+  display: flex;
+  justify-content: space-between;
+  padding: 16px 32px;
+  font-size: 16px;
+  color: var(--color-text-3);
+`
+
+const Page = styled.div`
+  flex: 1;
+  overflow-y: auto;
+  display: flex;
+  justify-content: center;
+  padding: 0 32px;
+`
+
+const Slide = styled.div`
+  width: 100%;
+  max-width: 1100px;
+  line-height: 1.6;
+`
+
+const Author = styled.div`
+  margin-bottom: 16px;
+  font-size: 18px;
+  font-weight: 500;
+  color: var(--color-text-2);
+`
+
+const Footer = styled.div`
+  padding: 12px 32px;
+  font-size: 13px;
+  text-align: center;
+  color: var(--color-text-3);
+`
+
+export default PresentationApp
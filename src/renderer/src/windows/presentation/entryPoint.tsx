@@ -0,0 +1,38 @@
+import '@renderer/assets/styles/index.scss'
+import '@ant-design/v5-patch-for-react-19'
+import '@renderer/databases'
+
+import AntdProvider from '@renderer/context/AntdProvider'
+import { ThemeProvider } from '@renderer/context/ThemeProvider'
+import storeSyncService from '@renderer/services/StoreSyncService'
+import store, { persistor } from '@renderer/store'
+import { message } from 'antd'
+import { FC } from 'react'
+import { createRoot } from 'react-dom/client'
+import { Provider } from 'react-redux'
+import { PersistGate } from 'redux-persist/integration/react'
+
+import PresentationApp from './PresentationApp'
+
+storeSyncService.subscribe()
+
+const App: FC = () => {
+  const [messageApi, messageContextHolder] = message.useMessage()
+  window.message = messageApi
+
+  return (
+    <Provider store={store}>
+      <ThemeProvider>
+        <AntdProvider>
+          <PersistGate loading={null} persistor={persistor}>
+            {messageContextHolder}
+            <PresentationApp />
+          </PersistGate>
+        </AntdProvider>
+      </ThemeProvider>
+    </Provider>
+  )
+}
+
+const root = createRoot(document.getElementById('root') as HTMLElement)
+root.render(<App />)
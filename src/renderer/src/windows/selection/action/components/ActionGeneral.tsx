@@ -17,7 +17,7 @@ import React, { FC, useCallback, useEffect, useMemo, useRef, useState } from 're
 import { useTranslation } from 'react-i18next'
 import styled from 'styled-components'
 
-import { processMessages } from './ActionUtils'
+import { deliverActionResult, processMessages } from './ActionUtils'
 import WindowFooter from './WindowFooter'
 
 interface Props {
@@ -104,6 +104,7 @@ const ActionGeneral: FC<Props> = React.memo(({ action, scrollToBottom }) => {
     const onFinish = (content: string) => {
       setContentToCopy(content)
       setIsLoading(false)
+      deliverActionResult(content, action)
     }
     const onError = (error: Error) => {
       setIsLoading(false)
@@ -120,7 +121,7 @@ const ActionGeneral: FC<Props> = React.memo(({ action, scrollToBottom }) => {
       onFinish,
       onError
     )
-  }, [scrollToBottom])
+  }, [action, scrollToBottom])
 
   useEffect(() => {
     fetchResult()
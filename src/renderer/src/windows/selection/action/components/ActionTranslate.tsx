@@ -22,7 +22,7 @@ import { FC, useCallback, useEffect, useMemo, useRef, useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import styled from 'styled-components'
 
-import { processMessages } from './ActionUtils'
+import { deliverActionResult, processMessages } from './ActionUtils'
 import WindowFooter from './WindowFooter'
 interface Props {
   action: ActionItem
@@ -110,6 +110,7 @@ const ActionTranslate: FC<Props> = ({ action, scrollToBottom }) => {
     const onFinish = (content: string) => {
       setContentToCopy(content)
       setIsLoading(false)
+      deliverActionResult(content, action)
     }
     const onError = (error: Error) => {
       setIsLoading(false)
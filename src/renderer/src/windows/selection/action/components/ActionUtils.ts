@@ -1,11 +1,15 @@
+import i18n from '@renderer/i18n'
 import { fetchChatCompletion } from '@renderer/services/ApiService'
 import { getAssistantMessage, getUserMessage } from '@renderer/services/MessagesService'
+import { NotificationService } from '@renderer/services/NotificationService'
 import store from '@renderer/store'
 import { updateOneBlock, upsertManyBlocks, upsertOneBlock } from '@renderer/store/messageBlock'
 import { newMessagesActions } from '@renderer/store/newMessage'
 import { Assistant, Topic } from '@renderer/types'
 import { Chunk, ChunkType } from '@renderer/types/chunk'
 import { AssistantMessageStatus, MessageBlockStatus } from '@renderer/types/newMessage'
+import type { ActionItem } from '@renderer/types/selectionTypes'
+import { uuid } from '@renderer/utils'
 import { isAbortError } from '@renderer/utils/error'
 import { createMainTextBlock, createThinkingBlock } from '@renderer/utils/messageUtils/create'
 
@@ -52,6 +56,7 @@ export const processMessages = async (
     await fetchChatCompletion({
       messages: [userMessage],
       assistant: { ...assistant, settings: { streamOutput: true } },
+      interactive: true,
       onChunkReceived: (chunk: Chunk) => {
         switch (chunk.type) {
           case ChunkType.THINKING_DELTA:
@@ -149,3 +154,29 @@ export const processMessages = async (
     console.error('Error fetching result:', err)
   }
 }
+
+/**
+ * 按设置把结果写回剪贴板或通过系统通知发送，方便直接在原应用中使用
+ */
+export const deliverActionResult = (content: string, action: ActionItem) => {
+  if (!content) return
+
+  const { isAutoCopyResult, isNotifyResult } = store.getState().selectionStore
+
+  if (isAutoCopyResult) {
+    window.api.selection.writeToClipboard(content)
+  }
+
+  // 窗口在前台时结果已经可见，不再重复通知
+  if (isNotifyResult && !document.hasFocus()) {
+    NotificationService.getInstance().send({
+      id: uuid(),
+      type: 'success',
+      title: action.isBuiltIn ? i18n.t(action.name) : action.name,
+      message: content.length > 200 ? content.slice(0, 200) + '...' : content,
+      timestamp: Date.now(),
+      source: 'assistant',
+      channel: 'system'
+    })
+  }
+}
@@ -0,0 +1,235 @@
+import { isTranscriptionModel } from '@renderer/config/models'
+import { useAllProviders } from '@renderer/hooks/useProvider'
+import { useSettings } from '@renderer/hooks/useSettings'
+import i18n from '@renderer/i18n'
+import { audioManager } from '@renderer/services/AudioManager'
+import { EVENT_NAMES, EventEmitter } from '@renderer/services/EventService'
+import { getModelUniqId } from '@renderer/services/ModelService'
+import {
+  formatTranscript,
+  summarizeTranscript,
+  transcribeSegment,
+  TranscriptSegment
+} from '@renderer/services/TranscriptionService'
+import { useAppDispatch } from '@renderer/store'
+import { setTranscriptionModel } from '@renderer/store/settings'
+import { getErrorMessage } from '@renderer/utils'
+import { defaultLanguage } from '@shared/config/constant'
+import { Button, Empty, Select, Tooltip } from 'antd'
+import dayjs from 'dayjs'
+import { Mic, MicOff, X } from 'lucide-react'
+import { FC, useEffect, useMemo, useRef, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import styled from 'styled-components'
+
+// 较长的语音段也会被切开，保证转写结果持续输出
+const MAX_SEGMENT_MS = 8000
+
+const TranscriptionApp: FC = () => {
+  const { language, transcriptionModel } = useSettings()
+  const { t } = useTranslation()
+  const dispatch = useAppDispatch()
+  const providers = useAllProviders()
+
+  const [listening, setListening] = useState(false)
+  const [speaking, setSpeaking] = useState(false)
+  const [segments, setSegments] = useState<TranscriptSegment[]>([])
+  const [summary, setSummary] = useState('')
+  const [summarizing, setSummarizing] = useState(false)
+  const listRef = useRef<HTMLDivElement>(null)
+
+  const models = useMemo(
+    () => providers.flatMap((provider) => provider.models.filter(isTranscriptionModel)),
+    [providers]
+  )
+
+  useEffect(() => {
+    i18n.changeLanguage(language || navigator.language || defaultLanguage)
+  }, [language])
+
+  useEffect(() => {
+    const offStarted = EventEmitter.on(EVENT_NAMES.SPEECH_STARTED, () => setSpeaking(true))
+    const offEnded = EventEmitter.on(EVENT_NAMES.SPEECH_ENDED, () => setSpeaking(false))
+    return () => {
+      offStarted()
+      offEnded()
+      audioManager.stop()
+    }
+  }, [])
+
+  useEffect(() => {
+    if (!listening || !transcriptionModel) return
+
+    return audioManager.onSegment(async (segment, sampleRate) => {
+      const time = Date.now() - (segment.length / sampleRate) * 1000
+      const text = await transcribeSegment(transcriptionModel, segment, sampleRate).catch((error) => {
+        window.message.error({ content: getErrorMessage(error), key: 'transcription-error' })
+        return ''
+      })
+      if (!text) return
+
+      const item = { id: crypto.randomUUID(), text, time }
+      setSegments((prev) => [...prev, item].sort((a, b) => a.time - b.time))
+      EventEmitter.emit(EVENT_NAMES.TRANSCRIPT_PARTIAL, item)
+    })
+  }, [listening, transcriptionModel])
+
+  useEffect(() => {
+    listRef.current?.scrollTo({ top: listRef.current.scrollHeight })
+  }, [segments, summary])
+
+  const onToggle = async () => {
+    if (listening) {
+      await audioManager.stop()
+      setListening(false)
+      setSpeaking(false)
+      return
+    }
+    try {
+      await audioManager.start({ hangoverMs: 600, maxSegmentMs: MAX_SEGMENT_MS })
+      setListening(true)
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+    }
+  }
+
+  const onSummarize = async () => {
+    setSummarizing(true)
+    try {
+      setSummary(await summarizeTranscript(segments))
+    } catch (error) {
+      window.message.error(getErrorMessage(error))
+    } finally {
+      setSummarizing(false)
+    }
+  }
+
+  const onSendToChat = () => {
+    const transcript = formatTranscript(segments)
+    window.api.transcription.sendToChat(summary ? `${summary}\n\n---\n\n${transcript}` : transcript)
+  }
+
+  return (
+    <Container>
+      <Header>
+        <Title>{t('transcription.title')}</Title>
+        <Select
+          size="small"
+          style={{ width: 180 }}
+          value={getModelUniqId(transcriptionModel) || undefined}
+          placeholder={t('transcription.select_model')}
+          onChange={(id) => dispatch(setTranscriptionModel(models.find((model) => getModelUniqId(model) === id)))}
+          options={models.map((model) => ({ value: getModelUniqId(model), label: model.name }))}
+        />
+        <Tooltip title={listening ? t('transcription.stop') : t('transcription.start')}>
+          <Button
+            size="small"
+            type={listening ? 'primary' : 'default'}
+            danger={listening}
+            disabled={!transcriptionModel}
+            icon={listening ? <MicOff size={14} /> : <Mic size={14} />}
+            onClick={onToggle}
+          />
+        </Tooltip>
+        <Button size="small" type="text" icon={<X size={14} />} onClick={() => window.api.transcription.close()} />
+      </Header>
+      <Transcript ref={listRef}>
+        {!segments.length && !summary && (
+          <Empty
+            image={Empty.PRESENTED_IMAGE_SIMPLE}
+            description={models.length ? t('transcription.empty') : t('transcription.no_model')}
+          />
+        )}
+        {segments.map((segment) => (
+          <Line key={segment.id}>
+            <Time>{dayjs(segment.time).format('HH:mm:ss')}</Time>
+            <span>{segment.text}</span>
+          </Line>
+        ))}
+        {speaking && <Listening>{t('transcription.listening')}</Listening>}
+        {summary && <Summary>{summary}</Summary>}
+      </Transcript>
+      <Footer>
+        <Button size="small" disabled={!segments.length} onClick={() => setSegments([])}>
+          {t('transcription.clear')}
+        </Button>
+        <Button size="small" disabled={!segments.length} loading={summarizing} onClick={onSummarize}>
+          {t('transcription.summarize')}
+        </Button>
+        <Button size="small" type="primary" disabled={!segments.length} onClick={onSendToChat}>
+          {t('transcription.send_to_chat')}
+        </Button>
+      </Footer>
+    </Container>
+  )
+}
+
+const Container = styled.div`
+  display: flex;
+  flex-direction: column;
+  height: 100%;
+  background-color: var(--color-background);
+`
+
+const Header = styled.div`
+  display: flex;
+  align-items: center;
+  gap: 6px;
+  padding: 8px 10px;
+  border-bottom: 0.5px solid var(--color-border);
+  -webkit-app-region: drag;
+
+  .ant-select,
+  button {
+    -webkit-app-region: no-drag;
+  }
+`
+
+const Title = styled.div`
+  flex: 1;
+  font-weight: 500;
+`
+
+const Transcript = styled.div`
+  flex: 1;
+  overflow-y: auto;
+  padding: 10px;
+  display: flex;
+  flex-direction: column;
+  gap: 8px;
+`
+
+const Line = styled.div`
+  display: flex;
+  gap: 8px;
+  line-height: 1.5;
+`
+
+const Time = styled.span`
+  flex-shrink: 0;
+  font-size: 12px;
+  color: var(--color-text-3);
+  line-height: 1.8;
+`
+
+const Listening = styled.div`
+  font-size: 12px;
+  color: var(--color-text-3);
+`
+
+const Summary = styled.div`
+  white-space: pre-wrap;
+  padding: 8px;
+  border-radius: 6px;
+  background-color: var(--color-background-soft);
+`
+
+const Footer = styled.div`
+  display: flex;
+  justify-content: flex-end;
+  gap: 6px;
+  padding: 8px 10px;
+  border-top: 0.5px solid var(--color-border);
+`
+
+export default TranscriptionApp